@@ -13,8 +13,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use hp_groth16::{Groth16Error, Proof, Scalar, VerificationKey};
+use hp_groth16::{
+    CommittedBatchItem, Groth16Error, Proof, ProofCommitment, Scalar, VerificationKey, VkCommitment,
+};
 use sp_runtime_interface::runtime_interface;
+use sp_std::vec::Vec;
 
 use crate::VerifyError;
 
@@ -37,6 +40,32 @@ pub trait Groth16Bn254Verify {
     fn validate_key(vk: VerificationKey) -> Result<(), VerifyError> {
         hp_groth16::validate_key::<hp_groth16::Bn254>(vk).map_err(Into::into)
     }
+    fn verify_with_commitment(
+        vk: VerificationKey,
+        vk_commitment: VkCommitment,
+        proof: Proof,
+        proof_commitment: ProofCommitment,
+        pubs: &[Scalar],
+    ) -> Result<bool, VerifyError> {
+        hp_groth16::verify_proof_with_commitment::<hp_groth16::Bn254>(
+            vk,
+            vk_commitment,
+            proof,
+            proof_commitment,
+            pubs,
+        )
+        .map_err(Into::into)
+    }
+    fn validate_key_with_commitment(
+        vk: VerificationKey,
+        vk_commitment: VkCommitment,
+    ) -> Result<(), VerifyError> {
+        hp_groth16::validate_key_with_commitment::<hp_groth16::Bn254>(vk, vk_commitment)
+            .map_err(Into::into)
+    }
+    fn verify_batch_with_commitments(batch: Vec<CommittedBatchItem>) -> Result<bool, VerifyError> {
+        hp_groth16::verify_batch_with_commitments::<hp_groth16::Bn254>(batch).map_err(Into::into)
+    }
 }
 
 #[runtime_interface]
@@ -47,4 +76,104 @@ pub trait Groth16Bls12_381Verify {
     fn validate_key(vk: VerificationKey) -> Result<(), VerifyError> {
         hp_groth16::validate_key::<hp_groth16::Bls12_381>(vk).map_err(Into::into)
     }
+    fn verify_with_commitment(
+        vk: VerificationKey,
+        vk_commitment: VkCommitment,
+        proof: Proof,
+        proof_commitment: ProofCommitment,
+        pubs: &[Scalar],
+    ) -> Result<bool, VerifyError> {
+        hp_groth16::verify_proof_with_commitment::<hp_groth16::Bls12_381>(
+            vk,
+            vk_commitment,
+            proof,
+            proof_commitment,
+            pubs,
+        )
+        .map_err(Into::into)
+    }
+    fn validate_key_with_commitment(
+        vk: VerificationKey,
+        vk_commitment: VkCommitment,
+    ) -> Result<(), VerifyError> {
+        hp_groth16::validate_key_with_commitment::<hp_groth16::Bls12_381>(vk, vk_commitment)
+            .map_err(Into::into)
+    }
+    fn verify_batch_with_commitments(batch: Vec<CommittedBatchItem>) -> Result<bool, VerifyError> {
+        hp_groth16::verify_batch_with_commitments::<hp_groth16::Bls12_381>(batch)
+            .map_err(Into::into)
+    }
+}
+
+#[runtime_interface]
+pub trait Groth16Bls12_377Verify {
+    fn verify(vk: VerificationKey, proof: Proof, pubs: &[Scalar]) -> Result<bool, VerifyError> {
+        hp_groth16::verify_proof::<hp_groth16::Bls12_377>(vk, proof, pubs).map_err(Into::into)
+    }
+    fn validate_key(vk: VerificationKey) -> Result<(), VerifyError> {
+        hp_groth16::validate_key::<hp_groth16::Bls12_377>(vk).map_err(Into::into)
+    }
+    fn verify_with_commitment(
+        vk: VerificationKey,
+        vk_commitment: VkCommitment,
+        proof: Proof,
+        proof_commitment: ProofCommitment,
+        pubs: &[Scalar],
+    ) -> Result<bool, VerifyError> {
+        hp_groth16::verify_proof_with_commitment::<hp_groth16::Bls12_377>(
+            vk,
+            vk_commitment,
+            proof,
+            proof_commitment,
+            pubs,
+        )
+        .map_err(Into::into)
+    }
+    fn validate_key_with_commitment(
+        vk: VerificationKey,
+        vk_commitment: VkCommitment,
+    ) -> Result<(), VerifyError> {
+        hp_groth16::validate_key_with_commitment::<hp_groth16::Bls12_377>(vk, vk_commitment)
+            .map_err(Into::into)
+    }
+    fn verify_batch_with_commitments(batch: Vec<CommittedBatchItem>) -> Result<bool, VerifyError> {
+        hp_groth16::verify_batch_with_commitments::<hp_groth16::Bls12_377>(batch)
+            .map_err(Into::into)
+    }
+}
+
+#[runtime_interface]
+pub trait Groth16Bw6_761Verify {
+    fn verify(vk: VerificationKey, proof: Proof, pubs: &[Scalar]) -> Result<bool, VerifyError> {
+        hp_groth16::verify_proof::<hp_groth16::BW6_761>(vk, proof, pubs).map_err(Into::into)
+    }
+    fn validate_key(vk: VerificationKey) -> Result<(), VerifyError> {
+        hp_groth16::validate_key::<hp_groth16::BW6_761>(vk).map_err(Into::into)
+    }
+    fn verify_with_commitment(
+        vk: VerificationKey,
+        vk_commitment: VkCommitment,
+        proof: Proof,
+        proof_commitment: ProofCommitment,
+        pubs: &[Scalar],
+    ) -> Result<bool, VerifyError> {
+        hp_groth16::verify_proof_with_commitment::<hp_groth16::BW6_761>(
+            vk,
+            vk_commitment,
+            proof,
+            proof_commitment,
+            pubs,
+        )
+        .map_err(Into::into)
+    }
+    fn validate_key_with_commitment(
+        vk: VerificationKey,
+        vk_commitment: VkCommitment,
+    ) -> Result<(), VerifyError> {
+        hp_groth16::validate_key_with_commitment::<hp_groth16::BW6_761>(vk, vk_commitment)
+            .map_err(Into::into)
+    }
+    fn verify_batch_with_commitments(batch: Vec<CommittedBatchItem>) -> Result<bool, VerifyError> {
+        hp_groth16::verify_batch_with_commitments::<hp_groth16::BW6_761>(batch).map_err(Into::into)
+    }
 }
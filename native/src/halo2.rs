@@ -0,0 +1,352 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::VerifyError;
+use codec::{Decode, Encode};
+use sp_runtime_interface::{pass_by::PassByCodec, runtime_interface};
+use sp_std::vec::Vec;
+
+/// Size in bytes of a compressed G2 point.
+pub const G2_SIZE: usize = 64;
+/// Size in bytes of an instance value: a big endian scalar field element.
+pub const FR_SIZE: usize = 32;
+
+/// The KZG multi-open scheme used by the prover.
+#[derive(PassByCodec, Encode, Decode, Clone, Copy, Debug, PartialEq)]
+pub enum Halo2Scheme {
+    /// `GWC19`.
+    Gwc,
+    /// `BDFG21`, aka SHPLONK.
+    Shplonk,
+}
+
+/// The hash function used by the prover's Fiat-Shamir transcript.
+#[derive(PassByCodec, Encode, Decode, Clone, Copy, Debug, PartialEq)]
+pub enum Halo2Transcript {
+    /// The halo2 default `Blake2bWrite` with `Challenge255`.
+    Blake2b,
+    /// The keccak256 EVM transcript of `snark-verifier`.
+    Keccak,
+}
+
+#[cfg(feature = "std")]
+mod depth_limit;
+
+#[cfg(feature = "std")]
+mod implementation {
+    use super::depth_limit::DepthLimited;
+    use super::*;
+    use halo2_proofs::{
+        arithmetic::CurveAffine,
+        halo2curves::{
+            bn256::{Bn256, Fq, Fr, G1Affine, G2Affine},
+            ff::PrimeField,
+            group::{prime::PrimeCurveAffine, GroupEncoding},
+        },
+        transcript::{Blake2bRead, Challenge255, TranscriptReadBuffer},
+    };
+    use snark_verifier::{
+        loader::native::NativeLoader,
+        pcs::kzg::{Bdfg21, Gwc19, KzgAs, KzgDecidingKey, LimbsEncoding},
+        system::halo2::transcript::evm::EvmTranscript,
+        util::{
+            arithmetic::{fe_from_limbs, root_of_unity},
+            transcript::TranscriptRead,
+        },
+        verifier::{plonk::PlonkProtocol, plonk::PlonkVerifier, SnarkVerifier},
+    };
+    use std::marker::PhantomData;
+
+    /// The accumulator encoding used by the aggregation circuits of both `snark-verifier` and
+    /// its forks.
+    const LIMBS: usize = 4;
+    const BITS: usize = 68;
+
+    type Halo2Verifier<S> = PlonkVerifier<KzgAs<Bn256, S>, LimbsEncoding<LIMBS, BITS>>;
+
+    /// The maximum nesting depth of a serialized protocol, that bounds the recursion in the
+    /// deserialization, evaluation and drop of its expressions: every level of an expression
+    /// takes two of them.
+    const MAX_PROTOCOL_DEPTH: usize = 256;
+
+    fn ensure(condition: bool, reason: &'static str) -> Result<(), &'static str> {
+        condition.then_some(()).ok_or(reason)
+    }
+
+    /// Check that the protocol is coherent: the `snark-verifier` code trusts it and would panic
+    /// on out of range indexes.
+    fn check_protocol(p: &PlonkProtocol<G1Affine>) -> Result<(), &'static str> {
+        let k = p.domain.k;
+        ensure((1..=Fr::S as usize).contains(&k), "Unsupported domain size")?;
+        let gen = root_of_unity::<Fr>(k);
+        ensure(
+            p.domain.n == 1 << k
+                && p.domain.gen == gen
+                && p.domain.gen_inv == gen.invert().unwrap()
+                && p.domain.n_inv == Fr::from(p.domain.n as u64).invert().unwrap(),
+            "Inconsistent domain",
+        )?;
+        ensure(
+            p.num_witness.len() == p.num_challenge.len(),
+            "Witnesses and challenges phases don't match",
+        )?;
+        ensure(
+            p.num_instance.iter().all(|&n| n <= p.domain.n),
+            "Instance column longer than the domain",
+        )?;
+        ensure(
+            p.instance_committing_key.is_none(),
+            "Committed instances are not supported",
+        )?;
+        ensure(p.linearization.is_none(), "Linearization is not supported")?;
+        ensure(
+            p.quotient.chunk_degree > 0 && p.quotient.numerator.degree() > 1,
+            "Invalid quotient",
+        )?;
+
+        // The polynomials are indexed as preprocessed, instances, witnesses and then quotient.
+        let num_instance = p.preprocessed.len() + p.num_instance.len();
+        let num_poly = num_instance + p.num_witness.iter().sum::<usize>() + 1;
+        let quotient = num_poly - 1;
+        let num_challenge = p.num_challenge.iter().sum::<usize>();
+        let in_domain = |rotation: i32| (rotation.unsigned_abs() as usize) < p.domain.n;
+
+        let used_queries = p.quotient.numerator.used_query();
+        let queries = p.queries.iter().chain(&p.evaluations).chain(&used_queries);
+        for query in queries.clone() {
+            ensure(
+                query.poly < num_poly && in_domain(query.rotation.0),
+                "Query out of range",
+            )?;
+        }
+        // Every query should be evaluated by the prover or computed by the verifier.
+        for query in queries {
+            ensure(
+                (p.preprocessed.len()..num_instance).contains(&query.poly)
+                    || (query.poly == quotient && query.rotation.0 == 0)
+                    || p.evaluations.contains(query),
+                "Missing evaluation",
+            )?;
+        }
+        ensure(
+            p.quotient
+                .numerator
+                .used_challenge()
+                .into_iter()
+                .all(|c| c < num_challenge),
+            "Challenge out of range",
+        )?;
+        ensure(
+            p.quotient
+                .numerator
+                .used_langrange()
+                .into_iter()
+                .all(in_domain),
+            "Lagrange polynomial out of range",
+        )?;
+        for indices in &p.accumulator_indices {
+            ensure(indices.len() == 4 * LIMBS, "Invalid accumulator encoding")?;
+            ensure(
+                indices
+                    .iter()
+                    .all(|&(column, row)| p.num_instance.get(column).is_some_and(|&n| row < n)),
+                "Accumulator out of range",
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Deserialize and check the protocol: its quotient numerator should have the declared
+    /// degree, that is used to charge the verification.
+    pub fn protocol(
+        bytes: &[u8],
+        quotient_degree: u32,
+    ) -> Result<PlonkProtocol<G1Affine>, VerifyError> {
+        use bincode::Options;
+
+        // The limit avoids to allocate more than the given bytes on malformed lengths, and the
+        // depth limit to overflow the stack on deeply nested expressions.
+        let protocol: PlonkProtocol<G1Affine> = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .with_limit(bytes.len() as u64)
+            .deserialize_seed(
+                DepthLimited::new(PhantomData::<PlonkProtocol<G1Affine>>, MAX_PROTOCOL_DEPTH),
+                bytes,
+            )
+            .map_err(|e| log::debug!("Cannot deserialize protocol: {:?}", e))
+            .map_err(|_| VerifyError::InvalidVerificationKey)?;
+        check_protocol(&protocol)
+            .and_then(|_| {
+                ensure(
+                    protocol.quotient.numerator.degree() == quotient_degree as usize,
+                    "Quotient degree doesn't match",
+                )
+            })
+            .map_err(|e| log::debug!("Invalid protocol: {}", e))
+            .map_err(|_| VerifyError::InvalidVerificationKey)?;
+        Ok(protocol)
+    }
+
+    pub fn deciding_key(s_g2: &[u8; G2_SIZE]) -> Result<KzgDecidingKey<Bn256>, VerifyError> {
+        let mut repr = <G2Affine as GroupEncoding>::Repr::default();
+        repr.as_mut().copy_from_slice(s_g2);
+        let s_g2: G2Affine = Option::from(G2Affine::from_bytes(&repr))
+            .filter(|p: &G2Affine| !bool::from(p.is_identity()))
+            .ok_or_else(|| log::debug!("Invalid [s]_2 point"))
+            .map_err(|_| VerifyError::InvalidVerificationKey)?;
+        Ok((G1Affine::generator(), G2Affine::generator(), s_g2).into())
+    }
+
+    fn instances(
+        protocol: &PlonkProtocol<G1Affine>,
+        raw: &[Vec<[u8; FR_SIZE]>],
+    ) -> Result<Vec<Vec<Fr>>, VerifyError> {
+        if raw.len() != protocol.num_instance.len()
+            || raw
+                .iter()
+                .zip(&protocol.num_instance)
+                .any(|(column, &n)| column.len() != n)
+        {
+            log::debug!("Instances don't match the protocol shape");
+            return Err(VerifyError::InvalidInput);
+        }
+        let instances = raw
+            .iter()
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|value| {
+                        let mut repr = *value;
+                        repr.reverse();
+                        Option::from(Fr::from_repr(repr))
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| log::debug!("Instance value not in field"))
+            .map_err(|_| VerifyError::InvalidInput)?;
+        // `LimbsEncoding` expects the accumulators to be valid points, and panics otherwise.
+        for indices in &protocol.accumulator_indices {
+            let limbs = indices
+                .iter()
+                .map(|&(column, row)| instances[column][row])
+                .collect::<Vec<_>>();
+            let [lhs_x, lhs_y, rhs_x, rhs_y]: [Fq; 4] = core::array::from_fn(|i| {
+                fe_from_limbs::<_, _, LIMBS, BITS>(
+                    limbs[i * LIMBS..(i + 1) * LIMBS]
+                        .try_into()
+                        .expect("Checked accumulator length. qed"),
+                )
+            });
+            if [(lhs_x, lhs_y), (rhs_x, rhs_y)]
+                .into_iter()
+                .any(|(x, y)| G1Affine::from_xy(x, y).is_none().into())
+            {
+                log::debug!("Invalid accumulator in instances");
+                return Err(VerifyError::InvalidInput);
+            }
+        }
+        Ok(instances)
+    }
+
+    fn verify_with<T>(
+        scheme: Halo2Scheme,
+        dk: &KzgDecidingKey<Bn256>,
+        protocol: &PlonkProtocol<G1Affine>,
+        instances: &[Vec<Fr>],
+        transcript: &mut T,
+    ) -> Result<(), VerifyError>
+    where
+        T: TranscriptRead<G1Affine, NativeLoader>,
+    {
+        macro_rules! run {
+            ($verifier:ty) => {{
+                let proof = <$verifier>::read_proof(dk, protocol, instances, transcript)
+                    .map_err(|e| log::debug!("Cannot read proof: {:?}", e))
+                    .map_err(|_| VerifyError::InvalidProofData)?;
+                <$verifier>::verify(dk, protocol, instances, &proof)
+                    .map_err(|e| log::debug!("Cannot verify proof: {:?}", e))
+                    .map_err(|_| VerifyError::VerifyError)
+            }};
+        }
+        match scheme {
+            Halo2Scheme::Gwc => run!(Halo2Verifier<Gwc19>),
+            Halo2Scheme::Shplonk => run!(Halo2Verifier<Bdfg21>),
+        }
+    }
+
+    pub fn verify(
+        scheme: Halo2Scheme,
+        transcript: Halo2Transcript,
+        s_g2: &[u8; G2_SIZE],
+        protocol: &[u8],
+        quotient_degree: u32,
+        proof: &[u8],
+        instances: &[Vec<[u8; FR_SIZE]>],
+    ) -> Result<(), VerifyError> {
+        let dk = deciding_key(s_g2)?;
+        let protocol = self::protocol(protocol, quotient_degree)?;
+        let instances = self::instances(&protocol, instances)?;
+        match transcript {
+            Halo2Transcript::Blake2b => verify_with(
+                scheme,
+                &dk,
+                &protocol,
+                &instances,
+                &mut Blake2bRead::<_, G1Affine, Challenge255<_>>::init(proof),
+            ),
+            Halo2Transcript::Keccak => verify_with(
+                scheme,
+                &dk,
+                &protocol,
+                &instances,
+                &mut EvmTranscript::<G1Affine, NativeLoader, _, _>::new(proof),
+            ),
+        }
+        .map(|_| log::trace!("verified"))
+    }
+}
+
+#[runtime_interface]
+pub trait Halo2Verify {
+    fn verify(
+        scheme: Halo2Scheme,
+        transcript: Halo2Transcript,
+        s_g2: &[u8; G2_SIZE],
+        protocol: &[u8],
+        quotient_degree: u32,
+        proof: &[u8],
+        instances: &[Vec<[u8; FR_SIZE]>],
+    ) -> Result<(), VerifyError> {
+        implementation::verify(
+            scheme,
+            transcript,
+            s_g2,
+            protocol,
+            quotient_degree,
+            proof,
+            instances,
+        )
+    }
+
+    fn validate_vk(
+        s_g2: &[u8; G2_SIZE],
+        protocol: &[u8],
+        quotient_degree: u32,
+    ) -> Result<(), VerifyError> {
+        implementation::deciding_key(s_g2)?;
+        implementation::protocol(protocol, quotient_degree).map(|_| ())
+    }
+}
@@ -19,7 +19,13 @@ use codec::{Decode, Encode};
 use sp_runtime_interface::pass_by::PassByCodec;
 
 mod groth16;
+mod halo2;
+mod plonk;
 mod risc0;
+mod sp1;
+mod stark;
+mod tee;
+mod ultrahonk;
 mod ultraplonk;
 mod zksync;
 
@@ -47,12 +53,19 @@ impl From<VerifyError> for hp_verifiers::VerifyError {
 pub use zksync::zksync_verify;
 pub use zksync::PROOF_SIZE as ZKSYNC_PROOF_SIZE;
 pub use zksync::PUBS_SIZE as ZKSYNC_PUBS_SIZE;
+pub use zksync::VK_SIZE as ZKSYNC_VK_SIZE;
 #[cfg(feature = "std")]
 pub use zksync_verify::HostFunctions as ZksyncVerifierHostFunctions;
 
 pub use risc0::risc_0_verify;
 #[cfg(feature = "std")]
 pub use risc0::risc_0_verify::HostFunctions as Risc0VerifierHostFunctions;
+pub use risc0::{Risc0ReceiptKind, Risc0Version};
+
+pub use sp1::sp_1_verify;
+#[cfg(feature = "std")]
+pub use sp1::sp_1_verify::HostFunctions as Sp1VerifierHostFunctions;
+pub use sp1::Sp1Version;
 
 pub use ultraplonk::ultraplonk_verify;
 #[cfg(feature = "std")]
@@ -61,12 +74,46 @@ pub use ultraplonk::PROOF_SIZE as ULTRAPLONK_PROOF_SIZE;
 pub use ultraplonk::PUBS_SIZE as ULTRAPLONK_PUBS_SIZE;
 pub use ultraplonk::VK_SIZE as ULTRAPLONK_VK_SIZE;
 
+pub use ultrahonk::ultrahonk_verify;
+#[cfg(feature = "std")]
+pub use ultrahonk::ultrahonk_verify::HostFunctions as UltrahonkVerifierHostFunctions;
+pub use ultrahonk::PROOF_SIZE as ULTRAHONK_PROOF_SIZE;
+pub use ultrahonk::PUBS_SIZE as ULTRAHONK_PUBS_SIZE;
+pub use ultrahonk::VK_SIZE as ULTRAHONK_VK_SIZE;
+
+pub use groth16::groth_16_bls_12_377_verify;
+#[cfg(feature = "std")]
+pub use groth16::groth_16_bls_12_377_verify::HostFunctions as Groth16Bls12_377VerifierHostFunctions;
 pub use groth16::groth_16_bls_12_381_verify;
 #[cfg(feature = "std")]
 pub use groth16::groth_16_bls_12_381_verify::HostFunctions as Groth16Bls12VerifierHostFunctions;
 pub use groth16::groth_16_bn_254_verify;
 #[cfg(feature = "std")]
 pub use groth16::groth_16_bn_254_verify::HostFunctions as Groth16Bn254VerifierHostFunctions;
+pub use groth16::groth_16_bw_6_761_verify;
+#[cfg(feature = "std")]
+pub use groth16::groth_16_bw_6_761_verify::HostFunctions as Groth16Bw6_761VerifierHostFunctions;
+
+pub use halo2::halo_2_verify;
+#[cfg(feature = "std")]
+pub use halo2::halo_2_verify::HostFunctions as Halo2VerifierHostFunctions;
+pub use halo2::{Halo2Scheme, Halo2Transcript, FR_SIZE as HALO2_FR_SIZE, G2_SIZE as HALO2_G2_SIZE};
+
+pub use plonk::plonk_verify;
+#[cfg(feature = "std")]
+pub use plonk::plonk_verify::HostFunctions as PlonkVerifierHostFunctions;
+
+pub use stark::plonky_2_verify;
+#[cfg(feature = "std")]
+pub use stark::plonky_2_verify::HostFunctions as Plonky2VerifierHostFunctions;
+pub use stark::{
+    Plonky2Hasher, MAX_DEGREE_BITS as PLONKY2_MAX_DEGREE_BITS,
+    MAX_NUM_QUERY_ROUNDS as PLONKY2_MAX_NUM_QUERY_ROUNDS,
+};
+
+pub use tee::tee_verify;
+#[cfg(feature = "std")]
+pub use tee::tee_verify::HostFunctions as TeeVerifierHostFunctions;
 
 #[cfg(feature = "std")]
 pub type HLNativeHostFunctions = (
@@ -75,4 +122,12 @@ pub type HLNativeHostFunctions = (
     UltraplonkVerifierHostFunctions,
     Groth16Bn254VerifierHostFunctions,
     Groth16Bls12VerifierHostFunctions,
+    Groth16Bls12_377VerifierHostFunctions,
+    Groth16Bw6_761VerifierHostFunctions,
+    PlonkVerifierHostFunctions,
+    Halo2VerifierHostFunctions,
+    Sp1VerifierHostFunctions,
+    UltrahonkVerifierHostFunctions,
+    Plonky2VerifierHostFunctions,
+    TeeVerifierHostFunctions,
 );
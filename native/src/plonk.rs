@@ -0,0 +1,45 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use hp_plonk::{Fr, PlonkError, VerificationKey, PROOF_SIZE};
+use sp_runtime_interface::runtime_interface;
+
+use crate::VerifyError;
+
+impl From<PlonkError> for VerifyError {
+    fn from(error: PlonkError) -> Self {
+        match error {
+            PlonkError::InvalidProof => VerifyError::InvalidProofData,
+            PlonkError::InvalidVerificationKey => VerifyError::InvalidVerificationKey,
+            PlonkError::InvalidInput => VerifyError::InvalidInput,
+            PlonkError::VerifyError => VerifyError::VerifyError,
+        }
+    }
+}
+
+#[runtime_interface]
+pub trait PlonkVerify {
+    fn verify(
+        vk: VerificationKey,
+        proof: &[u8; PROOF_SIZE],
+        pubs: &[Fr],
+    ) -> Result<(), VerifyError> {
+        hp_plonk::verify(&vk, proof, pubs).map_err(Into::into)
+    }
+
+    fn validate_key(vk: VerificationKey) -> Result<(), VerifyError> {
+        hp_plonk::validate_key(&vk).map_err(Into::into)
+    }
+}
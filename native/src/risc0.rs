@@ -14,29 +14,97 @@
 // limitations under the License.
 
 use crate::VerifyError;
-use sp_runtime_interface::runtime_interface;
+use codec::{Decode, Encode};
+use sp_runtime_interface::{pass_by::PassByCodec, runtime_interface};
+
+/// The risc0 zkVM releases: every release can change the recursion circuits and so the receipts
+/// that a verifier accepts.
+#[derive(PassByCodec, Encode, Decode, Clone, Copy, Debug, PartialEq)]
+pub enum Risc0Version {
+    V1_0,
+    V1_1,
+    V1_2,
+}
+
+/// The kind of the risc0 `InnerReceipt`: the order is the same of the bincode enum variants.
+#[derive(PassByCodec, Encode, Decode, Clone, Copy, Debug, PartialEq)]
+pub enum Risc0ReceiptKind {
+    Composite,
+    Succinct,
+    Groth16,
+}
 
 #[cfg(feature = "std")]
-impl From<risc0_verifier::VerifyError> for VerifyError {
-    fn from(value: risc0_verifier::VerifyError) -> Self {
-        match value {
-            risc0_verifier::VerifyError::InvalidData {
-                cause: risc0_verifier::DeserializeError::InvalidProof,
-            } => VerifyError::InvalidProofData,
-            risc0_verifier::VerifyError::InvalidData {
-                cause: risc0_verifier::DeserializeError::InvalidPublicInputs,
-            } => VerifyError::InvalidInput,
-            _ => VerifyError::VerifyError,
-        }
+impl Risc0ReceiptKind {
+    /// Rebuild the bincode serialized `InnerReceipt` from the serialized receipt of this kind.
+    fn inner_receipt(self, receipt: &[u8]) -> Vec<u8> {
+        let variant = match self {
+            Risc0ReceiptKind::Composite => 0_u32,
+            Risc0ReceiptKind::Succinct => 1,
+            Risc0ReceiptKind::Groth16 => 2,
+        };
+        [&variant.to_le_bytes()[..], receipt].concat()
     }
 }
 
+macro_rules! impl_from_verify_error {
+    ($($verifier:ident),*) => {
+        $(
+            #[cfg(feature = "std")]
+            impl From<$verifier::VerifyError> for VerifyError {
+                fn from(value: $verifier::VerifyError) -> Self {
+                    match value {
+                        $verifier::VerifyError::InvalidData {
+                            cause: $verifier::DeserializeError::InvalidProof,
+                        } => VerifyError::InvalidProofData,
+                        $verifier::VerifyError::InvalidData {
+                            cause: $verifier::DeserializeError::InvalidPublicInputs,
+                        } => VerifyError::InvalidInput,
+                        _ => VerifyError::VerifyError,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_verify_error!(
+    risc0_verifier_v1_0,
+    risc0_verifier_v1_1,
+    risc0_verifier_v1_2
+);
+
 #[runtime_interface]
 pub trait Risc0Verify {
     fn verify(vk: [u8; 32], proof: &[u8], pubs: &[u8]) -> Result<(), VerifyError> {
-        risc0_verifier::verify(vk.into(), proof, pubs)
+        risc0_verifier_v1_0::verify(vk.into(), proof, pubs)
             .inspect_err(|e| log::debug!("Cannot verify proof: {:?}", e))
             .map_err(Into::into)
             .map(|_| log::trace!("verified"))
     }
+
+    /// Verify a bincode serialized receipt of the given kind, generated by the given risc0
+    /// release, against the image id and the bincode serialized journal.
+    #[version(2)]
+    fn verify(
+        version: Risc0Version,
+        kind: Risc0ReceiptKind,
+        vk: [u8; 32],
+        proof: &[u8],
+        pubs: &[u8],
+    ) -> Result<(), VerifyError> {
+        let proof = kind.inner_receipt(proof);
+        match version {
+            Risc0Version::V1_0 => risc0_verifier_v1_0::verify(vk.into(), &proof, pubs)
+                .inspect_err(|e| log::debug!("Cannot verify proof: {:?}", e))
+                .map_err(Into::into),
+            Risc0Version::V1_1 => risc0_verifier_v1_1::verify(vk.into(), &proof, pubs)
+                .inspect_err(|e| log::debug!("Cannot verify proof: {:?}", e))
+                .map_err(Into::into),
+            Risc0Version::V1_2 => risc0_verifier_v1_2::verify(vk.into(), &proof, pubs)
+                .inspect_err(|e| log::debug!("Cannot verify proof: {:?}", e))
+                .map_err(Into::into),
+        }
+        .map(|_| log::trace!("verified"))
+    }
 }
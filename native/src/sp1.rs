@@ -0,0 +1,46 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::VerifyError;
+use codec::{Decode, Encode};
+use sp_runtime_interface::{pass_by::PassByCodec, runtime_interface};
+
+/// The SP1 major versions: every major changes the recursion circuits and so the proofs.
+#[derive(PassByCodec, Encode, Decode, Clone, Copy, Debug, PartialEq)]
+pub enum Sp1Version {
+    V3_0,
+    V4_0,
+}
+
+#[runtime_interface]
+pub trait Sp1Verify {
+    /// Verify a bincode serialized `SP1Proof` (`Core` or `Compressed`) against the program
+    /// vkey hash and the committed public values.
+    fn verify(
+        version: Sp1Version,
+        vk: [u8; 32],
+        proof: &[u8],
+        pubs: &[u8],
+    ) -> Result<(), VerifyError> {
+        match version {
+            Sp1Version::V3_0 => sp1_zkv_verifier_v3::verify(&vk, proof, pubs)
+                .inspect_err(|e| log::debug!("Cannot verify proof: {:?}", e)),
+            Sp1Version::V4_0 => sp1_zkv_verifier_v4::verify(&vk, proof, pubs)
+                .inspect_err(|e| log::debug!("Cannot verify proof: {:?}", e)),
+        }
+        .map_err(|_| VerifyError::VerifyError)
+        .map(|_| log::trace!("verified"))
+    }
+}
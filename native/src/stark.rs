@@ -0,0 +1,173 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::VerifyError;
+use codec::{Decode, Encode};
+use sp_runtime_interface::{pass_by::PassByCodec, runtime_interface};
+
+/// Largest supported circuit size, as log2 of the number of rows.
+pub const MAX_DEGREE_BITS: u32 = 24;
+/// Largest supported number of FRI query rounds.
+pub const MAX_NUM_QUERY_ROUNDS: u32 = 128;
+
+/// The hash used by the circuit: `PoseidonGoldilocksConfig` or `KeccakGoldilocksConfig`.
+#[derive(PassByCodec, Encode, Decode, Clone, Copy, Debug, PartialEq)]
+pub enum Plonky2Hasher {
+    Poseidon,
+    Keccak,
+}
+
+#[cfg(feature = "std")]
+mod implementation {
+    use super::*;
+    use plonky2::{
+        field::{
+            goldilocks_field::GoldilocksField,
+            types::{Field, Field64},
+        },
+        plonk::{
+            circuit_data::VerifierCircuitData,
+            config::{GenericConfig, KeccakGoldilocksConfig, PoseidonGoldilocksConfig},
+            proof::ProofWithPublicInputs,
+        },
+        util::serialization::{Buffer, DefaultGateSerializer, Read},
+    };
+
+    const D: usize = 2;
+    type F = GoldilocksField;
+
+    fn verifier_data<C>(vk: &[u8]) -> Result<VerifierCircuitData<F, C, D>, VerifyError>
+    where
+        C: GenericConfig<D, F = F>,
+    {
+        let data = VerifierCircuitData::<F, C, D>::from_bytes(vk.to_vec(), &DefaultGateSerializer)
+            .map_err(|e| log::debug!("Cannot deserialize verifier data: {:?}", e))
+            .map_err(|_| VerifyError::InvalidVerificationKey)?;
+        let degree_bits = data.common.degree_bits() as u32;
+        let num_query_rounds = data.common.config.fri_config.num_query_rounds as u32;
+        if degree_bits > MAX_DEGREE_BITS || num_query_rounds > MAX_NUM_QUERY_ROUNDS {
+            log::debug!(
+                "Unsupported FRI parameters: degree_bits {degree_bits}, query rounds {num_query_rounds}"
+            );
+            return Err(VerifyError::InvalidVerificationKey);
+        }
+        Ok(data)
+    }
+
+    fn verify_with<C>(
+        vk: &[u8],
+        proof: &[u8],
+        degree_bits: u32,
+        num_query_rounds: u32,
+        pubs: &[u64],
+    ) -> Result<(), VerifyError>
+    where
+        C: GenericConfig<D, F = F>,
+    {
+        let data = verifier_data::<C>(vk)?;
+        if degree_bits != data.common.degree_bits() as u32
+            || num_query_rounds != data.common.config.fri_config.num_query_rounds as u32
+        {
+            log::debug!("Declared FRI parameters don't match the circuit ones");
+            return Err(VerifyError::InvalidProofData);
+        }
+        if pubs.len() != data.common.num_public_inputs {
+            log::debug!("Wrong number of public inputs");
+            return Err(VerifyError::InvalidInput);
+        }
+        let public_inputs = pubs
+            .iter()
+            .map(|&v| (v < F::ORDER).then(|| F::from_canonical_u64(v)))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| log::debug!("Public input not in field"))
+            .map_err(|_| VerifyError::InvalidInput)?;
+        let mut buffer = Buffer::new(proof);
+        let proof = buffer
+            .read_proof::<F, C, D>(&data.common)
+            .map_err(|e| log::debug!("Cannot deserialize proof: {:?}", e))
+            .map_err(|_| VerifyError::InvalidProofData)?;
+        if !buffer.unread_bytes().is_empty() {
+            log::debug!("Trailing bytes after the proof");
+            return Err(VerifyError::InvalidProofData);
+        }
+        data.verify(ProofWithPublicInputs {
+            proof,
+            public_inputs,
+        })
+        .map_err(|e| log::debug!("Cannot verify proof: {:?}", e))
+        .map_err(|_| VerifyError::VerifyError)
+    }
+
+    /// The verifier data is untrusted and the plonky2 verifier assumes it's consistent: catch
+    /// any panic on malformed data instead of taking down the node.
+    fn guarded<R>(f: impl FnOnce() -> Result<R, VerifyError>) -> Result<R, VerifyError> {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or_else(|_| {
+            log::warn!("Plonky2 verifier panicked on malformed data");
+            Err(VerifyError::VerifyError)
+        })
+    }
+
+    pub fn verify(
+        hasher: Plonky2Hasher,
+        vk: &[u8],
+        proof: &[u8],
+        degree_bits: u32,
+        num_query_rounds: u32,
+        pubs: &[u64],
+    ) -> Result<(), VerifyError> {
+        guarded(|| match hasher {
+            Plonky2Hasher::Poseidon => verify_with::<PoseidonGoldilocksConfig>(
+                vk,
+                proof,
+                degree_bits,
+                num_query_rounds,
+                pubs,
+            ),
+            Plonky2Hasher::Keccak => verify_with::<KeccakGoldilocksConfig>(
+                vk,
+                proof,
+                degree_bits,
+                num_query_rounds,
+                pubs,
+            ),
+        })
+        .map(|_| log::trace!("verified"))
+    }
+
+    pub fn validate_vk(hasher: Plonky2Hasher, vk: &[u8]) -> Result<(), VerifyError> {
+        guarded(|| match hasher {
+            Plonky2Hasher::Poseidon => verifier_data::<PoseidonGoldilocksConfig>(vk).map(|_| ()),
+            Plonky2Hasher::Keccak => verifier_data::<KeccakGoldilocksConfig>(vk).map(|_| ()),
+        })
+    }
+}
+
+#[runtime_interface]
+pub trait Plonky2Verify {
+    fn verify(
+        hasher: Plonky2Hasher,
+        vk: &[u8],
+        proof: &[u8],
+        degree_bits: u32,
+        num_query_rounds: u32,
+        pubs: &[u64],
+    ) -> Result<(), VerifyError> {
+        implementation::verify(hasher, vk, proof, degree_bits, num_query_rounds, pubs)
+    }
+
+    fn validate_vk(hasher: Plonky2Hasher, vk: &[u8]) -> Result<(), VerifyError> {
+        implementation::validate_vk(hasher, vk)
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use hp_tee::{Attestation, Policy, ReportData, TeeError};
+use sp_runtime_interface::runtime_interface;
+
+use crate::VerifyError;
+
+impl From<TeeError> for VerifyError {
+    fn from(error: TeeError) -> Self {
+        match error {
+            TeeError::InvalidQuote | TeeError::InvalidCollateral => VerifyError::InvalidProofData,
+            TeeError::InvalidPolicy => VerifyError::InvalidVerificationKey,
+            TeeError::ReportDataMismatch => VerifyError::InvalidInput,
+            _ => VerifyError::VerifyError,
+        }
+    }
+}
+
+#[runtime_interface]
+pub trait TeeVerify {
+    fn verify(
+        policy: Policy,
+        attestation: Attestation,
+        report_data: &ReportData,
+        now: u64,
+    ) -> Result<(), VerifyError> {
+        hp_tee::verify(&policy, &attestation, report_data, now).map_err(Into::into)
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::VerifyError;
+use sp_runtime_interface::runtime_interface;
+
+/// Size of a `bb` UltraHonk proof generated with the keccak oracle hash.
+pub const PROOF_SIZE: usize = 440 * 32;
+pub const PUBS_SIZE: usize = 32;
+/// Size of a `bb` UltraHonk verification key.
+pub const VK_SIZE: usize = 1760;
+
+#[cfg(feature = "std")]
+impl From<ultrahonk_verifier::VerifyError> for VerifyError {
+    fn from(value: ultrahonk_verifier::VerifyError) -> Self {
+        match value {
+            ultrahonk_verifier::VerifyError::KeyError(e) => {
+                log::debug!("Invalid verification key on verify proof: {e:?}");
+                VerifyError::InvalidVerificationKey
+            }
+            ultrahonk_verifier::VerifyError::ProofError(e) => {
+                log::debug!("Cannot parse proof: {e:?}");
+                VerifyError::InvalidProofData
+            }
+            ultrahonk_verifier::VerifyError::PublicInputError { .. } => VerifyError::InvalidInput,
+            ultrahonk_verifier::VerifyError::VerificationError => VerifyError::VerifyError,
+        }
+    }
+}
+
+#[runtime_interface]
+pub trait UltrahonkVerify {
+    fn verify(
+        raw_vk: &[u8; VK_SIZE],
+        raw_proof: &[u8; PROOF_SIZE],
+        pubs: &[[u8; PUBS_SIZE]],
+    ) -> Result<(), VerifyError> {
+        let vk = ultrahonk_verifier::VerificationKey::try_from(&raw_vk[..]).map_err(|e| {
+            log::debug!("Cannot parse verification key: {:?}", e);
+            VerifyError::InvalidVerificationKey
+        })?;
+
+        ultrahonk_verifier::verify(&vk, raw_proof, pubs).map_err(Into::into)
+    }
+
+    fn validate_vk(raw_vk: &[u8; VK_SIZE]) -> Result<(), VerifyError> {
+        let _vk = ultrahonk_verifier::VerificationKey::try_from(&raw_vk[..]).map_err(|e| {
+            log::debug!("Cannot parse verification key: {:?}", e);
+            VerifyError::InvalidVerificationKey
+        })?;
+        Ok(())
+    }
+}
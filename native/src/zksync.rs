@@ -16,33 +16,212 @@
 use crate::VerifyError;
 use sp_runtime_interface::runtime_interface;
 
+#[cfg(feature = "std")]
+use bellman_ce::{
+    pairing::bn256::Bn256,
+    plonk::better_better_cs::{cs::Circuit, setup::VerificationKey},
+};
+
 pub const PUBS_SIZE: usize = 32;
 pub const PROOF_SIZE: usize = 44 * 32;
+/// The domain size, the number of public inputs and the total lookup entries length words
+/// followed by the 20 circuit commitments (gate setup, gate selectors, permutation, lookup
+/// selector, lookup tables and lookup table type) as big endian G1 points.
+///
+/// The gate set, the lookup width, the non residues and the setup G2 elements are not in the
+/// layout: just the circuits with the zkSync Era shape are supported.
+pub const VK_SIZE: usize = 43 * 32;
+
+#[cfg(feature = "std")]
+mod vk {
+    use super::{PUBS_SIZE, VK_SIZE};
+    use bellman_ce::{
+        pairing::{
+            bn256::{Bn256, Fq, FqRepr, G1Affine},
+            ff::{PrimeField, PrimeFieldRepr},
+            CurveAffine,
+        },
+        plonk::better_better_cs::{cs::Circuit, setup::VerificationKey},
+    };
+
+    const WORD_SIZE: usize = 32;
+    const HEADER_SIZE: usize = 3 * WORD_SIZE;
+    const G1_SIZE: usize = 2 * WORD_SIZE;
+    const GATE_SETUP: usize = 8;
+    const GATE_SELECTORS: usize = 2;
+    const PERMUTATION: usize = 4;
+    const LOOKUP_TABLES: usize = 4;
+    /// The largest domain the vk can declare: the bn254 scalar field has 2-adicity 28.
+    const MAX_DOMAIN_SIZE: u64 = 1 << 28;
+
+    #[derive(Debug)]
+    pub enum Error {
+        InvalidDomainSize,
+        InvalidNumInputs,
+        InvalidLookupEntriesLength,
+        InvalidPoint(usize),
+    }
+
+    /// A big endian word holding a `u64`.
+    fn word(bytes: &[u8]) -> Option<u64> {
+        let (high, low) = bytes.split_at(WORD_SIZE - 8);
+        high.iter()
+            .all(|&b| b == 0)
+            .then(|| u64::from_be_bytes(low.try_into().expect("8 bytes")))
+    }
+
+    fn write_word(out: &mut Vec<u8>, value: u64) {
+        out.extend_from_slice(&[0; WORD_SIZE - 8]);
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn fq(bytes: &[u8]) -> Option<Fq> {
+        let mut repr = FqRepr::default();
+        repr.read_be(bytes).ok()?;
+        Fq::from_repr(repr).ok()
+    }
+
+    fn g1(bytes: &[u8]) -> Option<G1Affine> {
+        if bytes.iter().all(|&b| b == 0) {
+            return Some(G1Affine::zero());
+        }
+        let (x, y) = bytes.split_at(WORD_SIZE);
+        G1Affine::from_xy_checked(fq(x)?, fq(y)?).ok()
+    }
+
+    fn write_g1(out: &mut Vec<u8>, point: &G1Affine) {
+        if point.is_zero() {
+            out.extend_from_slice(&[0; G1_SIZE]);
+            return;
+        }
+        let (x, y) = point.into_xy_unchecked();
+        x.into_repr()
+            .write_be(&mut *out)
+            .expect("Writing to a vec never fails");
+        y.into_repr()
+            .write_be(&mut *out)
+            .expect("Writing to a vec never fails");
+    }
+
+    /// Build the verification key for the circuit described by `raw`: everything that is not
+    /// in the layout (state width, gate set, non residues, setup G2 elements, ...) is taken
+    /// from the zkSync Era one. The proofs have a single public input: reject the vks that
+    /// declare a different number of inputs.
+    pub fn deserialize<C: Circuit<Bn256>>(
+        raw: &[u8; VK_SIZE],
+        mut vk: VerificationKey<Bn256, C>,
+    ) -> Result<VerificationKey<Bn256, C>, Error> {
+        let (header, points) = raw.split_at(HEADER_SIZE);
+        let mut words = header.chunks_exact(WORD_SIZE).map(word);
+        let mut next_word = || words.next().flatten();
+        let domain_size = next_word()
+            .filter(|&d| d.is_power_of_two() && (2..=MAX_DOMAIN_SIZE).contains(&d))
+            .ok_or(Error::InvalidDomainSize)?;
+        let num_inputs = next_word()
+            .filter(|&n| n as usize * WORD_SIZE == PUBS_SIZE)
+            .ok_or(Error::InvalidNumInputs)?;
+        let total_lookup_entries_length = next_word()
+            .filter(|&l| l < domain_size)
+            .ok_or(Error::InvalidLookupEntriesLength)?;
+        let mut points = points
+            .chunks_exact(G1_SIZE)
+            .enumerate()
+            .map(|(i, bytes)| g1(bytes).ok_or(Error::InvalidPoint(i)));
+        let mut take = |n: usize| points.by_ref().take(n).collect::<Result<Vec<_>, _>>();
+
+        vk.n = domain_size as usize - 1;
+        vk.num_inputs = num_inputs as usize;
+        vk.total_lookup_entries_length = total_lookup_entries_length as usize;
+        vk.gate_setup_commitments = take(GATE_SETUP)?;
+        vk.gate_selectors_commitments = take(GATE_SELECTORS)?;
+        vk.permutation_commitments = take(PERMUTATION)?;
+        vk.lookup_selector_commitment = take(1)?.pop();
+        vk.lookup_tables_commitments = take(LOOKUP_TABLES)?;
+        vk.lookup_table_type_commitment = take(1)?.pop();
+        Ok(vk)
+    }
+
+    pub fn serialize<C: Circuit<Bn256>>(vk: &VerificationKey<Bn256, C>) -> [u8; VK_SIZE] {
+        let mut out = Vec::with_capacity(VK_SIZE);
+        write_word(&mut out, vk.n as u64 + 1);
+        write_word(&mut out, vk.num_inputs as u64);
+        write_word(&mut out, vk.total_lookup_entries_length as u64);
+        vk.gate_setup_commitments
+            .iter()
+            .chain(vk.gate_selectors_commitments.iter())
+            .chain(vk.permutation_commitments.iter())
+            .chain(vk.lookup_selector_commitment.iter())
+            .chain(vk.lookup_tables_commitments.iter())
+            .chain(vk.lookup_table_type_commitment.iter())
+            .for_each(|p| write_g1(&mut out, p));
+        out.try_into()
+            .expect("zkSync Era vk should fit the vk layout")
+    }
+}
 
 #[runtime_interface]
 pub trait ZksyncVerify {
+    /// Verify against the zkSync Era vk: kept for the runtimes that predate custom vks.
+    fn verify(
+        proof_bytes: &[u8; PROOF_SIZE],
+        pubs_bytes: [u8; PUBS_SIZE],
+    ) -> Result<(), VerifyError> {
+        verify_with_vk(
+            zksync_era_verifier::default_eth_vk(),
+            proof_bytes,
+            pubs_bytes,
+        )
+    }
+
+    #[version(2)]
     fn verify(
+        vk_bytes: &[u8; VK_SIZE],
         proof_bytes: &[u8; PROOF_SIZE],
         pubs_bytes: [u8; PUBS_SIZE],
     ) -> Result<(), VerifyError> {
-        let pubs = zksync_era_verifier_deserialize::fr(&pubs_bytes)
-            .map_err(|e| log::error!("Cannot extract public inputs: {:?}", e))
-            .map_err(|_| VerifyError::InvalidInput)?;
-        let mut proof = zksync_era_verifier::deserialize_eth_proof(proof_bytes)
-            .map_err(|e| log::debug!("Cannot extract raw proof data: {:?}", e))
-            .map_err(|_| VerifyError::InvalidProofData)?;
-        log::trace!(
-            "Extracted public inputs [{:?}...{:?}] and proof data [{:?}...{:?}]",
-            pubs_bytes[0],
-            pubs_bytes[PUBS_SIZE - 1],
-            proof_bytes[0],
-            proof_bytes[PROOF_SIZE - 1]
-        );
-        proof.inputs = vec![pubs];
-        zksync_era_verifier::verify(&zksync_era_verifier::default_eth_vk(), &proof)
-            .map_err(|e| log::debug!("Cannot verify proof: {:?}", e))
-            .map_err(|_| VerifyError::VerifyError)
-            .and_then(|verified| verified.then_some(()).ok_or(VerifyError::VerifyError))
-            .map(|_| log::trace!("verified"))
+        let vk = vk::deserialize(vk_bytes, zksync_era_verifier::default_eth_vk())
+            .map_err(|e| log::debug!("Cannot parse verification key: {:?}", e))
+            .map_err(|_| VerifyError::InvalidVerificationKey)?;
+        verify_with_vk(vk, proof_bytes, pubs_bytes)
+    }
+
+    fn validate_vk(vk_bytes: &[u8; VK_SIZE]) -> Result<(), VerifyError> {
+        let _vk = vk::deserialize(vk_bytes, zksync_era_verifier::default_eth_vk())
+            .map_err(|e| log::debug!("Cannot parse verification key: {:?}", e))
+            .map_err(|_| VerifyError::InvalidVerificationKey)?;
+        Ok(())
     }
+
+    /// The zkSync Era mainnet vk, serialized in the layout accepted by `verify` and
+    /// `validate_vk`.
+    fn era_vk() -> [u8; VK_SIZE] {
+        vk::serialize(&zksync_era_verifier::default_eth_vk())
+    }
+}
+
+#[cfg(feature = "std")]
+fn verify_with_vk<C: Circuit<Bn256>>(
+    vk: VerificationKey<Bn256, C>,
+    proof_bytes: &[u8; PROOF_SIZE],
+    pubs_bytes: [u8; PUBS_SIZE],
+) -> Result<(), VerifyError> {
+    let pubs = zksync_era_verifier_deserialize::fr(&pubs_bytes)
+        .map_err(|e| log::error!("Cannot extract public inputs: {:?}", e))
+        .map_err(|_| VerifyError::InvalidInput)?;
+    let mut proof = zksync_era_verifier::deserialize_eth_proof(proof_bytes)
+        .map_err(|e| log::debug!("Cannot extract raw proof data: {:?}", e))
+        .map_err(|_| VerifyError::InvalidProofData)?;
+    log::trace!(
+        "Extracted public inputs [{:?}...{:?}] and proof data [{:?}...{:?}]",
+        pubs_bytes[0],
+        pubs_bytes[PUBS_SIZE - 1],
+        proof_bytes[0],
+        proof_bytes[PROOF_SIZE - 1]
+    );
+    proof.inputs = vec![pubs];
+    zksync_era_verifier::verify(&vk, &proof)
+        .map_err(|e| log::debug!("Cannot verify proof: {:?}", e))
+        .map_err(|_| VerifyError::VerifyError)
+        .and_then(|verified| verified.then_some(()).ok_or(VerifyError::VerifyError))
+        .map(|_| log::trace!("verified"))
 }
@@ -58,4 +58,7 @@ pub enum Subcommand {
 
     /// Db meta columns information.
     ChainInfo(sc_cli::ChainInfoCmd),
+
+    /// Verify a proof offline with the runtime verifiers.
+    Verify(crate::verify::VerifyCmd),
 }
@@ -246,6 +246,7 @@ pub fn run() -> sc_cli::Result<()> {
             let runner = cli.create_runner(cmd)?;
             runner.sync_run(|config| cmd.run::<Block>(&config))
         }
+        Some(Subcommand::Verify(cmd)) => cmd.run(),
         None => {
             let runner = cli.create_runner(&cli.run)?;
             sp_core::crypto::set_default_ss58_version(Ss58AddressFormat::from(
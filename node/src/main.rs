@@ -23,6 +23,7 @@ mod command;
 mod hardware;
 mod rpc;
 mod service;
+mod verify;
 
 fn main() -> sc_cli::Result<()> {
     command::run()
@@ -94,6 +94,7 @@ where
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: proof_of_existence_rpc::PoERuntimeApi<Block>,
     C::Api: aggregate_rpc::AggregateRuntimeApi<Block>,
+    C::Api: verifiers_rpc::VerifiersRuntimeApi<Block, Balance, AccountId, BlockNumber>,
     C::Api: BlockBuilder<Block>,
     C::Api: pallet_ismp_runtime_api::IsmpRuntimeApi<Block, H256>,
     P: TransactionPool + 'static,
@@ -107,6 +108,7 @@ where
     use sc_consensus_babe_rpc::{Babe, BabeApiServer};
     use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
+    use verifiers_rpc::{Verifiers, VerifiersApiServer};
 
     let mut module = RpcModule::new(());
     let FullDeps {
@@ -156,6 +158,7 @@ where
     )?;
     module.merge(PoE::new(client.clone()).into_rpc())?;
     module.merge(Aggregate::new(client.clone()).into_rpc())?;
+    module.merge(Verifiers::new(client.clone(), deny_unsafe).into_rpc())?;
     module.merge(IsmpRpcHandler::new(client, backend.clone())?.into_rpc())?;
 
     // Extend this RPC with a custom API by using the following syntax.
@@ -0,0 +1,335 @@
+// Copyright 2024, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The `verify` subcommand: check a proof offline with the same verifier code and
+//! configuration used by the runtime, without spending any fee.
+
+use std::path::{Path, PathBuf};
+
+use codec::DecodeAll;
+use hp_verifiers::Verifier;
+use pallet_verifiers::{DryRun, VkOrHash};
+use zkv_runtime::Runtime;
+
+/// The verifiers available in the runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum VerifierKind {
+    /// Fflonk
+    Fflonk,
+    /// ZkSync Era
+    Zksync,
+    /// Groth16
+    Groth16,
+    /// Risc0
+    Risc0,
+    /// Ultraplonk
+    Ultraplonk,
+    /// Proof of SQL
+    ProofOfSql,
+    /// Plonk
+    Plonk,
+    /// Intel SGX/TDX DCAP attestations
+    Tee,
+}
+
+/// How an input file is encoded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum InputFormat {
+    /// The hex string of the SCALE encoding used in the extrinsics (the `0x` prefix is optional).
+    #[default]
+    Hex,
+    /// The SCALE encoding used in the extrinsics.
+    Bin,
+    /// The format of the verifier's own tooling: the snarkjs JSON files for fflonk, groth16 and
+    /// plonk, the `bb` files for ultraplonk (the proof file for both `--proof` and `--pubs`), the
+    /// image id hex string and the bincode `Receipt` (for both `--proof` and `--pubs`) for risc0.
+    #[value(alias = "json")]
+    Native,
+}
+
+/// Verify a proof offline and print its verification key hash and statement hash.
+#[derive(Debug, clap::Parser)]
+pub struct VerifyCmd {
+    /// The verifier to use.
+    #[arg(long, value_enum)]
+    pub verifier: VerifierKind,
+
+    /// The verification key file.
+    #[arg(long)]
+    pub vk: PathBuf,
+
+    /// The proof file.
+    #[arg(long)]
+    pub proof: PathBuf,
+
+    /// The public inputs file.
+    #[arg(long)]
+    pub pubs: PathBuf,
+
+    /// The format of the input files.
+    #[arg(long, value_enum, default_value_t)]
+    pub format: InputFormat,
+
+    /// The format of the verification key file, if different from `--format`.
+    #[arg(long, value_enum)]
+    pub vk_format: Option<InputFormat>,
+
+    /// The format of the proof file, if different from `--format`.
+    #[arg(long, value_enum)]
+    pub proof_format: Option<InputFormat>,
+
+    /// The format of the public inputs file, if different from `--format`.
+    #[arg(long, value_enum)]
+    pub pubs_format: Option<InputFormat>,
+}
+
+/// The input parsing of a verifier: by default just the SCALE encoding is supported, override
+/// the `*_from_native` functions to support the format of its tooling.
+trait CliVerifier: Verifier {
+    const NAME: &'static str;
+
+    fn vk_from_native(_bytes: &[u8]) -> Result<Self::Vk, String> {
+        Err(format!(
+            "Native verification keys are not supported by {}",
+            Self::NAME
+        ))
+    }
+
+    fn proof_from_native(_bytes: &[u8]) -> Result<Self::Proof, String> {
+        Err(format!("Native proofs are not supported by {}", Self::NAME))
+    }
+
+    fn pubs_from_native(_bytes: &[u8], _vk: &Self::Vk) -> Result<Self::Pubs, String> {
+        Err(format!(
+            "Native public inputs are not supported by {}",
+            Self::NAME
+        ))
+    }
+}
+
+fn utf8(bytes: &[u8]) -> Result<&str, String> {
+    std::str::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+impl CliVerifier for pallet_fflonk_verifier::Fflonk {
+    const NAME: &'static str = "fflonk";
+
+    fn vk_from_native(bytes: &[u8]) -> Result<Self::Vk, String> {
+        pallet_fflonk_verifier::formats::vk_from_snarkjs(utf8(bytes)?).map_err(|e| e.to_string())
+    }
+
+    fn proof_from_native(bytes: &[u8]) -> Result<Self::Proof, String> {
+        pallet_fflonk_verifier::formats::proof_from_snarkjs(utf8(bytes)?).map_err(|e| e.to_string())
+    }
+
+    fn pubs_from_native(bytes: &[u8], _vk: &Self::Vk) -> Result<Self::Pubs, String> {
+        pallet_fflonk_verifier::formats::pubs_from_snarkjs(utf8(bytes)?).map_err(|e| e.to_string())
+    }
+}
+
+impl CliVerifier for pallet_zksync_verifier::Zksync {
+    const NAME: &'static str = "zksync";
+}
+
+impl CliVerifier for pallet_groth16_verifier::Groth16<Runtime> {
+    const NAME: &'static str = "groth16";
+
+    fn vk_from_native(bytes: &[u8]) -> Result<Self::Vk, String> {
+        pallet_groth16_verifier::formats::vk_from_snarkjs(utf8(bytes)?).map_err(|e| e.to_string())
+    }
+
+    fn proof_from_native(bytes: &[u8]) -> Result<Self::Proof, String> {
+        pallet_groth16_verifier::formats::proof_from_snarkjs(utf8(bytes)?)
+            .map_err(|e| e.to_string())
+    }
+
+    fn pubs_from_native(bytes: &[u8], vk: &Self::Vk) -> Result<Self::Pubs, String> {
+        pallet_groth16_verifier::formats::pubs_from_snarkjs(utf8(bytes)?, vk.curve)
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl CliVerifier for pallet_risc0_verifier::Risc0<Runtime> {
+    const NAME: &'static str = "risc0";
+
+    fn vk_from_native(bytes: &[u8]) -> Result<Self::Vk, String> {
+        pallet_risc0_verifier::formats::image_id_from_hex(utf8(bytes)?).map_err(|e| e.to_string())
+    }
+
+    fn proof_from_native(bytes: &[u8]) -> Result<Self::Proof, String> {
+        pallet_risc0_verifier::formats::receipt_from_bincode(bytes)
+            .map(|(proof, _)| proof)
+            .map_err(|e| e.to_string())
+    }
+
+    fn pubs_from_native(bytes: &[u8], _vk: &Self::Vk) -> Result<Self::Pubs, String> {
+        pallet_risc0_verifier::formats::receipt_from_bincode(bytes)
+            .map(|(_, pubs)| pubs)
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl CliVerifier for pallet_ultraplonk_verifier::Ultraplonk<Runtime> {
+    const NAME: &'static str = "ultraplonk";
+
+    fn vk_from_native(bytes: &[u8]) -> Result<Self::Vk, String> {
+        pallet_ultraplonk_verifier::formats::vk_from_bb(bytes).map_err(|e| e.to_string())
+    }
+
+    fn proof_from_native(bytes: &[u8]) -> Result<Self::Proof, String> {
+        pallet_ultraplonk_verifier::formats::proof_from_bb(bytes)
+            .map(|(proof, _)| proof)
+            .map_err(|e| e.to_string())
+    }
+
+    fn pubs_from_native(bytes: &[u8], vk: &Self::Vk) -> Result<Self::Pubs, String> {
+        use pallet_ultraplonk_verifier::formats;
+
+        let (_, pubs) = formats::proof_from_bb(bytes).map_err(|e| e.to_string())?;
+        let expected = formats::num_public_inputs(vk) as usize;
+        if pubs.len() != expected {
+            return Err(format!(
+                "Expected {expected} public inputs but found {}",
+                pubs.len()
+            ));
+        }
+        Ok(pubs)
+    }
+}
+
+impl CliVerifier for pallet_proofofsql_verifier::ProofOfSql<Runtime> {
+    const NAME: &'static str = "proof-of-sql";
+}
+
+impl CliVerifier for pallet_plonk_verifier::Plonk<Runtime> {
+    const NAME: &'static str = "plonk";
+
+    fn vk_from_native(bytes: &[u8]) -> Result<Self::Vk, String> {
+        pallet_plonk_verifier::formats::vk_from_snarkjs(utf8(bytes)?).map_err(|e| e.to_string())
+    }
+
+    fn proof_from_native(bytes: &[u8]) -> Result<Self::Proof, String> {
+        pallet_plonk_verifier::formats::proof_from_snarkjs(utf8(bytes)?).map_err(|e| e.to_string())
+    }
+
+    fn pubs_from_native(bytes: &[u8], _vk: &Self::Vk) -> Result<Self::Pubs, String> {
+        pallet_plonk_verifier::formats::pubs_from_snarkjs(utf8(bytes)?).map_err(|e| e.to_string())
+    }
+}
+
+impl CliVerifier for pallet_tee_verifier::Tee<Runtime> {
+    const NAME: &'static str = "tee";
+}
+
+impl VerifyCmd {
+    /// Run the verification: return an error if the proof is rejected.
+    pub fn run(&self) -> sc_cli::Result<()> {
+        match self.verifier {
+            VerifierKind::Fflonk => self.verify::<pallet_fflonk_verifier::Fflonk>(),
+            VerifierKind::Zksync => self.verify::<pallet_zksync_verifier::Zksync>(),
+            VerifierKind::Groth16 => self.verify::<pallet_groth16_verifier::Groth16<Runtime>>(),
+            VerifierKind::Risc0 => self.verify::<pallet_risc0_verifier::Risc0<Runtime>>(),
+            VerifierKind::Ultraplonk => {
+                self.verify::<pallet_ultraplonk_verifier::Ultraplonk<Runtime>>()
+            }
+            VerifierKind::ProofOfSql => {
+                self.verify::<pallet_proofofsql_verifier::ProofOfSql<Runtime>>()
+            }
+            VerifierKind::Plonk => self.verify::<pallet_plonk_verifier::Plonk<Runtime>>(),
+            VerifierKind::Tee => self.verify::<pallet_tee_verifier::Tee<Runtime>>(),
+        }
+    }
+
+    fn verify<V: CliVerifier>(&self) -> sc_cli::Result<()>
+    where
+        Runtime: pallet_verifiers::Config<V>,
+    {
+        let vk: V::Vk = load(
+            &self.vk,
+            self.vk_format.unwrap_or(self.format),
+            V::vk_from_native,
+        )?;
+        let proof: V::Proof = load(
+            &self.proof,
+            self.proof_format.unwrap_or(self.format),
+            V::proof_from_native,
+        )?;
+        let pubs: V::Pubs = load(
+            &self.pubs,
+            self.pubs_format.unwrap_or(self.format),
+            |bytes| V::pubs_from_native(bytes, &vk),
+        )?;
+
+        let vk_hash = V::vk_hash(&vk);
+        // The dry run reads the verifier state: an empty storage is a never disabled verifier.
+        // The current time is needed by the verifiers that check the proof validity period.
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+        let DryRun {
+            result,
+            statement,
+            weight,
+        } = sp_io::TestExternalities::default().execute_with(|| {
+            pallet_timestamp::Now::<Runtime>::put(now.as_millis() as u64);
+            pallet_verifiers::Pallet::<Runtime, V>::dry_run(&VkOrHash::from_vk(vk), &proof, &pubs)
+        });
+
+        println!("verifier: {}", V::NAME);
+        println!("vk hash: {vk_hash:?}");
+        println!("statement: {statement:?}");
+        println!(
+            "weight: ref_time {}, proof_size {}",
+            weight.ref_time(),
+            weight.proof_size()
+        );
+        match result {
+            Ok(()) => {
+                println!("result: verified");
+                Ok(())
+            }
+            Err(e) => {
+                println!("result: rejected ({e:?})");
+                Err(sc_cli::Error::Input(format!("Proof rejected: {e:?}")))
+            }
+        }
+    }
+}
+
+fn load<T: DecodeAll>(
+    path: &Path,
+    format: InputFormat,
+    from_native: impl FnOnce(&[u8]) -> Result<T, String>,
+) -> sc_cli::Result<T> {
+    let read_error =
+        |e: std::io::Error| sc_cli::Error::Input(format!("Cannot read {}: {e}", path.display()));
+    let parse_error =
+        |e: String| sc_cli::Error::Input(format!("Cannot parse {}: {e}", path.display()));
+    let decode = |bytes: Vec<u8>| {
+        T::decode_all(&mut bytes.as_slice()).map_err(|e| parse_error(e.to_string()))
+    };
+    match format {
+        InputFormat::Hex => {
+            let hex = std::fs::read_to_string(path).map_err(read_error)?;
+            let bytes =
+                sp_core::bytes::from_hex(hex.trim()).map_err(|e| parse_error(e.to_string()))?;
+            decode(bytes)
+        }
+        InputFormat::Bin => decode(std::fs::read(path).map_err(read_error)?),
+        InputFormat::Native => {
+            from_native(&std::fs::read(path).map_err(read_error)?).map_err(parse_error)
+        }
+    }
+}
@@ -0,0 +1,262 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The benchmarks of the weights shared by all the verifier pallets (`common::WeightInfo`).
+//! They don't depend on the verifier: the runtime can run them on any of its verifier
+//! instances.
+
+#![cfg(all(feature = "runtime-benchmarks", not(doc)))]
+
+use crate::common::{self, Statements};
+use crate::{
+    registration_footprint, ExpiringRegistrations, ExpiryCursor, PendingTransfers,
+    RegistrationExpiry, Registrations, RevokeReason, RevokedVks, Tickets, VkEntry, VkMetadata, Vks,
+    MAX_REVOKE_REASON_LENGTH, MAX_VK_CIRCUIT_URI_LENGTH, MAX_VK_NAME_LENGTH, MAX_VK_VERSION_LENGTH,
+};
+use codec::Decode;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{fungible::Mutate, Consideration, Hooks},
+    weights::Weight,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use hp_verifiers::Verifier;
+use sp_core::{Get, H256};
+use sp_runtime::traits::{Bounded, One, Saturating, TrailingZeroInput};
+use sp_std::vec;
+
+pub struct Pallet<T: Config<I>, I: 'static = ()>(crate::Pallet<T, I>)
+where
+    I: Verifier;
+pub trait Config<I: 'static = ()>: crate::Config<I>
+where
+    I: Verifier,
+{
+}
+impl<T: crate::Config<I>, I: 'static> Config<I> for T where I: Verifier {}
+pub type Call<T, I> = crate::Call<T, I>;
+
+fn index_statement<T: common::Config>(statement: H256) {
+    common::Pallet::<T>::index_statement(
+        statement,
+        1,
+        H256::repeat_byte(2),
+        Some(whitelisted_caller()),
+        Some(1),
+    );
+}
+
+/// The longest revoke reason.
+fn revoke_reason() -> RevokeReason {
+    vec![b'r'; MAX_REVOKE_REASON_LENGTH as usize]
+        .try_into()
+        .expect("The reason is not too long. qed")
+}
+
+/// The longest metadata.
+fn metadata() -> VkMetadata {
+    VkMetadata {
+        name: vec![b'n'; MAX_VK_NAME_LENGTH as usize].try_into().unwrap(),
+        circuit_uri: vec![b'u'; MAX_VK_CIRCUIT_URI_LENGTH as usize]
+            .try_into()
+            .unwrap(),
+        version: vec![b'v'; MAX_VK_VERSION_LENGTH as usize]
+            .try_into()
+            .unwrap(),
+    }
+}
+
+fn funded<T: crate::Config<I>, I: 'static>(account: T::AccountId) -> T::AccountId
+where
+    I: Verifier,
+{
+    T::Currency::set_balance(&account, Bounded::max_value() / 2u32.into());
+    account
+}
+
+/// Register a vk for `owner` as `register_vk` would, with the longest metadata and an expiry
+/// in 10 blocks. The vk is decoded from zeroes: the benchmarks don't depend on the verifier.
+fn register<T: crate::Config<I>, I: 'static>(owner: &T::AccountId) -> H256
+where
+    I: Verifier,
+{
+    let vk = I::Vk::decode(&mut TrailingZeroInput::zeroes()).expect("Vk from zeroes. qed");
+    let hash = I::vk_hash(&vk);
+    let metadata = Some(metadata());
+    let ticket = T::Ticket::new(owner, registration_footprint(&vk, &metadata))
+        .expect("The owner is funded. qed");
+    let expiry = frame_system::Pallet::<T>::block_number().saturating_add(10_u32.into());
+
+    Tickets::<T, I>::insert((owner, hash), Some(ticket));
+    Registrations::<T, I>::insert(owner, hash, metadata);
+    RegistrationExpiry::<T, I>::insert(owner, hash, expiry);
+    ExpiringRegistrations::<T, I>::insert(expiry, (owner, hash), ());
+    ExpiryCursor::<T, I>::put(expiry);
+    Vks::<T, I>::insert(hash, VkEntry::new(vk));
+    hash
+}
+
+#[instance_benchmarks(where I: Verifier)]
+mod benchmarks {
+
+    use super::*;
+
+    #[benchmark]
+    fn index_statement() {
+        // setup code
+        assert!(
+            T::StatementRetention::get().is_some(),
+            "The statements index should be enabled"
+        );
+        let statement = H256::repeat_byte(1);
+
+        #[block]
+        {
+            super::index_statement::<T>(statement);
+        }
+
+        // Verify
+        assert!(Statements::<T>::contains_key(statement));
+    }
+
+    #[benchmark]
+    fn prune_statement() {
+        // setup code
+        let retention =
+            T::StatementRetention::get().expect("The statements index should be enabled");
+        let block: BlockNumberFor<T> = One::one();
+        frame_system::Pallet::<T>::set_block_number(block);
+        let statement = H256::repeat_byte(1);
+        super::index_statement::<T>(statement);
+        let now = block.saturating_add(retention);
+
+        #[block]
+        {
+            common::Pallet::<T>::on_idle(now, Weight::MAX);
+        }
+
+        // Verify
+        assert!(!Statements::<T>::contains_key(statement));
+    }
+
+    #[benchmark]
+    fn revoke_vk() {
+        // setup code
+        let hash = H256::repeat_byte(2);
+
+        #[extrinsic_call]
+        revoke_vk(RawOrigin::Root, hash, revoke_reason());
+
+        // Verify
+        assert!(RevokedVks::<T, I>::contains_key(hash));
+    }
+
+    #[benchmark]
+    fn restore_vk() {
+        // setup code
+        let hash = H256::repeat_byte(2);
+        RevokedVks::<T, I>::insert(hash, revoke_reason());
+
+        #[extrinsic_call]
+        restore_vk(RawOrigin::Root, hash);
+
+        // Verify
+        assert!(!RevokedVks::<T, I>::contains_key(hash));
+    }
+
+    #[benchmark]
+    fn transfer_vk() {
+        // setup code
+        let owner = funded::<T, I>(whitelisted_caller());
+        let new_owner = funded::<T, I>(account("new_owner", 0, 0));
+        let hash = register::<T, I>(&owner);
+
+        #[extrinsic_call]
+        transfer_vk(RawOrigin::Signed(owner.clone()), hash, new_owner.clone());
+
+        // Verify
+        assert_eq!(PendingTransfers::<T, I>::get(&owner, hash), Some(new_owner));
+    }
+
+    #[benchmark]
+    fn accept_vk_transfer() {
+        // setup code
+        let owner = funded::<T, I>(whitelisted_caller());
+        let new_owner = funded::<T, I>(account("new_owner", 0, 0));
+        let hash = register::<T, I>(&owner);
+        PendingTransfers::<T, I>::insert(&owner, hash, &new_owner);
+
+        #[extrinsic_call]
+        accept_vk_transfer(RawOrigin::Signed(new_owner.clone()), hash, owner.clone());
+
+        // Verify
+        assert!(!Tickets::<T, I>::contains_key((&owner, hash)));
+        assert!(Tickets::<T, I>::contains_key((&new_owner, hash)));
+    }
+
+    #[benchmark]
+    fn renew_vk() {
+        // setup code
+        let owner = funded::<T, I>(whitelisted_caller());
+        let hash = register::<T, I>(&owner);
+        let expiry = frame_system::Pallet::<T>::block_number().saturating_add(20_u32.into());
+
+        #[extrinsic_call]
+        renew_vk(RawOrigin::Signed(owner.clone()), hash, Some(expiry));
+
+        // Verify
+        assert_eq!(RegistrationExpiry::<T, I>::get(&owner, hash), Some(expiry));
+    }
+
+    #[benchmark]
+    fn expire_vk() {
+        // setup code
+        let owner = funded::<T, I>(whitelisted_caller());
+        let hash = register::<T, I>(&owner);
+        let expiry = RegistrationExpiry::<T, I>::get(&owner, hash).expect("Registered. qed");
+
+        #[block]
+        {
+            crate::Pallet::<T, I>::on_idle(expiry, Weight::MAX);
+        }
+
+        // Verify
+        assert!(!Tickets::<T, I>::contains_key((&owner, hash)));
+        assert!(!Vks::<T, I>::contains_key(hash));
+    }
+
+    impl_benchmark_test_suite!(
+        FakeVerifierBench,
+        super::mock::test_ext(),
+        super::mock::Test
+    );
+}
+
+#[cfg(test)]
+type FakeVerifierBench<T> = Pallet<T, crate::mock::FakeVerifier>;
+
+#[cfg(test)]
+mod mock {
+    pub use crate::mock::Test;
+    use sp_runtime::BuildStorage;
+
+    pub fn test_ext() -> sp_io::TestExternalities {
+        crate::mock::StatementRetention::set(Some(10));
+        frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap()
+            .into()
+    }
+}
@@ -10,11 +10,25 @@ use sp_core::Get;
 pub trait WeightInfo {
     fn disable_verifier() -> Weight;
     fn on_verify_disabled_verifier() -> Weight;
+    fn index_statement() -> Weight;
+    fn prune_statement() -> Weight;
+    fn revoke_vk() -> Weight;
+    fn restore_vk() -> Weight;
+    fn transfer_vk() -> Weight;
+    fn accept_vk_transfer() -> Weight;
+    fn renew_vk() -> Weight;
+    fn expire_vk() -> Weight;
 }
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::WeightInfo;
+    use frame_support::{pallet_prelude::*, Identity};
+    use frame_system::pallet_prelude::*;
+    use sp_core::H256;
+    use sp_runtime::traits::Saturating;
+    #[cfg(feature = "std")]
+    use sp_runtime::{Deserialize, Serialize};
 
     #[pallet::pallet]
     /// The common pallet-verifiers component.
@@ -32,12 +46,149 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         /// Weights
         type CommonWeightInfo: WeightInfo;
+        /// How many blocks a verified statement is kept in the statements index: `None` means
+        /// that the verified statements are not indexed at all.
+        #[pallet::constant]
+        type StatementRetention: Get<Option<BlockNumberFor<Self>>>;
+    }
+
+    /// Where and how a statement has been verified.
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct StatementInfo<AccountId, BlockNumber> {
+        /// The block where the statement has been verified.
+        pub block: BlockNumber,
+        /// The index of the verifier pallet that verified the proof.
+        pub verifier: u8,
+        /// The hash of the verification key used to verify the proof.
+        pub vk_hash: H256,
+        /// The account that submitted the proof, if any.
+        pub account: Option<AccountId>,
+        /// The domain where the statement should be aggregated, if any.
+        pub domain_id: Option<u32>,
+    }
+
+    /// The verified statements: just the last verification of each statement is kept.
+    #[pallet::storage]
+    pub type Statements<T: Config> = StorageMap<
+        Hasher = Identity,
+        Key = H256,
+        Value = StatementInfo<T::AccountId, BlockNumberFor<T>>,
+    >;
+
+    /// The statements indexed at each block, used to prune the expired ones.
+    #[pallet::storage]
+    pub type StatementsExpiry<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Identity, H256, ()>;
+
+    /// The oldest block whose statements may not have been pruned yet.
+    #[pallet::storage]
+    pub type PruneCursor<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_statements(now, remaining_weight)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Return the info about the last verification of `statement`, if it is still in the
+        /// statements index.
+        pub fn statement_info(
+            statement: H256,
+        ) -> Option<StatementInfo<T::AccountId, BlockNumberFor<T>>> {
+            Statements::<T>::get(statement)
+        }
+
+        /// The weight of `index_statement`.
+        pub fn index_statement_weight() -> Weight {
+            if T::StatementRetention::get().is_some() {
+                T::CommonWeightInfo::index_statement()
+            } else {
+                Weight::zero()
+            }
+        }
+
+        /// Add a verified statement to the statements index, if it is enabled.
+        pub fn index_statement(
+            statement: H256,
+            verifier: u8,
+            vk_hash: H256,
+            account: Option<T::AccountId>,
+            domain_id: Option<u32>,
+        ) {
+            if T::StatementRetention::get().is_none() {
+                return;
+            }
+            let block = frame_system::Pallet::<T>::block_number();
+            Statements::<T>::insert(
+                statement,
+                StatementInfo {
+                    block,
+                    verifier,
+                    vk_hash,
+                    account,
+                    domain_id,
+                },
+            );
+            StatementsExpiry::<T>::insert(block, statement, ());
+            if !PruneCursor::<T>::exists() {
+                PruneCursor::<T>::put(block);
+            }
+        }
+
+        /// Remove the expired statements from the index while there is enough weight left.
+        fn prune_statements(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut used = db_weight.reads(1);
+            if remaining_weight.any_lt(used) {
+                return Weight::zero();
+            }
+            let (Some(retention), Some(mut cursor)) =
+                (T::StatementRetention::get(), PruneCursor::<T>::get())
+            else {
+                return used;
+            };
+            let next_block = db_weight.reads(1);
+            let prune = T::CommonWeightInfo::prune_statement();
+            let cursor_update = db_weight.writes(1);
+            'blocks: while cursor.saturating_add(retention) <= now {
+                let mut expired = StatementsExpiry::<T>::drain_prefix(cursor);
+                loop {
+                    if remaining_weight
+                        .any_lt(used.saturating_add(prune).saturating_add(cursor_update))
+                    {
+                        break 'blocks;
+                    }
+                    let Some((statement, ())) = expired.next() else {
+                        break;
+                    };
+                    Statements::<T>::mutate_exists(statement, |info| {
+                        if matches!(info, Some(info) if info.block == cursor) {
+                            *info = None;
+                        }
+                    });
+                    used.saturating_accrue(prune);
+                }
+                if remaining_weight.any_lt(
+                    used.saturating_add(next_block)
+                        .saturating_add(cursor_update),
+                ) {
+                    break;
+                }
+                cursor.saturating_inc();
+                used.saturating_accrue(next_block);
+            }
+            PruneCursor::<T>::put(cursor);
+            used.saturating_add(cursor_update)
+        }
     }
 }
 
 /// The implementation is quite rude now but should be fine. We implement this fixed weight
-/// for each runtime. Obviously we can write a real benchmark later to use in the final
-/// runtime but it should never be lot so far from this.
+/// for each runtime till the weights are generated from the `crate::benchmarking` benchmarks,
+/// but they should never be lot so far from this.
 impl<T: frame_system::Config> WeightInfo for T {
     fn disable_verifier() -> Weight {
         T::DbWeight::get().writes(1_u64)
@@ -46,4 +197,36 @@ impl<T: frame_system::Config> WeightInfo for T {
     fn on_verify_disabled_verifier() -> Weight {
         T::DbWeight::get().reads(1_u64)
     }
+
+    fn index_statement() -> Weight {
+        T::DbWeight::get().reads_writes(1_u64, 3_u64)
+    }
+
+    fn prune_statement() -> Weight {
+        T::DbWeight::get().reads_writes(2_u64, 2_u64)
+    }
+
+    fn revoke_vk() -> Weight {
+        T::DbWeight::get().reads_writes(1_u64, 1_u64)
+    }
+
+    fn restore_vk() -> Weight {
+        T::DbWeight::get().reads_writes(1_u64, 1_u64)
+    }
+
+    fn transfer_vk() -> Weight {
+        T::DbWeight::get().reads_writes(2_u64, 1_u64)
+    }
+
+    fn accept_vk_transfer() -> Weight {
+        T::DbWeight::get().reads_writes(9_u64, 10_u64)
+    }
+
+    fn renew_vk() -> Weight {
+        T::DbWeight::get().reads_writes(4_u64, 3_u64)
+    }
+
+    fn expire_vk() -> Weight {
+        T::DbWeight::get().reads_writes(6_u64, 7_u64)
+    }
 }
@@ -68,6 +68,8 @@ pub use pallet::*;
 
 pub use pallet_verifiers_macros::*;
 
+#[allow(missing_docs)]
+pub mod benchmarking;
 pub mod common;
 pub mod migrations;
 #[allow(missing_docs)]
@@ -75,6 +77,9 @@ pub mod mock;
 
 mod tests;
 
+pub use hp_on_proof_verified::{
+    LegacyOnProofVerified, OnProofRejected, OnProofVerifiedV2, ProofContext,
+};
 pub use hp_verifiers::WeightInfo;
 #[frame_support::pallet]
 pub mod pallet {
@@ -89,24 +94,45 @@ pub mod pallet {
     use frame_support::{
         dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo, PostDispatchInfo},
         pallet_prelude::*,
-        traits::{Consideration, Footprint},
-        Identity,
+        storage::with_storage_layer,
+        traits::{Consideration, Footprint, PalletInfoAccess},
+        CloneNoBound, Identity, PartialEqNoBound, RuntimeDebugNoBound,
     };
     use frame_system::pallet_prelude::*;
-    use hp_on_proof_verified::OnProofVerified;
+    use hp_on_proof_verified::{OnProofRejected, OnProofVerifiedV2, ProofContext};
     use sp_core::{hexdisplay::AsBytesRef, H256};
     use sp_io::hashing::keccak_256;
-    use sp_runtime::{traits::BadOrigin, ArithmeticError};
-    use sp_std::boxed::Box;
+    use sp_runtime::{
+        traits::{BadOrigin, Saturating},
+        ArithmeticError,
+    };
+    #[cfg(feature = "std")]
+    use sp_runtime::{Deserialize, Serialize};
+    use sp_std::{borrow::Cow, boxed::Box, vec::Vec};
 
     use hp_verifiers::{Verifier, VerifyError, WeightInfo};
 
     /// The in-code storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     /// Type alias for AccountId
     pub type AccountOf<T> = <T as frame_system::Config>::AccountId;
 
+    /// Maximum length in bytes of the reason of a verification key revocation.
+    pub const MAX_REVOKE_REASON_LENGTH: u32 = 256;
+
+    /// Why a verification key has been revoked (e.g. a link to the vulnerability report).
+    pub type RevokeReason = BoundedVec<u8, ConstU32<MAX_REVOKE_REASON_LENGTH>>;
+
+    /// Maximum length in bytes of the name in the verification key metadata.
+    pub const MAX_VK_NAME_LENGTH: u32 = 64;
+
+    /// Maximum length in bytes of the circuit URI in the verification key metadata.
+    pub const MAX_VK_CIRCUIT_URI_LENGTH: u32 = 256;
+
+    /// Maximum length in bytes of the version in the verification key metadata.
+    pub const MAX_VK_VERSION_LENGTH: u32 = 32;
+
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
 
@@ -140,6 +166,89 @@ pub mod pallet {
         }
     }
 
+    /// A single proof submission in a `submit_proofs` batch: it holds the same arguments
+    /// accepted by `submit_proof`.
+    #[derive(CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, Encode, Decode, TypeInfo)]
+    #[scale_info(skip_type_params(I))]
+    pub struct ProofSubmission<I: Verifier> {
+        /// The Vk or its registered hash
+        pub vk_or_hash: VkOrHash<I::Vk>,
+        /// The proof
+        pub proof: Box<I::Proof>,
+        /// The public inputs
+        pub pubs: Box<I::Pubs>,
+        /// The optional domain where the statement should be aggregated
+        pub domain_id: Option<u32>,
+    }
+
+    impl<I: Verifier> ProofSubmission<I> {
+        /// Build a new proof submission.
+        pub fn new(
+            vk_or_hash: VkOrHash<I::Vk>,
+            proof: I::Proof,
+            pubs: I::Pubs,
+            domain_id: Option<u32>,
+        ) -> Self {
+            Self {
+                vk_or_hash,
+                proof: Box::new(proof),
+                pubs: Box::new(pubs),
+                domain_id,
+            }
+        }
+    }
+
+    /// How `submit_proofs` should handle the submissions that fail.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub enum BatchMode {
+        /// A failing submission reverts the whole batch and its error is returned.
+        AllOrNothing,
+        /// A failing submission is reported by a `ProofRejected` event and the batch goes on.
+        BestEffort,
+    }
+
+    /// The reason why a proof submission checked by `dry_run` would be rejected.
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+    pub enum DryRunError {
+        /// Provided data has not valid public inputs.
+        InvalidInput,
+        /// Provided data has not valid proof.
+        InvalidProofData,
+        /// Verify proof failed.
+        VerifyError,
+        /// Provided an invalid verification key.
+        InvalidVerificationKey,
+        /// Provided an unregistered verification key hash.
+        VerificationKeyNotFound,
+        /// The verifier is disabled.
+        DisabledVerifier,
+        /// The verification key has been revoked.
+        RevokedVerificationKey,
+    }
+
+    impl From<VerifyError> for DryRunError {
+        fn from(e: VerifyError) -> Self {
+            match e {
+                VerifyError::InvalidInput => DryRunError::InvalidInput,
+                VerifyError::InvalidProofData => DryRunError::InvalidProofData,
+                VerifyError::VerifyError => DryRunError::VerifyError,
+                VerifyError::InvalidVerificationKey => DryRunError::InvalidVerificationKey,
+            }
+        }
+    }
+
+    /// The outcome of a `dry_run`.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct DryRun {
+        /// What `submit_proof` would do with the proof.
+        pub result: Result<(), DryRunError>,
+        /// The statement hash that `submit_proof` would produce.
+        pub statement: H256,
+        /// The weight that `submit_proof` would be charged.
+        pub weight: Weight,
+    }
+
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config<I: 'static = ()>: frame_system::Config + crate::common::Config
@@ -150,11 +259,17 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self, I>>
             + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// Proof verified call back
-        type OnProofVerified: OnProofVerified<Self::AccountId>;
+        type OnProofVerified: OnProofVerifiedV2<Self::AccountId>;
+        /// Proof rejected call back: it's called just for the rejections that don't fail the
+        /// whole call, like the ones in a `BatchMode::BestEffort` batch.
+        type OnProofRejected: OnProofRejected<Self::AccountId>;
         /// A means of providing some cost while data is stored on-chain.
         type Ticket: Consideration<Self::AccountId, Footprint>;
         /// Weights
         type WeightInfo: hp_verifiers::WeightInfo<I>;
+        /// The maximum number of proofs that can be submitted with a single `submit_proofs` call.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
         /// Currency used in benchmarks.
         #[cfg(feature = "runtime-benchmarks")]
         type Currency: Mutate<AccountOf<Self>>;
@@ -172,6 +287,59 @@ pub mod pallet {
         pub fn new(vk: V) -> Self {
             Self { vk, ref_count: 1 }
         }
+
+        /// The verification key.
+        pub(crate) fn vk(&self) -> &V {
+            &self.vk
+        }
+
+        /// Convert the vk, keeping the reference count.
+        pub(crate) fn map_vk<W>(self, f: impl FnOnce(V) -> W) -> VkEntry<W> {
+            VkEntry {
+                vk: f(self.vk),
+                ref_count: self.ref_count,
+            }
+        }
+    }
+
+    /// The metadata that the owner can attach to a verification key registration.
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq, Eq, Default, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct VkMetadata {
+        /// A human readable name of the circuit
+        pub name: BoundedVec<u8, ConstU32<MAX_VK_NAME_LENGTH>>,
+        /// Where the circuit can be found (e.g. a repository or an IPFS link)
+        pub circuit_uri: BoundedVec<u8, ConstU32<MAX_VK_CIRCUIT_URI_LENGTH>>,
+        /// The circuit version
+        pub version: BoundedVec<u8, ConstU32<MAX_VK_VERSION_LENGTH>>,
+    }
+
+    /// A verification key registered by an account.
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+    pub struct RegisteredVk<BlockNumber> {
+        /// Verification key hash
+        pub hash: H256,
+        /// The metadata attached by the owner, if any
+        pub metadata: Option<VkMetadata>,
+        /// The block where the registration expires, if any
+        pub expiry: Option<BlockNumber>,
+    }
+
+    /// The `RegisteredVk` of a runtime.
+    pub type RegisteredVkOf<T> = RegisteredVk<BlockNumberFor<T>>;
+
+    /// The storage footprint of a registration, which is covered by its deposit: the Vk and
+    /// its metadata.
+    pub(crate) fn registration_footprint<V: Encode>(
+        vk: &V,
+        metadata: &Option<VkMetadata>,
+    ) -> Footprint {
+        Footprint::from_parts(
+            1,
+            vk.encoded_size()
+                .saturating_add(metadata.as_ref().map_or(0, Encode::encoded_size)),
+        )
     }
 
     fn statement_hash(ctx: &[u8], vk_hash: &H256, pubs: &[u8]) -> H256 {
@@ -181,14 +349,17 @@ pub mod pallet {
         H256(keccak_256(data_to_hash.as_slice()))
     }
 
+    fn vk_hash<I: Verifier>(vk_or_hash: &VkOrHash<I::Vk>) -> H256 {
+        match vk_or_hash {
+            VkOrHash::Hash(h) => *h,
+            VkOrHash::Vk(vk) => I::vk_hash(vk),
+        }
+    }
+
     fn compute_hash<I: Verifier>(pubs: &I::Pubs, vk_or_hash: &VkOrHash<I::Vk>) -> H256 {
-        let hash = match vk_or_hash {
-            VkOrHash::Hash(h) => sp_std::borrow::Cow::Borrowed(h),
-            VkOrHash::Vk(vk) => sp_std::borrow::Cow::Owned(I::vk_hash(vk)),
-        };
         statement_hash(
             I::hash_context_data(),
-            hash.as_ref(),
+            &vk_hash::<I>(vk_or_hash),
             I::pubs_bytes(pubs).as_ref(),
         )
     }
@@ -215,6 +386,66 @@ pub mod pallet {
             /// Proof verified statement
             statement: H256,
         },
+        /// A proof in a `BestEffort` batch has been rejected.
+        ProofRejected {
+            /// Position of the rejected proof in the batch
+            index: u32,
+            /// The reason why the proof was rejected
+            error: DispatchError,
+        },
+        /// A `submit_proofs` batch has been processed.
+        BatchCompleted {
+            /// Number of verified proofs
+            verified: u32,
+            /// Number of rejected proofs
+            rejected: u32,
+        },
+        /// The Vk has been revoked.
+        VkRevoked {
+            /// Verification key hash
+            hash: H256,
+            /// Why the Vk has been revoked
+            reason: RevokeReason,
+        },
+        /// A revoked Vk has been restored.
+        VkRestored {
+            /// Verification key hash
+            hash: H256,
+        },
+        /// The registration of a Vk has been renewed.
+        VkRenewed {
+            /// Verification key hash
+            hash: H256,
+            /// The block where the registration expires: `None` if it never expires
+            expiry: Option<BlockNumberFor<T>>,
+        },
+        /// The registration of a Vk has expired and its funds have been unlocked: a
+        /// `VkUnregistered` event follows if no other account registered the Vk.
+        VkExpired {
+            /// Verification key hash
+            hash: H256,
+            /// The account that registered the Vk
+            account: T::AccountId,
+        },
+        /// The owner of a Vk registration proposed to transfer it to another account, which
+        /// should accept it with `accept_vk_transfer`.
+        VkTransferProposed {
+            /// Verification key hash
+            hash: H256,
+            /// The owner
+            from: T::AccountId,
+            /// The proposed new owner
+            to: T::AccountId,
+        },
+        /// The registration of a Vk has been transferred to another account.
+        VkTransferred {
+            /// Verification key hash
+            hash: H256,
+            /// The previous owner
+            from: T::AccountId,
+            /// The new owner
+            to: T::AccountId,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -234,6 +465,16 @@ pub mod pallet {
         DisabledVerifier,
         /// Verification key has already been registered.
         VerificationKeyAlreadyRegistered,
+        /// The verification key has been revoked.
+        RevokedVerificationKey,
+        /// The verification key has already been revoked.
+        VerificationKeyAlreadyRevoked,
+        /// The verification key has not been revoked.
+        VerificationKeyNotRevoked,
+        /// The registration expiry is not a future block.
+        InvalidExpiry,
+        /// No transfer of the verification key registration has been proposed to the caller.
+        TransferNotFound,
     }
 
     impl<T, I> From<VerifyError> for Error<T, I> {
@@ -268,6 +509,88 @@ pub mod pallet {
         I: Verifier,
     = StorageMap<Hasher = Blake2_128Concat, Key = (T::AccountId, H256), Value = Option<T::Ticket>>;
 
+    /// The revoked Vks, with the reason of their revocation.
+    #[pallet::storage]
+    #[pallet::getter(fn revoked_vks)]
+    pub type RevokedVks<T: Config<I>, I: 'static = ()>
+    where
+        I: Verifier,
+    = StorageMap<Hasher = Identity, Key = H256, Value = RevokeReason>;
+
+    /// The Vks registered by each account, with the metadata attached to the registration.
+    #[pallet::storage]
+    #[pallet::getter(fn registrations)]
+    pub type Registrations<T: Config<I>, I: 'static = ()>
+    where
+        I: Verifier,
+    = StorageDoubleMap<
+        Hasher1 = Blake2_128Concat,
+        Key1 = T::AccountId,
+        Hasher2 = Identity,
+        Key2 = H256,
+        Value = Option<VkMetadata>,
+    >;
+
+    /// The transfers of the Vk registrations proposed by their owner with `transfer_vk`, and
+    /// not accepted yet: owner, Vk hash -> proposed new owner.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_transfers)]
+    pub type PendingTransfers<T: Config<I>, I: 'static = ()>
+    where
+        I: Verifier,
+    = StorageDoubleMap<
+        Hasher1 = Blake2_128Concat,
+        Key1 = T::AccountId,
+        Hasher2 = Identity,
+        Key2 = H256,
+        Value = T::AccountId,
+    >;
+
+    /// The block where each time-limited registration expires.
+    #[pallet::storage]
+    #[pallet::getter(fn registration_expiry)]
+    pub type RegistrationExpiry<T: Config<I>, I: 'static = ()>
+    where
+        I: Verifier,
+    = StorageDoubleMap<
+        Hasher1 = Blake2_128Concat,
+        Key1 = T::AccountId,
+        Hasher2 = Identity,
+        Key2 = H256,
+        Value = BlockNumberFor<T>,
+    >;
+
+    /// The registrations that expire at each block, used to remove them: an entry is stale if
+    /// the registration has been renewed or unregistered in the meantime.
+    #[pallet::storage]
+    pub type ExpiringRegistrations<T: Config<I>, I: 'static = ()>
+    where
+        I: Verifier,
+    = StorageDoubleMap<
+        Hasher1 = Twox64Concat,
+        Key1 = BlockNumberFor<T>,
+        Hasher2 = Blake2_128Concat,
+        Key2 = (T::AccountId, H256),
+        Value = (),
+    >;
+
+    /// The oldest block whose expiring registrations may not have been removed yet.
+    #[pallet::storage]
+    pub type ExpiryCursor<T: Config<I>, I: 'static = ()>
+    where
+        I: Verifier,
+    = StorageValue<_, BlockNumberFor<T>>;
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I>
+    where
+        I: Verifier,
+    {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::expire_registrations(now, remaining_weight)
+        }
+    }
+
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
     // These functions materialize as "extrinsics", which are often compared to transactions.
     // Dispatchable functions must be annotated with a weight and must return a DispatchResult.
@@ -281,10 +604,7 @@ pub mod pallet {
         /// Accept either a Vk or its hash. If you use the Vk hash the Vk should be already registered
         /// with `register_vk` extrinsic.
         #[pallet::call_index(0)]
-        #[pallet::weight(match &vk_or_hash {
-                VkOrHash::Vk(_) => T::WeightInfo::submit_proof(proof, pubs),
-                VkOrHash::Hash(_) => T::WeightInfo::submit_proof_with_vk_hash(proof, pubs),
-            })]
+        #[pallet::weight(submit_proof_weight::<T, I>(vk_or_hash, proof, pubs))]
         pub fn submit_proof(
             origin: OriginFor<T>,
             vk_or_hash: VkOrHash<I::Vk>,
@@ -300,30 +620,311 @@ pub mod pallet {
                 !Self::disabled().unwrap_or_default(),
                 on_disable_error::<T, I>()
             );
-            let vk = match &vk_or_hash {
-                VkOrHash::Hash(h) => Vks::<T, I>::get(h)
-                    .map(|vk_entry| vk_entry.vk)
-                    .ok_or(Error::<T, I>::VerificationKeyNotFound)?,
-                VkOrHash::Vk(vk) => {
-                    I::validate_vk(vk).map_err(Error::<T, I>::from)?;
-                    vk.as_ref().clone()
-                }
-            };
             let account = ensure_signed(origin).ok();
-            let statement = compute_hash::<I>(&pubs, &vk_or_hash);
-            I::verify_proof(&vk, &proof, &pubs)
-                .inspect(|_| Self::deposit_event(Event::ProofVerified { statement }))
-                .map(|_x| T::OnProofVerified::on_proof_verified(account, domain_id, statement))
-                .map_err(Error::<T, I>::from)?;
+            Self::do_submit_proof(account, &vk_or_hash, &proof, &pubs, domain_id)?;
             Ok(().into())
         }
 
         /// Register a new verification key.
         /// On success emit a `VkRegistered` event that contain the hash to use on `submit_proof`.
-        /// Lock some funds, which can be unlocked by calling `unregister_vk`.
+        /// Lock some funds which can be unlocked by calling `unregister_vk`.
+        /// An expired registration of the same verification key that has not been removed yet
+        /// is removed first.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::register_vk(vk))]
+        #[pallet::weight(T::WeightInfo::register_vk(vk).saturating_add(
+            <T::CommonWeightInfo as crate::common::WeightInfo>::expire_vk()
+        ))]
         pub fn register_vk(origin: OriginFor<T>, vk: Box<I::Vk>) -> DispatchResultWithPostInfo {
+            Self::do_register_vk(origin, vk, None, None)
+        }
+
+        /// Disable verifier: both `register_vk` and `submit_proof` will return a
+        /// `DisabledVerifier` Error.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T::CommonWeightInfo as crate::common::WeightInfo>::disable_verifier())]
+        pub fn disable(origin: OriginFor<T>, disabled: bool) -> DispatchResult {
+            log::trace!("Disable verifier: {disabled}");
+            // Just root can disable/enable the verifier
+            ensure_root(origin)?;
+
+            Disabled::<T, I>::put(disabled);
+            Ok(())
+        }
+
+        /// Unregister a previously registered verification key.
+        /// Should be called by the same account used for registering the verification key.
+        /// Unlock the funds which were locked when registering the verification key, even if the
+        /// verification key has been revoked.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::unregister_vk())]
+        pub fn unregister_vk(origin: OriginFor<T>, vk_hash: H256) -> DispatchResult {
+            log::trace!("Unregister vk");
+            let account_id = ensure_signed(origin)?;
+            if let Some(ticket) = Tickets::<T, I>::take((&account_id, vk_hash)) {
+                Self::remove_registration(&account_id, vk_hash, ticket)
+            } else if Vks::<T, I>::contains_key(vk_hash) {
+                Err(BadOrigin)?
+            } else {
+                Err(Error::<T, I>::VerificationKeyNotFound)?
+            }
+        }
+
+        /// Submit a batch of proofs to verify in a single extrinsic. Every submission is handled
+        /// like a `submit_proof` call: each verified proof emits its own `ProofVerified` event
+        /// and is notified to `OnProofVerified`.
+        /// With `BatchMode::AllOrNothing` the proofs are checked all together by the verifier
+        /// batch verification, and any failing submission reverts the whole batch.
+        /// With `BatchMode::BestEffort` the proofs are checked one by one: every failing
+        /// submission emits a `ProofRejected` event and the remaining ones are processed anyway.
+        /// At the end a `BatchCompleted` event is emitted.
+        #[pallet::call_index(4)]
+        #[pallet::weight(submit_proofs_weight::<T, I>(proofs, mode))]
+        pub fn submit_proofs(
+            origin: OriginFor<T>,
+            proofs: BoundedVec<ProofSubmission<I>, T::MaxBatchSize>,
+            mode: BatchMode,
+        ) -> DispatchResultWithPostInfo {
+            log::trace!("Submitting {} proofs", proofs.len());
+            ensure!(
+                !Self::disabled().unwrap_or_default(),
+                on_disable_error::<T, I>()
+            );
+            let account = ensure_signed(origin).ok();
+            match mode {
+                BatchMode::AllOrNothing => Self::do_submit_proofs_batch(account, &proofs)?,
+                BatchMode::BestEffort => Self::do_submit_proofs_one_by_one(account, &proofs),
+            }
+            Ok(().into())
+        }
+
+        /// Revoke a verification key, e.g. because its circuit is underconstrained: the proofs
+        /// that use it are rejected with a `RevokedVerificationKey` error, whether the
+        /// verification key is given by hash or inline, and it cannot be registered anymore.
+        /// The accounts that registered it can still unregister it to unlock their funds.
+        /// On success emit a `VkRevoked` event.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T::CommonWeightInfo as crate::common::WeightInfo>::revoke_vk())]
+        pub fn revoke_vk(
+            origin: OriginFor<T>,
+            vk_hash: H256,
+            reason: RevokeReason,
+        ) -> DispatchResult {
+            log::trace!("Revoke vk {vk_hash:?}");
+            // Just root can revoke/restore a verification key
+            ensure_root(origin)?;
+            ensure!(
+                !RevokedVks::<T, I>::contains_key(vk_hash),
+                Error::<T, I>::VerificationKeyAlreadyRevoked
+            );
+
+            RevokedVks::<T, I>::insert(vk_hash, &reason);
+            Self::deposit_event(Event::VkRevoked {
+                hash: vk_hash,
+                reason,
+            });
+            Ok(())
+        }
+
+        /// Restore a revoked verification key: it can be used and registered again.
+        /// On success emit a `VkRestored` event.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T::CommonWeightInfo as crate::common::WeightInfo>::restore_vk())]
+        pub fn restore_vk(origin: OriginFor<T>, vk_hash: H256) -> DispatchResult {
+            log::trace!("Restore vk {vk_hash:?}");
+            ensure_root(origin)?;
+            RevokedVks::<T, I>::take(vk_hash).ok_or(Error::<T, I>::VerificationKeyNotRevoked)?;
+
+            Self::deposit_event(Event::VkRestored { hash: vk_hash });
+            Ok(())
+        }
+
+        /// Propose to transfer the registration of a verification key, with its metadata, to
+        /// another account (e.g. from a deployer key to a multisig) without unregistering it.
+        /// Should be called by the account that registered the verification key. Nothing moves
+        /// till `new_owner` accepts the transfer with `accept_vk_transfer`: the funds stay locked
+        /// from the caller till then. A further call replaces the pending transfer, and the
+        /// pending transfer is dropped if the registration is removed. An expired registration
+        /// cannot be transferred.
+        /// On success emit a `VkTransferProposed` event.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T::CommonWeightInfo as crate::common::WeightInfo>::transfer_vk())]
+        pub fn transfer_vk(
+            origin: OriginFor<T>,
+            vk_hash: H256,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            log::trace!("Transfer vk {vk_hash:?}");
+            let account_id = ensure_signed(origin)?;
+            Self::ensure_owner(&account_id, vk_hash)?;
+            ensure!(
+                new_owner != account_id,
+                Error::<T, I>::VerificationKeyAlreadyRegistered
+            );
+            PendingTransfers::<T, I>::insert(&account_id, vk_hash, &new_owner);
+
+            Self::deposit_event(Event::VkTransferProposed {
+                hash: vk_hash,
+                from: account_id,
+                to: new_owner,
+            });
+            Ok(())
+        }
+
+        /// Renew the registration of a verification key: it will expire at the `expiry` block
+        /// instead, or never if `expiry` is `None`.
+        /// Should be called by the account that registered the verification key, before the
+        /// registration expires.
+        /// On success emit a `VkRenewed` event.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T::CommonWeightInfo as crate::common::WeightInfo>::renew_vk())]
+        pub fn renew_vk(
+            origin: OriginFor<T>,
+            vk_hash: H256,
+            expiry: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            log::trace!("Renew vk {vk_hash:?}");
+            let account_id = ensure_signed(origin)?;
+            Self::ensure_owner(&account_id, vk_hash)?;
+            Self::set_expiry(&account_id, vk_hash, expiry)?;
+
+            Self::deposit_event(Event::VkRenewed {
+                hash: vk_hash,
+                expiry,
+            });
+            Ok(())
+        }
+
+        /// Accept the transfer of a verification key registration proposed by its owner `from`
+        /// with `transfer_vk`: the registration, with its metadata and expiry, moves to the
+        /// caller. The funds locked from `from` are unlocked and the same amount is locked from
+        /// the caller. An expired registration of the caller that has not been removed yet is
+        /// removed first.
+        /// On success emit a `VkTransferred` event.
+        #[pallet::call_index(9)]
+        #[pallet::weight(
+            <T::CommonWeightInfo as crate::common::WeightInfo>::accept_vk_transfer()
+                .saturating_add(<T::CommonWeightInfo as crate::common::WeightInfo>::expire_vk())
+        )]
+        pub fn accept_vk_transfer(
+            origin: OriginFor<T>,
+            vk_hash: H256,
+            from: T::AccountId,
+        ) -> DispatchResult {
+            log::trace!("Accept vk {vk_hash:?} transfer");
+            let new_owner = ensure_signed(origin)?;
+            ensure!(
+                PendingTransfers::<T, I>::get(&from, vk_hash).as_ref() == Some(&new_owner),
+                Error::<T, I>::TransferNotFound
+            );
+            ensure!(
+                !Self::is_expired(&from, vk_hash),
+                Error::<T, I>::VerificationKeyNotFound
+            );
+            let ticket = Tickets::<T, I>::take((&from, vk_hash))
+                .ok_or(Error::<T, I>::VerificationKeyNotFound)?;
+            PendingTransfers::<T, I>::remove(&from, vk_hash);
+            Self::remove_expired(&new_owner, vk_hash);
+            ensure!(
+                !Tickets::<T, I>::contains_key((&new_owner, vk_hash)),
+                Error::<T, I>::VerificationKeyAlreadyRegistered
+            );
+            let metadata = Registrations::<T, I>::take(&from, vk_hash).flatten();
+            let ticket = match ticket {
+                Some(ticket) => {
+                    ticket.drop(&from)?;
+                    let vk_entry =
+                        Vks::<T, I>::get(vk_hash).ok_or(Error::<T, I>::VerificationKeyNotFound)?;
+                    let footprint = registration_footprint(&vk_entry.vk, &metadata);
+                    Some(T::Ticket::new(&new_owner, footprint)?)
+                }
+                None => None,
+            };
+            Tickets::<T, I>::insert((&new_owner, vk_hash), ticket);
+            Registrations::<T, I>::insert(&new_owner, vk_hash, metadata);
+            let expiry = RegistrationExpiry::<T, I>::take(&from, vk_hash);
+            Self::set_expiry(&new_owner, vk_hash, expiry)?;
+
+            Self::deposit_event(Event::VkTransferred {
+                hash: vk_hash,
+                from,
+                to: new_owner,
+            });
+            Ok(())
+        }
+
+        /// Register a new verification key as `register_vk` does, optionally attaching some
+        /// metadata (name, circuit URI and version) to the registration: the locked funds
+        /// cover both the verification key and its metadata.
+        /// If `expiry` is given the registration is removed, and the funds are unlocked, at that
+        /// block unless it's renewed with `renew_vk` before.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::register_vk(vk).saturating_add(
+            <T::CommonWeightInfo as crate::common::WeightInfo>::expire_vk()
+        ))]
+        pub fn register_vk_with_options(
+            origin: OriginFor<T>,
+            vk: Box<I::Vk>,
+            metadata: Option<VkMetadata>,
+            expiry: Option<BlockNumberFor<T>>,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_register_vk(origin, vk, metadata, expiry)
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Pallet<T, I>
+    where
+        I: Verifier,
+    {
+        /// Check a proof submission exactly as `submit_proof` would, but without changing the
+        /// state: return the verification result, the statement hash and the weight of the
+        /// `submit_proof` call.
+        pub fn dry_run(vk_or_hash: &VkOrHash<I::Vk>, proof: &I::Proof, pubs: &I::Pubs) -> DryRun {
+            let result = if Self::disabled().unwrap_or_default() {
+                Err(DryRunError::DisabledVerifier)
+            } else if RevokedVks::<T, I>::contains_key(vk_hash::<I>(vk_or_hash)) {
+                Err(DryRunError::RevokedVerificationKey)
+            } else {
+                match vk_or_hash {
+                    VkOrHash::Hash(h) => Vks::<T, I>::get(h)
+                        .map(|vk_entry| Cow::Owned(vk_entry.vk))
+                        .ok_or(DryRunError::VerificationKeyNotFound),
+                    VkOrHash::Vk(vk) => I::validate_vk(vk)
+                        .map(|_| Cow::Borrowed(vk.as_ref()))
+                        .map_err(Into::into),
+                }
+                .and_then(|vk| I::verify_proof(&vk, proof, pubs).map_err(Into::into))
+            };
+            DryRun {
+                result,
+                statement: Self::statement_hash(vk_or_hash, pubs),
+                weight: submit_proof_weight::<T, I>(vk_or_hash, proof, pubs),
+            }
+        }
+
+        /// The statement hash that `submit_proof` would produce for the given Vk (or its hash)
+        /// and public inputs.
+        pub fn statement_hash(vk_or_hash: &VkOrHash<I::Vk>, pubs: &I::Pubs) -> H256 {
+            compute_hash::<I>(pubs, vk_or_hash)
+        }
+
+        /// The Vks registered by `account`, with their metadata and expiry.
+        pub fn registered_vks(account: &AccountOf<T>) -> Vec<RegisteredVkOf<T>> {
+            Registrations::<T, I>::iter_prefix(account)
+                .map(|(hash, metadata)| RegisteredVk {
+                    hash,
+                    metadata,
+                    expiry: RegistrationExpiry::<T, I>::get(account, hash),
+                })
+                .collect()
+        }
+
+        /// Register `vk` as `register_vk_with_options` does.
+        fn do_register_vk(
+            origin: OriginFor<T>,
+            vk: Box<I::Vk>,
+            metadata: Option<VkMetadata>,
+            expiry: Option<BlockNumberFor<T>>,
+        ) -> DispatchResultWithPostInfo {
             log::trace!("Register vk");
             ensure!(
                 !Self::disabled().unwrap_or_default(),
@@ -331,14 +932,22 @@ pub mod pallet {
             );
             let account_id = ensure_signed(origin)?;
             let hash = I::vk_hash(&vk);
+            ensure!(
+                !RevokedVks::<T, I>::contains_key(hash),
+                Error::<T, I>::RevokedVerificationKey
+            );
+            let weight = T::WeightInfo::register_vk(&vk);
+            let expired = Self::remove_expired(&account_id, hash);
             ensure!(
                 !Tickets::<T, I>::contains_key((&account_id, hash)),
                 Error::<T, I>::VerificationKeyAlreadyRegistered
             );
             I::validate_vk(&vk).map_err(Error::<T, I>::from)?;
-            let footprint = Footprint::from_encodable(&vk);
+            let footprint = registration_footprint(&vk, &metadata);
             let ticket = T::Ticket::new(&account_id, footprint)?;
-            Tickets::<T, I>::insert((account_id, hash), ticket);
+            Tickets::<T, I>::insert((&account_id, hash), ticket);
+            Registrations::<T, I>::insert(&account_id, hash, metadata);
+            Self::set_expiry(&account_id, hash, expiry)?;
             Vks::<T, I>::mutate(hash, |vk_entry| {
                 match vk_entry {
                     Some(VkEntry { ref_count, .. }) => {
@@ -353,49 +962,317 @@ pub mod pallet {
                 Ok::<_, DispatchError>(())
             })?;
             Self::deposit_event(Event::VkRegistered { hash });
-            Ok(().into())
+            // Refund the removal of the expired registration if there was none.
+            Ok((!expired).then_some(weight).into())
         }
 
-        /// Disable verifier: both `register_vk` and `submit_proof` will return a
-        /// `DisabledVerifier` Error.
-        #[pallet::call_index(2)]
-        #[pallet::weight(<T::CommonWeightInfo as crate::common::WeightInfo>::disable_verifier())]
-        pub fn disable(origin: OriginFor<T>, disabled: bool) -> DispatchResult {
-            log::trace!("Disable verifier: {disabled}");
-            // Just root can disable/enable the verifier
-            ensure_root(origin)?;
-
-            Disabled::<T, I>::put(disabled);
+        /// Set when the registration expires (`None` means never) and schedule its removal.
+        fn set_expiry(
+            account: &AccountOf<T>,
+            vk_hash: H256,
+            expiry: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let Some(expiry) = expiry else {
+                RegistrationExpiry::<T, I>::remove(account, vk_hash);
+                return Ok(());
+            };
+            ensure!(
+                expiry > frame_system::Pallet::<T>::block_number(),
+                Error::<T, I>::InvalidExpiry
+            );
+            RegistrationExpiry::<T, I>::insert(account, vk_hash, expiry);
+            ExpiringRegistrations::<T, I>::insert(expiry, (account, vk_hash), ());
+            ExpiryCursor::<T, I>::mutate(|cursor| {
+                *cursor = Some(cursor.map_or(expiry, |cursor| cursor.min(expiry)))
+            });
             Ok(())
         }
 
-        /// Unregister a previously registered verification key.
-        /// Should be called by the same account used for registering the verification key.
-        /// Unlock the funds which were locked when registering the verification key.
-        #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::unregister_vk())]
-        pub fn unregister_vk(origin: OriginFor<T>, vk_hash: H256) -> DispatchResult {
-            log::trace!("Unregister vk");
-            let account_id = ensure_signed(origin)?;
-            if let Some(ticket) = Tickets::<T, I>::take((&account_id, vk_hash)) {
-                if let Some(ticket) = ticket {
-                    ticket.drop(&account_id)?;
-                }
-                Vks::<T, I>::mutate_exists(vk_hash, |vk_entry| match vk_entry {
-                    Some(v) => {
-                        v.ref_count = v.ref_count.saturating_sub(1);
-                        if v.ref_count == 0 {
-                            *vk_entry = None;
-                            Self::deposit_event(Event::VkUnregistered { hash: vk_hash });
-                        }
+        /// Remove the registration whose `ticket` has already been taken: unlock its funds and
+        /// drop the Vk if nobody else registered it.
+        fn remove_registration(
+            account: &AccountOf<T>,
+            vk_hash: H256,
+            ticket: Option<T::Ticket>,
+        ) -> DispatchResult {
+            if let Some(ticket) = ticket {
+                ticket.drop(account)?;
+            }
+            Registrations::<T, I>::remove(account, vk_hash);
+            RegistrationExpiry::<T, I>::remove(account, vk_hash);
+            PendingTransfers::<T, I>::remove(account, vk_hash);
+            Vks::<T, I>::mutate_exists(vk_hash, |vk_entry| match vk_entry {
+                Some(v) => {
+                    v.ref_count = v.ref_count.saturating_sub(1);
+                    if v.ref_count == 0 {
+                        *vk_entry = None;
+                        Self::deposit_event(Event::VkUnregistered { hash: vk_hash });
                     }
-                    None => unreachable!(),
-                });
+                }
+                None => unreachable!(),
+            });
+            Ok(())
+        }
+
+        /// Check that `account` registered the Vk and the registration has not expired: fail with
+        /// `BadOrigin` if the Vk was registered by somebody else only.
+        fn ensure_owner(account: &AccountOf<T>, vk_hash: H256) -> DispatchResult {
+            ensure!(
+                !Self::is_expired(account, vk_hash),
+                Error::<T, I>::VerificationKeyNotFound
+            );
+            if Tickets::<T, I>::contains_key((account, vk_hash)) {
                 Ok(())
             } else if Vks::<T, I>::contains_key(vk_hash) {
-                Err(BadOrigin)?
+                Err(BadOrigin.into())
             } else {
-                Err(Error::<T, I>::VerificationKeyNotFound)?
+                Err(Error::<T, I>::VerificationKeyNotFound.into())
+            }
+        }
+
+        /// Whether the registration has expired, even if it has not been removed yet: it's
+        /// handled as if it was not there.
+        fn is_expired(account: &AccountOf<T>, vk_hash: H256) -> bool {
+            RegistrationExpiry::<T, I>::get(account, vk_hash)
+                .is_some_and(|expiry| expiry <= frame_system::Pallet::<T>::block_number())
+        }
+
+        /// Remove the registration if it has expired but has not been removed yet.
+        fn remove_expired(account: &AccountOf<T>, vk_hash: H256) -> bool {
+            let expired = Self::is_expired(account, vk_hash);
+            if expired {
+                Self::expire_registration(account, vk_hash);
+            }
+            expired
+        }
+
+        /// Remove an expired registration.
+        fn expire_registration(account: &AccountOf<T>, vk_hash: H256) {
+            let result = with_storage_layer(|| {
+                let ticket = Tickets::<T, I>::take((account, vk_hash))
+                    .ok_or(Error::<T, I>::VerificationKeyNotFound)?;
+                Self::deposit_event(Event::VkExpired {
+                    hash: vk_hash,
+                    account: account.clone(),
+                });
+                Self::remove_registration(account, vk_hash, ticket)
+            });
+            if let Err(e) = result {
+                log::warn!("Cannot remove the expired registration of vk {vk_hash:?}: {e:?}");
+            }
+        }
+
+        /// Remove the expired registrations while there is enough weight left.
+        fn expire_registrations(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut used = db_weight.reads(1);
+            if remaining_weight.any_lt(used) {
+                return Weight::zero();
+            }
+            let Some(mut cursor) = ExpiryCursor::<T, I>::get() else {
+                return used;
+            };
+            let next_block = db_weight.reads(1);
+            let expire = <T::CommonWeightInfo as crate::common::WeightInfo>::expire_vk();
+            let cursor_update = db_weight.writes(1);
+            'blocks: while cursor <= now {
+                let mut expiring = ExpiringRegistrations::<T, I>::drain_prefix(cursor);
+                loop {
+                    if remaining_weight
+                        .any_lt(used.saturating_add(expire).saturating_add(cursor_update))
+                    {
+                        break 'blocks;
+                    }
+                    let Some(((account, vk_hash), ())) = expiring.next() else {
+                        break;
+                    };
+                    if RegistrationExpiry::<T, I>::get(&account, vk_hash) == Some(cursor) {
+                        Self::expire_registration(&account, vk_hash);
+                    }
+                    used.saturating_accrue(expire);
+                }
+                if remaining_weight.any_lt(
+                    used.saturating_add(next_block)
+                        .saturating_add(cursor_update),
+                ) {
+                    break;
+                }
+                cursor.saturating_inc();
+                used.saturating_accrue(next_block);
+            }
+            ExpiryCursor::<T, I>::put(cursor);
+            used.saturating_add(cursor_update)
+        }
+
+        fn resolve_vk(vk_or_hash: &VkOrHash<I::Vk>) -> Result<Cow<I::Vk>, DispatchError> {
+            ensure!(
+                !RevokedVks::<T, I>::contains_key(vk_hash::<I>(vk_or_hash)),
+                Error::<T, I>::RevokedVerificationKey
+            );
+            match vk_or_hash {
+                VkOrHash::Hash(h) => Vks::<T, I>::get(h)
+                    .map(|vk_entry| Cow::Owned(vk_entry.vk))
+                    .ok_or_else(|| Error::<T, I>::VerificationKeyNotFound.into()),
+                VkOrHash::Vk(vk) => {
+                    I::validate_vk(vk).map_err(Error::<T, I>::from)?;
+                    Ok(Cow::Borrowed(vk.as_ref()))
+                }
+            }
+        }
+
+        fn proof_context(
+            account: Option<AccountOf<T>>,
+            domain_id: Option<u32>,
+            vk_or_hash: &VkOrHash<I::Vk>,
+            proof: &I::Proof,
+            pubs: &I::Pubs,
+        ) -> ProofContext<AccountOf<T>> {
+            ProofContext {
+                account,
+                domain_id,
+                verifier: I::hash_context_data(),
+                vk_hash: vk_hash::<I>(vk_or_hash),
+                statement: compute_hash::<I>(pubs, vk_or_hash),
+                weight: submit_proof_weight::<T, I>(vk_or_hash, proof, pubs),
+            }
+        }
+
+        fn on_verified(ctx: ProofContext<AccountOf<T>>) {
+            Self::deposit_event(Event::ProofVerified {
+                statement: ctx.statement,
+            });
+            crate::common::Pallet::<T>::index_statement(
+                ctx.statement,
+                <Self as PalletInfoAccess>::index() as u8,
+                ctx.vk_hash,
+                ctx.account.clone(),
+                ctx.domain_id,
+            );
+            T::OnProofVerified::on_proof_verified(&ctx);
+        }
+
+        fn do_submit_proof(
+            account: Option<AccountOf<T>>,
+            vk_or_hash: &VkOrHash<I::Vk>,
+            proof: &I::Proof,
+            pubs: &I::Pubs,
+            domain_id: Option<u32>,
+        ) -> DispatchResult {
+            let vk = Self::resolve_vk(vk_or_hash)?;
+            I::verify_proof(&vk, proof, pubs).map_err(Error::<T, I>::from)?;
+            Self::on_verified(Self::proof_context(
+                account, domain_id, vk_or_hash, proof, pubs,
+            ));
+            Ok(())
+        }
+
+        fn do_submit_proofs_batch(
+            account: Option<AccountOf<T>>,
+            proofs: &[ProofSubmission<I>],
+        ) -> DispatchResult {
+            let vks = proofs
+                .iter()
+                .map(|p| Self::resolve_vk(&p.vk_or_hash))
+                .collect::<Result<Vec<_>, _>>()?;
+            let batch = proofs
+                .iter()
+                .zip(vks.iter())
+                .map(|(p, vk)| (vk.as_ref(), p.proof.as_ref(), p.pubs.as_ref()))
+                .collect::<Vec<_>>();
+            I::verify_batch(&batch).map_err(Error::<T, I>::from)?;
+            for p in proofs {
+                Self::on_verified(Self::proof_context(
+                    account.clone(),
+                    p.domain_id,
+                    &p.vk_or_hash,
+                    &p.proof,
+                    &p.pubs,
+                ));
+            }
+            Self::deposit_event(Event::BatchCompleted {
+                verified: proofs.len() as u32,
+                rejected: 0,
+            });
+            Ok(())
+        }
+
+        fn do_submit_proofs_one_by_one(
+            account: Option<AccountOf<T>>,
+            proofs: &[ProofSubmission<I>],
+        ) {
+            let mut verified = 0_u32;
+            let mut rejected = 0_u32;
+            for (index, p) in proofs.iter().enumerate() {
+                match with_storage_layer(|| {
+                    Self::do_submit_proof(
+                        account.clone(),
+                        &p.vk_or_hash,
+                        &p.proof,
+                        &p.pubs,
+                        p.domain_id,
+                    )
+                }) {
+                    Ok(()) => verified.saturating_inc(),
+                    Err(error) => {
+                        rejected.saturating_inc();
+                        T::OnProofRejected::on_proof_rejected(
+                            &Self::proof_context(
+                                account.clone(),
+                                p.domain_id,
+                                &p.vk_or_hash,
+                                &p.proof,
+                                &p.pubs,
+                            ),
+                            &error,
+                        );
+                        Self::deposit_event(Event::ProofRejected {
+                            index: index as u32,
+                            error,
+                        });
+                    }
+                }
+            }
+            Self::deposit_event(Event::BatchCompleted { verified, rejected });
+        }
+    }
+
+    /// The weight of a single proof submission.
+    pub(crate) fn submit_proof_weight<T: Config<I>, I: Verifier>(
+        vk_or_hash: &VkOrHash<I::Vk>,
+        proof: &I::Proof,
+        pubs: &I::Pubs,
+    ) -> Weight {
+        match vk_or_hash {
+            VkOrHash::Vk(vk) => T::WeightInfo::submit_proof_with_vk(vk, proof, pubs),
+            VkOrHash::Hash(_) => T::WeightInfo::submit_proof_with_vk_hash(proof, pubs),
+        }
+        .saturating_add(crate::common::Pallet::<T>::index_statement_weight())
+    }
+
+    /// The weight of a batch of proof submissions: in `BatchMode::AllOrNothing` mode the proofs
+    /// are verified all together and the verifier can provide a cheaper weight.
+    pub(crate) fn submit_proofs_weight<T: Config<I>, I: Verifier>(
+        proofs: &[ProofSubmission<I>],
+        mode: &BatchMode,
+    ) -> Weight {
+        let single = proofs.iter().fold(Weight::zero(), |w, p| {
+            w.saturating_add(submit_proof_weight::<T, I>(
+                &p.vk_or_hash,
+                &p.proof,
+                &p.pubs,
+            ))
+        });
+        match mode {
+            BatchMode::BestEffort => single,
+            BatchMode::AllOrNothing => {
+                let batch = proofs
+                    .iter()
+                    .map(|p| {
+                        let with_vk_hash = matches!(p.vk_or_hash, VkOrHash::Hash(_));
+                        (p.proof.as_ref(), p.pubs.as_ref(), with_vk_hash)
+                    })
+                    .collect::<Vec<_>>();
+                T::WeightInfo::submit_proofs_batch(&batch, single)
             }
         }
     }
@@ -0,0 +1,83 @@
+#![cfg(not(doc))]
+
+use frame_support::{
+    traits::{GetStorageVersion, OnRuntimeUpgrade},
+    weights::Weight,
+};
+use hp_verifiers::Verifier;
+use sp_core::Get;
+
+use crate::{Config, Pallet, Vks};
+
+/// Implements [`OnRuntimeUpgrade`] for a verifier pallet added by a runtime upgrade: its
+/// genesis is never built, so its on-chain storage version is 0 and the migrations from V0
+/// would run on a storage that already has the current layout.
+///
+/// Set the on-chain storage version to the in-code one if it's still 0 and no vk is stored:
+/// don't use it for a pallet that was already deployed.
+pub struct InitStorageVersion<T, I>(core::marker::PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for InitStorageVersion<T, I>
+where
+    I: Verifier,
+{
+    fn on_runtime_upgrade() -> Weight {
+        let db_weight = T::DbWeight::get();
+        if Pallet::<T, I>::on_chain_storage_version() != 0 {
+            return db_weight.reads(1);
+        }
+        if Vks::<T, I>::iter_keys().next().is_some() {
+            log::warn!("Not a new verifier pallet: its storage version is left to 0");
+            return db_weight.reads(2);
+        }
+        Pallet::<T, I>::in_code_storage_version().put::<Pallet<T, I>>();
+        db_weight.reads_writes(2, 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::*;
+    use crate::tests::*;
+    use crate::VkEntry;
+    use frame_support::traits::StorageVersion;
+    use sp_core::H256;
+
+    #[test]
+    fn set_the_current_storage_version_of_a_new_pallet() {
+        test_ext().execute_with(|| {
+            StorageVersion::new(0).put::<Pallet<Test, FakeVerifier>>();
+
+            InitStorageVersion::<Test, FakeVerifier>::on_runtime_upgrade();
+
+            assert_eq!(
+                Pallet::<Test, FakeVerifier>::on_chain_storage_version(),
+                Pallet::<Test, FakeVerifier>::in_code_storage_version()
+            );
+        })
+    }
+
+    #[test]
+    fn not_change_the_storage_version_of_a_migrated_pallet() {
+        test_ext().execute_with(|| {
+            StorageVersion::new(2).put::<Pallet<Test, FakeVerifier>>();
+
+            InitStorageVersion::<Test, FakeVerifier>::on_runtime_upgrade();
+
+            assert_eq!(Pallet::<Test, FakeVerifier>::on_chain_storage_version(), 2);
+        })
+    }
+
+    #[test]
+    fn not_change_the_storage_version_of_a_pallet_with_vks() {
+        test_ext().execute_with(|| {
+            StorageVersion::new(0).put::<Pallet<Test, FakeVerifier>>();
+            Vks::<Test, FakeVerifier>::insert(H256::repeat_byte(1), VkEntry::new(42));
+
+            InitStorageVersion::<Test, FakeVerifier>::on_runtime_upgrade();
+
+            assert_eq!(Pallet::<Test, FakeVerifier>::on_chain_storage_version(), 0);
+        })
+    }
+}
@@ -1,4 +1,13 @@
 //! Storage migrations.
 
+/// Initialization of the verifier pallets added by a runtime upgrade
+pub mod init;
+
 /// Migration from v0 to v1
 pub mod v1;
+
+/// Migration from v1 to v2
+pub mod v2;
+
+/// Migration from v2 to v3
+pub mod v3;
@@ -0,0 +1,105 @@
+#![cfg(not(doc))]
+
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+use hp_verifiers::Verifier;
+use sp_core::Get;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use crate::{Config, Registrations, Tickets};
+
+/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V1 to V2.
+///
+/// V2 adds the [`crate::Registrations`] `StorageDoubleMap`, which indexes the registered vks by
+/// account: the registrations already in [`crate::Tickets`] are added to it, without metadata.
+pub struct InnerMigrateV1ToV2<T, I>(core::marker::PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T, I>
+where
+    I: Verifier,
+{
+    /// Migrate the storage from V1 to V2.
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        let mut n = 0_u64;
+        for (account, hash) in Tickets::<T, I>::iter_keys() {
+            Registrations::<T, I>::insert(account, hash, None::<crate::VkMetadata>);
+            n += 1;
+        }
+        T::DbWeight::get().reads_writes(n, n)
+    }
+
+    /// Verifies the storage was migrated correctly.
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        frame_support::ensure!(
+            Tickets::<T, I>::iter_keys()
+                .all(|(account, hash)| Registrations::<T, I>::contains_key(account, hash)),
+            "missing registration"
+        );
+        Ok(())
+    }
+}
+
+/// [`UncheckedOnRuntimeUpgrade`] implementation [`InnerMigrateV1ToV2`] wrapped in a
+/// [`VersionedMigration`](frame_support::migrations::VersionedMigration), which ensures that:
+/// - The migration only runs once when the on-chain storage version is 1
+/// - The on-chain storage version is updated to `2` after the migration executes
+/// - Reads/Writes from checking/settings the on-chain storage version are accounted for
+pub type MigrateV1ToV2<T, I> = VersionedMigration<
+    1, // The migration will only execute when the on-chain storage version is 1
+    2, // The on-chain storage version will be set to 2 after the migration is complete
+    InnerMigrateV1ToV2<T, I>,
+    crate::Pallet<T, I>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(any(all(feature = "try-runtime", test), doc))]
+mod test {
+    use self::InnerMigrateV1ToV2;
+    use super::*;
+    use crate::mock::*;
+    use crate::tests::*;
+    use frame_support::assert_ok;
+    use frame_support::weights::RuntimeDbWeight;
+    use sp_core::H256;
+
+    #[test]
+    fn successful_migration() {
+        test_ext().execute_with(|| {
+            // Populate `Tickets` storage map with some registrations
+            let num_entries = 5;
+            for i in 0..num_entries {
+                Tickets::<Test, FakeVerifier>::insert((USER_1, H256::from_low_u64_be(i)), None);
+            }
+            assert_eq!(
+                crate::Pallet::<Test, FakeVerifier>::registered_vks(&USER_1).len(),
+                0
+            );
+
+            // Check that `pre_upgrade` is successful
+            let bytes = InnerMigrateV1ToV2::<Test, FakeVerifier>::pre_upgrade()
+                .map_err(|e| format!("pre_upgrade failed: {:?}", e))
+                .unwrap();
+
+            // Perform runtime upgrade
+            let weight = InnerMigrateV1ToV2::<Test, FakeVerifier>::on_runtime_upgrade();
+
+            // Check that `post_upgrade` is successful
+            assert_ok!(InnerMigrateV1ToV2::<Test, FakeVerifier>::post_upgrade(
+                bytes
+            ));
+
+            // Check that weight are as expected
+            assert_eq!(
+                weight,
+                <<Test as frame_system::Config>::DbWeight as Get<RuntimeDbWeight>>::get()
+                    .reads_writes(num_entries, num_entries)
+            );
+
+            // Check that all the registrations are listed, without metadata
+            let registered = crate::Pallet::<Test, FakeVerifier>::registered_vks(&USER_1);
+            assert_eq!(registered.len() as u64, num_entries);
+            assert!(registered.iter().all(|r| r.metadata.is_none()));
+        })
+    }
+}
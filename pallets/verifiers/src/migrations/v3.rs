@@ -0,0 +1,322 @@
+#![cfg(not(doc))]
+
+use codec::Decode;
+#[cfg(feature = "try-runtime")]
+use codec::Encode;
+use frame_support::{
+    migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade, weights::Weight,
+};
+use hp_verifiers::Verifier;
+use sp_core::Get;
+use sp_std::vec::Vec;
+
+use crate::{Config, Registrations, Tickets, VkEntry, Vks};
+
+/// How a verifier translates its V2 stored vks, if its `Vk` format changed in V3.
+pub trait VkTranslation<V: Verifier> {
+    /// The `Vk` format stored in V2.
+    type OldVk: Decode;
+
+    /// Translate a V2 vk to the current `Vk` format.
+    fn translate(old: Self::OldVk) -> V::Vk;
+}
+
+/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V2 to V3.
+///
+/// In V3 the `Vk` format of some verifiers changed: their [`crate::Vks`] are translated with
+/// `X`, keeping their reference count.
+pub struct InnerMigrateV2ToV3<T, I, X>(core::marker::PhantomData<(T, I, X)>);
+
+impl<T: Config<I>, I: 'static, X: VkTranslation<I>> UncheckedOnRuntimeUpgrade
+    for InnerMigrateV2ToV3<T, I, X>
+where
+    I: Verifier,
+{
+    /// Migrate the storage from V2 to V3.
+    fn on_runtime_upgrade() -> Weight {
+        let mut n = 0_u64;
+        Vks::<T, I>::translate::<VkEntry<X::OldVk>, _>(|_, entry| {
+            n += 1;
+            Some(entry.map_vk(X::translate))
+        });
+        T::DbWeight::get().reads_writes(n, n)
+    }
+
+    /// Count the stored vks.
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        Ok((Vks::<T, I>::iter_keys().count() as u64).encode())
+    }
+
+    /// Verifies that all the stored vks have been translated.
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let before = u64::decode(&mut &state[..]).map_err(|_| "invalid pre_upgrade state")?;
+        frame_support::ensure!(
+            Vks::<T, I>::iter_values().count() as u64 == before,
+            "some vks were not translated"
+        );
+        Ok(())
+    }
+}
+
+/// Implements [`UncheckedOnRuntimeUpgrade`], migrating the state of this pallet from V2 to V3
+/// for the verifiers whose vk hash changes with their `Vk` format.
+///
+/// The [`crate::Vks`] are translated with `X`, keeping their reference count, and moved to the
+/// hash of the translated vk together with their [`crate::Tickets`] and
+/// [`crate::Registrations`]. The translated vks should not be registered yet: the storage
+/// items added in V3 (revocations and expiries) are empty, so they don't need to be moved.
+pub struct InnerRekeyV2ToV3<T, I, X>(core::marker::PhantomData<(T, I, X)>);
+
+impl<T: Config<I>, I: 'static, X: VkTranslation<I>> UncheckedOnRuntimeUpgrade
+    for InnerRekeyV2ToV3<T, I, X>
+where
+    I: Verifier,
+{
+    /// Migrate the storage from V2 to V3.
+    fn on_runtime_upgrade() -> Weight {
+        let mut n = 0_u64;
+        Vks::<T, I>::translate::<VkEntry<X::OldVk>, _>(|_, entry| {
+            n += 1;
+            Some(entry.map_vk(X::translate))
+        });
+        let moved = Vks::<T, I>::iter()
+            .map(|(old_hash, entry)| (old_hash, I::vk_hash(entry.vk())))
+            .filter(|(old_hash, hash)| old_hash != hash)
+            .collect::<Vec<_>>();
+        let (mut reads, mut writes) = (2 * n, n);
+        for (old_hash, hash) in moved.iter().copied() {
+            Vks::<T, I>::swap(old_hash, hash);
+            reads += 2;
+            writes += 2;
+        }
+        let tickets = Tickets::<T, I>::iter_keys().collect::<Vec<_>>();
+        let registrations = Registrations::<T, I>::iter_keys().collect::<Vec<_>>();
+        reads += (tickets.len() + registrations.len()) as u64;
+        for (account, old_hash) in tickets {
+            if let Some((_, hash)) = moved.iter().find(|(old, _)| *old == old_hash) {
+                Tickets::<T, I>::swap((&account, old_hash), (&account, hash));
+                reads += 2;
+                writes += 2;
+            }
+        }
+        for (account, old_hash) in registrations {
+            if let Some((_, hash)) = moved.iter().find(|(old, _)| *old == old_hash) {
+                Registrations::<T, I>::swap(&account, old_hash, &account, hash);
+                reads += 2;
+                writes += 2;
+            }
+        }
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    /// Count the stored vks, tickets and registrations.
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        Ok((
+            Vks::<T, I>::iter_keys().count() as u64,
+            Tickets::<T, I>::iter_keys().count() as u64,
+            Registrations::<T, I>::iter_keys().count() as u64,
+        )
+            .encode())
+    }
+
+    /// Verifies that all the stored vks have been translated and moved to their hash, with
+    /// their tickets and registrations.
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let (vks, tickets, registrations) =
+            <(u64, u64, u64)>::decode(&mut &state[..]).map_err(|_| "invalid pre_upgrade state")?;
+        frame_support::ensure!(
+            Vks::<T, I>::iter().count() as u64 == vks,
+            "some vks were not translated"
+        );
+        frame_support::ensure!(
+            Vks::<T, I>::iter().all(|(hash, entry)| I::vk_hash(entry.vk()) == hash),
+            "some vks were not moved to their hash"
+        );
+        frame_support::ensure!(
+            Tickets::<T, I>::iter_keys().count() as u64 == tickets
+                && Tickets::<T, I>::iter_keys().all(|(_, hash)| Vks::<T, I>::contains_key(hash)),
+            "some tickets were not moved"
+        );
+        frame_support::ensure!(
+            Registrations::<T, I>::iter_keys().count() as u64 == registrations
+                && Registrations::<T, I>::iter_keys()
+                    .all(|(_, hash)| Vks::<T, I>::contains_key(hash)),
+            "some registrations were not moved"
+        );
+        Ok(())
+    }
+}
+
+/// Implements [`UncheckedOnRuntimeUpgrade`] for the verifiers whose `Vk` format didn't change
+/// in V3: nothing to migrate.
+pub struct InnerUnchangedV2ToV3<T, I>(core::marker::PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for InnerUnchangedV2ToV3<T, I>
+where
+    I: Verifier,
+{
+    fn on_runtime_upgrade() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// [`UncheckedOnRuntimeUpgrade`] implementation [`InnerMigrateV2ToV3`] wrapped in a
+/// [`VersionedMigration`](frame_support::migrations::VersionedMigration), which ensures that:
+/// - The migration only runs once when the on-chain storage version is 2
+/// - The on-chain storage version is updated to `3` after the migration executes
+/// - Reads/Writes from checking/settings the on-chain storage version are accounted for
+pub type MigrateV2ToV3<T, I, X> = VersionedMigration<
+    2, // The migration will only execute when the on-chain storage version is 2
+    3, // The on-chain storage version will be set to 3 after the migration is complete
+    InnerMigrateV2ToV3<T, I, X>,
+    crate::Pallet<T, I>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// As [`MigrateV2ToV3`], for the verifiers whose vk hash changes with their `Vk` format: see
+/// [`InnerRekeyV2ToV3`].
+pub type RekeyV2ToV3<T, I, X> = VersionedMigration<
+    2,
+    3,
+    InnerRekeyV2ToV3<T, I, X>,
+    crate::Pallet<T, I>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// As [`MigrateV2ToV3`], for the verifiers whose `Vk` format didn't change: it just updates
+/// the on-chain storage version.
+pub type UnchangedV2ToV3<T, I> = VersionedMigration<
+    2,
+    3,
+    InnerUnchangedV2ToV3<T, I>,
+    crate::Pallet<T, I>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+#[cfg(any(all(feature = "try-runtime", test), doc))]
+mod test {
+    use self::InnerMigrateV2ToV3;
+    use super::*;
+    use crate::mock::*;
+    use crate::tests::*;
+    use frame_support::assert_ok;
+    use frame_support::weights::RuntimeDbWeight;
+    use sp_core::H256;
+
+    mod v2 {
+        use frame_support::{storage_alias, Identity};
+        use sp_core::H256;
+
+        use super::*;
+
+        /// V2 type for [`crate::Vks`], with a narrower vk.
+        #[storage_alias]
+        pub type Vks<T: Config<I>, I: 'static>
+        where
+            I: Verifier,
+        = StorageMap<crate::Pallet<T, I>, Identity, H256, VkEntry<u32>>;
+    }
+
+    struct Widen;
+
+    impl VkTranslation<FakeVerifier> for Widen {
+        type OldVk = u32;
+
+        fn translate(old: u32) -> u64 {
+            old.into()
+        }
+    }
+
+    #[test]
+    fn successful_migration() {
+        test_ext().execute_with(|| {
+            // Populate `Vks` storage map with some V2 entries
+            let num_entries = 5;
+            for i in 0..num_entries {
+                v2::Vks::<Test, FakeVerifier>::insert(
+                    H256::from_low_u64_be(i),
+                    VkEntry::new(i as u32),
+                );
+            }
+
+            // Check that `pre_upgrade` is successful
+            let bytes = InnerMigrateV2ToV3::<Test, FakeVerifier, Widen>::pre_upgrade()
+                .map_err(|e| format!("pre_upgrade failed: {:?}", e))
+                .unwrap();
+
+            // Perform runtime upgrade
+            let weight = InnerMigrateV2ToV3::<Test, FakeVerifier, Widen>::on_runtime_upgrade();
+
+            // Check that `post_upgrade` is successful
+            assert_ok!(InnerMigrateV2ToV3::<Test, FakeVerifier, Widen>::post_upgrade(bytes));
+
+            // Check that weight are as expected
+            assert_eq!(
+                weight,
+                <<Test as frame_system::Config>::DbWeight as Get<RuntimeDbWeight>>::get()
+                    .reads_writes(num_entries, num_entries)
+            );
+
+            // Check that all the vks have been translated
+            for i in 0..num_entries {
+                assert_eq!(
+                    Vks::<Test, FakeVerifier>::get(H256::from_low_u64_be(i)),
+                    Some(VkEntry::new(i))
+                );
+            }
+        })
+    }
+
+    #[test]
+    fn move_the_translated_vks_to_their_hash() {
+        test_ext().execute_with(|| {
+            // Populate the V2 storage with some vks, tickets and registrations
+            let num_entries = 5;
+            for i in 0..num_entries {
+                let old_hash = H256::from_low_u64_be(i);
+                v2::Vks::<Test, FakeVerifier>::insert(old_hash, VkEntry::new(i as u32));
+                Tickets::<Test, FakeVerifier>::insert((USER_1, old_hash), None);
+                Registrations::<Test, FakeVerifier>::insert(
+                    USER_1,
+                    old_hash,
+                    None::<crate::VkMetadata>,
+                );
+            }
+
+            // Check that `pre_upgrade` is successful
+            let bytes = InnerRekeyV2ToV3::<Test, FakeVerifier, Widen>::pre_upgrade()
+                .map_err(|e| format!("pre_upgrade failed: {:?}", e))
+                .unwrap();
+
+            // Perform runtime upgrade
+            InnerRekeyV2ToV3::<Test, FakeVerifier, Widen>::on_runtime_upgrade();
+
+            // Check that `post_upgrade` is successful
+            assert_ok!(InnerRekeyV2ToV3::<Test, FakeVerifier, Widen>::post_upgrade(
+                bytes
+            ));
+
+            // Check that the vks, the tickets and the registrations have been moved
+            for i in 0..num_entries {
+                let old_hash = H256::from_low_u64_be(i);
+                let hash = FakeVerifier::vk_hash(&i);
+                assert_eq!(Vks::<Test, FakeVerifier>::get(old_hash), None);
+                assert_eq!(Vks::<Test, FakeVerifier>::get(hash), Some(VkEntry::new(i)));
+                assert!(!Tickets::<Test, FakeVerifier>::contains_key((
+                    USER_1, old_hash
+                )));
+                assert!(Tickets::<Test, FakeVerifier>::contains_key((USER_1, hash)));
+                assert!(!Registrations::<Test, FakeVerifier>::contains_key(
+                    USER_1, old_hash
+                ));
+                assert!(Registrations::<Test, FakeVerifier>::contains_key(
+                    USER_1, hash
+                ));
+            }
+        })
+    }
+}
@@ -40,7 +40,8 @@ pub mod on_proof_verified {
         use frame_support::pallet_prelude::*;
         use sp_core::H256;
 
-        use hp_on_proof_verified::OnProofVerified;
+        use hp_on_proof_verified::{OnProofRejected, OnProofVerified, ProofContext};
+        use sp_runtime::DispatchError;
 
         #[pallet::pallet]
         pub struct Pallet<T>(_);
@@ -61,6 +62,12 @@ pub mod on_proof_verified {
                 domain_id: Option<u32>,
                 value: H256,
             },
+            RejectedProof {
+                account: Option<AccountOf<T>>,
+                domain_id: Option<u32>,
+                value: H256,
+                error: DispatchError,
+            },
         }
 
         impl<A, T: Config<AccountId = A>> OnProofVerified<A> for Pallet<T> {
@@ -73,6 +80,17 @@ pub mod on_proof_verified {
             }
         }
 
+        impl<A: Clone, T: Config<AccountId = A>> OnProofRejected<A> for Pallet<T> {
+            fn on_proof_rejected(ctx: &ProofContext<A>, error: &DispatchError) {
+                Self::deposit_event(Event::RejectedProof {
+                    account: ctx.account.clone(),
+                    domain_id: ctx.domain_id,
+                    value: ctx.statement,
+                    error: *error,
+                });
+            }
+        }
+
         pub fn new_proof_event<A, T: Config<AccountId = A>>(
             account: Option<A>,
             domain_id: Option<u32>,
@@ -180,6 +198,38 @@ impl crate::common::WeightInfo for MockCommonWeightInfo {
     fn on_verify_disabled_verifier() -> Weight {
         Weight::from_parts(1003, 1004)
     }
+
+    fn index_statement() -> Weight {
+        Weight::from_parts(1005, 1006)
+    }
+
+    fn prune_statement() -> Weight {
+        Weight::from_parts(1007, 1008)
+    }
+
+    fn revoke_vk() -> Weight {
+        Weight::from_parts(1009, 1010)
+    }
+
+    fn restore_vk() -> Weight {
+        Weight::from_parts(1011, 1012)
+    }
+
+    fn transfer_vk() -> Weight {
+        Weight::from_parts(1013, 1014)
+    }
+
+    fn renew_vk() -> Weight {
+        Weight::from_parts(1015, 1016)
+    }
+
+    fn expire_vk() -> Weight {
+        Weight::from_parts(1017, 1018)
+    }
+
+    fn accept_vk_transfer() -> Weight {
+        Weight::from_parts(1019, 1020)
+    }
 }
 
 // Configure a mock runtime to test the pallet.
@@ -207,11 +257,13 @@ parameter_types! {
     pub const BaseDeposit: Balance = 1;
     pub const PerByteDeposit: Balance = 2;
     pub const HoldReasonVkRegistration: RuntimeHoldReason = RuntimeHoldReason::CommonVerifiersPallet(crate::common::HoldReason::VkRegistration);
+    pub static StatementRetention: Option<u32> = None;
 }
 
 impl crate::Config<FakeVerifier> for Test {
     type RuntimeEvent = RuntimeEvent;
-    type OnProofVerified = OnProofVerifiedMock;
+    type OnProofVerified = crate::LegacyOnProofVerified<OnProofVerifiedMock>;
+    type OnProofRejected = OnProofVerifiedMock;
     type Ticket = HoldConsideration<
         AccountId,
         Balances,
@@ -219,6 +271,7 @@ impl crate::Config<FakeVerifier> for Test {
         LinearStoragePrice<BaseDeposit, PerByteDeposit, Balance>,
     >;
     type WeightInfo = MockWeightInfo;
+    type MaxBatchSize = ConstU32<4>;
     #[cfg(feature = "runtime-benchmarks")]
     type Currency = Balances;
 }
@@ -243,6 +296,7 @@ impl pallet_balances::Config for Test {
 
 impl crate::common::Config for Test {
     type CommonWeightInfo = MockCommonWeightInfo;
+    type StatementRetention = StatementRetention;
 }
 
 impl on_proof_verified::Config for Test {
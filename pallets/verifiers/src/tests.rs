@@ -66,6 +66,17 @@ pub mod registered_vk {
         "a65dc57cd8f1e436aaa8a8a473005040a4594f5411e0d9c7c5d7f20630217b79"
     ));
 
+    pub fn metadata() -> VkMetadata {
+        VkMetadata {
+            name: b"my circuit".to_vec().try_into().unwrap(),
+            circuit_uri: b"https://github.com/me/my-circuit"
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            version: b"1.0.0".to_vec().try_into().unwrap(),
+        }
+    }
+
     /// Provide an environment with a registered vk
     #[fixture]
     pub fn def_vk(mut test_ext: sp_io::TestExternalities) -> sp_io::TestExternalities {
@@ -126,7 +137,7 @@ mod register_should {
         test_ext.execute_with(|| {
             DisableStorage::set(Some(true));
             assert!(
-                FakeVerifierPallet::register_vk(RuntimeOrigin::signed(1), Box::new(42),).is_err(),
+                FakeVerifierPallet::register_vk(RuntimeOrigin::signed(1), Box::new(42)).is_err()
             );
         });
     }
@@ -136,7 +147,23 @@ mod register_should {
         let info = Call::<Test, FakeVerifier>::register_vk { vk: Box::new(42) }.get_dispatch_info();
 
         assert_eq!(info.pays_fee, Pays::Yes);
-        assert_eq!(info.weight, MockWeightInfo::register_vk(&43));
+        assert_eq!(
+            info.weight,
+            MockWeightInfo::register_vk(&43).saturating_add(MockCommonWeightInfo::expire_vk())
+        );
+
+        let info = Call::<Test, FakeVerifier>::register_vk_with_options {
+            vk: Box::new(42),
+            metadata: Some(metadata()),
+            expiry: Some(42),
+        }
+        .get_dispatch_info();
+
+        assert_eq!(info.pays_fee, Pays::Yes);
+        assert_eq!(
+            info.weight,
+            MockWeightInfo::register_vk(&43).saturating_add(MockCommonWeightInfo::expire_vk())
+        );
     }
 
     #[rstest]
@@ -155,6 +182,30 @@ mod register_should {
         })
     }
 
+    #[rstest]
+    fn attach_the_metadata_and_hold_a_deposit_for_it(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            let initial_reserved_balance = Balances::reserved_balance(USER_1);
+            let vk = 42;
+            assert_ok!(FakeVerifierPallet::register_vk_with_options(
+                RuntimeOrigin::signed(USER_1),
+                Box::new(vk),
+                Some(metadata()),
+                None
+            ));
+            assert_eq!(
+                FakeVerifierPallet::registrations(USER_1, FakeVerifier::vk_hash(&vk)),
+                Some(Some(metadata()))
+            );
+            assert_eq!(
+                Balances::reserved_balance(USER_1),
+                initial_reserved_balance
+                    + reserved_balance(&vk)
+                    + PerByteDeposit::get() * metadata().encoded_size() as Balance
+            );
+        })
+    }
+
     #[rstest]
     fn fail_if_insufficient_free_balance(mut test_ext: sp_io::TestExternalities) {
         test_ext.execute_with(|| {
@@ -524,6 +575,469 @@ mod submit_proof_should {
     }
 }
 
+mod submit_proofs_should {
+    use super::*;
+    use frame_support::{traits::Get, BoundedVec};
+    use on_proof_verified::new_proof_event;
+    use registered_vk::*;
+
+    type Submission = ProofSubmission<FakeVerifier>;
+    type MaxBatchSize = <Test as Config<FakeVerifier>>::MaxBatchSize;
+
+    fn batch(submissions: Vec<Submission>) -> BoundedVec<Submission, MaxBatchSize> {
+        submissions.try_into().unwrap()
+    }
+
+    fn valid(vk_or_hash: VkOrHash) -> Submission {
+        Submission::new(vk_or_hash, 42, 42, Some(666))
+    }
+
+    fn invalid() -> Submission {
+        Submission::new(VkOrHash::from_vk(REGISTERED_VK), 42, 24, Some(666))
+    }
+
+    #[rstest]
+    fn verify_all_proofs_and_notify_each_one(
+        mut def_vk: sp_io::TestExternalities,
+        #[values(BatchMode::AllOrNothing, BatchMode::BestEffort)] mode: BatchMode,
+    ) {
+        def_vk.execute_with(|| {
+            assert_ok!(FakeVerifierPallet::submit_proofs(
+                RuntimeOrigin::signed(USER_1),
+                batch(vec![
+                    valid(VkOrHash::from_vk(REGISTERED_VK)),
+                    valid(VkOrHash::from_hash(REGISTERED_VK_HASH)),
+                ]),
+                mode,
+            ));
+
+            let events = System::events()
+                .into_iter()
+                .map(|r| r.event)
+                .collect::<Vec<_>>();
+            let verified: RuntimeEvent = Event::<Test, FakeVerifier>::ProofVerified {
+                statement: VALID_HASH_REGISTERED_VK,
+            }
+            .into();
+            let notified: RuntimeEvent =
+                new_proof_event(Some(USER_1), Some(666), VALID_HASH_REGISTERED_VK).into();
+            assert_eq!(events.iter().filter(|e| **e == verified).count(), 2);
+            assert_eq!(events.iter().filter(|e| **e == notified).count(), 2);
+            System::assert_last_event(
+                Event::<Test, FakeVerifier>::BatchCompleted {
+                    verified: 2,
+                    rejected: 0,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[rstest]
+    fn revert_the_whole_batch_if_a_proof_fails_in_all_or_nothing_mode(
+        mut def_vk: sp_io::TestExternalities,
+    ) {
+        def_vk.execute_with(|| {
+            assert_noop!(
+                FakeVerifierPallet::submit_proofs(
+                    RuntimeOrigin::signed(USER_1),
+                    batch(vec![
+                        valid(VkOrHash::from_vk(REGISTERED_VK)),
+                        invalid(),
+                        valid(VkOrHash::from_hash(REGISTERED_VK_HASH)),
+                    ]),
+                    BatchMode::AllOrNothing,
+                ),
+                RError::VerifyError
+            );
+        });
+    }
+
+    #[rstest]
+    fn skip_and_report_failing_proofs_in_best_effort_mode(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_ok!(FakeVerifierPallet::submit_proofs(
+                RuntimeOrigin::signed(USER_1),
+                batch(vec![
+                    valid(VkOrHash::from_vk(REGISTERED_VK)),
+                    invalid(),
+                    Submission::new(VkOrHash::from_hash(H256::repeat_byte(1)), 42, 42, None),
+                    valid(VkOrHash::from_hash(REGISTERED_VK_HASH)),
+                ]),
+                BatchMode::BestEffort,
+            ));
+
+            System::assert_has_event(
+                Event::<Test, FakeVerifier>::ProofRejected {
+                    index: 1,
+                    error: RError::VerifyError.into(),
+                }
+                .into(),
+            );
+            System::assert_has_event(
+                Event::<Test, FakeVerifier>::ProofRejected {
+                    index: 2,
+                    error: RError::VerificationKeyNotFound.into(),
+                }
+                .into(),
+            );
+            let notified: RuntimeEvent =
+                new_proof_event(Some(USER_1), Some(666), VALID_HASH_REGISTERED_VK).into();
+            assert_eq!(
+                System::events()
+                    .into_iter()
+                    .filter(|r| r.event == notified)
+                    .count(),
+                2
+            );
+            System::assert_last_event(
+                Event::<Test, FakeVerifier>::BatchCompleted {
+                    verified: 2,
+                    rejected: 2,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[rstest]
+    fn notify_the_rejected_proofs_in_best_effort_mode(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_ok!(FakeVerifierPallet::submit_proofs(
+                RuntimeOrigin::signed(USER_1),
+                batch(vec![valid(VkOrHash::from_vk(REGISTERED_VK)), invalid()]),
+                BatchMode::BestEffort,
+            ));
+
+            System::assert_has_event(
+                on_proof_verified::Event::<Test>::RejectedProof {
+                    account: Some(USER_1),
+                    domain_id: Some(666),
+                    value: FakeVerifierPallet::statement_hash(
+                        &VkOrHash::from_vk(REGISTERED_VK),
+                        &24,
+                    ),
+                    error: RError::VerifyError.into(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[rstest]
+    fn reject_the_batch_if_disabled(
+        mut def_vk: sp_io::TestExternalities,
+        #[values(BatchMode::AllOrNothing, BatchMode::BestEffort)] mode: BatchMode,
+    ) {
+        def_vk.execute_with(|| {
+            DisableStorage::set(Some(true));
+            assert_err_ignore_postinfo!(
+                FakeVerifierPallet::submit_proofs(
+                    RuntimeOrigin::signed(USER_1),
+                    batch(vec![valid(VkOrHash::from_vk(REGISTERED_VK))]),
+                    mode,
+                ),
+                RError::DisabledVerifier
+            );
+        });
+    }
+
+    #[rstest]
+    fn revert_the_whole_batch_if_a_vk_is_not_registered_in_all_or_nothing_mode(
+        mut def_vk: sp_io::TestExternalities,
+    ) {
+        def_vk.execute_with(|| {
+            assert_noop!(
+                FakeVerifierPallet::submit_proofs(
+                    RuntimeOrigin::signed(USER_1),
+                    batch(vec![
+                        valid(VkOrHash::from_vk(REGISTERED_VK)),
+                        Submission::new(VkOrHash::from_hash(H256::repeat_byte(1)), 42, 42, None),
+                    ]),
+                    BatchMode::AllOrNothing,
+                ),
+                RError::VerificationKeyNotFound
+            );
+        });
+    }
+
+    #[rstest]
+    fn use_the_sum_of_the_configured_weights(
+        #[values(BatchMode::AllOrNothing, BatchMode::BestEffort)] mode: BatchMode,
+    ) {
+        let info = Call::<Test, FakeVerifier>::submit_proofs {
+            proofs: batch(vec![
+                valid(VkOrHash::from_vk(REGISTERED_VK)),
+                valid(VkOrHash::from_hash(REGISTERED_VK_HASH)),
+                valid(VkOrHash::from_hash(REGISTERED_VK_HASH)),
+            ]),
+            mode,
+        }
+        .get_dispatch_info();
+
+        assert_eq!(info.pays_fee, Pays::Yes);
+        assert_eq!(
+            info.weight,
+            MockWeightInfo::submit_proof(&42, &42)
+                + MockWeightInfo::submit_proof_with_vk_hash(&42, &42) * 2
+        );
+    }
+
+    #[test]
+    fn not_accept_more_than_max_batch_size_proofs() {
+        let proofs =
+            vec![valid(VkOrHash::from_vk(REGISTERED_VK)); MaxBatchSize::get() as usize + 1];
+
+        assert!(BoundedVec::<Submission, MaxBatchSize>::try_from(proofs).is_err());
+    }
+}
+
+mod dry_run_should {
+    use super::*;
+    use frame_support::weights::Weight;
+    use registered_vk::*;
+
+    #[rstest]
+    #[case::vk(VkOrHash::from_vk(REGISTERED_VK), MockWeightInfo::submit_proof(&42, &42))]
+    #[case::use_registered_vk(
+        VkOrHash::from_hash(REGISTERED_VK_HASH),
+        MockWeightInfo::submit_proof_with_vk_hash(&42, &42)
+    )]
+    fn return_the_statement_and_the_weight_of_a_valid_proof(
+        mut def_vk: sp_io::TestExternalities,
+        #[case] vk_or_hash: VkOrHash,
+        #[case] expected_weight: Weight,
+    ) {
+        def_vk.execute_with(|| {
+            let events = System::events().len();
+
+            assert_eq!(
+                FakeVerifierPallet::dry_run(&vk_or_hash, &42, &42),
+                DryRun {
+                    result: Ok(()),
+                    statement: VALID_HASH_REGISTERED_VK,
+                    weight: expected_weight,
+                }
+            );
+            assert_eq!(System::events().len(), events);
+        });
+    }
+
+    #[rstest]
+    #[case::invalid_proof(VkOrHash::from_vk(REGISTERED_VK), 24, DryRunError::VerifyError)]
+    #[case::invalid_vk(VkOrHash::from_vk(0), 42, DryRunError::InvalidVerificationKey)]
+    #[case::unregistered_vk(
+        VkOrHash::from_hash(H256::zero()),
+        42,
+        DryRunError::VerificationKeyNotFound
+    )]
+    fn return_the_verification_error(
+        mut def_vk: sp_io::TestExternalities,
+        #[case] vk_or_hash: VkOrHash,
+        #[case] pubs: u64,
+        #[case] expected: DryRunError,
+    ) {
+        def_vk.execute_with(|| {
+            assert_eq!(
+                FakeVerifierPallet::dry_run(&vk_or_hash, &42, &pubs).result,
+                Err(expected)
+            );
+        });
+    }
+
+    #[rstest]
+    fn return_disabled_verifier_error(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            FakeVerifierPallet::disable(RuntimeOrigin::root(), true).unwrap();
+
+            assert_eq!(
+                FakeVerifierPallet::dry_run(&VkOrHash::from_vk(REGISTERED_VK), &42, &42).result,
+                Err(DryRunError::DisabledVerifier)
+            );
+        });
+    }
+}
+
+mod statement_hash_should {
+    use super::*;
+    use registered_vk::*;
+
+    #[rstest]
+    #[case::vk(VkOrHash::from_vk(REGISTERED_VK))]
+    #[case::vk_hash(VkOrHash::from_hash(REGISTERED_VK_HASH))]
+    fn be_the_statement_of_the_verified_proof(#[case] vk_or_hash: VkOrHash) {
+        assert_eq!(
+            FakeVerifierPallet::statement_hash(&vk_or_hash, &42),
+            VALID_HASH_REGISTERED_VK
+        );
+    }
+}
+
+mod statement_index_should {
+    use super::*;
+    use crate::common::{StatementInfo, WeightInfo as _};
+    use frame_support::{
+        traits::{Get, Hooks, PalletInfoAccess},
+        weights::Weight,
+    };
+    use registered_vk::*;
+
+    const RETENTION: u32 = 10;
+
+    fn submit(pubs: u64) -> H256 {
+        assert_ok!(FakeVerifierPallet::submit_proof(
+            RuntimeOrigin::signed(USER_1),
+            VkOrHash::from_hash(REGISTERED_VK_HASH),
+            Box::new(pubs),
+            Box::new(pubs),
+            Some(3),
+        ));
+        FakeVerifierPallet::statement_hash(&VkOrHash::from_hash(REGISTERED_VK_HASH), &pubs)
+    }
+
+    fn on_idle(now: u32, remaining_weight: Weight) -> Weight {
+        <CommonVerifiersPallet as Hooks<u32>>::on_idle(now, remaining_weight)
+    }
+
+    #[rstest]
+    fn record_the_verified_statements(mut def_vk: sp_io::TestExternalities) {
+        StatementRetention::set(Some(RETENTION));
+        def_vk.execute_with(|| {
+            let statement = submit(42);
+
+            assert_eq!(
+                CommonVerifiersPallet::statement_info(statement),
+                Some(StatementInfo {
+                    block: 1,
+                    verifier: FakeVerifierPallet::index() as u8,
+                    vk_hash: REGISTERED_VK_HASH,
+                    account: Some(USER_1),
+                    domain_id: Some(3),
+                })
+            );
+        });
+    }
+
+    #[rstest]
+    fn not_record_anything_if_disabled(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            let statement = submit(42);
+
+            assert_eq!(CommonVerifiersPallet::statement_info(statement), None);
+        });
+    }
+
+    #[rstest]
+    fn not_record_rejected_proofs(mut def_vk: sp_io::TestExternalities) {
+        StatementRetention::set(Some(RETENTION));
+        def_vk.execute_with(|| {
+            let vk_or_hash = VkOrHash::from_hash(REGISTERED_VK_HASH);
+            assert!(FakeVerifierPallet::submit_proof(
+                RuntimeOrigin::signed(USER_1),
+                vk_or_hash.clone(),
+                Box::new(42),
+                Box::new(24),
+                None,
+            )
+            .is_err());
+
+            assert_eq!(
+                CommonVerifiersPallet::statement_info(FakeVerifierPallet::statement_hash(
+                    &vk_or_hash,
+                    &24
+                )),
+                None
+            );
+        });
+    }
+
+    #[rstest]
+    fn add_the_index_weight_to_submit_proof(
+        #[values(None, Some(RETENTION))] retention: Option<u32>,
+    ) {
+        StatementRetention::set(retention);
+        let info = Call::<Test, FakeVerifier>::submit_proof {
+            vk_or_hash: VkOrHash::from_vk(REGISTERED_VK),
+            proof: Box::new(42),
+            pubs: Box::new(42),
+            domain_id: None,
+        }
+        .get_dispatch_info();
+
+        let expected = match retention {
+            Some(_) => MockWeightInfo::submit_proof(&42, &42)
+                .saturating_add(MockCommonWeightInfo::index_statement()),
+            None => MockWeightInfo::submit_proof(&42, &42),
+        };
+        assert_eq!(info.weight, expected);
+    }
+
+    #[rstest]
+    fn prune_the_expired_statements(mut def_vk: sp_io::TestExternalities) {
+        StatementRetention::set(Some(RETENTION));
+        def_vk.execute_with(|| {
+            let old = submit(42);
+            System::set_block_number(5);
+            let new = submit(24);
+
+            on_idle(RETENTION, Weight::MAX);
+            assert!(CommonVerifiersPallet::statement_info(old).is_some());
+
+            on_idle(RETENTION + 1, Weight::MAX);
+            assert!(CommonVerifiersPallet::statement_info(old).is_none());
+            assert!(CommonVerifiersPallet::statement_info(new).is_some());
+
+            on_idle(RETENTION + 5, Weight::MAX);
+            assert!(CommonVerifiersPallet::statement_info(new).is_none());
+        });
+    }
+
+    #[rstest]
+    fn keep_the_last_verification_of_a_statement(mut def_vk: sp_io::TestExternalities) {
+        StatementRetention::set(Some(RETENTION));
+        def_vk.execute_with(|| {
+            let statement = submit(42);
+            System::set_block_number(5);
+            submit(42);
+
+            on_idle(RETENTION + 1, Weight::MAX);
+
+            assert_eq!(
+                CommonVerifiersPallet::statement_info(statement).map(|info| info.block),
+                Some(5)
+            );
+        });
+    }
+
+    #[rstest]
+    fn not_use_more_than_the_remaining_weight(mut def_vk: sp_io::TestExternalities) {
+        StatementRetention::set(Some(RETENTION));
+        def_vk.execute_with(|| {
+            let statements = [submit(42), submit(24)];
+            let db_weight = <Test as frame_system::Config>::DbWeight::get();
+            let remaining_weight = db_weight
+                .reads_writes(1, 1)
+                .saturating_add(MockCommonWeightInfo::prune_statement());
+
+            let used = on_idle(RETENTION + 1, remaining_weight);
+
+            assert!(used.all_lte(remaining_weight));
+            assert_eq!(
+                statements
+                    .iter()
+                    .filter(|s| CommonVerifiersPallet::statement_info(**s).is_some())
+                    .count(),
+                1
+            );
+
+            on_idle(RETENTION + 2, Weight::MAX);
+            assert!(statements
+                .iter()
+                .all(|s| CommonVerifiersPallet::statement_info(*s).is_none()));
+        });
+    }
+}
+
 #[cfg(test)]
 mod disable_should {
     use common::WeightInfo;
@@ -571,7 +1085,7 @@ mod disable_should {
             FakeVerifierPallet::disable(RuntimeOrigin::root(), true).unwrap();
 
             assert_err_ignore_postinfo!(
-                FakeVerifierPallet::register_vk(RuntimeOrigin::signed(1), 42.into(),),
+                FakeVerifierPallet::register_vk(RuntimeOrigin::signed(1), 42.into()),
                 RError::DisabledVerifier
             );
         });
@@ -597,7 +1111,7 @@ mod disable_should {
                 on_disable_error::<Test, FakeVerifier>(),
             );
             assert_err!(
-                FakeVerifierPallet::register_vk(RuntimeOrigin::signed(1), 42.into(),),
+                FakeVerifierPallet::register_vk(RuntimeOrigin::signed(1), 42.into()),
                 on_disable_error::<Test, FakeVerifier>(),
             );
             assert_eq!(
@@ -625,7 +1139,7 @@ mod disable_should {
             ));
             assert_ok!(FakeVerifierPallet::register_vk(
                 RuntimeOrigin::signed(USER_1),
-                42.into(),
+                42.into()
             ));
         });
     }
@@ -644,6 +1158,986 @@ mod disable_should {
     }
 }
 
+mod revoke_vk_should {
+    use super::*;
+    use registered_vk::*;
+
+    fn reason() -> RevokeReason {
+        RevokeReason::truncate_from(b"underconstrained circuit".to_vec())
+    }
+
+    fn revoke(vk_hash: H256) {
+        FakeVerifierPallet::revoke_vk(RuntimeOrigin::root(), vk_hash, reason()).unwrap();
+    }
+
+    fn submit(vk_or_hash: VkOrHash) -> DispatchResult {
+        FakeVerifierPallet::submit_proof(
+            RuntimeOrigin::signed(USER_1),
+            vk_or_hash,
+            Box::new(42),
+            Box::new(42),
+            None,
+        )
+        .map(|_| ())
+        .map_err(|e| e.error)
+    }
+
+    #[rstest]
+    fn emit_vk_revoked_event(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            revoke(REGISTERED_VK_HASH);
+
+            assert_eq!(
+                FakeVerifierPallet::revoked_vks(REGISTERED_VK_HASH),
+                Some(reason())
+            );
+            System::assert_last_event(
+                Event::VkRevoked {
+                    hash: REGISTERED_VK_HASH,
+                    reason: reason(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[rstest]
+    #[case::vk(VkOrHash::from_vk(REGISTERED_VK))]
+    #[case::vk_hash(VkOrHash::from_hash(REGISTERED_VK_HASH))]
+    fn reject_proofs(mut def_vk: sp_io::TestExternalities, #[case] vk_or_hash: VkOrHash) {
+        def_vk.execute_with(|| {
+            revoke(REGISTERED_VK_HASH);
+
+            assert_noop!(submit(vk_or_hash.clone()), RError::RevokedVerificationKey);
+            assert_eq!(
+                FakeVerifierPallet::dry_run(&vk_or_hash, &42, &42).result,
+                Err(DryRunError::RevokedVerificationKey)
+            );
+        });
+    }
+
+    #[rstest]
+    fn reject_proofs_in_a_batch(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            revoke(REGISTERED_VK_HASH);
+
+            assert_err_ignore_postinfo!(
+                FakeVerifierPallet::submit_proofs(
+                    RuntimeOrigin::signed(USER_1),
+                    vec![ProofSubmission::new(
+                        VkOrHash::from_vk(REGISTERED_VK),
+                        42,
+                        42,
+                        None
+                    )]
+                    .try_into()
+                    .unwrap(),
+                    BatchMode::AllOrNothing,
+                ),
+                RError::RevokedVerificationKey
+            );
+        });
+    }
+
+    #[rstest]
+    fn keep_accepting_proofs_with_other_vks(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            revoke(FakeVerifier::vk_hash(&24));
+
+            assert_ok!(submit(VkOrHash::from_hash(REGISTERED_VK_HASH)));
+            assert_ok!(submit(VkOrHash::from_vk(REGISTERED_VK)));
+        });
+    }
+
+    #[rstest]
+    fn reject_registration(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            revoke(REGISTERED_VK_HASH);
+
+            assert_noop!(
+                FakeVerifierPallet::register_vk(
+                    RuntimeOrigin::signed(USER_2),
+                    Box::new(REGISTERED_VK)
+                ),
+                RError::RevokedVerificationKey
+            );
+        });
+    }
+
+    #[rstest]
+    fn let_the_owners_unregister_and_release_the_deposit(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            revoke(REGISTERED_VK_HASH);
+            let initial_reserved_balance = Balances::reserved_balance(USER_1);
+
+            assert_ok!(FakeVerifierPallet::unregister_vk(
+                RuntimeOrigin::signed(USER_1),
+                REGISTERED_VK_HASH
+            ));
+            assert_eq!(
+                Balances::reserved_balance(USER_1),
+                initial_reserved_balance - reserved_balance(&REGISTERED_VK)
+            );
+            assert!(FakeVerifierPallet::vks(REGISTERED_VK_HASH).is_none());
+            assert!(FakeVerifierPallet::revoked_vks(REGISTERED_VK_HASH).is_some());
+        });
+    }
+
+    #[rstest]
+    fn be_undone_by_restore_vk(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            revoke(REGISTERED_VK_HASH);
+
+            assert_ok!(FakeVerifierPallet::restore_vk(
+                RuntimeOrigin::root(),
+                REGISTERED_VK_HASH
+            ));
+            System::assert_last_event(
+                Event::VkRestored {
+                    hash: REGISTERED_VK_HASH,
+                }
+                .into(),
+            );
+            assert_ok!(submit(VkOrHash::from_hash(REGISTERED_VK_HASH)));
+            assert_ok!(FakeVerifierPallet::register_vk(
+                RuntimeOrigin::signed(USER_2),
+                Box::new(REGISTERED_VK)
+            ));
+        });
+    }
+
+    #[test]
+    fn use_the_configured_weights() {
+        use common::WeightInfo;
+
+        let revoke = Call::<Test, FakeVerifier>::revoke_vk {
+            vk_hash: REGISTERED_VK_HASH,
+            reason: reason(),
+        }
+        .get_dispatch_info();
+        let restore = Call::<Test, FakeVerifier>::restore_vk {
+            vk_hash: REGISTERED_VK_HASH,
+        }
+        .get_dispatch_info();
+
+        assert_eq!(revoke.weight, MockCommonWeightInfo::revoke_vk());
+        assert_eq!(restore.weight, MockCommonWeightInfo::restore_vk());
+    }
+
+    mod fail {
+        use super::*;
+
+        #[rstest]
+        fn if_no_root(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_noop!(
+                    FakeVerifierPallet::revoke_vk(
+                        RuntimeOrigin::signed(USER_1),
+                        REGISTERED_VK_HASH,
+                        reason()
+                    ),
+                    DispatchError::BadOrigin
+                );
+                revoke(REGISTERED_VK_HASH);
+                assert_noop!(
+                    FakeVerifierPallet::restore_vk(
+                        RuntimeOrigin::signed(USER_1),
+                        REGISTERED_VK_HASH
+                    ),
+                    DispatchError::BadOrigin
+                );
+            });
+        }
+
+        #[rstest]
+        fn if_already_revoked(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                revoke(REGISTERED_VK_HASH);
+
+                assert_noop!(
+                    FakeVerifierPallet::revoke_vk(
+                        RuntimeOrigin::root(),
+                        REGISTERED_VK_HASH,
+                        reason()
+                    ),
+                    RError::VerificationKeyAlreadyRevoked
+                );
+            });
+        }
+
+        #[rstest]
+        fn to_restore_a_not_revoked_vk(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_noop!(
+                    FakeVerifierPallet::restore_vk(RuntimeOrigin::root(), REGISTERED_VK_HASH),
+                    RError::VerificationKeyNotRevoked
+                );
+            });
+        }
+    }
+}
+
+mod transfer_vk_should {
+    use super::*;
+    use registered_vk::*;
+
+    fn propose(from: AccountId, to: AccountId) -> DispatchResult {
+        FakeVerifierPallet::transfer_vk(RuntimeOrigin::signed(from), REGISTERED_VK_HASH, to)
+    }
+
+    fn accept(from: AccountId, to: AccountId) -> DispatchResult {
+        FakeVerifierPallet::accept_vk_transfer(RuntimeOrigin::signed(to), REGISTERED_VK_HASH, from)
+    }
+
+    fn transfer(from: AccountId, to: AccountId) -> DispatchResult {
+        propose(from, to)?;
+        accept(from, to)
+    }
+
+    #[rstest]
+    fn move_the_registration_and_its_metadata(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            FakeVerifierPallet::register_vk_with_options(
+                RuntimeOrigin::signed(USER_1),
+                Box::new(REGISTERED_VK),
+                Some(metadata()),
+                None,
+            )
+            .unwrap();
+
+            assert_ok!(transfer(USER_1, USER_2));
+
+            assert!(FakeVerifierPallet::deposits((USER_1, REGISTERED_VK_HASH)).is_none());
+            assert!(FakeVerifierPallet::deposits((USER_2, REGISTERED_VK_HASH)).is_some());
+            assert_eq!(FakeVerifierPallet::registered_vks(&USER_1), vec![]);
+            assert_eq!(
+                FakeVerifierPallet::registered_vks(&USER_2),
+                vec![RegisteredVk {
+                    hash: REGISTERED_VK_HASH,
+                    metadata: Some(metadata()),
+                    expiry: None,
+                }]
+            );
+            assert_eq!(
+                FakeVerifierPallet::pending_transfers(USER_1, REGISTERED_VK_HASH),
+                None
+            );
+        })
+    }
+
+    #[rstest]
+    fn move_the_deposit(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            let reserved_1 = Balances::reserved_balance(USER_1);
+            let reserved_2 = Balances::reserved_balance(USER_2);
+
+            assert_ok!(transfer(USER_1, USER_2));
+
+            assert_eq!(
+                Balances::reserved_balance(USER_1),
+                reserved_1 - reserved_balance(&REGISTERED_VK)
+            );
+            assert_eq!(
+                Balances::reserved_balance(USER_2),
+                reserved_2 + reserved_balance(&REGISTERED_VK)
+            );
+        })
+    }
+
+    #[rstest]
+    fn not_move_anything_till_the_new_owner_accepts(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            let reserved_1 = Balances::reserved_balance(USER_1);
+            let reserved_2 = Balances::reserved_balance(USER_2);
+
+            assert_ok!(propose(USER_1, USER_2));
+
+            assert_eq!(
+                FakeVerifierPallet::pending_transfers(USER_1, REGISTERED_VK_HASH),
+                Some(USER_2)
+            );
+            assert!(FakeVerifierPallet::deposits((USER_1, REGISTERED_VK_HASH)).is_some());
+            assert!(FakeVerifierPallet::deposits((USER_2, REGISTERED_VK_HASH)).is_none());
+            assert_eq!(Balances::reserved_balance(USER_1), reserved_1);
+            assert_eq!(Balances::reserved_balance(USER_2), reserved_2);
+        })
+    }
+
+    #[rstest]
+    fn replace_the_pending_transfer(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_ok!(propose(USER_1, USER_2));
+            assert_ok!(propose(USER_1, 1));
+
+            assert_noop!(accept(USER_1, USER_2), RError::TransferNotFound);
+        })
+    }
+
+    #[rstest]
+    fn keep_the_vk_registered(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_ok!(transfer(USER_1, USER_2));
+
+            assert!(FakeVerifierPallet::vks(REGISTERED_VK_HASH).is_some());
+            assert_ok!(FakeVerifierPallet::submit_proof(
+                RuntimeOrigin::signed(USER_1),
+                VkOrHash::from_hash(REGISTERED_VK_HASH),
+                Box::new(42),
+                Box::new(42),
+                None,
+            ));
+        })
+    }
+
+    #[rstest]
+    fn emit_vk_transfer_proposed_event(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_ok!(propose(USER_1, USER_2));
+
+            System::assert_last_event(
+                Event::VkTransferProposed {
+                    hash: REGISTERED_VK_HASH,
+                    from: USER_1,
+                    to: USER_2,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[rstest]
+    fn emit_vk_transferred_event(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_ok!(transfer(USER_1, USER_2));
+
+            System::assert_last_event(
+                Event::VkTransferred {
+                    hash: REGISTERED_VK_HASH,
+                    from: USER_1,
+                    to: USER_2,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[rstest]
+    fn let_just_the_new_owner_unregister_it(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            assert_ok!(transfer(USER_1, USER_2));
+
+            assert_noop!(
+                FakeVerifierPallet::unregister_vk(
+                    RuntimeOrigin::signed(USER_1),
+                    REGISTERED_VK_HASH
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(FakeVerifierPallet::unregister_vk(
+                RuntimeOrigin::signed(USER_2),
+                REGISTERED_VK_HASH
+            ));
+            assert!(FakeVerifierPallet::vks(REGISTERED_VK_HASH).is_none());
+        })
+    }
+
+    #[test]
+    fn use_the_configured_weights() {
+        let info = Call::<Test, FakeVerifier>::transfer_vk {
+            vk_hash: REGISTERED_VK_HASH,
+            new_owner: USER_2,
+        }
+        .get_dispatch_info();
+
+        assert_eq!(info.pays_fee, Pays::Yes);
+        assert_eq!(info.weight, MockCommonWeightInfo::transfer_vk());
+
+        let info = Call::<Test, FakeVerifier>::accept_vk_transfer {
+            vk_hash: REGISTERED_VK_HASH,
+            from: USER_1,
+        }
+        .get_dispatch_info();
+
+        assert_eq!(info.pays_fee, Pays::Yes);
+        assert_eq!(
+            info.weight,
+            MockCommonWeightInfo::accept_vk_transfer()
+                .saturating_add(MockCommonWeightInfo::expire_vk())
+        );
+    }
+
+    mod fail {
+        use super::*;
+
+        #[rstest]
+        fn on_root_origin(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_noop!(
+                    FakeVerifierPallet::transfer_vk(
+                        RuntimeOrigin::root(),
+                        REGISTERED_VK_HASH,
+                        USER_2
+                    ),
+                    DispatchError::BadOrigin
+                );
+                assert_ok!(propose(USER_1, USER_2));
+                assert_noop!(
+                    FakeVerifierPallet::accept_vk_transfer(
+                        RuntimeOrigin::root(),
+                        REGISTERED_VK_HASH,
+                        USER_1
+                    ),
+                    DispatchError::BadOrigin
+                );
+            })
+        }
+
+        #[rstest]
+        fn if_vk_exists_but_caller_did_not_register_it(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_noop!(propose(USER_2, USER_1), DispatchError::BadOrigin);
+            })
+        }
+
+        #[rstest]
+        fn on_nonexistent_vk(mut test_ext: sp_io::TestExternalities) {
+            test_ext.execute_with(|| {
+                assert_noop!(propose(USER_1, USER_2), RError::VerificationKeyNotFound);
+            })
+        }
+
+        #[rstest]
+        fn if_the_transfer_was_not_proposed(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_noop!(accept(USER_1, USER_2), RError::TransferNotFound);
+            })
+        }
+
+        #[rstest]
+        fn if_the_transfer_was_proposed_to_somebody_else(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_ok!(propose(USER_1, USER_2));
+
+                assert_noop!(accept(USER_1, 1), RError::TransferNotFound);
+            })
+        }
+
+        #[rstest]
+        fn if_the_registration_was_removed(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_ok!(propose(USER_1, USER_2));
+                assert_ok!(FakeVerifierPallet::unregister_vk(
+                    RuntimeOrigin::signed(USER_1),
+                    REGISTERED_VK_HASH
+                ));
+                FakeVerifierPallet::register_vk(
+                    RuntimeOrigin::signed(USER_1),
+                    Box::new(REGISTERED_VK),
+                )
+                .unwrap();
+
+                assert_noop!(accept(USER_1, USER_2), RError::TransferNotFound);
+            })
+        }
+
+        #[rstest]
+        fn if_the_new_owner_already_registered_it(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                FakeVerifierPallet::register_vk(
+                    RuntimeOrigin::signed(USER_2),
+                    Box::new(REGISTERED_VK),
+                )
+                .unwrap();
+                assert_ok!(propose(USER_1, USER_2));
+
+                assert_noop!(
+                    accept(USER_1, USER_2),
+                    RError::VerificationKeyAlreadyRegistered
+                );
+                assert_noop!(
+                    propose(USER_1, USER_1),
+                    RError::VerificationKeyAlreadyRegistered
+                );
+            })
+        }
+
+        #[rstest]
+        fn if_the_new_owner_cannot_afford_the_deposit(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_ok!(propose(USER_1, 1));
+
+                assert_noop!(
+                    accept(USER_1, 1),
+                    DispatchError::Token(sp_runtime::TokenError::FundsUnavailable)
+                );
+            })
+        }
+    }
+}
+
+mod registered_vks_should {
+    use super::*;
+    use registered_vk::*;
+
+    #[rstest]
+    fn list_the_vks_registered_by_the_account(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            FakeVerifierPallet::register_vk_with_options(
+                RuntimeOrigin::signed(USER_1),
+                Box::new(24),
+                Some(metadata()),
+                None,
+            )
+            .unwrap();
+            FakeVerifierPallet::register_vk(RuntimeOrigin::signed(USER_2), Box::new(42)).unwrap();
+
+            let mut registered = FakeVerifierPallet::registered_vks(&USER_1);
+            registered.sort_by_key(|r| r.hash);
+            let mut expected = vec![
+                RegisteredVk {
+                    hash: REGISTERED_VK_HASH,
+                    metadata: None,
+                    expiry: None,
+                },
+                RegisteredVk {
+                    hash: FakeVerifier::vk_hash(&24),
+                    metadata: Some(metadata()),
+                    expiry: None,
+                },
+            ];
+            expected.sort_by_key(|r| r.hash);
+            assert_eq!(registered, expected);
+        })
+    }
+
+    #[rstest]
+    fn not_list_the_unregistered_vks(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            FakeVerifierPallet::unregister_vk(RuntimeOrigin::signed(USER_1), REGISTERED_VK_HASH)
+                .unwrap();
+
+            assert_eq!(FakeVerifierPallet::registered_vks(&USER_1), vec![]);
+        })
+    }
+}
+
+mod expiry_should {
+    use super::*;
+    use crate::common::WeightInfo as _;
+    use frame_support::{
+        traits::{Get, Hooks},
+        weights::Weight,
+    };
+    use registered_vk::*;
+
+    const EXPIRY: u32 = 10;
+
+    fn register(account: AccountId, vk: Vk, expiry: Option<u32>) {
+        FakeVerifierPallet::register_vk_with_options(
+            RuntimeOrigin::signed(account),
+            Box::new(vk),
+            None,
+            expiry,
+        )
+        .unwrap();
+    }
+
+    fn renew(account: AccountId, expiry: Option<u32>) -> DispatchResult {
+        FakeVerifierPallet::renew_vk(RuntimeOrigin::signed(account), REGISTERED_VK_HASH, expiry)
+    }
+
+    fn on_idle(now: u32, remaining_weight: Weight) -> Weight {
+        <FakeVerifierPallet as Hooks<u32>>::on_idle(now, remaining_weight)
+    }
+
+    fn is_registered(account: AccountId, vk_hash: H256) -> bool {
+        FakeVerifierPallet::deposits((account, vk_hash)).is_some()
+    }
+
+    fn has_event(event: Event<Test, FakeVerifier>) -> bool {
+        let event: RuntimeEvent = event.into();
+        System::events().iter().any(|record| record.event == event)
+    }
+
+    #[rstest]
+    fn remove_the_registration_at_the_expiry_block(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            register(USER_1, REGISTERED_VK, Some(EXPIRY));
+
+            on_idle(EXPIRY - 1, Weight::MAX);
+            assert!(is_registered(USER_1, REGISTERED_VK_HASH));
+
+            on_idle(EXPIRY, Weight::MAX);
+            assert!(!is_registered(USER_1, REGISTERED_VK_HASH));
+            assert!(FakeVerifierPallet::vks(REGISTERED_VK_HASH).is_none());
+            assert_eq!(FakeVerifierPallet::registered_vks(&USER_1), vec![]);
+        });
+    }
+
+    #[rstest]
+    fn release_the_deposit(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            let initial_reserved_balance = Balances::reserved_balance(USER_1);
+            register(USER_1, REGISTERED_VK, Some(EXPIRY));
+
+            on_idle(EXPIRY, Weight::MAX);
+
+            assert_eq!(Balances::reserved_balance(USER_1), initial_reserved_balance);
+        });
+    }
+
+    #[rstest]
+    fn emit_vk_expired_event(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            register(USER_1, REGISTERED_VK, Some(EXPIRY));
+            System::reset_events();
+
+            on_idle(EXPIRY, Weight::MAX);
+
+            assert!(has_event(Event::VkExpired {
+                hash: REGISTERED_VK_HASH,
+                account: USER_1,
+            }));
+            System::assert_last_event(
+                Event::VkUnregistered {
+                    hash: REGISTERED_VK_HASH,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[rstest]
+    fn keep_the_vk_if_another_account_registered_it(mut def_vk: sp_io::TestExternalities) {
+        def_vk.execute_with(|| {
+            register(USER_2, REGISTERED_VK, Some(EXPIRY));
+
+            on_idle(EXPIRY, Weight::MAX);
+
+            assert!(!is_registered(USER_2, REGISTERED_VK_HASH));
+            assert!(is_registered(USER_1, REGISTERED_VK_HASH));
+            assert!(FakeVerifierPallet::vks(REGISTERED_VK_HASH).is_some());
+            assert!(has_event(Event::VkExpired {
+                hash: REGISTERED_VK_HASH,
+                account: USER_2,
+            }));
+            assert!(!has_event(Event::VkUnregistered {
+                hash: REGISTERED_VK_HASH,
+            }));
+        });
+    }
+
+    #[rstest]
+    fn be_postponed_by_renew_vk(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            register(USER_1, REGISTERED_VK, Some(EXPIRY));
+
+            assert_ok!(renew(USER_1, Some(2 * EXPIRY)));
+            System::assert_last_event(
+                Event::VkRenewed {
+                    hash: REGISTERED_VK_HASH,
+                    expiry: Some(2 * EXPIRY),
+                }
+                .into(),
+            );
+
+            on_idle(EXPIRY, Weight::MAX);
+            assert!(is_registered(USER_1, REGISTERED_VK_HASH));
+
+            on_idle(2 * EXPIRY, Weight::MAX);
+            assert!(!is_registered(USER_1, REGISTERED_VK_HASH));
+        });
+    }
+
+    #[rstest]
+    fn be_removed_by_renew_vk(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            register(USER_1, REGISTERED_VK, Some(EXPIRY));
+
+            assert_ok!(renew(USER_1, None));
+
+            on_idle(2 * EXPIRY, Weight::MAX);
+            assert!(is_registered(USER_1, REGISTERED_VK_HASH));
+            assert_eq!(
+                FakeVerifierPallet::registration_expiry(USER_1, REGISTERED_VK_HASH),
+                None
+            );
+        });
+    }
+
+    #[rstest]
+    fn not_apply_to_a_new_registration_after_unregistering(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            register(USER_1, REGISTERED_VK, Some(EXPIRY));
+            FakeVerifierPallet::unregister_vk(RuntimeOrigin::signed(USER_1), REGISTERED_VK_HASH)
+                .unwrap();
+            register(USER_1, REGISTERED_VK, None);
+
+            on_idle(EXPIRY, Weight::MAX);
+
+            assert!(is_registered(USER_1, REGISTERED_VK_HASH));
+        });
+    }
+
+    #[rstest]
+    fn follow_the_transferred_registration(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            register(USER_1, REGISTERED_VK, Some(EXPIRY));
+            FakeVerifierPallet::transfer_vk(
+                RuntimeOrigin::signed(USER_1),
+                REGISTERED_VK_HASH,
+                USER_2,
+            )
+            .unwrap();
+            FakeVerifierPallet::accept_vk_transfer(
+                RuntimeOrigin::signed(USER_2),
+                REGISTERED_VK_HASH,
+                USER_1,
+            )
+            .unwrap();
+            assert_eq!(
+                FakeVerifierPallet::registration_expiry(USER_2, REGISTERED_VK_HASH),
+                Some(EXPIRY)
+            );
+
+            on_idle(EXPIRY, Weight::MAX);
+
+            assert!(!is_registered(USER_2, REGISTERED_VK_HASH));
+            assert!(has_event(Event::VkExpired {
+                hash: REGISTERED_VK_HASH,
+                account: USER_2,
+            }));
+        });
+    }
+
+    #[rstest]
+    fn be_listed_with_the_registered_vks(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            register(USER_1, REGISTERED_VK, Some(EXPIRY));
+
+            assert_eq!(
+                FakeVerifierPallet::registered_vks(&USER_1),
+                vec![RegisteredVk {
+                    hash: REGISTERED_VK_HASH,
+                    metadata: None,
+                    expiry: Some(EXPIRY),
+                }]
+            );
+        });
+    }
+
+    #[rstest]
+    fn not_use_more_than_the_remaining_weight(mut test_ext: sp_io::TestExternalities) {
+        test_ext.execute_with(|| {
+            let vks = [42, 24];
+            for vk in vks {
+                register(USER_1, vk, Some(EXPIRY));
+            }
+            let registered = || {
+                vks.iter()
+                    .filter(|vk| is_registered(USER_1, FakeVerifier::vk_hash(vk)))
+                    .count()
+            };
+            let db_weight = <Test as frame_system::Config>::DbWeight::get();
+            let remaining_weight = db_weight
+                .reads_writes(1, 1)
+                .saturating_add(MockCommonWeightInfo::expire_vk());
+
+            let used = on_idle(EXPIRY, remaining_weight);
+
+            assert!(used.all_lte(remaining_weight));
+            assert_eq!(registered(), 1);
+
+            on_idle(EXPIRY + 1, Weight::MAX);
+            assert_eq!(registered(), 0);
+        });
+    }
+
+    #[rstest]
+    fn be_replaced_by_a_new_registration_before_its_removal(
+        mut test_ext: sp_io::TestExternalities,
+    ) {
+        test_ext.execute_with(|| {
+            register(USER_1, REGISTERED_VK, Some(EXPIRY));
+            System::set_block_number(EXPIRY);
+
+            let info = FakeVerifierPallet::register_vk(
+                RuntimeOrigin::signed(USER_1),
+                Box::new(REGISTERED_VK),
+            )
+            .unwrap();
+
+            assert_eq!(info.actual_weight, None);
+            assert!(has_event(Event::VkExpired {
+                hash: REGISTERED_VK_HASH,
+                account: USER_1,
+            }));
+            on_idle(EXPIRY, Weight::MAX);
+            assert!(is_registered(USER_1, REGISTERED_VK_HASH));
+            assert_eq!(
+                FakeVerifierPallet::registration_expiry(USER_1, REGISTERED_VK_HASH),
+                None
+            );
+        });
+    }
+
+    #[rstest]
+    fn not_charge_its_removal_to_a_new_registration_if_there_is_none(
+        mut test_ext: sp_io::TestExternalities,
+    ) {
+        test_ext.execute_with(|| {
+            let info = FakeVerifierPallet::register_vk(
+                RuntimeOrigin::signed(USER_1),
+                Box::new(REGISTERED_VK),
+            )
+            .unwrap();
+
+            assert_eq!(
+                info.actual_weight,
+                Some(MockWeightInfo::register_vk(&REGISTERED_VK))
+            );
+        });
+    }
+
+    #[rstest]
+    fn be_replaced_by_a_transferred_registration_before_its_removal(
+        mut def_vk: sp_io::TestExternalities,
+    ) {
+        def_vk.execute_with(|| {
+            register(USER_2, REGISTERED_VK, Some(EXPIRY));
+            assert_ok!(FakeVerifierPallet::transfer_vk(
+                RuntimeOrigin::signed(USER_1),
+                REGISTERED_VK_HASH,
+                USER_2,
+            ));
+            System::set_block_number(EXPIRY);
+            let initial_reserved_balance = Balances::reserved_balance(USER_2);
+
+            assert_ok!(FakeVerifierPallet::accept_vk_transfer(
+                RuntimeOrigin::signed(USER_2),
+                REGISTERED_VK_HASH,
+                USER_1,
+            ));
+
+            assert!(has_event(Event::VkExpired {
+                hash: REGISTERED_VK_HASH,
+                account: USER_2,
+            }));
+            assert!(!is_registered(USER_1, REGISTERED_VK_HASH));
+            assert!(is_registered(USER_2, REGISTERED_VK_HASH));
+            assert_eq!(
+                FakeVerifierPallet::registration_expiry(USER_2, REGISTERED_VK_HASH),
+                None
+            );
+            assert_eq!(Balances::reserved_balance(USER_2), initial_reserved_balance);
+        });
+    }
+
+    #[test]
+    fn use_the_configured_weights() {
+        let info = Call::<Test, FakeVerifier>::renew_vk {
+            vk_hash: REGISTERED_VK_HASH,
+            expiry: None,
+        }
+        .get_dispatch_info();
+
+        assert_eq!(info.pays_fee, Pays::Yes);
+        assert_eq!(info.weight, MockCommonWeightInfo::renew_vk());
+    }
+
+    mod fail {
+        use super::*;
+
+        #[rstest]
+        fn to_register_if_expiry_is_not_in_the_future(mut test_ext: sp_io::TestExternalities) {
+            test_ext.execute_with(|| {
+                assert_noop!(
+                    FakeVerifierPallet::register_vk_with_options(
+                        RuntimeOrigin::signed(USER_1),
+                        Box::new(REGISTERED_VK),
+                        None,
+                        Some(System::block_number())
+                    ),
+                    RError::InvalidExpiry
+                );
+            });
+        }
+
+        #[rstest]
+        fn to_renew_if_expiry_is_not_in_the_future(mut def_vk: sp_io::TestExternalities) {
+            def_vk.execute_with(|| {
+                assert_noop!(
+                    renew(USER_1, Some(System::block_number())),
+                    RError::InvalidExpiry
+                );
+            });
+        }
+
+        #[rstest]
+        fn to_renew_if_vk_exists_but_caller_did_not_register_it(
+            mut def_vk: sp_io::TestExternalities,
+        ) {
+            def_vk.execute_with(|| {
+                assert_noop!(renew(USER_2, Some(EXPIRY)), DispatchError::BadOrigin);
+            });
+        }
+
+        #[rstest]
+        fn to_renew_a_nonexistent_vk(mut test_ext: sp_io::TestExternalities) {
+            test_ext.execute_with(|| {
+                assert_noop!(renew(USER_1, Some(EXPIRY)), RError::VerificationKeyNotFound);
+            });
+        }
+
+        #[rstest]
+        fn to_renew_an_expired_registration_before_its_removal(
+            mut test_ext: sp_io::TestExternalities,
+        ) {
+            test_ext.execute_with(|| {
+                register(USER_1, REGISTERED_VK, Some(EXPIRY));
+                System::set_block_number(EXPIRY);
+
+                assert_noop!(
+                    renew(USER_1, Some(2 * EXPIRY)),
+                    RError::VerificationKeyNotFound
+                );
+            });
+        }
+
+        #[rstest]
+        fn to_transfer_an_expired_registration_before_its_removal(
+            mut test_ext: sp_io::TestExternalities,
+        ) {
+            test_ext.execute_with(|| {
+                register(USER_1, REGISTERED_VK, Some(EXPIRY));
+                assert_ok!(FakeVerifierPallet::transfer_vk(
+                    RuntimeOrigin::signed(USER_1),
+                    REGISTERED_VK_HASH,
+                    USER_2,
+                ));
+                System::set_block_number(EXPIRY);
+
+                assert_noop!(
+                    FakeVerifierPallet::transfer_vk(
+                        RuntimeOrigin::signed(USER_1),
+                        REGISTERED_VK_HASH,
+                        USER_2,
+                    ),
+                    RError::VerificationKeyNotFound
+                );
+                assert_noop!(
+                    FakeVerifierPallet::accept_vk_transfer(
+                        RuntimeOrigin::signed(USER_2),
+                        REGISTERED_VK_HASH,
+                        USER_1,
+                    ),
+                    RError::VerificationKeyNotFound
+                );
+            });
+        }
+    }
+}
+
 fn reserved_balance(vk: &Vk) -> Balance {
     BaseDeposit::get() + PerByteDeposit::get() * vk.encoded_size() as Balance
 }
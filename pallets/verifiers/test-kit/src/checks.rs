@@ -0,0 +1,294 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The conformance checks run by `verifier_conformance!`. Every check that touches the storage
+//! should be executed in a fresh externalities where the given accounts are funded.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::dispatch::DispatchResultWithPostInfo;
+use frame_system::RawOrigin;
+use hp_verifiers::{Verifier, WeightInfo};
+use pallet_verifiers::{Config, DryRunError, Error, Event, Pallet, VkEntry, VkOrHash, Vks};
+use sp_runtime::DispatchError;
+
+use crate::Fixtures;
+
+type AccountOf<T> = <T as frame_system::Config>::AccountId;
+
+fn submit<T: Config<V>, V: Verifier>(
+    account: &AccountOf<T>,
+    vk_or_hash: VkOrHash<V::Vk>,
+    proof: &V::Proof,
+    pubs: &V::Pubs,
+) -> DispatchResultWithPostInfo {
+    Pallet::<T, V>::submit_proof(
+        RawOrigin::Signed(account.clone()).into(),
+        vk_or_hash,
+        Box::new(proof.clone()),
+        Box::new(pubs.clone()),
+        None,
+    )
+}
+
+fn register<T: Config<V>, V: Verifier>(
+    account: &AccountOf<T>,
+    vk: &V::Vk,
+) -> DispatchResultWithPostInfo {
+    Pallet::<T, V>::register_vk(
+        RawOrigin::Signed(account.clone()).into(),
+        Box::new(vk.clone()),
+    )
+}
+
+fn unregister<T: Config<V>, V: Verifier>(
+    account: &AccountOf<T>,
+    vk: &V::Vk,
+) -> Result<(), DispatchError> {
+    Pallet::<T, V>::unregister_vk(RawOrigin::Signed(account.clone()).into(), V::vk_hash(vk))
+}
+
+fn error<T: Config<V>, V: Verifier>(e: Error<T, V>) -> DispatchError {
+    e.into()
+}
+
+fn has_event<T: Config<V>, V: Verifier>(event: Event<T, V>) -> bool {
+    let event: <T as frame_system::Config>::RuntimeEvent =
+        <T as Config<V>>::RuntimeEvent::from(event).into();
+    frame_system::Pallet::<T>::events()
+        .iter()
+        .any(|record| record.event == event)
+}
+
+/// The valid fixture is accepted with both an explicit and a registered verification key, and
+/// the emitted statement is the one returned by `statement_hash`.
+pub fn accept_valid_proof<T: Config<V>, V: Verifier, F: Fixtures<V>>(account: AccountOf<T>) {
+    let (vk, proof, pubs) = F::valid();
+    assert_eq!(V::validate_vk(&vk), Ok(()), "valid vk rejected");
+    assert_eq!(
+        V::verify_proof(&vk, &proof, &pubs),
+        Ok(()),
+        "valid proof rejected"
+    );
+
+    let statement = Pallet::<T, V>::statement_hash(&VkOrHash::from_vk(vk.clone()), &pubs);
+    submit::<T, V>(&account, VkOrHash::from_vk(vk.clone()), &proof, &pubs)
+        .expect("valid proof with explicit vk rejected");
+    assert!(has_event::<T, V>(Event::ProofVerified { statement }));
+
+    register::<T, V>(&account, &vk).expect("cannot register the valid vk");
+    frame_system::Pallet::<T>::reset_events();
+    submit::<T, V>(
+        &account,
+        VkOrHash::from_hash(V::vk_hash(&vk)),
+        &proof,
+        &pubs,
+    )
+    .expect("valid proof with registered vk rejected");
+    assert!(has_event::<T, V>(Event::ProofVerified { statement }));
+
+    let dry_run = Pallet::<T, V>::dry_run(&VkOrHash::from_vk(vk), &proof, &pubs);
+    assert_eq!(dry_run.result, Ok(()));
+    assert_eq!(dry_run.statement, statement);
+}
+
+/// A verification key stays registered until every account that registered it unregisters it,
+/// and an account cannot register the same key twice.
+pub fn count_vk_registrations<T: Config<V>, V: Verifier, F: Fixtures<V>>(
+    alice: AccountOf<T>,
+    bob: AccountOf<T>,
+) {
+    let (vk, proof, pubs) = F::valid();
+    let hash = V::vk_hash(&vk);
+
+    register::<T, V>(&alice, &vk).expect("cannot register the vk");
+    assert!(has_event::<T, V>(Event::VkRegistered { hash }));
+    assert_eq!(
+        register::<T, V>(&alice, &vk).map_err(|e| e.error),
+        Err(error::<T, V>(Error::VerificationKeyAlreadyRegistered))
+    );
+    register::<T, V>(&bob, &vk).expect("cannot register the vk twice");
+
+    unregister::<T, V>(&alice, &vk).expect("cannot unregister the vk");
+    assert!(Vks::<T, V>::contains_key(hash));
+    assert!(!has_event::<T, V>(Event::VkUnregistered { hash }));
+    submit::<T, V>(&alice, VkOrHash::from_hash(hash), &proof, &pubs)
+        .expect("vk removed while still registered by someone");
+    assert_eq!(
+        unregister::<T, V>(&alice, &vk),
+        Err(DispatchError::BadOrigin)
+    );
+
+    unregister::<T, V>(&bob, &vk).expect("cannot unregister the vk");
+    assert!(!Vks::<T, V>::contains_key(hash));
+    assert!(has_event::<T, V>(Event::VkUnregistered { hash }));
+    assert_eq!(
+        submit::<T, V>(&alice, VkOrHash::from_hash(hash), &proof, &pubs).map_err(|e| e.error),
+        Err(error::<T, V>(Error::VerificationKeyNotFound))
+    );
+    assert_eq!(
+        unregister::<T, V>(&bob, &vk),
+        Err(error::<T, V>(Error::VerificationKeyNotFound))
+    );
+}
+
+/// A disabled verifier rejects both proofs and registrations, and works again when enabled.
+pub fn reject_everything_when_disabled<T: Config<V>, V: Verifier, F: Fixtures<V>>(
+    account: AccountOf<T>,
+) {
+    let (vk, proof, pubs) = F::valid();
+    let disabled = Err(error::<T, V>(Error::DisabledVerifier));
+
+    assert_eq!(
+        Pallet::<T, V>::disable(RawOrigin::Signed(account.clone()).into(), true),
+        Err(DispatchError::BadOrigin)
+    );
+    Pallet::<T, V>::disable(RawOrigin::Root.into(), true).expect("cannot disable the verifier");
+
+    assert_eq!(
+        submit::<T, V>(&account, VkOrHash::from_vk(vk.clone()), &proof, &pubs).map_err(|e| e.error),
+        disabled
+    );
+    assert_eq!(
+        register::<T, V>(&account, &vk).map_err(|e| e.error),
+        disabled
+    );
+    assert_eq!(
+        Pallet::<T, V>::dry_run(&VkOrHash::from_vk(vk.clone()), &proof, &pubs).result,
+        Err(DryRunError::DisabledVerifier)
+    );
+
+    Pallet::<T, V>::disable(RawOrigin::Root.into(), false).expect("cannot enable the verifier");
+    submit::<T, V>(&account, VkOrHash::from_vk(vk), &proof, &pubs)
+        .expect("enabled verifier rejects a valid proof");
+}
+
+/// A revoked verification key rejects the proofs and the registrations, while its owners can
+/// still unregister it, and it works again when restored.
+pub fn reject_revoked_vks<T: Config<V>, V: Verifier, F: Fixtures<V>>(account: AccountOf<T>) {
+    let (vk, proof, pubs) = F::valid();
+    let hash = V::vk_hash(&vk);
+    let revoked = Err(error::<T, V>(Error::RevokedVerificationKey));
+
+    register::<T, V>(&account, &vk).expect("cannot register the vk");
+    assert_eq!(
+        Pallet::<T, V>::revoke_vk(
+            RawOrigin::Signed(account.clone()).into(),
+            hash,
+            Default::default()
+        ),
+        Err(DispatchError::BadOrigin)
+    );
+    Pallet::<T, V>::revoke_vk(RawOrigin::Root.into(), hash, Default::default())
+        .expect("cannot revoke the vk");
+    assert!(has_event::<T, V>(Event::VkRevoked {
+        hash,
+        reason: Default::default()
+    }));
+
+    for vk_or_hash in [VkOrHash::from_vk(vk.clone()), VkOrHash::from_hash(hash)] {
+        assert_eq!(
+            Pallet::<T, V>::dry_run(&vk_or_hash, &proof, &pubs).result,
+            Err(DryRunError::RevokedVerificationKey)
+        );
+        assert_eq!(
+            submit::<T, V>(&account, vk_or_hash, &proof, &pubs).map_err(|e| e.error),
+            revoked
+        );
+    }
+    unregister::<T, V>(&account, &vk).expect("cannot unregister a revoked vk");
+    assert_eq!(
+        register::<T, V>(&account, &vk).map_err(|e| e.error),
+        revoked
+    );
+
+    Pallet::<T, V>::restore_vk(RawOrigin::Root.into(), hash).expect("cannot restore the vk");
+    submit::<T, V>(&account, VkOrHash::from_vk(vk), &proof, &pubs)
+        .expect("restored vk rejects a valid proof");
+}
+
+/// The hashes depend just on the values and not on how the verification key is provided.
+pub fn compute_stable_hashes<T: Config<V>, V: Verifier, F: Fixtures<V>>() {
+    let (vk, _, pubs) = F::valid();
+    let vk_hash = V::vk_hash(&vk);
+    let decoded_vk = V::Vk::decode(&mut vk.encode().as_slice()).expect("cannot decode the vk");
+    let decoded_pubs =
+        V::Pubs::decode(&mut pubs.encode().as_slice()).expect("cannot decode the pubs");
+
+    assert!(!V::hash_context_data().is_empty(), "empty hash context");
+    assert_eq!(V::vk_hash(&decoded_vk), vk_hash);
+    assert_eq!(V::pubs_bytes(&decoded_pubs), V::pubs_bytes(&pubs));
+    let statement = Pallet::<T, V>::statement_hash(&VkOrHash::from_vk(vk), &pubs);
+    assert_eq!(
+        Pallet::<T, V>::statement_hash(&VkOrHash::from_hash(vk_hash), &pubs),
+        statement
+    );
+    assert_eq!(
+        Pallet::<T, V>::statement_hash(&VkOrHash::from_vk(decoded_vk), &decoded_pubs),
+        statement
+    );
+}
+
+/// The verification errors are reported unchanged by the pallet calls and by `dry_run`.
+pub fn map_verify_errors<T: Config<V>, V: Verifier, F: Fixtures<V>>(account: AccountOf<T>) {
+    for (vk, proof, pubs) in F::invalid() {
+        let expected = V::verify_proof(&vk, &proof, &pubs).expect_err("invalid proof accepted");
+        let expected_dry_run = DryRunError::from(
+            V::verify_proof(&vk, &proof, &pubs).expect_err("invalid proof accepted"),
+        );
+
+        assert_eq!(
+            Pallet::<T, V>::dry_run(&VkOrHash::from_vk(vk.clone()), &proof, &pubs).result,
+            Err(expected_dry_run)
+        );
+        assert_eq!(
+            submit::<T, V>(&account, VkOrHash::from_vk(vk), &proof, &pubs).map_err(|e| e.error),
+            Err(error::<T, V>(expected.into()))
+        );
+    }
+    for vk in F::invalid_vks() {
+        let expected = V::validate_vk(&vk).expect_err("invalid vk accepted");
+
+        assert_eq!(
+            register::<T, V>(&account, &vk).map_err(|e| e.error),
+            Err(error::<T, V>(expected.into()))
+        );
+        assert!(!Vks::<T, V>::contains_key(V::vk_hash(&vk)));
+    }
+}
+
+/// Bigger proofs and public inputs never cost less than the smaller ones.
+pub fn never_decrease_weight_on_bigger_inputs<V: Verifier, F: Fixtures<V>>() {
+    let inputs = F::by_size();
+    for pair in inputs.windows(2) {
+        let ((small_proof, small_pubs), (big_proof, big_pubs)) = (&pair[0], &pair[1]);
+        assert!(F::WeightInfo::submit_proof(small_proof, small_pubs)
+            .all_lte(F::WeightInfo::submit_proof(big_proof, big_pubs)));
+        assert!(
+            F::WeightInfo::submit_proof_with_vk_hash(small_proof, small_pubs).all_lte(
+                F::WeightInfo::submit_proof_with_vk_hash(big_proof, big_pubs)
+            )
+        );
+    }
+}
+
+/// The verification keys never exceed their `MaxEncodedLen`, that is used to compute the
+/// registration deposits and the storage proof sizes.
+pub fn bound_vk_encoded_len<V: Verifier, F: Fixtures<V>>() {
+    let (vk, _, _) = F::valid();
+    for vk in core::iter::once(vk).chain(F::invalid_vks()) {
+        assert!(vk.encoded_size() <= V::Vk::max_encoded_len());
+        assert!(VkEntry::new(vk).encoded_size() <= VkEntry::<V::Vk>::max_encoded_len());
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A conformance test suite for the verifiers implemented on top of `pallet-verifiers`.
+//!
+//! Implement `Fixtures` for your verifier and invoke `verifier_conformance!` in your crate: it
+//! builds a mock runtime with `pallet_verifiers` instanced for your verifier and generates a
+//! test for each check in the `checks` module.
+//!
+//! ```ignore
+//! #[cfg(test)]
+//! mod conformance {
+//!     use super::*;
+//!
+//!     pub struct UltraplonkFixtures;
+//!
+//!     impl pallet_verifiers_test_kit::Fixtures<Ultraplonk<MockRuntime>> for UltraplonkFixtures {
+//!         type WeightInfo = UltraplonkWeight<()>;
+//!
+//!         fn valid() -> (Vk, Proof, Pubs) {
+//!             (VALID_VK, VALID_PROOF.to_vec(), public_input())
+//!         }
+//!     }
+//!
+//!     pallet_verifiers_test_kit::verifier_conformance!(Ultraplonk<MockRuntime>, UltraplonkFixtures);
+//! }
+//! ```
+//!
+//! The crate that uses the macro should depend on `frame-support` and `frame-system`.
+
+pub mod checks;
+#[cfg(test)]
+mod tests;
+
+use hp_verifiers::{Verifier, WeightInfo};
+
+/// The data used by the conformance checks of the verifier `V`.
+pub trait Fixtures<V: Verifier> {
+    /// The weights that the runtime uses for `V`.
+    type WeightInfo: WeightInfo<V>;
+
+    /// A valid verification key, proof and public inputs.
+    fn valid() -> (V::Vk, V::Proof, V::Pubs);
+
+    /// Well formed submissions that `V::verify_proof` rejects.
+    fn invalid() -> Vec<(V::Vk, V::Proof, V::Pubs)> {
+        Vec::new()
+    }
+
+    /// Verification keys that `V::validate_vk` rejects.
+    fn invalid_vks() -> Vec<V::Vk> {
+        Vec::new()
+    }
+
+    /// Proofs and public inputs sorted by increasing size: their weights should never decrease.
+    fn by_size() -> Vec<(V::Proof, V::Pubs)> {
+        Vec::new()
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use frame_support;
+    pub use frame_system;
+    pub use pallet_balances;
+    pub use pallet_verifiers;
+    pub use sp_core;
+    pub use sp_io;
+    pub use sp_runtime;
+}
+
+/// Generate the conformance tests for a verifier: `verifier_conformance!(Verifier, Fixtures)`
+/// where `Fixtures` implements `pallet_verifiers_test_kit::Fixtures<Verifier>`. The tests
+/// are placed in a `verifier_conformance` module: you can choose another name with
+/// `verifier_conformance!(Verifier, Fixtures, module_name)`, and add some attributes to every
+/// generated test with `verifier_conformance!(Verifier, Fixtures, module_name, #[serial])`.
+#[macro_export]
+macro_rules! verifier_conformance {
+    ($verifier:ty, $fixtures:ty) => {
+        $crate::verifier_conformance!($verifier, $fixtures, verifier_conformance);
+    };
+    ($verifier:ty, $fixtures:ty, $name:ident $(, #[$attr:meta])*) => {
+        #[cfg(test)]
+        mod $name {
+            use super::*;
+            use $crate::__private::{
+                frame_support::{
+                    self, derive_impl, parameter_types,
+                    traits::{fungible::HoldConsideration, LinearStoragePrice},
+                },
+                frame_system, pallet_balances, pallet_verifiers,
+                sp_core::{ConstU128, ConstU32},
+                sp_io,
+                sp_runtime::{traits::IdentityLookup, BuildStorage},
+            };
+
+            type Balance = u128;
+            type AccountId = u64;
+
+            const ALICE: AccountId = 1;
+            const BOB: AccountId = 2;
+
+            pub mod verifier {
+                use super::*;
+
+                pub type Pallet<T> = pallet_verifiers::Pallet<T, $verifier>;
+                pub type Event<T> = pallet_verifiers::Event<T, $verifier>;
+                pub type Error<T> = pallet_verifiers::Error<T, $verifier>;
+                pub use pallet_verifiers::{
+                    __substrate_call_check, __substrate_event_check, tt_default_parts,
+                    tt_error_token,
+                };
+            }
+
+            frame_support::construct_runtime!(
+                pub enum Test
+                {
+                    System: frame_system,
+                    Balances: pallet_balances,
+                    CommonVerifiersPallet: pallet_verifiers::common,
+                    VerifierPallet: verifier,
+                }
+            );
+
+            #[derive_impl(frame_system::config_preludes::SolochainDefaultConfig as frame_system::DefaultConfig)]
+            impl frame_system::Config for Test {
+                type Block = frame_system::mocking::MockBlockU32<Test>;
+                type AccountId = AccountId;
+                type AccountData = pallet_balances::AccountData<Balance>;
+                type Lookup = IdentityLookup<Self::AccountId>;
+            }
+
+            parameter_types! {
+                pub const BaseDeposit: Balance = 1;
+                pub const PerByteDeposit: Balance = 2;
+                pub const HoldReasonVkRegistration: RuntimeHoldReason = RuntimeHoldReason::CommonVerifiersPallet(pallet_verifiers::common::HoldReason::VkRegistration);
+            }
+
+            impl pallet_verifiers::Config<$verifier> for Test {
+                type RuntimeEvent = RuntimeEvent;
+                type OnProofVerified = ();
+                type OnProofRejected = ();
+                type WeightInfo = <$fixtures as $crate::Fixtures<$verifier>>::WeightInfo;
+                type MaxBatchSize = ConstU32<16>;
+                type Ticket = HoldConsideration<
+                    AccountId,
+                    Balances,
+                    HoldReasonVkRegistration,
+                    LinearStoragePrice<BaseDeposit, PerByteDeposit, Balance>,
+                >;
+                #[cfg(feature = "runtime-benchmarks")]
+                type Currency = Balances;
+            }
+
+            impl pallet_balances::Config for Test {
+                type RuntimeEvent = RuntimeEvent;
+                type RuntimeHoldReason = RuntimeHoldReason;
+                type RuntimeFreezeReason = RuntimeFreezeReason;
+                type WeightInfo = ();
+                type Balance = Balance;
+                type DustRemoval = ();
+                type ExistentialDeposit = ConstU128<1>;
+                type AccountStore = System;
+                type ReserveIdentifier = [u8; 8];
+                type FreezeIdentifier = RuntimeFreezeReason;
+                type MaxLocks = ConstU32<10>;
+                type MaxReserves = ConstU32<10>;
+                type MaxFreezes = ConstU32<10>;
+            }
+
+            impl pallet_verifiers::common::Config for Test {
+                type CommonWeightInfo = Test;
+                type StatementRetention = ();
+            }
+
+            fn test_ext() -> sp_io::TestExternalities {
+                let mut t = frame_system::GenesisConfig::<Test>::default()
+                    .build_storage()
+                    .unwrap();
+                pallet_balances::GenesisConfig::<Test> {
+                    balances: vec![(ALICE, 1_000_000_000_000), (BOB, 1_000_000_000_000)],
+                }
+                .assimilate_storage(&mut t)
+                .unwrap();
+                let mut ext = sp_io::TestExternalities::from(t);
+                ext.execute_with(|| System::set_block_number(1));
+                ext
+            }
+
+            #[test]
+            $(#[$attr])*
+            fn accept_valid_proof() {
+                test_ext().execute_with(|| {
+                    $crate::checks::accept_valid_proof::<Test, $verifier, $fixtures>(ALICE)
+                })
+            }
+
+            #[test]
+            $(#[$attr])*
+            fn count_vk_registrations() {
+                test_ext().execute_with(|| {
+                    $crate::checks::count_vk_registrations::<Test, $verifier, $fixtures>(
+                        ALICE, BOB,
+                    )
+                })
+            }
+
+            #[test]
+            $(#[$attr])*
+            fn reject_everything_when_disabled() {
+                test_ext().execute_with(|| {
+                    $crate::checks::reject_everything_when_disabled::<Test, $verifier, $fixtures>(
+                        ALICE,
+                    )
+                })
+            }
+
+            #[test]
+            $(#[$attr])*
+            fn reject_revoked_vks() {
+                test_ext().execute_with(|| {
+                    $crate::checks::reject_revoked_vks::<Test, $verifier, $fixtures>(ALICE)
+                })
+            }
+
+            #[test]
+            $(#[$attr])*
+            fn compute_stable_hashes() {
+                test_ext().execute_with(|| {
+                    $crate::checks::compute_stable_hashes::<Test, $verifier, $fixtures>()
+                })
+            }
+
+            #[test]
+            $(#[$attr])*
+            fn map_verify_errors() {
+                test_ext().execute_with(|| {
+                    $crate::checks::map_verify_errors::<Test, $verifier, $fixtures>(ALICE)
+                })
+            }
+
+            #[test]
+            $(#[$attr])*
+            fn never_decrease_weight_on_bigger_inputs() {
+                $crate::checks::never_decrease_weight_on_bigger_inputs::<$verifier, $fixtures>()
+            }
+
+            #[test]
+            $(#[$attr])*
+            fn bound_vk_encoded_len() {
+                $crate::checks::bound_vk_encoded_len::<$verifier, $fixtures>()
+            }
+        }
+    };
+}
@@ -0,0 +1,103 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Run the conformance suite against a fake verifier.
+
+use frame_support::weights::Weight;
+use hp_verifiers::{Cow, Verifier, VerifyError, WeightInfo};
+
+use crate::Fixtures;
+
+/// Accept the proof iff vk != 0 and proof == pubs.
+pub struct FakeVerifier;
+
+impl Verifier for FakeVerifier {
+    type Proof = u64;
+    type Pubs = u64;
+    type Vk = u64;
+
+    fn hash_context_data() -> &'static [u8] {
+        b"fake"
+    }
+
+    fn verify_proof(
+        vk: &Self::Vk,
+        proof: &Self::Proof,
+        pubs: &Self::Pubs,
+    ) -> Result<(), VerifyError> {
+        Self::validate_vk(vk)?;
+        if proof == pubs {
+            Ok(())
+        } else {
+            Err(VerifyError::VerifyError)
+        }
+    }
+
+    fn validate_vk(vk: &Self::Vk) -> Result<(), VerifyError> {
+        match vk {
+            0 => Err(VerifyError::InvalidVerificationKey),
+            _ => Ok(()),
+        }
+    }
+
+    fn pubs_bytes(pubs: &Self::Pubs) -> Cow<[u8]> {
+        Cow::Owned(pubs.to_be_bytes().into())
+    }
+}
+
+/// The proof value is its size.
+pub struct FakeWeight;
+
+impl WeightInfo<FakeVerifier> for FakeWeight {
+    fn submit_proof(proof: &u64, _pubs: &u64) -> Weight {
+        Weight::from_parts(*proof, 0)
+    }
+
+    fn submit_proof_with_vk_hash(proof: &u64, _pubs: &u64) -> Weight {
+        Weight::from_parts(*proof, 1)
+    }
+
+    fn register_vk(_vk: &u64) -> Weight {
+        Weight::from_parts(1, 1)
+    }
+
+    fn unregister_vk() -> Weight {
+        Weight::from_parts(1, 1)
+    }
+}
+
+pub struct FakeFixtures;
+
+impl Fixtures<FakeVerifier> for FakeFixtures {
+    type WeightInfo = FakeWeight;
+
+    fn valid() -> (u64, u64, u64) {
+        (42, 7, 7)
+    }
+
+    fn invalid() -> Vec<(u64, u64, u64)> {
+        vec![(42, 7, 8), (0, 7, 7)]
+    }
+
+    fn invalid_vks() -> Vec<u64> {
+        vec![0]
+    }
+
+    fn by_size() -> Vec<(u64, u64)> {
+        vec![(1, 1), (2, 2), (3, 3)]
+    }
+}
+
+crate::verifier_conformance!(FakeVerifier, FakeFixtures);
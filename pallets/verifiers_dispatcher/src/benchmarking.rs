@@ -0,0 +1,77 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{BenchmarkHelper, Config, Pallet, RouteError, VerifierRoutes};
+use frame_benchmarking::v2::*;
+use frame_system::{pallet_prelude::OriginFor, RawOrigin};
+
+/// The largest payload used by the benchmarks.
+const MAX_PAYLOAD_SIZE: u32 = 1024 * 1024;
+
+#[benchmarks]
+mod benchmarks {
+
+    use super::*;
+
+    /// The routing overhead: the payload is decoded once to compute the call weight and once
+    /// to dispatch it. The dispatched payload has a trailing byte, so it is fully decoded and
+    /// then rejected without running the verification, which is charged by the verifier.
+    #[benchmark]
+    fn submit(l: Linear<0, MAX_PAYLOAD_SIZE>) {
+        // setup code
+        let (verifier_id, payload) = T::BenchmarkHelper::payload(l);
+        let malformed = [payload.as_slice(), &[0]].concat();
+        let origin: OriginFor<T> = RawOrigin::Signed(whitelisted_caller()).into();
+
+        #[block]
+        {
+            assert!(T::Verifiers::weight(verifier_id, &payload).is_some());
+            assert!(matches!(
+                T::Verifiers::submit(origin, verifier_id, &malformed),
+                Err(RouteError::InvalidPayload)
+            ));
+        }
+    }
+
+    impl_benchmark_test_suite!(Pallet, super::mock::test_ext(), crate::mock::Test);
+}
+
+#[cfg(test)]
+mod mock {
+    use crate::mock::{FooVerifier, Test};
+    use codec::Encode;
+    use frame_support::traits::PalletInfoAccess;
+    use pallet_verifiers::{ProofSubmission, VkOrHash};
+    use sp_runtime::BuildStorage;
+    use sp_std::vec::Vec;
+
+    /// The fake verifiers have fixed size submissions.
+    impl super::BenchmarkHelper for Test {
+        fn payload(_len: u32) -> (u8, Vec<u8>) {
+            let payload =
+                ProofSubmission::<FooVerifier>::new(VkOrHash::from_vk(1), 42, 42, None).encode();
+            (crate::mock::FooVerifierPallet::index() as u8, payload)
+        }
+    }
+
+    pub fn test_ext() -> sp_io::TestExternalities {
+        frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap()
+            .into()
+    }
+}
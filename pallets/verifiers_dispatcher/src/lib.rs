@@ -0,0 +1,317 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A front door pallet for the verifier pallets: the `submit` call takes a verifier id and a
+//! SCALE encoded `pallet_verifiers::ProofSubmission` and routes it to the `submit_proof` call of
+//! the right verifier pallet. The verifier id is the index of the verifier pallet in the runtime,
+//! so it never changes.
+//!
+//! The routed verifiers are configured as a tuple of `Route`:
+//! ```ignore
+//! impl pallet_verifiers_dispatcher::Config for Runtime {
+//!     type Verifiers = (
+//!         Route<Runtime, pallet_fflonk_verifier::Fflonk>,
+//!         Route<Runtime, pallet_groth16_verifier::Groth16<Runtime>>,
+//!     );
+//!     type WeightInfo = Runtime;
+//! }
+//! ```
+
+pub use pallet::*;
+
+pub mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, DecodeAll, Encode};
+use frame_support::{
+    dispatch::{DispatchResultWithPostInfo, GetDispatchInfo, PostDispatchInfo},
+    traits::{Contains, PalletInfoAccess},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::OriginFor;
+use hp_verifiers::Verifier;
+use pallet_verifiers::ProofSubmission;
+use scale_info::TypeInfo;
+use sp_runtime::traits::Dispatchable;
+#[cfg(feature = "std")]
+use sp_runtime::{Deserialize, Serialize};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
+
+/// A verifier that can be reached through the dispatcher.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct VerifierInfo {
+    /// The verifier id.
+    pub id: u8,
+    /// The context data used to compute the statement hashes.
+    #[cfg_attr(feature = "std", serde(with = "sp_core::bytes"))]
+    pub hash_context: Vec<u8>,
+    /// Whether the verifier accepts proofs: it's neither disabled nor filtered out by the
+    /// runtime call filter.
+    pub enabled: bool,
+}
+
+/// Why a submission cannot be routed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteError {
+    /// There is no verifier with the given id.
+    UnknownVerifier,
+    /// The payload is not a valid proof submission for the verifier.
+    InvalidPayload,
+}
+
+/// The set of verifiers the dispatcher can route to. Implemented by `Route` and by the tuples
+/// of `VerifierRoutes`.
+pub trait VerifierRoutes<O> {
+    /// Whether `verifier_id` is one of the routed verifiers.
+    fn contains(verifier_id: u8) -> bool;
+    /// The weight of the submission of `payload` to the verifier `verifier_id`, or `None` if
+    /// it cannot be routed.
+    fn weight(verifier_id: u8, payload: &[u8]) -> Option<Weight>;
+    /// Dispatch the submission of `payload` to the verifier `verifier_id`.
+    fn submit(
+        origin: O,
+        verifier_id: u8,
+        payload: &[u8],
+    ) -> Result<DispatchResultWithPostInfo, RouteError>;
+    /// The routed verifiers.
+    fn verifiers() -> Vec<VerifierInfo>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+#[allow(unused_variables, unused_mut)]
+impl<O> VerifierRoutes<O> for Tuple {
+    for_tuples!( where #( Tuple: VerifierRoutes<O> )* );
+
+    fn contains(verifier_id: u8) -> bool {
+        for_tuples!( #( if Tuple::contains(verifier_id) { return true; } )* );
+        false
+    }
+
+    fn weight(verifier_id: u8, payload: &[u8]) -> Option<Weight> {
+        for_tuples!( #(
+            if Tuple::contains(verifier_id) {
+                return Tuple::weight(verifier_id, payload);
+            }
+        )* );
+        None
+    }
+
+    fn submit(
+        origin: O,
+        verifier_id: u8,
+        payload: &[u8],
+    ) -> Result<DispatchResultWithPostInfo, RouteError> {
+        for_tuples!( #(
+            if Tuple::contains(verifier_id) {
+                return Tuple::submit(origin, verifier_id, payload);
+            }
+        )* );
+        Err(RouteError::UnknownVerifier)
+    }
+
+    fn verifiers() -> Vec<VerifierInfo> {
+        let mut verifiers = Vec::new();
+        for_tuples!( #( verifiers.extend(Tuple::verifiers()); )* );
+        verifiers
+    }
+}
+
+/// Route the submissions to the `submit_proof` call of `pallet_verifiers::Pallet<T, I>`, using
+/// the pallet index as verifier id.
+pub struct Route<T, I>(PhantomData<(T, I)>);
+
+impl<T, I> Route<T, I>
+where
+    T: pallet_verifiers::Config<I>,
+    I: Verifier + 'static,
+{
+    fn id() -> u8 {
+        <pallet_verifiers::Pallet<T, I> as PalletInfoAccess>::index() as u8
+    }
+
+    fn call(mut payload: &[u8]) -> Result<pallet_verifiers::Call<T, I>, RouteError> {
+        let ProofSubmission {
+            vk_or_hash,
+            proof,
+            pubs,
+            domain_id,
+        } = ProofSubmission::<I>::decode_all(&mut payload)
+            .map_err(|_| RouteError::InvalidPayload)?;
+        Ok(pallet_verifiers::Call::<T, I>::submit_proof {
+            vk_or_hash,
+            proof,
+            pubs,
+            domain_id,
+        })
+    }
+}
+
+impl<T, I> VerifierRoutes<OriginFor<T>> for Route<T, I>
+where
+    T: pallet_verifiers::Config<I>,
+    I: Verifier + 'static,
+    <T as frame_system::Config>::RuntimeCall: From<pallet_verifiers::Call<T, I>>
+        + Dispatchable<RuntimeOrigin = OriginFor<T>, PostInfo = PostDispatchInfo>,
+{
+    fn contains(verifier_id: u8) -> bool {
+        verifier_id == Self::id()
+    }
+
+    fn weight(_verifier_id: u8, payload: &[u8]) -> Option<Weight> {
+        Self::call(payload)
+            .ok()
+            .map(|call| call.get_dispatch_info().weight)
+    }
+
+    fn submit(
+        origin: OriginFor<T>,
+        _verifier_id: u8,
+        payload: &[u8],
+    ) -> Result<DispatchResultWithPostInfo, RouteError> {
+        // Dispatch it as a runtime call to honor the origin call filters (e.g. the
+        // `BaseCallFilter`) like the direct `submit_proof` calls.
+        Self::call(payload)
+            .map(|call| <T as frame_system::Config>::RuntimeCall::from(call).dispatch(origin))
+    }
+
+    fn verifiers() -> Vec<VerifierInfo> {
+        // The call filters act on whole verifier pallets: any call of the pallet tells if its
+        // proof submissions would be filtered out.
+        let filtered = !<T as frame_system::Config>::BaseCallFilter::contains(
+            &pallet_verifiers::Call::<T, I>::unregister_vk {
+                vk_hash: Default::default(),
+            }
+            .into(),
+        );
+        let disabled = pallet_verifiers::Pallet::<T, I>::disabled().unwrap_or_default();
+        vec![VerifierInfo {
+            id: Self::id(),
+            hash_context: I::hash_context_data().to_vec(),
+            enabled: !filtered && !disabled,
+        }]
+    }
+}
+
+/// Weight functions needed for `pallet_verifiers_dispatcher`.
+pub trait WeightInfo {
+    /// The routing overhead of `submit` with a payload of `l` bytes: the verifier pallet call
+    /// weight is added to it.
+    fn submit(l: u32) -> Weight;
+}
+
+/// The routing is just the payload decoding, that is small compared to the proof verification.
+/// We use this estimate in the runtimes until the weights are generated from the `benchmarking`
+/// benchmarks: a fixed overhead and the payload decoding cost per byte.
+impl<T: frame_system::Config> WeightInfo for T {
+    fn submit(l: u32) -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(l.into()))
+    }
+}
+
+/// Build the payloads used by the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+    /// A routed verifier id and a valid submission payload for it of about `len` bytes.
+    fn payload(len: u32) -> (u8, Vec<u8>);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    #[cfg(feature = "runtime-benchmarks")]
+    use super::BenchmarkHelper;
+    use super::{RouteError, VerifierInfo, VerifierRoutes, WeightInfo};
+    use frame_support::{
+        dispatch::{DispatchResultWithPostInfo, PostDispatchInfo},
+        pallet_prelude::*,
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_std::vec::Vec;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The verifiers that can be reached through `submit`.
+        type Verifiers: VerifierRoutes<OriginFor<Self>>;
+        /// Weights
+        type WeightInfo: WeightInfo;
+        /// Build the payloads used by the benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// There is no verifier with the given id.
+        UnknownVerifier,
+        /// The payload is not a valid proof submission for the verifier.
+        InvalidPayload,
+    }
+
+    impl<T> From<RouteError> for Error<T> {
+        fn from(e: RouteError) -> Self {
+            match e {
+                RouteError::UnknownVerifier => Error::<T>::UnknownVerifier,
+                RouteError::InvalidPayload => Error::<T>::InvalidPayload,
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Submit the SCALE encoded `pallet_verifiers::ProofSubmission` `payload` to the
+        /// verifier `verifier_id`. It behaves exactly like the `submit_proof` call of the
+        /// verifier pallet, with the same origin.
+        #[pallet::call_index(0)]
+        #[pallet::weight(
+            T::WeightInfo::submit(payload.len() as u32)
+                .saturating_add(T::Verifiers::weight(*verifier_id, payload).unwrap_or_default())
+        )]
+        pub fn submit(
+            origin: OriginFor<T>,
+            verifier_id: u8,
+            payload: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            log::trace!("Routing proof submission to verifier {verifier_id}");
+            let overhead = T::WeightInfo::submit(payload.len() as u32);
+            let add_overhead = |post_info: PostDispatchInfo| PostDispatchInfo {
+                actual_weight: post_info.actual_weight.map(|w| w.saturating_add(overhead)),
+                pays_fee: post_info.pays_fee,
+            };
+            T::Verifiers::submit(origin, verifier_id, &payload)
+                .map_err(Error::<T>::from)?
+                .map(add_overhead)
+                .map_err(|mut e| {
+                    e.post_info = add_overhead(e.post_info);
+                    e
+                })
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The verifiers that can be reached through `submit`.
+        pub fn list_verifiers() -> Vec<VerifierInfo> {
+            T::Verifiers::verifiers()
+        }
+    }
+}
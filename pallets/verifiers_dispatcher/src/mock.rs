@@ -0,0 +1,220 @@
+// Copyright 2024, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(test)]
+
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{fungible::HoldConsideration, Contains, LinearStoragePrice},
+    weights::Weight,
+};
+use hp_verifiers::{Verifier, VerifyError, WeightInfo};
+use sp_core::{ConstU128, ConstU32};
+use sp_runtime::traits::IdentityLookup;
+
+use crate::Route;
+pub use fake_verifiers::{BarVerifier, FooVerifier};
+
+pub type Balance = u128;
+pub type AccountId = u64;
+
+pub mod fake_verifiers {
+    use super::*;
+
+    /// Accept the proof iff vk != 0 and proof == pubs.
+    fn verify<N: PartialEq + Default>(vk: &N, proof: &N, pubs: &N) -> Result<(), VerifyError> {
+        match (vk, proof, pubs) {
+            (vk, _, _) if *vk == N::default() => Err(VerifyError::InvalidVerificationKey),
+            (_, proof, pubs) if proof == pubs => Ok(()),
+            _ => Err(VerifyError::VerifyError),
+        }
+    }
+
+    pub mod foo {
+        use super::*;
+
+        #[pallet_verifiers::verifier]
+        pub struct FooVerifier;
+
+        impl Verifier for FooVerifier {
+            type Proof = u64;
+            type Pubs = u64;
+            type Vk = u64;
+
+            fn hash_context_data() -> &'static [u8] {
+                b"foo"
+            }
+
+            fn verify_proof(
+                vk: &Self::Vk,
+                proof: &Self::Proof,
+                pubs: &Self::Pubs,
+            ) -> Result<(), VerifyError> {
+                verify(vk, proof, pubs)
+            }
+
+            fn pubs_bytes(pubs: &Self::Pubs) -> sp_std::borrow::Cow<[u8]> {
+                sp_std::borrow::Cow::Owned(pubs.to_be_bytes().into())
+            }
+        }
+    }
+
+    pub mod bar {
+        use super::*;
+
+        #[pallet_verifiers::verifier]
+        pub struct BarVerifier;
+
+        impl Verifier for BarVerifier {
+            type Proof = u32;
+            type Pubs = u32;
+            type Vk = u32;
+
+            fn hash_context_data() -> &'static [u8] {
+                b"bar"
+            }
+
+            fn verify_proof(
+                vk: &Self::Vk,
+                proof: &Self::Proof,
+                pubs: &Self::Pubs,
+            ) -> Result<(), VerifyError> {
+                verify(vk, proof, pubs)
+            }
+
+            fn pubs_bytes(pubs: &Self::Pubs) -> sp_std::borrow::Cow<[u8]> {
+                sp_std::borrow::Cow::Owned(pubs.to_be_bytes().into())
+            }
+        }
+    }
+
+    pub use bar::BarVerifier;
+    pub use foo::FooVerifier;
+}
+
+pub struct MockWeightInfo;
+impl<V: Verifier> WeightInfo<V> for MockWeightInfo {
+    fn submit_proof(_proof: &V::Proof, _pubs: &V::Pubs) -> Weight {
+        Weight::from_parts(1, 2)
+    }
+
+    fn submit_proof_with_vk_hash(_proof: &V::Proof, _pubs: &V::Pubs) -> Weight {
+        Weight::from_parts(3, 4)
+    }
+
+    fn register_vk(_vk: &V::Vk) -> Weight {
+        Weight::from_parts(5, 6)
+    }
+
+    fn unregister_vk() -> Weight {
+        Weight::from_parts(7, 8)
+    }
+}
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        CommonVerifiersPallet: pallet_verifiers::common,
+        FooVerifierPallet: fake_verifiers::foo,
+        BarVerifierPallet: fake_verifiers::bar,
+        VerifiersDispatcher: crate,
+    }
+);
+
+parameter_types! {
+    pub static FilterFooVerifier: bool = false;
+}
+
+/// Filter out the `FooVerifierPallet` calls when `FilterFooVerifier` is set.
+pub struct MockCallFilter;
+impl Contains<RuntimeCall> for MockCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        !(FilterFooVerifier::get() && matches!(call, RuntimeCall::FooVerifierPallet(..)))
+    }
+}
+
+#[derive_impl(frame_system::config_preludes::SolochainDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = MockCallFilter;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = frame_system::mocking::MockBlockU32<Test>;
+    type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+parameter_types! {
+    pub const BaseDeposit: Balance = 1;
+    pub const PerByteDeposit: Balance = 2;
+    pub const HoldReasonVkRegistration: RuntimeHoldReason = RuntimeHoldReason::CommonVerifiersPallet(pallet_verifiers::common::HoldReason::VkRegistration);
+}
+
+type Ticket = HoldConsideration<
+    AccountId,
+    Balances,
+    HoldReasonVkRegistration,
+    LinearStoragePrice<BaseDeposit, PerByteDeposit, Balance>,
+>;
+
+impl pallet_verifiers::Config<FooVerifier> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type OnProofVerified = ();
+    type OnProofRejected = ();
+    type Ticket = Ticket;
+    type WeightInfo = MockWeightInfo;
+    type MaxBatchSize = ConstU32<4>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Currency = Balances;
+}
+
+impl pallet_verifiers::Config<BarVerifier> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type OnProofVerified = ();
+    type OnProofRejected = ();
+    type Ticket = Ticket;
+    type WeightInfo = MockWeightInfo;
+    type MaxBatchSize = ConstU32<4>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Currency = Balances;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = ();
+}
+
+impl pallet_verifiers::common::Config for Test {
+    type CommonWeightInfo = Test;
+    type StatementRetention = ();
+}
+
+impl crate::Config for Test {
+    type Verifiers = (Route<Test, FooVerifier>, Route<Test, BarVerifier>);
+    type WeightInfo = Test;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = Test;
+}
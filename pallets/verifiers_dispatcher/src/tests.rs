@@ -0,0 +1,226 @@
+// Copyright 2024, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(test)]
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_err_ignore_postinfo, assert_noop, assert_ok, traits::PalletInfoAccess};
+use pallet_verifiers::VkOrHash;
+use rstest::{fixture, rstest};
+use sp_runtime::BuildStorage;
+
+const USER: AccountId = 42;
+
+#[fixture]
+fn test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(USER, 42_000_000_000)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::from(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn foo_id() -> u8 {
+    FooVerifierPallet::index() as u8
+}
+
+fn bar_id() -> u8 {
+    BarVerifierPallet::index() as u8
+}
+
+fn foo_payload(vk: u64, proof: u64, pubs: u64) -> Vec<u8> {
+    ProofSubmission::<FooVerifier>::new(VkOrHash::from_vk(vk), proof, pubs, None).encode()
+}
+
+fn bar_payload(vk: u32, proof: u32, pubs: u32) -> Vec<u8> {
+    ProofSubmission::<BarVerifier>::new(VkOrHash::from_vk(vk), proof, pubs, None).encode()
+}
+
+fn submit_call(verifier_id: u8, payload: Vec<u8>) -> Call<Test> {
+    Call::<Test>::submit {
+        verifier_id,
+        payload,
+    }
+}
+
+#[rstest]
+fn route_the_proof_to_the_right_verifier(mut test_ext: sp_io::TestExternalities) {
+    test_ext.execute_with(|| {
+        let foo_statement = FooVerifierPallet::statement_hash(&VkOrHash::from_vk(1), &42);
+        let bar_statement = BarVerifierPallet::statement_hash(&VkOrHash::from_vk(1), &24);
+
+        assert_ok!(VerifiersDispatcher::submit(
+            RuntimeOrigin::signed(USER),
+            foo_id(),
+            foo_payload(1, 42, 42)
+        ));
+        assert_ok!(VerifiersDispatcher::submit(
+            RuntimeOrigin::signed(USER),
+            bar_id(),
+            bar_payload(1, 24, 24)
+        ));
+
+        let events = System::events()
+            .into_iter()
+            .map(|r| r.event)
+            .collect::<Vec<_>>();
+        assert!(events.contains(&RuntimeEvent::FooVerifierPallet(
+            pallet_verifiers::Event::ProofVerified {
+                statement: foo_statement
+            }
+        )));
+        assert!(events.contains(&RuntimeEvent::BarVerifierPallet(
+            pallet_verifiers::Event::ProofVerified {
+                statement: bar_statement
+            }
+        )));
+    })
+}
+
+#[rstest]
+fn return_the_verifier_error(mut test_ext: sp_io::TestExternalities) {
+    test_ext.execute_with(|| {
+        assert_err_ignore_postinfo!(
+            VerifiersDispatcher::submit(
+                RuntimeOrigin::signed(USER),
+                foo_id(),
+                foo_payload(1, 42, 24)
+            ),
+            pallet_verifiers::Error::<Test, FooVerifier>::VerifyError
+        );
+    })
+}
+
+#[rstest]
+fn reject_unknown_verifier(mut test_ext: sp_io::TestExternalities) {
+    test_ext.execute_with(|| {
+        let unknown = VerifiersDispatcher::index() as u8;
+
+        assert_noop!(
+            VerifiersDispatcher::submit(
+                RuntimeOrigin::signed(USER),
+                unknown,
+                foo_payload(1, 42, 42)
+            ),
+            Error::<Test>::UnknownVerifier
+        );
+    })
+}
+
+#[rstest]
+#[case::empty(vec![])]
+#[case::payload_of_another_verifier(bar_payload(1, 42, 42))]
+#[case::trailing_bytes([foo_payload(1, 42, 42), vec![0]].concat())]
+fn reject_invalid_payload(mut test_ext: sp_io::TestExternalities, #[case] payload: Vec<u8>) {
+    test_ext.execute_with(|| {
+        assert_noop!(
+            VerifiersDispatcher::submit(RuntimeOrigin::signed(USER), foo_id(), payload),
+            Error::<Test>::InvalidPayload
+        );
+    })
+}
+
+#[test]
+fn add_the_routing_overhead_to_the_verifier_weight() {
+    let payload = foo_payload(1, 42, 42);
+    let verifier_weight = pallet_verifiers::Call::<Test, FooVerifier>::submit_proof {
+        vk_or_hash: VkOrHash::from_vk(1),
+        proof: Box::new(42),
+        pubs: Box::new(42),
+        domain_id: None,
+    }
+    .get_dispatch_info()
+    .weight;
+
+    test_ext().execute_with(|| {
+        assert_eq!(
+            submit_call(foo_id(), payload.clone())
+                .get_dispatch_info()
+                .weight,
+            <Test as crate::WeightInfo>::submit(payload.len() as u32)
+                .saturating_add(verifier_weight)
+        );
+        assert_eq!(
+            submit_call(foo_id(), vec![]).get_dispatch_info().weight,
+            <Test as crate::WeightInfo>::submit(0)
+        );
+    })
+}
+
+#[rstest]
+fn honor_the_call_filter(mut test_ext: sp_io::TestExternalities) {
+    test_ext.execute_with(|| {
+        FilterFooVerifier::set(true);
+
+        assert_err_ignore_postinfo!(
+            VerifiersDispatcher::submit(
+                RuntimeOrigin::signed(USER),
+                foo_id(),
+                foo_payload(1, 42, 42)
+            ),
+            frame_system::Error::<Test>::CallFiltered
+        );
+        assert_ok!(VerifiersDispatcher::submit(
+            RuntimeOrigin::signed(USER),
+            bar_id(),
+            bar_payload(1, 24, 24)
+        ));
+    })
+}
+
+#[rstest]
+fn list_the_routed_verifiers(mut test_ext: sp_io::TestExternalities) {
+    test_ext.execute_with(|| {
+        assert_ok!(BarVerifierPallet::disable(RuntimeOrigin::root(), true));
+
+        assert_eq!(
+            VerifiersDispatcher::list_verifiers(),
+            vec![
+                VerifierInfo {
+                    id: foo_id(),
+                    hash_context: b"foo".to_vec(),
+                    enabled: true,
+                },
+                VerifierInfo {
+                    id: bar_id(),
+                    hash_context: b"bar".to_vec(),
+                    enabled: false,
+                },
+            ]
+        );
+    })
+}
+
+#[rstest]
+fn list_the_filtered_verifiers_as_disabled(mut test_ext: sp_io::TestExternalities) {
+    test_ext.execute_with(|| {
+        FilterFooVerifier::set(true);
+
+        assert_eq!(
+            VerifiersDispatcher::list_verifiers()
+                .into_iter()
+                .map(|v| (v.id, v.enabled))
+                .collect::<Vec<_>>(),
+            vec![(foo_id(), false), (bar_id(), true)]
+        );
+    })
+}
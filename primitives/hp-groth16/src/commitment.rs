@@ -0,0 +1,215 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The gnark BSB22 commitment extension: the proof carries a Pedersen commitment `C` and its proof
+//! of knowledge, and the circuit gets a further public input that is the hash of `C` and of the
+//! committed public inputs. `C` is added to the prepared inputs before the usual pairing check.
+
+#![cfg_attr(not(feature = "implementation"), allow(dead_code))]
+
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::CanonicalSerialize;
+use sha2::{Digest, Sha256};
+use sp_std::vec::Vec;
+
+use crate::{Groth16Error, ProofCommitment, VkCommitment};
+
+/// The domain separation tag that gnark uses to hash the commitment.
+pub const COMMITMENT_DST: &[u8] = b"bsb22-commitment";
+
+/// A commitment checked against its commitment key.
+pub(crate) struct Commitment<E: Pairing> {
+    pub point: E::G1Affine,
+    pub pok: E::G1Affine,
+    pub g: E::G2Affine,
+    pub g_sigma_neg: E::G2Affine,
+    pub public_committed: Vec<u32>,
+}
+
+impl<E: Pairing> Commitment<E> {
+    /// A proof has a commitment if and only if its verification key has a commitment key.
+    pub fn try_new(
+        vk: Option<VkCommitment>,
+        proof: Option<ProofCommitment>,
+    ) -> Result<Option<Self>, Groth16Error> {
+        let (vk, proof) = match (vk, proof) {
+            (None, None) => return Ok(None),
+            (Some(vk), Some(proof)) => (vk, proof),
+            _ => return Err(Groth16Error::InvalidProof),
+        };
+        let point: E::G1Affine = proof
+            .commitment
+            .try_into_affine()
+            .map_err(|_| Groth16Error::InvalidProof)?;
+        let pok: E::G1Affine = proof
+            .pok
+            .try_into_affine()
+            .map_err(|_| Groth16Error::InvalidProof)?;
+        if point.is_zero() {
+            return Err(Groth16Error::InvalidProof);
+        }
+        Ok(Some(Self {
+            point,
+            pok,
+            g: vk
+                .g
+                .try_into_affine_unchecked()
+                .map_err(|_| Groth16Error::InvalidVerificationKey)?,
+            g_sigma_neg: vk
+                .g_sigma_neg
+                .try_into_affine_unchecked()
+                .map_err(|_| Groth16Error::InvalidVerificationKey)?,
+            public_committed: vk.public_committed,
+        }))
+    }
+
+    /// The further public input: the gnark hash of the commitment and of the committed public
+    /// `inputs`.
+    pub fn input(&self, inputs: &[E::ScalarField]) -> Result<E::ScalarField, Groth16Error> {
+        let committed = self
+            .public_committed
+            .iter()
+            .map(|&i| {
+                (i as usize)
+                    .checked_sub(1)
+                    .and_then(|i| inputs.get(i))
+                    .copied()
+                    .ok_or(Groth16Error::InvalidVerificationKey)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(hash_commitment::<E>(&self.point, &committed))
+    }
+}
+
+/// Check the commitment key of a verification key with `num_inputs` public inputs (the
+/// commitment hash excluded): the committed inputs should be strictly increasing indexes.
+pub(crate) fn validate_key<E: Pairing>(
+    vk: VkCommitment,
+    num_inputs: usize,
+) -> Result<(), Groth16Error> {
+    let g: E::G2Affine =
+        vk.g.try_into_affine()
+            .map_err(|_| Groth16Error::InvalidVerificationKey)?;
+    let g_sigma_neg: E::G2Affine = vk
+        .g_sigma_neg
+        .try_into_affine()
+        .map_err(|_| Groth16Error::InvalidVerificationKey)?;
+    // gnark lists the committed inputs in increasing order: so they are bounded by `num_inputs`
+    let mut previous = 0;
+    let sorted_in_range = vk.public_committed.iter().all(|&i| {
+        let ok = i > previous && i as usize <= num_inputs;
+        previous = i;
+        ok
+    });
+    if g.is_zero() || g_sigma_neg.is_zero() || !sorted_in_range {
+        return Err(Groth16Error::InvalidVerificationKey);
+    }
+    Ok(())
+}
+
+/// Hash the commitment `point` and the `committed` public inputs to a field element as gnark
+/// does: the message is the big-endian uncompressed point followed by the big-endian inputs.
+pub(crate) fn hash_commitment<E: Pairing>(
+    point: &E::G1Affine,
+    committed: &[E::ScalarField],
+) -> E::ScalarField {
+    let mut msg = Vec::with_capacity(point.uncompressed_size());
+    point
+        .serialize_uncompressed(&mut msg)
+        .expect("Serialize to a vector cannot fail. qed");
+    // arkworks writes x and y in little-endian: a not zero point doesn't set any flag bit.
+    let (x, y) = msg.split_at_mut(point.uncompressed_size() / 2);
+    x.reverse();
+    y.reverse();
+    for input in committed {
+        msg.extend(input.into_bigint().to_bytes_be());
+    }
+    hash_to_field(&msg, COMMITMENT_DST)
+}
+
+/// Hash to a field element as gnark-crypto `fr.Hash` does: `expand_message_xmd` with SHA-256
+/// and 128 bits of security, read as a big-endian integer.
+fn hash_to_field<F: PrimeField>(msg: &[u8], dst: &[u8]) -> F {
+    let len = 16 + (F::MODULUS_BIT_SIZE as usize).div_ceil(8);
+    F::from_be_bytes_mod_order(&expand_message_xmd(msg, dst, len))
+}
+
+/// `expand_message_xmd` with SHA-256 as defined in RFC 9380. `len` must be at most
+/// `255 * 32` bytes and `dst` at most 255 bytes.
+fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    const B_IN_BYTES: usize = 32;
+    const S_IN_BYTES: usize = 64;
+    let ell = len.div_ceil(B_IN_BYTES);
+    debug_assert!(ell <= 255 && dst.len() <= 255);
+
+    let b_0 = Sha256::new()
+        .chain_update([0; S_IN_BYTES])
+        .chain_update(msg)
+        .chain_update((len as u16).to_be_bytes())
+        .chain_update([0])
+        .chain_update(dst)
+        .chain_update([dst.len() as u8])
+        .finalize();
+    let mut result = Vec::with_capacity(ell * B_IN_BYTES);
+    let mut b_i = Sha256::new()
+        .chain_update(b_0)
+        .chain_update([1])
+        .chain_update(dst)
+        .chain_update([dst.len() as u8])
+        .finalize();
+    result.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xored: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = Sha256::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(dst)
+            .chain_update([dst.len() as u8])
+            .finalize();
+        result.extend_from_slice(&b_i);
+    }
+    result.truncate(len);
+    result
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use hex_literal::hex;
+    use rstest::rstest;
+
+    // RFC 9380, Appendix K.1
+    #[rstest]
+    #[case::empty_msg(b"", hex!("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"))]
+    #[case::abc(b"abc", hex!("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"))]
+    fn expand_message_as_rfc_9380(#[case] msg: &[u8], #[case] expected: [u8; 32]) {
+        assert_eq!(
+            expand_message_xmd(msg, b"QUUX-V01-CS02-with-expander-SHA256-128", 32),
+            expected
+        );
+    }
+
+    #[test]
+    fn expand_message_longer_than_a_hash() {
+        let long = expand_message_xmd(b"abc", COMMITMENT_DST, 48);
+
+        assert_eq!(long.len(), 48);
+        assert_ne!(
+            &long[..32],
+            &expand_message_xmd(b"abc", COMMITMENT_DST, 32)[..]
+        );
+    }
+}
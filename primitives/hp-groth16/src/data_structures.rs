@@ -23,10 +23,10 @@ use sp_runtime_interface::pass_by::{PassByCodec, PassByInner};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
-/// Maximum sizes for G1 in bytes
-pub const G1_MAX_SIZE: u32 = 96;
-/// Maximum sizes for G2 in bytes
-pub const G2_MAX_SIZE: u32 = G1_MAX_SIZE * 2;
+/// Maximum sizes for G1 in bytes (BW6-761)
+pub const G1_MAX_SIZE: u32 = 192;
+/// Maximum sizes for G2 in bytes (BLS12-381, BLS12-377 and BW6-761)
+pub const G2_MAX_SIZE: u32 = 192;
 
 /// Len of encoded vec with a give element size
 pub fn vec_max_encoded_len(element_size: usize, len: u32) -> usize {
@@ -83,6 +83,28 @@ pub struct VerificationKey {
     pub gamma_abc_g1: Vec<G1>,
 }
 
+/// The Pedersen commitment key that gnark (BSB22 extension) adds to the verification key.
+/// The circuit gets a further public input: the hash of the proof commitment and of the
+/// committed public inputs.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo, PassByCodec)]
+pub struct VkCommitment {
+    /// `g` point
+    pub g: G2,
+    /// `g_sigma_neg` point
+    pub g_sigma_neg: G2,
+    /// The 1-based indexes of the public inputs that are part of the commitment
+    pub public_committed: Vec<u32>,
+}
+
+/// The gnark (BSB22 extension) commitment that goes with a proof.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo, PassByCodec)]
+pub struct ProofCommitment {
+    /// The commitment point
+    pub commitment: G1,
+    /// The proof of knowledge of the commitment opening
+    pub pok: G1,
+}
+
 impl G1 {
     /// Try to convert the G1 point to an affine representation.
     pub fn try_into_affine<R: AffineRepr>(self) -> Result<R, SerializationError> {
@@ -226,8 +248,10 @@ impl<E: Pairing> TryFrom<ark_groth16::VerifyingKey<E>> for VerificationKey {
 #[macro_use]
 mod test {
     use super::*;
+    use ark_bls12_377::Bls12_377;
     use ark_bls12_381::Bls12_381;
     use ark_bn254::Bn254;
+    use ark_bw6_761::BW6_761;
     use ark_ec::pairing::Pairing;
     use ark_ff::UniformRand;
     use ark_std::rand::{rngs::StdRng, SeedableRng};
@@ -306,6 +330,8 @@ mod test {
     #[rstest]
     #[case::bn254(PhantomData::<Bn254>)]
     #[case::bls12_381(PhantomData::<Bls12_381>)]
+    #[case::bls12_377(PhantomData::<Bls12_377>)]
+    #[case::bw6_761(PhantomData::<BW6_761>)]
     fn curves<P: Pairing>(#[case] _p: P) {}
 
     mod serialize_and_deserialize {
@@ -18,13 +18,13 @@
 //! This module contains a dummy circuit for Groth16 verification.
 
 use ark_crypto_primitives::snark::SNARK;
-use ark_ec::pairing::Pairing;
-use ark_ff::PrimeField;
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_ff::{Field, PrimeField};
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
 use ark_std::{rand::rngs::StdRng, rand::SeedableRng, UniformRand};
 use sp_std::vec::Vec;
 
-use crate::{Proof, Scalar, VerificationKey};
+use crate::{Proof, ProofCommitment, Scalar, VerificationKey, VkCommitment, G1, G2};
 
 /// A dummy circuit.
 #[derive(Clone, Debug)]
@@ -67,3 +67,76 @@ pub fn get_instance<E: Pairing>(
 
     (proof, vk, inputs)
 }
+
+/// Get an instance of a gnark circuit with a commitment (BSB22 extension) to the odd public
+/// inputs, with the given number of inputs and random seed. Arkworks can't prove the commitment
+/// extension, so the proof is forged by a setup that knows all the discrete logarithms.
+pub fn get_instance_with_commitment<E: Pairing>(
+    num_inputs: usize,
+    rng_seed: Option<u64>,
+) -> (
+    Proof,
+    ProofCommitment,
+    VerificationKey,
+    VkCommitment,
+    Vec<Scalar>,
+) {
+    let rng = &mut StdRng::seed_from_u64(rng_seed.unwrap_or(0));
+    let g1 = E::G1Affine::generator();
+    let g2 = E::G2Affine::generator();
+    let mut rand = || E::ScalarField::rand(rng);
+
+    let inputs: Vec<_> = (0..num_inputs).map(|_| rand()).collect();
+    let public_committed: Vec<u32> = (1..=num_inputs as u32).step_by(2).collect();
+    // The one wire, the inputs and the commitment hash
+    let k: Vec<_> = (0..num_inputs + 2).map(|_| rand()).collect();
+    let (alpha, beta, gamma, delta) = (rand(), rand(), rand(), rand());
+    let (sigma, g, c) = (rand(), rand(), rand());
+    let (a, b) = (rand(), rand());
+
+    let commitment: E::G1Affine = (g1 * c).into();
+    let committed: Vec<_> = public_committed
+        .iter()
+        .map(|&i| inputs[i as usize - 1])
+        .collect();
+    let hash = crate::commitment::hash_commitment::<E>(&commitment, &committed);
+    let l = k[0]
+        + k[1..]
+            .iter()
+            .zip(inputs.iter().chain([&hash]))
+            .map(|(k, x)| *k * x)
+            .sum::<E::ScalarField>()
+        + c;
+    let proof_c = (a * b - alpha * beta - l * gamma) * delta.inverse().unwrap();
+
+    let g1_point = |x: E::ScalarField| G1::try_from_affine::<E::G1Affine>((g1 * x).into()).unwrap();
+    let g2_point = |x: E::ScalarField| G2::try_from_affine::<E::G2Affine>((g2 * x).into()).unwrap();
+    let proof = Proof {
+        a: g1_point(a),
+        b: g2_point(b),
+        c: g1_point(proof_c),
+    };
+    let proof_commitment = ProofCommitment {
+        commitment: g1_point(c),
+        pok: g1_point(c * sigma),
+    };
+    let vk = VerificationKey {
+        alpha_g1: g1_point(alpha),
+        beta_g2: g2_point(beta),
+        gamma_g2: g2_point(gamma),
+        delta_g2: g2_point(delta),
+        gamma_abc_g1: k.into_iter().map(g1_point).collect(),
+    };
+    let vk_commitment = VkCommitment {
+        g: g2_point(g),
+        g_sigma_neg: g2_point(-g * sigma),
+        public_committed,
+    };
+    let inputs = inputs
+        .into_iter()
+        .map(Scalar::try_from_scalar)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    (proof, proof_commitment, vk, vk_commitment, inputs)
+}
@@ -23,13 +23,19 @@ use ark_ec::pairing::Pairing;
 use ark_groth16::prepare_verifying_key;
 use sp_std::vec::Vec;
 
+mod commitment;
 mod data_structures;
 
+/// Rexported Bls12-377 curve
+pub use ark_bls12_377::Bls12_377;
 /// Rexported Bls curve
 pub use ark_bls12_381::Bls12_381;
 /// Rexported Bn curve
 pub use ark_bn254::Bn254;
+/// Rexported BW6-761 curve
+pub use ark_bw6_761::BW6_761;
 
+pub use commitment::COMMITMENT_DST;
 pub mod dummy_circuit;
 pub use data_structures::*;
 
@@ -80,6 +86,128 @@ pub fn verify_proof<E: Pairing>(
         .map_err(|_| Groth16Error::VerifyError)
 }
 
+/// Verify a batch of groth16 proofs against the `E` elliptic curve: every element is made by a
+/// verification key, a proof and its inputs.
+/// All the pairing checks are merged with a random linear combination in a single multi-pairing,
+/// so they share just one final exponentiation. The random coefficients are derived from the
+/// hash of the whole batch, so the result is deterministic. Return `Ok(true)` if and only if
+/// all proofs are valid.
+#[cfg(feature = "implementation")]
+pub fn verify_batch<E: Pairing>(
+    batch: Vec<(VerificationKey, Proof, Vec<Scalar>)>,
+) -> Result<bool, Groth16Error> {
+    let seed = batch_seed(&batch);
+    verify_batch_with_seed::<E>(
+        seed,
+        batch
+            .into_iter()
+            .map(|(vk, proof, inputs)| (vk, None, proof, None, inputs)),
+    )
+}
+
+/// A batch element whose proof could carry a gnark commitment: verification key, its
+/// commitment key, proof, its commitment and inputs (the commitment hash excluded).
+pub type CommittedBatchItem = (
+    VerificationKey,
+    Option<VkCommitment>,
+    Proof,
+    Option<ProofCommitment>,
+    Vec<Scalar>,
+);
+
+/// Verify a gnark groth16 proof with a commitment against the `E` elliptic curve using the
+/// provided verification key, its commitment key and inputs. The inputs don't include the
+/// commitment hash: it's computed from the commitment and the committed inputs.
+#[cfg(feature = "implementation")]
+pub fn verify_proof_with_commitment<E: Pairing>(
+    vk: VerificationKey,
+    vk_commitment: VkCommitment,
+    proof: Proof,
+    proof_commitment: ProofCommitment,
+    inputs: &[Scalar],
+) -> Result<bool, Groth16Error> {
+    let item = (
+        vk,
+        Some(vk_commitment),
+        proof,
+        Some(proof_commitment),
+        inputs.to_vec(),
+    );
+    verify_batch_with_seed::<E>(batch_seed(&[&item]), core::iter::once(item))
+}
+
+/// Like [`verify_batch`] but every proof could carry a gnark commitment: a proof must have a
+/// commitment if and only if its verification key has a commitment key.
+#[cfg(feature = "implementation")]
+pub fn verify_batch_with_commitments<E: Pairing>(
+    batch: Vec<CommittedBatchItem>,
+) -> Result<bool, Groth16Error> {
+    verify_batch_with_seed::<E>(batch_seed(&batch), batch.into_iter())
+}
+
+#[cfg(feature = "implementation")]
+fn batch_seed<T: codec::Encode>(batch: &[T]) -> [u8; 32] {
+    use codec::Encode;
+    use sha2::{Digest, Sha256};
+
+    Sha256::digest(batch.encode()).into()
+}
+
+#[cfg(feature = "implementation")]
+fn verify_batch_with_seed<E: Pairing>(
+    seed: [u8; 32],
+    batch: impl ExactSizeIterator<Item = CommittedBatchItem>,
+) -> Result<bool, Groth16Error> {
+    use ark_std::{
+        rand::{rngs::StdRng, SeedableRng},
+        UniformRand, Zero,
+    };
+
+    let rng = &mut StdRng::from_seed(seed);
+    let mut g1 = Vec::<E::G1Prepared>::with_capacity(4 * batch.len());
+    let mut g2 = Vec::<E::G2Prepared>::with_capacity(4 * batch.len());
+    for (vk, vk_commitment, proof, proof_commitment, inputs) in batch {
+        let proof: ark_groth16::Proof<E> =
+            proof.try_into().map_err(|_| Groth16Error::InvalidProof)?;
+        let vk: ark_groth16::VerifyingKey<E> = vk
+            .try_into_ark_unchecked()
+            .map_err(|_| Groth16Error::InvalidVerificationKey)?;
+        let commitment = commitment::Commitment::<E>::try_new(vk_commitment, proof_commitment)?;
+        let mut inputs = inputs
+            .into_iter()
+            .map(|v| v.try_into_scalar::<E::ScalarField>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| Groth16Error::InvalidInput)?;
+        if let Some(commitment) = &commitment {
+            let input = commitment.input(&inputs)?;
+            inputs.push(input);
+        }
+        let pvk = prepare_verifying_key::<E>(&vk);
+        let mut prepared_inputs = ark_groth16::Groth16::<E>::prepare_inputs(&pvk, &inputs)
+            .map_err(|_| Groth16Error::VerifyError)?;
+        let r = E::ScalarField::rand(rng);
+        if let Some(commitment) = commitment {
+            prepared_inputs += commitment.point;
+            // e(s*C, g_sigma_neg) * e(s*pok, g) == 1
+            let s = E::ScalarField::rand(rng);
+            g1.push(E::G1Prepared::from(commitment.point * s));
+            g2.push(commitment.g_sigma_neg.into());
+            g1.push(E::G1Prepared::from(commitment.pok * s));
+            g2.push(commitment.g.into());
+        }
+        // e(r*A, B) * e(r*L, -gamma) * e(r*C, -delta) * e(-r*alpha, beta) == 1
+        g1.push(E::G1Prepared::from(proof.a * r));
+        g2.push(proof.b.into());
+        g1.push(E::G1Prepared::from(prepared_inputs * r));
+        g2.push(pvk.gamma_g2_neg_pc);
+        g1.push(E::G1Prepared::from(proof.c * r));
+        g2.push(pvk.delta_g2_neg_pc);
+        g1.push(E::G1Prepared::from(vk.alpha_g1 * (-r)));
+        g2.push(vk.beta_g2.into());
+    }
+    Ok(E::multi_pairing(g1, g2).is_zero())
+}
+
 /// Verify a groth16 verification key against the `E` elliptic curve.
 #[cfg(feature = "implementation")]
 pub fn validate_key<E: Pairing>(vk: VerificationKey) -> Result<(), Groth16Error> {
@@ -88,6 +216,22 @@ pub fn validate_key<E: Pairing>(vk: VerificationKey) -> Result<(), Groth16Error>
         .map_err(|_| Groth16Error::InvalidVerificationKey)
 }
 
+/// Verify a gnark groth16 verification key with a commitment key against the `E` elliptic curve.
+#[cfg(feature = "implementation")]
+pub fn validate_key_with_commitment<E: Pairing>(
+    vk: VerificationKey,
+    vk_commitment: VkCommitment,
+) -> Result<(), Groth16Error> {
+    // The last input is the commitment hash
+    let num_inputs = vk
+        .gamma_abc_g1
+        .len()
+        .checked_sub(2)
+        .ok_or(Groth16Error::InvalidVerificationKey)?;
+    validate_key::<E>(vk)?;
+    commitment::validate_key::<E>(vk_commitment, num_inputs)
+}
+
 #[cfg(test)]
 mod should {
     use core::marker::PhantomData;
@@ -104,6 +248,8 @@ mod should {
     #[rstest]
     #[case::bn254(PhantomData::<Bn254>)]
     #[case::bls12_381(PhantomData::<Bls12_381>)]
+    #[case::bls12_377(PhantomData::<Bls12_377>)]
+    #[case::bw6_761(PhantomData::<BW6_761>)]
     fn curves<P: Pairing>(#[case] _p: P) {}
 
     mod verify_proof {
@@ -190,6 +336,279 @@ mod should {
         }
     }
 
+    mod verify_batch {
+        use super::*;
+
+        fn batch<E: Pairing>(seeds: &[u64]) -> Vec<(VerificationKey, Proof, Vec<Scalar>)> {
+            seeds
+                .iter()
+                .map(|&seed| {
+                    let (proof, vk, inputs) =
+                        dummy_circuit::get_instance::<E>(seed as usize, Some(seed));
+                    (vk, proof, inputs)
+                })
+                .collect()
+        }
+
+        #[apply(curves)]
+        fn succeed<E: Pairing>(#[case] _p: PhantomData<E>) {
+            assert!(verify_batch::<E>(batch::<E>(&[1, 3, 7, 3])).unwrap())
+        }
+
+        #[apply(curves)]
+        fn succeed_on_empty_batch<E: Pairing>(#[case] _p: PhantomData<E>) {
+            assert!(verify_batch::<E>(Vec::new()).unwrap())
+        }
+
+        #[apply(curves)]
+        fn fail_if_a_proof_is_not_valid<E: Pairing>(#[case] _p: PhantomData<E>) {
+            let mut batch = batch::<E>(&[1, 3, 7]);
+            let (other, _, _) = dummy_circuit::get_instance::<E>(3, Some(42));
+            batch[1].1 = other;
+
+            assert!(!verify_batch::<E>(batch).unwrap())
+        }
+
+        #[apply(curves)]
+        fn fail_if_proofs_are_swapped<E: Pairing>(#[case] _p: PhantomData<E>) {
+            let mut batch = batch::<E>(&[1, 2]);
+            let first = batch[0].1.clone();
+            batch[0].1 = batch[1].1.clone();
+            batch[1].1 = first;
+
+            assert!(!verify_batch::<E>(batch).unwrap())
+        }
+
+        #[apply(curves)]
+        fn fail_with_malformed_proof<E: Pairing>(#[case] _p: PhantomData<E>) {
+            let mut batch = batch::<E>(&[1, 3]);
+            batch[1].1.a.0[0] += 1;
+
+            assert_eq!(
+                verify_batch::<E>(batch).err().unwrap(),
+                Groth16Error::InvalidProof
+            )
+        }
+
+        #[apply(curves)]
+        fn fail_with_wrong_number_of_inputs<E: Pairing>(#[case] _p: PhantomData<E>) {
+            let mut batch = batch::<E>(&[1, 3]);
+            batch[1].2.pop();
+
+            assert_eq!(
+                verify_batch::<E>(batch).err().unwrap(),
+                Groth16Error::VerifyError
+            )
+        }
+    }
+
+    mod verify_proof_with_commitment {
+        use super::*;
+
+        #[apply(curves)]
+        fn succeed<E: Pairing>(#[case] _p: PhantomData<E>) {
+            let (proof, proof_commitment, vk, vk_commitment, inputs) =
+                dummy_circuit::get_instance_with_commitment::<E>(5, None);
+
+            assert!(verify_proof_with_commitment::<E>(
+                vk,
+                vk_commitment,
+                proof,
+                proof_commitment,
+                &inputs
+            )
+            .unwrap())
+        }
+
+        #[rstest]
+        #[case::committed_input(0)]
+        #[case::not_committed_input(1)]
+        fn fail_with_wrong_inputs(#[case] wrong: usize) {
+            let (proof, proof_commitment, vk, vk_commitment, mut inputs) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(5, None);
+            inputs[wrong] = Scalar::try_from_scalar(ark_bn254::Fr::one()).unwrap();
+
+            assert!(!verify_proof_with_commitment::<Bn254>(
+                vk,
+                vk_commitment,
+                proof,
+                proof_commitment,
+                &inputs
+            )
+            .unwrap())
+        }
+
+        #[test]
+        fn fail_with_wrong_commitment() {
+            let (proof, mut proof_commitment, vk, vk_commitment, inputs) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(5, Some(0));
+            let (_, other, _, _, _) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(5, Some(42));
+            proof_commitment.commitment = other.commitment;
+
+            assert!(!verify_proof_with_commitment::<Bn254>(
+                vk,
+                vk_commitment,
+                proof,
+                proof_commitment,
+                &inputs
+            )
+            .unwrap())
+        }
+
+        #[test]
+        fn fail_with_wrong_proof_of_knowledge() {
+            let (proof, mut proof_commitment, vk, vk_commitment, inputs) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(5, None);
+            proof_commitment.pok = proof_commitment.commitment.clone();
+
+            assert!(!verify_proof_with_commitment::<Bn254>(
+                vk,
+                vk_commitment,
+                proof,
+                proof_commitment,
+                &inputs
+            )
+            .unwrap())
+        }
+
+        #[test]
+        fn fail_with_wrong_committed_inputs() {
+            let (proof, proof_commitment, vk, mut vk_commitment, inputs) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(5, None);
+            vk_commitment.public_committed = vec![2];
+
+            assert!(!verify_proof_with_commitment::<Bn254>(
+                vk,
+                vk_commitment,
+                proof,
+                proof_commitment,
+                &inputs
+            )
+            .unwrap())
+        }
+
+        #[rstest]
+        #[case::zero(0)]
+        #[case::too_big(6)]
+        fn fail_with_committed_input_out_of_range(#[case] index: u32) {
+            let (proof, proof_commitment, vk, mut vk_commitment, inputs) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(5, None);
+            vk_commitment.public_committed.push(index);
+
+            assert_eq!(
+                verify_proof_with_commitment::<Bn254>(
+                    vk,
+                    vk_commitment,
+                    proof,
+                    proof_commitment,
+                    &inputs
+                )
+                .err()
+                .unwrap(),
+                Groth16Error::InvalidVerificationKey
+            )
+        }
+
+        #[test]
+        fn fail_with_malformed_commitment() {
+            let (proof, mut proof_commitment, vk, vk_commitment, inputs) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(5, None);
+            proof_commitment.commitment.0[0] += 1;
+
+            assert_eq!(
+                verify_proof_with_commitment::<Bn254>(
+                    vk,
+                    vk_commitment,
+                    proof,
+                    proof_commitment,
+                    &inputs
+                )
+                .err()
+                .unwrap(),
+                Groth16Error::InvalidProof
+            )
+        }
+
+        #[test]
+        fn fail_with_identity_commitment() {
+            let (proof, mut proof_commitment, vk, vk_commitment, inputs) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(5, None);
+            proof_commitment.commitment =
+                G1::try_from_affine(ark_bn254::G1Affine::identity()).unwrap();
+            proof_commitment.pok = proof_commitment.commitment.clone();
+
+            assert_eq!(
+                verify_proof_with_commitment::<Bn254>(
+                    vk,
+                    vk_commitment,
+                    proof,
+                    proof_commitment,
+                    &inputs
+                )
+                .err()
+                .unwrap(),
+                Groth16Error::InvalidProof
+            )
+        }
+    }
+
+    mod verify_batch_with_commitments {
+        use super::*;
+
+        fn batch<E: Pairing>(seeds: &[u64]) -> Vec<CommittedBatchItem> {
+            seeds
+                .iter()
+                .map(|&seed| {
+                    if seed % 2 == 0 {
+                        let (proof, proof_commitment, vk, vk_commitment, inputs) =
+                            dummy_circuit::get_instance_with_commitment::<E>(
+                                seed as usize,
+                                Some(seed),
+                            );
+                        (
+                            vk,
+                            Some(vk_commitment),
+                            proof,
+                            Some(proof_commitment),
+                            inputs,
+                        )
+                    } else {
+                        let (proof, vk, inputs) =
+                            dummy_circuit::get_instance::<E>(seed as usize, Some(seed));
+                        (vk, None, proof, None, inputs)
+                    }
+                })
+                .collect()
+        }
+
+        #[apply(curves)]
+        fn succeed_with_proofs_with_and_without_commitment<E: Pairing>(#[case] _p: PhantomData<E>) {
+            assert!(verify_batch_with_commitments::<E>(batch::<E>(&[1, 2, 3, 4])).unwrap())
+        }
+
+        #[test]
+        fn fail_if_a_commitment_is_not_valid() {
+            let mut batch = batch::<Bn254>(&[1, 2, 3, 4]);
+            batch[3].3 = batch[1].3.clone();
+
+            assert!(!verify_batch_with_commitments::<Bn254>(batch).unwrap())
+        }
+
+        #[rstest]
+        #[case::missed_proof_commitment(1)]
+        #[case::unexpected_proof_commitment(2)]
+        fn fail_if_commitment_and_key_mismatch(#[case] index: usize) {
+            let mut batch = batch::<Bn254>(&[1, 2, 3, 4]);
+            batch[index].3 = batch[(index + 1) % 4].3.clone();
+
+            assert_eq!(
+                verify_batch_with_commitments::<Bn254>(batch).err().unwrap(),
+                Groth16Error::InvalidProof
+            )
+        }
+    }
+
     mod validate_key {
         use super::*;
 
@@ -211,4 +630,66 @@ mod should {
             );
         }
     }
+
+    mod validate_key_with_commitment {
+        use super::*;
+
+        #[apply(curves)]
+        fn accept_valid_vk<E: Pairing>(#[case] _p: PhantomData<E>) {
+            let (_, _, vk, vk_commitment, _) =
+                dummy_circuit::get_instance_with_commitment::<E>(3, Some(0));
+
+            assert!(validate_key_with_commitment::<E>(vk, vk_commitment).is_ok());
+        }
+
+        #[test]
+        fn reject_malformed_commitment_key() {
+            let (_, _, vk, mut vk_commitment, _) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(3, Some(0));
+            vk_commitment.g_sigma_neg.0[0] += 1;
+
+            assert_eq!(
+                validate_key_with_commitment::<Bn254>(vk, vk_commitment),
+                Err(Groth16Error::InvalidVerificationKey)
+            );
+        }
+
+        #[rstest]
+        #[case::zero(0)]
+        #[case::too_big(4)]
+        fn reject_committed_input_out_of_range(#[case] index: u32) {
+            let (_, _, vk, mut vk_commitment, _) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(3, Some(0));
+            vk_commitment.public_committed.push(index);
+
+            assert_eq!(
+                validate_key_with_commitment::<Bn254>(vk, vk_commitment),
+                Err(Groth16Error::InvalidVerificationKey)
+            );
+        }
+
+        #[test]
+        fn reject_repeated_committed_input() {
+            let (_, _, vk, mut vk_commitment, _) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(3, Some(0));
+            vk_commitment.public_committed = vec![1, 1];
+
+            assert_eq!(
+                validate_key_with_commitment::<Bn254>(vk, vk_commitment),
+                Err(Groth16Error::InvalidVerificationKey)
+            );
+        }
+
+        #[test]
+        fn reject_vk_without_room_for_the_commitment_hash() {
+            let (_, _, mut vk, vk_commitment, _) =
+                dummy_circuit::get_instance_with_commitment::<Bn254>(0, Some(0));
+            vk.gamma_abc_g1.pop();
+
+            assert_eq!(
+                validate_key_with_commitment::<Bn254>(vk, vk_commitment),
+                Err(Groth16Error::InvalidVerificationKey)
+            );
+        }
+    }
 }
@@ -15,7 +15,11 @@
 
 #![no_std]
 
+use core::marker::PhantomData;
+
 use sp_core::H256;
+use sp_runtime::DispatchError;
+use sp_weights::Weight;
 
 /// Trait used by proof verifier pallets (e.g. pallet-settlement-fflonk) to signal that a successful proof verification
 /// happened.
@@ -24,3 +28,44 @@ use sp_core::H256;
 pub trait OnProofVerified<A> {
     fn on_proof_verified(account: Option<A>, domain_id: Option<u32>, pubs_hash: H256);
 }
+
+/// What a verifier pallet knows about a proof it has just checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofContext<A> {
+    /// The account that submitted the proof, if any.
+    pub account: Option<A>,
+    /// The domain where the statement should be aggregated, if any.
+    pub domain_id: Option<u32>,
+    /// The hash context data of the verifier that checked the proof.
+    pub verifier: &'static [u8],
+    /// The hash of the verification key used to check the proof.
+    pub vk_hash: H256,
+    /// The proof statement hash.
+    pub statement: H256,
+    /// The weight charged for the proof verification.
+    pub weight: Weight,
+}
+
+/// Second version of `OnProofVerified`: the subscribers receive the whole `ProofContext` instead
+/// of just the submitter, the domain and the statement hash.
+/// Use `LegacyOnProofVerified` to plug in the `OnProofVerified` implementations.
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+pub trait OnProofVerifiedV2<A> {
+    fn on_proof_verified(ctx: &ProofContext<A>);
+}
+
+/// Trait used by proof verifier pallets to signal that a proof has been rejected without
+/// failing the whole call (e.g. in a best effort batch).
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+pub trait OnProofRejected<A> {
+    fn on_proof_rejected(ctx: &ProofContext<A>, error: &DispatchError);
+}
+
+/// Adapt an `OnProofVerified` implementation (or a tuple of them) to `OnProofVerifiedV2`.
+pub struct LegacyOnProofVerified<T>(PhantomData<T>);
+
+impl<A: Clone, T: OnProofVerified<A>> OnProofVerifiedV2<A> for LegacyOnProofVerified<T> {
+    fn on_proof_verified(ctx: &ProofContext<A>) {
+        T::on_proof_verified(ctx.account.clone(), ctx.domain_id, ctx.statement)
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(missing_docs)]
+
+//! Provide a base interface and the std lib implementation for the snarkjs plonk verifier on
+//! the bn254 curve via arkworks library.
+//!
+//! All the field elements are encoded as 32 bytes big endian integers and the points by their
+//! affine coordinates, the same layout used by the snarkjs solidity verifier.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime_interface::pass_by::PassByCodec;
+
+#[cfg(feature = "implementation")]
+mod verifier;
+
+#[cfg(feature = "implementation")]
+pub use verifier::{validate_key, verify};
+
+/// Size in bytes of an encoded scalar field element.
+pub const FR_SIZE: usize = 32;
+/// Size in bytes of an encoded G1 point: `x` and `y`.
+pub const G1_SIZE: usize = 64;
+/// Size in bytes of an encoded G2 point: `x.c0`, `x.c1`, `y.c0` and `y.c1`.
+pub const G2_SIZE: usize = 128;
+/// Size in bytes of an encoded proof: the `A`, `B`, `C`, `Z`, `T1`, `T2`, `T3`, `Wxi` and
+/// `Wxiw` commitments followed by the `a`, `b`, `c`, `s1`, `s2` and `zw` evaluations.
+pub const PROOF_SIZE: usize = 9 * G1_SIZE + 6 * FR_SIZE;
+/// The largest domain size exponent supported by the bn254 scalar field.
+pub const MAX_POWER: u8 = 28;
+
+/// A scalar field element.
+pub type Fr = [u8; FR_SIZE];
+/// A G1 point; the point at infinity is encoded as all zeros.
+pub type G1 = [u8; G1_SIZE];
+/// A G2 point; the point at infinity is encoded as all zeros.
+pub type G2 = [u8; G2_SIZE];
+/// A plonk proof.
+pub type Proof = [u8; PROOF_SIZE];
+
+/// The snarkjs plonk verification key.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen, PassByCodec)]
+pub struct VerificationKey {
+    /// The domain size is `2^power`.
+    pub power: u8,
+    /// Number of public inputs.
+    pub n_public: u32,
+    /// Coset shift of the `b` wire.
+    pub k1: Fr,
+    /// Coset shift of the `c` wire.
+    pub k2: Fr,
+    /// Generator of the `2^power` roots of unity.
+    pub w: Fr,
+    /// Multiplication selector commitment.
+    pub qm: G1,
+    /// Left selector commitment.
+    pub ql: G1,
+    /// Right selector commitment.
+    pub qr: G1,
+    /// Output selector commitment.
+    pub qo: G1,
+    /// Constant selector commitment.
+    pub qc: G1,
+    /// First permutation commitment.
+    pub s1: G1,
+    /// Second permutation commitment.
+    pub s2: G1,
+    /// Third permutation commitment.
+    pub s3: G1,
+    /// `[tau]_2` from the trusted setup.
+    pub x2: G2,
+}
+
+/// Errors that can occur during plonk verification.
+#[derive(Debug, PartialEq)]
+pub enum PlonkError {
+    /// Invalid proof provided.
+    InvalidProof,
+    /// Invalid verification key provided.
+    InvalidVerificationKey,
+    /// Invalid input provided.
+    InvalidInput,
+    /// Verification failed.
+    VerifyError,
+}
+
+impl From<PlonkError> for hp_verifiers::VerifyError {
+    fn from(error: PlonkError) -> Self {
+        match error {
+            PlonkError::InvalidProof => hp_verifiers::VerifyError::InvalidProofData,
+            PlonkError::InvalidVerificationKey => hp_verifiers::VerifyError::InvalidVerificationKey,
+            PlonkError::InvalidInput => hp_verifiers::VerifyError::InvalidInput,
+            PlonkError::VerifyError => hp_verifiers::VerifyError::VerifyError,
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    include!("resources.rs");
+
+    #[test]
+    fn verify_valid_proof() {
+        assert_eq!(verify(&valid_vk(), &VALID_PROOF, &VALID_PUBS), Ok(()));
+    }
+
+    #[test]
+    fn validate_valid_key() {
+        assert_eq!(validate_key(&valid_vk()), Ok(()));
+    }
+
+    mod reject {
+        use super::*;
+
+        #[test]
+        fn wrong_public_inputs() {
+            let mut pubs = VALID_PUBS;
+            pubs[0][FR_SIZE - 1] ^= 1;
+
+            assert_eq!(
+                verify(&valid_vk(), &VALID_PROOF, &pubs),
+                Err(PlonkError::VerifyError)
+            );
+        }
+
+        #[test]
+        fn wrong_number_of_public_inputs() {
+            assert_eq!(
+                verify(&valid_vk(), &VALID_PROOF, &VALID_PUBS[..1]),
+                Err(PlonkError::InvalidInput)
+            );
+        }
+
+        #[test]
+        fn not_canonical_public_inputs() {
+            let mut pubs = VALID_PUBS;
+            pubs[0] = [0xff; FR_SIZE];
+
+            assert_eq!(
+                verify(&valid_vk(), &VALID_PROOF, &pubs),
+                Err(PlonkError::InvalidInput)
+            );
+        }
+
+        #[test]
+        fn wrong_evaluations() {
+            let mut proof = VALID_PROOF;
+            proof[PROOF_SIZE - 1] ^= 1;
+
+            assert_eq!(
+                verify(&valid_vk(), &proof, &VALID_PUBS),
+                Err(PlonkError::VerifyError)
+            );
+        }
+
+        #[test]
+        fn commitments_not_on_curve() {
+            let mut proof = VALID_PROOF;
+            proof[G1_SIZE - 1] ^= 1;
+
+            assert_eq!(
+                verify(&valid_vk(), &proof, &VALID_PUBS),
+                Err(PlonkError::InvalidProof)
+            );
+        }
+
+        #[test]
+        fn key_with_wrong_root_of_unity() {
+            let mut vk = valid_vk();
+            vk.power += 1;
+
+            assert_eq!(validate_key(&vk), Err(PlonkError::InvalidVerificationKey));
+            assert_eq!(
+                verify(&vk, &VALID_PROOF, &VALID_PUBS),
+                Err(PlonkError::InvalidVerificationKey)
+            );
+        }
+
+        #[test]
+        fn key_with_too_large_domain() {
+            let mut vk = valid_vk();
+            vk.power = MAX_POWER + 1;
+
+            assert_eq!(validate_key(&vk), Err(PlonkError::InvalidVerificationKey));
+        }
+
+        #[test]
+        fn key_with_invalid_points() {
+            let mut vk = valid_vk();
+            vk.x2[G2_SIZE - 1] ^= 1;
+
+            assert_eq!(validate_key(&vk), Err(PlonkError::InvalidVerificationKey));
+        }
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[allow(dead_code)]
+fn valid_vk() -> crate::VerificationKey {
+    crate::VerificationKey {
+        power: 3,
+        n_public: 2,
+        k1: hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+        k2: hex_literal::hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+        w: hex_literal::hex!("2b337de1c8c14f22ec9b9e2f96afef3652627366f8170a0a948dad4ac1bd5e80"),
+        qm: hex_literal::hex!(
+            "
+            0633f984cd2e68a741b4211e0677f95184c22e2e5c34cea53d1fe082f5853754
+            2c2f5ecb03608179a12ec743cd02436ffa6a30c489a452ab42131b4a16fd5664
+        "
+        ),
+        ql: hex_literal::hex!(
+            "
+            0063fb071ae205ad3a8902151e0c61c998e9ffc7e9d7e56507b8e9e75d4b057c
+            0172672c08ff33a25656a4850783d21c04b07a7406ef72e47f95c0a8257e379e
+        "
+        ),
+        qr: hex_literal::hex!(
+            "
+            0000000000000000000000000000000000000000000000000000000000000000
+            0000000000000000000000000000000000000000000000000000000000000000
+        "
+        ),
+        qo: hex_literal::hex!(
+            "
+            0633f984cd2e68a741b4211e0677f95184c22e2e5c34cea53d1fe082f5853754
+            0434efa7ddd11eb017217e72b47f14ed9d1739ccdecd77e1fa0d70ccc17fa6e3
+        "
+        ),
+        qc: hex_literal::hex!(
+            "
+            0000000000000000000000000000000000000000000000000000000000000000
+            0000000000000000000000000000000000000000000000000000000000000000
+        "
+        ),
+        s1: hex_literal::hex!(
+            "
+            2e855c958697982e896e1a6b20824ccd5134db9a46ba6f4c0dc3b42a645cade3
+            19d5b7581e61430a7eae50eda5190699f645afcef18205e131048efc1272bb55
+        "
+        ),
+        s2: hex_literal::hex!(
+            "
+            2c8b7fbc9c7d813368903e274acf8c67f71a3b93bb13084f97af707474ea5d6d
+            1f49cb170bfb50203a4246ff42f68f69e7e180755b779504374794e06340a98b
+        "
+        ),
+        s3: hex_literal::hex!(
+            "
+            08cdc2cd12e642b57d62cfe1a8ca4b060af6beef444783f2e2843393707ecf41
+            032519a3255afffc9a4be52afb9c1acfdb90f744a742e13a21c452abe8974361
+        "
+        ),
+        x2: hex_literal::hex!(
+            "
+            2b83dffaf1591826a3461ad0e4308cb77e451046b758eb06b807a7285b5fbed4
+            11c3ccfe897d28d06c5dcb48bab5b33658091689122ac0017bfe1b7e55ca430d
+            1a122ede0721661a080007f4064712e1948f8ac3f7abb9f7fcb7e488f21ad935
+            05876df32b44bf432fa5209aa620e0d7d53f53551e42f05af502d057d82c7fe4
+        "
+        ),
+    }
+}
+
+#[allow(dead_code)]
+static VALID_PROOF: crate::Proof = hex_literal::hex!(
+    "
+        1a4b4469b44f20c33872f7bdc2750e06598e1c697cc778fc1129867484655ea1
+        16ffce19653e1958a5e9df6672963ed166578d6f98d2f6f8fdbb1ab65727b159
+        15870949cae1f7fdb5d1490aa32e7664410b1cbcaadf99cbbfc08a38bcd5ec5a
+        1d336712fd67ad7932209b858625d3c62bc6921812788b0791e59415d2581dd7
+        023f5dc83b82176c38e0ccb5c72e9c71afcd65ba6f354c751cd15f6ae93f5dc5
+        2058638cf9349bf5032c215a83c16e3671b38ea4765a11dbf091a7d54a0870c9
+        0dce8438f8208b441aa046497cb4914f23e99c066e14659362d46d46fadd47cf
+        1df4958d472673f01fbfb9d08e2caaa19d0b4fd78c2cec0268d43a13efe26af6
+        1c570d97ab7dc97d0197e036f666efd9179842da418afb44ed4c6cfb5a57422b
+        118b0fb817c669217af3996f5b6220e465117122fb965d093818eebf2828b8d7
+        207bde7b938b05090e23e645eb7ae5728d417b667a1a01295637b508f86794f1
+        0a382d7a24cd4b784a5cefa8caa52db2e4070f16d7db61ae65bcfabe208d73fa
+        05fb1b672adfd479fe5df6736f0ce36dfaf1d4c2bd765dc3cc36b0b0a575a723
+        1435169c526393b1f445acd88ff24158dae3338e3d34db58756b9a4e960d4a21
+        01ac71918193bba07f4e07ced1689d51148ce004da1b7414761a62f17e957438
+        1d516e8d948412a05ba90809c7c2ef088a6598dd6e1c67b40f35f957567886dd
+        116daa1fe14b9ce990e343089fd144b1962cc2c6d7d4e4f38071a49a8b3edd45
+        149ef192b21e0a44236ed91f1374b881d30e5a68a7936e874388b3b802edb459
+        1eda16cd5bb78c495a8a6d6db023c5ca01003131d6d89ce07888b373f73077f6
+        06a7223f13c126db32c1681432bca286ee4f0d1e3465ae6125521bdef2abe69f
+        1f9397e0c4cd40f927bc639569cae5dbb34185bb718a01981ed91b0e6276bb40
+        113f85663621c2fe046e4f2f1a0afb01a9c9dd8ed3a135b82a9d870479033dad
+        1df8e298fd8190e00509fa6bc515aa5d33bb0215ac91ba8d23d4d9db73e1100e
+        13e4a24c9f267e485092444826b6a077483991d077354b292a5f374dcf6d857d
+    "
+);
+
+#[allow(dead_code)]
+static VALID_PUBS: [crate::Fr; 2] = [
+    hex_literal::hex!("1b5c55b407959beed51cf3824c0fc056da16bde54553ce7665edc8115eeb28b8"),
+    hex_literal::hex!("15bb0e36c31aa68f4bba42b7f4d96c8a0bc1f2a3d0f7401ab9a74811d842456a"),
+];
@@ -0,0 +1,243 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The snarkjs plonk verifier: it follows step by step the `plonk_verify` procedure of snarkjs
+//! (and its solidity template), Keccak256 transcript included.
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr as Scalar, G1Affine, G1Projective, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
+use sha3::{Digest, Keccak256};
+use sp_std::vec::Vec;
+
+use crate::{Fr, PlonkError, Proof, VerificationKey, FR_SIZE, G1_SIZE, MAX_POWER};
+
+/// Decode a big endian field element, rejecting the non canonical encodings.
+fn field<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    let value = F::from_be_bytes_mod_order(bytes);
+    (value.into_bigint().to_bytes_be() == bytes).then_some(value)
+}
+
+fn g1(bytes: &[u8]) -> Option<G1Affine> {
+    if bytes.iter().all(|b| *b == 0) {
+        return Some(G1Affine::zero());
+    }
+    let (x, y) = bytes.split_at(FR_SIZE);
+    let point = G1Affine::new_unchecked(field(x)?, field(y)?);
+    // The bn254 G1 cofactor is 1: every point on the curve is in the right subgroup.
+    point.is_on_curve().then_some(point)
+}
+
+fn g2(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.iter().all(|b| *b == 0) {
+        return Some(G2Affine::zero());
+    }
+    let fq = |i: usize| field::<Fq>(&bytes[i * FR_SIZE..(i + 1) * FR_SIZE]);
+    let point = G2Affine::new_unchecked(Fq2::new(fq(0)?, fq(1)?), Fq2::new(fq(2)?, fq(3)?));
+    (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+}
+
+struct Key {
+    power: u8,
+    k1: Scalar,
+    k2: Scalar,
+    w: Scalar,
+    qm: G1Affine,
+    ql: G1Affine,
+    qr: G1Affine,
+    qo: G1Affine,
+    qc: G1Affine,
+    s1: G1Affine,
+    s2: G1Affine,
+    s3: G1Affine,
+    x2: G2Affine,
+}
+
+impl TryFrom<&VerificationKey> for Key {
+    type Error = PlonkError;
+
+    fn try_from(vk: &VerificationKey) -> Result<Self, Self::Error> {
+        let err = || PlonkError::InvalidVerificationKey;
+        if vk.power == 0 || vk.power > MAX_POWER {
+            return Err(err());
+        }
+        let w = field::<Scalar>(&vk.w).ok_or_else(err)?;
+        // `w` should be a primitive `2^power` root of unity, that is `w^(2^(power - 1)) = -1`.
+        if (1..vk.power).fold(w, |acc, _| acc.square()) != -Scalar::one() {
+            return Err(err());
+        }
+        Ok(Self {
+            power: vk.power,
+            k1: field(&vk.k1).ok_or_else(err)?,
+            k2: field(&vk.k2).ok_or_else(err)?,
+            w,
+            qm: g1(&vk.qm).ok_or_else(err)?,
+            ql: g1(&vk.ql).ok_or_else(err)?,
+            qr: g1(&vk.qr).ok_or_else(err)?,
+            qo: g1(&vk.qo).ok_or_else(err)?,
+            qc: g1(&vk.qc).ok_or_else(err)?,
+            s1: g1(&vk.s1).ok_or_else(err)?,
+            s2: g1(&vk.s2).ok_or_else(err)?,
+            s3: g1(&vk.s3).ok_or_else(err)?,
+            x2: g2(&vk.x2).ok_or_else(err)?,
+        })
+    }
+}
+
+/// Check that the verification key is well formed.
+pub fn validate_key(vk: &VerificationKey) -> Result<(), PlonkError> {
+    Key::try_from(vk).map(|_| ())
+}
+
+fn challenge<'a>(data: impl IntoIterator<Item = &'a [u8]>) -> Scalar {
+    let mut hasher = Keccak256::new();
+    data.into_iter().for_each(|chunk| hasher.update(chunk));
+    Scalar::from_be_bytes_mod_order(&hasher.finalize())
+}
+
+fn encode(value: Scalar) -> Vec<u8> {
+    value.into_bigint().to_bytes_be()
+}
+
+/// Verify a snarkjs plonk proof with the given verification key and public inputs.
+pub fn verify(vk: &VerificationKey, proof: &Proof, pubs: &[Fr]) -> Result<(), PlonkError> {
+    let key = Key::try_from(vk)?;
+    if pubs.len() != vk.n_public as usize {
+        return Err(PlonkError::InvalidInput);
+    }
+    let inputs = pubs
+        .iter()
+        .map(|input| field::<Scalar>(input))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(PlonkError::InvalidInput)?;
+
+    let (commitments, evaluations) = proof.split_at(9 * G1_SIZE);
+    let points = commitments
+        .chunks_exact(G1_SIZE)
+        .map(g1)
+        .collect::<Option<Vec<_>>>()
+        .ok_or(PlonkError::InvalidProof)?;
+    let [a, b, c, z, t1, t2, t3, wxi, wxiw] = points[..] else {
+        unreachable!("Proof contains exactly 9 commitments. qed")
+    };
+    let evals = evaluations
+        .chunks_exact(FR_SIZE)
+        .map(field::<Scalar>)
+        .collect::<Option<Vec<_>>>()
+        .ok_or(PlonkError::InvalidProof)?;
+    let [eval_a, eval_b, eval_c, eval_s1, eval_s2, eval_zw] = evals[..] else {
+        unreachable!("Proof contains exactly 6 evaluations. qed")
+    };
+
+    // Fiat-Shamir challenges.
+    let vk_points = [
+        &vk.qm, &vk.ql, &vk.qr, &vk.qo, &vk.qc, &vk.s1, &vk.s2, &vk.s3,
+    ];
+    let beta = challenge(
+        vk_points
+            .into_iter()
+            .map(|point| &point[..])
+            .chain(pubs.iter().map(|input| &input[..]))
+            .chain([&commitments[..3 * G1_SIZE]]),
+    );
+    let gamma = challenge([&encode(beta)[..]]);
+    let alpha = challenge([
+        &encode(beta)[..],
+        &encode(gamma),
+        &commitments[3 * G1_SIZE..4 * G1_SIZE],
+    ]);
+    let xi = challenge([&encode(alpha)[..], &commitments[4 * G1_SIZE..7 * G1_SIZE]]);
+    let v1 = challenge([&encode(xi)[..], evaluations]);
+    let u = challenge([&commitments[7 * G1_SIZE..]]);
+    let mut v = [v1; 5];
+    for i in 1..v.len() {
+        v[i] = v[i - 1] * v1;
+    }
+
+    // Vanishing polynomial and Lagrange polynomials of the public inputs rows evaluated at xi.
+    let n = Scalar::from(1_u64 << key.power);
+    let xin = (0..key.power).fold(xi, |acc, _| acc.square());
+    let zh = xin - Scalar::one();
+    let mut lagrange = Vec::with_capacity(inputs.len().max(1));
+    let mut w = Scalar::one();
+    for _ in 0..inputs.len().max(1) {
+        let den = (n * (xi - w)).inverse().ok_or(PlonkError::VerifyError)?;
+        lagrange.push(w * zh * den);
+        w *= key.w;
+    }
+    let pi = -inputs
+        .iter()
+        .zip(&lagrange)
+        .map(|(input, l)| *input * l)
+        .sum::<Scalar>();
+    let l1 = lagrange[0];
+    let alpha2 = alpha.square();
+
+    // Constant term of the linearization polynomial.
+    let perm_a = eval_a + beta * eval_s1 + gamma;
+    let perm_b = eval_b + beta * eval_s2 + gamma;
+    let r0 = pi - l1 * alpha2 - alpha * perm_a * perm_b * (eval_c + gamma) * eval_zw;
+
+    // [F] = [D] + v1 [A] + v2 [B] + v3 [C] + v4 [S1] + v5 [S2] where [D] is the commitment of
+    // the linearization polynomial (with the `u [Z]` term of the batched opening).
+    let z_coeff = (eval_a + beta * xi + gamma)
+        * (eval_b + beta * key.k1 * xi + gamma)
+        * (eval_c + beta * key.k2 * xi + gamma)
+        * alpha
+        + l1 * alpha2
+        + u;
+    let s3_coeff = perm_a * perm_b * alpha * beta * eval_zw;
+    let f = G1Projective::msm(
+        &[
+            key.qm, key.ql, key.qr, key.qo, key.qc, z, key.s3, t1, t2, t3, a, b, c, key.s1, key.s2,
+        ],
+        &[
+            eval_a * eval_b,
+            eval_a,
+            eval_b,
+            eval_c,
+            Scalar::one(),
+            z_coeff,
+            -s3_coeff,
+            -zh,
+            -zh * xin,
+            -zh * xin.square(),
+            v[0],
+            v[1],
+            v[2],
+            v[3],
+            v[4],
+        ],
+    )
+    .expect("Bases and scalars have the same length. qed");
+    let e = -r0
+        + v[0] * eval_a
+        + v[1] * eval_b
+        + v[2] * eval_c
+        + v[3] * eval_s1
+        + v[4] * eval_s2
+        + u * eval_zw;
+
+    // e(-[A1], [x]_2) * e([B1], [1]_2) == 1
+    let a1 = wxi + wxiw * u;
+    let b1 = wxi * xi + wxiw * (u * xi * key.w) + f - G1Affine::generator() * e;
+    Bn254::multi_pairing(
+        [(-a1).into_affine(), b1.into_affine()],
+        [key.x2, G2Affine::generator()],
+    )
+    .is_zero()
+    .then_some(())
+    .ok_or(PlonkError::VerifyError)
+}
@@ -0,0 +1,335 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Intel PCS JSON collateral: the TCB info (v3) and the quoting enclave identity (v2).
+
+use serde::Deserialize;
+use sp_std::vec::Vec;
+
+use crate::{der::unix_time, TcbStatus, TeeError};
+
+type Result<T> = core::result::Result<T, TeeError>;
+
+#[derive(Deserialize)]
+struct Svn {
+    svn: u8,
+}
+
+#[derive(Deserialize)]
+struct RawTcb {
+    sgxtcbcomponents: Vec<Svn>,
+    pcesvn: u16,
+    #[serde(default)]
+    tdxtcbcomponents: Vec<Svn>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTcbLevel {
+    tcb: RawTcb,
+    tcb_status: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTdxModule {
+    mrsigner: String,
+    attributes: String,
+    attributes_mask: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTcbInfo {
+    id: String,
+    version: u32,
+    issue_date: String,
+    next_update: String,
+    fmspc: String,
+    pce_id: String,
+    tdx_module: Option<RawTdxModule>,
+    tcb_levels: Vec<RawTcbLevel>,
+}
+
+#[derive(Deserialize)]
+struct RawQeTcb {
+    isvsvn: u16,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawQeTcbLevel {
+    tcb: RawQeTcb,
+    tcb_status: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawQeIdentity {
+    id: String,
+    version: u32,
+    issue_date: String,
+    next_update: String,
+    miscselect: String,
+    miscselect_mask: String,
+    attributes: String,
+    attributes_mask: String,
+    mrsigner: String,
+    isvprodid: u16,
+    tcb_levels: Vec<RawQeTcbLevel>,
+}
+
+/// The TEE described by a collateral.
+#[derive(Debug, PartialEq)]
+pub enum Tee {
+    Sgx,
+    Tdx,
+}
+
+pub struct TcbLevel {
+    pub sgx_components: [u8; 16],
+    pub pce_svn: u16,
+    pub tdx_components: Option<[u8; 16]>,
+    pub status: TcbStatus,
+}
+
+pub struct TdxModule {
+    pub mr_signer: [u8; 48],
+    pub attributes: [u8; 8],
+    pub attributes_mask: [u8; 8],
+}
+
+pub struct TcbInfo {
+    pub tee: Tee,
+    pub issue_date: u64,
+    pub next_update: u64,
+    pub fmspc: [u8; 6],
+    pub pce_id: [u8; 2],
+    pub tdx_module: Option<TdxModule>,
+    pub levels: Vec<TcbLevel>,
+}
+
+pub struct QeIdentity {
+    pub tee: Tee,
+    pub issue_date: u64,
+    pub next_update: u64,
+    pub misc_select: u32,
+    pub misc_select_mask: u32,
+    pub attributes: [u8; 16],
+    pub attributes_mask: [u8; 16],
+    pub mr_signer: [u8; 32],
+    pub isv_prod_id: u16,
+    /// The minimum ISV SVN of each level and its status.
+    pub levels: Vec<(u16, TcbStatus)>,
+}
+
+fn hex<const N: usize>(value: &str) -> Option<[u8; N]> {
+    let value = value.as_bytes();
+    if value.len() != 2 * N {
+        return None;
+    }
+    let mut out = [0; N];
+    for (byte, digits) in out.iter_mut().zip(value.chunks_exact(2)) {
+        let digits = core::str::from_utf8(digits).ok()?;
+        *byte = u8::from_str_radix(digits, 16).ok()?;
+    }
+    Some(out)
+}
+
+/// Parse an ISO 8601 UTC date: `YYYY-MM-DDThh:mm:ssZ`.
+fn date(value: &str) -> Option<u64> {
+    let value = value.as_bytes();
+    if value.len() != 20 || value[19] != b'Z' {
+        return None;
+    }
+    let field = |range: core::ops::Range<usize>| {
+        core::str::from_utf8(&value[range])
+            .ok()?
+            .parse::<u64>()
+            .ok()
+    };
+    let separators = [(4, b'-'), (7, b'-'), (10, b'T'), (13, b':'), (16, b':')];
+    if separators.iter().any(|(i, s)| value[*i] != *s) {
+        return None;
+    }
+    unix_time(
+        field(0..4)?,
+        field(5..7)?,
+        field(8..10)?,
+        field(11..13)?,
+        field(14..16)?,
+        field(17..19)?,
+    )
+}
+
+fn status(value: &str) -> Option<TcbStatus> {
+    Some(match value {
+        "UpToDate" => TcbStatus::UpToDate,
+        "SWHardeningNeeded" => TcbStatus::SWHardeningNeeded,
+        "ConfigurationNeeded" => TcbStatus::ConfigurationNeeded,
+        "ConfigurationAndSWHardeningNeeded" => TcbStatus::ConfigurationAndSWHardeningNeeded,
+        "OutOfDate" => TcbStatus::OutOfDate,
+        "OutOfDateConfigurationNeeded" => TcbStatus::OutOfDateConfigurationNeeded,
+        "Revoked" => TcbStatus::Revoked,
+        _ => return None,
+    })
+}
+
+fn components(svns: &[Svn]) -> Option<[u8; 16]> {
+    let svns = svns.iter().map(|c| c.svn).collect::<Vec<_>>();
+    svns.try_into().ok()
+}
+
+impl TcbInfo {
+    pub fn parse(json: &[u8]) -> Result<Self> {
+        let raw: RawTcbInfo =
+            serde_json::from_slice(json).map_err(|_| TeeError::InvalidCollateral)?;
+        Self::try_from_raw(raw).ok_or(TeeError::InvalidCollateral)
+    }
+
+    fn try_from_raw(raw: RawTcbInfo) -> Option<Self> {
+        let tee = match (raw.id.as_str(), raw.version) {
+            ("SGX", 3) => Tee::Sgx,
+            ("TDX", 3) => Tee::Tdx,
+            _ => return None,
+        };
+        let tdx_module = match raw.tdx_module {
+            Some(module) => Some(TdxModule {
+                mr_signer: hex(&module.mrsigner)?,
+                attributes: hex(&module.attributes)?,
+                attributes_mask: hex(&module.attributes_mask)?,
+            }),
+            None => None,
+        };
+        let levels = raw
+            .tcb_levels
+            .iter()
+            .map(|level| {
+                let tdx_components = match tee {
+                    Tee::Sgx => None,
+                    Tee::Tdx => Some(components(&level.tcb.tdxtcbcomponents)?),
+                };
+                Some(TcbLevel {
+                    sgx_components: components(&level.tcb.sgxtcbcomponents)?,
+                    pce_svn: level.tcb.pcesvn,
+                    tdx_components,
+                    status: status(&level.tcb_status)?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            tee,
+            issue_date: date(&raw.issue_date)?,
+            next_update: date(&raw.next_update)?,
+            fmspc: hex(&raw.fmspc)?,
+            pce_id: hex(&raw.pce_id)?,
+            tdx_module,
+            levels,
+        })
+    }
+
+    /// The status of the first level (the levels are sorted from the newest) not newer than
+    /// the platform one. The first two TDX components are skipped for the TDX modules newer
+    /// than 1.0 (`tee_tcb_svn[1] > 0`), as they are the module version and not a SVN.
+    pub fn status(
+        &self,
+        cpu_svn: &[u8; 16],
+        pce_svn: u16,
+        tee_tcb_svn: Option<&[u8; 16]>,
+    ) -> Option<TcbStatus> {
+        let not_newer = |level: &[u8; 16], platform: &[u8; 16], skip: usize| {
+            level
+                .iter()
+                .zip(platform)
+                .skip(skip)
+                .all(|(level, platform)| level <= platform)
+        };
+        self.levels
+            .iter()
+            .find(|level| {
+                not_newer(&level.sgx_components, cpu_svn, 0)
+                    && level.pce_svn <= pce_svn
+                    && match (&level.tdx_components, tee_tcb_svn) {
+                        (None, None) => true,
+                        (Some(level), Some(svn)) => {
+                            not_newer(level, svn, if svn[1] > 0 { 2 } else { 0 })
+                        }
+                        _ => false,
+                    }
+            })
+            .map(|level| level.status)
+    }
+}
+
+impl QeIdentity {
+    pub fn parse(json: &[u8]) -> Result<Self> {
+        let raw: RawQeIdentity =
+            serde_json::from_slice(json).map_err(|_| TeeError::InvalidCollateral)?;
+        Self::try_from_raw(raw).ok_or(TeeError::InvalidCollateral)
+    }
+
+    fn try_from_raw(raw: RawQeIdentity) -> Option<Self> {
+        let tee = match (raw.id.as_str(), raw.version) {
+            ("QE", 2) => Tee::Sgx,
+            ("TD_QE", 2) => Tee::Tdx,
+            _ => return None,
+        };
+        let levels = raw
+            .tcb_levels
+            .iter()
+            .map(|level| Some((level.tcb.isvsvn, status(&level.tcb_status)?)))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            tee,
+            issue_date: date(&raw.issue_date)?,
+            next_update: date(&raw.next_update)?,
+            misc_select: u32::from_be_bytes(hex(&raw.miscselect)?),
+            misc_select_mask: u32::from_be_bytes(hex(&raw.miscselect_mask)?),
+            attributes: hex(&raw.attributes)?,
+            attributes_mask: hex(&raw.attributes_mask)?,
+            mr_signer: hex(&raw.mrsigner)?,
+            isv_prod_id: raw.isvprodid,
+            levels,
+        })
+    }
+
+    /// The status of the first level not newer than the given QE ISV SVN.
+    pub fn status(&self, isv_svn: u16) -> Option<TcbStatus> {
+        self.levels
+            .iter()
+            .find(|(min_svn, _)| *min_svn <= isv_svn)
+            .map(|(_, status)| *status)
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[test]
+    fn parse_iso_dates() {
+        assert_eq!(date("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(date("2024-02-29T12:34:56Z"), Some(1_709_210_096));
+        assert_eq!(date("2024-02-29 12:34:56Z"), None);
+        assert_eq!(date("2024-13-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn parse_hex_strings() {
+        assert_eq!(hex::<2>("0aFf"), Some([0x0a, 0xff]));
+        assert_eq!(hex::<2>("0aF"), None);
+        assert_eq!(hex::<2>("0aFg"), None);
+    }
+}
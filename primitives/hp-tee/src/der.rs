@@ -0,0 +1,473 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal DER reader of the X.509 certificates and CRLs of the Intel PKI: just the fields
+//! needed to check the chains, all signed with ECDSA P-256 and SHA-256.
+
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
+use sp_std::vec::Vec;
+
+use crate::{Fingerprint, TeeError};
+
+const BOOLEAN: u8 = 0x01;
+const INTEGER: u8 = 0x02;
+const BIT_STRING: u8 = 0x03;
+const OCTET_STRING: u8 = 0x04;
+const OID: u8 = 0x06;
+const UTC_TIME: u8 = 0x17;
+const GENERALIZED_TIME: u8 = 0x18;
+const SEQUENCE: u8 = 0x30;
+const EXPLICIT_0: u8 = 0xa0;
+const EXPLICIT_3: u8 = 0xa3;
+
+/// `ecdsa-with-SHA256`
+const ECDSA_WITH_SHA256: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02];
+
+type Result<T> = core::result::Result<T, TeeError>;
+
+/// A forward only reader of DER items.
+#[derive(Clone, Copy)]
+struct Der<'a>(&'a [u8]);
+
+impl<'a> Der<'a> {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn peek_tag(&self) -> Option<u8> {
+        self.0.first().copied()
+    }
+
+    /// Read an item: return its tag, its content and the whole item bytes.
+    fn any(&mut self) -> Option<(u8, &'a [u8], &'a [u8])> {
+        let data = self.0;
+        let (&tag, rest) = data.split_first()?;
+        let (&first, mut rest) = rest.split_first()?;
+        let len = if first < 0x80 {
+            first as usize
+        } else {
+            let octets = (first & 0x7f) as usize;
+            if octets == 0 || octets > 3 || rest.len() < octets {
+                return None;
+            }
+            let (len, tail) = rest.split_at(octets);
+            rest = tail;
+            len.iter().fold(0, |acc, b| (acc << 8) | *b as usize)
+        };
+        let header = data.len() - rest.len();
+        if rest.len() < len {
+            return None;
+        }
+        self.0 = &rest[len..];
+        Some((tag, &rest[..len], &data[..header + len]))
+    }
+
+    /// Read an item with the given tag and return its content.
+    fn read(&mut self, tag: u8) -> Option<&'a [u8]> {
+        match self.any()? {
+            (t, content, _) if t == tag => Some(content),
+            _ => None,
+        }
+    }
+
+    /// Read an item with the given tag and return the whole item bytes.
+    fn read_raw(&mut self, tag: u8) -> Option<&'a [u8]> {
+        match self.any()? {
+            (t, _, raw) if t == tag => Some(raw),
+            _ => None,
+        }
+    }
+
+    /// Read an item with the given tag, if it's the next one.
+    fn read_optional(&mut self, tag: u8) -> Option<Option<&'a [u8]>> {
+        if self.peek_tag() == Some(tag) {
+            self.read(tag).map(Some)
+        } else {
+            Some(None)
+        }
+    }
+
+    fn sequence(&mut self) -> Option<Der<'a>> {
+        self.read(SEQUENCE).map(Der)
+    }
+
+    fn skip(&mut self) -> Option<()> {
+        self.any().map(|_| ())
+    }
+
+    fn time(&mut self) -> Option<u64> {
+        match self.any()? {
+            (UTC_TIME, content, _) => {
+                // Two digits years: 50..=99 are 19xx.
+                let year = number(content.get(..2)?)?;
+                let year = if year >= 50 { 1900 + year } else { 2000 + year };
+                date_time(year, content.get(2..)?)
+            }
+            (GENERALIZED_TIME, content, _) => {
+                date_time(number(content.get(..4)?)?, content.get(4..)?)
+            }
+            _ => None,
+        }
+    }
+
+    /// A bit string without unused bits.
+    fn bit_string(&mut self) -> Option<&'a [u8]> {
+        match self.read(BIT_STRING)?.split_first()? {
+            (0, bits) => Some(bits),
+            _ => None,
+        }
+    }
+
+    fn ecdsa_sha256_algorithm(&mut self) -> Option<()> {
+        let mut algorithm = self.sequence()?;
+        (algorithm.read(OID)? == ECDSA_WITH_SHA256).then_some(())
+    }
+}
+
+/// Parse the decimal digits.
+fn number(digits: &[u8]) -> Option<u64> {
+    digits.iter().try_fold(0, |acc, d| {
+        d.is_ascii_digit().then(|| acc * 10 + (d - b'0') as u64)
+    })
+}
+
+/// The unix timestamp of the given date: `rest` is `MMDDhhmmssZ`.
+fn date_time(year: u64, rest: &[u8]) -> Option<u64> {
+    if rest.len() != 11 || rest[10] != b'Z' {
+        return None;
+    }
+    let field = |i: usize| number(&rest[i..i + 2]);
+    unix_time(year, field(0)?, field(2)?, field(4)?, field(6)?, field(8)?)
+}
+
+/// The unix timestamp of the given UTC date and time.
+pub fn unix_time(year: u64, month: u64, day: u64, hour: u64, min: u64, sec: u64) -> Option<u64> {
+    if year < 1970 || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    if hour > 23 || min > 59 || sec > 60 {
+        return None;
+    }
+    // The days from the civil algorithm, with the year starting in March.
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = y / 400;
+    let yoe = y % 400;
+    let doy = (153 * m + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    Some(days * 86_400 + hour * 3_600 + min * 60 + sec)
+}
+
+/// Check an ECDSA P-256 SHA-256 signature given as DER (`der = true`) or as raw `r || s`.
+pub fn verify_signature(key: &[u8], message: &[u8], signature: &[u8], der: bool) -> Result<()> {
+    let key = VerifyingKey::from_sec1_bytes(key).map_err(|_| TeeError::InvalidCollateral)?;
+    let signature = if der {
+        Signature::from_der(signature)
+    } else {
+        Signature::from_slice(signature)
+    }
+    .map_err(|_| TeeError::InvalidSignature)?;
+    key.verify(message, &signature)
+        .map_err(|_| TeeError::InvalidSignature)
+}
+
+/// The bits of a certificate needed to verify a chain.
+pub struct Certificate<'a> {
+    pub raw: &'a [u8],
+    tbs: &'a [u8],
+    signature: &'a [u8],
+    pub serial: &'a [u8],
+    pub issuer: &'a [u8],
+    pub subject: &'a [u8],
+    pub not_before: u64,
+    pub not_after: u64,
+    /// The SEC1 encoded public key.
+    pub public_key: &'a [u8],
+    extensions: Der<'a>,
+}
+
+impl<'a> Certificate<'a> {
+    pub fn parse(raw: &'a [u8]) -> Result<Self> {
+        Self::try_parse(raw).ok_or(TeeError::InvalidCollateral)
+    }
+
+    fn try_parse(raw: &'a [u8]) -> Option<Self> {
+        let mut outer = Der(raw);
+        let mut certificate = outer.sequence()?;
+        if !outer.is_empty() {
+            return None;
+        }
+        let tbs = certificate.read_raw(SEQUENCE)?;
+        certificate.ecdsa_sha256_algorithm()?;
+        let signature = certificate.bit_string()?;
+
+        let mut fields = Der(tbs).sequence()?;
+        fields.read_optional(EXPLICIT_0)?;
+        let serial = fields.read(INTEGER)?;
+        fields.ecdsa_sha256_algorithm()?;
+        let issuer = fields.read_raw(SEQUENCE)?;
+        let mut validity = fields.sequence()?;
+        let not_before = validity.time()?;
+        let not_after = validity.time()?;
+        let subject = fields.read_raw(SEQUENCE)?;
+        let mut key_info = fields.sequence()?;
+        key_info.skip()?;
+        let public_key = key_info.bit_string()?;
+        let mut extensions = Der(&[]);
+        while !fields.is_empty() {
+            match fields.any()? {
+                (EXPLICIT_3, content, _) => extensions = Der(content).sequence()?,
+                // Unique identifiers
+                _ => continue,
+            }
+        }
+        Some(Self {
+            raw,
+            tbs,
+            signature,
+            serial,
+            issuer,
+            subject,
+            not_before,
+            not_after,
+            public_key,
+            extensions,
+        })
+    }
+
+    /// The content of the extension with the given OID.
+    pub fn extension(&self, oid: &[u8]) -> Option<&'a [u8]> {
+        let mut extensions = self.extensions;
+        while !extensions.is_empty() {
+            let mut extension = extensions.sequence()?;
+            let id = extension.read(OID)?;
+            extension.read_optional(BOOLEAN)?;
+            let value = extension.read(OCTET_STRING)?;
+            if id == oid {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    pub fn fingerprint(&self) -> Fingerprint {
+        Sha256::digest(self.raw).into()
+    }
+
+    pub fn is_valid_at(&self, now: u64) -> bool {
+        (self.not_before..=self.not_after).contains(&now)
+    }
+
+    /// Check that this certificate has been issued by `issuer`.
+    pub fn verify_issued_by(&self, issuer: &Certificate) -> Result<()> {
+        if self.issuer != issuer.subject {
+            return Err(TeeError::UntrustedRoot);
+        }
+        verify_signature(issuer.public_key, self.tbs, self.signature, true)
+    }
+}
+
+/// The bits of a CRL needed to check a revocation.
+pub struct Crl<'a> {
+    tbs: &'a [u8],
+    signature: &'a [u8],
+    issuer: &'a [u8],
+    this_update: u64,
+    next_update: u64,
+    revoked: Der<'a>,
+}
+
+impl<'a> Crl<'a> {
+    pub fn parse(raw: &'a [u8]) -> Result<Self> {
+        Self::try_parse(raw).ok_or(TeeError::InvalidCollateral)
+    }
+
+    fn try_parse(raw: &'a [u8]) -> Option<Self> {
+        let mut outer = Der(raw);
+        let mut crl = outer.sequence()?;
+        if !outer.is_empty() {
+            return None;
+        }
+        let tbs = crl.read_raw(SEQUENCE)?;
+        crl.ecdsa_sha256_algorithm()?;
+        let signature = crl.bit_string()?;
+
+        let mut fields = Der(tbs).sequence()?;
+        fields.read_optional(INTEGER)?;
+        fields.ecdsa_sha256_algorithm()?;
+        let issuer = fields.read_raw(SEQUENCE)?;
+        let this_update = fields.time()?;
+        // The next update is optional in X.509 but the Intel CRLs always have it.
+        let next_update = fields.time()?;
+        let revoked = Der(fields.read_optional(SEQUENCE)?.unwrap_or_default());
+        Some(Self {
+            tbs,
+            signature,
+            issuer,
+            this_update,
+            next_update,
+            revoked,
+        })
+    }
+
+    /// Check that the CRL has been issued by `issuer` and it's valid at `now`.
+    pub fn verify(&self, issuer: &Certificate, now: u64) -> Result<()> {
+        if self.issuer != issuer.subject {
+            return Err(TeeError::CollateralMismatch);
+        }
+        verify_signature(issuer.public_key, self.tbs, self.signature, true)?;
+        if !(self.this_update..=self.next_update).contains(&now) {
+            return Err(TeeError::Expired);
+        }
+        Ok(())
+    }
+
+    /// Check that the given certificate has not been revoked by this CRL.
+    pub fn check(&self, certificate: &Certificate) -> Result<()> {
+        let mut revoked = self.revoked;
+        while !revoked.is_empty() {
+            let mut entry = revoked.sequence().ok_or(TeeError::InvalidCollateral)?;
+            if entry.read(INTEGER) == Some(certificate.serial) {
+                return Err(TeeError::Revoked);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The Intel SGX extensions of the PCK certificates: `1.2.840.113741.1.13.1`.
+const SGX_EXTENSIONS: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf8, 0x4d, 0x01, 0x0d, 0x01];
+/// The SGX extensions items, relative to `SGX_EXTENSIONS`.
+const SGX_TCB: &[u8] = &[0x02];
+const SGX_PCE_SVN: &[u8] = &[0x02, 0x11];
+const SGX_CPU_SVN: &[u8] = &[0x02, 0x12];
+const SGX_PCE_ID: &[u8] = &[0x03];
+const SGX_FMSPC: &[u8] = &[0x04];
+
+/// The platform TCB certified by a PCK certificate.
+pub struct PckTcb {
+    pub fmspc: [u8; 6],
+    pub pce_id: [u8; 2],
+    pub cpu_svn: [u8; 16],
+    pub pce_svn: u16,
+}
+
+/// Read the `(OID, value)` items of an SGX extensions sequence and return the value of the
+/// given `item`.
+fn sgx_item<'a>(mut items: Der<'a>, item: &[u8]) -> Option<Der<'a>> {
+    while !items.is_empty() {
+        let mut entry = items.sequence()?;
+        let id = entry.read(OID)?;
+        if id.strip_prefix(SGX_EXTENSIONS) == Some(item) {
+            return Some(entry);
+        }
+    }
+    None
+}
+
+fn octets<const N: usize>(mut value: Der) -> Option<[u8; N]> {
+    value.read(OCTET_STRING)?.try_into().ok()
+}
+
+impl PckTcb {
+    pub fn from_certificate(certificate: &Certificate) -> Result<Self> {
+        Self::try_from_certificate(certificate).ok_or(TeeError::InvalidCollateral)
+    }
+
+    fn try_from_certificate(certificate: &Certificate) -> Option<Self> {
+        let items = Der(certificate.extension(SGX_EXTENSIONS)?).sequence()?;
+        let tcb = sgx_item(items, SGX_TCB)?.sequence()?;
+        let pce_svn = sgx_item(tcb, SGX_PCE_SVN)?.read(INTEGER)?;
+        if pce_svn.len() > 3 {
+            return None;
+        }
+        let pce_svn = pce_svn
+            .iter()
+            .fold(0u32, |acc, b| (acc << 8) | *b as u32)
+            .try_into()
+            .ok()?;
+        Some(Self {
+            fmspc: octets(sgx_item(items, SGX_FMSPC)?)?,
+            pce_id: octets(sgx_item(items, SGX_PCE_ID)?)?,
+            cpu_svn: octets(sgx_item(tcb, SGX_CPU_SVN)?)?,
+            pce_svn,
+        })
+    }
+}
+
+/// Decode the certificates of a PEM chain.
+pub fn pem_chain(pem: &[u8]) -> Result<Vec<Vec<u8>>> {
+    const BEGIN: &[u8] = b"-----BEGIN CERTIFICATE-----";
+    const END: &[u8] = b"-----END CERTIFICATE-----";
+
+    let mut certificates = Vec::new();
+    let mut rest = pem;
+    while let Some(start) = find(rest, BEGIN) {
+        rest = &rest[start + BEGIN.len()..];
+        let end = find(rest, END).ok_or(TeeError::InvalidCollateral)?;
+        certificates.push(base64(&rest[..end]).ok_or(TeeError::InvalidCollateral)?);
+        rest = &rest[end + END.len()..];
+    }
+    Ok(certificates)
+}
+
+fn find(data: &[u8], needle: &[u8]) -> Option<usize> {
+    data.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Decode standard base64, ignoring the white spaces.
+fn base64(data: &[u8]) -> Option<Vec<u8>> {
+    let value = |c: u8| match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    };
+    let symbols = data
+        .iter()
+        .copied()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect::<Vec<_>>();
+    let content = symbols
+        .strip_suffix(b"==")
+        .or_else(|| symbols.strip_suffix(b"="))
+        .unwrap_or(&symbols);
+    if symbols.len() % 4 != 0 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(content.len() * 3 / 4);
+    let mut acc = 0u32;
+    for (i, c) in content.iter().enumerate() {
+        acc = (acc << 6) | value(*c)? as u32;
+        if i % 4 == 3 {
+            out.extend_from_slice(&acc.to_be_bytes()[1..]);
+            acc = 0;
+        }
+    }
+    match content.len() % 4 {
+        0 => {}
+        2 => out.push((acc >> 4) as u8),
+        3 => out.extend_from_slice(&((acc >> 2) as u16).to_be_bytes()),
+        _ => return None,
+    }
+    Some(out)
+}
@@ -0,0 +1,443 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(missing_docs)]
+
+//! Provide a base interface and the std lib implementation for the Intel DCAP (Data Center
+//! Attestation Primitives) quote verifier: ECDSA P-256 quotes produced by SGX enclaves (quote
+//! v3 and v4) and TDX trust domains (quote v4).
+//!
+//! The quote is verified against the collateral fetched from the Intel PCS (or a caching
+//! service) by the prover: the CRLs, the TCB info and the quoting enclave identity. Both the
+//! quote certificates and the collateral ones should chain up to one of the trusted roots of
+//! the [`Policy`], and the collateral should be valid at the given verification time.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime_interface::pass_by::PassByCodec;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "implementation")]
+mod collateral;
+#[cfg(feature = "implementation")]
+mod der;
+#[cfg(feature = "implementation")]
+mod quote;
+#[cfg(feature = "implementation")]
+mod verifier;
+
+#[cfg(feature = "implementation")]
+pub use verifier::verify;
+
+/// Size in bytes of the report data: the user data bound to the quote by the enclave.
+pub const REPORT_DATA_SIZE: usize = 64;
+/// Largest supported number of trusted roots in a policy.
+pub const MAX_ROOTS: u32 = 8;
+
+/// The report data.
+pub type ReportData = [u8; REPORT_DATA_SIZE];
+/// The SHA-256 digest of a DER encoded root CA certificate.
+pub type Fingerprint = [u8; 32];
+
+/// The TCB status of the platform, as defined by the Intel TCB info and QE identity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum TcbStatus {
+    /// The platform is patched.
+    UpToDate,
+    /// The platform is patched but some software hardening is needed.
+    SWHardeningNeeded,
+    /// The platform is patched but its configuration is not secure.
+    ConfigurationNeeded,
+    /// The platform needs both a configuration change and software hardening.
+    ConfigurationAndSWHardeningNeeded,
+    /// The platform is not patched.
+    OutOfDate,
+    /// The platform is not patched and its configuration is not secure.
+    OutOfDateConfigurationNeeded,
+    /// The platform keys have been revoked: it is never accepted.
+    Revoked,
+}
+
+/// The expected identity of the attested workload.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum Measurement {
+    /// An SGX enclave with the given `MRENCLAVE`.
+    SgxEnclave([u8; 32]),
+    /// Any SGX enclave signed by the given `MRSIGNER`.
+    SgxSigner([u8; 32]),
+    /// A TDX trust domain with the given `MRTD`.
+    TdxTd([u8; 48]),
+}
+
+/// What is trusted by the verifier.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo, PassByCodec)]
+pub struct Policy {
+    /// The fingerprints of the trusted root CAs (e.g. the Intel SGX Root CA).
+    pub roots: Vec<Fingerprint>,
+    /// The accepted TCB statuses.
+    pub accepted: Vec<TcbStatus>,
+    /// The expected workload: any enclave or trust domain if `None`, in which case the report
+    /// data alone should bind the workload.
+    pub measurement: Option<Measurement>,
+}
+
+/// The collateral needed to verify a quote, as served by the Intel PCS.
+#[derive(Clone, Debug, Default, PartialEq, Encode, Decode, TypeInfo)]
+pub struct Collateral {
+    /// The DER encoded CRL of the PCK platform or processor CA.
+    pub pck_crl: Vec<u8>,
+    /// The DER encoded CRL of the root CA.
+    pub root_ca_crl: Vec<u8>,
+    /// The `tcbInfo` JSON object, exactly as signed.
+    pub tcb_info: Vec<u8>,
+    /// The raw (`r || s`) signature of `tcb_info`.
+    pub tcb_info_signature: Vec<u8>,
+    /// The `enclaveIdentity` JSON object of the quoting enclave, exactly as signed.
+    pub qe_identity: Vec<u8>,
+    /// The raw (`r || s`) signature of `qe_identity`.
+    pub qe_identity_signature: Vec<u8>,
+    /// The PEM encoded chain of the TCB signing certificate, which signs both `tcb_info` and
+    /// `qe_identity`.
+    pub tcb_signing_chain: Vec<u8>,
+}
+
+/// A DCAP quote and its collateral.
+#[derive(Clone, Debug, Default, PartialEq, Encode, Decode, TypeInfo, PassByCodec)]
+pub struct Attestation {
+    /// The raw quote, with the PCK certificate chain as certification data.
+    pub quote: Vec<u8>,
+    /// The collateral.
+    pub collateral: Collateral,
+}
+
+/// Errors that can occur during the quote verification.
+#[derive(Debug, PartialEq)]
+pub enum TeeError {
+    /// The quote is malformed or not supported.
+    InvalidQuote,
+    /// The collateral or a certificate is malformed.
+    InvalidCollateral,
+    /// The policy is malformed.
+    InvalidPolicy,
+    /// A certificate chain doesn't end in a trusted root.
+    UntrustedRoot,
+    /// A certificate or the collateral is not valid at the verification time.
+    Expired,
+    /// A certificate has been revoked.
+    Revoked,
+    /// A signature is not valid.
+    InvalidSignature,
+    /// The collateral doesn't match the quote platform.
+    CollateralMismatch,
+    /// The TCB status is not accepted by the policy.
+    TcbStatusNotAccepted,
+    /// The enclave or trust domain is in debug mode.
+    DebugMode,
+    /// The workload measurement doesn't match the policy.
+    MeasurementMismatch,
+    /// The report data doesn't match.
+    ReportDataMismatch,
+}
+
+impl From<TeeError> for hp_verifiers::VerifyError {
+    fn from(error: TeeError) -> Self {
+        match error {
+            TeeError::InvalidQuote | TeeError::InvalidCollateral => {
+                hp_verifiers::VerifyError::InvalidProofData
+            }
+            TeeError::InvalidPolicy => hp_verifiers::VerifyError::InvalidVerificationKey,
+            TeeError::ReportDataMismatch => hp_verifiers::VerifyError::InvalidInput,
+            _ => hp_verifiers::VerifyError::VerifyError,
+        }
+    }
+}
+
+fn has_duplicates<T: PartialEq>(items: &[T]) -> bool {
+    items
+        .iter()
+        .enumerate()
+        .any(|(i, item)| items[..i].contains(item))
+}
+
+/// Check that the policy can accept something, i.e. that there is at least a trusted root and
+/// a TCB status other than `Revoked`, and that there are no duplicated entries.
+pub fn validate_policy(policy: &Policy) -> Result<(), TeeError> {
+    if policy.roots.is_empty()
+        || policy.roots.len() > MAX_ROOTS as usize
+        || has_duplicates(&policy.roots)
+        || has_duplicates(&policy.accepted)
+        || policy.accepted.iter().all(|s| *s == TcbStatus::Revoked)
+    {
+        return Err(TeeError::InvalidPolicy);
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "implementation"))]
+mod should {
+    use super::*;
+
+    include!("resources.rs");
+
+    #[test]
+    fn verify_valid_sgx_quote() {
+        assert_eq!(
+            verify(&sgx_policy(), &sgx_attestation(), &SGX_REPORT_DATA, NOW),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn verify_valid_tdx_quote() {
+        assert_eq!(
+            verify(&tdx_policy(), &tdx_attestation(), &TDX_REPORT_DATA, NOW),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn verify_sgx_quote_by_enclave_signer() {
+        let policy = Policy {
+            measurement: Some(Measurement::SgxSigner(SGX_MR_SIGNER)),
+            ..sgx_policy()
+        };
+
+        assert_eq!(
+            verify(&policy, &sgx_attestation(), &SGX_REPORT_DATA, NOW),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn verify_quote_of_any_workload() {
+        let policy = Policy {
+            measurement: None,
+            ..tdx_policy()
+        };
+
+        assert_eq!(
+            verify(&policy, &tdx_attestation(), &TDX_REPORT_DATA, NOW),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_valid_policy() {
+        assert_eq!(validate_policy(&sgx_policy()), Ok(()));
+    }
+
+    mod reject {
+        use super::*;
+
+        #[test]
+        fn wrong_report_data() {
+            let mut report_data = SGX_REPORT_DATA;
+            report_data[0] ^= 1;
+
+            assert_eq!(
+                verify(&sgx_policy(), &sgx_attestation(), &report_data, NOW),
+                Err(TeeError::ReportDataMismatch)
+            );
+        }
+
+        #[test]
+        fn untrusted_root() {
+            let policy = Policy {
+                roots: vec![[1; 32]],
+                ..sgx_policy()
+            };
+
+            assert_eq!(
+                verify(&policy, &sgx_attestation(), &SGX_REPORT_DATA, NOW),
+                Err(TeeError::UntrustedRoot)
+            );
+        }
+
+        #[test]
+        fn expired_collateral() {
+            let after_next_update = NOW + 30 * 24 * 3600;
+
+            assert_eq!(
+                verify(
+                    &sgx_policy(),
+                    &sgx_attestation(),
+                    &SGX_REPORT_DATA,
+                    after_next_update
+                ),
+                Err(TeeError::Expired)
+            );
+        }
+
+        #[test]
+        fn not_yet_valid_collateral() {
+            let before_issue = NOW - 30 * 24 * 3600;
+
+            assert_eq!(
+                verify(
+                    &sgx_policy(),
+                    &sgx_attestation(),
+                    &SGX_REPORT_DATA,
+                    before_issue
+                ),
+                Err(TeeError::Expired)
+            );
+        }
+
+        #[test]
+        fn revoked_pck_certificate() {
+            let mut attestation = sgx_attestation();
+            attestation.collateral.pck_crl =
+                include_bytes!("resources/pck_crl_revoked.der").to_vec();
+
+            assert_eq!(
+                verify(&sgx_policy(), &attestation, &SGX_REPORT_DATA, NOW),
+                Err(TeeError::Revoked)
+            );
+        }
+
+        #[test]
+        fn not_accepted_tcb_status() {
+            let policy = Policy {
+                accepted: vec![TcbStatus::UpToDate],
+                ..sgx_policy()
+            };
+
+            assert_eq!(
+                verify(&policy, &sgx_attestation(), &SGX_REPORT_DATA, NOW),
+                Err(TeeError::TcbStatusNotAccepted)
+            );
+        }
+
+        #[test]
+        fn debug_enclave() {
+            assert_eq!(
+                verify(
+                    &sgx_policy(),
+                    &sgx_debug_attestation(),
+                    &SGX_REPORT_DATA,
+                    NOW
+                ),
+                Err(TeeError::DebugMode)
+            );
+        }
+
+        #[test]
+        fn other_workload() {
+            let other_enclave = Policy {
+                measurement: Some(Measurement::SgxEnclave([0; 32])),
+                ..sgx_policy()
+            };
+            let other_tee = Policy {
+                measurement: Some(Measurement::SgxEnclave(SGX_MR_ENCLAVE)),
+                ..tdx_policy()
+            };
+
+            assert_eq!(
+                verify(&other_enclave, &sgx_attestation(), &SGX_REPORT_DATA, NOW),
+                Err(TeeError::MeasurementMismatch)
+            );
+            assert_eq!(
+                verify(&other_tee, &tdx_attestation(), &TDX_REPORT_DATA, NOW),
+                Err(TeeError::MeasurementMismatch)
+            );
+        }
+
+        #[test]
+        fn tampered_quote() {
+            let mut attestation = sgx_attestation();
+            // A byte of MRSIGNER: the quote signature doesn't match anymore.
+            attestation.quote[48 + 128] ^= 1;
+
+            assert_eq!(
+                verify(&sgx_policy(), &attestation, &SGX_REPORT_DATA, NOW),
+                Err(TeeError::InvalidSignature)
+            );
+        }
+
+        #[test]
+        fn tampered_collateral() {
+            let mut attestation = sgx_attestation();
+            let tcb_info = core::str::from_utf8(&attestation.collateral.tcb_info)
+                .unwrap()
+                .replace("SWHardeningNeeded", "UpToDate");
+            attestation.collateral.tcb_info = tcb_info.into_bytes();
+
+            assert_eq!(
+                verify(&sgx_policy(), &attestation, &SGX_REPORT_DATA, NOW),
+                Err(TeeError::InvalidSignature)
+            );
+        }
+
+        #[test]
+        fn collateral_of_another_tee() {
+            let attestation = Attestation {
+                collateral: tdx_collateral(),
+                ..sgx_attestation()
+            };
+
+            assert_eq!(
+                verify(&sgx_policy(), &attestation, &SGX_REPORT_DATA, NOW),
+                Err(TeeError::CollateralMismatch)
+            );
+        }
+
+        #[test]
+        fn malformed_quote() {
+            let mut attestation = sgx_attestation();
+            attestation.quote.truncate(1000);
+
+            assert_eq!(
+                verify(&sgx_policy(), &attestation, &SGX_REPORT_DATA, NOW),
+                Err(TeeError::InvalidQuote)
+            );
+        }
+
+        #[test]
+        fn malformed_collateral() {
+            let mut attestation = sgx_attestation();
+            attestation.collateral.root_ca_crl.truncate(100);
+
+            assert_eq!(
+                verify(&sgx_policy(), &attestation, &SGX_REPORT_DATA, NOW),
+                Err(TeeError::InvalidCollateral)
+            );
+        }
+
+        #[test]
+        fn policy_that_accepts_nothing() {
+            let no_roots = Policy {
+                roots: vec![],
+                ..sgx_policy()
+            };
+            let just_revoked = Policy {
+                accepted: vec![TcbStatus::Revoked],
+                ..sgx_policy()
+            };
+            let duplicated_roots = Policy {
+                roots: vec![ROOT_FINGERPRINT; 2],
+                ..sgx_policy()
+            };
+
+            for policy in [no_roots, just_revoked, duplicated_roots] {
+                assert_eq!(validate_policy(&policy), Err(TeeError::InvalidPolicy));
+                assert_eq!(
+                    verify(&policy, &sgx_attestation(), &SGX_REPORT_DATA, NOW),
+                    Err(TeeError::InvalidPolicy)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The layout of the ECDSA quotes: v3 for SGX enclaves and v4 for both SGX enclaves and TDX
+//! trust domains (TD report 1.0).
+
+use crate::{ReportData, TeeError};
+
+const SGX_REPORT_SIZE: usize = 384;
+const TD_REPORT_SIZE: usize = 584;
+
+/// `ECDSA-256-with-P-256`
+const ECDSA_P256: u16 = 2;
+const TEE_SGX: u32 = 0x00;
+const TEE_TDX: u32 = 0x81;
+/// The certification data that carries the PCK certificate chain.
+const PCK_CHAIN: u16 = 5;
+/// The v4 certification data that wraps the QE report and the PCK certificate chain.
+const QE_REPORT: u16 = 6;
+
+/// The Intel quoting enclaves vendor id.
+const INTEL_QE_VENDOR_ID: [u8; 16] = [
+    0x93, 0x9a, 0x72, 0x33, 0xf7, 0x9c, 0x4c, 0xa9, 0x94, 0x0a, 0x0d, 0xb3, 0x95, 0x7f, 0x06, 0x07,
+];
+
+type Result<T> = core::result::Result<T, TeeError>;
+
+/// An SGX enclave report: both the attested enclave and the quoting enclave ones.
+pub struct EnclaveReport<'a> {
+    pub raw: &'a [u8],
+    pub misc_select: u32,
+    pub attributes: [u8; 16],
+    pub mr_enclave: [u8; 32],
+    pub mr_signer: [u8; 32],
+    pub isv_prod_id: u16,
+    pub isv_svn: u16,
+    pub report_data: ReportData,
+}
+
+/// A TDX trust domain report.
+pub struct TdReport {
+    pub tee_tcb_svn: [u8; 16],
+    pub mr_signer_seam: [u8; 48],
+    pub seam_attributes: [u8; 8],
+    pub td_attributes: [u8; 8],
+    pub mr_td: [u8; 48],
+    pub report_data: ReportData,
+}
+
+/// The attested workload.
+pub enum Body<'a> {
+    Sgx(EnclaveReport<'a>),
+    Td(TdReport),
+}
+
+impl Body<'_> {
+    pub fn report_data(&self) -> &ReportData {
+        match self {
+            Body::Sgx(report) => &report.report_data,
+            Body::Td(report) => &report.report_data,
+        }
+    }
+}
+
+pub struct Quote<'a> {
+    /// The signed bytes: the header and the body.
+    pub signed: &'a [u8],
+    pub body: Body<'a>,
+    /// The raw (`r || s`) signature of `signed`.
+    pub signature: &'a [u8],
+    /// The attestation key: `x || y`.
+    pub attestation_key: &'a [u8],
+    pub qe_report: EnclaveReport<'a>,
+    /// The raw (`r || s`) signature of the QE report, by the PCK key.
+    pub qe_report_signature: &'a [u8],
+    pub qe_auth_data: &'a [u8],
+    /// The PEM encoded PCK certificate chain.
+    pub pck_chain: &'a [u8],
+}
+
+/// The quoting enclave certification data.
+struct QeCertification<'a> {
+    report: EnclaveReport<'a>,
+    report_signature: &'a [u8],
+    auth_data: &'a [u8],
+    pck_chain: &'a [u8],
+}
+
+/// A forward only reader of the little endian quote fields.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        (len <= self.0.len()).then(|| {
+            let (head, tail) = self.0.split_at(len);
+            self.0 = tail;
+            head
+        })
+    }
+
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N).map(|b| b.try_into().expect("N bytes"))
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    /// A `u32` length prefixed item.
+    fn sized(&mut self) -> Option<&'a [u8]> {
+        let len = self.u32()?;
+        self.take(len.try_into().ok()?)
+    }
+
+    /// Certification data of the given type.
+    fn certification_data(&mut self, expected: u16) -> Option<&'a [u8]> {
+        (self.u16()? == expected).then_some(())?;
+        self.sized()
+    }
+
+    fn enclave_report(&mut self) -> Option<EnclaveReport<'a>> {
+        let raw = self.take(SGX_REPORT_SIZE)?;
+        let mut r = Reader(raw);
+        // CPUSVN: the certified one is in the PCK certificate
+        r.take(16)?;
+        let misc_select = r.u32()?;
+        r.take(28)?;
+        let attributes = r.array()?;
+        let mr_enclave = r.array()?;
+        r.take(32)?;
+        let mr_signer = r.array()?;
+        r.take(96)?;
+        let isv_prod_id = r.u16()?;
+        let isv_svn = r.u16()?;
+        r.take(60)?;
+        let report_data = r.array()?;
+        Some(EnclaveReport {
+            raw,
+            misc_select,
+            attributes,
+            mr_enclave,
+            mr_signer,
+            isv_prod_id,
+            isv_svn,
+            report_data,
+        })
+    }
+
+    fn td_report(&mut self) -> Option<TdReport> {
+        let mut r = Reader(self.take(TD_REPORT_SIZE)?);
+        let tee_tcb_svn = r.array()?;
+        // MRSEAM
+        r.take(48)?;
+        let mr_signer_seam = r.array()?;
+        let seam_attributes = r.array()?;
+        let td_attributes = r.array()?;
+        // XFAM
+        r.take(8)?;
+        let mr_td = r.array()?;
+        // MRCONFIGID, MROWNER, MROWNERCONFIG and the four RTMRs
+        r.take(7 * 48)?;
+        let report_data = r.array()?;
+        Some(TdReport {
+            tee_tcb_svn,
+            mr_signer_seam,
+            seam_attributes,
+            td_attributes,
+            mr_td,
+            report_data,
+        })
+    }
+
+    /// The QE report, its signature, the QE authentication data and the PCK chain.
+    fn qe_certification(&mut self) -> Option<QeCertification<'a>> {
+        let report = self.enclave_report()?;
+        let report_signature = self.take(64)?;
+        let auth_len = self.u16()?;
+        let auth_data = self.take(auth_len as usize)?;
+        let pck_chain = self.certification_data(PCK_CHAIN)?;
+        Some(QeCertification {
+            report,
+            report_signature,
+            auth_data,
+            pck_chain,
+        })
+    }
+}
+
+impl<'a> Quote<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Self> {
+        Self::try_parse(data).ok_or(TeeError::InvalidQuote)
+    }
+
+    fn try_parse(data: &'a [u8]) -> Option<Self> {
+        let mut reader = Reader(data);
+        let version = reader.u16()?;
+        let key_type = reader.u16()?;
+        let tee_type = reader.u32()?;
+        reader.take(4)?;
+        let qe_vendor_id = reader.array()?;
+        reader.take(20)?;
+        if key_type != ECDSA_P256 || qe_vendor_id != INTEL_QE_VENDOR_ID {
+            return None;
+        }
+        // The v3 quotes have reserved zero bytes in place of the TEE type.
+        let body = match (version, tee_type) {
+            (3 | 4, TEE_SGX) => Body::Sgx(reader.enclave_report()?),
+            (4, TEE_TDX) => Body::Td(reader.td_report()?),
+            _ => return None,
+        };
+        let signed = &data[..data.len() - reader.0.len()];
+
+        let mut signature_data = Reader(reader.sized()?);
+        let signature = signature_data.take(64)?;
+        let attestation_key = signature_data.take(64)?;
+        let qe = match version {
+            3 => signature_data.qe_certification()?,
+            _ => Reader(signature_data.certification_data(QE_REPORT)?).qe_certification()?,
+        };
+        Some(Self {
+            signed,
+            body,
+            signature,
+            attestation_key,
+            qe_report: qe.report,
+            qe_report_signature: qe.report_signature,
+            qe_auth_data: qe.auth_data,
+            pck_chain: qe.pck_chain,
+        })
+    }
+}
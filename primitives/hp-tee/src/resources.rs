@@ -0,0 +1,127 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Recorded quotes and collateral of a test PKI that mirrors the Intel one: a root CA, a PCK
+// platform CA and a TCB signing certificate. The collateral is valid from 2024-05-25 to
+// 2024-06-24. The SGX platform is at the `SWHardeningNeeded` level, the TDX one is `UpToDate`.
+
+/// 2024-06-01T00:00:00Z
+#[allow(dead_code)]
+const NOW: u64 = 1_717_200_000;
+
+#[allow(dead_code)]
+const ROOT_FINGERPRINT: crate::Fingerprint =
+    hex_literal::hex!("0e4454d2baaa3c1df86317d974f4a455271d47e4fdf0bd82690c31ac37a292d8");
+
+#[allow(dead_code)]
+const SGX_MR_ENCLAVE: [u8; 32] =
+    hex_literal::hex!("ea7db3a46c1dbb1aad094ab3014ea81b4abdf5bfea3366605e4067d30dda7d9a");
+
+#[allow(dead_code)]
+const SGX_MR_SIGNER: [u8; 32] =
+    hex_literal::hex!("bab2b4100ee703f8d6f53774cf21b010f3f5d03085911a31ac0e12803fdfc266");
+
+#[allow(dead_code)]
+const SGX_REPORT_DATA: crate::ReportData = hex_literal::hex!(
+    "
+    339d6de46973acabf3362fb045dbad41a1dc35da82465d74186f380c77e30d65
+    5a117bbd5b1e59de654b4af9883d7d312938168b1ef0909aa3ece54a8b62200d
+"
+);
+
+#[allow(dead_code)]
+const TDX_MR_TD: [u8; 48] = hex_literal::hex!(
+    "9cbc703d6572d153b3435107c9ea1555fd7584b1d98ede4146fa92204e79e0869a12408e7fec2440db5a4b185432bde0"
+);
+
+#[allow(dead_code)]
+const TDX_REPORT_DATA: crate::ReportData = hex_literal::hex!(
+    "
+    e65e3f7a7a0345aaad2cf379de12c9eea4d83281509cb826fbf062e14b1555df
+    31506de5e8a66d08b6a2272363a54b453bca1c8f43665377e552c260a9d04930
+"
+);
+
+#[allow(dead_code)]
+fn sgx_collateral() -> crate::Collateral {
+    crate::Collateral {
+        pck_crl: include_bytes!("resources/pck_crl.der").to_vec(),
+        root_ca_crl: include_bytes!("resources/root_ca_crl.der").to_vec(),
+        tcb_info: include_bytes!("resources/sgx_tcb_info.json").to_vec(),
+        tcb_info_signature: include_bytes!("resources/sgx_tcb_info.sig").to_vec(),
+        qe_identity: include_bytes!("resources/sgx_qe_identity.json").to_vec(),
+        qe_identity_signature: include_bytes!("resources/sgx_qe_identity.sig").to_vec(),
+        tcb_signing_chain: include_bytes!("resources/tcb_signing_chain.pem").to_vec(),
+    }
+}
+
+#[allow(dead_code)]
+fn tdx_collateral() -> crate::Collateral {
+    crate::Collateral {
+        tcb_info: include_bytes!("resources/tdx_tcb_info.json").to_vec(),
+        tcb_info_signature: include_bytes!("resources/tdx_tcb_info.sig").to_vec(),
+        qe_identity: include_bytes!("resources/tdx_qe_identity.json").to_vec(),
+        qe_identity_signature: include_bytes!("resources/tdx_qe_identity.sig").to_vec(),
+        ..sgx_collateral()
+    }
+}
+
+/// An SGX v3 quote.
+#[allow(dead_code)]
+fn sgx_attestation() -> crate::Attestation {
+    crate::Attestation {
+        quote: include_bytes!("resources/sgx_quote.dat").to_vec(),
+        collateral: sgx_collateral(),
+    }
+}
+
+/// The same SGX v3 quote, by a debug enclave.
+#[allow(dead_code)]
+fn sgx_debug_attestation() -> crate::Attestation {
+    crate::Attestation {
+        quote: include_bytes!("resources/sgx_debug_quote.dat").to_vec(),
+        collateral: sgx_collateral(),
+    }
+}
+
+/// A TDX v4 quote.
+#[allow(dead_code)]
+fn tdx_attestation() -> crate::Attestation {
+    crate::Attestation {
+        quote: include_bytes!("resources/tdx_quote.dat").to_vec(),
+        collateral: tdx_collateral(),
+    }
+}
+
+#[allow(dead_code)]
+fn sgx_policy() -> crate::Policy {
+    crate::Policy {
+        roots: vec![ROOT_FINGERPRINT],
+        accepted: vec![
+            crate::TcbStatus::UpToDate,
+            crate::TcbStatus::SWHardeningNeeded,
+        ],
+        measurement: Some(crate::Measurement::SgxEnclave(SGX_MR_ENCLAVE)),
+    }
+}
+
+#[allow(dead_code)]
+fn tdx_policy() -> crate::Policy {
+    crate::Policy {
+        roots: vec![ROOT_FINGERPRINT],
+        accepted: vec![crate::TcbStatus::UpToDate],
+        measurement: Some(crate::Measurement::TdxTd(TDX_MR_TD)),
+    }
+}
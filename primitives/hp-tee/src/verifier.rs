@@ -0,0 +1,235 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The DCAP quote verification: it follows the Intel Quote Verification Library steps, from
+//! the certificate chains up to the TCB status, and then checks the attested workload against
+//! the policy.
+
+use sha2::{Digest, Sha256};
+use sp_std::vec::Vec;
+
+use crate::{
+    collateral::{QeIdentity, TcbInfo, Tee},
+    der::{pem_chain, verify_signature, Certificate, Crl, PckTcb},
+    quote::{Body, Quote},
+    validate_policy, Attestation, Measurement, Policy, ReportData, TcbStatus, TeeError,
+};
+
+type Result<T> = core::result::Result<T, TeeError>;
+
+/// The `DEBUG` bit of the SGX enclave attributes.
+const SGX_DEBUG: u8 = 0x02;
+/// The `DEBUG` bit of the TD attributes.
+const TD_DEBUG: u8 = 0x01;
+
+/// Check the chain (leaf first) at `now` and return its root: it should be self signed and
+/// trusted by the policy.
+fn verify_chain<'a, 'c>(
+    policy: &Policy,
+    chain: &'a [Certificate<'c>],
+    now: u64,
+) -> Result<&'a Certificate<'c>> {
+    let root = match chain {
+        [_, .., root] => root,
+        _ => return Err(TeeError::InvalidCollateral),
+    };
+    if !policy.roots.contains(&root.fingerprint()) {
+        return Err(TeeError::UntrustedRoot);
+    }
+    if chain.iter().any(|c| !c.is_valid_at(now)) {
+        return Err(TeeError::Expired);
+    }
+    for pair in chain.windows(2) {
+        pair[0].verify_issued_by(&pair[1])?;
+    }
+    root.verify_issued_by(root)?;
+    Ok(root)
+}
+
+fn parse_chain(certificates: &[Vec<u8>]) -> Result<Vec<Certificate<'_>>> {
+    certificates
+        .iter()
+        .map(|raw| Certificate::parse(raw))
+        .collect()
+}
+
+/// The status of the platform with an out of date quoting enclave.
+fn converge(platform: TcbStatus, qe: TcbStatus) -> TcbStatus {
+    match (platform, qe) {
+        (_, TcbStatus::Revoked) => TcbStatus::Revoked,
+        (TcbStatus::UpToDate | TcbStatus::SWHardeningNeeded, TcbStatus::OutOfDate) => {
+            TcbStatus::OutOfDate
+        }
+        (
+            TcbStatus::ConfigurationNeeded | TcbStatus::ConfigurationAndSWHardeningNeeded,
+            TcbStatus::OutOfDate,
+        ) => TcbStatus::OutOfDateConfigurationNeeded,
+        (platform, _) => platform,
+    }
+}
+
+fn masked_eq(value: &[u8], mask: &[u8], expected: &[u8]) -> bool {
+    value
+        .iter()
+        .zip(mask)
+        .map(|(v, m)| v & m)
+        .eq(expected.iter().copied())
+}
+
+/// Verify the attestation at the unix time `now` (in seconds): the quote should be genuine, its
+/// TCB status accepted, and it should attest the policy workload with the given report data.
+pub fn verify(
+    policy: &Policy,
+    attestation: &Attestation,
+    report_data: &ReportData,
+    now: u64,
+) -> Result<()> {
+    validate_policy(policy)?;
+    let quote = Quote::parse(&attestation.quote)?;
+    let collateral = &attestation.collateral;
+
+    // The PCK chain (the PCK certificate, the platform or processor CA and the root CA) and the
+    // TCB signing chain should share the trusted root, whose CRL is in the collateral.
+    let pck_chain = pem_chain(quote.pck_chain)?;
+    let pck_chain = parse_chain(&pck_chain)?;
+    let root = verify_chain(policy, &pck_chain, now)?;
+    let tcb_chain = pem_chain(&collateral.tcb_signing_chain)?;
+    let tcb_chain = parse_chain(&tcb_chain)?;
+    if verify_chain(policy, &tcb_chain, now)?.raw != root.raw {
+        return Err(TeeError::CollateralMismatch);
+    }
+
+    let root_ca_crl = Crl::parse(&collateral.root_ca_crl)?;
+    root_ca_crl.verify(root, now)?;
+    let pck_crl = Crl::parse(&collateral.pck_crl)?;
+    pck_crl.verify(&pck_chain[1], now)?;
+    pck_crl.check(&pck_chain[0])?;
+    for certificate in pck_chain[1..].iter().chain(&tcb_chain) {
+        root_ca_crl.check(certificate)?;
+    }
+
+    // The collateral.
+    let tcb_signer = &tcb_chain[0];
+    verify_signature(
+        tcb_signer.public_key,
+        &collateral.tcb_info,
+        &collateral.tcb_info_signature,
+        false,
+    )?;
+    verify_signature(
+        tcb_signer.public_key,
+        &collateral.qe_identity,
+        &collateral.qe_identity_signature,
+        false,
+    )?;
+    let tcb_info = TcbInfo::parse(&collateral.tcb_info)?;
+    let qe_identity = QeIdentity::parse(&collateral.qe_identity)?;
+    if !(tcb_info.issue_date..=tcb_info.next_update).contains(&now)
+        || !(qe_identity.issue_date..=qe_identity.next_update).contains(&now)
+    {
+        return Err(TeeError::Expired);
+    }
+
+    // The quote: the PCK key signs the QE report, which binds the attestation key that signs
+    // the quote.
+    let pck = &pck_chain[0];
+    let qe_report = &quote.qe_report;
+    verify_signature(
+        pck.public_key,
+        qe_report.raw,
+        quote.qe_report_signature,
+        false,
+    )?;
+    let key_hash: [u8; 32] = Sha256::new()
+        .chain_update(quote.attestation_key)
+        .chain_update(quote.qe_auth_data)
+        .finalize()
+        .into();
+    if qe_report.report_data[..32] != key_hash || qe_report.report_data[32..] != [0; 32] {
+        return Err(TeeError::InvalidSignature);
+    }
+    let attestation_key = [&[0x04], quote.attestation_key].concat();
+    verify_signature(&attestation_key, quote.signed, quote.signature, false)?;
+
+    // The TCB status of the platform and of the quoting enclave.
+    let pck_tcb = PckTcb::from_certificate(pck)?;
+    let (tee, tee_tcb_svn) = match &quote.body {
+        Body::Sgx(_) => (Tee::Sgx, None),
+        Body::Td(report) => (Tee::Tdx, Some(&report.tee_tcb_svn)),
+    };
+    if tcb_info.tee != tee
+        || qe_identity.tee != tee
+        || tcb_info.fmspc != pck_tcb.fmspc
+        || tcb_info.pce_id != pck_tcb.pce_id
+    {
+        return Err(TeeError::CollateralMismatch);
+    }
+    if let (Body::Td(report), Some(module)) = (&quote.body, &tcb_info.tdx_module) {
+        if report.mr_signer_seam != module.mr_signer
+            || !masked_eq(
+                &report.seam_attributes,
+                &module.attributes_mask,
+                &module.attributes,
+            )
+        {
+            return Err(TeeError::CollateralMismatch);
+        }
+    }
+    if qe_report.mr_signer != qe_identity.mr_signer
+        || qe_report.isv_prod_id != qe_identity.isv_prod_id
+        || qe_report.misc_select & qe_identity.misc_select_mask != qe_identity.misc_select
+        || !masked_eq(
+            &qe_report.attributes,
+            &qe_identity.attributes_mask,
+            &qe_identity.attributes,
+        )
+    {
+        return Err(TeeError::CollateralMismatch);
+    }
+    let platform_status = tcb_info
+        .status(&pck_tcb.cpu_svn, pck_tcb.pce_svn, tee_tcb_svn)
+        .ok_or(TeeError::TcbStatusNotAccepted)?;
+    let qe_status = qe_identity
+        .status(qe_report.isv_svn)
+        .ok_or(TeeError::TcbStatusNotAccepted)?;
+    match converge(platform_status, qe_status) {
+        TcbStatus::Revoked => return Err(TeeError::Revoked),
+        status if !policy.accepted.contains(&status) => return Err(TeeError::TcbStatusNotAccepted),
+        _ => {}
+    }
+
+    // The workload.
+    let debug = match &quote.body {
+        Body::Sgx(report) => report.attributes[0] & SGX_DEBUG != 0,
+        Body::Td(report) => report.td_attributes[0] & TD_DEBUG != 0,
+    };
+    if debug {
+        return Err(TeeError::DebugMode);
+    }
+    let expected = match (&policy.measurement, &quote.body) {
+        (None, _) => true,
+        (Some(Measurement::SgxEnclave(mr)), Body::Sgx(report)) => *mr == report.mr_enclave,
+        (Some(Measurement::SgxSigner(mr)), Body::Sgx(report)) => *mr == report.mr_signer,
+        (Some(Measurement::TdxTd(mr)), Body::Td(report)) => *mr == report.mr_td,
+        _ => false,
+    };
+    if !expected {
+        return Err(TeeError::MeasurementMismatch);
+    }
+    if quote.body.report_data() != report_data {
+        return Err(TeeError::ReportDataMismatch);
+    }
+    Ok(())
+}
@@ -66,6 +66,16 @@ pub trait Verifier: 'static {
         pubs: &Self::Pubs,
     ) -> Result<(), VerifyError>;
 
+    /// Verify a batch of proofs, each one with its verification key and public inputs: Should
+    /// return `Ok(())` if and only if all the proofs are valid. The default implementation
+    /// just calls `verify_proof` for each element and returns the first error: override it
+    /// if your proof system can check many proofs cheaper than one by one.
+    fn verify_batch(batch: &[(&Self::Vk, &Self::Proof, &Self::Pubs)]) -> Result<(), VerifyError> {
+        batch
+            .iter()
+            .try_for_each(|(vk, proof, pubs)| Self::verify_proof(vk, proof, pubs))
+    }
+
     /// Validate the verification key: Should return `Ok(())` if the verification key is valid.
     /// The default implementation accept all verification keys: our business logic could
     /// need something different.
@@ -102,6 +112,23 @@ pub trait WeightInfo<V: Verifier> {
     /// in the case of the vk is provided via a registered vk and its hash.
     fn submit_proof_with_vk_hash(proof: &V::Proof, pubs: &V::Pubs) -> Weight;
 
+    /// Here you should map the given request to a weight computed with your verifier
+    /// in the case of the vk is explicit and the verification cost depends on the vk too.
+    /// The default implementation ignores the vk and returns `submit_proof`.
+    fn submit_proof_with_vk(_vk: &V::Vk, proof: &V::Proof, pubs: &V::Pubs) -> Weight {
+        Self::submit_proof(proof, pubs)
+    }
+
+    /// Here you should map a batch of proofs that are verified all together by
+    /// `Verifier::verify_batch` to a weight computed with your verifier: every proof comes with
+    /// a flag that tells if its vk is provided via a registered vk and its hash. `single` is the
+    /// sum of the weights of the same proofs submitted one by one, and the default
+    /// implementation just returns it: override it if your verifier implements a real
+    /// batched verification.
+    fn submit_proofs_batch(_proofs: &[(&V::Proof, &V::Pubs, bool)], single: Weight) -> Weight {
+        single
+    }
+
     /// Here you should map the given request to a weight computed with your verifier.
     fn register_vk(vk: &V::Vk) -> Weight;
 
@@ -151,4 +178,17 @@ mod unit_verifier {
             <() as Verifier>::verify_proof(&(), &(), &()).unwrap_err()
         )
     }
+
+    #[test]
+    fn should_raise_error_on_batch() {
+        assert_eq!(
+            VerifyError::VerifyError,
+            <() as Verifier>::verify_batch(&[(&(), &(), &())]).unwrap_err()
+        )
+    }
+
+    #[test]
+    fn should_accept_empty_batch() {
+        assert!(<() as Verifier>::verify_batch(&[]).is_ok())
+    }
 }
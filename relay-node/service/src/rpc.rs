@@ -16,7 +16,7 @@
 
 use crate::polkadot_rpc::{BabeDeps, FullDeps, GrandpaDeps, RpcExtension};
 use jsonrpsee::RpcModule;
-use polkadot_primitives::{AccountId, Balance, Block, Nonce};
+use polkadot_primitives::{AccountId, Balance, Block, BlockNumber, Nonce};
 use sc_client_api::AuxStore;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
@@ -50,6 +50,7 @@ where
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: proof_of_existence_rpc::PoERuntimeApi<Block>,
     C::Api: aggregate_rpc::AggregateRuntimeApi<Block>,
+    C::Api: verifiers_rpc::VerifiersRuntimeApi<Block, Balance, AccountId, BlockNumber>,
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
     P: sc_transaction_pool_api::TransactionPool + Sync + Send + 'static,
@@ -64,6 +65,7 @@ where
     use sc_consensus_babe_rpc::{Babe, BabeApiServer};
     use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
     use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
+    use verifiers_rpc::{Verifiers, VerifiersApiServer};
 
     let mut io = RpcModule::new(());
     let BabeDeps {
@@ -102,7 +104,8 @@ where
         .into_rpc(),
     )?;
     io.merge(PoE::new(client.clone()).into_rpc())?;
-    io.merge(Aggregate::new(client).into_rpc())?;
+    io.merge(Aggregate::new(client.clone()).into_rpc())?;
+    io.merge(Verifiers::new(client, deny_unsafe).into_rpc())?;
 
     Ok(io)
 }
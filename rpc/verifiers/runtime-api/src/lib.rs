@@ -0,0 +1,153 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, DecodeAll, Encode};
+
+use hp_verifiers::Verifier;
+pub use pallet_verifiers::{common::StatementInfo, DryRunError, RegisteredVk, VkMetadata};
+use pallet_verifiers::{DryRun, RegisteredVkOf, VkOrHash};
+pub use pallet_verifiers_dispatcher::VerifierInfo;
+use scale_info::TypeInfo;
+use sp_core::H256;
+#[cfg(feature = "std")]
+use sp_runtime::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+use sp_weights::Weight;
+
+sp_api::decl_runtime_apis! {
+    #[api_version(1)]
+    pub trait VerifiersApi<Balance, AccountId, BlockNumber>
+    where
+        Balance: Codec,
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        // Check the given SCALE encoded proof submission against the verifier pallet with index
+        // `verifier_id`, without changing the state.
+        fn dry_run(verifier_id: u8, vk_or_hash: Vec<u8>, proof: Vec<u8>, pubs: Vec<u8>) -> Result<DryRunResult<Balance>, RequestError>;
+        // Compute the statement hash that the verifier pallet with index `verifier_id` would
+        // produce for the given SCALE encoded Vk (or its hash) and public inputs.
+        fn statement_hash(verifier_id: u8, vk_or_hash: Vec<u8>, pubs: Vec<u8>) -> Result<H256, RequestError>;
+        // Compute the hash of the given SCALE encoded Vk as the verifier pallet with index
+        // `verifier_id` does.
+        fn vk_hash(verifier_id: u8, vk: Vec<u8>) -> Result<H256, RequestError>;
+        // Return where and how the given statement has been verified, if it is still in the
+        // statements index.
+        fn statement_info(statement: H256) -> Option<StatementInfo<AccountId, BlockNumber>>;
+        // Return the verifiers that accept proofs through the verifiers dispatcher, with their
+        // ids, hash contexts and enabled state.
+        fn list_verifiers() -> Vec<VerifierInfo>;
+        // Return the verification keys registered by `account` in the verifier pallet with
+        // index `verifier_id`, with their metadata and expiry.
+        fn registered_vks(verifier_id: u8, account: AccountId) -> Result<Vec<RegisteredVk<BlockNumber>>, RequestError>;
+    }
+}
+
+/// The request cannot be processed.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum RequestError {
+    /// There is no verifier pallet with the given index.
+    UnknownVerifier(u8),
+    /// The verification key cannot be decoded.
+    UndecodableVk,
+    /// The verification key or its hash cannot be decoded.
+    UndecodableVkOrHash,
+    /// The proof cannot be decoded.
+    UndecodableProof,
+    /// The public inputs cannot be decoded.
+    UndecodablePubs,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct DryRunResult<Balance> {
+    /// `Ok` if `submit_proof` would accept the proof, the reason of the rejection otherwise.
+    pub result: Result<(), DryRunError>,
+    /// The statement hash that `submit_proof` would produce.
+    pub statement: H256,
+    /// The weight of the `submit_proof` call.
+    pub weight: Weight,
+    /// The fee for `weight`: it doesn't include the length fee and the tip.
+    pub fee: Balance,
+}
+
+impl<Balance> DryRunResult<Balance> {
+    pub fn new(dry_run: DryRun, fee: Balance) -> Self {
+        DryRunResult {
+            result: dry_run.result,
+            statement: dry_run.statement,
+            weight: dry_run.weight,
+            fee,
+        }
+    }
+}
+
+/// Decode the SCALE encoded proof submission and check it with `pallet_verifiers::Pallet::dry_run`.
+pub fn dry_run<T, I>(
+    mut vk_or_hash: &[u8],
+    mut proof: &[u8],
+    mut pubs: &[u8],
+) -> Result<DryRun, RequestError>
+where
+    T: pallet_verifiers::Config<I>,
+    I: Verifier + 'static,
+{
+    let vk_or_hash = VkOrHash::<I::Vk>::decode_all(&mut vk_or_hash)
+        .map_err(|_| RequestError::UndecodableVkOrHash)?;
+    let proof = I::Proof::decode_all(&mut proof).map_err(|_| RequestError::UndecodableProof)?;
+    let pubs = I::Pubs::decode_all(&mut pubs).map_err(|_| RequestError::UndecodablePubs)?;
+    Ok(pallet_verifiers::Pallet::<T, I>::dry_run(
+        &vk_or_hash,
+        &proof,
+        &pubs,
+    ))
+}
+
+/// Decode the SCALE encoded Vk (or its hash) and public inputs and compute the statement hash
+/// with `pallet_verifiers::Pallet::statement_hash`.
+pub fn statement_hash<T, I>(mut vk_or_hash: &[u8], mut pubs: &[u8]) -> Result<H256, RequestError>
+where
+    T: pallet_verifiers::Config<I>,
+    I: Verifier + 'static,
+{
+    let vk_or_hash = VkOrHash::<I::Vk>::decode_all(&mut vk_or_hash)
+        .map_err(|_| RequestError::UndecodableVkOrHash)?;
+    let pubs = I::Pubs::decode_all(&mut pubs).map_err(|_| RequestError::UndecodablePubs)?;
+    Ok(pallet_verifiers::Pallet::<T, I>::statement_hash(
+        &vk_or_hash,
+        &pubs,
+    ))
+}
+
+/// Decode the SCALE encoded Vk and compute its hash with `Verifier::vk_hash`.
+pub fn vk_hash<T, I>(mut vk: &[u8]) -> Result<H256, RequestError>
+where
+    T: pallet_verifiers::Config<I>,
+    I: Verifier + 'static,
+{
+    let vk = I::Vk::decode_all(&mut vk).map_err(|_| RequestError::UndecodableVk)?;
+    Ok(I::vk_hash(&vk))
+}
+
+/// List the Vks registered by `account` with `pallet_verifiers::Pallet::registered_vks`.
+pub fn registered_vks<T, I>(account: &T::AccountId) -> Result<Vec<RegisteredVkOf<T>>, RequestError>
+where
+    T: pallet_verifiers::Config<I>,
+    I: Verifier + 'static,
+{
+    Ok(pallet_verifiers::Pallet::<T, I>::registered_vks(account))
+}
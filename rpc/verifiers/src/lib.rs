@@ -0,0 +1,238 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use sc_rpc_api::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::Block as BlockT;
+
+pub use verifiers_rpc_runtime_api::VerifiersApi as VerifiersRuntimeApi;
+use verifiers_rpc_runtime_api::{
+    DryRunResult, RegisteredVk, RequestError, StatementInfo, VerifierInfo,
+};
+
+#[rpc(client, server)]
+pub trait VerifiersApi<
+    BlockHash,
+    AccountId,
+    DryRunResponse,
+    StatementInfoResponse,
+    RegisteredVkResponse,
+>
+{
+    #[method(name = "verifiers_dryRun")]
+    fn dry_run(
+        &self,
+        at: BlockHash,
+        verifier_id: u8,
+        vk_or_hash: Bytes,
+        proof: Bytes,
+        pubs: Bytes,
+    ) -> RpcResult<DryRunResponse>;
+
+    #[method(name = "verifiers_statementHash")]
+    fn statement_hash(
+        &self,
+        at: BlockHash,
+        verifier_id: u8,
+        vk_or_hash: Bytes,
+        pubs: Bytes,
+    ) -> RpcResult<H256>;
+
+    #[method(name = "verifiers_vkHash")]
+    fn vk_hash(&self, at: BlockHash, verifier_id: u8, vk: Bytes) -> RpcResult<H256>;
+
+    #[method(name = "verifiers_statementInfo")]
+    fn statement_info(
+        &self,
+        at: BlockHash,
+        statement: H256,
+    ) -> RpcResult<Option<StatementInfoResponse>>;
+
+    #[method(name = "verifiers_list")]
+    fn list_verifiers(&self, at: BlockHash) -> RpcResult<Vec<VerifierInfo>>;
+
+    #[method(name = "verifiers_registeredVks")]
+    fn registered_vks(
+        &self,
+        at: BlockHash,
+        verifier_id: u8,
+        account: AccountId,
+    ) -> RpcResult<Vec<RegisteredVkResponse>>;
+}
+
+pub struct Verifiers<C, P> {
+    client: Arc<C>,
+    deny_unsafe: DenyUnsafe,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Verifiers<C, P> {
+    // Creates a new instance of the Verifiers Rpc helper: `dry_run` runs an unmetered
+    // verification, so it's an unsafe method.
+    pub fn new(client: Arc<C>, deny_unsafe: DenyUnsafe) -> Self {
+        Self {
+            client,
+            deny_unsafe,
+            _marker: Default::default(),
+        }
+    }
+}
+
+// Error type of this RPC api.
+pub enum Error {
+    /// Verifier not found
+    UnknownVerifier,
+    /// The request data was not decodable.
+    DecodeError,
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::UnknownVerifier => 1,
+            Error::DecodeError => 2,
+            Error::RuntimeError => 3,
+        }
+    }
+}
+
+impl<C, Block, Balance, AccountId, BlockNumber>
+    VerifiersApiServer<
+        <Block as BlockT>::Hash,
+        AccountId,
+        DryRunResult<Balance>,
+        StatementInfo<AccountId, BlockNumber>,
+        RegisteredVk<BlockNumber>,
+    > for Verifiers<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: VerifiersRuntimeApi<Block, Balance, AccountId, BlockNumber>,
+    Balance: Codec + serde::Serialize + Send + Sync + 'static,
+    AccountId: Codec + serde::Serialize + serde::de::DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Codec + serde::Serialize + Send + Sync + 'static,
+{
+    fn dry_run(
+        &self,
+        at: Block::Hash,
+        verifier_id: u8,
+        vk_or_hash: Bytes,
+        proof: Bytes,
+        pubs: Bytes,
+    ) -> RpcResult<DryRunResult<Balance>> {
+        self.deny_unsafe.check_if_safe()?;
+        self.client
+            .runtime_api()
+            .dry_run(at, verifier_id, vk_or_hash.0, proof.0, pubs.0)
+            .map_err(|e| map_err(e, "Unable to dry run the proof."))
+            .and_then(|r| r.map_err(convert_request_error))
+            .map_err(Into::into)
+    }
+
+    fn statement_hash(
+        &self,
+        at: Block::Hash,
+        verifier_id: u8,
+        vk_or_hash: Bytes,
+        pubs: Bytes,
+    ) -> RpcResult<H256> {
+        self.client
+            .runtime_api()
+            .statement_hash(at, verifier_id, vk_or_hash.0, pubs.0)
+            .map_err(|e| map_err(e, "Unable to compute the statement hash."))
+            .and_then(|r| r.map_err(convert_request_error))
+            .map_err(Into::into)
+    }
+
+    fn vk_hash(&self, at: Block::Hash, verifier_id: u8, vk: Bytes) -> RpcResult<H256> {
+        self.client
+            .runtime_api()
+            .vk_hash(at, verifier_id, vk.0)
+            .map_err(|e| map_err(e, "Unable to compute the vk hash."))
+            .and_then(|r| r.map_err(convert_request_error))
+            .map_err(Into::into)
+    }
+
+    fn statement_info(
+        &self,
+        at: Block::Hash,
+        statement: H256,
+    ) -> RpcResult<Option<StatementInfo<AccountId, BlockNumber>>> {
+        self.client
+            .runtime_api()
+            .statement_info(at, statement)
+            .map_err(|e| map_err(e, "Unable to query the statement info."))
+            .map_err(Into::into)
+    }
+
+    fn list_verifiers(&self, at: Block::Hash) -> RpcResult<Vec<VerifierInfo>> {
+        self.client
+            .runtime_api()
+            .list_verifiers(at)
+            .map_err(|e| map_err(e, "Unable to list the verifiers."))
+            .map_err(Into::into)
+    }
+
+    fn registered_vks(
+        &self,
+        at: Block::Hash,
+        verifier_id: u8,
+        account: AccountId,
+    ) -> RpcResult<Vec<RegisteredVk<BlockNumber>>> {
+        self.client
+            .runtime_api()
+            .registered_vks(at, verifier_id, account)
+            .map_err(|e| map_err(e, "Unable to list the registered vks."))
+            .and_then(|r| r.map_err(convert_request_error))
+            .map_err(Into::into)
+    }
+}
+
+fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+    ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+}
+
+fn convert_request_error(e: RequestError) -> ErrorObjectOwned {
+    let undecodable = |what: &str| {
+        ErrorObject::owned(
+            Error::DecodeError.into(),
+            "Invalid request",
+            Some(format!("Cannot decode {what}")),
+        )
+    };
+    match e {
+        RequestError::UnknownVerifier(id) => ErrorObject::owned(
+            Error::UnknownVerifier.into(),
+            "Verifier not found",
+            Some(format!("No verifier pallet with index {id}")),
+        ),
+        RequestError::UndecodableVk => undecodable("verification key"),
+        RequestError::UndecodableVkOrHash => undecodable("verification key or its hash"),
+        RequestError::UndecodableProof => undecodable("proof"),
+        RequestError::UndecodablePubs => undecodable("public inputs"),
+    }
+}
@@ -866,26 +866,39 @@ type VkRegistrationHoldConsideration = HoldConsideration<
     LinearStoragePrice<VkRegistrationBaseDeposit, VkRegistrationByteDeposit, Balance>,
 >;
 
+parameter_types! {
+    pub const VerifiersStatementRetention: Option<BlockNumber> = Some(DAYS);
+}
+
 impl pallet_verifiers::common::Config for Runtime {
-    type CommonWeightInfo = Runtime;
+    type CommonWeightInfo = weights::pallet_verifiers::ZKVWeight<Runtime>;
+    type StatementRetention = VerifiersStatementRetention;
+}
+
+parameter_types! {
+    pub const VerifiersMaxBatchSize: u32 = 32;
 }
 
 impl pallet_verifiers::Config<pallet_fflonk_verifier::Fflonk> for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnProofVerified = (Poe, Aggregate);
+    type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<(Poe, Aggregate)>;
+    type OnProofRejected = ();
     type WeightInfo =
         pallet_fflonk_verifier::FflonkWeight<weights::pallet_fflonk_verifier::ZKVWeight<Runtime>>;
     type Ticket = VkRegistrationHoldConsideration;
+    type MaxBatchSize = VerifiersMaxBatchSize;
     #[cfg(feature = "runtime-benchmarks")]
     type Currency = Balances;
 }
 
 impl pallet_verifiers::Config<pallet_zksync_verifier::Zksync> for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnProofVerified = (Poe, Aggregate);
+    type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<(Poe, Aggregate)>;
+    type OnProofRejected = ();
     type WeightInfo =
         pallet_zksync_verifier::ZksyncWeight<weights::pallet_zksync_verifier::ZKVWeight<Runtime>>;
     type Ticket = VkRegistrationHoldConsideration;
+    type MaxBatchSize = VerifiersMaxBatchSize;
     #[cfg(feature = "runtime-benchmarks")]
     type Currency = Balances;
 }
@@ -897,6 +910,8 @@ parameter_types! {
 
 impl pallet_groth16_verifier::Config for Runtime {
     const MAX_NUM_INPUTS: u32 = Groth16MaxNumInputs::get();
+    // BLS12-377 and BW6-761 are not supported yet: they are available just to benchmark them.
+    const ACCEPT_UNBENCHMARKED_CURVES: bool = cfg!(feature = "runtime-benchmarks");
 }
 
 // We should be sure that the max number of inputs does not exceed the max number of inputs in the verifier crate.
@@ -907,11 +922,13 @@ const_assert!(
 
 impl pallet_verifiers::Config<pallet_groth16_verifier::Groth16<Runtime>> for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnProofVerified = (Poe, Aggregate);
+    type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<(Poe, Aggregate)>;
+    type OnProofRejected = ();
     type WeightInfo = pallet_groth16_verifier::Groth16Weight<
         weights::pallet_groth16_verifier::ZKVWeight<Runtime>,
     >;
     type Ticket = VkRegistrationHoldConsideration;
+    type MaxBatchSize = VerifiersMaxBatchSize;
     #[cfg(feature = "runtime-benchmarks")]
     type Currency = Balances;
 }
@@ -930,10 +947,12 @@ impl pallet_risc0_verifier::Config for Runtime {
 
 impl pallet_verifiers::Config<pallet_risc0_verifier::Risc0<Runtime>> for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnProofVerified = (Poe, Aggregate);
+    type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<(Poe, Aggregate)>;
+    type OnProofRejected = ();
     type WeightInfo =
         pallet_risc0_verifier::Risc0Weight<weights::pallet_risc0_verifier::ZKVWeight<Runtime>>;
     type Ticket = VkRegistrationHoldConsideration;
+    type MaxBatchSize = VerifiersMaxBatchSize;
     #[cfg(feature = "runtime-benchmarks")]
     type Currency = Balances;
 }
@@ -948,11 +967,13 @@ impl pallet_ultraplonk_verifier::Config for Runtime {
 
 impl pallet_verifiers::Config<pallet_ultraplonk_verifier::Ultraplonk<Runtime>> for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnProofVerified = (Poe, Aggregate);
+    type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<(Poe, Aggregate)>;
+    type OnProofRejected = ();
     type WeightInfo = pallet_ultraplonk_verifier::UltraplonkWeight<
         weights::pallet_ultraplonk_verifier::ZKVWeight<Runtime>,
     >;
     type Ticket = VkRegistrationHoldConsideration;
+    type MaxBatchSize = VerifiersMaxBatchSize;
     #[cfg(feature = "runtime-benchmarks")]
     type Currency = Balances;
 }
@@ -972,15 +993,107 @@ const_assert!(
 
 impl pallet_verifiers::Config<pallet_proofofsql_verifier::ProofOfSql<Runtime>> for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnProofVerified = (Poe, Aggregate);
+    type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<(Poe, Aggregate)>;
+    type OnProofRejected = ();
     type WeightInfo = pallet_proofofsql_verifier::ProofOfSqlWeight<
         weights::pallet_proofofsql_verifier::ZKVWeight<Runtime>,
     >;
     type Ticket = VkRegistrationHoldConsideration;
+    type MaxBatchSize = VerifiersMaxBatchSize;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Currency = Balances;
+}
+
+pub const PLONK_MAX_NUM_INPUTS: u32 = 32;
+parameter_types! {
+    pub const PlonkMaxNumInputs: u32 = PLONK_MAX_NUM_INPUTS;
+}
+
+impl pallet_plonk_verifier::Config for Runtime {
+    const MAX_NUM_INPUTS: u32 = PlonkMaxNumInputs::get();
+}
+
+// We should be sure that the max number of inputs does not exceed the max number of inputs in the verifier crate.
+const_assert!(
+    <Runtime as pallet_plonk_verifier::Config>::MAX_NUM_INPUTS
+        <= pallet_plonk_verifier::MAX_NUM_INPUTS
+);
+
+impl pallet_verifiers::Config<pallet_plonk_verifier::Plonk<Runtime>> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<(Poe, Aggregate)>;
+    type OnProofRejected = ();
+    type WeightInfo =
+        pallet_plonk_verifier::PlonkWeight<weights::pallet_plonk_verifier::ZKVWeight<Runtime>>;
+    type Ticket = VkRegistrationHoldConsideration;
+    type MaxBatchSize = VerifiersMaxBatchSize;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Currency = Balances;
+}
+
+parameter_types! {
+    pub const TeeMaxProofSize: u32 = 64 * 1024;
+}
+
+impl pallet_tee_verifier::Config for Runtime {
+    type MaxProofSize = TeeMaxProofSize;
+    type UnixTime = Timestamp;
+}
+
+impl pallet_verifiers::Config<pallet_tee_verifier::Tee<Runtime>> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<(Poe, Aggregate)>;
+    type OnProofRejected = ();
+    type WeightInfo =
+        pallet_tee_verifier::TeeWeight<weights::pallet_tee_verifier::ZKVWeight<Runtime>>;
+    type Ticket = VkRegistrationHoldConsideration;
+    type MaxBatchSize = VerifiersMaxBatchSize;
     #[cfg(feature = "runtime-benchmarks")]
     type Currency = Balances;
 }
 
+impl pallet_verifiers_dispatcher::Config for Runtime {
+    type Verifiers = (
+        pallet_verifiers_dispatcher::Route<Runtime, pallet_fflonk_verifier::Fflonk>,
+        pallet_verifiers_dispatcher::Route<Runtime, pallet_zksync_verifier::Zksync>,
+        pallet_verifiers_dispatcher::Route<Runtime, pallet_groth16_verifier::Groth16<Runtime>>,
+        pallet_verifiers_dispatcher::Route<Runtime, pallet_risc0_verifier::Risc0<Runtime>>,
+        pallet_verifiers_dispatcher::Route<
+            Runtime,
+            pallet_ultraplonk_verifier::Ultraplonk<Runtime>,
+        >,
+        pallet_verifiers_dispatcher::Route<
+            Runtime,
+            pallet_proofofsql_verifier::ProofOfSql<Runtime>,
+        >,
+        pallet_verifiers_dispatcher::Route<Runtime, pallet_plonk_verifier::Plonk<Runtime>>,
+        pallet_verifiers_dispatcher::Route<Runtime, pallet_tee_verifier::Tee<Runtime>>,
+    );
+    type WeightInfo = weights::pallet_verifiers_dispatcher::ZKVWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = Runtime;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_verifiers_dispatcher::BenchmarkHelper for Runtime {
+    fn payload(len: u32) -> (u8, Vec<u8>) {
+        use codec::Encode;
+        use frame_support::traits::PalletInfoAccess;
+
+        // The Proof-of-SQL proof is a plain byte vector, so the payload can have any size.
+        let payload = pallet_verifiers::ProofSubmission::<
+            pallet_proofofsql_verifier::ProofOfSql<Runtime>,
+        >::new(
+            pallet_verifiers::VkOrHash::from_hash(H256::zero()),
+            vec![0; len as usize],
+            Vec::new(),
+            None,
+        )
+        .encode();
+        (SettlementProofOfSqlPallet::index() as u8, payload)
+    }
+}
+
 parameter_types! {
     pub const Coprocessor: Option<StateMachine> = Some(StateMachine::Kusama(4009));
     pub const HostStateMachine: StateMachine = StateMachine::Substrate(*b"zkv_");
@@ -1065,6 +1178,9 @@ construct_runtime!(
         Proxy: pallet_proxy,
         CommonVerifiers: pallet_verifiers::common,
         SettlementProofOfSqlPallet: pallet_proofofsql_verifier,
+        SettlementPlonkPallet: pallet_plonk_verifier,
+        SettlementTeePallet: pallet_tee_verifier,
+        VerifiersDispatcher: pallet_verifiers_dispatcher,
         Aggregate: pallet_aggregate,
         Ismp: pallet_ismp,
         IsmpGrandpa: ismp_grandpa,
@@ -1163,6 +1279,9 @@ construct_runtime!(
         SettlementRisc0Pallet: pallet_risc0_verifier = 164,
         SettlementUltraplonkPallet: pallet_ultraplonk_verifier = 165,
         SettlementProofOfSqlPallet: pallet_proofofsql_verifier = 166,
+        VerifiersDispatcher: pallet_verifiers_dispatcher = 167,
+        SettlementPlonkPallet: pallet_plonk_verifier = 168,
+        SettlementTeePallet: pallet_tee_verifier = 173,
     }
 );
 
@@ -1251,6 +1370,10 @@ mod benches {
         [pallet_risc0_verifier, Risc0VerifierBench::<Runtime>]
         [pallet_ultraplonk_verifier, UltraplonkVerifierBench::<Runtime>]
         [pallet_proofofsql_verifier, ProofOfSqlVerifierBench::<Runtime>]
+        [pallet_plonk_verifier, PlonkVerifierBench::<Runtime>]
+        [pallet_tee_verifier, TeeVerifierBench::<Runtime>]
+        [pallet_verifiers, VerifiersBench::<Runtime, pallet_zksync_verifier::Zksync>]
+        [pallet_verifiers_dispatcher, VerifiersDispatcher]
     );
 }
 
@@ -1289,6 +1412,10 @@ mod benches {
         [pallet_risc0_verifier, Risc0VerifierBench::<Runtime>]
         [pallet_ultraplonk_verifier, UltraplonkVerifierBench::<Runtime>]
         [pallet_proofofsql_verifier, ProofOfSqlVerifierBench::<Runtime>]
+        [pallet_plonk_verifier, PlonkVerifierBench::<Runtime>]
+        [pallet_tee_verifier, TeeVerifierBench::<Runtime>]
+        [pallet_verifiers, VerifiersBench::<Runtime, pallet_zksync_verifier::Zksync>]
+        [pallet_verifiers_dispatcher, VerifiersDispatcher]
         // parachains
         [crate::parachains::configuration, Configuration]
         [crate::parachains::disputes, ParasDisputes]
@@ -1336,6 +1463,35 @@ sp_api::decl_runtime_apis! {
     }
 }
 
+/// Call the generic function `$f::<Runtime, V>($args)`, where `V` is the verifier handled by the
+/// verifier pallet with index `$verifier_id`, or return `RequestError::UnknownVerifier` if there is
+/// no such pallet.
+macro_rules! with_verifier {
+    ($verifier_id:expr, $($f:ident)::+ ( $($arg:expr),* )) => {{
+        use frame_support::traits::PalletInfoAccess;
+        let id = $verifier_id as usize;
+        match id {
+            _ if id == SettlementFFlonkPallet::index() =>
+                $($f)::+::<Runtime, pallet_fflonk_verifier::Fflonk>($($arg),*),
+            _ if id == SettlementZksyncPallet::index() =>
+                $($f)::+::<Runtime, pallet_zksync_verifier::Zksync>($($arg),*),
+            _ if id == SettlementGroth16Pallet::index() =>
+                $($f)::+::<Runtime, pallet_groth16_verifier::Groth16<Runtime>>($($arg),*),
+            _ if id == SettlementRisc0Pallet::index() =>
+                $($f)::+::<Runtime, pallet_risc0_verifier::Risc0<Runtime>>($($arg),*),
+            _ if id == SettlementUltraplonkPallet::index() =>
+                $($f)::+::<Runtime, pallet_ultraplonk_verifier::Ultraplonk<Runtime>>($($arg),*),
+            _ if id == SettlementProofOfSqlPallet::index() =>
+                $($f)::+::<Runtime, pallet_proofofsql_verifier::ProofOfSql<Runtime>>($($arg),*),
+            _ if id == SettlementPlonkPallet::index() =>
+                $($f)::+::<Runtime, pallet_plonk_verifier::Plonk<Runtime>>($($arg),*),
+            _ if id == SettlementTeePallet::index() =>
+                $($f)::+::<Runtime, pallet_tee_verifier::Tee<Runtime>>($($arg),*),
+            _ => Err(verifiers_rpc_runtime_api::RequestError::UnknownVerifier($verifier_id)),
+        }
+    }};
+}
+
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> RuntimeVersion {
@@ -1618,6 +1774,51 @@ impl_runtime_apis! {
         }
     }
 
+    impl verifiers_rpc_runtime_api::VerifiersApi<Block, Balance, AccountId, BlockNumber> for Runtime {
+        fn dry_run(
+            verifier_id: u8,
+            vk_or_hash: Vec<u8>,
+            proof: Vec<u8>,
+            pubs: Vec<u8>,
+        ) -> Result<verifiers_rpc_runtime_api::DryRunResult<Balance>, verifiers_rpc_runtime_api::RequestError> {
+            let dry_run = with_verifier!(verifier_id, verifiers_rpc_runtime_api::dry_run(&vk_or_hash, &proof, &pubs))?;
+            let fee = TransactionPayment::weight_to_fee(dry_run.weight);
+            Ok(verifiers_rpc_runtime_api::DryRunResult::new(dry_run, fee))
+        }
+
+        fn statement_hash(
+            verifier_id: u8,
+            vk_or_hash: Vec<u8>,
+            pubs: Vec<u8>,
+        ) -> Result<H256, verifiers_rpc_runtime_api::RequestError> {
+            with_verifier!(verifier_id, verifiers_rpc_runtime_api::statement_hash(&vk_or_hash, &pubs))
+        }
+
+        fn vk_hash(
+            verifier_id: u8,
+            vk: Vec<u8>,
+        ) -> Result<H256, verifiers_rpc_runtime_api::RequestError> {
+            with_verifier!(verifier_id, verifiers_rpc_runtime_api::vk_hash(&vk))
+        }
+
+        fn statement_info(
+            statement: H256,
+        ) -> Option<verifiers_rpc_runtime_api::StatementInfo<AccountId, BlockNumber>> {
+            CommonVerifiers::statement_info(statement)
+        }
+
+        fn list_verifiers() -> Vec<verifiers_rpc_runtime_api::VerifierInfo> {
+            VerifiersDispatcher::list_verifiers()
+        }
+
+        fn registered_vks(
+            verifier_id: u8,
+            account: AccountId,
+        ) -> Result<Vec<verifiers_rpc_runtime_api::RegisteredVk<BlockNumber>>, verifiers_rpc_runtime_api::RequestError> {
+            with_verifier!(verifier_id, verifiers_rpc_runtime_api::registered_vks(&account))
+        }
+    }
+
     #[cfg(feature = "relay")]
     #[api_version(10)]
     impl primitives::runtime_api::ParachainHost<Block> for Runtime {
@@ -1795,6 +1996,9 @@ impl_runtime_apis! {
             use pallet_risc0_verifier::benchmarking::Pallet as Risc0VerifierBench;
             use pallet_ultraplonk_verifier::benchmarking::Pallet as UltraplonkVerifierBench;
             use pallet_proofofsql_verifier::benchmarking::Pallet as ProofOfSqlVerifierBench;
+            use pallet_plonk_verifier::benchmarking::Pallet as PlonkVerifierBench;
+            use pallet_tee_verifier::benchmarking::Pallet as TeeVerifierBench;
+            use pallet_verifiers::benchmarking::Pallet as VerifiersBench;
 
             #[cfg(feature = "relay")]
             pub mod xcm {
@@ -1826,6 +2030,9 @@ impl_runtime_apis! {
             use pallet_risc0_verifier::benchmarking::Pallet as Risc0VerifierBench;
             use pallet_ultraplonk_verifier::benchmarking::Pallet as UltraplonkVerifierBench;
             use pallet_proofofsql_verifier::benchmarking::Pallet as ProofOfSqlVerifierBench;
+            use pallet_plonk_verifier::benchmarking::Pallet as PlonkVerifierBench;
+            use pallet_tee_verifier::benchmarking::Pallet as TeeVerifierBench;
+            use pallet_verifiers::benchmarking::Pallet as VerifiersBench;
 
             #[cfg(feature = "relay")]
             pub mod xcm {
@@ -96,4 +96,71 @@ pub type Unreleased = (
         pallet_ultraplonk_verifier::Ultraplonk<Runtime>,
     >,
     pallet_verifiers::migrations::v1::MigrateV0ToV1<Runtime, pallet_zksync_verifier::Zksync>,
+    VerifiersInitStorageVersion,
+    VerifiersMigrateV1ToV2,
+    VerifiersMigrateV2ToV3,
+);
+
+/// Set the storage version of the verifier pallets added in this release: they start with the
+/// current storage layout.
+pub type VerifiersInitStorageVersion = (
+    pallet_verifiers::migrations::init::InitStorageVersion<
+        Runtime,
+        pallet_plonk_verifier::Plonk<Runtime>,
+    >,
+    pallet_verifiers::migrations::init::InitStorageVersion<
+        Runtime,
+        pallet_tee_verifier::Tee<Runtime>,
+    >,
+);
+
+/// Index the registered verification keys by account in all the verifier pallets deployed
+/// before this release.
+pub type VerifiersMigrateV1ToV2 = (
+    pallet_verifiers::migrations::v2::MigrateV1ToV2<Runtime, pallet_fflonk_verifier::Fflonk>,
+    pallet_verifiers::migrations::v2::MigrateV1ToV2<Runtime, pallet_zksync_verifier::Zksync>,
+    pallet_verifiers::migrations::v2::MigrateV1ToV2<
+        Runtime,
+        pallet_groth16_verifier::Groth16<Runtime>,
+    >,
+    pallet_verifiers::migrations::v2::MigrateV1ToV2<Runtime, pallet_risc0_verifier::Risc0<Runtime>>,
+    pallet_verifiers::migrations::v2::MigrateV1ToV2<
+        Runtime,
+        pallet_ultraplonk_verifier::Ultraplonk<Runtime>,
+    >,
+    pallet_verifiers::migrations::v2::MigrateV1ToV2<
+        Runtime,
+        pallet_proofofsql_verifier::ProofOfSql<Runtime>,
+    >,
+);
+
+/// Translate the registered verification keys whose format changed in all the verifier pallets.
+pub type VerifiersMigrateV2ToV3 = (
+    pallet_verifiers::migrations::v3::MigrateV2ToV3<
+        Runtime,
+        pallet_fflonk_verifier::Fflonk,
+        pallet_fflonk_verifier::migrations::SetSingleInput,
+    >,
+    pallet_verifiers::migrations::v3::RekeyV2ToV3<
+        Runtime,
+        pallet_zksync_verifier::Zksync,
+        pallet_zksync_verifier::migrations::SetEraVk,
+    >,
+    pallet_verifiers::migrations::v3::MigrateV2ToV3<
+        Runtime,
+        pallet_groth16_verifier::Groth16<Runtime>,
+        pallet_groth16_verifier::migrations::AddVkCommitment,
+    >,
+    pallet_verifiers::migrations::v3::UnchangedV2ToV3<
+        Runtime,
+        pallet_risc0_verifier::Risc0<Runtime>,
+    >,
+    pallet_verifiers::migrations::v3::UnchangedV2ToV3<
+        Runtime,
+        pallet_ultraplonk_verifier::Ultraplonk<Runtime>,
+    >,
+    pallet_verifiers::migrations::v3::UnchangedV2ToV3<
+        Runtime,
+        pallet_proofofsql_verifier::ProofOfSql<Runtime>,
+    >,
 );
@@ -67,7 +67,10 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 RuntimeCall::SettlementZksyncPallet(..) |
                 RuntimeCall::SettlementGroth16Pallet(..) |
                 RuntimeCall::SettlementRisc0Pallet(..) |
-                RuntimeCall::SettlementUltraplonkPallet(..)
+                RuntimeCall::SettlementUltraplonkPallet(..) |
+                RuntimeCall::SettlementPlonkPallet(..) |
+                RuntimeCall::SettlementTeePallet(..) |
+                RuntimeCall::VerifiersDispatcher(..)
             ),
             ProxyType::Governance => matches!(
                 c,
@@ -262,7 +262,8 @@ fn pallet_risc0() {
         let dummy_origin = AccountId32::new([0; 32]);
 
         let dummy_vk = H256::default();
-        let dummy_proof = vec![];
+        let dummy_proof =
+            pallet_risc0_verifier::Proof::V1_0(pallet_risc0_verifier::Receipt::Composite(vec![]));
         let dummy_pubs = vec![];
 
         assert!(SettlementRisc0Pallet::submit_proof(
@@ -319,6 +320,44 @@ fn pallet_proofofsql_availability() {
     });
 }
 
+#[test]
+fn pallet_plonk() {
+    test().execute_with(|| {
+        let dummy_origin = AccountId32::new([0; 32]);
+        let dummy_proof = [0; pallet_plonk_verifier::PROOF_SIZE];
+        let dummy_pubs = Vec::new();
+
+        assert!(SettlementPlonkPallet::submit_proof(
+            RuntimeOrigin::signed(dummy_origin),
+            VkOrHash::from_hash(H256::zero()),
+            dummy_proof.into(),
+            dummy_pubs.into(),
+            None,
+        )
+        .is_err());
+        // just checking code builds, hence the pallet is available to the runtime
+    });
+}
+
+#[test]
+fn pallet_tee() {
+    test().execute_with(|| {
+        let dummy_origin = AccountId32::new([0; 32]);
+        let dummy_proof = pallet_tee_verifier::Proof::default();
+        let dummy_pubs = [0; pallet_tee_verifier::REPORT_DATA_SIZE];
+
+        assert!(SettlementTeePallet::submit_proof(
+            RuntimeOrigin::signed(dummy_origin),
+            VkOrHash::from_hash(H256::zero()),
+            dummy_proof.into(),
+            dummy_pubs.into(),
+            None,
+        )
+        .is_err());
+        // just checking code builds, hence the pallet is available to the runtime
+    });
+}
+
 // Test definition and execution. Test body must be written in the execute_with closure.
 #[test]
 fn pallet_poe() {
@@ -503,7 +503,10 @@ mod scheduler {
                 >::new_call_variant_submit_proof(
                     VkOrHash::from_hash(H256::zero()),
                     [0; pallet_fflonk_verifier::PROOF_SIZE].into(),
-                    [0; pallet_fflonk_verifier::PUBS_SIZE].into(),
+                    pallet_fflonk_verifier::Pubs::truncate_from(vec![
+                        [0; pallet_fflonk_verifier::PUBS_SIZE],
+                    ])
+                    .into(),
                     None,
                 )),
             );
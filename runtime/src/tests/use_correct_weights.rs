@@ -284,8 +284,30 @@ fn pallet_verifiers() {
     use pallet_verifiers::common::WeightInfo;
 
     assert_eq!(
-        <Runtime as pallet_verifiers::common::Config>::CommonWeightInfo::on_verify_disabled_verifier(),
-        <Runtime as pallet_verifiers::common::WeightInfo>::on_verify_disabled_verifier()
+        <Runtime as pallet_verifiers::common::Config>::CommonWeightInfo::index_statement(),
+        crate::weights::pallet_verifiers::ZKVWeight::<Runtime>::index_statement()
+    );
+    assert_eq!(
+        <Runtime as pallet_verifiers::common::Config>::CommonWeightInfo::revoke_vk(),
+        crate::weights::pallet_verifiers::ZKVWeight::<Runtime>::revoke_vk()
+    );
+    assert_eq!(
+        <Runtime as pallet_verifiers::common::Config>::CommonWeightInfo::accept_vk_transfer(),
+        crate::weights::pallet_verifiers::ZKVWeight::<Runtime>::accept_vk_transfer()
+    );
+    assert_eq!(
+        <Runtime as pallet_verifiers::common::Config>::CommonWeightInfo::expire_vk(),
+        crate::weights::pallet_verifiers::ZKVWeight::<Runtime>::expire_vk()
+    );
+}
+
+#[test]
+fn pallet_verifiers_dispatcher() {
+    use pallet_verifiers_dispatcher::WeightInfo;
+
+    assert_eq!(
+        <Runtime as pallet_verifiers_dispatcher::Config>::WeightInfo::submit(42),
+        crate::weights::pallet_verifiers_dispatcher::ZKVWeight::<Runtime>::submit(42)
     );
 }
 
@@ -21,7 +21,8 @@ use super::*;
 fn pallet_fflonk_verifier() {
     use pallet_fflonk_verifier::Fflonk;
     let dummy_proof = [0; pallet_fflonk_verifier::PROOF_SIZE];
-    let dummy_pubs = [0; pallet_fflonk_verifier::PUBS_SIZE];
+    let dummy_pubs =
+        pallet_fflonk_verifier::Pubs::truncate_from(vec![[0; pallet_fflonk_verifier::PUBS_SIZE]]);
     use pallet_fflonk_verifier::WeightInfo;
 
     assert_eq!(
@@ -33,6 +34,27 @@ fn pallet_fflonk_verifier() {
     );
 }
 
+#[test]
+fn pallet_fflonk_verifier_with_more_inputs() {
+    use pallet_fflonk_verifier::Fflonk;
+    let dummy_proof = [0; pallet_fflonk_verifier::PROOF_SIZE];
+    let dummy_pubs = pallet_fflonk_verifier::Pubs::truncate_from(vec![
+            [0; pallet_fflonk_verifier::PUBS_SIZE];
+            4
+        ]);
+    use crate::weights::pallet_fflonk_verifier::ZKVWeight;
+    use pallet_fflonk_verifier::WeightInfo;
+
+    assert_eq!(
+        <<Runtime as pallet_verifiers::Config<Fflonk>>::WeightInfo as pallet_verifiers::WeightInfo<Fflonk>>::submit_proof(
+            &dummy_proof,
+            &dummy_pubs
+        ),
+        ZKVWeight::<Runtime>::submit_proof() + ZKVWeight::<Runtime>::verify_proof(4)
+            - ZKVWeight::<Runtime>::verify_proof(1)
+    );
+}
+
 #[test]
 fn pallet_zksync_verifier() {
     use pallet_zksync_verifier::Zksync;
@@ -65,19 +87,101 @@ fn pallet_groth16_verifier() {
     );
 }
 
+#[test]
+fn pallet_groth16_verifier_batch() {
+    use pallet_groth16_verifier::Groth16;
+    use pallet_groth16_verifier::WeightInfo;
+
+    assert_eq!(
+        <<Runtime as pallet_verifiers::Config<Groth16<Runtime>>>::WeightInfo as
+            pallet_verifiers::WeightInfo<Groth16<Runtime>>>
+            ::submit_proofs_batch(
+            &[(&pallet_groth16_verifier::Proof::default(), &Vec::new(), false)],
+            frame_support::weights::Weight::MAX
+        ),
+        crate::weights::pallet_groth16_verifier::ZKVWeight::<Runtime>::submit_proofs_bn254(1)
+    );
+}
+
+#[test]
+fn pallet_groth16_verifier_batch_with_vk_hash() {
+    use pallet_groth16_verifier::Groth16;
+    use pallet_groth16_verifier::WeightInfo;
+
+    let (proof, pubs) = (pallet_groth16_verifier::Proof::default(), Vec::new());
+
+    assert_eq!(
+        <<Runtime as pallet_verifiers::Config<Groth16<Runtime>>>::WeightInfo as
+            pallet_verifiers::WeightInfo<Groth16<Runtime>>>
+            ::submit_proofs_batch(
+            &[(&proof, &pubs, false), (&proof, &pubs, true)],
+            frame_support::weights::Weight::MAX
+        ),
+        crate::weights::pallet_groth16_verifier::ZKVWeight::<Runtime>::submit_proofs_bn254_with_vk_hash(2)
+    );
+}
+
+#[test]
+fn pallet_groth16_verifier_batch_on_many_curves() {
+    use pallet_groth16_verifier::{Curve, Groth16, WeightInfo};
+
+    let proof = |curve| pallet_groth16_verifier::Proof {
+        curve,
+        ..Default::default()
+    };
+    let (bn254, bw6_761) = (proof(Curve::Bn254), proof(Curve::Bw6_761));
+    let pubs = Vec::new();
+
+    assert_eq!(
+        <<Runtime as pallet_verifiers::Config<Groth16<Runtime>>>::WeightInfo as
+            pallet_verifiers::WeightInfo<Groth16<Runtime>>>
+            ::submit_proofs_batch(
+            &[(&bn254, &pubs, false), (&bw6_761, &pubs, false), (&bn254, &pubs, false)],
+            frame_support::weights::Weight::MAX
+        ),
+        crate::weights::pallet_groth16_verifier::ZKVWeight::<Runtime>::submit_proofs_bn254(2)
+            .saturating_add(
+                crate::weights::pallet_groth16_verifier::ZKVWeight::<Runtime>::submit_proofs_bw6_761(1)
+            )
+    );
+}
+
+#[test]
+fn pallet_groth16_verifier_with_commitment() {
+    use pallet_groth16_verifier::{Groth16, ProofCommitment, WeightInfo, G1};
+
+    let proof = pallet_groth16_verifier::Proof::default().with_commitment(ProofCommitment {
+        commitment: G1(Vec::new()),
+        pok: G1(Vec::new()),
+    });
+
+    assert_eq!(
+        <<Runtime as pallet_verifiers::Config<Groth16<Runtime>>>::WeightInfo as
+            pallet_verifiers::WeightInfo<Groth16<Runtime>>>
+            ::submit_proof(
+            &proof,
+            &Vec::new()
+        ),
+        crate::weights::pallet_groth16_verifier::ZKVWeight::<Runtime>::submit_proof_bn254(0)
+            .saturating_add(
+                crate::weights::pallet_groth16_verifier::ZKVWeight::<Runtime>::submit_proofs_bn254(2)
+                    .saturating_sub(crate::weights::pallet_groth16_verifier::ZKVWeight::<Runtime>::submit_proofs_bn254(1))
+            )
+    );
+}
+
 #[test]
 fn pallet_settlement_risc0() {
-    use pallet_risc0_verifier::Risc0;
-    use pallet_risc0_verifier::WeightInfo;
+    use pallet_risc0_verifier::{Proof, Receipt, Risc0, WeightInfo};
 
     assert_eq!(
         <<Runtime as pallet_verifiers::Config<Risc0<Runtime>>>::WeightInfo as
             pallet_verifiers::WeightInfo<Risc0<Runtime>>>
             ::submit_proof(
-            &Vec::new(),
+            &Proof::V1_0(Receipt::Succinct(Vec::new())),
             &Vec::new()
         ),
-        crate::weights::pallet_risc0_verifier::ZKVWeight::<Runtime>::submit_proof_cycle_2_pow_13()
+        crate::weights::pallet_risc0_verifier::ZKVWeight::<Runtime>::submit_proof_cycle_2_pow_20()
     );
 }
 
@@ -107,6 +211,37 @@ fn pallet_settlement_proofofsql() {
             &Vec::new(),
             &Vec::new()
         ),
-        crate::weights::pallet_proofofsql_verifier::ZKVWeight::<Runtime>::submit_proof()
+        crate::weights::pallet_proofofsql_verifier::ZKVWeight::<Runtime>::submit_proof_max_nu_8()
+    );
+}
+
+#[test]
+fn pallet_settlement_plonk() {
+    use pallet_plonk_verifier::{Plonk, WeightInfo};
+
+    assert_eq!(
+        <<Runtime as pallet_verifiers::Config<Plonk<Runtime>>>::WeightInfo as
+            pallet_verifiers::WeightInfo<Plonk<Runtime>>>
+            ::submit_proof(
+            &[0; pallet_plonk_verifier::PROOF_SIZE],
+            &vec![[0; pallet_plonk_verifier::FR_SIZE]; 3]
+        ),
+        crate::weights::pallet_plonk_verifier::ZKVWeight::<Runtime>::submit_proof(3)
+    );
+}
+
+#[test]
+fn pallet_settlement_tee() {
+    use pallet_tee_verifier::{Proof, Tee, WeightInfo};
+
+    assert_eq!(
+        <<Runtime as pallet_verifiers::Config<Tee<Runtime>>>::WeightInfo as
+            pallet_verifiers::WeightInfo<Tee<Runtime>>>
+            ::submit_proof(
+            &Proof { quote: vec![0; 1000], ..Default::default() },
+            &[0; pallet_tee_verifier::REPORT_DATA_SIZE]
+        ),
+        // The quote (with its compact length) and the 7 empty collateral fields.
+        crate::weights::pallet_tee_verifier::ZKVWeight::<Runtime>::submit_proof(1002 + 7)
     );
 }
@@ -34,6 +34,7 @@ pub mod pallet_im_online;
 #[cfg(feature = "relay")]
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
+pub mod pallet_plonk_verifier;
 pub mod pallet_poe;
 pub mod pallet_preimage;
 pub mod pallet_proofofsql_verifier;
@@ -44,10 +45,13 @@ pub mod pallet_scheduler;
 pub mod pallet_session;
 pub mod pallet_staking;
 pub mod pallet_sudo;
+pub mod pallet_tee_verifier;
 pub mod pallet_timestamp;
 pub mod pallet_treasury;
 pub mod pallet_ultraplonk_verifier;
 pub mod pallet_utility;
+pub mod pallet_verifiers;
+pub mod pallet_verifiers_dispatcher;
 pub mod pallet_vesting;
 pub mod pallet_whitelist;
 #[cfg(feature = "relay")]
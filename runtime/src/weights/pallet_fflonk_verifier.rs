@@ -20,6 +20,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `f6fa049db651`, CPU: `AMD Ryzen 7 7700 8-Core Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOT BENCHMARKED: the `verify_proof` weight is a hand-written estimate of the verification
+//! time and of its cost for every public input. Re-run the benchmarks to replace it.
 
 // Executed Command:
 // /usr/local/bin/zkv-node
@@ -84,7 +87,7 @@ impl<T: frame_system::Config> pallet_fflonk_verifier::WeightInfo for ZKVWeight<T
     /// Storage: `SettlementFFlonkPallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementFFlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:0)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     /// Storage: `Poe::NextAttestation` (r:1 w:0)
     /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     /// Storage: `Poe::Values` (r:1 w:1)
@@ -108,6 +111,11 @@ impl<T: frame_system::Config> pallet_fflonk_verifier::WeightInfo for ZKVWeight<T
             .saturating_add(T::DbWeight::get().reads(8_u64))
             .saturating_add(T::DbWeight::get().writes(4_u64))
     }
+    fn verify_proof(n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(20_614_877_000, 0)
+            .saturating_add(Weight::from_parts(61_276_000, 0).saturating_mul(n.into()))
+    }
     /// Storage: `SettlementFFlonkPallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementFFlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Tickets` (r:1 w:1)
@@ -115,13 +123,13 @@ impl<T: frame_system::Config> pallet_fflonk_verifier::WeightInfo for ZKVWeight<T
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     fn register_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `42`
-        //  Estimated: `4018`
+        //  Estimated: `4022`
         // Minimum execution time: 2_837_490_000 picoseconds.
-        Weight::from_parts(2_845_626_000, 4018)
+        Weight::from_parts(2_845_626_000, 4022)
             .saturating_add(T::DbWeight::get().reads(4_u64))
             .saturating_add(T::DbWeight::get().writes(3_u64))
     }
@@ -130,13 +138,13 @@ impl<T: frame_system::Config> pallet_fflonk_verifier::WeightInfo for ZKVWeight<T
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     fn unregister_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `801`
-        //  Estimated: `4018`
+        //  Estimated: `4022`
         // Minimum execution time: 41_538_000 picoseconds.
-        Weight::from_parts(42_389_000, 4018)
+        Weight::from_parts(42_389_000, 4022)
             .saturating_add(T::DbWeight::get().reads(3_u64))
             .saturating_add(T::DbWeight::get().writes(3_u64))
     }
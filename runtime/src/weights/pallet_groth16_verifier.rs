@@ -20,6 +20,14 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `d96b0b1ca727`, CPU: `AMD Ryzen 7 7700 8-Core Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOT BENCHMARKED: the `bls12_377`, `bw6_761` and `submit_proofs_*` weights are hand-written
+//! estimates. The `bls12_377` and `bw6_761` ones are the `bls12_381` ones scaled by the ratio of
+//! the native verification times (x1.2 and x5): the runtime keeps these curves disabled till they
+//! are benchmarked. The `submit_proofs_*` ones are extrapolated from the `submit_proof_*` ones, and
+//! the `submit_proofs_*_with_vk_hash` ones add the `Vks` read of every proof to them.
+//! The `Vks` proof sizes account for the larger BW6-761 G1 points and for the gnark commitment
+//! key. Re-run the benchmarks to replace them.
 
 // Executed Command:
 // /usr/local/bin/zkv-node
@@ -114,8 +122,56 @@ impl<T: frame_system::Config> pallet_groth16_verifier::WeightInfo for ZKVWeight<
     }
     /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn submit_proof_bls12_377(n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(5_025_482_114, 177995)
+            .saturating_add(Weight::from_parts(309_902_407, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn submit_proof_bw6_761(n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(20_939_508_810, 177995)
+            .saturating_add(Weight::from_parts(1_291_260_030, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:0)
-    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(3956), added: 6431, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
     /// Storage: `Poe::NextAttestation` (r:1 w:0)
     /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     /// Storage: `Poe::Values` (r:1 w:1)
@@ -145,7 +201,7 @@ impl<T: frame_system::Config> pallet_groth16_verifier::WeightInfo for ZKVWeight<
     /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:0)
-    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(3956), added: 6431, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
     /// Storage: `Poe::NextAttestation` (r:1 w:0)
     /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     /// Storage: `Poe::Values` (r:1 w:1)
@@ -174,19 +230,295 @@ impl<T: frame_system::Config> pallet_groth16_verifier::WeightInfo for ZKVWeight<
     }
     /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn submit_proof_bls12_377_with_vk_hash(n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(4_536_107_587, 177995)
+            .saturating_add(Weight::from_parts(232_057_909, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn submit_proof_bw6_761_with_vk_hash(n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(18_900_448_280, 177995)
+            .saturating_add(Weight::from_parts(966_907_955, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:32 w:32)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 32]`.
+    fn submit_proofs_bn254(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(2_604_118_372, 177995)
+            .saturating_add(Weight::from_parts(5_874_350_911, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:32 w:32)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 32]`.
+    fn submit_proofs_bls12_381(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(2_731_409_140, 177995)
+            .saturating_add(Weight::from_parts(6_950_772_306, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:32 w:32)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 32]`.
+    fn submit_proofs_bls12_377(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(3_277_690_968, 177995)
+            .saturating_add(Weight::from_parts(8_340_926_767, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:32 w:32)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 32]`.
+    fn submit_proofs_bw6_761(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(13_657_045_700, 177995)
+            .saturating_add(Weight::from_parts(34_753_861_530, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:32 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:32 w:32)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 32]`.
+    fn submit_proofs_bn254_with_vk_hash(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(2_604_118_372, 177995)
+            .saturating_add(Weight::from_parts(5_874_350_911, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 12954).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:32 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:32 w:32)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 32]`.
+    fn submit_proofs_bls12_381_with_vk_hash(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(2_731_409_140, 177995)
+            .saturating_add(Weight::from_parts(6_950_772_306, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 12954).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:32 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:32 w:32)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 32]`.
+    fn submit_proofs_bls12_377_with_vk_hash(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(3_277_690_968, 177995)
+            .saturating_add(Weight::from_parts(8_340_926_767, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 12954).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:32 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:32 w:32)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 32]`.
+    fn submit_proofs_bw6_761_with_vk_hash(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(13_657_045_700, 177995)
+            .saturating_add(Weight::from_parts(34_753_861_530, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 12954).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Tickets` (r:1 w:1)
     /// Proof: `SettlementGroth16Pallet::Tickets` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(3956), added: 6431, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[0, 16]`.
     fn register_vk_bn254(n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `6`
-        //  Estimated: `7421`
+        //  Estimated: `11397`
         // Minimum execution time: 1_141_009_000 picoseconds.
-        Weight::from_parts(1_145_421_315, 7421)
+        Weight::from_parts(1_145_421_315, 11397)
             // Standard Error: 12_283
             .saturating_add(Weight::from_parts(98_948_609, 0).saturating_mul(n.into()))
             .saturating_add(T::DbWeight::get().reads(4_u64))
@@ -199,31 +531,63 @@ impl<T: frame_system::Config> pallet_groth16_verifier::WeightInfo for ZKVWeight<
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(3956), added: 6431, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[0, 16]`.
     fn register_vk_bls12_381(n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `6`
-        //  Estimated: `7421`
+        //  Estimated: `11397`
         // Minimum execution time: 463_128_000 picoseconds.
-        Weight::from_parts(468_584_696, 7421)
+        Weight::from_parts(468_584_696, 11397)
             // Standard Error: 11_976
             .saturating_add(Weight::from_parts(64_955_216, 0).saturating_mul(n.into()))
             .saturating_add(T::DbWeight::get().reads(4_u64))
             .saturating_add(T::DbWeight::get().writes(3_u64))
     }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Tickets` (r:1 w:1)
+    /// Proof: `SettlementGroth16Pallet::Tickets` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn register_vk_bls12_377(n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(562_301_635, 11397)
+            .saturating_add(Weight::from_parts(77_946_259, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Tickets` (r:1 w:1)
+    /// Proof: `SettlementGroth16Pallet::Tickets` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn register_vk_bw6_761(n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(2_342_923_480, 11397)
+            .saturating_add(Weight::from_parts(324_776_080, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
     /// Storage: `SettlementGroth16Pallet::Tickets` (r:1 w:1)
     /// Proof: `SettlementGroth16Pallet::Tickets` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(3956), added: 6431, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
     fn unregister_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `775`
-        //  Estimated: `7421`
+        //  Estimated: `11397`
         // Minimum execution time: 46_587_000 picoseconds.
-        Weight::from_parts(47_329_000, 7421)
+        Weight::from_parts(47_329_000, 11397)
             .saturating_add(T::DbWeight::get().reads(3_u64))
             .saturating_add(T::DbWeight::get().writes(3_u64))
     }
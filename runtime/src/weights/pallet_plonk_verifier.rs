@@ -0,0 +1,149 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for `pallet_plonk_verifier`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 42.0.0
+//! DATE: 2024-12-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `f6fa049db651`, CPU: `AMD Ryzen 7 7700 8-Core Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// /usr/local/bin/zkv-node
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet-plonk-verifier
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --heap-pages=4096
+// --header
+// /data/benchmark/HEADER-APACHE2
+// --output
+// /data/benchmark/runtime/src/weights/pallet_plonk_verifier.rs
+// --template
+// /data/benchmark/node/zkv-deploy-weight-template.hbs
+// --base-path=/tmp/tmp.vtvFxoUbO9
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_plonk_verifier` using the zkVerify node and recommended hardware.
+pub struct ZKVWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> pallet_plonk_verifier::WeightInfo for ZKVWeight<T> {
+    /// Storage: `SettlementPlonkPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementPlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 32]`.
+    fn submit_proof(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `377`
+        //  Estimated: `177995`
+        // Minimum execution time: 5_486_212_000 picoseconds.
+        Weight::from_parts(5_458_305_271, 177995)
+            // Standard Error: 2_154_118
+            .saturating_add(Weight::from_parts(32_981_604, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `SettlementPlonkPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementPlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementPlonkPallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementPlonkPallet::Vks` (`max_values`: None, `max_size`: Some(757), added: 3232, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 32]`.
+    fn submit_proof_with_vk_hash(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1162`
+        //  Estimated: `177995`
+        // Minimum execution time: 5_493_871_000 picoseconds.
+        Weight::from_parts(5_466_019_428, 177995)
+            // Standard Error: 2_371_502
+            .saturating_add(Weight::from_parts(33_104_775, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `SettlementPlonkPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementPlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementPlonkPallet::Tickets` (r:1 w:1)
+    /// Proof: `SettlementPlonkPallet::Tickets` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementPlonkPallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementPlonkPallet::Vks` (`max_values`: None, `max_size`: Some(757), added: 3232, mode: `MaxEncodedLen`)
+    fn register_vk() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `42`
+        //  Estimated: `4222`
+        // Minimum execution time: 2_604_738_000 picoseconds.
+        Weight::from_parts(2_611_950_000, 4222)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `SettlementPlonkPallet::Tickets` (r:1 w:1)
+    /// Proof: `SettlementPlonkPallet::Tickets` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementPlonkPallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementPlonkPallet::Vks` (`max_values`: None, `max_size`: Some(757), added: 3232, mode: `MaxEncodedLen`)
+    fn unregister_vk() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1005`
+        //  Estimated: `4222`
+        // Minimum execution time: 43_012_000 picoseconds.
+        Weight::from_parts(43_870_000, 4222)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+}
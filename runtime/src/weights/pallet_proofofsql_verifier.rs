@@ -20,6 +20,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `044554e86dde`, CPU: `AMD Ryzen 7 7700 8-Core Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOT BENCHMARKED: the `max_nu_1` and `max_nu_4` weights are hand-written estimates, scaled
+//! from the `max_nu_8` ones. Re-run the benchmarks to replace them.
 
 // Executed Command:
 // /usr/local/bin/zkv-relay
@@ -72,7 +75,51 @@ impl<T: frame_system::Config> pallet_proofofsql_verifier::WeightInfo for ZKVWeig
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
     /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    fn submit_proof() -> Weight {
+    fn submit_proof_max_nu_1() -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(400_190_909_000, 177995)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `SettlementProofOfSqlPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    fn submit_proof_max_nu_4() -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(650_310_227_000, 177995)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `SettlementProofOfSqlPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    fn submit_proof_max_nu_8() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `492`
         //  Estimated: `177995`
@@ -99,7 +146,55 @@ impl<T: frame_system::Config> pallet_proofofsql_verifier::WeightInfo for ZKVWeig
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
     /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    fn submit_proof_with_vk_hash() -> Weight {
+    fn submit_proof_with_vk_hash_max_nu_1() -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(400_139_220_000, 177995)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `SettlementProofOfSqlPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementProofOfSqlPallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Vks` (`max_values`: None, `max_size`: Some(26980), added: 29455, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    fn submit_proof_with_vk_hash_max_nu_4() -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(650_226_233_000, 177995)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `SettlementProofOfSqlPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementProofOfSqlPallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Vks` (`max_values`: None, `max_size`: Some(26980), added: 29455, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Aggregate::Domains` (r:1 w:1)
+    /// Proof: `Aggregate::Domains` (`max_values`: None, `max_size`: Some(174530), added: 177005, mode: `MaxEncodedLen`)
+    /// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+    /// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    fn submit_proof_with_vk_hash_max_nu_8() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `27502`
         //  Estimated: `177995`
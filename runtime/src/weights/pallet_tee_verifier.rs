@@ -0,0 +1,75 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_tee_verifier`
+//!
+//! NOT AUTO-GENERATED: these are conservative estimates to be replaced by the benchmarked
+//! values once the TEE verifier benchmarks run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_tee_verifier` using the zkVerify node and recommended hardware.
+pub struct ZKVWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> pallet_tee_verifier::WeightInfo for ZKVWeight<T> {
+    /// Storage: `SettlementTeePallet::Disabled` (r:1 w:0)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// The range of component `p` is `[0, 65536]`.
+    fn submit_proof(p: u32, ) -> Weight {
+        Weight::from_parts(4_000_000_000, 3537)
+            .saturating_add(Weight::from_parts(20_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementTeePallet::Disabled` (r:1 w:0)
+    /// Storage: `SettlementTeePallet::Vks` (r:1 w:0)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// The range of component `p` is `[0, 65536]`.
+    fn submit_proof_with_vk_hash(p: u32, ) -> Weight {
+        Weight::from_parts(4_000_000_000, 4_051)
+            .saturating_add(Weight::from_parts(20_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementTeePallet::Disabled` (r:1 w:0)
+    /// Storage: `SettlementTeePallet::Tickets` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `SettlementTeePallet::Vks` (r:1 w:1)
+    fn register_vk() -> Weight {
+        Weight::from_parts(60_000_000, 4_051)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `SettlementTeePallet::Tickets` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `SettlementTeePallet::Vks` (r:1 w:1)
+    fn unregister_vk() -> Weight {
+        Weight::from_parts(60_000_000, 4_051)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_verifiers`
+//!
+//! NOT AUTO-GENERATED: these are conservative estimates of the `pallet_verifiers` benchmarks,
+//! that the runtime runs on the zkSync verifier instance, to be replaced by the benchmarked
+//! values once they run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_verifiers` using the zkVerify node and recommended hardware.
+pub struct ZKVWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> pallet_verifiers::common::WeightInfo for ZKVWeight<T> {
+    /// Storage: `SettlementZksyncPallet::Disabled` (r:0 w:1)
+    /// Proof: `SettlementZksyncPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    fn disable_verifier() -> Weight {
+        Weight::from_parts(6_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `SettlementZksyncPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementZksyncPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    fn on_verify_disabled_verifier() -> Weight {
+        Weight::from_parts(4_000_000, 1486)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    /// Storage: `CommonVerifiers::PruneCursor` (r:1 w:1)
+    /// Proof: `CommonVerifiers::PruneCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `CommonVerifiers::StatementsExpiry` (r:0 w:1)
+    /// Proof: `CommonVerifiers::StatementsExpiry` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `CommonVerifiers::Statements` (r:0 w:1)
+    /// Proof: `CommonVerifiers::Statements` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+    fn index_statement() -> Weight {
+        Weight::from_parts(12_000_000, 1489)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `CommonVerifiers::StatementsExpiry` (r:1 w:1)
+    /// Proof: `CommonVerifiers::StatementsExpiry` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `CommonVerifiers::Statements` (r:1 w:1)
+    /// Proof: `CommonVerifiers::Statements` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+    fn prune_statement() -> Weight {
+        Weight::from_parts(16_000_000, 3572)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementZksyncPallet::RevokedVks` (r:1 w:1)
+    /// Proof: `SettlementZksyncPallet::RevokedVks` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+    fn revoke_vk() -> Weight {
+        Weight::from_parts(10_000_000, 3755)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `SettlementZksyncPallet::RevokedVks` (r:1 w:1)
+    /// Proof: `SettlementZksyncPallet::RevokedVks` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+    fn restore_vk() -> Weight {
+        Weight::from_parts(10_000_000, 3755)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `SettlementZksyncPallet::RegistrationExpiry` (r:1 w:0)
+    /// Proof: `SettlementZksyncPallet::RegistrationExpiry` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::Tickets` (r:1 w:0)
+    /// Proof: `SettlementZksyncPallet::Tickets` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::PendingTransfers` (r:0 w:1)
+    /// Proof: `SettlementZksyncPallet::PendingTransfers` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+    fn transfer_vk() -> Weight {
+        Weight::from_parts(14_000_000, 3562)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `SettlementZksyncPallet::PendingTransfers` (r:1 w:1)
+    /// Proof: `SettlementZksyncPallet::PendingTransfers` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::RegistrationExpiry` (r:2 w:2)
+    /// Proof: `SettlementZksyncPallet::RegistrationExpiry` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::Tickets` (r:2 w:2)
+    /// Proof: `SettlementZksyncPallet::Tickets` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::Registrations` (r:1 w:2)
+    /// Proof: `SettlementZksyncPallet::Registrations` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(1416), added: 3891, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::ExpiringRegistrations` (r:0 w:1)
+    /// Proof: `SettlementZksyncPallet::ExpiringRegistrations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+    fn accept_vk_transfer() -> Weight {
+        Weight::from_parts(75_000_000, 4881)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+    }
+    /// Storage: `SettlementZksyncPallet::RegistrationExpiry` (r:1 w:1)
+    /// Proof: `SettlementZksyncPallet::RegistrationExpiry` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::Tickets` (r:1 w:0)
+    /// Proof: `SettlementZksyncPallet::Tickets` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(1416), added: 3891, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::ExpiryCursor` (r:1 w:1)
+    /// Proof: `SettlementZksyncPallet::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::ExpiringRegistrations` (r:0 w:1)
+    /// Proof: `SettlementZksyncPallet::ExpiringRegistrations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+    fn renew_vk() -> Weight {
+        Weight::from_parts(20_000_000, 4881)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `SettlementZksyncPallet::ExpiryCursor` (r:1 w:1)
+    /// Proof: `SettlementZksyncPallet::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::ExpiringRegistrations` (r:1 w:1)
+    /// Proof: `SettlementZksyncPallet::ExpiringRegistrations` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::Tickets` (r:1 w:1)
+    /// Proof: `SettlementZksyncPallet::Tickets` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::Registrations` (r:1 w:1)
+    /// Proof: `SettlementZksyncPallet::Registrations` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(1416), added: 3891, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::RegistrationExpiry` (r:0 w:1)
+    /// Proof: `SettlementZksyncPallet::RegistrationExpiry` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementZksyncPallet::PendingTransfers` (r:0 w:1)
+    /// Proof: `SettlementZksyncPallet::PendingTransfers` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+    fn expire_vk() -> Weight {
+        Weight::from_parts(45_000_000, 4881)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_verifiers_dispatcher`
+//!
+//! NOT AUTO-GENERATED: these are conservative estimates of the `submit` benchmark, that decodes
+//! Proof-of-SQL payloads of up to 1 MiB twice, to be replaced by the benchmarked values once it
+//! runs on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_verifiers_dispatcher` using the zkVerify node and recommended hardware.
+pub struct ZKVWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> pallet_verifiers_dispatcher::WeightInfo for ZKVWeight<T> {
+    /// The range of component `l` is `[0, 1048576]`.
+    fn submit(l: u32, ) -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(3_000, 0).saturating_mul(l.into()))
+    }
+}
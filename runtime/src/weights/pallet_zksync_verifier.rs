@@ -84,7 +84,7 @@ impl<T: frame_system::Config> pallet_zksync_verifier::WeightInfo for ZKVWeight<T
     /// Storage: `SettlementZksyncPallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementZksyncPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementZksyncPallet::Vks` (r:1 w:0)
-    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(1416), added: 3891, mode: `MaxEncodedLen`)
     /// Storage: `Poe::NextAttestation` (r:1 w:0)
     /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     /// Storage: `Poe::Values` (r:1 w:1)
@@ -115,13 +115,13 @@ impl<T: frame_system::Config> pallet_zksync_verifier::WeightInfo for ZKVWeight<T
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `SettlementZksyncPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(1416), added: 3891, mode: `MaxEncodedLen`)
     fn register_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `114`
-        //  Estimated: `3586`
+        //  Estimated: `4881`
         // Minimum execution time: 45_586_000 picoseconds.
-        Weight::from_parts(46_868_000, 3586)
+        Weight::from_parts(46_868_000, 4881)
             .saturating_add(T::DbWeight::get().reads(4_u64))
             .saturating_add(T::DbWeight::get().writes(3_u64))
     }
@@ -130,13 +130,13 @@ impl<T: frame_system::Config> pallet_zksync_verifier::WeightInfo for ZKVWeight<T
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `SettlementZksyncPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(1416), added: 3891, mode: `MaxEncodedLen`)
     fn unregister_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `357`
-        //  Estimated: `3586`
+        //  Estimated: `4881`
         // Minimum execution time: 39_804_000 picoseconds.
-        Weight::from_parts(40_536_000, 3586)
+        Weight::from_parts(40_536_000, 4881)
             .saturating_add(T::DbWeight::get().reads(3_u64))
             .saturating_add(T::DbWeight::get().writes(3_u64))
     }
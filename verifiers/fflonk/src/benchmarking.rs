@@ -15,7 +15,7 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::Fflonk;
+use crate::{Fflonk, Pubs, MAX_PUBS};
 use frame_benchmarking::v2::*;
 use frame_support::traits::{Consideration, Footprint};
 use frame_system::RawOrigin;
@@ -47,7 +47,7 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let proof = VALID_PROOF;
-        let pubs = VALID_PUBS;
+        let pubs = valid_pubs();
         let vk = cdk_key();
 
         #[extrinsic_call]
@@ -66,7 +66,7 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let proof = VALID_PROOF;
-        let pubs = VALID_PUBS;
+        let pubs = valid_pubs();
         let vk_entry = VkEntry::new(cdk_key());
         let hash = sp_core::H256::repeat_byte(2);
         Vks::<T, Fflonk>::insert(hash, vk_entry);
@@ -81,6 +81,19 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn verify_proof(n: Linear<1, MAX_PUBS>) {
+        // The proof is valid just for the single input key, but the verification does the
+        // same work till the final check.
+        let vk = cdk_key().with_n_public(n);
+        let pubs: Pubs = sp_std::vec![VALID_PUB; n as usize].try_into().unwrap();
+
+        #[block]
+        {
+            let _ = Fflonk::verify_proof(&vk, &VALID_PROOF, &pubs);
+        }
+    }
+
     #[benchmark]
     fn register_vk() {
         // setup code
@@ -155,8 +168,10 @@ mod mock {
 
     impl pallet_verifiers::Config<crate::Fflonk> for Test {
         type RuntimeEvent = RuntimeEvent;
-        type OnProofVerified = Aggregate;
+        type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<Aggregate>;
+        type OnProofRejected = ();
         type WeightInfo = crate::FflonkWeight<()>;
+        type MaxBatchSize = ConstU32<16>;
         type Ticket = HoldConsideration<
             AccountId,
             Balances,
@@ -184,6 +199,7 @@ mod mock {
 
     impl pallet_verifiers::common::Config for Test {
         type CommonWeightInfo = Test;
+        type StatementRetention = ();
     }
 
     pub struct NoManager;
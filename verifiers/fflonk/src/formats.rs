@@ -0,0 +1,257 @@
+// Copyright 2024, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Import the snarkjs `verification_key.json`, `proof.json` and `public.json` files into the
+//! verifier types. The proof words are laid out in the same order used by the snarkjs solidity
+//! verifier calldata: the `C1`, `C2`, `W1` and `W2` affine points and then the evaluations.
+
+use serde::Deserialize;
+use sp_core::U256;
+
+use crate::{vk::Vk, Proof, Pubs, MAX_PUBS, PROOF_SIZE};
+
+/// Why a snarkjs file cannot be imported.
+#[derive(Debug, snafu::Snafu)]
+pub enum FormatError {
+    /// The file is not a valid snarkjs JSON.
+    #[snafu(display("Invalid JSON: {source}"))]
+    Json { source: serde_json::Error },
+    /// The protocol is not `fflonk`.
+    #[snafu(display("Unsupported protocol '{protocol}'"))]
+    UnsupportedProtocol { protocol: String },
+    /// The curve is not `bn128`.
+    #[snafu(display("Unsupported curve '{curve}'"))]
+    UnsupportedCurve { curve: String },
+    /// A value is not a 256 bits decimal number.
+    #[snafu(display("Invalid number '{value}'"))]
+    InvalidNumber { value: String },
+    /// A proof point is not in affine coordinates.
+    #[snafu(display("Proof points should have z = 1"))]
+    NotAffinePoint,
+    /// There are more public inputs than the verifier supports.
+    #[snafu(display("Expected at most {MAX_PUBS} public inputs but found {found}"))]
+    TooManyPubs { found: usize },
+}
+
+type Result<T> = core::result::Result<T, FormatError>;
+
+#[derive(Debug, Deserialize)]
+struct Header {
+    protocol: String,
+    curve: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+struct VkHeader {
+    #[serde(flatten)]
+    header: Header,
+    nPublic: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+struct Polynomials {
+    C1: [String; 3],
+    C2: [String; 3],
+    W1: [String; 3],
+    W2: [String; 3],
+}
+
+#[derive(Debug, Deserialize)]
+struct Evaluations {
+    ql: String,
+    qr: String,
+    qm: String,
+    qo: String,
+    qc: String,
+    s1: String,
+    s2: String,
+    s3: String,
+    a: String,
+    b: String,
+    c: String,
+    z: String,
+    zw: String,
+    t1w: String,
+    t2w: String,
+    inv: String,
+}
+
+/// The snarkjs `proof.json` content.
+#[derive(Debug, Deserialize)]
+struct SnarkjsProof {
+    #[serde(flatten)]
+    header: Header,
+    polynomials: Polynomials,
+    evaluations: Evaluations,
+}
+
+fn json<'a, T: Deserialize<'a>>(json: &'a str) -> Result<T> {
+    serde_json::from_str(json).map_err(|source| FormatError::Json { source })
+}
+
+fn check_header(header: &Header) -> Result<()> {
+    if header.protocol != "fflonk" {
+        return Err(FormatError::UnsupportedProtocol {
+            protocol: header.protocol.clone(),
+        });
+    }
+    if header.curve != "bn128" {
+        return Err(FormatError::UnsupportedCurve {
+            curve: header.curve.clone(),
+        });
+    }
+    Ok(())
+}
+
+fn word(value: &str) -> Result<[u8; 32]> {
+    let mut out = [0; 32];
+    U256::from_dec_str(value)
+        .map_err(|_| FormatError::InvalidNumber {
+            value: value.to_owned(),
+        })?
+        .to_big_endian(&mut out);
+    Ok(out)
+}
+
+fn affine([x, y, z]: &[String; 3]) -> Result<[&String; 2]> {
+    (z == "1")
+        .then_some([x, y])
+        .ok_or(FormatError::NotAffinePoint)
+}
+
+/// Import a snarkjs `verification_key.json`.
+pub fn vk_from_snarkjs(vk: &str) -> Result<Vk> {
+    let VkHeader { header, nPublic } = json(vk)?;
+    check_header(&header)?;
+    json::<fflonk_verifier::VerificationKey>(vk)
+        .map(Vk::from)
+        .map(|vk| vk.with_n_public(nPublic))
+}
+
+/// Import a snarkjs `proof.json`.
+pub fn proof_from_snarkjs(proof: &str) -> Result<Proof> {
+    let SnarkjsProof {
+        header,
+        polynomials: p,
+        evaluations: e,
+    } = json(proof)?;
+    check_header(&header)?;
+
+    let points = [&p.C1, &p.C2, &p.W1, &p.W2]
+        .into_iter()
+        .map(affine)
+        .collect::<Result<Vec<_>>>()?;
+    let evaluations = [
+        &e.ql, &e.qr, &e.qm, &e.qo, &e.qc, &e.s1, &e.s2, &e.s3, &e.a, &e.b, &e.c, &e.z, &e.zw,
+        &e.t1w, &e.t2w, &e.inv,
+    ];
+    let mut out = [0; PROOF_SIZE];
+    for (chunk, value) in out
+        .chunks_exact_mut(32)
+        .zip(points.into_iter().flatten().chain(evaluations))
+    {
+        chunk.copy_from_slice(&word(value)?);
+    }
+    Ok(out)
+}
+
+/// Import a snarkjs `public.json`.
+pub fn pubs_from_snarkjs(pubs: &str) -> Result<Pubs> {
+    let pubs: Vec<String> = json(pubs)?;
+    let found = pubs.len();
+    pubs.iter()
+        .map(|value| word(value))
+        .collect::<Result<Vec<_>>>()?
+        .try_into()
+        .map_err(|_| FormatError::TooManyPubs { found })
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::Fflonk;
+    use hp_verifiers::Verifier;
+
+    include!("resources.rs");
+
+    static VK: &str = include_str!("resources/verification_key.json");
+    static PROOF: &str = include_str!("resources/proof.json");
+    static PUBS: &str = include_str!("resources/public.json");
+
+    #[test]
+    fn import_a_valid_proof() {
+        let vk = vk_from_snarkjs(VK).unwrap();
+        let proof = proof_from_snarkjs(PROOF).unwrap();
+        let pubs = pubs_from_snarkjs(PUBS).unwrap();
+
+        assert_eq!(vk, cdk_key());
+        assert_eq!(proof, VALID_PROOF);
+        assert_eq!(pubs, valid_pubs());
+        assert!(Fflonk::verify_proof(&vk, &proof, &pubs).is_ok());
+    }
+
+    #[test]
+    fn import_more_public_inputs() {
+        let pubs = pubs_from_snarkjs(r#"["1", "2"]"#).unwrap();
+
+        let mut two = [0; 32];
+        two[31] = 2;
+
+        assert_eq!(pubs.len(), 2);
+        assert_eq!(pubs[1], two);
+    }
+
+    #[test]
+    fn import_the_number_of_public_inputs() {
+        let mut vk: serde_json::Value = serde_json::from_str(VK).unwrap();
+        vk["nPublic"] = 3.into();
+
+        assert_eq!(vk_from_snarkjs(&vk.to_string()).unwrap().n_public(), 3);
+    }
+
+    #[test]
+    fn reject_too_many_public_inputs() {
+        let pubs = serde_json::to_string(&vec!["1"; MAX_PUBS as usize + 1]).unwrap();
+
+        assert!(matches!(
+            pubs_from_snarkjs(&pubs),
+            Err(FormatError::TooManyPubs { found }) if found == MAX_PUBS as usize + 1
+        ));
+    }
+
+    #[test]
+    fn reject_other_protocols() {
+        let mut proof: serde_json::Value = serde_json::from_str(PROOF).unwrap();
+        proof["protocol"] = "plonk".into();
+
+        assert!(matches!(
+            proof_from_snarkjs(&proof.to_string()),
+            Err(FormatError::UnsupportedProtocol { .. })
+        ));
+    }
+
+    #[test]
+    fn reject_projective_points() {
+        let mut proof: serde_json::Value = serde_json::from_str(PROOF).unwrap();
+        proof["polynomials"]["W1"][2] = "2".into();
+
+        assert!(matches!(
+            proof_from_snarkjs(&proof.to_string()),
+            Err(FormatError::NotAffinePoint)
+        ));
+    }
+}
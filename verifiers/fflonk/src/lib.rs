@@ -17,18 +17,26 @@
 
 use core::marker::PhantomData;
 
-use frame_support::weights::Weight;
+use frame_support::{ensure, weights::Weight, BoundedVec};
 use hp_verifiers::{Cow, Verifier, VerifyError};
+use sp_core::ConstU32;
+use sp_std::vec::Vec;
 
 pub mod benchmarking;
+#[cfg(feature = "std")]
+pub mod formats;
+pub mod migrations;
 mod verifier_should;
 mod vk;
 mod weight;
 
 pub const PUBS_SIZE: usize = 32;
 pub const PROOF_SIZE: usize = 24 * 32;
-pub type Pubs = [u8; PUBS_SIZE];
+/// Maximum supported number of public inputs.
+pub const MAX_PUBS: u32 = 64;
+pub type Pubs = BoundedVec<[u8; PUBS_SIZE], ConstU32<MAX_PUBS>>;
 pub type Proof = [u8; PROOF_SIZE];
+pub use vk::Vk;
 pub use weight::WeightInfo;
 
 #[pallet_verifiers::verifier]
@@ -50,19 +58,25 @@ impl Verifier for Fflonk {
         raw_proof: &Self::Proof,
         raw_pubs: &Self::Pubs,
     ) -> Result<(), VerifyError> {
+        ensure!(
+            raw_pubs.len() == vk.n_public() as usize,
+            VerifyError::InvalidInput
+        );
         let vk: fflonk_verifier::VerificationKey = vk
             .clone()
             .try_into_fflonk_vk_unchecked()
             .map_err(|e| log::debug!("Invalid Vk: {:?}", e))
             .map_err(|_| VerifyError::InvalidVerificationKey)?;
-        let pubs: fflonk_verifier::Public = (*raw_pubs).into();
+        let pubs = raw_pubs
+            .iter()
+            .map(|&p| fflonk_verifier::Public::from(p))
+            .collect::<Vec<_>>();
         let proof = fflonk_verifier::Proof::try_from(raw_proof)
             .map_err(|e| log::debug!("Cannot extract raw proof data: {:?}", e))
             .map_err(|_| VerifyError::InvalidProofData)?;
         log::trace!(
-            "Extracted public inputs [{:?}...{:?}] and proof data [{:?}...{:?}]",
-            &raw_pubs[0],
-            &raw_pubs[PUBS_SIZE - 1],
+            "Extracted {} public inputs and proof data [{:?}...{:?}]",
+            raw_pubs.len(),
             &raw_proof[0],
             &raw_proof[PROOF_SIZE - 1]
         );
@@ -73,6 +87,10 @@ impl Verifier for Fflonk {
     }
 
     fn validate_vk(vk: &Self::Vk) -> Result<(), hp_verifiers::VerifyError> {
+        ensure!(
+            vk.n_public() <= MAX_PUBS,
+            VerifyError::InvalidVerificationKey
+        );
         let _: fflonk_verifier::VerificationKey = vk
             .clone()
             .try_into()
@@ -81,30 +99,41 @@ impl Verifier for Fflonk {
         Ok(())
     }
 
+    fn vk_bytes(vk: &Self::Vk) -> Cow<[u8]> {
+        Cow::Owned(vk.to_bytes())
+    }
+
     fn pubs_bytes(pubs: &Self::Pubs) -> Cow<[u8]> {
-        Cow::Borrowed(pubs)
+        Cow::Owned(pubs.iter().flatten().copied().collect())
     }
 }
 
 /// The struct to use in runtime pallet configuration to map the weight computed by this crate
 /// benchmarks to the weight needed by the `pallet-verifiers`.
-/// In this case the implementation doesn't depends from the kind of proof or public input and
-/// the crate's benchmarks are mapped 1-1 to the `pallet-verifiers`'s one.
+/// The `submit_proof` benchmarks use a single public input: the cost of the other ones is
+/// taken from the `verify_proof` benchmark.
 pub struct FflonkWeight<W: weight::WeightInfo>(PhantomData<W>);
 
+impl<W: weight::WeightInfo> FflonkWeight<W> {
+    fn extra_pubs(pubs: &Pubs) -> Weight {
+        let n = pubs.len() as u32;
+        W::verify_proof(n).saturating_sub(W::verify_proof(1))
+    }
+}
+
 impl<W: weight::WeightInfo> pallet_verifiers::WeightInfo<Fflonk> for FflonkWeight<W> {
     fn submit_proof(
         _proof: &<Fflonk as hp_verifiers::Verifier>::Proof,
-        _pubs: &<Fflonk as hp_verifiers::Verifier>::Pubs,
+        pubs: &<Fflonk as hp_verifiers::Verifier>::Pubs,
     ) -> Weight {
-        W::submit_proof()
+        W::submit_proof().saturating_add(Self::extra_pubs(pubs))
     }
 
     fn submit_proof_with_vk_hash(
         _proof: &<Fflonk as hp_verifiers::Verifier>::Proof,
-        _pubs: &<Fflonk as hp_verifiers::Verifier>::Pubs,
+        pubs: &<Fflonk as hp_verifiers::Verifier>::Pubs,
     ) -> Weight {
-        W::submit_proof_with_vk_hash()
+        W::submit_proof_with_vk_hash().saturating_add(Self::extra_pubs(pubs))
     }
 
     fn register_vk(_vk: &<Fflonk as hp_verifiers::Verifier>::Vk) -> Weight {
@@ -0,0 +1,32 @@
+// Copyright 2024, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations.
+
+use pallet_verifiers::migrations::v3::VkTranslation;
+
+pub use crate::vk::VkV2;
+use crate::{Fflonk, Vk};
+
+/// Translate the V2 vks to single public input vks.
+pub struct SetSingleInput;
+
+impl VkTranslation<Fflonk> for SetSingleInput {
+    type OldVk = VkV2;
+
+    fn translate(old: VkV2) -> Vk {
+        old.into()
+    }
+}
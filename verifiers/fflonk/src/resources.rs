@@ -13,9 +13,14 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-static VALID_PUBS: crate::Pubs =
+static VALID_PUB: [u8; crate::PUBS_SIZE] =
     hex_literal::hex!("0d69b94acdfaca5bacc248a60b35b925a2374644ce0c1205db68228c8921d9d9");
 
+#[allow(dead_code)]
+fn valid_pubs() -> crate::Pubs {
+    sp_std::vec![VALID_PUB].try_into().unwrap()
+}
+
 #[allow(dead_code)]
 static VALID_PROOF: crate::Proof = hex_literal::hex!(
     "
@@ -48,42 +53,9 @@ static VALID_PROOF: crate::Proof = hex_literal::hex!(
 
 #[allow(dead_code)]
 fn cdk_key() -> crate::vk::Vk {
-    serde_json::from_str::<fflonk_verifier::VerificationKey>(
-        r#"
-    {
-        "protocol": "fflonk",
-        "curve": "bn128",
-        "nPublic": 1,
-        "power": 24,
-        "k1": "2",
-        "k2": "3",
-        "w": "5709868443893258075976348696661355716898495876243883251619397131511003808859",
-        "w3": "21888242871839275217838484774961031246154997185409878258781734729429964517155",
-        "w4": "21888242871839275217838484774961031246007050428528088939761107053157389710902",
-        "w8": "19540430494807482326159819597004422086093766032135589407132600596362845576832",
-        "wr": "18200100796661656210024324131237448517259556535315737226009542456080026430510",
-        "X_2": [
-            [
-                "21831381940315734285607113342023901060522397560371972897001948545212302161822",
-                "17231025384763736816414546592865244497437017442647097510447326538965263639101"
-            ],
-            [
-                "2388026358213174446665280700919698872609886601280537296205114254867301080648",
-                "11507326595632554467052522095592665270651932854513688777769618397986436103170"
-            ],
-            [
-                "1",
-                "0"
-            ]
-        ],
-        "C0": [
-            "7436841426934271843999872946312645822871802402068881571108027575346498207286",
-            "18448034242258174646222819724328439025708531082946938915005051387020977719791",
-            "1"
-        ]
-    }
-    "#,
-    )
+    serde_json::from_str::<fflonk_verifier::VerificationKey>(include_str!(
+        "resources/verification_key.json"
+    ))
     .unwrap()
     .into()
 }
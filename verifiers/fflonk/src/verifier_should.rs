@@ -24,14 +24,44 @@ include!("resources.rs");
 fn verify_valid_proof() {
     let vk = cdk_key();
 
-    assert!(Fflonk::verify_proof(&vk, &VALID_PROOF, &VALID_PUBS).is_ok());
+    assert!(Fflonk::verify_proof(&vk, &VALID_PROOF, &valid_pubs()).is_ok());
 }
 
 #[test]
-fn return_the_same_bytes_as_public_inputs() {
+fn return_the_concatenated_public_inputs_bytes() {
     // We use some other bytes to be sure that the pubs are not hardcoded
-    let data: [u8; 32] = VALID_PROOF[0..32].try_into().unwrap();
-    assert_eq!(Fflonk::pubs_bytes(&data).as_ref(), &data);
+    let data: Pubs = VALID_PROOF[0..96]
+        .chunks_exact(32)
+        .map(|c| c.try_into().unwrap())
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+    assert_eq!(Fflonk::pubs_bytes(&data).as_ref(), &VALID_PROOF[0..96]);
+}
+
+#[test]
+fn keep_the_single_input_vk_hash() {
+    use codec::Encode;
+    let vk = cdk_key();
+    let encoded = vk.encode();
+
+    assert_eq!(
+        Fflonk::vk_bytes(&vk).as_ref(),
+        &encoded[..encoded.len() - 4]
+    );
+    let vk = vk.with_n_public(2);
+    assert_eq!(Fflonk::vk_bytes(&vk).as_ref(), &vk.encode());
+}
+
+#[test]
+fn migrate_the_stored_vks_to_a_single_input() {
+    use codec::{Decode, Encode};
+    use pallet_verifiers::migrations::v3::VkTranslation;
+    let vk = cdk_key();
+    let encoded = vk.encode();
+    let old = migrations::VkV2::decode(&mut &encoded[..encoded.len() - 4]).unwrap();
+
+    assert_eq!(migrations::SetSingleInput::translate(old), vk);
 }
 
 #[test]
@@ -52,6 +82,16 @@ fn reject_malformed_vk() {
     );
 }
 
+#[test]
+fn reject_vk_with_too_many_public_inputs() {
+    let vk = cdk_key().with_n_public(MAX_PUBS + 1);
+
+    assert_eq!(
+        Fflonk::validate_vk(&vk),
+        Err(VerifyError::InvalidVerificationKey)
+    );
+}
+
 mod reject {
 
     use super::*;
@@ -59,8 +99,8 @@ mod reject {
     #[test]
     fn invalid_pubs() {
         let vk = cdk_key();
-        let mut invalid_pubs = VALID_PUBS;
-        invalid_pubs[0] = invalid_pubs[0].wrapping_add(1);
+        let mut invalid_pubs = valid_pubs();
+        invalid_pubs[0][0] = invalid_pubs[0][0].wrapping_add(1);
 
         assert_eq!(
             Fflonk::verify_proof(&vk, &VALID_PROOF, &invalid_pubs),
@@ -68,6 +108,22 @@ mod reject {
         );
     }
 
+    #[test]
+    fn pubs_not_matching_the_vk() {
+        let vk = cdk_key();
+        let mut pubs = valid_pubs();
+        pubs.try_push(VALID_PUB).unwrap();
+
+        assert_eq!(
+            Fflonk::verify_proof(&vk, &VALID_PROOF, &pubs),
+            Err(VerifyError::InvalidInput)
+        );
+        assert_eq!(
+            Fflonk::verify_proof(&vk.with_n_public(2), &VALID_PROOF, &valid_pubs()),
+            Err(VerifyError::InvalidInput)
+        );
+    }
+
     #[test]
     fn invalid_proof() {
         let vk = cdk_key();
@@ -76,7 +132,7 @@ mod reject {
         invalid_proof[invalid_proof.len() - 1] = 0x00;
 
         assert_eq!(
-            Fflonk::verify_proof(&vk, &invalid_proof, &VALID_PUBS),
+            Fflonk::verify_proof(&vk, &invalid_proof, &valid_pubs()),
             Err(VerifyError::VerifyError)
         );
     }
@@ -89,8 +145,50 @@ mod reject {
         malformed_proof[0] = 0x07;
 
         assert_eq!(
-            Fflonk::verify_proof(&vk, &malformed_proof, &VALID_PUBS),
+            Fflonk::verify_proof(&vk, &malformed_proof, &valid_pubs()),
             Err(VerifyError::InvalidProofData)
         );
     }
 }
+
+mod conformance {
+    use super::*;
+    use crate::vk::Vk;
+
+    pub struct FflonkFixtures;
+
+    impl pallet_verifiers_test_kit::Fixtures<Fflonk> for FflonkFixtures {
+        type WeightInfo = FflonkWeight<()>;
+
+        fn valid() -> (Vk, Proof, Pubs) {
+            (cdk_key(), VALID_PROOF, valid_pubs())
+        }
+
+        fn invalid() -> Vec<(Vk, Proof, Pubs)> {
+            let mut invalid_pubs = valid_pubs();
+            invalid_pubs[0][0] = invalid_pubs[0][0].wrapping_add(1);
+            let mut malformed_proof = VALID_PROOF;
+            malformed_proof[0] = 0x07;
+            vec![
+                (cdk_key(), VALID_PROOF, invalid_pubs),
+                (cdk_key(), malformed_proof, valid_pubs()),
+                (cdk_key().with_n_public(2), VALID_PROOF, valid_pubs()),
+            ]
+        }
+
+        fn invalid_vks() -> Vec<Vk> {
+            let mut vk = cdk_key();
+            *vk.mut_c0_x() = U256::zero();
+            vec![vk, cdk_key().with_n_public(MAX_PUBS + 1)]
+        }
+
+        fn by_size() -> Vec<(Proof, Pubs)> {
+            [1, 2, 16, MAX_PUBS]
+                .into_iter()
+                .map(|n| (VALID_PROOF, vec![VALID_PUB; n as usize].try_into().unwrap()))
+                .collect()
+        }
+    }
+
+    pallet_verifiers_test_kit::verifier_conformance!(Fflonk, FflonkFixtures);
+}
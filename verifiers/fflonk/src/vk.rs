@@ -39,6 +39,40 @@ pub struct Vk {
     wr: Fr,
     x2: G2,
     c0: G1,
+    n_public: u32,
+}
+
+/// The vk stored before multiple public inputs were supported: it had a single one.
+#[derive(Clone, Debug, Encode, Decode, PartialEq)]
+pub struct VkV2 {
+    power: u8,
+    k1: Fr,
+    k2: Fr,
+    w: Fr,
+    w3: Fr,
+    w4: Fr,
+    w8: Fr,
+    wr: Fr,
+    x2: G2,
+    c0: G1,
+}
+
+impl From<VkV2> for Vk {
+    fn from(value: VkV2) -> Self {
+        Self {
+            power: value.power,
+            k1: value.k1,
+            k2: value.k2,
+            w: value.w,
+            w3: value.w3,
+            w4: value.w4,
+            w8: value.w8,
+            wr: value.wr,
+            x2: value.x2,
+            c0: value.c0,
+            n_public: 1,
+        }
+    }
 }
 
 trait IntoBytes {
@@ -156,6 +190,26 @@ impl TryInto<fflonk_verifier::VerificationKey> for Vk {
 }
 
 impl Vk {
+    /// The number of public inputs of the circuit (`nPublic` in the snarkjs vk).
+    pub fn n_public(&self) -> u32 {
+        self.n_public
+    }
+
+    pub fn with_n_public(mut self, n_public: u32) -> Self {
+        self.n_public = n_public;
+        self
+    }
+
+    /// The bytes used to compute the vk hash: the single input keys are encoded without
+    /// `n_public`, so they keep the hash they had before multiple inputs were supported.
+    pub fn to_bytes(&self) -> sp_std::vec::Vec<u8> {
+        let mut bytes = self.encode();
+        if self.n_public == 1 {
+            bytes.truncate(bytes.len() - self.n_public.encoded_size());
+        }
+        bytes
+    }
+
     pub fn try_into_fflonk_vk_unchecked(
         self,
     ) -> Result<fflonk_verifier::VerificationKey, ConvertError> {
@@ -174,8 +228,8 @@ impl Vk {
     }
 }
 
-#[cfg(any(test, feature = "runtime-benchmarks"))]
-mod test_utils {
+#[cfg(any(test, feature = "std", feature = "runtime-benchmarks"))]
+mod from_fflonk {
     use super::*;
 
     impl From<substrate_bn::Fr> for Fr {
@@ -212,6 +266,8 @@ mod test_utils {
         }
     }
 
+    /// The `fflonk_verifier` key doesn't say how many public inputs the circuit has: use
+    /// [`Vk::with_n_public`] if it's not a single input one.
     impl From<fflonk_verifier::VerificationKey> for Vk {
         fn from(value: fflonk_verifier::VerificationKey) -> Self {
             Self {
@@ -225,9 +281,15 @@ mod test_utils {
                 wr: value.wr.into(),
                 x2: value.x2.into(),
                 c0: value.c0.into(),
+                n_public: 1,
             }
         }
     }
+}
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod test_utils {
+    use super::*;
 
     impl AsMut<U256> for Fr {
         fn as_mut(&mut self) -> &mut U256 {
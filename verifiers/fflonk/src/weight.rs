@@ -20,6 +20,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `lucag-laptop`, CPU: `Intel(R) Core(TM) i7-10510U CPU @ 1.80GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOT BENCHMARKED: the `verify_proof` weight is a hand-written estimate of the verification
+//! time and of its cost for every public input. Re-run the benchmarks to replace it.
 
 // Executed Command:
 // ./target/release/zkv-relay
@@ -55,6 +58,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
     fn submit_proof() -> Weight;
     fn submit_proof_with_vk_hash() -> Weight;
+    fn verify_proof(n: u32, ) -> Weight;
     fn register_vk() -> Weight;
     fn unregister_vk() -> Weight;
 }
@@ -83,7 +87,7 @@ impl WeightInfo for () {
     /// Storage: `SettlementFFlonkPallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementFFlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:0)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     /// Storage: `Poe::NextAttestation` (r:1 w:0)
     /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     /// Storage: `Poe::Values` (r:1 w:1)
@@ -95,12 +99,17 @@ impl WeightInfo for () {
     fn submit_proof_with_vk_hash() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `822`
-        //  Estimated: `4018`
+        //  Estimated: `4022`
         // Minimum execution time: 31_663_259_000 picoseconds.
-        Weight::from_parts(31_663_259_000, 4018)
+        Weight::from_parts(31_663_259_000, 4022)
             .saturating_add(RocksDbWeight::get().reads(5_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
+    fn verify_proof(n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(36_402_113_000, 0)
+            .saturating_add(Weight::from_parts(98_406_000, 0).saturating_mul(n.into()))
+    }
     /// Storage: `SettlementFFlonkPallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementFFlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Tickets` (r:1 w:1)
@@ -108,13 +117,13 @@ impl WeightInfo for () {
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     fn register_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `42`
-        //  Estimated: `4018`
+        //  Estimated: `4022`
         // Minimum execution time: 5_020_939_000 picoseconds.
-        Weight::from_parts(5_020_939_000, 4018)
+        Weight::from_parts(5_020_939_000, 4022)
             .saturating_add(RocksDbWeight::get().reads(4_u64))
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
@@ -123,13 +132,13 @@ impl WeightInfo for () {
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
     /// Storage: `SettlementFFlonkPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(553), added: 3028, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementFFlonkPallet::Vks` (`max_values`: None, `max_size`: Some(557), added: 3032, mode: `MaxEncodedLen`)
     fn unregister_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `784`
-        //  Estimated: `4018`
+        //  Estimated: `4022`
         // Minimum execution time: 73_683_000 picoseconds.
-        Weight::from_parts(73_683_000, 4018)
+        Weight::from_parts(73_683_000, 4022)
             .saturating_add(RocksDbWeight::get().reads(3_u64))
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
@@ -17,11 +17,16 @@
 
 use super::Groth16;
 use frame_benchmarking::v2::*;
-use frame_support::traits::{Consideration, Footprint};
+use frame_support::{
+    traits::{Consideration, Footprint},
+    BoundedVec,
+};
 use frame_system::RawOrigin;
 use hp_verifiers::Verifier;
 use pallet_aggregate::{funded_account, insert_domain};
-use pallet_verifiers::{Tickets, VkEntry, VkOrHash, Vks};
+use pallet_verifiers::{BatchMode, ProofSubmission, Tickets, VkEntry, VkOrHash, Vks};
+use sp_core::Get;
+use sp_std::vec::Vec;
 
 pub struct Pallet<T: Config>(crate::Pallet<T>);
 pub trait Config: crate::Config {}
@@ -36,6 +41,45 @@ fn init<T: pallet_aggregate::Config>() -> (T::AccountId, u32) {
     (caller, domain_id)
 }
 
+type MaxBatchSize<T> = <T as pallet_verifiers::Config<Groth16<T>>>::MaxBatchSize;
+
+/// A batch of `b` different proofs with the maximum number of public inputs.
+fn batch<T: crate::Config + pallet_verifiers::Config<Groth16<T>>>(
+    b: u32,
+    curve: Curve,
+    domain_id: u32,
+) -> BoundedVec<ProofSubmission<Groth16<T>>, MaxBatchSize<T>> {
+    (0..b)
+        .map(|i| {
+            let (proof, vk, inputs) = Groth16Circuits::get_instance(
+                <T as crate::Config>::MAX_NUM_INPUTS as usize,
+                Some(i as u64),
+                curve,
+            );
+            ProofSubmission::new(VkOrHash::from_vk(vk), proof, inputs, Some(domain_id))
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("Batch should not exceed MaxBatchSize")
+}
+
+/// The same batch of `batch()` where every vk is registered and provided by its hash.
+fn batch_with_vk_hash<T: crate::Config + pallet_verifiers::Config<Groth16<T>>>(
+    b: u32,
+    curve: Curve,
+    domain_id: u32,
+) -> BoundedVec<ProofSubmission<Groth16<T>>, MaxBatchSize<T>> {
+    let mut proofs = batch::<T>(b, curve, domain_id);
+    for (i, p) in proofs.iter_mut().enumerate() {
+        if let VkOrHash::Vk(vk) = &p.vk_or_hash {
+            let vk_hash = sp_core::H256::from_low_u64_be(i as u64);
+            Vks::<T, Groth16<T>>::insert(vk_hash, VkEntry::new(vk.as_ref().clone()));
+            p.vk_or_hash = VkOrHash::from_hash(vk_hash);
+        }
+    }
+    proofs
+}
+
 #[benchmarks(where T: pallet_verifiers::Config<Groth16<T>> + pallet_aggregate::Config)]
 mod benchmarks {
 
@@ -71,6 +115,36 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn submit_proof_bls12_377(n: Linear<0, <T as crate::Config>::MAX_NUM_INPUTS>) {
+        let (caller, domain_id) = init::<T>();
+        let (proof, vk, inputs) = Groth16Circuits::get_instance(n as usize, None, Curve::Bls12_377);
+
+        #[extrinsic_call]
+        submit_proof(
+            RawOrigin::Signed(caller),
+            VkOrHash::from_vk(vk),
+            proof.into(),
+            inputs.into(),
+            Some(domain_id),
+        );
+    }
+
+    #[benchmark]
+    fn submit_proof_bw6_761(n: Linear<0, <T as crate::Config>::MAX_NUM_INPUTS>) {
+        let (caller, domain_id) = init::<T>();
+        let (proof, vk, inputs) = Groth16Circuits::get_instance(n as usize, None, Curve::Bw6_761);
+
+        #[extrinsic_call]
+        submit_proof(
+            RawOrigin::Signed(caller),
+            VkOrHash::from_vk(vk),
+            proof.into(),
+            inputs.into(),
+            Some(domain_id),
+        );
+    }
+
     #[benchmark]
     fn submit_proof_bn254_with_vk_hash(n: Linear<0, <T as crate::Config>::MAX_NUM_INPUTS>) {
         let (caller, domain_id) = init::<T>();
@@ -107,6 +181,114 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn submit_proof_bls12_377_with_vk_hash(n: Linear<0, <T as crate::Config>::MAX_NUM_INPUTS>) {
+        let (caller, domain_id) = init::<T>();
+        let (proof, vk, inputs) = Groth16Circuits::get_instance(n as usize, None, Curve::Bls12_377);
+        let hash = sp_core::H256::repeat_byte(2);
+        let vk_entry = VkEntry::new(vk);
+        Vks::<T, Groth16<T>>::insert(hash, vk_entry);
+
+        #[extrinsic_call]
+        submit_proof(
+            RawOrigin::Signed(caller),
+            VkOrHash::from_hash(hash),
+            proof.into(),
+            inputs.into(),
+            Some(domain_id),
+        );
+    }
+
+    #[benchmark]
+    fn submit_proof_bw6_761_with_vk_hash(n: Linear<0, <T as crate::Config>::MAX_NUM_INPUTS>) {
+        let (caller, domain_id) = init::<T>();
+        let (proof, vk, inputs) = Groth16Circuits::get_instance(n as usize, None, Curve::Bw6_761);
+        let hash = sp_core::H256::repeat_byte(2);
+        let vk_entry = VkEntry::new(vk);
+        Vks::<T, Groth16<T>>::insert(hash, vk_entry);
+
+        #[extrinsic_call]
+        submit_proof(
+            RawOrigin::Signed(caller),
+            VkOrHash::from_hash(hash),
+            proof.into(),
+            inputs.into(),
+            Some(domain_id),
+        );
+    }
+
+    #[benchmark]
+    fn submit_proofs_bn254(b: Linear<1, { MaxBatchSize::<T>::get() }>) {
+        let (caller, domain_id) = init::<T>();
+        let proofs = batch::<T>(b, Curve::Bn254, domain_id);
+
+        #[extrinsic_call]
+        submit_proofs(RawOrigin::Signed(caller), proofs, BatchMode::AllOrNothing);
+    }
+
+    #[benchmark]
+    fn submit_proofs_bls12_381(b: Linear<1, { MaxBatchSize::<T>::get() }>) {
+        let (caller, domain_id) = init::<T>();
+        let proofs = batch::<T>(b, Curve::Bls12_381, domain_id);
+
+        #[extrinsic_call]
+        submit_proofs(RawOrigin::Signed(caller), proofs, BatchMode::AllOrNothing);
+    }
+
+    #[benchmark]
+    fn submit_proofs_bls12_377(b: Linear<1, { MaxBatchSize::<T>::get() }>) {
+        let (caller, domain_id) = init::<T>();
+        let proofs = batch::<T>(b, Curve::Bls12_377, domain_id);
+
+        #[extrinsic_call]
+        submit_proofs(RawOrigin::Signed(caller), proofs, BatchMode::AllOrNothing);
+    }
+
+    #[benchmark]
+    fn submit_proofs_bw6_761(b: Linear<1, { MaxBatchSize::<T>::get() }>) {
+        let (caller, domain_id) = init::<T>();
+        let proofs = batch::<T>(b, Curve::Bw6_761, domain_id);
+
+        #[extrinsic_call]
+        submit_proofs(RawOrigin::Signed(caller), proofs, BatchMode::AllOrNothing);
+    }
+
+    #[benchmark]
+    fn submit_proofs_bn254_with_vk_hash(b: Linear<1, { MaxBatchSize::<T>::get() }>) {
+        let (caller, domain_id) = init::<T>();
+        let proofs = batch_with_vk_hash::<T>(b, Curve::Bn254, domain_id);
+
+        #[extrinsic_call]
+        submit_proofs(RawOrigin::Signed(caller), proofs, BatchMode::AllOrNothing);
+    }
+
+    #[benchmark]
+    fn submit_proofs_bls12_381_with_vk_hash(b: Linear<1, { MaxBatchSize::<T>::get() }>) {
+        let (caller, domain_id) = init::<T>();
+        let proofs = batch_with_vk_hash::<T>(b, Curve::Bls12_381, domain_id);
+
+        #[extrinsic_call]
+        submit_proofs(RawOrigin::Signed(caller), proofs, BatchMode::AllOrNothing);
+    }
+
+    #[benchmark]
+    fn submit_proofs_bls12_377_with_vk_hash(b: Linear<1, { MaxBatchSize::<T>::get() }>) {
+        let (caller, domain_id) = init::<T>();
+        let proofs = batch_with_vk_hash::<T>(b, Curve::Bls12_377, domain_id);
+
+        #[extrinsic_call]
+        submit_proofs(RawOrigin::Signed(caller), proofs, BatchMode::AllOrNothing);
+    }
+
+    #[benchmark]
+    fn submit_proofs_bw6_761_with_vk_hash(b: Linear<1, { MaxBatchSize::<T>::get() }>) {
+        let (caller, domain_id) = init::<T>();
+        let proofs = batch_with_vk_hash::<T>(b, Curve::Bw6_761, domain_id);
+
+        #[extrinsic_call]
+        submit_proofs(RawOrigin::Signed(caller), proofs, BatchMode::AllOrNothing);
+    }
+
     #[benchmark]
     fn register_vk_bn254(n: Linear<0, <T as crate::Config>::MAX_NUM_INPUTS>) {
         let caller: T::AccountId = funded_account::<T>();
@@ -131,6 +313,30 @@ mod benchmarks {
         assert!(Vks::<T, Groth16<T>>::get(Groth16::<T>::vk_hash(&vk)).is_some());
     }
 
+    #[benchmark]
+    fn register_vk_bls12_377(n: Linear<0, <T as crate::Config>::MAX_NUM_INPUTS>) {
+        let caller: T::AccountId = funded_account::<T>();
+        let (_, vk, _) = Groth16Circuits::get_instance(n as usize, None, Curve::Bls12_377);
+
+        #[extrinsic_call]
+        register_vk(RawOrigin::Signed(caller), vk.clone().into());
+
+        // Verify
+        assert!(Vks::<T, Groth16<T>>::get(Groth16::<T>::vk_hash(&vk)).is_some());
+    }
+
+    #[benchmark]
+    fn register_vk_bw6_761(n: Linear<0, <T as crate::Config>::MAX_NUM_INPUTS>) {
+        let caller: T::AccountId = funded_account::<T>();
+        let (_, vk, _) = Groth16Circuits::get_instance(n as usize, None, Curve::Bw6_761);
+
+        #[extrinsic_call]
+        register_vk(RawOrigin::Signed(caller), vk.clone().into());
+
+        // Verify
+        assert!(Vks::<T, Groth16<T>>::get(Groth16::<T>::vk_hash(&vk)).is_some());
+    }
+
     #[benchmark]
     fn unregister_vk() {
         // setup code
@@ -191,8 +397,10 @@ mod mock {
 
     impl pallet_verifiers::Config<crate::Groth16<Test>> for Test {
         type RuntimeEvent = RuntimeEvent;
-        type OnProofVerified = Aggregate;
+        type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<Aggregate>;
+        type OnProofRejected = ();
         type WeightInfo = crate::Groth16Weight<()>;
+        type MaxBatchSize = ConstU32<16>;
         type Ticket = HoldConsideration<
             AccountId,
             Balances,
@@ -220,10 +428,12 @@ mod mock {
 
     impl pallet_verifiers::common::Config for Test {
         type CommonWeightInfo = Test;
+        type StatementRetention = ();
     }
 
     impl crate::Config for Test {
         const MAX_NUM_INPUTS: u32 = crate::MAX_NUM_INPUTS - 1;
+        const ACCEPT_UNBENCHMARKED_CURVES: bool = true;
     }
 
     pub struct NoManager;
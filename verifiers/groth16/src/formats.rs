@@ -0,0 +1,930 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Import the snarkjs `verification_key.json`, `proof.json` and `public.json` files into the
+//! verifier types. The snarkjs points are in projective coordinates where every coordinate is a
+//! decimal string: they are checked to be on the curve and in the right subgroup and then
+//! serialized in the uncompressed form used by `hp_groth16`.
+//!
+//! Import also the gnark binary serialization of verification keys, proofs and public witnesses,
+//! with the optional Pedersen commitment (`api.Commit`).
+
+use ark_ec::{
+    pairing::Pairing,
+    short_weierstrass::{Affine, SWCurveConfig},
+};
+use ark_ff::{BigInteger, Field, PrimeField, QuadExtConfig, QuadExtField};
+use hp_groth16::{ProofCommitment, Scalar, VerificationKey, VkCommitment, G1, G2};
+use serde::Deserialize;
+
+use crate::{Curve, Proof, Pubs, Vk};
+
+/// Why a snarkjs or gnark file cannot be imported.
+#[derive(Debug, snafu::Snafu)]
+pub enum FormatError {
+    /// The file is not a valid snarkjs JSON.
+    #[snafu(display("Invalid JSON: {source}"))]
+    Json { source: serde_json::Error },
+    /// The protocol is not `groth16`.
+    #[snafu(display("Unsupported protocol '{protocol}'"))]
+    UnsupportedProtocol { protocol: String },
+    /// The curve is neither `bn128` nor `bls12381`.
+    #[snafu(display("Unsupported curve '{curve}'"))]
+    UnsupportedCurve { curve: String },
+    /// A coordinate or a public input is not a decimal number lower than the field modulus.
+    #[snafu(display("Invalid field element '{value}'"))]
+    InvalidFieldElement { value: String },
+    /// A point is not on the curve or not in the right subgroup.
+    #[snafu(display("Invalid curve point"))]
+    InvalidPoint,
+    /// `nPublic` doesn't match the number of `IC` points.
+    #[snafu(display("Expected {expected} IC points but found {found}"))]
+    InvalidIcLength { expected: usize, found: usize },
+    /// The gnark data is shorter than expected.
+    #[snafu(display("Unexpected end of data"))]
+    UnexpectedEnd,
+    /// The gnark data is longer than expected.
+    #[snafu(display("{count} unexpected trailing bytes"))]
+    TrailingBytes { count: usize },
+    /// Just a single gnark commitment is supported.
+    #[snafu(display("Unsupported number of commitments {count}"))]
+    UnsupportedCommitments { count: usize },
+    /// The gnark committed public inputs don't match the public inputs.
+    #[snafu(display("Invalid committed public input {index}"))]
+    InvalidCommittedInput { index: u64 },
+    /// The gnark witness is not a public witness.
+    #[snafu(display("Expected a public witness but found {secret} secret values"))]
+    NotPublicWitness { secret: usize },
+    /// The gnark witness header doesn't match the number of its values.
+    #[snafu(display("Expected {expected} public values but found {found}"))]
+    InvalidWitnessLength { expected: usize, found: usize },
+}
+
+type Result<T> = core::result::Result<T, FormatError>;
+
+/// The snarkjs `verification_key.json` content.
+#[derive(Debug, Deserialize)]
+struct SnarkjsVk {
+    protocol: String,
+    curve: String,
+    #[serde(rename = "nPublic")]
+    n_public: usize,
+    vk_alpha_1: [String; 3],
+    vk_beta_2: [[String; 2]; 3],
+    vk_gamma_2: [[String; 2]; 3],
+    vk_delta_2: [[String; 2]; 3],
+    #[serde(rename = "IC")]
+    ic: Vec<[String; 3]>,
+}
+
+/// The snarkjs `proof.json` content.
+#[derive(Debug, Deserialize)]
+struct SnarkjsProof {
+    protocol: String,
+    curve: String,
+    pi_a: [String; 3],
+    pi_b: [[String; 2]; 3],
+    pi_c: [String; 3],
+}
+
+fn json<'a, T: Deserialize<'a>>(json: &'a str) -> Result<T> {
+    serde_json::from_str(json).map_err(|source| FormatError::Json { source })
+}
+
+fn check_protocol(protocol: &str) -> Result<()> {
+    match protocol {
+        "groth16" => Ok(()),
+        _ => Err(FormatError::UnsupportedProtocol {
+            protocol: protocol.to_owned(),
+        }),
+    }
+}
+
+fn curve(curve: &str) -> Result<Curve> {
+    match curve {
+        "bn128" | "bn254" => Ok(Curve::Bn254),
+        "bls12381" | "bls12_381" => Ok(Curve::Bls12_381),
+        _ => Err(FormatError::UnsupportedCurve {
+            curve: curve.to_owned(),
+        }),
+    }
+}
+
+/// snarkjs only produces `bn128` and `bls12381` proofs.
+fn unsupported<T>(curve: Curve) -> Result<T> {
+    Err(FormatError::UnsupportedCurve {
+        curve: format!("{curve:?}"),
+    })
+}
+
+/// Parse a decimal field element: `F::from_str` silently reduces the values out of the field, so
+/// we check that the parsed element is the same number.
+fn field<F: PrimeField>(value: &str) -> Result<F> {
+    value
+        .parse::<F>()
+        .ok()
+        .filter(|f| f.into_bigint().to_string() == value)
+        .ok_or_else(|| FormatError::InvalidFieldElement {
+            value: value.to_owned(),
+        })
+}
+
+/// Check the point and convert it to affine coordinates: snarkjs uses `z = 1` for every point but
+/// the infinity, that has `z = 0`.
+fn checked<P: SWCurveConfig>(x: P::BaseField, y: P::BaseField, z: &[&str]) -> Result<Affine<P>> {
+    match z {
+        ["0"] | ["0", "0"] => return Ok(Affine::identity()),
+        ["1"] | ["1", "0"] => {}
+        _ => return Err(FormatError::InvalidPoint),
+    }
+    in_subgroup(Affine::new_unchecked(x, y))
+}
+
+fn in_subgroup<P: SWCurveConfig>(point: Affine<P>) -> Result<Affine<P>> {
+    (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve())
+        .then_some(point)
+        .ok_or(FormatError::InvalidPoint)
+}
+
+fn g1<P>(point: &[String; 3]) -> Result<G1>
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField,
+{
+    let [x, y, z] = point;
+    let point = checked::<P>(field(x)?, field(y)?, &[z.as_str()])?;
+    G1::try_from_affine(point).map_err(|_| FormatError::InvalidPoint)
+}
+
+fn g2<P, Q>(point: &[[String; 2]; 3]) -> Result<G2>
+where
+    P: SWCurveConfig<BaseField = QuadExtField<Q>>,
+    Q: QuadExtConfig,
+    Q::BaseField: PrimeField,
+{
+    let fq2 = |[c0, c1]: &[String; 2]| -> Result<QuadExtField<Q>> {
+        Ok(QuadExtField::new(field(c0)?, field(c1)?))
+    };
+    let [x, y, [z0, z1]] = point;
+    let point = checked::<P>(fq2(x)?, fq2(y)?, &[z0.as_str(), z1.as_str()])?;
+    G2::try_from_affine(point).map_err(|_| FormatError::InvalidPoint)
+}
+
+fn scalar<E: Pairing>(value: &str) -> Result<Scalar> {
+    Scalar::try_from_scalar(field::<E::ScalarField>(value)?).map_err(|_| {
+        FormatError::InvalidFieldElement {
+            value: value.to_owned(),
+        }
+    })
+}
+
+fn vk_on<E, P1, P2, Q>(vk: &SnarkjsVk) -> Result<VerificationKey>
+where
+    E: Pairing<G1Affine = Affine<P1>, G2Affine = Affine<P2>>,
+    P1: SWCurveConfig,
+    P1::BaseField: PrimeField,
+    P2: SWCurveConfig<BaseField = QuadExtField<Q>>,
+    Q: QuadExtConfig,
+    Q::BaseField: PrimeField,
+{
+    if vk.ic.len() != vk.n_public + 1 {
+        return Err(FormatError::InvalidIcLength {
+            expected: vk.n_public + 1,
+            found: vk.ic.len(),
+        });
+    }
+    Ok(VerificationKey {
+        alpha_g1: g1::<P1>(&vk.vk_alpha_1)?,
+        beta_g2: g2::<P2, Q>(&vk.vk_beta_2)?,
+        gamma_g2: g2::<P2, Q>(&vk.vk_gamma_2)?,
+        delta_g2: g2::<P2, Q>(&vk.vk_delta_2)?,
+        gamma_abc_g1: vk.ic.iter().map(g1::<P1>).collect::<Result<_>>()?,
+    })
+}
+
+fn proof_on<E, P1, P2, Q>(proof: &SnarkjsProof) -> Result<hp_groth16::Proof>
+where
+    E: Pairing<G1Affine = Affine<P1>, G2Affine = Affine<P2>>,
+    P1: SWCurveConfig,
+    P1::BaseField: PrimeField,
+    P2: SWCurveConfig<BaseField = QuadExtField<Q>>,
+    Q: QuadExtConfig,
+    Q::BaseField: PrimeField,
+{
+    Ok(hp_groth16::Proof {
+        a: g1::<P1>(&proof.pi_a)?,
+        b: g2::<P2, Q>(&proof.pi_b)?,
+        c: g1::<P1>(&proof.pi_c)?,
+    })
+}
+
+/// Import a snarkjs `verification_key.json`.
+pub fn vk_from_snarkjs(vk: &str) -> Result<Vk> {
+    let vk: SnarkjsVk = json(vk)?;
+    check_protocol(&vk.protocol)?;
+    let curve = curve(&vk.curve)?;
+    let inner = match curve {
+        Curve::Bn254 => vk_on::<ark_bn254::Bn254, _, _, _>(&vk)?,
+        Curve::Bls12_381 => vk_on::<ark_bls12_381::Bls12_381, _, _, _>(&vk)?,
+        Curve::Bls12_377 | Curve::Bw6_761 => unsupported(curve)?,
+    };
+    Ok(Vk::from_curve_and_vk(curve, inner))
+}
+
+/// Import a snarkjs `proof.json`.
+pub fn proof_from_snarkjs(proof: &str) -> Result<Proof> {
+    let proof: SnarkjsProof = json(proof)?;
+    check_protocol(&proof.protocol)?;
+    let curve = curve(&proof.curve)?;
+    let inner = match curve {
+        Curve::Bn254 => proof_on::<ark_bn254::Bn254, _, _, _>(&proof)?,
+        Curve::Bls12_381 => proof_on::<ark_bls12_381::Bls12_381, _, _, _>(&proof)?,
+        Curve::Bls12_377 | Curve::Bw6_761 => unsupported(curve)?,
+    };
+    Ok(Proof::new(curve, inner))
+}
+
+/// Import a snarkjs `public.json`: it doesn't name its curve, so the caller should provide it
+/// (i.e. the curve of the verification key).
+pub fn pubs_from_snarkjs(pubs: &str, curve: Curve) -> Result<Pubs> {
+    let pubs: Vec<String> = json(pubs)?;
+    pubs.iter()
+        .map(|value| match curve {
+            Curve::Bn254 => scalar::<ark_bn254::Bn254>(value),
+            Curve::Bls12_381 => scalar::<ark_bls12_381::Bls12_381>(value),
+            Curve::Bls12_377 | Curve::Bw6_761 => unsupported(curve),
+        })
+        .collect()
+}
+
+/// The flags that gnark puts in the most significant bits of the first byte of a point.
+struct Flags {
+    mask: u8,
+    uncompressed_infinity: Option<u8>,
+    compressed_smallest: u8,
+    compressed_largest: u8,
+    compressed_infinity: u8,
+}
+
+impl Flags {
+    fn of(curve: Curve) -> Self {
+        match curve {
+            // The base field has just 2 unused bits: the uncompressed infinity is all zeros.
+            Curve::Bn254 => Self {
+                mask: 0b11 << 6,
+                uncompressed_infinity: None,
+                compressed_smallest: 0b10 << 6,
+                compressed_largest: 0b11 << 6,
+                compressed_infinity: 0b01 << 6,
+            },
+            Curve::Bls12_381 | Curve::Bls12_377 | Curve::Bw6_761 => Self {
+                mask: 0b111 << 5,
+                uncompressed_infinity: Some(0b010 << 5),
+                compressed_smallest: 0b100 << 5,
+                compressed_largest: 0b101 << 5,
+                compressed_infinity: 0b110 << 5,
+            },
+        }
+    }
+}
+
+/// Read the big-endian gnark binary serialization: points can be compressed or not (`WriteTo`
+/// and `WriteRawTo`).
+struct GnarkReader<'a> {
+    bytes: &'a [u8],
+    flags: Flags,
+}
+
+impl<'a> GnarkReader<'a> {
+    fn new(bytes: &'a [u8], curve: Curve) -> Self {
+        Self {
+            bytes,
+            flags: Flags::of(curve),
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(FormatError::UnexpectedEnd);
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes(
+            bytes.try_into().expect("Took 4 bytes. qed"),
+        ))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        let bytes = self.take(8)?;
+        Ok(u64::from_be_bytes(
+            bytes.try_into().expect("Took 8 bytes. qed"),
+        ))
+    }
+
+    fn len(&mut self) -> Result<usize> {
+        self.u32().map(|len| len as usize)
+    }
+
+    fn prime_field<F: PrimeField>(&mut self) -> Result<F> {
+        prime_field(self.take(field_size::<F>())?)
+    }
+
+    /// gnark writes the coefficients of an extension field element from the highest one.
+    fn field<F: Field>(bytes: &[u8]) -> Result<F> {
+        let mut elems = bytes
+            .chunks(field_size::<F::BasePrimeField>())
+            .map(prime_field::<F::BasePrimeField>)
+            .collect::<Result<Vec<_>>>()?;
+        elems.reverse();
+        F::from_base_prime_field_elems(&elems).ok_or(FormatError::InvalidPoint)
+    }
+
+    fn point<P: SWCurveConfig>(&mut self) -> Result<Affine<P>> {
+        let size = field_size::<<P::BaseField as Field>::BasePrimeField>()
+            * P::BaseField::extension_degree() as usize;
+        let flag = *self.bytes.first().ok_or(FormatError::UnexpectedEnd)? & self.flags.mask;
+        let compressed = flag == self.flags.compressed_smallest
+            || flag == self.flags.compressed_largest
+            || flag == self.flags.compressed_infinity;
+        let mut bytes = self
+            .take(if compressed { size } else { 2 * size })?
+            .to_vec();
+        bytes[0] &= !self.flags.mask;
+        let infinity = if compressed {
+            flag == self.flags.compressed_infinity
+        } else {
+            match self.flags.uncompressed_infinity {
+                Some(infinity) => flag == infinity,
+                None => bytes.iter().all(|b| *b == 0),
+            }
+        };
+        if infinity {
+            return bytes
+                .iter()
+                .all(|b| *b == 0)
+                .then(Affine::identity)
+                .ok_or(FormatError::InvalidPoint);
+        }
+        if !compressed && flag != 0 {
+            return Err(FormatError::InvalidPoint);
+        }
+        let x = Self::field(&bytes[..size])?;
+        let point = if compressed {
+            Affine::get_point_from_x_unchecked(x, flag == self.flags.compressed_largest)
+                .ok_or(FormatError::InvalidPoint)?
+        } else {
+            Affine::new_unchecked(x, Self::field(&bytes[size..])?)
+        };
+        in_subgroup(point)
+    }
+
+    fn g1<P: SWCurveConfig>(&mut self) -> Result<G1> {
+        G1::try_from_affine(self.point::<P>()?).map_err(|_| FormatError::InvalidPoint)
+    }
+
+    fn g2<P: SWCurveConfig>(&mut self) -> Result<G2> {
+        G2::try_from_affine(self.point::<P>()?).map_err(|_| FormatError::InvalidPoint)
+    }
+
+    fn g1s<P: SWCurveConfig>(&mut self) -> Result<Vec<G1>> {
+        let len = self.len()?;
+        (0..len).map(|_| self.g1::<P>()).collect()
+    }
+
+    fn end(self) -> Result<()> {
+        match self.bytes.len() {
+            0 => Ok(()),
+            count => Err(FormatError::TrailingBytes { count }),
+        }
+    }
+}
+
+/// The size of a field element in the gnark serialization.
+fn field_size<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize).div_ceil(8)
+}
+
+/// Parse a big-endian field element that should be lower than the field modulus.
+fn prime_field<F: PrimeField>(bytes: &[u8]) -> Result<F> {
+    let value = F::from_be_bytes_mod_order(bytes);
+    (value.into_bigint().to_bytes_be().ends_with(bytes))
+        .then_some(value)
+        .ok_or_else(|| FormatError::InvalidFieldElement {
+            value: bytes.iter().map(|b| format!("{b:02x}")).collect(),
+        })
+}
+
+fn vk_on_gnark<E, P1, P2>(vk: &mut GnarkReader) -> Result<(VerificationKey, Option<VkCommitment>)>
+where
+    E: Pairing<G1Affine = Affine<P1>, G2Affine = Affine<P2>>,
+    P1: SWCurveConfig,
+    P2: SWCurveConfig,
+{
+    let alpha_g1 = vk.g1::<P1>()?;
+    let _beta_g1 = vk.g1::<P1>()?;
+    let beta_g2 = vk.g2::<P2>()?;
+    let gamma_g2 = vk.g2::<P2>()?;
+    let _delta_g1 = vk.g1::<P1>()?;
+    let delta_g2 = vk.g2::<P2>()?;
+    let gamma_abc_g1 = vk.g1s::<P1>()?;
+    let public_and_commitment_committed = (0..vk.len()?)
+        .map(|_| (0..vk.len()?).map(|_| vk.u64()).collect::<Result<Vec<_>>>())
+        .collect::<Result<Vec<_>>>()?;
+    let commitment_keys = (0..vk.len()?)
+        .map(|_| Ok((vk.g2::<P2>()?, vk.g2::<P2>()?)))
+        .collect::<Result<Vec<_>>>()?;
+    let vk_commitment = match (
+        public_and_commitment_committed.as_slice(),
+        commitment_keys.as_slice(),
+    ) {
+        ([], []) => None,
+        ([public_committed], [(g, g_sigma_neg)]) => {
+            // The commitment hash takes the last `gamma_abc_g1` point.
+            let num_inputs = gamma_abc_g1.len().saturating_sub(2) as u64;
+            let public_committed = public_committed
+                .iter()
+                .map(|&index| {
+                    (index > 0 && index <= num_inputs)
+                        .then_some(index as u32)
+                        .ok_or(FormatError::InvalidCommittedInput { index })
+                })
+                .collect::<Result<_>>()?;
+            Some(VkCommitment {
+                g: g.clone(),
+                g_sigma_neg: g_sigma_neg.clone(),
+                public_committed,
+            })
+        }
+        (_, keys) => {
+            return Err(FormatError::UnsupportedCommitments {
+                count: keys.len().max(public_and_commitment_committed.len()),
+            })
+        }
+    };
+    let vk = VerificationKey {
+        alpha_g1,
+        beta_g2,
+        gamma_g2,
+        delta_g2,
+        gamma_abc_g1,
+    };
+    Ok((vk, vk_commitment))
+}
+
+fn proof_on_gnark<E, P1, P2>(
+    proof: &mut GnarkReader,
+) -> Result<(hp_groth16::Proof, Option<ProofCommitment>)>
+where
+    E: Pairing<G1Affine = Affine<P1>, G2Affine = Affine<P2>>,
+    P1: SWCurveConfig,
+    P2: SWCurveConfig,
+{
+    let a = proof.g1::<P1>()?;
+    let b = proof.g2::<P2>()?;
+    let c = proof.g1::<P1>()?;
+    let mut commitments = proof.g1s::<P1>()?;
+    // gnark always writes the proof of knowledge, also without commitments.
+    let pok = proof.g1::<P1>()?;
+    let commitment = match commitments.len() {
+        0 => None,
+        1 => Some(ProofCommitment {
+            commitment: commitments.remove(0),
+            pok,
+        }),
+        count => return Err(FormatError::UnsupportedCommitments { count }),
+    };
+    Ok((hp_groth16::Proof { a, b, c }, commitment))
+}
+
+fn pubs_on_gnark<E: Pairing>(pubs: &mut GnarkReader) -> Result<Pubs> {
+    let n_public = pubs.len()?;
+    let secret = pubs.len()?;
+    if secret != 0 {
+        return Err(FormatError::NotPublicWitness { secret });
+    }
+    let len = pubs.len()?;
+    if len != n_public {
+        return Err(FormatError::InvalidWitnessLength {
+            expected: n_public,
+            found: len,
+        });
+    }
+    (0..len)
+        .map(|_| {
+            let value = pubs.prime_field::<E::ScalarField>()?;
+            Ok(Scalar::try_from_scalar(value).expect("Serialize to a vector cannot fail. qed"))
+        })
+        .collect()
+}
+
+/// Import a gnark verification key serialized by `WriteTo` or `WriteRawTo` (gnark v0.10 or
+/// later): it doesn't name its curve, so the caller should provide it. Just a single
+/// commitment is supported.
+pub fn vk_from_gnark(vk: &[u8], curve: Curve) -> Result<Vk> {
+    let mut reader = GnarkReader::new(vk, curve);
+    let (inner, commitment) = match curve {
+        Curve::Bn254 => vk_on_gnark::<ark_bn254::Bn254, _, _>(&mut reader)?,
+        Curve::Bls12_381 => vk_on_gnark::<ark_bls12_381::Bls12_381, _, _>(&mut reader)?,
+        Curve::Bls12_377 => vk_on_gnark::<hp_groth16::Bls12_377, _, _>(&mut reader)?,
+        Curve::Bw6_761 => vk_on_gnark::<hp_groth16::BW6_761, _, _>(&mut reader)?,
+    };
+    reader.end()?;
+    let vk = Vk::from_curve_and_vk(curve, inner);
+    Ok(match commitment {
+        Some(commitment) => vk.with_commitment(commitment),
+        None => vk,
+    })
+}
+
+/// Import a gnark proof serialized by `WriteTo` or `WriteRawTo` (gnark v0.10 or later): it
+/// doesn't name its curve, so the caller should provide it.
+pub fn proof_from_gnark(proof: &[u8], curve: Curve) -> Result<Proof> {
+    let mut reader = GnarkReader::new(proof, curve);
+    let (inner, commitment) = match curve {
+        Curve::Bn254 => proof_on_gnark::<ark_bn254::Bn254, _, _>(&mut reader)?,
+        Curve::Bls12_381 => proof_on_gnark::<ark_bls12_381::Bls12_381, _, _>(&mut reader)?,
+        Curve::Bls12_377 => proof_on_gnark::<hp_groth16::Bls12_377, _, _>(&mut reader)?,
+        Curve::Bw6_761 => proof_on_gnark::<hp_groth16::BW6_761, _, _>(&mut reader)?,
+    };
+    reader.end()?;
+    let proof = Proof::new(curve, inner);
+    Ok(match commitment {
+        Some(commitment) => proof.with_commitment(commitment),
+        None => proof,
+    })
+}
+
+/// Import a gnark public witness serialized by `MarshalBinary`. The commitment hash is not part
+/// of it: the verifier computes it.
+pub fn pubs_from_gnark(pubs: &[u8], curve: Curve) -> Result<Pubs> {
+    let mut reader = GnarkReader::new(pubs, curve);
+    let pubs = match curve {
+        Curve::Bn254 => pubs_on_gnark::<ark_bn254::Bn254>(&mut reader)?,
+        Curve::Bls12_381 => pubs_on_gnark::<ark_bls12_381::Bls12_381>(&mut reader)?,
+        Curve::Bls12_377 => pubs_on_gnark::<hp_groth16::Bls12_377>(&mut reader)?,
+        Curve::Bw6_761 => pubs_on_gnark::<hp_groth16::BW6_761>(&mut reader)?,
+    };
+    reader.end()?;
+    Ok(pubs)
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::Groth16;
+    use hp_verifiers::Verifier;
+    use rstest::rstest;
+
+    struct Mock;
+    impl crate::Config for Mock {
+        const MAX_NUM_INPUTS: u32 = 16;
+        const ACCEPT_UNBENCHMARKED_CURVES: bool = true;
+    }
+
+    fn fixtures(curve: Curve) -> (&'static str, &'static str, &'static str) {
+        match curve {
+            Curve::Bn254 => (
+                include_str!("resources/bn254_verification_key.json"),
+                include_str!("resources/bn254_proof.json"),
+                include_str!("resources/bn254_public.json"),
+            ),
+            Curve::Bls12_381 => (
+                include_str!("resources/bls12_381_verification_key.json"),
+                include_str!("resources/bls12_381_proof.json"),
+                include_str!("resources/bls12_381_public.json"),
+            ),
+            Curve::Bls12_377 | Curve::Bw6_761 => unreachable!("snarkjs has no {curve:?} fixtures"),
+        }
+    }
+
+    #[rstest]
+    fn import_a_valid_proof(#[values(Curve::Bn254, Curve::Bls12_381)] curve: Curve) {
+        let (vk, proof, pubs) = fixtures(curve);
+        let vk = vk_from_snarkjs(vk).unwrap();
+        let proof = proof_from_snarkjs(proof).unwrap();
+        let pubs = pubs_from_snarkjs(pubs, vk.curve).unwrap();
+
+        assert_eq!(vk.curve, curve);
+        assert_eq!(proof.curve, curve);
+        assert!(Groth16::<Mock>::validate_vk(&vk).is_ok());
+        assert!(Groth16::<Mock>::verify_proof(&vk, &proof, &pubs).is_ok());
+    }
+
+    #[test]
+    fn reject_a_point_that_is_not_on_the_curve() {
+        let (vk, _, _) = fixtures(Curve::Bn254);
+        let mut vk: serde_json::Value = serde_json::from_str(vk).unwrap();
+        vk["vk_alpha_1"][0] = "1".into();
+
+        assert!(matches!(
+            vk_from_snarkjs(&vk.to_string()),
+            Err(FormatError::InvalidPoint)
+        ));
+    }
+
+    #[test]
+    fn reject_a_public_input_out_of_the_field() {
+        // The bn254 scalar field modulus
+        let pubs =
+            r#"["21888242871839275222246405745257275088548364400416034343698204186575808495617"]"#;
+
+        assert!(matches!(
+            pubs_from_snarkjs(pubs, Curve::Bn254),
+            Err(FormatError::InvalidFieldElement { .. })
+        ));
+    }
+
+    #[rstest]
+    fn reject_public_inputs_on_a_curve_unknown_to_snarkjs(
+        #[values(Curve::Bls12_377, Curve::Bw6_761)] curve: Curve,
+    ) {
+        assert!(matches!(
+            pubs_from_snarkjs(r#"["1"]"#, curve),
+            Err(FormatError::UnsupportedCurve { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case::protocol("protocol", "plonk")]
+    #[case::curve("curve", "secp256k1")]
+    fn reject_unsupported_headers(#[case] field: &str, #[case] value: &str) {
+        let (_, proof, _) = fixtures(Curve::Bn254);
+        let mut proof: serde_json::Value = serde_json::from_str(proof).unwrap();
+        proof[field] = value.into();
+
+        assert!(matches!(
+            proof_from_snarkjs(&proof.to_string()),
+            Err(FormatError::UnsupportedProtocol { .. } | FormatError::UnsupportedCurve { .. })
+        ));
+    }
+
+    mod gnark {
+        use super::*;
+
+        /// Write the instances in the gnark binary serialization: the verifier types don't have
+        /// the `[β]1` and `[δ]1` points, so `[α]1` takes their place.
+        struct Writer {
+            bytes: Vec<u8>,
+            flags: Flags,
+            compressed: bool,
+        }
+
+        impl Writer {
+            fn new(curve: Curve, compressed: bool) -> Self {
+                Self {
+                    bytes: Vec::new(),
+                    flags: Flags::of(curve),
+                    compressed,
+                }
+            }
+
+            fn u32(&mut self, value: usize) {
+                self.bytes.extend((value as u32).to_be_bytes());
+            }
+
+            fn field<F: Field>(&mut self, value: &F) {
+                let elems = value.to_base_prime_field_elements().collect::<Vec<_>>();
+                for elem in elems.iter().rev() {
+                    self.bytes.extend(elem.into_bigint().to_bytes_be());
+                }
+            }
+
+            fn point<P: SWCurveConfig>(&mut self, point: Affine<P>) {
+                let start = self.bytes.len();
+                if point.infinity {
+                    self.field(&P::BaseField::ZERO);
+                    if self.compressed {
+                        self.bytes[start] |= self.flags.compressed_infinity;
+                    } else {
+                        self.field(&P::BaseField::ZERO);
+                        self.bytes[start] |= self.flags.uncompressed_infinity.unwrap_or(0);
+                    }
+                    return;
+                }
+                self.field(&point.x);
+                let flag = if !self.compressed {
+                    self.field(&point.y);
+                    0
+                } else if point.y > -point.y {
+                    self.flags.compressed_largest
+                } else {
+                    self.flags.compressed_smallest
+                };
+                self.bytes[start] |= flag;
+            }
+
+            fn g1<P: SWCurveConfig>(&mut self, point: &G1) {
+                self.point::<P>(point.clone().try_into_affine().unwrap());
+            }
+
+            fn g2<P: SWCurveConfig>(&mut self, point: &G2) {
+                self.point::<P>(point.clone().try_into_affine().unwrap());
+            }
+
+            fn vk<P1: SWCurveConfig, P2: SWCurveConfig>(mut self, vk: &Vk) -> Vec<u8> {
+                self.g1::<P1>(&vk.alpha_g1);
+                self.g1::<P1>(&vk.alpha_g1);
+                self.g2::<P2>(&vk.beta_g2);
+                self.g2::<P2>(&vk.gamma_g2);
+                self.g1::<P1>(&vk.alpha_g1);
+                self.g2::<P2>(&vk.delta_g2);
+                self.u32(vk.gamma_abc_g1.len());
+                vk.gamma_abc_g1.iter().for_each(|p| self.g1::<P1>(p));
+                let commitments = vk.commitment.iter().collect::<Vec<_>>();
+                self.u32(commitments.len());
+                for commitment in &commitments {
+                    self.u32(commitment.public_committed.len());
+                    for index in &commitment.public_committed {
+                        self.bytes.extend((*index as u64).to_be_bytes());
+                    }
+                }
+                self.u32(commitments.len());
+                for commitment in &commitments {
+                    self.g2::<P2>(&commitment.g);
+                    self.g2::<P2>(&commitment.g_sigma_neg);
+                }
+                self.bytes
+            }
+
+            fn proof<P1: SWCurveConfig, P2: SWCurveConfig>(mut self, proof: &Proof) -> Vec<u8> {
+                self.g1::<P1>(&proof.proof.a);
+                self.g2::<P2>(&proof.proof.b);
+                self.g1::<P1>(&proof.proof.c);
+                match &proof.commitment {
+                    Some(commitment) => {
+                        self.u32(1);
+                        self.g1::<P1>(&commitment.commitment);
+                        self.g1::<P1>(&commitment.pok);
+                    }
+                    None => {
+                        self.u32(0);
+                        self.point::<P1>(Affine::identity());
+                    }
+                }
+                self.bytes
+            }
+
+            fn pubs<F: PrimeField>(mut self, pubs: &Pubs) -> Vec<u8> {
+                self.u32(pubs.len());
+                self.u32(0);
+                self.u32(pubs.len());
+                for value in pubs {
+                    self.field(&value.clone().try_into_scalar::<F>().unwrap());
+                }
+                self.bytes
+            }
+        }
+
+        fn write_on<E, P1, P2>(
+            curve: Curve,
+            compressed: bool,
+            (proof, vk, pubs): &(Proof, Vk, Pubs),
+        ) -> (Vec<u8>, Vec<u8>, Vec<u8>)
+        where
+            E: Pairing<G1Affine = Affine<P1>, G2Affine = Affine<P2>>,
+            P1: SWCurveConfig,
+            P2: SWCurveConfig,
+        {
+            (
+                Writer::new(curve, compressed).vk::<P1, P2>(vk),
+                Writer::new(curve, compressed).proof::<P1, P2>(proof),
+                Writer::new(curve, compressed).pubs::<E::ScalarField>(pubs),
+            )
+        }
+
+        /// The gnark serialization of the verification key, proof and public inputs.
+        fn write(
+            curve: Curve,
+            compressed: bool,
+            instance: &(Proof, Vk, Pubs),
+        ) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+            match curve {
+                Curve::Bn254 => write_on::<ark_bn254::Bn254, _, _>(curve, compressed, instance),
+                Curve::Bls12_381 => {
+                    write_on::<ark_bls12_381::Bls12_381, _, _>(curve, compressed, instance)
+                }
+                Curve::Bls12_377 => {
+                    write_on::<hp_groth16::Bls12_377, _, _>(curve, compressed, instance)
+                }
+                Curve::Bw6_761 => {
+                    write_on::<hp_groth16::BW6_761, _, _>(curve, compressed, instance)
+                }
+            }
+        }
+
+        fn import(
+            curve: Curve,
+            (vk, proof, pubs): &(Vec<u8>, Vec<u8>, Vec<u8>),
+        ) -> (Proof, Vk, Pubs) {
+            (
+                proof_from_gnark(proof, curve).unwrap(),
+                vk_from_gnark(vk, curve).unwrap(),
+                pubs_from_gnark(pubs, curve).unwrap(),
+            )
+        }
+
+        #[rstest]
+        fn import_a_valid_proof_with_commitment(
+            #[values(Curve::Bn254, Curve::Bls12_381, Curve::Bls12_377, Curve::Bw6_761)]
+            curve: Curve,
+            #[values(true, false)] compressed: bool,
+        ) {
+            let instance = crate::groth16::Groth16::get_instance_with_commitment(3, Some(0), curve);
+
+            let (proof, vk, pubs) = import(curve, &write(curve, compressed, &instance));
+
+            assert_eq!(
+                (&proof, &vk, &pubs),
+                (&instance.0, &instance.1, &instance.2)
+            );
+            assert!(Groth16::<Mock>::validate_vk(&vk).is_ok());
+            assert!(Groth16::<Mock>::verify_proof(&vk, &proof, &pubs).is_ok());
+        }
+
+        #[rstest]
+        fn import_a_valid_proof_without_commitment(
+            #[values(Curve::Bn254, Curve::Bls12_381)] curve: Curve,
+            #[values(true, false)] compressed: bool,
+        ) {
+            let instance = crate::groth16::Groth16::get_instance(3, Some(0), curve);
+
+            let (proof, vk, pubs) = import(curve, &write(curve, compressed, &instance));
+
+            assert_eq!(
+                (&proof, &vk, &pubs),
+                (&instance.0, &instance.1, &instance.2)
+            );
+            assert!(Groth16::<Mock>::verify_proof(&vk, &proof, &pubs).is_ok());
+        }
+
+        #[test]
+        fn reject_a_point_that_is_not_on_the_curve() {
+            let instance = crate::groth16::Groth16::get_instance(1, Some(0), Curve::Bn254);
+            let (_, mut proof, _) = write(Curve::Bn254, false, &instance);
+            proof[63] ^= 1;
+
+            assert!(matches!(
+                proof_from_gnark(&proof, Curve::Bn254),
+                Err(FormatError::InvalidPoint)
+            ));
+        }
+
+        #[rstest]
+        #[case::truncated(-1)]
+        #[case::trailing_bytes(1)]
+        fn reject_data_of_wrong_length(#[case] delta: isize) {
+            let instance = crate::groth16::Groth16::get_instance(1, Some(0), Curve::Bn254);
+            let (mut vk, _, _) = write(Curve::Bn254, true, &instance);
+            vk.resize((vk.len() as isize + delta) as usize, 0);
+
+            assert!(matches!(
+                vk_from_gnark(&vk, Curve::Bn254),
+                Err(FormatError::UnexpectedEnd | FormatError::TrailingBytes { .. })
+            ));
+        }
+
+        #[test]
+        fn reject_committed_input_out_of_range() {
+            let mut instance =
+                crate::groth16::Groth16::get_instance_with_commitment(3, Some(0), Curve::Bn254);
+            instance.1.commitment.as_mut().unwrap().public_committed = vec![4];
+            let (vk, _, _) = write(Curve::Bn254, true, &instance);
+
+            assert!(matches!(
+                vk_from_gnark(&vk, Curve::Bn254),
+                Err(FormatError::InvalidCommittedInput { index: 4 })
+            ));
+        }
+
+        #[test]
+        fn reject_a_witness_with_secret_values() {
+            let mut pubs = Vec::new();
+            pubs.extend(1_u32.to_be_bytes());
+            pubs.extend(1_u32.to_be_bytes());
+            pubs.extend(2_u32.to_be_bytes());
+            pubs.extend([0; 64]);
+
+            assert!(matches!(
+                pubs_from_gnark(&pubs, Curve::Bn254),
+                Err(FormatError::NotPublicWitness { secret: 1 })
+            ));
+        }
+    }
+}
@@ -19,12 +19,19 @@ use hp_verifiers::VerifyError;
 use scale_info::TypeInfo;
 use sp_std::vec::Vec;
 
-pub use hp_groth16::{vec_max_encoded_len, Proof, Scalar, VerificationKey, G1, G2};
+pub use hp_groth16::{
+    vec_max_encoded_len, CommittedBatchItem, Proof, ProofCommitment, Scalar, VerificationKey,
+    VkCommitment, G1, G2,
+};
 
 #[derive(Copy, Clone, Debug, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum Curve {
     Bn254,
     Bls12_381,
+    /// Not supported yet: rejected unless [`crate::Config::ACCEPT_UNBENCHMARKED_CURVES`] is set.
+    Bls12_377,
+    /// Not supported yet: rejected unless [`crate::Config::ACCEPT_UNBENCHMARKED_CURVES`] is set.
+    Bw6_761,
 }
 
 #[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
@@ -35,6 +42,9 @@ pub struct VerificationKeyWithCurve {
     pub gamma_g2: G2,
     pub delta_g2: G2,
     pub gamma_abc_g1: Vec<G1>,
+    /// The gnark commitment key: if present, `gamma_abc_g1` has a further point for the
+    /// commitment hash input.
+    pub commitment: Option<VkCommitment>,
 }
 
 impl MaxEncodedLen for VerificationKeyWithCurve {
@@ -42,7 +52,10 @@ impl MaxEncodedLen for VerificationKeyWithCurve {
         Curve::max_encoded_len()
             + G1::max_encoded_len()
             + 3 * G2::max_encoded_len()
-            + vec_max_encoded_len(G1::max_encoded_len(), crate::MAX_NUM_INPUTS + 1)
+            + vec_max_encoded_len(G1::max_encoded_len(), crate::MAX_NUM_INPUTS + 2)
+            + 1
+            + 2 * G2::max_encoded_len()
+            + vec_max_encoded_len(u32::max_encoded_len(), crate::MAX_NUM_INPUTS)
     }
 }
 
@@ -55,6 +68,14 @@ impl VerificationKeyWithCurve {
             gamma_g2: vk.gamma_g2,
             delta_g2: vk.delta_g2,
             gamma_abc_g1: vk.gamma_abc_g1,
+            commitment: None,
+        }
+    }
+
+    pub fn with_commitment(self, commitment: VkCommitment) -> Self {
+        Self {
+            commitment: Some(commitment),
+            ..self
         }
     }
 
@@ -73,6 +94,9 @@ impl VerificationKeyWithCurve {
 pub struct ProofWithCurve {
     pub curve: Curve,
     pub proof: Proof,
+    /// The gnark commitment: a proof has it if and only if its verification key has a
+    /// commitment key.
+    pub commitment: Option<ProofCommitment>,
 }
 
 impl Default for ProofWithCurve {
@@ -84,13 +108,25 @@ impl Default for ProofWithCurve {
                 b: G2(Vec::default()),
                 c: G1(Vec::default()),
             },
+            commitment: None,
         }
     }
 }
 
 impl ProofWithCurve {
     pub fn new(curve: Curve, proof: Proof) -> Self {
-        Self { curve, proof }
+        Self {
+            curve,
+            proof,
+            commitment: None,
+        }
+    }
+
+    pub fn with_commitment(self, commitment: ProofCommitment) -> Self {
+        Self {
+            commitment: Some(commitment),
+            ..self
+        }
     }
 }
 
@@ -104,12 +140,60 @@ pub struct Groth16;
 
 impl Groth16 {
     pub fn verify_proof(
-        proof: Proof,
+        proof: ProofWithCurve,
         vk: VerificationKeyWithCurve,
         inputs: &[Scalar],
     ) -> Result<bool, VerifyError> {
         let curve = vk.curve;
+        let vk_commitment = vk.commitment.clone();
         let vk = vk.vk();
+        match (vk_commitment, proof.commitment) {
+            (None, None) => Self::verify_plain_proof(curve, vk, proof.proof, inputs),
+            (Some(vk_commitment), Some(proof_commitment)) => {
+                let proof = proof.proof;
+                match curve {
+                    Curve::Bn254 => native::groth_16_bn_254_verify::verify_with_commitment(
+                        vk,
+                        vk_commitment,
+                        proof,
+                        proof_commitment,
+                        inputs,
+                    ),
+                    Curve::Bls12_381 => native::groth_16_bls_12_381_verify::verify_with_commitment(
+                        vk,
+                        vk_commitment,
+                        proof,
+                        proof_commitment,
+                        inputs,
+                    ),
+                    Curve::Bls12_377 => native::groth_16_bls_12_377_verify::verify_with_commitment(
+                        vk,
+                        vk_commitment,
+                        proof,
+                        proof_commitment,
+                        inputs,
+                    ),
+                    Curve::Bw6_761 => native::groth_16_bw_6_761_verify::verify_with_commitment(
+                        vk,
+                        vk_commitment,
+                        proof,
+                        proof_commitment,
+                        inputs,
+                    ),
+                }
+                .map_err(Into::into)
+            }
+            // A proof has a commitment if and only if its verification key has a commitment key.
+            _ => Err(VerifyError::InvalidProofData),
+        }
+    }
+
+    fn verify_plain_proof(
+        curve: Curve,
+        vk: VerificationKey,
+        proof: Proof,
+        inputs: &[Scalar],
+    ) -> Result<bool, VerifyError> {
         match curve {
             Curve::Bn254 => {
                 native::groth_16_bn_254_verify::verify(vk, proof, inputs).map_err(Into::into)
@@ -117,7 +201,68 @@ impl Groth16 {
             Curve::Bls12_381 => {
                 native::groth_16_bls_12_381_verify::verify(vk, proof, inputs).map_err(Into::into)
             }
+            Curve::Bls12_377 => {
+                native::groth_16_bls_12_377_verify::verify(vk, proof, inputs).map_err(Into::into)
+            }
+            Curve::Bw6_761 => {
+                native::groth_16_bw_6_761_verify::verify(vk, proof, inputs).map_err(Into::into)
+            }
+        }
+    }
+
+    pub fn verify_batch(curve: Curve, batch: Vec<CommittedBatchItem>) -> Result<bool, VerifyError> {
+        match curve {
+            Curve::Bn254 => native::groth_16_bn_254_verify::verify_batch_with_commitments(batch),
+            Curve::Bls12_381 => {
+                native::groth_16_bls_12_381_verify::verify_batch_with_commitments(batch)
+            }
+            Curve::Bls12_377 => {
+                native::groth_16_bls_12_377_verify::verify_batch_with_commitments(batch)
+            }
+            Curve::Bw6_761 => {
+                native::groth_16_bw_6_761_verify::verify_batch_with_commitments(batch)
+            }
+        }
+        .map_err(Into::into)
+    }
+
+    pub fn validate_key(vk: VerificationKeyWithCurve) -> Result<(), VerifyError> {
+        let curve = vk.curve;
+        match vk.commitment.clone() {
+            None => {
+                let vk = vk.vk();
+                match curve {
+                    Curve::Bn254 => native::groth_16_bn_254_verify::validate_key(vk),
+                    Curve::Bls12_381 => native::groth_16_bls_12_381_verify::validate_key(vk),
+                    Curve::Bls12_377 => native::groth_16_bls_12_377_verify::validate_key(vk),
+                    Curve::Bw6_761 => native::groth_16_bw_6_761_verify::validate_key(vk),
+                }
+            }
+            Some(commitment) => {
+                let vk = vk.vk();
+                match curve {
+                    Curve::Bn254 => {
+                        native::groth_16_bn_254_verify::validate_key_with_commitment(vk, commitment)
+                    }
+                    Curve::Bls12_381 => {
+                        native::groth_16_bls_12_381_verify::validate_key_with_commitment(
+                            vk, commitment,
+                        )
+                    }
+                    Curve::Bls12_377 => {
+                        native::groth_16_bls_12_377_verify::validate_key_with_commitment(
+                            vk, commitment,
+                        )
+                    }
+                    Curve::Bw6_761 => {
+                        native::groth_16_bw_6_761_verify::validate_key_with_commitment(
+                            vk, commitment,
+                        )
+                    }
+                }
+            }
         }
+        .map_err(Into::into)
     }
 
     #[cfg(any(test, feature = "runtime-benchmarks"))]
@@ -133,6 +278,12 @@ impl Groth16 {
             Curve::Bls12_381 => hp_groth16::dummy_circuit::get_instance::<hp_groth16::Bls12_381>(
                 num_inputs, rng_seed,
             ),
+            Curve::Bls12_377 => hp_groth16::dummy_circuit::get_instance::<hp_groth16::Bls12_377>(
+                num_inputs, rng_seed,
+            ),
+            Curve::Bw6_761 => {
+                hp_groth16::dummy_circuit::get_instance::<hp_groth16::BW6_761>(num_inputs, rng_seed)
+            }
         };
 
         (
@@ -141,4 +292,33 @@ impl Groth16 {
             inputs,
         )
     }
+
+    /// Like [`Self::get_instance`] but for a gnark circuit with a commitment.
+    #[cfg(any(test, feature = "runtime-benchmarks"))]
+    pub fn get_instance_with_commitment(
+        num_inputs: usize,
+        rng_seed: Option<u64>,
+        curve: Curve,
+    ) -> (ProofWithCurve, VerificationKeyWithCurve, Vec<Scalar>) {
+        use hp_groth16::dummy_circuit::get_instance_with_commitment;
+
+        let (proof, proof_commitment, vk, vk_commitment, inputs) = match curve {
+            Curve::Bn254 => get_instance_with_commitment::<hp_groth16::Bn254>(num_inputs, rng_seed),
+            Curve::Bls12_381 => {
+                get_instance_with_commitment::<hp_groth16::Bls12_381>(num_inputs, rng_seed)
+            }
+            Curve::Bls12_377 => {
+                get_instance_with_commitment::<hp_groth16::Bls12_377>(num_inputs, rng_seed)
+            }
+            Curve::Bw6_761 => {
+                get_instance_with_commitment::<hp_groth16::BW6_761>(num_inputs, rng_seed)
+            }
+        };
+
+        (
+            ProofWithCurve::new(curve, proof).with_commitment(proof_commitment),
+            VerificationKeyWithCurve::from_curve_and_vk(curve, vk).with_commitment(vk_commitment),
+            inputs,
+        )
+    }
 }
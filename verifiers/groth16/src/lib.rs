@@ -16,13 +16,18 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod benchmarking;
+#[cfg(feature = "std")]
+pub mod formats;
 mod groth16;
+pub mod migrations;
 mod verifier_should;
 mod weight;
 
 use core::marker::PhantomData;
-use groth16::Curve;
-pub use groth16::{ProofWithCurve as Proof, VerificationKeyWithCurve as Vk};
+pub use groth16::{
+    Curve, ProofCommitment, ProofWithCurve as Proof, VerificationKeyWithCurve as Vk, VkCommitment,
+    G1, G2,
+};
 use hp_groth16::Scalar;
 use hp_verifiers::Verifier;
 use sp_std::vec::Vec;
@@ -33,6 +38,10 @@ pub use weight::WeightInfo;
 pub trait Config: 'static {
     /// Maximum supported number of public inputs.
     const MAX_NUM_INPUTS: u32;
+    /// Accept the curves whose weights are not benchmarked yet: BLS12-377 and BW6-761. Their
+    /// weights are estimates, so a production runtime should set it just for the benchmarks.
+    /// When it's off their vks and proofs are rejected as invalid.
+    const ACCEPT_UNBENCHMARKED_CURVES: bool;
 }
 
 #[pallet_verifiers::verifier]
@@ -55,14 +64,10 @@ impl<T: Config> Verifier for Groth16<T> {
         proof: &Self::Proof,
         pubs: &Self::Pubs,
     ) -> Result<(), hp_verifiers::VerifyError> {
-        if pubs.len() > T::MAX_NUM_INPUTS as usize {
-            return Err(hp_verifiers::VerifyError::InvalidInput);
-        }
-        if pubs.len() + 1 != vk.gamma_abc_g1.len() {
-            return Err(hp_verifiers::VerifyError::InvalidInput);
-        }
+        check_curve::<T>(vk, proof)?;
+        check_inputs::<T>(vk, pubs)?;
 
-        groth16::Groth16::verify_proof(proof.clone().into(), vk.clone(), pubs)
+        groth16::Groth16::verify_proof(proof.clone(), vk.clone(), pubs)
             .map_err(Into::into)
             .and_then(|r| {
                 r.then_some(())
@@ -70,6 +75,49 @@ impl<T: Config> Verifier for Groth16<T> {
             })
     }
 
+    fn verify_batch(
+        batch: &[(&Self::Vk, &Self::Proof, &Self::Pubs)],
+    ) -> Result<(), hp_verifiers::VerifyError> {
+        let mut bn254 = Vec::new();
+        let mut bls12_381 = Vec::new();
+        let mut bls12_377 = Vec::new();
+        let mut bw6_761 = Vec::new();
+        for (vk, proof, pubs) in batch {
+            check_curve::<T>(vk, proof)?;
+            check_inputs::<T>(vk, pubs)?;
+            let item = (
+                (*vk).clone().vk(),
+                vk.commitment.clone(),
+                proof.proof.clone(),
+                proof.commitment.clone(),
+                pubs.to_vec(),
+            );
+            match vk.curve {
+                Curve::Bn254 => bn254.push(item),
+                Curve::Bls12_381 => bls12_381.push(item),
+                Curve::Bls12_377 => bls12_377.push(item),
+                Curve::Bw6_761 => bw6_761.push(item),
+            }
+        }
+
+        [
+            (Curve::Bn254, bn254),
+            (Curve::Bls12_381, bls12_381),
+            (Curve::Bls12_377, bls12_377),
+            (Curve::Bw6_761, bw6_761),
+        ]
+        .into_iter()
+        .filter(|(_, items)| !items.is_empty())
+        .try_for_each(|(curve, items)| {
+            groth16::Groth16::verify_batch(curve, items)
+                .map_err(Into::into)
+                .and_then(|r| {
+                    r.then_some(())
+                        .ok_or(hp_verifiers::VerifyError::VerifyError)
+                })
+        })
+    }
+
     fn pubs_bytes(pubs: &Self::Pubs) -> hp_verifiers::Cow<[u8]> {
         let data = pubs
             .iter()
@@ -79,20 +127,77 @@ impl<T: Config> Verifier for Groth16<T> {
     }
 
     fn validate_vk(vk: &Self::Vk) -> Result<(), hp_verifiers::VerifyError> {
-        let curve = vk.curve;
-        let vk = vk.clone().vk();
-        match curve {
-            Curve::Bn254 => native::groth_16_bn_254_verify::validate_key(vk),
-            Curve::Bls12_381 => native::groth_16_bls_12_381_verify::validate_key(vk),
+        check_vk_curve::<T>(vk)?;
+        groth16::Groth16::validate_key(vk.clone())
+    }
+}
+
+fn check_vk_curve<T: Config>(vk: &Vk) -> Result<(), hp_verifiers::VerifyError> {
+    match vk.curve {
+        Curve::Bls12_377 | Curve::Bw6_761 if !T::ACCEPT_UNBENCHMARKED_CURVES => {
+            Err(hp_verifiers::VerifyError::InvalidVerificationKey)
         }
-        .map_err(Into::into)
+        _ => Ok(()),
+    }
+}
+
+/// The weight is computed on the proof curve: it should be the one we verify on.
+fn check_curve<T: Config>(vk: &Vk, proof: &Proof) -> Result<(), hp_verifiers::VerifyError> {
+    check_vk_curve::<T>(vk)?;
+    if proof.curve != vk.curve {
+        return Err(hp_verifiers::VerifyError::InvalidProofData);
     }
+    Ok(())
+}
+
+fn check_inputs<T: Config>(vk: &Vk, pubs: &Pubs) -> Result<(), hp_verifiers::VerifyError> {
+    if pubs.len() > T::MAX_NUM_INPUTS as usize {
+        return Err(hp_verifiers::VerifyError::InvalidInput);
+    }
+    // A commitment key takes a further point for the commitment hash input.
+    let extra = if vk.commitment.is_some() { 2 } else { 1 };
+    if pubs.len() + extra != vk.gamma_abc_g1.len() {
+        return Err(hp_verifiers::VerifyError::InvalidInput);
+    }
+    Ok(())
 }
 
 /// The struct to use in runtime pallet configuration to map the weight computed by this crate
 /// benchmarks to the weight needed by the `pallet-verifiers`.
 pub struct Groth16Weight<W: WeightInfo>(PhantomData<W>);
 
+impl<W: WeightInfo> Groth16Weight<W> {
+    fn submit_proofs(curve: Curve) -> fn(u32) -> frame_support::weights::Weight {
+        match curve {
+            Curve::Bn254 => W::submit_proofs_bn254,
+            Curve::Bls12_381 => W::submit_proofs_bls12_381,
+            Curve::Bls12_377 => W::submit_proofs_bls12_377,
+            Curve::Bw6_761 => W::submit_proofs_bw6_761,
+        }
+    }
+
+    fn submit_proofs_with_vk_hash(curve: Curve) -> fn(u32) -> frame_support::weights::Weight {
+        match curve {
+            Curve::Bn254 => W::submit_proofs_bn254_with_vk_hash,
+            Curve::Bls12_381 => W::submit_proofs_bls12_381_with_vk_hash,
+            Curve::Bls12_377 => W::submit_proofs_bls12_377_with_vk_hash,
+            Curve::Bw6_761 => W::submit_proofs_bw6_761_with_vk_hash,
+        }
+    }
+
+    /// A gnark commitment adds two pairings and the commitment hash input to the check: it's
+    /// charged as a further proof in a batch, that adds four pairings.
+    fn commitment(proof: &Proof) -> frame_support::weights::Weight {
+        match proof.commitment {
+            None => frame_support::weights::Weight::zero(),
+            Some(_) => {
+                let submit_proofs = Self::submit_proofs(proof.curve);
+                submit_proofs(2).saturating_sub(submit_proofs(1))
+            }
+        }
+    }
+}
+
 impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Groth16<T>> for Groth16Weight<W> {
     fn submit_proof(
         proof: &<Groth16<T> as Verifier>::Proof,
@@ -106,7 +211,10 @@ impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Groth16<T>> for Grot
         match proof.curve {
             Curve::Bn254 => W::submit_proof_bn254(n),
             Curve::Bls12_381 => W::submit_proof_bls12_381(n),
+            Curve::Bls12_377 => W::submit_proof_bls12_377(n),
+            Curve::Bw6_761 => W::submit_proof_bw6_761(n),
         }
+        .saturating_add(Self::commitment(proof))
     }
 
     fn submit_proof_with_vk_hash(
@@ -121,7 +229,58 @@ impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Groth16<T>> for Grot
         match proof.curve {
             Curve::Bn254 => W::submit_proof_bn254_with_vk_hash(n),
             Curve::Bls12_381 => W::submit_proof_bls12_381_with_vk_hash(n),
+            Curve::Bls12_377 => W::submit_proof_bls12_377_with_vk_hash(n),
+            Curve::Bw6_761 => W::submit_proof_bw6_761_with_vk_hash(n),
         }
+        .saturating_add(Self::commitment(proof))
+    }
+
+    fn submit_proofs_batch(
+        proofs: &[(
+            &<Groth16<T> as Verifier>::Proof,
+            &<Groth16<T> as Verifier>::Pubs,
+            bool,
+        )],
+        single: frame_support::weights::Weight,
+    ) -> frame_support::weights::Weight {
+        // Every commitment is charged as a further proof.
+        let count = |curve: Curve| -> u32 {
+            proofs
+                .iter()
+                .filter(|(proof, _, _)| proof.curve == curve)
+                .map(|(proof, _, _)| 1 + usize::from(proof.commitment.is_some()))
+                .sum::<usize>()
+                .try_into()
+                .expect("Batch size should fit in u32. qed")
+        };
+        // A curve with a registered vk in its proofs is charged as if every vk was registered.
+        let weight = |curve: Curve| {
+            if proofs
+                .iter()
+                .any(|(proof, _, with_vk_hash)| proof.curve == curve && *with_vk_hash)
+            {
+                Self::submit_proofs_with_vk_hash(curve)
+            } else {
+                Self::submit_proofs(curve)
+            }
+        };
+        // Every curve is verified in its own multi-pairing.
+        let batched = [
+            Curve::Bn254,
+            Curve::Bls12_381,
+            Curve::Bls12_377,
+            Curve::Bw6_761,
+        ]
+        .into_iter()
+        .map(|curve| (count(curve), weight(curve)))
+        .filter(|(count, _)| *count > 0)
+        .fold(
+            frame_support::weights::Weight::zero(),
+            |acc, (count, weight)| acc.saturating_add(weight(count)),
+        );
+        // Batched weights are computed in the worst case of `MAX_NUM_INPUTS` inputs for each
+        // proof: so, for batches with few inputs, the sum of the single weights could be lower.
+        batched.min(single)
     }
 
     fn register_vk(vk: &<Groth16<T> as Verifier>::Vk) -> frame_support::weights::Weight {
@@ -135,6 +294,8 @@ impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Groth16<T>> for Grot
         match vk.curve {
             Curve::Bn254 => W::register_vk_bn254(n),
             Curve::Bls12_381 => W::register_vk_bls12_381(n),
+            Curve::Bls12_377 => W::register_vk_bls12_377(n),
+            Curve::Bw6_761 => W::register_vk_bw6_761(n),
         }
     }
 
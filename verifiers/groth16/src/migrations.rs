@@ -0,0 +1,85 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations.
+
+use codec::{Decode, Encode};
+use pallet_verifiers::migrations::v3::VkTranslation;
+use sp_std::vec::Vec;
+
+use crate::{Config, Curve, Groth16, Vk, G1, G2};
+
+/// The vk stored in V2: it had no gnark commitment key.
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct VkV2 {
+    pub curve: Curve,
+    pub alpha_g1: G1,
+    pub beta_g2: G2,
+    pub gamma_g2: G2,
+    pub delta_g2: G2,
+    pub gamma_abc_g1: Vec<G1>,
+}
+
+/// Translate the V2 vks to vks without commitment key.
+pub struct AddVkCommitment;
+
+impl<T: Config> VkTranslation<Groth16<T>> for AddVkCommitment {
+    type OldVk = VkV2;
+
+    fn translate(old: VkV2) -> Vk {
+        Vk {
+            curve: old.curve,
+            alpha_g1: old.alpha_g1,
+            beta_g2: old.beta_g2,
+            gamma_g2: old.gamma_g2,
+            delta_g2: old.delta_g2,
+            gamma_abc_g1: old.gamma_abc_g1,
+            commitment: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    struct Mock;
+    impl Config for Mock {
+        const MAX_NUM_INPUTS: u32 = 16;
+        const ACCEPT_UNBENCHMARKED_CURVES: bool = false;
+    }
+
+    #[test]
+    fn append_an_empty_commitment_key() {
+        let (_, vk, _) = crate::groth16::Groth16::get_instance(3, Some(0), Curve::Bn254);
+        let old = VkV2 {
+            curve: vk.curve,
+            alpha_g1: vk.alpha_g1.clone(),
+            beta_g2: vk.beta_g2.clone(),
+            gamma_g2: vk.gamma_g2.clone(),
+            delta_g2: vk.delta_g2.clone(),
+            gamma_abc_g1: vk.gamma_abc_g1.clone(),
+        };
+        let mut stored = old.encode();
+
+        let translated = <AddVkCommitment as VkTranslation<Groth16<Mock>>>::translate(
+            VkV2::decode(&mut stored.as_slice()).unwrap(),
+        );
+
+        assert_eq!(translated, vk);
+        stored.push(0);
+        assert_eq!(translated.encode(), stored);
+    }
+}
@@ -22,11 +22,15 @@ use rstest_reuse::{apply, template};
 struct Mock;
 impl Config for Mock {
     const MAX_NUM_INPUTS: u32 = 16;
+    const ACCEPT_UNBENCHMARKED_CURVES: bool = true;
 }
 
 #[template]
 #[rstest]
-fn curves(#[values(Curve::Bn254, Curve::Bls12_381)] curve: Curve) {}
+fn curves(
+    #[values(Curve::Bn254, Curve::Bls12_381, Curve::Bls12_377, Curve::Bw6_761)] curve: Curve,
+) {
+}
 
 #[apply(curves)]
 fn validate_correct_proof(curve: Curve) {
@@ -48,6 +52,78 @@ fn validate_proof(curve: Curve, #[case] n: usize) {
     assert!(Groth16::<Mock>::verify_proof(&vk, &proof, &inputs).is_ok());
 }
 
+mod batch {
+    use hp_verifiers::VerifyError;
+
+    use super::*;
+
+    fn instances(seeds: &[(u64, Curve)]) -> Vec<(Vk, Proof, Pubs)> {
+        seeds
+            .iter()
+            .map(|&(seed, curve)| {
+                let (proof, vk, inputs) =
+                    groth16::Groth16::get_instance(seed as usize, Some(seed), curve);
+                (vk, proof, inputs)
+            })
+            .collect()
+    }
+
+    fn verify_batch(instances: &[(Vk, Proof, Pubs)]) -> Result<(), VerifyError> {
+        let batch = instances
+            .iter()
+            .map(|(vk, proof, pubs)| (vk, proof, pubs))
+            .collect::<Vec<_>>();
+        Groth16::<Mock>::verify_batch(&batch)
+    }
+
+    #[apply(curves)]
+    fn validate_correct_proofs(curve: Curve) {
+        assert!(verify_batch(&instances(&[(1, curve), (3, curve), (16, curve)])).is_ok());
+    }
+
+    #[test]
+    fn validate_correct_proofs_on_different_curves() {
+        assert!(verify_batch(&instances(&[
+            (1, Curve::Bn254),
+            (3, Curve::Bls12_381),
+            (2, Curve::Bn254),
+            (4, Curve::Bw6_761),
+            (5, Curve::Bls12_377),
+        ]))
+        .is_ok());
+    }
+
+    #[apply(curves)]
+    fn reject_if_a_proof_is_incorrect(curve: Curve) {
+        let mut instances = instances(&[(1, curve), (3, curve), (4, curve)]);
+        instances[2].1 = groth16::Groth16::get_instance(4, Some(42), curve).0;
+
+        assert_eq!(verify_batch(&instances), Err(VerifyError::VerifyError));
+    }
+
+    #[apply(curves)]
+    fn reject_too_many_inputs(curve: Curve) {
+        let mut instances = instances(&[(1, curve)]);
+        instances.push(instances_with_too_many_inputs(curve));
+
+        assert_eq!(verify_batch(&instances), Err(VerifyError::InvalidInput));
+    }
+
+    #[test]
+    fn reject_a_proof_on_a_different_curve_than_its_vk() {
+        let mut instances = instances(&[(1, Curve::Bn254), (3, Curve::Bls12_381)]);
+        instances[1].1.curve = Curve::Bn254;
+
+        assert_eq!(verify_batch(&instances), Err(VerifyError::InvalidProofData));
+    }
+
+    fn instances_with_too_many_inputs(curve: Curve) -> (Vk, Proof, Pubs) {
+        let (proof, vk, inputs) =
+            groth16::Groth16::get_instance(Mock::MAX_NUM_INPUTS as usize + 1, Some(0), curve);
+        (vk, proof, inputs)
+    }
+}
+
 mod reject {
     use hp_verifiers::VerifyError;
 
@@ -99,6 +175,40 @@ mod reject {
         );
     }
 
+    #[test]
+    fn proof_on_a_different_curve_than_its_vk() {
+        let (mut proof, vk, inputs) = groth16::Groth16::get_instance(1, Some(0), Curve::Bls12_381);
+        proof.curve = Curve::Bn254;
+
+        assert_eq!(
+            Groth16::<Mock>::verify_proof(&vk, &proof, &inputs),
+            Err(VerifyError::InvalidProofData)
+        );
+    }
+
+    #[rstest]
+    fn unbenchmarked_curves(#[values(Curve::Bls12_377, Curve::Bw6_761)] curve: Curve) {
+        struct Production;
+        impl Config for Production {
+            const MAX_NUM_INPUTS: u32 = 16;
+            const ACCEPT_UNBENCHMARKED_CURVES: bool = false;
+        }
+        let (proof, vk, inputs) = groth16::Groth16::get_instance(1, Some(0), curve);
+
+        assert_eq!(
+            Groth16::<Production>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+        assert_eq!(
+            Groth16::<Production>::verify_proof(&vk, &proof, &inputs),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+        assert_eq!(
+            Groth16::<Production>::verify_batch(&[(&vk, &proof, &inputs)]),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
     #[apply(curves)]
     fn incoherent_vk_and_num_inputs(curve: Curve) {
         let (proof, vk, _) = groth16::Groth16::get_instance(4, Some(0), curve);
@@ -110,3 +220,143 @@ mod reject {
         );
     }
 }
+
+mod commitment {
+    use hp_verifiers::VerifyError;
+
+    use super::*;
+
+    #[apply(curves)]
+    fn validate_correct_proof(curve: Curve) {
+        let (proof, vk, inputs) = groth16::Groth16::get_instance_with_commitment(10, None, curve);
+
+        assert!(Groth16::<Mock>::validate_vk(&vk).is_ok());
+        assert!(Groth16::<Mock>::verify_proof(&vk, &proof, &inputs).is_ok());
+    }
+
+    #[test]
+    fn validate_max_number_of_inputs() {
+        let (proof, vk, inputs) = groth16::Groth16::get_instance_with_commitment(
+            Mock::MAX_NUM_INPUTS as usize,
+            None,
+            Curve::Bn254,
+        );
+
+        assert!(Groth16::<Mock>::verify_proof(&vk, &proof, &inputs).is_ok());
+    }
+
+    #[test]
+    fn validate_batch_with_and_without_commitments() {
+        let (proof, vk, inputs) =
+            groth16::Groth16::get_instance_with_commitment(3, Some(1), Curve::Bn254);
+        let (other_proof, other_vk, other_inputs) =
+            groth16::Groth16::get_instance(2, Some(2), Curve::Bn254);
+
+        assert!(Groth16::<Mock>::verify_batch(&[
+            (&vk, &proof, &inputs),
+            (&other_vk, &other_proof, &other_inputs),
+        ])
+        .is_ok());
+    }
+
+    #[test]
+    fn reject_proof_without_commitment() {
+        let (mut proof, vk, inputs) =
+            groth16::Groth16::get_instance_with_commitment(3, Some(0), Curve::Bn254);
+        proof.commitment = None;
+
+        assert_eq!(
+            Groth16::<Mock>::verify_proof(&vk, &proof, &inputs),
+            Err(VerifyError::InvalidProofData)
+        );
+    }
+
+    #[test]
+    fn reject_unexpected_commitment() {
+        let (proof, _, _) =
+            groth16::Groth16::get_instance_with_commitment(3, Some(0), Curve::Bn254);
+        let (mut other_proof, vk, inputs) =
+            groth16::Groth16::get_instance(3, Some(0), Curve::Bn254);
+        other_proof.commitment = proof.commitment;
+
+        assert_eq!(
+            Groth16::<Mock>::verify_proof(&vk, &other_proof, &inputs),
+            Err(VerifyError::InvalidProofData)
+        );
+    }
+
+    #[test]
+    fn reject_wrong_committed_input() {
+        let (proof, vk, mut inputs) =
+            groth16::Groth16::get_instance_with_commitment(3, Some(0), Curve::Bn254);
+        inputs.swap(0, 1);
+
+        assert_eq!(
+            Groth16::<Mock>::verify_proof(&vk, &proof, &inputs),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn reject_vk_with_unsorted_committed_inputs() {
+        let (_, mut vk, _) =
+            groth16::Groth16::get_instance_with_commitment(3, Some(0), Curve::Bn254);
+        vk.commitment.as_mut().unwrap().public_committed = vec![3, 1];
+
+        assert_eq!(
+            Groth16::<Mock>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+}
+
+mod conformance {
+    use super::*;
+
+    type Groth16Mock = Groth16<Mock>;
+
+    fn instance(n: usize) -> (Vk, Proof, Pubs) {
+        let (proof, vk, inputs) = groth16::Groth16::get_instance(n, Some(0), Curve::Bn254);
+        (vk, proof, inputs)
+    }
+
+    pub struct Groth16Fixtures;
+
+    impl pallet_verifiers_test_kit::Fixtures<Groth16Mock> for Groth16Fixtures {
+        type WeightInfo = Groth16Weight<()>;
+
+        fn valid() -> (Vk, Proof, Pubs) {
+            instance(4)
+        }
+
+        fn invalid() -> Vec<(Vk, Proof, Pubs)> {
+            let (vk, proof, inputs) = instance(4);
+            let (other_proof, _, _) = groth16::Groth16::get_instance(4, Some(42), Curve::Bn254);
+            let mut malformed_proof = proof.clone();
+            malformed_proof.proof.a.0[0] += 1;
+            vec![
+                (vk.clone(), other_proof, inputs.clone()),
+                (vk, malformed_proof, inputs),
+            ]
+        }
+
+        fn invalid_vks() -> Vec<Vk> {
+            let (_, mut vk, _) =
+                groth16::Groth16::get_instance_with_commitment(3, Some(0), Curve::Bn254);
+            vk.commitment.as_mut().unwrap().public_committed = vec![3, 1];
+            vec![vk]
+        }
+
+        fn by_size() -> Vec<(Proof, Pubs)> {
+            [0, 1, 4, Mock::MAX_NUM_INPUTS as usize]
+                .into_iter()
+                .map(|n| {
+                    let (_, proof, pubs) = instance(n);
+                    (proof, pubs)
+                })
+                .collect()
+        }
+    }
+
+    pallet_verifiers_test_kit::verifier_conformance!(Groth16Mock, Groth16Fixtures);
+}
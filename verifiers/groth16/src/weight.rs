@@ -20,6 +20,14 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `lucag-laptop`, CPU: `Intel(R) Core(TM) i7-10510U CPU @ 1.80GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOT BENCHMARKED: the `bls12_377`, `bw6_761` and `submit_proofs_*` weights are hand-written
+//! estimates. The `bls12_377` and `bw6_761` ones are the `bls12_381` ones scaled by the ratio of
+//! the native verification times (x1.2 and x5): the runtime keeps these curves disabled till they
+//! are benchmarked. The `submit_proofs_*` ones are extrapolated from the `submit_proof_*` ones, and
+//! the `submit_proofs_*_with_vk_hash` ones add the `Vks` read of every proof to them.
+//! The `Vks` proof sizes account for the larger BW6-761 G1 points and for the gnark commitment
+//! key. Re-run the benchmarks to replace them.
 
 // Executed Command:
 // ./target/release/zkv-relay
@@ -55,10 +63,24 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
     fn submit_proof_bn254(n: u32, ) -> Weight;
     fn submit_proof_bls12_381(n: u32, ) -> Weight;
+    fn submit_proof_bls12_377(n: u32, ) -> Weight;
+    fn submit_proof_bw6_761(n: u32, ) -> Weight;
     fn submit_proof_bn254_with_vk_hash(n: u32, ) -> Weight;
     fn submit_proof_bls12_381_with_vk_hash(n: u32, ) -> Weight;
+    fn submit_proof_bls12_377_with_vk_hash(n: u32, ) -> Weight;
+    fn submit_proof_bw6_761_with_vk_hash(n: u32, ) -> Weight;
+    fn submit_proofs_bn254(b: u32, ) -> Weight;
+    fn submit_proofs_bls12_381(b: u32, ) -> Weight;
+    fn submit_proofs_bls12_377(b: u32, ) -> Weight;
+    fn submit_proofs_bw6_761(b: u32, ) -> Weight;
+    fn submit_proofs_bn254_with_vk_hash(b: u32, ) -> Weight;
+    fn submit_proofs_bls12_381_with_vk_hash(b: u32, ) -> Weight;
+    fn submit_proofs_bls12_377_with_vk_hash(b: u32, ) -> Weight;
+    fn submit_proofs_bw6_761_with_vk_hash(b: u32, ) -> Weight;
     fn register_vk_bn254(n: u32, ) -> Weight;
     fn register_vk_bls12_381(n: u32, ) -> Weight;
+    fn register_vk_bls12_377(n: u32, ) -> Weight;
+    fn register_vk_bw6_761(n: u32, ) -> Weight;
     fn unregister_vk() -> Weight;
 }
 
@@ -106,8 +128,42 @@ impl WeightInfo for () {
     }
     /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn submit_proof_bls12_377(_n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(11_985_603_600, 3537)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn submit_proof_bw6_761(_n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(49_940_015_000, 3537)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:0)
-    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(3956), added: 6431, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
     /// Storage: `Poe::NextAttestation` (r:1 w:0)
     /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     /// Storage: `Poe::Values` (r:1 w:1)
@@ -120,16 +176,16 @@ impl WeightInfo for () {
     fn submit_proof_bn254_with_vk_hash(_n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `833 + n * (66 ±0)`
-        //  Estimated: `7421`
+        //  Estimated: `11397`
         // Minimum execution time: 4_204_563_000 picoseconds.
-        Weight::from_parts(7_481_422_000, 7421)
+        Weight::from_parts(7_481_422_000, 11397)
             .saturating_add(RocksDbWeight::get().reads(5_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
     /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:0)
-    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(3956), added: 6431, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
     /// Storage: `Poe::NextAttestation` (r:1 w:0)
     /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     /// Storage: `Poe::Values` (r:1 w:1)
@@ -142,27 +198,241 @@ impl WeightInfo for () {
     fn submit_proof_bls12_381_with_vk_hash(_n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `1089 + n * (98 ±0)`
-        //  Estimated: `7421`
+        //  Estimated: `11397`
         // Minimum execution time: 4_317_263_000 picoseconds.
-        Weight::from_parts(8_517_582_000, 7421)
+        Weight::from_parts(8_517_582_000, 11397)
             .saturating_add(RocksDbWeight::get().reads(5_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
     /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn submit_proof_bls12_377_with_vk_hash(_n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(10_221_098_400, 11397)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn submit_proof_bw6_761_with_vk_hash(_n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(42_587_910_000, 11397)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:16 w:16)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 16]`.
+    fn submit_proofs_bn254(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(3_412_551_000, 1990)
+            .saturating_add(Weight::from_parts(6_815_298_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:16 w:16)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 16]`.
+    fn submit_proofs_bls12_381(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(3_571_230_000, 1990)
+            .saturating_add(Weight::from_parts(7_902_655_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:16 w:16)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 16]`.
+    fn submit_proofs_bls12_377(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(4_285_476_000, 1990)
+            .saturating_add(Weight::from_parts(9_483_186_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:16 w:16)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 16]`.
+    fn submit_proofs_bw6_761(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(17_856_150_000, 1990)
+            .saturating_add(Weight::from_parts(39_513_275_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:16 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:16 w:16)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 16]`.
+    fn submit_proofs_bn254_with_vk_hash(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(3_412_551_000, 1990)
+            .saturating_add(Weight::from_parts(6_815_298_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 12954).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:16 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:16 w:16)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 16]`.
+    fn submit_proofs_bls12_381_with_vk_hash(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(3_571_230_000, 1990)
+            .saturating_add(Weight::from_parts(7_902_655_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 12954).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:16 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:16 w:16)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 16]`.
+    fn submit_proofs_bls12_377_with_vk_hash(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(4_285_476_000, 1990)
+            .saturating_add(Weight::from_parts(9_483_186_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 12954).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:16 w:0)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:16 w:16)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 16]`.
+    fn submit_proofs_bw6_761_with_vk_hash(b: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(17_856_150_000, 1990)
+            .saturating_add(Weight::from_parts(39_513_275_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 12954).saturating_mul(b.into()))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Tickets` (r:1 w:1)
     /// Proof: `SettlementGroth16Pallet::Tickets` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(3956), added: 6431, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[0, 16]`.
     fn register_vk_bn254(_n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `42`
-        //  Estimated: `7421`
+        //  Estimated: `11397`
         // Minimum execution time: 1_173_541_000 picoseconds.
-        Weight::from_parts(2_802_456_000, 7421)
+        Weight::from_parts(2_802_456_000, 11397)
             .saturating_add(RocksDbWeight::get().reads(4_u64))
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
@@ -173,14 +443,44 @@ impl WeightInfo for () {
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(3956), added: 6431, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[0, 16]`.
     fn register_vk_bls12_381(_n: u32, ) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `42`
-        //  Estimated: `7421`
+        //  Estimated: `11397`
         // Minimum execution time: 509_098_000 picoseconds.
-        Weight::from_parts(1_584_973_000, 7421)
+        Weight::from_parts(1_584_973_000, 11397)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Tickets` (r:1 w:1)
+    /// Proof: `SettlementGroth16Pallet::Tickets` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn register_vk_bls12_377(_n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(1_901_967_600, 11397)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `SettlementGroth16Pallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementGroth16Pallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Tickets` (r:1 w:1)
+    /// Proof: `SettlementGroth16Pallet::Tickets` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    fn register_vk_bw6_761(_n: u32, ) -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(7_924_865_000, 11397)
             .saturating_add(RocksDbWeight::get().reads(4_u64))
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
@@ -189,13 +489,13 @@ impl WeightInfo for () {
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
     /// Storage: `SettlementGroth16Pallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(3956), added: 6431, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementGroth16Pallet::Vks` (`max_values`: None, `max_size`: Some(7932), added: 10407, mode: `MaxEncodedLen`)
     fn unregister_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `795`
-        //  Estimated: `7421`
+        //  Estimated: `11397`
         // Minimum execution time: 70_240_000 picoseconds.
-        Weight::from_parts(70_240_000, 7421)
+        Weight::from_parts(70_240_000, 11397)
             .saturating_add(RocksDbWeight::get().reads(3_u64))
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
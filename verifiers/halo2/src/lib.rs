@@ -0,0 +1,277 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A verifier for halo2 proofs on bn254 with a KZG commitment scheme (PSE fork and its
+//! derivatives). The verification key is the circuit description compiled by `snark-verifier`
+//! (the bincode serialized `PlonkProtocol`) together with the `[s]_2` point of the KZG setup
+//! and the degree of its quotient numerator, that is used to charge the verification.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use educe::Educe;
+use frame_support::{ensure, weights::Weight};
+use hp_verifiers::{Cow, Verifier, VerifyError};
+use scale_info::TypeInfo;
+use sp_core::Get;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+mod verifier_should;
+mod weight;
+pub use weight::WeightInfo;
+
+pub use native::{HALO2_FR_SIZE as FR_SIZE, HALO2_G2_SIZE as G2_SIZE};
+
+/// Largest supported size in bytes of the serialized protocol.
+pub const MAX_PROTOCOL_SIZE: u32 = 128 * 1024;
+/// Largest supported size in bytes of a proof.
+pub const MAX_PROOF_SIZE: u32 = 64 * 1024;
+/// Largest supported number of instance values.
+pub const MAX_INSTANCES: u32 = 256;
+/// Largest supported degree of the quotient numerator: the degree of the circuit's gates.
+pub const MAX_QUOTIENT_DEGREE: u32 = 16;
+
+pub trait Config: 'static {
+    /// Maximum size in bytes of the serialized protocol in a verification key.
+    type MaxProtocolSize: Get<u32>;
+    /// Maximum size in bytes of a proof.
+    type MaxProofSize: Get<u32>;
+    /// Maximum number of instance values, summed over all the instance columns.
+    type MaxInstances: Get<u32>;
+}
+
+/// An instance value: a big endian scalar field element.
+pub type Fr = [u8; FR_SIZE];
+/// The instance columns.
+pub type Pubs = Vec<Vec<Fr>>;
+
+/// The KZG multi-open scheme used by the prover.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum Scheme {
+    /// `GWC19`.
+    Gwc,
+    /// `BDFG21`, aka SHPLONK.
+    Shplonk,
+}
+
+impl From<Scheme> for native::Halo2Scheme {
+    fn from(value: Scheme) -> Self {
+        match value {
+            Scheme::Gwc => native::Halo2Scheme::Gwc,
+            Scheme::Shplonk => native::Halo2Scheme::Shplonk,
+        }
+    }
+}
+
+/// The hash function used by the prover's Fiat-Shamir transcript.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum Transcript {
+    /// The halo2 default `Blake2bWrite` with `Challenge255`.
+    Blake2b,
+    /// The keccak256 EVM transcript of `snark-verifier`.
+    Keccak,
+}
+
+impl From<Transcript> for native::Halo2Transcript {
+    fn from(value: Transcript) -> Self {
+        match value {
+            Transcript::Blake2b => native::Halo2Transcript::Blake2b,
+            Transcript::Keccak => native::Halo2Transcript::Keccak,
+        }
+    }
+}
+
+/// A halo2 proof with the information about how it was generated.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub struct Proof {
+    /// The multi-open scheme.
+    pub scheme: Scheme,
+    /// The transcript.
+    pub transcript: Transcript,
+    /// The proof bytes, as written in the transcript.
+    pub data: Vec<u8>,
+}
+
+// Here educe is used for Clone, Debug, and PartialEq to work around
+// a long-standing compiler bug https://github.com/rust-lang/rust/issues/26925
+#[derive(Educe, Encode, Decode, TypeInfo)]
+#[educe(Clone, Debug, PartialEq)]
+#[scale_info(skip_type_params(T))]
+pub struct Vk<T> {
+    /// The compressed `[s]_2` point of the KZG setup.
+    pub s_g2: [u8; G2_SIZE],
+    /// The bincode serialized `snark-verifier` `PlonkProtocol`.
+    pub protocol: Vec<u8>,
+    /// The degree of the protocol's quotient numerator.
+    pub quotient_degree: u32,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Vk<T> {
+    pub fn new(s_g2: [u8; G2_SIZE], protocol: Vec<u8>, quotient_degree: u32) -> Self {
+        Self {
+            s_g2,
+            protocol,
+            quotient_degree,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Config> Vk<T> {
+    fn validate_size(&self) -> Result<(), VerifyError> {
+        ensure!(
+            self.protocol.len() <= T::MaxProtocolSize::get() as usize
+                && self.quotient_degree <= MAX_QUOTIENT_DEGREE,
+            VerifyError::InvalidVerificationKey
+        );
+        Ok(())
+    }
+}
+
+impl<T: Config> MaxEncodedLen for Vk<T> {
+    fn max_encoded_len() -> usize {
+        let len = T::MaxProtocolSize::get();
+        G2_SIZE + codec::Compact(len).encoded_size() + len as usize + u32::max_encoded_len()
+    }
+}
+
+/// The total number of instance values.
+fn num_instances(pubs: &Pubs) -> usize {
+    pubs.iter().map(Vec::len).sum()
+}
+
+#[pallet_verifiers::verifier]
+pub struct Halo2<T>;
+
+impl<T: Config> Verifier for Halo2<T> {
+    type Proof = Proof;
+
+    type Pubs = Pubs;
+
+    type Vk = Vk<T>;
+
+    fn hash_context_data() -> &'static [u8] {
+        b"halo2"
+    }
+
+    fn verify_proof(
+        vk: &Self::Vk,
+        proof: &Self::Proof,
+        pubs: &Self::Pubs,
+    ) -> Result<(), VerifyError> {
+        vk.validate_size()?;
+        ensure!(
+            proof.data.len() <= T::MaxProofSize::get() as usize,
+            VerifyError::InvalidProofData
+        );
+        ensure!(
+            pubs.len() <= T::MaxInstances::get() as usize
+                && num_instances(pubs) <= T::MaxInstances::get() as usize,
+            VerifyError::InvalidInput
+        );
+        log::trace!("Verifying (native)");
+        native::halo_2_verify::verify(
+            proof.scheme.into(),
+            proof.transcript.into(),
+            &vk.s_g2,
+            &vk.protocol,
+            vk.quotient_degree,
+            &proof.data,
+            pubs,
+        )
+        .map_err(Into::into)
+    }
+
+    fn validate_vk(vk: &Self::Vk) -> Result<(), VerifyError> {
+        vk.validate_size()?;
+        native::halo_2_verify::validate_vk(&vk.s_g2, &vk.protocol, vk.quotient_degree)
+            .map_err(Into::into)
+    }
+
+    fn pubs_bytes(pubs: &Self::Pubs) -> Cow<[u8]> {
+        // The columns' lengths are fixed by the verification key which is part of the statement:
+        // we can just concatenate them.
+        let data = pubs.iter().flatten().flatten().copied().collect::<Vec<_>>();
+        Cow::Owned(data)
+    }
+}
+
+/// The struct to use in runtime pallet configuration to map the weight computed by this crate
+/// benchmarks to the weight needed by the `pallet-verifiers`. The verification cost depends on
+/// the verification key too: when it's not known, because just its hash is given, the largest
+/// protocol size and quotient degree are charged.
+pub struct Halo2Weight<W: weight::WeightInfo>(PhantomData<W>);
+
+fn saturated(len: usize) -> u32 {
+    len.try_into().unwrap_or(u32::MAX)
+}
+
+impl<W: weight::WeightInfo> Halo2Weight<W> {
+    fn worst_case_vk<T: Config>() -> (u32, u32) {
+        (T::MaxProtocolSize::get(), MAX_QUOTIENT_DEGREE)
+    }
+}
+
+impl<T: Config, W: weight::WeightInfo> pallet_verifiers::WeightInfo<Halo2<T>> for Halo2Weight<W> {
+    fn submit_proof(
+        proof: &<Halo2<T> as Verifier>::Proof,
+        pubs: &<Halo2<T> as Verifier>::Pubs,
+    ) -> Weight {
+        let (l, d) = Self::worst_case_vk::<T>();
+        W::submit_proof(
+            saturated(proof.data.len()),
+            saturated(num_instances(pubs)),
+            l,
+            d,
+        )
+    }
+
+    fn submit_proof_with_vk_hash(
+        proof: &<Halo2<T> as Verifier>::Proof,
+        pubs: &<Halo2<T> as Verifier>::Pubs,
+    ) -> Weight {
+        let (l, d) = Self::worst_case_vk::<T>();
+        W::submit_proof_with_vk_hash(
+            saturated(proof.data.len()),
+            saturated(num_instances(pubs)),
+            l,
+            d,
+        )
+    }
+
+    fn submit_proof_with_vk(
+        vk: &<Halo2<T> as Verifier>::Vk,
+        proof: &<Halo2<T> as Verifier>::Proof,
+        pubs: &<Halo2<T> as Verifier>::Pubs,
+    ) -> Weight {
+        // Oversized keys are rejected before the verification: charge them as the largest ones.
+        let (max_l, max_d) = Self::worst_case_vk::<T>();
+        W::submit_proof(
+            saturated(proof.data.len()),
+            saturated(num_instances(pubs)),
+            saturated(vk.protocol.len()).min(max_l),
+            vk.quotient_degree.min(max_d),
+        )
+    }
+
+    fn register_vk(vk: &<Halo2<T> as Verifier>::Vk) -> Weight {
+        W::register_vk(saturated(vk.protocol.len()))
+    }
+
+    fn unregister_vk() -> Weight {
+        W::unregister_vk()
+    }
+}
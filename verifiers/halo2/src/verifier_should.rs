@@ -0,0 +1,198 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use super::*;
+use sp_core::ConstU32;
+
+struct Mock;
+
+impl Config for Mock {
+    type MaxProtocolSize = ConstU32<1024>;
+    type MaxProofSize = ConstU32<2048>;
+    type MaxInstances = ConstU32<8>;
+}
+
+fn proof(len: usize) -> Proof {
+    Proof {
+        scheme: Scheme::Shplonk,
+        transcript: Transcript::Keccak,
+        data: vec![0; len],
+    }
+}
+
+/// The compressed bn254 G2 generator: a valid `[s]_2` point.
+fn g2_generator() -> [u8; G2_SIZE] {
+    let mut point = [0; G2_SIZE];
+    point[..32].copy_from_slice(&hex_literal::hex!(
+        "edf692d95cbdde46ddda5ef7d422436779445c5e66006a42761e1f12efde0018"
+    ));
+    point[32..].copy_from_slice(&hex_literal::hex!(
+        "c212f3aeb785e49712e7a9353349aaf1255dfb31b7bf60723a480d9293938e19"
+    ));
+    point
+}
+
+fn vk(protocol: Vec<u8>) -> Vk<Mock> {
+    Vk::new(g2_generator(), protocol, 4)
+}
+
+#[test]
+fn return_the_concatenated_instances_as_bytes() {
+    let pubs: Pubs = vec![vec![[1; FR_SIZE], [2; FR_SIZE]], vec![[3; FR_SIZE]]];
+
+    assert_eq!(
+        Halo2::<Mock>::pubs_bytes(&pubs).as_ref(),
+        [[1; FR_SIZE], [2; FR_SIZE], [3; FR_SIZE]].concat()
+    );
+}
+
+#[test]
+fn compute_weights_that_grow_with_proof_size_and_instances() {
+    type W = Halo2Weight<()>;
+    let small = vec![vec![[0; FR_SIZE]; 1]];
+    let big = vec![vec![[0; FR_SIZE]; 4]];
+
+    let base = <W as pallet_verifiers::WeightInfo<Halo2<Mock>>>::submit_proof(&proof(100), &small);
+    let longer =
+        <W as pallet_verifiers::WeightInfo<Halo2<Mock>>>::submit_proof(&proof(200), &small);
+    let wider = <W as pallet_verifiers::WeightInfo<Halo2<Mock>>>::submit_proof(&proof(100), &big);
+
+    assert!(longer.ref_time() > base.ref_time());
+    assert!(wider.ref_time() > base.ref_time());
+}
+
+#[test]
+fn compute_weights_that_grow_with_the_explicit_vk() {
+    use pallet_verifiers::WeightInfo as _;
+    type W = Halo2Weight<()>;
+    let pubs = vec![vec![[0; FR_SIZE]; 1]];
+    let submit = |vk: &Vk<Mock>| W::submit_proof_with_vk(vk, &proof(100), &pubs);
+
+    let base = submit(&vk(vec![0; 100]));
+    let bigger_protocol = submit(&vk(vec![0; 200]));
+    let higher_degree = submit(&Vk::new(g2_generator(), vec![0; 100], 8));
+
+    assert!(bigger_protocol.ref_time() > base.ref_time());
+    assert!(higher_degree.ref_time() > base.ref_time());
+}
+
+#[test]
+fn charge_the_largest_vk_when_it_is_not_known() {
+    use pallet_verifiers::WeightInfo as _;
+    type W = Halo2Weight<()>;
+    let pubs = vec![vec![[0; FR_SIZE]; 1]];
+    let largest = Vk::<Mock>::new(
+        g2_generator(),
+        vec![0; <Mock as Config>::MaxProtocolSize::get() as usize],
+        MAX_QUOTIENT_DEGREE,
+    );
+
+    assert_eq!(
+        W::submit_proof(&proof(100), &pubs),
+        W::submit_proof_with_vk(&largest, &proof(100), &pubs)
+    );
+    assert!(W::submit_proof_with_vk_hash(&proof(100), &pubs)
+        .all_gte(W::submit_proof(&proof(100), &pubs)));
+}
+
+mod reject {
+    use super::*;
+
+    #[test]
+    fn too_big_proof() {
+        let vk = vk(vec![]);
+        let proof = proof(<Mock as Config>::MaxProofSize::get() as usize + 1);
+
+        assert_eq!(
+            Halo2::<Mock>::verify_proof(&vk, &proof, &vec![]),
+            Err(VerifyError::InvalidProofData)
+        );
+    }
+
+    #[test]
+    fn too_many_instances() {
+        let vk = vk(vec![]);
+        let max = <Mock as Config>::MaxInstances::get() as usize;
+        let pubs = vec![vec![[0; FR_SIZE]; max / 2], vec![[0; FR_SIZE]; max / 2 + 1]];
+
+        assert_eq!(
+            Halo2::<Mock>::verify_proof(&vk, &proof(32), &pubs),
+            Err(VerifyError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn too_many_instance_columns() {
+        let vk = vk(vec![]);
+        let max = <Mock as Config>::MaxInstances::get() as usize;
+        let pubs = vec![vec![]; max + 1];
+
+        assert_eq!(
+            Halo2::<Mock>::verify_proof(&vk, &proof(32), &pubs),
+            Err(VerifyError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn too_big_protocol() {
+        let protocol = vec![0; <Mock as Config>::MaxProtocolSize::get() as usize + 1];
+        let vk = vk(protocol);
+
+        assert_eq!(
+            Halo2::<Mock>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+        assert_eq!(
+            Halo2::<Mock>::verify_proof(&vk, &proof(32), &vec![]),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn too_high_quotient_degree() {
+        let vk = Vk::<Mock>::new(g2_generator(), vec![], MAX_QUOTIENT_DEGREE + 1);
+
+        assert_eq!(
+            Halo2::<Mock>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+        assert_eq!(
+            Halo2::<Mock>::verify_proof(&vk, &proof(32), &vec![]),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn malformed_protocol() {
+        let vk = vk(vec![0xff; 64]);
+
+        assert_eq!(
+            Halo2::<Mock>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn invalid_s_g2() {
+        let vk = Vk::<Mock>::new([0xff; G2_SIZE], vec![], 4);
+
+        assert_eq!(
+            Halo2::<Mock>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+}
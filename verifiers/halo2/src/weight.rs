@@ -0,0 +1,95 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Weights for `pallet_halo2_verifier`
+//!
+//! NOT AUTO-GENERATED: these are conservative estimates derived from the plonk verifier
+//! weights scaled by the number of pairings and MSM terms of a typical halo2 KZG proof.
+//! They have to be replaced by benchmarked values once the benchmarking fixtures are available.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_halo2_verifier`.
+pub trait WeightInfo {
+    fn submit_proof(p: u32, n: u32, l: u32, d: u32, ) -> Weight;
+    fn submit_proof_with_vk_hash(p: u32, n: u32, l: u32, d: u32, ) -> Weight;
+    fn register_vk(l: u32, ) -> Weight;
+    fn unregister_vk() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `SettlementHalo2Pallet::Disabled` (r:1 w:0)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// The range of component `p` is `[0, 65536]`.
+    /// The range of component `n` is `[0, 256]`.
+    /// The range of component `l` is `[0, 131072]`.
+    /// The range of component `d` is `[2, 16]`.
+    fn submit_proof(p: u32, n: u32, l: u32, d: u32, ) -> Weight {
+        Weight::from_parts(12_000_000_000, 3537)
+            .saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(p.into()))
+            .saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(5_000, 0).saturating_mul(l.into()))
+            .saturating_add(Weight::from_parts(400_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementHalo2Pallet::Disabled` (r:1 w:0)
+    /// Storage: `SettlementHalo2Pallet::Vks` (r:1 w:0)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// The range of component `p` is `[0, 65536]`.
+    /// The range of component `n` is `[0, 256]`.
+    /// The range of component `l` is `[0, 131072]`.
+    /// The range of component `d` is `[2, 16]`.
+    fn submit_proof_with_vk_hash(p: u32, n: u32, l: u32, d: u32, ) -> Weight {
+        Weight::from_parts(12_000_000_000, 134_609)
+            .saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(p.into()))
+            .saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(5_000, 0).saturating_mul(l.into()))
+            .saturating_add(Weight::from_parts(400_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementHalo2Pallet::Disabled` (r:1 w:0)
+    /// Storage: `SettlementHalo2Pallet::Tickets` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `SettlementHalo2Pallet::Vks` (r:1 w:1)
+    /// The range of component `l` is `[0, 131072]`.
+    fn register_vk(l: u32, ) -> Weight {
+        Weight::from_parts(100_000_000, 134_609)
+            .saturating_add(Weight::from_parts(20_000, 0).saturating_mul(l.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `SettlementHalo2Pallet::Tickets` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `SettlementHalo2Pallet::Vks` (r:1 w:1)
+    fn unregister_vk() -> Weight {
+        Weight::from_parts(80_000_000, 134_609)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+}
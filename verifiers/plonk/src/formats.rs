@@ -0,0 +1,271 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Import the snarkjs `verification_key.json`, `proof.json` and `public.json` files into the
+//! verifier types. The proof words are laid out in the same order used by the snarkjs solidity
+//! verifier calldata: the `A`, `B`, `C`, `Z`, `T1`, `T2`, `T3`, `Wxi` and `Wxiw` affine points
+//! and then the evaluations.
+
+use serde::Deserialize;
+use sp_core::U256;
+
+use crate::{Fr, Proof, Pubs, Vk, FR_SIZE, PROOF_SIZE};
+
+/// Why a snarkjs file cannot be imported.
+#[derive(Debug, snafu::Snafu)]
+pub enum FormatError {
+    /// The file is not a valid snarkjs JSON.
+    #[snafu(display("Invalid JSON: {source}"))]
+    Json { source: serde_json::Error },
+    /// The protocol is not `plonk`.
+    #[snafu(display("Unsupported protocol '{protocol}'"))]
+    UnsupportedProtocol { protocol: String },
+    /// The curve is not `bn128`.
+    #[snafu(display("Unsupported curve '{curve}'"))]
+    UnsupportedCurve { curve: String },
+    /// A value is not a 256 bits decimal number.
+    #[snafu(display("Invalid number '{value}'"))]
+    InvalidNumber { value: String },
+    /// A point is neither in affine coordinates nor the point at infinity.
+    #[snafu(display("Points should have z = 1 or be the point at infinity"))]
+    NotAffinePoint,
+}
+
+type Result<T> = core::result::Result<T, FormatError>;
+
+#[derive(Debug, Deserialize)]
+struct Header {
+    protocol: String,
+    curve: String,
+}
+
+/// The snarkjs `verification_key.json` content.
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+struct SnarkjsVk {
+    #[serde(flatten)]
+    header: Header,
+    nPublic: u32,
+    power: u8,
+    k1: String,
+    k2: String,
+    Qm: [String; 3],
+    Ql: [String; 3],
+    Qr: [String; 3],
+    Qo: [String; 3],
+    Qc: [String; 3],
+    S1: [String; 3],
+    S2: [String; 3],
+    S3: [String; 3],
+    X_2: [[String; 2]; 3],
+    w: String,
+}
+
+/// The snarkjs `proof.json` content.
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+struct SnarkjsProof {
+    #[serde(flatten)]
+    header: Header,
+    A: [String; 3],
+    B: [String; 3],
+    C: [String; 3],
+    Z: [String; 3],
+    T1: [String; 3],
+    T2: [String; 3],
+    T3: [String; 3],
+    Wxi: [String; 3],
+    Wxiw: [String; 3],
+    eval_a: String,
+    eval_b: String,
+    eval_c: String,
+    eval_s1: String,
+    eval_s2: String,
+    eval_zw: String,
+}
+
+fn json<'a, T: Deserialize<'a>>(json: &'a str) -> Result<T> {
+    serde_json::from_str(json).map_err(|source| FormatError::Json { source })
+}
+
+fn check_header(header: &Header) -> Result<()> {
+    if header.protocol != "plonk" {
+        return Err(FormatError::UnsupportedProtocol {
+            protocol: header.protocol.clone(),
+        });
+    }
+    if header.curve != "bn128" {
+        return Err(FormatError::UnsupportedCurve {
+            curve: header.curve.clone(),
+        });
+    }
+    Ok(())
+}
+
+fn word(value: &str) -> Result<Fr> {
+    let mut out = [0; FR_SIZE];
+    U256::from_dec_str(value)
+        .map_err(|_| FormatError::InvalidNumber {
+            value: value.to_owned(),
+        })?
+        .to_big_endian(&mut out);
+    Ok(out)
+}
+
+/// Encode the given coordinates in `out`: snarkjs represents the point at infinity with `z = 0`
+/// while the verifier encodes it as all zeros.
+fn point<'a>(coordinates: impl IntoIterator<Item = &'a String>, out: &mut [u8]) -> Result<()> {
+    for (chunk, value) in out.chunks_exact_mut(FR_SIZE).zip(coordinates) {
+        chunk.copy_from_slice(&word(value)?);
+    }
+    Ok(())
+}
+
+fn g1([x, y, z]: &[String; 3], out: &mut [u8]) -> Result<()> {
+    match z.as_str() {
+        "1" => point([x, y], out),
+        "0" => {
+            out.fill(0);
+            Ok(())
+        }
+        _ => Err(FormatError::NotAffinePoint),
+    }
+}
+
+fn g2([x, y, z]: &[[String; 2]; 3], out: &mut [u8]) -> Result<()> {
+    match [z[0].as_str(), z[1].as_str()] {
+        ["1", "0"] => point(x.iter().chain(y), out),
+        ["0", "0"] => {
+            out.fill(0);
+            Ok(())
+        }
+        _ => Err(FormatError::NotAffinePoint),
+    }
+}
+
+/// Import a snarkjs `verification_key.json`.
+pub fn vk_from_snarkjs(vk: &str) -> Result<Vk> {
+    let v: SnarkjsVk = json(vk)?;
+    check_header(&v.header)?;
+
+    let mut out = Vk {
+        power: v.power,
+        n_public: v.nPublic,
+        k1: word(&v.k1)?,
+        k2: word(&v.k2)?,
+        w: word(&v.w)?,
+        qm: [0; hp_plonk::G1_SIZE],
+        ql: [0; hp_plonk::G1_SIZE],
+        qr: [0; hp_plonk::G1_SIZE],
+        qo: [0; hp_plonk::G1_SIZE],
+        qc: [0; hp_plonk::G1_SIZE],
+        s1: [0; hp_plonk::G1_SIZE],
+        s2: [0; hp_plonk::G1_SIZE],
+        s3: [0; hp_plonk::G1_SIZE],
+        x2: [0; hp_plonk::G2_SIZE],
+    };
+    g1(&v.Qm, &mut out.qm)?;
+    g1(&v.Ql, &mut out.ql)?;
+    g1(&v.Qr, &mut out.qr)?;
+    g1(&v.Qo, &mut out.qo)?;
+    g1(&v.Qc, &mut out.qc)?;
+    g1(&v.S1, &mut out.s1)?;
+    g1(&v.S2, &mut out.s2)?;
+    g1(&v.S3, &mut out.s3)?;
+    g2(&v.X_2, &mut out.x2)?;
+    Ok(out)
+}
+
+/// Import a snarkjs `proof.json`.
+pub fn proof_from_snarkjs(proof: &str) -> Result<Proof> {
+    let p: SnarkjsProof = json(proof)?;
+    check_header(&p.header)?;
+
+    let points = [&p.A, &p.B, &p.C, &p.Z, &p.T1, &p.T2, &p.T3, &p.Wxi, &p.Wxiw];
+    let evaluations = [
+        &p.eval_a, &p.eval_b, &p.eval_c, &p.eval_s1, &p.eval_s2, &p.eval_zw,
+    ];
+    let mut out = [0; PROOF_SIZE];
+    let (commitments, evals) = out.split_at_mut(points.len() * hp_plonk::G1_SIZE);
+    for (chunk, value) in commitments.chunks_exact_mut(hp_plonk::G1_SIZE).zip(points) {
+        g1(value, chunk)?;
+    }
+    point(evaluations, evals)?;
+    Ok(out)
+}
+
+/// Import a snarkjs `public.json`.
+pub fn pubs_from_snarkjs(pubs: &str) -> Result<Pubs> {
+    let pubs: Vec<String> = json(pubs)?;
+    pubs.iter().map(|value| word(value)).collect()
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::Plonk;
+    use hp_verifiers::Verifier;
+
+    include!("resources.rs");
+
+    static VK: &str = include_str!("resources/verification_key.json");
+    static PROOF: &str = include_str!("resources/proof.json");
+    static PUBS: &str = include_str!("resources/public.json");
+
+    struct Mock;
+
+    impl crate::Config for Mock {
+        const MAX_NUM_INPUTS: u32 = crate::MAX_NUM_INPUTS;
+    }
+
+    #[test]
+    fn import_a_valid_proof() {
+        let vk = vk_from_snarkjs(VK).unwrap();
+        let proof = proof_from_snarkjs(PROOF).unwrap();
+        let pubs = pubs_from_snarkjs(PUBS).unwrap();
+
+        assert_eq!((vk.clone(), proof, pubs.clone()), instance(2));
+        assert!(Plonk::<Mock>::verify_proof(&vk, &proof, &pubs).is_ok());
+    }
+
+    #[test]
+    fn import_the_points_at_infinity() {
+        let vk = vk_from_snarkjs(VK).unwrap();
+
+        assert_eq!(vk.qr, [0; hp_plonk::G1_SIZE]);
+    }
+
+    #[test]
+    fn reject_other_protocols() {
+        let mut proof: serde_json::Value = serde_json::from_str(PROOF).unwrap();
+        proof["protocol"] = "fflonk".into();
+
+        assert!(matches!(
+            proof_from_snarkjs(&proof.to_string()),
+            Err(FormatError::UnsupportedProtocol { .. })
+        ));
+    }
+
+    #[test]
+    fn reject_projective_points() {
+        let mut proof: serde_json::Value = serde_json::from_str(PROOF).unwrap();
+        proof["Wxi"][2] = "2".into();
+
+        assert!(matches!(
+            proof_from_snarkjs(&proof.to_string()),
+            Err(FormatError::NotAffinePoint)
+        ));
+    }
+}
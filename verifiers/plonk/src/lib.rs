@@ -0,0 +1,122 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod benchmarking;
+#[cfg(feature = "std")]
+pub mod formats;
+mod verifier_should;
+mod weight;
+
+use core::marker::PhantomData;
+use frame_support::{ensure, weights::Weight};
+use hp_verifiers::{Verifier, VerifyError};
+use sp_std::vec::Vec;
+
+pub use hp_plonk::{Fr, Proof, VerificationKey as Vk, FR_SIZE, PROOF_SIZE};
+pub type Pubs = Vec<Fr>;
+
+pub const MAX_NUM_INPUTS: u32 = 32;
+pub use weight::WeightInfo;
+
+pub trait Config: 'static {
+    /// Maximum supported number of public inputs.
+    const MAX_NUM_INPUTS: u32;
+}
+
+#[pallet_verifiers::verifier]
+pub struct Plonk<T>;
+
+impl<T: Config> Verifier for Plonk<T> {
+    type Proof = Proof;
+
+    type Pubs = Pubs;
+
+    type Vk = Vk;
+
+    fn hash_context_data() -> &'static [u8] {
+        b"plonk"
+    }
+
+    fn verify_proof(
+        vk: &Self::Vk,
+        proof: &Self::Proof,
+        pubs: &Self::Pubs,
+    ) -> Result<(), VerifyError> {
+        ensure!(
+            pubs.len() <= T::MAX_NUM_INPUTS as usize,
+            VerifyError::InvalidInput
+        );
+        ensure!(
+            pubs.len() == vk.n_public as usize,
+            VerifyError::InvalidInput
+        );
+
+        native::plonk_verify::verify(vk.clone(), proof, pubs).map_err(Into::into)
+    }
+
+    fn validate_vk(vk: &Self::Vk) -> Result<(), VerifyError> {
+        ensure!(
+            vk.n_public <= T::MAX_NUM_INPUTS,
+            VerifyError::InvalidVerificationKey
+        );
+
+        native::plonk_verify::validate_key(vk.clone()).map_err(Into::into)
+    }
+
+    fn pubs_bytes(pubs: &Self::Pubs) -> hp_verifiers::Cow<[u8]> {
+        let data = pubs
+            .iter()
+            .flat_map(|s| s.iter().cloned())
+            .collect::<Vec<_>>();
+        hp_verifiers::Cow::Owned(data)
+    }
+}
+
+/// The struct to use in runtime pallet configuration to map the weight computed by this crate
+/// benchmarks to the weight needed by the `pallet-verifiers`.
+pub struct PlonkWeight<W: WeightInfo>(PhantomData<W>);
+
+/// The verification time grows linearly with the number of public inputs.
+fn num_inputs(pubs: &Pubs) -> u32 {
+    pubs.len().try_into().unwrap_or(u32::MAX)
+}
+
+impl<T: Config, W: WeightInfo> pallet_verifiers::WeightInfo<Plonk<T>> for PlonkWeight<W> {
+    fn submit_proof(
+        _proof: &<Plonk<T> as Verifier>::Proof,
+        pubs: &<Plonk<T> as Verifier>::Pubs,
+    ) -> Weight {
+        W::submit_proof(num_inputs(pubs))
+    }
+
+    fn submit_proof_with_vk_hash(
+        _proof: &<Plonk<T> as Verifier>::Proof,
+        pubs: &<Plonk<T> as Verifier>::Pubs,
+    ) -> Weight {
+        W::submit_proof_with_vk_hash(num_inputs(pubs))
+    }
+
+    fn register_vk(_vk: &<Plonk<T> as Verifier>::Vk) -> Weight {
+        // The verification key has a fixed size: its validation doesn't depend on the
+        // number of public inputs.
+        W::register_vk()
+    }
+
+    fn unregister_vk() -> Weight {
+        W::unregister_vk()
+    }
+}
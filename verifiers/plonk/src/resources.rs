@@ -0,0 +1,27 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A valid instance of the test circuit with `n` public inputs, for `n` in `1..=32`: the circuit
+/// just checks that each public input is the square of a private witness.
+#[allow(dead_code)]
+fn instance(n: u32) -> (crate::Vk, crate::Proof, crate::Pubs) {
+    use codec::Decode;
+
+    let mut instances = <sp_std::vec::Vec<(crate::Vk, crate::Proof, crate::Pubs)>>::decode(
+        &mut &include_bytes!("resources/instances.bin")[..],
+    )
+    .expect("Valid instances. qed");
+    instances.swap_remove(n as usize - 1)
+}
@@ -0,0 +1,179 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use super::*;
+include!("resources.rs");
+
+struct Mock;
+
+impl Config for Mock {
+    const MAX_NUM_INPUTS: u32 = 8;
+}
+
+#[test]
+fn verify_valid_proof() {
+    let (vk, proof, pubs) = instance(2);
+
+    assert!(Plonk::<Mock>::verify_proof(&vk, &proof, &pubs).is_ok());
+}
+
+#[test]
+fn verify_proofs_with_any_supported_number_of_public_inputs() {
+    for n in 1..=Mock::MAX_NUM_INPUTS {
+        let (vk, proof, pubs) = instance(n);
+
+        assert!(Plonk::<Mock>::verify_proof(&vk, &proof, &pubs).is_ok());
+    }
+}
+
+#[test]
+fn validate_valid_vk() {
+    let (vk, _, _) = instance(2);
+
+    assert!(Plonk::<Mock>::validate_vk(&vk).is_ok());
+}
+
+#[test]
+fn return_the_concatenated_public_inputs_as_bytes() {
+    let (_, _, pubs) = instance(2);
+
+    assert_eq!(Plonk::<Mock>::pubs_bytes(&pubs).as_ref(), pubs.concat());
+}
+
+mod reject {
+    use super::*;
+
+    #[test]
+    fn invalid_pubs() {
+        let (vk, proof, mut pubs) = instance(2);
+        pubs[0][FR_SIZE - 1] ^= 1;
+
+        assert_eq!(
+            Plonk::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn invalid_proof() {
+        let (vk, mut proof, pubs) = instance(2);
+        proof[PROOF_SIZE - 1] ^= 1;
+
+        assert_eq!(
+            Plonk::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn malformed_proof() {
+        let (vk, mut proof, pubs) = instance(2);
+        proof[0] = 0xff;
+
+        assert_eq!(
+            Plonk::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::InvalidProofData)
+        );
+    }
+
+    #[test]
+    fn wrong_number_of_public_inputs() {
+        let (vk, proof, mut pubs) = instance(2);
+        pubs.pop();
+
+        assert_eq!(
+            Plonk::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn too_many_public_inputs() {
+        let (vk, proof, pubs) = instance(Mock::MAX_NUM_INPUTS + 1);
+
+        assert_eq!(
+            Plonk::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn vk_with_too_many_public_inputs() {
+        let (vk, _, _) = instance(Mock::MAX_NUM_INPUTS + 1);
+
+        assert_eq!(
+            Plonk::<Mock>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn malformed_vk() {
+        let (mut vk, _, _) = instance(2);
+        vk.qm[0] = 0xff;
+
+        assert_eq!(
+            Plonk::<Mock>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+}
+
+mod conformance {
+    use super::*;
+
+    type PlonkMock = Plonk<Mock>;
+
+    pub struct PlonkFixtures;
+
+    impl pallet_verifiers_test_kit::Fixtures<PlonkMock> for PlonkFixtures {
+        type WeightInfo = PlonkWeight<()>;
+
+        fn valid() -> (Vk, Proof, Pubs) {
+            instance(2)
+        }
+
+        fn invalid() -> Vec<(Vk, Proof, Pubs)> {
+            let (vk, proof, mut invalid_pubs) = instance(2);
+            invalid_pubs[0][FR_SIZE - 1] ^= 1;
+            let mut malformed_proof = proof;
+            malformed_proof[0] = 0xff;
+            vec![
+                (vk.clone(), proof, invalid_pubs),
+                (vk, malformed_proof, instance(2).2),
+            ]
+        }
+
+        fn invalid_vks() -> Vec<Vk> {
+            let (mut vk, _, _) = instance(2);
+            vk.qm[0] = 0xff;
+            vec![vk, instance(Mock::MAX_NUM_INPUTS + 1).0]
+        }
+
+        fn by_size() -> Vec<(Proof, Pubs)> {
+            [1, 2, 4, Mock::MAX_NUM_INPUTS]
+                .into_iter()
+                .map(|n| {
+                    let (_, proof, pubs) = instance(n);
+                    (proof, pubs)
+                })
+                .collect()
+        }
+    }
+
+    pallet_verifiers_test_kit::verifier_conformance!(PlonkMock, PlonkFixtures);
+}
@@ -0,0 +1,141 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Autogenerated weights for `pallet_plonk_verifier`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 36.0.0
+//! DATE: 2024-12-02, STEPS: `4`, REPEAT: `1`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `lucag-laptop`, CPU: `Intel(R) Core(TM) i7-10510U CPU @ 1.80GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/release/zkv-relay
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet-plonk-verifier
+// --extrinsic
+// *
+// --steps
+// 4
+// --repeat
+// 1
+// --heap-pages=4096
+// --header
+// /home/lucag/repos/NH-core/HEADER-APACHE2
+// --output
+// verifiers/plonk/src/weight.rs
+// --template
+// /home/lucag/repos/NH-core/node/zkv-pallets-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_plonk_verifier`.
+pub trait WeightInfo {
+    fn submit_proof(n: u32, ) -> Weight;
+    fn submit_proof_with_vk_hash(n: u32, ) -> Weight;
+    fn register_vk() -> Weight;
+    fn unregister_vk() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `SettlementPlonkPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementPlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 32]`.
+    fn submit_proof(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `241`
+        //  Estimated: `3537`
+        // Minimum execution time: 9_126_417_000 picoseconds.
+        Weight::from_parts(9_071_836_000, 3537)
+            // Standard Error: 41_572_000
+            .saturating_add(Weight::from_parts(55_694_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementPlonkPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementPlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementPlonkPallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementPlonkPallet::Vks` (`max_values`: None, `max_size`: Some(757), added: 3232, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 32]`.
+    fn submit_proof_with_vk_hash(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1026`
+        //  Estimated: `4222`
+        // Minimum execution time: 9_139_254_000 picoseconds.
+        Weight::from_parts(9_088_512_000, 4222)
+            // Standard Error: 38_104_000
+            .saturating_add(Weight::from_parts(55_917_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementPlonkPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementPlonkPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementPlonkPallet::Tickets` (r:1 w:1)
+    /// Proof: `SettlementPlonkPallet::Tickets` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementPlonkPallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementPlonkPallet::Vks` (`max_values`: None, `max_size`: Some(757), added: 3232, mode: `MaxEncodedLen`)
+    fn register_vk() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `42`
+        //  Estimated: `4222`
+        // Minimum execution time: 4_372_108_000 picoseconds.
+        Weight::from_parts(4_372_108_000, 4222)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `SettlementPlonkPallet::Tickets` (r:1 w:1)
+    /// Proof: `SettlementPlonkPallet::Tickets` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementPlonkPallet::Vks` (r:1 w:1)
+    /// Proof: `SettlementPlonkPallet::Vks` (`max_values`: None, `max_size`: Some(757), added: 3232, mode: `MaxEncodedLen`)
+    fn unregister_vk() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `988`
+        //  Estimated: `4222`
+        // Minimum execution time: 74_215_000 picoseconds.
+        Weight::from_parts(74_215_000, 4222)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A verifier for plonky2 proofs: a transparent, hash based proof system over the Goldilocks
+//! field with FRI as polynomial commitment scheme. The verification key is the serialized
+//! `VerifierCircuitData` (the common circuit data and the verifier-only data, which includes
+//! the circuit digest) of the circuit.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use educe::Educe;
+use frame_support::{ensure, weights::Weight};
+use hp_verifiers::{Cow, Verifier, VerifyError};
+use scale_info::TypeInfo;
+use sp_core::Get;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+mod verifier_should;
+mod weight;
+pub use weight::WeightInfo;
+
+pub use native::{
+    PLONKY2_MAX_DEGREE_BITS as MAX_DEGREE_BITS,
+    PLONKY2_MAX_NUM_QUERY_ROUNDS as MAX_NUM_QUERY_ROUNDS,
+};
+
+pub trait Config: 'static {
+    /// Maximum size in bytes of the serialized verifier circuit data.
+    type MaxVkSize: Get<u32>;
+    /// Maximum size in bytes of a proof.
+    type MaxProofSize: Get<u32>;
+    /// Maximum number of public inputs.
+    type MaxPubs: Get<u32>;
+}
+
+/// The hash used by the circuit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum Hasher {
+    /// `PoseidonGoldilocksConfig`.
+    Poseidon,
+    /// `KeccakGoldilocksConfig`.
+    Keccak,
+}
+
+impl From<Hasher> for native::Plonky2Hasher {
+    fn from(value: Hasher) -> Self {
+        match value {
+            Hasher::Poseidon => native::Plonky2Hasher::Poseidon,
+            Hasher::Keccak => native::Plonky2Hasher::Keccak,
+        }
+    }
+}
+
+// Here educe is used for Clone, Debug, and PartialEq to work around
+// a long-standing compiler bug https://github.com/rust-lang/rust/issues/26925
+#[derive(Educe, Encode, Decode, TypeInfo)]
+#[educe(Clone, Debug, PartialEq)]
+#[scale_info(skip_type_params(T))]
+pub struct Vk<T> {
+    /// The hash used by the circuit.
+    pub hasher: Hasher,
+    /// The serialized `VerifierCircuitData`.
+    pub bytes: Vec<u8>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Vk<T> {
+    pub fn new(hasher: Hasher, bytes: Vec<u8>) -> Self {
+        Self {
+            hasher,
+            bytes,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Config> MaxEncodedLen for Vk<T> {
+    fn max_encoded_len() -> usize {
+        let len = T::MaxVkSize::get();
+        Hasher::max_encoded_len() + codec::Compact(len).encoded_size() + len as usize
+    }
+}
+
+/// A plonky2 proof (without public inputs) with the FRI parameters of its circuit. The
+/// parameters define the proof weight and are checked against the verification key ones.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub struct Proof {
+    /// Log2 of the number of rows of the circuit.
+    pub degree_bits: u32,
+    /// Number of FRI query rounds.
+    pub num_query_rounds: u32,
+    /// The serialized proof.
+    pub bytes: Vec<u8>,
+}
+
+/// The public inputs: Goldilocks field elements in canonical form.
+pub type Pubs = Vec<u64>;
+
+#[pallet_verifiers::verifier]
+pub struct Plonky2<T>;
+
+impl<T: Config> Verifier for Plonky2<T> {
+    type Proof = Proof;
+
+    type Pubs = Pubs;
+
+    type Vk = Vk<T>;
+
+    fn hash_context_data() -> &'static [u8] {
+        b"plonky2"
+    }
+
+    fn verify_proof(
+        vk: &Self::Vk,
+        proof: &Self::Proof,
+        pubs: &Self::Pubs,
+    ) -> Result<(), VerifyError> {
+        ensure!(
+            vk.bytes.len() <= T::MaxVkSize::get() as usize,
+            VerifyError::InvalidVerificationKey
+        );
+        ensure!(
+            proof.bytes.len() <= T::MaxProofSize::get() as usize
+                && proof.degree_bits <= MAX_DEGREE_BITS
+                && proof.num_query_rounds <= MAX_NUM_QUERY_ROUNDS,
+            VerifyError::InvalidProofData
+        );
+        ensure!(
+            pubs.len() <= T::MaxPubs::get() as usize,
+            VerifyError::InvalidInput
+        );
+        log::trace!("Verifying (native)");
+        native::plonky_2_verify::verify(
+            vk.hasher.into(),
+            &vk.bytes,
+            &proof.bytes,
+            proof.degree_bits,
+            proof.num_query_rounds,
+            pubs,
+        )
+        .map_err(Into::into)
+    }
+
+    fn validate_vk(vk: &Self::Vk) -> Result<(), VerifyError> {
+        ensure!(
+            vk.bytes.len() <= T::MaxVkSize::get() as usize,
+            VerifyError::InvalidVerificationKey
+        );
+        native::plonky_2_verify::validate_vk(vk.hasher.into(), &vk.bytes).map_err(Into::into)
+    }
+
+    fn pubs_bytes(pubs: &Self::Pubs) -> Cow<[u8]> {
+        let data = pubs
+            .iter()
+            .flat_map(|v| v.to_be_bytes())
+            .collect::<Vec<_>>();
+        Cow::Owned(data)
+    }
+}
+
+/// The struct to use in runtime pallet configuration to map the weight computed by this crate
+/// benchmarks to the weight needed by the `pallet-verifiers`.
+pub struct Plonky2Weight<W: weight::WeightInfo>(PhantomData<W>);
+
+/// The verification cost is dominated by the Merkle paths checked in the FRI query rounds: the
+/// number of rounds times the path length (the degree bits).
+fn merkle_openings(proof: &Proof) -> u32 {
+    proof.num_query_rounds.saturating_mul(proof.degree_bits)
+}
+
+fn proof_size(proof: &Proof) -> u32 {
+    proof.bytes.len().try_into().unwrap_or(u32::MAX)
+}
+
+impl<T: Config, W: weight::WeightInfo> pallet_verifiers::WeightInfo<Plonky2<T>>
+    for Plonky2Weight<W>
+{
+    fn submit_proof(
+        proof: &<Plonky2<T> as Verifier>::Proof,
+        _pubs: &<Plonky2<T> as Verifier>::Pubs,
+    ) -> Weight {
+        W::submit_proof(merkle_openings(proof), proof_size(proof))
+    }
+
+    fn submit_proof_with_vk_hash(
+        proof: &<Plonky2<T> as Verifier>::Proof,
+        _pubs: &<Plonky2<T> as Verifier>::Pubs,
+    ) -> Weight {
+        W::submit_proof_with_vk_hash(merkle_openings(proof), proof_size(proof))
+    }
+
+    fn register_vk(vk: &<Plonky2<T> as Verifier>::Vk) -> Weight {
+        W::register_vk(vk.bytes.len().try_into().unwrap_or(u32::MAX))
+    }
+
+    fn unregister_vk() -> Weight {
+        W::unregister_vk()
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use super::*;
+use sp_core::ConstU32;
+
+struct Mock;
+
+impl Config for Mock {
+    type MaxVkSize = ConstU32<1024>;
+    type MaxProofSize = ConstU32<2048>;
+    type MaxPubs = ConstU32<4>;
+}
+
+fn proof(len: usize) -> Proof {
+    Proof {
+        degree_bits: 12,
+        num_query_rounds: 28,
+        bytes: vec![0; len],
+    }
+}
+
+fn vk(len: usize) -> Vk<Mock> {
+    Vk::new(Hasher::Poseidon, vec![0; len])
+}
+
+#[test]
+fn return_the_public_inputs_as_big_endian_words() {
+    let pubs = vec![1, 0x0203];
+
+    assert_eq!(
+        Plonky2::<Mock>::pubs_bytes(&pubs).as_ref(),
+        [[0, 0, 0, 0, 0, 0, 0, 1], [0, 0, 0, 0, 0, 0, 2, 3]].concat()
+    );
+}
+
+#[test]
+fn compute_weights_that_grow_with_fri_parameters_and_proof_size() {
+    use pallet_verifiers::WeightInfo as _;
+    type W = Plonky2Weight<()>;
+
+    let base = W::submit_proof(&proof(100), &vec![]);
+    let more_rounds = W::submit_proof(
+        &Proof {
+            num_query_rounds: 56,
+            ..proof(100)
+        },
+        &vec![],
+    );
+    let bigger_circuit = W::submit_proof(
+        &Proof {
+            degree_bits: 16,
+            ..proof(100)
+        },
+        &vec![],
+    );
+    let bigger_proof = W::submit_proof(&proof(200), &vec![]);
+
+    assert!(more_rounds.ref_time() > base.ref_time());
+    assert!(bigger_circuit.ref_time() > base.ref_time());
+    assert!(bigger_proof.ref_time() > base.ref_time());
+}
+
+mod reject {
+    use super::*;
+
+    #[test]
+    fn too_big_vk() {
+        let vk = vk(<Mock as Config>::MaxVkSize::get() as usize + 1);
+
+        assert_eq!(
+            Plonky2::<Mock>::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+        assert_eq!(
+            Plonky2::<Mock>::verify_proof(&vk, &proof(32), &vec![]),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn too_big_proof() {
+        let proof = proof(<Mock as Config>::MaxProofSize::get() as usize + 1);
+
+        assert_eq!(
+            Plonky2::<Mock>::verify_proof(&vk(32), &proof, &vec![]),
+            Err(VerifyError::InvalidProofData)
+        );
+    }
+
+    #[test]
+    fn unsupported_fri_parameters() {
+        let too_many_rounds = Proof {
+            num_query_rounds: MAX_NUM_QUERY_ROUNDS + 1,
+            ..proof(32)
+        };
+        let too_big_circuit = Proof {
+            degree_bits: MAX_DEGREE_BITS + 1,
+            ..proof(32)
+        };
+
+        for proof in [too_many_rounds, too_big_circuit] {
+            assert_eq!(
+                Plonky2::<Mock>::verify_proof(&vk(32), &proof, &vec![]),
+                Err(VerifyError::InvalidProofData)
+            );
+        }
+    }
+
+    #[test]
+    fn too_many_public_inputs() {
+        let pubs = vec![0; <Mock as Config>::MaxPubs::get() as usize + 1];
+
+        assert_eq!(
+            Plonky2::<Mock>::verify_proof(&vk(32), &proof(32), &pubs),
+            Err(VerifyError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn malformed_vk() {
+        assert_eq!(
+            Plonky2::<Mock>::validate_vk(&Vk::new(Hasher::Keccak, vec![0xff; 64])),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+}
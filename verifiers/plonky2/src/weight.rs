@@ -0,0 +1,87 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Weights for `pallet_plonky2_verifier`
+//!
+//! NOT AUTO-GENERATED: these are conservative estimates based on the cost of a Goldilocks
+//! Poseidon permutation per Merkle path step. They have to be replaced by benchmarked values
+//! once the benchmarking fixtures are available.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_plonky2_verifier`.
+pub trait WeightInfo {
+    fn submit_proof(c: u32, p: u32, ) -> Weight;
+    fn submit_proof_with_vk_hash(c: u32, p: u32, ) -> Weight;
+    fn register_vk(l: u32, ) -> Weight;
+    fn unregister_vk() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `SettlementPlonky2Pallet::Disabled` (r:1 w:0)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// The range of component `c` is `[0, 3072]`.
+    /// The range of component `p` is `[0, 1048576]`.
+    fn submit_proof(c: u32, p: u32, ) -> Weight {
+        Weight::from_parts(5_000_000_000, 3537)
+            .saturating_add(Weight::from_parts(10_000_000, 0).saturating_mul(c.into()))
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementPlonky2Pallet::Disabled` (r:1 w:0)
+    /// Storage: `SettlementPlonky2Pallet::Vks` (r:1 w:0)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// The range of component `c` is `[0, 3072]`.
+    /// The range of component `p` is `[0, 1048576]`.
+    fn submit_proof_with_vk_hash(c: u32, p: u32, ) -> Weight {
+        Weight::from_parts(5_000_000_000, 134_609)
+            .saturating_add(Weight::from_parts(10_000_000, 0).saturating_mul(c.into()))
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementPlonky2Pallet::Disabled` (r:1 w:0)
+    /// Storage: `SettlementPlonky2Pallet::Tickets` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `SettlementPlonky2Pallet::Vks` (r:1 w:1)
+    /// The range of component `l` is `[0, 131072]`.
+    fn register_vk(l: u32, ) -> Weight {
+        Weight::from_parts(100_000_000, 134_609)
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(l.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `SettlementPlonky2Pallet::Tickets` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `SettlementPlonky2Pallet::Vks` (r:1 w:1)
+    fn unregister_vk() -> Weight {
+        Weight::from_parts(80_000_000, 134_609)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+}
@@ -42,7 +42,45 @@ mod benchmarks {
     use super::*;
 
     #[benchmark]
-    fn submit_proof() {
+    fn submit_proof_max_nu_1() {
+        // setup code
+        let (caller, domain_id) = init::<T>();
+
+        let vk = include_bytes!("resources/VALID_VK_MAX_NU_1.bin").to_vec();
+        let proof = include_bytes!("resources/VALID_PROOF_MAX_NU_1.bin").to_vec();
+        let pubs = include_bytes!("resources/VALID_PUBS_MAX_NU_1.bin").to_vec();
+
+        #[extrinsic_call]
+        submit_proof(
+            RawOrigin::Signed(caller),
+            VkOrHash::from_vk(vk.into()),
+            proof.into(),
+            pubs.into(),
+            Some(domain_id),
+        );
+    }
+
+    #[benchmark]
+    fn submit_proof_max_nu_4() {
+        // setup code
+        let (caller, domain_id) = init::<T>();
+
+        let vk = include_bytes!("resources/VALID_VK_MAX_NU_4.bin").to_vec();
+        let proof = include_bytes!("resources/VALID_PROOF_MAX_NU_4.bin").to_vec();
+        let pubs = include_bytes!("resources/VALID_PUBS_MAX_NU_4.bin").to_vec();
+
+        #[extrinsic_call]
+        submit_proof(
+            RawOrigin::Signed(caller),
+            VkOrHash::from_vk(vk.into()),
+            proof.into(),
+            pubs.into(),
+            Some(domain_id),
+        );
+    }
+
+    #[benchmark]
+    fn submit_proof_max_nu_8() {
         // setup code
         let (caller, domain_id) = init::<T>();
 
@@ -61,7 +99,55 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn submit_proof_with_vk_hash() {
+    fn submit_proof_with_vk_hash_max_nu_1() {
+        // setup code
+        let (caller, domain_id) = init::<T>();
+
+        let vk_hash = sp_core::H256::repeat_byte(2);
+        let vk: crate::Vk<T> = include_bytes!("resources/VALID_VK_MAX_NU_1.bin")
+            .to_vec()
+            .into();
+        let proof = include_bytes!("resources/VALID_PROOF_MAX_NU_1.bin").to_vec();
+        let pubs = include_bytes!("resources/VALID_PUBS_MAX_NU_1.bin").to_vec();
+        let vk_entry = VkEntry::new(vk);
+        Vks::<T, ProofOfSql<T>>::insert(vk_hash, vk_entry);
+
+        #[extrinsic_call]
+        submit_proof(
+            RawOrigin::Signed(caller),
+            VkOrHash::from_hash(vk_hash),
+            proof.into(),
+            pubs.into(),
+            Some(domain_id),
+        );
+    }
+
+    #[benchmark]
+    fn submit_proof_with_vk_hash_max_nu_4() {
+        // setup code
+        let (caller, domain_id) = init::<T>();
+
+        let vk_hash = sp_core::H256::repeat_byte(2);
+        let vk: crate::Vk<T> = include_bytes!("resources/VALID_VK_MAX_NU_4.bin")
+            .to_vec()
+            .into();
+        let proof = include_bytes!("resources/VALID_PROOF_MAX_NU_4.bin").to_vec();
+        let pubs = include_bytes!("resources/VALID_PUBS_MAX_NU_4.bin").to_vec();
+        let vk_entry = VkEntry::new(vk);
+        Vks::<T, ProofOfSql<T>>::insert(vk_hash, vk_entry);
+
+        #[extrinsic_call]
+        submit_proof(
+            RawOrigin::Signed(caller),
+            VkOrHash::from_hash(vk_hash),
+            proof.into(),
+            pubs.into(),
+            Some(domain_id),
+        );
+    }
+
+    #[benchmark]
+    fn submit_proof_with_vk_hash_max_nu_8() {
         // setup code
         let (caller, domain_id) = init::<T>();
 
@@ -165,8 +251,10 @@ mod mock {
 
     impl pallet_verifiers::Config<crate::ProofOfSql<Test>> for Test {
         type RuntimeEvent = RuntimeEvent;
-        type OnProofVerified = Aggregate;
+        type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<Aggregate>;
+        type OnProofRejected = ();
         type WeightInfo = crate::ProofOfSqlWeight<()>;
+        type MaxBatchSize = ConstU32<16>;
         type Ticket = HoldConsideration<
             AccountId,
             Balances,
@@ -194,6 +282,7 @@ mod mock {
 
     impl pallet_verifiers::common::Config for Test {
         type CommonWeightInfo = Test;
+        type StatementRetention = ();
     }
 
     pub struct NoManager;
@@ -27,8 +27,10 @@ use sp_std::{marker::PhantomData, vec::Vec};
 
 pub mod benchmarking;
 mod errors;
+mod shape;
 mod verifier_should;
 mod weight;
+use shape::evaluation_rounds;
 pub use weight::WeightInfo;
 
 pub const LARGEST_MAX_NU_LIMIT: u32 = 8;
@@ -132,23 +134,107 @@ impl<T: Config> Verifier for ProofOfSql<T> {
     }
 }
 
+/// The size of the public inputs of the `nu` 1 and 4 benchmarks: bigger ones are charged as the
+/// `nu` 8 benchmark.
+const PUBS_SIZE_MAX_NU_1: usize = 10388;
+const PUBS_SIZE_MAX_NU_4: usize = 10431;
+
 pub struct ProofOfSqlWeight<W: weight::WeightInfo>(PhantomData<W>);
 
+impl<W: weight::WeightInfo> ProofOfSqlWeight<W> {
+    /// The `nu` of the smallest vk that is not smaller than the given one: `None` if it's
+    /// bigger than any supported vk.
+    fn vk_nu(vk: &[u8]) -> Option<u32> {
+        (0..=LARGEST_MAX_NU_LIMIT)
+            .find(|&nu| vk.len() <= VerificationKey::serialized_size(nu as usize))
+    }
+
+    /// The `nu` of the smallest benchmark whose public inputs are not smaller than the given
+    /// ones.
+    fn pubs_nu(pubs: &[u8]) -> u32 {
+        if pubs.len() <= PUBS_SIZE_MAX_NU_1 {
+            1
+        } else if pubs.len() <= PUBS_SIZE_MAX_NU_4 {
+            4
+        } else {
+            8
+        }
+    }
+
+    /// Map the submission to the benchmarked bracket with the smallest `nu` that is not less than
+    /// the proof, the vk and the public inputs ones: the proof size is chosen by the submitter,
+    /// so the vk `nu` bounds the verification work. An undecodable proof or an unknown vk are
+    /// charged as the biggest bracket.
+    fn bracket(
+        proof: &[u8],
+        pubs: &[u8],
+        vk_nu: Option<u32>,
+        brackets: [fn() -> Weight; 3],
+    ) -> Weight {
+        let [nu_1, nu_4, nu_8] = brackets;
+        let nu = evaluation_rounds(proof)
+            .zip(vk_nu)
+            .map(|(proof_nu, vk_nu)| proof_nu.max(vk_nu).max(Self::pubs_nu(pubs)));
+        match nu {
+            Some(0..=1) => nu_1(),
+            Some(2..=4) => nu_4(),
+            _ => nu_8(),
+        }
+    }
+}
+
 impl<T: Config, W: weight::WeightInfo> pallet_verifiers::WeightInfo<ProofOfSql<T>>
     for ProofOfSqlWeight<W>
 {
+    /// The vk is not known: charge the largest one the runtime accepts.
     fn submit_proof(
-        _proof: &<ProofOfSql<T> as hp_verifiers::Verifier>::Proof,
-        _pubs: &<ProofOfSql<T> as hp_verifiers::Verifier>::Pubs,
+        proof: &<ProofOfSql<T> as hp_verifiers::Verifier>::Proof,
+        pubs: &<ProofOfSql<T> as hp_verifiers::Verifier>::Pubs,
+    ) -> Weight {
+        Self::bracket(
+            proof,
+            pubs,
+            Some(T::largest_max_nu()),
+            [
+                W::submit_proof_max_nu_1,
+                W::submit_proof_max_nu_4,
+                W::submit_proof_max_nu_8,
+            ],
+        )
+    }
+
+    fn submit_proof_with_vk(
+        vk: &<ProofOfSql<T> as hp_verifiers::Verifier>::Vk,
+        proof: &<ProofOfSql<T> as hp_verifiers::Verifier>::Proof,
+        pubs: &<ProofOfSql<T> as hp_verifiers::Verifier>::Pubs,
     ) -> Weight {
-        W::submit_proof()
+        Self::bracket(
+            proof,
+            pubs,
+            Self::vk_nu(&vk.0),
+            [
+                W::submit_proof_max_nu_1,
+                W::submit_proof_max_nu_4,
+                W::submit_proof_max_nu_8,
+            ],
+        )
     }
 
+    /// The registered vk is not known: charge the largest one the runtime accepts.
     fn submit_proof_with_vk_hash(
-        _proof: &<ProofOfSql<T> as hp_verifiers::Verifier>::Proof,
-        _pubs: &<ProofOfSql<T> as hp_verifiers::Verifier>::Pubs,
+        proof: &<ProofOfSql<T> as hp_verifiers::Verifier>::Proof,
+        pubs: &<ProofOfSql<T> as hp_verifiers::Verifier>::Pubs,
     ) -> Weight {
-        W::submit_proof_with_vk_hash()
+        Self::bracket(
+            proof,
+            pubs,
+            Some(T::largest_max_nu()),
+            [
+                W::submit_proof_with_vk_hash_max_nu_1,
+                W::submit_proof_with_vk_hash_max_nu_4,
+                W::submit_proof_with_vk_hash_max_nu_8,
+            ],
+        )
     }
 
     fn register_vk(_vk: &<ProofOfSql<T> as hp_verifiers::Verifier>::Vk) -> Weight {
@@ -0,0 +1,116 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal walker of the CBOR serialized proof, used just to guess the verification cost: it
+//! reads the length of the Dory evaluation proof, that grows linearly with the `nu` of the
+//! commitment setup.
+
+/// The evaluation proof bytes that don't depend on `nu`.
+const EVALUATION_PROOF_BASE_SIZE: usize = 1376;
+/// The evaluation proof bytes added by every Dory reduction round.
+const EVALUATION_PROOF_ROUND_SIZE: usize = 3888;
+
+/// The number of Dory reduction rounds (i.e. the `nu`) of the given proof: `None` if the proof
+/// cannot be decoded.
+pub fn evaluation_rounds(proof: &[u8]) -> Option<u32> {
+    let mut reader = Reader(proof);
+    reader.find_key("proof")?;
+    reader.find_key("evaluation_proof")?;
+    let len = match reader.head()? {
+        // The bytes can be serialized either as a sequence or as a byte string.
+        (ARRAY | BYTES, len) => len,
+        _ => return None,
+    };
+    let rounds = usize::try_from(len)
+        .ok()?
+        .saturating_sub(EVALUATION_PROOF_BASE_SIZE)
+        .div_ceil(EVALUATION_PROOF_ROUND_SIZE);
+    rounds.try_into().ok()
+}
+
+const BYTES: u8 = 2;
+const TEXT: u8 = 3;
+const ARRAY: u8 = 4;
+const MAP: u8 = 5;
+const TAG: u8 = 6;
+
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take(&mut self, len: usize) -> Option<&[u8]> {
+        (len <= self.0.len()).then(|| {
+            let (head, tail) = self.0.split_at(len);
+            self.0 = tail;
+            head
+        })
+    }
+
+    /// Read an item head: the major type and its argument. Indefinite lengths are not
+    /// supported.
+    fn head(&mut self) -> Option<(u8, u64)> {
+        let initial = *self.take(1)?.first()?;
+        let (major, info) = (initial >> 5, initial & 0x1f);
+        let argument = match info {
+            0..=23 => info as u64,
+            24 => self.take(1)?[0] as u64,
+            25 => u16::from_be_bytes(self.take(2)?.try_into().ok()?) as u64,
+            26 => u32::from_be_bytes(self.take(4)?.try_into().ok()?) as u64,
+            27 => u64::from_be_bytes(self.take(8)?.try_into().ok()?),
+            _ => return None,
+        };
+        Some((major, argument))
+    }
+
+    fn skip_bytes(&mut self, len: u64) -> Option<()> {
+        self.take(len.try_into().ok()?).map(|_| ())
+    }
+
+    /// Skip a whole item. Nested items are counted instead of recursing, so a malicious
+    /// nesting cannot exhaust the stack.
+    fn skip(&mut self) -> Option<()> {
+        let mut pending: u64 = 1;
+        while pending > 0 {
+            pending -= 1;
+            match self.head()? {
+                (BYTES | TEXT, len) => self.skip_bytes(len)?,
+                (ARRAY, len) => pending = pending.checked_add(len)?,
+                (MAP, len) => pending = pending.checked_add(len.checked_mul(2)?)?,
+                (TAG, _) => pending += 1,
+                // Integers, simple values and floats: the argument is the whole value.
+                (_, _) => {}
+            }
+        }
+        Some(())
+    }
+
+    /// Move the reader to the value of the given text key of the map that starts here.
+    fn find_key(&mut self, key: &str) -> Option<()> {
+        let (MAP, entries) = self.head()? else {
+            return None;
+        };
+        for _ in 0..entries {
+            match self.head()? {
+                (TEXT, len) => {
+                    if self.take(len.try_into().ok()?)? == key.as_bytes() {
+                        return Some(());
+                    }
+                }
+                _ => return None,
+            }
+            self.skip()?;
+        }
+        None
+    }
+}
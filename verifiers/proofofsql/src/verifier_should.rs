@@ -162,3 +162,169 @@ mod reject {
         )
     }
 }
+
+mod weights {
+    use super::*;
+
+    #[rstest]
+    #[case::nu_1(include_bytes!("resources/VALID_PROOF_MAX_NU_1.bin"), 1)]
+    #[case::nu_4(include_bytes!("resources/VALID_PROOF_MAX_NU_4.bin"), 4)]
+    #[case::nu_8(include_bytes!("resources/VALID_PROOF_MAX_NU_8.bin"), 8)]
+    fn decode_the_evaluation_rounds(#[case] proof: &[u8], #[case] rounds: u32) {
+        assert_eq!(evaluation_rounds(proof), Some(rounds));
+    }
+
+    #[rstest]
+    #[case::empty(&[])]
+    #[case::not_a_map(&[0x80])]
+    #[case::without_proof(&[0xa1, 0x61, b'a', 0x00])]
+    fn not_decode_a_malformed_proof(#[case] proof: &[u8]) {
+        assert_eq!(evaluation_rounds(proof), None);
+    }
+
+    type W = ProofOfSqlWeight<()>;
+    type Mock = ProofOfSql<ConfigWithMaxNuEqualTo5>;
+
+    fn vk_max_nu_1() -> Vk<ConfigWithMaxNuEqualTo5> {
+        include_bytes!("resources/VALID_VK_MAX_NU_1.bin")
+            .to_vec()
+            .into()
+    }
+
+    #[rstest]
+    #[case::nu_1(
+        include_bytes!("resources/VALID_PROOF_MAX_NU_1.bin"),
+        <() as WeightInfo>::submit_proof_max_nu_1()
+    )]
+    #[case::nu_4(
+        include_bytes!("resources/VALID_PROOF_MAX_NU_4.bin"),
+        <() as WeightInfo>::submit_proof_max_nu_4()
+    )]
+    #[case::nu_8(
+        include_bytes!("resources/VALID_PROOF_MAX_NU_8.bin"),
+        <() as WeightInfo>::submit_proof_max_nu_8()
+    )]
+    #[case::malformed(&[], <() as WeightInfo>::submit_proof_max_nu_8())]
+    fn select_the_weight_bracket_by_evaluation_rounds(
+        #[case] proof: &[u8],
+        #[case] expected: Weight,
+    ) {
+        assert_eq!(
+            <W as pallet_verifiers::WeightInfo<Mock>>::submit_proof_with_vk(
+                &vk_max_nu_1(),
+                &proof.to_vec(),
+                &vec![]
+            ),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::nu_1(
+        include_bytes!("resources/VALID_VK_MAX_NU_1.bin"),
+        <() as WeightInfo>::submit_proof_max_nu_1()
+    )]
+    #[case::nu_4(
+        include_bytes!("resources/VALID_VK_MAX_NU_4.bin"),
+        <() as WeightInfo>::submit_proof_max_nu_4()
+    )]
+    #[case::nu_8(
+        include_bytes!("resources/VALID_VK_MAX_NU_8.bin"),
+        <() as WeightInfo>::submit_proof_max_nu_8()
+    )]
+    fn charge_at_least_the_vk_bracket(#[case] vk: &[u8], #[case] expected: Weight) {
+        let proof = include_bytes!("resources/VALID_PROOF_MAX_NU_1.bin").to_vec();
+
+        assert_eq!(
+            <W as pallet_verifiers::WeightInfo<Mock>>::submit_proof_with_vk(
+                &vk.to_vec().into(),
+                &proof,
+                &vec![]
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn charge_at_least_the_public_inputs_bracket() {
+        let proof = include_bytes!("resources/VALID_PROOF_MAX_NU_1.bin").to_vec();
+        let pubs = include_bytes!("resources/VALID_PUBS_MAX_NU_4.bin").to_vec();
+
+        assert_eq!(
+            <W as pallet_verifiers::WeightInfo<Mock>>::submit_proof_with_vk(
+                &vk_max_nu_1(),
+                &proof,
+                &pubs
+            ),
+            <() as WeightInfo>::submit_proof_max_nu_4()
+        );
+    }
+
+    #[test]
+    fn charge_the_largest_vk_when_it_is_not_known() {
+        let proof = include_bytes!("resources/VALID_PROOF_MAX_NU_1.bin").to_vec();
+
+        assert_eq!(
+            <W as pallet_verifiers::WeightInfo<Mock>>::submit_proof(&proof, &vec![]),
+            <() as WeightInfo>::submit_proof_max_nu_8()
+        );
+        assert_eq!(
+            <W as pallet_verifiers::WeightInfo<Mock>>::submit_proof_with_vk_hash(&proof, &vec![]),
+            <() as WeightInfo>::submit_proof_with_vk_hash_max_nu_8()
+        );
+    }
+}
+
+mod conformance {
+    use super::*;
+
+    type ProofOfSqlMock = ProofOfSql<ConfigWithMaxNuEqualTo5>;
+    type Proof = <ProofOfSqlMock as Verifier>::Proof;
+    type Pubs = <ProofOfSqlMock as Verifier>::Pubs;
+
+    pub struct ProofOfSqlFixtures;
+
+    impl pallet_verifiers_test_kit::Fixtures<ProofOfSqlMock> for ProofOfSqlFixtures {
+        type WeightInfo = ProofOfSqlWeight<()>;
+
+        fn valid() -> (Vk<ConfigWithMaxNuEqualTo5>, Proof, Pubs) {
+            let data = valid_test_data();
+            (data.vk.into(), data.proof, data.pubs)
+        }
+
+        fn invalid() -> Vec<(Vk<ConfigWithMaxNuEqualTo5>, Proof, Pubs)> {
+            let data = valid_test_data();
+            let mut invalid_pubs = data.pubs.clone();
+            let pubs_len = invalid_pubs.len();
+            invalid_pubs[pubs_len - 1] = invalid_pubs[pubs_len - 1].wrapping_add(1);
+            let mut malformed_proof = data.proof.clone();
+            malformed_proof[0] = malformed_proof[0].wrapping_add(1);
+            let mut malformed_vk = data.vk.clone();
+            malformed_vk[0] = malformed_vk[0].wrapping_add(1);
+            vec![
+                (data.vk.clone().into(), data.proof.clone(), invalid_pubs),
+                (data.vk.into(), malformed_proof, data.pubs.clone()),
+                (malformed_vk.into(), data.proof, data.pubs),
+            ]
+        }
+
+        fn by_size() -> Vec<(Proof, Pubs)> {
+            vec![
+                (
+                    include_bytes!("resources/VALID_PROOF_MAX_NU_1.bin").to_vec(),
+                    include_bytes!("resources/VALID_PUBS_MAX_NU_1.bin").to_vec(),
+                ),
+                (
+                    include_bytes!("resources/VALID_PROOF_MAX_NU_4.bin").to_vec(),
+                    include_bytes!("resources/VALID_PUBS_MAX_NU_4.bin").to_vec(),
+                ),
+                (
+                    include_bytes!("resources/VALID_PROOF_MAX_NU_8.bin").to_vec(),
+                    include_bytes!("resources/VALID_PUBS_MAX_NU_8.bin").to_vec(),
+                ),
+            ]
+        }
+    }
+
+    pallet_verifiers_test_kit::verifier_conformance!(ProofOfSqlMock, ProofOfSqlFixtures);
+}
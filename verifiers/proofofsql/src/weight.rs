@@ -20,6 +20,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `lucag-laptop`, CPU: `Intel(R) Core(TM) i7-10510U CPU @ 1.80GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOT BENCHMARKED: the `max_nu_1` and `max_nu_4` weights are hand-written estimates, scaled
+//! from the `max_nu_8` ones. Re-run the benchmarks to replace them.
 
 // Executed Command:
 // ./target/release/zkv-relay
@@ -53,8 +56,12 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_proofofsql_verifier`.
 pub trait WeightInfo {
-    fn submit_proof() -> Weight;
-    fn submit_proof_with_vk_hash() -> Weight;
+    fn submit_proof_max_nu_1() -> Weight;
+    fn submit_proof_max_nu_4() -> Weight;
+    fn submit_proof_max_nu_8() -> Weight;
+    fn submit_proof_with_vk_hash_max_nu_1() -> Weight;
+    fn submit_proof_with_vk_hash_max_nu_4() -> Weight;
+    fn submit_proof_with_vk_hash_max_nu_8() -> Weight;
     fn register_vk() -> Weight;
     fn unregister_vk() -> Weight;
 }
@@ -71,7 +78,39 @@ impl WeightInfo for () {
     /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
     /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    fn submit_proof() -> Weight {
+    fn submit_proof_max_nu_1() -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(598_467_565_000, 3537)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementProofOfSqlPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    fn submit_proof_max_nu_4() -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(972_509_793_000, 3537)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementProofOfSqlPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    fn submit_proof_max_nu_8() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `279`
         //  Estimated: `3537`
@@ -92,7 +131,43 @@ impl WeightInfo for () {
     /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
     /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-    fn submit_proof_with_vk_hash() -> Weight {
+    fn submit_proof_with_vk_hash_max_nu_1() -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(647_494_046_000, 30445)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementProofOfSqlPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementProofOfSqlPallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Vks` (`max_values`: None, `max_size`: Some(26980), added: 29455, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    fn submit_proof_with_vk_hash_max_nu_4() -> Weight {
+        // NOT benchmarked: an estimate, see the module docs.
+        Weight::from_parts(1_052_177_825_000, 30445)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `SettlementProofOfSqlPallet::Disabled` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `SettlementProofOfSqlPallet::Vks` (r:1 w:0)
+    /// Proof: `SettlementProofOfSqlPallet::Vks` (`max_values`: None, `max_size`: Some(26980), added: 29455, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::NextAttestation` (r:1 w:0)
+    /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::Values` (r:1 w:1)
+    /// Proof: `Poe::Values` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `Poe::FirstInsertionTime` (r:0 w:1)
+    /// Proof: `Poe::FirstInsertionTime` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    fn submit_proof_with_vk_hash_max_nu_8() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `27289`
         //  Estimated: `30445`
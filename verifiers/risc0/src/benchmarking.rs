@@ -31,6 +31,10 @@ pub type Call<T> = pallet_verifiers::Call<T, Risc0<T>>;
 
 include!("resources_benchmarking/vk_pubs.rs");
 
+fn v1_0_proof(inner_receipt: &[u8]) -> crate::Proof {
+    crate::Proof::V1_0(crate::Receipt::from_inner_receipt(inner_receipt).unwrap())
+}
+
 fn init<T: pallet_aggregate::Config>() -> (T::AccountId, u32) {
     let caller: T::AccountId = funded_account::<T>();
     let domain_id = 1;
@@ -48,9 +52,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_12.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_12.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_12.to_vec().into();
 
         #[extrinsic_call]
@@ -62,9 +67,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_13.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_13.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_13.to_vec().into();
 
         #[extrinsic_call]
@@ -76,9 +82,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_14.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_14.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_14.to_vec().into();
 
         #[extrinsic_call]
@@ -90,9 +97,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_15.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_15.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_15.to_vec().into();
 
         #[extrinsic_call]
@@ -104,9 +112,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_16.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_16.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_16.to_vec().into();
 
         #[extrinsic_call]
@@ -118,9 +127,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_17.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_17.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_17.to_vec().into();
 
         #[extrinsic_call]
@@ -132,9 +142,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_18.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_18.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_18.to_vec().into();
 
         #[extrinsic_call]
@@ -146,9 +157,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_19.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_19.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_19.to_vec().into();
 
         #[extrinsic_call]
@@ -160,9 +172,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_20.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_20.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_20.to_vec().into();
 
         #[extrinsic_call]
@@ -174,9 +187,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_21.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_21.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_21.to_vec().into();
 
         #[extrinsic_call]
@@ -188,9 +202,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_22.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_22.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_22.to_vec().into();
 
         #[extrinsic_call]
@@ -202,9 +217,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_23.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_23.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_23.to_vec().into();
 
         #[extrinsic_call]
@@ -216,9 +232,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_vk(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_24.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_24.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_24.to_vec().into();
 
         #[extrinsic_call]
@@ -230,9 +247,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_12.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_12.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_12.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -246,9 +264,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_13.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_13.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_13.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -262,9 +281,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_14.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_14.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_14.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -278,9 +298,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_15.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_15.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_15.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -294,9 +315,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_16.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_16.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_16.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -310,9 +332,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_17.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_17.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_17.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -326,9 +349,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_18.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_18.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_18.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -342,9 +366,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_19.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_19.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_19.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -358,9 +383,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_20.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_20.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_20.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -374,9 +400,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_21.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_21.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_21.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -390,9 +417,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_22.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_22.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_22.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -406,9 +434,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_23.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_23.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_23.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -422,9 +451,10 @@ mod benchmarks {
         let (caller, domain_id) = init::<T>();
 
         let vk = VkOrHash::from_hash(VALID_VK);
-        let proof = include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_24.bin")
-            .to_vec()
-            .into();
+        let proof = v1_0_proof(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_24.bin"
+        ))
+        .into();
         let pubs = VALID_PUBS_CYCLE_2_POW_24.to_vec().into();
         let vk_entry = VkEntry::new(VALID_VK);
         Vks::<T, Risc0<T>>::insert(VALID_VK, vk_entry);
@@ -510,8 +540,10 @@ mod mock {
 
     impl pallet_verifiers::Config<crate::Risc0<Test>> for Test {
         type RuntimeEvent = RuntimeEvent;
-        type OnProofVerified = Aggregate;
+        type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<Aggregate>;
+        type OnProofRejected = ();
         type WeightInfo = crate::Risc0Weight<()>;
+        type MaxBatchSize = ConstU32<16>;
         type Ticket = HoldConsideration<
             AccountId,
             Balances,
@@ -539,6 +571,7 @@ mod mock {
 
     impl pallet_verifiers::common::Config for Test {
         type CommonWeightInfo = Test;
+        type StatementRetention = ();
     }
 
     pub struct NoManager;
@@ -0,0 +1,113 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Import a bincode serialized risc0 1.0 `Receipt` (i.e. what `bincode::serialize(&receipt)`
+//! writes on the prover side): the proof is the serialized inner receipt and the public inputs
+//! are the serialized journal. The verification key is the guest image id, a 32 bytes hex string.
+
+use risc0_zkvm::Receipt;
+use sp_core::H256;
+
+use crate::{Proof, Pubs, Receipt as ProofReceipt, Vk};
+
+/// Why a risc0 file cannot be imported.
+#[derive(Debug, snafu::Snafu)]
+pub enum FormatError {
+    /// The receipt is not a valid bincode serialized `Receipt`.
+    #[snafu(display("Invalid receipt: {source}"))]
+    InvalidReceipt { source: bincode::Error },
+    /// The receipt kind is not supported (e.g. a fake receipt).
+    #[snafu(display("Unsupported receipt kind"))]
+    UnsupportedReceiptKind,
+    /// The image id is not a 32 bytes hex string.
+    #[snafu(display("Invalid image id '{image_id}'"))]
+    InvalidImageId { image_id: String },
+}
+
+type Result<T> = core::result::Result<T, FormatError>;
+
+/// Split a bincode serialized `Receipt` into the verifier proof and public inputs.
+pub fn receipt_from_bincode(receipt: &[u8]) -> Result<(Proof, Pubs)> {
+    let receipt: Receipt =
+        bincode::deserialize(receipt).map_err(|source| FormatError::InvalidReceipt { source })?;
+    let inner = bincode::serialize(&receipt.inner)
+        .map_err(|source| FormatError::InvalidReceipt { source })?;
+    let proof = ProofReceipt::from_inner_receipt(&inner)
+        .map(Proof::V1_0)
+        .ok_or(FormatError::UnsupportedReceiptKind)?;
+    let pubs = bincode::serialize(&receipt.journal)
+        .map_err(|source| FormatError::InvalidReceipt { source })?;
+    Ok((proof, pubs))
+}
+
+/// Parse the image id hex string (with or without the `0x` prefix), as printed by the risc0
+/// tooling.
+pub fn image_id_from_hex(image_id: &str) -> Result<Vk> {
+    let image_id = image_id.trim();
+    sp_core::bytes::from_hex(image_id)
+        .ok()
+        .filter(|bytes| bytes.len() == H256::len_bytes())
+        .map(|bytes| H256::from_slice(&bytes))
+        .ok_or_else(|| FormatError::InvalidImageId {
+            image_id: image_id.to_owned(),
+        })
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::Risc0;
+    use hp_verifiers::Verifier;
+
+    include!("resources.rs");
+
+    struct Mock;
+
+    impl crate::Config for Mock {
+        type MaxProofSize = sp_core::ConstU32<1000000>;
+        type MaxPubsSize = sp_core::ConstU32<100>;
+    }
+
+    static RECEIPT: &[u8] = include_bytes!("resources/receipt.bin");
+
+    #[test]
+    fn import_a_valid_receipt() {
+        let (proof, pubs) = receipt_from_bincode(RECEIPT).unwrap();
+
+        assert_eq!(
+            proof,
+            Proof::V1_0(ProofReceipt::from_inner_receipt(&VALID_PROOF).unwrap())
+        );
+        assert_eq!(pubs, VALID_PUBS.to_vec());
+        assert!(Risc0::<Mock>::verify_proof(&VALID_VK, &proof, &pubs).is_ok());
+    }
+
+    #[test]
+    fn reject_a_truncated_receipt() {
+        assert!(matches!(
+            receipt_from_bincode(&RECEIPT[..RECEIPT.len() / 2]),
+            Err(FormatError::InvalidReceipt { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_image_id() {
+        let hex = format!("{VALID_VK:?}");
+
+        assert_eq!(image_id_from_hex(&hex).unwrap(), VALID_VK);
+        assert_eq!(image_id_from_hex(&hex[2..]).unwrap(), VALID_VK);
+        assert!(image_id_from_hex("0x1234").is_err());
+    }
+}
@@ -17,15 +17,22 @@
 
 use core::marker::PhantomData;
 
+use codec::{Decode, Encode};
 use frame_support::{ensure, weights::Weight};
 use hp_verifiers::Verifier;
+use scale_info::TypeInfo;
 use sp_core::{Get, H256};
 use sp_std::vec::Vec;
 
 pub mod benchmarking;
+#[cfg(feature = "std")]
+pub mod formats;
+mod receipt;
 mod verifier_should;
 mod weight;
 
+pub use receipt::Receipt;
+
 pub trait Config: 'static {
     /// Maximum number of bytes contained in the proof (otherwise rejected)
     type MaxProofSize: Get<u32>;
@@ -45,8 +52,41 @@ pub trait Config: 'static {
 pub struct Risc0<T>;
 pub use weight::WeightInfo;
 
-pub type Proof = Vec<u8>;
+/// A risc0 receipt, tagged with the risc0 zkVM release that generated it.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum Proof {
+    V1_0(Receipt),
+    V1_1(Receipt),
+    V1_2(Receipt),
+}
+
+impl Proof {
+    pub fn version(&self) -> native::Risc0Version {
+        match self {
+            Proof::V1_0(_) => native::Risc0Version::V1_0,
+            Proof::V1_1(_) => native::Risc0Version::V1_1,
+            Proof::V1_2(_) => native::Risc0Version::V1_2,
+        }
+    }
+
+    pub fn receipt(&self) -> &Receipt {
+        match self {
+            Proof::V1_0(receipt) | Proof::V1_1(receipt) | Proof::V1_2(receipt) => receipt,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.receipt().bytes().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.receipt().bytes().is_empty()
+    }
+}
+
+/// The bincode serialized journal.
 pub type Pubs = Vec<u8>;
+/// The image id.
 pub type Vk = H256;
 
 impl<T: Config> Verifier for Risc0<T> {
@@ -75,7 +115,14 @@ impl<T: Config> Verifier for Risc0<T> {
             hp_verifiers::VerifyError::InvalidInput
         );
         log::trace!("Verifying (native)");
-        native::risc_0_verify::verify((*vk).into(), proof, pubs).map_err(Into::into)
+        native::risc_0_verify::verify(
+            proof.version(),
+            proof.receipt().kind(),
+            (*vk).into(),
+            proof.receipt().bytes(),
+            pubs,
+        )
+        .map_err(Into::into)
     }
 
     fn pubs_bytes(pubs: &Self::Pubs) -> hp_verifiers::Cow<[u8]> {
@@ -95,67 +142,80 @@ impl<T: Config> Verifier for Risc0<T> {
 /// benchmarks to the weight needed by the `pallet-verifiers`.
 pub struct Risc0Weight<W: weight::WeightInfo>(PhantomData<W>);
 
-pub static CYCLE_2_POW_FROM_12_TO_13: usize = 215538;
-pub static CYCLE_2_POW_FROM_14_TO_17: usize = 238578;
-pub static CYCLE_2_POW_FROM_18_TO_18: usize = 250290;
-pub static CYCLE_2_POW_FROM_19_TO_19: usize = 262514;
-pub static CYCLE_2_POW_FROM_20_TO_20: usize = 275762;
-pub static CYCLE_2_POW_FROM_21_TO_21: usize = 514256;
-pub static CYCLE_2_POW_FROM_22_TO_22: usize = 789934;
-pub static CYCLE_2_POW_FROM_23_TO_23: usize = 1353002;
-pub static CYCLE_2_POW_FROM_24_TO_24: usize = 2455714;
+/// The weights of the benchmarked single segment receipts, indexed by the segment `po2` from
+/// 16 (the smallest segment that risc0 generates) to 20 (the default segment limit).
+type SegmentWeights = [fn() -> Weight; 5];
+
+impl<W: weight::WeightInfo> Risc0Weight<W> {
+    fn segment(po2: u32, weights: &SegmentWeights) -> Weight {
+        let weight = weights[(po2.clamp(16, 20) - 16) as usize]();
+        if po2 > 20 {
+            // Bigger segments are not benchmarked: extrapolate with the last step.
+            let step = weights[4]().saturating_sub(weights[3]());
+            weight.saturating_add(step.saturating_mul((po2 - 20) as u64))
+        } else {
+            weight
+        }
+    }
+
+    fn receipt(receipt: &Receipt, weights: &SegmentWeights, worst: fn() -> Weight) -> Weight {
+        match receipt {
+            // Every segment is charged as a single segment submission: that counts the
+            // extrinsic overhead more than once, but it's an upper bound of the segments work.
+            Receipt::Composite(_) => receipt
+                .segments_po2()
+                .filter(|po2s| !po2s.is_empty())
+                .map(|po2s| {
+                    po2s.into_iter()
+                        .map(|po2| Self::segment(po2, weights))
+                        .fold(Weight::zero(), Weight::saturating_add)
+                })
+                // An undecodable receipt will be rejected, but we cannot know how much work
+                // the verifier does before: charge the worst case.
+                .unwrap_or_else(worst),
+            // NOT benchmarked: there is no succinct receipt fixture yet. Its seal is generated
+            // by the recursion circuit at po2 18, charge it as the biggest benchmarked segment
+            // to keep a margin till it is benchmarked.
+            Receipt::Succinct(_) => weights[4](),
+            // Verifying a Groth16 receipt is cheaper than any segment.
+            Receipt::Groth16(_) => weights[0](),
+        }
+    }
+}
 
 impl<T: Config, W: weight::WeightInfo> pallet_verifiers::WeightInfo<Risc0<T>> for Risc0Weight<W> {
     fn submit_proof(
         proof: &<Risc0<T> as hp_verifiers::Verifier>::Proof,
         _pubs: &<Risc0<T> as hp_verifiers::Verifier>::Pubs,
     ) -> Weight {
-        let len = proof.len();
-        if len <= CYCLE_2_POW_FROM_12_TO_13 {
-            W::submit_proof_cycle_2_pow_13()
-        } else if len <= CYCLE_2_POW_FROM_14_TO_17 {
-            W::submit_proof_cycle_2_pow_17()
-        } else if len <= CYCLE_2_POW_FROM_18_TO_18 {
-            W::submit_proof_cycle_2_pow_18()
-        } else if len <= CYCLE_2_POW_FROM_19_TO_19 {
-            W::submit_proof_cycle_2_pow_19()
-        } else if len <= CYCLE_2_POW_FROM_20_TO_20 {
-            W::submit_proof_cycle_2_pow_20()
-        } else if len <= CYCLE_2_POW_FROM_21_TO_21 {
-            W::submit_proof_cycle_2_pow_21()
-        } else if len <= CYCLE_2_POW_FROM_22_TO_22 {
-            W::submit_proof_cycle_2_pow_22()
-        } else if len <= CYCLE_2_POW_FROM_23_TO_23 {
-            W::submit_proof_cycle_2_pow_23()
-        } else {
-            W::submit_proof_cycle_2_pow_24()
-        }
+        Self::receipt(
+            proof.receipt(),
+            &[
+                W::submit_proof_cycle_2_pow_13,
+                W::submit_proof_cycle_2_pow_17,
+                W::submit_proof_cycle_2_pow_18,
+                W::submit_proof_cycle_2_pow_19,
+                W::submit_proof_cycle_2_pow_20,
+            ],
+            W::submit_proof_cycle_2_pow_24,
+        )
     }
 
     fn submit_proof_with_vk_hash(
         proof: &<Risc0<T> as hp_verifiers::Verifier>::Proof,
         _pubs: &<Risc0<T> as hp_verifiers::Verifier>::Pubs,
     ) -> Weight {
-        let len = proof.len();
-        if len <= CYCLE_2_POW_FROM_12_TO_13 {
-            W::submit_proof_with_vk_hash_cycle_2_pow_13()
-        } else if len <= CYCLE_2_POW_FROM_14_TO_17 {
-            W::submit_proof_with_vk_hash_cycle_2_pow_17()
-        } else if len <= CYCLE_2_POW_FROM_18_TO_18 {
-            W::submit_proof_with_vk_hash_cycle_2_pow_18()
-        } else if len <= CYCLE_2_POW_FROM_19_TO_19 {
-            W::submit_proof_with_vk_hash_cycle_2_pow_19()
-        } else if len <= CYCLE_2_POW_FROM_20_TO_20 {
-            W::submit_proof_with_vk_hash_cycle_2_pow_20()
-        } else if len <= CYCLE_2_POW_FROM_21_TO_21 {
-            W::submit_proof_with_vk_hash_cycle_2_pow_21()
-        } else if len <= CYCLE_2_POW_FROM_22_TO_22 {
-            W::submit_proof_with_vk_hash_cycle_2_pow_22()
-        } else if len <= CYCLE_2_POW_FROM_23_TO_23 {
-            W::submit_proof_with_vk_hash_cycle_2_pow_23()
-        } else {
-            W::submit_proof_with_vk_hash_cycle_2_pow_24()
-        }
+        Self::receipt(
+            proof.receipt(),
+            &[
+                W::submit_proof_with_vk_hash_cycle_2_pow_13,
+                W::submit_proof_with_vk_hash_cycle_2_pow_17,
+                W::submit_proof_with_vk_hash_cycle_2_pow_18,
+                W::submit_proof_with_vk_hash_cycle_2_pow_19,
+                W::submit_proof_with_vk_hash_cycle_2_pow_20,
+            ],
+            W::submit_proof_with_vk_hash_cycle_2_pow_24,
+        )
     }
 
     fn register_vk(_vk: &<Risc0<T> as hp_verifiers::Verifier>::Vk) -> Weight {
@@ -0,0 +1,174 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The risc0 receipt envelope and a minimal decoder of the bincode serialized composite receipt
+//! layout: it just walks the segments to read their cycles count, without any further check.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// The index of the `po2` word in a segment seal: it follows the rv32im circuit outputs.
+const SEAL_PO2_INDEX: usize = 138;
+
+/// A bincode serialized risc0 receipt, tagged with its kind. The bytes are the serialization of
+/// the `InnerReceipt` variant content (i.e. without the variant index).
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum Receipt {
+    Composite(Vec<u8>),
+    Succinct(Vec<u8>),
+    Groth16(Vec<u8>),
+}
+
+impl Receipt {
+    /// Split a bincode serialized `InnerReceipt` in its kind and its content: `None` if the
+    /// variant is not a supported kind (e.g. a `Fake` receipt).
+    pub fn from_inner_receipt(inner: &[u8]) -> Option<Self> {
+        let mut reader = Reader(inner);
+        let variant = reader.u32()?;
+        let bytes = reader.0.to_vec();
+        match variant {
+            0 => Some(Receipt::Composite(bytes)),
+            1 => Some(Receipt::Succinct(bytes)),
+            2 => Some(Receipt::Groth16(bytes)),
+            _ => None,
+        }
+    }
+
+    pub fn kind(&self) -> native::Risc0ReceiptKind {
+        match self {
+            Receipt::Composite(_) => native::Risc0ReceiptKind::Composite,
+            Receipt::Succinct(_) => native::Risc0ReceiptKind::Succinct,
+            Receipt::Groth16(_) => native::Risc0ReceiptKind::Groth16,
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        match self {
+            Receipt::Composite(data) | Receipt::Succinct(data) | Receipt::Groth16(data) => data,
+        }
+    }
+
+    /// The cycles count (as a power of two) of every segment of a composite receipt. `None` if
+    /// the receipt is not a composite one or it cannot be decoded.
+    pub fn segments_po2(&self) -> Option<Vec<u32>> {
+        match self {
+            Receipt::Composite(data) => composite_segments_po2(data),
+            _ => None,
+        }
+    }
+}
+
+fn composite_segments_po2(data: &[u8]) -> Option<Vec<u32>> {
+    let mut reader = Reader(data);
+    let segments = reader.u64()?;
+    let mut po2s = Vec::new();
+    for _ in 0..segments {
+        po2s.push(reader.segment()?);
+    }
+    Some(po2s)
+}
+
+/// A forward only reader of the bincode encoding (little endian fixed size integers and `u64`
+/// lengths).
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take(&mut self, len: usize) -> Option<&[u8]> {
+        (len <= self.0.len()).then(|| {
+            let (head, tail) = self.0.split_at(len);
+            self.0 = tail;
+            head
+        })
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)
+            .map(|b| u32::from_le_bytes(b.try_into().expect("4 bytes")))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take(8)
+            .map(|b| u64::from_le_bytes(b.try_into().expect("8 bytes")))
+    }
+
+    fn len(&mut self) -> Option<usize> {
+        self.u64().and_then(|len| len.try_into().ok())
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.take(len).map(|_| ())
+    }
+
+    /// A `SegmentReceipt`: return the `po2` read from its seal.
+    fn segment(&mut self) -> Option<u32> {
+        let seal_words = self.len()?;
+        let seal = self.take(seal_words.checked_mul(4)?)?;
+        let po2 = seal.chunks_exact(4).nth(SEAL_PO2_INDEX)?;
+        let po2 = u32::from_le_bytes(po2.try_into().expect("4 bytes"));
+        // index
+        self.skip(4)?;
+        // hashfn
+        let hashfn = self.len()?;
+        self.skip(hashfn)?;
+        // verifier parameters
+        self.skip(32)?;
+        self.claim()?;
+        Some(po2)
+    }
+
+    /// A `MaybePruned<T>`: the value is read by `value` and the pruned one is its digest.
+    fn maybe_pruned(&mut self, value: impl FnOnce(&mut Self) -> Option<()>) -> Option<()> {
+        match self.u32()? {
+            0 => value(self),
+            1 => self.skip(32),
+            _ => None,
+        }
+    }
+
+    /// A `ReceiptClaim`.
+    fn claim(&mut self) -> Option<()> {
+        // pre and post `SystemState`: pc and merkle root
+        self.maybe_pruned(|r| r.skip(4 + 32))?;
+        self.maybe_pruned(|r| r.skip(4 + 32))?;
+        // exit code: `Halted` and `Paused` carry the user exit code
+        if self.u32()? < 2 {
+            self.skip(4)?;
+        }
+        // input: no input is defined yet
+        self.maybe_pruned(|r| (r.u8()? == 0).then_some(()))?;
+        // output: journal and assumptions
+        self.maybe_pruned(|r| match r.u8()? {
+            0 => Some(()),
+            1 => {
+                r.maybe_pruned(|r| {
+                    let journal = r.len()?;
+                    r.skip(journal)
+                })?;
+                r.maybe_pruned(|r| {
+                    for _ in 0..r.u64()? {
+                        r.maybe_pruned(|r| r.skip(64))?;
+                    }
+                    Some(())
+                })
+            }
+            _ => None,
+        })
+    }
+}
@@ -28,13 +28,170 @@ impl Config for Mock {
 
 include!("resources.rs");
 
+fn valid_proof() -> Proof {
+    Proof::V1_0(Receipt::from_inner_receipt(&VALID_PROOF).unwrap())
+}
+
 #[test]
 fn verify_valid_proof() {
-    assert!(
-        Risc0::<Mock>::verify_proof(&VALID_VK, &VALID_PROOF.to_vec(), &VALID_PUBS.to_vec()).is_ok()
+    assert!(Risc0::<Mock>::verify_proof(&VALID_VK, &valid_proof(), &VALID_PUBS.to_vec()).is_ok());
+}
+
+#[test]
+fn map_the_proof_to_its_version_and_kind() {
+    let receipt = Receipt::Succinct(vec![1]);
+
+    assert_eq!(
+        Proof::V1_0(receipt.clone()).version(),
+        native::Risc0Version::V1_0
+    );
+    assert_eq!(
+        Proof::V1_1(receipt.clone()).version(),
+        native::Risc0Version::V1_1
+    );
+    assert_eq!(
+        Proof::V1_2(receipt.clone()).version(),
+        native::Risc0Version::V1_2
+    );
+    assert_eq!(
+        Receipt::Composite(vec![1]).kind(),
+        native::Risc0ReceiptKind::Composite
+    );
+    assert_eq!(receipt.kind(), native::Risc0ReceiptKind::Succinct);
+    assert_eq!(
+        Receipt::Groth16(vec![1]).kind(),
+        native::Risc0ReceiptKind::Groth16
     );
 }
 
+#[test]
+fn split_the_inner_receipt_in_kind_and_content() {
+    let receipt = Receipt::from_inner_receipt(&VALID_PROOF).unwrap();
+
+    assert_eq!(receipt, Receipt::Composite(VALID_PROOF[4..].to_vec()));
+    assert_eq!(
+        Receipt::from_inner_receipt(&[2, 0, 0, 0, 42]),
+        Some(Receipt::Groth16(vec![42]))
+    );
+    // `Fake` receipts are not supported
+    assert_eq!(Receipt::from_inner_receipt(&[3, 0, 0, 0]), None);
+}
+
+mod segments {
+    use super::*;
+
+    fn po2s(inner_receipt: &[u8]) -> Option<Vec<u32>> {
+        Receipt::from_inner_receipt(inner_receipt)
+            .unwrap()
+            .segments_po2()
+    }
+
+    #[test]
+    fn decode_a_single_segment() {
+        assert_eq!(po2s(&VALID_PROOF), Some(vec![16]));
+    }
+
+    #[test]
+    fn decode_many_segments() {
+        assert_eq!(
+            po2s(include_bytes!(
+                "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_22.bin"
+            )),
+            Some(vec![20, 20, 17])
+        );
+    }
+
+    #[test]
+    fn not_decode_a_truncated_receipt() {
+        assert_eq!(po2s(&VALID_PROOF[..VALID_PROOF.len() / 2]), None);
+    }
+
+    #[test]
+    fn not_decode_other_kinds() {
+        assert_eq!(
+            Receipt::Succinct(VALID_PROOF[4..].to_vec()).segments_po2(),
+            None
+        );
+    }
+}
+
+mod weights {
+    use super::*;
+
+    type W = Risc0Weight<()>;
+
+    fn submit(receipt: Receipt) -> Weight {
+        <W as pallet_verifiers::WeightInfo<Risc0<Mock>>>::submit_proof(
+            &Proof::V1_0(receipt),
+            &vec![],
+        )
+    }
+
+    #[test]
+    fn sum_the_segments_weight() {
+        let receipt = Receipt::from_inner_receipt(include_bytes!(
+            "resources_benchmarking/VALID_PROOF_CYCLE_2_POW_22.bin"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            submit(receipt),
+            <() as WeightInfo>::submit_proof_cycle_2_pow_20()
+                .saturating_mul(2)
+                .saturating_add(<() as WeightInfo>::submit_proof_cycle_2_pow_17())
+        );
+    }
+
+    #[test]
+    fn use_the_smallest_bracket_for_small_segments() {
+        let receipt = Receipt::from_inner_receipt(&VALID_PROOF).unwrap();
+
+        assert_eq!(
+            submit(receipt),
+            <() as WeightInfo>::submit_proof_cycle_2_pow_13()
+        );
+    }
+
+    #[test]
+    fn extrapolate_bigger_segments() {
+        let weights = [
+            <() as WeightInfo>::submit_proof_cycle_2_pow_13 as fn() -> Weight,
+            <() as WeightInfo>::submit_proof_cycle_2_pow_17,
+            <() as WeightInfo>::submit_proof_cycle_2_pow_18,
+            <() as WeightInfo>::submit_proof_cycle_2_pow_19,
+            <() as WeightInfo>::submit_proof_cycle_2_pow_20,
+        ];
+        let step = <() as WeightInfo>::submit_proof_cycle_2_pow_20()
+            .saturating_sub(<() as WeightInfo>::submit_proof_cycle_2_pow_19());
+
+        assert_eq!(
+            W::segment(22, &weights),
+            <() as WeightInfo>::submit_proof_cycle_2_pow_20()
+                .saturating_add(step.saturating_mul(2))
+        );
+    }
+
+    #[test]
+    fn charge_the_worst_case_for_undecodable_receipts() {
+        assert_eq!(
+            submit(Receipt::Composite(vec![1, 2, 3])),
+            <() as WeightInfo>::submit_proof_cycle_2_pow_24()
+        );
+    }
+
+    #[test]
+    fn use_fixed_weights_for_succinct_and_groth16_receipts() {
+        assert_eq!(
+            submit(Receipt::Succinct(vec![])),
+            <() as WeightInfo>::submit_proof_cycle_2_pow_20()
+        );
+        assert_eq!(
+            submit(Receipt::Groth16(vec![])),
+            <() as WeightInfo>::submit_proof_cycle_2_pow_13()
+        );
+    }
+}
+
 mod reject {
     use hp_verifiers::VerifyError;
 
@@ -46,18 +203,17 @@ mod reject {
         invalid_pubs[invalid_pubs.len() - 1] = invalid_pubs[invalid_pubs.len() - 1].wrapping_add(1);
 
         assert_eq!(
-            Risc0::<Mock>::verify_proof(&VALID_VK, &VALID_PROOF.to_vec(), &invalid_pubs.to_vec()),
+            Risc0::<Mock>::verify_proof(&VALID_VK, &valid_proof(), &invalid_pubs.to_vec()),
             Err(VerifyError::VerifyError)
         )
     }
 
     #[test]
     fn undeserializable_proof() {
-        let mut malformed_proof = VALID_PROOF.clone();
-        malformed_proof[0] = malformed_proof[0].wrapping_add(1);
+        let malformed_proof = Proof::V1_0(Receipt::Succinct(VALID_PROOF[4..].to_vec()));
 
         assert_eq!(
-            Risc0::<Mock>::verify_proof(&VALID_VK, &malformed_proof.to_vec(), &VALID_PUBS.to_vec()),
+            Risc0::<Mock>::verify_proof(&VALID_VK, &malformed_proof, &VALID_PUBS.to_vec()),
             Err(VerifyError::InvalidProofData)
         )
     }
@@ -68,14 +224,15 @@ mod reject {
         malformed_pubs[0] = malformed_pubs[0].wrapping_add(1);
 
         assert_eq!(
-            Risc0::<Mock>::verify_proof(&VALID_VK, &VALID_PROOF.to_vec(), &malformed_pubs.to_vec()),
+            Risc0::<Mock>::verify_proof(&VALID_VK, &valid_proof(), &malformed_pubs.to_vec()),
             Err(VerifyError::InvalidInput)
         )
     }
 
     #[test]
     fn too_big_proof() {
-        let too_big_proof = vec![0; Mock::max_proof_size() as usize + 1];
+        let too_big_receipt = Receipt::Composite(vec![0; Mock::max_proof_size() as usize + 1]);
+        let too_big_proof = Proof::V1_0(too_big_receipt);
 
         assert_eq!(
             Risc0::<Mock>::verify_proof(&VALID_VK, &too_big_proof, &VALID_PUBS.to_vec()),
@@ -88,8 +245,52 @@ mod reject {
         let too_big_pubs = vec![0; Mock::max_pubs_size() as usize + 1];
 
         assert_eq!(
-            Risc0::<Mock>::verify_proof(&VALID_VK, &VALID_PROOF.to_vec(), &too_big_pubs),
+            Risc0::<Mock>::verify_proof(&VALID_VK, &valid_proof(), &too_big_pubs),
             Err(VerifyError::InvalidInput)
         )
     }
 }
+
+mod conformance {
+    use super::*;
+
+    type Risc0Mock = Risc0<Mock>;
+
+    pub struct Risc0Fixtures;
+
+    impl pallet_verifiers_test_kit::Fixtures<Risc0Mock> for Risc0Fixtures {
+        type WeightInfo = Risc0Weight<()>;
+
+        fn valid() -> (Vk, Proof, Pubs) {
+            (VALID_VK, valid_proof(), VALID_PUBS.to_vec())
+        }
+
+        fn invalid() -> Vec<(Vk, Proof, Pubs)> {
+            let mut invalid_pubs = VALID_PUBS.to_vec();
+            let last = invalid_pubs.len() - 1;
+            invalid_pubs[last] = invalid_pubs[last].wrapping_add(1);
+            let malformed_proof = Proof::V1_0(Receipt::Succinct(VALID_PROOF[4..].to_vec()));
+            vec![
+                (VALID_VK, valid_proof(), invalid_pubs),
+                (VALID_VK, malformed_proof, VALID_PUBS.to_vec()),
+            ]
+        }
+
+        fn by_size() -> Vec<(Proof, Pubs)> {
+            [
+                include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_12.bin").as_slice(),
+                include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_16.bin"),
+                include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_20.bin"),
+                include_bytes!("resources_benchmarking/VALID_PROOF_CYCLE_2_POW_22.bin"),
+            ]
+            .into_iter()
+            .map(|inner_receipt| {
+                let receipt = Receipt::from_inner_receipt(inner_receipt).unwrap();
+                (Proof::V1_0(receipt), VALID_PUBS.to_vec())
+            })
+            .collect()
+        }
+    }
+
+    pallet_verifiers_test_kit::verifier_conformance!(Risc0Mock, Risc0Fixtures);
+}
@@ -0,0 +1,176 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+
+use codec::{Decode, Encode};
+use frame_support::{ensure, weights::Weight};
+use hp_verifiers::Verifier;
+use scale_info::TypeInfo;
+use sp_core::{Get, H256};
+use sp_std::vec::Vec;
+
+mod verifier_should;
+mod weight;
+
+pub trait Config: 'static {
+    /// Maximum number of bytes contained in the proof (otherwise rejected)
+    type MaxProofSize: Get<u32>;
+    /// Maximum number of bytes contained in the public inputs (otherwise rejected)
+    type MaxPubsSize: Get<u32>;
+
+    fn max_proof_size() -> u32 {
+        Self::MaxProofSize::get()
+    }
+
+    fn max_pubs_size() -> u32 {
+        Self::MaxPubsSize::get()
+    }
+}
+
+#[pallet_verifiers::verifier]
+pub struct Sp1<T>;
+pub use weight::WeightInfo;
+
+/// A bincode serialized `SP1Proof` (either `Core` or `Compressed`), tagged with the SP1 version
+/// that generated it.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum Proof {
+    V3_0(Vec<u8>),
+    V4_0(Vec<u8>),
+}
+
+impl Proof {
+    pub fn version(&self) -> native::Sp1Version {
+        match self {
+            Proof::V3_0(_) => native::Sp1Version::V3_0,
+            Proof::V4_0(_) => native::Sp1Version::V4_0,
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        match self {
+            Proof::V3_0(data) | Proof::V4_0(data) => data,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes().is_empty()
+    }
+}
+
+/// The committed public values.
+pub type Pubs = Vec<u8>;
+/// The program vkey hash.
+pub type Vk = H256;
+
+impl<T: Config> Verifier for Sp1<T> {
+    type Proof = Proof;
+
+    type Pubs = Pubs;
+
+    type Vk = Vk;
+
+    fn hash_context_data() -> &'static [u8] {
+        b"sp1"
+    }
+
+    fn verify_proof(
+        vk: &Self::Vk,
+        proof: &Self::Proof,
+        pubs: &Self::Pubs,
+    ) -> Result<(), hp_verifiers::VerifyError> {
+        log::trace!("Checking size");
+        ensure!(
+            proof.len() <= T::MaxProofSize::get() as usize,
+            hp_verifiers::VerifyError::InvalidProofData
+        );
+        ensure!(
+            pubs.len() <= T::MaxPubsSize::get() as usize,
+            hp_verifiers::VerifyError::InvalidInput
+        );
+        log::trace!("Verifying (native)");
+        native::sp_1_verify::verify(proof.version(), (*vk).into(), proof.bytes(), pubs)
+            .map_err(Into::into)
+    }
+
+    fn pubs_bytes(pubs: &Self::Pubs) -> hp_verifiers::Cow<[u8]> {
+        hp_verifiers::Cow::Borrowed(pubs)
+    }
+
+    fn vk_hash(vk: &Self::Vk) -> H256 {
+        *vk
+    }
+
+    fn vk_bytes(_vk: &Self::Vk) -> hp_verifiers::Cow<[u8]> {
+        panic!("SP1 vk is already hashed and we cannot know its preimage: use vk_hash() instead")
+    }
+}
+
+/// The struct to use in runtime pallet configuration to map the weight computed by this crate
+/// benchmarks to the weight needed by the `pallet-verifiers`.
+pub struct Sp1Weight<W: weight::WeightInfo>(PhantomData<W>);
+
+pub static PROOF_SIZE_2_POW_19: usize = 1 << 19;
+pub static PROOF_SIZE_2_POW_20: usize = 1 << 20;
+pub static PROOF_SIZE_2_POW_21: usize = 1 << 21;
+
+impl<T: Config, W: weight::WeightInfo> pallet_verifiers::WeightInfo<Sp1<T>> for Sp1Weight<W> {
+    fn submit_proof(
+        proof: &<Sp1<T> as hp_verifiers::Verifier>::Proof,
+        _pubs: &<Sp1<T> as hp_verifiers::Verifier>::Pubs,
+    ) -> Weight {
+        let len = proof.len();
+        if len <= PROOF_SIZE_2_POW_19 {
+            W::submit_proof_size_2_pow_19()
+        } else if len <= PROOF_SIZE_2_POW_20 {
+            W::submit_proof_size_2_pow_20()
+        } else if len <= PROOF_SIZE_2_POW_21 {
+            W::submit_proof_size_2_pow_21()
+        } else {
+            W::submit_proof_size_2_pow_22()
+        }
+    }
+
+    fn submit_proof_with_vk_hash(
+        proof: &<Sp1<T> as hp_verifiers::Verifier>::Proof,
+        _pubs: &<Sp1<T> as hp_verifiers::Verifier>::Pubs,
+    ) -> Weight {
+        let len = proof.len();
+        if len <= PROOF_SIZE_2_POW_19 {
+            W::submit_proof_with_vk_hash_size_2_pow_19()
+        } else if len <= PROOF_SIZE_2_POW_20 {
+            W::submit_proof_with_vk_hash_size_2_pow_20()
+        } else if len <= PROOF_SIZE_2_POW_21 {
+            W::submit_proof_with_vk_hash_size_2_pow_21()
+        } else {
+            W::submit_proof_with_vk_hash_size_2_pow_22()
+        }
+    }
+
+    fn register_vk(_vk: &<Sp1<T> as hp_verifiers::Verifier>::Vk) -> Weight {
+        W::register_vk()
+    }
+
+    fn unregister_vk() -> frame_support::weights::Weight {
+        W::unregister_vk()
+    }
+}
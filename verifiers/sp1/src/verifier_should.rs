@@ -0,0 +1,96 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use hp_verifiers::VerifyError;
+use sp_core::ConstU32;
+
+use super::*;
+
+struct Mock;
+
+impl Config for Mock {
+    type MaxProofSize = ConstU32<{ 1 << 22 }>;
+    type MaxPubsSize = ConstU32<64>;
+}
+
+#[test]
+fn use_the_vk_as_its_hash() {
+    let vk = H256::repeat_byte(0x42);
+
+    assert_eq!(Sp1::<Mock>::vk_hash(&vk), vk);
+}
+
+#[test]
+fn return_the_public_values_as_bytes() {
+    let pubs = vec![1, 2, 3];
+
+    assert_eq!(Sp1::<Mock>::pubs_bytes(&pubs).as_ref(), pubs.as_slice());
+}
+
+#[test]
+fn map_the_proof_to_its_version() {
+    assert_eq!(Proof::V3_0(vec![1]).version(), native::Sp1Version::V3_0);
+    assert_eq!(Proof::V4_0(vec![1]).version(), native::Sp1Version::V4_0);
+}
+
+#[test]
+fn select_the_weight_bracket_by_proof_size() {
+    use pallet_verifiers::WeightInfo as _;
+    type W = Sp1Weight<()>;
+    let submit = |len: usize| W::submit_proof(&Proof::V4_0(vec![0; len]), &vec![]);
+
+    assert_eq!(
+        submit(PROOF_SIZE_2_POW_19),
+        <() as WeightInfo>::submit_proof_size_2_pow_19()
+    );
+    assert_eq!(
+        submit(PROOF_SIZE_2_POW_19 + 1),
+        <() as WeightInfo>::submit_proof_size_2_pow_20()
+    );
+    assert_eq!(
+        submit(PROOF_SIZE_2_POW_21),
+        <() as WeightInfo>::submit_proof_size_2_pow_21()
+    );
+    assert_eq!(
+        submit(PROOF_SIZE_2_POW_21 + 1),
+        <() as WeightInfo>::submit_proof_size_2_pow_22()
+    );
+}
+
+mod reject {
+    use super::*;
+
+    #[test]
+    fn too_big_proof() {
+        let too_big_proof = Proof::V4_0(vec![0; Mock::max_proof_size() as usize + 1]);
+
+        assert_eq!(
+            Sp1::<Mock>::verify_proof(&H256::zero(), &too_big_proof, &vec![]),
+            Err(VerifyError::InvalidProofData)
+        )
+    }
+
+    #[test]
+    fn too_big_pubs() {
+        let too_big_pubs = vec![0; Mock::max_pubs_size() as usize + 1];
+
+        assert_eq!(
+            Sp1::<Mock>::verify_proof(&H256::zero(), &Proof::V4_0(vec![]), &too_big_pubs),
+            Err(VerifyError::InvalidInput)
+        )
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+//! The benchmarks verify the recorded TDX attestation, whose quote body is larger than the SGX
+//! one, at a time its collateral is valid: they expect `UnixTime` to be `pallet_timestamp`.
+
+use super::{proof_size, Proof, Tee};
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Consideration, Footprint};
+use frame_system::RawOrigin;
+use hp_verifiers::Verifier;
+use pallet_aggregate::{funded_account, insert_domain};
+use pallet_verifiers::{Tickets, VkEntry, VkOrHash, Vks};
+use sp_core::Get;
+
+include!("resources.rs");
+
+pub struct Pallet<T: Config>(crate::Pallet<T>);
+
+pub trait Config: crate::Config {}
+impl<T: crate::Config> Config for T {}
+pub type Call<T> = pallet_verifiers::Call<T, Tee<T>>;
+
+fn init<T: pallet_aggregate::Config + pallet_timestamp::Config<Moment = u64>>(
+) -> (T::AccountId, u32) {
+    pallet_timestamp::Pallet::<T>::set_timestamp(NOW * 1000);
+    let caller: T::AccountId = funded_account::<T>();
+    let domain_id = 1;
+    insert_domain::<T>(domain_id, caller.clone(), Some(1));
+    (caller, domain_id)
+}
+
+/// Pad the attestation up to `size` encoded bytes with trailing new lines after the PEM chain
+/// of the TCB signing certificate: they are scanned but don't change the verification result.
+fn padded(mut proof: Proof, size: u32) -> Proof {
+    let len = proof.collateral.tcb_signing_chain.len();
+    let pad = size.saturating_sub(proof_size(&proof)) as usize;
+    proof.collateral.tcb_signing_chain.resize(len + pad, b'\n');
+    // The length prefix of the chain can grow with the padding.
+    while proof_size(&proof) > size && proof.collateral.tcb_signing_chain.len() > len {
+        proof.collateral.tcb_signing_chain.pop();
+    }
+    proof
+}
+
+#[benchmarks(
+    where T: pallet_verifiers::Config<Tee<T>>
+        + pallet_aggregate::Config
+        + pallet_timestamp::Config<Moment = u64>
+)]
+mod benchmarks {
+
+    use super::*;
+
+    #[benchmark]
+    fn submit_proof(p: Linear<0, { T::MaxProofSize::get() }>) {
+        // setup code
+        let (caller, domain_id) = init::<T>();
+
+        let (vk, proof, pubs) = tdx_instance();
+        let proof = padded(proof, p);
+
+        #[extrinsic_call]
+        submit_proof(
+            RawOrigin::Signed(caller),
+            VkOrHash::from_vk(vk),
+            proof.into(),
+            pubs.into(),
+            Some(domain_id),
+        );
+    }
+
+    #[benchmark]
+    fn submit_proof_with_vk_hash(p: Linear<0, { T::MaxProofSize::get() }>) {
+        // setup code
+        let (caller, domain_id) = init::<T>();
+
+        let (vk, proof, pubs) = tdx_instance();
+        let proof = padded(proof, p);
+        let vk_hash = sp_core::H256::repeat_byte(2);
+        let vk_entry = VkEntry::new(vk);
+        Vks::<T, Tee<T>>::insert(vk_hash, vk_entry);
+
+        #[extrinsic_call]
+        submit_proof(
+            RawOrigin::Signed(caller),
+            VkOrHash::from_hash(vk_hash),
+            proof.into(),
+            pubs.into(),
+            Some(domain_id),
+        );
+    }
+
+    #[benchmark]
+    fn register_vk() {
+        // setup code
+        let caller: T::AccountId = funded_account::<T>();
+        let (vk, _, _) = tdx_instance();
+
+        #[extrinsic_call]
+        register_vk(RawOrigin::Signed(caller), vk.clone().into());
+
+        // Verify
+        assert!(Vks::<T, Tee<T>>::get(Tee::<T>::vk_hash(&vk)).is_some());
+    }
+
+    #[benchmark]
+    fn unregister_vk() {
+        // setup code
+        let caller: T::AccountId = funded_account::<T>();
+        let hash = sp_core::H256::repeat_byte(2);
+        let (vk, _, _) = tdx_instance();
+        let vk_entry = VkEntry::new(vk);
+        let footprint = Footprint::from_encodable(&vk_entry);
+        let ticket = T::Ticket::new(&caller, footprint).unwrap();
+
+        Vks::<T, Tee<T>>::insert(hash, vk_entry);
+        Tickets::<T, Tee<T>>::insert((caller.clone(), hash), ticket);
+
+        #[extrinsic_call]
+        unregister_vk(RawOrigin::Signed(caller), hash);
+    }
+
+    impl_benchmark_test_suite!(Pallet, super::mock::test_ext(), super::mock::Test);
+}
+
+#[cfg(test)]
+mod mock {
+    use frame_support::{
+        derive_impl, parameter_types,
+        sp_runtime::{traits::IdentityLookup, BuildStorage},
+        traits::{fungible::HoldConsideration, EnsureOrigin, LinearStoragePrice},
+    };
+    use sp_core::{ConstU128, ConstU32, ConstU64};
+
+    type Balance = u128;
+    type AccountId = u64;
+
+    // Configure a mock runtime to test the pallet.
+    frame_support::construct_runtime!(
+        pub enum Test
+        {
+            System: frame_system,
+            Balances: pallet_balances,
+            Timestamp: pallet_timestamp,
+            CommonVerifiersPallet: pallet_verifiers::common,
+            VerifierPallet: crate,
+            Aggregate: pallet_aggregate,
+        }
+    );
+
+    impl crate::Config for Test {
+        type MaxProofSize = ConstU32<{ 64 * 1024 }>;
+        type UnixTime = Timestamp;
+    }
+
+    #[derive_impl(frame_system::config_preludes::SolochainDefaultConfig as frame_system::DefaultConfig)]
+    impl frame_system::Config for Test {
+        type Block = frame_system::mocking::MockBlockU32<Test>;
+        type AccountId = AccountId;
+        type AccountData = pallet_balances::AccountData<Balance>;
+        type Lookup = IdentityLookup<Self::AccountId>;
+    }
+
+    impl pallet_timestamp::Config for Test {
+        type Moment = u64;
+        type OnTimestampSet = ();
+        type MinimumPeriod = ConstU64<1>;
+        type WeightInfo = ();
+    }
+
+    parameter_types! {
+        pub const BaseDeposit: Balance = 1;
+        pub const PerByteDeposit: Balance = 2;
+        pub const HoldReasonVkRegistration: RuntimeHoldReason = RuntimeHoldReason::CommonVerifiersPallet(pallet_verifiers::common::HoldReason::VkRegistration);
+    }
+
+    impl pallet_verifiers::Config<crate::Tee<Test>> for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<Aggregate>;
+        type OnProofRejected = ();
+        type WeightInfo = crate::TeeWeight<()>;
+        type MaxBatchSize = ConstU32<16>;
+        type Ticket = HoldConsideration<
+            AccountId,
+            Balances,
+            HoldReasonVkRegistration,
+            LinearStoragePrice<BaseDeposit, PerByteDeposit, Balance>,
+        >;
+        type Currency = Balances;
+    }
+
+    impl pallet_balances::Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type RuntimeHoldReason = RuntimeHoldReason;
+        type RuntimeFreezeReason = RuntimeFreezeReason;
+        type WeightInfo = ();
+        type Balance = Balance;
+        type DustRemoval = ();
+        type ExistentialDeposit = ConstU128<1>;
+        type AccountStore = System;
+        type ReserveIdentifier = [u8; 8];
+        type FreezeIdentifier = RuntimeFreezeReason;
+        type MaxLocks = ConstU32<10>;
+        type MaxReserves = ConstU32<10>;
+        type MaxFreezes = ConstU32<10>;
+    }
+
+    impl pallet_verifiers::common::Config for Test {
+        type CommonWeightInfo = Test;
+        type StatementRetention = ();
+    }
+
+    pub struct NoManager;
+    impl EnsureOrigin<RuntimeOrigin> for NoManager {
+        type Success = ();
+
+        fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+            Err(o)
+        }
+
+        fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+            Err(())
+        }
+    }
+
+    impl pallet_aggregate::Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type RuntimeHoldReason = RuntimeHoldReason;
+        type AggregationSize = ConstU32<32>;
+        type MaxPendingPublishQueueSize = ConstU32<16>;
+        type ManagerOrigin = NoManager;
+        type Hold = Balances;
+        type Consideration = ();
+        type EstimateCallFee = ConstU32<1_000_000>;
+        type ComputePublisherTip = ();
+        type WeightInfo = ();
+        const AGGREGATION_SIZE: u32 = 32;
+        type Currency = Balances;
+    }
+
+    /// Build genesis storage according to the mock runtime.
+    pub fn test_ext() -> sp_io::TestExternalities {
+        let mut ext = sp_io::TestExternalities::from(
+            frame_system::GenesisConfig::<Test>::default()
+                .build_storage()
+                .unwrap(),
+        );
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
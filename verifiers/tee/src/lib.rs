@@ -0,0 +1,150 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A verifier for Intel DCAP attestations: the proof is an ECDSA quote of an SGX enclave or of
+//! a TDX trust domain, together with the collateral served by the Intel PCS, and the public
+//! inputs are the 64 bytes of report data bound to the quote by the attested workload. The
+//! verification key is the policy: the trusted root CAs, the accepted TCB statuses and,
+//! optionally, the expected workload measurement.
+//!
+//! The collateral is checked against the block timestamp, so an attestation is accepted only
+//! while its certificates, CRLs, TCB info and QE identity are valid.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{ensure, traits::UnixTime, weights::Weight, BoundedVec};
+use hp_verifiers::{Cow, Verifier, VerifyError};
+use scale_info::TypeInfo;
+use sp_core::{ConstU32, Get};
+use sp_std::marker::PhantomData;
+
+pub mod benchmarking;
+mod verifier_should;
+mod weight;
+pub use weight::WeightInfo;
+
+pub use hp_tee::{
+    Attestation, Collateral, Fingerprint, Measurement, ReportData, TcbStatus, MAX_ROOTS,
+    REPORT_DATA_SIZE,
+};
+
+/// Largest number of accepted TCB statuses: one for each status.
+pub const MAX_TCB_STATUSES: u32 = 7;
+
+pub trait Config: 'static {
+    /// Maximum size in bytes of the encoded attestation: the quote and its collateral.
+    type MaxProofSize: Get<u32>;
+    /// The source of the verification time.
+    type UnixTime: UnixTime;
+}
+
+/// The verification policy.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Vk {
+    /// The fingerprints (SHA-256 of the DER encoding) of the trusted root CAs.
+    pub roots: BoundedVec<Fingerprint, ConstU32<MAX_ROOTS>>,
+    /// The accepted TCB statuses.
+    pub accepted: BoundedVec<TcbStatus, ConstU32<MAX_TCB_STATUSES>>,
+    /// The expected workload: if `None` any enclave or trust domain is accepted and the report
+    /// data alone should identify the workload.
+    pub measurement: Option<Measurement>,
+}
+
+impl From<&Vk> for hp_tee::Policy {
+    fn from(vk: &Vk) -> Self {
+        hp_tee::Policy {
+            roots: vk.roots.to_vec(),
+            accepted: vk.accepted.to_vec(),
+            measurement: vk.measurement,
+        }
+    }
+}
+
+/// The attestation: the raw quote and its collateral.
+pub type Proof = Attestation;
+/// The report data.
+pub type Pubs = ReportData;
+
+#[pallet_verifiers::verifier]
+pub struct Tee<T>;
+
+impl<T: Config> Verifier for Tee<T> {
+    type Proof = Proof;
+
+    type Pubs = Pubs;
+
+    type Vk = Vk;
+
+    fn hash_context_data() -> &'static [u8] {
+        b"tee"
+    }
+
+    fn verify_proof(
+        vk: &Self::Vk,
+        proof: &Self::Proof,
+        pubs: &Self::Pubs,
+    ) -> Result<(), VerifyError> {
+        ensure!(
+            proof_size(proof) <= T::MaxProofSize::get(),
+            VerifyError::InvalidProofData
+        );
+        let now = T::UnixTime::now().as_secs();
+        log::trace!("Verifying (native) at {now}");
+        native::tee_verify::verify(vk.into(), proof.clone(), pubs, now).map_err(Into::into)
+    }
+
+    fn validate_vk(vk: &Self::Vk) -> Result<(), VerifyError> {
+        hp_tee::validate_policy(&vk.into()).map_err(Into::into)
+    }
+
+    fn pubs_bytes(pubs: &Self::Pubs) -> Cow<[u8]> {
+        Cow::Borrowed(pubs)
+    }
+}
+
+/// The struct to use in runtime pallet configuration to map the weight computed by this crate
+/// benchmarks to the weight needed by the `pallet-verifiers`.
+pub struct TeeWeight<W: weight::WeightInfo>(PhantomData<W>);
+
+/// The signatures to check are always the same, but the quote and the collateral have to be
+/// hashed and parsed.
+fn proof_size(proof: &Proof) -> u32 {
+    proof.encoded_size().try_into().unwrap_or(u32::MAX)
+}
+
+impl<T: Config, W: weight::WeightInfo> pallet_verifiers::WeightInfo<Tee<T>> for TeeWeight<W> {
+    fn submit_proof(
+        proof: &<Tee<T> as Verifier>::Proof,
+        _pubs: &<Tee<T> as Verifier>::Pubs,
+    ) -> Weight {
+        W::submit_proof(proof_size(proof))
+    }
+
+    fn submit_proof_with_vk_hash(
+        proof: &<Tee<T> as Verifier>::Proof,
+        _pubs: &<Tee<T> as Verifier>::Pubs,
+    ) -> Weight {
+        W::submit_proof_with_vk_hash(proof_size(proof))
+    }
+
+    fn register_vk(_vk: &<Tee<T> as Verifier>::Vk) -> Weight {
+        W::register_vk()
+    }
+
+    fn unregister_vk() -> Weight {
+        W::unregister_vk()
+    }
+}
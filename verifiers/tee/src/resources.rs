@@ -0,0 +1,41 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// 2024-06-01T00:00:00Z: the recorded collateral is valid from 2024-05-25 to 2024-06-24.
+#[allow(dead_code)]
+const NOW: u64 = 1_717_200_000;
+
+#[allow(dead_code)]
+fn instances() -> sp_std::vec::Vec<(crate::Vk, crate::Proof, crate::Pubs)> {
+    use codec::Decode;
+
+    <sp_std::vec::Vec<(crate::Vk, crate::Proof, crate::Pubs)>>::decode(
+        &mut &include_bytes!("resources/attestations.bin")[..],
+    )
+    .expect("Valid instances. qed")
+}
+
+/// A valid SGX v3 quote of a production enclave, with a policy that accepts its TCB status
+/// (`SWHardeningNeeded`) and pins its `MRENCLAVE`.
+#[allow(dead_code)]
+fn sgx_instance() -> (crate::Vk, crate::Proof, crate::Pubs) {
+    instances().swap_remove(0)
+}
+
+/// A valid TDX v4 quote of an `UpToDate` platform, with a policy that pins the TD `MRTD`.
+#[allow(dead_code)]
+fn tdx_instance() -> (crate::Vk, crate::Proof, crate::Pubs) {
+    instances().swap_remove(1)
+}
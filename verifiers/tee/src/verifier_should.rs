@@ -0,0 +1,250 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(test)]
+
+use super::*;
+use core::time::Duration;
+include!("resources.rs");
+
+struct Clock<const SECS: u64>;
+
+impl<const SECS: u64> UnixTime for Clock<SECS> {
+    fn now() -> Duration {
+        Duration::from_secs(SECS)
+    }
+}
+
+struct Mock<const SECS: u64 = NOW>;
+
+impl<const SECS: u64> Config for Mock<SECS> {
+    type MaxProofSize = ConstU32<{ 16 * 1024 }>;
+    type UnixTime = Clock<SECS>;
+}
+
+#[test]
+fn verify_valid_sgx_attestation() {
+    let (vk, proof, pubs) = sgx_instance();
+
+    assert!(Tee::<Mock>::verify_proof(&vk, &proof, &pubs).is_ok());
+}
+
+#[test]
+fn verify_valid_tdx_attestation() {
+    let (vk, proof, pubs) = tdx_instance();
+
+    assert!(Tee::<Mock>::verify_proof(&vk, &proof, &pubs).is_ok());
+}
+
+#[test]
+fn validate_valid_vk() {
+    let (vk, _, _) = sgx_instance();
+
+    assert!(Tee::<Mock>::validate_vk(&vk).is_ok());
+}
+
+#[test]
+fn return_the_report_data_as_public_inputs_bytes() {
+    let (_, _, pubs) = sgx_instance();
+
+    assert_eq!(Tee::<Mock>::pubs_bytes(&pubs).as_ref(), pubs);
+}
+
+#[test]
+fn compute_weights_that_grow_with_the_attestation_size() {
+    use pallet_verifiers::WeightInfo as _;
+    type W = TeeWeight<()>;
+
+    let (_, proof, pubs) = sgx_instance();
+    let bigger_proof = Proof {
+        quote: [proof.quote.as_slice(), &[0; 100]].concat(),
+        ..proof.clone()
+    };
+
+    assert!(
+        W::submit_proof(&bigger_proof, &pubs).ref_time()
+            > W::submit_proof(&proof, &pubs).ref_time()
+    );
+}
+
+mod reject {
+    use super::*;
+
+    #[test]
+    fn too_big_attestation() {
+        let (vk, _, pubs) = sgx_instance();
+        let proof = Proof {
+            quote: vec![0; <Mock as Config>::MaxProofSize::get() as usize],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Tee::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::InvalidProofData)
+        );
+    }
+
+    #[test]
+    fn malformed_quote() {
+        let (vk, mut proof, pubs) = sgx_instance();
+        proof.quote.truncate(100);
+
+        assert_eq!(
+            Tee::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::InvalidProofData)
+        );
+    }
+
+    #[test]
+    fn tampered_quote() {
+        let (vk, mut proof, pubs) = sgx_instance();
+        // A byte of the enclave MRSIGNER: the quote signature doesn't match anymore.
+        proof.quote[48 + 128] ^= 1;
+
+        assert_eq!(
+            Tee::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn invalid_report_data() {
+        let (vk, proof, mut pubs) = tdx_instance();
+        pubs[0] ^= 1;
+
+        assert_eq!(
+            Tee::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn expired_collateral() {
+        const ONE_MONTH_LATER: u64 = NOW + 30 * 24 * 3600;
+        let (vk, proof, pubs) = sgx_instance();
+
+        assert_eq!(
+            Tee::<Mock<ONE_MONTH_LATER>>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn untrusted_root() {
+        let (mut vk, proof, pubs) = sgx_instance();
+        vk.roots[0] = [0; 32];
+
+        assert_eq!(
+            Tee::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn not_accepted_tcb_status() {
+        let (mut vk, proof, pubs) = sgx_instance();
+        vk.accepted = BoundedVec::truncate_from(vec![TcbStatus::UpToDate]);
+
+        assert_eq!(
+            Tee::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn another_workload() {
+        let (mut vk, proof, pubs) = tdx_instance();
+        vk.measurement = Some(Measurement::TdxTd([0; 48]));
+
+        assert_eq!(
+            Tee::<Mock>::verify_proof(&vk, &proof, &pubs),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn vk_that_accepts_nothing() {
+        let (vk, proof, pubs) = sgx_instance();
+        let no_roots = Vk {
+            roots: BoundedVec::new(),
+            ..vk.clone()
+        };
+        let just_revoked = Vk {
+            accepted: BoundedVec::truncate_from(vec![TcbStatus::Revoked]),
+            ..vk
+        };
+
+        for vk in [no_roots, just_revoked] {
+            assert_eq!(
+                Tee::<Mock>::validate_vk(&vk),
+                Err(VerifyError::InvalidVerificationKey)
+            );
+            assert_eq!(
+                Tee::<Mock>::verify_proof(&vk, &proof, &pubs),
+                Err(VerifyError::InvalidVerificationKey)
+            );
+        }
+    }
+}
+
+mod conformance {
+    use super::*;
+
+    type TeeMock = Tee<Mock>;
+
+    pub struct TeeFixtures;
+
+    impl pallet_verifiers_test_kit::Fixtures<TeeMock> for TeeFixtures {
+        type WeightInfo = TeeWeight<()>;
+
+        fn valid() -> (Vk, Proof, Pubs) {
+            sgx_instance()
+        }
+
+        fn invalid() -> Vec<(Vk, Proof, Pubs)> {
+            let (vk, proof, pubs) = sgx_instance();
+            let mut tampered_proof = proof.clone();
+            tampered_proof.quote[48 + 128] ^= 1;
+            let mut malformed_proof = proof.clone();
+            malformed_proof.quote.truncate(100);
+            let (tdx_vk, tdx_proof, mut invalid_pubs) = tdx_instance();
+            invalid_pubs[0] ^= 1;
+            vec![
+                (vk.clone(), tampered_proof, pubs.clone()),
+                (vk, malformed_proof, pubs),
+                (tdx_vk, tdx_proof, invalid_pubs),
+            ]
+        }
+
+        fn invalid_vks() -> Vec<Vk> {
+            let (vk, _, _) = sgx_instance();
+            vec![Vk {
+                roots: BoundedVec::new(),
+                ..vk
+            }]
+        }
+
+        fn by_size() -> Vec<(Proof, Pubs)> {
+            let (_, proof, pubs) = sgx_instance();
+            let bigger_proof = Proof {
+                quote: [proof.quote.as_slice(), &[0; 100]].concat(),
+                ..proof.clone()
+            };
+            vec![(proof, pubs.clone()), (bigger_proof, pubs)]
+        }
+    }
+
+    pallet_verifiers_test_kit::verifier_conformance!(TeeMock, TeeFixtures);
+}
@@ -0,0 +1,78 @@
+// Copyright 2024, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::*;
+
+struct MockRuntime;
+
+impl crate::Config for MockRuntime {
+    type MaxPubs = sp_core::ConstU32<10>;
+}
+
+#[test]
+fn return_the_concatenated_public_inputs_as_bytes() {
+    let pubs = vec![[1; PUBS_SIZE], [2; PUBS_SIZE]];
+
+    assert_eq!(
+        Ultrahonk::<MockRuntime>::pubs_bytes(&pubs).as_ref(),
+        pubs.concat()
+    );
+}
+
+#[test]
+fn compute_weights_that_grow_with_the_number_of_public_inputs() {
+    use pallet_verifiers::WeightInfo as _;
+    type W = UltrahonkWeight<()>;
+    let proof = vec![0; PROOF_SIZE];
+
+    assert!(
+        W::submit_proof(&proof, &vec![[0; PUBS_SIZE]; 8]).ref_time()
+            > W::submit_proof(&proof, &vec![[0; PUBS_SIZE]; 1]).ref_time()
+    );
+}
+
+mod reject {
+    use super::*;
+
+    #[test]
+    fn proof_with_wrong_size() {
+        for size in [0, PROOF_SIZE - 1, PROOF_SIZE + 1] {
+            assert_eq!(
+                Ultrahonk::<MockRuntime>::verify_proof(&[0; VK_SIZE], &vec![0; size], &vec![]),
+                Err(VerifyError::InvalidProofData)
+            );
+        }
+    }
+
+    #[test]
+    fn too_many_public_inputs() {
+        let pubs = vec![[0; PUBS_SIZE]; 11];
+
+        assert_eq!(
+            Ultrahonk::<MockRuntime>::verify_proof(&[0; VK_SIZE], &vec![0; PROOF_SIZE], &pubs),
+            Err(VerifyError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn malformed_vk() {
+        assert_eq!(
+            Ultrahonk::<MockRuntime>::validate_vk(&[0xff; VK_SIZE]),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2024, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Import the files written by Barretenberg `bb write_vk` and `bb prove`. The vk file is
+//! already the verifier key; the proof file is the public inputs (32 bytes each) followed by
+//! the proof.
+
+use crate::{Proof, Pubs, Vk, PROOF_SIZE, PUBS_SIZE, VK_SIZE};
+
+/// Why a bb file cannot be imported.
+#[derive(Debug, PartialEq, snafu::Snafu)]
+pub enum FormatError {
+    /// The vk file is not `VK_SIZE` bytes long.
+    #[snafu(display("Expected a {VK_SIZE} bytes vk but found {found} bytes"))]
+    InvalidVkSize { found: usize },
+    /// The proof file is not `PROOF_SIZE` bytes preceded by whole public inputs.
+    #[snafu(display("Invalid proof file size {found}"))]
+    InvalidProofSize { found: usize },
+}
+
+type Result<T> = core::result::Result<T, FormatError>;
+
+/// Import a `bb write_vk` file.
+pub fn vk_from_bb(vk: &[u8]) -> Result<Vk> {
+    vk.try_into()
+        .map_err(|_| FormatError::InvalidVkSize { found: vk.len() })
+}
+
+/// The number of public inputs declared in the verification key header.
+pub fn num_public_inputs(vk: &Vk) -> u32 {
+    // The header is circuit type, circuit size and number of public inputs as big endian u32.
+    u32::from_be_bytes(vk[8..12].try_into().expect("Slice length is fixed. qed"))
+}
+
+/// Split a `bb prove` file into the proof and the public inputs.
+pub fn proof_from_bb(proof: &[u8]) -> Result<(Proof, Pubs)> {
+    let pubs_len = proof
+        .len()
+        .checked_sub(PROOF_SIZE)
+        .filter(|len| len % PUBS_SIZE == 0)
+        .ok_or(FormatError::InvalidProofSize { found: proof.len() })?;
+    let (pubs, proof) = proof.split_at(pubs_len);
+    let pubs = pubs
+        .chunks_exact(PUBS_SIZE)
+        .map(|chunk| chunk.try_into().expect("Chunk length is fixed. qed"))
+        .collect();
+    Ok((proof.to_vec(), pubs))
+}
+
+/// Build the `bb prove` file of a proof and its public inputs.
+pub fn proof_to_bb(proof: &Proof, pubs: &Pubs) -> Vec<u8> {
+    pubs.iter().flatten().chain(proof.iter()).copied().collect()
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::Ultraplonk;
+    use hp_verifiers::Verifier;
+    use serial_test::serial;
+
+    struct MockRuntime;
+
+    impl crate::Config for MockRuntime {
+        type MaxPubs = sp_core::ConstU32<10>;
+    }
+
+    static VK: &[u8] = include_bytes!("resources/08_vk");
+    static BB_PROOF: &[u8] = include_bytes!("resources/08_bb_proof");
+
+    #[test]
+    #[serial]
+    fn import_a_valid_proof() {
+        let vk = vk_from_bb(VK).unwrap();
+        let (proof, pubs) = proof_from_bb(BB_PROOF).unwrap();
+
+        assert_eq!(proof, include_bytes!("resources/08_proof").to_vec());
+        assert_eq!(pubs.len() as u32, num_public_inputs(&vk));
+        assert!(Ultraplonk::<MockRuntime>::verify_proof(&vk, &proof, &pubs).is_ok());
+    }
+
+    #[test]
+    fn round_trip_the_proof_file() {
+        let (proof, pubs) = proof_from_bb(BB_PROOF).unwrap();
+
+        assert_eq!(proof_to_bb(&proof, &pubs), BB_PROOF);
+    }
+
+    #[test]
+    fn reject_invalid_sizes() {
+        assert_eq!(
+            vk_from_bb(&VK[1..]),
+            Err(FormatError::InvalidVkSize { found: VK_SIZE - 1 })
+        );
+        assert_eq!(
+            proof_from_bb(&BB_PROOF[1..]),
+            Err(FormatError::InvalidProofSize {
+                found: BB_PROOF.len() - 1
+            })
+        );
+        assert_eq!(
+            proof_from_bb(&BB_PROOF[..PROOF_SIZE - 1]),
+            Err(FormatError::InvalidProofSize {
+                found: PROOF_SIZE - 1
+            })
+        );
+    }
+}
@@ -34,6 +34,8 @@ pub trait Config: 'static {
 }
 
 pub mod benchmarking;
+#[cfg(feature = "std")]
+pub mod formats;
 mod verifier_should;
 pub mod weight;
 
@@ -192,3 +192,46 @@ mod reject {
         );
     }
 }
+
+mod conformance {
+    use super::*;
+
+    type UltraplonkMock = Ultraplonk<MockRuntime>;
+
+    pub struct UltraplonkFixtures;
+
+    impl pallet_verifiers_test_kit::Fixtures<UltraplonkMock> for UltraplonkFixtures {
+        type WeightInfo = UltraplonkWeight<()>;
+
+        fn valid() -> (Vk, Proof, Pubs) {
+            (VALID_VK, VALID_PROOF.to_vec(), public_input())
+        }
+
+        fn invalid() -> Vec<(Vk, Proof, Pubs)> {
+            let mut invalid_pubs = public_input();
+            invalid_pubs[0][0] = 0x10;
+            let mut invalid_proof = VALID_PROOF.to_vec();
+            invalid_proof[PROOF_SIZE - 1] = 0x00;
+            let mut small_proof = VALID_PROOF.to_vec();
+            small_proof.pop();
+            vec![
+                (VALID_VK, VALID_PROOF.to_vec(), invalid_pubs),
+                (VALID_VK, invalid_proof, public_input()),
+                (VALID_VK, small_proof, public_input()),
+            ]
+        }
+
+        fn invalid_vks() -> Vec<Vk> {
+            let mut vk = VALID_VK;
+            vk[0] = 0x10;
+            vec![vk]
+        }
+    }
+
+    pallet_verifiers_test_kit::verifier_conformance!(
+        UltraplonkMock,
+        UltraplonkFixtures,
+        verifier_conformance,
+        #[serial]
+    );
+}
@@ -47,7 +47,7 @@ mod benchmarks {
         // setup code
         let (caller, domain_id) = init::<T>();
 
-        let vk = VkOrHash::from_vk(());
+        let vk = VkOrHash::from_vk(Zksync::era_vk());
         let proof = PROOF.into();
         let pubs = PUBS.into();
 
@@ -63,7 +63,7 @@ mod benchmarks {
         let proof = PROOF.into();
         let pubs = PUBS.into();
         let hash = sp_core::H256::repeat_byte(2);
-        let vk_entry = VkEntry::new(());
+        let vk_entry = VkEntry::new(Zksync::era_vk());
         Vks::<T, Zksync>::insert(hash, vk_entry);
         let vk_or_hash = VkOrHash::from_hash(hash);
 
@@ -81,13 +81,13 @@ mod benchmarks {
     fn register_vk() {
         // setup code
         let caller: T::AccountId = funded_account::<T>();
-        let vk = ().into();
+        let vk = Zksync::era_vk();
 
         #[extrinsic_call]
-        register_vk(RawOrigin::Signed(caller), vk);
+        register_vk(RawOrigin::Signed(caller), vk.into());
 
         // Verify
-        assert!(Vks::<T, Zksync>::get(Zksync::vk_hash(&())).is_some());
+        assert!(Vks::<T, Zksync>::get(Zksync::vk_hash(&vk)).is_some());
     }
 
     #[benchmark]
@@ -95,8 +95,7 @@ mod benchmarks {
         // setup code
         let caller: T::AccountId = funded_account::<T>();
         let hash = sp_core::H256::repeat_byte(2);
-        let vk = ().into();
-        let vk_entry = VkEntry::new(vk);
+        let vk_entry = VkEntry::new(Zksync::era_vk());
         let footprint = Footprint::from_encodable(&vk_entry);
         let ticket = T::Ticket::new(&caller, footprint).unwrap();
 
@@ -149,8 +148,10 @@ mod mock {
 
     impl pallet_verifiers::Config<crate::Zksync> for Test {
         type RuntimeEvent = RuntimeEvent;
-        type OnProofVerified = Aggregate;
+        type OnProofVerified = pallet_verifiers::LegacyOnProofVerified<Aggregate>;
+        type OnProofRejected = ();
         type WeightInfo = crate::ZksyncWeight<()>;
+        type MaxBatchSize = ConstU32<16>;
         type Ticket = HoldConsideration<
             AccountId,
             Balances,
@@ -178,6 +179,7 @@ mod mock {
 
     impl pallet_verifiers::common::Config for Test {
         type CommonWeightInfo = Test;
+        type StatementRetention = ();
     }
 
     pub struct NoManager;
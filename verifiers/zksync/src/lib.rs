@@ -16,6 +16,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod benchmarking;
+pub mod migrations;
 mod verifier_should;
 mod weight;
 
@@ -26,6 +27,9 @@ use hp_verifiers::{Cow, Verifier, VerifyError};
 
 pub use native::ZKSYNC_PROOF_SIZE as PROOF_SIZE;
 pub use native::ZKSYNC_PUBS_SIZE as PUBS_SIZE;
+pub use native::ZKSYNC_VK_SIZE as VK_SIZE;
+
+pub type Vk = [u8; VK_SIZE];
 
 #[pallet_verifiers::verifier]
 pub struct Zksync;
@@ -36,29 +40,39 @@ impl Verifier for Zksync {
 
     type Pubs = [u8; PUBS_SIZE];
 
-    type Vk = ();
+    type Vk = Vk;
 
     fn hash_context_data() -> &'static [u8] {
         b"zksync"
     }
 
     fn verify_proof(
-        _vk: &Self::Vk,
+        vk: &Self::Vk,
         proof: &Self::Proof,
         pubs: &Self::Pubs,
     ) -> Result<(), VerifyError> {
-        native::zksync_verify::verify(proof, *pubs).map_err(Into::into)
+        native::zksync_verify::verify(vk, proof, *pubs).map_err(Into::into)
+    }
+
+    fn validate_vk(vk: &Self::Vk) -> Result<(), VerifyError> {
+        native::zksync_verify::validate_vk(vk).map_err(Into::into)
     }
 
     fn pubs_bytes(pubs: &Self::Pubs) -> Cow<[u8]> {
         Cow::Borrowed(pubs)
     }
 
-    fn vk_bytes(_vk: &Self::Vk) -> Cow<[u8]> {
-        // This verifier doesn't need any vk and the only admissible vk is the empty one.
-        // So, to make it in the same shape of the other verifiers, we return an empty array.
-        static VOID: &[u8] = &[];
-        Cow::Borrowed(VOID)
+    /// The vk is in the statement hash: before custom vks it was `()`, so the statements of the
+    /// proofs verified against the Era vk changed even if the proof and the pubs didn't.
+    fn vk_bytes(vk: &Self::Vk) -> Cow<[u8]> {
+        Cow::Borrowed(vk)
+    }
+}
+
+impl Zksync {
+    /// The verification key of the zkSync Era mainnet circuit.
+    pub fn era_vk() -> <Self as Verifier>::Vk {
+        native::zksync_verify::era_vk()
     }
 }
 
@@ -0,0 +1,31 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations.
+
+use pallet_verifiers::migrations::v3::VkTranslation;
+
+use crate::{Vk, Zksync};
+
+/// Translate the V2 `()` vks, which stood for the zkSync Era vk, to the encoded Era vk.
+pub struct SetEraVk;
+
+impl VkTranslation<Zksync> for SetEraVk {
+    type OldVk = ();
+
+    fn translate(_old: ()) -> Vk {
+        Zksync::era_vk()
+    }
+}
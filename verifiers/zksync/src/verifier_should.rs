@@ -17,12 +17,43 @@
 
 use hp_verifiers::Verifier;
 
-use crate::Zksync;
+use crate::{Zksync, VK_SIZE};
 include!("resources.rs");
 
 #[test]
 fn verify_valid_proof() {
-    assert!(Zksync::verify_proof(&(), &PROOF, &PUBS).is_ok());
+    assert!(Zksync::verify_proof(&Zksync::era_vk(), &PROOF, &PUBS).is_ok());
+}
+
+#[test]
+fn validate_era_vk() {
+    assert!(Zksync::validate_vk(&Zksync::era_vk()).is_ok());
+}
+
+#[test]
+fn return_the_same_bytes_as_vk() {
+    let vk = Zksync::era_vk();
+    assert_eq!(Zksync::vk_bytes(&vk).as_ref(), &vk);
+}
+
+#[test]
+fn zombienet_data_have_the_era_vk() {
+    let era_vk = Zksync::era_vk()
+        .iter()
+        .fold(String::from("0x"), |hex, b| hex + &format!("{b:02x}"));
+    let expected = format!("const VK = \"{era_vk}\"");
+
+    assert!(
+        include_str!("../../../zombienet-tests/js_scripts/zksync_data.js").contains(&expected),
+        "zombienet-tests/js_scripts/zksync_data.js should contain: {expected}"
+    );
+}
+
+#[test]
+fn translate_the_v2_vk_to_the_era_vk() {
+    use pallet_verifiers::migrations::v3::VkTranslation;
+
+    assert_eq!(crate::migrations::SetEraVk::translate(()), Zksync::era_vk());
 }
 
 #[test]
@@ -43,7 +74,7 @@ mod reject {
         invalid_pubs[0] = invalid_pubs[0].wrapping_add(1);
 
         assert_eq!(
-            Zksync::verify_proof(&(), &PROOF, &invalid_pubs),
+            Zksync::verify_proof(&Zksync::era_vk(), &PROOF, &invalid_pubs),
             Err(VerifyError::VerifyError)
         );
     }
@@ -54,19 +85,120 @@ mod reject {
         invalid_proof[730] = 0x00;
 
         assert_eq!(
-            Zksync::verify_proof(&(), &invalid_proof, &PUBS),
+            Zksync::verify_proof(&Zksync::era_vk(), &invalid_proof, &PUBS),
             Err(VerifyError::VerifyError)
         );
     }
 
+    #[test]
+    fn vk_of_another_circuit() {
+        let mut vk = Zksync::era_vk();
+        // Swap the first two gate setup commitments
+        let (first, second) = vk[96..].split_at_mut(64);
+        first.swap_with_slice(&mut second[..64]);
+
+        assert!(Zksync::validate_vk(&vk).is_ok());
+        assert_eq!(
+            Zksync::verify_proof(&vk, &PROOF, &PUBS),
+            Err(VerifyError::VerifyError)
+        );
+    }
+
+    #[test]
+    fn vk_with_invalid_domain_size() {
+        let mut vk = Zksync::era_vk();
+        vk[31] = vk[31].wrapping_add(1);
+
+        assert_eq!(
+            Zksync::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+        assert_eq!(
+            Zksync::verify_proof(&vk, &PROOF, &PUBS),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn vk_with_more_public_inputs() {
+        let mut vk = Zksync::era_vk();
+        vk[63] = 2;
+
+        assert_eq!(
+            Zksync::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn vk_with_more_lookup_entries_than_the_domain_size() {
+        let mut vk = Zksync::era_vk();
+        let domain_size: [u8; 32] = vk[..32].try_into().unwrap();
+        vk[64..96].copy_from_slice(&domain_size);
+
+        assert_eq!(
+            Zksync::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
+    #[test]
+    fn vk_with_point_not_on_curve() {
+        let mut vk = Zksync::era_vk();
+        vk[VK_SIZE - 1] = vk[VK_SIZE - 1].wrapping_add(1);
+
+        assert_eq!(
+            Zksync::validate_vk(&vk),
+            Err(VerifyError::InvalidVerificationKey)
+        );
+    }
+
     #[test]
     fn reject_malformed_proof() {
         let mut malformed_proof = PROOF;
         malformed_proof[0] = 0xff;
 
         assert_eq!(
-            Zksync::verify_proof(&(), &malformed_proof, &PUBS),
+            Zksync::verify_proof(&Zksync::era_vk(), &malformed_proof, &PUBS),
             Err(VerifyError::InvalidProofData)
         );
     }
 }
+
+mod conformance {
+    use super::*;
+    use crate::ZksyncWeight;
+
+    type Vk = <Zksync as Verifier>::Vk;
+    type Proof = <Zksync as Verifier>::Proof;
+    type Pubs = <Zksync as Verifier>::Pubs;
+
+    pub struct ZksyncFixtures;
+
+    impl pallet_verifiers_test_kit::Fixtures<Zksync> for ZksyncFixtures {
+        type WeightInfo = ZksyncWeight<()>;
+
+        fn valid() -> (Vk, Proof, Pubs) {
+            (Zksync::era_vk(), PROOF, PUBS)
+        }
+
+        fn invalid() -> Vec<(Vk, Proof, Pubs)> {
+            let mut invalid_pubs = PUBS;
+            invalid_pubs[0] = invalid_pubs[0].wrapping_add(1);
+            let mut malformed_proof = PROOF;
+            malformed_proof[0] = 0xff;
+            vec![
+                (Zksync::era_vk(), PROOF, invalid_pubs),
+                (Zksync::era_vk(), malformed_proof, PUBS),
+            ]
+        }
+
+        fn invalid_vks() -> Vec<Vk> {
+            let mut vk = Zksync::era_vk();
+            vk[VK_SIZE - 1] = vk[VK_SIZE - 1].wrapping_add(1);
+            vec![vk]
+        }
+    }
+
+    pallet_verifiers_test_kit::verifier_conformance!(Zksync, ZksyncFixtures);
+}
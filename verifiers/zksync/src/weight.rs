@@ -83,7 +83,7 @@ impl WeightInfo for () {
     /// Storage: `SettlementZksyncPallet::Disabled` (r:1 w:0)
     /// Proof: `SettlementZksyncPallet::Disabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
     /// Storage: `SettlementZksyncPallet::Vks` (r:1 w:0)
-    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(1416), added: 3891, mode: `MaxEncodedLen`)
     /// Storage: `Poe::NextAttestation` (r:1 w:0)
     /// Proof: `Poe::NextAttestation` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
     /// Storage: `Poe::Values` (r:1 w:1)
@@ -95,9 +95,9 @@ impl WeightInfo for () {
     fn submit_proof_with_vk_hash() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `378`
-        //  Estimated: `3537`
+        //  Estimated: `4881`
         // Minimum execution time: 11_599_761_000 picoseconds.
-        Weight::from_parts(11_599_761_000, 3537)
+        Weight::from_parts(11_599_761_000, 4881)
             .saturating_add(RocksDbWeight::get().reads(5_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
@@ -108,13 +108,13 @@ impl WeightInfo for () {
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
     /// Storage: `SettlementZksyncPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(1416), added: 3891, mode: `MaxEncodedLen`)
     fn register_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `114`
-        //  Estimated: `3550`
+        //  Estimated: `4881`
         // Minimum execution time: 131_130_000 picoseconds.
-        Weight::from_parts(131_130_000, 3550)
+        Weight::from_parts(131_130_000, 4881)
             .saturating_add(RocksDbWeight::get().reads(4_u64))
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
@@ -123,13 +123,13 @@ impl WeightInfo for () {
     /// Storage: `Balances::Holds` (r:1 w:1)
     /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
     /// Storage: `SettlementZksyncPallet::Vks` (r:1 w:1)
-    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Proof: `SettlementZksyncPallet::Vks` (`max_values`: None, `max_size`: Some(1416), added: 3891, mode: `MaxEncodedLen`)
     fn unregister_vk() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `340`
-        //  Estimated: `3550`
+        //  Estimated: `4881`
         // Minimum execution time: 126_194_000 picoseconds.
-        Weight::from_parts(126_194_000, 3550)
+        Weight::from_parts(126_194_000, 4881)
             .saturating_add(RocksDbWeight::get().reads(3_u64))
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
@@ -18,7 +18,7 @@ const ReturnCode = {
 
 const { init_api, submitProof, receivedEvents, registerDomain, holdDomain, unregisterDomain,
     aggregate, getBalance } = require('zkv-lib');
-const { PROOF: ZKSYNC_PROOF, PUBS: ZKSYNC_PUBS } = require('./zksync_data.js');
+const { PROOF: ZKSYNC_PROOF, PUBS: ZKSYNC_PUBS, VK: ZKSYNC_VK } = require('./zksync_data.js');
 const { PROOF: FFLONK_PROOF, PUBS: FFLONK_PUBS, VK: FFLONK_VK } = require('./fflonk_data.js');
 const { PROOF: GROTH16_PROOF, PUBS: GROTH16_PUBS, VK: GROTH16_VK } = require('./groth16_data.js');
 const { PROOF: RISC0_PROOF, PUBS: RISC0_PUBS, VK: RISC0_VK } = require('./risc0_data.js');
@@ -45,7 +45,7 @@ async function run(nodeName, networkInfo, _args) {
         {
             name: "Zksync",
             pallet: api.tx.settlementZksyncPallet,
-            args: [{ 'Vk': null }, ZKSYNC_PROOF, ZKSYNC_PUBS],
+            args: [{ 'Vk': ZKSYNC_VK }, ZKSYNC_PROOF, ZKSYNC_PUBS],
         },
         {
             name: "Risc0",
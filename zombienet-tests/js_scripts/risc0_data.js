@@ -1,4 +1,4 @@
-const PROOF = "0x00000000010000000000000039d2000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000089feff37abffff27bcffff1f000000005afeff2ff8fdff3f58ffff3f6dffff177efeff0f34feff5f69ffff370bffff270bffff2718ffff3f9cffff1ff6feff4fd2ffff6f8bfeff27c7ffff4747feff47e5feff57edfdff178fffff07e7ffff47dcfeff1fdaffff2f16feff4f85ffff576fffff070bffff27befeff0f45feff5738feff3f0dfeff1716feff4f07feff4700000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000006ffeff07fcfeff1f7cfeff1f5efeff0f03ffff67f6ffff4fabffff27c3feff6716ffff4ff2ffff6f0dffff1785feff579effff0f12feff6fa7feff47cdffff1741feff7718feff3f0fffff07f2ffff6f61ffff77c9ffff376bffff2741feff776bffff27a9feff37e7feff47fefeff0ff6fdff4f38ffff3fa5ffff5763feff67100000001af55f72fd951d4d4789324102c739326095be2e818185248e5f3556feb6706069795554d4226e5d207d15665708be18fe11561c306534008244e3364be8316eb4bb1949ddc4f7287e5d2435e51bd656d3b0a5765709db55051aec1473ac444d8fc77225db96b615f4f41418a279e075091de655687549472df373577421211645b6e90536dfeb0c0a766908cf1d3e5d4492914e14815b71cb402c6e91408129b92e331b3e76425469d42833a7d9fa56182fd40799c72201db3e7a27cc70df02c107b23daba7fc6d8bf62b084f1fe0142ca7104119358a06c43d1516c27443520b85cb2fe733213d22609b0f31f1a51ab2c400013811ca46cdd78901fa2e981762a2456345533929e1a1b02f98424b042c7d65051bc8de36c5b69606a6732f66ada52d208032cf6b60d99e58e537580b593a3d0f6123972c90a4a5707e3fc815d280721a2343ae143b5cc0548d3d8d2c0ab74b06565441615978375d73f2601151b23958d576ab2e1d3fa821e86c8b51351a4d3488d6740767d5b728b26b272a93b92263c5fabb65c59cd576ac1d8449dc54e36c79581f59029491298b168e57b475353f3b4257639d1b2e05faeb215e4c28ab5c0dd9e8682f0203211c232160ce9dab649d24a7156c01ac4cd44ec61e3fd6f34de988a749bdbdba0a521f9b0ff3662c1f8c387f3d8d4633281d203908d47e870dcbbf87552923f93fff8f64093a07552c30fdf077972d823fea24ea25d98589263ed313668174be5e2c40de0004141b47b093ab476ae9ec5f7591112639b4071c6c9ed044f9d7a623ddb305353c09b12ebe48dd2d57c06722d0eb1b66bee834691cfbe96d0060915d90e20b38fbcae275b8d73817bf14d2602faf11172927961d09515b5c9f1b160c8f46aa57fb86603ece214919463e84255857b92b59cceb32b94f3c4b96af9171c1298f03333aec5eb51e8f6cef1fc80b0d3af315d3a373338a8b536c58ff921ac5dc1612bd9e7719c7400677d1b8a406005c0427712ddc1004ee5e2d20892b2fc7c6862884324e43b0556b691653ba406e841d6d5475f22e785d6305d0d4e617a6dd876c3acc6f1a33bc1d632ce1da3d699c0f514fa34a0e8407c05744c42e612bed703aa58b024c3c712d28510714118bf28c24981e853ee899e44beffbf80864e85a046e5dca514b3fca72a0101c5fcdec7224204195366646733dde979335a9f4141b43f51b13e899fe4385cacb11abb15f0e0193aa3db8ce045a0d2c796d6335cf68bf330747a788f65adfb37f034f007b3e5a7e3238fba5be75d19ddc3b7c97290f0be6096d8b26643be16ebb5ad2fea1205c637e70606d1014b3357868470e0169194bb92b63734d29c343df22f02e4a712ddd267448788207f34a4f553d2be326f78b3251304a4905628d0c74af5c88438bc964775b46355d3aeb4d76a832024bb269220075e3590ccee19632f24e491aa03d236adce1dd50fa083843b0528f27cba3b2148892de62fa86183c41e63864878fee429dfaab62e0aee128371d8b6fb828123447533b66f206cd16c3806c1cf9ec8f53b3c70f6bc92f981b0467e96ac7e8c76b2cf518309e6adb2b7909a67541b93345b0922758c65ab9262043c0549a2d1558d26dbf18d5bb0742a26c300bfb944015a1ca4309955b4f4135fcac4103aab8771e002456a406b46a85c9ea650c97282524f97d73ef32fc0005e94a00fce66a6804ac87294c8cbb28b2073a714c7f8f4a31b12d583e42f55bdf9c804efb5fb15e3ff2952faeee7f2eb57b3330982e1437627af43ec60dd90ee138392dacb8c02cfb6d59290ec80731ee62013a2dc22671cc225726c6097a5ea45f8e3b1893474ebb5fa11b1186175bd7eb550933759a760d94fc0c70ba5b2f0776003499e4286cdb6d9a323dd3976e5f4cf66c27b5a613ccf46e716cae484dd7ee43506b0b476d9e961707b0c2c55f57ef734b3120e03ad7ce900a35959f1721d3ba40efaeab40be960048089b015810ed65245432910a3840cc736dc5a83804f4c320e1aaab22f3d70e5caa074d41543ea35fdfc66e14f6bc6a74dc5d5c6d62484c5f09922e57cc57d969ac5a540a5107cf631edf2f09e78cab54965c14357f4608108343e539f6633f139ee8443eb98fcf650728d027c0a20c0a04fe203dfeeb5f0f5e10004686ce7623c7c75333cdf0fd4c15632272c1ad9a2fe944810c1e09b303c9aa9d7563113c49c7dfb4021c032d3dc7e61b510800b821439ff31e925ee1227da1cf1b64b25e41511fd726e64c9133782eb86c1cbb221d33bcb623d94be640861fa71ab094d01cf3f658770646492c3f61c4535afeba48dc86d86602fe0074810e300d2bab5c468174625d00929717365dfc2bad1f2558f15c404b5504db75b4849b0b983d4d5cd4a3ce1073b7f15840edb02718f68c56cc1bbf738901d46d4f21080f93de83576d56993948e04a69445c3d36e0e18d004955c573a11f150690975b4ec8ba1149c16c654384e58b21942de306f9226b37d978b1193ee3e358eb44fe0b610da65a1558cd4707921a582f20e161f566f06dd55bd506f878161a8bad4a6d179e4167522fd670e6530f77f16e0820ecad5d5d893f3b34aec4a051b6fa353d37050575572df4309aab8152e449496d4f8fb36c64eb2d4ab74a4004cd8c37421da69c42ddf9a9444cf323371a4e1c022d9ba5306e485b35bbd1d3232a9428698085cf21ac8b83258a6d116b9be90b1ac7816866d469415624f79a7598413d1aa7f874570799d9457b1e7849e13564344dd1a0616ad88d7144f412106b1ae40299674249a030647769e21a46c0702a2b6adf064ac9de1a52e86de505f0df022138e8402740f0e03ec85bde031411505b896967466bc0404186723d4b35a69758660d1d207f761a28e0e6cb33836b202e7bbcbb5e2926056fc2ec4b3b7cce4157b604fe32f692683a55eee92ff3b3d1070587722828dfe85b4755be3eb574220de9a775371638ae3cb2df8e04e9fbfe67f998602f372bec51eae4e705ab481953eb138f201b257d46d7797b70951d612aa2ec22721a42451495fe44167c4f9416198b0061e3b475556f543f503ae77c05ab7bd63486c57d74e3b1ff69afae9a06eb95bd675df7eb3d2f14114042a0ba0ab85148634050b01d5dba8176bfbb2606a687ee55709ac1029a35d746a1eb1f52486eda5639ba325a2fb3256af13c437157123b654d55621c77ebe9164187f774b29b583a409ab469ce69406c0335e064b81b4a57dbdaff7775bb8530253a7205d1826f2e0c766a37fd1e6b3075cd2a568716d530cc0b6338222a4e064a60d20be89e4960394acb30ea34302d94882e5f57e91659b258c74aa0ab5c0f04bcf20be7150a28e3af6913dce92d5df0bed51f73a8cb6f80059e38ab509534cc208b118498626336a3b62fe76f8f2ac4706b6627ca0e0100c6fd395249fe77471b557420b373031ba1aa506185496cf7cd0823303bc7063b589f0f4babe91926c20d697230f824bbe0ac46ab80b61750effe29abfce83d5cf2ab178d168c4dff4add23efe9374c8ee83a42fae23c0300c2b718b9a82c599719365d96d2c1499de60b28f5b64273697a6b1b66cc7f6a71f8f33ac71dc54052c07248742725233e60c024bf465443136d152970b229722c6ae31b6c5583376de00a4686519961072c671d66cb0012aa1cd031fb84294e7dfb3838f38ea410e795c11cf48b734283f74406b32c1d04f21752778ab6c01027f3b149ec74293efd198d33befcff33367afd56f9541914198e763ca573612a4761d94f8729d323d5af506fb5fb7f2f0fadab5192d186351ccd913cbc590447aa8fe009410e660218a821722a6dc92625a9096c10e44367bc3d61487e3221684fcff744f933520d690d055ae2a48b4695085c723493341818f1232b09aec3486145175a539a2f1a8138b219fb3d2d007ab71a62e0beb24b96c463359a9b45610ce484535c25c05d283d3173cfb8b4767076c15b5d61f3410ef2df263158e128dcb85e414b07e56b7cbeb5390f9146579d2dfe12b80c2375af118f17a0a23059c915e8424105cb6590835b2c12a6e50b494acf44ccd8ea42fd5be3442cbe8334481f101f0b9816240782981c4e140546bb68cd0239bf261abb8b3b2bf3c69e5a5b5710451e57e55af76fb66c2de89e3cbea86c25f3fe4549f1307e5b2aba252bfca1451ea1afed2256f96b73b9375d2f4d5bed401a1f6f309663cd0c3b2cc939abd7980547bc4f25b97da439b41597224ad81005b93bc65b4a1ad42f1f71f8561e76a368a586991912c01a2d5860cd5f97bba46f9c14134b96e4940ea43d4421bed4951b0df47c2f99d4f6211d40ea30f52573590128ad73aac0765f1651d301e11c0e153adcb70e881c3b08ee137b18203c78570bfb0d724188f83e96d8bf3b9235f86fa740823adbac5364287a61646293ae68a1b16909c0f8581836f1384666b63f0592df4e23272a3c63b6e66c29e4ea3f342047a5290598401b7329e60fb627e0715deb2254c4eb073226eeac087cde95379d165f5af4d8790de90c67251baaec33757389048f04e02756a7fa3f75995c512b530910f4a55a703c1ae9359a90ee392360c15b50b80929368d1556dded250d696faa3a61fc6c20e8b3e45bf602fe304e1876456ec01a5fa8fbb81c0f3180154b9fab2e4874c97689e2d800218cf4429b84393e7119c762927eb403dd05695b6e55402bfd82871c1df74c293f5bf83d1b25332688197152ba073a05336a74419ce7bb372a1b2e4f6dbea9776657ae25fbab351d59e2c1157f1ef7197a0e5339560e9a2e92e61035af8dfe24a91448716682d83c159e9e003674aa6e6938913a7f5a120ab1343e15c7c28671623f6e72797bb26572589621ff924760c6d40c220c362a0cca87d5226e4ffc0c29f6e673e9449507ef194e3655ba6648049409414d5f98702bc24039ef2cb9247fee0618c02d4f5dc504f7459be680086172a1646def6351b2315e5e23165d2008b56e32645c7e649ab8fe734ef85d3963c79617a5308d5af0f4d0620799b33a9d9e1d3148f34603201645387ab80c3332b73e554914f32476e42554846512096f105b4888ecbf31d71bb21f9b4c786cb01bf24eae90db60767b6e1605f7162c080bb12c42e36b68572a456050c4582912d6e3118861fc5740d69143633c45577a349c3fa5bbca401b580e2f8d7f003cf6462415664dbc58ca5fea28ca645506b7fefe4bd9ca0624ee255016e42b0c2289fdb07580d2165e581ee45ffdbbea3f127d6d0b554dab185b1cbc1b932a1609af90270c0523063081886f1097542f1605763b4a5f082e270dd9852d251d786dcfa8bc54f4bdb20502cf420d3ab9246db5402d67b662d16efce148030436f86f4face54d6c40052ee3bd7f6d6a25ac027c63ed0428654832d52992743832c525bcb3b36ce582b2706bac3a46e852fe70f9620b717cc40c5b8509465aed6657222c47e939a1e62526576e1d4fd6b4684cc086c45d0f6a7f36f858a314479bef0b072dd907d137da3b631dfb707d946051ae57f6002c809072b90a0d6d2e5a804f2cf58845d9acf13f034b032974ec3f020042b264107d3867c797ce610a2aa207434f3c23fda9b61175447910940d1f1ed1547c45e28e2637ba78353d7077431168e4f962ee45304ac23ebc0d5c768e239b336f2103468049fa7c2403bf214c0091d6185185cc341e37c4f734d154b1017ac34c05f475700ee69c913f8f5cce4fc9657a0147718e6ae9b3ff48863c8c14f2937b376bfe0f1fb0b3d20c6a7d6b14490ddd534e0e794c43c72739b3ca5571d6694359270c15018076e048cc7974720451b34b2847d0283de0ea29b8492559ebb6e33342b67a6a75433028c02bcb71a481bf5b291f0d0ed712517462b77531def2e070205d3f2833423f15b8c31f2adda10b68ac555a664ef34d2673d8f02986945f68ee733e55b5be416cbb76420e54d8380febe1780896616e129e9dce2de923f33d2358941d4bd836099235475770cdc71c1bd6b06daf9f6a464ddc942244e3c067536dd93eec90405bd0fb8d0330a69d18a126b3568af0124402df811a750eea31830bc669c3a10346c3d9b253bc54e3647323486e42518f37ffe6c313b5e3a347e514ed3b41ae3a57cb8a7554daef071ab9094b2f14763d65a2049d17d3d2373982a9f2376f9ca14fcccceb193c3f6d603aca7a55ce058630808eba6d84b97b6cad00732393186827628cca3c62e5b83cc139c8591b85cc6a0d5f3c147eac4269068108259e75c471636a9f4ecdc6c104a4ab3d326a08b964c9b995717ef9bd04b3dcfc055c09f246272c9f3273938d328e86c347e89d64302a89995896c679468e3a8e33ddafeb00f368660e6848be25c0361e65b1e7d6657f8f093718f2ab4731efe05a2ab4345881d4830dbcf4844c47cd3b55704b8f11536ee0386bab8b0b7e34a315c4f7dc0f77c0274d938ed259fa326e5877f10420938a8b3320ecc4264ab876423df80f14e241645f40c8f9217119fc59517c503c41bbd01870a3382d8d98a217dd44401b024ac82930114b74268c242e21593256172cba42a9fae34c2e13ff19ce21506fe8f37637ae300f6bf482800e44264a30711c254d128a57275446f35e59377e768b65375638064961eec4483a6ebedf4bd18d280e411e6a25b2a1af431a8477721f3b693b72d2e24e83c0035428cbd36833ff0a6e89342863bd15f309de56f43449e7282d3e785d0ffc882b04c402dd71f8b6d02aa6a376595bcdc43da6897916383551637fcdaa5cefcd8c015210496bb962d830a126fb42d8ba003ad2379064b7961d13705498776a5a473401dc031876377d0856c63553b9922d3c41c3c5305cb1c762a683850b57e041554cfd6a25c06eee1a61bbee62b0beda3645a6103571295a27e863e726bd20dd35f74fe044aa0f4d5b8ce6c94d1024285af40f8f20b845a539a1e782359a14f85ac297d55e2d5f9f434b6c56159d3ccb517c68ec1509a00421a57f43544d22891e8b508c2c6d89e45cf6380960b06dc25182401c102475fc4a5119320cc442291d56d3203718d9042f1c6dec10533a5075d7b34d245ffecd1c05cd34202d79f03044fea525d183fd3531712714148b814b25f41b2762d7e3723155a54a35f7e301222da055517cd262ad53543674132519a6ccaa7462b9f341958b490d69fb1849ea37756fc5c7c93e07fa69772068c32ab725091c782b8f4c60103d5d5a1f4f5b988fe5358d52502c7b4aac69f0aed3226146325abffa021db01ab87212cb29560c942832c7a8653df8ac035555d14c00f933855234493100d1e1fb0ebd554a1924eeb0501b507d720daed20be2f77d252536f01a7aefb22b091aef4d9397252b6937634cfb71950b0f94344d1f68016ee55fdf5390f3cf0195eb484304565a2420a4a4511be6622f0ed5c6377264ae1b67adc3547f69993f9da2ee0d07980701e49eb9519fe3471d73589848a4f846746126361a5733e73372167e2d980e3959e7fc4a49192321686e930108b35593605b94be28c8994e2e7cc2e624cba1d50517ef8f12d519c4580e8e073545766a5526b5e04c6b34f24d81168269384924666b5676622f42a10e77227e4142b6620dd7f4ff2fce5b656dc5a270662f19c12c4840861b2a0d53047b7b781c73609b6af2d5f84fb0056c6123236b41656dce0a73cb8457d823150339bd9a0cbade9a4596c440041ad20d4e52b07f3dc55f8242d479946c6260b94597149962b5f26c52e20d315901ee71652fcd16330d28bd4d0957c0215d5f7f2b72b9ec34b06f013c5b9538684b39905c37f6c33ab0e11e412231c94fd35dc30a8e329a742fc3176b2ef7d80556836f51db1d1345cb72326cb2f5c53427821539fd99c02088d9c0382b95ca30ae80630920191a488ac95f39d47bf625e282831d2b04a40ca578e13a5bf02d5fad21246a59de683854757e3b4738be5e439299005e2aa434a194aa2efc9fb551b3036d2fbc59ab194d3bd929ebec294066b9b6061e518e2923dcda2ca9ccca7719536f5ded2c844954ce19104c13264c6e7f9e141907f543eccdfa2ec271f04800a3e13c4fb67f03074a5d46ada93b74ea89a74028660062a1e5e56fa0d7a529ad89196665023564e2c06e237978bc5a0d7a1808f673d343b145680707d3e2070b490375b30ee53ce2865a5f9f10ef2957cf4f5b321d83313c44694f0cba6306a5809d04d77f5f5f5363063e37958f4ab87b6a5437a2fb32d798cf3da8aef8744e1d0f37c330414e8803f73d50f3c5467b18046a2d227500408f5f2bb4840f4432e97a6b42f3952679bc22745a424669693b9c6a5959f92c3e3cd55487b7160fe12c666949650870db4e82163db18d0b856ffa1a50dcfe12a2a60f59c5bba734cd751338dfb31f3b0de58f19395f6e11f1ce7d115fa5e372e21080583bfb0164a5c31733f19a6f50a52e5a2a201614523d4b55064316122e6e5d9120329e7443a6426904e11d1613d822df554f973159d751956e97ea0a034041762563470846ee3a6874f711d2100879f9171858e2617cef4f28513f1751c8f238695718f70ce5f49a42f649862a2a960b57be3fdc00bbd0c63e6c5c191373c63f23080f412d96724101cde01172ed679654428e074984a92356628d873b131d55120132200715e190598796420c2020340eb820ca4206afb008c9b11c5b7cdd2c5f6db4145b2d88171ce609922fd65c30327ba3423517adf22e547f44452db4c01688c6fb22213cc424f03c883417dd600855632c06473d1963aa688f59cfed6049e5375423c624ad49bd89520662effc40f1d05f2ad43f863f7c8b2d2204327665e799272bafc6e22e04d7d72c5532a85ec6642e389209490cb2d882310eb5d75a7e103963b6bc5426cf5e545db1ee232b92a0fe05726c366dfea28114871a352131290618c4dad437ceb45b494be23d0e6d7b0a2040df7a6dd0e147646468ee4d97a13211454c5b0e38e3e0105a9e2b0f8e180b62dacc3c43221be85aff44267261c85e119fa4e944b4523844473eac6c9d2ec665da3f3013157c91532fa5a95ccd63ee27041652726d3d2b72a599d166c38dc769f03d90084cc5b65fb7218436a859ea56dfe5ec760fc2d90f2469b344a69c533ac92e2b05eaa7ba5d987acf131738181707c5df3b4d5ef5392ccce4006967746a6004d641e5abab2e8a3cec3f86271f30bee504414bd930337a22dc002b2e595d36eb68138f670523e15bd946a210b204e7269955863be65e8368f04182175e143a67024394005332f4a87a5b4bfe7d219e2e0154b9529c068509de6cdbf7aa2cec08353d20d0af41962171177b933b767999a2198ab87d5769186b2ca6fd175b88c229592844be613b2ce025f17f625da075aa490219415c884d10079a1bd5310f55406b3037861ecf10a715cabe5a68b5c455658bb20a744183d25bded67c1c5cc83f6d576a1419752e8f54817f465e5292891f427e32221469cd412abc2b1ba52bed5280e1b4628f0f183786c7e720eaf36e023c0a6d40a3f26566ec373e008a6e762374de7e2fab7e59113e6ea12a927c994fce1f8e453e14fc093c05d4017604e12de758b64278982048912dcc27460cc90694640822a2269f4c860efd3089599658cdd2623764045e5ae8aea6452173a5128042890255606d5d72639c6de17a763963e2720472fb9a1bc1561b4ef963ad288ac64511343c916a7bfd0917cdf15245e952ae5e6328e509de8a18725f1c923bb4d8c104a3b43e34114627685f1ee75c80fee15b78cbb609f19f396993c2f114590809282524945325409c63be04b95d77dbac45aa145c5f415a7550d4f3b5409c380c46b29dc32c7df6f477e9517a58bebe926670e6e61053cb7721aaac2504027f525ad88c1f3d56c94605ce768841209cf6078559221266ed6f18559a360101b6474c3362b47407772f26f31e7f2b754ebe10e88b931c8cb54575538809615f4cb9070357c91fd593b95566aa314662b67a39cafad538777be80c67d97a3bf1a8477303b33d511b57bc6ee359ac19c7edd53373829c3d0c1f673e5752192c60c3073245595c65c7961d075f9fbe1b40f37046006d935c9078251f1fc0dc519d2a2b5643b94160fd98986d44afaa2268842b57bd4c9b3f6a9aa548ea26f73bb621da4a19b8a910075298633588b72108ea464729c39e42d5fe453638e2702605505416678e774e0f2c64000249b718bc942d37e4d15945f8f65c508c6dc412c6ad1261c1265a545ad62f62c6f8935246ec600589ddf80b67aec66c21bcbe74407fc6725c75e341fe7c3c221015950be0b2ff74d0364a5be2398857f571d149594c5130ebbb653e51bffd5cab7e01637bb8e87434201d049c5287568e7b07250e8dd965d68cee3891f6fa414e0a80254908563147718161ebcbfb43ac48886a66b7593e06e65e5deb09204ccc2675184046f4174cc9305bd61b6d3642ead3570de05b18078f4074d80f604190ad725f039cab58f308b2717d75405390c0fd1b57eff33b1831ae35ca0f176ab8b11236d0a8e43e79108438e87d731b720f864fd2d5f03ffc13012892cf42494eb5380246e68f65ef48222608e431084630100034bf5968cc16875b75f83c0f878cb0196c487237631ba55a06f2475fbbbaa61d62ef9373a247a1694c1d04719bec600d4b57991b913840774d4acf29a17f4b4993eb305a4f8be310ebc1ee4ee9deb26d110c5e74d5384612e7d3456dc1edc13e68bc463b2f8c30540ba7e72468ba690bc02ab22627dc4263b2d76348fe7aa56b908ca9101afb484b7a7eca022ef9e064db0af056fb9d94264be5cf3e1e724553e4ddd518d903e439f2a26655fe19e32b39ed3477285a164c9685ac4c8ef328172802b82de6510003463ecc33f1aee05c09c9ae077a60d867f2f0db6c3b832b6ba9b2ed46f8b1b76666c03d68a52caa433ec58a0b93df026ad90fe80af8e1d2117bb361054b7b1b523eb3396172e04a45a36e0a7790c4ed770b5ff6131de8a6425eda30732e459b770f6f260289074911a69f346cc103601cb6eed7478a3ccb19c89b694114d895487f69953cb1100c1803dfae5b248df773ce4f5137478d5f320c420d0693d3725972995e33c297993aacb25f1df43ec90c1fe72a28c46e54304505c574a2202905ec02314fce54f84bc1d8736327e5733a4b394d759260be21ad4df53d773f85187194b120d35090000e916062a639e73a3b002c5773a78e67c31e343dc3e5756764cb8441f7ba214bdb4ad12abfee34191f3a3e765e65fb07f5052345c43bf45e95fd8b6f90bd1c0414effe380d91805fcc0b4f6940a85f005bd53e19d57120534d1d2e685af64837ee138a284aac7e766ca760340cc0e31bac85de719f63b23ebbb6070079e9b84a4429331d25b6fa49c6d574069c86960b158be1407791bf37c827864c1cf0a46b479742383b8dbe64f9ef712d4075c06bf3913f4b3667b23cd2a4a4094542985e6061e23e09021c505a6d4846ec2689275af84937c5ae0e21023cbd126db30c557e45df1b9656924ad4a5524786a09e1e9a64df560b87af17f5100d22dd757c252cd8dc41702e45586c2a7f4e9378ed6b6319194bf120590128b4474fc8d04e7400daf935f8cb763e878f0f634536cf5c76102d20ac85a6564e754928cc06e3675b88656e322ca61f9ffa214f40825343b51c8b2eb3001d2524a29e4e30b16a05818cce5c8152621073c91c60b3199459688d6401e7a4b571953a470ff2e7ac595a00422d690939599717c461cf81ed108bf74d3c168b12303bc8f31288f9e03452d6436caf5f9a42cff7ea621f4f4240885741421a546a0e40ba3f4a9fe1503216f4914659fe2850868951175a58cd431e193b14ce7cfa6c3398d733142c7206e4d8f85fe8c71827be262a0bb674b04303f7c640250bda27d86cef280ad61a48b015765e83b0ad615782e84c3717290f8b9f6045aaa0433114d9d6501c6e630cc66ff25ad9ec8a0f98d5b000b3d35b06c1b73e145931dd031ba8bf7629e96c5ffdcf896ac2d74d647c8fb111aa83fc262294fa2048f52c0b9c0caa24963c936fbb13c233e115825befb3b76cd08aa23e1056884d68dfc01e542c44182a33697620005f397de02816dd2f37599a8aef43dc96d734f45a411f99e3110f339b9c56088f771c64dce715ed192c13a7990068d707a332cd35c161b8162f5cc350776b1897cc5e4dc2633b10c8a24dede64605ea33bc4b28e69f1299a92442ad1f1d3afdd23475d647981550d847010bd9417304833227f3d4322cc33f1d3fb9271e40a226d845f63193553af64e0f6685f9654e450359616874574557353e029c7952f2e5af455531ad625959bb1faad004584a7d6f273a748629d96e6e16bcc6f027e95ef825b945c20b4eebc621754e8f7609970c51bdbcca5f8e1c842b620451595b46cc598b19816c7ce1675b607afb1de13bce59a8e1ce474a054e345d48060592658806e60dcd59efd3fe64a2314a5a4abcae487a883e4d1567ba694410603b506c140b1ad2726d15c9ad214528af6fcea056179a55994dfce9460c11f15458bf4fb41ece5636585fe6e717cb571812a6ba7b4d3b7f7408ceb45f3ebd5d7f11d5b8ed5982057d522f471b762f43861b6f21ca6782dd8f29d111fa1d57e217592d266a0e2ea77c2263dc57668fb1ca64c034b3625e881f37a1fbd755dabc13728b68092a9d466065354e5f27be88db052bb0ac28d086b91eb65e4b224b4efb29210f745a21cb0716b162ed2201e77c315b1e803040b243206ad46f08ee52dc628c1f85778a66745c2e18b82bd7348845f33c7c2098c0e42f01b73b1284fb2515470f0942538af32745647e0410d185085c68777212b61e6c3c7ffb25ea301634987af70c6897462f90804f5e026e02300bb7000ac6baed593a52313a5eef884cd7a3251d604b7a6d14391470490456443dc53f02deb7634539588151443ccc154ec58f2318100e2a6de87a1664da495a6b82fc6d4122fa06f3be9508f9c7910009a92c3558fe632ec00bdc41c6bed36d183dc71124cc7e3525c0130e8f602f5ac4a38169f3ccb37575cf78671ebc60517322df341308df3978628303ce94ad39132aea46f734e203a9873b4341b6ce1c295a6c639b61c10c122edc4341f1f86c6a75c8165acce62be9366570c2d0e169149c30193dc1a66a38fa7f6546e18d543ff3a46f8f91c064ee577045d412ee15234509351e67fa02b1bf881fcf039139b2b43555a01e1a02e9a0f47655f0f0530616730202eccf2deb847b520c60293bda8e535dc532f072a57852203b0dc42fb095da01ac25af5120a3e502fb1edb24f33e264ddfd15142bf37464ca2952a553f0e5b5711586c3151a225027bf8e96e1da9a17501756935060c0454df85d3575df7b8328d0b14674c218729ab63185b2325aa72dfab47481a335424ae26605d025ac31192bc2047440c8d4fb3a9af20a67cbf6362162b58e65ec94f5ff9b2145c25c929b4ff114d6f43001d31fedc32e5a0194dd038441cc035ad027256c9529170eb0f59cc26000da589304a3a790b2f9a9e334f65c81e4b8535393381b26cbd93b55d891894367afec0720af1e22758a9b81c5843c83acb07ca18952bc72e00b7bf1cf94b3b1cafa0bf4899c61162ec2523090445e9604a2e704f366ea3750a91c62dd167ae5f07430b5f2f15ba14195fa8013ec1463ba7d2ac1eb2e82863cad01455a844fd0118f69948394b8a6fe28a6a12cd325620b8f5801efa5b212b0a2c9462cb5cb1190376c92cab99df523458b348152e633286ba704f33462f636f1d0718c9a75c077fa9e10546e1c859fbeac51d44f4ec7336d22e0de3757c0f1b0abf0aca8d00718302e11383122c53b2eecd36e0f787433796be54f5420e191de8d659f6680529673f605417ebdd2ad04e4e19fb40bc6802776e0683871369971ac31562cfe86b67ef1138529f6e2de0b58049a26ac62788f902257189574efc1b96711db4560457b0447663d0476cce3c0c0fa75f001fe0b1b566a67e97711d99af288c13c36b65f8f318b5090206509849034c1b16153cc970384b02a67194ef4f74fc94a7680b86ad2bc22d3221e8995a38f19f57727da08529f497f332e558252d6c49c525c8e14a53c4f03e6a2953e44bbcf3b336aa151e68c61d114ad7fec46e4244b734f7c5f9095dbd151e6a261e084d123e3c92209374d2397a3edf362f5e85fe3529f90ba769f6913b097fb2907278f7fa124dfd3a2de06c15241171f06b45983230e73b6610ff60e61d1819aa492051ec23e7c68f4e35fd0c7054419149f8c25b037810515c08d7d93640632c55d77144520775c24b7214243988fbbb2be422f8683ee6ab353e5c9d2e5b80962a0f98021a555ec6602b49be315dc5ac3cf01aee3203ae1171fc4aeb174869b92a4b534748a2b4fa45160e932fa8ce9250658495275685d84170f3366a892dd51fd2c6691e8f1c3e444910c45c3142de5fded4df2b7a285929fb9f5d5200b5273ce9df813d2ce92777674a577519a00b51b5bcb44e3086aa0c9ab938168606b36c264c7c311c5b50600a80fd3c7427174e7904cc6a6929b377594ca81dd5e9f90ce5f17d0d8efc36774b27f401f018f355e80f320baa4a643c2f37d858d143234a7809a56e0dce140bcd4d8a772fbb1f53aa67715ca1e228372ea54d284ab7f25d36a5e90a78bae94411bc9035bcb0562121c62a3fc1e222462e43ba4823c2451a98e2327507f4966379948a0948d3df488888d643c2227c0417535256ab98190fb1386c37b08db2280df6445803c1ba41e9967c0776aed32cfbed5531acfca2126875052830506d70faec3504ca382a39b931ea21e80bd34f638d6f10b2de9b2609d2715d01129b719126ea24a9fd6e035ffdf320a89e9d28bbc00517f2e3c07053f18c25a06cc430862c525a245efe3e095c601afb05bd5cca858f0eb3f0ef054248894a1a55226153ff672b6043501ad0e42a554ef80a4ea893c60fc4053051586b5c332783c03432c8330f580be401f561376af5d0b835dd43382b190dfa0f3ba2e24dd019165a3269426481cc4863f97f49078765d70f6e724345c36bba171d9d2176b0d634473586c72bb28e496a197d17222ceb5e511e9d0a00c2296921f9656b409ceacd22a4949e4e09686b255a4483359a91322facb2a6516825d462dde436009443a8607d15b81002f8c4527414df20a8462820e9669b0784a47868ca1ce9265cb1de1aa66f3b4aca03ff5f8a7ce531a1ddca0d4619b9522409902e4472a71979a8a45ddf23be559c34323beaa0344cc0121d5b9e7df065a1012f23ec571f666761d571db74e7236a1935511736dd32de631b1c5dc878729da6450876ac846667167d543efcbc58f41f5d2af5bb8842efa324353e8a4a2611f0146415db7e338665f91b4151bf2c491fe746084dbe600b1b5a12ccfeca36ebfe6a2c7c14d14f40dbf1063afa4919941dde201a17a24e8d9cc65b18a5d830a508e51eceb4a6037c6d58340f296177fe90d4373f167b730b292f15e3654417ed1f2055aaf8e03d547aa52885c87d627060ae486106503d5534ae0a2f63cd0bdd95e90a1646f20e05df3f40aa2f2035caede54e71292b6b0de29f21f3acd72d89639157f993d664cbaefb693bd1795e796fba4e526bde29f8d18b0f227e5471fceba9363ea92b4e2cda2048521e7063aa56d45e2575c020d79c4a02f401165881593f32fc9613381147bf4fb30e1b25c8a9c35d79fbf0301e1f2c173cffb25d26261a36323ceb3ada2aaf287d562d2a4217a12751df665039aa8a4101cc8a68f1d080652c8717149e4b7f2bcff6043cda2e8906254c696068cd7e2603d2961429765265c07768627e40b3592a89f34329625a6a7a444335b6db06081db9b851db1aae5090e0c35b0b55ce745837fd5e337ff724734ded5347f5472887d6a743e141bc65865ad964cc19dc2e3f93a17437799b242619833cbf4ea1462b476e1ab75f36611536cd748942b53de6af9a1ea032a550d7cebc4150fe2e50c70ef14c782af80d9238a4513762c376a013c922b6d8b241499a905ccfc62438a48e1830ddee11426b716d1f14245d1843a6e15c3e78616cbd4c637559f3970dc71a071f54eaf4422a3a3b1140342111ea84c93f0d5f0842c09e3c2429b497134a29e6281f267273875b73454239b439ef38113a06d0ad16bf25a76bdd1d3c4fac4abb1a850e8c6f8e420e233cdc3b062e8ca43253d4323fd654c352bd17f84c650a862653059448a9b3ae760daaef1cd0c6103f2ea4db4611bb0741e4ae5a5c9c9b7412e49fab30f4b3975bbf81174bf773002177780f088b8225372a8ea36847642859e35a5b03e2417056a6ce9a5f2d36b909424bee25c8b63f5a2c9c683ed1306a3958ade3333e15e366096a5e74f8db761096cc34241867075280c44530be2378566eb9f9525bbf1109b5cf6f02920f8b23d966613e91bc72445d37dd51260b366dfb768870670ac42b5590216b68e9ba5b7263a42f85100f4d7c3e92334577a82d7c6f8731c6f5051d886db251a8e47a0ee3f0d1539e6d724d10b11a22f2badf74fc5c841382683f25294aba176ecf91665bfca951e4b65644a29ed775d2d8ab59a02b284106a65a6476f9cd58a34b180e8047351e0e4003531c034a0245368c0625bb4700b02b4269dcfe6301774b425d36205f1d84dfe3487c3ca75c47d39f5b187ef944df81e12180239f72d2c89c06d079d316d2a35a0622887708f14bab1561775f0c41353d469d4b9c0ad290c8657d125a737ab9b16b6c151e0bd569e34a73d1450b82c4921eadd32518449a122e1452b9396d941f0add6a3062de46b172da71936360036f3784eb555e9cd6e64b3b7fe951e2f83c40467f536e8bbb8c4d3e26815963c1c54103b4353ae079ba00ac1cb46a2d09ab5ed347da0dd4ac3f7542409f32136ff674c6ffae289aefa577ed6d430ddf6bfa48e9302e608393ae138c673f5fb7937120b82b8706654a360a7d06173fd957bf298abf2364a85e077151c84f5c1065f23e49b974342c998e2129fe516f84b2683cda238d0562cb901866d8a8484daec1284453c034882e2017ee682b4e0451f4571f78ae3725f4437733cb922051d48d02a6f7fc68d0585e16214739743a4ee8285300360f7ad79d0978a10a26557ad869b9b48a1d97227047231cf61fda2e1b3a0d7dd81c10fccb307b577000a0a2bf47cc7045410ab67d05bf5731704a005d277b1afd5a57a2db5432762b083bcdc031917ce7465b78e518e0dd312379012f183421920f90264a4ac92b6921c7492a203033cc07bbabf969758c1757644d741fe8bf0710c4c0e5768838034a92a13f6286c1df0724fa2a4f1aceca1877ba8269e93f9a5246f247098b523919ed74af1bfbd464224771ef5da07af2695493f27610e7dd71889f6f307ef3ac04f77d3260ee2f86265bcac50b9d5fa23a95f6ab427037ee10c91e936584aac71167a0ca153bd1d10ec917b209d1d9cc3522869f6cbc1ccb3334e92945531aed174bc3993705e8955a28f0d95dc6688923ffeedc49f459cf1fe686865cb9984418a111f254cad5e939ef008a43f1cf035401afc075c9c974172a185b14cb074810a3f9a029822ece651042780ab7ecd46553bc4b1dbe03010263c27755b43a285d6ba05912a634d6653853622bad80975173d1604c337286632702dc39c2eb114bc436db43626a85525b0b62685abcd9271b7a3c45552d44550ab7e5266d887f5962f9ab69121f0770f3e8c55dedc54a7220b42564ed32ed406cbe4718607750576c17235a58d714178fa30763e84d8d30cba7733d22cfb00206b608734817016e0e54440d894dbf3aa708bc735b98f15f877077572da1bd06a327f920de130761c8d1a12ae282db45f68943501dca556856c598193e322569cec23367056b3d752c691b2015394a37f600e4322bc0f159f7af166db8242f07f62d9933744c2103d8cf034af8a0ff29eea99c0ecce297075445c633cc63b27290077d310b1218122592d80ab3e9402b66fab701e1b31c498ac6374f6c994f4ad7e883564c9060597987fd563e601846781aca2fbd15fc45f1e0244ca0ac5b29bdc068545b390b165d70884e366b1451148f713dee6088448eb2ec69990608426292df6c7a8d023df0326a5d6a8fe15eb097fc733b13f40ef7f90e282d76a758354cdf38edc6653a3c6a0c5690348806359612619847512a2bbcee2709e8ca2804568c630bc36964376a764957cda511b49d7021cedffe04c4e07a4f970bde515c325b030749171c3f135226f61c701f727ac56a2c079e3cfa1b303cddbb2104346b6a222ac5d51fa0b0663a66fdc7559e4de82307a82e57e91de64448918b59d12c60336709440b2039e848526c084c4c561c607269b32cb8e37a454ae21957e7ffea5ee5033a21b11e1f56783bf160e38150501a563777fc373b6e95b8e2434b038363f1d971674e8f10147642d05188c69d154936ae6b2891663caa17522b939f6b700bc6ff496be5a928489d415d29540f18f0758758efc3213e5dacd82212d5e4049848b44cbb12b3477bb056555194b71da2962e2ecef2a2253c92f15a2c3b0c12a3541a428ee0c02a2f5bba5423874c2b6b7f2d06cfb42f3dbedc9a40848b28678b452a2a5936e828cb2af56c0d217a25030d8b571441232c584923738c8e9b5e9d07bd55d2a59e6d572c103e9931e34962a5ad244a1308437ceb87136569034af8a2995ece91c3722038b81acf21e25c8d8d3b67357ce34dcf4c9c1a09720b5c2a413a26e995a6669dd037724dcc9e1c35144e3f6d57381b8486f1304d90b909d10a1c24d077221f01aafe75b1fb6354e40b5f3f4e6c6f5cfb8d9738b54bee6b9405f8015e31ec4cab1f0d3f02758c3c4faade0fd1e9a111ec887a3c4ef2f332c7df3145f66a0a00992fbb34a64aae74c93af00eca10c874ded2a3125be42117b24ca00ebe886c2b0b23cb5c292b4635a8ab2465dff2d41821aae818540c26623d5c1c399b66540e03c8360e858d885b4a75bd3e8139ff583f68a759a31bf02be662831818b8b7541ffa5f71488fd92271e2a471ac270b1b88741628b7da3c3cd2bf0b2502f7402a5d62b6123ba54437ac2e4d48930dd3186f50366c95aabe74712c6e5b7cd44406e7f8f3638f35b3229e360412f78924767e1690022e6c737032d6ea4100b05e20ab5acb6d5debbc07f9a5ce2589bc6a2fd0efad32f59345301132b656eb157d5e38977073d8f190033ea6f70d63425c2e5d825f081602841c856ab34b13d9983bc7ac2c1589ff9e20350c803ca5ff9031f19e705e160a8b04c0c6a2614be82f6866f2054790b67c121a71bb2c649b6f386d2d09699fe806709439a164dc02242482ca4b6eaeadfc555ca9dc38e8d30518a8dadb3fcd663b57fae87b43b8a02c536ec3a754fdf4044e264be618832ddd22f5ae7b7123114166384078772daa1619da056a38023f6a5768c8502c4d223703c9424e5f79c4553527b6d741fe76d94c1c54cb0e49d4fe4882aa8017199b414bce6c2515f2b0974eeaf8bc5ade282b09f2d6591602bc4959a8acb851ba8a0428caef9d42c7719c067b24164122472d491401bd123a5fdd30593e0d0ee479fb53e7029969a94caf138a2e964d84a9454c9c5fcd353ecf17114dcb3d1a38d653287ddfe2404a33984dbc4c232b09b4cb663379ae12d9e21412bdbf3147f6a1cf6ee55ef573319193508e21c26549463c5ab5ef350bb8ef2624e06fae4a8ebae04115037550a950e446f0727075d3e6f732cb120c6a853cec10575bec4f2bccc73f5cbe89040bb23d6e88a7a1642eca81760dd2af174068be415e839a001ef5986687a9700edc25da71d1edc31a04ab862c4388b00e634b216a07562b1d0b87a125d9543f5689275f7121deb22fa52fb31ce891017223df0e5827627e505ef531649db34972db1e2947b4d1f33f02566a26d9bb756b0b9e635f4114502b4289453a4067777709570a22c7c1ad55920ff06a4df82821e785d1004bb9bc29afe86359e7fa463c18d756124efb6d435a709728aa4c8b5aec32356b5df8702bf520bf4da6291455fbd324682709fe46217b7012de32a56a442e1c5a51714e55cedf6501d3300f14c93f69332bb53522c942ab2314401e30ee1cce70aa54fe3bc6abc6414ca05b5d8278aa3508e46a07386741644512871d3e1aee774430ec0d40eb6f41edaa234f99a03b07bcd70140bdde5f58d60bb807579cf06463241d0b5922ea05beea2f236998486b7ad4232e5c24ff1c5b211d5aa874445af376762b6bb8f9254e09ed1315bd6f56db96542b3e64b35bedbc0904b668ec40e553b7711f6357213a836c25abc78029d4ba0172cfbcbd33d0b07b018627853a14dc396bdd5bd42f8b2fe73c460547254203e225b3a5ef0e0606d00ed7e68a16901cb506945180402aa0d3249664ed4247774359e6def14b6afb703d93b59337482ed534c1775d2a50147962eba059710cd51d2eba5a4f702199db735e455014dd156e1da299aa4f49a65a4e16c8de07399a7d1bac46531dd9cbca728b4e844d1d3f49234bd0ca1ef1dd803374c3fd4c34930b2b12ee520e2858d03e1452bd7028115c34dd5a2f5dddd0591205f612548673ac16e7dfaf015f24f231448d772758f747524b9e7a2994cdd0777010c61666089116f1c2ea3d052e4c41c5ffd7174dcce75f56234429b558321ff05b981b54961f6868f6c72044481c13e8e2db4d7dfba630bd8c0524b5d8130b4e793334509a2324baac1c561178ee59daad3015200aa75e6aaeda373584de566528014182c2051be39e885a5835f44c98daaa419016930453b0750e387f884f9372ca148703505cd99c846b13756936ddce494b606e50212249d71bc1d79f5cacb03638a172e26f683eed17fdfd3672d07fb66bcf0c8011e1b8f861284b55358961bb401d2ec539096e930d964f3c6609e603058b99172a10f55e4b23303653d8e42e0e4f1f842fbb08e365eb277b1f15b1d05afd92ac1a505c23364cfb7f0f6a5b60336452fc6a86606c2e84edc743d4873506ff17982b0695f4751a13cd37a58a5b13ef13886835c523258f222b25c49d7858a681d0566ed29e6c4debd40b93c4634e7d51c8008777c327c905664169032f76b2e5924d50622c224ca4890347d63d1e1fa85c701725b028f5844364e86ac771f0bf9b1f086b0807f5c7f85004a46e50a4883c0a8b1db0173b0955517aa8954449039867109a2b21e46779774b9cfc051d05bf114eb8ed549bf60e5e1ef67b3695c7d129d2a7e149b3b03869c1ae6069005aa25cc90d9b1ab593af1810c06d1458df745bec4827088c60563ea81d9a3d05e8c675ae0a086c130fd760250c8f15009ed7465c7dcc45165803430a0f7308c6d6610b3f9719511f98af3623783a3b2085716ad263d127e15ee00e19895151b0c23f4988715052a03c560545d2f23e5b5bde26612a705c504f9f57e6c24f4aa5b251014cc0f85e146a1e0545903c0a9b83225944f32d470b1a3557e1fe25725725ae58aaa733291343174551cbc175119e4c2067d9a1768209164fe3bf57079799152b1de03a34ad51470a4f1be51aa0ffc5357f22292d939d83319365ab0b1229aa2b020a9070499b421f2d9fe3591498777046b0204ea61f6165fb379313c423625366fdae096ef31a36d2129855eea1d42710be620e57dbf366bc376a39454cc556e2b982514525a52a94083e3fd3d6086b0d596f6d7e79aa1c6e2e2126784d804844b0236f3d1db86d7b5f230ba67f9600996c8e181511011e30dba21373631630da2baa6976215211f4065a3f338ffb6c6fe0b91af05d01446a067f3c95115f70e2941a737e4d1b207849ca51cdfc5d71afcd6a577e44ec511452432a4ac4790111b24b085e0b624a8eb01e30b739f3605309533247a1de5d3d0a5725164a91174f748558e315545ec63c6355d9725b4978a8ce1352636a0b00dc4a6382bba13917476f5c082ded60b0b8690f231f5d03c212f104fc3496528e548e03e610cf14af9450679d56ff6226815c68234e4b29f30b5c697f6bfc53b0fa49214bfa09315f027f34b99391549e1840473a2260273571f35a6533572d4978f7640ca8dc7143c01a647bfd06525eb9095dce044272f8073b57076cce6e751827124ab40b2625a9882fc6616f188f4fed04464df96ac9f8780d6398db322ff50b3ecd48513e82c0b057ce8c185337fbc91d28c0726ed5395e47d20bf6311ea0c234b1eed673ee5ba96ddb20bf42a3b15605ea84714e6c634508fea09c119bb0692ab9a44b50907c460d5ce7100f6c8a64289971e510c94de760d9827765309bfa5ae5da2b6798f5287648eb1c47ef85430f2c4a123bb275ee06384d672bbcadf006bde1d80edd0f7e25c9942d12e208ab070ca24b2de929c205934d4737814b8d0fddfeef6ac6ef3c3ced34433fb60e2f5f0fda6c66c219b242fda12f2bee3af63107b11e4704367749b0340772f2582745e0760328f2a45c1064e5e920860bb254309603447c3a79296eece46c3bb0ea5fb985df5b23d35c6b211aba64c0e4ce13abd34c2e46fd5461179b5822374a6a355ee6624e26167742117c423b4e95610dee7f0f6c620747134799a51da42daf1d80857e1a86ff933196526920c53b1f3095f2b003a1ee361030d0ae28a78ae5298dd74b511207a60a59a1183dd86e870ac0b1b40e36a7a614d48877770388cd3e93f3fe0d267ecd1f23019b229e76c52f465da41088dea057b7ed7d2691f7d84caedb7b3461816d354f699c525a990a62ec14c27056ea30592cb1f262b8aeb03a10c4b54abbb8c04750c57e294b576b2c11b7764647acf113f0b09518459e5615102183049e05bd1c3c156e455bf1c51d6229d5639ceb8c4365137f56c63ba52c19e8b8207b69e82925c549279c7e5764ab4fe73d527fb7475d476071ced6384b9f6e7433ea3a485e26e8e71a113f5113a26270573d5c1d6ff3a3d7613df5cd31857a4803cb58bb076bfb2f715402d7273cec40639bc8a33bb815914dd8c6bd4d70dfcf754cf430765fc5a7203d8ca134f4c2170cad996d6a1de6c0563bd55573e4996608d7cd493170a8e45b8d4596217a24af5f7aab3f714f524439e8928a55945b4b5042237714fcc2a1690f8f3b677e3ecc05d33ad163cfea785c34c36e0e1173c6054634093d7d1e2f72d2cf421105adca48edc1683ba06a7b6cb848f653fe1a5171ba4af01018c2b542006b005974fa177629b0c264488fa50660448660c87bc7461fc8843e09face543df60f0d3ad58677477a6319968a341e5203470213ae14371576fb046517ea3f39334341a0db5a741a3bf734ce212b1d85bfcf375f25df5139eca65c36f59b661ad9623e03e19a2b1ca6103aa546804351b65453a6e50841ee3f88775ee0a55c8e660a13e103ea1c884d761e6cbf341a6780a31d8d97390d4295e05bb39b3922d4973d3172e6c843b18f2a4d84d919770f7d2800118ee341c3929e5dd6d0aa220ba9b409cb1ac842329adc43c2710c1ee8a278672155bd36bae3ec23ad2fa252a2557724eca95e770c0b4877ce43ea0fb8d42f0f9030a71dc3e3310c64cae14ae089773d85df060740ab2e54510d2576dc205724abdbc4423d82170e5855f11c8d8627138cb08e0c9e9fad3e9ad4dc140b78b20cda81f5758036d430949c401a2e5b914abddc896189d69b3820682b2860df2703a98dcd050e960e6ac7119227df1df84f0569096b53b0e460e8037266a2090c36ff801f6edc26a118fd6e1d225cb27817f8f2712bd40f695109f44b29de522f562ace093a44c09b2113f0790ec0c71925ddc1d92204897c5ffa446a3c07cdb80bb030d14bb06d0e5ec8357202b960b62c2c12c40af0d62a6e1c52b503e8ea013ab7e74d2644f25474f43d9b737a10cc3030be55417a820753bb416f478e0d0f2d0e532a1ada16ff5144f29e2d3277f94b1ca0d35dcd4f761f2e9fb9618a8b28576233640ab022b918f01fbd406c61545e4894245bcb0cf801f4dbc37084d0bc19fd99ca0719d35542ea36972930dc77087fa00f3967b46504aa417d3a54977b12af9fb34f0bd91514a60d7648b247cd643baf5774b1dde12a2dec5d5c0d793f088a5d9f35a0481f04f52f33390099446946b46911c05dbe16e67eae05118f0e26c530a07053621d42dcec2724a3aeb927605b18095a6f53751126c0030f4784146bbb962b8253c0519e968f5cd678c15f64ddab2b6d25d55128fde4439c0aed6bd82be82bcb40ac30a113382661365f2ca886ce726e89416de1f136091b39b74a41b45b0399e9cb5c64f593760884375ae2c20a754f831f23027af02032262c28842bac450532b328ccbeba22029fbf47f5b569636d3fde3aaea0ea3f4edd54310a12f058a81e256e7643fd1baa3c1c066123eb6fd8ce94770af2f84d6156fb3e119440128b01ac671f85e53ae4637c60ee053902b07e63404dd62a1e27cb256eb7af07339036556200b70d505b446e3616a7c43cbf68625e30827c1daedbf541c211091cd3212319b94c05650e817374bcddae62dc1e380cdbf7413013db71708b5aea76b48eb17618405f1ce203e74b4e710158b689d55c9103655b90a1406752462011e1eeda3a54059a3971247a3da9ee941ab8fb2c1b20966e1e8ec081003d2f5347f3839b634cafc06d95843e17a0e0855b8594331457dad96aaedf377281c7d74a0527914c4cd4b003ed27ab46a195d40fc7663d621357e310b300485a1834bd45274d1e142bf5ea1d069df60394795e4056e0b56af5dedf5cf1f7b34c03633f3e6f7e594187ec2006ceee781e57e98a2b0b20071850a97305c1b5592300c7b63daa7a88029a5de11df2e821188045f547d4b0f2632d522d321765a9321ab3e21cf820762dc526ad0c68cbd946ead4f4572da81d727a170802e89a3f66957f8b63097d96062465084efde3445f7da33257cb471e44f30dfb5c7feda3297ceeac10677edd5b8bb68c58311a8d23834e4e1f8b4c4247d9124810e8db235647870967eb1be44053ae733828d0c9318fd0902cf95e6514a703e25b8ec0490c32e5d159b598972da91dc94d977f2763ee5cc62281209827c728513fe2d2b73a11276a5a2f78a034510abc2e9b0846437081295a1d199e0f3ab826090aaecc214457e2410868096fb2c9574f2cb65356727d413592c6ee5c8f5ed414896af60fc921b355345d272fd5888b075f44b62a3fc9b10dcb01e023657f47532f2cdf3d337bef7338d0454890f6ad2595ba206c0ba6780d2198fe2c5db6b2528990e41ee3bb7c3e420b734914735a440949ff730b86453a0e398d198a19e72c2426d943c807cc530c51602c59ca33482756161bbda6b3420b44691932bfc74920f0c539d196854dddc9a40440a6c0033202921603b2495725fc4c4e4970825a85c60f70c88bc274e2eb182370091c5802d2a776c5abc83982c9f362ee41675bed0d3c5a41e1ad25baf0ea5c6e54d72c1ea34565ae3f3d0d5698355daa45a16e71ab3c7379d8870f3072a02dce797d4bc429ac69901d4a131c8504564b24fe3a5c89c10b06c2f050fe18e26419dc9946532c1c1a481d841ed9e66c496a2a7141a37d5e60628b5446512d4d5287bd741c5505bd628902344072bcc1552a14e85ecd6514208f69f20fa9531374616a783fc4db5a6a7d75f03159600668d0b1895c128de849108f3a504b338225492a817642634f5a5972f33248ae51491debd9241602130ff4232a2a058ede282ca2302cb787cf1859b0f914c0afd52f70207b6f004fc41ce9a7e00f614543418286cc21b55a61119aacaa6dc81ae853b20e820e6b86603c5110ed12f0eeab07d82de81443571250b2833f0c68b378470abca0213ecc2511275651370719f3170a345648358e2839ddb5cc23e0057147f7304012ca84f2383497c56b4d6d1a10b3a1a623cb576b0d1e43096b29a94b55cfb2c876589c3225f5ca372c84cfc25887d4031cc1d26b6da858f572c3750a464589c724cdaefe456b47175ff76a9469b42a615a134ec727a8dbc17482714f3875789d46928de06c743f8d237c314d1a3fbe144f16a23c4614b9515a0574fd31f591d32cb15423136508d114070731054f5f7d20a455dc60989307564af2656ccb9b6b02aa12a1574bfdc03c3b4d2e1c3e49ae5240ec2320ddc09d69eaa1994666ff263c7c0fba752e58620d40b14574d773694691928e261cf0e7643af41f4d36acb951cdaa5160bd08c9672e7fe751074f161bbe9f3723c2364d1680dbcb368283ed309a19d11b424c696822b1a21e8588206928ad623a0df153134008ca323b907c2ddfc0401ff1665c48882a4e190a093e2eb5cc352fb8b131719b9a1a0bf3d36b2d00e0e546073da740eeba016803699d5512237e06c83757614dae513f6df75b2bbd945241839a5c69def38510367379445238aa357da8502ce4aedb257cf26e290ee29b2e69c2901bf67b183ff5d69527f832582c1745a15feec17b3a6cf698179d7eb3390f2dc53780bf080addc815526d9a710c9d0faa2030b92b689cab4b0c5691323d2f3402008b5c4c5a23ee2e029515c445284554548944d84ba0535450d0db3155b103b0109e4be65ba9ed5a105e23af5ca7b4980cce991c1ed563171d66bed06c554be15fa0a3fe0273fe7a6a22270354647a6476b35bf03c9a37430913681b2e2e263000ec365d4d386dc72f28e04619432bc66f00942e764cb2ea45295f08169902045dd9710c40fa8d1a5ffd009c3bfacc496f86b7063f9c7e870867041410453dc803ea0ed97188a26776e6723659afb5403e6c3285107df2b104e0428d09de120e3482c5121eaf208c163e54a371476a8c3a3142a03296821b3db11e0f5ef22d7f285effda6e3f2de03ebb1dd36f20451c10f378733c46beb67390e5f333f41b2c2b700684366a53254aad11d12c53eb3608a2201a61fcc79c65e48de36ef1da003ee07db7221383cc4d6b34e6499b0c3c10e24e6f2aeaa16a5835e8874cc5ff2a4e5b96c863e7140c04a8069635d5cdaa1ae84662405c17c811a673007585236957b000814546ff00367b113c184250791f37224b280f17ac0ea8ec3a0babc8e211519b8e1f5569591cb502ff0de6dfb0618e99b743cb15ed6119bbca281bbc54359d7c4a301a9d4925f72bcb18bc142e5689af78290428fb2c30a9170bd9e6bb65d8754156646ef369649c296dded78e64c641ed23fd35f44168b5d51a13ba945d28d6831a1c12ca3024ac1660b436460c755e83392ee7b573468b6c4e5e9e010e42226347fda2591abe67211e4a21b735602df34475450849427a600111946c24b3dbb229df56864c5b085f416b3a86220e47be06bfc3444717c536511905ef1e4bb9e727c44bde4fdff8832b0f972d19fb7c761b0584273db491245a484e4e66bd11dd3c8e336f6fba97bc5a7612f0698d54ee646a6bec5b7a7a4956d5a5f66069aa452b351cdd714779be1dbb766e456e35712b6034643567ae583679ff414092f8c65d962d301bb442bd1e085b30585777da671b35b70738bd3b6ba5137d3a89f3fa54617a1b4bc394ed736f93785dcbbb967062fe5709fa77bd03321a9d0dbacff91d7ade194ef595686244db4968ce573e7683be2e14eea1764d567a3f3d13f7605413784a6eed23e45e109d1d2640f4a9080d0ff82b5f703954e8b4d85c20694970f6d94c444c3a336ce0d5540b686c3801d7b2cb10d8beee6ba05b1865f7357c47d02f470568cb93136d9c56206fccff36c55b8349af63c6729bbcbc077cebeb649d570c7081c37a02d08d3a4178086648d7a24257d6207f0c4bbded45d2eb2b22f020014aaad51c574ebcc8730e03de0b743dfb500d097f732e1a96516a5ed62a04785f1e49c6ad6587e1a54f25b7134ba3b68c77aba8056e9e015c48e4c8ca3b5668650a84882f34e0baed30adcd0e5b88048c2a226f961434a45a453206a7015e166c21f97aad553dbf9859d3389b41612b0c253ee77f2ce3fc1c083197f2558ac8073c48e7950cc3b1d31700c14233952ca52f4864160b1791a63fef41f368425b944ed0b246260c65d34d61d36916cc9a554efd8d74690c8a2462a7aaeb4533f3fb51fc68256bb1a9376673939807a90882606138d5092165753e8acbb46e1103d8588ddc7d1d5e2eed042d9b8c5d9d12b24029b16807c9a4055cfbd4a70507a20e2fd3565e1f4c83210baae52462b5cdc73260535848ea1e0474e9559f1415e31d6860bf60360aea9d6a4af2ea2255faa967bc7a3269b6c411765a08791a511d8b25128fb923ac326621f820161b198fe24f56c91526cb4a2230183d4a2ec00eb33abee8bc547e773a61ccc7556218e1d0566b1d311a42b99d4e9fd35c014d68fa5399b56c21606cd937c3678b39d9267623b47f7e0c91937b35fa850f1ab158dc6a8b00694a8ec341252360a002440ed55bcf05e319ba0d383b574eb94fe1217133ad052a283c65e85a5051894475aab26ce9363e70afb9656b827c963ec996780eb229bd1862ebd61bfcecca2786439d384772716d1e705a042f14f20297b4c7711a610b6913b83244b7b88876293acb3b187586433ca02c51cb42924a2aef753847f75a551a12666e93a6ad673895213956cbe43d870f6f22ad6fb5437157a124a6ff61533e06e61c9b455a3a61bb5b6f79788f0a2e76d8673f8bff30ed471f255efc9857ea375271346735023f529111f700653f4486721c3f26ef1800e73d1681286102be1d0f0a39c11c6373400d0fd8340248965a631e25f75c1e103a8018c7d942589f3af667543a7006c7be2d4c9a34842f6b6fab76346a891d8f86bc5c92c7b6431df24f5ae527ac4a251783157d65896fa93f591b91aa5e312649f457406529730f51462981cc94739e8f02582fbd7d0cc5fec4565a11986e97203b5c1120de27e6b4d734035d6c543f24e46ae1b4696229b4e53a3de6671e249dad6f16555f32ab965a36ecf57619d1f1b73b6490ae268db0d7108fc93804300b640313e48d19719c972f94318a5f011c1f62a90a646f6ec47905a435a25101ecca7190166a73e69e260e08874f50215a172bba9594677321123c79d2793a99d5cb0171cb2e2695c23a07af19cb50b1daf614b3e87d559e3d120d43ce7e5464d9c26d1b900b0e7258571f0b443545d1f61421b5333e2da594a464d7ea033333b43659c055c75d9541f95ae336415425adc1132016961dc11c935d53613912b0e66d260cfcaf2ecbed76083147d1496770a266daf33060978c9f4fe8adc0189878a037bdd8c05c2bd6565fc404a511366e1f64b7e5864bbcacd2612173d4212fd0bb1aaa13c7741e60740dde6a3c4566edc03c01873b5c43a14a7702d3b559415a0d251608af216467d164588ae56dbe887f5e9a95aa63d2eada041183691420547b09da515924337783037e153764ab68920f19a39451d1f8a25aa7b79e18b75ab973020dda00f0cb0a3178457a29f6c3580c2e5d2a131da78b6baae88542954516007a481a007dc5912127b1cb60e41b884ce1599c4a9b35164335af173c8030a6421438cf702249df2e2bf3fc6c7dfb3630e3106f2d34ba7a3eeab9805fabf0c5453b2f5f1048bdd7010917c0137b67ba1d2b1be72075f694585cc503733118940ca6d0c63893023c660e176d18bc7d78012e281d6f9e012113cc08756c5198885621e9ee74b380cc2d248d1377f056542f5a6627527e8b2e5f7453ad386d0ee1407f531676f10c242e23def93c82e6716342f7cc23e688f877d97da35c2c771742a8f4663bdb52a147d596ec6bdc4cb208e6ab9b051691921b9e47752320a1ae50dda92327a8f311217009e0304ddd7e2f6c06141f924bb23efed0c9311b24b96c0c9be31c2358a463c798d03ef7cc075e8580e016fc4e0056aaf31c59d3638b422239c03e6dfe810ee53721591f8b9202c504720b4beace254c84773111af6a3936117e6d3d01781c764357170fb99f73d3dd075cb9ed126a025e903f6be9e626474b8648cb27f36b5cc63c681d9cfc6e45690e65fc14e51665b46a5869a71a608c819235ea63d9173a27a501c6205668a42d5258f998b650d751015bf6ded6583e37aa16da5b09000e7b2320e5d8953b00faa240ac53c43354ab5d34a40e0213751f3e5490ee171c9e5a476a58b8a20fe6b12e63225aa14db062894d6512a86b5bd222195161f60bfb65f148622612554fdaea27abba1737c81298534f8a6e10966e3b606dba7f2a3a5f43737e549d081c6ccd0d9e84e63137a6dd5bb4305c12f8d8a0280687bb2ab47fee6e4018a21ae477c63d41481b100c845050210625702071513a1b81d60fe4b1c20bfbea83231b62637492ceae28e786355af4010a1b6c51aa5bb4da3a6c5b47c25044fb761a65688a2ea199dd5d2359aa0dce3f954ad85b88426a70f42c3851ec36df73345569e6a3102c7d014354f0a61316ccbe6f213b5517957b8f26c2dff508a94da41b7346d810a31d84173bf64f5660bda06970f13375295f8712235fad5383518d154c31e6511a9cdf4469bac23d3bdf33194044c60a5e18ef0e22a7270bebbd873dbf79035c31c60b3d31a3021ce1029331edff9064d6042130b1e12e3ed24a1b048515b27719a70c765bb343050619d72ad51c272396a84a02fe3b310e793cec29849f1819397d594e41ddd20889f6b547a4f0782b7c51ce18e6e7963952809c6c25d8d334f4b77d5d85e1b3224950ce0822890938396be21f8115dc5b40bb312abb51bd263dbd9d12d768db5e7eb135058307452ee662b23d2251c94746ffaa65f3cc662092e4255cb758535cbddd3e0a79a4660a6b9d186a02cb3301af24826d879b120c046ff532e3218c70d2197b23ddeed444baf9230c0440fa313a18f31a1aaf5601920a604954f92f390123f62c1cecce1df7015c37cfa7ef440cb9520a1566cc2a2da4eb409bbbf26a4f8aa254c0fdf640d0b2830e6f252432afedce6e0c482a3a4e8a0f2157445d0a13565e775598b55375f5e66da77c404d88edc8170f537600d3dd416671c74f084405ca558829a62a198135520481001c55a30c245d27213e1bf645623998792854a5f15434c8095a2ab3b93a4fbadc12d08d37121a23003f3290ac14305086180743496cd144471e66e17239997b4c7360eec8443e15540ca87dd56a4fc1e0242116fb2d257a19170834f81c9abd815f19f3431f535a963369741624d51c723e2fbf514697db4f4a4f69fd17ad74fe0e9b6c3a432f1f2e49dfd9ed381b42d84f44f8ba0e1326b5123a583073f1096a26dce4d13581cc1c460f4bc06dd50fd621d06ef869e003e07502d8582f5e63435dbd7f2344f86f47554580db1d3647fe312b7d485f77c7ad37d3b3b328450d3232201a5c674dcdb103022e30207f61d7359830f509d680da3afea4150b907b052be238735a71eaf740eb88ba182d108b41e581db4e485a76716876d621b11529267739b2281457c6168ccd2122f0d3453d476a022058c44a2cbbc3b077692c0c07f2dd5004c6510b23ae4f8967eef79d3034c61725b768b35b3a87705495657621559acf3c370d21031b2fed20ea2f672cd5db5b10d77ba70a2750b76d54dd54423e6ade679c8fd206ebbbbf1dca356d5132cb1d71a8205f251f6e232b78610f1d29f5b405991f7e0579bc4564504ad44bc0e7ba1af2aa6a45967f5a61d37ab3484a20ab54166c3f54ffb184358794db33dced6c24e96c096a6307ff0122e1133a533c6a0947c16f1ffe0eb9094132d667f3dc78152f86e83f2527422d83b84140973cfc4e2309602610753150178a7a77efe14d50efc335179f23520f6dfa1e5cbda1432429dafb454a7f8048e5f21e03a998ab10831b55004618f74e5e5cc50b2f7fa1111f3bbc21ebc27f6bc65b833e3f93034dc9aee21642217e18d5adb610358f28248e5645416451f512bea09258bd0af1726e54fe23e6389a1b9e560903f1a8f3321d32f925d8237b6eeff92d0212c1cf18c1eef80c36751d3e59f2e55a7b861210079c4130d8dff2730f3e615e5f78a5078d2e47237e80e475578780161196b55d2eb2f5166d8c7b0930c42d08e3d49e41cdd81d5a870eb43ff02897648f121808d3549501e8742f63bf8648473d8a211d74a02c1a983be716e7e5173bc85f585c2b34936266b8e0100a830c1a8ceef045b726e6053c696152b563475e1fdde20012cc71072a3d201f42bbda77747a7a3aa0d6c716bdbbd35e99305a1d25c56d086172fd4cbcd3cd76f3631401a4b16c50d014ef4d2f7f8b6547a1e262996e9c67b8c2504064c0090602577d53f052dd6710012a68b0e35d53548c111568969f3a6946391ca2e0715d02c5f30c109a0514c64f14042fa0a7374057df5ea09415258d63c662242a6459d10e1872324ffd2dcf6aa56d8d6b2409a8b78b2c9b58ea0009cefc58dec4d63accdf171d5b5b6916d665fe63597607768b362935d20d6c65e4721c5542766c27bf7b1224ce35bc17fc586975e230f4292439812e51f14e297af41c0c9a33bc6a9c544d422b345a5c5b9f2e086d43585e68c5184d7ba0712efbe7173bd19606178e8cf02d1576e934e39adc50996c3a3a444d6a2a6163a35682d77408c2bf9e35155b05682bb640424930313eb66f0c5cd1b14d07ffbc832bbbb4aa1d83e6de4e5874b25fa3f6aa5000f5b80814af6a6457558f147c3708297c5c46207903ce6c9b8a85044773f174b93ffe20696a4262dbb2cb40fc6df319c39a4510799efe72d23203052daf883038a21817c4f47b44a135374c55c8014919cdc90073168559cdedb66396846171d697cc28a8ffd1199ec4393fbffb863d94ed171524e81f21858a533684c70d731f14ce55ae53f62f83cf754517bff918c805f746e355fe2156216e5beb34ba2b3442d54a9bd1db410207d42c42bda83dc89de80fc3a4dc5739b63e4927fb3f1aeb630154a71888099581a6629d819f3451a4cc1235500a7296a38d041f8dfb16da9e8d60f413eb39a74a1a067785911d7d06c96646c53d4585ca9d4bbcd3af136f871b38baca2204989daa01f690d977cd971a6c5e6e005e3a06ea1e4abdc575f8f0015872966f59d88cf54b6c00133394cd8f499c22b93fe525bb1e49bff268f66c75169f3e1113470d511d181a9e229096b04db865ea6a9f80a01a1884291d9f4fad22d4b577380dd4442b4c12e83e4859906bb9d7c54dfa36d65878fc8611eb24a418705db2223ba65763784c7539cdf5612cd4e3192ba93c5f261217ae5b5e6f4a23d4d175669c313e62eb8431523ad51914f6629a4db7e15115624b1a75541a0f41d499830f695afc3670797c2d6f43db0339e4a745c0794d34d688ec0f650008021b722b0bb8a0490493c91123b73331669cdc9d5ae5764c526a9dd14f996a310c0148160888134652d87f1f6792347b541338a37515b01c468b322a61e463584b4c84ad0ccd866b7283d1955ec1141e1d8f742d2a896c135f93f14d1af6ebb3586854c7045e33ba19ecbda901f872b44d138278652e5bc8017ba0ee568e193f1665450545cf350c09b76cde650534773d5f20f21df7e8df3a66ad9751fa1c3961b192da6de9567838ed643267050a776b98b1c2370f2c40401edf8565795f417631d6a560ede4af5de2e4c22ed42a86001ae8c5609c792f77cc285508e9f7aa64b4df59720e09ee1fc97ca73001e50306332ef93e9e67326f100d772d26d96545c1a649514d3190490948cd717d94193b562b400f4e9d254195e31e6ffe71a81f10d6c64d3cb00355a613ee7525e2ef51c2d5e52c6c05762f2d16836e313c534841ed7b5527a13854e666234a84d7c83623437b26c49e9b123010e8626b0d09285ccfd0562d8b0a17e80a794e5908b30de8ca29722ff3d36cc11867377c88b01732fe176fe70d583c821ecd1c1872145708ab761e407a943f9b58462e9aeebd6f52121a61ff651a5dbb352175bad3486c7c9f2428fc160f1175d65c032588c34737d2cb086e12700612e89f4253d2744a619c1e131e2fd12a88c0fe57e0ea992a5299ec6960ef1d73f127f2508727fc2a26f7496c505665410add033ef2e387393b6b88660d41633a6f8b9f5c2a36ed20d1e9920d4e6b9d410d1ac763ed08db546de20d4f4dafd00bdc21474169962276e2a5f025eb816c0a06e79d19f92092584957ff4fa70b76390e37dc43dbeb7f3050cf80708f52b52b4ae4de7498c8e95ab8455d2153194920496e406d816db3666b036905ae82d568cb0fea68e090e366767606200557761de32ef476298340127248fa133e67fa74c8e82b444b2e676d70277967b2c27a34619d955539145107e2fca24ca298af4d92359d4786fcc53f266d84645891416409f8432c293a685bbfbefb38295fd219a5c17b226c3b3f1a697f610c426b9149aa12b0503e3e9d3aa67a984bd4dc152bbbf6a610272dd94914a3194513ff6a0a91c9d2627c20e66294270a5e136b2f620c60824aeb57fb5fdd46bc52fea61e559f2e1e5785861e73d5c7595677152457bb17ff3c3617f10d77ede45a7010545b4cdad2000f02350fdbc19931a47597526e671273df2206556e96eb3ce0447d5c042b6d31c445b31fa3d5f11d927165773da30e529021b42e28913a0f5721e46f00cf57656018db3f8f9ba832473b683be37ddf3952dc5a348b2e2e6827f3e5540c8ba95222f4c9608e32cf6ce45e215d6cc1fc264b7f4a24f44f6b11a918231d777c883b79a0ae4e08b5b26d5fff916ae6f4384221f5e05d4efe696fef09863d17db1f499deb8a212a4ac64b7b5c40068676542617a5e900b068ae345d511b34e1e91c6ddbf77133b7d20056e818ac2d56bed4756a547e3976828d05b302a20fd784a01aaaaca40318685715f81b2c5b2fddb62e92b3f770908d8a0efff2a05c8a8e665b4c2fb8017942744f5a8ef3102828bd72653bae258b4d455ea6b52375855dd26e3184381af022810c5e5619539424ee42ac9086297b6dda0c15e2640f3e695b426e06c9332c5de82e8732436a59d1c93517ac8d7187f0f839ec35c35a9a72451005dfa14a2c8ca70fd0f60f0a2897f712bfc99030c857c036575bb83355c06d4f063491086b058e4689d3fe55d45a242254b4a81cddcc09632bb1f00195bc3d6a2875854a5615c32b8a8f812b9b81f93ca79be8675ec1ab0151e9ed0b0d22616f873eb349e726457157a2021c683d8c60bc89ac775bcd645795cfa12c34e24e08fabb1e41ae15f557d26e315cc6677a25e1365e538c3f1d29516efe6714477862eb96222bae7dfd745d40b40e9788870b03c4c42e01eca868274ec429c117715637b6621af1f1b863aaac894752c8d7443d76455e62c53d1e4d610707ae198c46f5b02e190a14e5581a6906496ad3431f6784773f0eb74717f180fb4b0ea138081dda7b288ca4b73062332959fcb5e84a645807088c887612ea5e9265eb64c232af71522faebdc82748c65e2de4623f2611e38154e26bd44a1b9f2d0ebb9e181b941134101818563259ba01319eb445357489c750cb686f0de8e8671319ce2256524250441f24cd0f8ca6b3270eff8d164ebc151901041d67413dc14847dd210471a4952d60693c1c95a7a00bd32148057c7dbe661b2db928236a3822bf03f874066b3e37f6c5eb14364ad43aed2ee83a675b331b7c357d65bb1b950e7d0fbe016fa2190edb88ce00a0e6936433b66309cf3ab85085f2f639d354f23d3a243231cc8d7f1178ba361307b9e671e8072e5aa744d84d43266e0aaef838036addc90241511c1d48c4c644189c60388cbc2438ad0e1a504f8c371ac48bbb45dcb9c32e364b61541e6a191a6084aa0db2de675b48ae881c860ac151c487113c74c6973ed6556f242390660aa94d92410e50d21f540fe66164af8f24e6a82e55cf0b053437b6d7455ab1d053474d776f566f8a555b234d4973234c3597fedb4a640efe079df1cc68f7999e310586f62b624b7649665cfc64f049cd4bb49f376feebb8203c32afc58b719f20435cdd802f35f2a1e2a21e33f73579f173354d0284a57234ab992194fd5963422e808dd34b8536128cb87853c6bf6393a68bf376018c9fa0d9908381e5e5dbc2c225cb36a1d20b2477266e73235dfad1f426ad13000fdfe2521da7b191f7d58307645c024408b254e1d28e3451d258a2fc7f1180ee6f04e55d752f54a8553254a179a7560944e5e5791430f09d41aab3f4ef04a0571c9622846ccf8135d50201422fd80770acbd56e88c77f6af64ac1672729890eb58783277943214b5d9db22721a6df7038fd5959ead4ba0206574b27e84409321d60303535a8cb561fb1d2006e4e4722eb252e5b9c9a571f9b09161c3842e3381a4fbc005fa9da707439da34ef14585fcb1dda2133f69a1208263135e84108342f45cd694b3d0a0394c6912b65e3f344528dd569d9caec3597a7e400de377f3b856486257a9f682270ff535f3b9f72358896ab0cb99c5c03e19d27002a5191035d1c9f3352c01b67751be14ec93ae10ec7af3b494d16cb49f671e631981d5e302929b85cff8e914108ea8633c97cb61ead6c95018a9f84364a18a1242b4b0b661d4fbc304f2be85495e38b1f63f3f7552f80713521b5190d01d42957a3097b0d6f9e6b2cccc13618b2f92b1124d038326e4f1e039b19b95e87689603276ae433f2ad2d4ed91a9f166e44936f836b6e2895deb92e16667e5c3c54ee360199be30632ded4bacc0ac67d4fdea4e1d0e634bc1a0df42c2ffc21665e18f5b2cbd3d1ca95c431e5595eb40bcef5a034cac637783f3ba72f50632648d270c51821431545deca905239aa4083daf443f93e8315aee012f4cb4cc6f431ff1a575402513033f56af39c886765c5bcbba41bbbca6163e76dc3bcd59021a96a22843cdd60c7582de4c5d04f8bc31e0fa0a43a52c67019b7f186966dfec60f3c31e68c6fc91638249e914adabf13d79c7344bc7fe015f1e83504699e9c66d845f314f1b9586193e336d494d7f1b24365be45fa232c64911553865358b351cebf6387374ee5e567d7701339803cc1d40c12745bf93993b7e687b073c3ecc75d7b1e9257cb90530a6de010a87ab370fcc7a9d14a0cf903ef2cbae269fb61033aa38d43b776366289d45b4131e56fc04714e415dcd321135d1dc5f728c650a128a71f901c8c67e018ad4c90e2056f647fe73f8031206c723608b9f1edc471f2dd7e59c39bc12095031b5e002e1a07e2aa097d87189bf163c5fb3f807b7217e30279dd94c45ae5e2d83ffc1509b3dd62bfd4be90858bf0367ff6ac54e302a756d6990914489873a5261051555f88bfe0fbdf410324003181962335433bbac9956017f12408b9816351df78e69628e52479e18d36b1aa7ff60ec45e620fb295c27d9dd8b168864c5002b89780e4509440c6814f73d4aba214afd81f711999c031d55f4963873a2a1154b7af06e5be3de70b4e1ba48bbbd0230c96b1e6c0724b15d9db16c124c0c58734eaea4440d17d91341d9fb67e38dd93dfcd60b64c5ce011b29af92593c3b3e11dc449439ea61be2fe7da400812ceec33d677b43dfb717e01d893f92c27d24d2e3e7cf15a32283a5fd9a489536e999729d9d6aa6a10b7dd0e875b8c203f13ef54b84b9d0150c82b2c3778b744171c72199a76cc2904a37233531cff22cbd3042f69376528c84bc73c15606606c8f18a33d8b45e34da2d47607709104842ee0855bb341a6230922c517230724e38b0a5593e9b8b64aa041467ce80c52eba037463b4a03322c15ac2141d3b92523edf2a22e75a3552a71ade60196ec50474d5b962e2935d6c52ba7f259712680ed0b71d702a7b491eaba88177067a0e6bdb8f5651fa0729321fbc1f6e2b0caa72f9464922cb6e6f6dbf69716ec08ef5418ab1ad0c701b203c364ab44fc4cfa3152fb70744bc4da501d6ea5c5748c2d8144f97b86bfb4ffe41db21654b05502d4473087062d4398c3fce2358306502b872f2c96c5c85b86544460feb334cb9ec06f427af1b0a1a0f20967c7b483d243265df1be600e3870437d19fba15fd3ac06ded22794c27b4da02e125bf15ca463038774c700bedcf0543e2765e5b5035da445d2c5849b02e435b5f679560a22e246aa00abf6da5508b35d4123b1267f8986205955e71e9ade339cba67d077968692a25611400b9a13153a20b5d0c317f3269e494547407d4f82d7b2a1f2ce990a76955b01219b0908966b6babd54edd1ca4a41d9ec22f0776811fc45bf587d7c5e3a05e4f35a7ede42135681b63771b7fc77a9568a6c0a6a1a4261853e1c6b01044bcb10f236558aaa5342ab4b5d02f8d3627a18927468124212d9c67567c5d34270dab36e26c78a0d5866ae9f6fb862a436be12cd4a924c65197abe70039730506457e7e82f441abc07040fb718438a35035f012e57b24a1f0a9d0bc635590b466eea285c63facc5d0fa9b69a4664fc5d3b43635e1c3381d7416888c006e8070b6a2ab9a715d974855c8403bf6f402df922767c8d0117aeba26b37dd91bc6d61c664dd1c040fc067c122a1de6330d570c730db6c02c5db3306c2b6ac4588c9a9c20623bde5828fb00067bb36a505641b458190fa65b69800a0b99235b454d88e74e6b038e436e4d530659bc0e248760ee4e2b173c47b13bf2247722276f0b946e4300aae66a098d572a4785e5776d37635b7014fe66b892a5200258d108c36af4300da49a191c35051161ab093b0f337c0e51f4006d5413c70e7e08427233d5e83a881f8a4be598e94c09fd8b0d6108403b98408714406e262f04ab53062f73731adbd53e4178e91630b8a0ca1bd6c7137264021376a6f9906128aa580f216aa2239e959f47498be41e68939543b3d5752d61ea27271a67f24d2d28b4071bc628116760c618c4043528eace7a6f2d78e863ad8f1e0301f70e591d330c33fb26e54494cc12201efc253d2fd0500ecb39d43bfb06334e76a4f72a910715054661485e35d9672538f3414167c44047615e0b1bc18cdf37102b0b3258e3285edb36675cb3448f5154b93d3a69959f2d8eb9222f47557e0e4f0a586d09086452fc3a18628a17476795f2d272f0ddf15352271e3be1791d033c3d09282af2a30bb9ccb961caf49235ae719174c2a81d38c5f50e2540964d2158d05374f63ffc29f8727f364ae8335d06e3d06d20adb12c4b03110680960c10c1b46a3e100aa0260d16a47686d8fa6b61106b1146643f246590b44cd0c2aa344988ed2aee3ef80018d58e0dc3d0fd5bb63285488bd318723eab8759d398905efeda7257b4da3c381ab1d806692fdb6d992cb92f8bdbfd70d4cdb4082e597366f1ad6d15f3160f02e9372e2ae01b1b74f85d9376f80ad83959ea3a2cca98d21728e5223e44317b0736529e33d142325d79a7836933f1655bdaf10f247d2bce30f68ea5442148cf48014f4027c1fa8630b2b8a2025020e83e9f3d0f1546e22b327ba0d763453cb9527439cc2628880726fe2dac03ff4e7519574064299c5bd14622e79e2664a19043fcdb282e99a5b9688ec3ce189465e872bfa42e7773d08c1e49539e4bcf73534611eee53f199a6b073a83201575a5f81ae532c82e36358731a7a49970dbcae668817bb443a493d72c0d452f2179360f39c59a6c229b3cc60223a01d532b127a7101c16625669ed11a05a0aa6e9ef7321f844b2108ef002a4ea29ba0014f12f84f864a8b773f443b5e2aa38432b5c5b535b58cba32b917b00fea156228632209128f5476213354a0478fbb20191196f83b8a3b1a6fcb8c8a501b1d662199be664f66461a0175c1a31c3466e1233b5996720813c85344798c1bf5489d23cc4c3845c3979f039809662d2fcd7d48d172dc15d1fcc469882c6a44368d565d5bb03f7400c5024d48a5d307a3c24f73b6c1a253aa19ae77dde3fe0146bba26693fc370fe0d2db6206f97c5374925843d188f73eaacc6b5b0cf1760df0bcfb566bd94039fdfdf03c25f06e6e848969424f28176af79a0b298c5d932bb84a094afbe8b773b845165decc4230df3feb87317d09447f37a8a52832d4832cedf9e181ce9c63c32768e386578e97246deaa5283507170f458440a9d78653f93d79b478c513f0359233746fc57e57026972114aae54128b89c49430b0dfa12a8c87f11a5c756488e9dcd44322b2024ef92c751ca3db94bad6e4d5979d7f4235ffcf353a2aaf60e0efc544cd3d71c39d3a07f347ba6f41f7558fb40e234321b85e97d1b9638570db218c3230428072f9505843637bce35aa9e40002a7ea4e124aa14242ea00dc4674850c17dff6df2d6da65a341020434d1f4783328562e163f8dcee277fca4220f3ffe046c938a44c67c95b4fbe5b5d0a934e4b58f7e5971d6662262c8a66eb057c1a0a64314144165e88fd50f66aca355dd65330c78bd92a2fa79d550c23220bce70af06d9e107529c32c6412066b46e4152d718b1f0266e7ac8f56fece35f44846d091bdc68110106759f4b3ea157634a75d758b789ba42b60eee2a6128b153221b35002440d03ea08e0316de52e66b09c1e40325980e68e8a2f7222ca37f47c961b7167a3a55744a0487556357443bfa5032570b2ae94e8af32419380bf7519a747256ba883d1e4b304622026cdc0aa1e72266fab5324ee1407a08b8a96b76f95a252efcc57e50b9663245bd22b506d5e9854ad3738b4f73c2c9592509f309f1336647eecb2b63da0d61540a5a4532687aab4520316c6c13b2cd3ad8cef94bda2c52750ceda829cdee7b5a3b7d6657991178362a687372d3d2892f71ddb639d6ebcc4dc4185b6336a9b56dbb88aa58fad16d487636bf31de836a5e321ffb3f20cc2f306091166aede6025b0625852b92050e4f7392bc5a69e4362dc24e18401a78472b1acb156e155b4e0d66d0632324ee943f5a5db6036891274af57a7f29536ab450f9dd753f1961bc0ee6c6ba683a36b72f8cd86648c6d86a6b24e4c5750f4b3b3c6e497e0d9161882b7ae0d171090dcc2f3015201ba3afc468d8cac919eff2631a3204a814749b5760779fb1464759f61fe0a9d336e77d8b39c360dd279097ab11a87c8f6395eaf8002c15630e298f7c4092b2f12c8909622184d70656ccb08d3a0afb5a52a269c918a3473a5b3641a800f19ec041b504750fa3c329131207dc2cb1b2f70fbc40fe43b583e3508139e95bc1265103c3d36d12d3560302d30df04e428c83576c7953039073492813f6675ede11bd154d57d3196c121f08e07104547c0a301f63c7ea4a232d0b4dac14a63bbec3af636311e4306eb4e501c6e85c6564122b59ed12711f0297e72ba7c47f51ee25126e850c263d7188081e8b8502517350605d5185323ee7965a40376d37664c69d92d4faa3c089ce56a274c692d0cc6069f0b8f619461d1568007acae92118eafe6086477153a11a7fe0aa6bcae164e6d955ac246e168c3eb2a2a15412254fbd83458f2723f08fae71c44519fc92bc5a4e5186c55e45080c07c70e68c4f2ed91a3606915db40dfbeaa45ffd4b2c4b715b6f4eda694410a68f2d6fb92b336665bf3e5267565a636a4ed214f281e644e7ff554fdd6ce51d96ec3b62b8e9f152e7507529e3d2853371a6db14dbd7e24746ef8d1c92684d3a08241536be845b210225a677b598b664585ce60592a20010d1b47474534c005b5a026d2a3cccb51d763c223d1cc7386b3fceb553c484a8587d6502507a97b5077442b55dfe0b203e27fc945e3e5ae43175b0b964a74e13085a86974c28f98c4a87231b3fa99c0507b36b7623a786c25de6b6b20142bcbf3e759ba31fd7d85a6f97334749456ea941d818e62c1227654f7d0faf2dc5790a3b4f50980e0b46ef01afbcdb1a97588967dc87f71c47a3003815191a6b46c8784ba7be763eb30c5568df372c6ddba4513e898cec4f2c535b76f0e87a4e653aa8324fcec90a011b84390d4e003f0067c4355d71bf699fcb1870ba409667be8fac342be852331937c6096771d8577baea8736ae1fc438528c1137a503e24d7d5a71ec510594f54228b766f3b0b495eaa872941f54c2e9adffe637c7bd41e8a9678205efb5a1148f0365a3de3f8763df28231e544b7095e9a36414ffe5d279407cb012afee660f7573f5377bb723b06a74367aca10c6c06d2fd5c18ee0c5a6ae6b123ac49153900c36774c06d01427d341a1da5290e5a4aa909397dae250b098c7c03dc86c04a17789167d9388d149c4ca60be31b26372db6c9768f631723c4e3b953a7e6af5a69befe345f1d0e2dab165e495ffb4f5653ba8b65957c4f6067239152c0aa28252a151526c882655570469857ae4fc41f3af97d232c45dd25c759dd371d6d40454c400612e5a8de6f79f39753da11155c2e09084c4a459463a5658e2f30757f1754f1851b302ef00078f59e71dfab3113859ba5640bfb19020118774d7ac92e4f10c9061fd89dcb4d2d935257288b416ea7e11507b930606e604bd5252d8a7f6622ecf6452ff014397823912035e5d402f7461303fca805766e8db903c94596101436913b7ea5882e06089f03bd8ae751b4d32d50bf0e57758e43a7702fd7e9305e746b5c0c7c4b5fcd786c747334bc4312bfc15227acb22f7c5a942a2368270e0d613313edfc1f600b55f55366ead40f27bb8633850ac22cb7acd20b941a4f1e873dcf54759f1e60dbdaf607ed3d1f0ce0f20d5fe5f2d21ca3d2423ba05e400f1355182da14b370405686234b77b3113b671073fea3aa90e9473a8185673481f325ba81e89f7e3250cdda8635f45fe564c26356b9fb9d46013c5b84ea100dc1e90076732f9dce9229d5b3807a50b5a233564e82193c47f65164672612fd3971557f8211d6432af0cd920682cb7030537d4c3ce6101caf40b87198f4ddf583467dde076425277ca39e9a2054c4262911c7da34a55bfa11d6494bcec4a95fe312dddefa232b65108081428c8251c88c41e2204c9131a003a5fa95ed2627e14e154b1b17524c303982b33855227028d22233bfe4345407add16bcec8e2bf5daa80a8d30a759b43fc46fa35f9135bc25540742cc8119536986176e58ff0e80421b2f40955858feb0396139f06d0d0d39bf257b78b66743be4911ccc6873d4b1596660665be2c95db3b3ae7cea93f6858ee59dbce274c8ee39805943c59452ed221347c21722e63f5dc56c191e924664377262b17f0266d8f9655d941f722e676d22d77e6a175ae5bf67485ac524905014d3bbbc06e4e3dbacf1878f3762396038d446b0ef04d33333f483abd2a307375f7366dfdb41f26d88b268b7ee31a15db9a4e2e8c4344af681d4152cabf6010ba507710f5f547d569a90194483472372537394d56fc511a07ef5396ccdb67f3ab9c1552cace71a3f46f1deb71383a0bdc1570e1e9a255d74ebe0b230c5d2ef9927b2cc363090da36fc809b4376a500174c43fc236f21228699428190781716c188d54cf4f2573d45093623cacb312e504815f12ed99018fd0e66de97a97491da08a194f7c202ac8b7fe000f7bc63c7768e36fdfe2594cd2ba832df577056a7a449258f859665df445f96d569d5e3d2550f412053da363c078475be1710f633ef1465ddb26831bd795141467a7b25993808a7576ad48073a7da42f7fadd429acafe1395c56a563188dd0164ed30538b674e317d03a682b958ac9407281827202dc743930628c47e13243342f6657484625621587107d43b3c9541a42dc706257f8534a58e3cd0c6896490801c5b329cff3e04401609f2f08622f0b7a4ac465377d452128124f14722ec362fd96cd0d196090708ea3fc1a37aaf715ac1ee976fc60df3513c15b4501f04c3313b65c31b4b20520d1d2883751a76243292da573e9764672e81c7b3bf80b53488508996e1b41376cf0dd0445632a6f501449611f2515e415ee91f45744f814260f5afe5a040b6f3e69a4062b2dd286681d06e5147c8cda2b5d74ac2d21167e1bf94b1751b61a8c65c9b394072799c225c6d16560830cd722e424d4450ca8ee3720bab76e2d5ebb50214a9e700a7906002b8537326fe1bf072db8aa364140dd167496126b4015f72ccb754539f3c8762cbd430a19c8b70a26e227a936b571905cdfed9b1ccf57ac03f3f7cb0841ca621cf0e48866d2c93f47c686c13679ba4e2e2aa2bb5f27e9c97778ccdd6d09b54b0e318a742fc1404765112d9402aae07c014fcc0c6b1207ed44af7dd8717282eb4a1f3bed3a6856fd2249f8a4510373c9458edeed044b3caf5dae16be124b9e9f5ba004ea073401c437a2dd1035628332314d639c584b42896f09f925779982d36a954b64618cc542374b93992c6a61d575ec153b75be20a775b13243082fd60261ab8f352591c05719e16448069cf0993b7df4fc3cab32aa482b8d474edd5d0567557096766f7e841d2fd8ed25b9942c241b7c2f3a63f2f96b47e1773a4d6c4b28b20c3a0a2712463d6569350a87233a309b6ee232ae8360581fb6f5438fdca733319edd19521fac2fb89066482011385b7d002d6ed6c3260c7df878451162a231b56680189c8009421829ca61466d351a0824933f89538d3d4142b101fb23f14c59c42374c18838162e144705e32ee701e23ba838635dac621ff6b55c9d032a56594474776c025d76455c98360471c446cc390277638334567a40ef5dab46933fed57b13016a7a40aadbc8d305e75fb1f38940e3251e7a55b9e1c5027a342f20a2e97f35bead6b970683bf0773f2a75476542d0486c46fe70328ef04c6f45ab24ee283a1d5b5322475baa7b4e12ef744b2e971511f5a05e6f32b94219050067742d14b411c436e471624b7c6214788f02ed4f233e3d6c1f248c83d70cc9b9a668ccfd3a374979894e26140d43609e22239d5e4506f576416daa3c603f43188f41aca254438b66281d3e88ef6147fdd92e93d9c938e57cd6641bed5b197f8dc02a2e17ee5c2ae0c40a02bc646050949b5339105e150ec51117c93e941ba2b52a0dfb8d8b63d3da3c3a47c2fa7081bc37700f34886a40a1671030124d0c4d6d465072d4be143147f356d1df963cb6039c46eb2c5455f280d7600bb0f1544a813d72084e2469cbb8915c57bda8519a2bf66de42ef3244644445c1afe953188d9a212a21b174482183e018b7784260f79337425875c5cf07fc577f814154ec6e5ee5b79363e2fa9759c2a7a115d014bf63f6ec2f8296aca29f66d09b2b06b7db1eb6f1690dd4f85ea6f6c8b95c1067d29495630f2742683d3020c29df6401b228f93a0bf1eb607a210a2d237c865e58132f316f1886141fd95e3f5d00946bde9c375fe054151ec10d020519d80f530c157d00edf68d164e7f6e501da483189413d84189a8e91601c5bf22f50d625b33ed87727d8ea71bb1b23626ded93f3c37dc961c413e8231c24e5e03368980485a76263ff76b4d1e38e8f00786c6f225e208f62653cf372f58399609e75533607de0f240e36874047c7f7233530b493d6b478e0b308cda532684e761ea0bd53c6b969458bfa8bb636328074417773106a77d3d05dca70a2759ce22094e7e3529bee3fb1ae3c55a60aa46e4101aff5c398741636d2165ec3845ac931b6de06a57575aca4b6c61fb30297ab01fa8f5761fee97eb5962e1ad2376f9873dfc26e13dd5aa18497135ea06414d2f48d946422596c0003b4762ee1f9a3e6f58a2eb667219b5145f76add2570a1bbf0dd8ae04640811cc4bb531511187f2fe366366551a168b902b554d90207075c86458af1d615c5d0055db1e254a8dae1a572f7a995a2fbc532228b7e62596203971ae230d3c1d161a67e678dc144cdfab2c1fdba14314f70f6d13fbc6333ed9aa77cdcaea37a5c7ec416cbe5b77c1373053029f4772d8366b6d840eec6ebe6b6046d7024c237564af3bf97db4055155b71a02e5eb07192f0b1666b21c54ca1ed275f59dad053d492d53bacca81baefc37653760284600b31f0e08505f11c9727b38ba1e27236d5330250e21185a0c92e128d7ac5f62cca39424b8e8461ac1232a1caa02957243e41f3c5ad8cd1143a0cc1554362525b0299a3677da6a6e03adc14b4943201eab47f915b0b47f6c3a39e036c74c0f3ad818f0400d332954285bff2a80c92423d7fb7145e9cfa832ef38f531f525344b33a6e8289148b6673a897167baf67864a0791e1b2374c13392a922351a49ce3abf01c141fe581c6491b28352b02870174b1912680db243376dd9dd31a612e7306314e52e7b719b45fc068b263056a706ff49b13967ea6576e18485353bbacc6cc17e2c6bbfd5a9281d835e58c67716753adfb952f4a2f7755b888b0cb02b983ee9e9d21bbe3b184eb86fbf65f3824f1c47d849626e28e12dada49b68477b5258ca46d930c7cecf59dc7cb16f67fcd068a56ad30caa4c89289a39fc76fe3c1429c831e17465b075066de06c22650718393bbabe3de3b5c71ccd1fd96a70bf2f4327cc5529711ab16155a87e375a09be6a65ced47093af2656c4a9c53662f5dd7545b17a3d5c78b011efff92562a9c671e39f4bb45ef39c64a920dc660a91735691cfb0e6d22de7e4be97be5182f600444f34bfb27ead4fa026fd7d36a148a8d4ff2305636b6e6050041e90d08dc4450276004a66d470f466202ed5e21109b9a20e88e821e85518e5c403e2a228896470e3168493f0a34c945041eda5b362d0607b6251a634600de49d4e90a26da5e66066f891f52c12b4e1242948174dfb327714a52b254e4a6dd316fbbcd68b6cf3122c479580740bea12618d1c71fd46e8d74662c20726ff25e6256363e68442d9e37c794eb6eae20146a4c2a3e63f7063569ca59fe10ccc4f73bbf395d3f4b04336026586f1e5ad40e63f7d24e54886c6d5c884b44233a079b1280e19321d32a425d6426691ccba7251d4ca4c7198021ee71c19d2673d3c10954b94aaf17b60ac423b021185dac6f68266749d03e138c2a569f98af452278f00337424d0434f8de0fd310ad4ee71d0f75cc000e28cbe87218d1fda34db34fd10b0f79ae26c000a166cccffa02f91bae2386984745fc3b86181f121b36609106406f420970027e9f1ba95339598872e74f01bd3d46416aad0b7041a1194df67249f7153b36b2cb425b05fea920867ace4e5c072f16f91703322ec48b614411cb025b8d8726fdf80d378efd0d6af68a7b66b9c0c26705c42450843d5720ac56d139fa926573a1053171b048da32802d694dfd824155f7a7093ace5e8e279bb7453fab488f54827de80cbc973549a4a7765ed5ad1540cb2977143a64e506288ca92e7df4a44d3c344f25e451773b91693177215c071d46d83415738487061a9ee211e425444259701b37ea4b092a146b24328b17430e6935bc72abdbef4c9bd01e03192bcc0ff5fc6a1e3436d743fbe44e6ed4dc386bce6b595768fdbb428c523c678b6e350e7bedf419b94d7a51e1ffda28d8d5ea49d1510318660e396b53a2954ce109d157ad41b658e75a262059b6ee505a6b6314461dbd1db5b00c5a66d5f7442fb0832679f0691bc395181115e9f945b0f522682e03f566976c5e4e82cdaa3e50f4de6478accc766b486e2ab599037612de852322b3a0234825be711259245e09b3c65ba8736d1df7f5404c11dc5e6247198d684c401c54c39f35352f18b0189011883b6e93f82981ae0d0440391b045619316dd9937138eb260a1a5146c031bb23185c251ce404bc0f5276a700c76378b3a661aa3c9a50876b636c65e41b1100c3a840d5a4c25785b5d616a2481a0d1e89910a26b5382743276252e590e83a9b3009254da9bd2864c4921e04d43f0049164a12fc4e47580577065907326f45170529657e424d62c5eb9f123609a3081fec5a63a33a3040ce11ef6e067fbe0cdd2a007685256825ea7e244698e5520d91f5bb330e9f1747229dd11f5e63da239b91a5603a756d292401735150e36a482dba2c27f45af56567102347c766dc3ae56a621fead3a95c6779cb52ef2d7237f6585c716a18db564644aa0c9bfd131a8429ec32d5784f3aa7e25c00376cc1221b027f0148127a665876fa271cbf9717a9f5cf167a10a01c3d7508609ff5910f8e853c37fb6e38592cd9003530d32754ebbe372304f20e731bf7da4e3cb3471ed64e3c059c534e50aad150593799f31c20b46859bada662c3670c247bd5655126b0a7630e75f0959ac1eeb66869661063b22a50be96a466ca62f165e64d1525cb14bfb025baa9f0e6148d8001c07410633fb425d2339f051dc9a1a531f6ddc767fa94c4912bb8a6fd820a05d1e9d150775251431b519d619414fe8572c347a5249256a2ed2bf195244558f24a8eb19471b17d50f65c85f566c0046346690e216ca52da00eb71d707d01c364c51467e2d5c978c637f0f210b408bf2599c865272763f4a278c36273cd1ef7b301588977578a84b00da43a3741231fa02365c762c41cf8d55195a153bc2396740cd4139037c56444216220763b197fc6430c07c748b06564ae8f9001489edb92ac1c3a821fd087d525cbf900266865f117ecf8c4c9b16715992e18f5a453d313127868a24adf6141cc8ee2b0eb3e35b5d9971d448264cb46788f0ed1f4978386896d5454a77d17f7406b90d1aaa409608156219012ea4ba3e88dd495f32215e32dc76934e6cf3992f29677c12bf432c52adb62b5175eb8c754cac443c7dd18461c8766d502ce9d019b27f8b3ad7781f0f9805982a562f750eaa26383315df1f0d995d8809fe40b651146fea2830ba9e0929513c04a40825028dc4e276c710e5473af31821c77b2d394aa96c38548de3006cca401e3500405c3c616a672eb6245d000fe754b2495c4346cbdc6d74d8870de2a5da3efc40d83c35910f04e89fac0095588b6cef3d6132bd50e759b04b1b63173fa70f76149262670e3f40670d2a34a82b0623ebf177517f3e992d623aa73923ee870ee9601b1d0856f2758e6d00725b3715042aeb1640e3fd2e4f22156232308c89689359cd239bae582e1f746d30174a363519a9822c9edf133d86a39817b550e55871f6aa6d82b49e0649227665e0eee4025d148259fdaec60ebfb73528eb46f24745ef174cb7e6900270cd0628a699ef37d09d2f0fab56a956f99b7d073595f5582396716cbc3aae3d5b42a31202060f02bcc81f735526ae451da2c35ee815a64ad852b5358ce4c9550d332d3dfb69c51c25eb673d10862411dfe26518c1031b0ab7d0a3251715b521a51fd36995cfb54e493eef6a9d15c7767ea68e0b6bc58605e8db2a489a4079148f277f65f54cb4246c3c4c056fa9143a3dc9c4393ee6ad0198b10553b33c4d1eeab2db0b91d1cd53173dbf0f0c92fe5c82458510b2dcab3758aa9e4335da0f643d460a2698f8bd5cb9b134770316b510a6173403c916ca6e23363c49375b036ba94d6146d28bf10585ffe60a2f90ed38faf6c0765051a80e48ed551d94b4540afbb4e7547b918506aa14090b849d1450f177d70cf89cba130736b84544bfe250e89089109afad83998f4be4e5578396c49e5471d630a403390026026fb08360dd497b74c55b6e261da2aa40163a1f6231eef2612e172ad3eccb6984bd3624a03e4716f4c40f6f02e984025464b156c23e4228a3aa3dc7f720d8413307eeae61e17cfde7005e91649ea534507bb21742a48e56c13ef00c04467a5b92a315e2c46aeacf20d6875c15237d00c56488c6f2aba009b188187061c151725628ec0735b2e20d817b5cc654d4edac463a034c558364d3b231439f52ec5743f27f9fddc093dcd92304301fc08935fc0536f1f375996c04e18c3daec4502e19223a661d60b1012b1002cc4e61fdcddf86c043eb269a4861700aa645240a3a36a1b1185943ca1c873135b93a340cb1033067d8c5a3b5f95845eb6b3684306c1f44cb333f26ce83abd364e55880fa0b8ca3e5611b0217624d46ef34c80249916841522da84117021f65e9ec7cc0a12ea3b0b432eba6ce8e1d060a610395bae19c65a6dbea960f0d3b813a8e54a391d26a464d77c714a6eacd4738a0c3275695bce18a80afc4fd96bc9659bdc133e3cf9c92d23b600359c4e6602abb8973fb9b940350ec8e375f3ce256893a7cd74c82a122ffec3ed514b593770566fa8739b03865386a535418abb9a6758259c45a136e25034a57b564300851c5bd7ba3a3a762c2d7fcb38305642f167c4c8ea53583405239a4c973dbed04252a2a549284bf80036feb5d1678943fa303be580172f21ba66657a7e6d42ac2d10db500735e5c7e926d5f11415eafda40f3b0edf456bfbf23971078363debf6764e1b4d81036638d61ac3db268aaca8a49c803af17d9294b70b296445a4a7ae13d98d3005099361c36f252e74b61310e13c277a645cbb5e443d53ad91705b8ce411edc194af1de3054786a091ed13f6b272572b751d2d8663d41d2a61c8711302ca802f7185dc0ef1de29dd04d2931fb3f7f6aad382e4798253056cf38beb14c1861332e37b459e42873612c4b07f5e17440042008c03c0a26a8e44e4c1df7fb2f83573a640660f123dd67bf400e510d2ffb87a556aa35c76b30948b476d009f403346944bf422b8725446842a64651d75da5e4900381abe433ecfbb72fb864e3cb564d2148c90254b23994468afbe2451c2a4da4aad481324dd126c121b1e5f4478c70c091750255aafb22305c30e2635dd44260b3ab2073a8524375e712e494f0f6aba1c48119f060685a737b0c78a674b9fca2ca8891363d6ddce6adc36402113472f50fabe245ba0f2a6466b71006a0f35eb3366592571564519679a99c250de8d9162e38b9e2c4f85c6226b37ef74e682402c3e287762128103444b2b840506079c074f554a60a29f5d063f27b574bb51df2135dcaf63efda242826c08d1d3f36394cda858032d1fa310a0fba615cd93c3c3bd72b1458639b5c5fe2407728df343d0f7b03cc43e77ae4472b96f55aa41e714d0ee50c2eca35f35b53d9a92ecb51745f6aecf4617579a55a4b9ec4637a48af4c8588fd2b8ce7d05f59e59114bec97c1ed238f335ab13e20d06eb836828e031181197671fa8f87d517777a5444227c90476bbf23f3e30d9570f599650263cca21ee44ef4d39fc3730179b965ed247e421f9dffc25a8ff0b3c322cbf2d1796c749497a106e907c8f40af4d805276377a6a2b3498424b9a591d0e7ffd5d6937bc661adc705502b3163b8cece9067f55de63df2ad529a838a41d0e4b4702cbcd9c32da25010cf05361520a45d02a1a4e2013c931613b551f193a3bcaf648fd1ee30e57b0fa3dfb5721063460bb48eaec4c10f52ddf5e7df5a13603c44a64f7dd20508dedd6180c246a5bbd947f0a9d85c5189ace9e1440ecbb619b351d023e6fd941947bb5575e51942c60602d4dae4ec75c293ae768dbd9f708a0a6161cf7938261ae1e7963af8bfd6443578716caa6c72df363880b04009a3040e2ed2a9211c036b598ef1a18b1af1800981c2f6199690193752553b46c006fe03ff13f2b1a5b299d37704c58589912d1ab02218d4e002a5b420154d608c6258a6ac274e4b07a425546d13f0288c753db86ea4f2ae48f045f14183cd9bd5118b944104b56b3b16f588a2439b50c4127405c934675872924f3635469b046976272f2fa45351c086c9aec675d2688fb37fabd2d72728cb72ac1894347475f5b72c7bc915f13d96759d627ac3f40741917cf31e96a951b926fc052d46d30227d6378bb5b2b6cf3d015a10f5d2ad50b726d47cfb700421267459712070d6760353dd650321045a743027b70951768522528c831193b128ea0487e74042a501ab26b96d4af009e1f8d6af245f615abbc2514bacd6566c2783543fb7d7b6cd36ea55108f6ff1a08d43270d6f7f470f987a50595bd2f3ad14a89675be017082b0f4a3e5da34b76f4ee1d24558ed50313b3f33589927a75069bae240dee7e4311081f7032f4680c91f2ac139abe571c36e6395e7192655310f2286690cb6d3e252961095898c85eb3b70b2d17419716e99c014565587f2b9661ab3b8cafe259a33cc04fa658c61c1dfc983af5bf9f5dd1be7d56526f72392af716718d8ee365d677a361c615591609416c67d2b9cf072d3d360ec7933c3ee426c7014d714a498d57d72f9b1b8b0fe45a7c566bd4d25d5e9cf02109faec574c31671b83aea92d3b3bd65fe7c7c2520c3bdb2be37a604fd6d4d83824c5f31b32a3e71f19558637e488ec599ea812662e0775121a0e4522341ae002c11a8e66f5a50515f1509002e85fd246f63f776ce528ae18bd46c932800a77093f4c7c72b483c83cf1561c5c07f2584298086e4a6a2ba56c0dea643d607ae903ec43763ba891a15805d82027342fc1332159d376c7f935099da08247ceffb31b49b9d53e35454f154aa9be04473b36270b15ad0352837c2b71c51c050d495d1e2423e55b83732507e833ec2a3997c1494a7e874d5d218e3b003d20065fbf4e2e22c9b22dcbe20e2f15e3bf2b100f45307281655d706b722e3690ba2553dea25dd7e6d834ce531435bd5eb80fbab1c46c0e4c95360c3d7e6684c4885088aff7033d505627769dbd3e43d11803c35bf92ded0c882b648eef6ad44e6802ee4da46451a693348276776540cdea01ac0cb06b0a038a590f304f5bc72d2a346fcc4b0853bf073a5467d7139b2e2764450fdc24502bbe60d6d521072450fd0df992ab2114d7cc26732fc31a83c9855530d2d81e1fc58f5afa91692602078a1bfacf7532e10e1f64a74638008447d2404d83a0171ac65e24f1df8636dce3a803db35d84a0d302e57d7a4cf44ed1b5108a6f0bd13398619170c9bd56de6657e625e3edc3600e304329f6869175a6b3d21f838bd281043d96821e1ff04e8ea890b17b5240e2bdd3073123eaf64984c5b6bb686404864cbf01da6c3e32ec08e175cad19156467320a4c73b55b1a3e0a28467b7ff004bd21b3530fe35931cc665c5ea78ffb4caf049e31699ca61206d25d59adefd936e505be68a6561872046c4b4c64d0734f8bee5e2e8cac8f1613a4cb605740e030cc93f42b0f73da248062991607822c4af02be8235a001c006f317b6fb31d221b505bfb21b817aa086fb1e652fa57292b8d1a9916be34c52ff4b15b65fae77e324a3cbf152427033356fb6202154ba537234d885add4e3731424f971608a34569b0c92e3c6d11123033afa200fcec853e3c906f0450b6f42baf7f8a5b580a5c579920d55c2186260ac18f530b2f9ef571c4bee1751cb08034cdeb552ea3314158c66c7c579408914dd7c39201d8721338c2707e48a247fd67d3c74e50d7c7902e8f73d26bcf1780626b3e286a69821277a480bd3723d8bc3b0ab75c0d47705855bdd73e400a6a3b60bbd8a36dfd5092211cbc983af12d3b7201d0ad7287ef9208a0314701f9b1d93ba88bcf656622a73e305ab44652bfd83b1d959737c1726f44aa6bb73aab879a600e5b3149ee0f22346abbab03da3eae316693676885d64320860e38611008a94eaa88d1689f6956325f1600640be47d43ba43e853aa8318779ee84c68bd1a0921c383cb340ca449118dc9442a7281b91d457069249a105754b7cf5e77743cd12adb2bd208b0961c0059fb584613ec466c4a58bf4114bf8f15022e5a473c9186039895053aa6b856037863083175a421573faa1f4192e120000aab4c1c82fe99060d3aca2197a4a804a830590c95061243c3191019b9b1062cf5e81c041700e61c3702fb1daa5ad80836159930ab9515181b12354a49688e24957c8a4cd881533868c421080d7b130889faee023ca2b973bad0806101d1195a57d271388372a33209931346a9ac177285290d1f45e0e254dabbdd2188227305999e5f007c4c203f97bbbe110e894c1e8a8b166dd942d57124a99b5017cf102efbad4f5b7c27bc235b33f7427d215376c3ba2e051865ae2db9c440216c37d8554bd1ce6ca24e895b54de31749f93440f278ee05d31e332561762d3313e58065712c58d2b88031963570a97124b6eda6b90035f762f2da4668bb95e35dc034f75eec9b429792f155bfe4c9b3023ee1550ba0c8f402a70c421804e3a46ce0174656134e70015a11461b451ce5cc71dec425dbe2d396c7158620c9a7e68e77bda4ae6702a6ecf2de86908447d0d82a2ec6910967757e4e0662445bdb75536e1ec6e038bf74a57e33657edc8eb1d12237e0ff9a8c41865bede0836aadc4367ebc26f585c85638bab957734e8665726f8432dc310f94fbb79835e4047174bdc43283e02b0260fe250874a7714843cd1dcb73a7a4a010e9f03526bacbd50037ad31e64aabcaf23f4324c1778751c63fc035f60b60aa023c129e129d49c0b004df7f1529e0ee375f1aa654983e9f07716316a740528f2038e30f36a98a2fe4b4e604e3bf1413f056185b500b605b04ac01a6d4071d29049b4dca560ce117422f7f368186728de4b6fa61037272308439c817e2855fb1a7183da0d49953f0f52671d3213395a476e5caee862a62092559de6c83b1daf691f8dbf880d5014aa169509f466d587a21d450f0e3ffc337c1374ef653e8dc0db15318ecd4ff92c224d9e59f9438cf25d085b649d5dd3fbdb60ede66737777906681ff8613cf18c426783da495a8be7f338fc7adf20371cab52050ddb1ab185ce0d9eaa633c3f05ac6615c7600fe5df68735bcb925e36fcfd2c7b620c328dc31b6b1124ac0903cfc642ada524336d528965c0978c2e7d4b997631a3ee1aba1ace009b11c2219389d74dd57f4a50f2ced855ef525474c8994b6443a2485eaf121e60f07b585681d4704a74a46f135379d93f36492208166bb62e4119de7666364231c2677623a8bf215634e001140e274b51d452a75c90a62b6e2010476d4a5fd455ac9f522648cd245ab774b37225a4ae716dc1100386c772347e2d9c4cc7109236f8ef4c0c46b09c396363be198c4dbc2866aaca48337cdd651d44054c84b12818b8d9e157d807894e40b0a815824090573f1db76120bf5349b26c324a406b1d6290887242319f1e0c0d8ab049e94fe564c0ab39345a0fea20e1836614e106b7016f1d6e72049e182956af4c315488720fd0cb065f230dea3e44145e4e39378a59d081f07496a88115ffeec01a19b9966018b3783b0a812d00908072415280060f3503de074b9de11c15351b624ca2e569841e9f326252590c63784b1fd812cb15c4317a1e41ffc90484183d24a05bbb68f4f5224f9a9e9b644f7c3c1c6b17b274be14fa38286f7a6c2417d747e351690e7060df701c1b92180a06b7556835576e3677120a16eeea383f444047df9da33561849f73a9045b6d6c8a4648085189113f87682b52d30906b9457e1a24656e2fbb387e3bb8a3c832d30e3c4a8702fa6559a2f01bec397a6c78f141033f6716005c24da6f2067962198338a4d788f5a218bcfdd616bb392160b6c141702980a56f319155369b4c36e78e970215a62c33001811d6a9b583741b2c2b53429b4822b0524820d867c1b565505984dedb77569a83557459caf4a39b969557434e2935310cfe86c21932358d4bbed5f9860cf0f41567b6795366e383008f72945f9ac31f2b392214ee1470a4385c53678f8972f146e69335367b223cfe5a847db57102aff31bf4086da60542cc5ac5b337efb3f3209a6027caec13e71f939720241fe461145774fe69bbd41d6631b5f8105b841ac5f153b431bab61e2f3e0322419fb69770ab60b0ad7c50847a95b098285d5177db61a0380cae31c3e40fb25f9342028976c55604a2af3255ec2e85ac2e9253654ae5e30fa1ddf3b0bc9a9191fafac39f0e15c1204d0a464c773732a6d236e3f2c52936660528f2cf3a79c23788faa39f46ce82d6aa22b48430e4a20caaae45f4d7db3343ab45e163a44ce0d53ac163f5990fe49b8df503fd5be37561a61bd32b8bac95fe6eaf1584825e017a5b40f132bf20d2f28b59b30e3c3c24b126c6f0d94bdc33e8d0e376ceebfeb62a37b5039c76a336c15626776d28f7d1c98f576307b14ef33e6fcc20a9739d64ac659693ebbcdb26a35ec410c497be01ac67c5d2cddd06a4914fe11101d326a739cbb04088311c561bd072c6cc15dc72c2e4a751129ec6933f6970422ec629c75ff96bf172ec8d516182e3425adfec053b67d0a39a74b04696838153eb61da26530b6db21a1af186381f7e80496b0022c3de8ba11d5515059a02325524d14ed2db2a31868dc659113506c9e2fc6da0d711efbfe713ce3340cab3132473418241ffddc502be67a4e6acbe7792f811be0578a6589300aa3192a44602c2be124cd56526f2a6212ce5b008eefde6409e13814709ced4311e94d70dbeba30140e1756d4c10082a9643b10e3f6a7774c3ffbf6c51d61a6691c4571bda85bc3627e6b5033c474c4ae2b3ae42cfdeec4417abd5408e1f0972ea5f533420b2bc6e78a7f434b0044b24f816b251d693dc2dbda52152d02ca810bb9926543b5ad313f20d0e6108c422459a2305774d6c301942d1e0203d2f6404a6c67e03de01e82621039a3c8ca9a230f306ec1083763c1d9724264bcaf1f7523a7f320e0024146fa99f07088092496dd23b214c11e58a3ba965566a9acce53203c34118e66c776367be0142d719806d0c1645085ab30b088ad6c91ee185cf1477704613e5689240ffecc2391ddf51289a3976032f855f1bd18caf4f5363de4c13054f0cd476092f2264c727d4af6e60be3c364422b80537d749851b3531da20932f4b6050570b6f55b19b6b8a41a431d5d07746c25dcb0689dffb566c0af6757b2c0f377e60af2112703516ac0d5d441f097d09871a6e34cc233325d47a511d311f140ab21d3e2adb82de09b9d8083cca87b34792e9162b4486486e3b94c969201dfd3f16d8bc2e11d52308e702f801696ec60c394e7b444ffa372119ac9c2fdfe07e478596b84a1f9701265e0bf5546b20196baf37f04875ce9f62b7f21b0a698b6f4ea7d353769dc95a0734bbb5185ed8ac2e48f9700127e7931c1af29a39964a746ffae59b3880faa10ee48f4c4c15b8032d66db70471fa72421f05a47365db5e83652f2ba0de11bfe0629d1822fb932dd5c8ad67e153cb35f3e59d85a0d321f2567ae1c261a0c9d9514d924993ffdfe7c30e2608a2bf2968d2def0c50228756d613097f6000a0a889195f7f8b611d334711dcef5e03d0a1d633aa5dc7401406fe28ba69de1bb33bd11b743fc60c93d6f45780b86f77b666ab3974814e4c2c5c945545ef4c6db157ee262ec80f0329ee301b5e7c195aee7c2d04842b222a84d27c532cf3043af17f824166f8145aa6b66c313ed58f33d1b3ff58af0b414a13ae3b569172796427583b0c96dfa6106f38161dd90f7677bf76ef533260685531f137488f1bef5813912c242d2ff7739607cf4bc800d209a67d521fe72ad0435eb42d661ebb502990ef77000879ba40394faa6e59fafd5b7c6d5272cf32923c2a0d665e28e247506604e932054dcf69f1438e02816f425af708ef103c4e3d6a8a1c15267140f2301d9f4c165c9fd50732149c6bdbf15b39593e4d53e2325e133fecf820eeea754c37289866cef02d1e3c87426c0c583677b6ce5a0872fa9c0e813cff2744ef602b233f5930b3860b622469903c3d28ae0fd0ff63648150f46c436d792bd4286635052d2a13830a8d39e48c443c0828a063061fb976c1e1823a074ef851acc2173268c46366e0a42044c907f122778e9c47aba76548b134e43941bf9a1fa26a6777bfe5475003c12b326fccc23fe5bd9c1273f959442b4b4a67df603f49d27a29187e10bc1b790eff2f16f1143823cdce395873c354f8e19b03316fe5692632a362da3add67f66a725b1d7b681307e500620fac9567e1599c40c5b9272270a4155858d0e06aff684a5d5f9f255bbcd9a636ade72e2c5d62a70a7cf8055d5cbf7d27feaf671e26f4f264f5d8b42f4d714d69ba438640ae1fed1365f0ba22be15f700af23c63488814663acc5eb12c450165b7ee106565d45284c6268185d2a23857079e0f75ae18d02207fbf902565813f598ffe5b55bc217a25518e030ce418e140db9e992d1aa1d14f710e045e1e03ff736a84e70c1aef142cf06ebd5d654557198e86d61167c5eb0ba231a52d27b23045c567346d4a64347126653a2dd179373526a5a150d50672293fd10a331cf11d19a91db3261e80af66daf91951bf579d427bd721757228473888484e4be3e2d1092925b940c946d74a3364c852b5f49c024e17300ef37e9c1a7d78eb202aab3634a882ba2adecf8b479a5ed6196ae47300c39a220e0d668a61e8f89652972669497451211d95080d49826a883c5b182c215d2bf62b4fb2e06f86ec3236a7589d362bc5fe5c30341262b3457f4c774cf34696572f6075659466e9c5e51a78d8c157110a35329caed52ff482202f1dfcdc7646d56a4f8ee58501fe519d2f27ef5a5e5c7e4d46b594c81d9f1efb0eae7c7340740255774647b54a8fd10631f6eb740bce7eec1697609c02d7f0dd2598e1746147fda677c7674a13e53e642298ce0357838a046c4a48cf28c348c422e8dcc511f8714422b27e763424ec8f662878414e04acfd69da31ea4cf64a4621cd7cb50f0cd4431e2a53094c441ecb4bfdb6c8193e1a1e40714b4617822e9f020598064fd2d8e93ea05730306455990aa16bfa18f422d12587d3391ba2fa8374e2460773171856131288c16d82eee557209b0c563e52720ee1e4105d50620024b15a8e53c88fca28c24f1a37275e38552255f56bc3f2b14ed3059d4322645f12c3218628f3d9bb6d23cf95761b2bee6d8168100baf2a19119a2dfc52556823252334a31db07b5e0a5df8713b2674901fa52a8311dd3a9f12ce243b1fa2fe4246a96d7315bc9ee668cdb3e62066f5f417c20771260b5c4d01a8b94502b88481197586b01ebc918b6ad76ebe6fba058b222db93518c2148616406d716ef8ea7e6899bf103b00aabf15b6f8e405b7b85d20d52b7b64d56a2b5f9128eb33dccc9b50c5a4782590db9e71d5bfb176534de53418430b62b83bf238629819587062a96d28cdcb0c8c051c6990dbf0586d1c0773f21f26047885fc18630fa16518425172e500fe1484c73d1a3959a33aaa92274707a30a3e61540f24f277f676ebaef845f3d4a802b074a85d94fbe673dc06c1067dc6e90ea39c525fa3eae55f9a8fae737a31f2199013262b25adb03b8e40a55cdf39a6720448b21539175d7417ada17453a19c4b1fc18833c9feee345913b2045849e73302439444d860e754696798454303331a5b0144027fc00851aa8d823771ce84718ae102701f44a04de5f0675772f46d6f45641b25a4d07d319db45271d47f2702f2a77e3621ddec6e220ac83bec8069478bb869363adb2f5e5c04961ac60b3d1c519f70150c4e651c8cd199392738e134cdcc7c45f4763d18850ec367bf5a823438e49f475c31ed1b69a20a684ea80f00a1103652e8219a5f2f42657611803b45f2bff933d715224ed7e9b14702692711cb45b75722a96540a3a1b3164507b12692859d40e6560f5f2a0ad52a1f7a3a30da574f77aa2f0252129b4864f18bb92e049dfa745632d3213f895371f0654e625d2d655efb169616bb50e711d9461519eae6680156cd657319f7781fbb259e4b98a022077b61624bb51ca831890d8b4b49d824765194f549db6b7d4cb9fb346eed52b14405c18f49d2f4016eb06563450e2a3758b62ede2e80fb7d2f3f99ea5fb84f774c58d6dc3517c6801557761a4b19b16048c21acf1bcf226f35a322752df554ca0dc50f975283da08306f0f720c0d968b315b8824379135f96cfe83644d7b3c6750c276e632e1e6be3e4717316e44109f670bce5d2acb453f30c819126b053b2c3349d6c633e7efad5a05a335434417333f16d2de50f7173d5f14dbc21a3cdbcd595257576d9d3a0568ab20e37412b8642eb5efef59bf9a6f0ba8ab0c370a640c003fca4e260b289c39b7c3d82f60cc745f4871ed65226258016f758928ceff4455614e6862e2c62d58203b3b5ca41ed824c612d7189b139134f8ecae581d34100035256e3dd641d639e149c36f39e17e3d9855904523a2f32a1503323d56dd844c9b75f033ad208e6cb9fb69175b341267ee354212488ec1298c5530616d801528fe583d41e6031f5a9b3e120ffb4a382ff82441238e8b421bc45ad22c4bf21f6028b29938a35f7e5ccc605f275470a13d20263a0855a2374d16924820f1055654d126fc0eed25717308aaca37fd509a5edf2fe73862b4cd1df59e5e1c81e15200924b6e762c0e9d5b6bfe401f16fefa68f6f921122f33ee0872d8bf1bbc5fcc1502fac5566cdecf34a69ac11bd58d25739c17d73f7ce4572c47e828180567cf3990c3c00ff6ea3803c463422df0f135575412b7267d2439206df43503811bf62d73f0f643e08ea850108acb27a6a3c9643fa73422fb071903f45b3e204d00a72c689664234c946217d1d4cd3ecc2e232294bd9760605af521c9c35f1f2ce68c44c3792e7407c71831efc2c50dc853b7222caed0277755a757525eb911cf44895216c4eb0d40e39750b6514a1d199f2756e9211b3ee216e8765cdf780348a7c83694feda346f15132b64acf832ac760308b43a974dde4cbb2f559bac4c4316217539bd4a09af18134d217fd022712ac458c1592637d2d0896866337c55e47fd43b0014cc3de84d9d5c1b999f1cbf948733d0b6f03691c10354b519d8559c124617f87427060ba61f17c5fdbb25002c0a643e4d4f69a330ad51713a506daff5a94b1f0703456e20b9350ab77c40c7d8a922f279c85c4191341348eb8e52891eea09f5de0b6ba605d90502f00a2e14cbec2049f8e65372d75c2bcfc0dc4655ea2462e37afa10809c02002c56f00630272d5ea7f59863d4c1a22a0a616e4c3beb4a2be8fb4d0ccac8664720334e70d6cefa1df60b0c04accf2433ff30c46de849ea5478f1733f90ca121009383e262a6367018271257573bffa43aa2a3a72789fb465d678d45139d1132844b1c1210516f023b4ae5565aae39130edbf4a04577b9e40db87bd5b71fbee168888db1137b05e31afeb246432fd46192ef0b5571cda9a12b6bb0d6e62981337d083e019f104ea1ff4e8b3442b19f76e363d3038054ddb3599df7a37ea4bea764461e53e69a1f93cc7d02912146366279e4af84e1b1cf85fea584d48e890042494891d33a71f0801ad5360012f69426cc61a135d0d7ccd53aef39164ef39442fa73b1874041c776851c49a350949b803297264650cd8d97069ebc22071dc8c4376595919a7734e545ad6bb6c653b241e34172b6094b16a2927a3710cd1fede06d69fe20fde4eaa6413a72064679dce02e98840102b50815fd1128d1b95bab1148646ff2343d5cb207707383bc12a521b90ae9a2a69069111ff1a7a4322fdb668cb62d5682416dc102e674c2143dd4c4247d1003a66a52e42b79c7e3e8052275ac82ea552da668f7136fbb81e6789973a61ef343d29d47e77e8915e2059d6f770da58d63971dc686a886d73426e8afd4767592b6c58a7fd18577474397f0a4c4abdc2c437455b7625f8029253ab54401b8a7d122d3a3b221da97fac445fe9bb56802b87236209323aebaa1e67afd8cc0a379e9b16bea9a4129499a50389a8e43f53d6e04a014eae3137deb561dccdf858fd193d671534ea480b8be04c627a5369f050796dca8d7634cee75e33fb85f96fac492d30105df169573546185c4a1b55eb74781e81a98534dd8dc131d46bca044af87160d80ad72cc91ae343cf505276a5edc324c09bcb46a4bda95cc950510b846f401ba80d5c1e3411213c9e59a44a24b43b092997761a5e461976ce55eb5217ff915d2b09c32e668508177e327522bb1208697c045971f8b46c4b42ec9753417bb85af67eb8517368874e1f466b363c3b2c3f1e71a2744aa37f19a4115d194f12b00e7a3b4a0d0f64c0564e61dc1e69b4521162a2c63cd909371338041a36d325ec2b5d37c1458c7ee66230d601725612a548ec1199708a7c052a29cfee776943020f0a170c0decc86047ca6a145c9cf9970ad8b98b58bf8bb00f0a9f615219489e3bf303af391616cd275a152b0432e5db47f3259c6c222f032c0122584ef7180c6e17214031abbcc442dc91c91892e05d0be2d7360daf9b4532629a0d3a9ef2170b34393a391e8a525d4d9f0233fc8c845294b92443063d546f092f5328e590e2172c932071b4f57d0ab69c882dea75915ca79ef01adf30810c1d78f74d5a04084d766ef5136b7af36970b613432506390eb6a18838e06e855dd1fbe74bffbc2e3419128e3724907b305a303834d2f0ea5658b72803e3d1f26726664f3e9b92e557817db25362a1f65824de7a58ffe1092c72b10c571e3a803faf8fee3851f64661e036e9444ed11137ccc0fa1a3dd45220a74cd073939ef045c389103b41e0223aac585755b033c62e1655535c5bf6233fcc5fe8188f6cec68ff5c8f1d75723539ca5e0a0cb74e426d6de9e64a82195230d555ad0b9c786c325484f6010907133e0d453125431f153c86d6882b142e6055e75681022380e4567a0ea83ca1158c02d9cccd10717aad0d1292aa393cef2672ad385c00ac400713d33c7f72b7b4923b3146202584c15e39a2ba715d1e4b9a18876a922a6a6cb94cbd83bc3a052c1a2dd42e6863e8526963f26695669b2f76593208041f01c83a6f0005c914267975652b4e752fd3847a277917ae5e969ca9333cd9d97507904b5414d8f326f402b85efeb74122fcf5ef19d1723143648faf527b967a3ae725f00a39d71765700e31377f378756b039490290ed9d729d53895a0da9a863f5a98e30da19926297dc0808b46f65482532be637181bd151f7d7b020a98bc28b022b428d8ebc054dfdb2c51e3a09261e2d6d6325543ed3803020310a226e202d5b1483ed5488a27041d533e5c174418ad11e15311324d02c9f1ce2d23a73269edea546af4a9de10f4acb0545d25837265c65d25d3a8d4758435e53596b32a0d32ecee12a42e655698dd706b591529684641694a55a72c47792c983e666ea6063a7553415b2db15f26a94f0e3e13e0700d66760743846d4af568f2404106b00f3dfee32f152eb540952015243d50a15195418c4a850a163f994dfc254726185cfc869b1ac252730fdb42070942f9b553c048d63dfb756e467fad2643c37c565d8d27821ad43fde458dab6260940e0b21dda4a74dd4524e4e73a4d56e54908d6f8f8fdd63e25c5072a8afc55fe62f0237ab5ebb0d5a40cd4c3cde8d6a6c014a59989bd037a0f49b03089a575fd42aff2c68edaa2fd3c76c337961812ad45d075696f0054639983c142868dc765fcd1f0b2e8aee3e846615467c2536662e985e2e14b1a66385aad236db71b56d38d1cd4bdcb89a5ce6c91a77790187736d71df7004f89538fe0d97567844b36c48beb032606bc24488160b2dccc05c74b5aa001ec23f2a151e72621d20710e75e057df1d256a2e53c47fe353d74d94416df16d6e72948368083b07239a264946ee5fdd6a26a5dd428f6f6c2466552c5aae5ff172d1369e1733021a747e7eeb6a0549c8709363b2704f820b678fe92f5600282b5b88a2f270c2ba1b0d1940e100d7dc2e67f8d94b4cd72204641a235343c102993fda37ca18dc12be42f8c05a13431c576187b1d10706a058254cc95c67548bf76262405739e7bea96fd4a8703fec686a2abc9d0b749b1bb50f7764776e0bbfb03840d527400e62d163b122d74912483f6b9d53d6624d5fd005ad9d4161f374a2296abc1b27eb9c376e3713d971f173ee594c15ab369687bb6abd73621a673418326a2bcf1061368207006bb33095eea74a34dee74721029b1c03044167c4a5a367c11ccc494dcc6b46de62ea14950e6335f8d49744706ee8088f1bc03c9fd9ef58dd259335f324ac6f43842d05c6c0475a57814c6f6a2b195dea05275973c75014592c9b683efa396945dc701458b5cf3958849e557086fe1335b09e1e4c5aeb59dbc8202c80538129bd284c24085b3e4d18d1253cc4b93b003a8bc729f262f9446497c00e93d34625dfb987256d770b66198bd86f1c452363d9be174311e93d5e68b8db6eeb310a5c231ee536f3519914a0b155477a579d75cbe455334d39a44492efb731ea6c9d00731dba44678c3d20a737885d0e5f4947e28d441768162d0c9bc2710b680de900da674f67fcb97a005a35fc13ce91712e6027055aeb0f3006a3c2ca6001f03a01bc02433fc4b8515adcf1e636dd6e762b757273548f9bf657d1a3a33a16dba9491a619d6c254dd72898a852468532ce0d0deafa2e49fb0d73b84d756486a01f5744eddf25aae0f308e26d753003174f67cbc1ee1f714d05080a5978321c10ae51346931717f35a82bb232c2475240d046ece08608ff47e422d5f07363a6155f2971effe66fc3ca237db47963b7712f918687c987671cba55766dfdc3e882ba908fde1144ddea1ac3599b50107844d310f3be4a945a4c46b1be6f07a3587a875660542684b8863310febb3d66d80585b67eb8e5c6b9363bb3ca601e16821cb6a2d3aea940dfd4b301ce510be6cf1d7cf0b8d2a5610446a9963e82a8a5d311ee1632238fa0e63253d1dbd7fd73514dd6d378221ab180596f04b9ebc281ce147b447dbc7ab3295c40d259cf60856352f8d3374ce3b2c4726b42e367e02606fa79b6105a0651bbd6e0f6e662603737f88d236f9d62530b6d07063177ca902db24fc6fc62aca654cd7de5680c07b61823b824216d5a813efa19177b76cb46d46611632a859da7527596d6a386d68131e3a88677f17bf33710e3b455abf296bbcfccc51f561bf52544de00caba2324b086a030168d2ac632f3cc2671388ed5ab0f307130d7e462793ac294d89571d28cc1950018dde73287957ae2480dcc354e5537408ee4f3461703dd2522aedc601cbaf553b12c1bd04014b0040dd53e0363e93b85d5d1c58457b815f4643cdd62880185e4d8cd3fa48798c7b4e5894d2568e7347643ab222115d7b4d4663410748f2548b6c78db4763dcc0c91dbef8800dfbb9ed1ff0f74f1f5e42061cdb781b3123b044551055c320fc065a245232de7217538652b477bd572fd25863d9c93e16f81d7a70416e302dd6dea81e3b9f400f05c128199620005a931dc73575db573a3de2a16193f10b320815625947bc7f00060f860b67138c4c5a7f5b62a69fbc5dc34a8b3389e5d22c3b8f196a6c207c24efc9363546a3766c4b831c203c96a6638ab3bc686f909e591ad9091bc4329602ae23f44035d6ff725aaf9b37dd26303f3e86846121112914bf16ea3dbb82b73f0b81970f7b10091218d2454fa7d6cc46d51fed0e5f55f1192b9a8e20a96e6937cf6783006327a35b1297d949d85aac373e104601ee686666c229a05fba470775de087b20151deb03649fe039ea99413fa313680f306893073f22ef271a2ef26a7c50f0324dbcb173f3730a69079e13530cab4651ae79021254435c6a944c8f54d8efea1be87ec16b2b9d525511f2e35e68b1ab298442520dfb60ee732ca31f19479dde2bb540ec60123ca255108e505348004b4b4b6b6e19f7d90d73a753a1374783b952c9c14a5ac15e725cb6d1c30df2f6ae647964384189571e4f5d1de029e103cf7288384658fa015172f56e31606c84244b0b8fa147df965b46a70c6f76ca5eb516c7b97950606a6768081bdc5d8a415a20fd47304913da33743f1337079f70d4731974fc29224762482eb388319f54a725e5d39f6aaf198f34c32bd46360e8ab399f9f7927f04cd92644bc600d8284904ad91a2f146bda835d01c3c7117c6a430402df3a0b9856d467d17dec1de37148431b50b8386add07641d26db723e37475a32ca031c7edd3c2b9127765239d81e3b82e6ae46ad4d2b4e2afdeb0303bd291fc28aae72a8ffb3522441624369c9c752a805774fef9934374357e72d162fb91a8c0303155eff4a3c55c2c46e0835ff66eac9b347987209713a00882501def8667ece651404656c00ec7e6f5e2302e546860ff149f10481653d92114a4fa8701be7afb63908bf9948d1334936860de26a7faf844dd27e4019aade842840c5010e6bfa2d1883d12842c1acc94bb1e59d03e824f550651bea1b7f43a720f0cd14759ea60e2e8cbbf63b69edad2a4ae0c01f01356c00ffaa2d350699456565d7f2573e316c4ad0ecd93b12792e77db7b302edfe46945067573412d3cab06daafbe59c7fdd7156e74dd728700a41bdb9f9b29758b82755192d7551abb5c4887f1be54566a924f79f529476469ba1cecb6365ed1f08264a9f5180764e4fe51fce939701a0504368e968e61809f642f6171e75d23b124045717db241a54f5409079a067dd011a306dc8cd4f18b0324eb8bb2115fd5b1374a578707690047e75a84c254a86153909ac850e7693077a3c8a55ed5709b5a861bd223f19240e081025abd63858d079449b79ba457ce0230b15ec0f267b8fae3ff2322208b518a56d2d890d690c4c50591816ee6727ddb0167f0bb905361c8e1188136007ed16386a6e53166cb0385158dc122f06e7af2445c3b0a23457dad5495fd6987634bd8e5aa331685603148d2379075b03ef651709cd16b03e8984ed778b7e4f6df864ab117c90c62a366a1739da52d11adcae770d98362248d2bfca33f912055f5f4e2a0372ee9049bc3be44476d4c55bb6280835e7030535308e2a14b9ec9d6a86ff2e6eaa59ed53b19bbc36ccfd9a4f50c6214d47984955ac6e927354f5902f8532802f49981a2c708cec7553a4282d9015da1da5bacc378f10090ff935ed649a7feb58189b3c10ea3911032851c701b1bf583f9152357677da6e4cdadf9c17e0fa593353c494757ef7885f5941bc31473d565bd9806965a2bd683119e01814c6c69601dcdebc31b5ad6a7755ba1251618c4d090f52434ed801ef6fbc4ff158ff858a5708a50f01d8b8b92fdb194720f1f31a5fe935cb2905e8840e79cb4342086ab6690fb79c64aa021636e9bf733cda4f6c6d15a0cd1ce48631647cf619041940d15aa20c30648b656825a8039a41ae5a3445dae87f738ceacd167a1db50f9f9cb14205b3ee58616c4a1da7f89b50bf5058718a11ee5d03cb521668bc7a17661bab4643e02413c964182ea1fe60604bd3910111ebf72e2fb9f10d2c325064875b01768b078e4d2d126c6d4a5159237ed11176339d195eebf96a3cd175a96e02a00c61e93c9844f96bfb3d3fe9774de2959344452e0a04f0d2a35d0e7fa062cb48e55e9a96402dcb9cff2e05496874427f9e7298ced1204c177a660e28663fc7e54a33889ba60e6164aa6d7c7c04239a8e8935bcdc7b6213db3a15c1271a6294e4d8202aca33073338017240ceb347fb89e403376ba86edf34660fdf10743eca8e203930d4c94a2544df05d584c36194fe403e4f045a0dcdd9410ae0e063149c42fd0a8e82990976011417b802f92aaf8ae83adab0b36e39192e58af47c14a6207936b88a0ce751c1c1b29ca40b676f365b546956dbd22cc98f741ce5f6710f3d10d534977b728dc4bc73df2de0410e72e375832bcd21384312b5757f265744b7e316a868b645853db1d692c0b675748342d4169c5926c237eac0135d4565ba28ee4338ed9e6248d9df812848e4f21edb00e256d00331bf957d2082c6eb976e9b27b60855e526952031237564e89647f61605aa2e12360c852a91fd3f04a5dcee86c6031550a6e14e9ce541f8b2763a9cd7506e9e0dc3ca74a7a454395ab63992f9043088f960081215a18f52a4e3e0cf6450b803933399870586add597b0f7141017448fb03500b61e24f7f767c181c78434c1805c4137462e41fbcc15b2da0e55559328794072826c954523b595a62c2473d5bfb1806e2ce31013de9d143d82c9229eea4b3194c4cb2181a59cf54571e653293890e1bd49a5909d318f04b7e60de73ba39886c392af103e3a8b11e9bb1d00464bdf4549a88292da7287944d0f2fb1fcbe1be1e66348e2a69408d34b44dbb237b3e6a367a29cb43bd6f664db18ce831d25dd3400eb7bb58f396dd164b463106048964069e1ca35a2e8b0a61f3b0cc411fae414be76cfa1fe7d5e003eb725c230ec06400d9c7356aa0bdd23931e81a0b792730459b93fb38bc379911692df53f4082004767155554520efa19b6037a29fa6af27707a65c10362d2f77423d126e5c79897353b7bc0686faae777ae1290e7e5f0824f853526bcebffa4e3fa7422704ec4c1a3a75414b0ff6ab1c175a7170922d900f7c28e61b17a7395950e4332e1f0f48021ad99b2cb39082764f14142cc6cfe64b88f62323424eb6528d1b2815644e066bf4303e707c49701fcae7f25386565565790b8b53a99c84732b55123524d2d60a507f9c164ee8a6295e737705d2e5f155a8f62b365affd56768060773baefd424a54b4337483383348abd8e15938a921cf4319068b10ab36e48b5f947bd7dc54e12092368d1d4f94d788591207dd5ab658ae4b076e85f0f0b66aed01f519bc766443ff9338df10a7662de9c2c89f4863a8f369319ebd911557566bd74c2fafa60fcd43745f451a74b4447a53d7555b12f3550b94072a809675e049a754267487044a0e56c47204834f48bb143c88a03262f22082b498e786c0cfa3d2fdd63474b3927f825cfb411414ba17f3fda6ad56f3146f411d749456de78212645292614767817565633362267854cf1879b33d5e9cbc5020b5148d6e2f219c6ed46b9d30fd587c274b06c452cdd71d680c85986a6055a3619e194d2e0ce0f00fa28f0160646c3862c6eaaa4ba5124068eb2ef603795e051c1ed54a456a89b0553ace9966c81d35674d97295bc072be4f21534d46729f3f62290efb43a5e8be4989fc240e63685c4ab4439723ffb0e52821400e2e5471cc6dab8bd05cec4f12658efad63be4791313baa4a821746c5310a83cca14cb68483c325c980c17c7ea04ae7b1b73b2f6a918c1f6d62644bc3c0114081c543a849e15c1dab85cb906053cb71260340a38736791a6221138167608446296466441162cff690513e18b8d6dc642334d3ab2d0393fdb117754ef931dd0b7b14ff1d9184b5498a14e7bf1ff5cb1457f773221e42a5d59e408decb10112c66a258f0058f585ee4cf2930b5a4387378b2079dc571768a39351b5d80db10f8ed775b55c5842a6d2bed554ad7b72a45bb806eed546973a5e2693897dd976d9b514c740c8c903995d74462552eea5c498c4221b6939b1ba8c9900fd0618c6c90147b0b7005cf6c66ce5d5be826de2c1b9ef616bc74b8540c51ce06b508f85d6ff56b173a23875ca03b173116fa3e044490006d7faba91944489107d8ac662b313ea405b277aa0f7e5de0479c853c63f6e3d955673e631d363c7a4d7e6c9947a2ae082ce04e8b01c583fe283b59912ffe2dd6339faca16405a0432ace41e0550cebc03b6b02ae5537a7236e9798fd4388ac896bb77ead3e23a6601e523fa204ad5d8346547e7e28f0ed2271427cde1a847caa0cbd493c0db6e1a962b8e5e30e9e488104b18f276b4fb53d5cc670f25fa50c7b623fcbb6649509fe531c6f3c4aa9874e0bd49531693803fd2f6f92de1910eac76a4e279c0388086d7327962164f65c5e549b689060d453ab5d24a70774a5d2cf39b53d071b153cad1825e49d2011ad8d6aeccc0a001f6d6e569ebf8f2835cb844a620d5d68231dc20993b9a21e9499d353d388bf6f5c1345004211783a6d3afc1e8fb2856b5a76131d5fff4336b579ef188bc8a72333aaba6954f21107f9971948f4afdb31862eed0030f6d8337ad28c502808362189ff7467f749d2057ef92b69a5af87426257194af7dbef4560592b57756e984e3e5f455aeb201875bddadc3ceb67cb01403ef8620d93fc232d28241f33dece0c4f91fa6fdcc30c6d51335e63f7c16a5143deb72d6514581770988d6c572e4539f3ceac2af03d4320278eae2df79d640047e55c577e9fe42a5744d13343486f1b9c556c6f36b0d868c296790915714e228e91121e25508419a56c744bb259f20fcedd274348069a3a7926933c986c23492fa7bd2694f8062499add455208465712660005903d7925f4602c4226bb02a3420f42b38a24e0018a72f486f925828013bb0101a26610377b168762be9fcdb50bde0ad1962479e5327f9fd3891ef8a4dbe6bb512f807f9520f788514d1f417297127e1767f23f0337e1008762f99005f431a0f6fb0b55267a678e76199ab4a4d66b55b6e796df127a68e2e1b2607e9530ba915371a2eb05a35f8b50b9d812e0fa0d169169b36904ff6b66d76e4f29837e32b7727fa9f9c445ce729596c771c6301c9f1231350fd09caf069540b48fb2da2af890f867ef907e2e5122b915e4c3f14f9313e78b3ec45605c6e0735dd402989bc9c4cd0235d05d0b0ab15765f10573f755921df5aba193cc08d39d4fa5f35c03af0555e657b60955c2376a8a52e56ceab0b6701bbd70eb2157c65c26dbc0e0cf7fd0a11194a1d704f03555ff6550d558c8a6fa3554f35c3babb30b1307b1cf96c2946836d596165fe974616c68d5fb2392c383906d05f91d5fd3bb860f56d9a639653d462792183503d31f272960e00d1416ac0bd5b41e1de3f3cf424284eb5325e179867d10fd016b671b2ba86011d6c2a4f50420a0c89a89445eb3eaf641fdc802371f3f62a1ad0df645d95142243f40d22a2cf2c3fd4c0314408498607a133eb3e2cf7da54a8446b3673cbab3d08a0ff0eefd910150f3305225e456a52894e301ed6c0795ff1627875d9fc8911ed7026665d1b1e747f7f36485fa00b441989514dc45d334e839be55007284b313e83772415510370f648a0593b7e612ca935c6409790120a70a6620b4a1c123738eebf5b697a8235248c05063814716a4601ec3aabf4516878d7536bfb0d687690c35e6473756e3e2d499b1c0a0f5a609bff0e4941e67d59a4242e674484df751afb1b1a68178056fa7aa830d4bbb263cfcddb07c8e74671181179400bd8b11f709c5b02a0e4475bf28ed00c3a3aad3a7ba87c0201a66634ec9bd45d7c0d236d692c3a1d401bef136fcd736eda98a0570537514e85ac91642221d1153beab16cd8e0dd680fd47d25b5b6041286a9d16e7862a039204cfa6108a6bb5952acbb19ab505a72479e0c5cfc48c1773bd7c31f4273c61abfdcac228f54320735e4575a826c7b546fb5c2734d9f72422bedd210928f740059f95227c856966a28ebc5260e7d3a24be756512cf1377097b716d349c15fd00c5b77a4686202a2c1c178208a4f72121e0ea883a12df6c73dbd3a06cded8681ce3053e5e3300492935cd2a4a0ca3a173c4e44b5566ee993a376fc34d72930a6f27c7376138496b1ff5bd7e0ce41de92840338f6e38b9032937a9495ed236201963125141cc1e8e6a05d2fe1a9ffea962cffaea393530c55a51ba47355b304c6198838d734275e247992233391614944a59afbc37362583655f2673519d340769cfc2130c60b665222f0dec1c3290bf688ff39a06a2d2b15043a0796d08743055de539170e16baa2e07b0663268eaa62116c8eb4f276e5c0404b36543aa0b795d96057330ac8c222babe4d427d7c3eb13bcd3b150a61c6e1fe8639814c4fa623072b9d7046d81593dd60a913bbb46932fca53ac489a3dd5350d34250dc320c15f8366d07649dc5a411ed8154367587c4be345914b112203182ca2d23a34fa84280809db3a5a4bbe40c9d5e9744947166a5f30d31983a9ca53a76bbd4faffab450d102e5259ad62f5044a2133a6c1c102fcc219c6387fc74459637dc377cb1c91847e9c2317afdd34312802739969e5f29b4a77c6ce861852c39ac500521429312946304322f39ec67e3bb1f4ae46476700e23986084215a3ac201cc5801ff464c4b56441c51bab1332d7db657bdcbf071da3e824a8aea5952cc322f448f1b4966baadfe6580f1612a00269a446fd34d7244250931ff02ce1c6eeb4d211726837141e828169c58bf1666e2303e6777af5335f08b114c75e86f1dc69a313a138a16f7ee6f66dc58196446cb7514fa274e1764921b55b1ce390a3cbd840c3cf7177452a17d0a3a9c19010407925920d66862e9e5cc3238cc0e25f88a710c9eafee231c82e0303077e4434f06f80237d558164ef508552b99fd27a51fd274542bf96b62d94122720d9e5bb9183a5d5e71c6613cc135500e393d2dd7eb185e9fdac43189ab7a19185acd53bba7ae77e68b0a3e220a796800c8ed2e04080a40ef494c3d64786c30ecf0863bedc38e236a168240a720945c67616d0dc6459a1923a2ba07b86be41b763efd2722c533703af8595710ec3676f5ff68668daa1548554cbf34ca567504133d7d48c84859317decb30b6dd7c974cbbc44502abc854b60ce732e07093b402891506298f6be5af757a056ad08783d67435e4a4305d1644e29d514e4d2fe7651d90c1eaaa00a1fcc5ada1368afa92b4e07fb24ba0fae08eb0937590a1322362b2f99774cb86810335a2a13fef1324f635d010b63c6c10d4586173f84853548ebe31a2dbc8c1b64711690608cce7a42c110e02bee8ff6548c524d69cd40c7593c12bf3477c43a192008d238e718b20e23930550b18166005bf661267a8e4d5c7187dc5e4e7dc75c40ddd055a7ad5d164e789e088b4cd83c13ff990115d683451c8b416a97d9dd213013ab0043599716083026077537e246ae6294078076c0738e084d3360b99b2969570d6598882a13c10dee3f17f436121dd2300fb1b6300a7d333f6374c53010c663985dbdedbd348034675be497796d86036e197f62b45d6146571a6ae12f75e3a1381c7d780d312e0eaa5b87464c111d2dda43305de03579c7122d38ca853d8ea25767a9e22364911509195631e6653cc8204ec255f84fb4dbaa4a102c3b31a530ed298585b03217f4da758aa79715efa78423843f9c7126246312e6df24144d20eb3e8ac388046397d207c6c9302f652a4770e3e1a7551f94c622fbdce11dbd6efc54acaab32db704be03f2d34c6a3e03e34f6500443503e2df6c85f6e350cab31406e18b66197e9e510997bef904f6302520da126129ec88371e0ae80e66fba27101bf03c02e58d7f25cd2548d630766206ef6d18a4252efa253892ecf050fae3e7314002e6381bfbf1cf336571e2098526403d1fa1ba39e171e4595bb6dfab2163e76b97b4d64b348408e233029e59f60243660b7199b4130053260b52339a7a419ab6f155bcae64b36dba6100b33cde06ef9e6702769eab53a51e5894e3e1fa6541c51380ac55cb00bfa7f8c67ed4264317426ae1b3d2175392da9470b3df33f4ef93c423c52c29556ac16e617c045d0067bece01742096a28cecb6717dbd1c03116451b3de5ce5a33ad5f933a23d2f92bffd44f256003c32e18d18e5801e2aa19454eeb714db9466843ceaa403e219d236d4931440f067620ee49f82cf00bc03660fd5f360ca2b438c2fc3e4876cc1a5dcefe0670ab461907a9211466c077ef243beec64f3978044058304e392eb0e332f0469c6ec792f63e95bc5b1ed4c17f74ae79552cf62b8f5d7820864b41a22b594ecdcd5200139248331e4e5f4ba0ce2c553daa1b2bc6140b5f290d3e0eec3c588f3772283e5b3d55285c194b2cf64d54bc73dc24bc58bb2460330310311b0344a5371f739e5f060ad1b66405f4945d0d2f37d56639c1ac50c5d3c76e6fecf32c6060266c02336f093885fa738b2c2a4c957d18775cc66e5688f8a46d95a48f6d34983f3a26a5205df7a8900dffb0835022082c0fe85b3f3d98cfa654bdd2d605d1b0cb63e5eb705fe0e1501ff583c727bec42201a9d613563d684b70946351597d03d20a73a02f1bd74fca7108ca24561c03cc0425cf6b3efc7cde1612860e2da8a88764a6ee5a39f8e8592862078c27e81fdd08340857552b829f3c2a71ca7438d49d430daff43631aad308bacbfc435fdf0d7134786466fb32c924094999142116221fcdf233317fb4ae66252630620854df441e91b66a07d63d026d0e5b1355db9a2a254cde10a0e8fd1742cae006e778b5209c8665219e49b2695561e363d5a81f278120063f86e03977429b672f3bac6e39dfb76850a010063f4320402a616a9821c9804c00ea64820c9381d64b03ded9085ac58e1daa780b2fd358ce6aa293ca30e2a3b11c6a8f95004166bf2f4e8a982c5cc5245b072dba6cacc3d9526aa27f6fe8fd61710d03ac1b0437162f8cff5c3f189d976132418b71e15e9f5be2d82d4642fbf070aae1b46bbc7873221683ae0e859c3c0c2eb55e2c17fd93424486153814f22d2064c0a3114ec8bc55a884a2379a160151f4559c426b8a9a00ae37990cc2a4fd41acf872133213f639a6aa1a2d04a2b5142f5eb10fc7af5a26fd8e6b429ef094090b5d4d54dc57655639a5f008aab4135352f1a03fc1e13c477e213a26928ad14925016c6ce96429334c51f11046747c099e768522a93386108cf3a05dafcc6a2fa0f4b0772a64553b62a9da6c08579a299e19da50fae1645c8e08ef1b4ab171424749e812cf12985bd37fd146da8bda0b5efe7b072a75303eb9a8081374932a0f2975ec005f718a3964523b740026e7487daafc099394a458617c5d6ba50e9221c786514cfe891d289177c34e147bc746e27d66770252ce0725793e757d37361f3700bc05290d9153aef919032aea8c75f6adc54ec258a62910bcdc619932ec491a33b565595d5277e084643c2536d6073b715c68b1eaa6207e5bc5430a1ff123e62090573e6a343867aafd5255156d637d88752e199032238be26927ea740c51f1e14b5b77b5ac5fbf8f13233e67e46b3059ad3cd2b62a6bdad38b21701b73097c79943f4574bf226f6d27245dc422377fc761020d14f33b7153be776166cd00b858bc210e9b6a3e6ff8674d796e2c50eeff1b13f1c55c659006b56ffa5aa93ac1fe7b4b0526a33ef672c940cbf2363ed64f2907aec5f965dd738e61f9aa8f57fdd52e423d72766e68b9c732b0604227dc033f753705d446bd77b96d53ab844d56c3f2142060a60162a8da1be7c28950e7b1c85caad82343ed2a30529be86762e87f154e36b8fc169f2f926462c7d23cca1dbc20d445fc2c8e0e863e367a0f2270e6a02419ab17390925fa4ee33b6351abed10747cb44853bb9c98061497496f729a216e5613a22162c0764a86bf9c0c24e5341d8e1ae3240867d820251d8e7247c22c71df909711ecb6bb71c96970609a7bde73fec3eb61a685b60b0e2bfe58fd460242d839b769305aee2bdd6766014fca3f1969141a3238d48e4871e18a4f6f34766f7be9d758657e491a84dfa06fad6a4e320aa05c19d9e89c163a804049d80fc62738f67c114b9af61f66f4dd5f39177d5440aa2c6d47b27b6cd9c7b30b5341a6163bcc5772e1685f49ed2f8a4f5bfb602ebf8ddd514286e6774202aa4be4a1c000369d1405134e491d0ffe26048a0c4549aca787705bade622a1105458f0c0c27107b37929093b9a4114edfe1e2185dc1a100bf42e432a8d6a2f93582807bff025662e11472b7dae3135bbc9379c33fa2b3aa7af5caeaaa16f9e0f7c4e10939629c889eb1948a02838c745320a8bb3084d1a5dd539c64bd4307ce03b74f6c5b22dd8944019f9024a466cf6bc119526ba5c462c297310c3fa605db6243833b42b7413575028ceedef60f0a74e2aa7c1f243f6b6da689a4b0114f38ba9358037c32d33bd713f497da31a9bea58595d99f95e3bb34e674b6e8d7272bce01f9da2ff577ffd2d374ccf047672770e29484a7e430abe935b2fac663c2de5ac46aebf6d5a3a29920a1da52c6008cd27214cb7394605b3885397f3d9502645d644c26b0649e26e401900aa3473b397874ef6af2d49ca1d477618961c626818af3469187d6ea31c051167219f39b1e08b14a45b1b49798d3a51b3cb93754642a04789ed9975246a5b0421d0b5123d2dcc09c0f29a1465d2ca112ef5125e83690b65cf696c3f0e6a88463e21b21ff9cffe3ce98cb133c8c8bc63bda275345f5e364396cc91391bd9e92a6a49452bcb6daa2e59e1d44d28b6675f19de691d0878de10dbb2fd0e5872fb245d259436317505032b4d6a66a9cb2f349d0a4e17b1eaa61a0211050590045d6b3e8a4e1c69642314a37bbf0bce61fc5aae504f0156269314fe28e61dfd064a0c3846e32fc2804e5aa9cd2c58544131304a6f4951b9bc882a2a6c1d040eb32e63bbb0d85a5a81a51f6eff743f20d2b54ef461474fba692a692afbae27f6831331a93791636c01fc04877c041112bf4166f7582744edd5795f10ecf8426cf2656365a9cc77c7499c285f371c0907d47260eb6dc44c2b52105ab0829038b25e5817f1b54c41cfb7d20ed6f38b17eb56372b29c252444ef69d443ef1f107f909d3006e19853dce6feb35079b221b129e253a0aa71f54d26b331353b17649d04ba1087f623a3c05809665eca1056672ca0f0f9c9232034b98865890804d526dce5a29a5b68730c9fe1e6c3d15e508a6762f08450d474c92205c0d661b98575702ba4f051ceb07808be85ff0d174065952c62e58f53362fb732648ac06d622f771f2770f86d11cfd077f6676df8a08c2a4ed13651d6f4fb4812059b1715215abbcc035b0a89211dc88dd4d5213ab58c1a8c6322b22024ffc42ae2e4628243a0eeab13e4a317171df8bbd5d3171b07269e7bf005f93b9390c587c6aa998d036399d5163eac07c7255b3f5596415600b9ca0c92d29aa9a38012f9b5b201132702904542e4f03d4575d28fa68cbcc213efa9a0447fb28757173645738391fe523d5584a41966b5a51630d472abef2214383b6384342f368727e07443c24f4fe14b01c322a005daa01d9cde169a7f85905cc28f96e12167b023c8053030c02e96690e357555f03a91236eb71209ead690b4b9c7e1a1705a5032b9c5449e401225e5f10b56211feaa0dc992c80c600ee0508eb342223a657c28468a333926737b71ae6ffa0033503e60d70c262f1cff914d1d0a405ed22c494a191c4d307a648c3309a420628042e941cda447383793866fafa6574c91cca666b1ea0f509750000717f18d3a36c7dc50ee9ebd05464d6c54443b7323ba51e60198ec780c3d009767a605d330b5a7ec19fba9625d40e257447aefca03a013496edce0e66386be664eef9ff4242bdf124f7b059e2d3efebe34266c0c3d57281f5e63be79520f9a9736cdc451484221d3483397762255ede160b2d2f25d433cdd5d11759e696aae6f37683b4c713f45a44f4cae3d5dff54e127fd331b0315b60e5ff84e2557d5eef93e7591fa4fa27f01001e32f04173a2f26443b7335ed7448b3df6fb8e039c38686141a5e33ce0927c5ce1ee261405ed9968a039581aecda1f2da854b54bd08afa2829f4a02dafee965e83691955c40f87357d9bf21cded46552a97c666a2cf9b50da0392a6ad8da410407d4643fb73910731fe9ac5dd3fc316e74299f128312415e4fd9893193bfeb57a534263749fff5089dacb8015f47b122e00c1f1527147c2f9f5e2e76a2ca6104a4359705ba912719255e6b245571e914b253d126df2b334cf8fb99662c51c12ec76f2976d0022035427c6a1eaa4e1046565ed5085f7439094668e04128f66437071e0360fc0b6408c3212d5977cbb6131259fe3bc4ec4f12a9ebdd1ba96dbf0fec4593497d3d39638454f5101983ca332006f156b6733d157444855171a2df18aceca411a2f93e16d3859b0b341ed0055c11ae11820f78775ca9716557ead6412d16743d4815336d99f8291cafcea5305b3c57027cbd592950ae826da3a3a61a9731f70c5ddff32ba563a448ea0e0f73d3ba4e37f6634515a18cc92092fb335cfc0d7d2c8b90dc6aa397e55ef95c02726fdd5a752be4794c9052d0759a54723bf9cf8a2761e57340e138cf4bd588e80e982af547fcc9345b6059940fe60de74cefbe69134e9b9049f67ed7045a3715465c55582f7208d91e8df3e8621db1073c6e4b13329ed1e72099b2722d77df1f23a9d1033280fb8a657b87992fae289f585bb5ba6ed682020adcb70c4ebab526716a9eab774b0e2b602af76124b2e1180077ccae3aa1a46506d198f337adaae9046d755128a1b02b45c476a805d6cd96039cef36145142f47660ab8c396644093e2451fe164311235e590a5f380359524aa51c5218dfd8d20958026d52968cc40c3cafff7242dbc664fca2fe2cfdcf2674c0c191050df4e918fbe35e22312f3962c6d079486dedab4235c7eb2b1b93ef06a8c5a739b3d7043757a49f2482566c18490d651ae586883083fdf86dc10eb64cdadd931e58b70166744dcb17877dd94028186f36f6ea4718608824659b0cfe0400d3651cc6c84c6ae18f9152361a101abbfd3c620ada64149a2eb5223d7429503e4d29688478960ee55b7c45efb96539b16e346c8ba339183449886700e52b36d8782226b2f165461ca0803c633e7131e39cec2078572757cc97da698ed8b933f641a835b2c3ee48df685964267f9a0b7894272f7929f9246a10ec6e32e9f829e7504b3e774d49315e36a9059055593bb3c1f22bbd21135648906c65e55c9467ad40c7395d00902d5b1baf28c8453020a020891ac9d386202ae0730f57d1594e449a9d25018d454d24c6473a278a557025090438ff60ca4d7cce45030ca17b32a25dea2ca815ca6cda80c72702bed535062f95196937302fe3c9ba43a23093692219661df750b76c055e1f3f16af5809c94d2521019fec20effba80e06ebf265c8b3cd1bac124a0e2aff93185102a21d86aed537d7447f68660e92388f33002325687003f706cf055e9f357255dd006dfe4b6a406046790f8618f91026dd9b12eb3f0e6f52100b50f3f0c446f799a660db29130bc43414415b5caa54213c550afc19ab6d0b1ce01f4686fe59d73d2763f19e475c1e417650cf5f474f8cb0a406b1bf452fabf4155f3eea617582b8d81b50c9cb56aea3391100366723859232144a8da5534e08b2107907405d47674f65b1324d38f1eb88137f3cf8037c1944768ca4cb7158c934404453a356da64e22ab288ca430eb46f080bde690c90183b49619e8f52185c245df8690f1c208094246ce9661464722a04b5d5cd13e8daac22bb0ab26040e07803f5f5cc4d7292173cce308613ac31ce0bd5824d6f3ad5f07715c0314ca0a224091e823b20d64dec20f15ded76d228222c7d5da141c797a24384c9942377666737ac00a458d4d2a42798004d358ffffa09e426c91d431ed0696a96c021b84469338916f7701a6b8430675a5c4773df155a2a2903260f564927c88b394cf296532adf34935c954d5866e94bd4083135c274bb27a032ca9df53005b1d75200290364cb4ff319cdccbc1bb2ef2e0cbe50160ae1b3dc555a2494116ad30f6b52824e5c3c7a20041d5fac07b044cc428e9905240cb9bb3710f2e302847d6f3f12602248943a465f0c911d5c9f22a70921e17a3bdd67420b8c859f776298e86ae93174250b069a5fda3e945c27ae2648f116e96359f3240ab2b84055b908d1380e4ee25f8ed12d50de233f12ed106e26f93aaa10d4995132232e5403361c4638ee9be55c7459646cf6563050588fed2832723013140977646e709222e7c2e64a44d7a94c544a9558bfe3ff3ad0c3cf0e9cd92970501adb6e22d5460c0d50f81caeb6a657761daa1a9970c10b5ff7cc1ce3561140272ded19a4f0a21127da7b69f43aa745fb82b1608ddc6837077df542ac08030de20d9757c9f1e664976d4d385328a07290ef0165827ea402b5bcd24eb86e6916f8488663e8785d63e434a75dabca0d2eb1f8136e1a507f2437433f3246f2032694387d721e7b1914662dc5761ed3f030f0bd0f2f1cbdcc68e85ee74af508211f889954039d5a540feeb81f76eb42081a786a513085a5fd53e2d3a57793f4ac7732efc34542fae513b0ab56229401176f3410135b6865651744eaf62a6a7089384efdcc4869a6a3482fd64c700d1bdb1093afd872106286506a3e42201e750c41bfe35871bdcfe34d6a639d76be2a2c5703d6302e5add622100610c339519b172492c594245a14b4b4f30a66db3e30129191157489697572eab575e3029ba6c2f0a99372b1c79f009fd7cfd3e557ac60606607c1b17351e3e6a71df07009d7254cef657697bd1b03b59e36d65d52aca744349ac082ef1642185d6b9411dfdcb652957774ace5b9d724c0c8d1a7acb5f0eb16fb424bb49913b4f407260cf80177360c5c350bb08dc2d06395b628bfb6d075d7dec5827687e39e805df2e406f3a6728ca78499365254b04a3111c16201e46d205182388fed3450596db736bdb1a491522dd4b194c35748487624fbc3ccc13fcd4b41177fd1b1fda207107e3690669cffc9324d729fe51fae8714e0506535732259041e70f2660a798920eea70822b85464b3d05e8c8344c225f4b35aa146267e6bb07e09cba15c24275285948520d7eb4bc68b000f43b44860c5dcb216c46d92f434ae63c8b1ea52cc563cfc4a80d5161ac704d208c58016ae542ce92c62db72aee1980ea957671d34253680bcb315ae8f2423206d80ce1d4356134192f324d799638904dc63d7ff86c42d4da570a4446a117b2892e3ce022170885c87968bc899c58397efc56dded45536848bd10acb1152b63101d2ed830d10de8a59141fea06e2bccaa166979967f1281ea2d0a31f66202aa051f669114f343065e84372f5a470d05db8e672822453c0265fd2da94fb55b666f273a602159119f572927fcd29d0783a8bc56a0a7936be76e8f3e58ef295a660b7a216837e32c8d0e1349f80b4a29a383642a1b0fac28a9f4a14a9ae80b6bb97fad6a85d2ce65ec8c1510783aed68c7f12364fc7e580d5b42693ae0b61c5fba30cc5ac4ea0646bac33f442729df0355d114576e06a9089c21ec204983107693296773f58dd66f399ec823e284586009d0e504ae3e7a4025841838819e9902e2e6294466cd9d6894a947699d25e605e8c25a41e123b045cffcab6107a62f71116ed9284a0f106f88d9ae42ad3bb45f273e9914ea3fe4279e89260576646c3a963733332c308d13ac23fb1d183ffe4fd668ce4830a8d255d16eba042a6401363be18b30b8bde86b3137d92bc8211073996049296e07286b81d5691ad9d1a62548ce911261d6804ab462dc77e1160267ac966c1b551f4b2160b9b8266dc6de3f94bc4a215b6f1c691eb7f04c0d4b4275cce2e203df2d5f3c6d542764528a69418c40257000b43f29af6c0a65a46f4e23e10cb354a39f4a15af35e5079a3426473bfd822f084aca59353c5017f031ec14ff095301c7b7f2713238d31546b76f5c66e2063ebc04a95a051c7f6a604ae26d46f8120321fde511f9c5765870605a407a314f70aec0a7077b185d3c5029a92b0f9fe9191bceb0661604b1040f696643c2e753436e05253f6d6fa23a84ba783556f5272fcfcd134898f48113cb473c61081072633e3dfa4a276e6b2d041b1d0eede2af1a6e2fdd5b8b933e6f5b0c0b63be082004bdf6353cb1073d6728fe471e5e446b18e0e13644247d16281fa6ca23462ee56e19f69d2533446a10b90a1c08f55aab701c8fd624374e57292551ea07df36b617da0f826ec0afc966bc2c6a75589886600a9888645cb4684c0c44ca7003b5030fcf7ffc352310ba442ab4961c67e77765542ec364e340d06fc62b49571bc29d296e29bd6efce6a42a32d2e34ce7aa7649e533c97710eb7a62608eb34e281764280951ec286054190f6d07e4223e2d6306823d3b4e404b860b7b73686e7235192e03a1903540bc8677049efe2b04c5f12b787967380fe0382e7c20ce0890972126a6d45847b8848934a0b0675f9fc3d858e4a17b3e018bc8383c40446cf9d1de4fc9c6312981c88706c6c1c73058540256e60bce2865acf96ac4a3725907a8ed299e208639ff8de303bc8a3a1ce92a0d599e10f930da959a35ff0d1808767d0b087009ea25c461095510eb0d16df34480ba17b1662ed2fb739c61b8a2337cd900b411296393e87d42758d4156f9beb4c1218eb2c4fb73b08254c9caa354b7621264527fe3140020d056e9ab270897f0d4ba711c461909c341935fe34630d5f9a744694eb4e7d950734430a5521f8f1d06036fd5034dfa33a2721a67e4418849b3cbef43e36526efc05af4c416dc231c96668aa58298304b60ecda443386ecab025012bd33c6b312a567fcb2e610e626a5e4e307549947095024541ef634eac9508563edf241a3ba967116e1f00ec92cc6b59e19737f16f36184de9de27c1328826e3c594589387b84c916d3529d6d3336912e4b635d6db4b69bce43b191118546059b8eb67d3c76d31be8d44240e8f821579338a080b352d20f0731336271667413102ff32c3a0514ffc2dc4437b9e6c5add2b4a374d5502662d116046f9d8c82dd54e34226185f56010b2622e4385d835678cdd3c1936ff5ab3bbc75e68882a5ef2ce1e266872b605dfe296779ee9632134c8b6121a159803eb9564507358921b4e69426e512ad104c3006a28998aec39155e5c118db5ea6965409c20356d5775af5b6b63726b5d001178a11d0da9be5c839d2b3515003947f915f76c2a70795042ee6a36e6ca69441671140f87b6f96278bd462e62df38261489c31dff424b27985258687dff69458ae74754d14b2e33ff198427ca90da5b842df646d45b8b2c1e55101c3d1b7d341f45a5357c414566c7de363ab444f81e5a41ab2ba24366023b123c0aaf6d72249e3e2c3b289ac06f4b5fed72d07e2537c76d404f98ad1a33fbb1ee4a59e81f44d1a13d11d0695b6dd365526f3dbe536e50eb5e21f53b2d074724cd0b75383e04859af20901833f630d9f0b3ba58f2229bb7372612e89da5389b4a4002e91ce502ecdd3471efc5a35f7b75261af97b21bb98aec5274e9893f9cc8b56091d7f22ed111620026577a58c9edd8151b960d6438a2a23b9fc5cd5669ff1f5495069355762d5b13e44dd13b57775e2a4101ab574f0a7375bf19dd754a8a385fde77325a4ea3872f180ecb4b66a06b3adf88a33368211d396ae8ca52307b7d03141d2922fdc915705a687a6705c5b6728dcb5d1cf069c7062fef8c3a7c72ff4a2957130fec95ca651ba45f00ac24aa0448d0a85fe77e4010165c4b162efb6e51302a2242475b66323af429266e0fbd290249745c57bb3064a7c7f12d085dd30cff947d63063fe047953e142efeb20d0a290e452e26e69a34cd7776299dbb0810e3b0c5297d5fc8387d73195f514f3e5c224b6f26d82a3906982b33406a41ff6966814868b59e1c0c1389b92668ec5146ebd98a313d773550a6aaa71964666e086c89ad0bfc19b63f3a33cb4370d1b467011e6c6a1d1fc7162fa781636f625a1b2fe93f50fea4ea702785b30ba27a8c3740f21928c5714575861b9f68997eb87530bd611cf2ec4d3f2f2540559354750fe00fe261ad69827487c2523fb413ce6ba1c167683af39c07cdec984dd249c00334fcf25567c1e342b977b5728ba9471bc31e3741c481716dcd4efa0f5b519820622b14348e18824ebbb7264094bf17536f89ec11c822a45f6174b86c0f043153aadbe711a019bb3fe50c98692d1a38778ca53d2b5e395a348fc55c16ac30fe11a5a4a411eeb5d33fb77510428ffc6300d739db026023062a95ebb41361b65426219f8f73be2c45370db0603239c65667ca45fc37a4a8c865966f6814d160d22ffd071b671cf2232408100d25af1a075fe1887f30ea5481339cab4676a910071f87fb7f737127a431ba7d114dbdb3383eee945e48a4cf4b70d792415d7e936e570245cd744a0f1528c4bd291aadd52364ded2df08e5569a1d80e41541f5f0732f4eb9d04e8d74744933345b48b590042d117b6c6e5a3a111360733700a2070276b0126a1fc31a8006b65aae32a7056d33dde60109e55ccc53529d5455e787c34e5311f429d4423b4724e86270fe46585d44bdf113733c5b7597a847270266bf61570d74424f56fc6aa5a385460136ed0d66d05e2eb99eeb4df6e54455f96d922b1e2f3e540f30f435b44778414fa16b47b48a286d42fa5b613289a45d1cece10447abff449a6df5510d68b941d9ba850c35caa964d8aa451e2954a562a8796741cdbbc53c989753495aec513d06ceed1f1264714de153a906de739b2479dc78357780e26ea4228e01f6e669766a1b4706c5a14c16198acc6168b09d354086ee4b98d33f05a26ebe42c16a9a5866126d6f1d56942bac038d6db75cf87352a156088e09f039ac878e7207a03a39c357090db723ef2bdb5b187124ad885964d52348663fda149de6d7685d81ed0d3d683d6ddc9600245591c7140071fa27d7d64508091c2777e5cbcf199d9ccf2f599c1407d454806cd9dac801d67ec245f4e2597053be671684c2636163b85d761fbe1477d07ca6104803764cb6859a033f213f3384b1961a134bb3541639d90d81929b62b9272502d6e8514261a1b20e17b404176e80d02d624365464fbd242bf6be551a8c0d561b287e0b2ffb2daa5af7d1b75f3ffab633eb8b5a152afb2c5c502a3d13020f95274e432e337a66104a72b4cb722384b327a94fc3562e2dec53b25be529cf83223eadb9bb5d8e431a29f3367c67e294cd296d536a65b6d46e026f3f922780c3516fc93a525fd47f9a353cef1c6724dc79479f353e60655376532228ad6e5d704d6775d6ac11b2ca1f57bc8e9f2984c04c6ee23b9e3c11e4e6139e06f7522c1a4a1f591371064d4ac93ff5029274b24ddc2b5398d22f06ee6a45583be06ee923062190f6ce5104b64c32d13fa657710b8f3ac980e137308ad1489076e027442a3e2f0d04c12782b5af53c958872085cafc039d47064b589d87692e1d30161749372c35b1bc1174cb8b3c7d5a7010d39122155cc8d351603e3e62f88d5a5cc62c2c4f46bc1136eb50c7464142037332344554fd730a153e2720007903a167607f041df79d8301e7edde59426575052341cd45e85cd2305c98c440a7ef5a3f1f973269d204d906012378103e2f310536d0307586498c5d7281f906e9cd8505a5d4fa022df6696cf0d46263ae413748e4a95831012bfe4bb9d98d07ad34b6745d77593e5da82a5dcd0ba7574350826e453df6048f1b710b34c5f70b4c40a54bcbf20d71b97e631912c170096456ca3f07e4c70ef44d85438e0a5a5944df9c3c962a570c5bea9f1a58958c5561371d12c6b1a3341e2d563fe15aa90642cd7307c24e4d274c9be76554b7e855d97bf203c72ae75631a93564d414261acc380c0a2b392a494e1fbb677d31f564c39f691ff569212aea4e405b9d75c831ac7f5e459719d568b80bc830e1983531ef51dc1d4b9d834f31f5411ebb135a251b82f076f4cd94368b8d383b467a8e7581cdad4c4ee21564a9a25657e83a201cf42ff171ea7ad01738af4e274b151d43a1737d0b466cb54fcbdf6942b99c50466535cc368ae3533c538fe17641b20d5eb586e80b2aae97448aea30169a41914ac025263c35828e623b661b6c93bb740cbc4a034f7d3ef06ec5e8e94f224d2a0cf277cb6135d85358507e1c45f62f4967d35eb04befcbca05c9fe19192ca5f50b7d2a816ac7516d7578008661076e931fa0b5f31059da0f64ff264d340db6592b98d33061ec701b3cbfe8d5102dfb104523d14f727e99297086eeb9342c2c2b11e7afb5141773482781298e1ee6484c030646a210c7399547b7c8a022d4caec4c37591a7416e28747d3071536a7cf1337c052512d46f54e24f080d71b1403bd1a2b02c8433139431430d1034d59ea4d449b3bfb551c4bb93c1f1db6667be7894fc11123714ce0ae2c1063471989a7584141001b2789ffbf539de5350cbe30fe6990df1f003e53793914b4c90c2d7a811d7bf38435a86a703572a9fb3d60349731907fca43c3ce556ced944042c4b22d70d64fcd56f9fb9104cda9320a2b4c252b749d8305a5d75437a9db6e0efa48665eab673b11a84ea36af9b9c90d57a2581c43f4ca49ee602d19a7ca9f357fdfc1313f5bbf21cdfb0d74a724f5084d0470281b674701341dc4431a397e1e6d71be3bf0a91a7465d82c72fc7cbc0419083f1556e7c4647b68c97203f85630327b1d541f97f73be2be0506ce2d0f4e0cb2c47024b676521c1df7680874c1646a0e925ae61241304cfc221923e804594e130f2dcabf187225a46638b698fc4ae6d7122a72fb10109589b150ec15f657542b0376f5c89d76b1aaf3695b445564c504bd41ce225c555aa9353067b3ae71bd103d5dc6e60a3c373c040dd6cbee546ea0a573b77db8561660645137f61e24e52f580cea129d34f1ec3f5e416b24694e296f300d0a363b0670da71df4e0220c2235e2be95e4d106116cd02d9624e04c5b4af04586c3c25cc7fd51f8ca23433cc163d66bf38473a40727c37b199a0492bb8193f2e1ccc696eefa662aa28d6362fdfb8248be9623bec91d6384d093c4ada88f34800ffa153816eb83f4955eb77634df8570521463309b9220fa199f7141d59c10d91ce333c4502233db271f049cc7ca9315ad02973058d776b7f6c626c23f01575572794385d05fd1307c69321aca0fd372b3a7f1ea8155228654c9e35c9ced030a0dfc875a8eebe0ada140e355964c1717d570c0abc5c0d0fa3e39b1576f2194848b4146864d5b431a005415a07f7832fc930f076fe4cb655d6cac34577087c581d45a65078ea6d4569593c135065cf3e73dcee058ab3a620957b4b08e8e7ba179786d868f047db55fdb761742a57051a38baca30403f157453302b075a660530c59619726ffc635ace9f36437fa3be0c0ede9f65034da429f068c35e8238ca6415ea31480e3c185cd2a381779fb0fe6b432dba3a5a4a9c495713875ede6d824bd3c64c38dcf7a32b355edd1df5adb474e978b7190a3fd7604812fb020368535b94d13531df8481729b3e3d71ffaec560095c9c493d9b0968eed1b5130b5ffe410b2d9650e7dc8c3083f2e41cb962666b3132664bd3741d72475be7467f0077344582103dd5d90e2e25d08b598edea81105be3161bf97b4093e8e7439d210a847e112885d296c7b2144a9b92a13224e3ce4808e51b7e650207453bf166d75ce5c72d9655379c3c11baa2a922aefcd720906732a26efc66448b3f43d71ee131122328f732f37f1720d4fa6175affd8835716a5490ec848de16c030b0752f67f72d1c49754e05e5f72cfbbfd871fbeeb609f237f6726a073d142d30100e206bb61861d26b06a11faa4a2a794433b718f46861fc092ab726574ce90efd477174cc5f0d4964139c810c3c07357f50d2703c6de96b745e2725ca7145f81517b77e0516ab421b54b3c08d6801fd3c38710aeb437c89873855ed905edb9e6325a2f88d6517b0bf362b624740587de25d8e481b1862a99e2b44aa7c4150753f2d22ffa240d920b52eaf10460a7180201aa1a60e222dea60306cec081098e1a00d639b3a3c5442314810803e1329cbdb2cb5d6094f0c294f48d230a3550022914214dbb92d7b0c4649e03dd66429724f58f115ad0c37755204a20e9c42890af730fb0ffe2b4f113c1ea2469972d52ed61d1e8523241214f51f4544893a8dc9321c36c7405d3d67f35e87cf9d53ee8a5b10c6b78468d3728e208fb8a35128dbc53d84001800470b65765b42861a856f700977666118f2f68d1a9dbc3e121f883b7744915056233b526ab1cacd153cd7425deaa359512b0eac22ae5cc3759611bc1f5adcb769fd6b4e515a6e4250e4f6b95782efd80655be647158b03555b843d7514d46cc09f06c6c37354a2007dd9afe3d9fb86302914512688b941105e3cc6528f3d84922916ee614ae5a2634266696522cdc4956d555a641a1a82d0518b38941156b6d3f3d8a5817c85a436e8e27f370c653ce1b7d661805ca05ff0d26c062748d3720402f845344dac0d46e3d8aca01bbc10134d25e70775a97282e1379476be0edfb516a33cf1ff0300a55f2d92332113551301756e6110e3cbb445d85ee3b226e3e6a4ff89c19a82f9a2cf2288541a1abc94c26a51c4b4738b17205ceca456b0ea873dffce276c98052204550db702d981225d0d63c2a9a3d615f6efb432ba086934cd57ddc11c6462e16d79f5f2c20596a153e9187765f032c2b0b5bfd67ea5b8630f2a2aa1976532b49dc4df52f2afa255d719d9d2381ac4d474f3fd25e34836b6386f446557c6b41504db3dd08873c702d76be312a451407774dc29a53c2dbb90b4d6e745e6c68f13f45cf652f0cd6010fd21c7b77365baa51e4781b2b5bfb4e4210ff2608f9e42f2f198b4235cb1504710011af24e9c92534a330e559f9a0db034f20c412d0c7c6498d674d150643166b0175e240c4dc1f67d7b9c36a75e2b56194c82b4feb49651a84d48869ae03a52cb2357044864337600883d475922708650171f303c44f6e5f6b35e44b0a5a4f2ed92d6f76ea4e156e41b4f5277ba5571b28135631e811d24424ac312da374b603ebd1a822ddb74d7776c1f21ed0c78c6a496d821e2b830a062bc26473794d771dd914106bd95cf85d19f9f2117a25ce2b50ced22fa9ef3106f0e72911b3655710631e2c76ccf10f5bc08dbf162051725e7cddc3169aec46247a19535ab03df11044e1f73435efad6d9e10f84e4bab79481c4c36452eb4cc5137563436e569640c18d9786d3828a53aca3bda1f54be6247e18c1966f0af12348b32d7263fec4a364bf62f265ccf8373a43a2a06c85f67678a71b848d7a199661bd10e38e9902d458f54041629659049130ff826c4c7a1534f9872278daae9330e16256929ef9866820268356cd1a11646bab52170836365332d573f3a488073d25e0746c5d66c12a6ef4b1da10d9d77c23fdf3dc36c066c650cd9240928e61aad243f6efc9366774b71c52a1d1bd6588c830965594f547087a7cd025b66c521caf9b44e4ff7614d1edd370b7ecca25db54be34e7d5f014bde48b337548f8c21f4520d20dd3ea45aec5c4513584ee15f4f81cd374e0da630390bd25ae1c80b24535b2d1957af9c6a347d6a3aebc84f2ef6da7559cc73ba0f917ff243f1ec1957d827f45a7266f172c08a4e4467d3541c3ec4f1037557a80f9e7dbd48905c7b5cdaef155a20bfd62ae2c6460725b0944b0a380f05e5e2c03d607189176be0b1673ee77013d70b3855c48d3e55d3fa7a47e340cd6c9abd52463176dc41986fda2738fd04642957680cbd092770aa609b5fbe488b3b2ed6e33aa3c6c60e20b6715918fa382352b103284cb9a83d5f39e65ad8722b5374c1a10953c65550b97bd036c2fe67286ac80e000a6ed96c43366c34ce7ed85ceddfe603cd736a5ce0ec662ddf0b504a83454d574acf4c17b027fb333d767d37748974760a3adf15d62d9a23c259de6e0822e82b2a714f4330c67021061a5b01f837fa73424e0175ba71701ae9cc4d57e6f28a5f3c5cae32b28d9e4c443e6c0534e1b1426e943067b1479a3bb3168f37470d1424e1536209ccb872417035bd318e5aa764d2d011505ce5331948b15401cee6da27a874fa0f6bca6d3d9d425e4982b12d1d6423356c79ec8b1e3e44e668c104255c92bb0e40116f8101d9f6ed16b9dd75615b916b6de75b0931be16a5360edd3045064b365d3ffe4b1cf23d0e09930003244fa31825879aa41f31aace252b3cf02ca80a7616c2d7d068c8d1c63aa337b74dae03f954e3850d12aef5b51d6912e663ffeb312d50ba966811cf1c2b93935a7671fbca37d282ef6197a06412fcdb3474da19fe55319f57742d094c518462e52d969b201d308e766a023ff31c0d1d32539ff984741d8a8d188eef077491687f3e94d4032c4d3ab540a3a6e75fdb7e19047a7ea2339c5b556292a9f4071954f66d52c7c63c2c14c34b2e08180bd48b62630fc04d3e3332172a6f1f8b22c7370b040d73bb6cc5f6ba1d6b86596b724dbb49761af073cfeda369ee08230fafaf243b71484f28cfcfc5255117692fa6d2f321dc3aab3bd83df624aecd2f2175da741f57152547d5a02315fdf80d378efd0d6af68a7b66b9c0c26705c42450843d5720ac56d139fa92657366f5446deda1e15c4e641f0f9303903a6ad1333e06cb422574da020c13449d77e2d2d6173abc63554127344ae2e67d01107db110000d922b04f8f56564173038e4dbca212946803a48cf2a0a6de3ce424d70d4658f6ca634c0d2825f09f0db6a64cab94e981a7f13f2dc1c4e99960c1e5364861fceb99528e09d7a57629e8e2d5367501fe4032459fb52723097ff647742da070e7e514554baa3df66f3285615a7dc6d59d350e21ca1e4c769990e7a7784ab1c2fe5718b2e5d545e09a319f738cdb93033acdd0f2b456db92e6586774d622d874ae7c884679330ec1036af1311b7f422214bb58b23313f911b7008fb123a83f62a3253f2608ca2be5659a6da17f0c69953341216004befe41879cb6e3508a9871443873a53041f7b2f9db29345754097127d028f18c611701781527d118d58fa52021a06586dca143625ee1f5dfdcfbd0b388254173686241f11cec266086d081f8e7cd214df21964367f56d6a2ccc85274dc74108fa183514cd56cb62e64049477a2df270c884512a293cf34c6cfd9240244ede692164e721fd5fca69e1391801e18b2f5575f7204cd43f785032dc1e0b4d84a30d6d38cb38962a4869517378572a354862600bbd5d2c760f197e424d62c5eb9f123609a3081fec5a63a33a3040ce11ef6e067fbe0cdd2a007605a0b7255ee77d2cd07339664f32ea03fc098b4c7c557b727d28cb55c4b39e235df00d4d5d654253163e64034eb4113e2420fe677f9c966a05a01e039f6cf16833d14353fb422b1a6fa15e72fa62e53fdfce551b5af0de0a787f90186615664e15af746a44732d52095c5f6d1cccbc09134dc54394b6174f6a234313e6312f4de225f86ababdab209472860b3aee545dc964c117a90ca912dca927536f8cae5b4b7da542e112880c40929040adecb25ded016a0c8dac662a5b369c10c2408e68d978dd63348ad832337c7914f927b3075f5e0f1c32878f56e4335366b899eb3546f3dc2ecc91275c00e8b91c8ba18770dd4f9a47890e4451ad50a82dc4f24d37205c86363f5ed81842e2180905466a195c720c5625042d72c3347f27ad5dae5d20c1b1024381294b662a3477f2535c320a07fd116fca166a2587c42baff5312d03458e5587114c0e319b820eba228201d5e53b302e354e5510b49f69f86f781162c484737e89e72cdae33c52fc1c4254ca60d8269086783e41915a574a00eb6ad17d30058bb388061e2b9d0365145f69a32d541966024a65a0d658709c929468eb2c2715fdcede23edb4e0298281ed0e16392e3b4d40591862deda5c54026c240df48263c93d0b154ec1b43e22eb700cdb50364205f7c86f697b1a022637184a1047a929e9fc944e7d505d4727d6a82d65f5d76c32abb825fe867621abbc3519e246a525f1bb2e25069aa01cd1ca0a047383f82e60a9ac4fd4b272443ef9290ca5274e6ea467617304b253386e7024157a51f8288879e84335108359c714ef68d0146d2d5291dd3266cbdf372a99f96c33b8033e0923574d7bed13676269d33cc1253c475a225d4405864e16ac2ec25654abae356799a4081a017a188c58c8582cc9a83f8a23763891568e59037ca61b5210ed2cd9be5317db7a294102804726b63d001153ad951f73263538b010a80750968c1490cfa632b9edf0760c4b303493d7b6636955d52d4562d24c9e40df31b25bff471200b20d01ee753437cbbd1f3d2f33360d44b9450bb3d3692db2c2660035144db5eb0443f988f038eddd5371c346e868c1680559f60461527e019a6764a677078028a96c02687c060b127f3493407b014f2eab644d04ab1ec99b3302a8ff662817e7465963742f14ce3cd028fc17243f45703f4d4620db34baa11c31ae0e7d0cc66cf15f3cf34c693fd3333a6467df3ceb0ad412a94a8c3f71996119b0b952230348226e6c9b2b0389901a3de9463446e8d9c2491fc4631d6000c36180c8695c53e1f6337ca84404ff6abe73c3f0bc3a165b4643c7b12770115cb851c16f7b38993e5d3958835b541352f772ec81fb46bc92906f108ed0023e26ec36b990e3675d9105557608c522a2d7e833bf31af34ae198628b595ce12693dc1106f3adf330276cf55ccbb7556cc1d5b18c943472913e344127334c844d3fc6b42ad881925867d4922dfbfa203818163701adfea0352e2ac441188be2176fdb8637b8d5307b7d426141b2d5a002e70193c2625ed527773ff764c7ece3924a387704ae16b4a80850330070ccd11b9cfcd1b1a1460731248d815ccdec5010af9ec19e19d575bc878704c1d848f103f87e64891658611a9f6dd6e3f49004f567b3c6ea0a31711c90caf0e4a78210b2157ed33c467ea09fdce75664bd93a412cf56c19681c26018473a5053b4b623ba508c54711d9b9624adc98555de4f915f08f6914addcd0366ad06f2d0532fe53ebc6a1051366e160fcc13d6c9577bc3c7475ad5eca473202c491b535967ddf0a37d00c56488c6f2aba009b188187061c151725628ec0735b2e20d817b5cc654d63a5f370d72eaf038207932c1d0d140d6f419a6ac6140c75d8c02074e9b46f65eda4cc32a49486609ffbac2516c05172470a564841a0c549712dce3d153a725210e7c62f6917ca618b6ef442a9e72a3a517d2e6f73eabb1eba9be210e78deb564ec3953b1fd27606afdf84385e692955a2b8fa286993c212e600325805f52614ff3e8d2f738a6c420f6d2351bb2c4259aaf4695d6ff3f764813738116dd7f07193fced186421d663fb67a65c5199cd745a22ec3938b27c1dac9f4c524800531e50e4323707283126ce50d225ded2d316a1dbb7389f21bd1e3118861aa2c5471c239ec2533e1e4527c4ca296c80e94f07290f130c9acb4e3cf7bfcc6493a8c42265640043c48e6011f7ecfa2c3694ee24f3266f4a48a0611a10b4cd384af9fe213e779a0378f28e18219c8f2021fd3f42d642ae2b7fc871082668d12bf81d3c3d965e84139d4c950415819577dc975552ec8381472db3ad58b891e54fb7bcd650f0ead163ff81cc34da8beb3125214d70dcf4785dd514b80437ba6e20aaaee646bdb0c1614b2805500f28aa49dffa30284193da03d85ff95ea0373003bbb20e1a2f928b6530b3903b32f42c3f9745fe388620273b6ec40d052cb69014404e451ecbb5e443d53ad91705b8ce411edc194af1de3054786a091ed13f6b272572b751200a043ec6d59554ebc2253466c86e6c4e1ea031a5c9b021d6d2695a6d327736db0abe08f75311330f00e507df8d810d5cbfb56ef50a696fde20b03cd44c5b27e279210dc32f006624e1a82a3939b30f1d63ac6d8074a906a1310e5ed5835001a8c95c3ca4a75923821fda1cf6d19357133881028cd1f02dd5273467f485d569e577c21b6ee47b2d1bca2432517c2f42368d3c49a04aaa045c34d45ef1ac5e224e4bbe1a3e086c34d6f81463f22a71516431416a687b91109109600296d9f35266273547dd6ed50ff2e8741948c7f14b89738402f85f24316e3ee7318a640517ef71762f7bacf16134f73449605e6118ccd82f60347ba41efd17d60f91a99b21646c2036ed161550b8a12f1da05d115f9877392872168948f7fd9f20e15af566af6dd938a41c2e3ab82e4d0383d75a00fb918d1400c10974fdf7564c885cb53e5faddd4fba0e0526c637e2049342d60ec9049901c95e8761a60d9a600f62064c396a6700742f7c6e00207a2c54f7796fba797e59b5778749435b534a7f41136cba7680454f09535da161620fc61b4b10c9a7725cab011a053b4e1d6b3cda6967e322e3038861f25ac374574d4cd13e4087cf3459ea882e1f2ad1521243206147247af816cf977962c26ca342816c53450522a80fff733b3dae338a11a3eb1011308c486dd5be2874b843a02cdc66fb6cdf3e4e4804b85929605a203e595d120f333c3d1929ab663d90d0b74fe1ac2303cc671f04e2183c4196796f276eac5d0660f96240f519f405618e7f349ae0c356a1a0e258b3faea6fcc49cb3b12cc8275168fb80d681ff46a2feebb6c7c41363effdb310cc1eaa369ed91650a4a8e8a55eebe0661a3f2b6521c00376bb5009a633ee2b52cf0e5d503e24877422590c768e903d507f1659e172625fa385d8fa173882b1d56be4aa2023803836076efb4424e71e6254fe3071f044f1e29bafe035966952d4255c4c03b372d7a25d0450445ca373d3bab375a05a31eda2e38d47d6e15c8c1017c8269274f56122aae39db508a72542d1aaffd4a8d195955c9b60a62caa18643f1f7434f26961e3be9c7b564e01e234db438de344545df5f4712180bcb2dce4ec253171c341737291ac93823aa8e3d554b339a6315c3865e4ea70f1827697a05a014cd64d7e1276ee324131caa9585442de7126c35106f444154f55b49b259738d07407426de851ed04a08769c6d0854ba1b2613be8fe86fa4102b3d97f15326fb21863e78f04b2732002d5bfe27cb4e94db272e77e2de6f0850666ce06a8c62550403005bc3001d646b831a9865632102fc2e3c738ebc6df8c87e27c285400ddde5715e6f6dcf46392ed2133d1b6450e9ee426f74f4e61ecc896c5f026ba956b815244e36cc2a1ad94e1936054f263873b6c21e775c157012209432e42fc004c21a845fb67fa5117aeed33239c1c92c03138e6fcdb8917541f1e61f49c32f6b92b6f42999fb662c6648b22cb0df762fdd4d6f3316b20f063995125d40a22176f47e8c62c38fad77209471562fc1631714ff5c24a34e8c2c439579248c8d974bd7d4d207bc2eb3037f90b84923633d5be177f613b8c8606bfaa5785cf09fc05bc0d9814eccb2b06e0777335017af5b04f6a93307a0c6842dfdf3281822ea2e52f5c1067681d7f04a6b7566042df1fa1e4a1cea256c1585680abe1d3fa032032936838518fa6e2b452c31370feddcee6d08f36367778eca6f60aa261e7890cd5beb9d313dc9cd03591b53d52df2dd0461da95a572ebe73e2447cb442d28e57655a98cd55599651505d559a608b5b5285b5288236e0d13f33c2c6e5b611123fd22799c3142e5130419202b0f0a1f9d10746f133950ed630611f9bae3386c28071a7269600c0482c230a0f8ac2231a9c738ffa95f5c5e3b2e017382f524dc9e8f755deb74706737993b00242c029774244a6d2f293f58f2975afc476e1fb255f11443dfb60bbd2b50000f42fb5a6f403c7114c8fe67f4a0da418ddba61e6f13b50519f63c3821004d01e9c32e66a20ba107175c46277edc5e56684e9757d8322b1663dd3825b65a113e7266b676245ecc0461da4219c0d0d749501f027472a809675e049a754267487044a0e56c47204834f48bb143c88a03262f22082b60b01f4a8fcdde699be67b6e8d6dd84dd0ae2151ee7ff11478cd9b22c933016366b27e2c95aad465dfe8233b6b81193c15add252c6bb1b0f03af270885646c5b66e96c37d63eed59286e7f6ad15bb9762be53e20adc6a90a493120180765362dafcffd3b6b08f548a692dc764076d51f7edcc35f5050954ef0dda96f81a7503367e15f21d1f1f9011e6e060257327557d63b330089afee493bef0d3ccd44b726d50a561947e4587011e5c140b5198b5a4f92ea36b5ae5632d553e1511b489c04465e0826c182d6651e3ece3d318b554446db2a4fe1c6b835d9afa339a466f3144f9ccb718c060e35aa1633497229c34b233586086ee86400daa7f42b38dac83b349ef06aa17dcc619f545a28ec48ea07701949497f20ff76f915ed5d01a6476ed050b5546348145c40c5d30c95937d5fc41c0b17140da121dff3e92f38654b401fd2f750bbdcf8425d1a5510272b08200aa7ee167469c5349a29a93b8c498858c0d2c41a1bb62e2b8027ca2a38838757e5b2b53ec1a43225abca5703c248862578d2ae17213dce60c1690e175bbaa757912fd632f41de24f414ad667c8d3b82ab663d011d7cee17263bdf40512f8531b63daeb112b7982668316765e86d8893666ce5d5be826de2c1b9ef616bc74b8540c51ce06b508f85d6ff56b173a23875c68fbc8032fa91f054ecfd03cbf76d811e7c00c1e17921e0da1965a1a80b8b30c93e5326001521b0a51c17b59d6e28149d1ce6f642b26563cef0bc65ceb8ad460d5872f15577bcb6152b6943c06d9710c48d1786d328dc74d4c76126afd18bf22a3277c3c247db51ddfbf7b643fddcf0fd003ef0c442d5932c7267816257e7b04e60a0c0e8e8f67435fa748331a91dc0991e8695790937023a5fd651a5a4a9e308a918c4adf18916fa80b5f3cf405fa5da4b3700bc1764609aab0fc17737f46132e61b2681a97115c7a240d0ddc9cb368831e6b049cee775e51d927548a6aaa4be345284a97369437c87bef3129d149570e84fc65d197e03f8b38765cc0421439c9805a0483e7ce05a1ee3f311533f30db5064203d7265363f61ab061f9fe5e3e34906470ba611155330cc61fd32a52227d7bc6686bbc6722c2017d2a9838155474aaa2184850ec47bc9f4173215f4334c0023106d2af8a5cb4214f57c77a1a651ff9f40d5eb8792fb96187024ae4de072747d03f1ac626269e0a4754c6497925ef6f552ddbf0154574972c20566dc43d2d961114696564114cf5b02cf030296d67031245e2ca9b0227216c2fba5b8028dd669a4f136db40cac53291bad8e410c3174fe70353a7a2a51d48f0a7790cf2905cb4c4c5a3cda00cbe6415977857a6937a4fa48f3f2111f189e793dbb18444cc5de903f2ca4a40310783e27ba2aad280f2390040843ca22c8ac6112964ba758608d3070df1beb2bbd87c75c6265ab5891847053ac957924a5478331a7fba55320d28a2f2564a3195c350175fc496625479e41465e93526f9b199b2496a00d1cbabf125d26bfab0f2070fa325293e40d0c7a673fcc211306730c8322766a7a4c91062c3185e69c538a210e2c555f926fdb125c3a485eb61329a78c0eabaf4e4a6d911231692ae958a4aa925fb446ef60ae362e11d535d974df9bc126cfce184ef416d0764deafd31b23f02135791b250e7215774b8fd4f68e095c6241e7b461191ceb251009cc107e1f0ca0c8400d120bed10c2e4240f44526255339600f9773adbbc04fb767894f0e75fa26a286b66f24f6885e5b7a18723ce76151ffc5261595fec11fa11cb038ae295f4efc94fd2993fa7d298573f61175bd716bf1608152ad5aa94182e19f4761693239e0fcfa428baed522e2d9fb133e3eb034b2a8994fc8df801a08699335c3f61c065626b811f7dc7350061e304936775e430db61e4d5cd35e04180b1b469905ae443ef17d259319c5236f92d639d434844c6ef8306028efee6141f5483205e362416375cc1c135085542a22210d07ddb01b4161ef17fb4cdb1c4c1bf041515e5a4782abf36eed33fa140fd29f6e9656a43b16fe272b7e9c915816174a2148c2211bb9f818730d108346b1c2711f0766dd40da5f59749cfa942919873705e513ec494c9253339f6ee34b859ad92efbc8071faaeed0736aa11a776110f654329748384114756c9d7023107cc5976ce52d74491533df5dc511a51cd03b1939b96706127da0522214f5e1051f450c419b91895fc0278658cc62b73f70785771e50b4a4e01af034d2948c1123df94a75769de7362687cc6ee8e8de1dc74c23394ebbfb0cf20b8326a95fa8738fb62443ec82285ab725c46da466f93e04a915103cb5ce24d6a29d371fec116deeadb157101e56175abd933276d3461a69593b3b7df872089291f707d0f3a777cbc013641383ed3be1143354ef9e564fa919b62def44956e03398824a5fa383e2a87163419bf6169ca846d338f4a873fc02cb927d9df777086a9d16e7862a039204cfa6108a6bb5952acbb19ab505a72479e0c5cfc48c177e3db1d6c42745b5a10d5875b09604940a08239186ede984a6bee994a6a6c2c1060177a253bc38a04384747678c3deb0771f6f902646d2052b678274daaff816bc50f4c3120ff8331d9be0d13b3d8744f614c8b54e9a01038a91e2d2da9a84e35215c3a4cb56d5c653495af6f36402839abc5e86243407745c456841215b27d769e8dd431555da400994d1a1c3363b15ed70e6f26b083803a6e8e09671432ca1c4a670a6026352069495629026034985f3d0006433a08473ad23a565c1f84c70f20850012df5f364b955bce58dafaa517e891646d0ace0d3b0e9b66706c4d1f012b778942ffa63d250124f970a8832e37d9bd70126c87ce3d8a6d3a1d6b9f0a6d07819547f172bd15112a6c5fa401833d7040c156a87b242d4610d258f4a5497434fc4c668fbc061238e53d0413916125613657494fdf1b201193a23dcc00205d27faef6d067af365de77e201e9cb521464871d578d1a922e9b88a01bd99bb96e493a182a2904a4513ebafd3cf5e9426d468060488341b21e52a4fe3abe93a04e20a02b1ef1c7cd7645b04c3785b35814bc18106cbf1d8528b521571f249c1b3c899135259498971a5959bb122367434d10b25a7780d9e3657db1913f58308e069637dc377cb1c91847e9c2317afdd34312802739969e5f29b4a77c6ce861852c33ab0d534af09f1752b9b122828d2b16284b0a037d2f71013bf027752ffa4d0ba33337464dfd8c28f90017007594d61a6aefe802946c491cccdf22463cb6ce6de4058154e431f86df209075c969a9967b2d96934159abe03d897366c734c242f2757bf1b906d7f2899f52e5bbd7f344762854b1fd5ba8b275031f65d7382df1bb187f00f4b842434528ab8477555fe731c4aa65147ab0073dac83656ec3edd28955b3a30890c2b2b3866365b4b1c65162fcf0c5fd963b64bcb6dd4545b1dce040edb1756a1837e0015cbf731a45ac063d3b50f2682b5fa21a136de686c651b7544ccef4aa684640c9862072bf0397e52e2888817a10b906f361fe00e7a9e071d163bca729294b2084888806eb00a45712df26402de3dc70e403e6a03f00b96596ec9992a67833e25bc22a0006188e37088053b1b95f831612e95482f33dbac209d82c63890a6454559b0dc4ecd5f9a0abcf5902c6e181f573a48370bc9488a04df323f045936be2f50984f3141408d3bdf30b7581b0ea21777a80b6d3647244a9761073c137af54695b23047dc0e7422bd1a134517214e36179689279af36341f5976870641ae45ccf7bdf513b3c9b09f90be42566163676c7aa0460798b240463425a163569e13615812647854e0b3bc302304ecebf9a518fccaf6792bab6569a3eef5e4b0cca0f54fcef1e561eee1828eb450a1351f65d006cae6d3bb2b72d6cbe52501a22fd11cfe86c3cd8d40344f0613f50114fc72f44b07d2a733eef46da006d3b8576ad055deed50654157611d2c51668f2072a0a47c71761ac21eb4d0d7c0e29f914ea1cfbddec70ac6e5c37e6684f472f296804f88914727b28bf090a554a51c3da67280e40f4512f87313ddc4365193168330b881b91261a2ead5d091be337f7cdd03dee84d72f25013419f25c0728a314c93f15300870e0f52b3d2ab609512852b06fe5c6084a10be333d93b9785857c98422597bb85417930f648cc722589ea4054bda935121411eb11f3c93101ae5ccfd2417262138aa35f134384686775b9c605fb6cb150e466ac358b19edd36e11baa05ae574456581db5762a29d30f2db37927dafc00269c5dd30e0ec67e09de9e183f263871507ca7e75a840d4d766ddcde311ce38a59eeb3085df498d65a329dd60241312d258c2a74058a2cdc636546993eb215ac38c50942365b4f3660d7cdf609eb5d6b3dab38722b8192246af744ee0d5ae8f927e976496d60ad2a5bad925e1e74754254323db31a1030951736d7863fcade637405ec33455d2c0e403efb061f633e727444fd1129da0e2b5a6288e11a97a6810a09328c0b1988fc16fc46e165b36ec13aee627b739f766166db11c950d761c75dd9356a34117f8053b1d5c742d6b2444120b8991720f2e80eecb8995fefe14d6ba90e28313b26b70e5881b94dbef9375e530a366ff3c6ad3e99e7ee54e0248d02fe777f4d5524623614611606d0d80702a7c0c71d35b17019eb01000940f27b439b4849434f40eb2574a1d631282d7a3a989d6f0c8c698b1b48d7d4341b84b37566e9934b3af6215c16564648277d4c4cce180332cc730765009def3bf25c0d240a114b5387dc0d56cf10e121174a3a6653fdf915ec3cea634b8c42182c5fa547ad7d0542ab97436b213f0d08757db472c4fc514c99f8ed0492f9ff294013b5259f4553461eb8d9732f34b41fe395ae6fcf421d21e9e7aa05f4c56c4f8b3e536e38fdce2f082b21559fe9e800d1e76c05ad23542ef2552030c0909c389040ab6fd612f9772c6502523b61c73271531b4e98c44d11dac3fd3f8c963f773719f66acd085e1e19ad44749b64c75e5dcbc6674798054e903edd2f9fc3bc3cac38e619d253366c4a8bd33d450a3b17ebee4a53c985b65da09abe0979cbec3ab3c61e6a0f58aa51c5113d2ed05c436e3059d22e11bdc123660c605be102e02e56ae742eec458e216963444ff2d0ea2c9f2f274888f87d7444616f08da4dd06508b4ea4280959a2d02480767abe3195d0c6fe072c6b7622eb195a74d31a70c4d2142045566be9f5f6dd56825af73ae48d63dd43c2115ff37018b8912033b1a4d6643dc287871322562d9ef3948194d0f9c0b8722e8354e5182a24f32d6890c4d3a2443386c887439a807065438af946a9d521c4dcf060375e4879559b1ceed0ac26fb4295b0dbc60e995053aa5348e2013288011e97fc6768e560c07a0a9182af295c3325b0e043720f8fa4414a15566564cc002c7bd8911c4f3a513708bbc1df60fcc019835385840632f28cd873255c5186e1fed394129381a7e6954addb0a7fd79823e2a628647521f166746ce10506d2c92828f2c03ae19f5a1d11584755766531669485c52ec737830d3df2ff3b69287452c6a5716a73d22f76d53b982af38e716c807c542d375a97717620715281ee7d37c29c86399e8e12718db8c93be2dabb35d8319b0e52a2f74a5a589037dc156475f35f18611beca812305e0739acfe506333bc2861687aa307d2390845e1d65377b62e7031272b581ee176bd30881f3d6b1f98994e5139151909f14f587b519527a5dc1b253ed67969e154593a34f61b342a6e3e634755e63436445a408c193469581b672839baa858b7cad11cbc22d61d6089be07f29fe97640fc19135de38a1c7396591783e338608212cc7647bf25590554403fb4d0d2561f51375374ce3a0b6d319755e2b3504191740513ae418f55d4d7d75b6734cf4c7282e155342b0b6862d76b60dcbf29518077e9736472a46e7e1e3600d5067328c952315b8f4ea5750fb53206fe35141e3d502508acf8342dd54d31573104ad21cc3bd76a1bb73d2bcc10a705d90f2516cff3c9489fd9196efe344712753d7073c1a48f606cad86434773983678d75b07302a5d7452a15034ad493538e576b0169a422e4d3bd27820cfdddc63b9412d37d97761602c4f894dea62094f38c22357c97e881a30491d4ddb61dd0a89ef60247892bd26d8c8b830f173613b8c4225743ed06c63eac73a03f3a4d414390f3e43a8119a36b8a41c3af7b0c32d68b3ed31de3aad02e46ea53ead11553493542430a860fb6b8f941a068f745031cf3b6a02dd9c96679439f258e4918e30394a4257a6d51233e58f9e674bc67e0b1c98ca275c1d191d8a9596060a1a9651f539981ee2c5697571933e46c03abb02f7c2bb37cc92847185eca901392f6b57eb76e8456456483599c0d918839c4a16d3b39f239dcfab1a021e3d35812dea0965e4f215c85faa5d4bc08f2033e96330ef25246412973c36b741c3761bc5024b47e85b595af15521a8b329113186f74629594263b6391b047dbea2689c72da520c5d3a2b3d3d86449a9c5714739a96169bfc6442d4c88703d0c08b31ded3e1237453392194bf9370486eca312cf5153b3c9c2677fa469a2c6f3e110c66310a417dd9c275f3ff7b762ea9ff268ecdf81a5a97c723f6cd05234f4c570f63d6c3362ed04957e6f28918400d862b133f3b33c2581a473604a16aafeb033df0fc6f114978287715c2c0072880452b349c5d576f08485b6ae32a3db6a91d27cd5de74bc62e374230fc8f4c3d94bb23ac731902e9f53373bf56d958e9b1a44bebc8e82b268ea36f8eff4b40d1dbc657e654c70d17590954977b9b44e79936536ba9384902484f3fa055b83c3b11540c8128ca0bdca28a70ae045e3b6023b7488ef6245092da7c355d7e0358a793dd4fea54ce20afd20036fa42f76076e1cb67b527f1722c9c1949b540374f15504035ecc89443f1406947a007ba58949a0b14336e4164b84c1163e46d5f7402b48646f8cbf06d5822ed3838e861758917243325ad4b0996b4516885d53d454cfad855a48182059c49b568950092217f8b7f6d0bb4c127203ffd1d7615e2707be3012cd9476d7313d0ce31854ca6727f04071e5f18852f2107a741b31f636b28a0e515c3e235755944905b6554fd5e41dca95b049a4d5e28934d0c45762b65be6ab263b159e85c8f64635c4ef1e149cf5410740ec36627d5ae8500c5f56461e40abc2e909b781d63c355192a5e122e27017d72a0a4933e455b724e3c31595fb575f7323f620f06c556a04adc39f4237bf6b33201a59d743380b7307474445fcf8fb7338903d05ff896065a8970dd6782a96f682cefde04f713d0074646156e15633f2ac1f9ce253a64ee030c5aec554160775fa236a8075a3ca81323a2c31969c4700e0cb0d05b8a3387406511b83b46feb8122c783104472d046867bb1569821c0a6af718731355ee016a4acb6b0b3089703cd43bed6dcaac6d4996fcda46da0378124d229e2324606b2b0e90f0269059ae4de9ce3867e711e401d734d230ab940360ed7183526b94c51e64ac6d4e7c713a36ae99f103d4e4c540f37d4006ac98bb1516080d0a0294bc528b5aec213483215f77c9d6702daf8d167d43293484fc5218353fcc0935e1575421d38a003c6e571b5960d161c8b24630f175de2694721428afe8e3515ac5546feb16cf0059a0ee3cc0490b3852587d556f8a032a42e5714bda749e07443cb17094601844e7a25e473502991c4b609e4b1d96cf2e0435d259d3abd841807c95373ca5f714dbb2413876fd524863cc1a0d02faa75e56f30930c48c2b0160831156d2cd74722aecce14b312b9471913b75896d17c18cecf512ef8482d48767640686cf7b13b6c9c2625b0646e2260a1e169e6f53c1008bc0501f1ed49726eb08742d8cae5131e48976330034f64fba0775ad9dc8919831365173ade384ea4e3ee5fedb3546ee0a0dc710ca6673cb896e338289a761dd38b7c187a308b58b6cdc413717a8e443bd84230d68d6b5e34e3cc762c88af0868313a4df2f1264b9084524ab541c821aa9fe501a7f5d15345a51b6810a96a68e14afd2917f959257613630ff435793bb68ba756b256f463d9d5020d51fc7900617f820d2824522432cdf438cc432c58398cc30305703428d5f30a41f6321867024e1467adf9191dd454601248b2d41fb3b40d01d965e53f9b6d1848f903262ea7c3a81078bf9b1b30ab42406322a06f5dacb312fad4aa762b2c3b7058988f3146ae305944a0c83d225b5f30890cbf503096be172ee2c9426263f74d10499e26a8086036517e3d0b563fe82bc4728355111892336e90321395e0c10a8a1a0f76b7aaa23b7cb95d49d4f6a90b6db2110a7594343cda6a3c546bb7e639edcf7c4faa9a5a4546f73b1e01f0d4418e769e6f22ec323bd7e2a408fd79643043767c21c44f884bb8cae76010f0f856da426a64ab608521ba390a3b1ea5860c856b9b34a08eae3afb5f4f5b911ffb1ade1bf42bd68834353a6dc36643494624987d0a07579e8f1096199e08fde637143982da1d4d253c408dd25e640d570a356531a97302ec09244cd0c65e58509540319cf474c7a3db32e7c4f4367eb56d43538bf7053461e5429a88a95f7bccd957bc9075440655df6fc6240752d4efeb0f1d58310c1ead73413defc7124f6be52d1e5999496dd87d4442820a53bcd3b91b965eeb5acd5af11bab41c81e1023ee6ce4234547793ec9423983c6160417df40a8786f3bd4971e6c4f1e7b2beecaa269fd22a54e41923f15a7032950b86c53143d8ca61cae9a0a5742c6c565a2dba26af820121cba0b5e2d2568f06e43cd524518f4aa297aa2ed03c8f01368e743b859f117c23ee187fe1f89cc334a0f606b314f23c323a1c10a4b895cc467a0990b0c119c2559a6751d050c33d147afd2fb75257e2e6d21eace4399bb8c3dab458f5e28d9cd2910e421203932eb2389877d1bfd03380b611b9360be394040aa8f16335d59eb433be71068ae5a1b27ce3a1124275bbb01c64a3e64ea5be901b757c2539aa20e69b62f434f7c20380aaa143835e4eaa62941016246cdf6641838ef4450585f235d74c98504e082983491257c1e56c94e405210b76d18479c5c1cd2eb6adb832402d68a6a6e9e7caf21510ebe0f293b13736a62f319d6e8fa356486e62fb4900e4b3c45c743151046561b22401ec2128b19d424124d2c63944bf06ef5408f1a807541ec3b147daaea575867303df272092c146c4939cc943c194da4c00aae88b418036e1304b57fc1544eab5f18bd40304dded3a5090fe19075cba71b284ed00d14d90c3875960a783edce8940da1c6a1111dfebc576423c0292cebb842072ea23c2dd5c5535fd12105581bec144c3c6448d676c42d73db084e1bf8806af1d2b3123cde28329a1810572d9aef6f5368447622da8f49e416f70cdebb4b10bc66d53a8b4595381c7bb70c74bda63d168c0a3697f8083d0138f931bfb3815f0ffb52520400fb0804c4e658d73a562aeb9699516b14373ab04b810e1af9410e8f43e418b603414092259b049779d822721dbb2ed4782b2286a61a533ea6e109002f882b68aead39b16595514ebc5b1c935d7a162223543b0536a603ef0a1c77f370da12de3e06033f50ed396f04b321538dff111a0c104354b5d2747f7cde2e3c07271ce9744134405e8b6ee6879d67539d6b159007d70d192bed3691e0fd3f269fcf538b597e1b3e10d1672559444f0fd76a1709928b2a774b4f731dfd7e240122c75264b9e86aeed28a3d40e6e71f88637674e026ef31b98b7a5927e3822e3065e127175d4f6b62f16e3138d8773409a56050bfa1ee0ea520972feed3c1189de8284439eb33446e90316e6e023f766446fd1f013236733073e259f833131008a9c13cd0c4f23417d9606aeca70677967c237316124326246bb3669e339a237b17704f240cc24255b3402964dec505821e8a1622f25368769aa6255461f007b1ac1d5ee6a4bb2640f304053cf2a858e802fe0028502f3bc66eff63fb48b35650ea7e725b71042a8a42d331f7905169a561660ea6a6076d0bf4c82744c97c4891c6f84974c77014525470272868e5319136b46216d4414a8bdda03499a18369c460d068d2a15d77a8fea92c09e064225b2e47242ee12e5f742e0d4175044974c3801b4ac74c21653bb5354dc1ce3b07342e25530741723189b1075ee638bc3c573dea5f3ede420649c54617cb51a90ca013f737b6f75c5015aff718ff845a3f93264d699b70e24a6c183932415a8b369a85543c260b6f4e65acdf242e683b3af630446776025b3fa607bb072b1edf037948a0475532e150c8e8d0339e16f64896b8cf41cb8de75bd2b341755663960a365bad3419308c3f4739086ae31ecf3fcca957132a670c4bc6889464cb93aa55df273519a4b5e01c09481521f3fc1232cf8dd955c087210673e421263482546af1d66c07664f7d438bdb3f246db2950ab6adb1545ad10b29df1cd5045144c76613a0f7087874fe4beb80011683522e1b30c2992b4a87aa311b52611e87725a43cad86f39736d853b9d87ef219aa58d64ac560544a46d9846e8788471cc1d0a1e72d7820c9fadcd4bd3f0a3702ffeb50b6cbcde1a109c085a2e0f4d24a94e1d75959aaa1a87adb435b9990a0723c93b69260290004cfa4f16d3723b6036046f594f418f66d2e3582c664077119ef0b771bc143f41f91eb8063358c032daa7ea14bd54d0232cf7456dd0a1512526da6b4b79de0c4e54cf5650f6e7f02da131ae12fbacf94d6d1fd005cbddae49e364232ea7de325d57809676d77c953917675761da98e53501ed3972b8d6931a2d44062ec5844a61fc9a536a930fdb2a7d33427679db913d1f3a0c671c15794b3f039965e1c2de00b47c7966d3331666106b980049216642d77e164b9aaa5614ea804f23c03de9009c68de68e7d03c1f39a4052c99548d083b677f4e6881fd02f1b53424c21e785301a76b227f18f714320aca6ae6ddd107f1748346ca38c52d113a000a2cc11a090754973a0c39e818293759398021ae25efbf40695a429848a53f12500efa806a47e03108105e4451ce85806348f460317e077e3954fc844e53607940a3038e1d9816fe3b5184f603d42695182975de1865e58161fbde3424a28c131d3c66c10554f6d2661d267d3d8f2f4e02045ce94ba00ad25e01c0cd03de1cd91f7c6ed83161b93c6595346d56f463c21f9c917104d6fb721e318ebe3b82904e248e67812319b1c504660dfa750d7ff3147435cc6031a1d03676f649054d37c34b914c2f34cb9e3b6cd675b239a222390e88544b6aa3dee76c5aa04f13b9083834c308da4c615cbd570fce6a16092112741059803a58d16c6daf8ccd42570fe15b39bfb85abf879f19dac92b5c6face22c4b711f72c0e1d550d5fd844dc7c65b447199902507fed75103bb793734867b6043184c0427152311e8882167a5e8a26d971d802dfa3ffd6ed5911d4aaeebf6631550a600da6b1f4c6741e9746a4f766c17999914c7d02153fed7b8138facab6ef1797e0dc0e16c3eb36f4861d766ae649a8ad91b960910730c2f481a2945b12c40dfb15826be1666fcde201c2da98349037d38396253b63b9794c057b13b52508995f0242f0e7f6ebc16321902be86351c92e23e36f2c906d7d389007dab3469cc7b326300904755e69fba2c3ceea55783791258031cac53a2752c717b9f0857afe1760ec7feb52f38796254085e87342c8e4b1769400a3aa7d51959f1861809f1ddb52f676a5457387be04247eac51e0509764967face396e09a661e2cc4263494b9471b1ffac4f95e9575826860d5663c5d33c5ee0995e8186c009209d312c2755c8325e76d96b40ffef2a0caedc1f155ccd40bad1cd4a4f16ed16ebce0973b97e445e295f1b73d2cadd24dffbdf0567dc774f97b50a353338993ff041467148c61f2295c3553e6eacb2323ac88d1987b0014444007a4b21423116675aee4d907aa85809420861c39fde1571401261e0507424dd3bcd0d2984ac2d167397757264d425264cd5516fc1ad6502453671bb50f946ffbafc5edd175168c19184497c2ed3447425ae63200f832452627d14c674302b4b373f5a6889e360254a5f47d06d1d11d9e25638b88252185f5116482204d30ea807dd6761e43b14fbd441416a22d31baf3dbd3ec0768223801d077722cc450c45f9a14c11ef7e732499d6495bbcd706ce3c105de236bc00670ab028a5a2232fe3c8896199c8f00ab5f229064b709622f2fbdb09d5326f10a2f2344ccdd70823322bb15123991d4dc8d14c71917bc05d440bde3dfdcd073cc94d8d70625e3f0634b23c06f568fe50b1a0bf678bc7eb3e8774e95bd4009532951df12195dc036c12f89b34797c3c0b87b6241d0c64ee0975a528162533376a02fc520d6b48e6031c742f2cdbea046fa39054525aec800a0a387b3aeb1e9e405e9c346a9692e868462ced5f5032a26aee68d440108d7a2caf8c690d7eb88f106c0de8347b566846bb0b8f1581ee964beb0ec74db3ea436b8230f65da417f3235a1d175e3142de13706f422423c58b41fef7e85b5383d5650cb1b81d6e1a3a438f6b42506a6b604faf602c77f94f12507a7a466de9a0642682e9123231d2f169177c930057c1567175ddeb2dc6f40e509cbec50568418e21b68bf56848aaba4cd45f2e3d4eae766ff865666e38f5530fb31bbb5d7ff80a7532ecd603b06b5c6184ef3e6a899aa438b5c8ba134869d63e34b8904d8276713a4e6c293410b148750ce7fb70487d8633b28d1b24bd4e4c3091f4e83adf627c7687e2ab049040b5553ced163c8bde02487c18c95cdfc81737e40ab8586f4f9f17a764f633330b11442b5a843fd7ca605adf077a3cf1714624602cb96233da7e73d15f060c970c8027622c02715eb0142780cf62218b346d410a50103b44dd9267a40463056ae08305ebb34d3c54e3d77463b3ef72ddfdf46150a9ee324a0ea00fe5e9b4536b50363fcaa2287719cf275c0620272a2f54da1cf131771b0ec9d74a89e2ec1b7f92d26e01df0c3b02f04422c302e833e6cc510247b5a2495c1b10776a1ecd244927d1372d6dc365fa97a91dadd3c4703d16b5434adc971e7cc6f106218e013c1ea6c81aff360c11be29552c3cdda93c7c22433b4eb8215b94017503efbf194f083820349d47220ecebd4941533ae5649c070b723b742f2f6422f95cf4a75823403fa076954bb76f21a23307c7713b517bcf9b636bc71b69dbd12f678674951a5feeed0e82fc89126accec18292f466a98b8c823b72f4f1183b8fa1737eadd4362cb6f56df83b06a0225241d43b02e77fe615046c00fa36e4546a2093e07131192c02c4cc6765864ccf8b36141d9981aaa9efe705c1c4d4fbf57d729aa56f2389cb43f4d4479fd4163c23f0cfcf35e3d028d4d490e30434be274041464b2b477088e53312322a266d066f221272d1c5e04b4c00303ae163d2137183ada7ab44d553ffc667538814d78231d0654e9da6eb51dce2e5e3f2874fcba3a53ecc5cc697c6c0f07aaed322b0b8bb9641f1ae55be3602227ae7ce1422413e82af4a8695223f51773f1914306fece2d5c500c190c79eaf72ed24ac85556ba141cc0f4de04561e52265430257262f71b1a84a38d6ce6f6b170a3ea327251f0e64f3812d26dc3c53d6ddc035636a82f476cd896101ba3adae60295b3d2dc75cda69c7933e73a5535f2eff16da39db54411bf6fa8776f1058a2f911d1e0b3077be553e1e90201ec41062712a6308ea1a10653ac2ef141f8ca91ddb5f594d7a5430414fe5737401995c4aefa4f00c1db4762523ab0c561aaf3b4301ec2e094b5c513348d8e81c17bc3c6bb97d4404aeab124963fbb7581fbbdd0257bc80009e3fc577eee830438c16b052e7d18b251c213e3ef344a4308d3fe73ce51cd45593c947494f10ec1160718c3c8811a25ee5299e261aa5fe0e6d8c5c574cfafa61634c6c024a973630f49e2466c1f1f537f008426833290c3ba8b3db2423eb8365d48784475f573a767310036163fd1d630ca31d24e34e3a04468e4b0713ce224140524905fa6906740350a152aa20fa2c3db89a115a9c141ce237884627a9264f82383f736eb3a31931ddf26cefc3d12afe8f7a3d164c9c0bbdee216e4cf15c57e52dbd674fef505d1e9a6a20dd8b826af8f30e1be6bbad31a5565704df421d69d06447775545524310b8a75cd05c7d1db6c0f338ca8ac6385fc36c64cd92686668ea045012e3876e6fc72006c0a25f035b7c89109b2ede13625d7c0a1944f74676a24347f46681563e765e1efbc3c46c7aa9f03e86893b2aad1e064398788b2c0f9284330a3a69161c610c23a136eb0344b28656b367881c922c696fe5ad34371702ff3ce65431619df34d48017ca41e9827a165e9027d4ff97b923950ac5801e7b98b000a3b5b444fea213b86c07827cfac352863543c576345c6020f295838ddadf674195047609a66c91a0c7ae610c114c5056bb46c0605962c5ac163fb150a9028357339d52e2697c746c331446a03a5245d0be1c226cd789840dd62c75ce808192337139e3d07565209b6132c3ea7134c1d5447e45175a59a1b5c926476d78db01cdc62415ac382752b7be1f34eab5a343c74516e02318860061961d424123ce25dbde2283d600ac26d12349e477c23de2b92fc45776ee86312a08fae1927a3e65e94fc5e4ee848d835a40bd65a2db7fb709aeebb0da6bb2a105b72d754f9e45e19f0e67d0e5abbc0181ba1362938fe682a8804fc1f5de2c515391953195f477744e77d4803c7985d6302baec74c4a36312cc3c2d3e6672106edafe7d11bdb90133d7e15b54866fc673141332059078424e0e6e09066ac63c44fc101e5a171fcf6d580e052b77238c657dc7d30cf2a04e6f77811f1d23f0024f46d0e3664d5b4d121a3d5f41d23e7768889d700158a85413ad32ba52af051f3d0f8cc36b658e4f33fbc0c87088301b31edfc5820d7c3292c176f411027e1040c3659225e07bcfa54c91e533d97a6eb139ef51860c5c35532854b145e0f4f4765517bfd653811bc20bb6d42636adbe626988888088115761d4ce0650bbb456c1d813405235a672711fb074908c1ef2759961527317c600d0cf7822e1bcadc0f7186bf5432313a085096057e3f66e84d7210d195446bfbaa0be89406017f674e71fe8a5e20a58c0e74d61b2d0eedd8b318e3c9a44346947d084e74c6321a3be67024e6ec745c130d420b70ac2426f31602e06ee31711c13c0d6763052419189a22d500e8738f497e6e028716277bdfc76274583545dbda53552cc3456f2ae44f4f4680f970bb38cc17bb71d445b21ff75223c62f23e0a1b7473718c11e5113b329e3430530339d886ddd6cc30781121d2a6ec45b4810c56b07e9b24357b60e6674d033220146c704333aa9a75998c6a22d9f7116257641016de876ec741d9110588f18f610c6ecb12b63de4f039bcb800813f6815a999807219df40a756ca3741e1555bb3ebe07c339f80ed1700d27385637313277d9b65224e08a2a0f2e84086b18e00321fb2bc92e7cb6450c39d0d43ab95e3362165eab45b09243503646691b586c361250f4706933ca3c5cef819454789dfb11a41d31212b3aad4c8b7d885c818fe3184cc7c3670536d053726bf931548e476ee975e4294d67f038ad4ba551b3c7f80c543dee0697eace68ce0c242e0b9eef60e3eb560ca273a6210f62da5bfef880536697f56195cb1b2b4eb84221e4a0f46ad0325d04abd2326bca6b664c03f81053230b14519a503123da826043a9509f4577aba25936460871182e170661cf706813bb844a533aec0df96ba2303e6e4e7766e4885cf0c6f763d8c9dd053f58b36f453c8301e7cc0202665d5d1483ae3e36cb5c50437746dc1b697f443688a43d58e6b6db2d5117692fa6d2f321dc3aab3bd83df624aecd2f2175da741f57152547d5a02315fdf80d378efd0d6af68a7b66b9c0c26705c42450843d5720ac56d139fa926573622c1c698d6867420905306f0930be2d6107e0569278d22cc5f1a9637a9342332381da6d16b9251db3a03b3cddccdb00c3e7010b517476025bb9774314c11f3c90ea2d1d0bb4bd0dfe4250596275074a80090861d7a48128dbfbdb13c3cff96cb0f60c204ebda0430e928a05c1a0f6560bdbc121004b423d50e6c325cd84af0fda397d51a06d9410a527bd1e9f3b213c6794465a04cceb1746c12725abb17828f0b7713b5e47b45a035bba5ef7898a714757a963b787c80f4419025fa50d4922bca39c50e07af36bdb6de51efc70575b76c6b74c67e4a4587eaf840ef90a9c38075eb40ac22d5b680fdeab361c05a01f6e08d230fe0db0304ad59f66323d3b0d1f3709669f944f31bd0aba022cc5a657985df9234f3dca228e34cb5e36656a740ed20c66be3f730116788b176ec8285d98e847773f06e30efc340837c62bcc7779c10f5324715d0daf11092a37e1225c58f8685ed1423e6980904410509dc4359d41542d07d8186f224d1f4372ac7f5d728ecb39f8fe2d58bceb0133a9f1d32db2ba5e3bdc6a4d5180880d551daffc6ed1cba3488a1e0836a3f5fd4a62d85c6d32dc1e0b4d84a30d6d38cb38962a4869517378572a354862600bbd5d2c760f197e424d62c5eb9f123609a3081fec5a63a33a3040ce11ef6e067fbe0cdd2a007646ebf96735bef5639fffaa35550b9002a789ba4b742fca6bd7f174036c50ee63ef8eb93cb5898d4281b8273422137775811dce58e8b8f92ba3352a12dcef6e164a13056727e946202a8d283cda1f3053eeb97a059237c0610a2d20514ff4b80872fbdd075f93b3769c9dec2b9b9704653d7e380d3b25995d0d62484742bb7c552f774128571af87294ed43764cbf7b4f750cb07744bcea1691043d304df279745b19614cab1725253e60cf7518e63f2985c232279c9ad46bb4bea21756c26c5c621b9420067a016f27c32252785bb0290295621ab4cd89327c2fa64d70796135fba61746367bf16a9ed3b27029230b585e0fa61e5e897d007bca603c7586e1477a337932682266597216916ee585d1772474fa70ea5c184560418903cb114214eb28f237b294321851eaac4d27931b5f79805f3af4cf5b54d574fb5b7733ac4eb3ab680bf09669071377845491fa82559ea4516e8ef75d69ac4c923e63e94969d264703b43e50963a56fcb6774370351a94cc90bf8267529bad3dd14d39d9d4f41bec125fb6bbd3448c7dd6b7b682f6610e7bc6889ebf03063d46b37c7332e0e6bc4b2478c228531127adb61cc22d52d862ae45ebdea4f428fc5b82e4345c46e8f6eb8137a486f47a201fe0b6054d8469fd6cf31422278619089b803a4a952105a67f8096a8e5145d204eb233645005165c205130222ac4a4add7945351b826c416b16495197955561a35c4c4bde12576d78193da38f7833c37aed642e2357199a8e474274324470694dfb000f170125533fb437b0cb025efce045391482c2204b7d444236af4a0d789a616f3a38a766b2a2df6f8cdba4481faabe289c4a8d7697b753296516a90b2536f00dca2fd84b5f4dd35959fe6c2e53fca53a57d2d0176a2159233ada7a74bc6afa1ec7848f4616d183763c3e16200b8c5d1243a35073205ffb5f266650183dceb914d5bcd9298bb53d6d91fa813a8afae844eaa9831ebbc21701fdb1963372ad091a64ecd8438f54a774f8d7320a3aeaea2191af6822e1d4bf3be40d485d16b53410bedfc22f5e83865e12053c675f835f459b69dd5b264fa43a32c5d977948aeb3d71a5d62505389438baf2c51bda77c02ccac07853e7fdb9453df7171666719323be39d22e39b8372839457404cd3dd47455bf40011970407774f65a504e73f0301f0f5b57c9c4b82e9275d30ef6ac161b61f484318d260c4cc5efc03d97a28240a5fc4b19383a0634cd716804629eaf76bcd0ce32dcc1a454e58d7f426de5e1323b7efc59ca3a2a2b29839555ef5b8c62e30dcf0ce164eb352f146e70261c5b1991207e1095c91c280848ac00f7a9511006854f1ed563a659d37a402e11d49108730aee38aec4e44105fa1e442ae0c66561502f582551700a80fe4b659cbfa1747c6cec18de20252848a74d1c444ffc5ce948466bdfc90c229988e20cd5da6c6ac276ea0efd77463f7483f30696784e22698f6704cd8f78412a082c2379c69d4c99f42c2dc578e3136c00e4062adef33c49b3081ab87f9a6bcb86da3f2901743855216d3d443e273b912a3549d317d269ac0b6f582629ed348242dc68c1f7452cca84740526e35f5c0f051b720aba584944ffe71f23fc8e604989d72dbbee0724dd081461eb9ba80a6e7d2b6b6844f12c8cecfc308af8d421245124012c37df381c23c729166ff2000012165961f5e63e765a784dc01431265c1d1f5a871c3457ca512a69a18697389d9d7b73fd3b1628ebc6a1051366e160fcc13d6c9577bc3c7475ad5eca473202c491b535967ddf0a37d00c56488c6f2aba009b188187061c151725628ec0735b2e20d817b5cc654d11c1f443e462910940d6d81a77927011ed63c56b4f03b36e3c26570b8e2ad650c28e7806423a044208a2e83f52ccda489964b64e750db42bef5d590559d83a0bc541d95574d36e2f1469f7662c0e4d701df03112b1117b03068696633940911b67fb4a587b647a058d98fe1d90a9064181eb2f15e53e651fe5f1c5710e35e322e20aff1b0084ba33158c9509fb83a84a951bd50d1bd04e3c75d5615816fb9026ebadbc75c79fa06e51d21416a2445356c38e91142a1ccc6962875f37c3355812e2031a049c184d4d17568b5bad22571ad996251cead1b742052a6c509123161d5157fd6bdc5a8b5e61451124988ac357f6068706e9df22299d2b2f5a0466002735b8dd4a9db499503f5cde62a8cd9216a65fe36f9658141b0107fa1d00e8454b719ab537e776ee6b0280de17aeb91e4cddbd5c01c6ec9657f4676771391d4c4df8a48c538ccbeb6497306a01a65b8763b4db936af20f9f42371d720770268064464a7552c6edda631a35125e73b2391410ba0512d8c0af4e83f4775cf2d0093fa1650f0c2b3aa84a803b6a04f7cf092a767c3f3f9bafbb2d23425c3e6b3e5d482f928b6530b3903b32f42c3f9745fe388620273b6ec40d052cb69014404e451ecbb5e443d53ad91705b8ce411edc194af1de3054786a091ed13f6b272572b7517758400ff546cf7019c3a74a9cb7ec53370dda73cfa9603598cc1f462cffe1053e28b22ed20458215f5e7026dff1c63e3612957102eb01607e49ac5591edac5809caae1f3a327b41b22d7f3a09ba13142ad95a1bbff2ae3961df4c681e9fe2588bb7a011d21f464d96acc74da27acf6ce803df2c581b0f2b055f950f57988b20d43110520ea0ee4211c7111341fcea19aebd3052e310845c8a4dca4f410c6d0803148458de91bc33450f592a6abb32683b388025fd0464336417574916bada546acfd90fb633e730f8ee25268d61d948d671c7238c2027577dc77e1c93ee28330a318e3e76122c0459fd704d8ffd0a30d11a0811aeeec60fa29e8d3595522424b499bb5b748b51318c055b5fcad5286894cd230f1b2879230617f547c326f048ee48b4711e85214e17722a1ccf1789273b68ac031889474ae3a6fe2723ceaf5724f25b67ffc0764fca845551be090722b374c83cf4f959588a102856ecd5c65fbf5ade3f93a80e457a70056ce727b624c0f28a1e78c08a3b7fe87c361c4acf7295071d1c0343325cd1516d15f5a2ba155f63796614a660089914e20613931233611dd82281abb564ea1d6243b905ed29c452fe0235002e6b800e263197915b3c3140d5336524062b506c50463a7f782af9c13f70d8ac97311660265c38bf7c438019320d033abf08f8e6dd6b359d673b4ab8f9592e860758c300aa22026c245f70eec03b00eb78523f72284732e35044e8085411363ce2717284f353f7f01052fea3fd1ea3b2dd6b489f1a4f7fec863f592ac4389c17532631e10f751edf403241bfcc17f15788384556b1072e977b3ddb83de44822a6d582d4435120466bc005171344e03fa766a8f767367693374764ff9e554442c095622f1d24903117f1452152a2deeb59d2faf40174c4c40a86e26529771c1c0155273efaa558e45145040cb520ab2f5b6013e8a284bf34d8e3d7737cc06118c864aac17722a70aa712f43bcaf60dd33702674919c5925c43c3eaea9463ec4827215330a68073ec37c3ff4730158252c0c449ada4564668fde146a48ce76e7a22c233e02d1698d7c87049c8a4f4f5834ea6edf788f23fda82b1869b85b5d8d9ab5080c4e251276c20a5e4dfe7f291d62ce3caa3d8d55efac3f721b0df63a0bcdbb4f06b09e3ce204361b8d239f6312b840596ae9d73865535073edc3d65e97a762415b0b2c5af00f1c165948bf4f1a47fd5df5c3c50ac5413b52614793473f310674e0f9c262f9fdd0322c249049939eca2278af616a8cd8ee44c3019912ad44d95221647c1cbb1b252dd0cf4d1c2b84373ce824a141f02e3a03adab7a4acc99503365001749cb97431d83afa94df560e977cb048369f91d286191fbd64641c4dc6416289d3a602ff751803a34247b6ae76ccf52a71b1b9220366970126422fed827bc840a2151c9cb05ba819b65b66cb933fcf3876765bee31f9ae0936692e52b3ee2399b7343d90673233c45384d7e5c2303323c4027cfd164912170165d933a13e2619f3aa252fc24681c7112e77a8d639c37dd25e354e268272fc711b47dce12b9ad59613cf861760434fe1efef2e30ca288f93772004d05eda4235a288c89413861b64dacf1b669e4ed1f75255f2d1b25a2ad325bb5990545c876539689c85fc6811872e5553d134c0b04295c269573d81c3264143fd937dd4a8f5b96a6ae728909792d6628941ede8cb03bcb7391485e2a8f3d067e5c26c1aea24ca2810b10cc77495445abdd30440f2438fc01e85e7c0cb42d8d81ba41ea1cb75dd7468e4063480d277efefa1c41bd5c555ed3825f2b25130dfd70fc54813f1718c0193775992d0876d5fc275254f08b20ced6155c97c0191bc2474d0a1d66fa6b0be0be140983c8214dea8211d7af923a2db4930854b8d72b56842802ebf6cd3045c80b12b12b385383715e4376c5323e1f48e9630db4a2312abcfe55aeaa9c218bdfc54227cc2e2942adf13c6d10310d1f2658129b456629dd22a55c6aa81822323919094583ed5f46088812bdcd49464952c568f2e2d165edd93a54299fc20bfe9eb9185776f06f6ef10c31e8dfb23480b6e54b29dd2069b2227f1de3159a4e2dbaf318ee17a856c61b2952c1237d3ca864cc42bf08164e395c7732fd6d1f15a911f822d82f8f1a6479b048c3e433147944b400fa94160ab66c4c493f1af90ff23a9302a701dc042f128e175583d35bf9f04d270a70f260072774348de0522573fc5e1fc49df76738457735668da70141c58d062af7a5222cd5454813d3cb089fa0625a531a326f24852e5acbc19c413867ef2ab541c00c51c89d3444c9a238d3e7453d0912a75ad661e2511d7b4222ce1ca8580f716b570199f148592dcb652deab945546c9b7484ea6b323235b14fe943bb5d00ca7f45facd60298adc432c2adabc0b611e8550844bba14e214714865da5a1f0e6f222a60361a3c42372f48bfcb2111e0189c4d62406d5c71e2bc66362eef1e237d3f69a8e4c6485c41f344b9700605e23c605c1a003a206931c2007a963b761ee03b6ff6437c0c8e696c24bad7ab0240de8b431e7b04261b83e10c3c02756f86615d270435c058329d05305f2e4b779f79003716d7232bbbf9db0c27af1677f39a260e5cf7fe19fc01086243a7453600ca663bd77bce6c9744683a74410e724e9c9f1e9215c83d016faf313d4c33463d7f5e54471ba63596a1a0140ea419552f56386ab695ae73d675f43e6614821afad6365552e7c4366c525a129e8388406a7bfb15933f910a6eded06d8782e766a80fdf49b0c82f06167cfd3db0b3f800d778b4405964ad7337d03611d8e1f6205b4d5f359de6343343ae883db20a6a238044cf67891b4f272c69d503e93bf34926fdde291baba902307a3c5266af614721933d49dcbf9459ab13713464cff135000e4343b59d344ff2a0b834cf45523df2759f337b0dac72c0d9e742cdb42a1496709b30d202184f34c18b46304d6e222f7b3148030a945f0afcda656224db094c5a644ceb3fb22462a173025519824fa90c3f6acc6bdc4ab1db5b68a1c76375b76cab02fc796233be68c70640392c363038ee42a78e7a350ed7686cfbcce94fd03217410528ed2e84b27260e7f37c4831a357179388a2040706f022f34e2731c0e26d4df5447917710ed816a3b1e86cd720d21796cf1408abb6a6741a124857fc351728d83dbc3d702d72212b69a267939e3833eab95972c2ea9b19a1c54a2161cda534a200454b9b83683b6210f95ea8b1a043f05006433811503942d9a768774b70183261f76c36da7a19f7e8ae42da4a752a839136514bbef0043b396e70faebe070b8815e49c1b2371baa852b755a1a397743278244d330935831049e005410c66fb9fd82771a61822a15a14b26c4006f401e0857145f8540111cef3a31a85b4c32cf887e05da169e562f19c541acf5b1363ae27d1c4723402be72a264e9dc8d85fa73a69166743aa404de18d3d8241ff5a651a2b44bee1602032115d6158ec56649d3f30347c3f40483f44bc4b9ac00169238ef7243583e2290e0f9a108c3930340b76561146bf7877811b6b593c0f7d070f9b653031b1450600d0ed3d3af2fb4fdfb5fd2a3d82582435664c10f9dd594b8fbe64766c08ee4acb1b32593a2949715e5c9346b43ae30866b1f2158c79225c49fc2143a2e466606dbf88003c1675064f1e901657969f4e5811100ed663a1394455f61476a5213eb545bd34155f244a4bed0b4787acb016f879f426a452033bc3172d3eeac9cf1cfa123c2db8062600bcab95088caa7c0b92ddc34cd7b9cd555861c2730db90b774d32ef308838c34941a6c15e6ede0e4a874456631b230f705a0ddf55879d8062aa5da55301c3fe542c803d73201d285bdfbcb941f8a9c7519f45566734aa4c10fe3d1a163021c411bcbddf2cfb126d2e4c7f0912644f3b74f83d0e1cfdda1e0edfff03407ce8e37589bd361e9165707796075f5f6cb41a4cc94eac42fe039f146b1848470166b9566daaaf17fc7d68111db6eb4ffa25aa1f8e54404a69db18138e89cb04d76d6c4c48fcb264256c2a11e4d01150c6f10076f7878558f4e6c349940377523e22d14bbce38c08539289551d11c313367b175224307e64b697594998b7ee52d59c31575d72750922fbb953d277b642ea730e475bffaa73d1cd662d2585e760e940606558bcb940827a5b3d5771fd1c94ce375c1fbb551b064a781b03ae081eb967473fbbdef4513f8e622b9cf2523485c87c0565252a658b525305ff87bb5c3bfe2c229afa840dfbd9cb3c645e1f663a25534254beca707c1153225fe2994be13b0a6b94a05751eae85414b0dba0209857513d3b97ef49b9a81818964f7e6eabc5f63001463b0cf379c74fb97aea3963646c563c7ef8699d79c95ba4044716b42d140f06afe70ef757ec5c33f74323f21a8205ae61d06ff94a066e986a4a26422fee639577e772d5bb99555849b962fe80e36e170f90229af6162377ac4960cd9e216b05db245ec25fa336ce9d3a2ff855626e532d4b1ee1ee6939b0d7871b4d67b95722ccaf53237dad0f93d2a6658382bf33e071875c99c94a24fd199543646c185260cbaa4ebaf9cf015ada2023eaa75a1fa02f773d0ec5db4432a4f92367da236040b2b307e5014d77eb027f154d984176b8d74247048fdd592e57c514deb78d3bc6460057afb4c435b2e1c15620c139724516055ec2ee76630f57c01cae432f5b3adb2d125935d0574b7bf94f435be50813dbaa6523b14939bf776e5942c5ed2ce40bee3bf3da2340ffc7625c348fa90890622121e17d61776cc0801b44a4e8139ecf327509477164e6a1064acc0b413f8d970705cc28215c5ecdf045cbedab07a84d942c905acb1ac41f397663bd0947c9d6983db567d8715bd24f191d397b160cb87c302f77782d05a5a636fc68c04ed0b0c34c54277254a1c582234491814e2e518d3571211967cc375423fd3f961a01920d3b385139253723df20e05ee93227583637db351c0e82675a215cd42e380ac6a62ee2aa1e0293c7dc63f0337770e317eb413e2540037f72605744db8c464754673c5292566bea4cf07421a45677ca56032f98c69a4fdf6c4e09d9f74972f8edaf0cbd93a272e89c844bf1afca0d3ac39d068f872027ac4e4e139971ed5ffddb5c437e9d996f1796f361e76f8e5f7127416b93b57d52e885006205b5060e08d075689f9c8c2b973b5637b9e66d74b07799476c7fb0691708d76d7788324e68a2f606870b4615e1c59d6b2ec2701091969b5047607c25f373a4051a750e69aad0636a1c8f6800113b3d6ff050ea73d2652e3642abad055acbf923349b2536a4178216fdb41b75771382366348f31df4ed28536d91923867153f085c6bbd281227ff669d86876eb0161947da3553765080632c5416406159d6260103b2766b61df5618282fd756c13f5002d198b00debbbb07527a9e90272b3d749fcf1eb07f46561360bf4ad2a1d820063035f9c60675edc02dad62210c0b49971d5d6d9247a12731fb10d873ae1994926d5010036fc55be54d368284fb6ce201a1d2d240695f728240dcedf0a996de7133c87f82280c4c14d66a62f095d4c97762c56236266f957420ce49e115483fc1ef7f7e46e8e2dba263d2938517487850a5c0345272996195469dda55fec1f7325ad7a4117f6084012649500659842fc359a9c7d59dc9a505f9e8de950ec0cd73d4f1ed03c057b3c432701a239f3ca2a0a5ec4d03cfb507326451d9a4987c7a41ea3c4a36c6bf70334c7d4485b6d543d4463bd5f4d32e3761c04d3435fec216266baf5cb492af51359f8df476c3deb7274c1177221c4910b378e4fe63a3dd399284c4d4f676263d302f9ce3a53a81daa26bd736e03c19e4c36117dae1a94b8951c4894521799c631660c10055982603855f2e1ef449c9aaf2f9ce1746f965bcb75256a5035492113088d832066e214ad5df433b264c4670666947716232ac5156f9da7b8437b1ca1550541aa444bbdbb49e49632101326df6333d9f349d286751de7221a33896555779b36b541f047ff4db525d646af72203c098d35341c10aa11068fcf0dad668755a1f53437b8fbcd32cfd7261e7f9a134d3df0353de2186013694e3a512f75d94bbc5fd31980403f5648c7c934efbb87395ff75b63a0bf6f00ab69f20d2dfcde1b61dc10484b71bf07adf6313eb23acd176ac4fc2fa8d79463a17de031b02ad00cf495ae5da28fe54d6b6ceb74669498066192fe6949dd72462eaf6d5d14050270c9602c39f1b5f46de9053135f3f3ed3b54daa741daac4536f8a4f91a2c50790597e8620afdd435358a0ad9313c98b10cfa64b8693ca4732982d976545d55a340cf05ef0f17ea9e32a051216569914e37181a0d4a3ea7f906f453ca017086cf60d1fd2467f5aa3020204c850fd1424237018e516ce05bd9718dc22f29405fb0695ba8566c54238d3947289b39a024b202dacc492eea01bb55dadb1869c3ef5c526c33415b81b2b601a4233e3be2a1961c765d6b690ceb9d1d08eece4348ecb40d864143421297825ef531ac0328ce6d37bc9cba63abf7846c2031513bd623d9541c78fd5251089c35e952f234fafe6c52fe2dac0531c7b32c3edd4f0e2a3e2e63e571ef3b710ca56c20dbba4cb8b0f1507f1c5434b4003164d71425711a048a0b0017f60d67bdca3f2f0b2b12b053f9102c743564bd17f221f963ec5c7bce0d2d41e3c05f3ea5944bed0d43143a994c083b504501f40238067bb1d3363edfc971ccf88610d820d40136268c59af78ed0af549a227817bb55d5eed363624a8eb0c8a65806ab7d85a39c505f33dfc745a21d808941d28a7b04d14ac65155f9eb62f885c6c3fc515672cf01f2c1aefca750d518b2725c8664617cf58db3a51041a5a52785a01d2ab644e805dfc44e54aa02a201d5d39a139844dd2b6046ec884cb6e0981a626b79d8a1aa7030d6bfc37e2286657215c8cf03f6c5ee1886cf837185aa86c4001690ab727354dd3585a7b996a8868b272d2f67540e38aac1e23341f3aff25d71fc6df4008df6011121a80683515a00441841a2f12d2e43668c7aaa21f5fe48c3b2bb83364818b9130d00b27652654d0525203360bf2307a4114baaa5f360f3328f727296121a91903397df91f8e68d6098236e20be258045b7af7ec24fa6efa69149550347036456a8ebd2869bcc71148386d30470d963c69955375044e838f18a4bdda73e997e2204e536a1007d6ca4c730e7e5f31edb22384fb895520aa5b265e645c6cad28f56420b97a66dddfbc3bd4fb8a06bca43b33e2a9f82592a3f251755a3354e81f5737297fc8190f99201758b8e1400974f25ab1fa6022ce85e05d153fa82843a4da613f6a5901d260cd45f3e3ca6d71508a0150d99d40d21b013f992bbc0696de0841e1b0a727701dc142790d8e12df2d7933ffe0295fed54c82f6bbd3e3d6163fb26a2dd1103703db71c8e3de50483417b0440bd412f6b2eab2b6d08d21b20c8fd6becb31b21eab5f43a7fb3b97471296c59a8be98170f11d7161ce8ff687bcf20522d26544b5431820a89431d22d4a6a7668aca77522f4a961df38ccb4d1b573958854924055c207d3daa0d5b60f77bc64d3c29d44856e2972d3e37ea3c47b52719be64790cc3ad205cb9ba15254e16fd41457e324bc5499226163db5439cc9623f56a5c226d0a06e1237f5ac536337d147f75b6c6a7477562e75598873d6619571a0c37610464e4a6d5d213f420faeb212e89fbc5709718f49af39af32ba1ad2498e3107055dd5d5122baee713c6b2d84963ef5175f9b1a90207720360777db64ba322c60eb6e2ff0c4e8ae8536321460291a1e137a15edc656cba4148e6f1c930b861b83469ddaa4ded55cd4b5884b5064f15ee278762026c4bec7c3e6456e523449e666992a33d0fe4c0cc771bfe742292e8ae6d94302c326d01d35834e2593a50d75d2892b1436bf066ea349db5506410635e340a918c4805b8b563eb511903164dc30e2a5b855bf5f5234bcf7c4c1e11da490b5ba73b4a42ed85320e700b28c59eba557d9f8e1092088916d3274a750b96615d8950800639598b31e108e04c05437319c678cb495fd7e516ddb9ce49b126d9678b4e7118109e296305373046290631491803d21b9a4f7969931eea23fc5f5e6bb6802254d9ee10581d8ed254d7b78e2c5bd355326a4c475ffe060861a054b668eb67d57766feaa5bfd836b24dff0bb3f3910fa320426f8598e434c12a2809d56dc865b1863c8ce5fee4438761adc486d6b952544129e090f35773c553aee89591946a430c17e995808179314f8c44c651b816c6911aa15569aa06c616a00af6ec03b236a84ff81022ea9bf588ccb1d3c4c32843066d5aa3e9e85c43ebaf7206f7db7e112b6cfd604f21eed1dad134709281ce718caec3476e411284dee3ddd53d3e017636c7cb433663da4425fc4c539cd48173c0588695fd2c9eb748c7526199c7abc073c5c9906ed5aaa311af7f8756e1066344e70ef67ede8d7216ee1493ed29592377767106e8acc564a655fd007a9108771e9de0e0d95c3a92509abf216fda5586aed57d06bf847ab6b5d165b09a315875238b6d015d69d160b8d4b080a9e2e0b0e78e94d2d230eff2b4c94e16f29350113cd9eef6347e2ce012fc7436cfb2d8337bdd26c31e96bd74444903e68c2e0c8284e0b0b1117b67863c4f926118400576607f5cb57e07d496fbd6df038724dfb4f01fe791747e4d907b96bba185a3f7b00d887945d42e78e0fe62e5b6fca31b85810c978121db292380d33af4781fe4e60d3c66b4b19cde91d7a76b3692e2b691af589aa37f1a2533e23957963a781db12013f53658399eb6d9a62426f498cf1033ac4215ae294a9051439bc472a00f02d2926011044e84e31d4e78c63815f875b736a3d11a1b2c56ab27c45574c15f957dd4f932b8eb6d13421ebc35459480f31c25f14082385cd30a01a7371222962551a25202e6d8f6c6dab8e3f2bfca1206eaa6b2b49ea104c2b35b2843e1cb02b2d0dd3d75a1bc7a130fb9ae745d47c6c68ce452867f77fe36f4715de4afc4627033577d11daf0c8721b075a777adcd2139ee64c757d076aa076e03e95606fe5f73a6fc9f08af67c566cf94455f1a646a1dad98df158c1eaa1306d2b30bae0dfb4060b5610325d8ff62ae95d85f05b49f4ae381b7642c272c60dead4a03b0024f1b7ba729300f21a57309686a588e5af61dc3b12a0313f85e14a1d455212189c5135f46ec29cd94055933916a6547a54208a1028f3794fe465f9d482e3892766648fa9b143c9c4b763ddc870a1b3788ce2fba92c00b1a419866daae265e9b3ee95725057a2074e8f801bb952a1c3c6e362b1b7f5601622e2976e733d929c7a1327739e329223f2e4b1359e7d34714447f39db7a030f3545833c6351580d4667656a911e735406c4fa0ebe587b28fcb2df4907f4d732296d5023ba7e6562aee22f28053bad47c6af7315ef729c0ec79cc42612cddc414921b01620229d4142f6752a0d172b7006b6161bc0df6209e72c112397cd1d36b451e029dc1b7a08dfa7d82a48bc8440305ed0345a465b58993a9a685df00b1edd04796d2d57f03a6590be542d11bc6e6d7f221953b1946a73fe7b20bb23162aeec3490b72f63321bc2ed21d97ddab65ea3a1d5fb3cfac21138d2c75324fbe2c29114960edad85718e62284163568d3ab0523c69b6cef93e4b5ca06978db515e67f0e15c9df54546da66651aec20d92a3f2cbb192198ec4a3af95f4af21ab1012da3c525a6b7de1fdb87782ac0bb1042602ef60a38ab211b51e1c070a79d4a61b1136a5d5744e74f35f71128e4330a12340eb342d93da8484a6f1652637d67714e6323246c2b1d4b31fad248d355d61af7f7661122e1886ba1f3c468b4fc4d675d9fe55688aab02c3e1ece113a4e2e180450ec5f0fef6a2efacf7b179eb44e0dc523bf2534cb4c257ae8944dfb5b5b1d55ca81412df6391460405e4fb29acc5ae14fa404fc871e3cda0d5e4d768b8a52d00b8e033dcedd283a47ad228cf1ab64c651935950acab27a19e816cb4fe4f1e6a49d402ccc8e86c602b301f492a271bdccaab7451cc734a4a9d1561053af83fda64df1b2a8339113984c24fda422059a0265206b8c6e407e9c25a6efa3c7169919d044686d24907de8748025949b5025d6f926fb9d19625e4f8646e28bd3d0ea8a0ee15c80a6975aabaf83695bb890ec823fb776435db4ddef6b23f727110169f433c4aa7f458602564af169d192d73ea6ec71b0debfd3b3232e3714f7b003396eac52f68839b6292bd58073e895e285e1d5509a84789604a688606fdb5ed6357f14516bbf5a341884c49622f34c5610307535eaae8bb2ed6439939d7732421c75771323b8a6173bdac3a6c47e5476126048148ec0ed45958c56b74d6589f58a189ec64dfc1e15829e90102c3d172573810ef0d04590c62f4d4e860c79f7f495868e11b50778043d7341a0ed83ecb5a8bd1be2f177fc4139f0a187480a1fa32a292f01f14091f48d9981077ed25ba2e7664be46d4d2961cd185453757608e698c967b2268e5ae2e1a35510b9b9e1573a096b70753dc694f519b77560b6bc0654fbdfa4415f19a368d3368329949f248900ffe2f179dd635822c785cf5e5ce10247b246640dffb27dfb3b55a01bab51683297d4764fe6a6418f59f77c41bfe2aa92b8d05ab21f5512024923e15a2694317007862e5a8f870abf3c47550cc510ecdc28859a2dce94b409a9f39307383253932103c2c077025c60f6874c20687164e3c8b01dd40a76372d6a74c1e582c541557f63ce238153fe18f465a3ca3d8255f5bc7168b34b611990bc56fb037eb177a8ebb37a039ad59b14a4576ba8f74077ad2c464067b8501a95565375b28c40d13edf411e43f734634ffcc435fa3fa41cce15b46f7cabd25b47c39043017f65dcd66536aff80b82f696f3c7596c397220ef8d85bbcf77951f57ea12deb08dd039ecdda5451afa02cd218f859b823ec3f200367075571ae3b185680171a17d52f2f6964156914af61f85f7967216489565555f06527665129db5fe203f1949e12d63e83722722f3263093ab391861e36f088ee8038ea70c2ffcb73957ce9b956df43c6b6d91aa99377f58bd2a7371816344aa274b493c78081239026cb9ef553bf985d60455a52832e34d6d24525c162f45d2321dac2e9a5cdcda2029582c22161c78ba04a5455f60837fca4917fb686327ca9c308eed98452ff9df292536333e874bd732ca7fb560283b1a5f6b526b2040b30a3cf13c5f12efc3706a0522244100c0090355f499532a4b41466b558a424500d206f362381488592828469995731ed5c7697bc077282865bc3ab7fdc6732f41f52eb53091284afb463f80b6d11ec32dd73e9859544666707d602872e65f8c70312cac3c3e232bac26175f43316d4b34fc07f93720715585705ed705743292c0f128328328448334c108abf62a7593919830ab90b357f2ec5246b874115e8152db3f8e8e6154ec2e0f24943e65064b293b0547a4ce40f8f55a75fd7e962c82c18e4da4a2af1ed362a95879d0901b557b9a6a2df3e00fe2ce43017af1e7421ee0b67238fa2c530f8044317b200808c7912c4c02baf8559a407732a5a1e96b17bf510157d58b1c897c460795bcfa1cc81ef8259612192ba62cf271e5e9910b8e84703ed3638f0146c3e00d24c27a034f4a1203e5cf6c650e516b341c6c9029318a7841af99b9490e74bb0eb51c6b0e4065715bc5767d4b2c1efe5f036b54654e3aa630c36d5762156ed36f9a5a0d695fcb0e6cb2a129041aab4331f0465923176971735b55325b0f20a8309df7bd70833cdf0d548ba952d235645683bb640258b6e6291d4e8e118bde3b71ccda3e5a4c085f235180ea10728bd43f2cf60d63cb96cf1d37376f5c913a343ecb330f4cb02b983ee9e9d21bbe3b184eb86fbf65f3824f1c47d849626e28e12dada49b68477b5258ca46d930c7cecf59dc7cb16f67fcd068a56ad30caa4c89289a39fc761b49c5535f7a261246c4130a4200b96d043a8d0c76be19269dd460263833646f3701924d4efac125b7b8292664ba8955ce0b6a14427a2c485143ab0c71e5ce079fbf5f161c1cc6061fee4b5924d1b609997c6130e7d6ef574257153628f260713bcadb0562459c695f13141cec827643e11dd403f64b321d41af655e380ed72dbd78463b0ab0510952b63a07132966721a84921484c59a0fd89dee3f7667612261bba012a07c0843d8adac04b41ef448ec8a024d6fb1ba3f15b1914a24fde80c262da94cb60bc075c4b4c20cf0ad670a29c70a068381f2484163ae3f52b5570446ef632c7e88bb5c47023012cf3ccf5498b0585ee2ee0f1707acc620dc6b3647b8bf03554308373c096994417f3b4a2130adff75f59f542ba1ffd45496ce8e31d41a5c39b7ebf552c5a0d8517226c228769675251ac3e32b8403f8261efe2f29569c070f3649b6360201912b5a227060e0345a1da9acc2124f18b9355df9cb3e807be365f039452304022800cc3790070a6def1783cd346a04a8167144dea12d0fb341145ee7443f66ecd8715aabe34e4ada1d10ee75771d18e9f167ca8ccb58ec67f718994ad70f712ed53405616e178598247636d83f6e9bb9a94f429154703abfff566dc43204988234617ae11a41a91e6e0314b9b74a7a2b70449d665111f550430b56db9968b994005d0d2816199bddbd3afb3dc828cda3010f927d094cdfbc60062f227c40b72cae042720993031aad308bacbfc435fdf0d7134786466fb32c924094999142116221fcdf23331925cf147d830994f92097e36548a1607d599825d72fd9c7559907c0b9d2b390edb2e72040763a91d70732f52e4b79855dfa29850ae309c524ee5d570fa8dfa3d3d9f444b32fd043c937b3f701cb7ff7710d1f565698b0231c1c77a2aeb52245e17fba07275f38504e390b517542557761f8a476c35a7403c5c0caf199183906adca979677566511a1fffbc663155e60b5d2f1e34b9c5b916af34e26995d46e14cc7ca9198b47593dab17246be5eb1e543c3e2b69c342a95114d4c677fa3dce294d11835fb15b067060f7d30f9f9a482bd976d82ff64c8a657ede995a07c4f032eb6f8f464c770e72d8aa7721589df02795c66e67098ffd72e8fff724db1087378b9b254771e80811d8dd6166d8bd3365a71d993c0002c1729a7a574379ca08367ff8624b7b925f266e0fd937b59da231fdb02563737ea43e873bde717b918b5e9e448b02dbdfed3074f93501914de60a801c921c7218b91cc35e8060aa46942ef5c10675b260c111898f0260a167c01b9e185606e3dadf1ef7e484023c48d050f26915115c88246666d63d25829b413f1e1bd00ab224875d6138b236042bf76d90725f2dc194f145dc842c22d45de74d5866a70246ddaa2346dd354458f2bc4ac786514cfe891d289177c34e147bc746e27d66770252ce0725793e757d37361f631e2a17a365225ea533d6480319025c9c347f344eb13910c7675d3c27907e2c1c7bb753472edd49c9103d3c7327a4471b32b74a0c25946d669b81467977cd01de33cd6f3163b238bbcdfe5891d0cb43b3eba31605f3004fd25ac166f54d6e1af739362b249aa33e6486c461f1439456164d606b3cedea2af586ff0aa15e9b2ffb38a1445e288832d64eb74e916b4d23308d2c3034de6841e53cca3ba21ac456cbca6f58d6e36a19b543ef6b7ec5ae2fbf39926fef5b672d7d5be534203ba32a0ff41d41cb8be123f1f00543f2bd6423b269583dd12ba15f71d98308e1de5c034fe03a0d4917ea2b9581ce26aa63cb228d5d5e1ec8bcbd448915f8185d1998519e9dee5563be603609f8294df9feea30436e633ca0dfad63946c6552c691a32bca1b600c66dde60a0bd4a25cb573cd3189952d5559b3d40f4993603be9b7441525011f60f34e461757ab6716c766e73017a3ec3e82a7ae2858de053651dd3833e0ee20411e96372b9e338174ed9dc11fe019ae4f9fd9ec4aa3ac4e4d1d02ee5e5da7046dbc25e605bbc2d25ad0d57c5b4a5054727653b510f65a154a564698271a8eb622ddc214412f1f566ee4b7490270a65c3a2311df194c36764eb7169165013cd4703b2038547ffcc84313ad581c7b570208361c3375aecd2a47c4066760a83f4a3ed0bf5616cc51442b304de9560d0a4a41f005f9201b4b385196b3ae2738635120d67a230aba609f02085f9d16ef220658a7eb616d5e072e3970a3ac4044b69673d392a36568606d42bcfdbb53ac324d132a26764d7d71463544ea7a45bb744431935f176d580034363696900d4c0f245d9c64cf1d590f860626c3c3307b3cf75865644575eb43c258fbb36e1b9be623333ff55838cc144c66a53fe611f8eda249be6d1640d084212785b87d58a4a3ab3e5082972867f5dd37576dbe286d17ad55fbe1833d0113144da1206736a673904de4aefd2432beb641431edc3134f59b15aab324132ce07d14a7aabe6488e3152d6a146e34df99e41e55b0d80148f0da65eb14d0254cb4bd590ee7a651a4f1ae65008536027e0aee10596a58157ab0655e645df253bf913f53e15b1016d68f8c10986d7e1e8477eb377122a1381fc5ee0b51cf83762d9ef51fe10acc40b2efbe5b1f5982346f64ce71d7425d64e3cd136ba69c85602e247d2959e7702520782646d761a54f58699139bb1dac087709c40798f4b64e3567e161aec09a3f3de51864f8150559ccd0491e08dad204f8728c35d4f8e85dbf0b8004ae76c12969ee28696204d159f09ada4d8683dc6f7d5c71630d7bde6da7287a537108075fb32d8f405f713b09d034b80f85b4cb6c5fd4b25a90b953604fd3710529939d67992e8c0cd6060a75d3d6ea6fcc86d5717e27c422fe1bfd59ef733e27c798fe036155842c6ad4d653066a692cf0d80b0d905bf46ddb08b330fdfbc37692517451de906452965a26575e8ff431bf30e6754d1f7e29ed672623eec2014ddf7fea54c67c245777f51f3202007e342e958a1633082120b26a4c6046044a6a71993f7399f917042f0c712e2cba710f082cb119e052d05eee1f4c2ba055683b20c0d95e014bcb49a25a8246d472423dc61dc62162f61359826ebe2a2e013d37c8f9754bf2fc7f0e837cee4bfc156874e1812603a92eda4057bb6735f376ee4c5cfd850a0e453d462e9d6429a30cdf628293185019d9b31213a7b95ae81d6c50e3c7ad3d909c8c3a9ae97140d1c71901b058506460d54b0fac2ce0239e05d4235af76851f585283050a3cd02604e6d1e4b98865890804d526dce5a29a5b68730c9fe1e6c3d15e508a6762f08450d474c7fa7f10cc22f8536f3d0fd5ee4075654b06b235168e55e3b4ab05c54c0502b6f2eb8eb147fed234228ea8056aaa18368e2fa2801e88e9d766cfbd27520d5d6397876e81bab31b50dc64178331e5a9572feca354254dd7f4d8c13a8596ab0073eb0edc16a7e7ba77124807e054477ca72384f275507258d004668b86fe644a95851758d3c33fab7517661fb73d85714042b754523da3b36523aa95f00d4e5e1621d617f5ccb699c1d6a2aa22bd0c19d76b3af9f6cf14b6e5786d53e1a100ae6531fde434bad542a26038ec032ec23cf267a9ada5c350dd62d2f72de2c8bd21a07bf6e39350b6c9642c684d034e1f2ed58840a0926547610029f1e99742330aa54fda2e93f9547143fc8bc286b5428f03634c19521418ad0405d8856099327eb4588443f302e0e00142fb51f60c00f074b3a0e3f09ab988a3ded5ab921ad320d6727baba189f252f3dfbb42c3676894d0eeac6b9722ae7e81f97bc013a2973b7393890330c0599981f8b17a635b1a2795c84982b6b5389c02b47bc305de80765525ad0cf5fc50d8c162f41d6422bf1736c0286b0740e35ad5d982672720f804676d813f314e776266255d18347e521d761986eed4a0a99f103a71eb506c0704a32dce0e66386be664eef9ff4242bdf124f7b059e2d3efebe34266c0c3d57281f5e1dd69b5e848abb4be811071c417b87476f66ce7122c25a20ee0b996fd1bfaa13153887771d10f93ee2e8ed0f0951a5649249c04531169244d8f05d1a4f557f2766ef9d672d8b552955f29a3fbf180207fb9432555b5f3f5a871ccb5b21b0ee29bd34ab23a4a394242cdf4466bdfe2240795a33772f4b581b63dbed2749ce5700aab44940316d065a46f9f3580b16561cf8834d5f51336c599e8a5f0d33a27561a211ef2ed60ced0bf9bad26cbdd44d5ab0cb28505b60d002859e1b4ab7da646d4ff21b2d2e76b723a702f32bef7a235042b1f61acf0189490a799a135e6c49260802b367aa3aa400b9c28751bac8456e867d75778437a0199bedf06c70e6883a24a36f0d9361da5c3f15162f905c0541a771a54ebb91eb64a272075c69523546cb1c614a30368e6cce45f030b146d55abc345c58ce9014049c251a745c1c19081cde362efc49fb09818d873f450a39475bdbd4236c569212925fcb663651e040150fee666d1f9b4bd0673a4c9600001566737b48eaa4505851e4f95ab7583c7420f94e1dd7b0385c006f4d02d5e890664d5c5b5b78826b30acfd5359754fd468eda0a40dd3c5ad7654eedc3f63e10b7199ed670b991efb096f4d9b085ae8a6077ed82515283988627be7613aa8d4a655cab0ea3d434e741d6e4ba13fb99b8a567726c94706fb257719046b4409b6a0614438ee1417791102035d661bbd3ddb70cffdb420459df06cd690b3514caaa24609a6be1c670ada282e7ce561ff07065ee258f46ce099f456d4d3190a22d12927da166919552b9e31c281423f565d555470f0785bb68f8c5b94540974fef69b316c856f4b7667dd137f649a11d68e770094d46e45ab5cf22a328e3510ca29f86c942e7d123eb9696975d6532c79f3790926666748d56bae61b46cd761c667d65b037c9e4ce1fba61e55581e326dfa104844b807375e254f349d0f5a193f591e1997ce664731e379383bbe1e683742ad0f96a0d92a87710a203ed25d56cad5244467f8804f1e912475e5f9f929b2177c57b4a41d3a44fe810e488e76564c77e71ec35832759c66066ec05e17121e9dcf0b467a3e1bb7dce407d5f0ad674cbf0b6f1211ca25738270015718b75b11057e3065cf8c2c3d1557545da26a3770735609f704893db009f82f0b3b6670a30a0f72569f60650229e616b32f271591b9045fac6d6d0f0ed9834902ed8e37735aea4f8ce6b36878129b1cb6b88a1a28f9b534812be60d6607da7029ca2807eba28b1d23b6ba4004a4d540f9aaab4d8b7216026eb41812ffb3b54155ebcd3b3721ba4cb4ffa5648886d652f27c152ad1191c4600432e23f6c0af5b23cabd398c60b5615a9d02381f8ed34671877434d22fc44aec44362a10c6e95aaf86e66f9f97766cb65b051c98c49905b494235804e2395e2f3673608de9546befd03774a19fb81b092dcb505b09de0ff8af661d4cbe8759fc59af5dc0659e540953bf1d017974436b9d8006bb2e755290badd15e0b10c2899407f142a47f2693488b84a8f88fc27f3d72719da7406567ff2852bfdb42a71d94b1554618a286b37ccc45781b0246a42b19c65c9f8be6a716670413080980b97d2df513118b155f7b7d0558f861e388ca05975aa91583fc55f4338f1c15f2a9dc3d22d6ce9624adc488230fef0f64fd0c56946769cf1037b9bd83837d6b60c1de8bb3a8b4c2306fa67a826beb7fb04c6f9144533d887172524d2669b27383cc036574a97b08e5e8465651901399071165f0048d51ef322ca10ad2d0289415dcfec390da27668103e980f09f7b65671ab73e11f544da246bddc5a226264fe45a5ebf26b8d65df0a8cf7b124b158c741fe925b3de621c602cdbfd44480350d59d14da625a2ec6a46635d6d12d2dcbe2f6a6478505275dc1a7be163756953ed45a55cb51cb790d03eb5cbeb5947c4ca75215065696184710da0c9b92a6903fd1dceb5685158bbc1519692d1351f3855700bcfef0a9aeff565c06b0350bfb6c613f86155328fb48e1bf32e4f6dd814cc0362de024377c8df696ba80238f97c2f052aa2343c52e46251ee0db817e9abd147c833a557a89e0434eb4f9d282eb35b23f725383477e7ed328b75ad12e4b79c1769364d1f09498b1eb6dd903615e3b00b6fcf2b7783ecd60537c48a21dcba312520cde4545c3f7d406b410c69b4c87e37607ba83f4278c141ae6db111cd493f6cb39c6e1acd80bd229425851a675b4c499265e304e4f367574087044d686b7639947459011b8ce41b730f682846c2462b79825a57ba87586c5f7d49370572ea3249b0c95aff1a932812b89264fa94ee44f77c076228222a5d6c514a667154b81597904a69242d3f4321934b7438dbba52ae64cd29d1382f03b629987018bc0d27a7f8741ce200d2341d5590111a1c536ed5488716be04243075e95a4680509f33c4b987094188f60ffe7070080829d84d78253c6a31b3056ca55287481a7ecc008f5fcd57485f2c4a3ebc3f54c47fed09d807ca62da21034b31931e5a2e30de772213414d2d6ba74cb2f7714553120122f498802227f7691712aed45089ba263b46f6603f1380186d66abb65059e1e718b24788024f9a2d3910ce7407f005cd2ce4a12a640da2c455a6929738692e275f4506ca3b8c5c4a0999d232381b3cc7410ea8a5770b10e553144c6c2c963bff6cef072348f2ace501dabd550b50683f1f1c439e374698d070b7f3625207b77a1978fa403c314e4f1995c9ae3b13b4aa6f8b365c2425a02d614eaa304bd9dafe1e5fd58e5bdd8d8f43acc38614bf2523477b15bd144cab1266bb12523801286607a76e1472a5c3d16aa8e291377c752247843da61d7fa8e830bb038b084c039e39fb75e512f4a2a34bb6e6af4801b27c3662fc512e7c2fb753750d891f00b1ed1ef218cc614a3fca1f77d81053b23e05611ef4e561206819041fa0790ecc708632c1e936713306ec36ca2b305cab061a16195ee227a5605752ca538f3a2fbc7d6c3da3a473b800830cf3a4633ef95f695595175c263bca2a63ddfccb4be40c1730046ebd07fa3622214922126cb1d1d50531f32839b7c16f5ca83f87293444843d61cfd8738b05ea25e0273d74d685ea38173e5f2fa9709d4dc56be475353d8e23db811244e697203f0208b554964cec0d4af56b185f3a2b0e82ef464f2c08da5f5fc97753f54f8d1a246832584c25472d7240a9525c6a8168f357d31691e0e05e652f5d63eb98be25bc570c20cc2e3044ebb64a4e3f86bf3d139fac42a90c246d756ec103143ca121ead75e6a7e8b430ba9bd8625051b6849dfda384a272824727e42f15bcb44b06e7ed99c55e1697f1fff6a4770d871d23aea07f81697c6a5589592d00fb9ffb537e556da1d1b0a6860e792cc1bf3f3cc524479c31a2e4e783927c21a007e97351c4059211fbacae76f88f3f013863fab52eb63565fc054dd095455c16f0385831c28623d67dc692113b6b45939240d3f1a771fe12ea454fd3424b23e38a6a1de6f7cb7e21de8041a35074eb6670e19a23b4a4bc5515ff2bc1ef0b94435b506f16dd3827b3557ea2b07b063555ae50dbb41b5d414058b13a971b9af150158218b3b2a2d8d67ffb54e63566dd43df616cf442c8b1b76db1d7557227dc625b9d9c25c9ea1eb3f601214383f032976101761587290cf01673e01211ef2635cbceef745e2d3871ddc85373d1d7d682ad712c917a7459a4a5c753c55b34910031bf6db2bf445091f32552d48219186073cd5ba41613d0f318455dd250c03a62a6c72cb107104454733ba551044ac282356c6c30abed9e46791d41e490a2a06572da2663e79623b581822c952aac4802eb3be7507fddd4c2387b95f6e83386467d067143c3ae6611457d03700a7d0ca1ac166bc0084d85562e7c40e19550a7720f3e93b6bd8c46862f8b38d0a30903454b899595e717f5a75037a86054285a71ee206e50c9d33672f2f7b8f76e8e8d5211c9f641b5d552b39f3c2ab379ba88935228647235d360a2da04f8638f3e2d66e5f5589287d06250fadcb9a6c5fd2fa31cc5e5040f8525d73eb6b2c02114c6844b9733b1146bffd49534f5a5a38185e22321e7a0a73715c49ee5b0e355a4b175b9671811de709cc5c45af065565a38273b96a254e36ce4b358eb2af12fd39503f4b342e2d1ebb7d0be49d9057a277724800bcca081f3b366bd7134a47d00645723011506a642e4c3149a9e13012f8cf51ce592b24cead3e223f8d082785883d2ea6659964e774b365540a1a4f6cacc439b2966c21b02b73309089d20f63c93f770eeefa126dec204cfa7d244c2476d968cc98c8757bde800beff3ed45bb96c02cb0b39f2f8de8837185191873f7e80f1ad6884e37a118a040a6088d5a5e72aa5aa0328640201609532edd90304413ac4318e6254048c3876eb647fd69621b7e15b41c1e2f31eb435778bccb1c3faa1d268de033681dbc8b5b099c8b117fb7773c48203349eeb22f267502755856c97f0df4c54f03c51ab621c0bfa61b978c170fa13dec06b961102ed28e2953c5b2d40316b5ea429be5540e3fa8da18ee5b854c3017cc49403c8104997abe4dc3643d535d004714ea7339115d18235ff497546715e3693a9acb093e82bd7f2b5104bf4efca4740b81e6cc485fdae938f240cb5a16602863b88ed72096fff9035bbb97079472b63e07bc552c3a09415c43a4d5027725b123137a9939c98d851913ec8060b563e70d088259527a79701f71905409f61f70131389e73b26d9306455c1e833248f95422f05df5ad60baf22d2a852678011e674b7e1cd44d5d16636f1e83118b7ae0e7506fdf42a3d962d46581ed3750e307d11a401156138b32d480853e1148b74061cc22770203e5adc18e9049c4339772451c56c8925ef24a05221e5481019d52c239d01a2444fdbcf390e923276bf0bdb6be6a00a5cfa16986c6612f14404d36e0a10feca5d4e1d0875c3b8ef32f380ea0b5ca6d9166a37754c08dabf307836492dc46091055fc0556105c798719ccbd669220078568fdffb6954e05951c347051e9af0824bdda4623ce99ec9246964a25dbdcd2e080f53d669ba103d5de2786327c990df4b43ec251b027d0b0c4ec8ad4ad4998244a860a101083d2921f92dcc5ff7142043cdadc93010a76f202715cb6181f6c366d46adf53ea7ebb7348791d51db2182101dac6764a5bec24b01c5cf6e8adb2e2f9e1dea1fdcadfb1ad846de29cc3ab01e02fe7d1e336aa127a5316622ea91371ddf18fe397b567a08db12714cea68dc66c7a0c3340c5c97095f9e2960dd57b84b1b62b009edb1216fb5b5e064034185745303c94f0739722392189175601d514741b82857c2a2375fdd6444639c5a9a6d0c89972653f4d96f9ba20423adae4d69e3c3962ff85f616cf527096f4032b0067d785904b170d611ee701d209198e11f77b07b43cc613a30ac57606fe2cdee0761e8ff1179f80917a0d1db68c0929c745ef2ec5e6f49a45703686272bf9a7f0cc4e71f4f2e71bd37b64aca435267cc2748c3e33cfae846437deaac2bc754ee2c84a18942bbd60e6d852f9a554f215929d5eba336ead6b532e79abc0ff115b97473a3f66985b425545b844801fc5e340ea387007107d4000473c10e71c80e1556717e8748d9af02203771245efdc5a74bc67f5130e8bec54e84eeb5674253e23e77bb30360b9ea1066f4f7558e1f17760796e0b6b315efb542c3d4361ac0c466516d8f23eb3be0b692947936d161bb96a034e252a151dea110356e8269bb2da72f5d7b71568e44f15d30fed72feb80336b30c9053e754b46a27b80f62f5c3a404b733117205f82d7532aa8f67cb2c4051fdf8d2144e42772bc7476a3c56333d379597c54ed3cdf036ee804f5255804d4b04c60d2637ed9d5ed7edc8166eee8a2f62d2f770fe2d0136dd06a454d37a32209ff3ac308c7c2b27ba4afa2d5a21f60dc5cc601a9ba58053f06ec322a9828269505e3e514a35421786a5e616e2fac70a410aee0048c1276e42faab5dfe4d6057c0e73038afe7a577b5fe5b255af59a3b8cc3ef2d8b861e544ddfd33788ab690b0a71fd0819d9bc3d1541a40117b5ae7255eb67239f5ef261978dda06e0e5320568fe554755c7144678acc52cbfdabd4fba62ac47f83dca1bb12f8669a436d734d7e65245b8476a348914cc31bc407f338067de77a3b8931a70e351345aec791abb81f25b2a7f8e76fea17d4f3d743c08cf457704b315941522a28b4a16c9a207107c1c0e9e92be1a35eb8052c93f8e17f72c49517ac4ba3614b0124903533a5f731998525972521693ed7761458ef328d4ebcf500648c826df3a215321aa39211415d4448c7ca04568b0ab35f160662c7bc6cf7285685b5371171c470c513852e442b03d97559a372b9c8f47f62fbd5753ad2976b40391522f338610959c801ed7321c0d16fa8b2df887fd76e4896d6dab97800fba15010b109d7f3d2ab7cf5e3cd1f24dffafd519f638b45eb8bd3e6d290e895d1a36fb506e94ff0fd106c750813140252c68516307fd3642a0232104a80dcc6abccbc3124b1d680440dce832f012bb0d7491a24d86c82c290ab64d5581f4d2513f57db3f978da543b8a4b97138f1e90c0f5cbf70e9a005356816e535a1d5aa58f49f5a38c0788b1d1709a65427f77b6c28da9741ab2f5406f6fcea1bd8939220b2d2e709d64ae175bf8767710341322454acc80a8e03764f41037e57eab292709d9ae458d76f131a7cb4610fc6ac232120f2d266f8d59234c315592ae646c845154d99329844954efb0e4d379d2a8d56884d060f4f762b4a964bed4ac060b71bc2bddb53715d07157e85ed674b275f40761bda765c623234b7a1e17638740435feaac0393ae6c35fbba5de03e890bd2b1bab96338467e95b2bfb903060c2180834d7b94c3083fd3b3ff5125345845416c1d0ac24f274001854dfc8149fd8930f0fd8a6473e0b5f04123b2e1d41d9105cd9dfcb18135adc18fac273155e581c4312dd993a1961b813de8a5021e7c6e11be1dd2b40a350b849dcf90547ad5c73596757ed44e8ab4b5b935b5a5768ebf54833ced32186253718948b840caf41842eb2db592c1cd81703449ac40fd7c3412c27a75c20e31b1533b917231f2816990e7a3fec7721665922b865866ef1c9f94e5df0ba259545b755d2239f2b9ec95d3e8ed4640852441065253b846f73af5f212262c019ec153666b513b81199bead6c7ffc615499101d4f9652283fccda070bc1f3d57055cb775a56c66f61e0efd37637aecc459b337962b9f8240643105719dff0065f188b7473a2b9154677a2b014e5df43704f939b75ca52a27238b15f2da63aa154a9e6396a1c3f3325bd704f20d6f57a68a8a261321e1a3248ff3e5376b779ce5f7d2896302d3d976cca596755d37aff589cf2866cc8b8c976214eb647c51ca62a6944e724b3677119f5e9c615d10627092f656a14921d8560f37d953fefdc1f19f79ca7077e66cc62cc5cd30e1f325c68f390e34148415035ad6f6113aa29ec4a4b676848504b72527ff41a6f0c4311567ad3932b99351361f28d68536bfcfc0742205833ad96bf165088084ebd459c58bb4b9900b0b5c35ec810c333c040d40e4f50ef1c75219b5e69be814be2b982333d82a030e5e3593b3bcc1259655be34ed8dd16761558b44985187b666228da24cbb88915b2e64b0df02dbb05edb9832930e367579c525365d9a8714120a5335baee1376af78fb466b2161c58ad18d43c070b3f3cbaca1f58091b66772228526110ae826a9569a375522abf699ab08c2c8aea9d20d72d9c0e3b6ddd38f0d42834f247ab130fe9016537e6664158b9754c441183731993462188d39020bfb4e60509fa16582d4ebf23ff3f19288deb7a28b264783d68fb7d0ca1c013740dbdc63444fe4f21cc56970973c9543869f4c422dcb9853a6ddb0830acd37f0a9d9a7e5997a5a17760e03709babaa775894be34346bd9c075c46e743cb91c73e2fea57522b58343ba79e78371359a60ad67c7626a1bc1822d89f19687aa2ef3d652c391ba04a674c02b5ca1111447563c58a6c76dca2fd59b5786470e8e7132bc9710646a90a4903bc4dd73cc409fc462739d206fe39610578550441b7ce342bc8c56542e59df50ffd055c6d41784700c1b8c7772beb8f16d90d316e1018a0549843970e7744342e453fb03925963a276e63f5178554c65885df7b7713a24f0068ee7c0bbe7fa90b5bf0bf030788920433bdf803c92b0048dad7d373777f620f0f646c58eab94d18276be33c36398776ff64a70b2648016687f29c24ceda023b7d71d95ea589da004c3d3c249614e572b3f7fd439f310206a9c8172f9d3e7d05d1e2760abfa8204008dfb91b2ed2111639118c3f7c970f2dcc39d46ac43ae828d946fe07e53951084d2e155cb97b1c689bc0230ed0430a1bacc24c70903afe65745c07148c8cfe47ffa74b43f391310173a1c3062325b457cd74316534c58d047c51052f4a85236553dec4560873cf1999446847ef42fe5eb5a5514bbaf102597fa00e30c1bcbe150f666c1d2649a33521a01665c14ab03c24f87b5c624e3364f95c9530a7771e2270c2fb72a46e3a16aa45cc5122a66723c41c4356c020395c9985e2111e4124484b7b82311de6ea53a4563e3c57b73a533e4e2155d6eb5c3bf4f7a52be6e15b720b8e923c829dd646a5e8570267621422a4e2ad3a18d5be53b4acb36fef2b004a0493bc5ac2072e6e840ebd68829da75aecdb6a333d8f1a1fbada544d045c500fb9e76c138e6a6524daa4034de567a518c9e94f163cfb813192a28d3578a4a56ba15e674552687c1c36f891297171ae3c0684df0849559353abb0e13882053f6127088330c6bcb738ff6c4f550286d508ee057245217aad4d4de91b5b8a878f6071424949bf2da23aa401ae1d76f4a86bf142ce652b221b188273ca35f94ba06722bb0d5ceae4f62a88aa0e1106b1864112e86b73d41f7634a80bd840386be351f8b17658c5d60b038416c25cd778ca5cca2a75065b6a1d3e9722326bddc19a0816746e103b95790473a4cf4050ee650cc8fe2948f1b5980f73340e2212124452615c025ea7f5b823b4e65f3bb2397f37dad2f8571c8bf04674909f487d46f00b10126e4ce6dc9e2785f20a395b473d00a993f8514ed6e04a1c418e3c71ac5f5903332931dc8301247ae6bc753437e50da1e2c421386468694612615dd6f2934791093e1b0a91f24ddebebc5d6213061f1a93620e29d68f60fc921e7452eb7104f4fd745cc6237e129c700c052520c46877f17c22cb59e92cb7e84e2573e2231cc4cf866684069e41f882e74d7c751a6cca428d355c6a222e4377a00a780f3b6e74b7743c31c6634ae91e9f6c72aa157032207141d7840e467c4e840f993bdb57c395b1771c968f0b1bd1087664704d597c750c50bec64d56ceb65d38b1dce73d3459ad5361614151b897fd527bff9f3b3a06772b92b0275fe64dc13fcdaff6593ca6263fb2a58d009eaeb15bd274a126dc0f2d6b606ac0121437dc5634fa4472cb92ec10ea438a664139f108025c8f1e39f3d33795e92b724b1c3265850d9f06b694a0565039e12419d5a5511518736fa9bf40212d15e00ac0c1626af09e2172cf8c9420d69c745fdfa7000517e95035364de135d9371d6e64f31a58a99a7d59318dcc61edfb792a5bfbaa6ba49592295c4aec33fe0fe173edcb8a117c61cc059eeeca6e2bb627095d22de473ecfd202286ebf70f0da510c1731f068487abf48c3cc7b0ecff36835a126255a5360a56290ef9a09b64de8232f937e07f2534d0ebefc70043c0d834677d0c45a89565e6cf17d664e9dcaf5227e03f66c89d99c6cf01d6621dc24890146baf50a88c83c49ba614b2299dd9103ec4d5f6b3791e608141235055925be132d07ee03fefa44476bf1961b94703768b1e6386ca978a14112f19313967a8118a7ee1b2ee639033ebfcfa070f9b28020d248f441ffaab94624ed2f390ac20f34ee6e7d3a46322c2fd0d6d404d47d82501dc58e2de17b4c52318c0f45201f912ea0bbdb1fc3fc47673978f253ab2e844f9519410e171e7c55257845383bc4d21d8d04b65c4380ec1db9603a376bf3f5665fccb374411cf0465fda1b24076cd54f0fac1b1812559815b88aff6e2b4d485bbe30e22d08882170aefd5f0f557d923ca3933063f587590ec17fd82258272c261620d734b1986c4594fdd3038246576515341e32a677c0079fdbb14b57296008e417277128971f477109f150abcdba1096a0023b87d37404a5074b5f62e5a76973230939b6f1a20cea04dc3c0029bf6305f0a40757ca6f523fa2f91e1924af38751a7c68c7feeb2e9f899268aa3dae080022c35ff57114102c4cc80c33f3e06ba09ba9421b167b154e5fae25973a142700682c637c101055d0da005ba5fc2a7333a7c05767b731629132fa0db2f95163ad409064e8578c3b65f0643870f4b7500f7a94027ae1585215334046938bd36f747ccb05e8a67c71262ddf3d39849935aee58b46ebd7c7207cee836693061f6fb83d8c2b3b3b9630f8c40c5187ed5e3001590b06cc341536c3c36122c045c43a57aa8618c4260048a287e7476ca52339aaf6523f2ebf7e37a826d471141e2a6d3c1e6973bfe76b0dc8ca802e00206071d12ae64312d3ab4152959b438a31436d18b2c006fd766c577762fb03f2fd00323c161864c2e4cc01941c8666482e1c363bfad57438cadc05621bb72a6c45e93248cfb5740087ad1c9c3ad45f6552f6736106703189caad2bfd40032a2387e7704d5c0a12bea06565d31d3834cc2ddb3812bee92b4fd797235814ce3966da4d3b7cd7eb3f0d6b91410f3b7d21f830232e9c932f47b1f4dc2bdd51ca5a3109c567fd7326110a076c6661f77b3f0a5ebc522267e929301bca2d8389a90f10c8e655c1e1c176d1c80155a163b85ce74500214645ec7566351221867624563d26e66be1d0306f395e3662df5a5e36fccd0e3ac7ff88596635da6325ca6a0b8d82e64fed821d1090a8c50d96be5d2fddf46c47fa3eb408cff8151e04d9ba101650034127b6bd72a1931a02cd05e329cafec131d470fa4182397f62714efb094cabf1575fbaea24fc43530630582f17187e5472eef6092244329a45141f39078387993ac7efb457cbddc32c1b1458524c1d627385909708f5ca62093ec1157750bab92147d5b11bc7e51e523e761803e8da8f027483866b12348d3a8ce5f9239e6d5d61e9a02633a4bfd86a5696cb1e9505b25ebadf7e50a37e9755bd884d02e6474c67e1516a508ec7ac5d153ce671651c8c0320124231e47f50257433ea61e715d91f2ebfe95b58a7741484edb2546ca4dd38ccfb98024cd09b07edc2c7246d2baa5d10cf934158fe5f15ccffc927506b234242030a151c8ccd686c2b942ae7d2a34970fd0375df6d5236ef98dd03a0c68545c932cc5555126851bdcd0c549ec31e060398350679f6752125991b57e2ca356a25a5d01234f9064564915b692d920976793ca3582c9774669414964ac777491c2c0b4908e7c9f842be4953546873320d5d19ba36c3a7cc5f51da432d6d2b004b07cb835de6f72a0678938e2c69481048978b610cb50e586f1e20f95250e4120f5e83ed35908f586e6c3d5263d5268963cbcdcf406549f108c3e55b3fa917b7124e69d50d84344f62e98f1b1898e7bc2778b778006266395403e0c73514c42477b1ff4a211fcc3a219d57b73424cc956fc410f31ac65d10094596832336fae356393f1b7265fdb36d13f1dd0d3c13f402aa8f8477110241472981736a1762251274f4346a56cd42079711b56763aebd26ab254a367ba9e621e9c19c595bfaed7462514d279d7035711d7fea73ceccc460b9dce32e9dfec826b597f0200fd23e393ecc3341e549906607398a5e98cc5d5c71ee9a5f7c88ad333e5fc15b4a65592647347933119d1843ff0eb14968ab884cb01dea5fbb34603147d2111480937a3f1f891f23ad3631129d27355c9102a2472a25dd18f312e54045c3ec3fb1a10a6d6a118b61c60cf64944e270649b26483d52e71321449dc1144f405221c181776945f1f352359f4a09267dda2285687b65b13f6558c70f3f5cb913ce2d15abc4336558c8379084c701527d424dd7713b25b737d315f6df6a0594124b38575b792c2bd9e42baf071722096b146fe38fca2de212253b5a39813ab6f90b4e95a8801e69ab873397c70c5b8da5a44db7dbc00c84dd6d72cf665c76c246a172c2ec4c66c31a481856338c22a3b6aa6ed7071d242e8f4876b693dc3459d268117d0e5b6904593f714e07aa5914ca61255d88d9261eb5164d1bc8a46880b2c074b0cd3a384ac8b7581add4467f8d80a142f922e42dbe7f476222a224e0b6b3a2843ca6842b0f2eb6f631a8d68fb2d704b2960c763b59d7b3410e910371a9f8262ec1aad603ee5a86f5aaf4153bc4e2502527ccd22c0ff5e0272bae74698f06f6c9f909749eef34c413d6a652f76812a7089913b1f9d6d7430541ad1408416d9453a2f3913aabd3e1e78c2655f58dd4b3cf813b06ff30a0b04e59ed227a5a873147bf0262f2bcc4d3129d42649d2d3b752ea6ce1537934e83f226bd41780e9bc1838f74e3bef08dc61c892e81d54d717778d8d94553228665727684f460e1ba6120f2852372ed8e0468c7b444cb98b1c5708643c4409e228675390d4186ff2d736ad8888281194f048cef4714068d83333e63d7d3665db810673db64454be42a5abaa4ff1971420157dff2d40c7f8b495681688d5c421a7356ee98a02e02cdb42a36e886269c544e48699e2971f6c1776b6fd4c30205bacd3ccf20c446d1a0203efbae1e640ae6d342bb429a53ada1861d00bddd53b397403ce532bf4ea10076555399ff6356c0de106e923f40e8e239169bc04574baf74306c1a2872bd8f43040f95a48066a25f277390c1c37ffa3ef3fdbe2d21c4a1cdd550880793f46143315f7ae8c6580c4932ab30b5d67332d5e6c5606a30818d75d5d18074620aea54200240d4b0aebb3743003eaf76757d5d1690de22f1429b83624795a57603f619044f703da0dd31eee172f1f7d4fc883fe60b2f9750772b07a1555e85e4665edc21ceac4f005ec9ee227d6c5a26bf71dbe729adee55bca065c24257a0f366a79f645a85a93481c927407cacbfd5b6569a550914f8a2302eb321e38c8711ed0d3262c1dd4d2211763151a58765977b3aeb324186c8402e802882ab3d4cf4b70323b227e17036ac59a24406ee2713bdbfab463161b1d5dac9df6640a0061157a51f50f3fa50348ed2bdf338099e85511c0460bb9a6e10f4a2ce36e7d0f071186f1c10d7efa1335c74597225c1092609859f6553f8e291f8a27600ffe6d295e2ff6e626145d7a1100198e32b848f76544fbbe4dcdf1af5a13b8d452ee4867539d6a26452f9a5d37af887b5c72e79b616501fb1904746f0386caa45ea0c3b50790a8ca019fc0201dc3fc053ded33b165d6554a37a011726aaf8e83716b947d297c1bb511bf684e1fee96c2186ab6ff4bb119a91eb1604834fcf2361d5d19b63b5fa77c3f85b3de62a400c54a5f65e9228f643721c7c3e35620c2394fc78f205274d52e189e97a404d542e43c918dba297757d33ec48084691e294625b8595c778ebcdc75cb40482ca4303d7715a5e43daa278668be96fb7282d0de745b6b245f8249bf5b150c3338d86cf63c8c56010d773fa7573071863578b99e61fd1e2d13a407c10c9fc2c61cb682101099990a6014cba160f694c13a22c76f2a3806342f89b17905e1c5416eec9f595b20d6344b768c183c18eb790ae3ffc512dc2d08355383795629582a3ccf2d6b3d9184954d0c85043a7001ae1cdb0c747666805b50ad5d467168c27a33df29532337bf4e7608e7201d76be172c9b7f65619c20f85fb4c9171b8818840c8209dd737b8d98581a040112592935395d77570c35377104f26f2058c0b68f47172433133168777717be2c60b00312043178755a257813576fd05b4963760c533e02c460074ac117de1ecf52039d5250b7d4684b47945e59e7499a3262be9a3d24b2e535846ce22b4f3dad2473c3162e75f6a45c3ebbe850861db71ccc70c4372f72081e070a512e76e5cc5eb45af24f7b4b0967990abd3ab2157c5c698e5d1039469853ec1c5f01ee940a6ba733460e72ed375350047f6b6debca5a71389e718d36d43513ca8e33d8ae264c75c48d0749545645008532443d1022179e915b5053a7273254999167f407384d7e5b254fff452b1e29083d04816d2d72ecb5543f8102430dcb982e3f352979198936a00cadb3276a32ac2234b15ca0176bfaf63b05b21546ff54746d1c5c255f4094496d16db5b3d496dc337664fba67ac410c295149d0402bd0703ba2c9f93aa1977c63e2c7e50fe54705250e574a65eb4ffe6e291e7c2b55a76a1380114268a4a463619c0ec8094b0acd01cd139466c094594a66318e7402ebde0709a3b5227dc5d83907ecf84db2d87c0c25b41862a1521d4843db0426775e632cb5dccc5637d3d24abaf23c57e47b7565d6190e6253366051832afe0ca590ff06aa23b52ba9f3e2675692dd26ebd1a70b0100d728cde4a968225d7938e8d44040c916277606f5b86971f8d02d5bb632261e730727aacbfa7739861c4917e7cc12b43ff54bd0289f3b5e20b4457b9f6044e2e95d3bf163e56bdfaa7a06e79380239d7c56744958c25202b12d0b574e9e1c8f03b35378cd6b1a3413761f2285845fa0d9e2647bcb490fb63a1970636de561db255b2b62878c02cf3c1f618027635e9e3e9835bb7ef971e7fb0e28976eb7266b21dd082c1bde50706e862ec3b5942bc144be70b138a44e4995ff629fe32d1406ffa85ce3feb55557ba5e0309b1c91d31e20a594a9791645b5f0368ab1b79265e288a401bc7d025667d555933e2fb1e0cfee96e14f99c5bd5f97e404b71a245a3ffc848cb66395219b7035187d22028924ff507a6bf845514032c75fecaa31d20560a1f884601255ea79d0ae9efea6b066b4c0e5546821c84ebc645628f16577470c7739d13b123f90105451ea0a25ae88ea075127a01328745c7058be5d55159106a4b5b1aef367b40a534fbe453481ed74d22297886400458974dd505762d5fcd956da642044defcb6655cf436b1ff740dd0cee543731a42e4e318576d81e862479418eb0075e32287c535bb82218230bc755764e7565d62d1c0216372c5e83aa9b1db3a28f25f63abc77455ba901ec620c0d03ab3c729429f23a95a4a834b077f32139203b0c0dfc7b5e85cd6031b0fbbb355e0e902487e7864553d36471d0ddf01d9de0641bfa724f2584748256b414c44e2053c436364ad924a78ef14950331c40fae37f245e2193539b1d5e17a19cf214422a1a23eac4ba31164558246a0dc428c26adb55fd5b40080724e24fda43e95530fe5152c3530c433c67a1599c9f2b480a914b1205609e5de5b87c42e0902a4c9edc3a38c5303a31fd531063bb0d9512e3f5c129f3d5f22217a1893ffe9dfa571d1c65184d655c3ba149c02a51a9cf47b5228b18a025ba674afc8a560f2cab407445061195735262cf2332421fa230464e066925f7152518605e3849593cc34b94ab6667b9bdaa0de744102ae727ef16803f9b3d3f79fd18d052bb24d480176fe1cecc529878e00238e6ca2a8561693a8518ca45fa4e92041f69e644af291b406fd34c07b6f46a733701382cd1bc0169919f2e515e4e6164a54b8935b622303a75ad962cd2fb6828ea72621e11009d6ae2259b3140e0f82bfa2bac1df8f605053982844851d6a46139557b61bed1da5c481fe628450f6f11ed968f1c990eb05b26d93c2cd1c20336eac90d5adaab3d5c642a7d7201ed436570b184014441621bcdbe5037664c8b584b52c654ae1fef0f718a0367fcc9df6335547f369de4840eef994c358aeafc64d5985964feb0503186907c0c82bd6227a7882c44a6fde13791694a7113934c631149223c9f13c359f266f55dfdd3d509a086c931a04e3816eb5ee03628d81a32b2f6d94079f9ea0d52909401e9c42a773ba00514d6791c68cd8fe73833dc7619f5d72b6a6e736c26d0b9201c7c5cb361403ab352c2112826fa713f2d2b9ef31e9077470284026939b7dacc1b15fa5f4cc108f60958d3294991a2501b8929ca1de10ba13b5bc630149e1bc761f458a06943362d35f1f96349b3fe1574aaf4bc4c8dbb6d505d94b1084ca9292199e4123547d31c5e6b33af5eb2adee0e58ffb72f1936ed5db9544636cdeddb458742cb0df308a561e2378309ad2c9872d5bdb821d7e7212706203242a614d32d893e8053cf5d5813daabed697278bf49f7197065b2d4504d7b7d4018a686da0fe82b584673352c64f5de0a1bfbaf063e07a3241d5d508f0697a4a3018ac6be24e905fb0f1cec2f20c71936154319204c352e6841c0f4e6149b779831450e8a2a3d1a3a67f97a4b398cba4d3a43bf72573793bd2575ab2948b8feb267602b81010099b131ece663328b8df9099dcb1427087c95331e406c1917fd6059fa16e57309afe2162d2908604210876c746474419abe9d44a36b745082bbdd23fba6d56d5c8739420346fb588e86c2526c253f5d57d1124ca11548076f624d66b024201179998f4e31a7e63475e50f4ca814d64c78e32001862ea8431e4f0a5fcc49d063c2de690ee5a418045a43962fd3e32f20d9c5b77042cad257a761f47590675c4960c1a53607c03f2c1dd05846754b05197a3e5c48d95067344df8d262e659010a6323ca063097ab4496f76c75f0710e1f671a161d845df84932ee2227f9c04526ebeebf52822a1b74395982143333ef7536cd2069ef893c27e6e76b5ef355b62b2620fb23c4722d6a7812095ce9ffc5090b432e3611e7a7693070ba0b19616535bdae9053adfe97453ed16f1d70a73863a768ad0d4dab4970cedf0b40351c6f21dc8cc466f416151b0a54f02987f9c814903db72fe50d5051fd8c9266ac21026169a3a94f9fbd4845b89d3e0f4307314641f55b254d7e754315b14d4395838e4ef49bef3eebac001b2275625b8404ec02ce8fa16d9df86a29aba38f3fa7ebef38502aaa67ba4006766859986af23ba82d6acd2202b9d55706b0670429f4afaa47de43f96252f2966695c15a3d60f8071f89c09d62d950d16d342a8d3ededeec288ac69e62fc635570c0044a706a21403ef5e07d4755b63e4f8a5c080f89ef4b29102544255329d94af17ed903bddba46bfe27302f4ec7ad5e259c8417c1e93421a660321e7edf3b1033d26b75a1a1ff04096f2f5729ed59014f45f0528d43ff2ef8eef33765cc064a821291082593301071e5d8658052660525a0045c37e6851001047f5d1de29b5d2d72c44ec97aea5bf64c96038427a22553067e41168e5343198904323ee9e04ef39e9c47f58d971ef6626b1d6699910a42d4bd3709b5185c2698f728b3d4b309f4e43870e2149d3c1c54bf65f6be8a55843738044cceb21a66d6c62e0cd6ab1936faad2e7fbb0c75e117571935d89a3a4e25a31badd3f1417342531c928ef622fd2fa95153caa043204f5f50a404484a347361447a1aa549b924bf4fb28d91363877d46bfd89b3141fd3576ab63eec1dd5a3ac4844511d477294a41555b4e616a237df33da64203bac2234358e59a649c3214331357a1a54b56f1c51b717070fb1ace535062580332ddb436ab8231802c05e714e35613b4391ee5d397e96621992ac453fc9ce7e48e32fad2a366de43de6637b5e15c16131a0467c5d5523fd3c27377173bfec3f204b662e33b8066b2bd0473a499be8cd401d711b311eed6b0a71cbd91d4b5a7110c6a74e2fcf7de9759f08112bd18116517cadd922c7ce5672b8f2ff0417f43612d4f4544c83f9430ad2f4041c3a81e61385feab5be7fb305b89c2662d1d5ddc00f274b320f88b3a387f05d24b6ac3a0601e1ba440e478d2108fe60557157031079d3ac76ac3f1ad46ddf8e304978b9132682e594ec0ff1231e1bbed04e9e0fd369565b625406e7e114df0f53525ccd96924da4c6d91f1d310ab50ce0f42deb706eb43e16e665dcc25db8053392033eb0492e90b28bbfa830bf78baf67fd637a162b9d67658f73e73ded9a8e15c50a024d2f349b5768fb7b29642e695b95c4d567944119435eab022da4b20564b6581b2d21c18842612cab567b712f1d6866525ab0708d2399476e6a57e2160276fc0330ef807d4fdeca77309a45e61419885c0a54118830e90b457576a1e05e970403228b2aed355f4b110853882d44306d8b6a3ad5b44b6d60f60c19ae802f32263a2dce53eb1dcc41d0453041e56713985452c682126129df115d84f52548ccdfc92d168a6d4504047f3365561a668aad7858579c7a6379f971131bc7fe0934c0ec554be9f020745e34015b202b451c9310367129ef65599c774631b2ec4e1ee09e63fcb19a6b02b5c40f5d4f6a0df644fa4e2939583bfc946c1b6661866201245d2861d22874dd857301c8d34d71ebc89964d576702ba0cc6941513971435c7f531cea18ff76e1581356ee46b045a5bf860ffb66891c286db0541f128f3c7a7f271c57eeb922231b2f3aab515846e4402f0f5daf463b84f0696d1c37356dc3af0a6a6e462062aad4c36d7cb7436c2efaa036108e7d2486878735954b334144becb5dc15b47224a05e72046c44674a0a947183f705b557b2fe345cae00f05b7843525b134fa4d5acd4832c513f944d3c13c4ea8ae432852e41b6adaff0473de38ce4004cad612429e00631e93d30d2e293b763407bb3941057253228859560c93834737f83860102e62272c6cd70580fe3a09d7a955688bc4f1201973b936ac50de3e4a8c6237fe858957de669037103ff14c1f21282187359f3bb201064d9f2f076238c9d3188a7e9c11711fbb59e73da902ee96250ec700045ddcac8077452e2c74f2579547e68c844bd918274de6aed1306db4bf0f197d9c3083dc6a3aa3d86c1d91afce694816df2001a1563d158c475f752b601b07f56c4b22a47b6dbd9ca62a04dfd939339f0b3734056f2761f2010ee494bf4f03691e3402a4d7072b7de42db72b6765e7f44a6841389151ae65e1624298817550fbba65e4a95d0f49214b16c5c9ac4c89bf7516ec86ff2f8cc8d432d5997e40f32afd48bb1c7d517ddb45319570c91472a35c09cc5eb449f90d4b314c5071325584cd4245078767429d74191466246759b51f661838006b6d19031cc4fb5e594a847334f13c8b27e5ac94681cbed71f9cfaa235da862b583deee74101db761219a2550edb799e57cdb6b2606dc95613085df765259d5c603f85a308cb69256bd1a07014b31de613e7c6334e2e18aa38fc41e810b519d35634eec3645dc58e3fff4bcd658d49d261b02d654576d82865e193b75ce5774674a372dd3a45d793564c0dfb5dfedc603228e33832f4152037cd740f3655dbb84f87577a6bede666624703613ab3e4c414f33a620bedcbb852c4fabb191c4d645d3e14775bdcae236d8e2746256e11eb28e94c2009f2757b752f38f0040573bb1c411fc316117e811ddd620e6c5ffd3c0a2d05533eba3db42383ca5329c10618174707331deea6033d1a2503258dc2b46edc810810918c2326d8220852c640413b90b0c32d4fde4a148109af5f5e2d753d89804b04b25a3f0883fc4e28b14da82817f787353b70524568b9170a20f38127dadc7e62220ea40ea7212f73ab365338f2a0cf089b9e943b0120b23c17dc636e709c523ead0dc630a76a3102d5e16b28b6261a65628cfd620ee3811988db773089f0b554f009d2605536e821f89aab3d866411555613cd50a9f6570a975d031d56a43b004f24a270452c82587406d708ca06832f6f963f0dee2c7130360dd7306ff13d1591799c199b1c41031c56271b27f23d537a45a20b8d4427250fd0ac470afd785b8df328114a962b26e489291fd1882a37e3d8ff3131777037a96ce643072ccd26642330216257d37719b57048ee34b46ffb373625901e625625c22c56b2531b2ff8090063432e0c6f25fb97331a8f8753795a276136ac9e67a7e23f2d971e662b3266844eeddc9d5e136367753ac87c64da5880287ba1360ad004f06031f63e30a84b3f56505e1d5bc5d5ae04668a262a8523d80d4df3556f270f6b1f1024bd6d758fa43cce8714195db4ac39477f5119f952a2201ef96008e502ef4410c9cc5d768ef50481568f2aef5d4c453fa1de56834b3503b78bfe1d084a1b24020cc56660673c1d3679e33e625e881e13007b08fd93236836019c2c9401a31bc82fa52ffc66b90435459f58ec3c380a436785578c6e4157a56c135d0fe6002410577a754d91c652cb306f74e597e9555449c8026416844b57d6260f23af7b5db45683292335da76a044190369ec044194eb0534ab8b242d18862540c9f55a4cf89adc5423714b38db77102539d7097354cc350f6cc1d7520523a62e1dcd425e44391f4ca081b538b9904e079a35a66d0072b30bd5b52d47c393881f752a3c7064e3df1225d7f66a865f59680842be755fbee07615983324777fe301ae0ab42e53bb8f4a64e0ad2402df4165cbf9004bd4ae57562f3a423a55de476ae1fb2541b31eda591fa646529525134a12fabd1e0bad537752e217412053e40a36d6561e5fb608495cfae73d2004454cdbd09339f69b3c55cf3aca49e6f2016f60c23e3b1f2fb72d54049b470a080e63bcde6c45d5d1ca13263a8a6774b4553fa754701dab43666853eff858e044ef65cfca8f1f38ef9d162dd99a28ff32d61129b289249a70a40abeec652f4b0a4454eb7bef4660765e16053b650ddaebdd0499cd046653759738e7d49c2ee3b63a5fe7af3f59589d364d803e2f53b48fd52484f8f312e7e1a060f547a83a2ea05f0d7875a902507eb70ecf5f3227ddbb2b5bde39fd56825c000ee69e72657c32810d0b8b70319f2ef636a1596920daf38171ca323a1930c8d109db5a2d441ad7ef6f1b115d51084ab809ed0953203675b84f1861f219eb414f36b53e44246ef2962491b2be27996d4562179246367b17701d06c3d849ecb194055325be4d85dcee04c0c468381017810e892ca92894de2b21dcac4e35e8ce010744ff371613048e2e01b38814837fab12a938ab1862aad070badab1325d9e063c3817691cfc86af2767cbc32c043dac69657f525f60890e76d5187a7251d8de3f2301b3508d29ec58b207bd04d6aae75e9267a051c535332c3e0b7967caf79740cc512142dffd3f03fbe9e928aaa4ef03207c122ef92e4a165a0c0301dda385745c9551390a004520335da622db089c2affe4f951b6945d0fd28b36202f63430958d8f16de0e5921e77a6d747bc66972bc5dd977199c7c715486a60435344d558c82f841d48f2cd73b9e2381d251cb556576817465d9d7713b701c844e8871924d298b02be47e5a26a22524653e580963758f3a618b2657315f1cbc1625f9f36d86189d1c14d6076a041075371996bf3c2739db6973e8a328c663273026e69a5de93efe29239a2d31a488da3d58fcc34002599e1b2dfc4d2f5d4346750b317f5b9da7991a61ffe709716ea0661105606cfa00b948d52d2418029e485d7ec12e558e3e3443f7815e4222601a00b318b94d11b47415d2afcc3b378ea123c5771b1b0514bb6a275f954f92ada0572de761624903ca64463890151ad84d5a635f305607ab814e29992e467dca077547fc0e369049ac5fe5247d0470aa142ba07f371d04f6c8154f00f820eac63e02a8a5d81d63931c1e9701c32d18816608322fdd5c363c67077b67d43008b836254afc315b318f770cecb2657412c93d588ddfcf1d9630203bb5ba445ea1bfa62fb7b8ad6ffa1ddf66aadba252a325643b6c249e3143814c4e2de14b23c5f0ab0e012fc14e2559495e79a5dd461db3445ba6301401f5b38613d7139e5bdb35984747ff631540d59a412dab6535c984d22a0fdbd53a75ce9b2fdcc26833294b4069bf082a6cb834646223c2386990c10428737478085f1a413f6f9e465dfc803f44a325044802a5f80612922a2ad1e928091e52691b5ee1a1303be23e0b8b7fab60d4b6e67298cdf03fb34618021aacef017ebf3622d915e54659113f5d67997f6d0718840a529f236676eb30374d959205d4510e3afdd57702a7b38c230df6274af06c47571580a45f95594e2f51399223c00ac157a3c3ff4f1f1f65716698fe1a955c9b0f70348722dd1ec727f321b8071bfd0936725cf30a8809e460209ef3351be0006ed06b7666990f7103c1dd9011c614433f1cfb7e245aa7b256660b3e18d7575432db03eb00d738555d8b77c74d13ff660c1e8e014b1873c258ea3d8421c26072530570cd3ed9e4dd39edb999347b501c214eecd329f775de0fb81e9b0dea89eb210c4a3e43ee3c182181eb62287922bf44046fe85044c41045ab2c4a2c1539c669f9ab4f6bf2e3856a4fb05c0686a35473a766243a949f5110820fae082d78a665d8268a3be29ff3259f355101d2c99c4746271830ef71bb328d87cc0b58bb306a3559b830e2759c4c9a8bbe366085ce6f8397150a84c5a2261619cc1a52def2076d87d05a3ddd9b704e96ad09568eb36faca84c74f843a476907eef0290617a4732daf3599fdc4c6791d9ff02b2c6da49fe27cb4e94db272e77e2de6f0850666ce06a8c62550403005bc3001d646b831a9865632102fc2e3c738ebc6df8c87e27c285400ddde5715e6f6dcf46392ed213cd3209641dc37544dc1eb311d82bec305ec61b624483882e1628cf777d093570c5d5db2efdbc1c25854b474c964c5e726ff3f14ffee9606349f80f3ab482dc5a5d5a8d527c09086591f907138d933e653b9edc6531cbf055d773e01d03a8ff1841661526f0968d3d117f8f769b26881067ae0930ed2c1c13c61fd36cdf6c8329b8b03665fdec6066407c345c6a5e2c1a6c87ab77e0db672d1a16045be1fa250c8d187c19ce439418a6032a5b342d094332ce8b0e0ed8cc6c62a25508a3f7ad0b46efc4602ad2360e5cd0b45fc06eda5d6f1f846f33dfe236fccec215fdb1f303b1ee97661ef0cc483a07c30822d26c706b154b527825d66ec4ae0c15a0f88d7793a6e3410d45fd3cb7b8b7537df4f1356846525043aa835de2bebc4ff0264c1acc397a6ec8248c016677a776be4c2a59943fda066e934c191d05b623e90b33692c4d3e3117ee9c6b87d7bb0088de6f7128ed8828b17dff1d0ebbbb4761f67e5910d5835472d0955a4fbf0f50369c6843f11e2032d5b24b183149481946244f6faa5e275710928560baed875e466ccb455ef28a0a9be4b74c8cc9f1199939d97659a5e22e3209f95d9d790137a39d1a301ebead4127b7051c5fa1756b3ea9ee0f845398508fcd6502990a67563371b31d635258185f0b251ec22c4c2e1605a129c2d96854541e6328d1049d017c89f7453629172a78e6606211c3fe4c18ce39220519592b9a28883ff21d725e6e4c6e62e639b73883b4b158a245c068bedc59246ddb8a51931cd1731e317928a429861ba45b0247cc76cf4e32d5216867c52e0549236413f17f7f266ed6570e9bfa64528abb441f655dc23d6f2314528376b354b73d014747eb61485d6518008875a65a85f48910f11ccc15c4d67950d74d0746a5d21d573d1d5307c1e67a14f817bc56807d52749ad08d2f70ffa01d11cc996c766fc2023ee88c20c63c1c5882565c4ec1d15003cb72cf01c124de321bd86218c1308937684d64559f0a301314bd6951d79c515bf525424d65eea37544805700680c6927fd383844a8aa2e18cbf1a73716c5e91902c9be1ff4639427c2179e416d78125cf91c030e2fe4e73da7254b6f59284137eb7eca1810dfa3381bc2703cb7c5d5289c686a27ec541a5773cd511218503d736e5d673e4274e12e61916969c3453105e022af67fa99a21052947115edca9307856c973b60778c148188551895c0625368db13680c8a3319b379af4493d62347658164640c793b67644d412b4199c100d7039c013cbb995360f63c265e6c041dddaead637bd5e15c33767433a5e89304b6dd974a63492502b6663a598017a047b025104bccc4462d44c87e6c54b2633b8f573656f2a7ed3c25bcab496c2c0042b03c730a8a46c96648e4935911f59d6aa717ea22e4aefe0ccf3e3b1138fdb426c6b4f50c2533004329f98c5d5d2d6b5a35cb2b317cf5b73362f61b1f1ed197192c2beb4cc1cef04e2653ff65bbc32803aae7cd0e480ec610dba8d738e4676659d99a355fe9bd4a502c4bd334e2b38143fdfba04bd9b9e4757918a7121c2ea32fd3acb12f93337d1a87c60849ad6fb970f8ff45456988725a8f01234fac941f5cf681534c2bd978186d46927379c4564a02fa543f540f901fdf7bee6a2bc5f969ee899e6931658d5815a70c61314d6a47afd1875d2832533ed65e2e6798348054a2c32624bad4fa43ebb86217abe3e94d1440f53f0b3f4e23a875db71ad28a400eeb7c158a8fceb4baa62c5347b8f4a2456b5086cf6947f3588e78222ebf50d2c2df59c36ef82a70d2942e11b7fbc4b3068b3226476c3046b32749172545d1a5df3c832228646935d77b25c198b0ea5593dda1f43a694f0159a27d91c42c3270b04533127460fac3ca7367c57a7489758f16782508a6d920b6cbc8b307f93d043f7f026369aaf9b5f753b7b5c5b3e2659fe59df6aa0392d141c668b51f73ef311d45b4f369d6098452e86e37204164b0b43fcf62cdf849663cd94695e690d4e22891c2b6eaf63ef3e4b22fc6ef7a504711fcfe912b7617e5506d24756013e1b254b589c697d746519d756cc2ebbb711438c0f80258316f5119540540096a30e68403a1730d7439b4ffac8525204f0675e7cd07e0075d84b5ccfd7e21e13780000aa53a4589e17756136cb46369fb4423d8542531265b35233a4d4090e53456e5fcbfa2223c51fde3482112a5e7cd13a33e9ce703a1c414b2dc6c6dc34384e2114620a0026c06f9d61981dd9191602560e5cf979465f25e43aabae2d358c18982299d86743862b6a6313bf1406449fd73f16e4d270b9430b3e65ce0a374b0c1815f260440b593e944836f7cd6390e222520d0b1b435ab43a02941ff01986e03e229a0bba3ba99ec35cf0e9dc09b4107b325562d954df68f05dbf494c0f6e50e069698e4b1e1713d941594587680d9ed11fbf1e46389b777b06b386b52ccdbb4a08de34290cd6e81b418d361d4dab208e143ae6d9354a8afb6d49a2b273d3908c4f61233a027b19f1263bd49d1e7188f10187a16813b959505cbde37d6a86b94721b25a745ea2c027080776fe75895114293e15493e1ef21846e766b006a859986b7894cf2867ee55355a876e21f742ca5aa628f05babe1c80f48c9921fc4dbe02d6bd74c0194f2b74ed3f20861cbc05e3ad1e45f2438e4223014f2ba28a31b05515508c21eac348006dc78ee26051ee52ce3042716a69a1a546f5f5143d789d36c0f3dcf195c92e2717be1056cb216f53378df274046cc6f144eb20f6fc7271c17098cb84fb0728f5db3e1d635813ecb0a4013c37734d4c430782896469a5bba5658b2d61e50402d2f9ff963668141614cf1a467172c128b46213ed500dc38dc2980fde6510fd69d47356ac474fdd47e6c9ce3b57155f5114c6092ba0fb8b50519e5a9ef39f75d6d541bcd6c1deb544f420f12a73b4e66a36e9b09184afc61e2367001814f46c24e250104d6674800f61e29e989730ca0766e722c454e2500f3629734b41765e4991899f4b2465f53e82149602a7373b4eb2989b675051d1b713e0ec01a032bfe18151c03837248d18562589c5608c97d6c6b8cd867645db0bd4ebf848a1b23058d48012a690317ee3733dbc9833d00dc4849dcaf0b5b8cadf870c9470c64288411526186e01673eba447ca55df61e1a44e01a759ff3a2da03003ba4e2c383afcf61c8f76af52a69905729fc1b42536c9cc6c79463442be518323566db024cffa4b1a38444118c1bdc95610767d4f8bf9ca68a4a32e2d7d6686190a2ea769e148ae50362d665c5619811ef4fd534c1f7bea04b172a5638288ce6a0c411308b28ea9226d98563feb08ae3baacef251c61a1b28c47b1322421f12267dca7f012a293947574785446b6c0967af5e282e8a705a4585bdaa0eea39cb40d4a81f24ff2d5e223e2f9a6984145111c057cc34f5c70211c962161257e46724939df045e913b5777b5c4601dc817c072115484d31dd474fd48e2d421dbbe64cc153b9547a17c64bc0e9e973d3c920177fc14c49f2cbe55c33fae40c7b2f6376d99a171e954e6068f92c9b03cf2e7711383c2250e444511c8d011860d64d2530e95ad4202c9d1643953c491f75b0e827b024c85581b50d00eb11ef3937060f5573e461595ece342f0979e33ea31ba639a2ea9021bbb2ca48e40d72666178fb7587af226094bc7d77765f4c5ea85c91666e43961b40d30319d7fe4d2661dc6630290989150f357013fb3d9e3b66083955f1e2440f4d88912edeab770c24face16e505ce4beac8d72b73da58151546573a02868d0747268e7229b6953e6e5d710e6ce7a76ef5045774ca4bd951847f435985a8b95c688a214234ba8c63a375602a269fc73a513d8c645f7d8768b249a01fbda2042353baa503aa55f6182b8a8733d03b4f10b9b816186bd8a0545d15824a9d28c25cd496a24e0ebff22f0aefe0024d068d772a7cd86fdbbf7622c388061b40dd9d06edd46d23d515c840f0a0f84f1a784f3a77e93d46c5c75d1e799b104a4521cb4629b8a02feb63ff01f0560b16a3468b156bcd061c6738596b28bf5064a977fb052217135d386b183f62589910539e897427e25d365971a10ae8c16e157fc3741899132170c2cc9e18f6bc8e1c1f1f646e89606c051327f62a79da5d6a1b30eb3d82f6dd51ea18214fc48bd56dd1249817fd58cf4dea4705219cff9b489f202b642715e65e3cad5d1923feb504800924411d464019f696f673d84d1135cdb1754bac8b754d94b3e5627d60a8684b45853fb1f04212165ee82625ec0e35bf33bb132f80ec4d40543e2205e24e4f70c9fb5d57bab05eca3fac11db06006cbc5c193b0d45f516172e9026bd64073564ca900668ad0b2bb06a53036fb2030c0186af13151baa06f415533ab970dd2c4e905b0c78473671d632843782f94164e58de711812e5b092f403a06ee984769d787a00f9a568d534cc1175abf4c4014e2b2914598c6391d1d23d85ed4651a3e493cf26aecaa445f73e90c6858a2900e4d63af5215bb8e0c5672e5684b9a24677eadd86dc6644570cc7047235039595afaed3d15b0039f65146fde59673d60439940be70c57cbe47f4443633cbb0be0d3cebf05b39d8660f5fb3b31facd78e164119a21ae11d2e4f226a5d449571ba2888994b21466c1b709a3eef5e4b0cca0f54fcef1e561eee1828eb450a1351f65d006cae6d3bb2b72d6cbe52501a22fd11cfe86c3cd8d40344f0613f50114fc72f44b07d2a733eef4635cf81099f23be0fe1b1171f04f69f65c78ca848a423e00dd89ec9625f3b763fc5d04b386973d007118d7d1cb906957572a8ea727a1d692f2530b613940c5d1d84392a5dd71c6f6e7eddc47123131959f29ff16aa2d8c911a89f103c690a7545c8aa450a377004449b8b011eed3f674ee215b72824a21d29688fcc6b1be5b24da4c8da2f129fac73b49208457ce84d450dfc5d5ca44f5075fab31f2201acd477fda0c656276cfd29ab46a8040a572b4867fedf26dff3ce5404d36201ff7a3259ccbee9592e04174c599f7c12bf5e8e5c2447cd1b21b4314b3ba149505d4b900c02ef1f6832a4671c1c22343f008c330478f0242a21e1bf0c02a5031def1d321e68ae402b584c7e4ad61d8b5f1ffcb82604c9784c65b5dd3f1422b754d7e396691439565fd7ae2b5e8adec960c2d7cf27260b0047994a857388d6834b30a041379c6d0854ba1b2613be8fe86fa4102b3d97f15326fb21863e78f04b2732002d5bfe27cb4e94db272e77e2de6f0850666ce06a8c62550403005bc3001d646b831a9865632102fc2e3c738ebc6df8c87e27c285400ddde5715e6f6dcf46392ed213abc503607ce72a10661c2c34ac85b06f729e1d4c6cd6eb59c0efe72864ae2b5df232db1fe99e9b1130a79d1a09d67b5792145c38e25016651a9e470b4e598d3b6d8b1568982c0b0cac00e232c83a8f71aef7c245f103194fe9715817e1d15c6e6f4ba1039e8078359d5a571b8a1a642147477d07016d8f779f788a3d01037d68eee2cd6a40bfa321704bde2ca0eba84bcb778758ae10560d6e817d4f1dbc645408239104298d1c754e2dee3c5b78051fc3786c68b6ed081f4df9aa36ac775d0154be2942bca6661783a938451b27332746e2451f5b3fc075caf60c409a90831710f4a95a278422571e68a60b5f0cba6cb42c3a5cc8fc244b817faa613f6f013660db3042f1917733dcd8f04672ce014a694d8173ad3dc90fc0e71d4e2afa8254c9454a3ae744d228f78fc14717f1f838f891113c4039b34c1a1bf9463b5a535dbccf290181b8d21447ec99145eeaee1c9a5455028f51be5af3fa654f09759b57da555b27f6820c027427cc4b6727207142ba88270f05156e92775073c713ed3cb066f63eab300f113cb669700a4fae1200ced3209944d1631d7dc71ccf0048159c75b77140ab6d411ac98c53a081ef62ca6f5f1136ae3f32fdb9c0628622c25f8e0ba33598f51e75efcaed22a3bb622cf1d8276f4e2af429ab560624be7f4d625b9af906a86d271a29cf9f69d3f19e5dcce21459139a6f183b272137bc8ce70597ffa23ed1316b548abc2822c2c35418c1818749afd41e001e06fd60f08d430cecd76e3603b58320bd236b2c2ee6175e83ed1c1901f1081ce8f4003afbcc19701c5ac5583b26971dc089af6de928671ef4a24f3aa4013f48b3d87835d1023042b8aec13f3b0e155ba0923767be0f3f1ff75da010087e472a52706554a3ab7c69883df6509569554445ec946665084b085a78a805971c656678af0c70cb86fa267c8aa03436d71b002e4df076cae3ef59fe0c25208364b008dbdce76754282c3199dbf00f8d2e12377bf6b763f60182216d0a0140f081df60f7835168350f1265a2f0052cfb03ff4d9138b65a89e55a2e05c2d3630033c56812fa46298c42e01e887b8e1ed7f76e10e6b0632af3094e50b0df08198805ce725fba2629bbc46c477f10ce028cd27b38fca1dc1664276555c5949c383cec8a4747ae322111da4a221a6a00610219e5720ba6ea34f3ffe612805a46735213ce0b44ee3342a5642d2dab6d631b4de05c235416931582fa8e7579ec8d367664d64def3bef67ad14c7144b641338ad029f57449b610f1ca76c1090cb5c3f208f2c1103acdf0e7f64284fba3ea468c0ed8b5d0fec1e4660d90e29998c59511eadf03d622a833f621b7329af54bf1583633e0ae37c301ada232d27295a3627514f026a90d06846bd69d24dc50a591086f1a8223b855046a904b1124acafc012e6a6552ff62345d5fde3f09366a321422614610ea82606b910d8027db851b6325d6a26b59ea6320ebbaf43b11c3f56dd28a1040715ab8233909222c18ff414765acfd27657d051210b84a54c598a82a6be88c16f1a8722bed739a5e3fd3a91f68764a0b834f31647c9f8646776f7f3f6e8bc6419005282a27e6cd50ab51d2358397f7617ceeb46037941f09b4a6ac320844a20b1e6d3963650b13072bd6025db8706123216b9d0a1cff9d70e5a8d4595e6b35590c60e0692df64071e6877a75e5a8d65f90815c0f64c1494e34b11f34625f416c098ceb3651fe1a0d6664be4b5f8a9246154bc237b0bcb26fb05fd216c7b0ac5a92c55f5784e0ff6272c37d4799716f1769b9a81ea27ca41d86bf910aa2971c098835fc12aba361760890180f3e4d1471d40ea04496e1715fdc0c97507f6f9a1a95a1a403045ea20b9d3e4a3e5d00e82c2f30152779be272809495750c370b570208ee2408366c914ce96225e7c01fc65f8f7622028dd7518eb56110d00e9bb3257567719fa845368b3e757452ff8a8218b9210380eaf4364831c612948b83f4bcb1127536b1b4b6ece2a741efbf847182bec0667bc9f6135c1d28158e16fd81cdcec2a45690ed20fa69f3b0c4eac6013c1cfa96ff9ab0647aa7a7c1adb7dd3327d944b4eddccbc53ebf17207adf4b9754c9a2a5b8bf9d50edf65240a1c7ee610c6468e160a1107742014c80877674f7600be2206b5846a6352d2de150072761257cfd647da5e8d63eb5dde29e011d228eacc671d54afe1529915ba04d0cc4a60504abf35f9187809c0f285498b4d692e5770733b08ea8e2e6b3a504689c3bf1f0cef174e1aca2a2d833d4d046cf0b36e39a1950a4925ec0c52d6dc45ca04184fa0c7f8231a80070a8f26bc74bbf9191c6530f57186bffc2ab1c8d7299e80215bbbf8185085a932609c263c757f3c0f48fa95ef2620b33b5ae34fdd6071110d51602978219a8e6270e7c5dc2843f510764db3d101060a2530799d66307b35ac630d1a2f1a0b9b756d43ae2f2c3989b81ff836115677261767bd868924464ac23e2d797a128fdc0e550b464f09289e1829df5964382bd2f81356499910981dcd645e1221667b70c56331854e524e2aa31d78118a030805563b7713506473121702c92d2f4542572e445dd27e3a944f20739d7e3921b5d4e645bf5ce959db557e702f4c3a230f562715684f5314e747c134418b1f6b2442cf2e565ef251040f4a51ba26bf0ab9848f36a7d8532a1a2d64056368ac1737d7e758784cb65ddffc0a19f4824a71114c176427f0445810c72e6de8c9db27d8dcda4315c3d63d907eb106f64a33045d96f43eb7b0b9144124dc49b874e23fc8a5a1314e83650eab8c745f5863cd08ea511b24d3ae9543a813ba2f481d81657fc3c02f3a98b86f465a2a46c05dd44b015c104134aa406935c84e16871f9377f312a802e43c523a43b9c26b361546195f9d4319023cd17782d9af23ba199b59e42a4808330e227473ec30752fdd152cfb838735e2aa8a3e2d53d15ce4451575747afa480995670e9272a35219c15f72042b8e2c57f49104cf6cc03255e9ac750eb11a38bfaaa719f1ab0c3407f7b661017be0596be13b30dc0a1b130ba49e03d9166364248bb20289388229ff5b345a49e5002268d9374acf011d567b163b18ba9df95b2b41e56dfdf3fd2a7d83413c07b89647be46ef46777a6b677be24c66bfa6540fa49ce800e14a282f2211ec5dde8d0b21b34ea03a5f5965052735006bd5961010b21a2b6253c23f513981c125868e7d091a8b6550d968d14bf9d4682f10460306ede8210eae839a33c55be400597016354da90a18fd733d3fb503070db7903f0039c0134737183911a0621e49db2d7d377672a40c0571b045dbe1a85608736b184d90260499d3ca3dad47ff1290c4b41903fa4f0ccd35f538f61c654d65c07e73e6ab0637c4f93e2ac1d5000ce368254f6e4ef07400a5785545a12674e789f762f285dc6de90d466804523a51cc5d2404c47d9806c78d8754b074891ee4715f1fab5820558b685173a5c22741829a733e1a682c6f1cb8687146909d522e417f5020b451052ad2b61e48be582af1d6c717da1bae14f3671355b340336d8e3cf76ef4ebf46fa6902b2d7a5263326d8682345add423d1be6da3d845e6d48a93f1461247883739ae149136d046d6d64f11008ccb8bf35674e81094f5a2d0edc58bf0cba2b724e54b01d314b7d6b0e1ca74b7735fd1d582c1f3b3bc022e811c1fb4b2bb7899e6402e09d48eb7d0546edca25617ee8f65f009d4c75e932244a420bf96b85e300176eae5e2fb8994a6b7a16b5020ead8002324dfa1a21482248db70de4ab4da75259e9b934396e2702208eb9c735195e95b8ae2584171c27e2ba0fd2229c01d041fb347c21165fc33423a76af07c2bbf11ee713e41ff8954f5471073266e1a1070345dc6010b0ee2d4c4c60010bfb38561bb375710f940e6b30fdda3a475627cc6da267863857e893391c39dd2b4f7a352ff40f4f60ad142320e586e139e2709d52e58ce3645f60c8370426006742fc990c51735263725483036e8d470a7732c726f7998e2dba689749be9ca44dd67bcc40f0f10449fbbf6f1a1b0f992170dc6c5770e9c476380e8444b2f228255d674c5d16ede21e4df3e4774e35240f2ddaae4f55a9f6396b1ee4084672821d9265ee082b83ec6a6d1eb95a29c3e41955f824573c63a42e40b71d42c28cd4574117af3195a1d34c20c7d45060bb310c0ba89c40e7719b3c4deece05bf26a56eab9e2964e4de3c086f324b32d5ab23694e4d94203ade1f55c961293ac141b36c02161830164a1573aab0eb1f373b2218b6938c5c2da26743fe1fa724c7a81d4316939d5170ae6d56192c7d69f6252117ee7b0928dc8a075802c8113e4bd0f4438cbe262c2969c01ed0c1610741ae1174e4681e26730cf846bf50ad3b4000954302cda02ba1037b581d282f08980e630453a8784f761d3d0d7853c024f949b2496f0e554c044b1a4e93fefb2fa1b8084bdb950a51ccb1006e341ba1172c4b885fe0d82b247e52aa5f9817ed11ec41135ccb8a44393dd250624acd390b8be5b67645387c38cd31714710775c3afa85181c9a57d10dae4fe83cf7363a42973fce6cec979b3f627e724cb191e003df81e2435a95e952ab38933b6f5e223d5fe773115b5ab52b66bb0b5fea733105e51ccb2126c68e089a3a8f610ba77653c2488160aa9ddc3aab52ac1b1462eb01d2a77807f3e15b4b53363e6dcf700f6dafc4771843198b1766216934baee1c2bb2b4e30ea5d7e5454fa90c3e4cd307500446810446265c5f047cbf3052af8c76a890936ca8489d0525c036451ae23d1c4c22e063262e7a5db3617d56ee4784207848631563d17559b7632c6b2014573118d49e1d4436dc4025229a5e9ff5683533993c41c251bf15214f8929dca2636c0e663c33d6b29c5fe2bf1e49061738413cd8dc31490b6170674bb10f7317ea2a3fb36e288037d41f056f4521aecaf64e8d3e3628f0a27348a73f0340d752a876a8f7d040fbe3804f713d6a5dbe03116fa1bef55f29343b67b741e50ca27f332a5dc1fd321a8fc015373e8938b76f3f0165eacd6fd04d1f4026637c6dde13e84382de6a4fafb60d2b86ab966bbec6da102af5826924a81931ecf855703a4cf710f051fa273e7ebe0b427b0f0644619d411847d33badf5a85e0889bc5a53d96901a7d12a64d47508421576305b3a18363f82a77a0d4d18e5295d53ae3d4ab6dc25aa9a4b6a6eff606b9a8aa532ec98f149ad0dba2f4ecee56fa09fee66eda4053d3b81763161da4f414c4bb937fb6b3e23edbe5314ed095346f448dd626ded513488d36002a2b271285f432561a5ad1a18d23462381fb2b96145a3ac0f8e0cc83e6c87152f8d330040f5ac76528f5bfe096e38cc51ec412042c027790451f3bf00739c2746ff553419bba0ea1b10485b6515169422d2accf44af07067697558b5be466362acd3ca264cd21520040e3cf386890715744efa140b23fb727593bb02e995d584b509a542c0ee1d1505385ce58d410f8344a40e11312d0d16fbd0ea9266a894c0fbb9bb20885e2aa0f1ca9af50bce20c2bdf2a076a0e52c11448edfb07bb2d8e04e190b60ced12f621fa6bd347972c236433aafc0cc6612f1f555a101ab7523573aeaf0b5e16d95461d2375a26a83d864026865e25631060472311e83f80ee84112e7c9365e68a2c3783ebe23a37d9d1555b714d353e8e9e056a9136270cafed5a36a1882eed9f4f730bf3da3a71d07d18877f120df9d5a75dff4c6e2ed722d6396d20b1473d31e3393d727e1722303855f265283c718e0f05ac360513f2c81c641dd63a115fef9b036276190ead5e20471fca19275ff48962e04f6d0a503b4b10244bec47781f0c248cf05e144545232cc62e3e3e76cce438a12ad417a3e0c314bcaa52081379d537c05da01b4dc41d0237bc0231ab482902322b2b4f88117851b21ffe6b2bc7383cb1fa6b17bd862e066adb176c13054d769d64975222dedb27752d713809c64d02ec1ff3156ff2d736ad8888281194f048cef4714068d83333e63d7d3665db810673db64454be42a5abaa4ff1971420157dff2d40c7f8b495681688d5c421a7356ee98a02e02cdb42a36e886269c544e48699e2971f6c1776b6fd4c30205bacd3ccf20c446bb1947293968fe3d34db5e337c9a085e7543a805ff2368697d68c567d2770e3cc493be6b3d3b044bc7164511ca84075356554168890d660dcf04671056b2c247e8214628b0d8812c43d692214c9e0f75fc2147179e4b866b5828c4261e36122e94c69a3716f2562be466e2153469372c3cab9636652cd0054c0c270d95b68d0cf9f3ed61c4d4451e2ff077036ee18d08f0f2563fc6c6fc6b5daa2317670bd06cd1047e41b7c81613ce18561286883f54f1335727199036491885363756847521bf32cd636ef5862286c35c4eff16c72055393b04b1c33c30eec23c2cb528765c0c1a3414afe2725afcc6dd15aee80773ca9ab06a22ce441f2a5da07387f86d21b02c7b60a43ad045f0b09d6f66a5171c8f3b51186a527f14580cb17294c55d1698f589335338ad42dede66395b26f76561c4b23da1da042eb0a57015cf4b950d1e65965dec574f4f25af776bf8ce7b3865a3486e89e2d862691d5624bfdc366e8950333e96b6ec33a773fa6f4893f4117c7f92655f193a77a7319d516c1ee8242ff6e626145d7a1100198e32b848f76544fbbe4dcdf1af5a13b8d452ee4867539d6a26452f9a5d37af887b5c72e79b616501fb1904746f0386caa45ea0c3b50790a8ca019fc0201dc3fc053ded33b165d6554a37a011726aaf8e83716b947d290c1fbe5b3e386c6c93bba3286ff184093f4f2003ee45e068037f4b5fa2b9d538b31b072ba945d1179207e3088b55bb6afb713c5be632350112950d0f0e5c3032bcfe1a29bafdc54e9d5db5750f2d8e3c6d76ca77358af7300182a91ee9b81b6192be4d4abf8d9f662ad16217612aca23c7c0b9413819d65abcc3352eb884d147f61e036d7f007022c4fc676165ebfe6fd36c5231a5183c597f3733480fe21f427cf2866bfadd4530254558636cec9f7530c05e2a995a802cf153fc5cc2820a41107ae2756d9ad9579467de5841f5046b45219139432e5a281fe27000eb165f06acaa1e09bb2eb7057f94341520201b2d2e83352c6e4c537557ee3d49839a3e085354c47759ff642386040535641de720f5d20b08dced5f2cca22485b5f4b081334796d6f248b56633282f1597117d46f6809623c8c49244d960a4b1d687555768e3b1c6dfb4d2f54f81a736b761b1617df86923227125c2ee899d25997d09b5a9968603afd49056fe083c336b7573e6cc742666fdab7d93bc6369d40394e54383e01212feefb624dd37164770cca104f287fd53bb16c7833cbbfa633211c5a20ef1a3d383053f413d54ddc2ac798296fe3ef5077e5874f6aad7963082af14d22d1770d5bdd050c570e509f405f66c11f00f388185ccc48674d7aa01c44ddaf3445fbe84a546be34d50bea577a4042b4007c6ad36d0cc91176e09021b06814d5c070fba218f70e850bc4bf049722ef10123c90f6bc9c9172cedb64d13a8df70398a5c220b2f86a72d46e7c4124ee43052813ee71e3e144151f5901f5157981b1d5318e951e4bb6c409dcc9b6862f58853750bac4d98294c0614070c07e13c801d512512113086e84b0139303692eecd70456b6526e2e4de508251af4b211afe6e1eb238189f7e4b110fb9674e0bc48a38e25822663288771450621d47ae34dd20c778c1571da4e5581e8e7256aecffa6e7c6adb4865fe8429f1437674cde3013d3d6bd05f9afc3e164f426a4319e8324bcc749f56016fa66aa052e375d57c7424e6d1d934031fc14f402462295041d876f737273fcb6ea83006ea23012e2af95b88b7db3dd73e5a436ef33119cd8096123b3c6777154f116a89dca466d9ff5a2314987b09881a6e3bbd63484a5745133d49130a1c3c379144b6719067846ed45033430f5b51468e662753883d9257c10d1380226367bd3237b02ca2334d5fcc2b04be3e5697b87d32b498b70e78fc243bc26a0a73879e0170e365ef45de50b323ce249c43330910562c321a41870dd30ac3315f369a5afd3263d2e340eb246d3b9a1cda1d353d30090ca04e59f21ea756931d9c63fe12d0742d446b07cb60075c53ab9220fc5e291deaf5cb09b2fa343bff4808196aafb32c43fb1853e084c807e60133344696d6696f3214506b90d9411ba96d103f593d771de22d03af41bd020b0b9d5b7abdbe00361dc40d9e11456b100fb6522d079024064a35410b9a4062360b2e24c737611ec04aad7023463375bf8950768ca2d416ff64170c03fca23a20e50d03161d286e4c1a1a06910a3729314f5c4594c5c901b646382f79461376cfcbf770bcdfed6ef79e8a68945c745cb0eb3b39a2e6b227f4081417a01004715f1a1a579f86b424853a5e5e59f38c3eea514c6c3e9996634a6d92002cc13747d645a5549e072355720cc270c23cae4165f8ef697f9c1c2b980f696116372c5e83aa9b1db3a28f25f63abc77455ba901ec620c0d03ab3c729429f23a95a4a834b077f32139203b0c0dfc7b5e85cd6031b0fbbb355e0e902487e7864553d36471d0ddf01d9de0641bfa724f2584748256b414c44e2053c436364ad924bba208252683443f7caf9d3dcd97cd0c8924cf0a61f4fb6606ea58682bcc4506d5da2f7371d6c344e726802396d6a2027afd923b23888a48fb3647536c2bf76071d0024b9a3be11be1ebec59ffcd1467120e016115f8f6083c1e6533d6788240bc72b65f161ec576a974c529adf7e77321039b067d99a34009b8cc52a819fd116a12fe3406a894770431426a88abb7004500d6761ea8f81a083a0969fb9afa51d41b3c0994454d365650f06de065e112b0ad1508575ee2393843172b56e3873412220c1935b4c5237f044748375b0d50a227454e6ff4870d287331329afa6e3791ed062b6a426867474a1f4b9a62f43adbf24f3ef4b0a34eea4aea66abefd63d62d7c14757821438f73e7219efdb030d02e33e25becf632c545bf2189af8f224a23b7a43c925e872bbf7a968863be731a48fb8699efd9326f43eae1d2067f7366d7ddc081476c636e34ba831cd890d63c5073d76f1a8ec3307adc74fcb7e8d50ccb3631e1eba607354ba371e22324a3577351f1b8cdaa0093acd6b0dc03a4f304b52c654ae1fef0f718a0367fcc9df6335547f369de4840eef994c358aeafc64d5985964feb0503186907c0c82bd6227a7882c44a6fde13791694a7113934c631149223c9f13c359f266f55dfdd3d509a086c931a04e3816eb5ee03628d81a32da082d6b2de9bd52ecdbee60caf0480f72df39723ee8264af5a1cd2be03c0e64d7476811120ddf71872bfa193f310941d8159163bce68e0919e8d757d5a1c23e316f70185b6e3f083f45c51414a234207709b233efa500425901472c7bee5f51fbe07341225d7b316244a261b3e65001da759220f5b44c73da470c73a19659080a6d131b7dd27037bcf16c3db5e3e10e4bc6fe31521a306fbbe06d3d95d24f1647898f4beae4be5edb4ee44a791ea92f47e2e03eb0978b715474ac289bb26c11e98eb7634df7e166f53cd275827daa171d95510cbade781e440c273a8ae1401109689b73b940a008a56fda5486cc1d0b380cd108e3453e749878664f6a999b2381bca64365d80b59f8b39c1270331d0d85959e593dd0515d12995e28a22d993a8e6aa2667d76e22276e1c13b5fbf155c4e646307b9c0553b7060ec582704eb57f06d965592a8fe6828cec20bc82d7924546ffc5a4d7cbf3d34236c0c1b9e7f60449c1d29f18ef4290a2d30576517490a919db6727863d032f0b0e3086ac239150af3116b5e05bd04cd6178327e95f66508fd331d3b26961ed0a69b2780e04e26b328692509b4b92bf0ff2e40efdd120fc7353a1fffab511780b7233abf83a35a2cd5705ecd8d342d4efb730fdab014475a88ae2a9dd09025a17f8e21cbded32a2ce9712ca4bf23578aecc645caa4491089b8e91b9bbe9d70efed2b70bae0885b534e241bac218e124d1669084507cd57549205026dc387177c6c4a4600062d4cd4a4a029223e106bbe0f2c122c05d95d39a0f110e14fbf77c87e5b1fc5f79932f288f60577b3bd1db3ba0d1f298d37343550c71b6c1abd748778806d93ea402e28ed2a1257c77b72273f9a75db79e230ccdcb65c6c246b1a196d415b091f6d173364672ff2ef2d37e6f6e96a9454416e0f94dd6263be8f270372433971becc60ff695e1adc45535218bb8031fe58be5f46364b51819809177f936529da807e6a572b1b3ecd51a62ec3873f329201801a189a7874d1544b35826b6b31c8c4004a4084ef7102b69636a7edd26e2901fc5c45466359d42e7556c64b99761eb0cf260397470c687b3e2164bb4227b82e45726e83ec0005f3ac20503dfc072bdcc75c17b5cc5d56306d494b57293b8401eb76045e073059558c5815dc552e678a3d47bc88341e8d03ad3bfa62ad16bae4ab4e1b8c3e73cef5250ec3f85043c7d1c21b0e3ade3abd519148d0e4fc552e0798042fac121ffb648d6354a2684fdef98a0d7f9b6e2043932858654ab13bfd17cb3ae455702b8254eb1575b0a21661be203ad0423a6e554d995ddf1c6462e8070b779eca7f08af6e9c6819744d17be53952fe84db572bc86461722675f3ca883f72104622c6addb146236fa06e3c7245d02cc4928854a35d6d42f0c84c77270f7d56d8565104770fd7379b14b007efcc356d65e6234f822fb9696b058909e8730456c863fd24c019821c1d872a37559c36582b161008c9b27911e4ebd1728dcbeb22c3517f096b85f63a740e73374fbc0b2587764f1d1e4ee358aab0692e0841254b19ecf42b4845921083462924581b142764f9436266f5691cab22b848799cef62e2f7a53121581947fe32aa23cdc9b055b1ce670425d887232a375c244636ae5798d0146597f7462101b86333fb91b47597924549bbc2dd42a0c2fd6d2d491564c030d12f39a7ef60b460934cdbde554d4335a04bcad279152800e74488fd732a95c0b9203760835201d1e0624a15d257f7f8183f741e5a371caab65a396a3b5c8cdf9577c169fb573e19c7017ca35124440bd71bdd151171f534372590cb823b3f20c016cfd61c36007a1a5f14528121a624f84d8beb486b2b64e73f64e8592cbeef6e3a657ca9664c1fcc03e65ae43215963232d87e5f727998c06d8eab4a4826fbfe115cf8211a3df1dc3cd8e55061a0985f44328a1b14dad0735d1ffe5c5b2dc89f4bc8ac1e1528ca6f465ea2bf690a6d4d23e5bb0063b2c7dc4545bbf66ae2b6fb04c37c1f0c974c1568cc99da34c26ae76e220e0848f3a8ec508d6aa44fd39c7330cb8eb149ea83e3603228875d05d1e02508386a7425e521045dc88c6862d9ef3948194d0f9c0b8722e8354e5182a24f32d6890c4d3a2443386c887439f450393caa904e5a4513933c10342f444e5e7c69e3532d173cd9bc331ea8f87452c94a593cec6608951e490ec9954f53f4b57b742f02b65669a2d96525742c76a619505b17f48e317ad47859124783233328ea4286c70c0e164f6a18a8390f4eda04002058be8c6b50b35b2014fa790d50ca2d25d2b89e2f659acb159e2f931531dd12125837ff06ce50c56514c19242924efd3cd6020502e78f0836baec610cac740f413ddcf83328df9d2276cb123a6647f41f9e105c020c98ad4aac2c861402e8e46d07f7402449c6d8151c03e22d5aca7b5fb4aa881e4a604b687a46522b85dbe646d5362f0f238d083b999bfa695899773c22f45511852d8816193f9a23c9620923f24e036338aac22730972122bb421d052cbc30186095ae77df20b376295470672f1d0925407db3422491ea411c89b726b8a67d3ffe930e438093c0053e5ebd6dcc3f071d01929b6ebc5f87606997e50c2fe7623b7463025b8ab3b41e3913fe2d78a17125f5ca5f276f604960abcc8120e41a5462b6c85a032f85e6458ef72144e3755c3a2dfb9b1630b50c140c651d720fc81b748defd5069dd97c59988d7e526c982d1852a0931d5b6e453b594a70682e2cff09eceab935ce6bfa21d5067328c952315b8f4ea5750fb53206fe35141e3d502508acf8342dd54d3157c55d312597a86d2c1800f534c0614772964bf815459a5b2a7b66693dd40cc772eb28f649938ccf4b3699d5019483a937f4dabf65d2660a5548e72138d24f746d483dc7295184aa66f6593d6612c3cc5fb08d82508066e6723d22f91415b47d09ad01b41d17f4bc1c8749915f8db0ec587d485841d00515201a629f03241a6d168f5d9f4f760fb66e63616506fb57955c0e6ac94bbc6bd15fcda7b54717faf24f28fe0f1ce4572a2774bda63b6b93c867536c0c58a8c6a857c9df1867e754ae25b379b6674deb92345194f65cd0105b265a1e7106b4c1fc4bc355a765c0cf3d75ca18593e3490420df783c66ba8153d243027501a3d6aec090cb3c51d6b79d130351e00366b9eba4593cba6636498aa2a09ae5708a647bd33ab3d3e61b506a50aad534c249549a93e15bbe563f2000c4773e872231b1194405c3754700825b81fde470f126e71df54dae78d37c9f6e4537206be60964a7a48ed978631eda3221a00b8c92eb11fff35f1cbf474f67ec92106493706ca58f974abe6d964d71edf2025b7c32902d93717c7e874048d11546a71da0d1f3c853b40972963304ee73538561c65039f109338726db747bed33c6b3e9f650018acbf09821267367d95643051f68c4f95ac9140b6aee931745995698f65815cbeda3d1381092a629302c2706a90c83cbfe6d775b065b017b3f29818ffdee0470f81c9237463a873bb2a0f752977e76f10cb184052dc71082c4615152fe6e64627ef613fbc7fc3038ab7c40b064884120b4a33291532aa70b2da825cead1193b9b1cb433df8b661f8d57b963480d0920c774e73d3221ff12deace768a25df62486090e2cdd65bc0e90f7924ad63d9f456887f722323a7e43d9e1f8172dbf766f258c8f2089b47d519198e513879bb05123cf433131e5ce6781fe5548fbfc394d4b8ef862ceb53f728be43f775991511e1033902b8110b61fccd3d85f4ef6ba39f1033f6c3e053a00f2b5f2468255cb1b04d9a935c974436c63057e490c10e84c64b2d36e109bcd0a1fd994779cf5105d59c18529ee15483e126f86311f2fce3dafcc770eca9dd26e5f5e75609b2830008d8b520ed192f86866d5356480de825e370f1d2a5a88c314a1587d464b6b5c35defd8c4d1d386c6ac98bea6f316c902536e2c14f0b94202f8cf3845ff15af9591e36bc61c56dc645029a304f1da7853b4416e267957d8e0c9e7a3c3e3460076efad6f956c5ed5e15f22f22216467ad347091a35a18e8ec76b131444a68cb2a34f6b3935b8394273eb4ea646ea50c2c10b3714d0e79997d1aa8db8b4d5391671df80a90476c9aeb2eed6c4201a55461582ff5ff707dac8943739f1d0428b90a16ff5e763800eef5381f28135990cb864cd79d9608ee01db07fae5b7498b28e31ea4503946361fb969a1726f60d883dc38e27f0017dcddb94027b2ce10075b42258a78f64c726e8f1151ede259badb654f6db3795d4fbb5447a48eb15783772856f4cc78774dacf7081435a42441877b0fe9252f3f2eafb04accd75901b6ce97367997532a4fd4614fe6ff9b20d7f1ca3eb080e663de036054dd5ee43ca7ef9d6a7e10ec68c918af5ccfb7231702be6e4a9833972a85a44a5020ef6b64fbb384480be19d3ccbc3524ef70279033bdd7c557b43bc5dc92c7e1257252d1e5922526f91c1df433440e22a94127136a648cc6a60daab1d86d23e68c94656129e538d73da826120fdb2aa10539f484a62447f1205582c58112a71305b60996d339d735b6938c834d5b78021e73f987175573d734eca3854a8464a6cc48c2b0160831156d2cd74722aecce14b312b9471913b75896d17c18cecf512e94013576870eeb65684654326175ff4fc409dd060052625f0bd5bd01851a97016e8f763cad456156545b1c6d52cd420a2d49a136e4578e2a6df4cd645020213415a8c94e1bd1d73826675877e5607e1af7b0f5737558a23a3e3e50191b19d53b093d1526571a1f55b8ab3d3fe6c13a6bd0eddb3636129032e1b1470aa68b4250ff40e810f910580c52e8c81cf2654c3886935c121e8ab96126b989471385ce5e103bb33128aa3860dde67f20c96f8067f796481b3bc411652d38064ae2ee4f269dde6e3250438b49d8dd3e601a770f3b1c47622d3ddc8107e5042844cb2a8f3cb4beb12916271934261b6e43ba874c532df1aa153f9b9e046f36321b8e154d170d2edc2625c3ae5e1340e94a2a930b4f058cef02545f524ac5a342510cdd36033fdd8e0467322d2a2b956a0183d4b537767680777ad8ef536c7bfd35e91a5038b4b6cc158867fe57c80930360a5d9212c774c80321e3dd0a6b50c61bbb1745640a00255e22daba336a1f496bf2973230f2e0f947b3be4676c38c52525ca2940802873941026cc41d4397a253749fcd60a8ad3843a22c9c6992f55572a0b52c45869a9d3e0cfcb26eef693101cfe0980b27c39f534f34cb166018c1367a8a0d51fb5f4f5b911ffb1ade1bf42bd68834353a6dc36643494624987d0a07579e8f1094fe3100208c3428f1fdd92953bea377e2776a37597d764b9721ed0a9babad6fa2a7ac6e2d97b515bc7aea22596ad740d49a8b53f19cf1754e406b022563ea6d7cb33018dd7a8a0558793336d2fea72cbcf35e0dab3ba61d3952a31cc8283c3ad0d8e9669cd8b86f41319e3f7ad7e93d04f95130fcc64a02768e9c62c8167d2d7004940901991c40aaee9244f5c8192952002c652af10d74b172b3759612be4fd9407248d3092f42924ca922c1bc92573af1971b826c8426b9c9b13913b863143febd925073cce1cc29d0a199607ea315a08225d45ef0e149a1ca46a4a21f4355193156c0cbd2d0fae25b270eaccbd1746f5d32a7f645a07a7bda6718c45700b86b74633981eea527bfc365098d32b5836c49319f565c23410b5ef764d8c5e4431d00553d950d4575ddcc100d5fa302d46c0ec29217b995813a7f45d7f90f72648f4183f66df2b4c3ae672187318953bbd017b4ed9b74a3ac2b222622a4c785e3ab4bc3bc258df3c6a84146b3cea5763abf5b9204996bc15c8993c6fa057982efc43f5672064f5419dfcf838980286152b655434f18895400c7cc36c3560df77c82e1a65963b1b3f7816b62f74f1611eb1a40947e667ae516770be638d84c623f55917642fa947312b5eef5ef5a0484a8d51532e0c4ce35109600472c8caaa195154a84f216f376f7e4f4325f6d13b3f8dc41b413a9eee4146c7142025fbff704e355c5249d64b190c127f693c74be550dba6f63e9122c2e740cce1d6f7c28620aa42e665ecd51441bba0b27e433ee58a1ccc23a335a995e68ce5f440e167755c167093ee471fe334ae90b0b2167eb3de13cac6f09fcd00cc2e36d2e80bdb63dd330a10f992ab2559cbce229a2b57b38c52d9206262dd434fe7f0976d83a9350ebb2501a3faa3613bbccfe2ce59a040ebe75b752f9ed0f24fa84b84c666e695bd57daa6a70dfbf4673b9715d0a396a28f5aed573b24b1060d7a39962584341115c35fa0a8c5d167294baa0545885c91476f3110d673894264de79539abaa6219b7bd905e3864ee085a3a4f719035205d262d9c613d45fc3afcbe3b02340ecc410257cf01b85f430433ebb15c8ede3255cb68cc03beeaf84e788acd52afbf200814582f0f127e075cf845532dddd58e1d0a759f096842c3272e51741625e787767d7afc30ba122360e5f6424f9076b0641b1e734ec4254a3ac057a85db090f7613173920f8b9b7a4b7b207719288b9e31aeb9c152bf4c99108046963278e8150105729506f3c4de6926d2963ebfd6ce3f6799845fea8d153abb90f06bcf14f55757752f6c7cee2603af2db43741fe241e9b3105621cfd5935ff2cca31093d2006ec9d0427975bae16c5936d52a0d7916e044af15ed89ca529da02213a7660290bf159262756815153b0031030d271a3724e951616de44752f32a1f354fb6355731a52c83d9147202c282cf758a886d85cf80c7001c9e4bb5bbfff754edf417140d0dfe75d290c82746e52d002e2252a0d179fe9737d40014db0bf5d2d60e2b3089b835015199c9413edb6c46c6d7f9b6acea77b054042a7273d5c7708842304112a59696c4f42ee5c8430bb527b168f1ee9c94b288f7b6f6c7df63168bd6c885f88231261b74cd9679812090526a0dc42fa27a1355d02cf1b1e084e5b96116e220a743273282e5c50a9f3503d928c27771ef3f43dca816611b74b3a2643733475825332658cd5ee4b71ff7e58725fda2547f78a53b802756000e1e41c226d3b5ecbe73728ca96b3535f11a9231b7e826c51694f0989d995755e4f97665a8c5d2410b3781f5f1fdd30a05d17480333c75bd70124091a34863d280032329b0fd8711acd3344e727c05fe8d2ae38d106b128f4e4810a3a7ea26bf240773c23ce6d022242bb0401f51561e0291c704ff27c4b27695141a1fe8f108725d83c2e876662e98f7476a88142298855e9419da1965e8187ee03fd8afc28fd3ce56de2bf1911ece1ce3e7850f121aa0c5b36027003726b34b656408ffe6678d06737b653512e224a12600d1d8943f10c3218551bad5072b08d69a26dde090496803974e3c0108c6928430f47b746759ea564470c6d5dca7450746c15c21917e36e557d72ec5767411d4ce26ad20a2a2a0008a332134006e069213bac941ca2270d122058ff41f5c88533e05e4d5db4602f62e1670f24127e8704f6910534cca94e021468bc3f652f3a768c21820e3a9e51115a8bc056e3cd921453895c37eac1a225d00372744151b63ac802712c8b34146befe45a4c241fb027310bd05542f0af3dc182ca0b77e40d3c9daed930034d625eeab34539dfc2c53ad18fb9595f7cbf6fd2c0fa143d7cd36b7cbfe85a605aaf0a2d410d12fa5ef14086d37a76d4caa84d91ef562fb610ed5e091987606a835874fd74ba5292261c306e27252f01459a6a2df76d4696a1bf48839a2d62e9939459c0cf5e184bec361a2b6daf1aa714ba50e5d400214b378214f0991c6ff14a535cfe807f3a266ee902fb208a066506b572f158536a7ea3a518c4e874033192e66a6ee4f628d4bf4077286eb62d00fafe4f606ac76a7959142cb7d07563fbf7441445783668e371945ee01b4932ebd0b6372bcd236f96b91d247f1b3a1b8072ca1915c4af2835ab2907cdeb810784e66d306a830342ed0b6540ff4cae3a4f24282fdb269715d4feac5f38b62e5e08b806487c6f9b5112827f69c1ea582c1e9d0e37c66a2e28c2d7044cf5be9801bc12f13db1d62734862d800ae8ec5a629882ba25a4612a0c9f9ada6a59d7240f855e9214e257fc2da689e92aa66a464f7cb6bc1823d0cd566d55384e4243f46766ae9233bf48224dbc0c515b51458865df6c9836fa497e27e846b80707c4a14959a87f6b057f3208917ad85f89dae52bc300e36204140b3ab969fe51296bd112fe1d006e032ca1081407d43b1da9fa148d0afc1ea3f46d2bad9418710ebd1857345ba2153bb929443b0bc1533dd2b00e7ef4ef6c60b946754eed9a198a68a200a04625571f9de209d51c2873085a394efc4a2d394e724e6c77f5ce1f6f3987371ad0be4e95636118c4d08d558c7333487cefa6676ee6333b22adf53a73032a4d9cfec206bde40c3ddc199c40fcd4f5088c083f2ccf076225134a915bb492664f9d97df566938a12628d9690af840a24fdbf4a031f2c40d4d0b3196083fee6650c779df02e690ca3bc0d5f1549e93f15b8ea715099e8bd83bfbde7342e21e6006c7809f188eeb2a368e3bd1252621363cf47f3f3cd4d14a654f26b35699f4fa2ffc143350d216b93bd6a6832bcbfd4d60a5a17b1e3b164962e2013856a8b910285cbf825e8ff5624cf87cb66056124e00963c082381d02e413da2915e88876f163ea1986464181570d6fdad4a6d68833a763cf302743b91123657c64d28ceda60c1fa716984cddb238f698864e64d613d3cf2774f3a9ed45d417d130810cf1230f00928517866a34300e6de16ea46d1043eccbd6e1671ca1174fe56621dc5e65d75b13b12ab26c9591aa4cd302a79d8193c50ad287b788d6a263f4a3fdcfd8b2ad5d0002f1a824d1ee82d89489c3a5617afc75c2a6b42373a3ab89432ec2a60760777121d96335f1eb1896638d1cc8b776a0c9639de7a616a3b7588580779de002b7d0a55b33acd6c099ca20e432e1f3bc039d2352e63cf71a80d5132c593af2befd2f05cda951a6eef74736ec6b3131849b394538771fb72016ee6388a28d641525a3831cc76b96a1733564b3f31361239acad156e785861bd17fb1194cb6c57369d0027d7627f25c7cf4300c065e928c8f6ef2b72fe7c16c9f9361e7d83a16819beaf61c78f3843536ad500e1438150f2455d669e4d79016ffcdc1e545a3d59d6b982158b099a458e5d5319fea4fd1ee126ce60c15cf020641ede6dc863895f8df4426282bd25018aaa3b24867f214ee1a2c61fbcfbfd675939706c7576363d9c50a66abac933075824b70d577b324bb833f515e6473701985b7a0a7f7ce04067553960f56946216673e36be2d07e1f11c1fd21b9a185187a74bc1e130841424581fc20b198961d943d2c46df28ef3128e389031cbd486067ed14208162f12013dea5747f7a224119d2084b18f1f122d6856927eae6304bfe1b025ee964461acc3fc33a7e5b1d2a6e852458cc532e18c04abe177a9b941b1fdfb149b76870107dbcba7346e9d7694f5c274ec9c3d270ef4b2b229b8ba12e345cd46510e3fe359c79d44e6a8ae847c13b014e4a3a3a47d853955394c52767002c141bda85c2150cda3e201f5b63448d96c346317cc754202d6400e7af29482b91a7274a4f26538a8a5b02117235609defe73ead112557c0937e001a43f454e9f6934733e0c06a80e29b6e4396305d3b0a7a07452ce25d847e0f6fba2d7764602253702daa022cd9bcaa41054f7666d80e123301ba523278734021163e4c4e4ac98e6356787b2b93fe1635c56f5c0e33d155584168ed42c275cd3cb1b3eb5a86ef2421a883e125f2307e31455fbf6bd7e66258e1d43f0252929004752291559ba88109c1a0ce57e9fcad0f92f8374aba64641ec3482b1f37c8ae7254ed5209a0edcc6640f90b20725f17037b6b005a1230e153dca46f5f1edd257425c9c069663dc3214380ae1a61fcc571b2bbf913f28a476e7d23fc4f6f07b501b6b0c572a307463851acb10c4a745a23922d596125bd47022e73cf64053a950fb018f94b6cb4a607d098bd74c73cc35e855d60673f0758138ddc921fe19db51b92b74a14c8d3612913618d211349c61c0f600e561dfa354d825698507ac345259026375dc1e0141ff92e72397e02b424c2e838693a70925f68d1f92dcb706a268137731ac50d445b16dfb126865a233bb6011d2950ee2d61e149b25bfb564b461bd5e74c3a550c11726b651bdd50245b2ebc8b1f90abbc2ef337ca2a55f32f69c1892b4105f92a0efb1e294696b75925d907100ab64c52549837276da0ffa41ecd76586026267307fb8bb676fe01b12cf67bb970fa72b6223e5b847752edb053f55db8141483e56f6bed66321f71d460edd62e240b11e525c2f8ba39c6f653052f025e17cd71310bc4ec091f5b039c57fc061d55fff6f56188b5ac49710aa93a245d6a2c3a18087534abe81d699a43077bc4ee5dabc82c35d55425240c355322611bfc06aa0dfc411159650ed734e33fd397165db509572e6343614fa386dd70121a6d5a0c66e937a7245a42e5909b3cff6dbc77d4d93d75d1b80f57476ea56b206eee18ff0788214b73e21623ec494d9269081b11c64f5a08025c0ef694c22421e5b46242d7b43721490c6c37e09a1524318c12f02e15142c62d2023c94c642aca0ce6f32e3540090121053ba38501e1cbdc46809fa67652d7dbd1cdfa6676f9f8d6e0f00aa791cf5cf3152f0852b4c13ceea3897a65671ddfcb27748d53862d07ec52d6929e806ced50e34f22cd04c7dec723ce3fe10147919a8099b275947b9b56d67bb8fb75242b2fd4d122760616072126f77f92b429553702089398e27665eab74a00c0e5d2a99f91613b4d475bfe908068ba88f71fe695a4ee013693845b2880856b94f77a2babc68db16e8236077d23b257ff725c8f74444d8929e7324cd8f0eabad255fb3b46408bd2a2c34fc26101a235f0c03a257dd0bd3a54a2685889d59221184238e8fc80b2b7c10157de1870261ef745651eae1473860774fe350f10937588b2dfdd45b26a448640969bde36b4e6d250f6b5c3f01b6b4430aed2106111d680b4605f52c5fa27d340f66000d4fdebd102db4a4284cc0ceec084de8935aaf12b741fa13133f1976926a75e414160565a91c3470150f5beda0002b8ca062b3720b52001ce45713ba480a9152941ee4b2f320ccd2f155838809112322407234d6fc641b58c43d33c99634fafebd1586dbcb191a30e72fb45eaf3638b66b6c08b7767251accf6464c9d4438196332ffcf8c36868130718489e353d44f10d4c2093062d90458c5db78ae177df7f944ec0309e1da89e1a36a0eda26072eb1341256d4040b51d2a71b120310e179d00717230b0213371ae73f5102f6d9922124e9eb41337df1ec81f8c57573ead40086fc0c2126db11d0954b9e1661cf7db1427da4a461393b5541542a91e5f08e73c46be1b8e5d98ee8136c445822ab181741cb239f971e3f60a0e780f474efbd7264ebe13db4a8f70775c9fe95339aa81ec206935523a905dc16f3efd79178b07d14fe4c975565776d93f7e46eb25e9f4fe6563d72659dc3d3d59fd62df0075aa4a014d32842d967e8d0833230f59c3904215e43584129db24e16b6e12616db7cf04d35679f33ebda013eb6da934c76308a1a6fe1660d33c4d10cc5a7a31f588e966c79369b3e6312605af09c670977a45b08e56e7200aa8d10513030007269e2442293f11447d68cc16ef79f216d2cccbb7018b8d44f7d93821398d1e508ac404333ead4d176a5758d290697c334ccb11c4e410b9340ff8ca1709ed52655e22e9e6e167ddb0108cd5e5f09482c149040763805707a5dddc65702d7314105dddf3a67a286771cbd1d1223c71647560a4e2100965fb36bbde03e0ce1bbd809f1cf0529eef0c851dfe2b3647740321e4395ab63992f9043088f960081215a18f52a4e3e0cf6450b803933399870586add597b0f7141017448fb03500b61e24f7f767c181c78434c1805c4137462e41f1f957a5c10523955986e29088c9a4f2ea2a8a352499f272a0a99ef48e736903ec343f22bd0e60947565f704dbdfb70454e7dc32b4073d510679bc7673092eb520d3c993005c8eb5dbcc1e9542fbb1f0b8cd63a11d1763f59fc506153f4800215939c8e32f27f5c6cf3ba6f2d11c9c3309baa986bf7c4b937cccbbd2abdc80f763fb7591770f5e327c420782d0cb66f6da3ddda1cd17b1434f26c7730a38b982bd1e3bb1627f72448419df6602cfd00621c322559e5adce347ef73357f5302f47d104a95f4bdf123d785a3f058f20661459498a329599655edd5e9c4768dc5c50957bfe77ff78cf267ee2d259c8ba3504d8031c528f1c645d9bf248288885ca02458a772bc9d8b213a1e44b21c4396431691a931358410d373cb92a53de4c8612fab95a2122045f31b9644d53d625dd61ee5c07059e979d305dc4fa2b0836652259b7132fe174715c54a4b25e48c0cf3e1f954d053ff60b18c965385cc0124871d089f560784b8a2860838757ed5f542c00c0ab589eb9702c2bc2005b03ff081efd7bcd765f0f4541267d7c2b6c56aa397602144e9da616349f2d303a9a857c4e9657e547d088236a2083725efa47e15ca54d6447e895940c0793140e739c1a548617e867173dea57f8e987092b150b3d955209361e9a3127859c2605b1cc5f48dc22cf6e4f4a131da71b94703d46f8135f1db76658ef155e755597574fcbab20231b0265775683313931ef10ae66271f147cd96b8311a47304b94f4361fd5716af71273d55617a6fbb2b2e1232233b37d58b27119aea6d0e56af876deabe995a33834c1665c16f017c0b9940e19af611ce5844660b8e370bd4dd233a0357bf1fa728854f9fded812c05d4b5eb041be51b6f05e489f646d41b6dc5e3ff037194551f77b61df746832b61b70445ea1af1f18ebc0666ff1674a04c240659ce63d1cfa40486101db6f4ee7c11c3c860ee26cc37d0165b777723bb99af838b40c6933f05ecf55d7ca046dbdf6be7780a81d4097969e571dce294178372d5c38806f74fc478458582c1e119a22824fac45b675ddbd246e6741cd289165036c539fec6e014af459c358155908b4bd17fc465c04280351110f8cf85d6d0d390babd0f370356ad82e72b3c169c83faf744464f14158980200cad9fb024ce7ca081d6d261a9314725622ed354000f04a52656fcc219a094971530fa52a8149ce03689a9e0840b83e4860b64d1cdf1ade6feda863266db4d326bad4995f518a2e209a90ad47356f533b3d1b45774c6e94215596a74e46b7586df7c9d95681a58e6f74f5f4217720b67521e3d32e09dace7644cbc74455d5db1c09b4a61fff321a64ce9f010d193f7f3dff72fa636abea02ddaa0bc76fe94e83a6d651d6c697d4254935da93973af3a289b246d668301e15beca79136499ef54ef69171605a5b0f6c980b7a469ac529698001176df313d917e69e670a8c88fd462e0b7452b19ee71e62cfec2961f8376766a1285dc91d4b16da7b574154f85d5b95431c3791c1e767e668e50ff2889366f7655639795fb66c805b95196976b06df91af745734a1940ad372e0fabb3f71b4ceda05cf3a7412539aaf6546bb836390b8b965929d19f4969c45b701db4be204447c862dcb5ac1fb36a9a57b27116421159153b9b9c313ee974f343cd3e7d56b7e6ef628d6b6b2fe5e2cf71a261e76db6164016fd2af3571fc7901452d9c00d978d5d497c9f841fde92206673358252782f650076836965c0747c0f390c0f252a2aba3d7b5eb01251935f1b7fdf532ad56e6575810b402a7dddf2187762222174516168ef4b8e3682c7945256f3b933686c5d174b1cd31db506f903aa11f80d68a0a40c3bebb02acbb7e8137253136447acdb08f683672472d20b38d4442d37ab9bc014c795c21724c8446f7eaf46774fa1c35acb6ad012dfa1667372e39b16d681bd28a865b16baa0a58143daad93416a8cd7118a0ac566d80b737c28b0a0e0dfea370dcc68625ccd6e43b09c23f0c62757061f7ace220ee4afd026d190f616a792e279741b769237ed04b4f9ad3391525b31842cc22462a3bbe682b0137410d96d20bbe117746d95c1d0e6caa3d579335574775d0443fffdb571e71d6ab2b49579e1c2fc05657086cbf2163904f0af0be016db45105163292ad3c85eb1e6718a6a60d58a83e72e70e4967271c381bb43f9d6d20b23c717e04eb617932fd5a60127829a09a136fd47714499a7138383dafa247d4204b59424a6f6f4a42da0a89151868fa5f8e21de61da7711d0c33ca368612663211934f5974b57383e83237345c94c4bc2ab7697302e5529985b6333b3b145bdbfa96f5e8c0854589988307b4229524054f83b53f8a0613cded6177d5d5d77cb612a39ed27a543267c1377bebb5b18353b2e6119eb2e0b05b5c300e3a6fe766c2ae43c9ff0a3320e77c4125242365b7b720750cf074d197a6d250e7adcef018dd4a220c629934ab4c1e2295bba686d3f573c0da71ad50db779ee24471afb19dbf24f6b59906d0d8da40e6c6bba9b1cbeb4364a575c513ec6bf1317ad73d15c3f7fe60262ddce214ca1676764cc4f011bdf48347314a75a77536d0449ef4b48c264aa667c24ed764e2bb23912e1ea43ab56f45a20b66d63b3da9936cd219d2389457136dfc1c010fa820b179174fe59b649382d15b766266588e91eddd6c529cb1ea6505ec709270c680d04b2c86822556350512bea87005775106c48c7a437c66afb292072376ac9954324f3df2777e97687655568b3351959835ab6fb7375170cb85ca5f9c1360a742715fef4fc3e5092f75c2f486c158ebd481c578a48144334921c8b8236619ebb810dcb8b924417ce7156a4e38847d3182617c86dd3032cc560697ed54738a80c3c6fc9a9cd69dadc524b6459d745ee80321bf68aee1a9cf64e515b29a074dd3b401a6020ff5b1cfca831deefb1429d2a2f0ec417832914b4e14e06c3a255737d0e3a3e876e5926dba61ec86459351ff4660f4184610e3db2d613fe5a3c0765ef973bd306624dbaa9315f26bbf63a184d4725bef6fd37bac32b77e3c6060735069a3bd7cc0d4f8cc6a120335a505e290dd950ed5cf111260d6947c0d5b35d924fe84581ffc672242334625e3ade41186ad24d9a800a20a55e8e5ab538b80cb4daea5b43a3ab230b203460a1dc9a52445c7a5949abfa2d88171b568501ec7705f4a310259b652a263a081a5f3589008f12e6443096ad2503ab843d0af92063b42e1c36d4d31b4eb840e03f1edb68101d8c311847f9600fd627e3296f50543379a5a43e03296e44931b8742856267005469612c287d2375ecdb0065082d6828f10a215cf7205114ab039427d74c985761612f0cee208532dfc17c65daf638553ca466266fb37a52bb367f420186ee63883d285b5d9bd974c653632e7889b440769c4d0990ac13081cea462c1d5a6b58ae9997325992cc488245e45248b33a1be773a5290128ba1f290cef3568aa43384a79b72d7018b33941e216211e59d433b3b1011ae2badb1c6f597b0c819c4457335cb30d75c8d4528b6db103532e3466fdc1423d8108bd7091ed2e6e00b5ff45875a03237c6e3a3272aec3357851d440ddf5c15705e4623b170974239b2ea34613443c3e9b3fb7073dea406e581d8f3c5db344244b179f52ca56ca0efcb23c0c182891710882235f28768e533fbb1843f52aef466f4dfc11522a466efe1f1d32273ab915d61b915cb01d0d3bcf98505bdbb1a61e4bf28a00af770213c17e5873c085cc3c57474d0fdce6c449c4584b279c698232f9befa491acc724650e6154ff61aa4199ae95852058b235b6563353b25e74b6e6641c95c32662307f677f259bfaf5034be556735577f3813c2809645e8337a3e948f8b323e09a41d73c24b048f8f9c757556441af4316d1ba5db9d09a442f96e274bac3cfa1f2761d1aea74409771a69dff6cf3f7deeee71f6178634ffd29f765e4ccb23a8c9075138fabf2e8176964167c3d858bcc263599101b33b5ea70b29fc87e63ba38a39398c1b4b5648ced42e6512890949a13454b74c2577b1ad1904e65e83431d67e96db19e102741ac200dbad14f49fa262a32be66a015d751e505f1a07e2355dcdf6a96c7f87486c8633a7f6bae294638660394daa72aa93720548173b915f1ced93e10ad8d3ab45b6f06049cf65aba48392179406754a9f93803b2bd662b4969c73715ce6522205e1232b07005175ea9b34ac763f0169a85bf75f2da1b72f151d821cda33d72a715670ca7f0ed63447ef62a43450876d30ed01dbc45ec00a13f9932013029549d2be7130fdb2153c1e1bd3738785e5b184e2c599fd15077f3e166752f42be1672ca2402760462722c38ab4710d3c4006fc877155b5f8e4be11cf156cc705741e4d48751a47edc44abc8852390ac3b3e9e559e3abada8f35c4fa50080393043b3807ea068f9b3c687fa3135b1e04b4434b392a17b4cc016e8735d23502b3df2253095676907dbc73f8b270166ac9525b1eb59b3778d9832889783d72db59bd086c363c5a7cd1c3108cafe56df4dabd24a4660753a563a708e09eaf57766e632669c3a6400b1cf20ec34f365e03804d5f21b4494e04f1626d0c28e420ce8c490301cde11306962a4457b5c36b40fbc410bc4e812e6364d070384d8a5f75d69412cbd52f1e9d7469723f5d2221293c536efa88de0ccb33f04ec9618c280a4bf105e1a98830e83dad6220d0bd01cc19583a9ebeac4f92184f376da5400dbf024112faf7fd3c2f7f9e5b8c9b2d5a2aec5866eeaa16558fff4a5d798400371a60602c6a2cc666cb1345136158d173e60a7e0b502a130c41aea05044f7b02079dc920a84bbcb46b3ac541b895a2030e9255d1c7bc1f22f2f99523265581d229d94dd734f1d805d291baa14f073b045c456935669d46e03d8f52d359f4030088206391fb3818d440c2a3b38243fe12ca8881e05e9b67f486815b54c77837c3c0238ac404fa06f26be25a94fd980b84262ecf61a08a0c95af0fa97172122080a5a116d2681f0621b5201e42c307b485d19b7fe5a815a30337632f841d6b8e26c5f302b515661821e95699937c59add2e72a2b92a9747954e3772e72e448ff32825dadc0e7a5f262c78a730238ce9ff0c78d6eb433b9d431c04995253708b16656be49f5958f60f265d631e2c1f15544df0feb62052d7801ee45672071e25ad683173920f8b9b7a4b7b207719288b9e31aeb9c152bf4c99108046963278e8150105729506f3c4de6926d2963ebfd6ce3f6799845fea8d153abb90f06bcf14f55757752f6c7cee2603af2db43741fe241e9b3105621cfd5935ff2cca31093d20066a86960a0d36551a6d139c5958b56e6386914f3d63424433913f473b285f3f5c5d7bce1be4079e2ba18ca45ed9d0da2932ba0b287b06930e0e2c4844f3431a280b69f441f540ea0d6a33da1a2e73b019c825a2534422e908e8819c61dbb8023ede97fd6a0bbf6a17cff7704d799f537027dc156c444a9967ebf2c91c6664d7239f6ab9111b90375b1f462075c793f5320e139d548115726e143b643b1a6b21632c4c8046b1bbc54bb369b04ae6524701cfbba2760b4d291fd7e97312b489d2596090bd7628a75c6535e5b17094e69f3ba5f8e624da128a1562439c643b2b162f680a080019e22511d0ed1a28c4747a5ccea64e12530e7107f852ce660e035255877219005dd0c635c11fd81c0b30150be884142d55cd13724867f437273f686c44c64973ba560906214e583a460bab345b1a801298ca8c50b0035c58e2d1643c9b9cd9703e1dea26e1023e3d9b58eb200d124b412b8446570ddb8564c999ba11114b74347e86d63a3e67ac5cef59c740fbf69e2b49bd4429a58af3401829c43642b31d4de314c6277f391410da9567430100114f0062ce39807ad64001097f2decf90c1cbc0b854891821a69ef761a70e7cab56148da962857665820bd88da1e061f522e5a0aec0d74d66808cd20c2497b6afe3b84bfd42053cb3001f21c84270759486951b26721d3a07a67f9b2cd128940fd5f2f1db24d6142d72113a3da3d57531168c7c3ca74159f7930e55985275f9f255bbcd9a636ade72e2c5d62a70a7cf8055d5cbf7d27feaf671e26f4f26419742e655b376e2b51336b22cb677b2d0ed7a93acbb2c05b6238226aa040ca162d56524f09881c3fbf8a4326088bcc3ceb4fc8043708a050fd5ece3a24e3673e534b1c19046248276f6a8470bb9b8569a8385d0bd85bde1cd96ac821475cef281ced4e7677a7db3e090bc45b8f0c9052438cf053c5edf55e1927ac652375fd25dd5394410fc2a12bcf780c317928f725f124877607806d2c628faf4b020e494ebc3655512222ad6f725ec82be70f2406be700b55db385a00fcd1372591ee615071fde6775f94331acf6c0f28b5b7a3724e822e5bf422991e79cddf2f1ec9761cfcda26489c72555f1c0fee331c07e546e945a504af21b243ce1e3b36a1dd3d4cf97676700917f1026f73fe4a8666ed433501b115c5d1be205d2f2269f2dcc70a3fed243a3085dd033d30334633f864634bef7c4cf4a45e01b3fbc234c4fde308c305356608f4795c0321aa3d87d31a61b7f36f2100856761c0b7213f648e2a08273b332ccd1231716be86b2fa107643d37ba6e4774ac0339218472692ffc7f28cda66b4f78f249584e40156ada7b20472648241783d0525a18e68a313687f22c1117a177eeb2c615c6af07567c7d544c246186375569cc5c5dcde96deec4a14624ec8f662878414e04acfd69da31ea4cf64a4621cd7cb50f0cd4431e2a53094ce0fbc83be27e99685f86c3371474402f0625b64c40350235e358c353eeed8433662e6f2c00b3d541848e0e2a5d27b514d5a5d5032e2ad76def0e7b09ae35a56bdcb4c44e145fa23c5d0b913b38815d5e5cfd712cc0beec358d949d732022151b31204e71666fa74e773da5406ea475053db919166343a72093c7854b306e0f322c77dd0748faca15b1c67b2bc0f31163e0876903539d2f5cb346f81c3895a857a5c485580a59752be293ff1fd57d394e7226f364ec92c745bcccc80717a9637242295e6da7b70973047c3750a5274131afeb20584511c30659932e60acd7c775da38240dffe4b21888bf4e4b207d1618975cba6deb2e332ccb43e115bddae541ab1a7f5c6ea46a1dba3dc73d151eb81c6734266dbfaf225eb753c776d4699719be42e31f74ed2c5dfabe555f4f44ef0206b4712d42606433f3a07b206664aa07af32e336d0f19d32883b7f70d3a6d570f97638774689223598b8534a77cf8c3629a7fa315114236aea060676ad8e132c47970804d2144d494c204504741b7502d56b306746c3c215bf3d96302e8f5a435d3c170051f2be31c45c9361abcb5e3c74fd4b4e09264f31696b415cb3f55666b917f33001bb3515be06cd3d0a61951eaeb6e3705e4a093c6dd26f3251612e6be858a463738ac856ca0d2510df24f356d326ae106edff53e11e6c8595b1ea110a65af90597079a602abad730ea3eea28852bfc0dfa2e6e509b09ec312ccc23509848a4272e54f324c2fbc44466daeb456cd3ef668b74c45660ae2c62ec9c8c64528d1f60c79e732266a2fb0cb2e9d9335c585042a17ae367a0c7bc73e427af1eeb4d95434aff1f740c3ab012efafa965ec5947564b3c0a3b9ca6e03441dca232dbe7d024d15ebd2d5edaaa4fe8752f449aeb55601aa0763b9375645048531f4c053ca80ed6f6124ef192e6155b99193766dba44f449afc6afe782a333582b22001d50154d4ecee6df360e51f4e15332331d3c470c3d71c2605bca76900900f6d8c70883394785637df7d09571a166657c69180718f8c4e1fa6dc0025a7780218a120ce4a6fce1f42a286e03cd9a2914429a22869a97ae61c8b1bc54049a2e04cf114a547b840df0c30c21700b6bd95278328301db322857245bac4074abc0c2df91bfb3316e6831c3624cc476a43eb5871bc3c6928af405ad32d172e81ca235830621857fe28af3d842ce46e995b88707cd45128c223517661ab975fce69843427b5b94e6bebbe138e47d10f7c093458ac145343a0b9103fb46bc473d83da3716f0ac228ae24250d067d7054fe2be33007ad3c226593df3f1586651d73b01c5c6f650b3dd78d7b477935f4171d436c38999db62a3de0d01f66af9b241067f528b4e19e1895bc43038b626331b777bb6af86c636d472943071cc624003195af069cbd49060c44ab01908b67510434c20d9e32752f8a3fe274416430491625ed6aef15383684e3fa1ae4e7912fb4b07542abe54241f6bf6e1e5e51ff3bd274b205ef029921d142e739203c14005619b46270636e195bc9e46a95b928055e2b975fb2332c66913f332bad16cd364c518b6ac52b2c27006e3c082362114c87f38966407a5c60621b4859791cd0480c00285b6523b01bb1f0f65aebae5d495b343e36c8bd0e55077e2312046c041109edc2242ef3263f838d213454f0ff34120b76765b323b391ec87a06899329226b639b37cbf08c3b0de7e2041d8ffb680770a44cd352260f0f94a86b3f284852005a4b0ebbf1df59b347f934542f3218c98fa872c2aa673b9e37a75e5d526a6a73f0f643e08ea850108acb27a6a3c9643fa73422fb071903f45b3e204d00a72ce034490d22cb9d1c9067ab5b60d0da2201541e5f2054c63ecf54506a692bb0241e17682eb1ef7d5db1118e6c959cf20818ddf465f9bd6a3323a64052777fbf14aaaac932fb0d4a0771a62547cf4caf2c00586a19d13c497645d0df4a72f97e22797f99732f55512ffec3c65f634b0f58e38c1a7537bb0f674289f0480b9a4e525685e4164fcb031c5e42c32c12b7ce12c28b0b6e7c6a40369115b253fda40e595ab0524e1273ae24cec6db3d0232f23efde8e54592d9686310377d26c46f2674589fae2b064a6e4067fba818002495549d5b31373bad532be977c1185caaee620d6b93157935562466fd231586edb64375096670df7349033ce4c633001c3b36d7c7161eeaaa3a60ba52a737e0296b1cf16f0502e19093664d8d3f5fe085926971f71956115bf707e4183441d856e03d5f729a5999636628c55c0824306add5e3620bf63d05fae3de246296728b6382db394282d62e7d64795e4163a39f7ae407824671bb16c7205c05fe73ef23f552efb24704dc0b8c20d8ce55520a240ae110d735c4a44881c03ac20ec4d736e9f37575b831f2100e6361eaa70779383041d88d0181fefab5a03557fab2ac4083a118aa3ad1454ae390dbaf6155c1004a738b6bb0d6e62981337d083e019f104ea1ff4e8b3442b19f76e363d3038054ddb35914c1d072615a916361ad52c2d1cc7307720a5281875862044d3a668caf4636659896348f985dc0fce57483c8414ae5f5438d62b99e0764b414c0e11912bf025e8737276674a263f08c2f2200fbe0e42aed1a700fd8bd913ba8867641582f555b45d2c4c7016415251359f177935fc52070243498cb5fb1b8f34ae35a40d1f1ff13e8d015dc946383135a423ff08711d842baf75c43d8b58fffb4010ce388c3fd6e9b742d3438420ab62414d866ca057f5bc4f67c916d44d855e47344d77830bc88cde2e1d15fd001803091747891823ee0bea5857111d4f24082f11fc5b520e45dd5f4afcaa8305fa5aff45018a0f04186ebb5e9053911f51f08a734fb7184cd83ab6505788ee4ed7e31a5741061d279b243435f564bb6396242429ca74cb550272246273d93312281d800d1e87cd39cf30d95380b406776ff0fc38b6272c04c9d3022a61c81a2ce8492a5e56399d49054d5507e8d61d1b5db1150385a8680867f4725240af5343e53e7473f937836e78c0166b0a371b049116a871d0555132ba4ab15e9b2f47316a37815094f4955e1a3e4d67182435610339704738476c118588fd2b8ce7d05f59e59114bec97c1ed238f335ab13e20d06eb836828e031181197671fa8f87d517777a5444227c90476bbf23f3e30d9570f599650263cca21ee44ef4d39fc3730179b965ed247e421f9dffc25a8ff0b3c322cbf2d1796c749497a106e907c8f40af4d805276377a6a2b3498424b9a591d0e7ffd5d6937bc66d3078133785da21db518404c07d1a74870a00c3461b5900e2aa43c1fa6e7cc2a8619fe700224032945fbae1c7c4e2c332ae3b6649fa46b2cb48be20f8ccdca4412169174ad4b241a275cd9613f477a21967d613738dd5a475508a24925cb841e56d6882e6f027b527a7200049743d72d20ffdd09e5fc8b569022394fd0e44b2df1fc635a7f92dc26b49e413d42e5a7727b14c30199f4570aad9d833b3caa225f42cbff5ae197a72897399a1593e690233a1b116125ea352c02af4213bb14686b628bd44814e00659a9f63470f1ccbd3e0e216710419554208755884a9047312e6cd4f0687342ae0d29615371448d11776da644660c81851289ee953fcf3f0e3d654cfb28a541cf1db57573099d16942a96aae406d24bb32e739f03399b705b51362e3309e779f7653bd79e2af3ed6d064b93075e41ffc0251c7b685285afd907c5abb770653c8167209553496bc4a36f53068e6bf1fb9866ae02dc02643e2d5390a866565850630f9dff453ea41adb03b8ea645857fe2a440cf0e537e85c271cd8f1b76e4b3a816c579135413046d74355f4e85a027ea14695203550be77dd5acd8cb05e5434af6b64c47072ecaa2c71094fa05f58a8611a6038800302986869edde173f0a44470a8cb5db47546b4c19154fc81996153932ba1ff26469425842243ba25e031f950da2f2ed61a0541b233602822c29f2f94c81c766311249bf015e5b0505cf21c27727b2c36cd852b65c7698435ba0e93a3c663b4c4256ef810580ec5438d3582752f8692f30958b9c0f7653964f5a4ef15b571cb75298d96921b2627507c3309644a84c13000de5ac59f666d602928931560e689f04c1714e6de623d06dea8c726e0dcdfc760b741a05c21e4c49c8f74b41c4216c3cf932932925a34945888ddd3e9aa75c134899627424f74235468bda30d01e07766c217d216689920c71b7981121dc3558747d070f4e65084e30770e4ef23341450194354c74e92a5dcdbc9f5a3e880658420dae34af55a75c445c950e217ff35f6e524b4b46cf7315c264f04d94602a3105e43412616e521b69579608fedcb02838d2ec76b88ef1461822eb6d5a34da0c8305f86048f661428fae3938707f58443704d35bdb8b7d7428a3ac72e80db725da8a12620424153358054f6badfbe253ce22926668178924f4cbd56587c90658b82f03042fa586643047d3569b123b2c065a43164bac3a6d4fff8d4ecbe0991af28bf14e012f01581be52d6d67828d14068df701c391fd236722ec3c94e6c7001877fb6ff8606d1b302d4d6c6ab42a45edef635bd25b8746977d191e42f7852b259bb30bf85974702df7b442a210f14db3f0fc4e16e47220c3ab2106ca63cd3324045f73a671d9423ec32d5b1492746309162d427e84895f8d6df53ba92d7f3eb485ab19047bfd295d1a45549ab05e682d363f5fb12fed5e4a781367a933ea6980c09e255a22164a199e896a7e74b1772aa2346269470b5a3eac487054c32850a7ffd96e1d965a107be3a02f5a8fbf24e01aa409180fe060ac7c40400122aa2725f5bf476961902d65b4dd0e061d3f6e96947735394802594df56d101b281e2cb25c7120dc540e5b8ebf3f67bc9cdd0406e56b4fbfef0729d4ce390fa5885b464c65906bf09ca7165b03a9749bd0531314789e7558d6b858b180a92024fbf81b124d5816fc8f3254d1b3f924af7d401b09f290097cbba56adfa74d042d83a7137aad652eeb45820f62ff2f5327fc2a28004c942edacf4f05f758c41a4769ed625055a3414f0551715ef0bd00079cde364fdc5412d318dc3cb12d3d2c48840d6f14c6d827a4672066bdf1730bdcf2063cbef3e66f160bb63c27ca82249d0197351ee45c02e1b2ad6ee7695f7192929e706d5da81c2949f63165dce3707386aa539a8ec70e5436d64f21512e283d57a0331930051e9b40ae0060fe7f03c14aac3a95f2d93709225c3785f8fe111cf4f128b100555cb2eaaf2984463631366451536db6e575992e9271b1b78734b0a9db09a2d375283fee430784d9850050edc36d3405f95ab00b9239676f9725a835ba24db5ad41795bb09263a90e002c375bb63bd53386b518a79768229e13940498d58d2df0d2fd943740dab28b56fcfd44726667fe46c1a143f453d11e430b787a8045a4e172948a4fb207c16fc133b2f451c96b9463114d68e4ca805663fdb8f814b635c452480e6b85b99f4d34082c8ed2b3da29950ae3dca63d0176f04bc30515a6d7d1d6694d8564c0873f61e386da83bd8ffa955314db56e431a407735623f1a6a78e31f2e474c387d02e03b01763e6b92ce1b76b427d366d4fd0b4c0f63b84f3668b50ef0931e6be1eda71cd6d6ef46ab743e32b0eac60fdd591b380d322b432f923c6e8a52546462ecfe317e7aac5dc215ee5c9797ff3dc1244b3dd9cf965918e1511857fa7f123e099e3351174e06dda52d065d10a473c8990d1454cbb862d2310611d75b396a1b0b75412e35cb251b2d1c75d737b35e2cd80a067d11c2557c698837045f9f2e661d4d3c14f53e756dff1d17a8e7e51c7a31b21acd371f3faf65ef164b6f7669289e9439e71f06749d19594fbae0f705edd4835b0624a4493802aa242b6971694f720a5151a53f682cbaa1742eeb0e01bdf6af30efe0b6697a36451d22791d3205690460de72ec50211bc40467fef74b49cfdf00744aec656080635852d1415ba083b10dbbd3092bcef3ee5f10c7d5298ce227014106526f2e959448831531164c7c5637bb59ba104e9a133905a1c60c65901f4694a9b53e3921da1a32f6d24b1a4b0652879f2b2d1fee802710dda20beabfdc0a466f8a6c4f97102cfc33fd352da07b5af056f643f4a49e4bcb549f62b98e1230e4b88e23da95150040d4980087324e18eb2854154c31b230929ee3262a19ed10dbe81e6b2b485c33fa770454b33e1c77fb0f89710b32023127a68d32cc2d371c5ef99517c5b2524771edf62e5d800106b57b326ebed020642b28740a986ec3303b148b5dc4dc713d74b06c161698b107eb343501c8ea42412b3c4e3cf1941474bbea7531c3084d2929a72f2b4c69460302983d4b8465f24ba2c6e542149fad2378085248a22cf4401dc7ff4e3b7cc145fffbcc6e62d8d76f4850c137d3754b3fb467e6760f1a380a65858430a370b15d9fbaa34646185c5777f8991274bc180f74d36e1e6b19d46ea78cc34f9bab8633fdbf2b6879f2325894a0ae298ae89e4e60c1c058af89ba6d90a9b43c7300f15b4ffa346169ad174402f80335f73d5434a4f9244be4e90802dba466113a95d13095845f37cadacd30588efd53c058fd43df3478658fdfcc37b8bb2858e6c4364eca38e63481baa67143e60f29142f3c076f54b163023cb83c23202f1c2f49c171bf4391407ec2285bf35f27169e17c74a7e10160f1f0f6026e56eab180e217f33104e7c05a366951a775e0d06e849824f98b0fd56f03619049ffe823d3406584e224d6904dfd20c6b16dde406e5cca02835313627cf21c060231f844ad7620720f8c1fb6b01e53b1302be477175790145f7e75f033bcfde15cfd6b53fe1cd82005d68771cb80d5644ccde125f7992e077d0c5e438d85ef36f3eea283c27efce60d00d581da21e9f55eab490199bc82458938e4e19507ef04b07ec176521a3a9603794416e081477471abc3964b675b01452087c72b99f9e49fcf06664759933417c787f6d5bd12d3868e3646cf8d82f15b41062693cbafc72b20a7007d7e4b82f91ac976020f09d55645a1f113c76205041856b1b93cf0744c3d9e50fd93d96510a4f5e09fd74f76e4026bf55533c9c3768a0e2689aab95609a7ce15ceec2640a01854e553c76e10454cb093feffd185ebc923063092f8a1001369f75bcdef14556fa1646ba2ade2c435c2d733221c2172c538d265d46911d49dbc041b7e5a610cdba815e919a5e518357b318b84f4a0357d1481a3fadf401e11341135e88065d01b2e42a939d6236f372ba4a928c106aef1959713ec0c23a7316b56a0fb43b2af5106d4f17ff5451f59b1c430945cc555c97512b2ed12732d86cdd206869f80d5cab8a6e771344291a55920bfe29fc03dd6a44429a98036e5274e15c854a90694914150b12b82d4c4cdbcd1681248d493bf9e22e014c0e261abdf11dfc79b9063b4ed8701348011a98b21f19484e944888e05c4dfe6de66c3b3e5f4f0f97360fde8c5b51b439726ab39c0e5d4308d0407e145112c6c48245f1a2ba0f5928234bb96c963d58261e390c79065cae865f2d8171e900c37d381439049963a92719646636cd259abe76293d1de14d47ba8a434fde141a2975b00824fae422020ea2535aca9c2f5c5cb4751d1a9c4b9093001af488f06199a88623bf3e7028dde2e0643f56171eb3c32f440bc49503b68dd86d37593a58f11f812ffeab3d06383363313139fd0a1371f677347bd414505bb66fc87eec14ab2d19367607fc441f4bb2017c971556374bf43fe6c9143e663e1f4004a0065d3814485959ea7b26d9b0372fc05cca3c59341d4a8046c41b7fc6b16c3f40703ab9814d66e2784a53b1dc506054068072d394af632dd86f545506c36432a9c725f014ec044c3def0914c0392105bb6c012f62e50ed13ad813a6843142b61cb572e52d501d6511bc03b48fe645a1193d5fecc10c2b12cf94140170b3226bd43115b9d87864e1918833e713155768efe65634b1e10791369065ca9c9c189cee1a1b26437864366c924eadd4fa109e47203d841d602b9a09976d26942275c58d8f0a8b2f020e4dd4582a5091df053769c26fa3cdbb4ac920be239e1ee737ba65bf561bd63618f505de46a7c5cb224d67f75f21452d0d953d6b3ef5b484702e12070b6731d91a30642376b304da01569421478f355f557c64033b85430145d48d436f700f8b03b1c80d2fb374e941daa1ac6504635463f3401e3f2b229a468291356403cac4499af663708b58af46bb00d3248571e42539dda70d5d9030582878d01c8c6993680f22826b89ea095c71c4e6018e362210739c5941dce28370134f1967c834b25a4c4082631eceab0f40194d2a24274f2f3df6c134faf38b111939a62c85b8873f4c2f3d1e3cf31d3d1e47da6e0dba794b1be557063dbcfd2cd0c0a613d99fb5481e173b0c3f67950b4e889d4829393f75bd59161e82442112af7f2f2a31bb05614157281548d8865dd2a5f21545d7fd396b4ff636c17c7209e9c6e474cc31345325476d404c2b13325276e7339ae2e31ae6b2bc44bd90d626e05f730763313a23ce262e585c8615515a19eb3df3131843eed235542b9cf35bb74f0607b850960aed7d8224a5e01731775dce2c367c396775aa5f2d64f6b6112668cd3f7c1a2662f907a640261ca423206e2e74d92ee84f3b8b9862cb44e628d70fc8535f5a86603d72696ec9fe9506f70524734eb46d36a2ff896dbbce413d8efdf024df9371086119f754fcfea53565176f140678cf31987940301678b220e4dcad694d53bf2a12da97168453985a5455326e2550df6585585252ac353f5ca776de6446f9ed625f1a583799dc54336538a64d03f2090310490129d306375712465255ff29b314f2572b67cc44fa2f06ad1a275a38791b5a6f9a44eadce103a879f30527faf63a06e202382806d103bfcb802ec3dae411bafa820046334754891d9b190d6816684d91534636727b05df28a838a2687c59e6b9056080466b2d8e35b6240857cd5659716c390e4a0a1506f2093fe7018d5bbfaaa9290e9d7335bfd3bc715f20d56077267124c9b5e01fa17e5b583bbacc6cc17e2c6bbfd5a9281d835e58c67716753adfb952f4a2f7755b888b0cb02b983ee9e9d21bbe3b184eb86fbf65f3824f1c47d849626e28e12dada49b68477b5258ca46d930c7cecf59dc7cb16f67fcd068a56ad30caa4c89289a39fc769bd5634cc3773770eb3afa09695ffd113ae1e933f10d295552dca645ee5650290e3ebb2c04591e4d86cda374fc387550339aff6cf2687f5ab3979247ce95655b85cd762996d64b59feacff556a80ab3d94288323fe4fed4ea8ae140ba24e61052114832040107025cd36bd52fb5f1040e935b845ba63185608b6ee2e15e1fd2c0f468211c7a6891c117a4d6ca3f95e773a23856edc85f60c45ef4a5b18d2bb4c48987460b5298b6a3e80d3075bc8822358f5ac1842d17850fa8b6b4210ffd0679de3054fc5ab3853ee68625872b2fe6e869cb723676e0b5d85bada05dac1fc28e0ddd23aae77ed5339c709024a80d60606b0241691d06d74f58dc106821488031ea0f11c7e96256cb21b6d5ee4b5d23c49f51b1a513de176f87dad153ae2205051f69a1c22ec301d9500ae020c2d391d11d5b26b9e3d4d26341fb757d6fddc765052d3364a2c5b0f0475bd183cb7273ca063162eeec256540b2a78501883cf4c7899fb02e771d5279d6e105faede725cb81c8f63f6e4cf3666c27b081408625d47d55247de7ecb0e8fbe7a5f83267357f3bded6c65475469e43f651c86759a5e0beb5977545cdf5ce407b05652b5a20c2396112f8ee4f124ec4cab1bbad15669210bc46530cd6e10bdd6514ea8554f62195f646911b52c7408b11f1249f4923b6d4c9c3fa15f134397ec4106c7a20e60b0e7b70023965e1ecc72024b3fc6716c0cffca047eff9746439fe633b497fe33f5e9c615d10627092f656a14921d8560f37d953fefdc1f19f79ca7077e66cc6212c893353856b91727b227210c3b8d67ea3ec26bc662c428cd39005b996788731a240c55f3c5850b9d219a0c7d0f440c44c92f2656557028ec7f5e67dfbcfd70bd741d4db6b801099f6571299f21231e878063502b1e3e1a1b7e4c1f83e09c3e8f01b927ee97221f03318513ad33dc30472d2764bad3b02b96bf2b0697fedb3c4df9b95a1482166f08e7b85910855844a093a724f77ff850056792452e04302fdb91c665014bdc73053129272508e32fa5c6f902625ba61cdafc7b2a9b7ab1178fd7ec581309d91b3acc7320415c0265dea91825f715ae12bdd56e6cf8402523d559f658a01b060cf0a4ac373793920c3ed9cc497e19fc141f454a733bf8c7159ec8925565266e545a0d9333c3262a3e9d14f309c9a9003aa8ab8a5d73a77a0021102c61c0e8ca0b341faa35cc3c8216fcacf253cb36b13451449d1177749a1cdbeaf1332760df76f91fe529980d9c2f44b7124fd2a83366ce1bff0477f2907502e3725845868402b414e22df1e3bc0a4b95940023591406b5772458b7353e03fc40a421fd9bd8025b302427b728fb5d22c19e50c67694606b182a15eb1c2512a08653345b840946157adc08525f2253330d176f9590ec60d771a0146c5dfd38b7ce342bc8c56542e59df50ffd055c6d41784700c1b8c7772beb8f16d90d316e70294d1260e29c1c177f595567cbe327d3917b0f2c6a3a59f2d2f00b3dee68544c59b05126be1b6958d5373df0f1e15b6ae1fe5376cc56278c32fa4feff9213b10c6e147088cdc4714ceaa2269908c261d889b72030d16044c13cd4c163bb569043a9123e5a48b4ac414855c58e9dc2f8b918f6316bb2409d4b05519d8d2c602b06c0c63798e4101655f441747f76e1146399d4f1284a52b263afa1b0ee0775d995d130058c2a21e4ad7541dec9dab0040c7e838f2d98648edab0e6f04ee3473f8f3d056e7d6477637c8b537cf9d9956e94d120dfadbcb271a3e021e485c210f8dd1a2049214e975ff6e305b755f1135313c2141aa02c80fd21bc42f09f0544f507de30fe390451a2a0e685f9f6c9235528ac900e3710e16ca933a59df91740a1ca6273d71f0bc5bbe753937a04e1f77e50ec71ca76c7b077c4bda54242e43026b5d4e115203d1302e2c724f3af82f118a9dae06ca142055c1f7bb2a7b049819784e502d62b8ce16c981450e422f8a3ec814225d83d6f86929d02521ec9b5f3d1d1ef41ef05fcf6b9f2f0c3b10825f0b8685615f2039a45c3378f717763a3627702ea83478e47f10be00ae08f94d9f01e24900056f2eea38f61e712bd73bbc27ef94961f26518a48f5fb463906deec172c3706113ee869172e2d9b47ccf5e757a18b463a6f09364ab9c34e4e7c33fb5abb8780439b1a9f3fe320490e56cfa061b2fe2130dc6a9e714b03526a4072a870f18c93157f5d501821fadd33e1f62d097c94b34bf48d162eccc93e4306db185a059da42d544fa663dab15e5d1ddc504199a48336f677ee74d772fe64130ddf6d6948115ffbf458470bddb51f681fbe2e0f58ec3eaf47eb47e9c3891bc9b93034076d602a9ae8056e08a5893a581ae04de3f81a556ed41301a15cef61dc2ae32a8a649c21e3f2ec26c9a12e102d5cb0245f79b4681c13c348b471176717cc7e17dcb3713b7a6c4306f31e5d545c6e89113b73fd4a1baabf3b833e37514177ec5bc59da04b1aa0ad0c1aec9a68f043c9320efb432047858f1fd4f99a09c487476b3a81660cf601695bf572c05ee25b5152e5f7251d6ff5a321bd5c011ce85c1308c0f9536b2e840163b82a2633371f4d37e788a8261ff5a5291a6d6b31ca056046415e98125d344b627c57640242e7da41c8e4ca6f773f665f0a2f1555d600421424ee9c4a23e3e576dc06fb71e40d830205e7ef43b8c84826e3711f1a57f3e45568434f380dd60e31673aa86059be386e89728c360d6b28670d7b6e2ddab0b82b4640532da1e4ad4b08b635008d6483758dc9883837047a2ebd428957ed456a6beed58b0b9595c8503422835465f0ee314da16b7661548d531bae970afe23793349835250c8a89249502a46712e632a622e91fe1893e38a48f8ae47156e363517f9f4f94e06f48d264341e6485ea2704944ca1c4bd03c140e2233f34120aec4763406f15b5389b602aae889627eca5f75f378c5046276d73e50f3d2534a06b3259900db6d357ed25d146434151474d3492ae0c401aabe3922012dca24ebaa3c0c9781b80f8f243820c5da6134492d714d6d81a304330ed00e5854ef56aa39c13eb190964c97a0d95e3ed0e0737b37ff432b891809c13c7b3edb19af1e4a098664a63fa35fffc4d13c1acc96054cac4366b96ef46017d00025844ebe11bcd6482eeaf44862f01aa05bc33a383f0f50551584c1bc5db469f6222e82fc3345400c42d3fe2b73206fa44c2375553546afee1c2dd9b8120b072b286557500f3f8f0c3504e9711730e12656e0bd110ebfcfa070f9b28020d248f441ffaab94624ed2f390ac20f34ee6e7d3a46322c2fdfe4e123988c563b17cdee6d422a15547a1fc20f923e972180f220018193272ff72dae53043adc65d91128216d091b5a1635313b80ae4c4201ab2e4ba9564f407e013027de2d4a6e21fa4d3e3e06f01d5c75cd423d02404a32079f6f89bc2066887c8d508880bb021eed5f775c023d146e2333131bd0234ebad70a555c00e728bbd7ca5ca1ed06453eef243fcfe4022b8db3d14493d9a11bf6c39b65b6c7dc53944cb424859bab1bdf5be6684132f56e46f12b6ef242706f042b0a699a8ec958e09df439d2c3de241b3ad65e38800c222cf53a69ed9c3c685c0b1e43cec00451579ba744299f845b19bb1a4bd8c7cc6291d55e5efd781274f30cdb0d39285925a015ce0618d91637a9c1b94141943d1e607c4d037c8ffd3bbe4a2d546c560b1b7cc7186dc2618f3fb5400f2db238bc56b7d3ef19fd6ecf02eac5315bd3dbc45857e4d40f2e5daf24743cd5588b64ae338313737185d3e46d4dfa205d56c380039cd12a4951e7756c51c848287d9532321bf25c4a6820054f4651cc22eaf8e74737236264875dfc5324c7ec3723939d55fbd9fe07ea91b357dbde7d07c3a6eb1c39ccac082857df28c44c6f1e2bfdd845c65748152872ba398f0ef96aa3f8f652c8ca802e00206071d12ae64312d3ab4152959b438a31436d18b2c006fd766c571292c3146c9b55222423d2361895e6004cef9d4b39dfd2002334b4539b7e9f52cbc1656f583a992de112d0126896796b55b3dd0300d6cd36051ec9013b657c180621615638f00a1f30fa931e18b36e6f072b59293495e862f4363c3c54a0d23ff0b22012a64c2825ab35ce311e95e35ec79e242200ee9521405bb13a4ddbe4084661a007059fa20945924269d7298a5e9ec81c20681a9a5f25774172480c1716ea2d475e22f7f5547d32bc078479c00eddc50a453b9b0b7144996f24cd35920032d3a6193e6ba6707b47416c183c731411f9a374893c2e2aae94d67759c43b60ffb8a65e8308175fa23822315174ae61ba2d52192b29434f8f00aa6a7f49643486300036a2d1650bf0ebe73350f340407962fd05b126af07b58605210d0cfe4593cc4939b9bcfe10b2234a3434c37e1cc562924561a7c96a81f39413e4c0d623202a3a38e26d5f076823681c71dd145202a588466dd8706abfab952621f45651d439d9119e4d644d0e14656acee168629e56024b58989a39562e4900b66b8950fbc6f34c299de95917b0b24a1a3666160b6e27236dd84763fac8fe5550e0f1579bdc2c3ea6635e4b3cfa49163becf44c27541c4f9e85291598ebb1208a8a5229506977184062b214cc2c003fa3024b1490ccc815c95c603c6cffe76b6f60cd2a11525f3ee171b0353174e434fd7b190d8531c342fb15736e4fd97c02fb816c257b174714c8e7ff6d0afd8c0e960e46695d5aab663eb952232cd4df4af7e41d5120585408385c4d07b8f68465e2fb671737a3882087c97c0912f744767c39f1693da96873aa20161f1928593d4ac46566a2e2236ec6b03526ee213b2eb7f107708259d753ccdef420a8b2d62cdef3e45465b6e004d63e20327e7a206aabe0ce1c39cbd7360d018654d8fb4b51ddd17a482da57b63ef7910398614841ee44cff2c2d9258270c9017383b295f6446a504189d7fb246c92a6d5fb3922c56fe8ac20aa6f02452cb2bb222975bd13c944298193825f524ea9156544cd7664ac2573c710c236b76f10f696e0500a5162fe52d3bc65edb723b2a8f6485d1ca2389fb69328889a41cfbf114021031b30be3ae1923dbb02332c3b691134d2e632fbdb3025b13d9e84d0ff90202344e24604fac8141188e2c715c33a76c808d68717ee30a4abbfd3c620ada64149a2eb5223d7429503e4d29688478960ee55b7c45efb96539b16e346c8ba339183449886700e52b36d8782226b2f165461ca0803c633e7131e39cec2078572757cc97da698ed8b933f641a835b2c3ee48df685964267f9a0b7894272f7929f9246a10ec6e32e9f829e7504b3e774d49315e36a9059055593be6d15469e7eab7030285c92d2482002432d4a16c4d26472391874e4d0b237b70a76ff711dfe7df1dc389ca6a857e9a26a9201b5e68f7825c89bab32854ba1f4f334cbf5e293b852521945431a058fc276514b0005f1f1108103c365a562a540c6bfb3f00f9264e5751bdc230e4c548458fb907266f3ad433506b7f61108eca4953c56e1b153b2d07ccecf21cd41aac27b1b476452d15446d10f7225432429a555150a93e15cf1f66681a0d432dc67545314d194e615ff42dd719613cf66e131df2fa6c5b9219940bd3a9a05373f1641109b2b72b99aaaf77fa73f76ccb213304851cec38ae20230c163c2c6efb6bb30333b887140b08586999945a0bbda0a061ada5ec3b3f97156a5302156bf2fef4691acfec6c073ece483c88c73e04d5d632f0bb8404b0d8e558c2a2044c23bb44409119220f3a2f2d43edcbb556cd590228cff83d1f90fc3c5ccee8d22262b9d20e81c9d03448bdb11238e4fe649968382f7b283a66972426420d39c2597671f8155e0dfd746137d81b5e73ca2287c04a570e8f211232fb856f59de692ed93b81366f9a540cd361b96a70bf424b4007895ef0534e60f5664b1dc6f049151ac65f327767d93cdb66a90600ee464af527280295e36f66e074b6047a559a69f7dae268ab2223498bbe6b24e882355531459234418a48595b5407573a3a83051a4ec8298d27821ad43fde458dab6260940e0b21dda4a74dd4524e4e73a4d56e54908d6f8f8fdd63e25c5072a8afc55fe62f0237ab5ebb0d5a40cd4c3cde8d6a6c014a59eed89563dd51cb33a3aeff19504eda12a3236e2b6e3b2749c1517f2a7df7c017002a04617bcc5c0c1c0f6a3f06d6eb04e90c6c587da3385e4886245b29adaf41c810e60f71748e424b8d582e56237d43b48df64e4ee7a81dc9ff9f43d7bd8a14e5623c4e382cb8016c381a6925c393492ea8f755f9c03118dfe1481aa876623d914e1c6d5248311e40029d177650790f7ab3c363952d54590a89982bb053ac7061e7220f90a4a7194f785d4edef94943ad369871d7570669d68a0738d83b1e4a4f12bc472e396c14b7b65a345edbb42189209f1ce8b6f94a77b59a4d80e7c404ab4e633842118d0c40af130c77453347748436180efeca03006de4286e04c421722d994dc469450ce521e84502d4091779d7f12384b1c275327e4b2f1674bc05cdb5d90ae753d222d14afe31c3862b0fca7ac6060f5f0c65998c6169f7493f4b13c5a960e7f05f45be46de19264f97022f882d6d24d9386373f03b7754af6d6f86525d023890e33b8e3b1a5aa0769f6851c3b112ea5a7444a6a2c23d4e4d966a6d241d0bf4e5a70dc476d01ed963e7378507cf67a5670a607b3988665891580dc11ccc494dcc6b46de62ea14950e6335f8d49744706ee8088f1bc03c9fd9ef58dd259335f324ac6f43842d05c6c0475a57814c6f6a2b195dea05275973c75014f912f26c2d217c360564ea13a552714712e4381f344233248d4414705c328718dc4f4b455a6c1a577d5f685a6d33447211159c1007c27037ee86aa73489386168d55326a09b2c041bbf4644fd3eebd303f1e1c580858da561064d77785e3b2086c5fe01aede88d74093fc91420629d44414b6c14903c6673db59a2061440b2702c17cc5bafed1d5da7e96d58bafb7863f6ecad1643a2702cd15ed11aa89822751280623c6cfa6e0732a25d4d11215148304cf123a9467f30a5bcea7155ef3c5a1482d945ce44ed211b3e8c42a178960e5f81350caed3560b617cb57092ebff25aeec1c23788d9e704efec072686a6357972cec51ad6ebf5d3097841e8e0e4a47cf2a8a54849ee704da05f41122fcf250658f3f60114eb62ee4e8af4fdb77d40e57a0f6018aabfc1476eca440637bb44564a436491e74196156964a06c21472468992f93231a1c44bf6a3b9223f51cb6df1828d421a84034283bd5e1e46062c4797761030533cd1365e656d671c922a1615f5fb1df6cfcd05f0aaca0cbf0f7d57776bb8379d667d4846520d69ba5c58689eac723e9ccc861603c6b13ad6a1fd0babb84e34dd25ab420c8eaf22c0926c4edde86462f34c3d1a181cb53880197f532bcd903f45833d6fbf4a1f07037154429490b662969cc730474e3922ae823a1cdcbb8b587b8ea207928f4d45f3c8d94327e5b36c22ac8c150af1a72339055c5486ef4c0ab39e06126038d364b684e34c54406d5555778b371037de594c151618815a864af76beb45ca17104c32d4ac42ea404f66efb8177093030077698bc05258731039616faf6f10a9216406ea827565fd296178ffbd532b1f6b4e78adb156229641715f7f6664e8405741c8f5eb0a1530c56b73996005bf726240a7bd06053f703f632511f26285f9ef42a1fb055a4a172773bd37c7651234d14f07bf7f12c6482d0e7e2f341ddf224e4658d1b96da17b1d583f536c3d886bf9457b2b2d63a662af0103e0c27488223d5826fe58117bb5e558dddc31706abb1d2c122530053754764f0e8b614db71e956170969352cc7a10753f7138091725770b4c834b712a7d82333cb3356be7e6f630d18d4c01877990375b1b606e7034456336eef60a1f9a5777de78df2bef9de9030153122e4d458b38fd9c905c447b12479607d50a944a200cb0bdc0454c1a462eff4ba31f30fb7824731379151edd430e16e6d13344300f35ac921866a2419b628ee86802dbf19934c27812737015b550a7a3fc391cdd521d468fb903ae8e7a2fcadc0962658e504c95bf412bd398af184e0e2c0630eb0d63a5e6d4718f00d966736b862182f54f31ea731c33ae0e913dc3485e2e4f936a39638214072d31a84fb63378642b332a1674b18756b1b50c3f7ea5b33bb02c6505ee5624256f700e31e072c35c0d8fb01fb1e8a809f73b2d4c68c8ad09129198745340fa4e56558237d32d1d65e3a2850612d11518a06ee964547cbf7187984a0cd0256b62eca6ed6c3812b1025f2d1923134ca337a4f0b746cd507e65451920252a8c543f8214944936808f397e7d04188f801706c01bad06af99784880a8a0337d87572eecd7a301af109031c3980c2bbe8d31443f2ca073ffc9747211332a257561932eba9c682c0ffc0d776caad15c9947c02a4020f820ac941147f95a685c8a83042068dd2f08fe05e14d99ab296d3b4d7e509cad93652a9c56527a6e29548eb5b2418bbd67277138d204150aa52ebf0d62412c84f66c13da33743f1337079f70d4731974fc29224762482eb388319f54a725e5d39f6aaf198f34c32bd46360e8ab399f9f7927f04cd92644bc600d8284904ad91a2f145ca529041f30f851943d24276f56f257a5dcf25764561f1db805c90f950185355b2bd1528ef36f0ddd2e046b20464a48ad34d87025542772aa87d231b2bf8352db67bb6305b0602f6f78974f0544f202c80b7a23e48678301bf4822dfecd136381a736702f80321a1519d026e14f46652f5cdf1ef930022f4295972187f0ec3a153239197bdd7610a0755e16567f0567598dce42f10f7f611a21f105990109491caead3471ed1534b533a959ae31ef6b273bd45e6b053b69a0e21139231f1b06e21af97216f61a45e9ee17270f023b47ac19992bd27ce013d900911159f7e73341e9b23cd2019f487fdaca426063f82e4c91eb1db5a7897137b8ac1b0d94844699e0a9034219605ce98fe818f97a1c5b6251905dcb92327215c64450ff72486c5a69ba5d58ffd405888d5a50270ffe701eb63b02075e0530d958054a89d0c3496a21ef1f788b03247ab4622564af11075b0c780045351d3728c91a162e64c17629af2667cbc22b447c63bd0d48bf1b2f70273461741e5e69a0e6275d04838c1525016b421660bc03527eeb74045ce865d59385243382126f0626f11e1e4a834fa578707690047e75a84c254a86153909ac850e7693077a3c8a55ed5709b5a861bd223f19240e081025abd63858d079449b79ba457ce0230b15ec0f267b8fae3f34b45d1e8feedc51516cb73adb4a04241bb72d0a96b50840e8a7764b704d4510782ecb40c0878b0323042e50df5c0f224d972958d37a0916e0a0f752c704bd5ebc6fed55acd2fb0d5f7e0737ff3c2d035621a3032507994d78a17c6d1b532c02769f73608cee6a44565c47019d53c55515c636095cfbe019560aa149bab44774c1e9a42a831b840a9c3b360e5c8afa4bc38bea4d95af201b141c13496958060900f5bc652fcc2061f5677a5114fa7574c5c1976f28d7016a5458882f54453a40ebb5ef376487555fdde2e33732687d0b703795580f9172007667955ee6d8540a5fe36e63242f2a6bcc752b4522042a011628ca33c9e3117146322a4147d30a6d4deee2279cbc4f73600ea9429fa6660fe3460c5ffa769e0289525812bae5c6504a6fce63f2a3316e54db56581fdc074a0aff591a3948713cedcdb415d663dc2047a1ea405eb20d6045029f2babb1c03cd1244313ab19d12b38bf88006045bf1a010a696bf870523127a00832d6be10703a21595a07e49d1088ade533387f492a258bca49fd2a116e3efe806e19722a2d5576c65843f28c529c11207308b5ad75d6219921d71aef0ef13ce9563ca5390d0405732cbcf587076be2f522d842eb516aa8406320a6651b8515c2136e8c372140034c4d9dd9983f73fd6313459b873dd8e1cc01a578e717f60db0768cfd69381290f742768f60614a5c8051a02236614e355c5249d64b190c127f693c74be550dba6f63e9122c2e740cce1d6f7c286279f57856d69909064aaa01767e6b2e4c09862e39ca3b450c6afede44e9c32f574c52a72dd6e61a6501df60241314961e940a524c232f5e1b9257146e4a37cb366fdf61151cde806e6c388030c378c510ff046a7596b58b749e614033a4d6652021654033ede1b549b8cb2f5ce2b7434798b88447bf0406074675e4519ed38d501d3e1e6bbef82e2ea919f0414a930567f4f4ff2f0f30685d5d4e7d2c7625e2252428ae531af9390bf6509d5d164bbc2ab1ac5727e1f93e6a4095f0209a0f2c0b037f641c54271e504deb380ee7538a1d583395428111304fc7ab816b89ad572835ee1e145c64530f34c8281e64a946167895971fcd6831564d847f19e5242d422ec2b643fb9155695c88391889618249f72a3821c62c380c0c4085345c10732f72656942c16df65ae63ebe5677385c398c471547ee2faf2693467d04ee0ce03e61bab36b89de3e1907b77a661c413f726f4f0d3c10a402720fbdcf290f51a97606554640af6e3b6cdc59865d218cba7477db8b316640ba33febb833565511c2befd90d21547530228c7c293651a9d313f7d5f55be2ce2f75fc4233563fa55e2ebd76b71f5c2eeb0e6c54d72df165396475a6aa2388ae873772dc275c7cbb99461d934f699e713a6689e2f077431d502482e433109ebda508c9e173215332084cf889ff055bc72b15e942c53584e05354293ce41e531fc342578a660ce65ac172b678da3c11ac1863b99db732b83d607185fcff6f3347335dc460a447bcdc526d1901170d96287d1520796338b41770297c19dc0246fca8255073cd43dc2ed4342d718c251cbab1194dcae4540177e60ca3361c6ccf45a5327f88220ca5ecd030fde1f4154677ac69eff28434b3f10208a56c593990ae282ea3aa8a361ad4e60166d3a008a18d5224d5502b33ba9a5766d1ee034467a55e3930cdf0621d19437565a22b5580e5522c1ca1bc497d1f9d5324fc7f576fb6364bb8799d36d6920c1b4645563b269d9e031e63a021f61974364d890321f805d16fc3c01b02bb779b540d1a305189481302101d314318625b2316962f54bb703d5090ca4855ac3c74300b09962bdeeb55438b17665b77ec32771f00bb4530b0e6248f023e370568fc07f9adfd141ca0f10ae1953363a04fff10277c106b72ccf06487cc47183b4b350f5bb7a2515f7b9d073e04f4581f35fc58a1d2915dda8cf81bf30d8256729a7d29b6276370b81d69466eb4465b5a4f7c0674df1f45ada773255fff9710a8e8ea6b9282173c84b4c2494551472182291e616a96c021b84469338916f7701a6b8430675a5c4773df155a2a2903260f564927c88b394cf296532adf34935c954d5866e94bd4083135c274bb27a032ca9df530652244681d7339429c73bf4f2af6ec0afd74d06ce4bcdb4a33e0b4016040473375b39b267185f54c073b61154c6ee905fcaaf45bf9f98307e1d349508a20f04700403f438bd2fa56beb7c7142b1c2a3347c5c3566bca7669e8631274dae94f114d1a243a6202ba1a4b3334225402c32105db28685816da61f69f226617639f34aed09e2622ef7304c7f9484630e72f11c5c89d5b504e060a671c4648ef055c499e6d8e548c153d1c340d3c499f4201138f5ab9092a4cc007034baa5222cc5563fb60c273b40f0b09f2c1f82979add64a8c399945fd95ef57ca5b411e2be2d07602dca33149ad2523bff2d168b3f43273af18dc538dfa76019a7d526f7f3e6b6a032fe704b5d0216a5b92e4708a01e63d17f3ac26549c3f45c621876fb50bb338816d475dac8e3201d4bf333b2b8b671975a2e416cc055e0366944648f9437516015aeb305e3b3f4ccef6a21495aa685768cbb75edb972c26d4af091f4bffc33d99df8d71d702cd25df1ac66b7092ce687b97242261306d290fd69e23c8a0fd3ef83e3c0659741715e9da2e047d0c406c15d9d83d7acf1e14315db15afc2b7b08b0ab56229401176f3410135b6865651744eaf62a6a7089384efdcc4869a6a3482fd64c700d1bdb1093afd872106286506a3e42201e750c41bfe35871bdcfe34d564a240ca12a391e75fa2f0ab8656847f0562a6ec61f5c46d708315d0d534b0f53c9c468b9010534b49c8439b9afa727eab1fb24d3f70365088e912708f896506ee05339572fbb00282365492b28433021dcbc629b28773161c6da078d72680dd9fcec293cfd6f12ca13352997e8445207ac1741b286085be790f458efc49c3f0200c85fb21b18506117f220d6060219fdcaa01688f80a2638c68b0ac710522160c9d16d869c3b6c1620c331e163ee0be85c1f342b2f75395e9e7b08eb5edf0ac2cebb41d1c4744d373592570a6f02438b472d20069618540fcab604cb6c401b839e560269b25109672c9f4b7949743ee0128042b46c134a136e55032dd5af77a498484a07f0771491ffcd4773def34a8dc5e83b94120828923fdf47b491f8752e5a3250f37c6e145055c11423db631f11260a16dad57d73c35caf59259d6f496d0d431b3eab3a151c06fd2234f64a107882516b755c6e56d5ce3a65b3f2f819f09cc42447f5486afc17223ee4df9b0e6939c71d15763835552c5755c2a8df6473bce905fc48ec1f9dda3710fc9afa4282188c3966538e6081d2ba38ac676a182b2e5a2dfff9730f2db0f773d6c2d33e7cc3fa344ba628252a685b00c26a343372997772bddb8312e5014d43a46de34188538a15a2af647286ca612bce43452993e2c112ae98d4000983b20e08b528629cd72f0780b3344c3c1fa300bbaffe5bebc2a227433a026209fa42601404873a6870c53d62e42c192f80d63738823a05ea3fa8644effe3056578f20f6237992ffc52e3498f60e3756bb6510ee4be8a6aeb66502290c6862bb73b344dc4b5ab63d39b635a44391c305f0a3144ff649a6e8ecb913424cf4f285b0b404cbc65a72892b1463d3f6d6d5fa4cc3f548cd196380f1e146e4e7b346865e45004971f19501369543a6c839715fd58de68bf84b2659daae55fffd7f7157af805775bd2ed41a982ca1b8b6b2e34abdab6760d5d42533162f52beff4054d5431883d01eab70e27f33420b3411868d9a38a604c9468161b9f69664b1de7521db6e254c359541e4f860d345405b5298f77a82c323ab95bb6303b413dc63b4782874038ff088271a27612593f7bf95fce93867354da78602422ee1c573ab20556a6e60a3b788b7362aece40f726e1183f02f021b7e4386a8c6fe52cbc0c775c00d43809ff3598715b9ce0018a3d210cabc50d4f7b8c7330f6987943a9ca4b3ca345b76481c1e06f662a1d756c99040da0cf2f72aaefd246dae1f04f8114e04966f31f77165a4d16b35d2d3fb934b66ddaeb4d4e6aa86a4e471756582353ed5d085e486bc8af1762a1de4f685c60b7371e6bad2a7e52ce464359db03f2cf3a30c0b10d4fb92951194d8c64221163e7596bbc16136290964d321263501445c3550dfc4960314c17365fc5294e8c0f9e6923467032953907470d07bf57a0dd80464568e949c43914705ca0c12e8592d906c1c0e70170c75e481039a448c6abb90a282b173871d3a23cc7cc5116c420cc2963b9473d0f350668260ad96397fcd706e075064ad8ee5a1b1c6db733108c2c607bcfa876cd83a63a3bb2134b7f6b753a86055e0bb2afd31b75367616a0b4b561cc75ae09c03ce110aaca857221caaf31403fc24311926042975f622dfef82b5676936f37e2130f65982dd734fa9a2f2e8effae4e68e0442df44c360368fb451e418dd3208cb4513d86b3c22bea69c05b3414814ff718373b6f4025192691ea311501d46c7235192e03a1903540bc8677049efe2b04c5f12b787967380fe0382e7c20ce0890972126a6d45847b8848934a0b0675f9fc3d858e4a17b3e018bc8383c40446cb626542ed46fab67e25e696aaec786238eb2764cce6fa933d698573858b94c57168d2b6ce0872b23fe80492bc30f906323cf8f5b0f9ffd202d77f00b2862744833adb40c914cb5530b78ab61540f0d72e8b6e760c29b5b018db0855ce20fbf6d88eaf21d70f884226c966c70c5ab4e060227014889b3d41f96a081367b74025dc2103b0d8e93853f5a40520748c14504034dfc522b10a95dfcda3e44e20da46ef7932169cc453a4c7456161d3685ea22b7ec7d5734e2aa493803c138afe08137ef45cc135798481cf593bd3a2d36fe5c836d8414dee3fc761cf6da2229964f122ce3551550abba6c6059392837b3de1b79e9a36f1bcfe21310d9280ced9ed85d8ab61b318ec80f4449e6d7330e5cd709079a9763c44a01519e626e349b87445885629a462b2baf2a92b0d711005663405acaf70572856b1634df844e0ddbba766fd844029b20b441fff71d465e068447c29c742bc878a077a6d13c2898d79143b7b48b45b6280f553447f83182045f35855e8f68552fb876de995c51a893976c60592227a39481258199153d6278003cecb9f85461397269cf10ef3ef437753768882a5ef2ce1e266872b605dfe296779ee9632134c8b6121a159803eb9564507358921b4e69426e512ad104c3006a28998aec39155e5c118db5ea6965409c20776d764f0f6da553fda54e63c332e95e4d938f4993d3a32727310059459b4453df651c53d1be4c052f21d53f0531911604795f59557e1f5da0ce1c607e0ef15e90a04c2c7c02d04813ba605ee84d91271c0617754910530f0abee45e35317853d77b5019c1f4e02b3bc5ba6d0abcd6508d3ecd4c9a0181615674e752bb214472996ead2335a81b191d45330c4297fd33f5c7e6209afc3715f8cfd34cd907976444d3db26ffafbb255706a123ad841f6081c4ee34719f0e761725550433bfce2de7f0824f0d1b760c89bac974f1d33236f9c6ed0a268db06a5f6d5d3327fd9e72b854385f54551708f201f62db77a9a017ddd041a83d8bb6c711f940da0cf2f0e8c4f4d1105092922027d6423ef9cc801d3a56f29363143555f0a625355d197701a2d3e74158ec3440e6a6e53c6a3e932a6978f4992c566262551dd15f446304575767513f3a4b24619705d5a987fa945b54b763c2fab306655483932839c065f67946d3d847d2f2dde3b914acadd9439ff85ff24a95a82132f0cd17442c4a30b5a27af256c95192c61410766ea847c57cdc26f6693b9f024a62edf47e51f8352cfc6853593a9ca6a5ca7c50f4aa8a04302e841416fce43759491fc663bd84a589c9f2a1f98680d2c6f71e63bcf90c406ea148c27643d2d6c4e25c90891b88104c2488160aa9ddc3aab52ac1b1462eb01d2a77807f3e15b4b53363e6dcf700f6dafc4771843198b1766216934baee1c2bb2b4e30ea5d7e5454fa90c3e4cd3075022b1c304c01871119cf0173f4aa1f2278d5aa22027c71a49bb50d113b84d2b36dfb04b5c2b0f7d2f8bed914870e67708a4635d2453e98f528bae89427bc2c0186ef0ba14cdb48e3d508643391cdfa43691616d4b54cbd1627dd3dc2b7e239e32218ed14224142a7235d4783651beff089313294cb0b24016fbf2682aed16af1f86c076056338433879dc5375e1746f276c286653a5f7542196b6cc3f5909046106587e65fcdd945f67540f76ee03a718cf63044d7e491c492af5bb0cf8b2f53766bce11b60b02b2ef9897d72296dd06bfe7e505bd23bd909cb30b61a25855916bf9f610c604a4c3ba0661942eeb09827a263e66c35e6484fbe62c3455b531825021ecf64301eb83b3863695c8c8eb42d386f9b065cdca40dc8c87b2e1a271f4fbd328e20992a81018d6d1c2dfffb0f3ccd8f2755c2f6ef65d9eb5322b49fe76b4cce1161055ae70eabff2f1e8c227d4ef30b6258d3a1294a133af36d31c9ae0b7851d74bdbecec2394e53434fce83255bdda0117af50414cee744045b6d1185ef3adfa0cbb87324ab9693d1edc579f6dc3498e2cf0c05a3cf2fccd3e9205da3b41f5ef406d5c7f0245f5724ea80856406590d51db0eb2f12bb491032e6de343f421b3022f39d7f552a68fb5320a4904e28e0a2128cfb603eaeb368284020254e8d8f305d2d86803e1fd5c7246044993024b00b3e0f995053bd8d0e24ae8b836f254aef49205d817031c61c5322114170b2b87a386a9e166d37145e562c8e2162fd5ea035f34f6a065ae6040f40bc1e1d1ea04c564ce0bf195032624df52a810ccf24152ea61d5c1d0584b9660ca64b0ba8f14a59d64bf85b1454b05ceb32194784f21d605be2aa3275ac8212725cf637564ee26fef8da53bdf67bd146d2a1070acd7d5482582922565a4e1622f25e85f3fd876145a5a9e2594fe2c36373c7334c6cbe8064d40d72f7310f314a637e242951fcb4b2f7a8a2eb6683d1fb499796c245f2a2a123dca01fe74a824ccb393361dfd2a3df1f7890284f6a643cd0fff57ba15d31ee1d6c30f96260d3189dc82341774441dc2ffd6617b4d1131dad723379d0a0974f7b4563c938a330865d83b1027c4fd11b0e6625a36518327bd957d11c69fc92cd54a716e1a75de5d470ea215a3a6e75fdb7e19047a7ea2339c5b556292a9f4071954f66d52c7c63c2c14c34b2e08180bd48b62630fc04d3e3332172a6f1f8b22c7370b040d73bb6cc5f6ba1d6b86596b724dbb49761af073cfeda369ee08230fafaf243b71484f28cfcfc5255117692fa6d2f321dc3aab3bd83df624aecd2f2175da741f57152547d5a02315fdf80d378efd0d6af68a7b66b9c0c26705c42450843d5720ac56d139fa926573d9b9266d79177c5bf52691283c8a667279aab532453ef309a277e7511348e441a92288567b626c61f0651b38ece05d59a0eec71c29dcc024dfcd485ee357e44a94ac421a143721601520711e9d66c85aef2f43132aaff2139f6cb252ac76d53805ef966fca5c624496cd6d40439a105e4e48ba12d695c4260e469801373d7d45da0d6038828b441ba8b022479bc9d949c37dbb514a25586a6da410554dd006425a4cb172ae77266969ec864f6393ad6ca305ec07070e44034e87f508167b7651368160466a24dc2e70abc414ef264060bdbb0034526fcc3569c2b83277c6ee6dd981dc3a3275d6588be7d364f13a056167bca44c978e351579b87469f8167e62f6134b24360b0b220af9c7024b72081a7d42922a9e7021119a5e0b1182cba7566b4f717069aee7409ed03061fb54a12232066d35ead9a1332f08701631e07043fdcfbd0b388254173686241f11cec266086d081f8e7cd214df21964367f56d6a2ccc85274dc74108fa183514cd56cb62e64049477a2df270c884512a293cf34c6cfd9240244ede692164e721fd5fca69e1391801e18b2f5575f7204cd43f785032dc1e0b4d84a30d6d38cb38962a4869517378572a354862600bbd5d2c760f197e424d62c5eb9f123609a3081fec5a63a33a3040ce11ef6e067fbe0cdd2a0076a3279767ebf4054bfcb7ba658c6bda5b72d562711e7aa82ebe4f352277443c085583706ecebcce3617d10a0c679ad4229ff7326981195c6f32479975bdc8ac73fb9bc4148436fb36e955de769d78f608c54ed20c9e601c2f1ad9d045c5d26c151308591f73b5d7104bb6660eea0d9602f8261570498a5f617315c31820a9ea6926e479304385ba6dba8d13564081ae3bd3e6cb6ebe8075742b604c6fcb6e7e553978984b578a6c67c6000c662baa4f23aabb6c09e4d5573411e2204893b8b539fdb06d444d22dd624944744354cc4813b9b3db16b7fe104e0c6ddc772938c92370f9e270d6cb870369cc140ff43bab2aec210805ca5c274993bfd41dc3333359ea30cf05195e4e36a5def415bef12f456eb1403dd9e3591205fde75d5bbf2265ef5d684bcc71fb5a10cedf3453f008182f19ff58819f934824e2ed2cb5e0c328a0c1366375440a2d7e66005d77940a07106a58278812a15c1ee4084a6d75b15e3d3c470d10df2e65860bbd23576e33656dbaa966595aad58d51b400dcdc00f058e67d0405d0306309aa4302f5b70ce49f08ffc5b00e93d082e115550e81cdc435e27da0bbf0b9c175f8cce035d51c84108d4d94f2bf7fe3ff533bc75957a136910adc07521fff147ffe5460a758f9573e1021029d36c3e484c092712d10b4374902b74427a8f466115b3b03d9065714a43fe653a4988185a59496b748199c47449c090445b956669c53b1c327f56fb19408db33b0cebd82df2d101115383b0003689ed33f6ce3a09892da77308161958f4ec3915d5cf6c029894ab030b69a337ecd63d5dacab48465caa56122bb92d588c188672416d1e3d486d6232c321ee2114391a0e333dad5be3e632731116df178137dc465f3e6577781dad74cf3dab2a92423a0e6ce5716b2720df5dfa9efb0248ba7d4b84f7060bf21c4d3ccc056e005c9e9e205059e61ee1cf742f67310e4a6404df767492c03587fb6e2ac541ee435dc1b02f724f350fbf0d0c5362a0995cc36bfc20ada6e33ca4586650145d1a778eb400768de8c6355480d842bfd39f01e49fa443f76a35742ecf58322573436d1c8bb22fb0f3ed127fbd5c29ae6c726d7d88d20eca6fbb09e7c6a13fb07e011b81373920b4acca72bd275b771dd258757e898c459d05fe573608684f03ee7a05cd12311adf9f082d67bfae30f574b93785816c02c140a2062bffb44cdd2d44285abc960915cd2a5fa0f9d84aef807c4c4fad9a07ce82e60d165a9b2396c5b85312fcc56cd69bd14eafebbb2c9f4a640a28087d76a7dfbd10bca04c6b03c65a3c2dfa3c5d107a0c2da3ecc209bf46c01e41ddec1023ed79631b3b4c5d47c5fb4fd47d39449d7d355278785422d771410bb02fb81b6745754c7ddcee4138cf7f051489a65d801606233d97d948ddff88120b5f8d5a9552c748dfc5c57191a7e71e5e0e162243deb84ce2872a0f6589851266c78c6b554f3039c3b8d42643f3ff4cb0e2d7334d43da5e3cdbfc13463dc861ab0d1d007a8dc3749e19b128a7109738ef8a3941012f473895685062f646a5412968017157eecc317394a109f7a1be3c09bfa33151904e11e0ade9020f6471772382c247d6f1e77535b0ba1a3f87e64891658611a9f6dd6e3f49004f567b3c6ea0a31711c90caf0e4a78210b2157ed33c467ea09fdce75664bd93a412cf56c19681c26018473a5053b4b623ba508c54711d9b9624adc98555de4f915f08f6914addcd0366ad06f2d0532fe53ebc6a1051366e160fcc13d6c9577bc3c7475ad5eca473202c491b535967ddf0a37d00c56488c6f2aba009b188187061c151725628ec0735b2e20d817b5cc654db0644b5e0848005d0c1700083a3f7f52ffa19d094e79f35a33b49422f87739556f31d81b37647b45cb968b41fcbf693e97c7074bcc2bdb17718a963d4e16a6608e4edd5a5bd18c46fbde733c93ccc94bf6b1b8324d3ca06e2f1f734e8e19d551a87b8025e83e64350194c414e3e7640a50cb6138875a94349e53b93a16621a02e1163f6f73544a21738ce2354112cc58b9c2654ac1d8c313baab645f234b4a70589d413e9b7d3c2580ff4f27f81b821441e8ed2f5e21f561a5f8c8199b6f063d23c0106278f5241f0a88d70a587e5a3a076eb353eaa2d64eff9ddf11bb0dde61959f4922c308dc6a4d236676cc9a66585c74190a5a49e53050e05f354da3ee493fd0f86ba78ec234a5b36e38f2eb1e376fd353386040a7595c4e730f7fccd05e957d18711aff053ccc49913a017f1e077eccec62a9ad5f0e59996a7475ca6743965e84139d4c950415819577dc975552ec8381472db3ad58b891e54fb7bcd650f0ead163ff81cc34da8beb3125214d70dcf4785dd514b80437ba6e20aaaee646bdb0c1614b2805500f28aa49dffa30284193da03d85ff95ea0373003bbb20e1a2f928b6530b3903b32f42c3f9745fe388620273b6ec40d052cb69014404e451ecbb5e443d53ad91705b8ce411edc194af1de3054786a091ed13f6b272572b75144e9d60a315a8c3a8a3a6760cc0fe20ea08d651f2b1287025df4741e3b35911d6dcb42263e64e122e85d72195cee5e00e13b290d515e350e31d45331dd72885863face614e090c287190051b613e9a18ffc18d3caa8f8902189a8751d8c1da13aaa82a0a59a97a58b1188f3778e50468c31110134188e22e374a3f4c1f770f28a0e89469ca95bb50b2d1a93e75e0456e9c31fb6f2944db294adb4c1871feaa2cd4b3fc396fc55d1b58118113bf01c0005c3a241b554c944e98305b2cabef824dc8b26e3508e90c3f927d556f60812e71ce870222c4092a303246925ac9827e04483f7a31abbb4a67f82eba632591c572c39ed547404846633bfd6e6a88b6395d9c052f3b714da81bff391f0c6bb42e1a68830959658b4f371551186c1c7fea306acecf3bbed7ad3ce87f5c75ee99503b3e081120efcad173cff8ae3d51b10c6e200bf75c762cb1204643b012198a615b6381c7591702ff4cfe80ae03e21e4a3bc1203a28070f8c1f535a851805f72d05d3719e1c96200c2b29ef4a7215a12c16fcb8966ab079c76ff888d3369b2e09309874b413fc58c61c374bf64c6964665b3ae67d6a7a83574c5c61a4659b745807d34ed8656e31480e59584c376ab72565e514ac4464d567132c3e7b1e12293d1e2b653f1da0f54a20a2652b2626d31f09f5b4093f6393a2481101087523db4038b4aa4034f08ceb40d583e2451dabda11333c3d1929ab663d90d0b74fe1ac2303cc671f04e2183c4196796f276eac5d060b29f85310e3b34acdbc93393982cb42ea729e493a4b5147d9a5f75487f5080611d22e4f7da92f052f4ba972a8c3c54b86865f1367548a50c63b6d4290f20760837fa36acddfca276f85201dbe03b718ad23881044df440df5307a573bd269502c3e600ff5860059a4a94d5cea671a20f101c452b6432801dcad454633b7972332bfee069c49ce7534db0a24ee2a604107b07c61198c57526d307f1bdbb83d3c3b92d92e3cf5c6345b6f7813e88a016fa422314d5f50f06f679fce27585daa53aa64c46f19485b6c4dcf271f7ec5f43dd305501c8d35a63d1d17d554f0ad37425ee75d509389113d0231a03dd1667d2ab5c5e5329d7e4714c7363f20bb814d12dff74658e988705738b36b3dea212c57b80dd168d851b730974d581764a10d38da367c1639f6234057d600395ef13d028a99ec23689bfd1238400925299f636f234399445483ce4b9f3f2423d743245c675d733a86848c6fd43660759e5435629d1f7b6191a52e6ffea71c3f921ea4190cd67b3d6023b973ca6eab2f86c0a15e490d2b54a80022212d67c32b326c93152c389072cfe9bc1347be0d076b06c24e1f3ab13581523925ddd6874ccf22fc1abd5a7f00247e7625a0ea5716dd92a206e9aaf5658931b05bc5e35409ff25043a5389764b5369cb5afd9be1691182df31f1d388185b4fea37eb2ff210d83db50c299d5b0065c0fc5dfb6c216e4ffa4c29e9171327b568f20eef75ec345183d054c641dd3ae83bf203278e604ce4cdb962d64ed220635d480ab579d53582071f47804fc5326baebb6967da1c0697aa5562d6c51352dcb826174518b544fae0b9492c95c93fc42f60276f8e6f50419efe72824e5a47fa662259cf080d779ba2cf5cb0dd240c7b6b9d3532945c596328cd72927bd6337a03d66533ffb66caec36a54a6aacf3dfe364e1ae660813dfb3f9f5ed2d731561f9e424fcb494a10f8f20a1b0a8f06244b63c8369460190fe27aa3727ec87d2c58b5962e66ec6a3daac1452419847652a13f0154a263e36ca12e2f18903fd0629298ae0432cdde0195b7744ff15df3482c3ee850954fd134414b953e946a7b645c35522c8da90e2eed128073b1e47b4cf58ae7721b2591348f7d9a1df5e393286bce63493c3af56fd8f5df1d81635f616635fe0457657515ef7aee5efa4b837272d18775dcb5060902d8c149b8d8091f3e8e734b95f94b63d336234b1db6a240a268891448e7a65c4c2e107736225f22491d9f03f785ce34c01ef752316f8a0d803541272cd6776addcfce42b7b461314d63ed191f18244135dd9d13832f49185bb78f262e23f16b89007a77588b4047c231f22f8e34083c32a4496641a2882e7fe02b3ff7416069a5a91b50da3dfc7128874d04ed64d35e1d931f1265a0d8287d2040481c44bd707c4ecd201c756e2ee679e420b74be46a261e2518ccc9f462d834cd4a932d7b1e49331c5fb846ae76927b8b39135ca474651871596bfb320636f4eb4bd467b66c74116926deed50530964854c66089b457b3f0e07436f9d0627b1b03803f96a150c33033659a6502032a54030041be1505db7b83b6242094e1df0fa0a777301762da5d724272a7d22e7ce5f09afa4c236b93c6542a2d95616c968ca16580613079a95d85ab5f82a211815ad21b727be4178cbfc2c7e49ed0b67d8a527c03be72c40a6781dc8f44065ee4cf22a79169c530878f66ec523cf0f03cb4417a9385c316efe5b39aa55ed4e81982e5d01a81874669d8a200662e54e8fb7500a790c9f6373c07e15a64a9f0f70032b6ff86e98319827585d684f6a0bc612591573fcbe515262ae6d5a91bc50c16b4a4c5921cb5e9d9b7d54b12fd72ba14ccf31a03e6a2d04aaa53711ab782ec3d5ec6a9751b156b6820754c222dc66d4ef8e02c03dfb4c7b8c11615c8a3c58de67e9083c0e9b70a2395f36f015e647ea715e39349c2a591248f7415e94b62baeafce411ba8df278e8126410931592906e91916773d844bc9dec70ebdeb5d030e2df064ebc9374d217efd65656e18111845574363dcb667b97d54046fcac12241899952f6577d52d3c1d001d650083ab8560d27f1b4ae3d09ff860fdae6f840751fb51ae778165d640177041712bf6306aced3feb0cdf5db0cffb1855b6d715f0b1245278238b3a44213d1629cfb53642c0a35a6046243518e65d2191729d370a7ea77050d6d634fa59bd449a7ffc6955cbfc160ed1fc44b4069c2e682f445b0aadb3408169ad433e83be229b1ec11daa8438451c8365750111e034c5d4386f86d69d016718994adf674519e3b7775a6339da6cf712c74232f9076d5eff930dedabee75057a764f27ccca1fa900fb23b1aa46646847164bade56306db69ff1206a4983dd93ca912d8b0e9592e8c2168771e5f0e9c23d5368e428102b7d89569c7af7b05f920c468ce328b23e28a4651d254d124b44a6c6c42aa27561a99d261899c3226ae431960ac4cb23baf29c01e95db4157c238a7529ef9c20a7b2945650666db614de5061267716d186c827f09d6a9d86ab076295736ac1d63b513dc6608e8792d4b94bf34d4dd084f19e7ad50edc63a1354b159719b3ab53bad310967969a0e196c69a2581d16e12192199908ec73c234007e974b53358e1edf14293092869c76907b2a220a6c392b52047651885bf962e8f4931b1baa76271cbd2f2ad0dd994ed58a8e1ad2a1e63fb8841e56637fd9343e5cd607e30a725497e3933898d1ac6501a6a7143f3da02087455c08fffd3274576e113480d1482b03afb172e2e3110fa4961973fcac3840f298d728d65cfc078e3c7e5cee1ce90910f5837317c09c3b697142534b37bf427ca0065bf1c1006c8cda2a5cf930e246b934d32cad801d77651f4b15ce0a9f30bd54e401ad743d5b694e03686ab4580708cb3211e616060dd52eed602812bf3b25802d0d2de66e63af667a5357432d2c37ab1906466c675d679b4c1af325592c52a8e754f8aa33119c680f5f9ebd794b57a6d001a128ec1cc622dd088ad17b55a2a0d5677770ae55aede1477f1dd264623f2d0470f6d921f340a03231ec067766f141334f2081f34ddb6f531e5e2bc705413e5776dd6b41107cbce3e7f50a764acdbc633cfdd0b0427f4e1760e623f69ec11e8635e9041628f5335357b3ec01d8ac8d75be9f6176a37d135424c431a7237008d3b90c32832007ddd16e912c24489c9ab327a8283323ad1d662644a93159903a75a8322782a41a90b686fc78631cc2c8e27e492344fce37f908888b472606370216ce8fc55d3ebf79244561164147ff02757026493b0726e617e0391f2a461316573d53074b3ebed01318d10148de43ab52be894e6d30378474e237a0745755724fae501e007a7b822e8778fb3d80334b21cee65d43f3e3c80def0c54020dfe0d102c657252201470689d1b3925153cbe5080964970512a5e4909e97157ceedf26bad14ed44fed1b2511d6a4d54bc5f5d13e65cff50cfb7e246d006f955406320125e156444d2dc6362612b784f9559a301ed6d523ad9299d388bb0471891a1875f1880204d131d6f3e926b283478c98f58c72bb626f5101e15b4b03c0799add6195c5830480b3f8316ee9fda1273d252510e828120978a7d03c783d6393adfad6babebab5228eefd3ef88726279843ee190923fb03bb16bb2a843a2c66bfd5bd5f09509138a14bd92ff982c2149f9a704e31c54d5611d45571b0019c01b38fc738a6ea8a5b3de48854dc2dc80702c475405508ba1f1366062396849855261ad41a9649940d2477be2e60a763758788a3253eac133d5ceff9691b7ffb0f462f444e8f2e555dc4d51646e08ca7397b1b222267e7d7320ce77f4fb301b543c15fc2619991773ede996d3b634fd70fe5472a548dca1e191d4b483ea407432fa68bd42abe692b502b840f033346851647447e40ca505d2d378d820ede3fd8585fe0cc478ffedf117dddbb47b85eef2ee7d1162837e73520afb7116180bc56778e485b77f5a5080fbb35e764f4ce8313d3bca6048ba4c15964beee3e7bbfbc3158e47e2757283573d6796a06f20c8b683493cb44ab2cc5667808d96abb35b80515f9eb74ea6c8522262d3b46db3f895d0434cd1da3c92d44e103ba29cdc3bb497bc68503ec51c811bb19b423ca711220b8b84c1015f5e05fce54e64cff6d623dace7761d7108810dcaccf272a7b869095f2f2f12cf093324cb37c61801dc6e428abab156a607b217573d8927396b19207189e929e381cb1bf5b29320307d954e97ad434a48a69c10f36c901a4587681b80ec18485887a8018de03527160cc245f7fb066e5d382142f545c312d6fa0752256bc564747d466ba080303ca79c4073422b34306f52883bd57d376d0585090748a7574655153614e53f7615f8003f199f929f365c5bb70720bd160fc10504338bf4a0183fcb2e07cdcf29699cf97f6dcece4335ee5c272eb23d0c147a848414263c150b267d6d66cc8a2f31349fb32a6603193e0edb4022bd9482078b7fe043634a4310ea2f066c8f78320bb722cf5cb2bc0e5058e8584e168d9f4be567fc680a889a1dcfd5a76cb2cd0e1995e07a4d4ff2d005208a9c4b898828008999583b79ded42a003f671a587e755451f1e02788f2111d77109959a684d733ebe6ea702bcc7d454dfb582082da905cd2f05f742841e16ced3abd4f8c1a784f48d449327737ce0c3e0b7b277d018f41efd2d70498f5ae6f0df5bd72835fa637a8456561e430ef207f4cbb39f0295c463f19941f4747922fc536eb098e81e33b3187cf61bc457c36bbee5d2a3e53e11c11d65d6b3f56a7459783584f8de45c423c4c3e00e46d4213935e6124901b8b1577a23d67cbfebe6ca209ba155f46c81b6cc5ea265073575a35178627c44fe241d161ca0550a7f04f3fb4b45740310853e151ed6e6d94c134fa631467a368551e67cef240936e813bed95276e5e8e390169a83d3875f0f9623aa8151b9955ea6163841c3fb0c60144756a8972fb2c8f55a9312d02fe02041bfaf31f5f0313052ea769091424aa6b52c47eef20860b0a15d24d3e73b89ac30dc40cdc65fe4f1509150fee666d1f9b4bd0673a4c9600001566737b48eaa4505851e4f95ab7583c7420f94e1dd7b0385c006f4d02d5e890664d5c5b5b78826b30acfd5359754fd468eda0a40dd3c5ad7654eedc3f63e10b7199ed670b991efb096f4d9b085ae8a6077ed82515283988627be7613aa8d4a655cab0ea3d434e741d6e4ba13fb99b8a567726c94706fb257719046b4409b6a0614438ee1417791102035d661bbd3ddb70cffdb420459df06cd690b3514caaa24609a6be1c670ada282e7ce561ff07065e2f4aa4120a42cc674b34b51a0e830e4dcfb59a292b210e6947cbdd3f358a6c43d51b5e4b0c0ee5345d98453ac4c6b62ebe51506be5df19270e71d55e1dc72d24cf6a3555322d024c7eb8232102c87d47851111452b26104b2e88785a7139092e766b034a67b00b4f91e876603ecf55581b1ff841adb2b60b47e80f38da18526df299b4305f9ae06f86093d365835a51d125f58003dcf8f47d9b8ac19fb9b7a61c0b2010492f56c3b3cc2d07504fab35cde545e16d466ce37656bed3b2289764bc345602da27fac500d82f82b6186841f05e0232cd9eb000374f2fe5607b0b904284fd90e4d5abd223169ef17b9439c1ab702ed01d2b33202e025ef142287617198f86933b645031b3f0c413022c62017dc7b0b6d15e279127f71714349ee690430e9ab2614231d0a0cbbb756435b47451923186ae3789948ddbf6448ed6b412cc8d2db50ce8803125704504777fd6b53b62f0048ba2b36260749514e2deaf21223b6ba4004a4d540f9aaab4d8b7216026eb41812ffb3b54155ebcd3b3721ba4cb4ffa5648886d652f27c152ad1191c4600432e23f6c0af5b23cabd398c60b561490523640c6e191d98330c119e24ad22842ede0a5319090ef72c2d3b737ae7148b151a5a4fa1d06b1f3e9a5cbb55837106661529b425355bb5fabf70174c091583ad6955f33dde5a9a6ade25f883eb206703ff41ed2c0a378116d25b6babea2eb0a44b3c2d763f00e8721071172c2804d42ed10bee45706e1818d65fa5560816f806b26708c8ea353263b35f2498d671322a613bc75af83514f2756a6aaa200e851ae303c05f8d464ffd906ea7374a410c38813ac87d9072f4627c0e7e22ae2fe3322a5d8a40af37ef088833588e4201f4e4b55b217442186a964e2b0a02113b12e17e180d816b64c3a72a4f50d31401f9d5226d647b9c0f3c0bce649c9350531dbbe05df2172712bb23fb4d5028de26222a1671f5591f5a7b07b50496c3a23799aad537d6e310137b654a2cb2a9b04616368d10db1e6a00ad539710304278461a2543415c8e106ee93c491269f19816ead6044a70e3ee31e5fcc1637b1b540ea123b05c94b16a74eb024855cb71bf2302faac2b44ea0335aaa6093185809a417684fd6e753dcd62e5908823ea967823c351492005dcba54c30d5565f13839687a8f97772292fe730a4c1d00236e9e689d9cd4357832351d1c1c1254db7b003749d6314ddb36c51903144d35a2194b5b346dd02a706c200fb46fd3345f7e4723a3c260106f8c9917c528af2e58388013dd6d42128f85762f6329a50c5b50ca48f70e9b4673514e6cf5c83f5d14234869c88b394cf296532adf34935c954d5866e94bd4083135c274bb27a032ca9df5308b68b354737aad40e77b5a58ef9d426307967c2e0269750ab036915e8f73be0658f3560e96e73a1375bde83f6a101c146894b659e421ad0b33e88b573e8274367ad2931ea1b539419189ed3425a144006185bd33055ce3107ba3c328fbdfc90035fd641c3fa7c813328eff1e0e29c72d66323c1f1f607871385d3c3a489ce5762fc1174f63004a05a8482e396205f35c3884435db21940370bd367461b0df62c96af853e3c53294e7ea55b68cfcf5272eb053c6b3593481aa2abb93ba6a0c210548dcd6c0f171e5814801931e6041303c7ce2d093fa0fb36d6ffe124c822462ce462913f4d63a851558ba0305662d514c82cce385787810e06a65753b8f50b4cc54bc619f08de301d5c520428d20cf525f65a85c305a75700c448b6515ffe32ce3f19d2f7f02ed67c3aefc725ae27d2b7b238822f169033a6ca8cb1869deab347ae42f37dc66ce19ebb828487599ff18a3cc5c668e21f840e4917149d321de608b1d510ca8d26a246e7ec8086e5f4525cadf0831c93a074689ab051d00c3eb227b7fb2726b7d7d6ea32243063e8c490b6acce111b8c37615f2677b6c5e4fcf32d9660d44f017286ed756bd153b98ae06144fc5312be5ae5900992822c07058622fd64c700d1bdb1093afd872106286506a3e42201e750c41bfe35871bdcfe34d31bf7129c0780b55b42df55e008b234ce2b7573c0ede3a0eb8e8280a4002a633b0431f096a060e46e32f4b412a930a74e0f9a5452ee2335c0171cb70e5fcd2036b532a4143b4060f1d738015b8e8276f9e327b3b1201f15907ffff6606092409be0fb0290db2b12c724ea954496e936419a2da1cd5253536b0769313d09ae66199e18e3bb7b5f00238f11837e0d642753f7265665fc412280556ba4d7a45792776ab7b58611ea021d7d11b52da0b1a58f941e23fbf0a82601a23e735efea335d9a00e54d5d61c94b810bc24b39c58c61e08f1c0da7ed5c3ce58ffa60a7aea03ae311390c139b482c1d44ce55a205604bf2425e2555de353f0b22ee605e3f951ceafa7630f5257e3e44f4662c3509a93e74f0ab689e829b73a1f2ca6a5e878956c93ec275cd6fe1296fb9355bb1110f3c6affeb09fc5673197f3a62610fcf5163ab5c324ae8d54b035854f41fcdb9260bd4949625af9e1749579adb539bb7a94f1061d338b992d6283d64f76b0f2191093343531504df8a5e5546bd744d943f3bb6cc7508caf9f3710f9ec70b74a0a8068b35a7155e8f73542269c21a0f0e27389da16d15d286046f4f345f0505b62d5c0c03a257b423b804e9e4493238445c0633801b095ff1da4a05907a52db6e786c4aaabc4e796a9a165d70f765733ea412eb148e0f66939546b50e75731409ef026a2ae520b162e45be9e39b4e0fb2b2767156ab7078873d63ea11b40ad6ba5e2fb08dfa46dd31f23e4dbaab3a1471723033afa0269685e147c275926a6acca932899b871c4774ff620073b65c8cff035a5435171642fb74123f1f5c0f2221293b4cc7a51f4189c9518a72012041b7f64cb2819a1d67603546d8c01a3cd45cde2bb31113254eb8b90a072ba16468c9f8024640a73922da371b92829d467d9b1a40c2a960540700cb5881f19c2e47f3136177e3337370b88a0a932a5c2e58f5d85dba85fc319180893ad7ceb63e9413954b6e077566e4d8fc1d2cb09e4af13b3a52a9c4673382dbb23b1cfa9f14cf23bb62ff8a9a4e7355a84f51de4d15dcaec677c528736982de6e748bb792674bb0b46522148e20c1a25638027ebb5a4955f5771888741c83961212e7a97872b3ce7a6aab2c081363bffe696194356b89c6885905d3a2345a86e7554e088674bef293096b514526521e8407865b012461360a54e4a7b744c6c2ae596cd51e198490df7759572816b515964795c9f14b9494fe122582f405db690b3394f4de3d3d644513922ffc0c60e99148ad0f3417a0d179424a53b9174d09534d25ff56751260ef450f0919676321c851e060017672d8ce2343ac4350800ed96bbc34f83fdbbcc1420abd2a44b242ee72d7c8d32f026b651408a459465705ad4f4dd51d26c08abb5f5b33202368ce806b800a87659e1c8f4da3e3595dffab09476dfa4d14f234903caf801d1051408c524fc6dd31428105523ec4996a70baf209c7cfa227cb4d591401c4896c2dd64e489e77df3cedf6df520c8e2d47e1f6e65e7000135b738d68732ac82f5d2ba17351cdbc39111ba1f948c1c5cb04d9bed24181c92837b4a7792eb925a4229340d00c8674740b195a2273a13a6942b616fa00416fac37f7c9d4626ecbdb4dcb41053f069e5719ab6ab05da0b0e1444d680f6ce1a2e36d798e4e40a552332fdb577f29e55e985e4f9bf5065c9bdc372617647372e10769dddcb376ee656865cf11780027e1084502597761abd01f258bd49b2da3ce7f2a0b27866baa4f715277bd8115f18621490326cf4de49e0267aace1f3beb5c6d1090972126a6d45847b8848934a0b0675f9fc3d858e4a17b3e018bc8383c40446c08055b4980c43d00edda926e51070b6e3cdf3c092e56ad084b7c4c2b50ee8b62f172765701c9b71bc52f5707b1960943bd9b0752811a16343bd4336b5abe2522e85ee9473c9ffc5c28e47348360fe05d30e771750dc34358bd2e8062ee1b64424f83ce6145870a300f3005149761654dfd0725287dcae40854dc344bab00ab1bdfc2725c92e98a284553f62c32fa8c424f06c76a4995ba09e0359962f2c9832605a89835f643bd1e8b9ee056938c9d5caf513a204b289c59e50b3d08fa6a5f44d89a5b0e326dc2111c684b6ec151262c645fba2690f93c6e76ea2b654b941d70816318229e154b3c924bfc2fddceb1312b5b6542aaee8270a556fa4ce3cb087524df03308b64956551a4510cbc86406994be6f0ffd630d50d539b75a3d09004fae35141973f93c38fff92f01caf2064a0a162b662c558463ac34886564d2484ad47eb46b1fcc43504ef687318480950fea6ce21ca409bd144274080a71e4d0737e5b24143d00e601360f96267afe8d2a27f2ab3b40fb1d34e8039821b8a65c4dfca333347098ba59bea0e01a7ca7da5c937ed831a82ed40f0e8a315a61f4b04c5d4492225e6d02025b35c875be37350aced3924cfd075a6167168c106546d3007358921b4e69426e512ad104c3006a28998aec39155e5c118db5ea6965409c20c0c7ed62e5d91e73d585e2266ae1591579390513f59a7e4c1ef343049899d72cc9fb674a793fe0528ea62b56829fb06bb11fde1465ae8b0884f9c2638e0fcc40d146e71ff4ec1d777ec06861890473574f35876df5ff83398635882210a0890254831346b351d62b9382353ec8cc9c06511259639b15093a922119328a8b820073068240edaebd5f01e7fc3583d1952de5254424aba1f22a83725c6d354d374286102f5bc61277412b37182b35301819bbc80742effe526c45e6fc05528c9c20e56f66451eb42c54a49de72add84df18f14f38509951121cacfd02423a64aa2497fdbb0618ec51412836e52111693165c503082db8eacc03c1437d1d70216f4c896b991274519c68e2832143aec633155b2c4712716d6f19e40425450e5b62183c697b20ccf5484dc5bb560a60379f0a0600e85b5af0ba20b8540432c9c777268c6a34353fa0434fd11fe076272ea93c607e523650e6ca5306d485181f7f536208efb75d4a0ab80656840556948f152931bee423de83cb091744110d520f4e2d9b143360a4a2484e1b7e2377b3aee7644eee8436fb05ef3540e6c66edc9553762690d86ec22c8167ff890a5abe3c01337e305049a5633212c726521f82ef4a5b8495d910ab12923f8b66200ff7edd456d38b2f2a6d04c22c2e4735034c03c32a608ae945c21140705e7fcb1dcfae662e6e8b55719820d36c2f55c40076409a483482562e7fa1885d2d89af37ace761778dcee4017b6d2f51165a4169846913370d14d663b15ce03e8dd33074fee6a75f278b7e1caf10cd050394f4687572906da5f88a4ab26eb42b6ce63e672b31f94602df7a343a749a14152c79669abb334972422a3fc8fe6831201e4f089f4c07135f8a481787b10524aeeb33657b53cb4e74e661271917ce2e3c8dac228401f93fa7366b45f9dbd12ee66baa1ca299f43a7344687239cd8b702387f904430b9050db3e9f457d01085f4e1b12514b33af48b955427060c2164151dc2e111dd78202b0d16427a7fbbb2c8ab2ea08f0e3e80dd3d8c84e1218df4cbfe482460ecfc85141e251703bc8827688566f24479f184acf0d82489d78c445661d904d60f3d9017a4aad505e2f3b58a1af6175053d00188cdc681f6d737e6d703c0e620181b30170d6fb416ed5523c8b7bfd18603ec00c195c6d5b04d90851c19ebc4bc94dad33aa320147ace70e499fadf947f0ad322f85fb8f6a51e8ad5df3783b498ff9591b0f174a53b6eb6e3060af4a279d06f33495ac2361ef90a31cd62c3c40f741654c9c98170d14e09b2c5ad6d04618a1e95041b99f493b585258b369896eae65b24ad796874be58f443fad8b2919719fd41ab7eca376e1d82043cc5cd917dc66f90a8c77c36467fc9848d6bae06a2124da024bfc3f454f05b06842224852a04ebe419c313244090c5b071203947674b75226b9a420209c8bb8623e85ba021130b2305b1bbb18d5d2f110f34f6828d676ef0284520262519908027ac24d0182ad2b0ea2152f5db932982fde711f014537f15a084ebc347717c83839f89d46b76c362d19f3095679ef883411973728dc18d016929ce360e5a5332b1669b9725742d55703993011381ae43911f5d81ba8850f0371ae6d1b63db2c2757d84d1a8659d96fe427a96f4065ea15c6a761658481956f106a2e153e24f659adfc855b115674206fa0892c4935f704eb40603c1c3db213308568392da42745b75541514be2be4f8acb044c26ca215885a283023821760fa815ba4bcbb172450df9985ea9eb8f379fb3be089651b23323020248959d9260eb99a22c05aab151f98b8705c530c15c8bc9025cb730c12933c7f61a3cd1572c055d8a23e54eb50db45d9e44d9565447dfd57e3a862813578272a767dc8e640b352a5e287ef93c63943d51251cd5044f38e76c1fd1b0224d618da7408e58c02c985ecc76d1aed26856f1d45a50f5f671fb86551d92a95b07a33f395a2ff5826d319cb946e9c6cc4dbaa60a4d1cbfcd644dba691546f0dc333cebdf26312ac712b61900031c823f69b7d7d62862def0414557cf41fef36b17ecc98502759aa06dc737745e96d5e82e192de2312216230ce926c0098242684e7e82c46fb009f6273c16c44caed6f93e2319024e81ceb126fa214052c71c6d18a37bc85497e7800a6d6ddd0b456e975e6ac34469bed920053ed8790fddad736db1804a6baecf2609dde4da0ca17f9f1785fa0f6cbe2c0f2b6877d526ff6bda1b618e55702c1bcd00f5f3b9588d9c7d51f5664107ac9d62013c3a5d33cd4a710e363ac801d3e4ba358d226e452c7f1959fa53c24e267eb448add26e3a68b3ca2252eefa14adaf075a581f60358683f63ab1330f7359682c52c5f4d749bd51316e8d9683564cc4943669cae367ceca2a7031d9f14fbbad662dcbae0f554d93853ed3497c3fd306b759ab3d8120f66eeb742691c9770a4a0f5d1c8f573f71b01a74c0ec184f18c2f04eaac4bb0fe6e20e01d768e9625ca034277c582f2b78469a008bce125a559658009be6644446ff8404af1c720dfeb32a5acb353654f3537509beb48f1322a59243937e485c79fd3a1be263070a00c22a5a56e4e2337e489d60ea746f06883c39694dba124718948c422d608e4ded52004b0b54980280245a51a06837407f0b575b340e9352c26120484d2f143cc59461062371003dd14ee0758cb1ea354ebbfd3a37d97676a29d7f0ed88636103587ff5fd1ba8b18452dce583a89d0200f92b2405128814a88c6485bd0c32058c672a2532c99fe273ada342617c98135e8876b687621d801e5eee62854b7f25da66f686a80f67277c06769529653802d982b7d305cfe7b2c69cceb4b08c0e52f59d9ae4121560c37123ec4667ff33c4a73c98c7245526f38b6563d0572ce2e0e8b2b5376569bb624017c0e766e2c5f75fc478a5a16af03767cdcac359505a90a8421ca5bb4fd8346eea9e25dc6211137c3f620640cbb8165c0fb0a528ccd425c2b1f35244555775a3968e84db1f3753e840bad0734545e58b45cdf692978fb3cbfb1ea0fdcd02e1b045b1a06abf05f751eaf0a51cf00f74389112f63e3aa1362d0fdd835492cd22c4601204d805085485f3c976a62437546ef34b05e660ae01794eac02e454a3d6d7c326e5b57ec906097b37e63dff43b288dc16e095d7d9f0c1fbf7045fbc973019306ff3dcd6eef17f1800100c0615310b9023c65f91fc70a7520cb457ffad80d1373f86514efb433f44b2d5700033957b798f21ff599f502db065e0622ae122885452a339c4af86ba1ea503635205f179be3e52658cfd63ed3005a4e53b9302b66dd5153e8df473cb285c10aac5b8846489895064f2a61776dba533d087ad148632258536d7772133377e47387919d641e2334394e00f20b3bba390668c73a1b1762c57540ff36458fa1ac60db03896addb2b21a78160a252fb7ff3f03bf7377b0e4b3443ca036073e799a5466f7cc67e76a825097dc7e36a64e95070405982c4ded67412d03312b4943da5b08daf35a1d8e7150d0aac50c330e1a7786b1785ff2a3e6382304786e90778e12a0292d088a07911e5bbcbb26b54dcd377da53f6c9e8411618d02d7138500b524b53ff0286def7a263f62c54682525f6b6b379d3877c7d744d8ae460c536f7c0a35e698160f7760777a5ae13c1e10325d58d2735f44153e18720b2c06e643e1699c832a120068557139638f44fcf14523e8b7001c4649664284da9f065c98ed638b12bd581c7b55052e6aa1036157e265f3e84145a715ce713c8c8d44adf0da77939ece1c398c64602a74e1627c898717cbc3dc02ed8f3538a6848f52ca84f9491d5d86568144fd6fe7bf1a006059f254000f0f0e8bdfd65e7b83f07434a77d590247015acf4f6636ec75e122025ce2779e43e75aa69e3c431b563a3ce9de8b65893d0457a6f0482542bb890ed25fae3d3a4e8220b849fb369bcac94b672a96035186e86e415faf24ecc31837bb29db0fb777d5507ed9b30b93d6801da21e33372672c60c222c4f52934b3c572377553850121361a5ba8311b439a626ed1e355c5d69d073d4a486384dd8891eaa87a4374d4dee710aa1823d94016a47e33b690f0f4f1a72d6ad690f115dfa2111c412690c0f463cf6669310c6127c76af872c283f2d891cd2cbc350aa485a19f3ecf116403c6e297346b24470211f3cac897d62f4daa073611edf694cae0d316a3a086179ff6428944d5318b99e4d3fd5957342a05218189aa37b4f7773fa1b87a6d315d2025554e0b21d12b03af50f44345143aa6fc92109c4265a87acf900fc611b24aa8eb55e97925e1158d6ee5783a38609bba0c27724c36437fa6f6e0d08f1c941807ef91a68dc4f62bc02072976c1926a381258137cec802477ec5c629804e25c070269142bb7f7661bea4071e3f650347bfb0828bd56e3377154a6361976ab2d55a6843b5637262b867d8b0ae2ddd41767dece5d3ed5141ea9c6a53f4ceffe32e6bdf53c40386613fa93f3288ecaef393ea3b7143d13ee0f718f7c472208e83b4c0b626a55f2d63a6915133a67462961410c63403582b214ca201345128e2b5d003ae407c64471720fae8f4c768dfd4ea9245a1804e74154b6bf9d724e41651143519654f5ed2d1e5e93ad26510b0f3b76f1244105111e2876bf9c6f2db13b1a6f4b697332309954bc16dd6208953d4ac004bb2d22502a76dc695c39d341b62fcdf1c65ba39b231eb659571bedfd3455415b0f37159caf2f4fd4790d7b2cf24e6b2a954e99985c5334dcaf04d01a8b393c8ebb67df3e7f1c85cdb6681fd5124572449d300162100b2bed63042a9274164f8e99605510870b08b4e14a7e66441b2abc3e259bfe1a7308a1bf685ee1644b888f7b17debe223473a95836e007ef08e6c023085b1194704ef6220706e9fd19ca180a71ca9ebc630505f65594f6fb7487664351e89a4b04231cf513c38a20370f2aeb6e06cbed1a499e4e3e0903012b18cc6e21a214e642f86bc74e02155e5e3f8b4777f3a3f1770db6c81d12887609b835b86927f293731fb05c2b7369f066ef433c35e9e13a26538c411866d723193a513159c992b02f93b43b6c177b840c3fa7026a42d57618f587796fb497f939777ee05274808323022dd31082c6f83cb6560310d649676fbe7131712a18131194d292227788d458f4a05d59bd613b59ac92006526f7c23448d062253821033ddb2e7a04e4055a03be593a29ab7de34c167d4c218d99f8270c0aa02f916682356098005480a18f480137e82e4213b015ad957845f3ba5e51cda2d54990e2ba188b4abb2916f3a62d16a5784446cb820b857c6200b8989d2050761c5e71263b480a4da62539378e1a30baaf34c23c914747c4d165d42e052a7695210ca5e8003cc0fcc36ceb765e2bf23d5a43e3adf8404043da1531badc75aa7a956e16dc9d071dc1f76981a5aa0c0bf56939c86596026978a91f26cccf4688ba3e096941fe041053e703b23acd0ca3278922bde1920afaac3166d299073f962a7e42d6c1381bbff4c44b978e9c44014a6b2194e0d3231a417377ceb20613305b486ca828902c40d83c6f7333c97502fa574b1056f176fb7b5546d03ab1280aca0939f987ce18a2fa6d68559eed5fc9b3fa2cb583d826b78b9e7461702253d4a1bf4d32c01607a707c569a8bd0665ced3952d7f278126fd967358953115549f97413fea4bc66410fadb761484981829019e280744e8610963fb0b8554a414b5176d3bb4864557bb90f16c04cc2b1393e17a4a6751dc3478acb1107450e525a4e49b379ef24e74f63f90335c67cf427cc66d147a572909a7d0b44f9cb7475c896d4a3cd47ab808cf6cb6063a96073841c1f90b5590be2046c695087e9d3c222bdce56b80f6a00652147c3c9e854d065d27703e34a49c4ce9b1ed2b57d93d5b6814af3273f3d61730d46d40287dcd6e955386117bc38500d52ded6fb8069774e03480100ee9c638b5135008afa6650cdc829f0e7f420d1651e81641aa4513628fddcc1a7796173f4d223a551e8b6d707b11d70c71678c06be505311e39b0a1695267146b27bb6159c1ded52e2b17406bdb12a637f35b21701670f073654c241f6101d1646b6350f644fe555006909587c68e43e30ea0b015980e076acd30d70f20a6356401e25130ef55232b43694335398b4113fd3ad6f19dfff3c18e9f33c894d17672e12f010bc91d12e4aedf661888907765425231b7ae3812ef967a549a107c2769e21611c08a1a74872bc041b7637720f2846310933605b6ab660ea33cd5953617806c6022b4e6f09e505b01c8a3ede20f81a7848b941085bb1ae474bbf847475406ec85ceb985f4e2956572ea0c55d09e6251a0b0978b64d0a77e21160760050c700185231b05220468c8e1584c2f143c96a0a21daaf5f0cbb01d55a0b16b723886b90237271765ee45f900d9fd7541ef0bfa45c9663fc72cbd8684a32899249fa9a8213f59142131684cb59a04d8b0d98adc375bd0b2b200bebf5608af54b0caa9585442de7126c35106f444154f55b49b259738d07407426de851ed04a08769c6d0854ba1b2613be8fe86fa4102b3d97f15326fb21863e78f04b2732002d5bfe27cb4e94db272e77e2de6f0850666ce06a8c62550403005bc3001d646b831a9865632102fc2e3c738ebc6df8c87e27c285400ddde5715e6f6dcf46392ed2137df9d942a414317024178f3ee7be8a31d5e895498612250f36d35a40252f1d2fc8a81f3b4c74e00a0593282f299a2a70fb22511fba59c302cdb5d8539488433d9635383d9bf5db1ff665006c11c7d12ba17d711bd8d8a911610d334ce453034edc7a4d5ea9725e4750e6f659f814ac3300c4926a3cbb5e54fca86e54052a1c29efc29305d0db585cd259f05f4a87cf0040b3e8131c262c0c0ce44e594db06f4ff4ffb075beff362c540a0930b0fe673161285a335424852540bd105fc5ea1a5844a86c199493d4581c25dc2c04963d45227751509895400751b00777741610774a854a6ab24b8b2b212ac064fa54075f20b4956447522d027be00172efa2425c0a8674485ea94569bd197c483c25835cfe3535466ea52260299a2b5ae6f22f59607ac374df3e5e6ee4f2cd35685b386ec8c508614ccc00144ab27f37cf67154dde4754086190d20f02d62500d41a5e338648fe089075ca46a13025211183f00d5d2b6020b8c4ae6dbf4e565ab3c4a323c18f9a209ecaf24485b9b60920a6d432327e1d58a0a6c909f79dcc50da3e3f49235336772984cd699e8ae14fb4f0fb431c1ff91a4d51345246368026aef9bc268b43134e9be5ef6fb7b0f835ae08f61037cf863584796b4e1128830bfda25a01bd704f20d6f57a68a8a261321e1a3248ff3e5376b779ce5f7d2896302d3d976cca596755d37aff589cf2866cc8b8c976214eb647c51ca62a6944e724b3677119f5e9c615d10627092f656a14921d8560f37d953fefdc1f19f79ca7077e66cc624a857a61ce94435dc4bd841059329f37dc1d8d3b86e55a406076d359fa8f2618e5fe1c14afa9351d003533403e713920a731796dac21fa0369be24277fcc483610d1fa6f1e2b6162dd4e40267080d747f94bcb45de5d9e03ef0a815ff98f052f85d1013986cad64810779462ad6559145bc4305bf4fd511729f94f4fe192864423e0ee61c8d8360daf1611369f3f431360bea32445bec55ee562504f752828282eed00377fb04a3a770c6b12f8a1ee4887704272cc0f240633b6e62c78b9b11ab1f8b836ad715072d90883162f4938006fceea20734eb22a16cdad5f0fc5c3641e83180e8721657324019f0eb7e40c415ce1723e237c0f77b7971d716dbf7a72ede5d3538e222b397daaab53f29c1a07c27c9306b6031176c12770026fbc7663f50f3441a15e536f71677d5f9b845137d3a43f62ac8b205c09712c58b0a87453c368e07542c6572cf8099e0e48343012a03f2414c56d3e466e9ff0379528b944be487b3e73eb5276905cb1148b6fdb60b06f730ecac5860455911712f958dd497aa2ef3d652c391ba04a674c02b5ca1111447563c58a6c76dca2fd59b5786470e8e7132bc9710646a90a4903bc4dd73cc409fc462739d206fe39610578550441b7ce342bc8c56542e59df50ffd055c6d41784700c1b8c7772beb8f16d90d316eb2f36975aada114c6b57e7514a48507207b81041fe0d560acecd02017b2f226675192957b93b2f6d736b442498338e31631f180955135f166bcbb2621a2e61075945d732e9c65c0658620e75283975622c81c3380600d71f73776c30cddcc7605582f323ebb99750ae74924493c2ea5304626b266e0f0c134ddd7f5cdde2d82f17f8384de3f2f7020ab32b524409c20e89c37072bb571041edc59b322eb20c264ecbe46e17eef542cdd8b173a341b03fd60f5b457e330156273fd75523994624721cb2729892d60034a0f10ca05728212a01e6413a96a82306a0575aa28a2a5e31745d127118f0743565cc11654b070c917d7728fe7b59432c64f305e7465b501a576d27d95d0c634d595f607441b024d0193074713d8406f337550a5c524519010368403668454e0ed76959b1b262435afb5765e8f77411b7bdef0844936b3c0557a75cc3357e4eab75f649e8580551f4eca82224d386348870ab4f845cb713bfdb5814e909c562c5db0b2a53d4354d6a35c733578d9d4ca57d0c6b878bb1454fbe054d054c4c6744a98b16c1ec9707e3038e69ebc9fb7686fcdc5c2c4dcf5a6966823508c12b2eb836ad6b16a782258e28cf772e5e000c56b07873b496823da8231a4701fd382a2e13a12b1bd1b166fa2e1a5c158bf31c08991b5f78b7ff6316efde0300781a0df24b1909c35d1049b39f703c8786ff5f0ea89c52afed5307ed28ba14c508a65b05847224b6c3072b712f8e3ab6369f26d48c442a99e1e040042edc2bdfd71e38adf0e931226b2c61b5f97160206c3f02c334a8587802002f8064fc02ce696b37b848fd5057fd7b1fb50fc6392b425520b428376e0f9a583aaee7c65930979d4f70a76033e47ba9743c4c922f88ac394e1cc7e709a88c0524fe01531a4bcea856c0edca56e21fd74b95266d34548ee763f2f3b24d5d6ad72f55183718b45978749679e31530d5893570969a267b25ee4049dfc23988cab6626d1be855df000366ff942a3abb057b025d708a14b67af5725f376a76634e3f52ecf33132bdb65a61df6b690dc0b8fe47b6ad19641f9ac66586c2d938ddaba74764d097179c939f3c04998538061ed870c264517757130b07896d6141aa3955572ad12b713cd37c602fcd9b333c71ac10ec51c454ffe4b55af8d2d2100d69382127b3571cf07acc397734441c04100508a5fbbd29721005105e99e16ded451a5da085524af7f6d93b1cb68b757065ad700698646fb7276b3ac09b8c74423ebe51dfe7aa0343d82c0b9f23d93d94e4b639b559ee4d68234a6d7d5d1671cbc171007beeb325e1932872b0b2bb3887686170b2ecb57439121c48c830f44efcff3f29dd6f081f0da7d833a9da846a023f04487b5fa9529bc8de1c70e8414bf8209c41add4a7767fccb30797a5de59f116cd51af9a884e579d1d0bb5464b280dcd7e16c67dc402e57ea201ba0b2853eaa19b7667ccf64a3762dc3f80e8035629daba2e65123d637c658c61359c917181695206b0015b28486ffa6543e4c22691e2f7682f50393aaf54df228c709c6a2f57f40285b48c48a47ca95f7145655f29af146221b1eb3332b21437530ea203250d206d8325b43f1b043624df976776c51b545d80546e35bd81b3444812b321a4e8f22cc15ccc634680790d0229a361c3cb82582392bc373474f804a367ef22a721bc043687ab0b1a1abb33ba00f93aba614b2299dd9103ec4d5f6b3791e608141235055925be132d07ee03fefa44476bf1961b94703768b1e6386ca978a14112f19313967a8118a7ee1b2ee639033ebfcfa070f9b28020d248f441ffaab94624ed2f390ac20f34ee6e7d3a46322c2fda1adf3be427fe4bad19ff55b3e20911ced365172fbf34031c311e50998aa02328ef1407e9d1255dc85fde5dcf6dc76c4300a01bf2e656356e283a7632c7c54bed06ce502d0c0a60c815092d6668ae0a132c7d39c83d21283c9b744b1d5f131b8bac0e0fb889896ec77c405a19375b71a2627840a62c334e46b09813e38ad8748c131a354b87dd524ca2695ccfcf2c2d5fc97242d2eb850dddbec50fb792636368ac2040b01faf699b938e2b1fda022efac51817854cd140c548e23293145f3c1b8bd5625ab031618bbef308405f5748ecf75a59e8425437883fae76e206bf5d1851de6393d87922fba19d420abede2027b4a327e22fa35fb6f0a73eb25a6b07635414031b861c5ef53ea756d1e2d315cee63e1351015d56ef24f92b30f5f818213f7177a2bd123c8d6a486ca8b4df6b4deeb821926fc72f98181e7004d68772e03c02529372fa4248b2d171ae7f164e4ec2aa2592283b45e257be161c48aa55b0528d4b40574f17ca7aab4502b9fd19746a8244c34a5e23f9799912b4579d25f8c40c5187ed5e3001590b06cc341536c3c36122c045c43a57aa8618c4260048a287e7476ca52339aaf6523f2ebf7e37a826d471141e2a6d3c1e6973bfe76b0dc8ca802e00206071d12ae64312d3ab4152959b438a31436d18b2c006fd766c57b6d48374386b246637cee43f34740646a4d73945f31d4f4d5a65bd25aa41b207031caf016810d348dc4f856d08fd96074a6560043b7fbb285550522ecf4570059d7f246da61df023ecfbd86574910858761ae42c99fda110de957c3a3c9aa95ea043b72e978cc31bd8f1910186c4324406d18b098a30ce3ef51d9b233bf5980e0893922be325f9184baf7670d90f8c700e87330fabb1ae142bae3e171c727441820f27513cd95b12f331c715dde0491fce5d7737f8098b41791dde0f05014054e06a79143bb701629673266e5d074b666d2219514155da6b21aeb416e6afae6fc1a3667506b7a9734a32fa173daa882eecfa281ea62d95629b0039364234b61f32f3fb20a411b8646d6b8f085b485a59054f6467b508613ef324f525dfa48468de68df49a19e544997c5d64770755b0687e2230771b69d014bc8f31b0221cf606c18bb1cfb49a10732379d293c20f1741d79af006b192918349b5467c51f957455d0d4059f868c4fec3a932e254ff40c4336fd6c66fad8182876a41d4b4c92167ba6f41f7558fb40e234321b85e97d1b9638570db218c3230428072f9505843637bce35aa9e40002a7ea4e124aa14242ea00dc4674850c17dff6df2d6da65a341020434d1f4783328562e163f8dcee277fca4220f3ffe046c938a44c67c95b4fbe5b5d0a934e4b58f7e5971d6662262c8a66eb057c1a0a64314144165e88fd50f66aca355dd65330c78bd92a2fa79d550c23220bce70af06d9e107529c32c6415416dc23e62b881ec665ef6e5ae78908772fa763c9ccbb50088c36595fa97f0ee9e3d5425c4c5154eb92c339d9bab66454961c5ebd31821d28c81e37d3f6857169a1160c984bd611699b4e755d71991336409c44f680283896473202adf2f25c59c46370c6f8a033acad663fd8fc5e5c2d893a5428b8f13c01b09e3678bfa523d4535a6bf0c89c771873526b8adc371dd37e176529c4b2413066024f6f9c9d347d39461395c13b4760c10c66b70e4b4f235e89731c7e8928f1b63e235f049843b8fb761d50b701164dcb60072d27ae61dddcf42bba786113d44ec56f50aec717ba8d990c68e51b02d0d6b96ff1086a19a537680855817135f8cbac0f94dda24945188f03dbfc7373d482ac0c6e0bc723fb30cb6405b2ce2828996453d033ef627f89c90ec59651601e0359728f60c872544dc44ea055f470420306048f89e8500180c93148f683657a5710167ed9003e9f73ae1592286545c2123716e0a2f72d5bc16b23f917925da7cf801a46629461191e6b49bc98b0383e4bb641093fde757ae0d171090dcc2f3015201ba3afc468d8cac919eff2631a3204a814749b576007de021a4a8021081b0e9b5f65acdd5170bb0742094cc45578466d33e012b93da5c62f3914797b31e23b52718525c27524657154b9f7411475cb4a0b44d6fe10cef4a139366232663b68d864f4eb6446b256eb5c965c981f2fdc2d36e38944643239c6167fc7a56d08c8ea56fcdf555226faab68160cf96f8755ba3208b3f1731079f0678fb7d362e283ad5dcdbf8752de67961b418cd81cb42f804c744d2e33dcca494ecf65743e5817b96907cfa35371aabf2d65c1b533f3b2275344e54f19fec73d05f25e7077cfc7345634c1f920a0b12a51aa3df702ca416c5d74babf439692894c0559e962aa8b9b119486f706d666c0326c771952667bc90b076eea35be18bb4c4d8d0c45e63cf14a4bbcdf31fda15324984fb247e8929b0a2bce80736021b9411e3ec24979e19449a154115a51c2830a5b11d32d86e7d04825e329379f6ddc4b4c7de26f5f2ac060fc11a75cf122045c8fc8aa5e5cdabb0a053201443b01e705dbe257704535ab4f1c3be65bf230f4355bf41d5d03be825ee7f33b74f565106ce36bf80799119e2e8725aa078ce3b100ba45022f921aa91dc7c8ee451febbd4259a7db031ab4d07279bf01654aca575b028eac2bcb0fb91cbdf6497207555732022df01e6b9a2e566930044af183536b60e47e5f8f1fae4830c7356c6200387239f8254e51f2911ab6aa95726985424774d1e6667ffc837417f76612f928993be7e2387534701a045ecf0023cb9e0b473cd96f349006d225f6ace010ac59a74131f741196c667a4f2cbe893f7e290f091ef9c035fb9f2e774b0a4847d3d3124d38413d4ab7c13f563683452b16dc673a8b02064aa526ec3943f89b685725f745f388f43ccad40631d77d2f578948c207564de802ad7e4f1508a1a15ca8b52a19c3ea6b3412e089558604ed365e859d7650ccb172afd7cf21e5fa745f5130d46aec3df60c16c7dc6481237f091e5da071ed59551c8a67342d3ee300157d50255b05de1f3e22b5bb3f1a28370332efcb632c920d127c440e471346205ed76629755c996b20e32fca76e2a75d2ce29b0c68472932538afd74370122693ef34d65454ef09f696a533b2650051706c1924f51c2c0872d5ad58266e096d70cd79f343edcee531d3f06b1457da73f6d5dcb984b67e6a248b8f7f01b6f111d4e5fde910108d20b347e5a89348361f93166adb9385046aa475f51f8712d8add2f0396a45f34eba431b3be383f5a756d0c06b1283f540b5c5ab9f42a6f57d4a22c499a5874c710952d46acca224b49dc0f64c3e550147a2332e593d17047aa455609bb4d6ed683ad524cce82089e3fd301a0a3631b6cb55b0df724f949b3fafd56bbe5ca23e2d5e0070c260864c1b0eb33f1af305411ab5d3fa8c92c49b55c3c40a2574b2b15c6335be91a551122d2ae1a9f040e197d5014398d46d3399134621c3eb0b13403a74f5f6dde982c8eab1377cfa1356a04469862b11aac19229e434dfee13a049049bb596157061f4d457c007a29a66fdf1414486e13bd0f94bfbc382db88b0307335607dff5a16886db8e5f9394043187c7a14e1c1e5a14b73f795777451e767016c0635d9a590d883a573d4cf0426045df19142c765b46f8b0ae26d280e32fba0a4157a726e62df7d8ef2fa4043b49b67b8b53267e9347c11d6c44e8a4c323a32843658d9c3849e4dd57252dd4bc39a861686f4316c31474a1d420b59f8e4904193d4d5a5d5d0a94aa9062ca7cad53a1df3a761c019e4e32d30a03b4bd621e47604265ecbbe134949e235739049d691db196051a02b2756aa5004d04ca194ef28f0a2862201231d09f31283dfb35299385fe4fb740bf6cea968775561c75479e56692fb314f435caef8132b5910b4021ccc9333256081192d4f6332a9e3b07df8f706228555c59960e316786dc482fb8454a42c2e29627d4902560f439f020b8b80c4fd5639e24c3f579384c2fb85233cc5e2d342af8229835f730165b61397a23e85c1cbace31c352eb0d24a30a745fe75c40af175c5964466034baa2da22db389a3b5825332922091232be6c8e2a309920107172aa547b987f749c567b46f85f1645b2a88145daf90b30f978ab6693e12633fe6ba13d4d47c35cbe043e569b8b161bf1fe945d7da63b0038436c5413f37b6f1df5c576545a822af2554f0531c6b93b865a7e0f856d943c9bba055748080e4bb3effa350fd1881b60228f066c607f0b9b5022148c071f57f13c780386487317987e7e6c7cb97f2b3fb5ef089db16e66d8721b5ad55e812264529f645fde6a62a6ccb276d6c8ae30ea3de54dc1615c3b9f8b45406246132ba6aa762655092835df7b9363c2f54439d3ace73d0d96520783d2b850dec088102422492380cdcc64540811599b967315257232064a21c873f8cfde4892f2dc059a705426be669001b4074a655b4fff552378915a23be72153492f62e34820570fdc53d0f8443847285a5320bf8c2ac4915e95450faee68092302f870a0634a72cff90070c6d97477006094588abdb875e6a91a27939c74684df3726aa8054146304903539066377675b7a518574adc477fc9a8417f64c133ad67c65632b1b0237616b7519af4625057b0053d04df4a65ca75086199745229b5416820de38c848e07e7b640eafbf496bc7152e64d03a4bca2f772fe0346a67de83cd3d5c0194269fcc70395dc2f30c600f566ccaf0e2632f451b66568aca3ee5ec295db0d504519452be5e617a1e373bf5781c7928eb0d9d80d86db14a0e525e0bd927b3d745733e338a53d3f46f2a1ed2d134280f914c545e27355fe6d669733bda041409be0e6f7c513debde48119ace7868665b3c76908cfb204da3cf708edfcd57e4e298746666d768975b812dd1926360aac31b1137b5e7530ba4f7425328db70b6364f5310246534f72ae748b2ff9009d33c5912a6b7945278fcc61b1ff97042cce4ac5f02a0724e3e61ce2f53934d1f2683c21f3ca52101bb350e599106bb292e8bdd3ad9dcf447707d652191681a56602dfd641f74e9673da84e53ca83d0437b03ca0821d65b2a1b22832b68424a119dd42a535d27c34d0622bb6b69bd6a0db2ef002a1932685a7aedc1771851b73bc5a50063e194c849d21e3833058fbf2e0abc066556fea769bdb0903489742f0179edbd628be39f60ac091e545c8e1f5a0c30202e5701302bdc28b0246a889a0caccbde247101306814eb7850bc382c14ddd8c955135f4552dd5485017dc33d54820f5821b5ddeb005aaf3e278556046f5f3ea81eb85b3e2295a91c2654c99e6f14e68e080e914d1d56f84d02d33e256ede54e65b1d7a4524d0f4a85972d42742e713ca036234406226bb8367b9600460feae7c10d044fb4c03c502779a5adc36621edd3940fda16b60f89e6fd3c35152f66f0101085ab3271e088c20ae0ee52fa813851c382449494c100401fce3c0096500f2303337645ee9b0e760c6e7790d021ca96b87d76d470372cb1ce0d86b3ac33e052be287594228831b2101c2ce766ec04036c8c14d5979d1b2452ff650088c30d433f652ec5d16d3dc05abbf9f489987f963b5a16643c3916731a99ee91485a4fb4360cbe1758c65e42594c9433d34f10e72c81c960f1715a146158bb13147ce1326596f0b645bf4bc570294d767e48e7377d7e8e144ee1f8813799d8668568781244f5a0509de9c2e30eaf6a448003d4b30d569bc0113958560d4ad5c0dee0f3301f9a7b35dfae9c82146501c528602ed256485f81cf701d3016439af42ca00e710ef67ba19b7e7db1f1be42b242cb6713ca47ef05515ce5314e646541f54fce6011c1ae51250daaa6800584c6f73523d5cc15fda0ca2873b3cd30ed538c55c7e203d7f155793334672e2d54c14dcf1e44cee86f11d067dfe62235c743a9e9c3c40029a0c705e5db34c0088435db9c36861a5a84904710dc00345b3944a8aa1df031b3efd1743959d554e914b6b09fdca65d223151467c2590d9d66455b91d7a65e1fa6c47236958359cc963c6f923cd658af84484e6433bc323dc3ca0343420f220bd1bc5af380eb19858e216004d0cf0e0d10c60378de5473ecfb9218dc79e53e72d3bb5297a84526a6b8b41fad551f73214a6d4f5079982e2353e45ab0ca4159e95ab00601c1515cde9fc924e77c4370fa82746c47034030a5206f31018a6515e0fc7b0c800f464975472e483bd3263b3089df5786e2743efadd5662d121a121e6eb4b4df5849742b1976c639a84f64f136372166af29f695ad6e36bd330ac51c5b1a41f2dc3715ce441d12624c9a95be2c7a91cef74f61f3286a05466d0c20e1496ad0a4aee825fdeac91429f7f24263e86876b9ac528686e6f7d5b26b8c65c77eb24041461c47157347a5ff4e98a205cc878589d663f766795ec75c52cdc001ad3d73b55ce3e1b1b1a41654e870606c313f65c289bb50af3ebee3beb8fc76c20535f433dc27548d2bd3f4f56d13f195e8bf10cea96f571315d4375a57ee80f74350c6f8e8ae43162e87e29cba8c61bdde4e50587312d0f17d6b73b084f093c2ca89d0600d22c2214f4486f809447483b50746a6184282860cd8766bb4ad834fbc75c5f6cac4330c583006d5eeeed73104c530e87bb8a05ad78f10913ee943513d08968bb932c4b11796a07fe486e114e17cb65a6966726337256322c63944bf06ef5408f1a807541ec3b147daaea575867303df272092c146c4939cc943c194da4c00aae88b418036e1304b57fc1544eab5f18bd40304dded3a509fe48fd0efdd3df0b36ab44378945195723239a73fa6c51079a2b2c13f29d54009d8dc5707ffb6e077c8dd656fbbe19325e33404b626d8b3a8038cf54ed37f71fed5f600d245cbe24673fb92f01e3f019e88116603d18226c16f1e403c04f903a7d735d287e79c90f46affd27da785f40c047db4358763d7096636b66a754d60f2e21b1003c502b53347ad73bfd4fa833f84a360a2e6dbe34e2cfe5059eed6e6ec9c22c50bf4a3f0a58f9c82a64690848ed0ea309a027c712dc265e59f96b4d2b1aec693d255d2a40ba16052580358b52d285f00e99dbdc130476e72bb58a95017341f14d3862b53211dcde0e59043557959774740d548c03b240965e4c0df26385d8b740caecd31e1c2c7f22e351d624fbedeb70c351a7208c4444745ff65235ea99c94aa461256783f2486a59df505ad9e88f06d5527a097cb28c351e9b726e6ef43622fab8271ce98637481cdf1719f349153f43c58002ebc3c25a224efa391364f533d33347568264b957ffb96e5b841f9a6db9c510412603fe106a1dae6e7828cd1a82a1a9432e076201b60a25204bb2ad023770a800326ff6637260331710644c65f615136167c2bd51f5eb723fbb179f24bd3c7f19fc6c4b77937b9e0144f470428457f71e177ab01c0086c171e221cf5e4ea0495f43c07e5b3ad9f205d83eed7711a08207b395236f9bebea384d732455bc309f45dc7b5e5c5d5ca86122e61536eb2a29750183de6eb838912497182d29255b9d35168ea8132f38331db45ea909755d1b07b343214a752e0651b3c2695c0791e21974969763e6939767a04d9c4490a7bb2ee83bb70d161c5d55f9a57440186da11c315f864d4093f8576f733162a79d40220560e952dea784661e874b774660be338e3f1e6ec4a4d62d9f66895162b1216f5b35365a20151d4fdcf22943167ea70a29a7024f0550530c79a3a9408130e8479a9abd46f3e3c34041c8801e730e4b4ae361b9255813b82bd7c747756fdac62307b8e5525059d1504718e5291b01b33af070b3118c6de546dfc63c064c25ab0536e11f0a8689dc75d65cfe05dfdc40366f98564075b35e655659cd370809f82360fc94138bc73e611c96cc4bf8e8a000789701466c821919441ee4347333d659f98db3400ce459169bd27e43465849548624ec343f88ac39e7c29a586a193d15759c4043d10cae4b43a40f4d0a281509d6091a05e6e69d205f0ecf1791a5543276646d3fb25fe53ddf31e13e1ff26d54ab9348607f37d54a160b1512b0eec70d6a5bda1eaf4636498b7d592ae4937372a07af7350da1c5611500b570703f051ffd5b3a4e2da05e2c8e23482ea057d3068d92066503dfeb0b1e7a18557de5e12d1714af000c02ff652c783068bfae9e065d84b36891f9384a1c507d4c18ea335a3ace8a3f47dfe447f7b3b85800d1c771c7362c4e7e61a830d216c1712f214802075de22df8c4d41ec24bcd3634f77d14a44ce8184de05305eb713372b637917666788a0c9cd09f5917941d5fdd9f6834730e983cf3f13947fab8d72918bf21464d8ff72655a7f011a4e2d217a1021d2c820b986d9395ed4a6aff57541b8d8360892c51511404e1073460dc3c9dee09323615941236d1e93891f9f756ae864c6cdc41467010bb5e7067070663a352e1708b40581f2cddfc4eeaf041712b3e8024418df76d16d3de0c699a5767c6ef15762becc162211b2461d34c36123260860efedc773152ed1a745442a713785916728f2f4b0b5f7f286826ceab5f51cd0f0ae8afb941b07bd44a00344c6971e7153c5250b10240a7175e0d2b6444731b6d1c393ef6567f40a632550c3336c9611e43d01c99625c488b7658aad71501b5a749cf187443a76bf608cbe3da4233354126de2c656914632f4beef85243f55b500416734d40be9a2065aba88c3b28ded537003bfb59907f370fa8754e4f21176367dadef91a1dd97a1a22f6bd4345265a4b18e40117841b8b38fb43a8674502481ebc06ce4ee4eeb31a5910db02a66e1376d5060821b290f66a7314f21d2301fa600d24315ad6c6eb6b5b61c924b180bc00e6dfb71a5cf9c4234ff9db223be3ad3bbc66806f4f180846e0127f349f995f5b8d36922a299852077064b81392d484141c43ec6b3176b80077027b4fc6a15911ec632d491abbf35b554cec0c89d904269b573601ecb6b21f9d517c15249873775f1805460581682dd4ec203c012f8f09fabf16506917702f69b7fb24511f8d2019dd9c17a4281438b59fa806595c6d4178267f6dc02c024e6356e55d804c003108961e20259002758846744e424a6d0e57f87f32d5cda6539aab88731deb4244b5c1e821e27ded05bc80c12b61c4c905e934961865c9c80245a4943281497b4eb923c77329460a64d5cad7121e8dde1141f03c5780504a64df18a8745896ca4c0b094002a60b38078ced4219ae8c6644b9efd129b975dc089e370157563a27131ca07466e9d2f016fe7cfd1674f91b3186f13b04d0e71f18db7aa530ff475b62bda42164f0d4183c13a07f5f3cc94e497f533330ba60bd4e6af7b638b900e21efefb4d18d21cf8359bb6e600c26c156d72ad9217a8c6cd2e2f7b4d43e9b049436fade4311be6ef096222715940308e47be4eb3278cce8a3da87f910a93a8e175cf5d171a804e9c1221117c14520f8951f6cd3055c3d18101df5c3772c06a7c392ba1d5578cd1d466529c4a629382ca4c77add81d6f7a564388c9fa5db170934a0bcd3b1a3d5bfb2e516b0617bc687b09abbac03c257fda55e2982e37311dbb43c5653339e9c4ce11a5442c04d3142b1a6723d73ff021051ce3da3506cddd51167343c7511e9759656c7d7446e2882529cbf46d1c734f6a6a4033eb1462fae92e7755e1607b893b47cb42cc63523dd629b3786957f60bdd4b6dc9ea3c9318231e09f6622002ec6c0db09218209833cb03bb8c92539a907c2d047e1e1c18598b03ee48414abd5d1267e58e5a527bb55d269265605df09a7f681e4e2a16bc643614241aad7052aba664ac8a073f0490f116715fd81c1f53db6815ec9543dffb5f471860d975971e2c15b6b72904d07ec731fee0be62253ad45f0c30ba062e3e972b25412121ea564d625fe22b0684a54c6027a05173a6050b24d7a8b9455bb22a537cc5484070ba6d4ea0d5d81243e199251bbe0e5adbe443444a017039c5439914c659d323b0fa115eba9ad05c48bac020f10bfe264c8c7251652d1b13d1aad83a52891a5c0935886658605d298485dd774e0a062ed6daf15b1425dc5145d13f42218c4d2ae356103939745b1c5dd11c563458b907c518e42d43f68474c4a1fd4d0cab133229520b3c5782b8015f2ad765dc0aa637dc43d318d500276ed27b241f7af8ce4c58046445ac13854d2bbb70395a1c4f39227a1a4470ad7d1633472c6d4de17541646c631fe094992a9d0bde6d57e8cb2e0d30ff77a03cfd0be891ef1d932cc35ad2ac3627d3ff2700a700c52940e3ec6241eab43eb9ce9868e2759e363ce9033538887203ade7c13139d903163ae2517589a6705aa056eb15071be17575f7170e072f556a183a5b58ce207d0a633c3a6df825f05bc115a22964758306c151d10b0d399e457bf2a2286131b26c5c9f04249b7a941e5299dd5570c5202b059a3c3ba8567f70464511192bb2d537bfb6cf30a06a2e4955abce2c7b60477530deab5319177b2851ee0560ce934e236e7f351c9a0b4c02ea07034e2f05501e05cb900c9dc0cb1c48e0f90e01518b40cba73b60950aea74eb4dfd342baead4681a5a053b60d0370db85026d8876551a253b8f65f0d25e74963da52f9bda602ba381744c4824014679b43d2a1e0f5c47f5a0f90ef4ede80eb90daa4a349cfb4af848fd22ffa07b2d6aa28d468c42db1aaf869f432ff7ae0b7d0751744f027173c3fc7845a8567e38b4fec85c2471435f1fbd9605d9c3df1cccc51756ce021700caa26d6f47144154a533cb1131f3db4b2e3e7132c006624f42b10b179f007f01ff9ad469508ec45aeda7800ab5ebba1a22bc4c12261c9236e597495bdff7b55d2a7cab470834fa149740924e066f3556160948680166a20b7919eb6af54d195e973da24d73666669847b67300d5d8e71a295dc319390176fae17bf4e2c59712cae287731a28d8027f4bc0819ec2c1c0ff962e90f4151fa0061ee6e57d6b0d15f4e14ad3251e46b761ef9432f87e1fd101522600e67681252fe4a346cc52376514770c110f9892447989bc2539747f96a1e542054b1a3fc2403c61a217277c9528226d648df228208c7aae70f0b96cc5a4ff4c7743ac7e73c7cd6346aa05f416f2c0aa44c6fa9f462c4adc030a9f81912445ffc500c52f824f22b4419cd7dcf728f80da772d93a5540b43d91f4eb3586075b1c643a008420a803758746d68da40ea21b171e868242ba721370b2a4d7d42c2cf4459aeee875033ebac0012748a635985533707bfb60de14567328812806b72dce11eb5029b14ded03f6c330ce34d97eebc1d66bf02639b1cf159a75c664af96a2c1135e31539b00f9a0bf54f45273b76ec30bf497e15cc2cb229eb3cb3730b1b694642c64a5d37c1b94e651b674a280d876c26656d4ee0a83d0c0a497a3a314a3c354f36ed3fec9f0d52fda42f739d0ddd73e5a6d917efaffa70bf86fe5572e8b547aa42325128b7704ffb329a2bf0a0ce4e964a50528f08c63378eb4e3b9c7c0021dd3dba032aef78455c3a3c6892202a27577f8e21c7464f658aff4721240ca5248ef8035e34fa5570216e95399045d249da5b8224f769e51f68a4ba303d712e15bd1ab25efba1d437554ef247b3eca75bc835795d4062b23dec5d027284491b2142a48335a9ea2c0eb1bf0c404659a900d35b11501d504733278053653a89001f06e2e72ba90b5f6debff2562b2a8fc091cfc34150ce89e3c8bb1ae01e386806d7f3192013d892a6d784cf17175e42d2ab54c0b2986dd4852419a4b728a75403d580b1c74c6d0dc2b0275391d1efa352f547d622f6e94d83dd72aee66ffb97c509b24b057f63f78618641f40ccc608b193251d65061aaa23aa8340f7580a92d243aff312b4bd0d45df79b7c556f1774208dc6f845457a253d4215764da6d75124497ec5408144c52f97eabd66b07c66328f334f73eda4c96040aba7655816c638df83c0224758e102e71f0d1c37f2db6f0b2e701c13f8b70bc5b3145920052355d6ce5f568aa59a57e01811274d47801507f83962cae1365a33535b49a95e9027c8746f4c892db825041cd92e046c222d4f680d284dc7d801c8402e02fd179277b68e21590d72221d17b66024e997436e3ffd28058cd9a003b25d2757897c1a6c54cef30adb8e7b424aeebb235701df2368749c39c032791143f5531123cedd23cddb160d3868a456b8dccc5594990f4198ab53517c35f34ab411451413fe8767ca33f61aa9cb910ed46009494f5e043f0c378b738609ce54e650fe0b89b692564da20241a54934239d25d46876a73e1e2c3d385da8d6204605843e749df8d10a755f03259166fd52be6d03752834a73b0803fb07cde7a85d2cd76d1f8e80fe3b0d2d82368fd70d64778df16de5ed1166eb5d420b919d7408e4266e35d1e0a06e1f1eff0fc1104743e4919431ad83240ccbe80458e7dba45a28091a2356eb8d71a932571b63aeab39dc728d67071c25272847f24ba0fa9519cfaa486d01af9e3741d74a374d3acb6eb8f88e5ffc797109e2b7cf5c2c530c4ea2c8a76699dedd40f9dfea5c5328ae114020fa09114c9605bb5adb059b5a136ff2cf4d2904a47d5bb76a8a247578ca0a7b22162fc59d2d12de52325c7ca305766243160454c612615bc16b23f917925da7cf801a46629461191e6b49bc98b0383e4bb641093fde757ae0d171090dcc2f3015201ba3afc468d8cac919eff2631a3204a814749b5760f2a606763b7a5d37d4073f6bddf0d1075e91363373f5a34c851ef2625c6a072341b8d165d73e8e411d51b434e0bfb123112cb94d888de5009ec3c538f833b2453598b40e10bdec39a64fd369a0c4a3138a4cb83904fc552497d4c803df04280fdb9d1d6774d5a34f73131831076b617118f2da6f39469b2146e8fb10080d3c47d9eae43d9c243d580ac5472ceba1e91b79e40b15f4a1463cdf7aeb3c9f222c58dad3422b561836050c44cd440567e25626a8bf555cafbe4ca8d70208bfe41802ac148a05851d8c464c0d7923a03cdd09a757a476d180eb53cd7a7a5c61c6c74811ff1134cd5ece194fe8f1011501f24cbe67954eeeddd4727b4f3e68ddbaf5653929ca1d5f3b7d5058ad7602b92acb3c2a1fb12331daf05dab4f2f2c183e6d3345df221f28a9c439318c8918caffd2180a60630cc780136ee09e475338e235759cdd653fdfe92f07aefd424fa79574133026b1419712cf5f25488e302a6acb124c0f6c4bbfe11f2ce026745bc9cc1857268b4c0fc068ce2736dfef0c044f0e757e6e664c3a296a184889ab3cc7d8f02a985a814d6fb7625fec532848480df5569fa6043b03bd7d5a63c83e05ff03a84d6b03544e5c5abb5facee6759045dc01448efaf127f894848237e996e0acac2234216712c971c722cf0ee3966364f2c00e5799f6148ba971ce9a86d4a7b042a136b525e62b5bdc16c006a42078396681b58f188684c09a50117e91f0131be2c63536cbe4ac5233105ef766f61103fb42ca897835dea2e5d68adcb2701f4b23520eadd0d371c5cd902942f910f5497351d8284c91a4d6484170213e64564b8fe459f6e4e6efe6c4d5ec7a9c035d5ffe50b367db344b2860f13e2bfbc6df419d06f8f265652747ddf57d4c31d4190901817e0990b5f48a30f72eeb09a4b58c6f44eb949f8618a352607fe5a2d49e4b04239bb6f174aee48350bc83ea52026611204617a802c8ad4e92c3736c953dea9250c26888974d9220e01a3c6924142ba9111774ae441ebb87b5014aec821a33c6e22f52a6e62c1f21e377b3ed95d1e4d6c21a5f7a16801a2b6715ef092606e1fbf043c8d4b2abd18dc34498d7977a1e50b60edd0b94451a203051375fe7154cd204fa1dc2d392dd5fb06f7685025b399855fe32ff43781c94a1ef6cd841abc98d46d1e55a945b7e95820f483066bb81a55725aa8584414cf7e3270228870eea1d7448ccee272ebe91a564639c36d491c736263a5e56f9b7d9a2a4c15e10dc3772a208befc65c3e99656ade2f4f0c9f3e4d4e0dad3f52031803492ad41a254c07121169eb755a4c7772060303e05614bcbd41f174d0661149d11ffa3a036f4672196f14180747afea0d5ee6eeb3206d27173c1de0bd0cbcca8710b2f4c561cd37806b8c1ce22631cc9f2b7d126568de36c760a704845465a1bc496e06db2d4a80c828ecf4e031c6581e4ac4eedd66200bd44cedd03661e3f5180f775211474eac83230b84ac2b0102043066adc7691fd501495de62c6f3a7a3a1c807e3834aefa30352864f04dff3ac26d9d80a3185115170f6a5bf82def45c5239e8b8d4871bebb6c08f0bb6a6bdda0330d88772c65ff4862e9db6d36408d9d0211dec472a94a991fc7e59a3b67851c22eafd56000fcb8e32af518c39e9e4bc494645842523abb7653ed45658dc1031368ee0ac653abb6704c4c0e9640174f43468030031f723f80087caa525846eb714e098d713a5e74e2ab4948c0b23d64519b5dd914f2f9bb01380ff8a61c3405d554cf4f7456642db300fc98617b725166ddde5a44bd9cb420ad84edc2648f39d3cf101dc74b297cd29b5735145a159c65d0d980a6a27eff91019adb2469af7720b00644a2c33d28948fa9fd15a34ec0306340448472fc57031a81bc225bbfe421bbab40c6cfde5b944da0bdc6edd3d8107d3a05307a0e403292d5f5a5645bdbc6323e0bf325468b142dcd4145302549509adb13a73b9001d33d2cbc2063160fd2e6fc787401a0567473174e369a9d0ff564c25962f139e1932700f283c4707234f2d687f43275c140a6a04996d73105c0c5b61383a0e3c972740f6bb5f3538cd3d211d134b0df931125b246e341de3572d93abcf6dc7ce8f47cf09054147fc3631371a0e666ca99b23ac10ab4e3514794e76025051231d893c83729d509f4fe4270d7177665b0760475b217967e4d11c25071c4b5fef4506415e688228b0490a023fbf76377d67f2735826a9753d020e45e01ca468d52521739bc051095c16d2033e882b460f93f26b66e1292c58bba53a70ba483edf402a473460cb1c575e7d083a3aba081e111e6b94052a3fcf6ce9127061421ccc41274cb0cb851767a6d62554021125f541f44acee08311a2830c3e5cfe863b35d62b3619a5d22915d9186301f73b404d33f04b77576104b5ef706f6b6d504363729158c735e93b50911d4c90333027fb904e506ff93c531393ca5fd7725e403ecbae4983a3170617025a03ba2a8f32835da92d25578a4149ea351b762bc845cf1e784ef48e423390d86e676c88e025db7bf94560d4ff43eaf26a1e2965ca3956d45a55fe759f1baa7ea36fdb1f1722734ff92681fc842796eed077a3c15159df9c976b6062a01c03c72550af70b633f2535d17c50f852b08e888129e3340233b9a7f11c9b28a4020b7fd4958d2db659516ff276fd5dc038bc2872040429950537b990eb965be4c58c86f41b568c2534b7039093109f44044612c5f431d4718e40e0d3e3859df11c4a0f7128127663233b7b13584c8791be0f23f16cd4f5a213089cc27ae38c25185c050171ef5881012991715b76a7b1bd7d1a27747efe1071137e80ce04ff14b7ae9c04aa421905e09109f0b4a3a2c2b473fa66a77f7ef2131bfc22808745a0f0c5df929b45b713dd7679b36c019911973e64f2b08dea8030123fb264a3621687ee1f8363e8d0b11ac3749183c496f65baff5b02f9fd883c12acd03e55732f122795e56c82452a2a4791e953876b904e452a665e268139609eba00704c936c5657f9666b0f677b60f9bdeb732a0d8928e54b0a49d21f403d11120d512f07907127cafb6b11237752bc4c9d5c574030491f31b5764e730b29ec08477492a6d06af6fab644c39ae720ef6bdc0333b52111a9b6b3639dd8ab0aebf67d141185445f3b135c06599cab4f3d7a2530398b0d470079876ac8eff22f3dd2741e7e52f060673a5f2d0e5bb00d2110884447e0fa2d63b8162804c421711409ae2d2abeec7317f63252a946fb01289a671a2d91bf6d1cdce0346f57dd597fc5336e483390759fa9e548cad1bd3234d3453cec8d0848e0a425472f95942401eff262ee62b70bc028c5697fd62b4c45bce02cb4d90e1c1dac6a2d41f87e31f53b38034983e400c466b23328bd7b6eb23ce2597258054be8c45c6f1a140c60b8733f3a3b9b131320d5f75166acb16aa0820213086e237456c58313b2a136290d2bef5c6c8d576d3fa74318b916790167e6ca7480f6462e45012021c819237263ea0670df5ab755ecdc9d26ad830c5d27cafa7713c3f66d6babe94052385e6046847159c92e4463cb0d2a75e43ad624c56d2d076a29162da6021d09c3771946abe917037e45ba77e978994e95ad137321f5f06d14fe52155c38096941c1033f543788097af3a322b2af384b7b62622db4920521151f7745bbbfda0e18353448b486cb087a0fe9557b961b38da945243a39f8912d54d8267cc9f7306d435245253bfd870645ff74ddedf0e1d129dcd681e577a75822c976b2c88422717c27f0bc0df256b6ea4e262fd64336a1beef519fe9d4424e227a211f1ecc555ecaa761584cf515ed9ccd32866eb3732c61ed442bb5120192cdb30264c88f1201c32a05d81fe3c5ebe8e1a73868e7e17ab58710aa54a4b0ee80654147b30ac0c0a4d4c3a7dac994221af321824c58f70e4c71d30700f581944a5a62f4c17360451d029328c54576948a4af337432d01d171ef10811166266ba44253f2e574a77b35da15e8d49f9053575f46142bce611c90f84470b51ba64a26cdf4f6eb07a1b535acc5b52c67908f3b7243198c7c52f4710364c21008e3567ee0c73e4e0a03c3830286d092c2d58ffa5b43ba540dd3cb106452965da9b15b3fa6475a8c8ac6d55c68251dbebec1318ce4d58107b9516ab0c9935d4d4d41e001c047143875b5418310b585dedfe32b5bec649dd54cf28037c8d65fdb268487ac5d74623abbe1e80e9ba57f10d4e66f4bfb1207627b7164db6330694065325d227a53e25ec4313e58fd4006bb6553d3018eb1bb9e0be2d630035003c1cbb4a96b4ab733f19c370d537293ec01e5e6c6dd6145757e99d0cf5af180adda704566e904e451c79ef3b252bef3558044071dc49673d7675c3522c415614a1f5fa56ed80f56896c18521f6c69e706c9d4d4ad32c0d53dd8e38692dcf1136cd8006735f063a24d9912520652daf4cbedad304089aa2358d6b8470040d99686a75e472123e907740cd65264b33c52ab4577b09d8a3174725c20464f44e252d7c49824a968c605f6a32216b51cdf3529e50c90d06bbe446e51e76200e48f31ec118ec6a57059c3dbd0cc960ba7e84711154b266b565744c8c648a1e41625d6e3e900c715dea9d55f4911e5013bd872af38bc33323bd2e54cf3e291176999f3b58080f0a102d9a546e3625623f7cf55dd293e25090a03d3760dd8045a92fe218f66aca355dd65330c78bd92a2fa79d550c23220bce70af06d9e107529c32c6415fdbae7782fc4d1311a7e41f093fd429d491c04c745dc01ad671225cdee093492a70ff14f03e9f2f1d92df419a12262522f04f38f59b69215999cd43b70c3546978e0955addba86478022f23637caf6324da6b5c961ef12392b1c252cb83696754c48822ab8c0a5a0fb57a09eef7fc3125fc0e052dbe1d3727bfc93ddd59ee740bc8be37a7088f727a484801fab28558acd8a00ae2df10509f1c53557260e341609afa2072a3b2266a235003cc3ef16e2303f23d35968210a413a4203d59b255eac7d44f1acb4f558be42b67936f6161adfda455c1141e675f0629127620366d06594e334e95b22d2e5ca02257e66b36c7203431cc145a08bb004d5f9cef3318c0f558651cc3f436a29235588e9fb53f7ff0186c4df9100d08eb142fc5a585745ef46a171f759838625aca63c4ab3d068ced2c53da5f726ea1df1907192c530ef596ca2fe00c312af1580843a1389f2ce2001b2a65412f428d9e4160b41d0740874a8e68ac288f57ee6352296d0d1c11ef756f40c1f509166bb4406c9f2bf12ce74ca11e48280704e54f5400a8d7686725bbba2c44ac113e2668ae29e0571760dee0ed6511f72f57f39bb474aaf9d56f3cf6ed558572fe117b223164bbb856778b8fd74f3c9af0718a07175584ac742022caf05b95e93f662c4c77095725c0496e0c3c45aafe051b3736e16bac14d54d672be913d7c22b38f81dc777d2a6530a809cd94cd8d839518452dd3f678d7c5e7e726b698f56db0777940c2a89f01809de68bc3d99818733f5056d22498bee41ca451d42112a4e0727dd131385d0bf12c6669d005b00dd1c1ec21824121e0f74e73cab760ecb2d56a3d36342af920e3d53d1c224efcd0625394aa638e4ab1c64259bfe2acdd90a160909f856ec49223a3ca7c20d4bd490564d7ec5069ee58d5f437ba12e396c206b32c04018120b45167b0ca33b1434232429274d6bff3a2430fcb15503761db631c22dcb6985effe15e2da0831e637212c0c4ac554d468712a15b5874c2c1777539e8d202027ca3927afe12c1382cf8a1df557152df6b1a1508e01d05fa2db4027352b7f6d56723104c5a361758a862500b4ba131b2b45a96835c883164c5e584bcf001c0a629bb866858ff73f40309521601a59519e52b9430ad53b2c95e85e588ec0be4604aa4018aa78bf1bf981255569329639f480d308c3b7961932e8ab1367018f6eae04c508aa998355ba0f6349f8e2833e27c79651257cfa4db5fba2612bac6460567d256a0d8f3371aa7b5c399bdb261933474d190974a37494bccf123216b5416059d5128e4916026602d95ab7233005d864a46ee6b6b20142bcbf3e759ba31fd7d85a6f97334749456ea941d818e62c1227654fe7b18e60c6bf946d9812cf5203907f0c2c98256175f889138a04b61df908450b80fdfe592061656da19964191e999d55784f5e71e3e87644f17a85737717420e0cf87b6a02a42c069825f74084f77741b1d07c1460e91b493feee41a6eb7e67379caa2508787300140900a7044492b0cb9c71f63766ad14e50b8b7434d314020e23b7e6300fae218dcfdb22eb2760173207106176384df29b4ff5d55b0161b0c1ffbac76d13dfc7401b4282f7c0c3929f4258f1a18f60a186129661208a34f1afa022a39202ada28f8ed5f6c1e8d6667ceb80d65f50f74064b629931773a7f5ef98513298a30d6321ba81e0ce60084393f87d73541f82603f7d2f404886c626c550ac060f7c249495df2090b915b3937e734136751cabf5fe0788d39363c6a597933233e2564906274b9c20d25afe85973b2e271fb3d9c5dc8b6902d39c3d60fb64d70688b6ea92dc4498a6204082e035d7b47397c6c99660f10e958c0ade75a10cec41abb7a611df7cd5c0925ac3d41c1ef962ac06dec04a2caeb0c42949a581db71c109eef811d41559014a1e80b12a642291a9b23c90072dbde137244fd15a46f486354e73c155591ea4933795f39989915033d73731c7aa704547b6f5627b930606e604bd5252d8a7f6622ecf6452ff014397823912035e5d402f746130335a5bd08ebaf4c44a586d80b4d7af70197afd94696a9213b4735f13a56f5a84341d39f752bb51d596e55ce216f91616529da060428a6ab0d1c0f7d3941e83e0aae0cb745fab90b61128fa94845e7e8779c6bc7762225b454fd5adf2a997dd7767d887e2af999356515051c7670769d29021c283c5433430a097b0f4b0e5cea0c14dd59645fad563db9ea0f477f50fc51089b2034be2e99110c08d2145135ba6d1f4a653c902d7137c735a86b86c60f38e432ed1ec695d71b7b4e9b1ee0aa56470ed8fc07485397260ab2a140a85fed6e44bf87748fc3544cdf9c8437e38ff97305a6c138fbe6fc4027522e4fb243464174357e2c088f5a1fe4c06571e00c1159cd07f350c7530a25bd7f8617c84fd91361ce0d6fd61d912490686b33ff4abd65018e0c13df8a463ab6a08e333c0f5352da67a42c6c7c01385bf7ac69df45a2077195701cd3fb5226ff958625fd626b045c11c942f6675c49453e947709facc11f18c115ec3807e6bb57cb10cd61aeb07002bdc4a1d0f156ea425c61f54c4620a0c650b34ec06b32e1e32e3559c76973677deff3852c3b55f53f26a74f0ff84000ecd1635e6acdf7344e7db65b48cf92a989d651a57726521800b451605eb4f29a8f34a50b8240e06d0a1e4504dc9fe59a41d8351fd4826744a764806cce4d52c8595856e4e1488593a04d15138d5a33c56bb6f3e0a8e41529cc78144c19da865437a501b437c6e462d6c8e5a550cc05f47d8920b91a29d0d21211b57bb1dff22654021008d3c7a21eb18b5080236b203b22dc6294d52171e5784f14ac7089c54e435426a0970095de014ff38ccf22f11337fc75e2d5a402a3d60a36db258fc0d6829be17170d434463e0d466ff89ad5f6b845635c8a1f5634e27ea2635e36059afd8e2359a60bc5560f1651849cb1e55b22c332e1aeff43f6ea81500e291a319987f98254cfdb80304d19658445d772ff627b0756154a76a730a5e0a1e974019b7f5f22188b7d56dfceea05e17614e7263471e4b51935d1101ad6c1001e780306b88b24f691b1131ea0d8d745f586665ee96c837bf2eb04a672b1b2f9d68b76f1e62532f3f087867019eb1204e370d2992f53e26aaf1a73418520f730c78b26bf2e6bd328b02434524248b49aad4bc10a7c9ae1315e29c3eda6be31d8bca1d31f2689b2606d4ba0f1a06835d999ddf1c749d3b35ea3b971f6ed02b1b9a4ae50856a16a181b2c0216963f35285261dd31d2a5b9565b8ded6f46c7a430303c67426101902c9ea5610d38cd4f3ade1c9510f6a0400d33dce5312b02f50ecf56c76507b2303268a38c279b535702e0332e5c29eb3a4f23759d1743a153477386773bfa4c6139f982b4323468125cefe7e64d30d09b4378857b2dba03ea55eb290149287b70679fe6331d4019d0080893581dcc751e2602fae402b9448766a4fa692aebefca3d6f6229686eb8c9144c831965e44fea0cd91836630de1103aa6ab77073a9f830808a0ab1423647b6959b7473f8625c82bc9d64b150a965a5d0f36c950d4586a24c072380dde36c42abc73e032f8035b059f1b7176bf1d617333a61b21ae6dc21cacf7373513799f17d2599b418b50ed65548ef66e4fdaaf0b77e5c76f4fb55c2ef6334128bcfa544540b2b22d5e1a3a67ee50922f7379eb709858d572ceaa63737264682e133bab2fd080302df41ea31bbdf64a0ec9c3454d570b774d55dc4d582d618114eb1ea260a13b3b28a9a39677e4fbb64e6c38ca36cee4846995b487537559c04b0dd5266f8e3a4113559ee86a1fe75d6ba618be284b93992c6a61d575ec153b75be20a775b13243082fd60261ab8f352591c057193356e34f0931fd116315f64dbd976c0a0032fc14a0a3726c6d24b32450642d2f5ab49564e94cc44e5c08f42481bc6210cbe2d62c27ced5605583110a8cb35d68788360274a24bd4be2bc134c07372437b97ab55dd5b02c39264ab8679a8353605d49f65ed6d7180c904fc62a6389b1165d39c32da95b681a641cc145b600356d85967b5291c81d6e693b811a828a0c46c8f4bb3fd447823d3dfa6221523e1b337c1c0c17e9b6121754befa33639693448734f26c5ae09373939f50117cebdd298dd06b3c90cbd10651c10712837ff820ef64760bfbe2520a02746767897a174e7d471c311b54f1562100ce2a1010a2481c7f3b4a4084244769ab822de5accf59d72fa32f9606cf6db592e1093004cc53d4f9c0191949381817d34c508dee784f8b716e27c1a0550a0592af509516dd2f6cbb586e0fd2dc2ac58a366db9c0d81ddabaea2028c741707b14524a1144c25cdb43f632909468647d4b5834135aa95d98b8173816741315f497fb047ef9160b2c12f539c9a65029adcbcd2479267d28b0fcc9028798315d68dde733d9a5b424af3abd4a42205064b1a2c336ef4bdc19ff564340fa79652b4f078c36fa0e705f761ca11af42e8743d4ca2770010b5b5447c2fa7081bc37700f34886a40a1671030124d0c4d6d465072d4be143147f356b8bdc5662f3e962d5c0cea4e2ecee9482d33735ac034932239374636ac601421b10c503b8ee69a44f05acf3bab81e014791ccf746ebcef5b06106550b488596846e3a016b3ffe32a46663149b9113239a3a81373bf191f7326eefe48ae600857d676bb188f69161b2875a43356ba341eeca7e96fa367fc12ed317e495d67373cbc0ee953779dc963bafe40098a34ba447167c23405ee842c4f762525f32f2c70e576361b2a51c76db9178f49a89cd7476e9eb11d00d4c905c0eea12521c4f24261b4bf17b2b73346d43f3c468fb18d56785eaf28504a4b22b17be176271f2f081c243c68249eb70d63dcaf4e76a007237e0b2a555cd3d653e86ddb23025676678249f743506da8688eb7e9317d9a621284c4082c26f8aa1895fda96b703483058db94607c0249b0f5009941f3292a469cdf3671728d512526cb82b6cb06278257a1e1f25efb3be52c3060000a41ffe56f88bf44bdbde381eb7e74f1d35a3471ac0cd1d0a14364255e8a3ed0d22e8a7007002802c0cf57d3a6386d52d609ca847bc6d980d74ac4e2adfb99467b00b586dd11c2c068f0383087bc9bc50f2359f4d13a8fb357edda146bed3fe34f3027e2ead38896dd2d9ce444b6c756a10ae064c232c43134efcab27c955735de9212c58e624a91ca373e60232478c30295808399e1f703933d20168f3a6625f1771046f46f41c039222dd1ffe3e1127ede26b43bbd0f70ee79ac729fa9c8e720077be77f3a841114546a91a6b35cc3e77e7727777ae860af11f4a69577e524e75372335f3b26d3d6443a340b5f2d0433b4c770101d8ca75ca07226ec1680431bd65076b83b0be3be44e227559660570d3f65a742226fd6d0ff1c25ec1393f6773873a1e7278c11bd8c0d25fc9c88c2383814925eaaf001ae0c65f13ffc98a2a29fd1451810d48020a047155bf9d051e22c11a0dfb7a983409268f50699cde16df9e4d5506d1a26ddb095b1a5b1cdf71596c1c246d4d3b58fc001c36fd40b236d5158f268ed520110fdfac1675776029f67960218413454a607bc71a0f80b05ca7f0bf256ea91111f2f8655c51091403258cc26fa0730b73884770338636096f5a320c3641d9652d7ca43d0a8606b461507e122c2c42de684e53242a8fd2c067d52f504a0bfe52180ae36927d2d37c28d5201234ec281f091de13c016e9451076fb81a4d59f6fb60f98c6a55cae9a96167e9fe26b7a03961c28e985469b0b504c95cba45150b92655161e41211926d4180e10a4296de4b49ffb2943ecf2d423637a1e70a028873065ad73d5bf71913344410fa66477b5258ca46d930c7cecf59dc7cb16f67fcd068a56ad30caa4c89289a39fc7659ab3c46f5d9fd655244595669f47c47349f350af259dc49799fcb284b811074eed4042d5c38a13d79158f406da5fe50c1ec614d4fb7ef1ffa7e6f179c026f167ccdc537ea815261f8879a6245bd0073f700fc54f8ece2271ca3cc59e4aad31134a9863b9b3e5f1cd5452b5a82621e431bc0f0753ca15a60fabd1f4ba143c02fa1bd7816db5bff5ad7c2251e07b00f4ffd688b6a09f543292a06f86f6e7de80020664765624d1d6a478aab4b68b92b58fb80f56d3e08cd6716bc071db19af64f1a7a80745a612f27805567572f25af05675bc93ce536d0188a5bf112401545555288c13b4860d065d1f29a2191be3f6bb782f457f99c0e488f87521335b4fc1b3b43dc47a0eeaf6859e4f82cfde8745b1e79f3769cbe4c0551a7d44cc22a8d523117724746e046561c024e4046df1676f18cf428cf7c5f1542e50d29b271663b558aa94251886944973496768d99782f488c045cde61365ea4f0c609759c76290b49d973645c90496514b637d407a74e93a17128b3d66e3d56a2eb22fcf3155a7f512638a6613f36f5150173197c2141bac93952b441f200e3c84960629f025ce4f22f168bdef314db43504ab1ac3845016dbb230097f4265cff505f67961a709e260c4f1f3a271e6c0c485b3c7aa439b39dbb6f6a36b90bfa1c613bb1bb2e3ef1faf61181e945014d83e85f4300567440ed86323194463cbd15ba725686a33291ff0829b11bca325b46d1656563150434ea5f6c9327af1b340d3a4994c39560a50a4b729af5746a3fb6ad5c6bef7f0cf2624464045e9f6acc1d9d144c1b221eda55de14dc1cca7612359e60146d331c678d8d52f382db1334d67a1b6175713c3524510f65b7d64fea62e06a4391bc25e366d50b924c5077890fb840f85d28102a7a6d269c617365a8846e218b219531738406433cacf149d65cdc57f2755e4fca6abc532eb2250c5ed55b2c8885af01d2af3e37ad61735c93bc8045a65eb72a94e8dc370692ce5041bcbc3c23ba120ff4f2c972a0a94461219b7c07b827f3016fb02e5d1105042b957eca2939af2f49589a7619e0bdda277ed715290b34346ebedc404c56be881f23da3e0e9a86323d6501434e64b5f00a3a128c1772221c32a2db384cad66ab6310fd143eb819395946f0ea358c0a3e46c46ef96a65c63713655928358039714b008d6a243a0c314b67c9f61f3d9409332257544193b5647177d44e5822d7a86e0dc90868d07af93721156a409820a949b44abb47f148a775a34b706aaf4b2c251d09e047b0bbfc29f0cd4563d433a33628977d2ea5215f3b47283c496f89223f155aab6dac2d0f47518b54747a1c2d4c6ce96227d7edf4320a867a71cd751a3cb86eff2455545e4edbaa313d6680414e0aff873b42a6fb3d91bc482fdc17f05cecb44c1b6b96b865363545594fa8a66bb5bad94eb185224c2965ab05d2af882854852a75263b8308076c2c23d9b48029f288d51f9d390b315f71d8550fef074865a8b21b792da40e7c5f2a63130201006947f50f8d5c5a6252fd955bca0b991fcf87213ccb64e42f4e60f12cf2e9f54bf512995594a11f6011ffac69bad1624658c4586f293b354789acda30d516981fa03a53090c57610a0f1f81193cccd768a1fb466de807cc4d5e8fa02ab15f511093aa6238118855521ac26502aa29f6441ca5763d36028b6d85b71f3e0a0a93300828ee466997a95746e2c602656e2e54b3086b30a38b4955bab55462511aff0b9719470dd24d8303518fe0326c0aae10e677e64fc3861303afee1876f5e06a08400a5b134a1611380ff8823e3db7cf22c4f08d244854e57076ecf31f15b49601b03b6b26e2f4a77730309f26068bcd3d757d6e3ab1d82f6238bf9122e627d61e2964f65dee792635dfdc980c9f6d4d255bc455380b3738427753b935597ed84e9d214512f1d0f21d923c481976699d0bbf364b62ef3b724f0559b527a5bdb42743ad711da49a921f15d58a5e2d6594567ea06154c49c4d733c794639f58f4b5624538f5f41c88c6ccf342026223d07126e5d4e3760d78b384d304d59b8bd803917225c2397997c38a434623c52b12814c307210143431120209b1977898d4b1bf38d4b6219f6c13352c3be4bea4a73074150162e69c28b60bce18c2893c6d3189f61c2100c10125e49f79467e8374d03a1d396154e5aca2d4e56bb408bb1da0025fac12aa55fe770201e44153c131064c3dada1c4091b6582f5be66ae67c9d5520bd2b40b27b53539705bb6830c5f2691fc37f3ab9541c320d1d2c77682394523c46185499e3543ca174d05c4f126013e175b570fef1556c02041650b358d722eef7e45b45474c01a5adc233ae13241dc0dc356e94205f152aa36434a7662200746cd84224ba580f915b07033868a368deaa506d92e7605ca56bc007f42d6d014e0df23c3980435a96c733307fa0b23e7715a56350838d59359db54d07442c56f7c1ef6008a383739dd30d51dacbdb57cda65f1032bedb475869ae7113970a3d6881b60f4660de063690fc343d60854623e4ba385770f17201ca6548b63bb940a3ead4778d210951be809e07a96a323dfddc4720a8ebeb7796c9ac25eae424628941b000f14dbc5cbab05047fd3cd91ab9ffef12daec0e316864d247b475080f9db5945848e57b0d839d1443cad6aa37dd261e779cff7d532d88f863225219391d3d7a003a4c7160586e442195f91260d5645a41532cf32efd7f641954cc491d0df04e692af05c247fce8b44cadc177037f71f5e088e5f0df69a5403d24adc3db107fe321d8f0a487505f64207cbf825544ac2205e300c2f1ddde21e3b90e7465063513477d13e71aaeca74e6b4a501678ced4113f8d8d33a8614e54e55f2850d5cab5115983805f52f7d1342b12cf54d8caf04d0feab057e536c45ae8224401bb369a63dc4ae260b5a6b551d2011f2fc07d08381b3f5a749185a56ac174221ffaf4d92405d1046eb274472c8ad05e12a6db2c1de63a106eb2a2806c648e27303ae3ab208ea1662eeb67ca2f1374df548081c6305a1f8d1dd038bf2fe62d23696a5e2061ab12a9015f4b2902e9e3ec17150559709f5f5b05965c0420b06a3e6067a00d2bb0b31b4b74ac1f140a9df6287cf47549440fc415a6037d182449bf2d2ec424182fbc3d26619ac1144669c531540ec12bc725ef1a6708b706b9b0d125738f552e4653e076f000153008eda4585a86d24fb20f835ad71498116475356064a65e63d2fb843fe104466e30a44065f43fbc2510afe2695cf2ca251ce5142ff8233c76b70e823fe7fcb35540df311e9a712b31731e344d993a86448ff5832ffc022a1f76a6854d4a799804bf270642c912cc6501e3454dc030ee03eab1460c749eb93a34facc49b41d205c530b6e291f2e331c140e2618665d8c12bf60475c5912af1640a71f2f5f2dfe10f97518706a12c64e73b1fa0e8edf9a07029d8d75433f77637c695548fcb5e71727b54611ea13411cd6955b50780ded720e30784ff7a7a12f82e891469e0c250b7e6ddd01f68d0714df30682a2305802f0cb5b34655e1ae720081011ba1eeec005d470e3f8e693c4ac8c3585a76926309e653925b7dbdcf38f9bf2f02251568458aaca80995e44135ed397b761ff70f7005ded55523add515a270c74cd1b6c4746aa09433556d0d2697887b700a07510975eb8f1157fe3d624e37db4466e6843d1841d92eb917a121cf9dd028b431137340452d431c2c3e362badea52b4963f652b4ad672ff4d341152af020bd1673e6cbbc9b967311791134157c50d2972f8129f150023ed8bec5ad7dec655ed983425e8fd823bf2f4a7730d11122deecde62632f62e0ee73a2217f89ca8308b5d580917f8da2a99cfc963a9e6a63960c76570dd522637e34bb121d2b1305f31fca466524b8f25ebac8c57cebbc721139e391b3dbdad3f2de93f3ffe507e177cfe5a586ba5844b6a980b338f00eb01ef485057a735da4e8108f031969bfe756975f0582b4134449ec59059cc1e653c33354f361f58ac4dac54f068c5fee463969eba70ce6d3215d7592f21eb04d8014b6c30178b23575f522c581c1cd7352c599e5563f6a29d6f54d869007fc2b42eac433321d504b75474026432175b99735c42945fc6794474b9ee8c08a740b75a8caae5280f8a584e44613b3e1acede36a836076b72356b2f3a1aa63b1467d03c5aa60a2723defe330753e81b3468e00b0894590ef8e5a208c82212758c76ba1ba4e8c95425e0004672f4651c669e035728b6de55a006b420760db15781bb0a26fa1a8874017d087642219b410013b324d8332843b95649418a83784e9a97246a815bbd634fc8b80cbd29b25cd930b1325591ae5c7a3dc4521e6049248ee0a860863a575a9ac3fa7733ff13407858893b3533db0f3b264116caff13270d16de1f452fe3068eb636208cbe10589e9e4b076403be648e252047464b903b304cd10f595eb82e46e8aa05c0c0126ac7757d348fb57c220e73151d63cff91a6788a72729124b500c46965c1cfa3468ba8a6542bf55645e30ba4b39082ad735612f780ce399141b8140a8755b166c548ee4585614acb43517bb323d61968648c29a692c2db20610bd0e592045ef9b1c0b8fd429f623276bcb20be493121cd4263336724cd7b0a511f5fcb1d77eed4571ed5c7697bc077282865bc3ab7fdc6732f41f52eb53091284afb463f80b6d11ec32dd73e9859544666707d602872e65f8c70312cac3c3e232bac26175f43316d79f57856d69909064aaa01767e6b2e4c09862e39ca3b450c6afede44e9c32f574c52a72dd6e61a6501df60241314961e940a524c232f5e1b9257146e4a37cb366fdf61151cde806e6c388030c378c510ff046a7596b58b749e614033a4d6652021654033ede1b549b8cb2f5ce2b7434798b88447bf0406074675e4519ed38d501d3e1e6bbef82e2ea919f0414a930567f4f4ff2f0f30685d5d4e7d2c7625e2252428ae531af9390bf6509d5d164bbc2ab1ac5727e1f93e6a4095f0209a0f2c0b037f641c54271e504deb380ee7538a1d583395428111304fc7ab816b89ad572835ee1e145c64530f34c8281e64a946167895971fcd6831564d847f19e5242d422ec2b643fb9155695c88391889618249f72a3821c62c380c0c4085345c10732f72656942c16df65ae63ebe5677385c398c471547ee2faf2693467d04ee0ce03e61bab36b89de3e1907b77a661c413f726f4f0d3c10a402720fbdcf290f51a97606554640af6e3b6cdc59865d218cba7477db8b316640ba33febb833565511c2befd90d21547530228c7c293651a9d313f7d5f55be2ce2f75fc4233563fa55e2e6c40795c56621c6e9b38455f84479f773f5897156782b2452a13e4614aafba543e4f60387320ac506a897a1b4e5cb32a1038b92e9bd4ab4319aa47243f8f5c5ab5cb931ba0a55344deccb35a9a2c4c55d731c04a52b5dd4a172b54778cc29d064fa84b1864febf266d7c6f514648776f1424ab72a84d3046f549d43b5a447a1b3ee3df0a1b8b3f6c02be16216103a6571c61d63e9425113e3fe4d76108723b5f473e440bd43cc0176c459d5df6b3f856902b85103bd0dc5b38f07d70b1689b0e19cb7f5c92487d68a94c80353139e36e80557d0410a7564fbbe4885795f6db35828c746468ec395137ce4b219ff76d2653b13a0e8a83c23afd2c54013d0b4664a84e485dee13cb218591d7687fc7736deb94fb15b666a0311e3efa4854f1fb5c27c0644f0a79b714738e0a614dd29246f6e2e937682ba862bfe93a348e8ad26dc6fa411c1dc7c56c2147754c2ac3b944639dba2b62351a013e93c571d47f2b5b4193db60100a4b48e7088d2e35e5c01db6859a033f213f3384b1961a134bb3541639d90d81929b62b9272502d6e8514261a1b20e17b404176e80d02d624365464fbd242bf6be551a8c0d561b287e0b2ffb2daa5af7d1b75f3ffab633eb8b5a152afb2c5c502a3d13020f95274e432e337a66104a72b4cb722384b327a94fc3562e2dec53b25be529cf83223eadb9bb5d8e431a29f3367c67e294cd296d536a65b6d46e026f3f922780c3516fc93a525fd47f9a353cef1c6724dc79479f353e60655376532228ad6e5d704d6775d6ac11e4a2fb10b31be14314a31f74fa0ac50a39b7e16625d37b700f40bb1c15cac610b3660645d030c74a5345715f50b49405130ef2779d3550105599d3553ee2fe268f445a58d123a54301d17d193e01826454acc65798d1c10f635c156639626f334e439835587b1a481741656d2657125b036a9c3b1e1bc5044557626ea3a96b3b81c90e00c871207620bf93399f9dd9402dc6283cfb9b563bc25648465a529b74aed2a9188bec1c5c0c9a3970142e6552bb845125062c61087530e33e3107b6642d61315f9ded4946d5656939144743227c434227d732460091f5c617bd3e6675137904012725cd6f6fa6702a162dd26337c76161ad28421fe176b90ca3f1d12ad6ca97670180476dfea20350bdb09f091c7af01b004f0f227413d342ef78d25ccd0ba7574350826e453df6048f1b710b34c5f70b4c40a54bcbf20d71b97e631912c170096456ca3f07e4c70ef44d85438e0a5a5944df9c3c962a570c5bea9f1a58958c5561371d12c6b1a3341e2d563fe15aa90642cd7307c24e4d274c9be76554b7e855d97bf203c72ae75631a93564d414261acc380c0a2b392a494e1fbb677d31f564c39f691ff569212aea4e405b9d75c831ac7f5e459719d568b80bc830e1983531ef51dc1d4b9d834f31f5411ebb135a251b82f076f4cd94368b8d383b1b76e936526dc33b31a26757e2d0c3242b11e00b2bad1b20c3c9ee6073d16c10ac74cb1cac77813f429b6259954ea01ada1f2057d60c5770ca961e63fa48ef11926e2b295eafe24ff169b67772bad5698f7eef48b27bf61fadb9ee03bd2ad359f09252074bb646099b4e612a1009d5547239951b99a9d7754aa018277e44b44cc37278178c87e35a7f2f0b22fb4ddd38651cb512277cf23f69856a01e9b24a64469bad4382984338cee74077f4f1ca25b6f0ba1fabb112107ef9667288329b009a32fc667ec1cb1ad32da00e69f3046de1bce92adfcb21709bf95a0b4f1e350fd8a7ad241bd64253f55d3f3d9235ff41caa8a23fc0ade277cd26991eff79851d6e34e6465bb7ba6dbda7990dd4d99d263adf3b2a16f9ca06bb17cb2d4163bd3902f7e3088262c074858f4f2ed808023074b0de705cdcf00e86251335c217db61f01415124cc0dd0b7bfb0d5641c0f84ae963e23fc8d46963331bce74829b70383e07b6088045c0640848a24fc8298c6b7409fa2bd733c41977d0c446fa4ff74a8778db425b77713b78a2643996de7908e3ad936653f52d60b94bd95f0dc66b3c6b45014fb13b343f14d33a22ead7426f65fcac18a2637b20b77a2d14adbcca3cb882df56dfea495851ba3f285c7bea07445fe323c87f6a5304eb7034bb42f9320a869933292b7e33d06268759d58fc457217391068b39a6b539a6d2d2ea3f01748296b567f06821d059ae213e53b467791dcdf6f7d575f60416af67687a8ad27169f864acb6a2c3c60f95420dcc80c599ab2b12d9043fa33a380825f2c28624950e8da3cde3d90152feae46e5aeeb960e4c8cd27ed08771b4ce0b438b4c405746538631d5eaf684bca2ce441539e284fc09ec03fcc11773ffc215b0043ff46743478fd21855af962f94da40cf1ac5653da305e4538398610e0208c67124be550cbc48469882a9756292308687959b84103402d3d3d613d3be9362501f8d8fa4f6ffc5565eec3aa19de6670331a410540563942595da4542bda880f37e4ce5235f5d20749771adc10f0883a5e8ec5b14da6d09529f19bc72af332f0042804a408c4bab15b0ba77a66183750523a8de551eb97b256e24a405be3bde86741066057faed486285f900772e321a0fea0fe3519f76e06a1baaac31819ad9611a21e940d3ccd7531c2b224c2b49ad3fff87eb20cddc50775f923f336eb1e01621f5543100e4ea3cb30652483198d925d4c8e60cf421ca6f740a88302f9ccb2d8c39d86cafb670030e78ac093f0d6e0f58e2725401ac996775d4cc37bd411560240e572d932f1c00528f110ea635801236eecf0ef9da5064c0e6e4178bddbc2aad3eb74868570003551825765c3deb5894944415244e26053c870e6a0fbed72fe3e4593b7bf0cc48b1705532194b8a6abfe99a6e7201784b81b1cc751e8f6064e08fda02a5f4c60065ef1d18deb9512728450b288415eb5b3a8198305e5e5f23b0ab6d6ef443f869f0ba8312f6305958324a2945fa102b643a444729aebe2c47fcc03276e8877f54c88607045087327521f5325bd0889144654c204a6e7ff728eed1b5130b5ffe410b2d9650e7dc8c3083f2e41cb962666b3132664bd3741d72475be7467f0077344582103dd5d90e2e25d08b598edea81105be3161bf97b4093e8e7439d210a847e112885d296c7b2144a9b92a13224e3ce4808e51b7e650207453bf166d75ce5c72d9655379c3c11baa2a922aefcd720906732a26efc66448b3f43d71ee131122328f732f37f1720d4fa6175affd8835716a5490ec848de16c030b0752f67f72d1c49754e05e5f72cfbbfd871fbeeb609f237f6726a073d147b10ae03ee50591870d7b22d06bacc537b1a80763132ee1d01cb9c0451dd7a6aa018cd1fe86ee52c545170645f49e10fa2d48459b52062560ed27f20b6715662016f281fadcf9c259603d117a4e94c71fb08e71bfb6b3d29b816930b7d1b6d77c7e9901c3ad34c5bbfde8226f5b451489fb30863829d090a4ac84e732479680001c9406ed7a0686c17045d245260797222759e419652f35cff8d5d3d5b322d2b5b99d72657a54c036b548205dc4b1171353e7d44103d635a03870d41520b9842e1e40731c86aad2f99b9914f452508719752a206517e213cabed1939161ed64d683b3e1cb29d5a74909e2645d4e25a61a49b603ceca8931c1b842647d608f069525cbc01d5bad22347ad5802d17ca00c84814b00946eb1136164f728cfeb390e28dbc53d84001800470b65765b42861a856f700977666118f2f68d1a9dbc3e121f883b7744915056233b526ab1cacd153cd7425deaa359512b0eac22ae5cc3759611bc1f5adcb769fd6b4e515a6e4250e4f6b95782efd80655be647158b03555b843d7514d46cc09f06c6c37354a2007dd9afe3d9fb86302914512688b941105e3cc6528f3d84922916ee614ae5a2634266696522cdc4956d555a641a1a82d0518b38941156b6d3f3d8a5817c85a436e8e27f370c653ce1b7d661805ca05ff0dadf9cd12d0f8387093aade1540da3a5a91722929a08a032fb1791f5c4bfcec7610a98f722ca46b50754e2c06d79a8342bbce1d223fec0d23ea4509516b266354ea47644f898c0668a1e1ba7235133b0868d9492ca6d78e2d4c2d774ae8cbef0012bf206fe8718512c7679f1f78fb0334d7155031d2da9817059acf4738355d051c6c484e622d636e8e71e52be9274606c69b7c1478c7310096e0db687a732d187de1a42122e05a6e581ac83c2f2fe52d2642c51400812a41f3d41f1584c0ee68f6e41f51eae6b6399ab3c31d6e8190189099a763bb3a640c729bb420d8fc85109dbddb776184814900a92d499c9e6d7441936f0d733c774b0c078621403f961e0ba0100aaf9af85444b80b63a245cd608449584830cbfb01f869dc6426c39234c8f3646f6341f8276052543ccce8af1abdd99e604be324416bcf64364ea5832d1309b40aa8cb354805bace09d710110f146aa94c52f5721850753729f3344f5b3bb5014db095ab2bceca04266aa45f394ee86c61bfdf2372eb811a5a9c845b217e31240b11c26a3e0500c400ca755b3ed48e2c32c819de6c5d32e2751b601e16ec5bf85bdd619b7351825242e668773cfa20682d7a2cc61da6a54e5a9fa6cb082eb961028791701d3ef3df445f2c7c2061f25206330fb05eb7fe6c6a85913e75c08dbf162051725e7cddc3169aec46247a19535ab03df11044e1f73435efad6d9e10f84e4bab79481c4c36452eb4cc5137563436e569640c18d9786d3828a53aa8103f49158cf30595233335ae1a32715501195a0aab6d1e960fdc5df37f6176fba030115138dd3aca99bc42d7f6e93621b9c906c971a249bb021157baa5d6031d3c29264619d10a1ca8f7355cf06375b5d0df391722d100c1ffb46e4422820996eb7b2a056d4056f3a1cf48379b356f25b9b970dd3fd82a95eed507fadb4f31e67a1974664f9d5374da7d2bbeac5f1f19ea3730cea9892d8e0faf05ef60dd219721a518953598326cdcdd6fcf679970d566d30afe95ff1eaf94454740d3d93b6de10965b0bfdf057b0b524ae0016f18d20f494188ed98224346bd2a10df1715605f0e68ba154a2cae028b12ce02a62f5e151674ea93ea55d03815382dd6093bdbf00f2345a9d5599329646e03c7cb363c69e753aa07fb54c359c400194a42602904eb3ec25ecd4ee8437b1d2462e63fb09c644fbcce136f2670415493888474c3360c31529d8c01062213280c1b441c4891a43f9ab7834c0991a50a349aca3fff0eb14968ab884cb01dea5fbb34603147d2111480937a3f1f891f23ad3631129d27355c9102a2472a25dd18f312e54045c3ec3fb1a10a6d6a118b61c60cf649c16d1c21c5c43141cbe27205d927f56041b13f27f419841626b53172d69804089f3ba045350969437027503289096b3a31bfa262259f00109ac04361f94c5d3f80a76c5042a4a65250dea5636dc123010457e3580a4421302ca0ae3d716a743c63bbf65cfc2fab175c2d9544343b3028eb2ce215567f2a6dfaa1fe5172c45d60a91bac720639d770c6541f12be3bbc54fb15f377363ed000a2d48725cf217075c8f2ff65b0eb966ba5547f4f3d797f50d06c3e454dbd556f4ff223186c2ee4628689f72a4cd70b3fa7fcb82c6ff3cb0cdda88e3ebb8a023cd2b6886717e3ed094aa433560bcfec2ed5e58444a359ac60f465b151ed58ce68275d1f5f9808826cd1270f2c36bfed72cfcd1c34fc066829d9e92634b513a60263b98f09f48876021e8e55067e1d9650c0a323354a2a9054736a735a9bdc347302031d7672f17b284c4d7652c25b5f054bb69a0be901516c57eca347db25d1320e734a5dd1f38665281db0200fea2410ac8d713dae22fc70950bb857591bcb6a09f846638fb99456bdf0e1132c07963bc7ba2e0f03ed470f36b9db411a5b0718ec493b2ebfc8f14749e2fe4b90c8dd110f43c1083495c267466763704064145d2264b464212421600a0f901d118b2d484fcee54d67831d2e86c2ac4fe088cc2a219eac2a3b3ac05de01d7b0b3e3cdc1bcf9a96415a638d700691f90a2168d55d49807838fc5fa64213490568d747e73edf08aa0df4ab705ddbfa881b492016775bb6621dda5e230a2e4fb31c238e77521f220370ea62e9365ffb6e60a4f1bf12490d163258823c2f0db51d65f4b5cb1f98ddbe22fa8a8b54447aa94b4e7c8d2c8521652b201f5a6b19e62b008ee2410ae7b8f938dce95c6368caa83951ed84589c658415c882896737cc5b06412fea65fde5302da14baa6f5a2d4e4e7143465952ebb56dc0169d7723dd196e2b97af3d36c7ff2ec07a992de88b2f706ff7ed778788d4513e5aee0cebf68176acc90131e5e61e64f09213217fa70b1ec7391b0d874a9a47eac977381865040f2627e9130ff1806018ada63c3db07f014afae34b3190b55e344c964986821c245a469a0d97a396272291284846a5d62525266d0cbaa2b42d27399e1b622acd717b57552f1a13fd252238922ad2010b55f9f01f580aa4f84d1fc4336daa8f4d76b5767f45617be55668c1a842be897f2a54537130dfcd8c1b73d80146d9dbbd24c2eb3c6c6730dc12d998061abe22800a585c4239013f6b72328abf443eecac6b9d90555c45ead242b931da408aa354231df75e18452de90e20c2af3902fd6d36eb130f4f09597d399cdf15237657a8613015cf5bd5f98114acf6261f929f3a34c72e316d8e7309031b5b9977a9af6f22471dfe006235d1344b0613488a2792493fa5364d9a0e4126109a527365cbc341c43a975d6d48ed4b7e97b503a5debe35b5c1566eda88495d0f3d92082d0e916183cc1f1c85efb55aa29e602abea62746ad232b5deb22546370eb5c07ed922d3e66cd3932e07f6d11b62db827b408271048484f23d00a2211ad1ad232aab4682ddf67fd6e874b944bdffe663ad2aba329c9fdb5539fbc6c1f05ab8522ddbe61301be8240ea5662d2fec4c8b3f8b1aef0060366042c9dcb529e2c9d61783a682445106be504d556068182712421e6bb1291dda026a77b418525e8b373089c1e250b1d0416161fcf4411ba67322c7a0de634ad5b76b931d43453a2a73353289bf21f4f0a85928bfc070ec0adf1445c86245c239951e274b6036d88cd31bc183ae4d6598164ea8a5e56dd24dee155cf275359614f9313386a5098cce5b3af4d8bf6a1940ba6f2a6e1d5f38ee7141cd28e716c099816b0cdec351827c8c508f33996ce280a919f4387b3b032c1637d1417472af43c73afa57fb5459951740eb002b2a1fe92076c089470971920652c6afa2495a04f31578c1456d18c484554a1d963d621e8f38dc39852fb61b676b6fe6492a4e6918666c5e3862cde1dd0d4550452b3dcdfb0f82cf7156aa383f675e72866acd18a8546b61dc43f5932f5a07459f39450175397d8bc11a15936264d3b6550c8f0f9628a6b8e90d59c2ff6825ac8f4a3c937520c0bc5e100b50483b7e1130220e587a0ee8b54d37e891bc38efdc5032475ae6553a31f055d30b1b1c10b74763370d4e5b11ca90635a84b338d4d86e1914c4fb022712d931272c8443ba30a865a78750466c893929b6f17136713689207a6b7a256ce0d61ca23ca20b676de65f2df5d23fc8246a1da8d80503b8c9442289a2f045f717344d2172491e83b3b65d3f4e9e1f941fca2e0f214f705dae164a1e7de7260f483203ef0e782db81b2226c0cc7b342f10a32f25453072b86ffd6e641df26b3537254d8361993e8af1ff36b54eb177f79a0766cb4a6646bafa753aef45e30ce017ed5222f5b971f1ddc17353558b62e5fe3d543a5a5b6bc5ece735ccac2c4cf68c5746d8d005509c682603178baf2ecd172f1274c6771b64317b63e205f16f0821ef4ff6bab80f36917e04a6fd9e2477e95857449b7368388876348953fe245fcdcb715369d43359d2983d7cda863936e09a5e4e0402371e47c22744fb09518eb90d5885524a208abe1b39f2b3c95516821f2b5e46fb67055ebc1e67f23546daaf266e6e8ac449f2f82a5605adf20930202535578183144304bc035b445700264f811ccf9e3370387583338918d16ee76cb064dbadfa5e8390b3173bdedf03ec489d08438e0e59d148ce4fe778e54ca2b12d374cfc1b183abd973b1fe03d27c2d7c163803f1428cef97e0a9d18f2763628556975bd5d60fe131058119f960a1d62385107254520c95494707d6fc05e54453c19e1fb9f214291952ef6eaff2a7c67270a7c021d25d5bc901146de645b492dee7316498643930cf51aceeed1058aa972698d76e5320669d6333f557b48b364cd6d1d2d4955e1b7855a150d0510698a62169a5be911aad97b62c8c3283484da6503a22ea81e739ceb21c4642c45976f7306384f34475af0391f5a23035a1be83f7596609b4636adef573ddd99582697ef0600a11d1e48f3a06441f6d402aff58155dc23a73f0c0ffb77c49a3207140f7943e7c1c91b7beea12b68b8bb2d5f627c1c57bebf2bfee4394d54ce0d4e674ad250b6598c2a1139ed1760edca555e2b4d472762aa4332e30a2ecf0dd2663819f0355c4c403fc7024e0ea66402175d2979537b3f2b74e8928425795da3229711e74205f242043237c14734319d75d124434e5afdd01552072d756af4ce4a9d447b6da6519203746c3b4ca541ed70b8e8df7679be0b4083ea603c38263b6637e46f5fb335f9025167a316763f900775cc03310a76da034f65033dfff3761aa9e9003c65c3e14ca4682c2d19891e360bc6da1aa0a0b96626497373a8012f2b190d3c1ff7b81c1841d1a92c73a6094843277d43f6ad5c471c3ff73611fba54d1414035564839d45e457c41863c6b11562c65645ca00a875599ac64f4af85160cea1c8530c59f803be29c31f99d82c2230411517b1924f3958b6be1cedfc4b4a39c6b352e72785046254de3c6122113d96825f5f818596650090706fa98314445ae7de0eeb8f444aef9aa11cb131bc0e6bef8e2c932b8a595a341e4f3209c65e2fce420ba1c0971cd8210f13fe42d9380f117d132b1e0523bd66f218b9b3c14ae2592a566a765a1e99c4045e74849e39b210c45a8172cd67225b362b2b7e203e27ff99325516c72c20c03a1e3930d326e0eaa562f99a28729d73575c299eec00935be20ca9e14e5e8437af3caab0fb6c3d703e2b6962675797a3151d4bfb63554e77842612b89b357ec38436f4c0100c85684744af442e5ec7af1c54b38f5c3051545061f0651b74140e755a30ea585a77c9a52981dc9a58294d826005a7c72a96d1013051c9f60bb46ef15b9a6fb7754166ce3a0030cd2a7b16ce6cf5e5d334ec98315ce661d61bb7a30d4d9a8a881460386f3c32602a2649846d5c6611d92d94a4cd60e521cd6280402e585a6ca524711ac53d901b4d696189493af9f52340c1aff3659539f66296706e190538b90645a527143893aa40142e0c122a89b82789a17e1e682ea04303de7e0609183037e618903e862729603ecdac45027ed36b3df7f0533c90836d6877ee180b7fd7725874d275cdd3d1365ce75c616893f66050a5172762e2f13d8ded276107b1063b10f9bc3ee2f3ce48e9a6a066532d2e004f7063608de7be2db0131639ad72756879118571d950922cf78a0267de225c598b36a263e7f700329dae1e72eccb392b4a49da2cdc8d232e4dd97e50ef73c366f98e583b72d9562e27a6b13a2842990bde0e7312a12be373bf29356bc7dd0f665e423b47f9d51352a84e3354b470ce3786246f1413032b1bce5c306e860a2b1dcb7f876a08d8a61ddb59ff4dd7fc067054e34b634d54ea20cb68123ee0eff221fa685f1b2d7ffe2a7a1cb45e666a2161a389eb25ffecf660f8248c3efde89970cd171826fc8b83317ee4215ce0a2a2422a003d083b8b680ff21b3d6aa0892f64b1592c2f38b9be385b4c1607749b6e37f54eaa06eaa994776d72ce227467527625d4af742ffb593586456a5c342bc969fe19ef5a2ea11871b3013f576bab644979326432b7510f58eabecf192042a33c45b1965e2caa11265581cb1f450434466b4942719e1e7a23a605891cdb180c2dadbc21692a89002e46c47d4091372b742ffd886b5c9d980aa00dd8171f650204a0b9a53b22d3a83c9b65622be51811609c81b368207e01538ee1c30dcd451f22793a58254b86fb761b59d811e831d015c6b4ff2e3d9d80045be357119f3b49115d718d6fd1833768f7ee6f75d8ac286e3839d053a7cd8b643e3e164fffbfdc63f210d16458829204c6fcb35517a33a4769299d1616194569a7507642da1782259e8d8a0b807c6357d7df1743431d9b0c41e6ef4875f01522906cd75c0c482a771094d7336ffb6b46f0c60077fbb51c1303a86a4db6cd923f80cedf6cca184869c9ca622106a0be270945a00a339916389f4565362e770f4af456d6293c93f62f882cbd6f76fb4b4111fff85904ebc271a15de1406fb8a133de616703929a7a05763e3807759e5a06b30e9d0ef432ad2364f5851ee416e42fd90eab22424d561fbebd444523ed063c4fa3b922dac9f94983665c5e7520327050e67076ad27743b2cd2b6350c51da1a6be86f139d2df00ae5fd1a6b4ce9ec75d65ceb5a27769065afcc0f0d1364f533d33347568264b957ffb96e5b841f9a6db9c510412603fe106a1dae6e7828cd1a82a1a9432e076201b60a25204bb2ad023770a800326ff663726033173415261664a5f400c3e3e52bfb8fa76a25dfef6465056c1ee8c84605241c9e560dda752421e5380a138210237d22e041dd49c1272e0de835d27c672b57664b3c59eb266f57fe9837f802295d71d25b26e6f242042757c3637d996a0e557cdf5eb343575063bcdb53ced53357a23a041be01c164eb7e057484ee62f63207ef9550f57c130315636350fb53c4e8f923075cabdbc05bba5a8384189834e3838ba4c61d557400a398d5ff1fa7275ed256e3c918bcc29a161fe3d1e19202b162e92561c514531be676227d9e6f234f05f360a76eb410fade2d004fa99485942e6ca6b583dd31481e7c317b56ecf5d4e6bb14e2b91605d5a0b393af9442b0d72393b108d58c34676aa04021b95bd669ca9d9636afa5a06f2fe0e4f516b5461db2fb110d6a6f0270291880f371c8e4e3b87e800b70dd170e7558c77c127eb1fbf76ad4ecf7f26065ba4c713a5bcb5490fe7d54eda4c2318158dd41d14345a653945fb337547885b426b8d4b9601b42d0fd3df6c24b95e4c83fd4b10eab0392e0b651b149aefd64976513009fafe1b0554c37e76312e075148e04942575244626e44c81eba21d40e24ab610bac6f8b7532bca84414c0964d1e5b001012f8815d37d4522885cc4f422604d357cf733c0687b5aa46ccfc0109ea0a84760d2d8a76092eef2c91ff976bf95ab073a5ee944446133d3f0b18995bcd3e6b48a08e9c29fa968053e7a6645ba5a5b159cc5e7e0b9427421ecb9e0b473cd96f349006d225f6ace010ac59a74131f741196c667a4f2cbe893fe77fbb2bade5d772c297b519ebebfe2e8b688d6e67d5fc0bed949e704f73a9542886e81d25fd770eda74fe5d0982c34f7afcfb4c4efefc46af6c50561d6cd74bd55cbc28f4a83801c089af066c833950b86ab200ae81d16ed9d88e00bbc76f493bb7831c3f97d802b27b063464e220322bb56e03dc0a6f3d3a7c85530908ac3a5742d65e101daa2d4e9aa51ffbe8050068fc9064fc94c3522e964e29504f5d52b99b8d400c109b0b9d6bec2bef6e73014e551704116e61035a0dac3e649af860ef56b215f32313694daf5a7786005a4d1914c453173a3d727e5eb201ad3df705061dd3688fe4c12197b6c5088dc4cf2bd9efdc10cee0215da9a55d2b44e27433f9a811265f60e710de8ec33957ed2e6eecfc3d3d54e8415e319c653661c792620544d26432c09d0fe6bc1d21ea57d0631cf3f53a5550756724487122df8e4f27e3e3112d88638173836ce10129117f75df0d2d6e17c6f7261cdb574ef9c853750979034cbe76f4005565210e9b0048661cf25758569dca049273f92008a7fa5ba33f9c4de2a27a30701f970d74f01d3dd66eb05773c8b25b7794af4eeabb162e7d2da13e65ac05411b657c5582ab1855f763f917c01f444a1a64100ea6acab039f040e197d5014398d46d3399134621c3eb0b13403a74f5f6dde982c8eab13772a520e6d4b47d748c584a223254bb27476df1333741ff63e8da0206c590cec32c81cb775200fdd65068d1774e1102e13759d51355801291276f05113ff6af63a4e0a6a47b7eefd19f472a3716b7d1616949d6965190130185b248025df66ed23bc03ef08816734705cd8d9327d279122f0cbcb2a7f60862af2a6056be5b190505791c45f72e6122fa1316e17ef34615bb122112693db0d33f60b075491126722e22718002d996661dd5c994c6a7ac364d8aac93103158f0e0e05db00fad3be1af4062f2c9446fb448e0726060237a932b9b9e761420d06082eff376dbe04ec4b21bc81163d08001361f1224e39cd2428762aaa3aa9ca347625e26e4af5939c43a56e2c093185693c80b1ce37849794487ff93f1a2e2974753a0c603f96ae7046b86ef830cdf31e67549e78075298445fea81c842e260522ab0cdb54a19e7382ba96304684d506500dfc3142e0aef4557ff3e2f4b5fd23d19531705688b166d246fb9472b457f88760a673426872df8475b501e04c7e089763bd397323c2acc156a7b81734ff4c852a03fd94f8e41392eae94dd2c03324d423b459251c1da2d48d2d0306536eefe383e007d44d7f4ad00e5782a5a5b874572bb6d2b431a476c3e1d7bd513a5e7fc590e6e9f57431f54567e78b750a00162583861524b91ba1236561e2f5aabcf1c189c09c8727d65280424c2a63b3624972f774d8259482573583c4f2e1ddd40db6f3e213109e51d4703131fed2671a56d67aa44b11ae0a7194ad52fb77364ab0e311455b432d01bea6e7bbfc70a3faf4620a89a336c9cf7b0132fcddc034300442b95116771e4b2f5356c92d6343bce1115f0c95e1a7014336b8c9fad29d585b53fcb02820ae1537602e7a2e239710baa59ea70776f18fea571702fa470cebd750bb83908670f3d5c25e099d008f6a4721821d57759cbe190729fd95718f05e1033a32d7813b292174c59a46e355054f305ddbb752498eec54b84adb56b6cd7c340bc3b9d01eb26352972a0315e5e34cd6969164c0a0fc0a35df7e8bd63b7f2de56e845603e44538335903e9b5a1d640a725946514f03e2730122f77f254afdb31da179840ff4e5824d6f434a4c71c3ef3b460f1a777196d0023fcaaf48952ee15bb8f11209189ff701cb78db2a398739403a7aad2b774a9a558299ca055b1abf413851ec5dd2556718eac3876c1a827d142dab0519d6b9334f84cf6d33217f973d579e012fee14c372cb2b602d3c87b32c2a03ab47970c8f5bc70e72333b36b84f5b10c8389b1b310dc8ddde5dedfbd940d44770268790753c71348b42903c224a186c0004a5f2fd1888dabd4b4537601792df8b2577200c36be0c0273b0f3284c8efeda1880addb368407142d1567ee692aba1757f994181caf80040fef48ac52370b1d7247590b4c0701cb655470695e5c4d58500069377783066a742dab9200c2b685599df08659e8b97219b5d68c40844cee612f5f315784f0d95d870f12658a7fa86aebcb61038071e1760d56db35c8df1277af69ba672f06c2667992a70daaf3c9354075261079828732bc5150106eb52c0194a6d63c7a80e10f652d5819e210f9364a19bb74c19d0041b7853915838db066f76b0c4b9404f974b976c0099fa853349a118a6a6c448755ac36e867e82ed108dcd0d02dd418e43cb41c9855b22fae51efb0a947c136e85970084d6db5a7b4409ecc3b6c198859520b1b224a5e5082074ccb49086e39015e3298266636e758339f58eb109e13771eb2497f5c7f346517e6af216cb24ed01c0755df448c3de0195f3ea81eb85b3e2295a91c2654c99e6f14e68e080e914d1d56f84d02d33e256ed534af7642442023540e5d40d969331b11410c5d8369cd14246b9b608f6c97179329ab3eb714e92b7e0f2f3cf6ba5538a12e3d71c6ce313db6d542472ef09f57403bbe100977cc164ddf7d175ce9e041dfb4f456638eb0417f02b26700a46f31f47eed330e134a60e9231a0f4a30024142f99a301a751e1b1fb5a663e91d4c4d911a5617f79f18265dde5122e88fc134b9518658fb99405dce0f1e33e388ce025598a04d3e01862b88bf8b110ef19009e6164f20089cdb6bf4ef19550030e370c2d2e568b7e0af3945e85816d76ae40b062b5d4ca9d35c2b03c70e56e5b77f1c0f5eed65e33c1d073af2091e3844ab5a003fcb52326843241420c908ba8fde765d4ec950bcc4d32dc6a25246580fbe2da86f3f0959b87c28e28a450af899c56a13ceac0fa58cf51611a2a5728db5d563ed1ec96e3447015bad9ff40062e5767638555a4ea203297470fefc493ffcef0b41227f20d32b9c67e6219e3bb8221306f750576bc8c4e61799c56f6312a8684e3f725e22429e934f58f70975e50eda075b8f14494c388a207d28213b028a9e2916a1a45225c1fb19de0cd61d6a1f4900c2d2a2015bb21977d842d7011ba68a18246eca3357994a049a2c9d32feecc7348aa1df031b3efd1743959d554e914b6b09fdca65d223151467c2590d9d66455b27edd2399ae4c458101ad419ec198a3c98363956789e385cec8f2019f24b800af855683f86e2f637cd538642370cf70918aef25fa563c811a605a806d4effd7069b6d575cf3b190119527f15ad9fc20ac1cc19204131a769ec9e6e5a8718c405210830702b34467712dedf0eb3de7a5d8a371326f2bef93676f4d729282dca3c8a5c475e64441c0f4da7364134336b1fa2f7cd69bc2de101bba45d130d3b8e23dce36268dd28e00067a839148b2be012f186472d44f7531a385aa84204b8376154d0c907d9095207e0e54518124d6159f66a4d1ecffcdf4aed341b4097dd34480700b138e6224627169d617460a0692f75acc417652455115df10924d594a33751023558eaab2700e63d3e4b740fef334e80712dcad0eb3430a98f1dae476e5561bea056bee86d3651b3a1088bb50f4be312ba0b6eb14b621b7d2d1890ec312d571264350d959b03d68256060e987f33e75a67024326021a9e954a7634edc5482c1ba770be954149163d271122e636661edf8a0b923247109c70ca51c89e1f52e3cee20ffc89b43a3c295161645bbe463f66ae2decfde22986dd12404d131f61d04eae61850b4c3fec6be3675e5faf541370340cd5133c72cdc5c025ef15353f84f2013a2be8e54f1e438562a6853f13e08fd612dbe140102d01d763f35d2e40056d096faf1f016283360524285a0d30e8640054e930f42bbbbbd33905468715982b33406a41ff6966814868b59e1c0c1389b92668ec5146ebd98a313d773550bf67cd66c13ca759ef9095190f3b9b08b1f3962be91b665461de4c3886bd6718317f9a597a6bc8638fe2c167ee66872d00b690389ed0ce4b6628cc5d3900b53e437b2249322e323a803883444a7fc654534ba00369e6070020e589009fce8e53d3779d1f8198f76879c80137e387ec3331739e06fddfcd4d81b95774d31ffb05ced66009d27f212b0b5c9740a524fd0d2c539b5b09d97a05ae6ded5fc6d5613b8142ec72b398b3232dd57b0ac3bd6b19e2149302059b182395c903016bf9096b0d06aa0b09bc293bf57103404d1e496e453d854eae29aa1effdc1b0b780d404f5e14b44843f358711f2e760f6375246a9d96a83044d5dc181a3c4d64037ea863a472fc4818bad33c09127313eef25503b1cbe110ea81f26ecc2b853d709c275006d7086ddc5fbd392c598c2f684ed93396ae16474e73e047776b8856d572e254a8b6b2214a649f09539847356e33345ff5505e4d6cb89f6206c1ea30de978828ff5dee30af03e108813b553b205f885ee4f9f61097e0c7504c6377453d37232834d81f36bcfdbe20c8657f3efe94963136f79576e1472d1c2a83893f47db3a54ba619815a61bf137ec07cc086b60745fe797131749b0c56aaa58c7275807ad5b267085270c5e985bed78c84892240a371201af33b551dd47ec9fee2f18b4d46cfc7de85940d4611ae13ce12264db0b29bd96b262519a400291126b132cf33b4cf3e43b082f8a2b39884eae26d7d96635aeecec689f608b615b95d85a1c47fc39cf2cb735940cea044c940b04462bab2036b03123186b6759de941e0357787c4753740548b6a5086e39cac5368a702a0d994a366491c8ef4af90cea0d2d01f60c1c739f0fda69ff2c4bdba6430f4bce08ca689413ee60b74daeb98d58cb6bee27079e9544d224d2555aed1e3282f559109e096e224eeba93c84cc1f11e882d84a304a743437636c10ccd54267b45ee57328d7d8608338d3025574ff7662eee826d89c6e1a1a6c9d08e1c5e91e2538f02eccaaff0bc477956b5f3cc4256b920e1a49c89665272e142e104ce6314fd6773dfd60733763e7166eec25dc0930306808d334ce56b08f683a8089a51d19f75952732ebb064dca0040b6d70c6163e84e1c8373f8206a8ec3012e182e65d19791696b532c5d31910000205d7375814a6e296930ae1bff660d4e1e3bfb110204e644da1d2b02fdcb7d3be757e354d016e47294a88a305f16894fd4b1d8167504be479ef2aa71e225c675efee630f7597ae6de6b8ed2a9324482fe503d316b821f12e38290464fb412775defe09559470cd13a5789e43a5cdb55a638d9470242d030d04cb4f6a97f81e0e5c092316ebfe12560106cd6a7fa36940ad97571236c4d145c0eba868a45ece52aafd0722071b970098d4976a29c31e12945d76287009e02f1176117359609c66405ade40ce8eec680ed8da28d5179f1ac62f410699a1a871c55bc056359576274e271b71cfaa510fc7b4bf1f53925442dc51ad1eccb5946cf524924d4f374e02fe86fd3b7121dc4818a93c1566d4764f8c94086b6741d1485abbe54cfa2e624b4a605069fe63b615f457db551a94dc3ad4c7ec4f2f8e8514367bfa74c479d7169304571ef3c6955d13ee145d1d273d734bf2a971af1d2a3099609e043afede0ea3ebbc2a899ce40057ae562988f18f03bd59d347d52eed51091cfa0e0af06d1cedda4150f39705013c99c165df31b929937fb6714efc7273f9a1f126aecad5739cb9390de9419e15e51fc948f1520402c0a42e336491f60e720b8f4373de2128e67fd652b797ce32407725348927eb280c498d6781f43f39655e0d232b16eb62593774247242bd13eb28264a7313f9134524581f9fee730c43df1955c3a6612fa4c43570417b6e480e69f3004d01dd1a75732260f30af55ed3d5434ebc2fe819e2f89b038e6f973a64639929b94f0a4e161c87424da4d4677ead9c603e526b338d21606992d89334387aec05726532063aafe61d0a61bf0f2b35356610746e26a8a03e45bcbce7228850905471bb36273899ce772dd90c064eecfc3d3093204bb8e62a73811160221865b026bc5e6e159b72d45577adfa578a6d8c3a41dc026e399dcc6bfcb90f3ce173dc1fdb3d174c79d3447679cd8f23ba0fcf67a49a272cb8c7a763e1b9b65b35b471584b06e216925df551f02f827037c3691630bce01b90e9fd75d50d4c73e1387a52b5c9c508e1db64335a26e32a3d942675a3460e6e3ae12e281273f543b158ef461e69b3680e17c12181c627375fceb4501c15b4264b0a6b1816a3d907dfc3140131a9a61d4f4b8553b0e0f84ecf9d6d44969d875a9611b82c2ef4672f190b155a1905431bf7cb5e13ff649b4144f4a45b4f6d7a032695556cd2ec3e4afae6424890aa405afece642d10cb8128015dce2ce1daec4f66d1df26ef69a50fdc1ee5407ad8ba339743433c3393465f394a633b3395a64ca278af51c81e73505632ab4b52f0d24740c43805d3894214be5cf927f8512a6d9a7c803e685fe15c49dec811c853083d744a4b56fb1f333e1c509d767e9b511d03ec8b46849586443897bf031fd9221a0be2745cc2e37960441ea6127681152f3b1487637126914135015d6258df8655ca7e65766ea34f6adbfe17608915f02361d4a514440b3c374aeea963a4416b3512ad4109ffd80c4a54655e16ceed595b8009466502cf064da663325e35a42402acfaf23723f9490f50c96a242c36864ac8ef05062ac78904b95cf07586a0170f754b150805aec0067fca4d153482323e8439a632b0681c5a96b5bf5e290a972c1ec2646788e7584ef8323d0de8890966d340630d5f4e645d0ed2c94d367dd357ffe4961f3e0cdc018706a6226bbefc4cfb4d6176d859be462f67ce622aba6f1ba7d6830d315bb4704f2d7f6ecc65cf125119623fec75c650524da616e50633227f2453434584980ecc0e9057391b7b0fdb45315393c44962aa415c1ff042010e27a6200ebf88cd7462ed7e6898dc6b445787f85909403b2a85dceb50d5aeee2bab9dfd5879b5db528ba9207309162e76ff6d0a658740c77674e2df2db5bdc2325b28c7581b5cfd040e79203a9a6a2f0422e344428a497554990f7132ed9e646c93e6d14d471b7853f4dca85621cb913c052d366e8580823f6a593060935215589ad946369cc5030a5f062b537a6124579f53824219742a502234646998a81c511fd5316a85533751c160e91d93eec91456391b700f53994d60f50835e75edb0e714c5d3bc4c4472dd2e31a2bac37d23d2f676b16f55f46773bb0f01fd2e0a654a3cf3d0ff32d940acd3545215e0c5307bda746608d9a243079420b51d1659e4764667240746817588163121c32ed3e57943bdd445a9cc1169925a501ed7ea31885bdc30ba5a8376963be4c376f3dc46c844b2268c80c61703a4e464d29c46b12a0b4d808ab82c646c087770708bc3d0262690a57ed3e10438651906d836a3a12234dcf32891d34524f97ba1f5d0d7c190e6a784ef2a2ff02fda5f034f56d5d2974a38534549fe63cfb023e6458cc8d136712f3053e572158e07d5751efa7350439704d5c4d33bb2477f0836b3cadc972cd7945174ea4f10a179e046655931321e9593049110bd11467314d38b6552e6e7a64aa2bdd1b4028a760a82e984d233a84fc390d94af9f4a5b20d3007d3f6f23045c105ae49c1c5bb768765b89f7d320512d331b3d548a1af11def5c21699d618c58730e948d7344e901581d74a73f4a5c287333f2200202a7836b2e5978b12133d43724da25931bd867664810c9ae2500c89e64caba3638d2563867c09ed22c2619825a81c63b14ae447329f5ab415cb5631144472c8f0c6f0e6453ead32d2c31e39a372a23a50852ad9e76873d46101643b35a4160c124d8ea626d97ad0d21a4206d6760d8af04a8854c2738430b3f85f93a52cfa5a76ef534064fcd7fdc15ed3b7e2d2dd92948165f7855c47310183f751a6796fdd447ae15104bad5452638932980d59861a61705b4a6e0414df16b3aff81d31e6d715a98edd63df622a35f1a33d4dc9906615cbffcd010dd90e60f0d1ca53f3062d03548953265e08bc380111df0e0a96934a6bbff8659e15c90366d1436749f25c0be669306be8e8a92506b765068a6f595f7c2b2c23b99e2f705a61f734701d5850fd75e4755eebb0386f8b644472ec3873ddd28f25e206e4601023ad6e65692160dc2c133b4cc4d645e5e2294c5f687b47e008075b70fd18398137fd3d6031020f9c2f9c41273f6827ac90684c5114224601865201eba102706f860869e2dc676c8854475c1595fa21e73330243cea1f6dd4e69e2cbc7a551f4d175243cbf2cd64c558aa5feef58a3913d697145c6bfc30158b2775205c365cefa89749987ccc1adf850e3476e50076bb85dc30be0d1e327c99ec0a1b68150c6191c228529e2e28bba6fa661036a967f3dce528514a0a4f9cb5ec2ebbd4a92a9ebb581a593da80faf6b9d2ef280287194d96076df62e31d7c9b9171debda3540fa19f7197904271b0fc042a7cb18f67634b062e7d4fa40f5eef346b0171543af9a5ee0e1fde451613f8c923203e705738ecfb60532ba041fe03b0322b2d2a49ee65863a69503b1dec203705569c2c2ad939f7182569835154407d6506eed3509a321033c467f0246519e9721455651eea285c235b6be1407e6af5470680c3039ab8d619f3119f0183fb4a5fcc112c27fce63310181c52212075af763047940d7f3fce0b912e2e11dab9de2d90890c638237d75c103e4f39df7a33441941ad3d253b1764094bd040e95323459523a019d6830e11d09992778db4b46bf42b2e1f85f384310fcd335e0acc0e77e3e1c855ecf76c235ce0c14f81942813496e2d699458b16332fcf52eb004c70843ee4a7786a7761146f9386769c6495c2a73221839493727a91dcf73f756c55c458260070b6f0333f5276f0e086732348753463905c9d84cd7944f68c89c0a08130aea3350de413603a4c816f8686f1e8f922f6c4b623529899962622c1a696390a7ab50af7cec1b53afd01ec1204039bce5d7254ff37702c880a9084921e570adcabb2d4b20cb2e026d2317b61a372a39023832c99ef8452e767f73e557ea1e176d8d51ece51202d36e061abb4b9f4db64ba1246f96d01dae69e257452974127a95cb50a58a193c7e269b12f6fc9c53ca90a069ef866d3da4181122caebea632dd6593130b378761a339a3d615aaa3172b08b27217125104d8b05640d348a1fb569ee5d19a18b4bbacfba6b55b1382336e2f44334a1c456bb86a16700f54575413aa20ac7d5e259f514950c557c2e37fb7b643c279d9532e3f9d43b9b78703b3536c6041378673c2f7eee42eb9efe49d2e9f465058012485c1aae44e109906d62376b1c8bedde541d0fe35882deba1730a8f65b8ac8c2757238df46a416ad59e4bb77037b996d106fd6c84eec17184d370e81500cb576296eac2762e7c9f151fdd87645ce8938687dae764fe91d715f729a89089f8a13532fe8ba1df5b0b904a113243f680e9347f6a5536281ebbf02c546ce5986fcc41610c8516f00f2404be4a5505e021c5c309a57c224e1862c13fc4db36704d8784fc56c2375ef32956469617b2663e3864c77f0381f78e16c005e5194703c04ef573fa0a871cbe31263b675bf33e5f6ea7386874a06d44ae245024e3b17c9c0de6062ac0f4aab03065561f3291fe4758f6709dcd46191ccef5ff07fee722d204530976e0747a3d3952754a4b24c7cf5c77714c8ad17dd608551b644af578acbb618f92f0115af9f955b22335165eae07d1a1b86d150ad739066f73dac2c5e31780a9fad316c7e46a1478c2a1c15575be871d2e7fa4ea402e202f5d6d60b5fef6749d0423a6e554d995ddf1c6462e8070b779eca7f08af6e9c6819744d17be53952fde49ae160456c21412bfc9516cd7d755523ddd029deebc1b14bcc8419ba7fd4ef208d8436c6da50e6185c11bea70d3487b7c135d60dcc25a4b1cc53cc924a93540afdf42a0ebc63074929e2b42f8fe58bb2443366dc6115616dd700883f69506f0d1b96fea6892717afdcd4aa8cffe5d45975b4f0ce73f474fb9594e178d2845e6cb33049fc73c67f0046b5d595d874673581003ebaadd613871951862e36637e8a18417aa4472342b159d2dc6f7d6035f6fd915ce440e091195b834024d8b0ba2f0cf71d2c32a050449415123f3bd5728faaa70ad378e084a508602e4b592118881ce4b4afdde77373fa218738ffe2fc5645c646c4c8c6993c2eb4ca1ab4e6c9fc3a175e1f64874dc90db0f684ad14377269a3de61c3f76fb531e533ddb381fab12863d532faf656ec41d12745536446677c82d92c39f53eeea801ffbbdb7602396745f26fec63d24e2020209a2ef1e46d3674ddcdce3029555f01db838361d13a87705accba910a44d496566599119a84a7c3e9c8c101bf851576e80f064777ef1894a46a6554f19368f03e7ff2d2eab19ac5d7947d972c310cb5deeef942b4143236f09a28f1b7fa82223e4cb4262476abb4029243b64b993af2fba32103497acd66a4c357313b1001d0494d544415f1a261b615bdb0d9b589609c0a9f5414f61db4a1b2bd60712023912d11f5508b009f74b9ce7f529ca2b87472a0dc130f647c95bde3bc81acc2d003a29de8a3675ce3a69a122882b3d3d3a2bafaf3007ec3eef2bf4916f3fe405ca7689d6e741f3514b74bef9546f650346519ff8b675a974aa6060d7e560109c790741284564e4711f5d46b85631395dd015fb86b3137444f31e5270392cd5fb640d42023071c87c7336e905065dd1096f39e7ef2f635aca3f0f31c323111c8ab40b30f74c65d3687123b6a1ce3baa8d650c755b64110f358d2c3c55a61f65256c473ac69a4d36d312117dbb9821ca04556a01ee2f73d8415375ecadd26aa454950879fd8a2393194342b8774c1d709e344cc970a527586d8824e1e4f528bb61177631bb8c2306ea660370fbb32c57e14b0e2da6773ad50b531f6cc6cc4b7dbe2437a6d4db48544c8e322e287e4529d62d531d80bc6f5e6c4a7300f253512c63b47293191a298dfa8a5dd5f2fb6d8e78b875dff0c36e9a29ce09ff0696361c701869a824047515c34419deba9a5179d3740a2c8f380305a09757c5a7bb2d79fe2638cdcde85b73c92861d1b82c33836e7a498ed4c144512e5736f9807366ea5d9a0a5bc5802589f49a439e5a9b68597daf17c72e02406154863f9e76f768854b80426a813d67226e4c645aa7262a788e17237523173f1ca21d723be3ec6016a2c220240f3b3688e4df0371f2444cff9e7c0326d9af49f7e1502b1ffca744e6601665afac5411f558d22ff02aa942c314bb4483bcc94cf4d5fc15b8b9e46649870a075d2002308edb3648183d8744ec769c763815654a7f0c3265cebe000a2442d427244a8e75ff684b6311a069740367d57520825f0807cc14349e268b016c905813ff462c13fc3e2c1645b10d4c92d5084c5263dd148990042addc19f48633c2a35a23e193459ca3d4595947649f6ccd5133229051a6a5b5758c73e305e49f6ca093b755a550426650d6046324ce768116076d68a4b23fc5469a8155733fb0f1c184c45f868ca512c42a4886510a4407c76413fbb535221d1680ac7e82215493f77fd5b0e2c0a3ca5603b2a460f1a43662af8669664b7ca97470541bb0439c7f042d3169c2a5988f577ba03e814343615051542981d71ed7b4864ae246dfbabcc608e68e6677daf0270c45ab80b2788fb6c9690f25099fe9919006cd13bd10f9e091847d956354e1952040f5c42f7ae100db3fc1243511d2e25692ff1013ed9900a8fce841e33ce70555d9b9302139aab6c8d465924f52c154433b333037b22436cd229f12869e5e70769411748958c1139b16b22365633ff56a2faa32d4964053bcd98ec1413d40e0288b5f85dbe165c0d59644d2c8b352751607e96717ac8824ecd0dc36d8016da661b9e5953c37f674739edd744d31df10efdadc43f5bac42328900cb65efc4f05096633e6596de640a8e8036754903a9386d6af5028c9e5603cb17a71bc4e3a359e2cbd306ce3db81f9e115012bd75175378a2a5626e04e56d71bf534588bf1b113a9135021771192c79ee8d4078f8f85f09040f3a01b22e06dd23fd059b617a0335dd1012afce8f47920096471a485d2990600631ce53dd774b48a225e3b57e38916e8d49047cfa264bed7e467ea5214852fd275adbf0f340ad471b56bdff792de391e23c39f1f565ef13004b7a033a3af4f7293cd8a724433e406b6e65953b11cb4500249dbb4e31a72ce4068c2851211304b9167b58f5381a52c3485f303673add3b07506be0e1f0278081cb3d7a468cdec913a360d462150e68231089bcf4ebc206f641512eb020bbacb670f63021037fd694179c79147fcc43153a608ca71de622e5f84e8fe5e518b4f628acdb745366ff402c4f5f0541db9592e84ae3f454067857186b6c944a63c4121376d265251ffba14ee9127290957a72f2fcf600bd117f6691a3500366319fc6b122a8743f133633fe849ed0d4b04fc676264435008b7f92674db0c5c366626188d0d7e2a26979d216f5bc93526e8e33b59c50b608fbf1544a9afbe03af08ff314679f93ebf9b24407104a85dbdf1351b2e85175e482807008ce97c60d5b6b25ae2edd54dccda04710114b9691405880ca51878370c9edd46b8d27b625be34b6e8a3c721a88e47f0d590b223119f6a873d7da37277a93344923b7276cd7c3441fea96d643edba8944c34f42753bcea73b2bc604765d0fec6aa104194831356147d51bbb551d11040148838623bbbcaf696d326f087e5ba020a1599a32805f0c1e96b7c85d913d593b41db9621b8cc7b2e15dca5390c4c374abe67170b54179b11a23b653cebb9fc408bb398306202195906f97f0c4abc471afe01815526979d4f9901d06ba9c44f02585a7b291a79185490ebb827cb6768126918ca0e70a15171162dca105b6d6e5405d4e41f711f5e610618b70e059de92946362c630b985f4bc96ec22c979371649dde4e117cee0616d8a4390ae1f8e8679316184e10c233023753d2628b5eab10411e3f67ec042a4c2ca2ec5ff5ac6c6e8338f060c15e58540a33b135797dc75c7fc6f25de2a6bb77bfa0c714f0a6074867df7454486e083c932df336760a49251c75fa1768243f0099bfd41022a0491078fe8270aeac650fd6ac9e062e365620f9d96f14ee27ed49841ca52d0e19a46170a27c01c8dedc534a78f64b14bddf1bea39ec06d3bd6463fb61e4744f20a90510d4af11c8862a01afb9ea2b4f8b0564f460836f793c26118a9d4e0a4bb3055e3b896349b2207635a0c82c3c58dc3b4978ecc15534ea8356aa40855c0da41e230f2dd734db1ea0227aa8eb5a3cc66604c9c490539de373391698c31033e41156f744d524e4fe2c7706e43e71a3f14474834b801b1e483318891ff13a4b45a83d5c6528693658c63c7b92f13ea706ee460441b74119f28f20eb23f9261dde0302ab850564ac3b9d21b292e23f905e0d4c5249ce58c941f328e545f54bb4be4175f518a6644955b55475b9001d831433264db1cd409a25f646a38db5034b4ab01b6b6ac63afd2e9343f0d532408aa5d859e0348520cc99606e8ccc801143d4f32dc9d6ee742d493628c1cc113519bb64277077fb2d1a9fd050d7536171064c046006d78568077eaa4f5e3dc10c092e2d4fcdad530a1e162c170cc8d6580cd32e53d5708648dadc62313d6be763a07fed4d3c1ef36c1543c46a3feae52ca6ce3d768791cf2e6a4fb545a319c72eefd0892fbc3cb6486d387c20bff0f56e073b191f6acacf50b1c01c1a5631885b4c454f024e51ee06db9b713ae1ce1d7046aba524af51603d134cb612866f9148dcf5de69da96134a561f857120d2612cf5e47f2bbfcceb74fc28c7204131d66920c0cd04ce6f9842c0539032922c58462a488041e8ad4354e45f4c22bc323c42148cf0397c317530d0f6542e59d5fc256a2f852f919f4f2d356c1c76c817ad3ce55ba60b68f8346f7977552f47d45d1ed5dcaa39babf9501e512d061a0329118bf10f624830b4023efe67d3ead8a033c409de32e3f800946400c56590911de17e992151cf6df9969d245e2707763e529555b90281dcc441715e5305992690e317602bb681f5d5b22d85bab0561af5868098d303b6f2e365518bc4159a381152b6db7d2417ee6936f5b9d0c57a843d445e23a6162b7212e089328f02b7fbbe370cfc6e13559f7b3190a036621ec7a9f1930ec016d99fa260118f13038d758214924c9aa19b861901b83370e32704f152624e7104e597b4842c540290147478d644247ce0e0e9177176cf344494635fd49d9748327bc41c355f813ee13e894cd5f81eb616228a6d70ad8690e0cb4090b2da0497c4f63033f5937c2a22c7db4a55e4b1a660aae67e75101f7156ab3a7f9331caa9605247e2c166457191db8798529920e7f5289ee264daadb0d081b24ae008301444865f6c753f5dc901ea2fa9d7144a6c84880a05f2714e5d7549c501e4d9acac5042649a6461f36ce05598e941a2108f44711f7da6e1aa0d23ead3871768e03e31c69b4a874dc04d80b4bc44f28f3df466a963e744da40b3336b5e61a05f70c40731c760048031ce34d8b21ef67d811f02a467900611eda9543ceec7018a9c43c287d2f2b34aeade64b6e8fdc20fabac041efb5a53212defa43ead2ae4e95ac31227e992c16aeabec1e62379657ec48f040920c4113b3f7e16c324d1310056b271b3cb29328b29f0a5a2fc8466582bebe1caae3c31b76520b5c58c4971e092d733fdc3e422191325a68753a1f2269c2cd25c08b7164ba122c2b59b1bd24aea88f2b23b8f56c50cbc72da0f2404a1a216b1cf8b2b4512fa44b1b262c2f278c2ee06608d7675f03e07714cc745f1f64daff3edeed731f16ee4b49792315330d49175174cc344fe5d7a169adf21718f926b85d3c6cc90a6ec23c12533eba12777c2d5665b2732f927de81928881a26563f0961b847ea1fe4c346419c45e419aa0a0431f56b6c2d17410b69b1a35b25e54d40062996a93170df9c45d0db2d696e3f4676f2be88065f38601c768c5a1a94f9e612c4659353f0aae051a64a011f3c4e7d166bb8d25329b4b116f4ff455db1248d17c42c4b636e34c76a2bd0d134fb91a677d9e6c7073e006569e64f385aab046d6af3214401db2a7629c684821fa62c0c0372af9022ebe36504c878a052875439043ff63c7295a34a34bb754140283cdb7641468a29be5aba52b3e79d339681365ad76adb23e01c8f09c7172210734150403183e723b3f3113d7b0c8e6dd2b11704cf95124331473325e9d9c14115472d13779e8c06ed5d90367596564cbe1d51407b569f469eeefa1dfd51f64c9ab6ea2683cb69272179fc4dba5c220ac333c6194b6b11106a91350624cacc250086942778c1e42c3d7b214b4fbc8973c0e6200e43b52075eddf1c4545b3813e52af9b1bb904855fa474622c428f6565ae9aa126bf0ab177fc4dd11d1d9ca90ec67ae6035a5ca6286c2a6a0a4f72ec24c1272f6f9c57de3eddedf22ddef3715c13a8904b99bea749453c4d503aaeea275533802ccdd1302d8cf64068474a6411ef8bc3552d62cc46cc3d94569edc9c5a91172d046dfe51341adc3b4febef6c484144e65fa2583a5b3c85256bcda7e814b515c34557d945341480ac53937e1b0b37edfd19b56fc30f6e47eb18b214450805400275d5b6386a23862b4248650b034aa9e707100a7f7522112f4fd47be15e4dbc7d0ecb064562b5a28335c0094924dd63b015042eed3ece2ffa76252a8c7225ce976e5500312672deed0db9c65a45a0db3425f8a2466416bcb5626f05f4100c00a0344969c052f77c4e2919f1537359b46705734b8c4c9c3ef2040cf509768f595c6932bd922a47b113093f08343feb9a5b6223f560379aacba39b1cf7b633e412d070e03b470f15b2b431dce513963760b508100e2521421fb42201a3a39ed7211496e0f7d31926145051ad64722a0490b667b42981c09d6473029be7a428baa292e25e7df29211ad1681ef28c520112c6304100603e9ef0df25123b1962477a9002fc7ab66fb5364f6b888d19092110412edb5799667873e64b9e4baf5b89d3426b024acc482d29f04afe2ea40ec0250429576e2a265b7a542667f5e12b3a079f51a8decd04eb260405d9622a283777d96724dd9f6bd4af8e6095d86663566e1b24b285101cd9937f3de291581e4dc3d84375d5295b833aaa23e529c562481d1914555c886d479ea6288763c60e7ec7074fe427764e0548a51469b21d2f6ba2e707bc6cbf5aa3b9a50aab49c63a3399c64f72a21142fec26d167040e22f5beb600a2b126707d8515d1729f18d3944f1b5049f721a2a5375b66e852df264212efb4212b7b8617a79183b02a1431819049d28107a3759e1de72086b612172b2d4ec1a68b76c6236fb9f3a73bf1f1d4d3c5b754f3a9b041a88be24dd6d4406f1e37b102bed35011c282b081827d8443008f7547658c41f6f07f10c6302a20fbc02d5416883854e00d22f67da752f1af7858045ff7df108728bc33080f612233bfb58742dd9d21384c3d93093e3150e8ee32d1796c6470a4810e61502d62a103f80fb59c0bbe65ff1676a0658a36720661b1e244f7b180b6addd1642f6df36f529b0d0b47f263444cc0692e7e29ef3c92b57c4244315d1068c57275c14173613961e254b7d9732e1ae2934bb254db3217fd9915105318487d81e264894f741fa9cbc84136825c0ba64c143590a9852d9c0be11c27744367bef69a3c6b2ac719401bd22450276602bbfffd6ce3b7f90076d2f275f363ba71b697f63010b83f3f427a2d6fe600da1524ebc67311a8022ee4e10539b3a15516447cff628820ab180802632ea637f544294c2750b13d8057cadd34614b7326662e892d2e9102e62f05941d563f7a1d59bdca85683546a017be703917a265b7137c9f9a28d6029d1f6ce4ac0b6363ad0fa89f8b51ad750e0678918853a0e34724057f551b1b198e5a0aa012622cc8fe0618799e7371b50347bef210600e88c71eec2b4755bd8bc0091af2566f2edb5d005d28f342b9724b74787ffe520864764a4084d139087cc46ee03c531fa79aeb67c54d2329b00ed6094dad8e3e5bee983385cb7748dfc7f46e8b7f7e2ccf07f525fa134210e2f08935722da7489eee3f0020e700719dd04476018db627b7c2242f1834716d1fc090141810a1181bc0f36af5c58d55adf2ff1d0eda7c05ad475a100de6520859ea253e95997e42c2cc0d0eff6bab402690971cbbc8433d28b3667555ca400dd4a62f57f8764f0d26fc6937ec550a4cdb2f225f05b0664c92b4664c5289db0309c39f0751c3b627b8677a44627d76228605c62330eb3c5097b83d71ce2621703ab16e5228ec6362b745813f449ad91e110a091da116135afc3a454bd9f6381784876c147184a436999de94c520a3a53b8cc394cd5492b0c8cc34e4c8e2e720f3cb6737342cb263ace023f320acc45266bc3f16329c8d676a52e3f47726c7929958b934543dbc710c7346270dfd2214a37b27459c17e5253b05e584b6e608826fddf686f8670e91824f7364dbe84886b7bbb351c4a314f66800b6a43cb248710b0a8393964eac041a4ed89672e2e3f4dfc02404e051dc816a696df5fcf18c018f97aa80ed809a65b01120a4379ff4b536c4c805cc5c65c48a4e28f0f77f35021dd83be2dee4eca06f210015c63ac9c431dbb0e76dea7c7015fe9ab34b954a75b61a1763aacbae039219de30d9f13ce21872e8121f135ab2f64bbe12fcba87f31301f687107cb9745a2536933a2a34e1d61fee629fd8a9303ac932e3b3d2db10fbf2cc650ae928543b24f75579ae84206e54283562dce076ee5cae05e35fb80249e800e29bc7baa1bbada6f26105da800baf55e3d46a07204905f6b3843d6e1488d86e906ffbb9e73815dac3fbfdce540f3b38a58e362603019d54d1cf72c3e1424fbfd41b9cf344587acb55c93e7472d0b9cdd74dd8efa58554be413b428802ee3a2850e7766ba63417dbd4eea6e333ef6a0c20800e2870bb394a56bc100d13c36733d1282c2f7581da7a45b513144605b40ad698523f3442f16155b788272363d99e80bbabb7a6b1b42e2520af3a413b147ad550ce4172b013384334b08cc5ad514a66404ffe558f871e51cc8780348311af55ce58b6c428423c573c161360fb66aaa6f2ed8e14c6245b82f580e090f043a8c515221252fe6835f473a44270299f64e5a22e0be190ee0790706fe296c1f852e2e41e14a66ec8d6048d350e276a721f83b335af73960bb066eeb53932281e83e5ff3f7d61305a7467761c31d52278f534357164622efb8a529c4c99c3feff62c372a3ec076cf20fa4008aefc255d9b0c13186d0877d9ad8d214aed68355fd48b516ab3ad7088c65e06dbc6895c7600841b05fc072d4fd1bd37363d6b562d7fcb181e26bc0dc86afe101ef5382f4d8b02172c18e5431ca0481623c4bb43e95c5c620f5b3169830ad14eed6f0b57bc5ade16fcdf473c2f1a3e136f7f27486d5f083f48c41c197b718d4118215f26f2193e680d08ed3c6527de13b21b1565de17a1737624383f4b7c915102421945a699f031ce8c0d4604ea3c0d205d51524421094dc9f04947249b7722efde9b148731333fde196d506abbe76f346d023dce629f03c0bb5917167886249762f87724c37f5d293d335d5608a12a4cf6e946bb225f36111fbc344e1dbf036debaf31e134c852358698172f286207d7d1ae2b8781d37123275b1507b2a60a72d4a471fff6cd150efafa1eea52763c195ab70ae3485874774c94527e370e583a4cd118b6087c31a4b15c62cc5b82316a8fec1dd24c414d66fc1d1dc7a83c3ec49256668bbd0757165cdc55726659245ed13772d3a5e64bb76dc64ad7868360ba847800243daa334c19802c179ea93c6eeb2656db83926edea62c01edee2c13384f93108b932e4b6999083b31154972a880f53e9cf36a4a11dcd11bf3a7e2599fbe8841809ddb045cab1b1758d1b72307eb2f1293952c6db3d9a311a11ea1657354622e8254fb33ba8b6d55da6b272935d47106afc51c60a4bb3a420a2ba40a5d2d8e05117f43562c808519576bde40c8a1aa4d30960b0118de0a275b977471e68b063f3299982b0a242b0794f28a19bed1ac64b1fe9c0c79c1d84d8baaeb393d55441ed17e7d54ca320668515e93546fda6b5bb5e5eb185f190156b254b062ef596f64612bfa0f40411e54fa0c66024d4663117c458d045d191718c9a02a061e21866373b65d6b5dca2273cf0e5c00dd8ef1727b95950f803736743fdcc739662df927f1c08209b8e60b42ce83d50130fea66f6679e16b4b32492f4c92f87049488738352f0a0591b26123dda60223d3955762fafe8e5c40d62574c09e765414520e297ec012025e70794f8a50e35782e043390c4c0b262e14690e3328897069a95551aa7b0c4d420603377d96250890b0f52b4933e719ebd86901bf26f8438ca439159405ed0b7f244b554b0b184cb65bc86d6af83415ef19fd4c17a5a5667ef2481d08f01c24f824026e3c534119da3c8654ade008263980db6bbca21203bb77e165489a6d0f96edad6d43b33d1f10f38b2f9c33dd2c0e23fb58be4d3e2d1420606086517b6c83dcdb738b8dcb73d4555b0733de2302122a20611848433710dba324931dd54c8ecb725cfc5a492977988148d4ae5046b5ed5f576c18715440df542d99cc2953151dcc4ab3f0763d59ee2c68d6b8516d3bd9c054a6efb933de69a824b8f00c1773826c0a6ec48d691cf0065d5c098f5ef80d92026407955585459e4fe532425f4b52cb12c58e5b35c1af6805b55eae53d5b89171f1ae00438d9293436afd480864685068f1e508640c740e305ecbc05525d3355cbf707a717bab58295b00de30cd8bed118fa9471321ddfa56f634035efd8609401791d34da6f7900c5b4bcf5bb3cbc439af948c10b0dcbd5d8b504d4fc7f2055d27cd3424e532fd708e5b780a47039d3980cb616c8cd02441b16f05618bcf083413515a689219a8242394a231f2b96341d9802d300fe04273d0d07c6ec87dd50d41e27e0ce10424617be9c4414bb69c3a4a453005a14f4404b3a93b729ce36b1abba81b2c7c2f335b3cfa971da9cad2433a8a4d6dfbafee30148b0862a63ca505fb5bc01e9b5f8127f2796a544788903faca0dc411e8b1949621e923bd08a9f2f087e10271b912111c0d9a13f3e430b0cf47d1064578abd4deadfb3392a07bf480b47fe330e986f69dca41a4fedfa1129031d16616a3ba058482cdd66c9fa3568fb22385faf94ab0c1f06ad0e1bfe0c1e02f5046e38e423096f41ef6b1beca83ae9a0f94fe7fe8d6d8c05c320fbe8ca4a56cb4a0434dfe35d6828cb0750af8a5f433e155270b79126f7f3eb75ef3a0360cbb3470e0dd7481d06bb2e4cae69472184751a6ab06bf614ecfbc245d6fba5413870c70696d1980249f7324f1b3cf774f6395407951aff671cd5df0a9608bc2cd8f1b76e4b3a816c579135413046d74355f4e85a027ea14695203550be77dd5ae5accd676de1e927dbdc1a5a1a513137f6637956b66d1251c2907e640e59b237eba4440b5a719048e2715f268c8bdc6c778f784c8040f7440929a813833cb063d0c7df54ce5d8931f226215fa270cc20fc09f50d48346e2ceb8d3c12e5a41e65459da108b41872605e7166726aecdc0ff629ce61a8f5ab139dba59174878cc740cc4240a4af3663675d2aa089616377475bf31693d4683236687ce6351d14f203a2a36182590e03e1a826b54091cae3d7f217102a03e5966f0937f4a38d55b6a63fe543c9aec23313068930bb9db13432f0db41ce44c5a10e2788205b16c6b58d8049774b8d2ee1c9dbb946286d62c60db5d15468a25b111595acd6db26ac76c8bdef54f7dbcba4707341d21a5559f3617d7394314a1e4101ece3c4f753ae84af6aebd02fe86651a17c7993241f7f706316ece639a2eb82015283437d582002f01b3f51f1d5c835389ef09653454614c8dda2c16c390022f26b8730fac72b90e0d7a86174698130bd2a7691436d1825fb9429b74e234680083bc4f442d0dcf217e16f802ed9d732ccb3c81437ede422f3db7da17f1baaa1441ed9a684ee9706a37699f3a1e26b31ad40a62505d8c6f481709a151863a371c30968700e2afe032a868e31953e38a5a5ad6bf19826d8306bf893f2cf0ddb8097c3e9d698e9e0b1e303d1e6455fc964e5182da0d74c687123117ba3e38be8c2d1e47c6350a8a2b15073ce06e9d752d1d512f7906bfbeea0caef4e84885ea47638f631b5585c71c349056b342c0497322c176802d6d2160627a32b64830afea0b6fa7653bf0a64d25786b626e6313bd1c9d60c704aec5874d85e1c449ba92125d42b487262a60c13655029b283bf7f518d97b562ee1331d0fef554a380892210635c33b776185063c84d8f138bbdd6b055a8e906572a2123be7c0463bdf8ff700d339d6591038a86cef760b4c258b5554c6590a4c3667913a458d47595dfa97147b84f42e5c9667507dd87b638a66910c6bbe74032357222821d9944f86850977460a1a6f07805b3351200447f4f21e30f29476134478332024e3e71432b5925d362cdc3c4d36e84a03db2d434b30155e7980b71698b17c0207cb3466b367a66f86d800494620a91603be63443e4b600eaeddd36314942e3f42cd2f5cbd4ea5263c768c6bbe7b7f3d0cf5550fbd2e527136ff7909a569480f2543fa1f069fb20f63587d03f1c6210069a3113d56122d721cf97f5238b6286f7a3c2f0c80562275de9f2034ebd25a4eb5cf466e672274031109ba6f193cdf3d2fd8b921d5febb6568e16249841f3b5e07d38850d82b70662f8ba1503edc004de6b1d663eb70ef0417ded568d9c6036ac2129a1e72ee0f4ad734f2734ff37c6175ffaa76f0c23a3045b41d5219879e43579a464eaa5c5332d0e1e30bfaf8115534015a0ed307617499ad3927a6ee3e0199ac77469562b65f6adc294dc52e7e10e073e10d1c47da64c65a2f6df48eb30bad95da206fee1d0128352544cce1af70dd6b5f746fb0fa0d1eb4bb1a0082df3ccb13b211141dc832a4ca9869674e7501b2ede238ce830a6faefefe3dd488c50c35103f623072c362776e603c31bdf9185c57ee3d22af084439d5174b5a411544bb1d7c552a1530124cfae02d44e3b01d4491965953a97424e6a7e9452b8821070379943e9076b544ac496a3ea1d77259ec81886bbf5e8848c667e977acbb067401fa2864c6056964d535ba6f50eae5545b3bbc600ab5d41efde69431ec72880356acf20937b2a8559ec8056c803a9b0ed4c5634b69461351b0aedc244133316aee14be5b4c8aa36382caba077edf901006a952253a49042e25592d585af0155f1c3c792850e060053b05df54aeb4b5550c753b10e9739c35639ca6671f5184774728630c296e6f0180059319da026b737e584413313aab4aaf81853523f1cf6a026a7f49195fc249d4dc07083f3ee009192d4b1fa61b067246a8215b6dda697488fd446fb1c0520dad8ea12b94de0d482d1527617413632a5143ee4f64d168196a5161661b4f39126891c36b2bad290ede002620bf04b61847a1c832ccd712123bb4e25740f6c426904c5207d5be3d220ece7a0c3f6cb570dd7cef21b8f41424aa30bc6c2ceba2231a40f21e4eb54d40afded2109f01ff14bf827b463b857708d03ce35ba0acc5566e334d39acd06f371959f163bb17000fd0c98c41a364a946fc0e0a14206afb1dc808fc28abc62a108a19a51f69fe562764b79c6eddb7f91d3e61f246fb15fa37980acc7143e35c3038a37661960f5a27d5597e73894ebc125e575471ab7266021c8fd45d05de03632cce044f146407761992744c2f80426a0d514a66954cdd2424431a1ff9d4d700699cc328202c6c3857a3d64c108fdf511c159d0740559b44de43ad4c86c1f5622730525cf42fb0706f65963ab3c4e82fa4df6d4dde111c17653d9e641a48410f2457394605e41566d471cd6033c3835c0e74fd6b52c74319ff291a1de050176ae54da56933aebe75dd307757ea886313bb374c097547b4346a793f2bd0e52b2122b9df775f9f971f223351560aa05f03fc906461f69b7f7704217901ec3b0a1c1df40d71d502b3056094c66be3abb446f2416456a15e62331073c41af4838156aa84b52b82e9a864e788f93ed1b69e0576c45956402b6f2c81f824323a841236f17d0546cf5aef2d6c191142e1e7c317dc8bd84e1c0e8d50ae3560185101e2056023ff3f8d63a518dc6bc55320aaf1065a66f83cc7bb362a0386b0192139874a891f11041f1f9c5332a21e274375a1284db1b10115103c3f997ded0220655515362141662d0c45057e229546413fca009d7fbc71a8c021262451a275a8d9cf015465ee6da99b5d6c4e1a00317f52dc264591e367e9dfce20731cde12c92dec72d3f5802ca644264d3872a5540ccdfa45b16b2a13384aa8446e48e243cb0d750e51778b621e60cb09a79a7334fb59c43ef348b4623ff0e707e85d7c6509492876c9e5aa2537c8e70ccf19e301eabd7977dcdf61128747490cbade7275201ef74595fd540c918ea24926801c415ad7865f4ae3bb414b6d841024168a4dc9ffb03db266722900689f49a1c6a02af247df03002a014130c05661c55c0c2d8bc8813bc0bc0e5559fd750bc3223d657d6f3f02ac65d201bb8b1f4cce59757308fe442c3cd47e0933df1a6930b41a52dd1bec334f5fbb686b56bb72c003b1117fda812cf0f37e1a82aea9482a167e08b0c5993db3d8d971669fb949b904e328e0408e4a7ff7db6e1b67c1159803f038587fea2f2f4e621613fd65132f13fc6d0388040df0e8b4167f0f8b0890f08e5fd510b95525f69403877aac000b82800a8fdeca20dd75195b647c2c35b6240448b319dc721162bd3df5b1e814483a756f85e0520e5474e82cbe53d765ad2c012c455ac21edcada94fc028685cf67f5b088ee2ed4a0cf95c526e110303b78637564928e42d26c62f4407b81f546cdbd829a5f85d12355d7e3e2488a2158622e36b86dd94652f5c3355b5ac0d47ac0019143d50173f650ec11af619561e3c47db451954bd1f7e7f9e3e798de26e1938ab451e639c1a312b4c557a2e75543439da40033cfc5c96cc6e0d24e7e7157872876e3e11d00cbc614c7670a6d4172564664089c30b573159961e012e9f3fbe0748459ad19d026f66cf028467d53fd759451696afda6537831944e2f984136e0d5d025f6dba4138e7e11641015d37981d2e025ea2b13946268e47ace15829c09f69419d2ea148ff606506928b283bbd95f9093e0d3453b8421d4aae27c572e7aa01647137e956ae3f4d6d6efd2f29990c48196aabbe499ee39470adc6435c633fe1466b8d6a6e83202d5726488e68c4a29048cb69786b8ae9e21c20e90f622c7ec766f869fd3fba0ebe0031cd1e27c4d11c2341da770620007e2a17ec4c2cb96c6742610a8253a356c21d257ced3ce1fe81325f4e887257b61f70edbc6a6030606852b942de618369730f9939756f3b5fbd06325a5e3c24bbf50ef581f43e9a1b5e4028d505505fa5a40513269f532419e5676c82ed033af0fe0b1509a56263ede05751f59f578d5f6d4db16c703902a6d41bf0893d5ccaa3b36f8173d05f98abda186189c6516fd5e82fb481890a3db955113ea3d8510f90514c8302573ef16c341e1370b555ca749a48a555590be946781e8ed24120813a944630b6b939253e7a6f8a254527353e0a68093db80a28489c0a9deaab0c246edf434d36a74e17eb6d2ebbb8a32254ca383df314bb20dbea291a37f5166bc701d773f3b78205b543bd014eb93251e8f9741eb3811051358f693fe4d5d135786db32f839715243f863f2374bcba556088b45035f3b11abb36de4478594b4e93b43b0dbcdd82217353b673f39c9e00d746f477a0fcea2a04abf70ee09eca6dba3323003887b63c7a30cc0b68fb50307d97a96bf90fd24d346f4775398dd076d9551b199c00b2467cd0ae6aab76dc6c6a1e595f045327245fde42572f8e5d4d3115aa0192fc36486994974dbd545e1aa1549d6e4b8b3d2cf496066eac180530cc5dc54f10639e7277423627ae69e257452974127a95cb50a58a193c7e269b12f6fc9c53ca90a069ef866d3da4181122caebea632dd6593130b378761a339a3d615aaa3172b08b27217125104d8b05640d348a1fb569ee5d19a18b4bbacfba6b55b1382336e2f44334a1c456bb86a16700f54575413aa20ac7d5e259f514950c557c2e37fb7b643c279d95320a3d142d4a3be83abaa6b71ca2ee5f581d09c505b40141085d891e4ad486dd486d57dd383061923cb574792c66bc725f5df79117212e4b1e17e564213de2480f0fc821099b5f2f4d6aed471dde71e269ee4fce722d627c4c5312d23dbf55060d05bcb358e6e6c46a2f77694a17b642683934cb47115c503c5f1bb23be55c090a1893403916a7d34b780aed53132300541cd19a06aa48e864f153493454396870438a6f4d8238fe62f1f4216c5119014204d8b7395cf8244a1d449a176e2eef0ac2accc48effafe1d1cf72829ef240b1634bd3918381f8f08c724e467589fcf1821c0f112b1c7de64b84b035e48c2fd0f66d4f73c2d95633cd3bba5061385fb3bd5cf3d345f75020c7330f44ff9dafc41d0751101d104515ad7bfc222daa9ce008e963053e99ebf5d28ee9c242162ab3e63620148f61b1e0865b43857b84d1d610a046f0b2fac9f0ccd5c54457ad4ce39106fe42291c9262a7f6a68165aef252ea15e5b08d1938e6bdb142d4b4ca71921f8922911b4df0f6ff4d46b26e7bb54529a00302a5479df2070e9896cf27c3204c0f19c4223060f48b7066a535217d050000000000900000000000000706f736569646f6e3262d97bc46d0a877acb857043cbb90a6beafa21c97f01472952fd28be15b4750800000000b0282000c6f44f45b5d84773736d2f7d16af1bcf85f9350c8912e63a447397d0d6eae6ed00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000010000000004000000000000000100007800000000000000000000000000000000000000000a12bed13d02e3b2864daeb2405ce14658388b804007aa3cac696762cfe35fdb"
+const PROOF = { V1_0: { Composite: "0x010000000000000039d2000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000089feff37abffff27bcffff1f000000005afeff2ff8fdff3f58ffff3f6dffff177efeff0f34feff5f69ffff370bffff270bffff2718ffff3f9cffff1ff6feff4fd2ffff6f8bfeff27c7ffff4747feff47e5feff57edfdff178fffff07e7ffff47dcfeff1fdaffff2f16feff4f85ffff576fffff070bffff27befeff0f45feff5738feff3f0dfeff1716feff4f07feff4700000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000006ffeff07fcfeff1f7cfeff1f5efeff0f03ffff67f6ffff4fabffff27c3feff6716ffff4ff2ffff6f0dffff1785feff579effff0f12feff6fa7feff47cdffff1741feff7718feff3f0fffff07f2ffff6f61ffff77c9ffff376bffff2741feff776bffff27a9feff37e7feff47fefeff0ff6fdff4f38ffff3fa5ffff5763feff67100000001af55f72fd951d4d4789324102c739326095be2e818185248e5f3556feb6706069795554d4226e5d207d15665708be18fe11561c306534008244e3364be8316eb4bb1949ddc4f7287e5d2435e51bd656d3b0a5765709db55051aec1473ac444d8fc77225db96b615f4f41418a279e075091de655687549472df373577421211645b6e90536dfeb0c0a766908cf1d3e5d4492914e14815b71cb402c6e91408129b92e331b3e76425469d42833a7d9fa56182fd40799c72201db3e7a27cc70df02c107b23daba7fc6d8bf62b084f1fe0142ca7104119358a06c43d1516c27443520b85cb2fe733213d22609b0f31f1a51ab2c400013811ca46cdd78901fa2e981762a2456345533929e1a1b02f98424b042c7d65051bc8de36c5b69606a6732f66ada52d208032cf6b60d99e58e537580b593a3d0f6123972c90a4a5707e3fc815d280721a2343ae143b5cc0548d3d8d2c0ab74b06565441615978375d73f2601151b23958d576ab2e1d3fa821e86c8b51351a4d3488d6740767d5b728b26b272a93b92263c5fabb65c59cd576ac1d8449dc54e36c79581f59029491298b168e57b475353f3b4257639d1b2e05faeb215e4c28ab5c0dd9e8682f0203211c232160ce9dab649d24a7156c01ac4cd44ec61e3fd6f34de988a749bdbdba0a521f9b0ff3662c1f8c387f3d8d4633281d203908d47e870dcbbf87552923f93fff8f64093a07552c30fdf077972d823fea24ea25d98589263ed313668174be5e2c40de0004141b47b093ab476ae9ec5f7591112639b4071c6c9ed044f9d7a623ddb305353c09b12ebe48dd2d57c06722d0eb1b66bee834691cfbe96d0060915d90e20b38fbcae275b8d73817bf14d2602faf11172927961d09515b5c9f1b160c8f46aa57fb86603ece214919463e84255857b92b59cceb32b94f3c4b96af9171c1298f03333aec5eb51e8f6cef1fc80b0d3af315d3a373338a8b536c58ff921ac5dc1612bd9e7719c7400677d1b8a406005c0427712ddc1004ee5e2d20892b2fc7c6862884324e43b0556b691653ba406e841d6d5475f22e785d6305d0d4e617a6dd876c3acc6f1a33bc1d632ce1da3d699c0f514fa34a0e8407c05744c42e612bed703aa58b024c3c712d28510714118bf28c24981e853ee899e44beffbf80864e85a046e5dca514b3fca72a0101c5fcdec7224204195366646733dde979335a9f4141b43f51b13e899fe4385cacb11abb15f0e0193aa3db8ce045a0d2c796d6335cf68bf330747a788f65adfb37f034f007b3e5a7e3238fba5be75d19ddc3b7c97290f0be6096d8b26643be16ebb5ad2fea1205c637e70606d1014b3357868470e0169194bb92b63734d29c343df22f02e4a712ddd267448788207f34a4f553d2be326f78b3251304a4905628d0c74af5c88438bc964775b46355d3aeb4d76a832024bb269220075e3590ccee19632f24e491aa03d236adce1dd50fa083843b0528f27cba3b2148892de62fa86183c41e63864878fee429dfaab62e0aee128371d8b6fb828123447533b66f206cd16c3806c1cf9ec8f53b3c70f6bc92f981b0467e96ac7e8c76b2cf518309e6adb2b7909a67541b93345b0922758c65ab9262043c0549a2d1558d26dbf18d5bb0742a26c300bfb944015a1ca4309955b4f4135fcac4103aab8771e002456a406b46a85c9ea650c97282524f97d73ef32fc0005e94a00fce66a6804ac87294c8cbb28b2073a714c7f8f4a31b12d583e42f55bdf9c804efb5fb15e3ff2952faeee7f2eb57b3330982e1437627af43ec60dd90ee138392dacb8c02cfb6d59290ec80731ee62013a2dc22671cc225726c6097a5ea45f8e3b1893474ebb5fa11b1186175bd7eb550933759a760d94fc0c70ba5b2f0776003499e4286cdb6d9a323dd3976e5f4cf66c27b5a613ccf46e716cae484dd7ee43506b0b476d9e961707b0c2c55f57ef734b3120e03ad7ce900a35959f1721d3ba40efaeab40be960048089b015810ed65245432910a3840cc736dc5a83804f4c320e1aaab22f3d70e5caa074d41543ea35fdfc66e14f6bc6a74dc5d5c6d62484c5f09922e57cc57d969ac5a540a5107cf631edf2f09e78cab54965c14357f4608108343e539f6633f139ee8443eb98fcf650728d027c0a20c0a04fe203dfeeb5f0f5e10004686ce7623c7c75333cdf0fd4c15632272c1ad9a2fe944810c1e09b303c9aa9d7563113c49c7dfb4021c032d3dc7e61b510800b821439ff31e925ee1227da1cf1b64b25e41511fd726e64c9133782eb86c1cbb221d33bcb623d94be640861fa71ab094d01cf3f658770646492c3f61c4535afeba48dc86d86602fe0074810e300d2bab5c468174625d00929717365dfc2bad1f2558f15c404b5504db75b4849b0b983d4d5cd4a3ce1073b7f15840edb02718f68c56cc1bbf738901d46d4f21080f93de83576d56993948e04a69445c3d36e0e18d004955c573a11f150690975b4ec8ba1149c16c654384e58b21942de306f9226b37d978b1193ee3e358eb44fe0b610da65a1558cd4707921a582f20e161f566f06dd55bd506f878161a8bad4a6d179e4167522fd670e6530f77f16e0820ecad5d5d893f3b34aec4a051b6fa353d37050575572df4309aab8152e449496d4f8fb36c64eb2d4ab74a4004cd8c37421da69c42ddf9a9444cf323371a4e1c022d9ba5306e485b35bbd1d3232a9428698085cf21ac8b83258a6d116b9be90b1ac7816866d469415624f79a7598413d1aa7f874570799d9457b1e7849e13564344dd1a0616ad88d7144f412106b1ae40299674249a030647769e21a46c0702a2b6adf064ac9de1a52e86de505f0df022138e8402740f0e03ec85bde031411505b896967466bc0404186723d4b35a69758660d1d207f761a28e0e6cb33836b202e7bbcbb5e2926056fc2ec4b3b7cce4157b604fe32f692683a55eee92ff3b3d1070587722828dfe85b4755be3eb574220de9a775371638ae3cb2df8e04e9fbfe67f998602f372bec51eae4e705ab481953eb138f201b257d46d7797b70951d612aa2ec22721a42451495fe44167c4f9416198b0061e3b475556f543f503ae77c05ab7bd63486c57d74e3b1ff69afae9a06eb95bd675df7eb3d2f14114042a0ba0ab85148634050b01d5dba8176bfbb2606a687ee55709ac1029a35d746a1eb1f52486eda5639ba325a2fb3256af13c437157123b654d55621c77ebe9164187f774b29b583a409ab469ce69406c0335e064b81b4a57dbdaff7775bb8530253a7205d1826f2e0c766a37fd1e6b3075cd2a568716d530cc0b6338222a4e064a60d20be89e4960394acb30ea34302d94882e5f57e91659b258c74aa0ab5c0f04bcf20be7150a28e3af6913dce92d5df0bed51f73a8cb6f80059e38ab509534cc208b118498626336a3b62fe76f8f2ac4706b6627ca0e0100c6fd395249fe77471b557420b373031ba1aa506185496cf7cd0823303bc7063b589f0f4babe91926c20d697230f824bbe0ac46ab80b61750effe29abfce83d5cf2ab178d168c4dff4add23efe9374c8ee83a42fae23c0300c2b718b9a82c599719365d96d2c1499de60b28f5b64273697a6b1b66cc7f6a71f8f33ac71dc54052c07248742725233e60c024bf465443136d152970b229722c6ae31b6c5583376de00a4686519961072c671d66cb0012aa1cd031fb84294e7dfb3838f38ea410e795c11cf48b734283f74406b32c1d04f21752778ab6c01027f3b149ec74293efd198d33befcff33367afd56f9541914198e763ca573612a4761d94f8729d323d5af506fb5fb7f2f0fadab5192d186351ccd913cbc590447aa8fe009410e660218a821722a6dc92625a9096c10e44367bc3d61487e3221684fcff744f933520d690d055ae2a48b4695085c723493341818f1232b09aec3486145175a539a2f1a8138b219fb3d2d007ab71a62e0beb24b96c463359a9b45610ce484535c25c05d283d3173cfb8b4767076c15b5d61f3410ef2df263158e128dcb85e414b07e56b7cbeb5390f9146579d2dfe12b80c2375af118f17a0a23059c915e8424105cb6590835b2c12a6e50b494acf44ccd8ea42fd5be3442cbe8334481f101f0b9816240782981c4e140546bb68cd0239bf261abb8b3b2bf3c69e5a5b5710451e57e55af76fb66c2de89e3cbea86c25f3fe4549f1307e5b2aba252bfca1451ea1afed2256f96b73b9375d2f4d5bed401a1f6f309663cd0c3b2cc939abd7980547bc4f25b97da439b41597224ad81005b93bc65b4a1ad42f1f71f8561e76a368a586991912c01a2d5860cd5f97bba46f9c14134b96e4940ea43d4421bed4951b0df47c2f99d4f6211d40ea30f52573590128ad73aac0765f1651d301e11c0e153adcb70e881c3b08ee137b18203c78570bfb0d724188f83e96d8bf3b9235f86fa740823adbac5364287a61646293ae68a1b16909c0f8581836f1384666b63f0592df4e23272a3c63b6e66c29e4ea3f342047a5290598401b7329e60fb627e0715deb2254c4eb073226eeac087cde95379d165f5af4d8790de90c67251baaec33757389048f04e02756a7fa3f75995c512b530910f4a55a703c1ae9359a90ee392360c15b50b80929368d1556dded250d696faa3a61fc6c20e8b3e45bf602fe304e1876456ec01a5fa8fbb81c0f3180154b9fab2e4874c97689e2d800218cf4429b84393e7119c762927eb403dd05695b6e55402bfd82871c1df74c293f5bf83d1b25332688197152ba073a05336a74419ce7bb372a1b2e4f6dbea9776657ae25fbab351d59e2c1157f1ef7197a0e5339560e9a2e92e61035af8dfe24a91448716682d83c159e9e003674aa6e6938913a7f5a120ab1343e15c7c28671623f6e72797bb26572589621ff924760c6d40c220c362a0cca87d5226e4ffc0c29f6e673e9449507ef194e3655ba6648049409414d5f98702bc24039ef2cb9247fee0618c02d4f5dc504f7459be680086172a1646def6351b2315e5e23165d2008b56e32645c7e649ab8fe734ef85d3963c79617a5308d5af0f4d0620799b33a9d9e1d3148f34603201645387ab80c3332b73e554914f32476e42554846512096f105b4888ecbf31d71bb21f9b4c786cb01bf24eae90db60767b6e1605f7162c080bb12c42e36b68572a456050c4582912d6e3118861fc5740d69143633c45577a349c3fa5bbca401b580e2f8d7f003cf6462415664dbc58ca5fea28ca645506b7fefe4bd9ca0624ee255016e42b0c2289fdb07580d2165e581ee45ffdbbea3f127d6d0b554dab185b1cbc1b932a1609af90270c0523063081886f1097542f1605763b4a5f082e270dd9852d251d786dcfa8bc54f4bdb20502cf420d3ab9246db5402d67b662d16efce148030436f86f4face54d6c40052ee3bd7f6d6a25ac027c63ed0428654832d52992743832c525bcb3b36ce582b2706bac3a46e852fe70f9620b717cc40c5b8509465aed6657222c47e939a1e62526576e1d4fd6b4684cc086c45d0f6a7f36f858a314479bef0b072dd907d137da3b631dfb707d946051ae57f6002c809072b90a0d6d2e5a804f2cf58845d9acf13f034b032974ec3f020042b264107d3867c797ce610a2aa207434f3c23fda9b61175447910940d1f1ed1547c45e28e2637ba78353d7077431168e4f962ee45304ac23ebc0d5c768e239b336f2103468049fa7c2403bf214c0091d6185185cc341e37c4f734d154b1017ac34c05f475700ee69c913f8f5cce4fc9657a0147718e6ae9b3ff48863c8c14f2937b376bfe0f1fb0b3d20c6a7d6b14490ddd534e0e794c43c72739b3ca5571d6694359270c15018076e048cc7974720451b34b2847d0283de0ea29b8492559ebb6e33342b67a6a75433028c02bcb71a481bf5b291f0d0ed712517462b77531def2e070205d3f2833423f15b8c31f2adda10b68ac555a664ef34d2673d8f02986945f68ee733e55b5be416cbb76420e54d8380febe1780896616e129e9dce2de923f33d2358941d4bd836099235475770cdc71c1bd6b06daf9f6a464ddc942244e3c067536dd93eec90405bd0fb8d0330a69d18a126b3568af0124402df811a750eea31830bc669c3a10346c3d9b253bc54e3647323486e42518f37ffe6c313b5e3a347e514ed3b41ae3a57cb8a7554daef071ab9094b2f14763d65a2049d17d3d2373982a9f2376f9ca14fcccceb193c3f6d603aca7a55ce058630808eba6d84b97b6cad00732393186827628cca3c62e5b83cc139c8591b85cc6a0d5f3c147eac4269068108259e75c471636a9f4ecdc6c104a4ab3d326a08b964c9b995717ef9bd04b3dcfc055c09f246272c9f3273938d328e86c347e89d64302a89995896c679468e3a8e33ddafeb00f368660e6848be25c0361e65b1e7d6657f8f093718f2ab4731efe05a2ab4345881d4830dbcf4844c47cd3b55704b8f11536ee0386bab8b0b7e34a315c4f7dc0f77c0274d938ed259fa326e5877f10420938a8b3320ecc4264ab876423df80f14e241645f40c8f9217119fc59517c503c41bbd01870a3382d8d98a217dd44401b024ac82930114b74268c242e21593256172cba42a9fae34c2e13ff19ce21506fe8f37637ae300f6bf482800e44264a30711c254d128a57275446f35e59377e768b65375638064961eec4483a6ebedf4bd18d280e411e6a25b2a1af431a8477721f3b693b72d2e24e83c0035428cbd36833ff0a6e89342863bd15f309de56f43449e7282d3e785d0ffc882b04c402dd71f8b6d02aa6a376595bcdc43da6897916383551637fcdaa5cefcd8c015210496bb962d830a126fb42d8ba003ad2379064b7961d13705498776a5a473401dc031876377d0856c63553b9922d3c41c3c5305cb1c762a683850b57e041554cfd6a25c06eee1a61bbee62b0beda3645a6103571295a27e863e726bd20dd35f74fe044aa0f4d5b8ce6c94d1024285af40f8f20b845a539a1e782359a14f85ac297d55e2d5f9f434b6c56159d3ccb517c68ec1509a00421a57f43544d22891e8b508c2c6d89e45cf6380960b06dc25182401c102475fc4a5119320cc442291d56d3203718d9042f1c6dec10533a5075d7b34d245ffecd1c05cd34202d79f03044fea525d183fd3531712714148b814b25f41b2762d7e3723155a54a35f7e301222da055517cd262ad53543674132519a6ccaa7462b9f341958b490d69fb1849ea37756fc5c7c93e07fa69772068c32ab725091c782b8f4c60103d5d5a1f4f5b988fe5358d52502c7b4aac69f0aed3226146325abffa021db01ab87212cb29560c942832c7a8653df8ac035555d14c00f933855234493100d1e1fb0ebd554a1924eeb0501b507d720daed20be2f77d252536f01a7aefb22b091aef4d9397252b6937634cfb71950b0f94344d1f68016ee55fdf5390f3cf0195eb484304565a2420a4a4511be6622f0ed5c6377264ae1b67adc3547f69993f9da2ee0d07980701e49eb9519fe3471d73589848a4f846746126361a5733e73372167e2d980e3959e7fc4a49192321686e930108b35593605b94be28c8994e2e7cc2e624cba1d50517ef8f12d519c4580e8e073545766a5526b5e04c6b34f24d81168269384924666b5676622f42a10e77227e4142b6620dd7f4ff2fce5b656dc5a270662f19c12c4840861b2a0d53047b7b781c73609b6af2d5f84fb0056c6123236b41656dce0a73cb8457d823150339bd9a0cbade9a4596c440041ad20d4e52b07f3dc55f8242d479946c6260b94597149962b5f26c52e20d315901ee71652fcd16330d28bd4d0957c0215d5f7f2b72b9ec34b06f013c5b9538684b39905c37f6c33ab0e11e412231c94fd35dc30a8e329a742fc3176b2ef7d80556836f51db1d1345cb72326cb2f5c53427821539fd99c02088d9c0382b95ca30ae80630920191a488ac95f39d47bf625e282831d2b04a40ca578e13a5bf02d5fad21246a59de683854757e3b4738be5e439299005e2aa434a194aa2efc9fb551b3036d2fbc59ab194d3bd929ebec294066b9b6061e518e2923dcda2ca9ccca7719536f5ded2c844954ce19104c13264c6e7f9e141907f543eccdfa2ec271f04800a3e13c4fb67f03074a5d46ada93b74ea89a74028660062a1e5e56fa0d7a529ad89196665023564e2c06e237978bc5a0d7a1808f673d343b145680707d3e2070b490375b30ee53ce2865a5f9f10ef2957cf4f5b321d83313c44694f0cba6306a5809d04d77f5f5f5363063e37958f4ab87b6a5437a2fb32d798cf3da8aef8744e1d0f37c330414e8803f73d50f3c5467b18046a2d227500408f5f2bb4840f4432e97a6b42f3952679bc22745a424669693b9c6a5959f92c3e3cd55487b7160fe12c666949650870db4e82163db18d0b856ffa1a50dcfe12a2a60f59c5bba734cd751338dfb31f3b0de58f19395f6e11f1ce7d115fa5e372e21080583bfb0164a5c31733f19a6f50a52e5a2a201614523d4b55064316122e6e5d9120329e7443a6426904e11d1613d822df554f973159d751956e97ea0a034041762563470846ee3a6874f711d2100879f9171858e2617cef4f28513f1751c8f238695718f70ce5f49a42f649862a2a960b57be3fdc00bbd0c63e6c5c191373c63f23080f412d96724101cde01172ed679654428e074984a92356628d873b131d55120132200715e190598796420c2020340eb820ca4206afb008c9b11c5b7cdd2c5f6db4145b2d88171ce609922fd65c30327ba3423517adf22e547f44452db4c01688c6fb22213cc424f03c883417dd600855632c06473d1963aa688f59cfed6049e5375423c624ad49bd89520662effc40f1d05f2ad43f863f7c8b2d2204327665e799272bafc6e22e04d7d72c5532a85ec6642e389209490cb2d882310eb5d75a7e103963b6bc5426cf5e545db1ee232b92a0fe05726c366dfea28114871a352131290618c4dad437ceb45b494be23d0e6d7b0a2040df7a6dd0e147646468ee4d97a13211454c5b0e38e3e0105a9e2b0f8e180b62dacc3c43221be85aff44267261c85e119fa4e944b4523844473eac6c9d2ec665da3f3013157c91532fa5a95ccd63ee27041652726d3d2b72a599d166c38dc769f03d90084cc5b65fb7218436a859ea56dfe5ec760fc2d90f2469b344a69c533ac92e2b05eaa7ba5d987acf131738181707c5df3b4d5ef5392ccce4006967746a6004d641e5abab2e8a3cec3f86271f30bee504414bd930337a22dc002b2e595d36eb68138f670523e15bd946a210b204e7269955863be65e8368f04182175e143a67024394005332f4a87a5b4bfe7d219e2e0154b9529c068509de6cdbf7aa2cec08353d20d0af41962171177b933b767999a2198ab87d5769186b2ca6fd175b88c229592844be613b2ce025f17f625da075aa490219415c884d10079a1bd5310f55406b3037861ecf10a715cabe5a68b5c455658bb20a744183d25bded67c1c5cc83f6d576a1419752e8f54817f465e5292891f427e32221469cd412abc2b1ba52bed5280e1b4628f0f183786c7e720eaf36e023c0a6d40a3f26566ec373e008a6e762374de7e2fab7e59113e6ea12a927c994fce1f8e453e14fc093c05d4017604e12de758b64278982048912dcc27460cc90694640822a2269f4c860efd3089599658cdd2623764045e5ae8aea6452173a5128042890255606d5d72639c6de17a763963e2720472fb9a1bc1561b4ef963ad288ac64511343c916a7bfd0917cdf15245e952ae5e6328e509de8a18725f1c923bb4d8c104a3b43e34114627685f1ee75c80fee15b78cbb609f19f396993c2f114590809282524945325409c63be04b95d77dbac45aa145c5f415a7550d4f3b5409c380c46b29dc32c7df6f477e9517a58bebe926670e6e61053cb7721aaac2504027f525ad88c1f3d56c94605ce768841209cf6078559221266ed6f18559a360101b6474c3362b47407772f26f31e7f2b754ebe10e88b931c8cb54575538809615f4cb9070357c91fd593b95566aa314662b67a39cafad538777be80c67d97a3bf1a8477303b33d511b57bc6ee359ac19c7edd53373829c3d0c1f673e5752192c60c3073245595c65c7961d075f9fbe1b40f37046006d935c9078251f1fc0dc519d2a2b5643b94160fd98986d44afaa2268842b57bd4c9b3f6a9aa548ea26f73bb621da4a19b8a910075298633588b72108ea464729c39e42d5fe453638e2702605505416678e774e0f2c64000249b718bc942d37e4d15945f8f65c508c6dc412c6ad1261c1265a545ad62f62c6f8935246ec600589ddf80b67aec66c21bcbe74407fc6725c75e341fe7c3c221015950be0b2ff74d0364a5be2398857f571d149594c5130ebbb653e51bffd5cab7e01637bb8e87434201d049c5287568e7b07250e8dd965d68cee3891f6fa414e0a80254908563147718161ebcbfb43ac48886a66b7593e06e65e5deb09204ccc2675184046f4174cc9305bd61b6d3642ead3570de05b18078f4074d80f604190ad725f039cab58f308b2717d75405390c0fd1b57eff33b1831ae35ca0f176ab8b11236d0a8e43e79108438e87d731b720f864fd2d5f03ffc13012892cf42494eb5380246e68f65ef48222608e431084630100034bf5968cc16875b75f83c0f878cb0196c487237631ba55a06f2475fbbbaa61d62ef9373a247a1694c1d04719bec600d4b57991b913840774d4acf29a17f4b4993eb305a4f8be310ebc1ee4ee9deb26d110c5e74d5384612e7d3456dc1edc13e68bc463b2f8c30540ba7e72468ba690bc02ab22627dc4263b2d76348fe7aa56b908ca9101afb484b7a7eca022ef9e064db0af056fb9d94264be5cf3e1e724553e4ddd518d903e439f2a26655fe19e32b39ed3477285a164c9685ac4c8ef328172802b82de6510003463ecc33f1aee05c09c9ae077a60d867f2f0db6c3b832b6ba9b2ed46f8b1b76666c03d68a52caa433ec58a0b93df026ad90fe80af8e1d2117bb361054b7b1b523eb3396172e04a45a36e0a7790c4ed770b5ff6131de8a6425eda30732e459b770f6f260289074911a69f346cc103601cb6eed7478a3ccb19c89b694114d895487f69953cb1100c1803dfae5b248df773ce4f5137478d5f320c420d0693d3725972995e33c297993aacb25f1df43ec90c1fe72a28c46e54304505c574a2202905ec02314fce54f84bc1d8736327e5733a4b394d759260be21ad4df53d773f85187194b120d35090000e916062a639e73a3b002c5773a78e67c31e343dc3e5756764cb8441f7ba214bdb4ad12abfee34191f3a3e765e65fb07f5052345c43bf45e95fd8b6f90bd1c0414effe380d91805fcc0b4f6940a85f005bd53e19d57120534d1d2e685af64837ee138a284aac7e766ca760340cc0e31bac85de719f63b23ebbb6070079e9b84a4429331d25b6fa49c6d574069c86960b158be1407791bf37c827864c1cf0a46b479742383b8dbe64f9ef712d4075c06bf3913f4b3667b23cd2a4a4094542985e6061e23e09021c505a6d4846ec2689275af84937c5ae0e21023cbd126db30c557e45df1b9656924ad4a5524786a09e1e9a64df560b87af17f5100d22dd757c252cd8dc41702e45586c2a7f4e9378ed6b6319194bf120590128b4474fc8d04e7400daf935f8cb763e878f0f634536cf5c76102d20ac85a6564e754928cc06e3675b88656e322ca61f9ffa214f40825343b51c8b2eb3001d2524a29e4e30b16a05818cce5c8152621073c91c60b3199459688d6401e7a4b571953a470ff2e7ac595a00422d690939599717c461cf81ed108bf74d3c168b12303bc8f31288f9e03452d6436caf5f9a42cff7ea621f4f4240885741421a546a0e40ba3f4a9fe1503216f4914659fe2850868951175a58cd431e193b14ce7cfa6c3398d733142c7206e4d8f85fe8c71827be262a0bb674b04303f7c640250bda27d86cef280ad61a48b015765e83b0ad615782e84c3717290f8b9f6045aaa0433114d9d6501c6e630cc66ff25ad9ec8a0f98d5b000b3d35b06c1b73e145931dd031ba8bf7629e96c5ffdcf896ac2d74d647c8fb111aa83fc262294fa2048f52c0b9c0caa24963c936fbb13c233e115825befb3b76cd08aa23e1056884d68dfc01e542c44182a33697620005f397de02816dd2f37599a8aef43dc96d734f45a411f99e3110f339b9c56088f771c64dce715ed192c13a7990068d707a332cd35c161b8162f5cc350776b1897cc5e4dc2633b10c8a24dede64605ea33bc4b28e69f1299a92442ad1f1d3afdd23475d647981550d847010bd9417304833227f3d4322cc33f1d3fb9271e40a226d845f63193553af64e0f6685f9654e450359616874574557353e029c7952f2e5af455531ad625959bb1faad004584a7d6f273a748629d96e6e16bcc6f027e95ef825b945c20b4eebc621754e8f7609970c51bdbcca5f8e1c842b620451595b46cc598b19816c7ce1675b607afb1de13bce59a8e1ce474a054e345d48060592658806e60dcd59efd3fe64a2314a5a4abcae487a883e4d1567ba694410603b506c140b1ad2726d15c9ad214528af6fcea056179a55994dfce9460c11f15458bf4fb41ece5636585fe6e717cb571812a6ba7b4d3b7f7408ceb45f3ebd5d7f11d5b8ed5982057d522f471b762f43861b6f21ca6782dd8f29d111fa1d57e217592d266a0e2ea77c2263dc57668fb1ca64c034b3625e881f37a1fbd755dabc13728b68092a9d466065354e5f27be88db052bb0ac28d086b91eb65e4b224b4efb29210f745a21cb0716b162ed2201e77c315b1e803040b243206ad46f08ee52dc628c1f85778a66745c2e18b82bd7348845f33c7c2098c0e42f01b73b1284fb2515470f0942538af32745647e0410d185085c68777212b61e6c3c7ffb25ea301634987af70c6897462f90804f5e026e02300bb7000ac6baed593a52313a5eef884cd7a3251d604b7a6d14391470490456443dc53f02deb7634539588151443ccc154ec58f2318100e2a6de87a1664da495a6b82fc6d4122fa06f3be9508f9c7910009a92c3558fe632ec00bdc41c6bed36d183dc71124cc7e3525c0130e8f602f5ac4a38169f3ccb37575cf78671ebc60517322df341308df3978628303ce94ad39132aea46f734e203a9873b4341b6ce1c295a6c639b61c10c122edc4341f1f86c6a75c8165acce62be9366570c2d0e169149c30193dc1a66a38fa7f6546e18d543ff3a46f8f91c064ee577045d412ee15234509351e67fa02b1bf881fcf039139b2b43555a01e1a02e9a0f47655f0f0530616730202eccf2deb847b520c60293bda8e535dc532f072a57852203b0dc42fb095da01ac25af5120a3e502fb1edb24f33e264ddfd15142bf37464ca2952a553f0e5b5711586c3151a225027bf8e96e1da9a17501756935060c0454df85d3575df7b8328d0b14674c218729ab63185b2325aa72dfab47481a335424ae26605d025ac31192bc2047440c8d4fb3a9af20a67cbf6362162b58e65ec94f5ff9b2145c25c929b4ff114d6f43001d31fedc32e5a0194dd038441cc035ad027256c9529170eb0f59cc26000da589304a3a790b2f9a9e334f65c81e4b8535393381b26cbd93b55d891894367afec0720af1e22758a9b81c5843c83acb07ca18952bc72e00b7bf1cf94b3b1cafa0bf4899c61162ec2523090445e9604a2e704f366ea3750a91c62dd167ae5f07430b5f2f15ba14195fa8013ec1463ba7d2ac1eb2e82863cad01455a844fd0118f69948394b8a6fe28a6a12cd325620b8f5801efa5b212b0a2c9462cb5cb1190376c92cab99df523458b348152e633286ba704f33462f636f1d0718c9a75c077fa9e10546e1c859fbeac51d44f4ec7336d22e0de3757c0f1b0abf0aca8d00718302e11383122c53b2eecd36e0f787433796be54f5420e191de8d659f6680529673f605417ebdd2ad04e4e19fb40bc6802776e0683871369971ac31562cfe86b67ef1138529f6e2de0b58049a26ac62788f902257189574efc1b96711db4560457b0447663d0476cce3c0c0fa75f001fe0b1b566a67e97711d99af288c13c36b65f8f318b5090206509849034c1b16153cc970384b02a67194ef4f74fc94a7680b86ad2bc22d3221e8995a38f19f57727da08529f497f332e558252d6c49c525c8e14a53c4f03e6a2953e44bbcf3b336aa151e68c61d114ad7fec46e4244b734f7c5f9095dbd151e6a261e084d123e3c92209374d2397a3edf362f5e85fe3529f90ba769f6913b097fb2907278f7fa124dfd3a2de06c15241171f06b45983230e73b6610ff60e61d1819aa492051ec23e7c68f4e35fd0c7054419149f8c25b037810515c08d7d93640632c55d77144520775c24b7214243988fbbb2be422f8683ee6ab353e5c9d2e5b80962a0f98021a555ec6602b49be315dc5ac3cf01aee3203ae1171fc4aeb174869b92a4b534748a2b4fa45160e932fa8ce9250658495275685d84170f3366a892dd51fd2c6691e8f1c3e444910c45c3142de5fded4df2b7a285929fb9f5d5200b5273ce9df813d2ce92777674a577519a00b51b5bcb44e3086aa0c9ab938168606b36c264c7c311c5b50600a80fd3c7427174e7904cc6a6929b377594ca81dd5e9f90ce5f17d0d8efc36774b27f401f018f355e80f320baa4a643c2f37d858d143234a7809a56e0dce140bcd4d8a772fbb1f53aa67715ca1e228372ea54d284ab7f25d36a5e90a78bae94411bc9035bcb0562121c62a3fc1e222462e43ba4823c2451a98e2327507f4966379948a0948d3df488888d643c2227c0417535256ab98190fb1386c37b08db2280df6445803c1ba41e9967c0776aed32cfbed5531acfca2126875052830506d70faec3504ca382a39b931ea21e80bd34f638d6f10b2de9b2609d2715d01129b719126ea24a9fd6e035ffdf320a89e9d28bbc00517f2e3c07053f18c25a06cc430862c525a245efe3e095c601afb05bd5cca858f0eb3f0ef054248894a1a55226153ff672b6043501ad0e42a554ef80a4ea893c60fc4053051586b5c332783c03432c8330f580be401f561376af5d0b835dd43382b190dfa0f3ba2e24dd019165a3269426481cc4863f97f49078765d70f6e724345c36bba171d9d2176b0d634473586c72bb28e496a197d17222ceb5e511e9d0a00c2296921f9656b409ceacd22a4949e4e09686b255a4483359a91322facb2a6516825d462dde436009443a8607d15b81002f8c4527414df20a8462820e9669b0784a47868ca1ce9265cb1de1aa66f3b4aca03ff5f8a7ce531a1ddca0d4619b9522409902e4472a71979a8a45ddf23be559c34323beaa0344cc0121d5b9e7df065a1012f23ec571f666761d571db74e7236a1935511736dd32de631b1c5dc878729da6450876ac846667167d543efcbc58f41f5d2af5bb8842efa324353e8a4a2611f0146415db7e338665f91b4151bf2c491fe746084dbe600b1b5a12ccfeca36ebfe6a2c7c14d14f40dbf1063afa4919941dde201a17a24e8d9cc65b18a5d830a508e51eceb4a6037c6d58340f296177fe90d4373f167b730b292f15e3654417ed1f2055aaf8e03d547aa52885c87d627060ae486106503d5534ae0a2f63cd0bdd95e90a1646f20e05df3f40aa2f2035caede54e71292b6b0de29f21f3acd72d89639157f993d664cbaefb693bd1795e796fba4e526bde29f8d18b0f227e5471fceba9363ea92b4e2cda2048521e7063aa56d45e2575c020d79c4a02f401165881593f32fc9613381147bf4fb30e1b25c8a9c35d79fbf0301e1f2c173cffb25d26261a36323ceb3ada2aaf287d562d2a4217a12751df665039aa8a4101cc8a68f1d080652c8717149e4b7f2bcff6043cda2e8906254c696068cd7e2603d2961429765265c07768627e40b3592a89f34329625a6a7a444335b6db06081db9b851db1aae5090e0c35b0b55ce745837fd5e337ff724734ded5347f5472887d6a743e141bc65865ad964cc19dc2e3f93a17437799b242619833cbf4ea1462b476e1ab75f36611536cd748942b53de6af9a1ea032a550d7cebc4150fe2e50c70ef14c782af80d9238a4513762c376a013c922b6d8b241499a905ccfc62438a48e1830ddee11426b716d1f14245d1843a6e15c3e78616cbd4c637559f3970dc71a071f54eaf4422a3a3b1140342111ea84c93f0d5f0842c09e3c2429b497134a29e6281f267273875b73454239b439ef38113a06d0ad16bf25a76bdd1d3c4fac4abb1a850e8c6f8e420e233cdc3b062e8ca43253d4323fd654c352bd17f84c650a862653059448a9b3ae760daaef1cd0c6103f2ea4db4611bb0741e4ae5a5c9c9b7412e49fab30f4b3975bbf81174bf773002177780f088b8225372a8ea36847642859e35a5b03e2417056a6ce9a5f2d36b909424bee25c8b63f5a2c9c683ed1306a3958ade3333e15e366096a5e74f8db761096cc34241867075280c44530be2378566eb9f9525bbf1109b5cf6f02920f8b23d966613e91bc72445d37dd51260b366dfb768870670ac42b5590216b68e9ba5b7263a42f85100f4d7c3e92334577a82d7c6f8731c6f5051d886db251a8e47a0ee3f0d1539e6d724d10b11a22f2badf74fc5c841382683f25294aba176ecf91665bfca951e4b65644a29ed775d2d8ab59a02b284106a65a6476f9cd58a34b180e8047351e0e4003531c034a0245368c0625bb4700b02b4269dcfe6301774b425d36205f1d84dfe3487c3ca75c47d39f5b187ef944df81e12180239f72d2c89c06d079d316d2a35a0622887708f14bab1561775f0c41353d469d4b9c0ad290c8657d125a737ab9b16b6c151e0bd569e34a73d1450b82c4921eadd32518449a122e1452b9396d941f0add6a3062de46b172da71936360036f3784eb555e9cd6e64b3b7fe951e2f83c40467f536e8bbb8c4d3e26815963c1c54103b4353ae079ba00ac1cb46a2d09ab5ed347da0dd4ac3f7542409f32136ff674c6ffae289aefa577ed6d430ddf6bfa48e9302e608393ae138c673f5fb7937120b82b8706654a360a7d06173fd957bf298abf2364a85e077151c84f5c1065f23e49b974342c998e2129fe516f84b2683cda238d0562cb901866d8a8484daec1284453c034882e2017ee682b4e0451f4571f78ae3725f4437733cb922051d48d02a6f7fc68d0585e16214739743a4ee8285300360f7ad79d0978a10a26557ad869b9b48a1d97227047231cf61fda2e1b3a0d7dd81c10fccb307b577000a0a2bf47cc7045410ab67d05bf5731704a005d277b1afd5a57a2db5432762b083bcdc031917ce7465b78e518e0dd312379012f183421920f90264a4ac92b6921c7492a203033cc07bbabf969758c1757644d741fe8bf0710c4c0e5768838034a92a13f6286c1df0724fa2a4f1aceca1877ba8269e93f9a5246f247098b523919ed74af1bfbd464224771ef5da07af2695493f27610e7dd71889f6f307ef3ac04f77d3260ee2f86265bcac50b9d5fa23a95f6ab427037ee10c91e936584aac71167a0ca153bd1d10ec917b209d1d9cc3522869f6cbc1ccb3334e92945531aed174bc3993705e8955a28f0d95dc6688923ffeedc49f459cf1fe686865cb9984418a111f254cad5e939ef008a43f1cf035401afc075c9c974172a185b14cb074810a3f9a029822ece651042780ab7ecd46553bc4b1dbe03010263c27755b43a285d6ba05912a634d6653853622bad80975173d1604c337286632702dc39c2eb114bc436db43626a85525b0b62685abcd9271b7a3c45552d44550ab7e5266d887f5962f9ab69121f0770f3e8c55dedc54a7220b42564ed32ed406cbe4718607750576c17235a58d714178fa30763e84d8d30cba7733d22cfb00206b608734817016e0e54440d894dbf3aa708bc735b98f15f877077572da1bd06a327f920de130761c8d1a12ae282db45f68943501dca556856c598193e322569cec23367056b3d752c691b2015394a37f600e4322bc0f159f7af166db8242f07f62d9933744c2103d8cf034af8a0ff29eea99c0ecce297075445c633cc63b27290077d310b1218122592d80ab3e9402b66fab701e1b31c498ac6374f6c994f4ad7e883564c9060597987fd563e601846781aca2fbd15fc45f1e0244ca0ac5b29bdc068545b390b165d70884e366b1451148f713dee6088448eb2ec69990608426292df6c7a8d023df0326a5d6a8fe15eb097fc733b13f40ef7f90e282d76a758354cdf38edc6653a3c6a0c5690348806359612619847512a2bbcee2709e8ca2804568c630bc36964376a764957cda511b49d7021cedffe04c4e07a4f970bde515c325b030749171c3f135226f61c701f727ac56a2c079e3cfa1b303cddbb2104346b6a222ac5d51fa0b0663a66fdc7559e4de82307a82e57e91de64448918b59d12c60336709440b2039e848526c084c4c561c607269b32cb8e37a454ae21957e7ffea5ee5033a21b11e1f56783bf160e38150501a563777fc373b6e95b8e2434b038363f1d971674e8f10147642d05188c69d154936ae6b2891663caa17522b939f6b700bc6ff496be5a928489d415d29540f18f0758758efc3213e5dacd82212d5e4049848b44cbb12b3477bb056555194b71da2962e2ecef2a2253c92f15a2c3b0c12a3541a428ee0c02a2f5bba5423874c2b6b7f2d06cfb42f3dbedc9a40848b28678b452a2a5936e828cb2af56c0d217a25030d8b571441232c584923738c8e9b5e9d07bd55d2a59e6d572c103e9931e34962a5ad244a1308437ceb87136569034af8a2995ece91c3722038b81acf21e25c8d8d3b67357ce34dcf4c9c1a09720b5c2a413a26e995a6669dd037724dcc9e1c35144e3f6d57381b8486f1304d90b909d10a1c24d077221f01aafe75b1fb6354e40b5f3f4e6c6f5cfb8d9738b54bee6b9405f8015e31ec4cab1f0d3f02758c3c4faade0fd1e9a111ec887a3c4ef2f332c7df3145f66a0a00992fbb34a64aae74c93af00eca10c874ded2a3125be42117b24ca00ebe886c2b0b23cb5c292b4635a8ab2465dff2d41821aae818540c26623d5c1c399b66540e03c8360e858d885b4a75bd3e8139ff583f68a759a31bf02be662831818b8b7541ffa5f71488fd92271e2a471ac270b1b88741628b7da3c3cd2bf0b2502f7402a5d62b6123ba54437ac2e4d48930dd3186f50366c95aabe74712c6e5b7cd44406e7f8f3638f35b3229e360412f78924767e1690022e6c737032d6ea4100b05e20ab5acb6d5debbc07f9a5ce2589bc6a2fd0efad32f59345301132b656eb157d5e38977073d8f190033ea6f70d63425c2e5d825f081602841c856ab34b13d9983bc7ac2c1589ff9e20350c803ca5ff9031f19e705e160a8b04c0c6a2614be82f6866f2054790b67c121a71bb2c649b6f386d2d09699fe806709439a164dc02242482ca4b6eaeadfc555ca9dc38e8d30518a8dadb3fcd663b57fae87b43b8a02c536ec3a754fdf4044e264be618832ddd22f5ae7b7123114166384078772daa1619da056a38023f6a5768c8502c4d223703c9424e5f79c4553527b6d741fe76d94c1c54cb0e49d4fe4882aa8017199b414bce6c2515f2b0974eeaf8bc5ade282b09f2d6591602bc4959a8acb851ba8a0428caef9d42c7719c067b24164122472d491401bd123a5fdd30593e0d0ee479fb53e7029969a94caf138a2e964d84a9454c9c5fcd353ecf17114dcb3d1a38d653287ddfe2404a33984dbc4c232b09b4cb663379ae12d9e21412bdbf3147f6a1cf6ee55ef573319193508e21c26549463c5ab5ef350bb8ef2624e06fae4a8ebae04115037550a950e446f0727075d3e6f732cb120c6a853cec10575bec4f2bccc73f5cbe89040bb23d6e88a7a1642eca81760dd2af174068be415e839a001ef5986687a9700edc25da71d1edc31a04ab862c4388b00e634b216a07562b1d0b87a125d9543f5689275f7121deb22fa52fb31ce891017223df0e5827627e505ef531649db34972db1e2947b4d1f33f02566a26d9bb756b0b9e635f4114502b4289453a4067777709570a22c7c1ad55920ff06a4df82821e785d1004bb9bc29afe86359e7fa463c18d756124efb6d435a709728aa4c8b5aec32356b5df8702bf520bf4da6291455fbd324682709fe46217b7012de32a56a442e1c5a51714e55cedf6501d3300f14c93f69332bb53522c942ab2314401e30ee1cce70aa54fe3bc6abc6414ca05b5d8278aa3508e46a07386741644512871d3e1aee774430ec0d40eb6f41edaa234f99a03b07bcd70140bdde5f58d60bb807579cf06463241d0b5922ea05beea2f236998486b7ad4232e5c24ff1c5b211d5aa874445af376762b6bb8f9254e09ed1315bd6f56db96542b3e64b35bedbc0904b668ec40e553b7711f6357213a836c25abc78029d4ba0172cfbcbd33d0b07b018627853a14dc396bdd5bd42f8b2fe73c460547254203e225b3a5ef0e0606d00ed7e68a16901cb506945180402aa0d3249664ed4247774359e6def14b6afb703d93b59337482ed534c1775d2a50147962eba059710cd51d2eba5a4f702199db735e455014dd156e1da299aa4f49a65a4e16c8de07399a7d1bac46531dd9cbca728b4e844d1d3f49234bd0ca1ef1dd803374c3fd4c34930b2b12ee520e2858d03e1452bd7028115c34dd5a2f5dddd0591205f612548673ac16e7dfaf015f24f231448d772758f747524b9e7a2994cdd0777010c61666089116f1c2ea3d052e4c41c5ffd7174dcce75f56234429b558321ff05b981b54961f6868f6c72044481c13e8e2db4d7dfba630bd8c0524b5d8130b4e793334509a2324baac1c561178ee59daad3015200aa75e6aaeda373584de566528014182c2051be39e885a5835f44c98daaa419016930453b0750e387f884f9372ca148703505cd99c846b13756936ddce494b606e50212249d71bc1d79f5cacb03638a172e26f683eed17fdfd3672d07fb66bcf0c8011e1b8f861284b55358961bb401d2ec539096e930d964f3c6609e603058b99172a10f55e4b23303653d8e42e0e4f1f842fbb08e365eb277b1f15b1d05afd92ac1a505c23364cfb7f0f6a5b60336452fc6a86606c2e84edc743d4873506ff17982b0695f4751a13cd37a58a5b13ef13886835c523258f222b25c49d7858a681d0566ed29e6c4debd40b93c4634e7d51c8008777c327c905664169032f76b2e5924d50622c224ca4890347d63d1e1fa85c701725b028f5844364e86ac771f0bf9b1f086b0807f5c7f85004a46e50a4883c0a8b1db0173b0955517aa8954449039867109a2b21e46779774b9cfc051d05bf114eb8ed549bf60e5e1ef67b3695c7d129d2a7e149b3b03869c1ae6069005aa25cc90d9b1ab593af1810c06d1458df745bec4827088c60563ea81d9a3d05e8c675ae0a086c130fd760250c8f15009ed7465c7dcc45165803430a0f7308c6d6610b3f9719511f98af3623783a3b2085716ad263d127e15ee00e19895151b0c23f4988715052a03c560545d2f23e5b5bde26612a705c504f9f57e6c24f4aa5b251014cc0f85e146a1e0545903c0a9b83225944f32d470b1a3557e1fe25725725ae58aaa733291343174551cbc175119e4c2067d9a1768209164fe3bf57079799152b1de03a34ad51470a4f1be51aa0ffc5357f22292d939d83319365ab0b1229aa2b020a9070499b421f2d9fe3591498777046b0204ea61f6165fb379313c423625366fdae096ef31a36d2129855eea1d42710be620e57dbf366bc376a39454cc556e2b982514525a52a94083e3fd3d6086b0d596f6d7e79aa1c6e2e2126784d804844b0236f3d1db86d7b5f230ba67f9600996c8e181511011e30dba21373631630da2baa6976215211f4065a3f338ffb6c6fe0b91af05d01446a067f3c95115f70e2941a737e4d1b207849ca51cdfc5d71afcd6a577e44ec511452432a4ac4790111b24b085e0b624a8eb01e30b739f3605309533247a1de5d3d0a5725164a91174f748558e315545ec63c6355d9725b4978a8ce1352636a0b00dc4a6382bba13917476f5c082ded60b0b8690f231f5d03c212f104fc3496528e548e03e610cf14af9450679d56ff6226815c68234e4b29f30b5c697f6bfc53b0fa49214bfa09315f027f34b99391549e1840473a2260273571f35a6533572d4978f7640ca8dc7143c01a647bfd06525eb9095dce044272f8073b57076cce6e751827124ab40b2625a9882fc6616f188f4fed04464df96ac9f8780d6398db322ff50b3ecd48513e82c0b057ce8c185337fbc91d28c0726ed5395e47d20bf6311ea0c234b1eed673ee5ba96ddb20bf42a3b15605ea84714e6c634508fea09c119bb0692ab9a44b50907c460d5ce7100f6c8a64289971e510c94de760d9827765309bfa5ae5da2b6798f5287648eb1c47ef85430f2c4a123bb275ee06384d672bbcadf006bde1d80edd0f7e25c9942d12e208ab070ca24b2de929c205934d4737814b8d0fddfeef6ac6ef3c3ced34433fb60e2f5f0fda6c66c219b242fda12f2bee3af63107b11e4704367749b0340772f2582745e0760328f2a45c1064e5e920860bb254309603447c3a79296eece46c3bb0ea5fb985df5b23d35c6b211aba64c0e4ce13abd34c2e46fd5461179b5822374a6a355ee6624e26167742117c423b4e95610dee7f0f6c620747134799a51da42daf1d80857e1a86ff933196526920c53b1f3095f2b003a1ee361030d0ae28a78ae5298dd74b511207a60a59a1183dd86e870ac0b1b40e36a7a614d48877770388cd3e93f3fe0d267ecd1f23019b229e76c52f465da41088dea057b7ed7d2691f7d84caedb7b3461816d354f699c525a990a62ec14c27056ea30592cb1f262b8aeb03a10c4b54abbb8c04750c57e294b576b2c11b7764647acf113f0b09518459e5615102183049e05bd1c3c156e455bf1c51d6229d5639ceb8c4365137f56c63ba52c19e8b8207b69e82925c549279c7e5764ab4fe73d527fb7475d476071ced6384b9f6e7433ea3a485e26e8e71a113f5113a26270573d5c1d6ff3a3d7613df5cd31857a4803cb58bb076bfb2f715402d7273cec40639bc8a33bb815914dd8c6bd4d70dfcf754cf430765fc5a7203d8ca134f4c2170cad996d6a1de6c0563bd55573e4996608d7cd493170a8e45b8d4596217a24af5f7aab3f714f524439e8928a55945b4b5042237714fcc2a1690f8f3b677e3ecc05d33ad163cfea785c34c36e0e1173c6054634093d7d1e2f72d2cf421105adca48edc1683ba06a7b6cb848f653fe1a5171ba4af01018c2b542006b005974fa177629b0c264488fa50660448660c87bc7461fc8843e09face543df60f0d3ad58677477a6319968a341e5203470213ae14371576fb046517ea3f39334341a0db5a741a3bf734ce212b1d85bfcf375f25df5139eca65c36f59b661ad9623e03e19a2b1ca6103aa546804351b65453a6e50841ee3f88775ee0a55c8e660a13e103ea1c884d761e6cbf341a6780a31d8d97390d4295e05bb39b3922d4973d3172e6c843b18f2a4d84d919770f7d2800118ee341c3929e5dd6d0aa220ba9b409cb1ac842329adc43c2710c1ee8a278672155bd36bae3ec23ad2fa252a2557724eca95e770c0b4877ce43ea0fb8d42f0f9030a71dc3e3310c64cae14ae089773d85df060740ab2e54510d2576dc205724abdbc4423d82170e5855f11c8d8627138cb08e0c9e9fad3e9ad4dc140b78b20cda81f5758036d430949c401a2e5b914abddc896189d69b3820682b2860df2703a98dcd050e960e6ac7119227df1df84f0569096b53b0e460e8037266a2090c36ff801f6edc26a118fd6e1d225cb27817f8f2712bd40f695109f44b29de522f562ace093a44c09b2113f0790ec0c71925ddc1d92204897c5ffa446a3c07cdb80bb030d14bb06d0e5ec8357202b960b62c2c12c40af0d62a6e1c52b503e8ea013ab7e74d2644f25474f43d9b737a10cc3030be55417a820753bb416f478e0d0f2d0e532a1ada16ff5144f29e2d3277f94b1ca0d35dcd4f761f2e9fb9618a8b28576233640ab022b918f01fbd406c61545e4894245bcb0cf801f4dbc37084d0bc19fd99ca0719d35542ea36972930dc77087fa00f3967b46504aa417d3a54977b12af9fb34f0bd91514a60d7648b247cd643baf5774b1dde12a2dec5d5c0d793f088a5d9f35a0481f04f52f33390099446946b46911c05dbe16e67eae05118f0e26c530a07053621d42dcec2724a3aeb927605b18095a6f53751126c0030f4784146bbb962b8253c0519e968f5cd678c15f64ddab2b6d25d55128fde4439c0aed6bd82be82bcb40ac30a113382661365f2ca886ce726e89416de1f136091b39b74a41b45b0399e9cb5c64f593760884375ae2c20a754f831f23027af02032262c28842bac450532b328ccbeba22029fbf47f5b569636d3fde3aaea0ea3f4edd54310a12f058a81e256e7643fd1baa3c1c066123eb6fd8ce94770af2f84d6156fb3e119440128b01ac671f85e53ae4637c60ee053902b07e63404dd62a1e27cb256eb7af07339036556200b70d505b446e3616a7c43cbf68625e30827c1daedbf541c211091cd3212319b94c05650e817374bcddae62dc1e380cdbf7413013db71708b5aea76b48eb17618405f1ce203e74b4e710158b689d55c9103655b90a1406752462011e1eeda3a54059a3971247a3da9ee941ab8fb2c1b20966e1e8ec081003d2f5347f3839b634cafc06d95843e17a0e0855b8594331457dad96aaedf377281c7d74a0527914c4cd4b003ed27ab46a195d40fc7663d621357e310b300485a1834bd45274d1e142bf5ea1d069df60394795e4056e0b56af5dedf5cf1f7b34c03633f3e6f7e594187ec2006ceee781e57e98a2b0b20071850a97305c1b5592300c7b63daa7a88029a5de11df2e821188045f547d4b0f2632d522d321765a9321ab3e21cf820762dc526ad0c68cbd946ead4f4572da81d727a170802e89a3f66957f8b63097d96062465084efde3445f7da33257cb471e44f30dfb5c7feda3297ceeac10677edd5b8bb68c58311a8d23834e4e1f8b4c4247d9124810e8db235647870967eb1be44053ae733828d0c9318fd0902cf95e6514a703e25b8ec0490c32e5d159b598972da91dc94d977f2763ee5cc62281209827c728513fe2d2b73a11276a5a2f78a034510abc2e9b0846437081295a1d199e0f3ab826090aaecc214457e2410868096fb2c9574f2cb65356727d413592c6ee5c8f5ed414896af60fc921b355345d272fd5888b075f44b62a3fc9b10dcb01e023657f47532f2cdf3d337bef7338d0454890f6ad2595ba206c0ba6780d2198fe2c5db6b2528990e41ee3bb7c3e420b734914735a440949ff730b86453a0e398d198a19e72c2426d943c807cc530c51602c59ca33482756161bbda6b3420b44691932bfc74920f0c539d196854dddc9a40440a6c0033202921603b2495725fc4c4e4970825a85c60f70c88bc274e2eb182370091c5802d2a776c5abc83982c9f362ee41675bed0d3c5a41e1ad25baf0ea5c6e54d72c1ea34565ae3f3d0d5698355daa45a16e71ab3c7379d8870f3072a02dce797d4bc429ac69901d4a131c8504564b24fe3a5c89c10b06c2f050fe18e26419dc9946532c1c1a481d841ed9e66c496a2a7141a37d5e60628b5446512d4d5287bd741c5505bd628902344072bcc1552a14e85ecd6514208f69f20fa9531374616a783fc4db5a6a7d75f03159600668d0b1895c128de849108f3a504b338225492a817642634f5a5972f33248ae51491debd9241602130ff4232a2a058ede282ca2302cb787cf1859b0f914c0afd52f70207b6f004fc41ce9a7e00f614543418286cc21b55a61119aacaa6dc81ae853b20e820e6b86603c5110ed12f0eeab07d82de81443571250b2833f0c68b378470abca0213ecc2511275651370719f3170a345648358e2839ddb5cc23e0057147f7304012ca84f2383497c56b4d6d1a10b3a1a623cb576b0d1e43096b29a94b55cfb2c876589c3225f5ca372c84cfc25887d4031cc1d26b6da858f572c3750a464589c724cdaefe456b47175ff76a9469b42a615a134ec727a8dbc17482714f3875789d46928de06c743f8d237c314d1a3fbe144f16a23c4614b9515a0574fd31f591d32cb15423136508d114070731054f5f7d20a455dc60989307564af2656ccb9b6b02aa12a1574bfdc03c3b4d2e1c3e49ae5240ec2320ddc09d69eaa1994666ff263c7c0fba752e58620d40b14574d773694691928e261cf0e7643af41f4d36acb951cdaa5160bd08c9672e7fe751074f161bbe9f3723c2364d1680dbcb368283ed309a19d11b424c696822b1a21e8588206928ad623a0df153134008ca323b907c2ddfc0401ff1665c48882a4e190a093e2eb5cc352fb8b131719b9a1a0bf3d36b2d00e0e546073da740eeba016803699d5512237e06c83757614dae513f6df75b2bbd945241839a5c69def38510367379445238aa357da8502ce4aedb257cf26e290ee29b2e69c2901bf67b183ff5d69527f832582c1745a15feec17b3a6cf698179d7eb3390f2dc53780bf080addc815526d9a710c9d0faa2030b92b689cab4b0c5691323d2f3402008b5c4c5a23ee2e029515c445284554548944d84ba0535450d0db3155b103b0109e4be65ba9ed5a105e23af5ca7b4980cce991c1ed563171d66bed06c554be15fa0a3fe0273fe7a6a22270354647a6476b35bf03c9a37430913681b2e2e263000ec365d4d386dc72f28e04619432bc66f00942e764cb2ea45295f08169902045dd9710c40fa8d1a5ffd009c3bfacc496f86b7063f9c7e870867041410453dc803ea0ed97188a26776e6723659afb5403e6c3285107df2b104e0428d09de120e3482c5121eaf208c163e54a371476a8c3a3142a03296821b3db11e0f5ef22d7f285effda6e3f2de03ebb1dd36f20451c10f378733c46beb67390e5f333f41b2c2b700684366a53254aad11d12c53eb3608a2201a61fcc79c65e48de36ef1da003ee07db7221383cc4d6b34e6499b0c3c10e24e6f2aeaa16a5835e8874cc5ff2a4e5b96c863e7140c04a8069635d5cdaa1ae84662405c17c811a673007585236957b000814546ff00367b113c184250791f37224b280f17ac0ea8ec3a0babc8e211519b8e1f5569591cb502ff0de6dfb0618e99b743cb15ed6119bbca281bbc54359d7c4a301a9d4925f72bcb18bc142e5689af78290428fb2c30a9170bd9e6bb65d8754156646ef369649c296dded78e64c641ed23fd35f44168b5d51a13ba945d28d6831a1c12ca3024ac1660b436460c755e83392ee7b573468b6c4e5e9e010e42226347fda2591abe67211e4a21b735602df34475450849427a600111946c24b3dbb229df56864c5b085f416b3a86220e47be06bfc3444717c536511905ef1e4bb9e727c44bde4fdff8832b0f972d19fb7c761b0584273db491245a484e4e66bd11dd3c8e336f6fba97bc5a7612f0698d54ee646a6bec5b7a7a4956d5a5f66069aa452b351cdd714779be1dbb766e456e35712b6034643567ae583679ff414092f8c65d962d301bb442bd1e085b30585777da671b35b70738bd3b6ba5137d3a89f3fa54617a1b4bc394ed736f93785dcbbb967062fe5709fa77bd03321a9d0dbacff91d7ade194ef595686244db4968ce573e7683be2e14eea1764d567a3f3d13f7605413784a6eed23e45e109d1d2640f4a9080d0ff82b5f703954e8b4d85c20694970f6d94c444c3a336ce0d5540b686c3801d7b2cb10d8beee6ba05b1865f7357c47d02f470568cb93136d9c56206fccff36c55b8349af63c6729bbcbc077cebeb649d570c7081c37a02d08d3a4178086648d7a24257d6207f0c4bbded45d2eb2b22f020014aaad51c574ebcc8730e03de0b743dfb500d097f732e1a96516a5ed62a04785f1e49c6ad6587e1a54f25b7134ba3b68c77aba8056e9e015c48e4c8ca3b5668650a84882f34e0baed30adcd0e5b88048c2a226f961434a45a453206a7015e166c21f97aad553dbf9859d3389b41612b0c253ee77f2ce3fc1c083197f2558ac8073c48e7950cc3b1d31700c14233952ca52f4864160b1791a63fef41f368425b944ed0b246260c65d34d61d36916cc9a554efd8d74690c8a2462a7aaeb4533f3fb51fc68256bb1a9376673939807a90882606138d5092165753e8acbb46e1103d8588ddc7d1d5e2eed042d9b8c5d9d12b24029b16807c9a4055cfbd4a70507a20e2fd3565e1f4c83210baae52462b5cdc73260535848ea1e0474e9559f1415e31d6860bf60360aea9d6a4af2ea2255faa967bc7a3269b6c411765a08791a511d8b25128fb923ac326621f820161b198fe24f56c91526cb4a2230183d4a2ec00eb33abee8bc547e773a61ccc7556218e1d0566b1d311a42b99d4e9fd35c014d68fa5399b56c21606cd937c3678b39d9267623b47f7e0c91937b35fa850f1ab158dc6a8b00694a8ec341252360a002440ed55bcf05e319ba0d383b574eb94fe1217133ad052a283c65e85a5051894475aab26ce9363e70afb9656b827c963ec996780eb229bd1862ebd61bfcecca2786439d384772716d1e705a042f14f20297b4c7711a610b6913b83244b7b88876293acb3b187586433ca02c51cb42924a2aef753847f75a551a12666e93a6ad673895213956cbe43d870f6f22ad6fb5437157a124a6ff61533e06e61c9b455a3a61bb5b6f79788f0a2e76d8673f8bff30ed471f255efc9857ea375271346735023f529111f700653f4486721c3f26ef1800e73d1681286102be1d0f0a39c11c6373400d0fd8340248965a631e25f75c1e103a8018c7d942589f3af667543a7006c7be2d4c9a34842f6b6fab76346a891d8f86bc5c92c7b6431df24f5ae527ac4a251783157d65896fa93f591b91aa5e312649f457406529730f51462981cc94739e8f02582fbd7d0cc5fec4565a11986e97203b5c1120de27e6b4d734035d6c543f24e46ae1b4696229b4e53a3de6671e249dad6f16555f32ab965a36ecf57619d1f1b73b6490ae268db0d7108fc93804300b640313e48d19719c972f94318a5f011c1f62a90a646f6ec47905a435a25101ecca7190166a73e69e260e08874f50215a172bba9594677321123c79d2793a99d5cb0171cb2e2695c23a07af19cb50b1daf614b3e87d559e3d120d43ce7e5464d9c26d1b900b0e7258571f0b443545d1f61421b5333e2da594a464d7ea033333b43659c055c75d9541f95ae336415425adc1132016961dc11c935d53613912b0e66d260cfcaf2ecbed76083147d1496770a266daf33060978c9f4fe8adc0189878a037bdd8c05c2bd6565fc404a511366e1f64b7e5864bbcacd2612173d4212fd0bb1aaa13c7741e60740dde6a3c4566edc03c01873b5c43a14a7702d3b559415a0d251608af216467d164588ae56dbe887f5e9a95aa63d2eada041183691420547b09da515924337783037e153764ab68920f19a39451d1f8a25aa7b79e18b75ab973020dda00f0cb0a3178457a29f6c3580c2e5d2a131da78b6baae88542954516007a481a007dc5912127b1cb60e41b884ce1599c4a9b35164335af173c8030a6421438cf702249df2e2bf3fc6c7dfb3630e3106f2d34ba7a3eeab9805fabf0c5453b2f5f1048bdd7010917c0137b67ba1d2b1be72075f694585cc503733118940ca6d0c63893023c660e176d18bc7d78012e281d6f9e012113cc08756c5198885621e9ee74b380cc2d248d1377f056542f5a6627527e8b2e5f7453ad386d0ee1407f531676f10c242e23def93c82e6716342f7cc23e688f877d97da35c2c771742a8f4663bdb52a147d596ec6bdc4cb208e6ab9b051691921b9e47752320a1ae50dda92327a8f311217009e0304ddd7e2f6c06141f924bb23efed0c9311b24b96c0c9be31c2358a463c798d03ef7cc075e8580e016fc4e0056aaf31c59d3638b422239c03e6dfe810ee53721591f8b9202c504720b4beace254c84773111af6a3936117e6d3d01781c764357170fb99f73d3dd075cb9ed126a025e903f6be9e626474b8648cb27f36b5cc63c681d9cfc6e45690e65fc14e51665b46a5869a71a608c819235ea63d9173a27a501c6205668a42d5258f998b650d751015bf6ded6583e37aa16da5b09000e7b2320e5d8953b00faa240ac53c43354ab5d34a40e0213751f3e5490ee171c9e5a476a58b8a20fe6b12e63225aa14db062894d6512a86b5bd222195161f60bfb65f148622612554fdaea27abba1737c81298534f8a6e10966e3b606dba7f2a3a5f43737e549d081c6ccd0d9e84e63137a6dd5bb4305c12f8d8a0280687bb2ab47fee6e4018a21ae477c63d41481b100c845050210625702071513a1b81d60fe4b1c20bfbea83231b62637492ceae28e786355af4010a1b6c51aa5bb4da3a6c5b47c25044fb761a65688a2ea199dd5d2359aa0dce3f954ad85b88426a70f42c3851ec36df73345569e6a3102c7d014354f0a61316ccbe6f213b5517957b8f26c2dff508a94da41b7346d810a31d84173bf64f5660bda06970f13375295f8712235fad5383518d154c31e6511a9cdf4469bac23d3bdf33194044c60a5e18ef0e22a7270bebbd873dbf79035c31c60b3d31a3021ce1029331edff9064d6042130b1e12e3ed24a1b048515b27719a70c765bb343050619d72ad51c272396a84a02fe3b310e793cec29849f1819397d594e41ddd20889f6b547a4f0782b7c51ce18e6e7963952809c6c25d8d334f4b77d5d85e1b3224950ce0822890938396be21f8115dc5b40bb312abb51bd263dbd9d12d768db5e7eb135058307452ee662b23d2251c94746ffaa65f3cc662092e4255cb758535cbddd3e0a79a4660a6b9d186a02cb3301af24826d879b120c046ff532e3218c70d2197b23ddeed444baf9230c0440fa313a18f31a1aaf5601920a604954f92f390123f62c1cecce1df7015c37cfa7ef440cb9520a1566cc2a2da4eb409bbbf26a4f8aa254c0fdf640d0b2830e6f252432afedce6e0c482a3a4e8a0f2157445d0a13565e775598b55375f5e66da77c404d88edc8170f537600d3dd416671c74f084405ca558829a62a198135520481001c55a30c245d27213e1bf645623998792854a5f15434c8095a2ab3b93a4fbadc12d08d37121a23003f3290ac14305086180743496cd144471e66e17239997b4c7360eec8443e15540ca87dd56a4fc1e0242116fb2d257a19170834f81c9abd815f19f3431f535a963369741624d51c723e2fbf514697db4f4a4f69fd17ad74fe0e9b6c3a432f1f2e49dfd9ed381b42d84f44f8ba0e1326b5123a583073f1096a26dce4d13581cc1c460f4bc06dd50fd621d06ef869e003e07502d8582f5e63435dbd7f2344f86f47554580db1d3647fe312b7d485f77c7ad37d3b3b328450d3232201a5c674dcdb103022e30207f61d7359830f509d680da3afea4150b907b052be238735a71eaf740eb88ba182d108b41e581db4e485a76716876d621b11529267739b2281457c6168ccd2122f0d3453d476a022058c44a2cbbc3b077692c0c07f2dd5004c6510b23ae4f8967eef79d3034c61725b768b35b3a87705495657621559acf3c370d21031b2fed20ea2f672cd5db5b10d77ba70a2750b76d54dd54423e6ade679c8fd206ebbbbf1dca356d5132cb1d71a8205f251f6e232b78610f1d29f5b405991f7e0579bc4564504ad44bc0e7ba1af2aa6a45967f5a61d37ab3484a20ab54166c3f54ffb184358794db33dced6c24e96c096a6307ff0122e1133a533c6a0947c16f1ffe0eb9094132d667f3dc78152f86e83f2527422d83b84140973cfc4e2309602610753150178a7a77efe14d50efc335179f23520f6dfa1e5cbda1432429dafb454a7f8048e5f21e03a998ab10831b55004618f74e5e5cc50b2f7fa1111f3bbc21ebc27f6bc65b833e3f93034dc9aee21642217e18d5adb610358f28248e5645416451f512bea09258bd0af1726e54fe23e6389a1b9e560903f1a8f3321d32f925d8237b6eeff92d0212c1cf18c1eef80c36751d3e59f2e55a7b861210079c4130d8dff2730f3e615e5f78a5078d2e47237e80e475578780161196b55d2eb2f5166d8c7b0930c42d08e3d49e41cdd81d5a870eb43ff02897648f121808d3549501e8742f63bf8648473d8a211d74a02c1a983be716e7e5173bc85f585c2b34936266b8e0100a830c1a8ceef045b726e6053c696152b563475e1fdde20012cc71072a3d201f42bbda77747a7a3aa0d6c716bdbbd35e99305a1d25c56d086172fd4cbcd3cd76f3631401a4b16c50d014ef4d2f7f8b6547a1e262996e9c67b8c2504064c0090602577d53f052dd6710012a68b0e35d53548c111568969f3a6946391ca2e0715d02c5f30c109a0514c64f14042fa0a7374057df5ea09415258d63c662242a6459d10e1872324ffd2dcf6aa56d8d6b2409a8b78b2c9b58ea0009cefc58dec4d63accdf171d5b5b6916d665fe63597607768b362935d20d6c65e4721c5542766c27bf7b1224ce35bc17fc586975e230f4292439812e51f14e297af41c0c9a33bc6a9c544d422b345a5c5b9f2e086d43585e68c5184d7ba0712efbe7173bd19606178e8cf02d1576e934e39adc50996c3a3a444d6a2a6163a35682d77408c2bf9e35155b05682bb640424930313eb66f0c5cd1b14d07ffbc832bbbb4aa1d83e6de4e5874b25fa3f6aa5000f5b80814af6a6457558f147c3708297c5c46207903ce6c9b8a85044773f174b93ffe20696a4262dbb2cb40fc6df319c39a4510799efe72d23203052daf883038a21817c4f47b44a135374c55c8014919cdc90073168559cdedb66396846171d697cc28a8ffd1199ec4393fbffb863d94ed171524e81f21858a533684c70d731f14ce55ae53f62f83cf754517bff918c805f746e355fe2156216e5beb34ba2b3442d54a9bd1db410207d42c42bda83dc89de80fc3a4dc5739b63e4927fb3f1aeb630154a71888099581a6629d819f3451a4cc1235500a7296a38d041f8dfb16da9e8d60f413eb39a74a1a067785911d7d06c96646c53d4585ca9d4bbcd3af136f871b38baca2204989daa01f690d977cd971a6c5e6e005e3a06ea1e4abdc575f8f0015872966f59d88cf54b6c00133394cd8f499c22b93fe525bb1e49bff268f66c75169f3e1113470d511d181a9e229096b04db865ea6a9f80a01a1884291d9f4fad22d4b577380dd4442b4c12e83e4859906bb9d7c54dfa36d65878fc8611eb24a418705db2223ba65763784c7539cdf5612cd4e3192ba93c5f261217ae5b5e6f4a23d4d175669c313e62eb8431523ad51914f6629a4db7e15115624b1a75541a0f41d499830f695afc3670797c2d6f43db0339e4a745c0794d34d688ec0f650008021b722b0bb8a0490493c91123b73331669cdc9d5ae5764c526a9dd14f996a310c0148160888134652d87f1f6792347b541338a37515b01c468b322a61e463584b4c84ad0ccd866b7283d1955ec1141e1d8f742d2a896c135f93f14d1af6ebb3586854c7045e33ba19ecbda901f872b44d138278652e5bc8017ba0ee568e193f1665450545cf350c09b76cde650534773d5f20f21df7e8df3a66ad9751fa1c3961b192da6de9567838ed643267050a776b98b1c2370f2c40401edf8565795f417631d6a560ede4af5de2e4c22ed42a86001ae8c5609c792f77cc285508e9f7aa64b4df59720e09ee1fc97ca73001e50306332ef93e9e67326f100d772d26d96545c1a649514d3190490948cd717d94193b562b400f4e9d254195e31e6ffe71a81f10d6c64d3cb00355a613ee7525e2ef51c2d5e52c6c05762f2d16836e313c534841ed7b5527a13854e666234a84d7c83623437b26c49e9b123010e8626b0d09285ccfd0562d8b0a17e80a794e5908b30de8ca29722ff3d36cc11867377c88b01732fe176fe70d583c821ecd1c1872145708ab761e407a943f9b58462e9aeebd6f52121a61ff651a5dbb352175bad3486c7c9f2428fc160f1175d65c032588c34737d2cb086e12700612e89f4253d2744a619c1e131e2fd12a88c0fe57e0ea992a5299ec6960ef1d73f127f2508727fc2a26f7496c505665410add033ef2e387393b6b88660d41633a6f8b9f5c2a36ed20d1e9920d4e6b9d410d1ac763ed08db546de20d4f4dafd00bdc21474169962276e2a5f025eb816c0a06e79d19f92092584957ff4fa70b76390e37dc43dbeb7f3050cf80708f52b52b4ae4de7498c8e95ab8455d2153194920496e406d816db3666b036905ae82d568cb0fea68e090e366767606200557761de32ef476298340127248fa133e67fa74c8e82b444b2e676d70277967b2c27a34619d955539145107e2fca24ca298af4d92359d4786fcc53f266d84645891416409f8432c293a685bbfbefb38295fd219a5c17b226c3b3f1a697f610c426b9149aa12b0503e3e9d3aa67a984bd4dc152bbbf6a610272dd94914a3194513ff6a0a91c9d2627c20e66294270a5e136b2f620c60824aeb57fb5fdd46bc52fea61e559f2e1e5785861e73d5c7595677152457bb17ff3c3617f10d77ede45a7010545b4cdad2000f02350fdbc19931a47597526e671273df2206556e96eb3ce0447d5c042b6d31c445b31fa3d5f11d927165773da30e529021b42e28913a0f5721e46f00cf57656018db3f8f9ba832473b683be37ddf3952dc5a348b2e2e6827f3e5540c8ba95222f4c9608e32cf6ce45e215d6cc1fc264b7f4a24f44f6b11a918231d777c883b79a0ae4e08b5b26d5fff916ae6f4384221f5e05d4efe696fef09863d17db1f499deb8a212a4ac64b7b5c40068676542617a5e900b068ae345d511b34e1e91c6ddbf77133b7d20056e818ac2d56bed4756a547e3976828d05b302a20fd784a01aaaaca40318685715f81b2c5b2fddb62e92b3f770908d8a0efff2a05c8a8e665b4c2fb8017942744f5a8ef3102828bd72653bae258b4d455ea6b52375855dd26e3184381af022810c5e5619539424ee42ac9086297b6dda0c15e2640f3e695b426e06c9332c5de82e8732436a59d1c93517ac8d7187f0f839ec35c35a9a72451005dfa14a2c8ca70fd0f60f0a2897f712bfc99030c857c036575bb83355c06d4f063491086b058e4689d3fe55d45a242254b4a81cddcc09632bb1f00195bc3d6a2875854a5615c32b8a8f812b9b81f93ca79be8675ec1ab0151e9ed0b0d22616f873eb349e726457157a2021c683d8c60bc89ac775bcd645795cfa12c34e24e08fabb1e41ae15f557d26e315cc6677a25e1365e538c3f1d29516efe6714477862eb96222bae7dfd745d40b40e9788870b03c4c42e01eca868274ec429c117715637b6621af1f1b863aaac894752c8d7443d76455e62c53d1e4d610707ae198c46f5b02e190a14e5581a6906496ad3431f6784773f0eb74717f180fb4b0ea138081dda7b288ca4b73062332959fcb5e84a645807088c887612ea5e9265eb64c232af71522faebdc82748c65e2de4623f2611e38154e26bd44a1b9f2d0ebb9e181b941134101818563259ba01319eb445357489c750cb686f0de8e8671319ce2256524250441f24cd0f8ca6b3270eff8d164ebc151901041d67413dc14847dd210471a4952d60693c1c95a7a00bd32148057c7dbe661b2db928236a3822bf03f874066b3e37f6c5eb14364ad43aed2ee83a675b331b7c357d65bb1b950e7d0fbe016fa2190edb88ce00a0e6936433b66309cf3ab85085f2f639d354f23d3a243231cc8d7f1178ba361307b9e671e8072e5aa744d84d43266e0aaef838036addc90241511c1d48c4c644189c60388cbc2438ad0e1a504f8c371ac48bbb45dcb9c32e364b61541e6a191a6084aa0db2de675b48ae881c860ac151c487113c74c6973ed6556f242390660aa94d92410e50d21f540fe66164af8f24e6a82e55cf0b053437b6d7455ab1d053474d776f566f8a555b234d4973234c3597fedb4a640efe079df1cc68f7999e310586f62b624b7649665cfc64f049cd4bb49f376feebb8203c32afc58b719f20435cdd802f35f2a1e2a21e33f73579f173354d0284a57234ab992194fd5963422e808dd34b8536128cb87853c6bf6393a68bf376018c9fa0d9908381e5e5dbc2c225cb36a1d20b2477266e73235dfad1f426ad13000fdfe2521da7b191f7d58307645c024408b254e1d28e3451d258a2fc7f1180ee6f04e55d752f54a8553254a179a7560944e5e5791430f09d41aab3f4ef04a0571c9622846ccf8135d50201422fd80770acbd56e88c77f6af64ac1672729890eb58783277943214b5d9db22721a6df7038fd5959ead4ba0206574b27e84409321d60303535a8cb561fb1d2006e4e4722eb252e5b9c9a571f9b09161c3842e3381a4fbc005fa9da707439da34ef14585fcb1dda2133f69a1208263135e84108342f45cd694b3d0a0394c6912b65e3f344528dd569d9caec3597a7e400de377f3b856486257a9f682270ff535f3b9f72358896ab0cb99c5c03e19d27002a5191035d1c9f3352c01b67751be14ec93ae10ec7af3b494d16cb49f671e631981d5e302929b85cff8e914108ea8633c97cb61ead6c95018a9f84364a18a1242b4b0b661d4fbc304f2be85495e38b1f63f3f7552f80713521b5190d01d42957a3097b0d6f9e6b2cccc13618b2f92b1124d038326e4f1e039b19b95e87689603276ae433f2ad2d4ed91a9f166e44936f836b6e2895deb92e16667e5c3c54ee360199be30632ded4bacc0ac67d4fdea4e1d0e634bc1a0df42c2ffc21665e18f5b2cbd3d1ca95c431e5595eb40bcef5a034cac637783f3ba72f50632648d270c51821431545deca905239aa4083daf443f93e8315aee012f4cb4cc6f431ff1a575402513033f56af39c886765c5bcbba41bbbca6163e76dc3bcd59021a96a22843cdd60c7582de4c5d04f8bc31e0fa0a43a52c67019b7f186966dfec60f3c31e68c6fc91638249e914adabf13d79c7344bc7fe015f1e83504699e9c66d845f314f1b9586193e336d494d7f1b24365be45fa232c64911553865358b351cebf6387374ee5e567d7701339803cc1d40c12745bf93993b7e687b073c3ecc75d7b1e9257cb90530a6de010a87ab370fcc7a9d14a0cf903ef2cbae269fb61033aa38d43b776366289d45b4131e56fc04714e415dcd321135d1dc5f728c650a128a71f901c8c67e018ad4c90e2056f647fe73f8031206c723608b9f1edc471f2dd7e59c39bc12095031b5e002e1a07e2aa097d87189bf163c5fb3f807b7217e30279dd94c45ae5e2d83ffc1509b3dd62bfd4be90858bf0367ff6ac54e302a756d6990914489873a5261051555f88bfe0fbdf410324003181962335433bbac9956017f12408b9816351df78e69628e52479e18d36b1aa7ff60ec45e620fb295c27d9dd8b168864c5002b89780e4509440c6814f73d4aba214afd81f711999c031d55f4963873a2a1154b7af06e5be3de70b4e1ba48bbbd0230c96b1e6c0724b15d9db16c124c0c58734eaea4440d17d91341d9fb67e38dd93dfcd60b64c5ce011b29af92593c3b3e11dc449439ea61be2fe7da400812ceec33d677b43dfb717e01d893f92c27d24d2e3e7cf15a32283a5fd9a489536e999729d9d6aa6a10b7dd0e875b8c203f13ef54b84b9d0150c82b2c3778b744171c72199a76cc2904a37233531cff22cbd3042f69376528c84bc73c15606606c8f18a33d8b45e34da2d47607709104842ee0855bb341a6230922c517230724e38b0a5593e9b8b64aa041467ce80c52eba037463b4a03322c15ac2141d3b92523edf2a22e75a3552a71ade60196ec50474d5b962e2935d6c52ba7f259712680ed0b71d702a7b491eaba88177067a0e6bdb8f5651fa0729321fbc1f6e2b0caa72f9464922cb6e6f6dbf69716ec08ef5418ab1ad0c701b203c364ab44fc4cfa3152fb70744bc4da501d6ea5c5748c2d8144f97b86bfb4ffe41db21654b05502d4473087062d4398c3fce2358306502b872f2c96c5c85b86544460feb334cb9ec06f427af1b0a1a0f20967c7b483d243265df1be600e3870437d19fba15fd3ac06ded22794c27b4da02e125bf15ca463038774c700bedcf0543e2765e5b5035da445d2c5849b02e435b5f679560a22e246aa00abf6da5508b35d4123b1267f8986205955e71e9ade339cba67d077968692a25611400b9a13153a20b5d0c317f3269e494547407d4f82d7b2a1f2ce990a76955b01219b0908966b6babd54edd1ca4a41d9ec22f0776811fc45bf587d7c5e3a05e4f35a7ede42135681b63771b7fc77a9568a6c0a6a1a4261853e1c6b01044bcb10f236558aaa5342ab4b5d02f8d3627a18927468124212d9c67567c5d34270dab36e26c78a0d5866ae9f6fb862a436be12cd4a924c65197abe70039730506457e7e82f441abc07040fb718438a35035f012e57b24a1f0a9d0bc635590b466eea285c63facc5d0fa9b69a4664fc5d3b43635e1c3381d7416888c006e8070b6a2ab9a715d974855c8403bf6f402df922767c8d0117aeba26b37dd91bc6d61c664dd1c040fc067c122a1de6330d570c730db6c02c5db3306c2b6ac4588c9a9c20623bde5828fb00067bb36a505641b458190fa65b69800a0b99235b454d88e74e6b038e436e4d530659bc0e248760ee4e2b173c47b13bf2247722276f0b946e4300aae66a098d572a4785e5776d37635b7014fe66b892a5200258d108c36af4300da49a191c35051161ab093b0f337c0e51f4006d5413c70e7e08427233d5e83a881f8a4be598e94c09fd8b0d6108403b98408714406e262f04ab53062f73731adbd53e4178e91630b8a0ca1bd6c7137264021376a6f9906128aa580f216aa2239e959f47498be41e68939543b3d5752d61ea27271a67f24d2d28b4071bc628116760c618c4043528eace7a6f2d78e863ad8f1e0301f70e591d330c33fb26e54494cc12201efc253d2fd0500ecb39d43bfb06334e76a4f72a910715054661485e35d9672538f3414167c44047615e0b1bc18cdf37102b0b3258e3285edb36675cb3448f5154b93d3a69959f2d8eb9222f47557e0e4f0a586d09086452fc3a18628a17476795f2d272f0ddf15352271e3be1791d033c3d09282af2a30bb9ccb961caf49235ae719174c2a81d38c5f50e2540964d2158d05374f63ffc29f8727f364ae8335d06e3d06d20adb12c4b03110680960c10c1b46a3e100aa0260d16a47686d8fa6b61106b1146643f246590b44cd0c2aa344988ed2aee3ef80018d58e0dc3d0fd5bb63285488bd318723eab8759d398905efeda7257b4da3c381ab1d806692fdb6d992cb92f8bdbfd70d4cdb4082e597366f1ad6d15f3160f02e9372e2ae01b1b74f85d9376f80ad83959ea3a2cca98d21728e5223e44317b0736529e33d142325d79a7836933f1655bdaf10f247d2bce30f68ea5442148cf48014f4027c1fa8630b2b8a2025020e83e9f3d0f1546e22b327ba0d763453cb9527439cc2628880726fe2dac03ff4e7519574064299c5bd14622e79e2664a19043fcdb282e99a5b9688ec3ce189465e872bfa42e7773d08c1e49539e4bcf73534611eee53f199a6b073a83201575a5f81ae532c82e36358731a7a49970dbcae668817bb443a493d72c0d452f2179360f39c59a6c229b3cc60223a01d532b127a7101c16625669ed11a05a0aa6e9ef7321f844b2108ef002a4ea29ba0014f12f84f864a8b773f443b5e2aa38432b5c5b535b58cba32b917b00fea156228632209128f5476213354a0478fbb20191196f83b8a3b1a6fcb8c8a501b1d662199be664f66461a0175c1a31c3466e1233b5996720813c85344798c1bf5489d23cc4c3845c3979f039809662d2fcd7d48d172dc15d1fcc469882c6a44368d565d5bb03f7400c5024d48a5d307a3c24f73b6c1a253aa19ae77dde3fe0146bba26693fc370fe0d2db6206f97c5374925843d188f73eaacc6b5b0cf1760df0bcfb566bd94039fdfdf03c25f06e6e848969424f28176af79a0b298c5d932bb84a094afbe8b773b845165decc4230df3feb87317d09447f37a8a52832d4832cedf9e181ce9c63c32768e386578e97246deaa5283507170f458440a9d78653f93d79b478c513f0359233746fc57e57026972114aae54128b89c49430b0dfa12a8c87f11a5c756488e9dcd44322b2024ef92c751ca3db94bad6e4d5979d7f4235ffcf353a2aaf60e0efc544cd3d71c39d3a07f347ba6f41f7558fb40e234321b85e97d1b9638570db218c3230428072f9505843637bce35aa9e40002a7ea4e124aa14242ea00dc4674850c17dff6df2d6da65a341020434d1f4783328562e163f8dcee277fca4220f3ffe046c938a44c67c95b4fbe5b5d0a934e4b58f7e5971d6662262c8a66eb057c1a0a64314144165e88fd50f66aca355dd65330c78bd92a2fa79d550c23220bce70af06d9e107529c32c6412066b46e4152d718b1f0266e7ac8f56fece35f44846d091bdc68110106759f4b3ea157634a75d758b789ba42b60eee2a6128b153221b35002440d03ea08e0316de52e66b09c1e40325980e68e8a2f7222ca37f47c961b7167a3a55744a0487556357443bfa5032570b2ae94e8af32419380bf7519a747256ba883d1e4b304622026cdc0aa1e72266fab5324ee1407a08b8a96b76f95a252efcc57e50b9663245bd22b506d5e9854ad3738b4f73c2c9592509f309f1336647eecb2b63da0d61540a5a4532687aab4520316c6c13b2cd3ad8cef94bda2c52750ceda829cdee7b5a3b7d6657991178362a687372d3d2892f71ddb639d6ebcc4dc4185b6336a9b56dbb88aa58fad16d487636bf31de836a5e321ffb3f20cc2f306091166aede6025b0625852b92050e4f7392bc5a69e4362dc24e18401a78472b1acb156e155b4e0d66d0632324ee943f5a5db6036891274af57a7f29536ab450f9dd753f1961bc0ee6c6ba683a36b72f8cd86648c6d86a6b24e4c5750f4b3b3c6e497e0d9161882b7ae0d171090dcc2f3015201ba3afc468d8cac919eff2631a3204a814749b5760779fb1464759f61fe0a9d336e77d8b39c360dd279097ab11a87c8f6395eaf8002c15630e298f7c4092b2f12c8909622184d70656ccb08d3a0afb5a52a269c918a3473a5b3641a800f19ec041b504750fa3c329131207dc2cb1b2f70fbc40fe43b583e3508139e95bc1265103c3d36d12d3560302d30df04e428c83576c7953039073492813f6675ede11bd154d57d3196c121f08e07104547c0a301f63c7ea4a232d0b4dac14a63bbec3af636311e4306eb4e501c6e85c6564122b59ed12711f0297e72ba7c47f51ee25126e850c263d7188081e8b8502517350605d5185323ee7965a40376d37664c69d92d4faa3c089ce56a274c692d0cc6069f0b8f619461d1568007acae92118eafe6086477153a11a7fe0aa6bcae164e6d955ac246e168c3eb2a2a15412254fbd83458f2723f08fae71c44519fc92bc5a4e5186c55e45080c07c70e68c4f2ed91a3606915db40dfbeaa45ffd4b2c4b715b6f4eda694410a68f2d6fb92b336665bf3e5267565a636a4ed214f281e644e7ff554fdd6ce51d96ec3b62b8e9f152e7507529e3d2853371a6db14dbd7e24746ef8d1c92684d3a08241536be845b210225a677b598b664585ce60592a20010d1b47474534c005b5a026d2a3cccb51d763c223d1cc7386b3fceb553c484a8587d6502507a97b5077442b55dfe0b203e27fc945e3e5ae43175b0b964a74e13085a86974c28f98c4a87231b3fa99c0507b36b7623a786c25de6b6b20142bcbf3e759ba31fd7d85a6f97334749456ea941d818e62c1227654f7d0faf2dc5790a3b4f50980e0b46ef01afbcdb1a97588967dc87f71c47a3003815191a6b46c8784ba7be763eb30c5568df372c6ddba4513e898cec4f2c535b76f0e87a4e653aa8324fcec90a011b84390d4e003f0067c4355d71bf699fcb1870ba409667be8fac342be852331937c6096771d8577baea8736ae1fc438528c1137a503e24d7d5a71ec510594f54228b766f3b0b495eaa872941f54c2e9adffe637c7bd41e8a9678205efb5a1148f0365a3de3f8763df28231e544b7095e9a36414ffe5d279407cb012afee660f7573f5377bb723b06a74367aca10c6c06d2fd5c18ee0c5a6ae6b123ac49153900c36774c06d01427d341a1da5290e5a4aa909397dae250b098c7c03dc86c04a17789167d9388d149c4ca60be31b26372db6c9768f631723c4e3b953a7e6af5a69befe345f1d0e2dab165e495ffb4f5653ba8b65957c4f6067239152c0aa28252a151526c882655570469857ae4fc41f3af97d232c45dd25c759dd371d6d40454c400612e5a8de6f79f39753da11155c2e09084c4a459463a5658e2f30757f1754f1851b302ef00078f59e71dfab3113859ba5640bfb19020118774d7ac92e4f10c9061fd89dcb4d2d935257288b416ea7e11507b930606e604bd5252d8a7f6622ecf6452ff014397823912035e5d402f7461303fca805766e8db903c94596101436913b7ea5882e06089f03bd8ae751b4d32d50bf0e57758e43a7702fd7e9305e746b5c0c7c4b5fcd786c747334bc4312bfc15227acb22f7c5a942a2368270e0d613313edfc1f600b55f55366ead40f27bb8633850ac22cb7acd20b941a4f1e873dcf54759f1e60dbdaf607ed3d1f0ce0f20d5fe5f2d21ca3d2423ba05e400f1355182da14b370405686234b77b3113b671073fea3aa90e9473a8185673481f325ba81e89f7e3250cdda8635f45fe564c26356b9fb9d46013c5b84ea100dc1e90076732f9dce9229d5b3807a50b5a233564e82193c47f65164672612fd3971557f8211d6432af0cd920682cb7030537d4c3ce6101caf40b87198f4ddf583467dde076425277ca39e9a2054c4262911c7da34a55bfa11d6494bcec4a95fe312dddefa232b65108081428c8251c88c41e2204c9131a003a5fa95ed2627e14e154b1b17524c303982b33855227028d22233bfe4345407add16bcec8e2bf5daa80a8d30a759b43fc46fa35f9135bc25540742cc8119536986176e58ff0e80421b2f40955858feb0396139f06d0d0d39bf257b78b66743be4911ccc6873d4b1596660665be2c95db3b3ae7cea93f6858ee59dbce274c8ee39805943c59452ed221347c21722e63f5dc56c191e924664377262b17f0266d8f9655d941f722e676d22d77e6a175ae5bf67485ac524905014d3bbbc06e4e3dbacf1878f3762396038d446b0ef04d33333f483abd2a307375f7366dfdb41f26d88b268b7ee31a15db9a4e2e8c4344af681d4152cabf6010ba507710f5f547d569a90194483472372537394d56fc511a07ef5396ccdb67f3ab9c1552cace71a3f46f1deb71383a0bdc1570e1e9a255d74ebe0b230c5d2ef9927b2cc363090da36fc809b4376a500174c43fc236f21228699428190781716c188d54cf4f2573d45093623cacb312e504815f12ed99018fd0e66de97a97491da08a194f7c202ac8b7fe000f7bc63c7768e36fdfe2594cd2ba832df577056a7a449258f859665df445f96d569d5e3d2550f412053da363c078475be1710f633ef1465ddb26831bd795141467a7b25993808a7576ad48073a7da42f7fadd429acafe1395c56a563188dd0164ed30538b674e317d03a682b958ac9407281827202dc743930628c47e13243342f6657484625621587107d43b3c9541a42dc706257f8534a58e3cd0c6896490801c5b329cff3e04401609f2f08622f0b7a4ac465377d452128124f14722ec362fd96cd0d196090708ea3fc1a37aaf715ac1ee976fc60df3513c15b4501f04c3313b65c31b4b20520d1d2883751a76243292da573e9764672e81c7b3bf80b53488508996e1b41376cf0dd0445632a6f501449611f2515e415ee91f45744f814260f5afe5a040b6f3e69a4062b2dd286681d06e5147c8cda2b5d74ac2d21167e1bf94b1751b61a8c65c9b394072799c225c6d16560830cd722e424d4450ca8ee3720bab76e2d5ebb50214a9e700a7906002b8537326fe1bf072db8aa364140dd167496126b4015f72ccb754539f3c8762cbd430a19c8b70a26e227a936b571905cdfed9b1ccf57ac03f3f7cb0841ca621cf0e48866d2c93f47c686c13679ba4e2e2aa2bb5f27e9c97778ccdd6d09b54b0e318a742fc1404765112d9402aae07c014fcc0c6b1207ed44af7dd8717282eb4a1f3bed3a6856fd2249f8a4510373c9458edeed044b3caf5dae16be124b9e9f5ba004ea073401c437a2dd1035628332314d639c584b42896f09f925779982d36a954b64618cc542374b93992c6a61d575ec153b75be20a775b13243082fd60261ab8f352591c05719e16448069cf0993b7df4fc3cab32aa482b8d474edd5d0567557096766f7e841d2fd8ed25b9942c241b7c2f3a63f2f96b47e1773a4d6c4b28b20c3a0a2712463d6569350a87233a309b6ee232ae8360581fb6f5438fdca733319edd19521fac2fb89066482011385b7d002d6ed6c3260c7df878451162a231b56680189c8009421829ca61466d351a0824933f89538d3d4142b101fb23f14c59c42374c18838162e144705e32ee701e23ba838635dac621ff6b55c9d032a56594474776c025d76455c98360471c446cc390277638334567a40ef5dab46933fed57b13016a7a40aadbc8d305e75fb1f38940e3251e7a55b9e1c5027a342f20a2e97f35bead6b970683bf0773f2a75476542d0486c46fe70328ef04c6f45ab24ee283a1d5b5322475baa7b4e12ef744b2e971511f5a05e6f32b94219050067742d14b411c436e471624b7c6214788f02ed4f233e3d6c1f248c83d70cc9b9a668ccfd3a374979894e26140d43609e22239d5e4506f576416daa3c603f43188f41aca254438b66281d3e88ef6147fdd92e93d9c938e57cd6641bed5b197f8dc02a2e17ee5c2ae0c40a02bc646050949b5339105e150ec51117c93e941ba2b52a0dfb8d8b63d3da3c3a47c2fa7081bc37700f34886a40a1671030124d0c4d6d465072d4be143147f356d1df963cb6039c46eb2c5455f280d7600bb0f1544a813d72084e2469cbb8915c57bda8519a2bf66de42ef3244644445c1afe953188d9a212a21b174482183e018b7784260f79337425875c5cf07fc577f814154ec6e5ee5b79363e2fa9759c2a7a115d014bf63f6ec2f8296aca29f66d09b2b06b7db1eb6f1690dd4f85ea6f6c8b95c1067d29495630f2742683d3020c29df6401b228f93a0bf1eb607a210a2d237c865e58132f316f1886141fd95e3f5d00946bde9c375fe054151ec10d020519d80f530c157d00edf68d164e7f6e501da483189413d84189a8e91601c5bf22f50d625b33ed87727d8ea71bb1b23626ded93f3c37dc961c413e8231c24e5e03368980485a76263ff76b4d1e38e8f00786c6f225e208f62653cf372f58399609e75533607de0f240e36874047c7f7233530b493d6b478e0b308cda532684e761ea0bd53c6b969458bfa8bb636328074417773106a77d3d05dca70a2759ce22094e7e3529bee3fb1ae3c55a60aa46e4101aff5c398741636d2165ec3845ac931b6de06a57575aca4b6c61fb30297ab01fa8f5761fee97eb5962e1ad2376f9873dfc26e13dd5aa18497135ea06414d2f48d946422596c0003b4762ee1f9a3e6f58a2eb667219b5145f76add2570a1bbf0dd8ae04640811cc4bb531511187f2fe366366551a168b902b554d90207075c86458af1d615c5d0055db1e254a8dae1a572f7a995a2fbc532228b7e62596203971ae230d3c1d161a67e678dc144cdfab2c1fdba14314f70f6d13fbc6333ed9aa77cdcaea37a5c7ec416cbe5b77c1373053029f4772d8366b6d840eec6ebe6b6046d7024c237564af3bf97db4055155b71a02e5eb07192f0b1666b21c54ca1ed275f59dad053d492d53bacca81baefc37653760284600b31f0e08505f11c9727b38ba1e27236d5330250e21185a0c92e128d7ac5f62cca39424b8e8461ac1232a1caa02957243e41f3c5ad8cd1143a0cc1554362525b0299a3677da6a6e03adc14b4943201eab47f915b0b47f6c3a39e036c74c0f3ad818f0400d332954285bff2a80c92423d7fb7145e9cfa832ef38f531f525344b33a6e8289148b6673a897167baf67864a0791e1b2374c13392a922351a49ce3abf01c141fe581c6491b28352b02870174b1912680db243376dd9dd31a612e7306314e52e7b719b45fc068b263056a706ff49b13967ea6576e18485353bbacc6cc17e2c6bbfd5a9281d835e58c67716753adfb952f4a2f7755b888b0cb02b983ee9e9d21bbe3b184eb86fbf65f3824f1c47d849626e28e12dada49b68477b5258ca46d930c7cecf59dc7cb16f67fcd068a56ad30caa4c89289a39fc76fe3c1429c831e17465b075066de06c22650718393bbabe3de3b5c71ccd1fd96a70bf2f4327cc5529711ab16155a87e375a09be6a65ced47093af2656c4a9c53662f5dd7545b17a3d5c78b011efff92562a9c671e39f4bb45ef39c64a920dc660a91735691cfb0e6d22de7e4be97be5182f600444f34bfb27ead4fa026fd7d36a148a8d4ff2305636b6e6050041e90d08dc4450276004a66d470f466202ed5e21109b9a20e88e821e85518e5c403e2a228896470e3168493f0a34c945041eda5b362d0607b6251a634600de49d4e90a26da5e66066f891f52c12b4e1242948174dfb327714a52b254e4a6dd316fbbcd68b6cf3122c479580740bea12618d1c71fd46e8d74662c20726ff25e6256363e68442d9e37c794eb6eae20146a4c2a3e63f7063569ca59fe10ccc4f73bbf395d3f4b04336026586f1e5ad40e63f7d24e54886c6d5c884b44233a079b1280e19321d32a425d6426691ccba7251d4ca4c7198021ee71c19d2673d3c10954b94aaf17b60ac423b021185dac6f68266749d03e138c2a569f98af452278f00337424d0434f8de0fd310ad4ee71d0f75cc000e28cbe87218d1fda34db34fd10b0f79ae26c000a166cccffa02f91bae2386984745fc3b86181f121b36609106406f420970027e9f1ba95339598872e74f01bd3d46416aad0b7041a1194df67249f7153b36b2cb425b05fea920867ace4e5c072f16f91703322ec48b614411cb025b8d8726fdf80d378efd0d6af68a7b66b9c0c26705c42450843d5720ac56d139fa926573a1053171b048da32802d694dfd824155f7a7093ace5e8e279bb7453fab488f54827de80cbc973549a4a7765ed5ad1540cb2977143a64e506288ca92e7df4a44d3c344f25e451773b91693177215c071d46d83415738487061a9ee211e425444259701b37ea4b092a146b24328b17430e6935bc72abdbef4c9bd01e03192bcc0ff5fc6a1e3436d743fbe44e6ed4dc386bce6b595768fdbb428c523c678b6e350e7bedf419b94d7a51e1ffda28d8d5ea49d1510318660e396b53a2954ce109d157ad41b658e75a262059b6ee505a6b6314461dbd1db5b00c5a66d5f7442fb0832679f0691bc395181115e9f945b0f522682e03f566976c5e4e82cdaa3e50f4de6478accc766b486e2ab599037612de852322b3a0234825be711259245e09b3c65ba8736d1df7f5404c11dc5e6247198d684c401c54c39f35352f18b0189011883b6e93f82981ae0d0440391b045619316dd9937138eb260a1a5146c031bb23185c251ce404bc0f5276a700c76378b3a661aa3c9a50876b636c65e41b1100c3a840d5a4c25785b5d616a2481a0d1e89910a26b5382743276252e590e83a9b3009254da9bd2864c4921e04d43f0049164a12fc4e47580577065907326f45170529657e424d62c5eb9f123609a3081fec5a63a33a3040ce11ef6e067fbe0cdd2a007685256825ea7e244698e5520d91f5bb330e9f1747229dd11f5e63da239b91a5603a756d292401735150e36a482dba2c27f45af56567102347c766dc3ae56a621fead3a95c6779cb52ef2d7237f6585c716a18db564644aa0c9bfd131a8429ec32d5784f3aa7e25c00376cc1221b027f0148127a665876fa271cbf9717a9f5cf167a10a01c3d7508609ff5910f8e853c37fb6e38592cd9003530d32754ebbe372304f20e731bf7da4e3cb3471ed64e3c059c534e50aad150593799f31c20b46859bada662c3670c247bd5655126b0a7630e75f0959ac1eeb66869661063b22a50be96a466ca62f165e64d1525cb14bfb025baa9f0e6148d8001c07410633fb425d2339f051dc9a1a531f6ddc767fa94c4912bb8a6fd820a05d1e9d150775251431b519d619414fe8572c347a5249256a2ed2bf195244558f24a8eb19471b17d50f65c85f566c0046346690e216ca52da00eb71d707d01c364c51467e2d5c978c637f0f210b408bf2599c865272763f4a278c36273cd1ef7b301588977578a84b00da43a3741231fa02365c762c41cf8d55195a153bc2396740cd4139037c56444216220763b197fc6430c07c748b06564ae8f9001489edb92ac1c3a821fd087d525cbf900266865f117ecf8c4c9b16715992e18f5a453d313127868a24adf6141cc8ee2b0eb3e35b5d9971d448264cb46788f0ed1f4978386896d5454a77d17f7406b90d1aaa409608156219012ea4ba3e88dd495f32215e32dc76934e6cf3992f29677c12bf432c52adb62b5175eb8c754cac443c7dd18461c8766d502ce9d019b27f8b3ad7781f0f9805982a562f750eaa26383315df1f0d995d8809fe40b651146fea2830ba9e0929513c04a40825028dc4e276c710e5473af31821c77b2d394aa96c38548de3006cca401e3500405c3c616a672eb6245d000fe754b2495c4346cbdc6d74d8870de2a5da3efc40d83c35910f04e89fac0095588b6cef3d6132bd50e759b04b1b63173fa70f76149262670e3f40670d2a34a82b0623ebf177517f3e992d623aa73923ee870ee9601b1d0856f2758e6d00725b3715042aeb1640e3fd2e4f22156232308c89689359cd239bae582e1f746d30174a363519a9822c9edf133d86a39817b550e55871f6aa6d82b49e0649227665e0eee4025d148259fdaec60ebfb73528eb46f24745ef174cb7e6900270cd0628a699ef37d09d2f0fab56a956f99b7d073595f5582396716cbc3aae3d5b42a31202060f02bcc81f735526ae451da2c35ee815a64ad852b5358ce4c9550d332d3dfb69c51c25eb673d10862411dfe26518c1031b0ab7d0a3251715b521a51fd36995cfb54e493eef6a9d15c7767ea68e0b6bc58605e8db2a489a4079148f277f65f54cb4246c3c4c056fa9143a3dc9c4393ee6ad0198b10553b33c4d1eeab2db0b91d1cd53173dbf0f0c92fe5c82458510b2dcab3758aa9e4335da0f643d460a2698f8bd5cb9b134770316b510a6173403c916ca6e23363c49375b036ba94d6146d28bf10585ffe60a2f90ed38faf6c0765051a80e48ed551d94b4540afbb4e7547b918506aa14090b849d1450f177d70cf89cba130736b84544bfe250e89089109afad83998f4be4e5578396c49e5471d630a403390026026fb08360dd497b74c55b6e261da2aa40163a1f6231eef2612e172ad3eccb6984bd3624a03e4716f4c40f6f02e984025464b156c23e4228a3aa3dc7f720d8413307eeae61e17cfde7005e91649ea534507bb21742a48e56c13ef00c04467a5b92a315e2c46aeacf20d6875c15237d00c56488c6f2aba009b188187061c151725628ec0735b2e20d817b5cc654d4edac463a034c558364d3b231439f52ec5743f27f9fddc093dcd92304301fc08935fc0536f1f375996c04e18c3daec4502e19223a661d60b1012b1002cc4e61fdcddf86c043eb269a4861700aa645240a3a36a1b1185943ca1c873135b93a340cb1033067d8c5a3b5f95845eb6b3684306c1f44cb333f26ce83abd364e55880fa0b8ca3e5611b0217624d46ef34c80249916841522da84117021f65e9ec7cc0a12ea3b0b432eba6ce8e1d060a610395bae19c65a6dbea960f0d3b813a8e54a391d26a464d77c714a6eacd4738a0c3275695bce18a80afc4fd96bc9659bdc133e3cf9c92d23b600359c4e6602abb8973fb9b940350ec8e375f3ce256893a7cd74c82a122ffec3ed514b593770566fa8739b03865386a535418abb9a6758259c45a136e25034a57b564300851c5bd7ba3a3a762c2d7fcb38305642f167c4c8ea53583405239a4c973dbed04252a2a549284bf80036feb5d1678943fa303be580172f21ba66657a7e6d42ac2d10db500735e5c7e926d5f11415eafda40f3b0edf456bfbf23971078363debf6764e1b4d81036638d61ac3db268aaca8a49c803af17d9294b70b296445a4a7ae13d98d3005099361c36f252e74b61310e13c277a645cbb5e443d53ad91705b8ce411edc194af1de3054786a091ed13f6b272572b751d2d8663d41d2a61c8711302ca802f7185dc0ef1de29dd04d2931fb3f7f6aad382e4798253056cf38beb14c1861332e37b459e42873612c4b07f5e17440042008c03c0a26a8e44e4c1df7fb2f83573a640660f123dd67bf400e510d2ffb87a556aa35c76b30948b476d009f403346944bf422b8725446842a64651d75da5e4900381abe433ecfbb72fb864e3cb564d2148c90254b23994468afbe2451c2a4da4aad481324dd126c121b1e5f4478c70c091750255aafb22305c30e2635dd44260b3ab2073a8524375e712e494f0f6aba1c48119f060685a737b0c78a674b9fca2ca8891363d6ddce6adc36402113472f50fabe245ba0f2a6466b71006a0f35eb3366592571564519679a99c250de8d9162e38b9e2c4f85c6226b37ef74e682402c3e287762128103444b2b840506079c074f554a60a29f5d063f27b574bb51df2135dcaf63efda242826c08d1d3f36394cda858032d1fa310a0fba615cd93c3c3bd72b1458639b5c5fe2407728df343d0f7b03cc43e77ae4472b96f55aa41e714d0ee50c2eca35f35b53d9a92ecb51745f6aecf4617579a55a4b9ec4637a48af4c8588fd2b8ce7d05f59e59114bec97c1ed238f335ab13e20d06eb836828e031181197671fa8f87d517777a5444227c90476bbf23f3e30d9570f599650263cca21ee44ef4d39fc3730179b965ed247e421f9dffc25a8ff0b3c322cbf2d1796c749497a106e907c8f40af4d805276377a6a2b3498424b9a591d0e7ffd5d6937bc661adc705502b3163b8cece9067f55de63df2ad529a838a41d0e4b4702cbcd9c32da25010cf05361520a45d02a1a4e2013c931613b551f193a3bcaf648fd1ee30e57b0fa3dfb5721063460bb48eaec4c10f52ddf5e7df5a13603c44a64f7dd20508dedd6180c246a5bbd947f0a9d85c5189ace9e1440ecbb619b351d023e6fd941947bb5575e51942c60602d4dae4ec75c293ae768dbd9f708a0a6161cf7938261ae1e7963af8bfd6443578716caa6c72df363880b04009a3040e2ed2a9211c036b598ef1a18b1af1800981c2f6199690193752553b46c006fe03ff13f2b1a5b299d37704c58589912d1ab02218d4e002a5b420154d608c6258a6ac274e4b07a425546d13f0288c753db86ea4f2ae48f045f14183cd9bd5118b944104b56b3b16f588a2439b50c4127405c934675872924f3635469b046976272f2fa45351c086c9aec675d2688fb37fabd2d72728cb72ac1894347475f5b72c7bc915f13d96759d627ac3f40741917cf31e96a951b926fc052d46d30227d6378bb5b2b6cf3d015a10f5d2ad50b726d47cfb700421267459712070d6760353dd650321045a743027b70951768522528c831193b128ea0487e74042a501ab26b96d4af009e1f8d6af245f615abbc2514bacd6566c2783543fb7d7b6cd36ea55108f6ff1a08d43270d6f7f470f987a50595bd2f3ad14a89675be017082b0f4a3e5da34b76f4ee1d24558ed50313b3f33589927a75069bae240dee7e4311081f7032f4680c91f2ac139abe571c36e6395e7192655310f2286690cb6d3e252961095898c85eb3b70b2d17419716e99c014565587f2b9661ab3b8cafe259a33cc04fa658c61c1dfc983af5bf9f5dd1be7d56526f72392af716718d8ee365d677a361c615591609416c67d2b9cf072d3d360ec7933c3ee426c7014d714a498d57d72f9b1b8b0fe45a7c566bd4d25d5e9cf02109faec574c31671b83aea92d3b3bd65fe7c7c2520c3bdb2be37a604fd6d4d83824c5f31b32a3e71f19558637e488ec599ea812662e0775121a0e4522341ae002c11a8e66f5a50515f1509002e85fd246f63f776ce528ae18bd46c932800a77093f4c7c72b483c83cf1561c5c07f2584298086e4a6a2ba56c0dea643d607ae903ec43763ba891a15805d82027342fc1332159d376c7f935099da08247ceffb31b49b9d53e35454f154aa9be04473b36270b15ad0352837c2b71c51c050d495d1e2423e55b83732507e833ec2a3997c1494a7e874d5d218e3b003d20065fbf4e2e22c9b22dcbe20e2f15e3bf2b100f45307281655d706b722e3690ba2553dea25dd7e6d834ce531435bd5eb80fbab1c46c0e4c95360c3d7e6684c4885088aff7033d505627769dbd3e43d11803c35bf92ded0c882b648eef6ad44e6802ee4da46451a693348276776540cdea01ac0cb06b0a038a590f304f5bc72d2a346fcc4b0853bf073a5467d7139b2e2764450fdc24502bbe60d6d521072450fd0df992ab2114d7cc26732fc31a83c9855530d2d81e1fc58f5afa91692602078a1bfacf7532e10e1f64a74638008447d2404d83a0171ac65e24f1df8636dce3a803db35d84a0d302e57d7a4cf44ed1b5108a6f0bd13398619170c9bd56de6657e625e3edc3600e304329f6869175a6b3d21f838bd281043d96821e1ff04e8ea890b17b5240e2bdd3073123eaf64984c5b6bb686404864cbf01da6c3e32ec08e175cad19156467320a4c73b55b1a3e0a28467b7ff004bd21b3530fe35931cc665c5ea78ffb4caf049e31699ca61206d25d59adefd936e505be68a6561872046c4b4c64d0734f8bee5e2e8cac8f1613a4cb605740e030cc93f42b0f73da248062991607822c4af02be8235a001c006f317b6fb31d221b505bfb21b817aa086fb1e652fa57292b8d1a9916be34c52ff4b15b65fae77e324a3cbf152427033356fb6202154ba537234d885add4e3731424f971608a34569b0c92e3c6d11123033afa200fcec853e3c906f0450b6f42baf7f8a5b580a5c579920d55c2186260ac18f530b2f9ef571c4bee1751cb08034cdeb552ea3314158c66c7c579408914dd7c39201d8721338c2707e48a247fd67d3c74e50d7c7902e8f73d26bcf1780626b3e286a69821277a480bd3723d8bc3b0ab75c0d47705855bdd73e400a6a3b60bbd8a36dfd5092211cbc983af12d3b7201d0ad7287ef9208a0314701f9b1d93ba88bcf656622a73e305ab44652bfd83b1d959737c1726f44aa6bb73aab879a600e5b3149ee0f22346abbab03da3eae316693676885d64320860e38611008a94eaa88d1689f6956325f1600640be47d43ba43e853aa8318779ee84c68bd1a0921c383cb340ca449118dc9442a7281b91d457069249a105754b7cf5e77743cd12adb2bd208b0961c0059fb584613ec466c4a58bf4114bf8f15022e5a473c9186039895053aa6b856037863083175a421573faa1f4192e120000aab4c1c82fe99060d3aca2197a4a804a830590c95061243c3191019b9b1062cf5e81c041700e61c3702fb1daa5ad80836159930ab9515181b12354a49688e24957c8a4cd881533868c421080d7b130889faee023ca2b973bad0806101d1195a57d271388372a33209931346a9ac177285290d1f45e0e254dabbdd2188227305999e5f007c4c203f97bbbe110e894c1e8a8b166dd942d57124a99b5017cf102efbad4f5b7c27bc235b33f7427d215376c3ba2e051865ae2db9c440216c37d8554bd1ce6ca24e895b54de31749f93440f278ee05d31e332561762d3313e58065712c58d2b88031963570a97124b6eda6b90035f762f2da4668bb95e35dc034f75eec9b429792f155bfe4c9b3023ee1550ba0c8f402a70c421804e3a46ce0174656134e70015a11461b451ce5cc71dec425dbe2d396c7158620c9a7e68e77bda4ae6702a6ecf2de86908447d0d82a2ec6910967757e4e0662445bdb75536e1ec6e038bf74a57e33657edc8eb1d12237e0ff9a8c41865bede0836aadc4367ebc26f585c85638bab957734e8665726f8432dc310f94fbb79835e4047174bdc43283e02b0260fe250874a7714843cd1dcb73a7a4a010e9f03526bacbd50037ad31e64aabcaf23f4324c1778751c63fc035f60b60aa023c129e129d49c0b004df7f1529e0ee375f1aa654983e9f07716316a740528f2038e30f36a98a2fe4b4e604e3bf1413f056185b500b605b04ac01a6d4071d29049b4dca560ce117422f7f368186728de4b6fa61037272308439c817e2855fb1a7183da0d49953f0f52671d3213395a476e5caee862a62092559de6c83b1daf691f8dbf880d5014aa169509f466d587a21d450f0e3ffc337c1374ef653e8dc0db15318ecd4ff92c224d9e59f9438cf25d085b649d5dd3fbdb60ede66737777906681ff8613cf18c426783da495a8be7f338fc7adf20371cab52050ddb1ab185ce0d9eaa633c3f05ac6615c7600fe5df68735bcb925e36fcfd2c7b620c328dc31b6b1124ac0903cfc642ada524336d528965c0978c2e7d4b997631a3ee1aba1ace009b11c2219389d74dd57f4a50f2ced855ef525474c8994b6443a2485eaf121e60f07b585681d4704a74a46f135379d93f36492208166bb62e4119de7666364231c2677623a8bf215634e001140e274b51d452a75c90a62b6e2010476d4a5fd455ac9f522648cd245ab774b37225a4ae716dc1100386c772347e2d9c4cc7109236f8ef4c0c46b09c396363be198c4dbc2866aaca48337cdd651d44054c84b12818b8d9e157d807894e40b0a815824090573f1db76120bf5349b26c324a406b1d6290887242319f1e0c0d8ab049e94fe564c0ab39345a0fea20e1836614e106b7016f1d6e72049e182956af4c315488720fd0cb065f230dea3e44145e4e39378a59d081f07496a88115ffeec01a19b9966018b3783b0a812d00908072415280060f3503de074b9de11c15351b624ca2e569841e9f326252590c63784b1fd812cb15c4317a1e41ffc90484183d24a05bbb68f4f5224f9a9e9b644f7c3c1c6b17b274be14fa38286f7a6c2417d747e351690e7060df701c1b92180a06b7556835576e3677120a16eeea383f444047df9da33561849f73a9045b6d6c8a4648085189113f87682b52d30906b9457e1a24656e2fbb387e3bb8a3c832d30e3c4a8702fa6559a2f01bec397a6c78f141033f6716005c24da6f2067962198338a4d788f5a218bcfdd616bb392160b6c141702980a56f319155369b4c36e78e970215a62c33001811d6a9b583741b2c2b53429b4822b0524820d867c1b565505984dedb77569a83557459caf4a39b969557434e2935310cfe86c21932358d4bbed5f9860cf0f41567b6795366e383008f72945f9ac31f2b392214ee1470a4385c53678f8972f146e69335367b223cfe5a847db57102aff31bf4086da60542cc5ac5b337efb3f3209a6027caec13e71f939720241fe461145774fe69bbd41d6631b5f8105b841ac5f153b431bab61e2f3e0322419fb69770ab60b0ad7c50847a95b098285d5177db61a0380cae31c3e40fb25f9342028976c55604a2af3255ec2e85ac2e9253654ae5e30fa1ddf3b0bc9a9191fafac39f0e15c1204d0a464c773732a6d236e3f2c52936660528f2cf3a79c23788faa39f46ce82d6aa22b48430e4a20caaae45f4d7db3343ab45e163a44ce0d53ac163f5990fe49b8df503fd5be37561a61bd32b8bac95fe6eaf1584825e017a5b40f132bf20d2f28b59b30e3c3c24b126c6f0d94bdc33e8d0e376ceebfeb62a37b5039c76a336c15626776d28f7d1c98f576307b14ef33e6fcc20a9739d64ac659693ebbcdb26a35ec410c497be01ac67c5d2cddd06a4914fe11101d326a739cbb04088311c561bd072c6cc15dc72c2e4a751129ec6933f6970422ec629c75ff96bf172ec8d516182e3425adfec053b67d0a39a74b04696838153eb61da26530b6db21a1af186381f7e80496b0022c3de8ba11d5515059a02325524d14ed2db2a31868dc659113506c9e2fc6da0d711efbfe713ce3340cab3132473418241ffddc502be67a4e6acbe7792f811be0578a6589300aa3192a44602c2be124cd56526f2a6212ce5b008eefde6409e13814709ced4311e94d70dbeba30140e1756d4c10082a9643b10e3f6a7774c3ffbf6c51d61a6691c4571bda85bc3627e6b5033c474c4ae2b3ae42cfdeec4417abd5408e1f0972ea5f533420b2bc6e78a7f434b0044b24f816b251d693dc2dbda52152d02ca810bb9926543b5ad313f20d0e6108c422459a2305774d6c301942d1e0203d2f6404a6c67e03de01e82621039a3c8ca9a230f306ec1083763c1d9724264bcaf1f7523a7f320e0024146fa99f07088092496dd23b214c11e58a3ba965566a9acce53203c34118e66c776367be0142d719806d0c1645085ab30b088ad6c91ee185cf1477704613e5689240ffecc2391ddf51289a3976032f855f1bd18caf4f5363de4c13054f0cd476092f2264c727d4af6e60be3c364422b80537d749851b3531da20932f4b6050570b6f55b19b6b8a41a431d5d07746c25dcb0689dffb566c0af6757b2c0f377e60af2112703516ac0d5d441f097d09871a6e34cc233325d47a511d311f140ab21d3e2adb82de09b9d8083cca87b34792e9162b4486486e3b94c969201dfd3f16d8bc2e11d52308e702f801696ec60c394e7b444ffa372119ac9c2fdfe07e478596b84a1f9701265e0bf5546b20196baf37f04875ce9f62b7f21b0a698b6f4ea7d353769dc95a0734bbb5185ed8ac2e48f9700127e7931c1af29a39964a746ffae59b3880faa10ee48f4c4c15b8032d66db70471fa72421f05a47365db5e83652f2ba0de11bfe0629d1822fb932dd5c8ad67e153cb35f3e59d85a0d321f2567ae1c261a0c9d9514d924993ffdfe7c30e2608a2bf2968d2def0c50228756d613097f6000a0a889195f7f8b611d334711dcef5e03d0a1d633aa5dc7401406fe28ba69de1bb33bd11b743fc60c93d6f45780b86f77b666ab3974814e4c2c5c945545ef4c6db157ee262ec80f0329ee301b5e7c195aee7c2d04842b222a84d27c532cf3043af17f824166f8145aa6b66c313ed58f33d1b3ff58af0b414a13ae3b569172796427583b0c96dfa6106f38161dd90f7677bf76ef533260685531f137488f1bef5813912c242d2ff7739607cf4bc800d209a67d521fe72ad0435eb42d661ebb502990ef77000879ba40394faa6e59fafd5b7c6d5272cf32923c2a0d665e28e247506604e932054dcf69f1438e02816f425af708ef103c4e3d6a8a1c15267140f2301d9f4c165c9fd50732149c6bdbf15b39593e4d53e2325e133fecf820eeea754c37289866cef02d1e3c87426c0c583677b6ce5a0872fa9c0e813cff2744ef602b233f5930b3860b622469903c3d28ae0fd0ff63648150f46c436d792bd4286635052d2a13830a8d39e48c443c0828a063061fb976c1e1823a074ef851acc2173268c46366e0a42044c907f122778e9c47aba76548b134e43941bf9a1fa26a6777bfe5475003c12b326fccc23fe5bd9c1273f959442b4b4a67df603f49d27a29187e10bc1b790eff2f16f1143823cdce395873c354f8e19b03316fe5692632a362da3add67f66a725b1d7b681307e500620fac9567e1599c40c5b9272270a4155858d0e06aff684a5d5f9f255bbcd9a636ade72e2c5d62a70a7cf8055d5cbf7d27feaf671e26f4f264f5d8b42f4d714d69ba438640ae1fed1365f0ba22be15f700af23c63488814663acc5eb12c450165b7ee106565d45284c6268185d2a23857079e0f75ae18d02207fbf902565813f598ffe5b55bc217a25518e030ce418e140db9e992d1aa1d14f710e045e1e03ff736a84e70c1aef142cf06ebd5d654557198e86d61167c5eb0ba231a52d27b23045c567346d4a64347126653a2dd179373526a5a150d50672293fd10a331cf11d19a91db3261e80af66daf91951bf579d427bd721757228473888484e4be3e2d1092925b940c946d74a3364c852b5f49c024e17300ef37e9c1a7d78eb202aab3634a882ba2adecf8b479a5ed6196ae47300c39a220e0d668a61e8f89652972669497451211d95080d49826a883c5b182c215d2bf62b4fb2e06f86ec3236a7589d362bc5fe5c30341262b3457f4c774cf34696572f6075659466e9c5e51a78d8c157110a35329caed52ff482202f1dfcdc7646d56a4f8ee58501fe519d2f27ef5a5e5c7e4d46b594c81d9f1efb0eae7c7340740255774647b54a8fd10631f6eb740bce7eec1697609c02d7f0dd2598e1746147fda677c7674a13e53e642298ce0357838a046c4a48cf28c348c422e8dcc511f8714422b27e763424ec8f662878414e04acfd69da31ea4cf64a4621cd7cb50f0cd4431e2a53094c441ecb4bfdb6c8193e1a1e40714b4617822e9f020598064fd2d8e93ea05730306455990aa16bfa18f422d12587d3391ba2fa8374e2460773171856131288c16d82eee557209b0c563e52720ee1e4105d50620024b15a8e53c88fca28c24f1a37275e38552255f56bc3f2b14ed3059d4322645f12c3218628f3d9bb6d23cf95761b2bee6d8168100baf2a19119a2dfc52556823252334a31db07b5e0a5df8713b2674901fa52a8311dd3a9f12ce243b1fa2fe4246a96d7315bc9ee668cdb3e62066f5f417c20771260b5c4d01a8b94502b88481197586b01ebc918b6ad76ebe6fba058b222db93518c2148616406d716ef8ea7e6899bf103b00aabf15b6f8e405b7b85d20d52b7b64d56a2b5f9128eb33dccc9b50c5a4782590db9e71d5bfb176534de53418430b62b83bf238629819587062a96d28cdcb0c8c051c6990dbf0586d1c0773f21f26047885fc18630fa16518425172e500fe1484c73d1a3959a33aaa92274707a30a3e61540f24f277f676ebaef845f3d4a802b074a85d94fbe673dc06c1067dc6e90ea39c525fa3eae55f9a8fae737a31f2199013262b25adb03b8e40a55cdf39a6720448b21539175d7417ada17453a19c4b1fc18833c9feee345913b2045849e73302439444d860e754696798454303331a5b0144027fc00851aa8d823771ce84718ae102701f44a04de5f0675772f46d6f45641b25a4d07d319db45271d47f2702f2a77e3621ddec6e220ac83bec8069478bb869363adb2f5e5c04961ac60b3d1c519f70150c4e651c8cd199392738e134cdcc7c45f4763d18850ec367bf5a823438e49f475c31ed1b69a20a684ea80f00a1103652e8219a5f2f42657611803b45f2bff933d715224ed7e9b14702692711cb45b75722a96540a3a1b3164507b12692859d40e6560f5f2a0ad52a1f7a3a30da574f77aa2f0252129b4864f18bb92e049dfa745632d3213f895371f0654e625d2d655efb169616bb50e711d9461519eae6680156cd657319f7781fbb259e4b98a022077b61624bb51ca831890d8b4b49d824765194f549db6b7d4cb9fb346eed52b14405c18f49d2f4016eb06563450e2a3758b62ede2e80fb7d2f3f99ea5fb84f774c58d6dc3517c6801557761a4b19b16048c21acf1bcf226f35a322752df554ca0dc50f975283da08306f0f720c0d968b315b8824379135f96cfe83644d7b3c6750c276e632e1e6be3e4717316e44109f670bce5d2acb453f30c819126b053b2c3349d6c633e7efad5a05a335434417333f16d2de50f7173d5f14dbc21a3cdbcd595257576d9d3a0568ab20e37412b8642eb5efef59bf9a6f0ba8ab0c370a640c003fca4e260b289c39b7c3d82f60cc745f4871ed65226258016f758928ceff4455614e6862e2c62d58203b3b5ca41ed824c612d7189b139134f8ecae581d34100035256e3dd641d639e149c36f39e17e3d9855904523a2f32a1503323d56dd844c9b75f033ad208e6cb9fb69175b341267ee354212488ec1298c5530616d801528fe583d41e6031f5a9b3e120ffb4a382ff82441238e8b421bc45ad22c4bf21f6028b29938a35f7e5ccc605f275470a13d20263a0855a2374d16924820f1055654d126fc0eed25717308aaca37fd509a5edf2fe73862b4cd1df59e5e1c81e15200924b6e762c0e9d5b6bfe401f16fefa68f6f921122f33ee0872d8bf1bbc5fcc1502fac5566cdecf34a69ac11bd58d25739c17d73f7ce4572c47e828180567cf3990c3c00ff6ea3803c463422df0f135575412b7267d2439206df43503811bf62d73f0f643e08ea850108acb27a6a3c9643fa73422fb071903f45b3e204d00a72c689664234c946217d1d4cd3ecc2e232294bd9760605af521c9c35f1f2ce68c44c3792e7407c71831efc2c50dc853b7222caed0277755a757525eb911cf44895216c4eb0d40e39750b6514a1d199f2756e9211b3ee216e8765cdf780348a7c83694feda346f15132b64acf832ac760308b43a974dde4cbb2f559bac4c4316217539bd4a09af18134d217fd022712ac458c1592637d2d0896866337c55e47fd43b0014cc3de84d9d5c1b999f1cbf948733d0b6f03691c10354b519d8559c124617f87427060ba61f17c5fdbb25002c0a643e4d4f69a330ad51713a506daff5a94b1f0703456e20b9350ab77c40c7d8a922f279c85c4191341348eb8e52891eea09f5de0b6ba605d90502f00a2e14cbec2049f8e65372d75c2bcfc0dc4655ea2462e37afa10809c02002c56f00630272d5ea7f59863d4c1a22a0a616e4c3beb4a2be8fb4d0ccac8664720334e70d6cefa1df60b0c04accf2433ff30c46de849ea5478f1733f90ca121009383e262a6367018271257573bffa43aa2a3a72789fb465d678d45139d1132844b1c1210516f023b4ae5565aae39130edbf4a04577b9e40db87bd5b71fbee168888db1137b05e31afeb246432fd46192ef0b5571cda9a12b6bb0d6e62981337d083e019f104ea1ff4e8b3442b19f76e363d3038054ddb3599df7a37ea4bea764461e53e69a1f93cc7d02912146366279e4af84e1b1cf85fea584d48e890042494891d33a71f0801ad5360012f69426cc61a135d0d7ccd53aef39164ef39442fa73b1874041c776851c49a350949b803297264650cd8d97069ebc22071dc8c4376595919a7734e545ad6bb6c653b241e34172b6094b16a2927a3710cd1fede06d69fe20fde4eaa6413a72064679dce02e98840102b50815fd1128d1b95bab1148646ff2343d5cb207707383bc12a521b90ae9a2a69069111ff1a7a4322fdb668cb62d5682416dc102e674c2143dd4c4247d1003a66a52e42b79c7e3e8052275ac82ea552da668f7136fbb81e6789973a61ef343d29d47e77e8915e2059d6f770da58d63971dc686a886d73426e8afd4767592b6c58a7fd18577474397f0a4c4abdc2c437455b7625f8029253ab54401b8a7d122d3a3b221da97fac445fe9bb56802b87236209323aebaa1e67afd8cc0a379e9b16bea9a4129499a50389a8e43f53d6e04a014eae3137deb561dccdf858fd193d671534ea480b8be04c627a5369f050796dca8d7634cee75e33fb85f96fac492d30105df169573546185c4a1b55eb74781e81a98534dd8dc131d46bca044af87160d80ad72cc91ae343cf505276a5edc324c09bcb46a4bda95cc950510b846f401ba80d5c1e3411213c9e59a44a24b43b092997761a5e461976ce55eb5217ff915d2b09c32e668508177e327522bb1208697c045971f8b46c4b42ec9753417bb85af67eb8517368874e1f466b363c3b2c3f1e71a2744aa37f19a4115d194f12b00e7a3b4a0d0f64c0564e61dc1e69b4521162a2c63cd909371338041a36d325ec2b5d37c1458c7ee66230d601725612a548ec1199708a7c052a29cfee776943020f0a170c0decc86047ca6a145c9cf9970ad8b98b58bf8bb00f0a9f615219489e3bf303af391616cd275a152b0432e5db47f3259c6c222f032c0122584ef7180c6e17214031abbcc442dc91c91892e05d0be2d7360daf9b4532629a0d3a9ef2170b34393a391e8a525d4d9f0233fc8c845294b92443063d546f092f5328e590e2172c932071b4f57d0ab69c882dea75915ca79ef01adf30810c1d78f74d5a04084d766ef5136b7af36970b613432506390eb6a18838e06e855dd1fbe74bffbc2e3419128e3724907b305a303834d2f0ea5658b72803e3d1f26726664f3e9b92e557817db25362a1f65824de7a58ffe1092c72b10c571e3a803faf8fee3851f64661e036e9444ed11137ccc0fa1a3dd45220a74cd073939ef045c389103b41e0223aac585755b033c62e1655535c5bf6233fcc5fe8188f6cec68ff5c8f1d75723539ca5e0a0cb74e426d6de9e64a82195230d555ad0b9c786c325484f6010907133e0d453125431f153c86d6882b142e6055e75681022380e4567a0ea83ca1158c02d9cccd10717aad0d1292aa393cef2672ad385c00ac400713d33c7f72b7b4923b3146202584c15e39a2ba715d1e4b9a18876a922a6a6cb94cbd83bc3a052c1a2dd42e6863e8526963f26695669b2f76593208041f01c83a6f0005c914267975652b4e752fd3847a277917ae5e969ca9333cd9d97507904b5414d8f326f402b85efeb74122fcf5ef19d1723143648faf527b967a3ae725f00a39d71765700e31377f378756b039490290ed9d729d53895a0da9a863f5a98e30da19926297dc0808b46f65482532be637181bd151f7d7b020a98bc28b022b428d8ebc054dfdb2c51e3a09261e2d6d6325543ed3803020310a226e202d5b1483ed5488a27041d533e5c174418ad11e15311324d02c9f1ce2d23a73269edea546af4a9de10f4acb0545d25837265c65d25d3a8d4758435e53596b32a0d32ecee12a42e655698dd706b591529684641694a55a72c47792c983e666ea6063a7553415b2db15f26a94f0e3e13e0700d66760743846d4af568f2404106b00f3dfee32f152eb540952015243d50a15195418c4a850a163f994dfc254726185cfc869b1ac252730fdb42070942f9b553c048d63dfb756e467fad2643c37c565d8d27821ad43fde458dab6260940e0b21dda4a74dd4524e4e73a4d56e54908d6f8f8fdd63e25c5072a8afc55fe62f0237ab5ebb0d5a40cd4c3cde8d6a6c014a59989bd037a0f49b03089a575fd42aff2c68edaa2fd3c76c337961812ad45d075696f0054639983c142868dc765fcd1f0b2e8aee3e846615467c2536662e985e2e14b1a66385aad236db71b56d38d1cd4bdcb89a5ce6c91a77790187736d71df7004f89538fe0d97567844b36c48beb032606bc24488160b2dccc05c74b5aa001ec23f2a151e72621d20710e75e057df1d256a2e53c47fe353d74d94416df16d6e72948368083b07239a264946ee5fdd6a26a5dd428f6f6c2466552c5aae5ff172d1369e1733021a747e7eeb6a0549c8709363b2704f820b678fe92f5600282b5b88a2f270c2ba1b0d1940e100d7dc2e67f8d94b4cd72204641a235343c102993fda37ca18dc12be42f8c05a13431c576187b1d10706a058254cc95c67548bf76262405739e7bea96fd4a8703fec686a2abc9d0b749b1bb50f7764776e0bbfb03840d527400e62d163b122d74912483f6b9d53d6624d5fd005ad9d4161f374a2296abc1b27eb9c376e3713d971f173ee594c15ab369687bb6abd73621a673418326a2bcf1061368207006bb33095eea74a34dee74721029b1c03044167c4a5a367c11ccc494dcc6b46de62ea14950e6335f8d49744706ee8088f1bc03c9fd9ef58dd259335f324ac6f43842d05c6c0475a57814c6f6a2b195dea05275973c75014592c9b683efa396945dc701458b5cf3958849e557086fe1335b09e1e4c5aeb59dbc8202c80538129bd284c24085b3e4d18d1253cc4b93b003a8bc729f262f9446497c00e93d34625dfb987256d770b66198bd86f1c452363d9be174311e93d5e68b8db6eeb310a5c231ee536f3519914a0b155477a579d75cbe455334d39a44492efb731ea6c9d00731dba44678c3d20a737885d0e5f4947e28d441768162d0c9bc2710b680de900da674f67fcb97a005a35fc13ce91712e6027055aeb0f3006a3c2ca6001f03a01bc02433fc4b8515adcf1e636dd6e762b757273548f9bf657d1a3a33a16dba9491a619d6c254dd72898a852468532ce0d0deafa2e49fb0d73b84d756486a01f5744eddf25aae0f308e26d753003174f67cbc1ee1f714d05080a5978321c10ae51346931717f35a82bb232c2475240d046ece08608ff47e422d5f07363a6155f2971effe66fc3ca237db47963b7712f918687c987671cba55766dfdc3e882ba908fde1144ddea1ac3599b50107844d310f3be4a945a4c46b1be6f07a3587a875660542684b8863310febb3d66d80585b67eb8e5c6b9363bb3ca601e16821cb6a2d3aea940dfd4b301ce510be6cf1d7cf0b8d2a5610446a9963e82a8a5d311ee1632238fa0e63253d1dbd7fd73514dd6d378221ab180596f04b9ebc281ce147b447dbc7ab3295c40d259cf60856352f8d3374ce3b2c4726b42e367e02606fa79b6105a0651bbd6e0f6e662603737f88d236f9d62530b6d07063177ca902db24fc6fc62aca654cd7de5680c07b61823b824216d5a813efa19177b76cb46d46611632a859da7527596d6a386d68131e3a88677f17bf33710e3b455abf296bbcfccc51f561bf52544de00caba2324b086a030168d2ac632f3cc2671388ed5ab0f307130d7e462793ac294d89571d28cc1950018dde73287957ae2480dcc354e5537408ee4f3461703dd2522aedc601cbaf553b12c1bd04014b0040dd53e0363e93b85d5d1c58457b815f4643cdd62880185e4d8cd3fa48798c7b4e5894d2568e7347643ab222115d7b4d4663410748f2548b6c78db4763dcc0c91dbef8800dfbb9ed1ff0f74f1f5e42061cdb781b3123b044551055c320fc065a245232de7217538652b477bd572fd25863d9c93e16f81d7a70416e302dd6dea81e3b9f400f05c128199620005a931dc73575db573a3de2a16193f10b320815625947bc7f00060f860b67138c4c5a7f5b62a69fbc5dc34a8b3389e5d22c3b8f196a6c207c24efc9363546a3766c4b831c203c96a6638ab3bc686f909e591ad9091bc4329602ae23f44035d6ff725aaf9b37dd26303f3e86846121112914bf16ea3dbb82b73f0b81970f7b10091218d2454fa7d6cc46d51fed0e5f55f1192b9a8e20a96e6937cf6783006327a35b1297d949d85aac373e104601ee686666c229a05fba470775de087b20151deb03649fe039ea99413fa313680f306893073f22ef271a2ef26a7c50f0324dbcb173f3730a69079e13530cab4651ae79021254435c6a944c8f54d8efea1be87ec16b2b9d525511f2e35e68b1ab298442520dfb60ee732ca31f19479dde2bb540ec60123ca255108e505348004b4b4b6b6e19f7d90d73a753a1374783b952c9c14a5ac15e725cb6d1c30df2f6ae647964384189571e4f5d1de029e103cf7288384658fa015172f56e31606c84244b0b8fa147df965b46a70c6f76ca5eb516c7b97950606a6768081bdc5d8a415a20fd47304913da33743f1337079f70d4731974fc29224762482eb388319f54a725e5d39f6aaf198f34c32bd46360e8ab399f9f7927f04cd92644bc600d8284904ad91a2f146bda835d01c3c7117c6a430402df3a0b9856d467d17dec1de37148431b50b8386add07641d26db723e37475a32ca031c7edd3c2b9127765239d81e3b82e6ae46ad4d2b4e2afdeb0303bd291fc28aae72a8ffb3522441624369c9c752a805774fef9934374357e72d162fb91a8c0303155eff4a3c55c2c46e0835ff66eac9b347987209713a00882501def8667ece651404656c00ec7e6f5e2302e546860ff149f10481653d92114a4fa8701be7afb63908bf9948d1334936860de26a7faf844dd27e4019aade842840c5010e6bfa2d1883d12842c1acc94bb1e59d03e824f550651bea1b7f43a720f0cd14759ea60e2e8cbbf63b69edad2a4ae0c01f01356c00ffaa2d350699456565d7f2573e316c4ad0ecd93b12792e77db7b302edfe46945067573412d3cab06daafbe59c7fdd7156e74dd728700a41bdb9f9b29758b82755192d7551abb5c4887f1be54566a924f79f529476469ba1cecb6365ed1f08264a9f5180764e4fe51fce939701a0504368e968e61809f642f6171e75d23b124045717db241a54f5409079a067dd011a306dc8cd4f18b0324eb8bb2115fd5b1374a578707690047e75a84c254a86153909ac850e7693077a3c8a55ed5709b5a861bd223f19240e081025abd63858d079449b79ba457ce0230b15ec0f267b8fae3ff2322208b518a56d2d890d690c4c50591816ee6727ddb0167f0bb905361c8e1188136007ed16386a6e53166cb0385158dc122f06e7af2445c3b0a23457dad5495fd6987634bd8e5aa331685603148d2379075b03ef651709cd16b03e8984ed778b7e4f6df864ab117c90c62a366a1739da52d11adcae770d98362248d2bfca33f912055f5f4e2a0372ee9049bc3be44476d4c55bb6280835e7030535308e2a14b9ec9d6a86ff2e6eaa59ed53b19bbc36ccfd9a4f50c6214d47984955ac6e927354f5902f8532802f49981a2c708cec7553a4282d9015da1da5bacc378f10090ff935ed649a7feb58189b3c10ea3911032851c701b1bf583f9152357677da6e4cdadf9c17e0fa593353c494757ef7885f5941bc31473d565bd9806965a2bd683119e01814c6c69601dcdebc31b5ad6a7755ba1251618c4d090f52434ed801ef6fbc4ff158ff858a5708a50f01d8b8b92fdb194720f1f31a5fe935cb2905e8840e79cb4342086ab6690fb79c64aa021636e9bf733cda4f6c6d15a0cd1ce48631647cf619041940d15aa20c30648b656825a8039a41ae5a3445dae87f738ceacd167a1db50f9f9cb14205b3ee58616c4a1da7f89b50bf5058718a11ee5d03cb521668bc7a17661bab4643e02413c964182ea1fe60604bd3910111ebf72e2fb9f10d2c325064875b01768b078e4d2d126c6d4a5159237ed11176339d195eebf96a3cd175a96e02a00c61e93c9844f96bfb3d3fe9774de2959344452e0a04f0d2a35d0e7fa062cb48e55e9a96402dcb9cff2e05496874427f9e7298ced1204c177a660e28663fc7e54a33889ba60e6164aa6d7c7c04239a8e8935bcdc7b6213db3a15c1271a6294e4d8202aca33073338017240ceb347fb89e403376ba86edf34660fdf10743eca8e203930d4c94a2544df05d584c36194fe403e4f045a0dcdd9410ae0e063149c42fd0a8e82990976011417b802f92aaf8ae83adab0b36e39192e58af47c14a6207936b88a0ce751c1c1b29ca40b676f365b546956dbd22cc98f741ce5f6710f3d10d534977b728dc4bc73df2de0410e72e375832bcd21384312b5757f265744b7e316a868b645853db1d692c0b675748342d4169c5926c237eac0135d4565ba28ee4338ed9e6248d9df812848e4f21edb00e256d00331bf957d2082c6eb976e9b27b60855e526952031237564e89647f61605aa2e12360c852a91fd3f04a5dcee86c6031550a6e14e9ce541f8b2763a9cd7506e9e0dc3ca74a7a454395ab63992f9043088f960081215a18f52a4e3e0cf6450b803933399870586add597b0f7141017448fb03500b61e24f7f767c181c78434c1805c4137462e41fbcc15b2da0e55559328794072826c954523b595a62c2473d5bfb1806e2ce31013de9d143d82c9229eea4b3194c4cb2181a59cf54571e653293890e1bd49a5909d318f04b7e60de73ba39886c392af103e3a8b11e9bb1d00464bdf4549a88292da7287944d0f2fb1fcbe1be1e66348e2a69408d34b44dbb237b3e6a367a29cb43bd6f664db18ce831d25dd3400eb7bb58f396dd164b463106048964069e1ca35a2e8b0a61f3b0cc411fae414be76cfa1fe7d5e003eb725c230ec06400d9c7356aa0bdd23931e81a0b792730459b93fb38bc379911692df53f4082004767155554520efa19b6037a29fa6af27707a65c10362d2f77423d126e5c79897353b7bc0686faae777ae1290e7e5f0824f853526bcebffa4e3fa7422704ec4c1a3a75414b0ff6ab1c175a7170922d900f7c28e61b17a7395950e4332e1f0f48021ad99b2cb39082764f14142cc6cfe64b88f62323424eb6528d1b2815644e066bf4303e707c49701fcae7f25386565565790b8b53a99c84732b55123524d2d60a507f9c164ee8a6295e737705d2e5f155a8f62b365affd56768060773baefd424a54b4337483383348abd8e15938a921cf4319068b10ab36e48b5f947bd7dc54e12092368d1d4f94d788591207dd5ab658ae4b076e85f0f0b66aed01f519bc766443ff9338df10a7662de9c2c89f4863a8f369319ebd911557566bd74c2fafa60fcd43745f451a74b4447a53d7555b12f3550b94072a809675e049a754267487044a0e56c47204834f48bb143c88a03262f22082b498e786c0cfa3d2fdd63474b3927f825cfb411414ba17f3fda6ad56f3146f411d749456de78212645292614767817565633362267854cf1879b33d5e9cbc5020b5148d6e2f219c6ed46b9d30fd587c274b06c452cdd71d680c85986a6055a3619e194d2e0ce0f00fa28f0160646c3862c6eaaa4ba5124068eb2ef603795e051c1ed54a456a89b0553ace9966c81d35674d97295bc072be4f21534d46729f3f62290efb43a5e8be4989fc240e63685c4ab4439723ffb0e52821400e2e5471cc6dab8bd05cec4f12658efad63be4791313baa4a821746c5310a83cca14cb68483c325c980c17c7ea04ae7b1b73b2f6a918c1f6d62644bc3c0114081c543a849e15c1dab85cb906053cb71260340a38736791a6221138167608446296466441162cff690513e18b8d6dc642334d3ab2d0393fdb117754ef931dd0b7b14ff1d9184b5498a14e7bf1ff5cb1457f773221e42a5d59e408decb10112c66a258f0058f585ee4cf2930b5a4387378b2079dc571768a39351b5d80db10f8ed775b55c5842a6d2bed554ad7b72a45bb806eed546973a5e2693897dd976d9b514c740c8c903995d74462552eea5c498c4221b6939b1ba8c9900fd0618c6c90147b0b7005cf6c66ce5d5be826de2c1b9ef616bc74b8540c51ce06b508f85d6ff56b173a23875ca03b173116fa3e044490006d7faba91944489107d8ac662b313ea405b277aa0f7e5de0479c853c63f6e3d955673e631d363c7a4d7e6c9947a2ae082ce04e8b01c583fe283b59912ffe2dd6339faca16405a0432ace41e0550cebc03b6b02ae5537a7236e9798fd4388ac896bb77ead3e23a6601e523fa204ad5d8346547e7e28f0ed2271427cde1a847caa0cbd493c0db6e1a962b8e5e30e9e488104b18f276b4fb53d5cc670f25fa50c7b623fcbb6649509fe531c6f3c4aa9874e0bd49531693803fd2f6f92de1910eac76a4e279c0388086d7327962164f65c5e549b689060d453ab5d24a70774a5d2cf39b53d071b153cad1825e49d2011ad8d6aeccc0a001f6d6e569ebf8f2835cb844a620d5d68231dc20993b9a21e9499d353d388bf6f5c1345004211783a6d3afc1e8fb2856b5a76131d5fff4336b579ef188bc8a72333aaba6954f21107f9971948f4afdb31862eed0030f6d8337ad28c502808362189ff7467f749d2057ef92b69a5af87426257194af7dbef4560592b57756e984e3e5f455aeb201875bddadc3ceb67cb01403ef8620d93fc232d28241f33dece0c4f91fa6fdcc30c6d51335e63f7c16a5143deb72d6514581770988d6c572e4539f3ceac2af03d4320278eae2df79d640047e55c577e9fe42a5744d13343486f1b9c556c6f36b0d868c296790915714e228e91121e25508419a56c744bb259f20fcedd274348069a3a7926933c986c23492fa7bd2694f8062499add455208465712660005903d7925f4602c4226bb02a3420f42b38a24e0018a72f486f925828013bb0101a26610377b168762be9fcdb50bde0ad1962479e5327f9fd3891ef8a4dbe6bb512f807f9520f788514d1f417297127e1767f23f0337e1008762f99005f431a0f6fb0b55267a678e76199ab4a4d66b55b6e796df127a68e2e1b2607e9530ba915371a2eb05a35f8b50b9d812e0fa0d169169b36904ff6b66d76e4f29837e32b7727fa9f9c445ce729596c771c6301c9f1231350fd09caf069540b48fb2da2af890f867ef907e2e5122b915e4c3f14f9313e78b3ec45605c6e0735dd402989bc9c4cd0235d05d0b0ab15765f10573f755921df5aba193cc08d39d4fa5f35c03af0555e657b60955c2376a8a52e56ceab0b6701bbd70eb2157c65c26dbc0e0cf7fd0a11194a1d704f03555ff6550d558c8a6fa3554f35c3babb30b1307b1cf96c2946836d596165fe974616c68d5fb2392c383906d05f91d5fd3bb860f56d9a639653d462792183503d31f272960e00d1416ac0bd5b41e1de3f3cf424284eb5325e179867d10fd016b671b2ba86011d6c2a4f50420a0c89a89445eb3eaf641fdc802371f3f62a1ad0df645d95142243f40d22a2cf2c3fd4c0314408498607a133eb3e2cf7da54a8446b3673cbab3d08a0ff0eefd910150f3305225e456a52894e301ed6c0795ff1627875d9fc8911ed7026665d1b1e747f7f36485fa00b441989514dc45d334e839be55007284b313e83772415510370f648a0593b7e612ca935c6409790120a70a6620b4a1c123738eebf5b697a8235248c05063814716a4601ec3aabf4516878d7536bfb0d687690c35e6473756e3e2d499b1c0a0f5a609bff0e4941e67d59a4242e674484df751afb1b1a68178056fa7aa830d4bbb263cfcddb07c8e74671181179400bd8b11f709c5b02a0e4475bf28ed00c3a3aad3a7ba87c0201a66634ec9bd45d7c0d236d692c3a1d401bef136fcd736eda98a0570537514e85ac91642221d1153beab16cd8e0dd680fd47d25b5b6041286a9d16e7862a039204cfa6108a6bb5952acbb19ab505a72479e0c5cfc48c1773bd7c31f4273c61abfdcac228f54320735e4575a826c7b546fb5c2734d9f72422bedd210928f740059f95227c856966a28ebc5260e7d3a24be756512cf1377097b716d349c15fd00c5b77a4686202a2c1c178208a4f72121e0ea883a12df6c73dbd3a06cded8681ce3053e5e3300492935cd2a4a0ca3a173c4e44b5566ee993a376fc34d72930a6f27c7376138496b1ff5bd7e0ce41de92840338f6e38b9032937a9495ed236201963125141cc1e8e6a05d2fe1a9ffea962cffaea393530c55a51ba47355b304c6198838d734275e247992233391614944a59afbc37362583655f2673519d340769cfc2130c60b665222f0dec1c3290bf688ff39a06a2d2b15043a0796d08743055de539170e16baa2e07b0663268eaa62116c8eb4f276e5c0404b36543aa0b795d96057330ac8c222babe4d427d7c3eb13bcd3b150a61c6e1fe8639814c4fa623072b9d7046d81593dd60a913bbb46932fca53ac489a3dd5350d34250dc320c15f8366d07649dc5a411ed8154367587c4be345914b112203182ca2d23a34fa84280809db3a5a4bbe40c9d5e9744947166a5f30d31983a9ca53a76bbd4faffab450d102e5259ad62f5044a2133a6c1c102fcc219c6387fc74459637dc377cb1c91847e9c2317afdd34312802739969e5f29b4a77c6ce861852c39ac500521429312946304322f39ec67e3bb1f4ae46476700e23986084215a3ac201cc5801ff464c4b56441c51bab1332d7db657bdcbf071da3e824a8aea5952cc322f448f1b4966baadfe6580f1612a00269a446fd34d7244250931ff02ce1c6eeb4d211726837141e828169c58bf1666e2303e6777af5335f08b114c75e86f1dc69a313a138a16f7ee6f66dc58196446cb7514fa274e1764921b55b1ce390a3cbd840c3cf7177452a17d0a3a9c19010407925920d66862e9e5cc3238cc0e25f88a710c9eafee231c82e0303077e4434f06f80237d558164ef508552b99fd27a51fd274542bf96b62d94122720d9e5bb9183a5d5e71c6613cc135500e393d2dd7eb185e9fdac43189ab7a19185acd53bba7ae77e68b0a3e220a796800c8ed2e04080a40ef494c3d64786c30ecf0863bedc38e236a168240a720945c67616d0dc6459a1923a2ba07b86be41b763efd2722c533703af8595710ec3676f5ff68668daa1548554cbf34ca567504133d7d48c84859317decb30b6dd7c974cbbc44502abc854b60ce732e07093b402891506298f6be5af757a056ad08783d67435e4a4305d1644e29d514e4d2fe7651d90c1eaaa00a1fcc5ada1368afa92b4e07fb24ba0fae08eb0937590a1322362b2f99774cb86810335a2a13fef1324f635d010b63c6c10d4586173f84853548ebe31a2dbc8c1b64711690608cce7a42c110e02bee8ff6548c524d69cd40c7593c12bf3477c43a192008d238e718b20e23930550b18166005bf661267a8e4d5c7187dc5e4e7dc75c40ddd055a7ad5d164e789e088b4cd83c13ff990115d683451c8b416a97d9dd213013ab0043599716083026077537e246ae6294078076c0738e084d3360b99b2969570d6598882a13c10dee3f17f436121dd2300fb1b6300a7d333f6374c53010c663985dbdedbd348034675be497796d86036e197f62b45d6146571a6ae12f75e3a1381c7d780d312e0eaa5b87464c111d2dda43305de03579c7122d38ca853d8ea25767a9e22364911509195631e6653cc8204ec255f84fb4dbaa4a102c3b31a530ed298585b03217f4da758aa79715efa78423843f9c7126246312e6df24144d20eb3e8ac388046397d207c6c9302f652a4770e3e1a7551f94c622fbdce11dbd6efc54acaab32db704be03f2d34c6a3e03e34f6500443503e2df6c85f6e350cab31406e18b66197e9e510997bef904f6302520da126129ec88371e0ae80e66fba27101bf03c02e58d7f25cd2548d630766206ef6d18a4252efa253892ecf050fae3e7314002e6381bfbf1cf336571e2098526403d1fa1ba39e171e4595bb6dfab2163e76b97b4d64b348408e233029e59f60243660b7199b4130053260b52339a7a419ab6f155bcae64b36dba6100b33cde06ef9e6702769eab53a51e5894e3e1fa6541c51380ac55cb00bfa7f8c67ed4264317426ae1b3d2175392da9470b3df33f4ef93c423c52c29556ac16e617c045d0067bece01742096a28cecb6717dbd1c03116451b3de5ce5a33ad5f933a23d2f92bffd44f256003c32e18d18e5801e2aa19454eeb714db9466843ceaa403e219d236d4931440f067620ee49f82cf00bc03660fd5f360ca2b438c2fc3e4876cc1a5dcefe0670ab461907a9211466c077ef243beec64f3978044058304e392eb0e332f0469c6ec792f63e95bc5b1ed4c17f74ae79552cf62b8f5d7820864b41a22b594ecdcd5200139248331e4e5f4ba0ce2c553daa1b2bc6140b5f290d3e0eec3c588f3772283e5b3d55285c194b2cf64d54bc73dc24bc58bb2460330310311b0344a5371f739e5f060ad1b66405f4945d0d2f37d56639c1ac50c5d3c76e6fecf32c6060266c02336f093885fa738b2c2a4c957d18775cc66e5688f8a46d95a48f6d34983f3a26a5205df7a8900dffb0835022082c0fe85b3f3d98cfa654bdd2d605d1b0cb63e5eb705fe0e1501ff583c727bec42201a9d613563d684b70946351597d03d20a73a02f1bd74fca7108ca24561c03cc0425cf6b3efc7cde1612860e2da8a88764a6ee5a39f8e8592862078c27e81fdd08340857552b829f3c2a71ca7438d49d430daff43631aad308bacbfc435fdf0d7134786466fb32c924094999142116221fcdf233317fb4ae66252630620854df441e91b66a07d63d026d0e5b1355db9a2a254cde10a0e8fd1742cae006e778b5209c8665219e49b2695561e363d5a81f278120063f86e03977429b672f3bac6e39dfb76850a010063f4320402a616a9821c9804c00ea64820c9381d64b03ded9085ac58e1daa780b2fd358ce6aa293ca30e2a3b11c6a8f95004166bf2f4e8a982c5cc5245b072dba6cacc3d9526aa27f6fe8fd61710d03ac1b0437162f8cff5c3f189d976132418b71e15e9f5be2d82d4642fbf070aae1b46bbc7873221683ae0e859c3c0c2eb55e2c17fd93424486153814f22d2064c0a3114ec8bc55a884a2379a160151f4559c426b8a9a00ae37990cc2a4fd41acf872133213f639a6aa1a2d04a2b5142f5eb10fc7af5a26fd8e6b429ef094090b5d4d54dc57655639a5f008aab4135352f1a03fc1e13c477e213a26928ad14925016c6ce96429334c51f11046747c099e768522a93386108cf3a05dafcc6a2fa0f4b0772a64553b62a9da6c08579a299e19da50fae1645c8e08ef1b4ab171424749e812cf12985bd37fd146da8bda0b5efe7b072a75303eb9a8081374932a0f2975ec005f718a3964523b740026e7487daafc099394a458617c5d6ba50e9221c786514cfe891d289177c34e147bc746e27d66770252ce0725793e757d37361f3700bc05290d9153aef919032aea8c75f6adc54ec258a62910bcdc619932ec491a33b565595d5277e084643c2536d6073b715c68b1eaa6207e5bc5430a1ff123e62090573e6a343867aafd5255156d637d88752e199032238be26927ea740c51f1e14b5b77b5ac5fbf8f13233e67e46b3059ad3cd2b62a6bdad38b21701b73097c79943f4574bf226f6d27245dc422377fc761020d14f33b7153be776166cd00b858bc210e9b6a3e6ff8674d796e2c50eeff1b13f1c55c659006b56ffa5aa93ac1fe7b4b0526a33ef672c940cbf2363ed64f2907aec5f965dd738e61f9aa8f57fdd52e423d72766e68b9c732b0604227dc033f753705d446bd77b96d53ab844d56c3f2142060a60162a8da1be7c28950e7b1c85caad82343ed2a30529be86762e87f154e36b8fc169f2f926462c7d23cca1dbc20d445fc2c8e0e863e367a0f2270e6a02419ab17390925fa4ee33b6351abed10747cb44853bb9c98061497496f729a216e5613a22162c0764a86bf9c0c24e5341d8e1ae3240867d820251d8e7247c22c71df909711ecb6bb71c96970609a7bde73fec3eb61a685b60b0e2bfe58fd460242d839b769305aee2bdd6766014fca3f1969141a3238d48e4871e18a4f6f34766f7be9d758657e491a84dfa06fad6a4e320aa05c19d9e89c163a804049d80fc62738f67c114b9af61f66f4dd5f39177d5440aa2c6d47b27b6cd9c7b30b5341a6163bcc5772e1685f49ed2f8a4f5bfb602ebf8ddd514286e6774202aa4be4a1c000369d1405134e491d0ffe26048a0c4549aca787705bade622a1105458f0c0c27107b37929093b9a4114edfe1e2185dc1a100bf42e432a8d6a2f93582807bff025662e11472b7dae3135bbc9379c33fa2b3aa7af5caeaaa16f9e0f7c4e10939629c889eb1948a02838c745320a8bb3084d1a5dd539c64bd4307ce03b74f6c5b22dd8944019f9024a466cf6bc119526ba5c462c297310c3fa605db6243833b42b7413575028ceedef60f0a74e2aa7c1f243f6b6da689a4b0114f38ba9358037c32d33bd713f497da31a9bea58595d99f95e3bb34e674b6e8d7272bce01f9da2ff577ffd2d374ccf047672770e29484a7e430abe935b2fac663c2de5ac46aebf6d5a3a29920a1da52c6008cd27214cb7394605b3885397f3d9502645d644c26b0649e26e401900aa3473b397874ef6af2d49ca1d477618961c626818af3469187d6ea31c051167219f39b1e08b14a45b1b49798d3a51b3cb93754642a04789ed9975246a5b0421d0b5123d2dcc09c0f29a1465d2ca112ef5125e83690b65cf696c3f0e6a88463e21b21ff9cffe3ce98cb133c8c8bc63bda275345f5e364396cc91391bd9e92a6a49452bcb6daa2e59e1d44d28b6675f19de691d0878de10dbb2fd0e5872fb245d259436317505032b4d6a66a9cb2f349d0a4e17b1eaa61a0211050590045d6b3e8a4e1c69642314a37bbf0bce61fc5aae504f0156269314fe28e61dfd064a0c3846e32fc2804e5aa9cd2c58544131304a6f4951b9bc882a2a6c1d040eb32e63bbb0d85a5a81a51f6eff743f20d2b54ef461474fba692a692afbae27f6831331a93791636c01fc04877c041112bf4166f7582744edd5795f10ecf8426cf2656365a9cc77c7499c285f371c0907d47260eb6dc44c2b52105ab0829038b25e5817f1b54c41cfb7d20ed6f38b17eb56372b29c252444ef69d443ef1f107f909d3006e19853dce6feb35079b221b129e253a0aa71f54d26b331353b17649d04ba1087f623a3c05809665eca1056672ca0f0f9c9232034b98865890804d526dce5a29a5b68730c9fe1e6c3d15e508a6762f08450d474c92205c0d661b98575702ba4f051ceb07808be85ff0d174065952c62e58f53362fb732648ac06d622f771f2770f86d11cfd077f6676df8a08c2a4ed13651d6f4fb4812059b1715215abbcc035b0a89211dc88dd4d5213ab58c1a8c6322b22024ffc42ae2e4628243a0eeab13e4a317171df8bbd5d3171b07269e7bf005f93b9390c587c6aa998d036399d5163eac07c7255b3f5596415600b9ca0c92d29aa9a38012f9b5b201132702904542e4f03d4575d28fa68cbcc213efa9a0447fb28757173645738391fe523d5584a41966b5a51630d472abef2214383b6384342f368727e07443c24f4fe14b01c322a005daa01d9cde169a7f85905cc28f96e12167b023c8053030c02e96690e357555f03a91236eb71209ead690b4b9c7e1a1705a5032b9c5449e401225e5f10b56211feaa0dc992c80c600ee0508eb342223a657c28468a333926737b71ae6ffa0033503e60d70c262f1cff914d1d0a405ed22c494a191c4d307a648c3309a420628042e941cda447383793866fafa6574c91cca666b1ea0f509750000717f18d3a36c7dc50ee9ebd05464d6c54443b7323ba51e60198ec780c3d009767a605d330b5a7ec19fba9625d40e257447aefca03a013496edce0e66386be664eef9ff4242bdf124f7b059e2d3efebe34266c0c3d57281f5e63be79520f9a9736cdc451484221d3483397762255ede160b2d2f25d433cdd5d11759e696aae6f37683b4c713f45a44f4cae3d5dff54e127fd331b0315b60e5ff84e2557d5eef93e7591fa4fa27f01001e32f04173a2f26443b7335ed7448b3df6fb8e039c38686141a5e33ce0927c5ce1ee261405ed9968a039581aecda1f2da854b54bd08afa2829f4a02dafee965e83691955c40f87357d9bf21cded46552a97c666a2cf9b50da0392a6ad8da410407d4643fb73910731fe9ac5dd3fc316e74299f128312415e4fd9893193bfeb57a534263749fff5089dacb8015f47b122e00c1f1527147c2f9f5e2e76a2ca6104a4359705ba912719255e6b245571e914b253d126df2b334cf8fb99662c51c12ec76f2976d0022035427c6a1eaa4e1046565ed5085f7439094668e04128f66437071e0360fc0b6408c3212d5977cbb6131259fe3bc4ec4f12a9ebdd1ba96dbf0fec4593497d3d39638454f5101983ca332006f156b6733d157444855171a2df18aceca411a2f93e16d3859b0b341ed0055c11ae11820f78775ca9716557ead6412d16743d4815336d99f8291cafcea5305b3c57027cbd592950ae826da3a3a61a9731f70c5ddff32ba563a448ea0e0f73d3ba4e37f6634515a18cc92092fb335cfc0d7d2c8b90dc6aa397e55ef95c02726fdd5a752be4794c9052d0759a54723bf9cf8a2761e57340e138cf4bd588e80e982af547fcc9345b6059940fe60de74cefbe69134e9b9049f67ed7045a3715465c55582f7208d91e8df3e8621db1073c6e4b13329ed1e72099b2722d77df1f23a9d1033280fb8a657b87992fae289f585bb5ba6ed682020adcb70c4ebab526716a9eab774b0e2b602af76124b2e1180077ccae3aa1a46506d198f337adaae9046d755128a1b02b45c476a805d6cd96039cef36145142f47660ab8c396644093e2451fe164311235e590a5f380359524aa51c5218dfd8d20958026d52968cc40c3cafff7242dbc664fca2fe2cfdcf2674c0c191050df4e918fbe35e22312f3962c6d079486dedab4235c7eb2b1b93ef06a8c5a739b3d7043757a49f2482566c18490d651ae586883083fdf86dc10eb64cdadd931e58b70166744dcb17877dd94028186f36f6ea4718608824659b0cfe0400d3651cc6c84c6ae18f9152361a101abbfd3c620ada64149a2eb5223d7429503e4d29688478960ee55b7c45efb96539b16e346c8ba339183449886700e52b36d8782226b2f165461ca0803c633e7131e39cec2078572757cc97da698ed8b933f641a835b2c3ee48df685964267f9a0b7894272f7929f9246a10ec6e32e9f829e7504b3e774d49315e36a9059055593bb3c1f22bbd21135648906c65e55c9467ad40c7395d00902d5b1baf28c8453020a020891ac9d386202ae0730f57d1594e449a9d25018d454d24c6473a278a557025090438ff60ca4d7cce45030ca17b32a25dea2ca815ca6cda80c72702bed535062f95196937302fe3c9ba43a23093692219661df750b76c055e1f3f16af5809c94d2521019fec20effba80e06ebf265c8b3cd1bac124a0e2aff93185102a21d86aed537d7447f68660e92388f33002325687003f706cf055e9f357255dd006dfe4b6a406046790f8618f91026dd9b12eb3f0e6f52100b50f3f0c446f799a660db29130bc43414415b5caa54213c550afc19ab6d0b1ce01f4686fe59d73d2763f19e475c1e417650cf5f474f8cb0a406b1bf452fabf4155f3eea617582b8d81b50c9cb56aea3391100366723859232144a8da5534e08b2107907405d47674f65b1324d38f1eb88137f3cf8037c1944768ca4cb7158c934404453a356da64e22ab288ca430eb46f080bde690c90183b49619e8f52185c245df8690f1c208094246ce9661464722a04b5d5cd13e8daac22bb0ab26040e07803f5f5cc4d7292173cce308613ac31ce0bd5824d6f3ad5f07715c0314ca0a224091e823b20d64dec20f15ded76d228222c7d5da141c797a24384c9942377666737ac00a458d4d2a42798004d358ffffa09e426c91d431ed0696a96c021b84469338916f7701a6b8430675a5c4773df155a2a2903260f564927c88b394cf296532adf34935c954d5866e94bd4083135c274bb27a032ca9df53005b1d75200290364cb4ff319cdccbc1bb2ef2e0cbe50160ae1b3dc555a2494116ad30f6b52824e5c3c7a20041d5fac07b044cc428e9905240cb9bb3710f2e302847d6f3f12602248943a465f0c911d5c9f22a70921e17a3bdd67420b8c859f776298e86ae93174250b069a5fda3e945c27ae2648f116e96359f3240ab2b84055b908d1380e4ee25f8ed12d50de233f12ed106e26f93aaa10d4995132232e5403361c4638ee9be55c7459646cf6563050588fed2832723013140977646e709222e7c2e64a44d7a94c544a9558bfe3ff3ad0c3cf0e9cd92970501adb6e22d5460c0d50f81caeb6a657761daa1a9970c10b5ff7cc1ce3561140272ded19a4f0a21127da7b69f43aa745fb82b1608ddc6837077df542ac08030de20d9757c9f1e664976d4d385328a07290ef0165827ea402b5bcd24eb86e6916f8488663e8785d63e434a75dabca0d2eb1f8136e1a507f2437433f3246f2032694387d721e7b1914662dc5761ed3f030f0bd0f2f1cbdcc68e85ee74af508211f889954039d5a540feeb81f76eb42081a786a513085a5fd53e2d3a57793f4ac7732efc34542fae513b0ab56229401176f3410135b6865651744eaf62a6a7089384efdcc4869a6a3482fd64c700d1bdb1093afd872106286506a3e42201e750c41bfe35871bdcfe34d6a639d76be2a2c5703d6302e5add622100610c339519b172492c594245a14b4b4f30a66db3e30129191157489697572eab575e3029ba6c2f0a99372b1c79f009fd7cfd3e557ac60606607c1b17351e3e6a71df07009d7254cef657697bd1b03b59e36d65d52aca744349ac082ef1642185d6b9411dfdcb652957774ace5b9d724c0c8d1a7acb5f0eb16fb424bb49913b4f407260cf80177360c5c350bb08dc2d06395b628bfb6d075d7dec5827687e39e805df2e406f3a6728ca78499365254b04a3111c16201e46d205182388fed3450596db736bdb1a491522dd4b194c35748487624fbc3ccc13fcd4b41177fd1b1fda207107e3690669cffc9324d729fe51fae8714e0506535732259041e70f2660a798920eea70822b85464b3d05e8c8344c225f4b35aa146267e6bb07e09cba15c24275285948520d7eb4bc68b000f43b44860c5dcb216c46d92f434ae63c8b1ea52cc563cfc4a80d5161ac704d208c58016ae542ce92c62db72aee1980ea957671d34253680bcb315ae8f2423206d80ce1d4356134192f324d799638904dc63d7ff86c42d4da570a4446a117b2892e3ce022170885c87968bc899c58397efc56dded45536848bd10acb1152b63101d2ed830d10de8a59141fea06e2bccaa166979967f1281ea2d0a31f66202aa051f669114f343065e84372f5a470d05db8e672822453c0265fd2da94fb55b666f273a602159119f572927fcd29d0783a8bc56a0a7936be76e8f3e58ef295a660b7a216837e32c8d0e1349f80b4a29a383642a1b0fac28a9f4a14a9ae80b6bb97fad6a85d2ce65ec8c1510783aed68c7f12364fc7e580d5b42693ae0b61c5fba30cc5ac4ea0646bac33f442729df0355d114576e06a9089c21ec204983107693296773f58dd66f399ec823e284586009d0e504ae3e7a4025841838819e9902e2e6294466cd9d6894a947699d25e605e8c25a41e123b045cffcab6107a62f71116ed9284a0f106f88d9ae42ad3bb45f273e9914ea3fe4279e89260576646c3a963733332c308d13ac23fb1d183ffe4fd668ce4830a8d255d16eba042a6401363be18b30b8bde86b3137d92bc8211073996049296e07286b81d5691ad9d1a62548ce911261d6804ab462dc77e1160267ac966c1b551f4b2160b9b8266dc6de3f94bc4a215b6f1c691eb7f04c0d4b4275cce2e203df2d5f3c6d542764528a69418c40257000b43f29af6c0a65a46f4e23e10cb354a39f4a15af35e5079a3426473bfd822f084aca59353c5017f031ec14ff095301c7b7f2713238d31546b76f5c66e2063ebc04a95a051c7f6a604ae26d46f8120321fde511f9c5765870605a407a314f70aec0a7077b185d3c5029a92b0f9fe9191bceb0661604b1040f696643c2e753436e05253f6d6fa23a84ba783556f5272fcfcd134898f48113cb473c61081072633e3dfa4a276e6b2d041b1d0eede2af1a6e2fdd5b8b933e6f5b0c0b63be082004bdf6353cb1073d6728fe471e5e446b18e0e13644247d16281fa6ca23462ee56e19f69d2533446a10b90a1c08f55aab701c8fd624374e57292551ea07df36b617da0f826ec0afc966bc2c6a75589886600a9888645cb4684c0c44ca7003b5030fcf7ffc352310ba442ab4961c67e77765542ec364e340d06fc62b49571bc29d296e29bd6efce6a42a32d2e34ce7aa7649e533c97710eb7a62608eb34e281764280951ec286054190f6d07e4223e2d6306823d3b4e404b860b7b73686e7235192e03a1903540bc8677049efe2b04c5f12b787967380fe0382e7c20ce0890972126a6d45847b8848934a0b0675f9fc3d858e4a17b3e018bc8383c40446cf9d1de4fc9c6312981c88706c6c1c73058540256e60bce2865acf96ac4a3725907a8ed299e208639ff8de303bc8a3a1ce92a0d599e10f930da959a35ff0d1808767d0b087009ea25c461095510eb0d16df34480ba17b1662ed2fb739c61b8a2337cd900b411296393e87d42758d4156f9beb4c1218eb2c4fb73b08254c9caa354b7621264527fe3140020d056e9ab270897f0d4ba711c461909c341935fe34630d5f9a744694eb4e7d950734430a5521f8f1d06036fd5034dfa33a2721a67e4418849b3cbef43e36526efc05af4c416dc231c96668aa58298304b60ecda443386ecab025012bd33c6b312a567fcb2e610e626a5e4e307549947095024541ef634eac9508563edf241a3ba967116e1f00ec92cc6b59e19737f16f36184de9de27c1328826e3c594589387b84c916d3529d6d3336912e4b635d6db4b69bce43b191118546059b8eb67d3c76d31be8d44240e8f821579338a080b352d20f0731336271667413102ff32c3a0514ffc2dc4437b9e6c5add2b4a374d5502662d116046f9d8c82dd54e34226185f56010b2622e4385d835678cdd3c1936ff5ab3bbc75e68882a5ef2ce1e266872b605dfe296779ee9632134c8b6121a159803eb9564507358921b4e69426e512ad104c3006a28998aec39155e5c118db5ea6965409c20356d5775af5b6b63726b5d001178a11d0da9be5c839d2b3515003947f915f76c2a70795042ee6a36e6ca69441671140f87b6f96278bd462e62df38261489c31dff424b27985258687dff69458ae74754d14b2e33ff198427ca90da5b842df646d45b8b2c1e55101c3d1b7d341f45a5357c414566c7de363ab444f81e5a41ab2ba24366023b123c0aaf6d72249e3e2c3b289ac06f4b5fed72d07e2537c76d404f98ad1a33fbb1ee4a59e81f44d1a13d11d0695b6dd365526f3dbe536e50eb5e21f53b2d074724cd0b75383e04859af20901833f630d9f0b3ba58f2229bb7372612e89da5389b4a4002e91ce502ecdd3471efc5a35f7b75261af97b21bb98aec5274e9893f9cc8b56091d7f22ed111620026577a58c9edd8151b960d6438a2a23b9fc5cd5669ff1f5495069355762d5b13e44dd13b57775e2a4101ab574f0a7375bf19dd754a8a385fde77325a4ea3872f180ecb4b66a06b3adf88a33368211d396ae8ca52307b7d03141d2922fdc915705a687a6705c5b6728dcb5d1cf069c7062fef8c3a7c72ff4a2957130fec95ca651ba45f00ac24aa0448d0a85fe77e4010165c4b162efb6e51302a2242475b66323af429266e0fbd290249745c57bb3064a7c7f12d085dd30cff947d63063fe047953e142efeb20d0a290e452e26e69a34cd7776299dbb0810e3b0c5297d5fc8387d73195f514f3e5c224b6f26d82a3906982b33406a41ff6966814868b59e1c0c1389b92668ec5146ebd98a313d773550a6aaa71964666e086c89ad0bfc19b63f3a33cb4370d1b467011e6c6a1d1fc7162fa781636f625a1b2fe93f50fea4ea702785b30ba27a8c3740f21928c5714575861b9f68997eb87530bd611cf2ec4d3f2f2540559354750fe00fe261ad69827487c2523fb413ce6ba1c167683af39c07cdec984dd249c00334fcf25567c1e342b977b5728ba9471bc31e3741c481716dcd4efa0f5b519820622b14348e18824ebbb7264094bf17536f89ec11c822a45f6174b86c0f043153aadbe711a019bb3fe50c98692d1a38778ca53d2b5e395a348fc55c16ac30fe11a5a4a411eeb5d33fb77510428ffc6300d739db026023062a95ebb41361b65426219f8f73be2c45370db0603239c65667ca45fc37a4a8c865966f6814d160d22ffd071b671cf2232408100d25af1a075fe1887f30ea5481339cab4676a910071f87fb7f737127a431ba7d114dbdb3383eee945e48a4cf4b70d792415d7e936e570245cd744a0f1528c4bd291aadd52364ded2df08e5569a1d80e41541f5f0732f4eb9d04e8d74744933345b48b590042d117b6c6e5a3a111360733700a2070276b0126a1fc31a8006b65aae32a7056d33dde60109e55ccc53529d5455e787c34e5311f429d4423b4724e86270fe46585d44bdf113733c5b7597a847270266bf61570d74424f56fc6aa5a385460136ed0d66d05e2eb99eeb4df6e54455f96d922b1e2f3e540f30f435b44778414fa16b47b48a286d42fa5b613289a45d1cece10447abff449a6df5510d68b941d9ba850c35caa964d8aa451e2954a562a8796741cdbbc53c989753495aec513d06ceed1f1264714de153a906de739b2479dc78357780e26ea4228e01f6e669766a1b4706c5a14c16198acc6168b09d354086ee4b98d33f05a26ebe42c16a9a5866126d6f1d56942bac038d6db75cf87352a156088e09f039ac878e7207a03a39c357090db723ef2bdb5b187124ad885964d52348663fda149de6d7685d81ed0d3d683d6ddc9600245591c7140071fa27d7d64508091c2777e5cbcf199d9ccf2f599c1407d454806cd9dac801d67ec245f4e2597053be671684c2636163b85d761fbe1477d07ca6104803764cb6859a033f213f3384b1961a134bb3541639d90d81929b62b9272502d6e8514261a1b20e17b404176e80d02d624365464fbd242bf6be551a8c0d561b287e0b2ffb2daa5af7d1b75f3ffab633eb8b5a152afb2c5c502a3d13020f95274e432e337a66104a72b4cb722384b327a94fc3562e2dec53b25be529cf83223eadb9bb5d8e431a29f3367c67e294cd296d536a65b6d46e026f3f922780c3516fc93a525fd47f9a353cef1c6724dc79479f353e60655376532228ad6e5d704d6775d6ac11b2ca1f57bc8e9f2984c04c6ee23b9e3c11e4e6139e06f7522c1a4a1f591371064d4ac93ff5029274b24ddc2b5398d22f06ee6a45583be06ee923062190f6ce5104b64c32d13fa657710b8f3ac980e137308ad1489076e027442a3e2f0d04c12782b5af53c958872085cafc039d47064b589d87692e1d30161749372c35b1bc1174cb8b3c7d5a7010d39122155cc8d351603e3e62f88d5a5cc62c2c4f46bc1136eb50c7464142037332344554fd730a153e2720007903a167607f041df79d8301e7edde59426575052341cd45e85cd2305c98c440a7ef5a3f1f973269d204d906012378103e2f310536d0307586498c5d7281f906e9cd8505a5d4fa022df6696cf0d46263ae413748e4a95831012bfe4bb9d98d07ad34b6745d77593e5da82a5dcd0ba7574350826e453df6048f1b710b34c5f70b4c40a54bcbf20d71b97e631912c170096456ca3f07e4c70ef44d85438e0a5a5944df9c3c962a570c5bea9f1a58958c5561371d12c6b1a3341e2d563fe15aa90642cd7307c24e4d274c9be76554b7e855d97bf203c72ae75631a93564d414261acc380c0a2b392a494e1fbb677d31f564c39f691ff569212aea4e405b9d75c831ac7f5e459719d568b80bc830e1983531ef51dc1d4b9d834f31f5411ebb135a251b82f076f4cd94368b8d383b467a8e7581cdad4c4ee21564a9a25657e83a201cf42ff171ea7ad01738af4e274b151d43a1737d0b466cb54fcbdf6942b99c50466535cc368ae3533c538fe17641b20d5eb586e80b2aae97448aea30169a41914ac025263c35828e623b661b6c93bb740cbc4a034f7d3ef06ec5e8e94f224d2a0cf277cb6135d85358507e1c45f62f4967d35eb04befcbca05c9fe19192ca5f50b7d2a816ac7516d7578008661076e931fa0b5f31059da0f64ff264d340db6592b98d33061ec701b3cbfe8d5102dfb104523d14f727e99297086eeb9342c2c2b11e7afb5141773482781298e1ee6484c030646a210c7399547b7c8a022d4caec4c37591a7416e28747d3071536a7cf1337c052512d46f54e24f080d71b1403bd1a2b02c8433139431430d1034d59ea4d449b3bfb551c4bb93c1f1db6667be7894fc11123714ce0ae2c1063471989a7584141001b2789ffbf539de5350cbe30fe6990df1f003e53793914b4c90c2d7a811d7bf38435a86a703572a9fb3d60349731907fca43c3ce556ced944042c4b22d70d64fcd56f9fb9104cda9320a2b4c252b749d8305a5d75437a9db6e0efa48665eab673b11a84ea36af9b9c90d57a2581c43f4ca49ee602d19a7ca9f357fdfc1313f5bbf21cdfb0d74a724f5084d0470281b674701341dc4431a397e1e6d71be3bf0a91a7465d82c72fc7cbc0419083f1556e7c4647b68c97203f85630327b1d541f97f73be2be0506ce2d0f4e0cb2c47024b676521c1df7680874c1646a0e925ae61241304cfc221923e804594e130f2dcabf187225a46638b698fc4ae6d7122a72fb10109589b150ec15f657542b0376f5c89d76b1aaf3695b445564c504bd41ce225c555aa9353067b3ae71bd103d5dc6e60a3c373c040dd6cbee546ea0a573b77db8561660645137f61e24e52f580cea129d34f1ec3f5e416b24694e296f300d0a363b0670da71df4e0220c2235e2be95e4d106116cd02d9624e04c5b4af04586c3c25cc7fd51f8ca23433cc163d66bf38473a40727c37b199a0492bb8193f2e1ccc696eefa662aa28d6362fdfb8248be9623bec91d6384d093c4ada88f34800ffa153816eb83f4955eb77634df8570521463309b9220fa199f7141d59c10d91ce333c4502233db271f049cc7ca9315ad02973058d776b7f6c626c23f01575572794385d05fd1307c69321aca0fd372b3a7f1ea8155228654c9e35c9ced030a0dfc875a8eebe0ada140e355964c1717d570c0abc5c0d0fa3e39b1576f2194848b4146864d5b431a005415a07f7832fc930f076fe4cb655d6cac34577087c581d45a65078ea6d4569593c135065cf3e73dcee058ab3a620957b4b08e8e7ba179786d868f047db55fdb761742a57051a38baca30403f157453302b075a660530c59619726ffc635ace9f36437fa3be0c0ede9f65034da429f068c35e8238ca6415ea31480e3c185cd2a381779fb0fe6b432dba3a5a4a9c495713875ede6d824bd3c64c38dcf7a32b355edd1df5adb474e978b7190a3fd7604812fb020368535b94d13531df8481729b3e3d71ffaec560095c9c493d9b0968eed1b5130b5ffe410b2d9650e7dc8c3083f2e41cb962666b3132664bd3741d72475be7467f0077344582103dd5d90e2e25d08b598edea81105be3161bf97b4093e8e7439d210a847e112885d296c7b2144a9b92a13224e3ce4808e51b7e650207453bf166d75ce5c72d9655379c3c11baa2a922aefcd720906732a26efc66448b3f43d71ee131122328f732f37f1720d4fa6175affd8835716a5490ec848de16c030b0752f67f72d1c49754e05e5f72cfbbfd871fbeeb609f237f6726a073d142d30100e206bb61861d26b06a11faa4a2a794433b718f46861fc092ab726574ce90efd477174cc5f0d4964139c810c3c07357f50d2703c6de96b745e2725ca7145f81517b77e0516ab421b54b3c08d6801fd3c38710aeb437c89873855ed905edb9e6325a2f88d6517b0bf362b624740587de25d8e481b1862a99e2b44aa7c4150753f2d22ffa240d920b52eaf10460a7180201aa1a60e222dea60306cec081098e1a00d639b3a3c5442314810803e1329cbdb2cb5d6094f0c294f48d230a3550022914214dbb92d7b0c4649e03dd66429724f58f115ad0c37755204a20e9c42890af730fb0ffe2b4f113c1ea2469972d52ed61d1e8523241214f51f4544893a8dc9321c36c7405d3d67f35e87cf9d53ee8a5b10c6b78468d3728e208fb8a35128dbc53d84001800470b65765b42861a856f700977666118f2f68d1a9dbc3e121f883b7744915056233b526ab1cacd153cd7425deaa359512b0eac22ae5cc3759611bc1f5adcb769fd6b4e515a6e4250e4f6b95782efd80655be647158b03555b843d7514d46cc09f06c6c37354a2007dd9afe3d9fb86302914512688b941105e3cc6528f3d84922916ee614ae5a2634266696522cdc4956d555a641a1a82d0518b38941156b6d3f3d8a5817c85a436e8e27f370c653ce1b7d661805ca05ff0d26c062748d3720402f845344dac0d46e3d8aca01bbc10134d25e70775a97282e1379476be0edfb516a33cf1ff0300a55f2d92332113551301756e6110e3cbb445d85ee3b226e3e6a4ff89c19a82f9a2cf2288541a1abc94c26a51c4b4738b17205ceca456b0ea873dffce276c98052204550db702d981225d0d63c2a9a3d615f6efb432ba086934cd57ddc11c6462e16d79f5f2c20596a153e9187765f032c2b0b5bfd67ea5b8630f2a2aa1976532b49dc4df52f2afa255d719d9d2381ac4d474f3fd25e34836b6386f446557c6b41504db3dd08873c702d76be312a451407774dc29a53c2dbb90b4d6e745e6c68f13f45cf652f0cd6010fd21c7b77365baa51e4781b2b5bfb4e4210ff2608f9e42f2f198b4235cb1504710011af24e9c92534a330e559f9a0db034f20c412d0c7c6498d674d150643166b0175e240c4dc1f67d7b9c36a75e2b56194c82b4feb49651a84d48869ae03a52cb2357044864337600883d475922708650171f303c44f6e5f6b35e44b0a5a4f2ed92d6f76ea4e156e41b4f5277ba5571b28135631e811d24424ac312da374b603ebd1a822ddb74d7776c1f21ed0c78c6a496d821e2b830a062bc26473794d771dd914106bd95cf85d19f9f2117a25ce2b50ced22fa9ef3106f0e72911b3655710631e2c76ccf10f5bc08dbf162051725e7cddc3169aec46247a19535ab03df11044e1f73435efad6d9e10f84e4bab79481c4c36452eb4cc5137563436e569640c18d9786d3828a53aca3bda1f54be6247e18c1966f0af12348b32d7263fec4a364bf62f265ccf8373a43a2a06c85f67678a71b848d7a199661bd10e38e9902d458f54041629659049130ff826c4c7a1534f9872278daae9330e16256929ef9866820268356cd1a11646bab52170836365332d573f3a488073d25e0746c5d66c12a6ef4b1da10d9d77c23fdf3dc36c066c650cd9240928e61aad243f6efc9366774b71c52a1d1bd6588c830965594f547087a7cd025b66c521caf9b44e4ff7614d1edd370b7ecca25db54be34e7d5f014bde48b337548f8c21f4520d20dd3ea45aec5c4513584ee15f4f81cd374e0da630390bd25ae1c80b24535b2d1957af9c6a347d6a3aebc84f2ef6da7559cc73ba0f917ff243f1ec1957d827f45a7266f172c08a4e4467d3541c3ec4f1037557a80f9e7dbd48905c7b5cdaef155a20bfd62ae2c6460725b0944b0a380f05e5e2c03d607189176be0b1673ee77013d70b3855c48d3e55d3fa7a47e340cd6c9abd52463176dc41986fda2738fd04642957680cbd092770aa609b5fbe488b3b2ed6e33aa3c6c60e20b6715918fa382352b103284cb9a83d5f39e65ad8722b5374c1a10953c65550b97bd036c2fe67286ac80e000a6ed96c43366c34ce7ed85ceddfe603cd736a5ce0ec662ddf0b504a83454d574acf4c17b027fb333d767d37748974760a3adf15d62d9a23c259de6e0822e82b2a714f4330c67021061a5b01f837fa73424e0175ba71701ae9cc4d57e6f28a5f3c5cae32b28d9e4c443e6c0534e1b1426e943067b1479a3bb3168f37470d1424e1536209ccb872417035bd318e5aa764d2d011505ce5331948b15401cee6da27a874fa0f6bca6d3d9d425e4982b12d1d6423356c79ec8b1e3e44e668c104255c92bb0e40116f8101d9f6ed16b9dd75615b916b6de75b0931be16a5360edd3045064b365d3ffe4b1cf23d0e09930003244fa31825879aa41f31aace252b3cf02ca80a7616c2d7d068c8d1c63aa337b74dae03f954e3850d12aef5b51d6912e663ffeb312d50ba966811cf1c2b93935a7671fbca37d282ef6197a06412fcdb3474da19fe55319f57742d094c518462e52d969b201d308e766a023ff31c0d1d32539ff984741d8a8d188eef077491687f3e94d4032c4d3ab540a3a6e75fdb7e19047a7ea2339c5b556292a9f4071954f66d52c7c63c2c14c34b2e08180bd48b62630fc04d3e3332172a6f1f8b22c7370b040d73bb6cc5f6ba1d6b86596b724dbb49761af073cfeda369ee08230fafaf243b71484f28cfcfc5255117692fa6d2f321dc3aab3bd83df624aecd2f2175da741f57152547d5a02315fdf80d378efd0d6af68a7b66b9c0c26705c42450843d5720ac56d139fa92657366f5446deda1e15c4e641f0f9303903a6ad1333e06cb422574da020c13449d77e2d2d6173abc63554127344ae2e67d01107db110000d922b04f8f56564173038e4dbca212946803a48cf2a0a6de3ce424d70d4658f6ca634c0d2825f09f0db6a64cab94e981a7f13f2dc1c4e99960c1e5364861fceb99528e09d7a57629e8e2d5367501fe4032459fb52723097ff647742da070e7e514554baa3df66f3285615a7dc6d59d350e21ca1e4c769990e7a7784ab1c2fe5718b2e5d545e09a319f738cdb93033acdd0f2b456db92e6586774d622d874ae7c884679330ec1036af1311b7f422214bb58b23313f911b7008fb123a83f62a3253f2608ca2be5659a6da17f0c69953341216004befe41879cb6e3508a9871443873a53041f7b2f9db29345754097127d028f18c611701781527d118d58fa52021a06586dca143625ee1f5dfdcfbd0b388254173686241f11cec266086d081f8e7cd214df21964367f56d6a2ccc85274dc74108fa183514cd56cb62e64049477a2df270c884512a293cf34c6cfd9240244ede692164e721fd5fca69e1391801e18b2f5575f7204cd43f785032dc1e0b4d84a30d6d38cb38962a4869517378572a354862600bbd5d2c760f197e424d62c5eb9f123609a3081fec5a63a33a3040ce11ef6e067fbe0cdd2a007605a0b7255ee77d2cd07339664f32ea03fc098b4c7c557b727d28cb55c4b39e235df00d4d5d654253163e64034eb4113e2420fe677f9c966a05a01e039f6cf16833d14353fb422b1a6fa15e72fa62e53fdfce551b5af0de0a787f90186615664e15af746a44732d52095c5f6d1cccbc09134dc54394b6174f6a234313e6312f4de225f86ababdab209472860b3aee545dc964c117a90ca912dca927536f8cae5b4b7da542e112880c40929040adecb25ded016a0c8dac662a5b369c10c2408e68d978dd63348ad832337c7914f927b3075f5e0f1c32878f56e4335366b899eb3546f3dc2ecc91275c00e8b91c8ba18770dd4f9a47890e4451ad50a82dc4f24d37205c86363f5ed81842e2180905466a195c720c5625042d72c3347f27ad5dae5d20c1b1024381294b662a3477f2535c320a07fd116fca166a2587c42baff5312d03458e5587114c0e319b820eba228201d5e53b302e354e5510b49f69f86f781162c484737e89e72cdae33c52fc1c4254ca60d8269086783e41915a574a00eb6ad17d30058bb388061e2b9d0365145f69a32d541966024a65a0d658709c929468eb2c2715fdcede23edb4e0298281ed0e16392e3b4d40591862deda5c54026c240df48263c93d0b154ec1b43e22eb700cdb50364205f7c86f697b1a022637184a1047a929e9fc944e7d505d4727d6a82d65f5d76c32abb825fe867621abbc3519e246a525f1bb2e25069aa01cd1ca0a047383f82e60a9ac4fd4b272443ef9290ca5274e6ea467617304b253386e7024157a51f8288879e84335108359c714ef68d0146d2d5291dd3266cbdf372a99f96c33b8033e0923574d7bed13676269d33cc1253c475a225d4405864e16ac2ec25654abae356799a4081a017a188c58c8582cc9a83f8a23763891568e59037ca61b5210ed2cd9be5317db7a294102804726b63d001153ad951f73263538b010a80750968c1490cfa632b9edf0760c4b303493d7b6636955d52d4562d24c9e40df31b25bff471200b20d01ee753437cbbd1f3d2f33360d44b9450bb3d3692db2c2660035144db5eb0443f988f038eddd5371c346e868c1680559f60461527e019a6764a677078028a96c02687c060b127f3493407b014f2eab644d04ab1ec99b3302a8ff662817e7465963742f14ce3cd028fc17243f45703f4d4620db34baa11c31ae0e7d0cc66cf15f3cf34c693fd3333a6467df3ceb0ad412a94a8c3f71996119b0b952230348226e6c9b2b0389901a3de9463446e8d9c2491fc4631d6000c36180c8695c53e1f6337ca84404ff6abe73c3f0bc3a165b4643c7b12770115cb851c16f7b38993e5d3958835b541352f772ec81fb46bc92906f108ed0023e26ec36b990e3675d9105557608c522a2d7e833bf31af34ae198628b595ce12693dc1106f3adf330276cf55ccbb7556cc1d5b18c943472913e344127334c844d3fc6b42ad881925867d4922dfbfa203818163701adfea0352e2ac441188be2176fdb8637b8d5307b7d426141b2d5a002e70193c2625ed527773ff764c7ece3924a387704ae16b4a80850330070ccd11b9cfcd1b1a1460731248d815ccdec5010af9ec19e19d575bc878704c1d848f103f87e64891658611a9f6dd6e3f49004f567b3c6ea0a31711c90caf0e4a78210b2157ed33c467ea09fdce75664bd93a412cf56c19681c26018473a5053b4b623ba508c54711d9b9624adc98555de4f915f08f6914addcd0366ad06f2d0532fe53ebc6a1051366e160fcc13d6c9577bc3c7475ad5eca473202c491b535967ddf0a37d00c56488c6f2aba009b188187061c151725628ec0735b2e20d817b5cc654d63a5f370d72eaf038207932c1d0d140d6f419a6ac6140c75d8c02074e9b46f65eda4cc32a49486609ffbac2516c05172470a564841a0c549712dce3d153a725210e7c62f6917ca618b6ef442a9e72a3a517d2e6f73eabb1eba9be210e78deb564ec3953b1fd27606afdf84385e692955a2b8fa286993c212e600325805f52614ff3e8d2f738a6c420f6d2351bb2c4259aaf4695d6ff3f764813738116dd7f07193fced186421d663fb67a65c5199cd745a22ec3938b27c1dac9f4c524800531e50e4323707283126ce50d225ded2d316a1dbb7389f21bd1e3118861aa2c5471c239ec2533e1e4527c4ca296c80e94f07290f130c9acb4e3cf7bfcc6493a8c42265640043c48e6011f7ecfa2c3694ee24f3266f4a48a0611a10b4cd384af9fe213e779a0378f28e18219c8f2021fd3f42d642ae2b7fc871082668d12bf81d3c3d965e84139d4c950415819577dc975552ec8381472db3ad58b891e54fb7bcd650f0ead163ff81cc34da8beb3125214d70dcf4785dd514b80437ba6e20aaaee646bdb0c1614b2805500f28aa49dffa30284193da03d85ff95ea0373003bbb20e1a2f928b6530b3903b32f42c3f9745fe388620273b6ec40d052cb69014404e451ecbb5e443d53ad91705b8ce411edc194af1de3054786a091ed13f6b272572b751200a043ec6d59554ebc2253466c86e6c4e1ea031a5c9b021d6d2695a6d327736db0abe08f75311330f00e507df8d810d5cbfb56ef50a696fde20b03cd44c5b27e279210dc32f006624e1a82a3939b30f1d63ac6d8074a906a1310e5ed5835001a8c95c3ca4a75923821fda1cf6d19357133881028cd1f02dd5273467f485d569e577c21b6ee47b2d1bca2432517c2f42368d3c49a04aaa045c34d45ef1ac5e224e4bbe1a3e086c34d6f81463f22a71516431416a687b91109109600296d9f35266273547dd6ed50ff2e8741948c7f14b89738402f85f24316e3ee7318a640517ef71762f7bacf16134f73449605e6118ccd82f60347ba41efd17d60f91a99b21646c2036ed161550b8a12f1da05d115f9877392872168948f7fd9f20e15af566af6dd938a41c2e3ab82e4d0383d75a00fb918d1400c10974fdf7564c885cb53e5faddd4fba0e0526c637e2049342d60ec9049901c95e8761a60d9a600f62064c396a6700742f7c6e00207a2c54f7796fba797e59b5778749435b534a7f41136cba7680454f09535da161620fc61b4b10c9a7725cab011a053b4e1d6b3cda6967e322e3038861f25ac374574d4cd13e4087cf3459ea882e1f2ad1521243206147247af816cf977962c26ca342816c53450522a80fff733b3dae338a11a3eb1011308c486dd5be2874b843a02cdc66fb6cdf3e4e4804b85929605a203e595d120f333c3d1929ab663d90d0b74fe1ac2303cc671f04e2183c4196796f276eac5d0660f96240f519f405618e7f349ae0c356a1a0e258b3faea6fcc49cb3b12cc8275168fb80d681ff46a2feebb6c7c41363effdb310cc1eaa369ed91650a4a8e8a55eebe0661a3f2b6521c00376bb5009a633ee2b52cf0e5d503e24877422590c768e903d507f1659e172625fa385d8fa173882b1d56be4aa2023803836076efb4424e71e6254fe3071f044f1e29bafe035966952d4255c4c03b372d7a25d0450445ca373d3bab375a05a31eda2e38d47d6e15c8c1017c8269274f56122aae39db508a72542d1aaffd4a8d195955c9b60a62caa18643f1f7434f26961e3be9c7b564e01e234db438de344545df5f4712180bcb2dce4ec253171c341737291ac93823aa8e3d554b339a6315c3865e4ea70f1827697a05a014cd64d7e1276ee324131caa9585442de7126c35106f444154f55b49b259738d07407426de851ed04a08769c6d0854ba1b2613be8fe86fa4102b3d97f15326fb21863e78f04b2732002d5bfe27cb4e94db272e77e2de6f0850666ce06a8c62550403005bc3001d646b831a9865632102fc2e3c738ebc6df8c87e27c285400ddde5715e6f6dcf46392ed2133d1b6450e9ee426f74f4e61ecc896c5f026ba956b815244e36cc2a1ad94e1936054f263873b6c21e775c157012209432e42fc004c21a845fb67fa5117aeed33239c1c92c03138e6fcdb8917541f1e61f49c32f6b92b6f42999fb662c6648b22cb0df762fdd4d6f3316b20f063995125d40a22176f47e8c62c38fad77209471562fc1631714ff5c24a34e8c2c439579248c8d974bd7d4d207bc2eb3037f90b84923633d5be177f613b8c8606bfaa5785cf09fc05bc0d9814eccb2b06e0777335017af5b04f6a93307a0c6842dfdf3281822ea2e52f5c1067681d7f04a6b7566042df1fa1e4a1cea256c1585680abe1d3fa032032936838518fa6e2b452c31370feddcee6d08f36367778eca6f60aa261e7890cd5beb9d313dc9cd03591b53d52df2dd0461da95a572ebe73e2447cb442d28e57655a98cd55599651505d559a608b5b5285b5288236e0d13f33c2c6e5b611123fd22799c3142e5130419202b0f0a1f9d10746f133950ed630611f9bae3386c28071a7269600c0482c230a0f8ac2231a9c738ffa95f5c5e3b2e017382f524dc9e8f755deb74706737993b00242c029774244a6d2f293f58f2975afc476e1fb255f11443dfb60bbd2b50000f42fb5a6f403c7114c8fe67f4a0da418ddba61e6f13b50519f63c3821004d01e9c32e66a20ba107175c46277edc5e56684e9757d8322b1663dd3825b65a113e7266b676245ecc0461da4219c0d0d749501f027472a809675e049a754267487044a0e56c47204834f48bb143c88a03262f22082b60b01f4a8fcdde699be67b6e8d6dd84dd0ae2151ee7ff11478cd9b22c933016366b27e2c95aad465dfe8233b6b81193c15add252c6bb1b0f03af270885646c5b66e96c37d63eed59286e7f6ad15bb9762be53e20adc6a90a493120180765362dafcffd3b6b08f548a692dc764076d51f7edcc35f5050954ef0dda96f81a7503367e15f21d1f1f9011e6e060257327557d63b330089afee493bef0d3ccd44b726d50a561947e4587011e5c140b5198b5a4f92ea36b5ae5632d553e1511b489c04465e0826c182d6651e3ece3d318b554446db2a4fe1c6b835d9afa339a466f3144f9ccb718c060e35aa1633497229c34b233586086ee86400daa7f42b38dac83b349ef06aa17dcc619f545a28ec48ea07701949497f20ff76f915ed5d01a6476ed050b5546348145c40c5d30c95937d5fc41c0b17140da121dff3e92f38654b401fd2f750bbdcf8425d1a5510272b08200aa7ee167469c5349a29a93b8c498858c0d2c41a1bb62e2b8027ca2a38838757e5b2b53ec1a43225abca5703c248862578d2ae17213dce60c1690e175bbaa757912fd632f41de24f414ad667c8d3b82ab663d011d7cee17263bdf40512f8531b63daeb112b7982668316765e86d8893666ce5d5be826de2c1b9ef616bc74b8540c51ce06b508f85d6ff56b173a23875c68fbc8032fa91f054ecfd03cbf76d811e7c00c1e17921e0da1965a1a80b8b30c93e5326001521b0a51c17b59d6e28149d1ce6f642b26563cef0bc65ceb8ad460d5872f15577bcb6152b6943c06d9710c48d1786d328dc74d4c76126afd18bf22a3277c3c247db51ddfbf7b643fddcf0fd003ef0c442d5932c7267816257e7b04e60a0c0e8e8f67435fa748331a91dc0991e8695790937023a5fd651a5a4a9e308a918c4adf18916fa80b5f3cf405fa5da4b3700bc1764609aab0fc17737f46132e61b2681a97115c7a240d0ddc9cb368831e6b049cee775e51d927548a6aaa4be345284a97369437c87bef3129d149570e84fc65d197e03f8b38765cc0421439c9805a0483e7ce05a1ee3f311533f30db5064203d7265363f61ab061f9fe5e3e34906470ba611155330cc61fd32a52227d7bc6686bbc6722c2017d2a9838155474aaa2184850ec47bc9f4173215f4334c0023106d2af8a5cb4214f57c77a1a651ff9f40d5eb8792fb96187024ae4de072747d03f1ac626269e0a4754c6497925ef6f552ddbf0154574972c20566dc43d2d961114696564114cf5b02cf030296d67031245e2ca9b0227216c2fba5b8028dd669a4f136db40cac53291bad8e410c3174fe70353a7a2a51d48f0a7790cf2905cb4c4c5a3cda00cbe6415977857a6937a4fa48f3f2111f189e793dbb18444cc5de903f2ca4a40310783e27ba2aad280f2390040843ca22c8ac6112964ba758608d3070df1beb2bbd87c75c6265ab5891847053ac957924a5478331a7fba55320d28a2f2564a3195c350175fc496625479e41465e93526f9b199b2496a00d1cbabf125d26bfab0f2070fa325293e40d0c7a673fcc211306730c8322766a7a4c91062c3185e69c538a210e2c555f926fdb125c3a485eb61329a78c0eabaf4e4a6d911231692ae958a4aa925fb446ef60ae362e11d535d974df9bc126cfce184ef416d0764deafd31b23f02135791b250e7215774b8fd4f68e095c6241e7b461191ceb251009cc107e1f0ca0c8400d120bed10c2e4240f44526255339600f9773adbbc04fb767894f0e75fa26a286b66f24f6885e5b7a18723ce76151ffc5261595fec11fa11cb038ae295f4efc94fd2993fa7d298573f61175bd716bf1608152ad5aa94182e19f4761693239e0fcfa428baed522e2d9fb133e3eb034b2a8994fc8df801a08699335c3f61c065626b811f7dc7350061e304936775e430db61e4d5cd35e04180b1b469905ae443ef17d259319c5236f92d639d434844c6ef8306028efee6141f5483205e362416375cc1c135085542a22210d07ddb01b4161ef17fb4cdb1c4c1bf041515e5a4782abf36eed33fa140fd29f6e9656a43b16fe272b7e9c915816174a2148c2211bb9f818730d108346b1c2711f0766dd40da5f59749cfa942919873705e513ec494c9253339f6ee34b859ad92efbc8071faaeed0736aa11a776110f654329748384114756c9d7023107cc5976ce52d74491533df5dc511a51cd03b1939b96706127da0522214f5e1051f450c419b91895fc0278658cc62b73f70785771e50b4a4e01af034d2948c1123df94a75769de7362687cc6ee8e8de1dc74c23394ebbfb0cf20b8326a95fa8738fb62443ec82285ab725c46da466f93e04a915103cb5ce24d6a29d371fec116deeadb157101e56175abd933276d3461a69593b3b7df872089291f707d0f3a777cbc013641383ed3be1143354ef9e564fa919b62def44956e03398824a5fa383e2a87163419bf6169ca846d338f4a873fc02cb927d9df777086a9d16e7862a039204cfa6108a6bb5952acbb19ab505a72479e0c5cfc48c177e3db1d6c42745b5a10d5875b09604940a08239186ede984a6bee994a6a6c2c1060177a253bc38a04384747678c3deb0771f6f902646d2052b678274daaff816bc50f4c3120ff8331d9be0d13b3d8744f614c8b54e9a01038a91e2d2da9a84e35215c3a4cb56d5c653495af6f36402839abc5e86243407745c456841215b27d769e8dd431555da400994d1a1c3363b15ed70e6f26b083803a6e8e09671432ca1c4a670a6026352069495629026034985f3d0006433a08473ad23a565c1f84c70f20850012df5f364b955bce58dafaa517e891646d0ace0d3b0e9b66706c4d1f012b778942ffa63d250124f970a8832e37d9bd70126c87ce3d8a6d3a1d6b9f0a6d07819547f172bd15112a6c5fa401833d7040c156a87b242d4610d258f4a5497434fc4c668fbc061238e53d0413916125613657494fdf1b201193a23dcc00205d27faef6d067af365de77e201e9cb521464871d578d1a922e9b88a01bd99bb96e493a182a2904a4513ebafd3cf5e9426d468060488341b21e52a4fe3abe93a04e20a02b1ef1c7cd7645b04c3785b35814bc18106cbf1d8528b521571f249c1b3c899135259498971a5959bb122367434d10b25a7780d9e3657db1913f58308e069637dc377cb1c91847e9c2317afdd34312802739969e5f29b4a77c6ce861852c33ab0d534af09f1752b9b122828d2b16284b0a037d2f71013bf027752ffa4d0ba33337464dfd8c28f90017007594d61a6aefe802946c491cccdf22463cb6ce6de4058154e431f86df209075c969a9967b2d96934159abe03d897366c734c242f2757bf1b906d7f2899f52e5bbd7f344762854b1fd5ba8b275031f65d7382df1bb187f00f4b842434528ab8477555fe731c4aa65147ab0073dac83656ec3edd28955b3a30890c2b2b3866365b4b1c65162fcf0c5fd963b64bcb6dd4545b1dce040edb1756a1837e0015cbf731a45ac063d3b50f2682b5fa21a136de686c651b7544ccef4aa684640c9862072bf0397e52e2888817a10b906f361fe00e7a9e071d163bca729294b2084888806eb00a45712df26402de3dc70e403e6a03f00b96596ec9992a67833e25bc22a0006188e37088053b1b95f831612e95482f33dbac209d82c63890a6454559b0dc4ecd5f9a0abcf5902c6e181f573a48370bc9488a04df323f045936be2f50984f3141408d3bdf30b7581b0ea21777a80b6d3647244a9761073c137af54695b23047dc0e7422bd1a134517214e36179689279af36341f5976870641ae45ccf7bdf513b3c9b09f90be42566163676c7aa0460798b240463425a163569e13615812647854e0b3bc302304ecebf9a518fccaf6792bab6569a3eef5e4b0cca0f54fcef1e561eee1828eb450a1351f65d006cae6d3bb2b72d6cbe52501a22fd11cfe86c3cd8d40344f0613f50114fc72f44b07d2a733eef46da006d3b8576ad055deed50654157611d2c51668f2072a0a47c71761ac21eb4d0d7c0e29f914ea1cfbddec70ac6e5c37e6684f472f296804f88914727b28bf090a554a51c3da67280e40f4512f87313ddc4365193168330b881b91261a2ead5d091be337f7cdd03dee84d72f25013419f25c0728a314c93f15300870e0f52b3d2ab609512852b06fe5c6084a10be333d93b9785857c98422597bb85417930f648cc722589ea4054bda935121411eb11f3c93101ae5ccfd2417262138aa35f134384686775b9c605fb6cb150e466ac358b19edd36e11baa05ae574456581db5762a29d30f2db37927dafc00269c5dd30e0ec67e09de9e183f263871507ca7e75a840d4d766ddcde311ce38a59eeb3085df498d65a329dd60241312d258c2a74058a2cdc636546993eb215ac38c50942365b4f3660d7cdf609eb5d6b3dab38722b8192246af744ee0d5ae8f927e976496d60ad2a5bad925e1e74754254323db31a1030951736d7863fcade637405ec33455d2c0e403efb061f633e727444fd1129da0e2b5a6288e11a97a6810a09328c0b1988fc16fc46e165b36ec13aee627b739f766166db11c950d761c75dd9356a34117f8053b1d5c742d6b2444120b8991720f2e80eecb8995fefe14d6ba90e28313b26b70e5881b94dbef9375e530a366ff3c6ad3e99e7ee54e0248d02fe777f4d5524623614611606d0d80702a7c0c71d35b17019eb01000940f27b439b4849434f40eb2574a1d631282d7a3a989d6f0c8c698b1b48d7d4341b84b37566e9934b3af6215c16564648277d4c4cce180332cc730765009def3bf25c0d240a114b5387dc0d56cf10e121174a3a6653fdf915ec3cea634b8c42182c5fa547ad7d0542ab97436b213f0d08757db472c4fc514c99f8ed0492f9ff294013b5259f4553461eb8d9732f34b41fe395ae6fcf421d21e9e7aa05f4c56c4f8b3e536e38fdce2f082b21559fe9e800d1e76c05ad23542ef2552030c0909c389040ab6fd612f9772c6502523b61c73271531b4e98c44d11dac3fd3f8c963f773719f66acd085e1e19ad44749b64c75e5dcbc6674798054e903edd2f9fc3bc3cac38e619d253366c4a8bd33d450a3b17ebee4a53c985b65da09abe0979cbec3ab3c61e6a0f58aa51c5113d2ed05c436e3059d22e11bdc123660c605be102e02e56ae742eec458e216963444ff2d0ea2c9f2f274888f87d7444616f08da4dd06508b4ea4280959a2d02480767abe3195d0c6fe072c6b7622eb195a74d31a70c4d2142045566be9f5f6dd56825af73ae48d63dd43c2115ff37018b8912033b1a4d6643dc287871322562d9ef3948194d0f9c0b8722e8354e5182a24f32d6890c4d3a2443386c887439a807065438af946a9d521c4dcf060375e4879559b1ceed0ac26fb4295b0dbc60e995053aa5348e2013288011e97fc6768e560c07a0a9182af295c3325b0e043720f8fa4414a15566564cc002c7bd8911c4f3a513708bbc1df60fcc019835385840632f28cd873255c5186e1fed394129381a7e6954addb0a7fd79823e2a628647521f166746ce10506d2c92828f2c03ae19f5a1d11584755766531669485c52ec737830d3df2ff3b69287452c6a5716a73d22f76d53b982af38e716c807c542d375a97717620715281ee7d37c29c86399e8e12718db8c93be2dabb35d8319b0e52a2f74a5a589037dc156475f35f18611beca812305e0739acfe506333bc2861687aa307d2390845e1d65377b62e7031272b581ee176bd30881f3d6b1f98994e5139151909f14f587b519527a5dc1b253ed67969e154593a34f61b342a6e3e634755e63436445a408c193469581b672839baa858b7cad11cbc22d61d6089be07f29fe97640fc19135de38a1c7396591783e338608212cc7647bf25590554403fb4d0d2561f51375374ce3a0b6d319755e2b3504191740513ae418f55d4d7d75b6734cf4c7282e155342b0b6862d76b60dcbf29518077e9736472a46e7e1e3600d5067328c952315b8f4ea5750fb53206fe35141e3d502508acf8342dd54d31573104ad21cc3bd76a1bb73d2bcc10a705d90f2516cff3c9489fd9196efe344712753d7073c1a48f606cad86434773983678d75b07302a5d7452a15034ad493538e576b0169a422e4d3bd27820cfdddc63b9412d37d97761602c4f894dea62094f38c22357c97e881a30491d4ddb61dd0a89ef60247892bd26d8c8b830f173613b8c4225743ed06c63eac73a03f3a4d414390f3e43a8119a36b8a41c3af7b0c32d68b3ed31de3aad02e46ea53ead11553493542430a860fb6b8f941a068f745031cf3b6a02dd9c96679439f258e4918e30394a4257a6d51233e58f9e674bc67e0b1c98ca275c1d191d8a9596060a1a9651f539981ee2c5697571933e46c03abb02f7c2bb37cc92847185eca901392f6b57eb76e8456456483599c0d918839c4a16d3b39f239dcfab1a021e3d35812dea0965e4f215c85faa5d4bc08f2033e96330ef25246412973c36b741c3761bc5024b47e85b595af15521a8b329113186f74629594263b6391b047dbea2689c72da520c5d3a2b3d3d86449a9c5714739a96169bfc6442d4c88703d0c08b31ded3e1237453392194bf9370486eca312cf5153b3c9c2677fa469a2c6f3e110c66310a417dd9c275f3ff7b762ea9ff268ecdf81a5a97c723f6cd05234f4c570f63d6c3362ed04957e6f28918400d862b133f3b33c2581a473604a16aafeb033df0fc6f114978287715c2c0072880452b349c5d576f08485b6ae32a3db6a91d27cd5de74bc62e374230fc8f4c3d94bb23ac731902e9f53373bf56d958e9b1a44bebc8e82b268ea36f8eff4b40d1dbc657e654c70d17590954977b9b44e79936536ba9384902484f3fa055b83c3b11540c8128ca0bdca28a70ae045e3b6023b7488ef6245092da7c355d7e0358a793dd4fea54ce20afd20036fa42f76076e1cb67b527f1722c9c1949b540374f15504035ecc89443f1406947a007ba58949a0b14336e4164b84c1163e46d5f7402b48646f8cbf06d5822ed3838e861758917243325ad4b0996b4516885d53d454cfad855a48182059c49b568950092217f8b7f6d0bb4c127203ffd1d7615e2707be3012cd9476d7313d0ce31854ca6727f04071e5f18852f2107a741b31f636b28a0e515c3e235755944905b6554fd5e41dca95b049a4d5e28934d0c45762b65be6ab263b159e85c8f64635c4ef1e149cf5410740ec36627d5ae8500c5f56461e40abc2e909b781d63c355192a5e122e27017d72a0a4933e455b724e3c31595fb575f7323f620f06c556a04adc39f4237bf6b33201a59d743380b7307474445fcf8fb7338903d05ff896065a8970dd6782a96f682cefde04f713d0074646156e15633f2ac1f9ce253a64ee030c5aec554160775fa236a8075a3ca81323a2c31969c4700e0cb0d05b8a3387406511b83b46feb8122c783104472d046867bb1569821c0a6af718731355ee016a4acb6b0b3089703cd43bed6dcaac6d4996fcda46da0378124d229e2324606b2b0e90f0269059ae4de9ce3867e711e401d734d230ab940360ed7183526b94c51e64ac6d4e7c713a36ae99f103d4e4c540f37d4006ac98bb1516080d0a0294bc528b5aec213483215f77c9d6702daf8d167d43293484fc5218353fcc0935e1575421d38a003c6e571b5960d161c8b24630f175de2694721428afe8e3515ac5546feb16cf0059a0ee3cc0490b3852587d556f8a032a42e5714bda749e07443cb17094601844e7a25e473502991c4b609e4b1d96cf2e0435d259d3abd841807c95373ca5f714dbb2413876fd524863cc1a0d02faa75e56f30930c48c2b0160831156d2cd74722aecce14b312b9471913b75896d17c18cecf512ef8482d48767640686cf7b13b6c9c2625b0646e2260a1e169e6f53c1008bc0501f1ed49726eb08742d8cae5131e48976330034f64fba0775ad9dc8919831365173ade384ea4e3ee5fedb3546ee0a0dc710ca6673cb896e338289a761dd38b7c187a308b58b6cdc413717a8e443bd84230d68d6b5e34e3cc762c88af0868313a4df2f1264b9084524ab541c821aa9fe501a7f5d15345a51b6810a96a68e14afd2917f959257613630ff435793bb68ba756b256f463d9d5020d51fc7900617f820d2824522432cdf438cc432c58398cc30305703428d5f30a41f6321867024e1467adf9191dd454601248b2d41fb3b40d01d965e53f9b6d1848f903262ea7c3a81078bf9b1b30ab42406322a06f5dacb312fad4aa762b2c3b7058988f3146ae305944a0c83d225b5f30890cbf503096be172ee2c9426263f74d10499e26a8086036517e3d0b563fe82bc4728355111892336e90321395e0c10a8a1a0f76b7aaa23b7cb95d49d4f6a90b6db2110a7594343cda6a3c546bb7e639edcf7c4faa9a5a4546f73b1e01f0d4418e769e6f22ec323bd7e2a408fd79643043767c21c44f884bb8cae76010f0f856da426a64ab608521ba390a3b1ea5860c856b9b34a08eae3afb5f4f5b911ffb1ade1bf42bd68834353a6dc36643494624987d0a07579e8f1096199e08fde637143982da1d4d253c408dd25e640d570a356531a97302ec09244cd0c65e58509540319cf474c7a3db32e7c4f4367eb56d43538bf7053461e5429a88a95f7bccd957bc9075440655df6fc6240752d4efeb0f1d58310c1ead73413defc7124f6be52d1e5999496dd87d4442820a53bcd3b91b965eeb5acd5af11bab41c81e1023ee6ce4234547793ec9423983c6160417df40a8786f3bd4971e6c4f1e7b2beecaa269fd22a54e41923f15a7032950b86c53143d8ca61cae9a0a5742c6c565a2dba26af820121cba0b5e2d2568f06e43cd524518f4aa297aa2ed03c8f01368e743b859f117c23ee187fe1f89cc334a0f606b314f23c323a1c10a4b895cc467a0990b0c119c2559a6751d050c33d147afd2fb75257e2e6d21eace4399bb8c3dab458f5e28d9cd2910e421203932eb2389877d1bfd03380b611b9360be394040aa8f16335d59eb433be71068ae5a1b27ce3a1124275bbb01c64a3e64ea5be901b757c2539aa20e69b62f434f7c20380aaa143835e4eaa62941016246cdf6641838ef4450585f235d74c98504e082983491257c1e56c94e405210b76d18479c5c1cd2eb6adb832402d68a6a6e9e7caf21510ebe0f293b13736a62f319d6e8fa356486e62fb4900e4b3c45c743151046561b22401ec2128b19d424124d2c63944bf06ef5408f1a807541ec3b147daaea575867303df272092c146c4939cc943c194da4c00aae88b418036e1304b57fc1544eab5f18bd40304dded3a5090fe19075cba71b284ed00d14d90c3875960a783edce8940da1c6a1111dfebc576423c0292cebb842072ea23c2dd5c5535fd12105581bec144c3c6448d676c42d73db084e1bf8806af1d2b3123cde28329a1810572d9aef6f5368447622da8f49e416f70cdebb4b10bc66d53a8b4595381c7bb70c74bda63d168c0a3697f8083d0138f931bfb3815f0ffb52520400fb0804c4e658d73a562aeb9699516b14373ab04b810e1af9410e8f43e418b603414092259b049779d822721dbb2ed4782b2286a61a533ea6e109002f882b68aead39b16595514ebc5b1c935d7a162223543b0536a603ef0a1c77f370da12de3e06033f50ed396f04b321538dff111a0c104354b5d2747f7cde2e3c07271ce9744134405e8b6ee6879d67539d6b159007d70d192bed3691e0fd3f269fcf538b597e1b3e10d1672559444f0fd76a1709928b2a774b4f731dfd7e240122c75264b9e86aeed28a3d40e6e71f88637674e026ef31b98b7a5927e3822e3065e127175d4f6b62f16e3138d8773409a56050bfa1ee0ea520972feed3c1189de8284439eb33446e90316e6e023f766446fd1f013236733073e259f833131008a9c13cd0c4f23417d9606aeca70677967c237316124326246bb3669e339a237b17704f240cc24255b3402964dec505821e8a1622f25368769aa6255461f007b1ac1d5ee6a4bb2640f304053cf2a858e802fe0028502f3bc66eff63fb48b35650ea7e725b71042a8a42d331f7905169a561660ea6a6076d0bf4c82744c97c4891c6f84974c77014525470272868e5319136b46216d4414a8bdda03499a18369c460d068d2a15d77a8fea92c09e064225b2e47242ee12e5f742e0d4175044974c3801b4ac74c21653bb5354dc1ce3b07342e25530741723189b1075ee638bc3c573dea5f3ede420649c54617cb51a90ca013f737b6f75c5015aff718ff845a3f93264d699b70e24a6c183932415a8b369a85543c260b6f4e65acdf242e683b3af630446776025b3fa607bb072b1edf037948a0475532e150c8e8d0339e16f64896b8cf41cb8de75bd2b341755663960a365bad3419308c3f4739086ae31ecf3fcca957132a670c4bc6889464cb93aa55df273519a4b5e01c09481521f3fc1232cf8dd955c087210673e421263482546af1d66c07664f7d438bdb3f246db2950ab6adb1545ad10b29df1cd5045144c76613a0f7087874fe4beb80011683522e1b30c2992b4a87aa311b52611e87725a43cad86f39736d853b9d87ef219aa58d64ac560544a46d9846e8788471cc1d0a1e72d7820c9fadcd4bd3f0a3702ffeb50b6cbcde1a109c085a2e0f4d24a94e1d75959aaa1a87adb435b9990a0723c93b69260290004cfa4f16d3723b6036046f594f418f66d2e3582c664077119ef0b771bc143f41f91eb8063358c032daa7ea14bd54d0232cf7456dd0a1512526da6b4b79de0c4e54cf5650f6e7f02da131ae12fbacf94d6d1fd005cbddae49e364232ea7de325d57809676d77c953917675761da98e53501ed3972b8d6931a2d44062ec5844a61fc9a536a930fdb2a7d33427679db913d1f3a0c671c15794b3f039965e1c2de00b47c7966d3331666106b980049216642d77e164b9aaa5614ea804f23c03de9009c68de68e7d03c1f39a4052c99548d083b677f4e6881fd02f1b53424c21e785301a76b227f18f714320aca6ae6ddd107f1748346ca38c52d113a000a2cc11a090754973a0c39e818293759398021ae25efbf40695a429848a53f12500efa806a47e03108105e4451ce85806348f460317e077e3954fc844e53607940a3038e1d9816fe3b5184f603d42695182975de1865e58161fbde3424a28c131d3c66c10554f6d2661d267d3d8f2f4e02045ce94ba00ad25e01c0cd03de1cd91f7c6ed83161b93c6595346d56f463c21f9c917104d6fb721e318ebe3b82904e248e67812319b1c504660dfa750d7ff3147435cc6031a1d03676f649054d37c34b914c2f34cb9e3b6cd675b239a222390e88544b6aa3dee76c5aa04f13b9083834c308da4c615cbd570fce6a16092112741059803a58d16c6daf8ccd42570fe15b39bfb85abf879f19dac92b5c6face22c4b711f72c0e1d550d5fd844dc7c65b447199902507fed75103bb793734867b6043184c0427152311e8882167a5e8a26d971d802dfa3ffd6ed5911d4aaeebf6631550a600da6b1f4c6741e9746a4f766c17999914c7d02153fed7b8138facab6ef1797e0dc0e16c3eb36f4861d766ae649a8ad91b960910730c2f481a2945b12c40dfb15826be1666fcde201c2da98349037d38396253b63b9794c057b13b52508995f0242f0e7f6ebc16321902be86351c92e23e36f2c906d7d389007dab3469cc7b326300904755e69fba2c3ceea55783791258031cac53a2752c717b9f0857afe1760ec7feb52f38796254085e87342c8e4b1769400a3aa7d51959f1861809f1ddb52f676a5457387be04247eac51e0509764967face396e09a661e2cc4263494b9471b1ffac4f95e9575826860d5663c5d33c5ee0995e8186c009209d312c2755c8325e76d96b40ffef2a0caedc1f155ccd40bad1cd4a4f16ed16ebce0973b97e445e295f1b73d2cadd24dffbdf0567dc774f97b50a353338993ff041467148c61f2295c3553e6eacb2323ac88d1987b0014444007a4b21423116675aee4d907aa85809420861c39fde1571401261e0507424dd3bcd0d2984ac2d167397757264d425264cd5516fc1ad6502453671bb50f946ffbafc5edd175168c19184497c2ed3447425ae63200f832452627d14c674302b4b373f5a6889e360254a5f47d06d1d11d9e25638b88252185f5116482204d30ea807dd6761e43b14fbd441416a22d31baf3dbd3ec0768223801d077722cc450c45f9a14c11ef7e732499d6495bbcd706ce3c105de236bc00670ab028a5a2232fe3c8896199c8f00ab5f229064b709622f2fbdb09d5326f10a2f2344ccdd70823322bb15123991d4dc8d14c71917bc05d440bde3dfdcd073cc94d8d70625e3f0634b23c06f568fe50b1a0bf678bc7eb3e8774e95bd4009532951df12195dc036c12f89b34797c3c0b87b6241d0c64ee0975a528162533376a02fc520d6b48e6031c742f2cdbea046fa39054525aec800a0a387b3aeb1e9e405e9c346a9692e868462ced5f5032a26aee68d440108d7a2caf8c690d7eb88f106c0de8347b566846bb0b8f1581ee964beb0ec74db3ea436b8230f65da417f3235a1d175e3142de13706f422423c58b41fef7e85b5383d5650cb1b81d6e1a3a438f6b42506a6b604faf602c77f94f12507a7a466de9a0642682e9123231d2f169177c930057c1567175ddeb2dc6f40e509cbec50568418e21b68bf56848aaba4cd45f2e3d4eae766ff865666e38f5530fb31bbb5d7ff80a7532ecd603b06b5c6184ef3e6a899aa438b5c8ba134869d63e34b8904d8276713a4e6c293410b148750ce7fb70487d8633b28d1b24bd4e4c3091f4e83adf627c7687e2ab049040b5553ced163c8bde02487c18c95cdfc81737e40ab8586f4f9f17a764f633330b11442b5a843fd7ca605adf077a3cf1714624602cb96233da7e73d15f060c970c8027622c02715eb0142780cf62218b346d410a50103b44dd9267a40463056ae08305ebb34d3c54e3d77463b3ef72ddfdf46150a9ee324a0ea00fe5e9b4536b50363fcaa2287719cf275c0620272a2f54da1cf131771b0ec9d74a89e2ec1b7f92d26e01df0c3b02f04422c302e833e6cc510247b5a2495c1b10776a1ecd244927d1372d6dc365fa97a91dadd3c4703d16b5434adc971e7cc6f106218e013c1ea6c81aff360c11be29552c3cdda93c7c22433b4eb8215b94017503efbf194f083820349d47220ecebd4941533ae5649c070b723b742f2f6422f95cf4a75823403fa076954bb76f21a23307c7713b517bcf9b636bc71b69dbd12f678674951a5feeed0e82fc89126accec18292f466a98b8c823b72f4f1183b8fa1737eadd4362cb6f56df83b06a0225241d43b02e77fe615046c00fa36e4546a2093e07131192c02c4cc6765864ccf8b36141d9981aaa9efe705c1c4d4fbf57d729aa56f2389cb43f4d4479fd4163c23f0cfcf35e3d028d4d490e30434be274041464b2b477088e53312322a266d066f221272d1c5e04b4c00303ae163d2137183ada7ab44d553ffc667538814d78231d0654e9da6eb51dce2e5e3f2874fcba3a53ecc5cc697c6c0f07aaed322b0b8bb9641f1ae55be3602227ae7ce1422413e82af4a8695223f51773f1914306fece2d5c500c190c79eaf72ed24ac85556ba141cc0f4de04561e52265430257262f71b1a84a38d6ce6f6b170a3ea327251f0e64f3812d26dc3c53d6ddc035636a82f476cd896101ba3adae60295b3d2dc75cda69c7933e73a5535f2eff16da39db54411bf6fa8776f1058a2f911d1e0b3077be553e1e90201ec41062712a6308ea1a10653ac2ef141f8ca91ddb5f594d7a5430414fe5737401995c4aefa4f00c1db4762523ab0c561aaf3b4301ec2e094b5c513348d8e81c17bc3c6bb97d4404aeab124963fbb7581fbbdd0257bc80009e3fc577eee830438c16b052e7d18b251c213e3ef344a4308d3fe73ce51cd45593c947494f10ec1160718c3c8811a25ee5299e261aa5fe0e6d8c5c574cfafa61634c6c024a973630f49e2466c1f1f537f008426833290c3ba8b3db2423eb8365d48784475f573a767310036163fd1d630ca31d24e34e3a04468e4b0713ce224140524905fa6906740350a152aa20fa2c3db89a115a9c141ce237884627a9264f82383f736eb3a31931ddf26cefc3d12afe8f7a3d164c9c0bbdee216e4cf15c57e52dbd674fef505d1e9a6a20dd8b826af8f30e1be6bbad31a5565704df421d69d06447775545524310b8a75cd05c7d1db6c0f338ca8ac6385fc36c64cd92686668ea045012e3876e6fc72006c0a25f035b7c89109b2ede13625d7c0a1944f74676a24347f46681563e765e1efbc3c46c7aa9f03e86893b2aad1e064398788b2c0f9284330a3a69161c610c23a136eb0344b28656b367881c922c696fe5ad34371702ff3ce65431619df34d48017ca41e9827a165e9027d4ff97b923950ac5801e7b98b000a3b5b444fea213b86c07827cfac352863543c576345c6020f295838ddadf674195047609a66c91a0c7ae610c114c5056bb46c0605962c5ac163fb150a9028357339d52e2697c746c331446a03a5245d0be1c226cd789840dd62c75ce808192337139e3d07565209b6132c3ea7134c1d5447e45175a59a1b5c926476d78db01cdc62415ac382752b7be1f34eab5a343c74516e02318860061961d424123ce25dbde2283d600ac26d12349e477c23de2b92fc45776ee86312a08fae1927a3e65e94fc5e4ee848d835a40bd65a2db7fb709aeebb0da6bb2a105b72d754f9e45e19f0e67d0e5abbc0181ba1362938fe682a8804fc1f5de2c515391953195f477744e77d4803c7985d6302baec74c4a36312cc3c2d3e6672106edafe7d11bdb90133d7e15b54866fc673141332059078424e0e6e09066ac63c44fc101e5a171fcf6d580e052b77238c657dc7d30cf2a04e6f77811f1d23f0024f46d0e3664d5b4d121a3d5f41d23e7768889d700158a85413ad32ba52af051f3d0f8cc36b658e4f33fbc0c87088301b31edfc5820d7c3292c176f411027e1040c3659225e07bcfa54c91e533d97a6eb139ef51860c5c35532854b145e0f4f4765517bfd653811bc20bb6d42636adbe626988888088115761d4ce0650bbb456c1d813405235a672711fb074908c1ef2759961527317c600d0cf7822e1bcadc0f7186bf5432313a085096057e3f66e84d7210d195446bfbaa0be89406017f674e71fe8a5e20a58c0e74d61b2d0eedd8b318e3c9a44346947d084e74c6321a3be67024e6ec745c130d420b70ac2426f31602e06ee31711c13c0d6763052419189a22d500e8738f497e6e028716277bdfc76274583545dbda53552cc3456f2ae44f4f4680f970bb38cc17bb71d445b21ff75223c62f23e0a1b7473718c11e5113b329e3430530339d886ddd6cc30781121d2a6ec45b4810c56b07e9b24357b60e6674d033220146c704333aa9a75998c6a22d9f7116257641016de876ec741d9110588f18f610c6ecb12b63de4f039bcb800813f6815a999807219df40a756ca3741e1555bb3ebe07c339f80ed1700d27385637313277d9b65224e08a2a0f2e84086b18e00321fb2bc92e7cb6450c39d0d43ab95e3362165eab45b09243503646691b586c361250f4706933ca3c5cef819454789dfb11a41d31212b3aad4c8b7d885c818fe3184cc7c3670536d053726bf931548e476ee975e4294d67f038ad4ba551b3c7f80c543dee0697eace68ce0c242e0b9eef60e3eb560ca273a6210f62da5bfef880536697f56195cb1b2b4eb84221e4a0f46ad0325d04abd2326bca6b664c03f81053230b14519a503123da826043a9509f4577aba25936460871182e170661cf706813bb844a533aec0df96ba2303e6e4e7766e4885cf0c6f763d8c9dd053f58b36f453c8301e7cc0202665d5d1483ae3e36cb5c50437746dc1b697f443688a43d58e6b6db2d5117692fa6d2f321dc3aab3bd83df624aecd2f2175da741f57152547d5a02315fdf80d378efd0d6af68a7b66b9c0c26705c42450843d5720ac56d139fa926573622c1c698d6867420905306f0930be2d6107e0569278d22cc5f1a9637a9342332381da6d16b9251db3a03b3cddccdb00c3e7010b517476025bb9774314c11f3c90ea2d1d0bb4bd0dfe4250596275074a80090861d7a48128dbfbdb13c3cff96cb0f60c204ebda0430e928a05c1a0f6560bdbc121004b423d50e6c325cd84af0fda397d51a06d9410a527bd1e9f3b213c6794465a04cceb1746c12725abb17828f0b7713b5e47b45a035bba5ef7898a714757a963b787c80f4419025fa50d4922bca39c50e07af36bdb6de51efc70575b76c6b74c67e4a4587eaf840ef90a9c38075eb40ac22d5b680fdeab361c05a01f6e08d230fe0db0304ad59f66323d3b0d1f3709669f944f31bd0aba022cc5a657985df9234f3dca228e34cb5e36656a740ed20c66be3f730116788b176ec8285d98e847773f06e30efc340837c62bcc7779c10f5324715d0daf11092a37e1225c58f8685ed1423e6980904410509dc4359d41542d07d8186f224d1f4372ac7f5d728ecb39f8fe2d58bceb0133a9f1d32db2ba5e3bdc6a4d5180880d551daffc6ed1cba3488a1e0836a3f5fd4a62d85c6d32dc1e0b4d84a30d6d38cb38962a4869517378572a354862600bbd5d2c760f197e424d62c5eb9f123609a3081fec5a63a33a3040ce11ef6e067fbe0cdd2a007646ebf96735bef5639fffaa35550b9002a789ba4b742fca6bd7f174036c50ee63ef8eb93cb5898d4281b8273422137775811dce58e8b8f92ba3352a12dcef6e164a13056727e946202a8d283cda1f3053eeb97a059237c0610a2d20514ff4b80872fbdd075f93b3769c9dec2b9b9704653d7e380d3b25995d0d62484742bb7c552f774128571af87294ed43764cbf7b4f750cb07744bcea1691043d304df279745b19614cab1725253e60cf7518e63f2985c232279c9ad46bb4bea21756c26c5c621b9420067a016f27c32252785bb0290295621ab4cd89327c2fa64d70796135fba61746367bf16a9ed3b27029230b585e0fa61e5e897d007bca603c7586e1477a337932682266597216916ee585d1772474fa70ea5c184560418903cb114214eb28f237b294321851eaac4d27931b5f79805f3af4cf5b54d574fb5b7733ac4eb3ab680bf09669071377845491fa82559ea4516e8ef75d69ac4c923e63e94969d264703b43e50963a56fcb6774370351a94cc90bf8267529bad3dd14d39d9d4f41bec125fb6bbd3448c7dd6b7b682f6610e7bc6889ebf03063d46b37c7332e0e6bc4b2478c228531127adb61cc22d52d862ae45ebdea4f428fc5b82e4345c46e8f6eb8137a486f47a201fe0b6054d8469fd6cf31422278619089b803a4a952105a67f8096a8e5145d204eb233645005165c205130222ac4a4add7945351b826c416b16495197955561a35c4c4bde12576d78193da38f7833c37aed642e2357199a8e474274324470694dfb000f170125533fb437b0cb025efce045391482c2204b7d444236af4a0d789a616f3a38a766b2a2df6f8cdba4481faabe289c4a8d7697b753296516a90b2536f00dca2fd84b5f4dd35959fe6c2e53fca53a57d2d0176a2159233ada7a74bc6afa1ec7848f4616d183763c3e16200b8c5d1243a35073205ffb5f266650183dceb914d5bcd9298bb53d6d91fa813a8afae844eaa9831ebbc21701fdb1963372ad091a64ecd8438f54a774f8d7320a3aeaea2191af6822e1d4bf3be40d485d16b53410bedfc22f5e83865e12053c675f835f459b69dd5b264fa43a32c5d977948aeb3d71a5d62505389438baf2c51bda77c02ccac07853e7fdb9453df7171666719323be39d22e39b8372839457404cd3dd47455bf40011970407774f65a504e73f0301f0f5b57c9c4b82e9275d30ef6ac161b61f484318d260c4cc5efc03d97a28240a5fc4b19383a0634cd716804629eaf76bcd0ce32dcc1a454e58d7f426de5e1323b7efc59ca3a2a2b29839555ef5b8c62e30dcf0ce164eb352f146e70261c5b1991207e1095c91c280848ac00f7a9511006854f1ed563a659d37a402e11d49108730aee38aec4e44105fa1e442ae0c66561502f582551700a80fe4b659cbfa1747c6cec18de20252848a74d1c444ffc5ce948466bdfc90c229988e20cd5da6c6ac276ea0efd77463f7483f30696784e22698f6704cd8f78412a082c2379c69d4c99f42c2dc578e3136c00e4062adef33c49b3081ab87f9a6bcb86da3f2901743855216d3d443e273b912a3549d317d269ac0b6f582629ed348242dc68c1f7452cca84740526e35f5c0f051b720aba584944ffe71f23fc8e604989d72dbbee0724dd081461eb9ba80a6e7d2b6b6844f12c8cecfc308af8d421245124012c37df381c23c729166ff2000012165961f5e63e765a784dc01431265c1d1f5a871c3457ca512a69a18697389d9d7b73fd3b1628ebc6a1051366e160fcc13d6c9577bc3c7475ad5eca473202c491b535967ddf0a37d00c56488c6f2aba009b188187061c151725628ec0735b2e20d817b5cc654d11c1f443e462910940d6d81a77927011ed63c56b4f03b36e3c26570b8e2ad650c28e7806423a044208a2e83f52ccda489964b64e750db42bef5d590559d83a0bc541d95574d36e2f1469f7662c0e4d701df03112b1117b03068696633940911b67fb4a587b647a058d98fe1d90a9064181eb2f15e53e651fe5f1c5710e35e322e20aff1b0084ba33158c9509fb83a84a951bd50d1bd04e3c75d5615816fb9026ebadbc75c79fa06e51d21416a2445356c38e91142a1ccc6962875f37c3355812e2031a049c184d4d17568b5bad22571ad996251cead1b742052a6c509123161d5157fd6bdc5a8b5e61451124988ac357f6068706e9df22299d2b2f5a0466002735b8dd4a9db499503f5cde62a8cd9216a65fe36f9658141b0107fa1d00e8454b719ab537e776ee6b0280de17aeb91e4cddbd5c01c6ec9657f4676771391d4c4df8a48c538ccbeb6497306a01a65b8763b4db936af20f9f42371d720770268064464a7552c6edda631a35125e73b2391410ba0512d8c0af4e83f4775cf2d0093fa1650f0c2b3aa84a803b6a04f7cf092a767c3f3f9bafbb2d23425c3e6b3e5d482f928b6530b3903b32f42c3f9745fe388620273b6ec40d052cb69014404e451ecbb5e443d53ad91705b8ce411edc194af1de3054786a091ed13f6b272572b7517758400ff546cf7019c3a74a9cb7ec53370dda73cfa9603598cc1f462cffe1053e28b22ed20458215f5e7026dff1c63e3612957102eb01607e49ac5591edac5809caae1f3a327b41b22d7f3a09ba13142ad95a1bbff2ae3961df4c681e9fe2588bb7a011d21f464d96acc74da27acf6ce803df2c581b0f2b055f950f57988b20d43110520ea0ee4211c7111341fcea19aebd3052e310845c8a4dca4f410c6d0803148458de91bc33450f592a6abb32683b388025fd0464336417574916bada546acfd90fb633e730f8ee25268d61d948d671c7238c2027577dc77e1c93ee28330a318e3e76122c0459fd704d8ffd0a30d11a0811aeeec60fa29e8d3595522424b499bb5b748b51318c055b5fcad5286894cd230f1b2879230617f547c326f048ee48b4711e85214e17722a1ccf1789273b68ac031889474ae3a6fe2723ceaf5724f25b67ffc0764fca845551be090722b374c83cf4f959588a102856ecd5c65fbf5ade3f93a80e457a70056ce727b624c0f28a1e78c08a3b7fe87c361c4acf7295071d1c0343325cd1516d15f5a2ba155f63796614a660089914e20613931233611dd82281abb564ea1d6243b905ed29c452fe0235002e6b800e263197915b3c3140d5336524062b506c50463a7f782af9c13f70d8ac97311660265c38bf7c438019320d033abf08f8e6dd6b359d673b4ab8f9592e860758c300aa22026c245f70eec03b00eb78523f72284732e35044e8085411363ce2717284f353f7f01052fea3fd1ea3b2dd6b489f1a4f7fec863f592ac4389c17532631e10f751edf403241bfcc17f15788384556b1072e977b3ddb83de44822a6d582d4435120466bc005171344e03fa766a8f767367693374764ff9e554442c095622f1d24903117f1452152a2deeb59d2faf40174c4c40a86e26529771c1c0155273efaa558e45145040cb520ab2f5b6013e8a284bf34d8e3d7737cc06118c864aac17722a70aa712f43bcaf60dd33702674919c5925c43c3eaea9463ec4827215330a68073ec37c3ff4730158252c0c449ada4564668fde146a48ce76e7a22c233e02d1698d7c87049c8a4f4f5834ea6edf788f23fda82b1869b85b5d8d9ab5080c4e251276c20a5e4dfe7f291d62ce3caa3d8d55efac3f721b0df63a0bcdbb4f06b09e3ce204361b8d239f6312b840596ae9d73865535073edc3d65e97a762415b0b2c5af00f1c165948bf4f1a47fd5df5c3c50ac5413b52614793473f310674e0f9c262f9fdd0322c249049939eca2278af616a8cd8ee44c3019912ad44d95221647c1cbb1b252dd0cf4d1c2b84373ce824a141f02e3a03adab7a4acc99503365001749cb97431d83afa94df560e977cb048369f91d286191fbd64641c4dc6416289d3a602ff751803a34247b6ae76ccf52a71b1b9220366970126422fed827bc840a2151c9cb05ba819b65b66cb933fcf3876765bee31f9ae0936692e52b3ee2399b7343d90673233c45384d7e5c2303323c4027cfd164912170165d933a13e2619f3aa252fc24681c7112e77a8d639c37dd25e354e268272fc711b47dce12b9ad59613cf861760434fe1efef2e30ca288f93772004d05eda4235a288c89413861b64dacf1b669e4ed1f75255f2d1b25a2ad325bb5990545c876539689c85fc6811872e5553d134c0b04295c269573d81c3264143fd937dd4a8f5b96a6ae728909792d6628941ede8cb03bcb7391485e2a8f3d067e5c26c1aea24ca2810b10cc77495445abdd30440f2438fc01e85e7c0cb42d8d81ba41ea1cb75dd7468e4063480d277efefa1c41bd5c555ed3825f2b25130dfd70fc54813f1718c0193775992d0876d5fc275254f08b20ced6155c97c0191bc2474d0a1d66fa6b0be0be140983c8214dea8211d7af923a2db4930854b8d72b56842802ebf6cd3045c80b12b12b385383715e4376c5323e1f48e9630db4a2312abcfe55aeaa9c218bdfc54227cc2e2942adf13c6d10310d1f2658129b456629dd22a55c6aa81822323919094583ed5f46088812bdcd49464952c568f2e2d165edd93a54299fc20bfe9eb9185776f06f6ef10c31e8dfb23480b6e54b29dd2069b2227f1de3159a4e2dbaf318ee17a856c61b2952c1237d3ca864cc42bf08164e395c7732fd6d1f15a911f822d82f8f1a6479b048c3e433147944b400fa94160ab66c4c493f1af90ff23a9302a701dc042f128e175583d35bf9f04d270a70f260072774348de0522573fc5e1fc49df76738457735668da70141c58d062af7a5222cd5454813d3cb089fa0625a531a326f24852e5acbc19c413867ef2ab541c00c51c89d3444c9a238d3e7453d0912a75ad661e2511d7b4222ce1ca8580f716b570199f148592dcb652deab945546c9b7484ea6b323235b14fe943bb5d00ca7f45facd60298adc432c2adabc0b611e8550844bba14e214714865da5a1f0e6f222a60361a3c42372f48bfcb2111e0189c4d62406d5c71e2bc66362eef1e237d3f69a8e4c6485c41f344b9700605e23c605c1a003a206931c2007a963b761ee03b6ff6437c0c8e696c24bad7ab0240de8b431e7b04261b83e10c3c02756f86615d270435c058329d05305f2e4b779f79003716d7232bbbf9db0c27af1677f39a260e5cf7fe19fc01086243a7453600ca663bd77bce6c9744683a74410e724e9c9f1e9215c83d016faf313d4c33463d7f5e54471ba63596a1a0140ea419552f56386ab695ae73d675f43e6614821afad6365552e7c4366c525a129e8388406a7bfb15933f910a6eded06d8782e766a80fdf49b0c82f06167cfd3db0b3f800d778b4405964ad7337d03611d8e1f6205b4d5f359de6343343ae883db20a6a238044cf67891b4f272c69d503e93bf34926fdde291baba902307a3c5266af614721933d49dcbf9459ab13713464cff135000e4343b59d344ff2a0b834cf45523df2759f337b0dac72c0d9e742cdb42a1496709b30d202184f34c18b46304d6e222f7b3148030a945f0afcda656224db094c5a644ceb3fb22462a173025519824fa90c3f6acc6bdc4ab1db5b68a1c76375b76cab02fc796233be68c70640392c363038ee42a78e7a350ed7686cfbcce94fd03217410528ed2e84b27260e7f37c4831a357179388a2040706f022f34e2731c0e26d4df5447917710ed816a3b1e86cd720d21796cf1408abb6a6741a124857fc351728d83dbc3d702d72212b69a267939e3833eab95972c2ea9b19a1c54a2161cda534a200454b9b83683b6210f95ea8b1a043f05006433811503942d9a768774b70183261f76c36da7a19f7e8ae42da4a752a839136514bbef0043b396e70faebe070b8815e49c1b2371baa852b755a1a397743278244d330935831049e005410c66fb9fd82771a61822a15a14b26c4006f401e0857145f8540111cef3a31a85b4c32cf887e05da169e562f19c541acf5b1363ae27d1c4723402be72a264e9dc8d85fa73a69166743aa404de18d3d8241ff5a651a2b44bee1602032115d6158ec56649d3f30347c3f40483f44bc4b9ac00169238ef7243583e2290e0f9a108c3930340b76561146bf7877811b6b593c0f7d070f9b653031b1450600d0ed3d3af2fb4fdfb5fd2a3d82582435664c10f9dd594b8fbe64766c08ee4acb1b32593a2949715e5c9346b43ae30866b1f2158c79225c49fc2143a2e466606dbf88003c1675064f1e901657969f4e5811100ed663a1394455f61476a5213eb545bd34155f244a4bed0b4787acb016f879f426a452033bc3172d3eeac9cf1cfa123c2db8062600bcab95088caa7c0b92ddc34cd7b9cd555861c2730db90b774d32ef308838c34941a6c15e6ede0e4a874456631b230f705a0ddf55879d8062aa5da55301c3fe542c803d73201d285bdfbcb941f8a9c7519f45566734aa4c10fe3d1a163021c411bcbddf2cfb126d2e4c7f0912644f3b74f83d0e1cfdda1e0edfff03407ce8e37589bd361e9165707796075f5f6cb41a4cc94eac42fe039f146b1848470166b9566daaaf17fc7d68111db6eb4ffa25aa1f8e54404a69db18138e89cb04d76d6c4c48fcb264256c2a11e4d01150c6f10076f7878558f4e6c349940377523e22d14bbce38c08539289551d11c313367b175224307e64b697594998b7ee52d59c31575d72750922fbb953d277b642ea730e475bffaa73d1cd662d2585e760e940606558bcb940827a5b3d5771fd1c94ce375c1fbb551b064a781b03ae081eb967473fbbdef4513f8e622b9cf2523485c87c0565252a658b525305ff87bb5c3bfe2c229afa840dfbd9cb3c645e1f663a25534254beca707c1153225fe2994be13b0a6b94a05751eae85414b0dba0209857513d3b97ef49b9a81818964f7e6eabc5f63001463b0cf379c74fb97aea3963646c563c7ef8699d79c95ba4044716b42d140f06afe70ef757ec5c33f74323f21a8205ae61d06ff94a066e986a4a26422fee639577e772d5bb99555849b962fe80e36e170f90229af6162377ac4960cd9e216b05db245ec25fa336ce9d3a2ff855626e532d4b1ee1ee6939b0d7871b4d67b95722ccaf53237dad0f93d2a6658382bf33e071875c99c94a24fd199543646c185260cbaa4ebaf9cf015ada2023eaa75a1fa02f773d0ec5db4432a4f92367da236040b2b307e5014d77eb027f154d984176b8d74247048fdd592e57c514deb78d3bc6460057afb4c435b2e1c15620c139724516055ec2ee76630f57c01cae432f5b3adb2d125935d0574b7bf94f435be50813dbaa6523b14939bf776e5942c5ed2ce40bee3bf3da2340ffc7625c348fa90890622121e17d61776cc0801b44a4e8139ecf327509477164e6a1064acc0b413f8d970705cc28215c5ecdf045cbedab07a84d942c905acb1ac41f397663bd0947c9d6983db567d8715bd24f191d397b160cb87c302f77782d05a5a636fc68c04ed0b0c34c54277254a1c582234491814e2e518d3571211967cc375423fd3f961a01920d3b385139253723df20e05ee93227583637db351c0e82675a215cd42e380ac6a62ee2aa1e0293c7dc63f0337770e317eb413e2540037f72605744db8c464754673c5292566bea4cf07421a45677ca56032f98c69a4fdf6c4e09d9f74972f8edaf0cbd93a272e89c844bf1afca0d3ac39d068f872027ac4e4e139971ed5ffddb5c437e9d996f1796f361e76f8e5f7127416b93b57d52e885006205b5060e08d075689f9c8c2b973b5637b9e66d74b07799476c7fb0691708d76d7788324e68a2f606870b4615e1c59d6b2ec2701091969b5047607c25f373a4051a750e69aad0636a1c8f6800113b3d6ff050ea73d2652e3642abad055acbf923349b2536a4178216fdb41b75771382366348f31df4ed28536d91923867153f085c6bbd281227ff669d86876eb0161947da3553765080632c5416406159d6260103b2766b61df5618282fd756c13f5002d198b00debbbb07527a9e90272b3d749fcf1eb07f46561360bf4ad2a1d820063035f9c60675edc02dad62210c0b49971d5d6d9247a12731fb10d873ae1994926d5010036fc55be54d368284fb6ce201a1d2d240695f728240dcedf0a996de7133c87f82280c4c14d66a62f095d4c97762c56236266f957420ce49e115483fc1ef7f7e46e8e2dba263d2938517487850a5c0345272996195469dda55fec1f7325ad7a4117f6084012649500659842fc359a9c7d59dc9a505f9e8de950ec0cd73d4f1ed03c057b3c432701a239f3ca2a0a5ec4d03cfb507326451d9a4987c7a41ea3c4a36c6bf70334c7d4485b6d543d4463bd5f4d32e3761c04d3435fec216266baf5cb492af51359f8df476c3deb7274c1177221c4910b378e4fe63a3dd399284c4d4f676263d302f9ce3a53a81daa26bd736e03c19e4c36117dae1a94b8951c4894521799c631660c10055982603855f2e1ef449c9aaf2f9ce1746f965bcb75256a5035492113088d832066e214ad5df433b264c4670666947716232ac5156f9da7b8437b1ca1550541aa444bbdbb49e49632101326df6333d9f349d286751de7221a33896555779b36b541f047ff4db525d646af72203c098d35341c10aa11068fcf0dad668755a1f53437b8fbcd32cfd7261e7f9a134d3df0353de2186013694e3a512f75d94bbc5fd31980403f5648c7c934efbb87395ff75b63a0bf6f00ab69f20d2dfcde1b61dc10484b71bf07adf6313eb23acd176ac4fc2fa8d79463a17de031b02ad00cf495ae5da28fe54d6b6ceb74669498066192fe6949dd72462eaf6d5d14050270c9602c39f1b5f46de9053135f3f3ed3b54daa741daac4536f8a4f91a2c50790597e8620afdd435358a0ad9313c98b10cfa64b8693ca4732982d976545d55a340cf05ef0f17ea9e32a051216569914e37181a0d4a3ea7f906f453ca017086cf60d1fd2467f5aa3020204c850fd1424237018e516ce05bd9718dc22f29405fb0695ba8566c54238d3947289b39a024b202dacc492eea01bb55dadb1869c3ef5c526c33415b81b2b601a4233e3be2a1961c765d6b690ceb9d1d08eece4348ecb40d864143421297825ef531ac0328ce6d37bc9cba63abf7846c2031513bd623d9541c78fd5251089c35e952f234fafe6c52fe2dac0531c7b32c3edd4f0e2a3e2e63e571ef3b710ca56c20dbba4cb8b0f1507f1c5434b4003164d71425711a048a0b0017f60d67bdca3f2f0b2b12b053f9102c743564bd17f221f963ec5c7bce0d2d41e3c05f3ea5944bed0d43143a994c083b504501f40238067bb1d3363edfc971ccf88610d820d40136268c59af78ed0af549a227817bb55d5eed363624a8eb0c8a65806ab7d85a39c505f33dfc745a21d808941d28a7b04d14ac65155f9eb62f885c6c3fc515672cf01f2c1aefca750d518b2725c8664617cf58db3a51041a5a52785a01d2ab644e805dfc44e54aa02a201d5d39a139844dd2b6046ec884cb6e0981a626b79d8a1aa7030d6bfc37e2286657215c8cf03f6c5ee1886cf837185aa86c4001690ab727354dd3585a7b996a8868b272d2f67540e38aac1e23341f3aff25d71fc6df4008df6011121a80683515a00441841a2f12d2e43668c7aaa21f5fe48c3b2bb83364818b9130d00b27652654d0525203360bf2307a4114baaa5f360f3328f727296121a91903397df91f8e68d6098236e20be258045b7af7ec24fa6efa69149550347036456a8ebd2869bcc71148386d30470d963c69955375044e838f18a4bdda73e997e2204e536a1007d6ca4c730e7e5f31edb22384fb895520aa5b265e645c6cad28f56420b97a66dddfbc3bd4fb8a06bca43b33e2a9f82592a3f251755a3354e81f5737297fc8190f99201758b8e1400974f25ab1fa6022ce85e05d153fa82843a4da613f6a5901d260cd45f3e3ca6d71508a0150d99d40d21b013f992bbc0696de0841e1b0a727701dc142790d8e12df2d7933ffe0295fed54c82f6bbd3e3d6163fb26a2dd1103703db71c8e3de50483417b0440bd412f6b2eab2b6d08d21b20c8fd6becb31b21eab5f43a7fb3b97471296c59a8be98170f11d7161ce8ff687bcf20522d26544b5431820a89431d22d4a6a7668aca77522f4a961df38ccb4d1b573958854924055c207d3daa0d5b60f77bc64d3c29d44856e2972d3e37ea3c47b52719be64790cc3ad205cb9ba15254e16fd41457e324bc5499226163db5439cc9623f56a5c226d0a06e1237f5ac536337d147f75b6c6a7477562e75598873d6619571a0c37610464e4a6d5d213f420faeb212e89fbc5709718f49af39af32ba1ad2498e3107055dd5d5122baee713c6b2d84963ef5175f9b1a90207720360777db64ba322c60eb6e2ff0c4e8ae8536321460291a1e137a15edc656cba4148e6f1c930b861b83469ddaa4ded55cd4b5884b5064f15ee278762026c4bec7c3e6456e523449e666992a33d0fe4c0cc771bfe742292e8ae6d94302c326d01d35834e2593a50d75d2892b1436bf066ea349db5506410635e340a918c4805b8b563eb511903164dc30e2a5b855bf5f5234bcf7c4c1e11da490b5ba73b4a42ed85320e700b28c59eba557d9f8e1092088916d3274a750b96615d8950800639598b31e108e04c05437319c678cb495fd7e516ddb9ce49b126d9678b4e7118109e296305373046290631491803d21b9a4f7969931eea23fc5f5e6bb6802254d9ee10581d8ed254d7b78e2c5bd355326a4c475ffe060861a054b668eb67d57766feaa5bfd836b24dff0bb3f3910fa320426f8598e434c12a2809d56dc865b1863c8ce5fee4438761adc486d6b952544129e090f35773c553aee89591946a430c17e995808179314f8c44c651b816c6911aa15569aa06c616a00af6ec03b236a84ff81022ea9bf588ccb1d3c4c32843066d5aa3e9e85c43ebaf7206f7db7e112b6cfd604f21eed1dad134709281ce718caec3476e411284dee3ddd53d3e017636c7cb433663da4425fc4c539cd48173c0588695fd2c9eb748c7526199c7abc073c5c9906ed5aaa311af7f8756e1066344e70ef67ede8d7216ee1493ed29592377767106e8acc564a655fd007a9108771e9de0e0d95c3a92509abf216fda5586aed57d06bf847ab6b5d165b09a315875238b6d015d69d160b8d4b080a9e2e0b0e78e94d2d230eff2b4c94e16f29350113cd9eef6347e2ce012fc7436cfb2d8337bdd26c31e96bd74444903e68c2e0c8284e0b0b1117b67863c4f926118400576607f5cb57e07d496fbd6df038724dfb4f01fe791747e4d907b96bba185a3f7b00d887945d42e78e0fe62e5b6fca31b85810c978121db292380d33af4781fe4e60d3c66b4b19cde91d7a76b3692e2b691af589aa37f1a2533e23957963a781db12013f53658399eb6d9a62426f498cf1033ac4215ae294a9051439bc472a00f02d2926011044e84e31d4e78c63815f875b736a3d11a1b2c56ab27c45574c15f957dd4f932b8eb6d13421ebc35459480f31c25f14082385cd30a01a7371222962551a25202e6d8f6c6dab8e3f2bfca1206eaa6b2b49ea104c2b35b2843e1cb02b2d0dd3d75a1bc7a130fb9ae745d47c6c68ce452867f77fe36f4715de4afc4627033577d11daf0c8721b075a777adcd2139ee64c757d076aa076e03e95606fe5f73a6fc9f08af67c566cf94455f1a646a1dad98df158c1eaa1306d2b30bae0dfb4060b5610325d8ff62ae95d85f05b49f4ae381b7642c272c60dead4a03b0024f1b7ba729300f21a57309686a588e5af61dc3b12a0313f85e14a1d455212189c5135f46ec29cd94055933916a6547a54208a1028f3794fe465f9d482e3892766648fa9b143c9c4b763ddc870a1b3788ce2fba92c00b1a419866daae265e9b3ee95725057a2074e8f801bb952a1c3c6e362b1b7f5601622e2976e733d929c7a1327739e329223f2e4b1359e7d34714447f39db7a030f3545833c6351580d4667656a911e735406c4fa0ebe587b28fcb2df4907f4d732296d5023ba7e6562aee22f28053bad47c6af7315ef729c0ec79cc42612cddc414921b01620229d4142f6752a0d172b7006b6161bc0df6209e72c112397cd1d36b451e029dc1b7a08dfa7d82a48bc8440305ed0345a465b58993a9a685df00b1edd04796d2d57f03a6590be542d11bc6e6d7f221953b1946a73fe7b20bb23162aeec3490b72f63321bc2ed21d97ddab65ea3a1d5fb3cfac21138d2c75324fbe2c29114960edad85718e62284163568d3ab0523c69b6cef93e4b5ca06978db515e67f0e15c9df54546da66651aec20d92a3f2cbb192198ec4a3af95f4af21ab1012da3c525a6b7de1fdb87782ac0bb1042602ef60a38ab211b51e1c070a79d4a61b1136a5d5744e74f35f71128e4330a12340eb342d93da8484a6f1652637d67714e6323246c2b1d4b31fad248d355d61af7f7661122e1886ba1f3c468b4fc4d675d9fe55688aab02c3e1ece113a4e2e180450ec5f0fef6a2efacf7b179eb44e0dc523bf2534cb4c257ae8944dfb5b5b1d55ca81412df6391460405e4fb29acc5ae14fa404fc871e3cda0d5e4d768b8a52d00b8e033dcedd283a47ad228cf1ab64c651935950acab27a19e816cb4fe4f1e6a49d402ccc8e86c602b301f492a271bdccaab7451cc734a4a9d1561053af83fda64df1b2a8339113984c24fda422059a0265206b8c6e407e9c25a6efa3c7169919d044686d24907de8748025949b5025d6f926fb9d19625e4f8646e28bd3d0ea8a0ee15c80a6975aabaf83695bb890ec823fb776435db4ddef6b23f727110169f433c4aa7f458602564af169d192d73ea6ec71b0debfd3b3232e3714f7b003396eac52f68839b6292bd58073e895e285e1d5509a84789604a688606fdb5ed6357f14516bbf5a341884c49622f34c5610307535eaae8bb2ed6439939d7732421c75771323b8a6173bdac3a6c47e5476126048148ec0ed45958c56b74d6589f58a189ec64dfc1e15829e90102c3d172573810ef0d04590c62f4d4e860c79f7f495868e11b50778043d7341a0ed83ecb5a8bd1be2f177fc4139f0a187480a1fa32a292f01f14091f48d9981077ed25ba2e7664be46d4d2961cd185453757608e698c967b2268e5ae2e1a35510b9b9e1573a096b70753dc694f519b77560b6bc0654fbdfa4415f19a368d3368329949f248900ffe2f179dd635822c785cf5e5ce10247b246640dffb27dfb3b55a01bab51683297d4764fe6a6418f59f77c41bfe2aa92b8d05ab21f5512024923e15a2694317007862e5a8f870abf3c47550cc510ecdc28859a2dce94b409a9f39307383253932103c2c077025c60f6874c20687164e3c8b01dd40a76372d6a74c1e582c541557f63ce238153fe18f465a3ca3d8255f5bc7168b34b611990bc56fb037eb177a8ebb37a039ad59b14a4576ba8f74077ad2c464067b8501a95565375b28c40d13edf411e43f734634ffcc435fa3fa41cce15b46f7cabd25b47c39043017f65dcd66536aff80b82f696f3c7596c397220ef8d85bbcf77951f57ea12deb08dd039ecdda5451afa02cd218f859b823ec3f200367075571ae3b185680171a17d52f2f6964156914af61f85f7967216489565555f06527665129db5fe203f1949e12d63e83722722f3263093ab391861e36f088ee8038ea70c2ffcb73957ce9b956df43c6b6d91aa99377f58bd2a7371816344aa274b493c78081239026cb9ef553bf985d60455a52832e34d6d24525c162f45d2321dac2e9a5cdcda2029582c22161c78ba04a5455f60837fca4917fb686327ca9c308eed98452ff9df292536333e874bd732ca7fb560283b1a5f6b526b2040b30a3cf13c5f12efc3706a0522244100c0090355f499532a4b41466b558a424500d206f362381488592828469995731ed5c7697bc077282865bc3ab7fdc6732f41f52eb53091284afb463f80b6d11ec32dd73e9859544666707d602872e65f8c70312cac3c3e232bac26175f43316d4b34fc07f93720715585705ed705743292c0f128328328448334c108abf62a7593919830ab90b357f2ec5246b874115e8152db3f8e8e6154ec2e0f24943e65064b293b0547a4ce40f8f55a75fd7e962c82c18e4da4a2af1ed362a95879d0901b557b9a6a2df3e00fe2ce43017af1e7421ee0b67238fa2c530f8044317b200808c7912c4c02baf8559a407732a5a1e96b17bf510157d58b1c897c460795bcfa1cc81ef8259612192ba62cf271e5e9910b8e84703ed3638f0146c3e00d24c27a034f4a1203e5cf6c650e516b341c6c9029318a7841af99b9490e74bb0eb51c6b0e4065715bc5767d4b2c1efe5f036b54654e3aa630c36d5762156ed36f9a5a0d695fcb0e6cb2a129041aab4331f0465923176971735b55325b0f20a8309df7bd70833cdf0d548ba952d235645683bb640258b6e6291d4e8e118bde3b71ccda3e5a4c085f235180ea10728bd43f2cf60d63cb96cf1d37376f5c913a343ecb330f4cb02b983ee9e9d21bbe3b184eb86fbf65f3824f1c47d849626e28e12dada49b68477b5258ca46d930c7cecf59dc7cb16f67fcd068a56ad30caa4c89289a39fc761b49c5535f7a261246c4130a4200b96d043a8d0c76be19269dd460263833646f3701924d4efac125b7b8292664ba8955ce0b6a14427a2c485143ab0c71e5ce079fbf5f161c1cc6061fee4b5924d1b609997c6130e7d6ef574257153628f260713bcadb0562459c695f13141cec827643e11dd403f64b321d41af655e380ed72dbd78463b0ab0510952b63a07132966721a84921484c59a0fd89dee3f7667612261bba012a07c0843d8adac04b41ef448ec8a024d6fb1ba3f15b1914a24fde80c262da94cb60bc075c4b4c20cf0ad670a29c70a068381f2484163ae3f52b5570446ef632c7e88bb5c47023012cf3ccf5498b0585ee2ee0f1707acc620dc6b3647b8bf03554308373c096994417f3b4a2130adff75f59f542ba1ffd45496ce8e31d41a5c39b7ebf552c5a0d8517226c228769675251ac3e32b8403f8261efe2f29569c070f3649b6360201912b5a227060e0345a1da9acc2124f18b9355df9cb3e807be365f039452304022800cc3790070a6def1783cd346a04a8167144dea12d0fb341145ee7443f66ecd8715aabe34e4ada1d10ee75771d18e9f167ca8ccb58ec67f718994ad70f712ed53405616e178598247636d83f6e9bb9a94f429154703abfff566dc43204988234617ae11a41a91e6e0314b9b74a7a2b70449d665111f550430b56db9968b994005d0d2816199bddbd3afb3dc828cda3010f927d094cdfbc60062f227c40b72cae042720993031aad308bacbfc435fdf0d7134786466fb32c924094999142116221fcdf23331925cf147d830994f92097e36548a1607d599825d72fd9c7559907c0b9d2b390edb2e72040763a91d70732f52e4b79855dfa29850ae309c524ee5d570fa8dfa3d3d9f444b32fd043c937b3f701cb7ff7710d1f565698b0231c1c77a2aeb52245e17fba07275f38504e390b517542557761f8a476c35a7403c5c0caf199183906adca979677566511a1fffbc663155e60b5d2f1e34b9c5b916af34e26995d46e14cc7ca9198b47593dab17246be5eb1e543c3e2b69c342a95114d4c677fa3dce294d11835fb15b067060f7d30f9f9a482bd976d82ff64c8a657ede995a07c4f032eb6f8f464c770e72d8aa7721589df02795c66e67098ffd72e8fff724db1087378b9b254771e80811d8dd6166d8bd3365a71d993c0002c1729a7a574379ca08367ff8624b7b925f266e0fd937b59da231fdb02563737ea43e873bde717b918b5e9e448b02dbdfed3074f93501914de60a801c921c7218b91cc35e8060aa46942ef5c10675b260c111898f0260a167c01b9e185606e3dadf1ef7e484023c48d050f26915115c88246666d63d25829b413f1e1bd00ab224875d6138b236042bf76d90725f2dc194f145dc842c22d45de74d5866a70246ddaa2346dd354458f2bc4ac786514cfe891d289177c34e147bc746e27d66770252ce0725793e757d37361f631e2a17a365225ea533d6480319025c9c347f344eb13910c7675d3c27907e2c1c7bb753472edd49c9103d3c7327a4471b32b74a0c25946d669b81467977cd01de33cd6f3163b238bbcdfe5891d0cb43b3eba31605f3004fd25ac166f54d6e1af739362b249aa33e6486c461f1439456164d606b3cedea2af586ff0aa15e9b2ffb38a1445e288832d64eb74e916b4d23308d2c3034de6841e53cca3ba21ac456cbca6f58d6e36a19b543ef6b7ec5ae2fbf39926fef5b672d7d5be534203ba32a0ff41d41cb8be123f1f00543f2bd6423b269583dd12ba15f71d98308e1de5c034fe03a0d4917ea2b9581ce26aa63cb228d5d5e1ec8bcbd448915f8185d1998519e9dee5563be603609f8294df9feea30436e633ca0dfad63946c6552c691a32bca1b600c66dde60a0bd4a25cb573cd3189952d5559b3d40f4993603be9b7441525011f60f34e461757ab6716c766e73017a3ec3e82a7ae2858de053651dd3833e0ee20411e96372b9e338174ed9dc11fe019ae4f9fd9ec4aa3ac4e4d1d02ee5e5da7046dbc25e605bbc2d25ad0d57c5b4a5054727653b510f65a154a564698271a8eb622ddc214412f1f566ee4b7490270a65c3a2311df194c36764eb7169165013cd4703b2038547ffcc84313ad581c7b570208361c3375aecd2a47c4066760a83f4a3ed0bf5616cc51442b304de9560d0a4a41f005f9201b4b385196b3ae2738635120d67a230aba609f02085f9d16ef220658a7eb616d5e072e3970a3ac4044b69673d392a36568606d42bcfdbb53ac324d132a26764d7d71463544ea7a45bb744431935f176d580034363696900d4c0f245d9c64cf1d590f860626c3c3307b3cf75865644575eb43c258fbb36e1b9be623333ff55838cc144c66a53fe611f8eda249be6d1640d084212785b87d58a4a3ab3e5082972867f5dd37576dbe286d17ad55fbe1833d0113144da1206736a673904de4aefd2432beb641431edc3134f59b15aab324132ce07d14a7aabe6488e3152d6a146e34df99e41e55b0d80148f0da65eb14d0254cb4bd590ee7a651a4f1ae65008536027e0aee10596a58157ab0655e645df253bf913f53e15b1016d68f8c10986d7e1e8477eb377122a1381fc5ee0b51cf83762d9ef51fe10acc40b2efbe5b1f5982346f64ce71d7425d64e3cd136ba69c85602e247d2959e7702520782646d761a54f58699139bb1dac087709c40798f4b64e3567e161aec09a3f3de51864f8150559ccd0491e08dad204f8728c35d4f8e85dbf0b8004ae76c12969ee28696204d159f09ada4d8683dc6f7d5c71630d7bde6da7287a537108075fb32d8f405f713b09d034b80f85b4cb6c5fd4b25a90b953604fd3710529939d67992e8c0cd6060a75d3d6ea6fcc86d5717e27c422fe1bfd59ef733e27c798fe036155842c6ad4d653066a692cf0d80b0d905bf46ddb08b330fdfbc37692517451de906452965a26575e8ff431bf30e6754d1f7e29ed672623eec2014ddf7fea54c67c245777f51f3202007e342e958a1633082120b26a4c6046044a6a71993f7399f917042f0c712e2cba710f082cb119e052d05eee1f4c2ba055683b20c0d95e014bcb49a25a8246d472423dc61dc62162f61359826ebe2a2e013d37c8f9754bf2fc7f0e837cee4bfc156874e1812603a92eda4057bb6735f376ee4c5cfd850a0e453d462e9d6429a30cdf628293185019d9b31213a7b95ae81d6c50e3c7ad3d909c8c3a9ae97140d1c71901b058506460d54b0fac2ce0239e05d4235af76851f585283050a3cd02604e6d1e4b98865890804d526dce5a29a5b68730c9fe1e6c3d15e508a6762f08450d474c7fa7f10cc22f8536f3d0fd5ee4075654b06b235168e55e3b4ab05c54c0502b6f2eb8eb147fed234228ea8056aaa18368e2fa2801e88e9d766cfbd27520d5d6397876e81bab31b50dc64178331e5a9572feca354254dd7f4d8c13a8596ab0073eb0edc16a7e7ba77124807e054477ca72384f275507258d004668b86fe644a95851758d3c33fab7517661fb73d85714042b754523da3b36523aa95f00d4e5e1621d617f5ccb699c1d6a2aa22bd0c19d76b3af9f6cf14b6e5786d53e1a100ae6531fde434bad542a26038ec032ec23cf267a9ada5c350dd62d2f72de2c8bd21a07bf6e39350b6c9642c684d034e1f2ed58840a0926547610029f1e99742330aa54fda2e93f9547143fc8bc286b5428f03634c19521418ad0405d8856099327eb4588443f302e0e00142fb51f60c00f074b3a0e3f09ab988a3ded5ab921ad320d6727baba189f252f3dfbb42c3676894d0eeac6b9722ae7e81f97bc013a2973b7393890330c0599981f8b17a635b1a2795c84982b6b5389c02b47bc305de80765525ad0cf5fc50d8c162f41d6422bf1736c0286b0740e35ad5d982672720f804676d813f314e776266255d18347e521d761986eed4a0a99f103a71eb506c0704a32dce0e66386be664eef9ff4242bdf124f7b059e2d3efebe34266c0c3d57281f5e1dd69b5e848abb4be811071c417b87476f66ce7122c25a20ee0b996fd1bfaa13153887771d10f93ee2e8ed0f0951a5649249c04531169244d8f05d1a4f557f2766ef9d672d8b552955f29a3fbf180207fb9432555b5f3f5a871ccb5b21b0ee29bd34ab23a4a394242cdf4466bdfe2240795a33772f4b581b63dbed2749ce5700aab44940316d065a46f9f3580b16561cf8834d5f51336c599e8a5f0d33a27561a211ef2ed60ced0bf9bad26cbdd44d5ab0cb28505b60d002859e1b4ab7da646d4ff21b2d2e76b723a702f32bef7a235042b1f61acf0189490a799a135e6c49260802b367aa3aa400b9c28751bac8456e867d75778437a0199bedf06c70e6883a24a36f0d9361da5c3f15162f905c0541a771a54ebb91eb64a272075c69523546cb1c614a30368e6cce45f030b146d55abc345c58ce9014049c251a745c1c19081cde362efc49fb09818d873f450a39475bdbd4236c569212925fcb663651e040150fee666d1f9b4bd0673a4c9600001566737b48eaa4505851e4f95ab7583c7420f94e1dd7b0385c006f4d02d5e890664d5c5b5b78826b30acfd5359754fd468eda0a40dd3c5ad7654eedc3f63e10b7199ed670b991efb096f4d9b085ae8a6077ed82515283988627be7613aa8d4a655cab0ea3d434e741d6e4ba13fb99b8a567726c94706fb257719046b4409b6a0614438ee1417791102035d661bbd3ddb70cffdb420459df06cd690b3514caaa24609a6be1c670ada282e7ce561ff07065ee258f46ce099f456d4d3190a22d12927da166919552b9e31c281423f565d555470f0785bb68f8c5b94540974fef69b316c856f4b7667dd137f649a11d68e770094d46e45ab5cf22a328e3510ca29f86c942e7d123eb9696975d6532c79f3790926666748d56bae61b46cd761c667d65b037c9e4ce1fba61e55581e326dfa104844b807375e254f349d0f5a193f591e1997ce664731e379383bbe1e683742ad0f96a0d92a87710a203ed25d56cad5244467f8804f1e912475e5f9f929b2177c57b4a41d3a44fe810e488e76564c77e71ec35832759c66066ec05e17121e9dcf0b467a3e1bb7dce407d5f0ad674cbf0b6f1211ca25738270015718b75b11057e3065cf8c2c3d1557545da26a3770735609f704893db009f82f0b3b6670a30a0f72569f60650229e616b32f271591b9045fac6d6d0f0ed9834902ed8e37735aea4f8ce6b36878129b1cb6b88a1a28f9b534812be60d6607da7029ca2807eba28b1d23b6ba4004a4d540f9aaab4d8b7216026eb41812ffb3b54155ebcd3b3721ba4cb4ffa5648886d652f27c152ad1191c4600432e23f6c0af5b23cabd398c60b5615a9d02381f8ed34671877434d22fc44aec44362a10c6e95aaf86e66f9f97766cb65b051c98c49905b494235804e2395e2f3673608de9546befd03774a19fb81b092dcb505b09de0ff8af661d4cbe8759fc59af5dc0659e540953bf1d017974436b9d8006bb2e755290badd15e0b10c2899407f142a47f2693488b84a8f88fc27f3d72719da7406567ff2852bfdb42a71d94b1554618a286b37ccc45781b0246a42b19c65c9f8be6a716670413080980b97d2df513118b155f7b7d0558f861e388ca05975aa91583fc55f4338f1c15f2a9dc3d22d6ce9624adc488230fef0f64fd0c56946769cf1037b9bd83837d6b60c1de8bb3a8b4c2306fa67a826beb7fb04c6f9144533d887172524d2669b27383cc036574a97b08e5e8465651901399071165f0048d51ef322ca10ad2d0289415dcfec390da27668103e980f09f7b65671ab73e11f544da246bddc5a226264fe45a5ebf26b8d65df0a8cf7b124b158c741fe925b3de621c602cdbfd44480350d59d14da625a2ec6a46635d6d12d2dcbe2f6a6478505275dc1a7be163756953ed45a55cb51cb790d03eb5cbeb5947c4ca75215065696184710da0c9b92a6903fd1dceb5685158bbc1519692d1351f3855700bcfef0a9aeff565c06b0350bfb6c613f86155328fb48e1bf32e4f6dd814cc0362de024377c8df696ba80238f97c2f052aa2343c52e46251ee0db817e9abd147c833a557a89e0434eb4f9d282eb35b23f725383477e7ed328b75ad12e4b79c1769364d1f09498b1eb6dd903615e3b00b6fcf2b7783ecd60537c48a21dcba312520cde4545c3f7d406b410c69b4c87e37607ba83f4278c141ae6db111cd493f6cb39c6e1acd80bd229425851a675b4c499265e304e4f367574087044d686b7639947459011b8ce41b730f682846c2462b79825a57ba87586c5f7d49370572ea3249b0c95aff1a932812b89264fa94ee44f77c076228222a5d6c514a667154b81597904a69242d3f4321934b7438dbba52ae64cd29d1382f03b629987018bc0d27a7f8741ce200d2341d5590111a1c536ed5488716be04243075e95a4680509f33c4b987094188f60ffe7070080829d84d78253c6a31b3056ca55287481a7ecc008f5fcd57485f2c4a3ebc3f54c47fed09d807ca62da21034b31931e5a2e30de772213414d2d6ba74cb2f7714553120122f498802227f7691712aed45089ba263b46f6603f1380186d66abb65059e1e718b24788024f9a2d3910ce7407f005cd2ce4a12a640da2c455a6929738692e275f4506ca3b8c5c4a0999d232381b3cc7410ea8a5770b10e553144c6c2c963bff6cef072348f2ace501dabd550b50683f1f1c439e374698d070b7f3625207b77a1978fa403c314e4f1995c9ae3b13b4aa6f8b365c2425a02d614eaa304bd9dafe1e5fd58e5bdd8d8f43acc38614bf2523477b15bd144cab1266bb12523801286607a76e1472a5c3d16aa8e291377c752247843da61d7fa8e830bb038b084c039e39fb75e512f4a2a34bb6e6af4801b27c3662fc512e7c2fb753750d891f00b1ed1ef218cc614a3fca1f77d81053b23e05611ef4e561206819041fa0790ecc708632c1e936713306ec36ca2b305cab061a16195ee227a5605752ca538f3a2fbc7d6c3da3a473b800830cf3a4633ef95f695595175c263bca2a63ddfccb4be40c1730046ebd07fa3622214922126cb1d1d50531f32839b7c16f5ca83f87293444843d61cfd8738b05ea25e0273d74d685ea38173e5f2fa9709d4dc56be475353d8e23db811244e697203f0208b554964cec0d4af56b185f3a2b0e82ef464f2c08da5f5fc97753f54f8d1a246832584c25472d7240a9525c6a8168f357d31691e0e05e652f5d63eb98be25bc570c20cc2e3044ebb64a4e3f86bf3d139fac42a90c246d756ec103143ca121ead75e6a7e8b430ba9bd8625051b6849dfda384a272824727e42f15bcb44b06e7ed99c55e1697f1fff6a4770d871d23aea07f81697c6a5589592d00fb9ffb537e556da1d1b0a6860e792cc1bf3f3cc524479c31a2e4e783927c21a007e97351c4059211fbacae76f88f3f013863fab52eb63565fc054dd095455c16f0385831c28623d67dc692113b6b45939240d3f1a771fe12ea454fd3424b23e38a6a1de6f7cb7e21de8041a35074eb6670e19a23b4a4bc5515ff2bc1ef0b94435b506f16dd3827b3557ea2b07b063555ae50dbb41b5d414058b13a971b9af150158218b3b2a2d8d67ffb54e63566dd43df616cf442c8b1b76db1d7557227dc625b9d9c25c9ea1eb3f601214383f032976101761587290cf01673e01211ef2635cbceef745e2d3871ddc85373d1d7d682ad712c917a7459a4a5c753c55b34910031bf6db2bf445091f32552d48219186073cd5ba41613d0f318455dd250c03a62a6c72cb107104454733ba551044ac282356c6c30abed9e46791d41e490a2a06572da2663e79623b581822c952aac4802eb3be7507fddd4c2387b95f6e83386467d067143c3ae6611457d03700a7d0ca1ac166bc0084d85562e7c40e19550a7720f3e93b6bd8c46862f8b38d0a30903454b899595e717f5a75037a86054285a71ee206e50c9d33672f2f7b8f76e8e8d5211c9f641b5d552b39f3c2ab379ba88935228647235d360a2da04f8638f3e2d66e5f5589287d06250fadcb9a6c5fd2fa31cc5e5040f8525d73eb6b2c02114c6844b9733b1146bffd49534f5a5a38185e22321e7a0a73715c49ee5b0e355a4b175b9671811de709cc5c45af065565a38273b96a254e36ce4b358eb2af12fd39503f4b342e2d1ebb7d0be49d9057a277724800bcca081f3b366bd7134a47d00645723011506a642e4c3149a9e13012f8cf51ce592b24cead3e223f8d082785883d2ea6659964e774b365540a1a4f6cacc439b2966c21b02b73309089d20f63c93f770eeefa126dec204cfa7d244c2476d968cc98c8757bde800beff3ed45bb96c02cb0b39f2f8de8837185191873f7e80f1ad6884e37a118a040a6088d5a5e72aa5aa0328640201609532edd90304413ac4318e6254048c3876eb647fd69621b7e15b41c1e2f31eb435778bccb1c3faa1d268de033681dbc8b5b099c8b117fb7773c48203349eeb22f267502755856c97f0df4c54f03c51ab621c0bfa61b978c170fa13dec06b961102ed28e2953c5b2d40316b5ea429be5540e3fa8da18ee5b854c3017cc49403c8104997abe4dc3643d535d004714ea7339115d18235ff497546715e3693a9acb093e82bd7f2b5104bf4efca4740b81e6cc485fdae938f240cb5a16602863b88ed72096fff9035bbb97079472b63e07bc552c3a09415c43a4d5027725b123137a9939c98d851913ec8060b563e70d088259527a79701f71905409f61f70131389e73b26d9306455c1e833248f95422f05df5ad60baf22d2a852678011e674b7e1cd44d5d16636f1e83118b7ae0e7506fdf42a3d962d46581ed3750e307d11a401156138b32d480853e1148b74061cc22770203e5adc18e9049c4339772451c56c8925ef24a05221e5481019d52c239d01a2444fdbcf390e923276bf0bdb6be6a00a5cfa16986c6612f14404d36e0a10feca5d4e1d0875c3b8ef32f380ea0b5ca6d9166a37754c08dabf307836492dc46091055fc0556105c798719ccbd669220078568fdffb6954e05951c347051e9af0824bdda4623ce99ec9246964a25dbdcd2e080f53d669ba103d5de2786327c990df4b43ec251b027d0b0c4ec8ad4ad4998244a860a101083d2921f92dcc5ff7142043cdadc93010a76f202715cb6181f6c366d46adf53ea7ebb7348791d51db2182101dac6764a5bec24b01c5cf6e8adb2e2f9e1dea1fdcadfb1ad846de29cc3ab01e02fe7d1e336aa127a5316622ea91371ddf18fe397b567a08db12714cea68dc66c7a0c3340c5c97095f9e2960dd57b84b1b62b009edb1216fb5b5e064034185745303c94f0739722392189175601d514741b82857c2a2375fdd6444639c5a9a6d0c89972653f4d96f9ba20423adae4d69e3c3962ff85f616cf527096f4032b0067d785904b170d611ee701d209198e11f77b07b43cc613a30ac57606fe2cdee0761e8ff1179f80917a0d1db68c0929c745ef2ec5e6f49a45703686272bf9a7f0cc4e71f4f2e71bd37b64aca435267cc2748c3e33cfae846437deaac2bc754ee2c84a18942bbd60e6d852f9a554f215929d5eba336ead6b532e79abc0ff115b97473a3f66985b425545b844801fc5e340ea387007107d4000473c10e71c80e1556717e8748d9af02203771245efdc5a74bc67f5130e8bec54e84eeb5674253e23e77bb30360b9ea1066f4f7558e1f17760796e0b6b315efb542c3d4361ac0c466516d8f23eb3be0b692947936d161bb96a034e252a151dea110356e8269bb2da72f5d7b71568e44f15d30fed72feb80336b30c9053e754b46a27b80f62f5c3a404b733117205f82d7532aa8f67cb2c4051fdf8d2144e42772bc7476a3c56333d379597c54ed3cdf036ee804f5255804d4b04c60d2637ed9d5ed7edc8166eee8a2f62d2f770fe2d0136dd06a454d37a32209ff3ac308c7c2b27ba4afa2d5a21f60dc5cc601a9ba58053f06ec322a9828269505e3e514a35421786a5e616e2fac70a410aee0048c1276e42faab5dfe4d6057c0e73038afe7a577b5fe5b255af59a3b8cc3ef2d8b861e544ddfd33788ab690b0a71fd0819d9bc3d1541a40117b5ae7255eb67239f5ef261978dda06e0e5320568fe554755c7144678acc52cbfdabd4fba62ac47f83dca1bb12f8669a436d734d7e65245b8476a348914cc31bc407f338067de77a3b8931a70e351345aec791abb81f25b2a7f8e76fea17d4f3d743c08cf457704b315941522a28b4a16c9a207107c1c0e9e92be1a35eb8052c93f8e17f72c49517ac4ba3614b0124903533a5f731998525972521693ed7761458ef328d4ebcf500648c826df3a215321aa39211415d4448c7ca04568b0ab35f160662c7bc6cf7285685b5371171c470c513852e442b03d97559a372b9c8f47f62fbd5753ad2976b40391522f338610959c801ed7321c0d16fa8b2df887fd76e4896d6dab97800fba15010b109d7f3d2ab7cf5e3cd1f24dffafd519f638b45eb8bd3e6d290e895d1a36fb506e94ff0fd106c750813140252c68516307fd3642a0232104a80dcc6abccbc3124b1d680440dce832f012bb0d7491a24d86c82c290ab64d5581f4d2513f57db3f978da543b8a4b97138f1e90c0f5cbf70e9a005356816e535a1d5aa58f49f5a38c0788b1d1709a65427f77b6c28da9741ab2f5406f6fcea1bd8939220b2d2e709d64ae175bf8767710341322454acc80a8e03764f41037e57eab292709d9ae458d76f131a7cb4610fc6ac232120f2d266f8d59234c315592ae646c845154d99329844954efb0e4d379d2a8d56884d060f4f762b4a964bed4ac060b71bc2bddb53715d07157e85ed674b275f40761bda765c623234b7a1e17638740435feaac0393ae6c35fbba5de03e890bd2b1bab96338467e95b2bfb903060c2180834d7b94c3083fd3b3ff5125345845416c1d0ac24f274001854dfc8149fd8930f0fd8a6473e0b5f04123b2e1d41d9105cd9dfcb18135adc18fac273155e581c4312dd993a1961b813de8a5021e7c6e11be1dd2b40a350b849dcf90547ad5c73596757ed44e8ab4b5b935b5a5768ebf54833ced32186253718948b840caf41842eb2db592c1cd81703449ac40fd7c3412c27a75c20e31b1533b917231f2816990e7a3fec7721665922b865866ef1c9f94e5df0ba259545b755d2239f2b9ec95d3e8ed4640852441065253b846f73af5f212262c019ec153666b513b81199bead6c7ffc615499101d4f9652283fccda070bc1f3d57055cb775a56c66f61e0efd37637aecc459b337962b9f8240643105719dff0065f188b7473a2b9154677a2b014e5df43704f939b75ca52a27238b15f2da63aa154a9e6396a1c3f3325bd704f20d6f57a68a8a261321e1a3248ff3e5376b779ce5f7d2896302d3d976cca596755d37aff589cf2866cc8b8c976214eb647c51ca62a6944e724b3677119f5e9c615d10627092f656a14921d8560f37d953fefdc1f19f79ca7077e66cc62cc5cd30e1f325c68f390e34148415035ad6f6113aa29ec4a4b676848504b72527ff41a6f0c4311567ad3932b99351361f28d68536bfcfc0742205833ad96bf165088084ebd459c58bb4b9900b0b5c35ec810c333c040d40e4f50ef1c75219b5e69be814be2b982333d82a030e5e3593b3bcc1259655be34ed8dd16761558b44985187b666228da24cbb88915b2e64b0df02dbb05edb9832930e367579c525365d9a8714120a5335baee1376af78fb466b2161c58ad18d43c070b3f3cbaca1f58091b66772228526110ae826a9569a375522abf699ab08c2c8aea9d20d72d9c0e3b6ddd38f0d42834f247ab130fe9016537e6664158b9754c441183731993462188d39020bfb4e60509fa16582d4ebf23ff3f19288deb7a28b264783d68fb7d0ca1c013740dbdc63444fe4f21cc56970973c9543869f4c422dcb9853a6ddb0830acd37f0a9d9a7e5997a5a17760e03709babaa775894be34346bd9c075c46e743cb91c73e2fea57522b58343ba79e78371359a60ad67c7626a1bc1822d89f19687aa2ef3d652c391ba04a674c02b5ca1111447563c58a6c76dca2fd59b5786470e8e7132bc9710646a90a4903bc4dd73cc409fc462739d206fe39610578550441b7ce342bc8c56542e59df50ffd055c6d41784700c1b8c7772beb8f16d90d316e1018a0549843970e7744342e453fb03925963a276e63f5178554c65885df7b7713a24f0068ee7c0bbe7fa90b5bf0bf030788920433bdf803c92b0048dad7d373777f620f0f646c58eab94d18276be33c36398776ff64a70b2648016687f29c24ceda023b7d71d95ea589da004c3d3c249614e572b3f7fd439f310206a9c8172f9d3e7d05d1e2760abfa8204008dfb91b2ed2111639118c3f7c970f2dcc39d46ac43ae828d946fe07e53951084d2e155cb97b1c689bc0230ed0430a1bacc24c70903afe65745c07148c8cfe47ffa74b43f391310173a1c3062325b457cd74316534c58d047c51052f4a85236553dec4560873cf1999446847ef42fe5eb5a5514bbaf102597fa00e30c1bcbe150f666c1d2649a33521a01665c14ab03c24f87b5c624e3364f95c9530a7771e2270c2fb72a46e3a16aa45cc5122a66723c41c4356c020395c9985e2111e4124484b7b82311de6ea53a4563e3c57b73a533e4e2155d6eb5c3bf4f7a52be6e15b720b8e923c829dd646a5e8570267621422a4e2ad3a18d5be53b4acb36fef2b004a0493bc5ac2072e6e840ebd68829da75aecdb6a333d8f1a1fbada544d045c500fb9e76c138e6a6524daa4034de567a518c9e94f163cfb813192a28d3578a4a56ba15e674552687c1c36f891297171ae3c0684df0849559353abb0e13882053f6127088330c6bcb738ff6c4f550286d508ee057245217aad4d4de91b5b8a878f6071424949bf2da23aa401ae1d76f4a86bf142ce652b221b188273ca35f94ba06722bb0d5ceae4f62a88aa0e1106b1864112e86b73d41f7634a80bd840386be351f8b17658c5d60b038416c25cd778ca5cca2a75065b6a1d3e9722326bddc19a0816746e103b95790473a4cf4050ee650cc8fe2948f1b5980f73340e2212124452615c025ea7f5b823b4e65f3bb2397f37dad2f8571c8bf04674909f487d46f00b10126e4ce6dc9e2785f20a395b473d00a993f8514ed6e04a1c418e3c71ac5f5903332931dc8301247ae6bc753437e50da1e2c421386468694612615dd6f2934791093e1b0a91f24ddebebc5d6213061f1a93620e29d68f60fc921e7452eb7104f4fd745cc6237e129c700c052520c46877f17c22cb59e92cb7e84e2573e2231cc4cf866684069e41f882e74d7c751a6cca428d355c6a222e4377a00a780f3b6e74b7743c31c6634ae91e9f6c72aa157032207141d7840e467c4e840f993bdb57c395b1771c968f0b1bd1087664704d597c750c50bec64d56ceb65d38b1dce73d3459ad5361614151b897fd527bff9f3b3a06772b92b0275fe64dc13fcdaff6593ca6263fb2a58d009eaeb15bd274a126dc0f2d6b606ac0121437dc5634fa4472cb92ec10ea438a664139f108025c8f1e39f3d33795e92b724b1c3265850d9f06b694a0565039e12419d5a5511518736fa9bf40212d15e00ac0c1626af09e2172cf8c9420d69c745fdfa7000517e95035364de135d9371d6e64f31a58a99a7d59318dcc61edfb792a5bfbaa6ba49592295c4aec33fe0fe173edcb8a117c61cc059eeeca6e2bb627095d22de473ecfd202286ebf70f0da510c1731f068487abf48c3cc7b0ecff36835a126255a5360a56290ef9a09b64de8232f937e07f2534d0ebefc70043c0d834677d0c45a89565e6cf17d664e9dcaf5227e03f66c89d99c6cf01d6621dc24890146baf50a88c83c49ba614b2299dd9103ec4d5f6b3791e608141235055925be132d07ee03fefa44476bf1961b94703768b1e6386ca978a14112f19313967a8118a7ee1b2ee639033ebfcfa070f9b28020d248f441ffaab94624ed2f390ac20f34ee6e7d3a46322c2fd0d6d404d47d82501dc58e2de17b4c52318c0f45201f912ea0bbdb1fc3fc47673978f253ab2e844f9519410e171e7c55257845383bc4d21d8d04b65c4380ec1db9603a376bf3f5665fccb374411cf0465fda1b24076cd54f0fac1b1812559815b88aff6e2b4d485bbe30e22d08882170aefd5f0f557d923ca3933063f587590ec17fd82258272c261620d734b1986c4594fdd3038246576515341e32a677c0079fdbb14b57296008e417277128971f477109f150abcdba1096a0023b87d37404a5074b5f62e5a76973230939b6f1a20cea04dc3c0029bf6305f0a40757ca6f523fa2f91e1924af38751a7c68c7feeb2e9f899268aa3dae080022c35ff57114102c4cc80c33f3e06ba09ba9421b167b154e5fae25973a142700682c637c101055d0da005ba5fc2a7333a7c05767b731629132fa0db2f95163ad409064e8578c3b65f0643870f4b7500f7a94027ae1585215334046938bd36f747ccb05e8a67c71262ddf3d39849935aee58b46ebd7c7207cee836693061f6fb83d8c2b3b3b9630f8c40c5187ed5e3001590b06cc341536c3c36122c045c43a57aa8618c4260048a287e7476ca52339aaf6523f2ebf7e37a826d471141e2a6d3c1e6973bfe76b0dc8ca802e00206071d12ae64312d3ab4152959b438a31436d18b2c006fd766c577762fb03f2fd00323c161864c2e4cc01941c8666482e1c363bfad57438cadc05621bb72a6c45e93248cfb5740087ad1c9c3ad45f6552f6736106703189caad2bfd40032a2387e7704d5c0a12bea06565d31d3834cc2ddb3812bee92b4fd797235814ce3966da4d3b7cd7eb3f0d6b91410f3b7d21f830232e9c932f47b1f4dc2bdd51ca5a3109c567fd7326110a076c6661f77b3f0a5ebc522267e929301bca2d8389a90f10c8e655c1e1c176d1c80155a163b85ce74500214645ec7566351221867624563d26e66be1d0306f395e3662df5a5e36fccd0e3ac7ff88596635da6325ca6a0b8d82e64fed821d1090a8c50d96be5d2fddf46c47fa3eb408cff8151e04d9ba101650034127b6bd72a1931a02cd05e329cafec131d470fa4182397f62714efb094cabf1575fbaea24fc43530630582f17187e5472eef6092244329a45141f39078387993ac7efb457cbddc32c1b1458524c1d627385909708f5ca62093ec1157750bab92147d5b11bc7e51e523e761803e8da8f027483866b12348d3a8ce5f9239e6d5d61e9a02633a4bfd86a5696cb1e9505b25ebadf7e50a37e9755bd884d02e6474c67e1516a508ec7ac5d153ce671651c8c0320124231e47f50257433ea61e715d91f2ebfe95b58a7741484edb2546ca4dd38ccfb98024cd09b07edc2c7246d2baa5d10cf934158fe5f15ccffc927506b234242030a151c8ccd686c2b942ae7d2a34970fd0375df6d5236ef98dd03a0c68545c932cc5555126851bdcd0c549ec31e060398350679f6752125991b57e2ca356a25a5d01234f9064564915b692d920976793ca3582c9774669414964ac777491c2c0b4908e7c9f842be4953546873320d5d19ba36c3a7cc5f51da432d6d2b004b07cb835de6f72a0678938e2c69481048978b610cb50e586f1e20f95250e4120f5e83ed35908f586e6c3d5263d5268963cbcdcf406549f108c3e55b3fa917b7124e69d50d84344f62e98f1b1898e7bc2778b778006266395403e0c73514c42477b1ff4a211fcc3a219d57b73424cc956fc410f31ac65d10094596832336fae356393f1b7265fdb36d13f1dd0d3c13f402aa8f8477110241472981736a1762251274f4346a56cd42079711b56763aebd26ab254a367ba9e621e9c19c595bfaed7462514d279d7035711d7fea73ceccc460b9dce32e9dfec826b597f0200fd23e393ecc3341e549906607398a5e98cc5d5c71ee9a5f7c88ad333e5fc15b4a65592647347933119d1843ff0eb14968ab884cb01dea5fbb34603147d2111480937a3f1f891f23ad3631129d27355c9102a2472a25dd18f312e54045c3ec3fb1a10a6d6a118b61c60cf64944e270649b26483d52e71321449dc1144f405221c181776945f1f352359f4a09267dda2285687b65b13f6558c70f3f5cb913ce2d15abc4336558c8379084c701527d424dd7713b25b737d315f6df6a0594124b38575b792c2bd9e42baf071722096b146fe38fca2de212253b5a39813ab6f90b4e95a8801e69ab873397c70c5b8da5a44db7dbc00c84dd6d72cf665c76c246a172c2ec4c66c31a481856338c22a3b6aa6ed7071d242e8f4876b693dc3459d268117d0e5b6904593f714e07aa5914ca61255d88d9261eb5164d1bc8a46880b2c074b0cd3a384ac8b7581add4467f8d80a142f922e42dbe7f476222a224e0b6b3a2843ca6842b0f2eb6f631a8d68fb2d704b2960c763b59d7b3410e910371a9f8262ec1aad603ee5a86f5aaf4153bc4e2502527ccd22c0ff5e0272bae74698f06f6c9f909749eef34c413d6a652f76812a7089913b1f9d6d7430541ad1408416d9453a2f3913aabd3e1e78c2655f58dd4b3cf813b06ff30a0b04e59ed227a5a873147bf0262f2bcc4d3129d42649d2d3b752ea6ce1537934e83f226bd41780e9bc1838f74e3bef08dc61c892e81d54d717778d8d94553228665727684f460e1ba6120f2852372ed8e0468c7b444cb98b1c5708643c4409e228675390d4186ff2d736ad8888281194f048cef4714068d83333e63d7d3665db810673db64454be42a5abaa4ff1971420157dff2d40c7f8b495681688d5c421a7356ee98a02e02cdb42a36e886269c544e48699e2971f6c1776b6fd4c30205bacd3ccf20c446d1a0203efbae1e640ae6d342bb429a53ada1861d00bddd53b397403ce532bf4ea10076555399ff6356c0de106e923f40e8e239169bc04574baf74306c1a2872bd8f43040f95a48066a25f277390c1c37ffa3ef3fdbe2d21c4a1cdd550880793f46143315f7ae8c6580c4932ab30b5d67332d5e6c5606a30818d75d5d18074620aea54200240d4b0aebb3743003eaf76757d5d1690de22f1429b83624795a57603f619044f703da0dd31eee172f1f7d4fc883fe60b2f9750772b07a1555e85e4665edc21ceac4f005ec9ee227d6c5a26bf71dbe729adee55bca065c24257a0f366a79f645a85a93481c927407cacbfd5b6569a550914f8a2302eb321e38c8711ed0d3262c1dd4d2211763151a58765977b3aeb324186c8402e802882ab3d4cf4b70323b227e17036ac59a24406ee2713bdbfab463161b1d5dac9df6640a0061157a51f50f3fa50348ed2bdf338099e85511c0460bb9a6e10f4a2ce36e7d0f071186f1c10d7efa1335c74597225c1092609859f6553f8e291f8a27600ffe6d295e2ff6e626145d7a1100198e32b848f76544fbbe4dcdf1af5a13b8d452ee4867539d6a26452f9a5d37af887b5c72e79b616501fb1904746f0386caa45ea0c3b50790a8ca019fc0201dc3fc053ded33b165d6554a37a011726aaf8e83716b947d297c1bb511bf684e1fee96c2186ab6ff4bb119a91eb1604834fcf2361d5d19b63b5fa77c3f85b3de62a400c54a5f65e9228f643721c7c3e35620c2394fc78f205274d52e189e97a404d542e43c918dba297757d33ec48084691e294625b8595c778ebcdc75cb40482ca4303d7715a5e43daa278668be96fb7282d0de745b6b245f8249bf5b150c3338d86cf63c8c56010d773fa7573071863578b99e61fd1e2d13a407c10c9fc2c61cb682101099990a6014cba160f694c13a22c76f2a3806342f89b17905e1c5416eec9f595b20d6344b768c183c18eb790ae3ffc512dc2d08355383795629582a3ccf2d6b3d9184954d0c85043a7001ae1cdb0c747666805b50ad5d467168c27a33df29532337bf4e7608e7201d76be172c9b7f65619c20f85fb4c9171b8818840c8209dd737b8d98581a040112592935395d77570c35377104f26f2058c0b68f47172433133168777717be2c60b00312043178755a257813576fd05b4963760c533e02c460074ac117de1ecf52039d5250b7d4684b47945e59e7499a3262be9a3d24b2e535846ce22b4f3dad2473c3162e75f6a45c3ebbe850861db71ccc70c4372f72081e070a512e76e5cc5eb45af24f7b4b0967990abd3ab2157c5c698e5d1039469853ec1c5f01ee940a6ba733460e72ed375350047f6b6debca5a71389e718d36d43513ca8e33d8ae264c75c48d0749545645008532443d1022179e915b5053a7273254999167f407384d7e5b254fff452b1e29083d04816d2d72ecb5543f8102430dcb982e3f352979198936a00cadb3276a32ac2234b15ca0176bfaf63b05b21546ff54746d1c5c255f4094496d16db5b3d496dc337664fba67ac410c295149d0402bd0703ba2c9f93aa1977c63e2c7e50fe54705250e574a65eb4ffe6e291e7c2b55a76a1380114268a4a463619c0ec8094b0acd01cd139466c094594a66318e7402ebde0709a3b5227dc5d83907ecf84db2d87c0c25b41862a1521d4843db0426775e632cb5dccc5637d3d24abaf23c57e47b7565d6190e6253366051832afe0ca590ff06aa23b52ba9f3e2675692dd26ebd1a70b0100d728cde4a968225d7938e8d44040c916277606f5b86971f8d02d5bb632261e730727aacbfa7739861c4917e7cc12b43ff54bd0289f3b5e20b4457b9f6044e2e95d3bf163e56bdfaa7a06e79380239d7c56744958c25202b12d0b574e9e1c8f03b35378cd6b1a3413761f2285845fa0d9e2647bcb490fb63a1970636de561db255b2b62878c02cf3c1f618027635e9e3e9835bb7ef971e7fb0e28976eb7266b21dd082c1bde50706e862ec3b5942bc144be70b138a44e4995ff629fe32d1406ffa85ce3feb55557ba5e0309b1c91d31e20a594a9791645b5f0368ab1b79265e288a401bc7d025667d555933e2fb1e0cfee96e14f99c5bd5f97e404b71a245a3ffc848cb66395219b7035187d22028924ff507a6bf845514032c75fecaa31d20560a1f884601255ea79d0ae9efea6b066b4c0e5546821c84ebc645628f16577470c7739d13b123f90105451ea0a25ae88ea075127a01328745c7058be5d55159106a4b5b1aef367b40a534fbe453481ed74d22297886400458974dd505762d5fcd956da642044defcb6655cf436b1ff740dd0cee543731a42e4e318576d81e862479418eb0075e32287c535bb82218230bc755764e7565d62d1c0216372c5e83aa9b1db3a28f25f63abc77455ba901ec620c0d03ab3c729429f23a95a4a834b077f32139203b0c0dfc7b5e85cd6031b0fbbb355e0e902487e7864553d36471d0ddf01d9de0641bfa724f2584748256b414c44e2053c436364ad924a78ef14950331c40fae37f245e2193539b1d5e17a19cf214422a1a23eac4ba31164558246a0dc428c26adb55fd5b40080724e24fda43e95530fe5152c3530c433c67a1599c9f2b480a914b1205609e5de5b87c42e0902a4c9edc3a38c5303a31fd531063bb0d9512e3f5c129f3d5f22217a1893ffe9dfa571d1c65184d655c3ba149c02a51a9cf47b5228b18a025ba674afc8a560f2cab407445061195735262cf2332421fa230464e066925f7152518605e3849593cc34b94ab6667b9bdaa0de744102ae727ef16803f9b3d3f79fd18d052bb24d480176fe1cecc529878e00238e6ca2a8561693a8518ca45fa4e92041f69e644af291b406fd34c07b6f46a733701382cd1bc0169919f2e515e4e6164a54b8935b622303a75ad962cd2fb6828ea72621e11009d6ae2259b3140e0f82bfa2bac1df8f605053982844851d6a46139557b61bed1da5c481fe628450f6f11ed968f1c990eb05b26d93c2cd1c20336eac90d5adaab3d5c642a7d7201ed436570b184014441621bcdbe5037664c8b584b52c654ae1fef0f718a0367fcc9df6335547f369de4840eef994c358aeafc64d5985964feb0503186907c0c82bd6227a7882c44a6fde13791694a7113934c631149223c9f13c359f266f55dfdd3d509a086c931a04e3816eb5ee03628d81a32b2f6d94079f9ea0d52909401e9c42a773ba00514d6791c68cd8fe73833dc7619f5d72b6a6e736c26d0b9201c7c5cb361403ab352c2112826fa713f2d2b9ef31e9077470284026939b7dacc1b15fa5f4cc108f60958d3294991a2501b8929ca1de10ba13b5bc630149e1bc761f458a06943362d35f1f96349b3fe1574aaf4bc4c8dbb6d505d94b1084ca9292199e4123547d31c5e6b33af5eb2adee0e58ffb72f1936ed5db9544636cdeddb458742cb0df308a561e2378309ad2c9872d5bdb821d7e7212706203242a614d32d893e8053cf5d5813daabed697278bf49f7197065b2d4504d7b7d4018a686da0fe82b584673352c64f5de0a1bfbaf063e07a3241d5d508f0697a4a3018ac6be24e905fb0f1cec2f20c71936154319204c352e6841c0f4e6149b779831450e8a2a3d1a3a67f97a4b398cba4d3a43bf72573793bd2575ab2948b8feb267602b81010099b131ece663328b8df9099dcb1427087c95331e406c1917fd6059fa16e57309afe2162d2908604210876c746474419abe9d44a36b745082bbdd23fba6d56d5c8739420346fb588e86c2526c253f5d57d1124ca11548076f624d66b024201179998f4e31a7e63475e50f4ca814d64c78e32001862ea8431e4f0a5fcc49d063c2de690ee5a418045a43962fd3e32f20d9c5b77042cad257a761f47590675c4960c1a53607c03f2c1dd05846754b05197a3e5c48d95067344df8d262e659010a6323ca063097ab4496f76c75f0710e1f671a161d845df84932ee2227f9c04526ebeebf52822a1b74395982143333ef7536cd2069ef893c27e6e76b5ef355b62b2620fb23c4722d6a7812095ce9ffc5090b432e3611e7a7693070ba0b19616535bdae9053adfe97453ed16f1d70a73863a768ad0d4dab4970cedf0b40351c6f21dc8cc466f416151b0a54f02987f9c814903db72fe50d5051fd8c9266ac21026169a3a94f9fbd4845b89d3e0f4307314641f55b254d7e754315b14d4395838e4ef49bef3eebac001b2275625b8404ec02ce8fa16d9df86a29aba38f3fa7ebef38502aaa67ba4006766859986af23ba82d6acd2202b9d55706b0670429f4afaa47de43f96252f2966695c15a3d60f8071f89c09d62d950d16d342a8d3ededeec288ac69e62fc635570c0044a706a21403ef5e07d4755b63e4f8a5c080f89ef4b29102544255329d94af17ed903bddba46bfe27302f4ec7ad5e259c8417c1e93421a660321e7edf3b1033d26b75a1a1ff04096f2f5729ed59014f45f0528d43ff2ef8eef33765cc064a821291082593301071e5d8658052660525a0045c37e6851001047f5d1de29b5d2d72c44ec97aea5bf64c96038427a22553067e41168e5343198904323ee9e04ef39e9c47f58d971ef6626b1d6699910a42d4bd3709b5185c2698f728b3d4b309f4e43870e2149d3c1c54bf65f6be8a55843738044cceb21a66d6c62e0cd6ab1936faad2e7fbb0c75e117571935d89a3a4e25a31badd3f1417342531c928ef622fd2fa95153caa043204f5f50a404484a347361447a1aa549b924bf4fb28d91363877d46bfd89b3141fd3576ab63eec1dd5a3ac4844511d477294a41555b4e616a237df33da64203bac2234358e59a649c3214331357a1a54b56f1c51b717070fb1ace535062580332ddb436ab8231802c05e714e35613b4391ee5d397e96621992ac453fc9ce7e48e32fad2a366de43de6637b5e15c16131a0467c5d5523fd3c27377173bfec3f204b662e33b8066b2bd0473a499be8cd401d711b311eed6b0a71cbd91d4b5a7110c6a74e2fcf7de9759f08112bd18116517cadd922c7ce5672b8f2ff0417f43612d4f4544c83f9430ad2f4041c3a81e61385feab5be7fb305b89c2662d1d5ddc00f274b320f88b3a387f05d24b6ac3a0601e1ba440e478d2108fe60557157031079d3ac76ac3f1ad46ddf8e304978b9132682e594ec0ff1231e1bbed04e9e0fd369565b625406e7e114df0f53525ccd96924da4c6d91f1d310ab50ce0f42deb706eb43e16e665dcc25db8053392033eb0492e90b28bbfa830bf78baf67fd637a162b9d67658f73e73ded9a8e15c50a024d2f349b5768fb7b29642e695b95c4d567944119435eab022da4b20564b6581b2d21c18842612cab567b712f1d6866525ab0708d2399476e6a57e2160276fc0330ef807d4fdeca77309a45e61419885c0a54118830e90b457576a1e05e970403228b2aed355f4b110853882d44306d8b6a3ad5b44b6d60f60c19ae802f32263a2dce53eb1dcc41d0453041e56713985452c682126129df115d84f52548ccdfc92d168a6d4504047f3365561a668aad7858579c7a6379f971131bc7fe0934c0ec554be9f020745e34015b202b451c9310367129ef65599c774631b2ec4e1ee09e63fcb19a6b02b5c40f5d4f6a0df644fa4e2939583bfc946c1b6661866201245d2861d22874dd857301c8d34d71ebc89964d576702ba0cc6941513971435c7f531cea18ff76e1581356ee46b045a5bf860ffb66891c286db0541f128f3c7a7f271c57eeb922231b2f3aab515846e4402f0f5daf463b84f0696d1c37356dc3af0a6a6e462062aad4c36d7cb7436c2efaa036108e7d2486878735954b334144becb5dc15b47224a05e72046c44674a0a947183f705b557b2fe345cae00f05b7843525b134fa4d5acd4832c513f944d3c13c4ea8ae432852e41b6adaff0473de38ce4004cad612429e00631e93d30d2e293b763407bb3941057253228859560c93834737f83860102e62272c6cd70580fe3a09d7a955688bc4f1201973b936ac50de3e4a8c6237fe858957de669037103ff14c1f21282187359f3bb201064d9f2f076238c9d3188a7e9c11711fbb59e73da902ee96250ec700045ddcac8077452e2c74f2579547e68c844bd918274de6aed1306db4bf0f197d9c3083dc6a3aa3d86c1d91afce694816df2001a1563d158c475f752b601b07f56c4b22a47b6dbd9ca62a04dfd939339f0b3734056f2761f2010ee494bf4f03691e3402a4d7072b7de42db72b6765e7f44a6841389151ae65e1624298817550fbba65e4a95d0f49214b16c5c9ac4c89bf7516ec86ff2f8cc8d432d5997e40f32afd48bb1c7d517ddb45319570c91472a35c09cc5eb449f90d4b314c5071325584cd4245078767429d74191466246759b51f661838006b6d19031cc4fb5e594a847334f13c8b27e5ac94681cbed71f9cfaa235da862b583deee74101db761219a2550edb799e57cdb6b2606dc95613085df765259d5c603f85a308cb69256bd1a07014b31de613e7c6334e2e18aa38fc41e810b519d35634eec3645dc58e3fff4bcd658d49d261b02d654576d82865e193b75ce5774674a372dd3a45d793564c0dfb5dfedc603228e33832f4152037cd740f3655dbb84f87577a6bede666624703613ab3e4c414f33a620bedcbb852c4fabb191c4d645d3e14775bdcae236d8e2746256e11eb28e94c2009f2757b752f38f0040573bb1c411fc316117e811ddd620e6c5ffd3c0a2d05533eba3db42383ca5329c10618174707331deea6033d1a2503258dc2b46edc810810918c2326d8220852c640413b90b0c32d4fde4a148109af5f5e2d753d89804b04b25a3f0883fc4e28b14da82817f787353b70524568b9170a20f38127dadc7e62220ea40ea7212f73ab365338f2a0cf089b9e943b0120b23c17dc636e709c523ead0dc630a76a3102d5e16b28b6261a65628cfd620ee3811988db773089f0b554f009d2605536e821f89aab3d866411555613cd50a9f6570a975d031d56a43b004f24a270452c82587406d708ca06832f6f963f0dee2c7130360dd7306ff13d1591799c199b1c41031c56271b27f23d537a45a20b8d4427250fd0ac470afd785b8df328114a962b26e489291fd1882a37e3d8ff3131777037a96ce643072ccd26642330216257d37719b57048ee34b46ffb373625901e625625c22c56b2531b2ff8090063432e0c6f25fb97331a8f8753795a276136ac9e67a7e23f2d971e662b3266844eeddc9d5e136367753ac87c64da5880287ba1360ad004f06031f63e30a84b3f56505e1d5bc5d5ae04668a262a8523d80d4df3556f270f6b1f1024bd6d758fa43cce8714195db4ac39477f5119f952a2201ef96008e502ef4410c9cc5d768ef50481568f2aef5d4c453fa1de56834b3503b78bfe1d084a1b24020cc56660673c1d3679e33e625e881e13007b08fd93236836019c2c9401a31bc82fa52ffc66b90435459f58ec3c380a436785578c6e4157a56c135d0fe6002410577a754d91c652cb306f74e597e9555449c8026416844b57d6260f23af7b5db45683292335da76a044190369ec044194eb0534ab8b242d18862540c9f55a4cf89adc5423714b38db77102539d7097354cc350f6cc1d7520523a62e1dcd425e44391f4ca081b538b9904e079a35a66d0072b30bd5b52d47c393881f752a3c7064e3df1225d7f66a865f59680842be755fbee07615983324777fe301ae0ab42e53bb8f4a64e0ad2402df4165cbf9004bd4ae57562f3a423a55de476ae1fb2541b31eda591fa646529525134a12fabd1e0bad537752e217412053e40a36d6561e5fb608495cfae73d2004454cdbd09339f69b3c55cf3aca49e6f2016f60c23e3b1f2fb72d54049b470a080e63bcde6c45d5d1ca13263a8a6774b4553fa754701dab43666853eff858e044ef65cfca8f1f38ef9d162dd99a28ff32d61129b289249a70a40abeec652f4b0a4454eb7bef4660765e16053b650ddaebdd0499cd046653759738e7d49c2ee3b63a5fe7af3f59589d364d803e2f53b48fd52484f8f312e7e1a060f547a83a2ea05f0d7875a902507eb70ecf5f3227ddbb2b5bde39fd56825c000ee69e72657c32810d0b8b70319f2ef636a1596920daf38171ca323a1930c8d109db5a2d441ad7ef6f1b115d51084ab809ed0953203675b84f1861f219eb414f36b53e44246ef2962491b2be27996d4562179246367b17701d06c3d849ecb194055325be4d85dcee04c0c468381017810e892ca92894de2b21dcac4e35e8ce010744ff371613048e2e01b38814837fab12a938ab1862aad070badab1325d9e063c3817691cfc86af2767cbc32c043dac69657f525f60890e76d5187a7251d8de3f2301b3508d29ec58b207bd04d6aae75e9267a051c535332c3e0b7967caf79740cc512142dffd3f03fbe9e928aaa4ef03207c122ef92e4a165a0c0301dda385745c9551390a004520335da622db089c2affe4f951b6945d0fd28b36202f63430958d8f16de0e5921e77a6d747bc66972bc5dd977199c7c715486a60435344d558c82f841d48f2cd73b9e2381d251cb556576817465d9d7713b701c844e8871924d298b02be47e5a26a22524653e580963758f3a618b2657315f1cbc1625f9f36d86189d1c14d6076a041075371996bf3c2739db6973e8a328c663273026e69a5de93efe29239a2d31a488da3d58fcc34002599e1b2dfc4d2f5d4346750b317f5b9da7991a61ffe709716ea0661105606cfa00b948d52d2418029e485d7ec12e558e3e3443f7815e4222601a00b318b94d11b47415d2afcc3b378ea123c5771b1b0514bb6a275f954f92ada0572de761624903ca64463890151ad84d5a635f305607ab814e29992e467dca077547fc0e369049ac5fe5247d0470aa142ba07f371d04f6c8154f00f820eac63e02a8a5d81d63931c1e9701c32d18816608322fdd5c363c67077b67d43008b836254afc315b318f770cecb2657412c93d588ddfcf1d9630203bb5ba445ea1bfa62fb7b8ad6ffa1ddf66aadba252a325643b6c249e3143814c4e2de14b23c5f0ab0e012fc14e2559495e79a5dd461db3445ba6301401f5b38613d7139e5bdb35984747ff631540d59a412dab6535c984d22a0fdbd53a75ce9b2fdcc26833294b4069bf082a6cb834646223c2386990c10428737478085f1a413f6f9e465dfc803f44a325044802a5f80612922a2ad1e928091e52691b5ee1a1303be23e0b8b7fab60d4b6e67298cdf03fb34618021aacef017ebf3622d915e54659113f5d67997f6d0718840a529f236676eb30374d959205d4510e3afdd57702a7b38c230df6274af06c47571580a45f95594e2f51399223c00ac157a3c3ff4f1f1f65716698fe1a955c9b0f70348722dd1ec727f321b8071bfd0936725cf30a8809e460209ef3351be0006ed06b7666990f7103c1dd9011c614433f1cfb7e245aa7b256660b3e18d7575432db03eb00d738555d8b77c74d13ff660c1e8e014b1873c258ea3d8421c26072530570cd3ed9e4dd39edb999347b501c214eecd329f775de0fb81e9b0dea89eb210c4a3e43ee3c182181eb62287922bf44046fe85044c41045ab2c4a2c1539c669f9ab4f6bf2e3856a4fb05c0686a35473a766243a949f5110820fae082d78a665d8268a3be29ff3259f355101d2c99c4746271830ef71bb328d87cc0b58bb306a3559b830e2759c4c9a8bbe366085ce6f8397150a84c5a2261619cc1a52def2076d87d05a3ddd9b704e96ad09568eb36faca84c74f843a476907eef0290617a4732daf3599fdc4c6791d9ff02b2c6da49fe27cb4e94db272e77e2de6f0850666ce06a8c62550403005bc3001d646b831a9865632102fc2e3c738ebc6df8c87e27c285400ddde5715e6f6dcf46392ed213cd3209641dc37544dc1eb311d82bec305ec61b624483882e1628cf777d093570c5d5db2efdbc1c25854b474c964c5e726ff3f14ffee9606349f80f3ab482dc5a5d5a8d527c09086591f907138d933e653b9edc6531cbf055d773e01d03a8ff1841661526f0968d3d117f8f769b26881067ae0930ed2c1c13c61fd36cdf6c8329b8b03665fdec6066407c345c6a5e2c1a6c87ab77e0db672d1a16045be1fa250c8d187c19ce439418a6032a5b342d094332ce8b0e0ed8cc6c62a25508a3f7ad0b46efc4602ad2360e5cd0b45fc06eda5d6f1f846f33dfe236fccec215fdb1f303b1ee97661ef0cc483a07c30822d26c706b154b527825d66ec4ae0c15a0f88d7793a6e3410d45fd3cb7b8b7537df4f1356846525043aa835de2bebc4ff0264c1acc397a6ec8248c016677a776be4c2a59943fda066e934c191d05b623e90b33692c4d3e3117ee9c6b87d7bb0088de6f7128ed8828b17dff1d0ebbbb4761f67e5910d5835472d0955a4fbf0f50369c6843f11e2032d5b24b183149481946244f6faa5e275710928560baed875e466ccb455ef28a0a9be4b74c8cc9f1199939d97659a5e22e3209f95d9d790137a39d1a301ebead4127b7051c5fa1756b3ea9ee0f845398508fcd6502990a67563371b31d635258185f0b251ec22c4c2e1605a129c2d96854541e6328d1049d017c89f7453629172a78e6606211c3fe4c18ce39220519592b9a28883ff21d725e6e4c6e62e639b73883b4b158a245c068bedc59246ddb8a51931cd1731e317928a429861ba45b0247cc76cf4e32d5216867c52e0549236413f17f7f266ed6570e9bfa64528abb441f655dc23d6f2314528376b354b73d014747eb61485d6518008875a65a85f48910f11ccc15c4d67950d74d0746a5d21d573d1d5307c1e67a14f817bc56807d52749ad08d2f70ffa01d11cc996c766fc2023ee88c20c63c1c5882565c4ec1d15003cb72cf01c124de321bd86218c1308937684d64559f0a301314bd6951d79c515bf525424d65eea37544805700680c6927fd383844a8aa2e18cbf1a73716c5e91902c9be1ff4639427c2179e416d78125cf91c030e2fe4e73da7254b6f59284137eb7eca1810dfa3381bc2703cb7c5d5289c686a27ec541a5773cd511218503d736e5d673e4274e12e61916969c3453105e022af67fa99a21052947115edca9307856c973b60778c148188551895c0625368db13680c8a3319b379af4493d62347658164640c793b67644d412b4199c100d7039c013cbb995360f63c265e6c041dddaead637bd5e15c33767433a5e89304b6dd974a63492502b6663a598017a047b025104bccc4462d44c87e6c54b2633b8f573656f2a7ed3c25bcab496c2c0042b03c730a8a46c96648e4935911f59d6aa717ea22e4aefe0ccf3e3b1138fdb426c6b4f50c2533004329f98c5d5d2d6b5a35cb2b317cf5b73362f61b1f1ed197192c2beb4cc1cef04e2653ff65bbc32803aae7cd0e480ec610dba8d738e4676659d99a355fe9bd4a502c4bd334e2b38143fdfba04bd9b9e4757918a7121c2ea32fd3acb12f93337d1a87c60849ad6fb970f8ff45456988725a8f01234fac941f5cf681534c2bd978186d46927379c4564a02fa543f540f901fdf7bee6a2bc5f969ee899e6931658d5815a70c61314d6a47afd1875d2832533ed65e2e6798348054a2c32624bad4fa43ebb86217abe3e94d1440f53f0b3f4e23a875db71ad28a400eeb7c158a8fceb4baa62c5347b8f4a2456b5086cf6947f3588e78222ebf50d2c2df59c36ef82a70d2942e11b7fbc4b3068b3226476c3046b32749172545d1a5df3c832228646935d77b25c198b0ea5593dda1f43a694f0159a27d91c42c3270b04533127460fac3ca7367c57a7489758f16782508a6d920b6cbc8b307f93d043f7f026369aaf9b5f753b7b5c5b3e2659fe59df6aa0392d141c668b51f73ef311d45b4f369d6098452e86e37204164b0b43fcf62cdf849663cd94695e690d4e22891c2b6eaf63ef3e4b22fc6ef7a504711fcfe912b7617e5506d24756013e1b254b589c697d746519d756cc2ebbb711438c0f80258316f5119540540096a30e68403a1730d7439b4ffac8525204f0675e7cd07e0075d84b5ccfd7e21e13780000aa53a4589e17756136cb46369fb4423d8542531265b35233a4d4090e53456e5fcbfa2223c51fde3482112a5e7cd13a33e9ce703a1c414b2dc6c6dc34384e2114620a0026c06f9d61981dd9191602560e5cf979465f25e43aabae2d358c18982299d86743862b6a6313bf1406449fd73f16e4d270b9430b3e65ce0a374b0c1815f260440b593e944836f7cd6390e222520d0b1b435ab43a02941ff01986e03e229a0bba3ba99ec35cf0e9dc09b4107b325562d954df68f05dbf494c0f6e50e069698e4b1e1713d941594587680d9ed11fbf1e46389b777b06b386b52ccdbb4a08de34290cd6e81b418d361d4dab208e143ae6d9354a8afb6d49a2b273d3908c4f61233a027b19f1263bd49d1e7188f10187a16813b959505cbde37d6a86b94721b25a745ea2c027080776fe75895114293e15493e1ef21846e766b006a859986b7894cf2867ee55355a876e21f742ca5aa628f05babe1c80f48c9921fc4dbe02d6bd74c0194f2b74ed3f20861cbc05e3ad1e45f2438e4223014f2ba28a31b05515508c21eac348006dc78ee26051ee52ce3042716a69a1a546f5f5143d789d36c0f3dcf195c92e2717be1056cb216f53378df274046cc6f144eb20f6fc7271c17098cb84fb0728f5db3e1d635813ecb0a4013c37734d4c430782896469a5bba5658b2d61e50402d2f9ff963668141614cf1a467172c128b46213ed500dc38dc2980fde6510fd69d47356ac474fdd47e6c9ce3b57155f5114c6092ba0fb8b50519e5a9ef39f75d6d541bcd6c1deb544f420f12a73b4e66a36e9b09184afc61e2367001814f46c24e250104d6674800f61e29e989730ca0766e722c454e2500f3629734b41765e4991899f4b2465f53e82149602a7373b4eb2989b675051d1b713e0ec01a032bfe18151c03837248d18562589c5608c97d6c6b8cd867645db0bd4ebf848a1b23058d48012a690317ee3733dbc9833d00dc4849dcaf0b5b8cadf870c9470c64288411526186e01673eba447ca55df61e1a44e01a759ff3a2da03003ba4e2c383afcf61c8f76af52a69905729fc1b42536c9cc6c79463442be518323566db024cffa4b1a38444118c1bdc95610767d4f8bf9ca68a4a32e2d7d6686190a2ea769e148ae50362d665c5619811ef4fd534c1f7bea04b172a5638288ce6a0c411308b28ea9226d98563feb08ae3baacef251c61a1b28c47b1322421f12267dca7f012a293947574785446b6c0967af5e282e8a705a4585bdaa0eea39cb40d4a81f24ff2d5e223e2f9a6984145111c057cc34f5c70211c962161257e46724939df045e913b5777b5c4601dc817c072115484d31dd474fd48e2d421dbbe64cc153b9547a17c64bc0e9e973d3c920177fc14c49f2cbe55c33fae40c7b2f6376d99a171e954e6068f92c9b03cf2e7711383c2250e444511c8d011860d64d2530e95ad4202c9d1643953c491f75b0e827b024c85581b50d00eb11ef3937060f5573e461595ece342f0979e33ea31ba639a2ea9021bbb2ca48e40d72666178fb7587af226094bc7d77765f4c5ea85c91666e43961b40d30319d7fe4d2661dc6630290989150f357013fb3d9e3b66083955f1e2440f4d88912edeab770c24face16e505ce4beac8d72b73da58151546573a02868d0747268e7229b6953e6e5d710e6ce7a76ef5045774ca4bd951847f435985a8b95c688a214234ba8c63a375602a269fc73a513d8c645f7d8768b249a01fbda2042353baa503aa55f6182b8a8733d03b4f10b9b816186bd8a0545d15824a9d28c25cd496a24e0ebff22f0aefe0024d068d772a7cd86fdbbf7622c388061b40dd9d06edd46d23d515c840f0a0f84f1a784f3a77e93d46c5c75d1e799b104a4521cb4629b8a02feb63ff01f0560b16a3468b156bcd061c6738596b28bf5064a977fb052217135d386b183f62589910539e897427e25d365971a10ae8c16e157fc3741899132170c2cc9e18f6bc8e1c1f1f646e89606c051327f62a79da5d6a1b30eb3d82f6dd51ea18214fc48bd56dd1249817fd58cf4dea4705219cff9b489f202b642715e65e3cad5d1923feb504800924411d464019f696f673d84d1135cdb1754bac8b754d94b3e5627d60a8684b45853fb1f04212165ee82625ec0e35bf33bb132f80ec4d40543e2205e24e4f70c9fb5d57bab05eca3fac11db06006cbc5c193b0d45f516172e9026bd64073564ca900668ad0b2bb06a53036fb2030c0186af13151baa06f415533ab970dd2c4e905b0c78473671d632843782f94164e58de711812e5b092f403a06ee984769d787a00f9a568d534cc1175abf4c4014e2b2914598c6391d1d23d85ed4651a3e493cf26aecaa445f73e90c6858a2900e4d63af5215bb8e0c5672e5684b9a24677eadd86dc6644570cc7047235039595afaed3d15b0039f65146fde59673d60439940be70c57cbe47f4443633cbb0be0d3cebf05b39d8660f5fb3b31facd78e164119a21ae11d2e4f226a5d449571ba2888994b21466c1b709a3eef5e4b0cca0f54fcef1e561eee1828eb450a1351f65d006cae6d3bb2b72d6cbe52501a22fd11cfe86c3cd8d40344f0613f50114fc72f44b07d2a733eef4635cf81099f23be0fe1b1171f04f69f65c78ca848a423e00dd89ec9625f3b763fc5d04b386973d007118d7d1cb906957572a8ea727a1d692f2530b613940c5d1d84392a5dd71c6f6e7eddc47123131959f29ff16aa2d8c911a89f103c690a7545c8aa450a377004449b8b011eed3f674ee215b72824a21d29688fcc6b1be5b24da4c8da2f129fac73b49208457ce84d450dfc5d5ca44f5075fab31f2201acd477fda0c656276cfd29ab46a8040a572b4867fedf26dff3ce5404d36201ff7a3259ccbee9592e04174c599f7c12bf5e8e5c2447cd1b21b4314b3ba149505d4b900c02ef1f6832a4671c1c22343f008c330478f0242a21e1bf0c02a5031def1d321e68ae402b584c7e4ad61d8b5f1ffcb82604c9784c65b5dd3f1422b754d7e396691439565fd7ae2b5e8adec960c2d7cf27260b0047994a857388d6834b30a041379c6d0854ba1b2613be8fe86fa4102b3d97f15326fb21863e78f04b2732002d5bfe27cb4e94db272e77e2de6f0850666ce06a8c62550403005bc3001d646b831a9865632102fc2e3c738ebc6df8c87e27c285400ddde5715e6f6dcf46392ed213abc503607ce72a10661c2c34ac85b06f729e1d4c6cd6eb59c0efe72864ae2b5df232db1fe99e9b1130a79d1a09d67b5792145c38e25016651a9e470b4e598d3b6d8b1568982c0b0cac00e232c83a8f71aef7c245f103194fe9715817e1d15c6e6f4ba1039e8078359d5a571b8a1a642147477d07016d8f779f788a3d01037d68eee2cd6a40bfa321704bde2ca0eba84bcb778758ae10560d6e817d4f1dbc645408239104298d1c754e2dee3c5b78051fc3786c68b6ed081f4df9aa36ac775d0154be2942bca6661783a938451b27332746e2451f5b3fc075caf60c409a90831710f4a95a278422571e68a60b5f0cba6cb42c3a5cc8fc244b817faa613f6f013660db3042f1917733dcd8f04672ce014a694d8173ad3dc90fc0e71d4e2afa8254c9454a3ae744d228f78fc14717f1f838f891113c4039b34c1a1bf9463b5a535dbccf290181b8d21447ec99145eeaee1c9a5455028f51be5af3fa654f09759b57da555b27f6820c027427cc4b6727207142ba88270f05156e92775073c713ed3cb066f63eab300f113cb669700a4fae1200ced3209944d1631d7dc71ccf0048159c75b77140ab6d411ac98c53a081ef62ca6f5f1136ae3f32fdb9c0628622c25f8e0ba33598f51e75efcaed22a3bb622cf1d8276f4e2af429ab560624be7f4d625b9af906a86d271a29cf9f69d3f19e5dcce21459139a6f183b272137bc8ce70597ffa23ed1316b548abc2822c2c35418c1818749afd41e001e06fd60f08d430cecd76e3603b58320bd236b2c2ee6175e83ed1c1901f1081ce8f4003afbcc19701c5ac5583b26971dc089af6de928671ef4a24f3aa4013f48b3d87835d1023042b8aec13f3b0e155ba0923767be0f3f1ff75da010087e472a52706554a3ab7c69883df6509569554445ec946665084b085a78a805971c656678af0c70cb86fa267c8aa03436d71b002e4df076cae3ef59fe0c25208364b008dbdce76754282c3199dbf00f8d2e12377bf6b763f60182216d0a0140f081df60f7835168350f1265a2f0052cfb03ff4d9138b65a89e55a2e05c2d3630033c56812fa46298c42e01e887b8e1ed7f76e10e6b0632af3094e50b0df08198805ce725fba2629bbc46c477f10ce028cd27b38fca1dc1664276555c5949c383cec8a4747ae322111da4a221a6a00610219e5720ba6ea34f3ffe612805a46735213ce0b44ee3342a5642d2dab6d631b4de05c235416931582fa8e7579ec8d367664d64def3bef67ad14c7144b641338ad029f57449b610f1ca76c1090cb5c3f208f2c1103acdf0e7f64284fba3ea468c0ed8b5d0fec1e4660d90e29998c59511eadf03d622a833f621b7329af54bf1583633e0ae37c301ada232d27295a3627514f026a90d06846bd69d24dc50a591086f1a8223b855046a904b1124acafc012e6a6552ff62345d5fde3f09366a321422614610ea82606b910d8027db851b6325d6a26b59ea6320ebbaf43b11c3f56dd28a1040715ab8233909222c18ff414765acfd27657d051210b84a54c598a82a6be88c16f1a8722bed739a5e3fd3a91f68764a0b834f31647c9f8646776f7f3f6e8bc6419005282a27e6cd50ab51d2358397f7617ceeb46037941f09b4a6ac320844a20b1e6d3963650b13072bd6025db8706123216b9d0a1cff9d70e5a8d4595e6b35590c60e0692df64071e6877a75e5a8d65f90815c0f64c1494e34b11f34625f416c098ceb3651fe1a0d6664be4b5f8a9246154bc237b0bcb26fb05fd216c7b0ac5a92c55f5784e0ff6272c37d4799716f1769b9a81ea27ca41d86bf910aa2971c098835fc12aba361760890180f3e4d1471d40ea04496e1715fdc0c97507f6f9a1a95a1a403045ea20b9d3e4a3e5d00e82c2f30152779be272809495750c370b570208ee2408366c914ce96225e7c01fc65f8f7622028dd7518eb56110d00e9bb3257567719fa845368b3e757452ff8a8218b9210380eaf4364831c612948b83f4bcb1127536b1b4b6ece2a741efbf847182bec0667bc9f6135c1d28158e16fd81cdcec2a45690ed20fa69f3b0c4eac6013c1cfa96ff9ab0647aa7a7c1adb7dd3327d944b4eddccbc53ebf17207adf4b9754c9a2a5b8bf9d50edf65240a1c7ee610c6468e160a1107742014c80877674f7600be2206b5846a6352d2de150072761257cfd647da5e8d63eb5dde29e011d228eacc671d54afe1529915ba04d0cc4a60504abf35f9187809c0f285498b4d692e5770733b08ea8e2e6b3a504689c3bf1f0cef174e1aca2a2d833d4d046cf0b36e39a1950a4925ec0c52d6dc45ca04184fa0c7f8231a80070a8f26bc74bbf9191c6530f57186bffc2ab1c8d7299e80215bbbf8185085a932609c263c757f3c0f48fa95ef2620b33b5ae34fdd6071110d51602978219a8e6270e7c5dc2843f510764db3d101060a2530799d66307b35ac630d1a2f1a0b9b756d43ae2f2c3989b81ff836115677261767bd868924464ac23e2d797a128fdc0e550b464f09289e1829df5964382bd2f81356499910981dcd645e1221667b70c56331854e524e2aa31d78118a030805563b7713506473121702c92d2f4542572e445dd27e3a944f20739d7e3921b5d4e645bf5ce959db557e702f4c3a230f562715684f5314e747c134418b1f6b2442cf2e565ef251040f4a51ba26bf0ab9848f36a7d8532a1a2d64056368ac1737d7e758784cb65ddffc0a19f4824a71114c176427f0445810c72e6de8c9db27d8dcda4315c3d63d907eb106f64a33045d96f43eb7b0b9144124dc49b874e23fc8a5a1314e83650eab8c745f5863cd08ea511b24d3ae9543a813ba2f481d81657fc3c02f3a98b86f465a2a46c05dd44b015c104134aa406935c84e16871f9377f312a802e43c523a43b9c26b361546195f9d4319023cd17782d9af23ba199b59e42a4808330e227473ec30752fdd152cfb838735e2aa8a3e2d53d15ce4451575747afa480995670e9272a35219c15f72042b8e2c57f49104cf6cc03255e9ac750eb11a38bfaaa719f1ab0c3407f7b661017be0596be13b30dc0a1b130ba49e03d9166364248bb20289388229ff5b345a49e5002268d9374acf011d567b163b18ba9df95b2b41e56dfdf3fd2a7d83413c07b89647be46ef46777a6b677be24c66bfa6540fa49ce800e14a282f2211ec5dde8d0b21b34ea03a5f5965052735006bd5961010b21a2b6253c23f513981c125868e7d091a8b6550d968d14bf9d4682f10460306ede8210eae839a33c55be400597016354da90a18fd733d3fb503070db7903f0039c0134737183911a0621e49db2d7d377672a40c0571b045dbe1a85608736b184d90260499d3ca3dad47ff1290c4b41903fa4f0ccd35f538f61c654d65c07e73e6ab0637c4f93e2ac1d5000ce368254f6e4ef07400a5785545a12674e789f762f285dc6de90d466804523a51cc5d2404c47d9806c78d8754b074891ee4715f1fab5820558b685173a5c22741829a733e1a682c6f1cb8687146909d522e417f5020b451052ad2b61e48be582af1d6c717da1bae14f3671355b340336d8e3cf76ef4ebf46fa6902b2d7a5263326d8682345add423d1be6da3d845e6d48a93f1461247883739ae149136d046d6d64f11008ccb8bf35674e81094f5a2d0edc58bf0cba2b724e54b01d314b7d6b0e1ca74b7735fd1d582c1f3b3bc022e811c1fb4b2bb7899e6402e09d48eb7d0546edca25617ee8f65f009d4c75e932244a420bf96b85e300176eae5e2fb8994a6b7a16b5020ead8002324dfa1a21482248db70de4ab4da75259e9b934396e2702208eb9c735195e95b8ae2584171c27e2ba0fd2229c01d041fb347c21165fc33423a76af07c2bbf11ee713e41ff8954f5471073266e1a1070345dc6010b0ee2d4c4c60010bfb38561bb375710f940e6b30fdda3a475627cc6da267863857e893391c39dd2b4f7a352ff40f4f60ad142320e586e139e2709d52e58ce3645f60c8370426006742fc990c51735263725483036e8d470a7732c726f7998e2dba689749be9ca44dd67bcc40f0f10449fbbf6f1a1b0f992170dc6c5770e9c476380e8444b2f228255d674c5d16ede21e4df3e4774e35240f2ddaae4f55a9f6396b1ee4084672821d9265ee082b83ec6a6d1eb95a29c3e41955f824573c63a42e40b71d42c28cd4574117af3195a1d34c20c7d45060bb310c0ba89c40e7719b3c4deece05bf26a56eab9e2964e4de3c086f324b32d5ab23694e4d94203ade1f55c961293ac141b36c02161830164a1573aab0eb1f373b2218b6938c5c2da26743fe1fa724c7a81d4316939d5170ae6d56192c7d69f6252117ee7b0928dc8a075802c8113e4bd0f4438cbe262c2969c01ed0c1610741ae1174e4681e26730cf846bf50ad3b4000954302cda02ba1037b581d282f08980e630453a8784f761d3d0d7853c024f949b2496f0e554c044b1a4e93fefb2fa1b8084bdb950a51ccb1006e341ba1172c4b885fe0d82b247e52aa5f9817ed11ec41135ccb8a44393dd250624acd390b8be5b67645387c38cd31714710775c3afa85181c9a57d10dae4fe83cf7363a42973fce6cec979b3f627e724cb191e003df81e2435a95e952ab38933b6f5e223d5fe773115b5ab52b66bb0b5fea733105e51ccb2126c68e089a3a8f610ba77653c2488160aa9ddc3aab52ac1b1462eb01d2a77807f3e15b4b53363e6dcf700f6dafc4771843198b1766216934baee1c2bb2b4e30ea5d7e5454fa90c3e4cd307500446810446265c5f047cbf3052af8c76a890936ca8489d0525c036451ae23d1c4c22e063262e7a5db3617d56ee4784207848631563d17559b7632c6b2014573118d49e1d4436dc4025229a5e9ff5683533993c41c251bf15214f8929dca2636c0e663c33d6b29c5fe2bf1e49061738413cd8dc31490b6170674bb10f7317ea2a3fb36e288037d41f056f4521aecaf64e8d3e3628f0a27348a73f0340d752a876a8f7d040fbe3804f713d6a5dbe03116fa1bef55f29343b67b741e50ca27f332a5dc1fd321a8fc015373e8938b76f3f0165eacd6fd04d1f4026637c6dde13e84382de6a4fafb60d2b86ab966bbec6da102af5826924a81931ecf855703a4cf710f051fa273e7ebe0b427b0f0644619d411847d33badf5a85e0889bc5a53d96901a7d12a64d47508421576305b3a18363f82a77a0d4d18e5295d53ae3d4ab6dc25aa9a4b6a6eff606b9a8aa532ec98f149ad0dba2f4ecee56fa09fee66eda4053d3b81763161da4f414c4bb937fb6b3e23edbe5314ed095346f448dd626ded513488d36002a2b271285f432561a5ad1a18d23462381fb2b96145a3ac0f8e0cc83e6c87152f8d330040f5ac76528f5bfe096e38cc51ec412042c027790451f3bf00739c2746ff553419bba0ea1b10485b6515169422d2accf44af07067697558b5be466362acd3ca264cd21520040e3cf386890715744efa140b23fb727593bb02e995d584b509a542c0ee1d1505385ce58d410f8344a40e11312d0d16fbd0ea9266a894c0fbb9bb20885e2aa0f1ca9af50bce20c2bdf2a076a0e52c11448edfb07bb2d8e04e190b60ced12f621fa6bd347972c236433aafc0cc6612f1f555a101ab7523573aeaf0b5e16d95461d2375a26a83d864026865e25631060472311e83f80ee84112e7c9365e68a2c3783ebe23a37d9d1555b714d353e8e9e056a9136270cafed5a36a1882eed9f4f730bf3da3a71d07d18877f120df9d5a75dff4c6e2ed722d6396d20b1473d31e3393d727e1722303855f265283c718e0f05ac360513f2c81c641dd63a115fef9b036276190ead5e20471fca19275ff48962e04f6d0a503b4b10244bec47781f0c248cf05e144545232cc62e3e3e76cce438a12ad417a3e0c314bcaa52081379d537c05da01b4dc41d0237bc0231ab482902322b2b4f88117851b21ffe6b2bc7383cb1fa6b17bd862e066adb176c13054d769d64975222dedb27752d713809c64d02ec1ff3156ff2d736ad8888281194f048cef4714068d83333e63d7d3665db810673db64454be42a5abaa4ff1971420157dff2d40c7f8b495681688d5c421a7356ee98a02e02cdb42a36e886269c544e48699e2971f6c1776b6fd4c30205bacd3ccf20c446bb1947293968fe3d34db5e337c9a085e7543a805ff2368697d68c567d2770e3cc493be6b3d3b044bc7164511ca84075356554168890d660dcf04671056b2c247e8214628b0d8812c43d692214c9e0f75fc2147179e4b866b5828c4261e36122e94c69a3716f2562be466e2153469372c3cab9636652cd0054c0c270d95b68d0cf9f3ed61c4d4451e2ff077036ee18d08f0f2563fc6c6fc6b5daa2317670bd06cd1047e41b7c81613ce18561286883f54f1335727199036491885363756847521bf32cd636ef5862286c35c4eff16c72055393b04b1c33c30eec23c2cb528765c0c1a3414afe2725afcc6dd15aee80773ca9ab06a22ce441f2a5da07387f86d21b02c7b60a43ad045f0b09d6f66a5171c8f3b51186a527f14580cb17294c55d1698f589335338ad42dede66395b26f76561c4b23da1da042eb0a57015cf4b950d1e65965dec574f4f25af776bf8ce7b3865a3486e89e2d862691d5624bfdc366e8950333e96b6ec33a773fa6f4893f4117c7f92655f193a77a7319d516c1ee8242ff6e626145d7a1100198e32b848f76544fbbe4dcdf1af5a13b8d452ee4867539d6a26452f9a5d37af887b5c72e79b616501fb1904746f0386caa45ea0c3b50790a8ca019fc0201dc3fc053ded33b165d6554a37a011726aaf8e83716b947d290c1fbe5b3e386c6c93bba3286ff184093f4f2003ee45e068037f4b5fa2b9d538b31b072ba945d1179207e3088b55bb6afb713c5be632350112950d0f0e5c3032bcfe1a29bafdc54e9d5db5750f2d8e3c6d76ca77358af7300182a91ee9b81b6192be4d4abf8d9f662ad16217612aca23c7c0b9413819d65abcc3352eb884d147f61e036d7f007022c4fc676165ebfe6fd36c5231a5183c597f3733480fe21f427cf2866bfadd4530254558636cec9f7530c05e2a995a802cf153fc5cc2820a41107ae2756d9ad9579467de5841f5046b45219139432e5a281fe27000eb165f06acaa1e09bb2eb7057f94341520201b2d2e83352c6e4c537557ee3d49839a3e085354c47759ff642386040535641de720f5d20b08dced5f2cca22485b5f4b081334796d6f248b56633282f1597117d46f6809623c8c49244d960a4b1d687555768e3b1c6dfb4d2f54f81a736b761b1617df86923227125c2ee899d25997d09b5a9968603afd49056fe083c336b7573e6cc742666fdab7d93bc6369d40394e54383e01212feefb624dd37164770cca104f287fd53bb16c7833cbbfa633211c5a20ef1a3d383053f413d54ddc2ac798296fe3ef5077e5874f6aad7963082af14d22d1770d5bdd050c570e509f405f66c11f00f388185ccc48674d7aa01c44ddaf3445fbe84a546be34d50bea577a4042b4007c6ad36d0cc91176e09021b06814d5c070fba218f70e850bc4bf049722ef10123c90f6bc9c9172cedb64d13a8df70398a5c220b2f86a72d46e7c4124ee43052813ee71e3e144151f5901f5157981b1d5318e951e4bb6c409dcc9b6862f58853750bac4d98294c0614070c07e13c801d512512113086e84b0139303692eecd70456b6526e2e4de508251af4b211afe6e1eb238189f7e4b110fb9674e0bc48a38e25822663288771450621d47ae34dd20c778c1571da4e5581e8e7256aecffa6e7c6adb4865fe8429f1437674cde3013d3d6bd05f9afc3e164f426a4319e8324bcc749f56016fa66aa052e375d57c7424e6d1d934031fc14f402462295041d876f737273fcb6ea83006ea23012e2af95b88b7db3dd73e5a436ef33119cd8096123b3c6777154f116a89dca466d9ff5a2314987b09881a6e3bbd63484a5745133d49130a1c3c379144b6719067846ed45033430f5b51468e662753883d9257c10d1380226367bd3237b02ca2334d5fcc2b04be3e5697b87d32b498b70e78fc243bc26a0a73879e0170e365ef45de50b323ce249c43330910562c321a41870dd30ac3315f369a5afd3263d2e340eb246d3b9a1cda1d353d30090ca04e59f21ea756931d9c63fe12d0742d446b07cb60075c53ab9220fc5e291deaf5cb09b2fa343bff4808196aafb32c43fb1853e084c807e60133344696d6696f3214506b90d9411ba96d103f593d771de22d03af41bd020b0b9d5b7abdbe00361dc40d9e11456b100fb6522d079024064a35410b9a4062360b2e24c737611ec04aad7023463375bf8950768ca2d416ff64170c03fca23a20e50d03161d286e4c1a1a06910a3729314f5c4594c5c901b646382f79461376cfcbf770bcdfed6ef79e8a68945c745cb0eb3b39a2e6b227f4081417a01004715f1a1a579f86b424853a5e5e59f38c3eea514c6c3e9996634a6d92002cc13747d645a5549e072355720cc270c23cae4165f8ef697f9c1c2b980f696116372c5e83aa9b1db3a28f25f63abc77455ba901ec620c0d03ab3c729429f23a95a4a834b077f32139203b0c0dfc7b5e85cd6031b0fbbb355e0e902487e7864553d36471d0ddf01d9de0641bfa724f2584748256b414c44e2053c436364ad924bba208252683443f7caf9d3dcd97cd0c8924cf0a61f4fb6606ea58682bcc4506d5da2f7371d6c344e726802396d6a2027afd923b23888a48fb3647536c2bf76071d0024b9a3be11be1ebec59ffcd1467120e016115f8f6083c1e6533d6788240bc72b65f161ec576a974c529adf7e77321039b067d99a34009b8cc52a819fd116a12fe3406a894770431426a88abb7004500d6761ea8f81a083a0969fb9afa51d41b3c0994454d365650f06de065e112b0ad1508575ee2393843172b56e3873412220c1935b4c5237f044748375b0d50a227454e6ff4870d287331329afa6e3791ed062b6a426867474a1f4b9a62f43adbf24f3ef4b0a34eea4aea66abefd63d62d7c14757821438f73e7219efdb030d02e33e25becf632c545bf2189af8f224a23b7a43c925e872bbf7a968863be731a48fb8699efd9326f43eae1d2067f7366d7ddc081476c636e34ba831cd890d63c5073d76f1a8ec3307adc74fcb7e8d50ccb3631e1eba607354ba371e22324a3577351f1b8cdaa0093acd6b0dc03a4f304b52c654ae1fef0f718a0367fcc9df6335547f369de4840eef994c358aeafc64d5985964feb0503186907c0c82bd6227a7882c44a6fde13791694a7113934c631149223c9f13c359f266f55dfdd3d509a086c931a04e3816eb5ee03628d81a32da082d6b2de9bd52ecdbee60caf0480f72df39723ee8264af5a1cd2be03c0e64d7476811120ddf71872bfa193f310941d8159163bce68e0919e8d757d5a1c23e316f70185b6e3f083f45c51414a234207709b233efa500425901472c7bee5f51fbe07341225d7b316244a261b3e65001da759220f5b44c73da470c73a19659080a6d131b7dd27037bcf16c3db5e3e10e4bc6fe31521a306fbbe06d3d95d24f1647898f4beae4be5edb4ee44a791ea92f47e2e03eb0978b715474ac289bb26c11e98eb7634df7e166f53cd275827daa171d95510cbade781e440c273a8ae1401109689b73b940a008a56fda5486cc1d0b380cd108e3453e749878664f6a999b2381bca64365d80b59f8b39c1270331d0d85959e593dd0515d12995e28a22d993a8e6aa2667d76e22276e1c13b5fbf155c4e646307b9c0553b7060ec582704eb57f06d965592a8fe6828cec20bc82d7924546ffc5a4d7cbf3d34236c0c1b9e7f60449c1d29f18ef4290a2d30576517490a919db6727863d032f0b0e3086ac239150af3116b5e05bd04cd6178327e95f66508fd331d3b26961ed0a69b2780e04e26b328692509b4b92bf0ff2e40efdd120fc7353a1fffab511780b7233abf83a35a2cd5705ecd8d342d4efb730fdab014475a88ae2a9dd09025a17f8e21cbded32a2ce9712ca4bf23578aecc645caa4491089b8e91b9bbe9d70efed2b70bae0885b534e241bac218e124d1669084507cd57549205026dc387177c6c4a4600062d4cd4a4a029223e106bbe0f2c122c05d95d39a0f110e14fbf77c87e5b1fc5f79932f288f60577b3bd1db3ba0d1f298d37343550c71b6c1abd748778806d93ea402e28ed2a1257c77b72273f9a75db79e230ccdcb65c6c246b1a196d415b091f6d173364672ff2ef2d37e6f6e96a9454416e0f94dd6263be8f270372433971becc60ff695e1adc45535218bb8031fe58be5f46364b51819809177f936529da807e6a572b1b3ecd51a62ec3873f329201801a189a7874d1544b35826b6b31c8c4004a4084ef7102b69636a7edd26e2901fc5c45466359d42e7556c64b99761eb0cf260397470c687b3e2164bb4227b82e45726e83ec0005f3ac20503dfc072bdcc75c17b5cc5d56306d494b57293b8401eb76045e073059558c5815dc552e678a3d47bc88341e8d03ad3bfa62ad16bae4ab4e1b8c3e73cef5250ec3f85043c7d1c21b0e3ade3abd519148d0e4fc552e0798042fac121ffb648d6354a2684fdef98a0d7f9b6e2043932858654ab13bfd17cb3ae455702b8254eb1575b0a21661be203ad0423a6e554d995ddf1c6462e8070b779eca7f08af6e9c6819744d17be53952fe84db572bc86461722675f3ca883f72104622c6addb146236fa06e3c7245d02cc4928854a35d6d42f0c84c77270f7d56d8565104770fd7379b14b007efcc356d65e6234f822fb9696b058909e8730456c863fd24c019821c1d872a37559c36582b161008c9b27911e4ebd1728dcbeb22c3517f096b85f63a740e73374fbc0b2587764f1d1e4ee358aab0692e0841254b19ecf42b4845921083462924581b142764f9436266f5691cab22b848799cef62e2f7a53121581947fe32aa23cdc9b055b1ce670425d887232a375c244636ae5798d0146597f7462101b86333fb91b47597924549bbc2dd42a0c2fd6d2d491564c030d12f39a7ef60b460934cdbde554d4335a04bcad279152800e74488fd732a95c0b9203760835201d1e0624a15d257f7f8183f741e5a371caab65a396a3b5c8cdf9577c169fb573e19c7017ca35124440bd71bdd151171f534372590cb823b3f20c016cfd61c36007a1a5f14528121a624f84d8beb486b2b64e73f64e8592cbeef6e3a657ca9664c1fcc03e65ae43215963232d87e5f727998c06d8eab4a4826fbfe115cf8211a3df1dc3cd8e55061a0985f44328a1b14dad0735d1ffe5c5b2dc89f4bc8ac1e1528ca6f465ea2bf690a6d4d23e5bb0063b2c7dc4545bbf66ae2b6fb04c37c1f0c974c1568cc99da34c26ae76e220e0848f3a8ec508d6aa44fd39c7330cb8eb149ea83e3603228875d05d1e02508386a7425e521045dc88c6862d9ef3948194d0f9c0b8722e8354e5182a24f32d6890c4d3a2443386c887439f450393caa904e5a4513933c10342f444e5e7c69e3532d173cd9bc331ea8f87452c94a593cec6608951e490ec9954f53f4b57b742f02b65669a2d96525742c76a619505b17f48e317ad47859124783233328ea4286c70c0e164f6a18a8390f4eda04002058be8c6b50b35b2014fa790d50ca2d25d2b89e2f659acb159e2f931531dd12125837ff06ce50c56514c19242924efd3cd6020502e78f0836baec610cac740f413ddcf83328df9d2276cb123a6647f41f9e105c020c98ad4aac2c861402e8e46d07f7402449c6d8151c03e22d5aca7b5fb4aa881e4a604b687a46522b85dbe646d5362f0f238d083b999bfa695899773c22f45511852d8816193f9a23c9620923f24e036338aac22730972122bb421d052cbc30186095ae77df20b376295470672f1d0925407db3422491ea411c89b726b8a67d3ffe930e438093c0053e5ebd6dcc3f071d01929b6ebc5f87606997e50c2fe7623b7463025b8ab3b41e3913fe2d78a17125f5ca5f276f604960abcc8120e41a5462b6c85a032f85e6458ef72144e3755c3a2dfb9b1630b50c140c651d720fc81b748defd5069dd97c59988d7e526c982d1852a0931d5b6e453b594a70682e2cff09eceab935ce6bfa21d5067328c952315b8f4ea5750fb53206fe35141e3d502508acf8342dd54d3157c55d312597a86d2c1800f534c0614772964bf815459a5b2a7b66693dd40cc772eb28f649938ccf4b3699d5019483a937f4dabf65d2660a5548e72138d24f746d483dc7295184aa66f6593d6612c3cc5fb08d82508066e6723d22f91415b47d09ad01b41d17f4bc1c8749915f8db0ec587d485841d00515201a629f03241a6d168f5d9f4f760fb66e63616506fb57955c0e6ac94bbc6bd15fcda7b54717faf24f28fe0f1ce4572a2774bda63b6b93c867536c0c58a8c6a857c9df1867e754ae25b379b6674deb92345194f65cd0105b265a1e7106b4c1fc4bc355a765c0cf3d75ca18593e3490420df783c66ba8153d243027501a3d6aec090cb3c51d6b79d130351e00366b9eba4593cba6636498aa2a09ae5708a647bd33ab3d3e61b506a50aad534c249549a93e15bbe563f2000c4773e872231b1194405c3754700825b81fde470f126e71df54dae78d37c9f6e4537206be60964a7a48ed978631eda3221a00b8c92eb11fff35f1cbf474f67ec92106493706ca58f974abe6d964d71edf2025b7c32902d93717c7e874048d11546a71da0d1f3c853b40972963304ee73538561c65039f109338726db747bed33c6b3e9f650018acbf09821267367d95643051f68c4f95ac9140b6aee931745995698f65815cbeda3d1381092a629302c2706a90c83cbfe6d775b065b017b3f29818ffdee0470f81c9237463a873bb2a0f752977e76f10cb184052dc71082c4615152fe6e64627ef613fbc7fc3038ab7c40b064884120b4a33291532aa70b2da825cead1193b9b1cb433df8b661f8d57b963480d0920c774e73d3221ff12deace768a25df62486090e2cdd65bc0e90f7924ad63d9f456887f722323a7e43d9e1f8172dbf766f258c8f2089b47d519198e513879bb05123cf433131e5ce6781fe5548fbfc394d4b8ef862ceb53f728be43f775991511e1033902b8110b61fccd3d85f4ef6ba39f1033f6c3e053a00f2b5f2468255cb1b04d9a935c974436c63057e490c10e84c64b2d36e109bcd0a1fd994779cf5105d59c18529ee15483e126f86311f2fce3dafcc770eca9dd26e5f5e75609b2830008d8b520ed192f86866d5356480de825e370f1d2a5a88c314a1587d464b6b5c35defd8c4d1d386c6ac98bea6f316c902536e2c14f0b94202f8cf3845ff15af9591e36bc61c56dc645029a304f1da7853b4416e267957d8e0c9e7a3c3e3460076efad6f956c5ed5e15f22f22216467ad347091a35a18e8ec76b131444a68cb2a34f6b3935b8394273eb4ea646ea50c2c10b3714d0e79997d1aa8db8b4d5391671df80a90476c9aeb2eed6c4201a55461582ff5ff707dac8943739f1d0428b90a16ff5e763800eef5381f28135990cb864cd79d9608ee01db07fae5b7498b28e31ea4503946361fb969a1726f60d883dc38e27f0017dcddb94027b2ce10075b42258a78f64c726e8f1151ede259badb654f6db3795d4fbb5447a48eb15783772856f4cc78774dacf7081435a42441877b0fe9252f3f2eafb04accd75901b6ce97367997532a4fd4614fe6ff9b20d7f1ca3eb080e663de036054dd5ee43ca7ef9d6a7e10ec68c918af5ccfb7231702be6e4a9833972a85a44a5020ef6b64fbb384480be19d3ccbc3524ef70279033bdd7c557b43bc5dc92c7e1257252d1e5922526f91c1df433440e22a94127136a648cc6a60daab1d86d23e68c94656129e538d73da826120fdb2aa10539f484a62447f1205582c58112a71305b60996d339d735b6938c834d5b78021e73f987175573d734eca3854a8464a6cc48c2b0160831156d2cd74722aecce14b312b9471913b75896d17c18cecf512e94013576870eeb65684654326175ff4fc409dd060052625f0bd5bd01851a97016e8f763cad456156545b1c6d52cd420a2d49a136e4578e2a6df4cd645020213415a8c94e1bd1d73826675877e5607e1af7b0f5737558a23a3e3e50191b19d53b093d1526571a1f55b8ab3d3fe6c13a6bd0eddb3636129032e1b1470aa68b4250ff40e810f910580c52e8c81cf2654c3886935c121e8ab96126b989471385ce5e103bb33128aa3860dde67f20c96f8067f796481b3bc411652d38064ae2ee4f269dde6e3250438b49d8dd3e601a770f3b1c47622d3ddc8107e5042844cb2a8f3cb4beb12916271934261b6e43ba874c532df1aa153f9b9e046f36321b8e154d170d2edc2625c3ae5e1340e94a2a930b4f058cef02545f524ac5a342510cdd36033fdd8e0467322d2a2b956a0183d4b537767680777ad8ef536c7bfd35e91a5038b4b6cc158867fe57c80930360a5d9212c774c80321e3dd0a6b50c61bbb1745640a00255e22daba336a1f496bf2973230f2e0f947b3be4676c38c52525ca2940802873941026cc41d4397a253749fcd60a8ad3843a22c9c6992f55572a0b52c45869a9d3e0cfcb26eef693101cfe0980b27c39f534f34cb166018c1367a8a0d51fb5f4f5b911ffb1ade1bf42bd68834353a6dc36643494624987d0a07579e8f1094fe3100208c3428f1fdd92953bea377e2776a37597d764b9721ed0a9babad6fa2a7ac6e2d97b515bc7aea22596ad740d49a8b53f19cf1754e406b022563ea6d7cb33018dd7a8a0558793336d2fea72cbcf35e0dab3ba61d3952a31cc8283c3ad0d8e9669cd8b86f41319e3f7ad7e93d04f95130fcc64a02768e9c62c8167d2d7004940901991c40aaee9244f5c8192952002c652af10d74b172b3759612be4fd9407248d3092f42924ca922c1bc92573af1971b826c8426b9c9b13913b863143febd925073cce1cc29d0a199607ea315a08225d45ef0e149a1ca46a4a21f4355193156c0cbd2d0fae25b270eaccbd1746f5d32a7f645a07a7bda6718c45700b86b74633981eea527bfc365098d32b5836c49319f565c23410b5ef764d8c5e4431d00553d950d4575ddcc100d5fa302d46c0ec29217b995813a7f45d7f90f72648f4183f66df2b4c3ae672187318953bbd017b4ed9b74a3ac2b222622a4c785e3ab4bc3bc258df3c6a84146b3cea5763abf5b9204996bc15c8993c6fa057982efc43f5672064f5419dfcf838980286152b655434f18895400c7cc36c3560df77c82e1a65963b1b3f7816b62f74f1611eb1a40947e667ae516770be638d84c623f55917642fa947312b5eef5ef5a0484a8d51532e0c4ce35109600472c8caaa195154a84f216f376f7e4f4325f6d13b3f8dc41b413a9eee4146c7142025fbff704e355c5249d64b190c127f693c74be550dba6f63e9122c2e740cce1d6f7c28620aa42e665ecd51441bba0b27e433ee58a1ccc23a335a995e68ce5f440e167755c167093ee471fe334ae90b0b2167eb3de13cac6f09fcd00cc2e36d2e80bdb63dd330a10f992ab2559cbce229a2b57b38c52d9206262dd434fe7f0976d83a9350ebb2501a3faa3613bbccfe2ce59a040ebe75b752f9ed0f24fa84b84c666e695bd57daa6a70dfbf4673b9715d0a396a28f5aed573b24b1060d7a39962584341115c35fa0a8c5d167294baa0545885c91476f3110d673894264de79539abaa6219b7bd905e3864ee085a3a4f719035205d262d9c613d45fc3afcbe3b02340ecc410257cf01b85f430433ebb15c8ede3255cb68cc03beeaf84e788acd52afbf200814582f0f127e075cf845532dddd58e1d0a759f096842c3272e51741625e787767d7afc30ba122360e5f6424f9076b0641b1e734ec4254a3ac057a85db090f7613173920f8b9b7a4b7b207719288b9e31aeb9c152bf4c99108046963278e8150105729506f3c4de6926d2963ebfd6ce3f6799845fea8d153abb90f06bcf14f55757752f6c7cee2603af2db43741fe241e9b3105621cfd5935ff2cca31093d2006ec9d0427975bae16c5936d52a0d7916e044af15ed89ca529da02213a7660290bf159262756815153b0031030d271a3724e951616de44752f32a1f354fb6355731a52c83d9147202c282cf758a886d85cf80c7001c9e4bb5bbfff754edf417140d0dfe75d290c82746e52d002e2252a0d179fe9737d40014db0bf5d2d60e2b3089b835015199c9413edb6c46c6d7f9b6acea77b054042a7273d5c7708842304112a59696c4f42ee5c8430bb527b168f1ee9c94b288f7b6f6c7df63168bd6c885f88231261b74cd9679812090526a0dc42fa27a1355d02cf1b1e084e5b96116e220a743273282e5c50a9f3503d928c27771ef3f43dca816611b74b3a2643733475825332658cd5ee4b71ff7e58725fda2547f78a53b802756000e1e41c226d3b5ecbe73728ca96b3535f11a9231b7e826c51694f0989d995755e4f97665a8c5d2410b3781f5f1fdd30a05d17480333c75bd70124091a34863d280032329b0fd8711acd3344e727c05fe8d2ae38d106b128f4e4810a3a7ea26bf240773c23ce6d022242bb0401f51561e0291c704ff27c4b27695141a1fe8f108725d83c2e876662e98f7476a88142298855e9419da1965e8187ee03fd8afc28fd3ce56de2bf1911ece1ce3e7850f121aa0c5b36027003726b34b656408ffe6678d06737b653512e224a12600d1d8943f10c3218551bad5072b08d69a26dde090496803974e3c0108c6928430f47b746759ea564470c6d5dca7450746c15c21917e36e557d72ec5767411d4ce26ad20a2a2a0008a332134006e069213bac941ca2270d122058ff41f5c88533e05e4d5db4602f62e1670f24127e8704f6910534cca94e021468bc3f652f3a768c21820e3a9e51115a8bc056e3cd921453895c37eac1a225d00372744151b63ac802712c8b34146befe45a4c241fb027310bd05542f0af3dc182ca0b77e40d3c9daed930034d625eeab34539dfc2c53ad18fb9595f7cbf6fd2c0fa143d7cd36b7cbfe85a605aaf0a2d410d12fa5ef14086d37a76d4caa84d91ef562fb610ed5e091987606a835874fd74ba5292261c306e27252f01459a6a2df76d4696a1bf48839a2d62e9939459c0cf5e184bec361a2b6daf1aa714ba50e5d400214b378214f0991c6ff14a535cfe807f3a266ee902fb208a066506b572f158536a7ea3a518c4e874033192e66a6ee4f628d4bf4077286eb62d00fafe4f606ac76a7959142cb7d07563fbf7441445783668e371945ee01b4932ebd0b6372bcd236f96b91d247f1b3a1b8072ca1915c4af2835ab2907cdeb810784e66d306a830342ed0b6540ff4cae3a4f24282fdb269715d4feac5f38b62e5e08b806487c6f9b5112827f69c1ea582c1e9d0e37c66a2e28c2d7044cf5be9801bc12f13db1d62734862d800ae8ec5a629882ba25a4612a0c9f9ada6a59d7240f855e9214e257fc2da689e92aa66a464f7cb6bc1823d0cd566d55384e4243f46766ae9233bf48224dbc0c515b51458865df6c9836fa497e27e846b80707c4a14959a87f6b057f3208917ad85f89dae52bc300e36204140b3ab969fe51296bd112fe1d006e032ca1081407d43b1da9fa148d0afc1ea3f46d2bad9418710ebd1857345ba2153bb929443b0bc1533dd2b00e7ef4ef6c60b946754eed9a198a68a200a04625571f9de209d51c2873085a394efc4a2d394e724e6c77f5ce1f6f3987371ad0be4e95636118c4d08d558c7333487cefa6676ee6333b22adf53a73032a4d9cfec206bde40c3ddc199c40fcd4f5088c083f2ccf076225134a915bb492664f9d97df566938a12628d9690af840a24fdbf4a031f2c40d4d0b3196083fee6650c779df02e690ca3bc0d5f1549e93f15b8ea715099e8bd83bfbde7342e21e6006c7809f188eeb2a368e3bd1252621363cf47f3f3cd4d14a654f26b35699f4fa2ffc143350d216b93bd6a6832bcbfd4d60a5a17b1e3b164962e2013856a8b910285cbf825e8ff5624cf87cb66056124e00963c082381d02e413da2915e88876f163ea1986464181570d6fdad4a6d68833a763cf302743b91123657c64d28ceda60c1fa716984cddb238f698864e64d613d3cf2774f3a9ed45d417d130810cf1230f00928517866a34300e6de16ea46d1043eccbd6e1671ca1174fe56621dc5e65d75b13b12ab26c9591aa4cd302a79d8193c50ad287b788d6a263f4a3fdcfd8b2ad5d0002f1a824d1ee82d89489c3a5617afc75c2a6b42373a3ab89432ec2a60760777121d96335f1eb1896638d1cc8b776a0c9639de7a616a3b7588580779de002b7d0a55b33acd6c099ca20e432e1f3bc039d2352e63cf71a80d5132c593af2befd2f05cda951a6eef74736ec6b3131849b394538771fb72016ee6388a28d641525a3831cc76b96a1733564b3f31361239acad156e785861bd17fb1194cb6c57369d0027d7627f25c7cf4300c065e928c8f6ef2b72fe7c16c9f9361e7d83a16819beaf61c78f3843536ad500e1438150f2455d669e4d79016ffcdc1e545a3d59d6b982158b099a458e5d5319fea4fd1ee126ce60c15cf020641ede6dc863895f8df4426282bd25018aaa3b24867f214ee1a2c61fbcfbfd675939706c7576363d9c50a66abac933075824b70d577b324bb833f515e6473701985b7a0a7f7ce04067553960f56946216673e36be2d07e1f11c1fd21b9a185187a74bc1e130841424581fc20b198961d943d2c46df28ef3128e389031cbd486067ed14208162f12013dea5747f7a224119d2084b18f1f122d6856927eae6304bfe1b025ee964461acc3fc33a7e5b1d2a6e852458cc532e18c04abe177a9b941b1fdfb149b76870107dbcba7346e9d7694f5c274ec9c3d270ef4b2b229b8ba12e345cd46510e3fe359c79d44e6a8ae847c13b014e4a3a3a47d853955394c52767002c141bda85c2150cda3e201f5b63448d96c346317cc754202d6400e7af29482b91a7274a4f26538a8a5b02117235609defe73ead112557c0937e001a43f454e9f6934733e0c06a80e29b6e4396305d3b0a7a07452ce25d847e0f6fba2d7764602253702daa022cd9bcaa41054f7666d80e123301ba523278734021163e4c4e4ac98e6356787b2b93fe1635c56f5c0e33d155584168ed42c275cd3cb1b3eb5a86ef2421a883e125f2307e31455fbf6bd7e66258e1d43f0252929004752291559ba88109c1a0ce57e9fcad0f92f8374aba64641ec3482b1f37c8ae7254ed5209a0edcc6640f90b20725f17037b6b005a1230e153dca46f5f1edd257425c9c069663dc3214380ae1a61fcc571b2bbf913f28a476e7d23fc4f6f07b501b6b0c572a307463851acb10c4a745a23922d596125bd47022e73cf64053a950fb018f94b6cb4a607d098bd74c73cc35e855d60673f0758138ddc921fe19db51b92b74a14c8d3612913618d211349c61c0f600e561dfa354d825698507ac345259026375dc1e0141ff92e72397e02b424c2e838693a70925f68d1f92dcb706a268137731ac50d445b16dfb126865a233bb6011d2950ee2d61e149b25bfb564b461bd5e74c3a550c11726b651bdd50245b2ebc8b1f90abbc2ef337ca2a55f32f69c1892b4105f92a0efb1e294696b75925d907100ab64c52549837276da0ffa41ecd76586026267307fb8bb676fe01b12cf67bb970fa72b6223e5b847752edb053f55db8141483e56f6bed66321f71d460edd62e240b11e525c2f8ba39c6f653052f025e17cd71310bc4ec091f5b039c57fc061d55fff6f56188b5ac49710aa93a245d6a2c3a18087534abe81d699a43077bc4ee5dabc82c35d55425240c355322611bfc06aa0dfc411159650ed734e33fd397165db509572e6343614fa386dd70121a6d5a0c66e937a7245a42e5909b3cff6dbc77d4d93d75d1b80f57476ea56b206eee18ff0788214b73e21623ec494d9269081b11c64f5a08025c0ef694c22421e5b46242d7b43721490c6c37e09a1524318c12f02e15142c62d2023c94c642aca0ce6f32e3540090121053ba38501e1cbdc46809fa67652d7dbd1cdfa6676f9f8d6e0f00aa791cf5cf3152f0852b4c13ceea3897a65671ddfcb27748d53862d07ec52d6929e806ced50e34f22cd04c7dec723ce3fe10147919a8099b275947b9b56d67bb8fb75242b2fd4d122760616072126f77f92b429553702089398e27665eab74a00c0e5d2a99f91613b4d475bfe908068ba88f71fe695a4ee013693845b2880856b94f77a2babc68db16e8236077d23b257ff725c8f74444d8929e7324cd8f0eabad255fb3b46408bd2a2c34fc26101a235f0c03a257dd0bd3a54a2685889d59221184238e8fc80b2b7c10157de1870261ef745651eae1473860774fe350f10937588b2dfdd45b26a448640969bde36b4e6d250f6b5c3f01b6b4430aed2106111d680b4605f52c5fa27d340f66000d4fdebd102db4a4284cc0ceec084de8935aaf12b741fa13133f1976926a75e414160565a91c3470150f5beda0002b8ca062b3720b52001ce45713ba480a9152941ee4b2f320ccd2f155838809112322407234d6fc641b58c43d33c99634fafebd1586dbcb191a30e72fb45eaf3638b66b6c08b7767251accf6464c9d4438196332ffcf8c36868130718489e353d44f10d4c2093062d90458c5db78ae177df7f944ec0309e1da89e1a36a0eda26072eb1341256d4040b51d2a71b120310e179d00717230b0213371ae73f5102f6d9922124e9eb41337df1ec81f8c57573ead40086fc0c2126db11d0954b9e1661cf7db1427da4a461393b5541542a91e5f08e73c46be1b8e5d98ee8136c445822ab181741cb239f971e3f60a0e780f474efbd7264ebe13db4a8f70775c9fe95339aa81ec206935523a905dc16f3efd79178b07d14fe4c975565776d93f7e46eb25e9f4fe6563d72659dc3d3d59fd62df0075aa4a014d32842d967e8d0833230f59c3904215e43584129db24e16b6e12616db7cf04d35679f33ebda013eb6da934c76308a1a6fe1660d33c4d10cc5a7a31f588e966c79369b3e6312605af09c670977a45b08e56e7200aa8d10513030007269e2442293f11447d68cc16ef79f216d2cccbb7018b8d44f7d93821398d1e508ac404333ead4d176a5758d290697c334ccb11c4e410b9340ff8ca1709ed52655e22e9e6e167ddb0108cd5e5f09482c149040763805707a5dddc65702d7314105dddf3a67a286771cbd1d1223c71647560a4e2100965fb36bbde03e0ce1bbd809f1cf0529eef0c851dfe2b3647740321e4395ab63992f9043088f960081215a18f52a4e3e0cf6450b803933399870586add597b0f7141017448fb03500b61e24f7f767c181c78434c1805c4137462e41f1f957a5c10523955986e29088c9a4f2ea2a8a352499f272a0a99ef48e736903ec343f22bd0e60947565f704dbdfb70454e7dc32b4073d510679bc7673092eb520d3c993005c8eb5dbcc1e9542fbb1f0b8cd63a11d1763f59fc506153f4800215939c8e32f27f5c6cf3ba6f2d11c9c3309baa986bf7c4b937cccbbd2abdc80f763fb7591770f5e327c420782d0cb66f6da3ddda1cd17b1434f26c7730a38b982bd1e3bb1627f72448419df6602cfd00621c322559e5adce347ef73357f5302f47d104a95f4bdf123d785a3f058f20661459498a329599655edd5e9c4768dc5c50957bfe77ff78cf267ee2d259c8ba3504d8031c528f1c645d9bf248288885ca02458a772bc9d8b213a1e44b21c4396431691a931358410d373cb92a53de4c8612fab95a2122045f31b9644d53d625dd61ee5c07059e979d305dc4fa2b0836652259b7132fe174715c54a4b25e48c0cf3e1f954d053ff60b18c965385cc0124871d089f560784b8a2860838757ed5f542c00c0ab589eb9702c2bc2005b03ff081efd7bcd765f0f4541267d7c2b6c56aa397602144e9da616349f2d303a9a857c4e9657e547d088236a2083725efa47e15ca54d6447e895940c0793140e739c1a548617e867173dea57f8e987092b150b3d955209361e9a3127859c2605b1cc5f48dc22cf6e4f4a131da71b94703d46f8135f1db76658ef155e755597574fcbab20231b0265775683313931ef10ae66271f147cd96b8311a47304b94f4361fd5716af71273d55617a6fbb2b2e1232233b37d58b27119aea6d0e56af876deabe995a33834c1665c16f017c0b9940e19af611ce5844660b8e370bd4dd233a0357bf1fa728854f9fded812c05d4b5eb041be51b6f05e489f646d41b6dc5e3ff037194551f77b61df746832b61b70445ea1af1f18ebc0666ff1674a04c240659ce63d1cfa40486101db6f4ee7c11c3c860ee26cc37d0165b777723bb99af838b40c6933f05ecf55d7ca046dbdf6be7780a81d4097969e571dce294178372d5c38806f74fc478458582c1e119a22824fac45b675ddbd246e6741cd289165036c539fec6e014af459c358155908b4bd17fc465c04280351110f8cf85d6d0d390babd0f370356ad82e72b3c169c83faf744464f14158980200cad9fb024ce7ca081d6d261a9314725622ed354000f04a52656fcc219a094971530fa52a8149ce03689a9e0840b83e4860b64d1cdf1ade6feda863266db4d326bad4995f518a2e209a90ad47356f533b3d1b45774c6e94215596a74e46b7586df7c9d95681a58e6f74f5f4217720b67521e3d32e09dace7644cbc74455d5db1c09b4a61fff321a64ce9f010d193f7f3dff72fa636abea02ddaa0bc76fe94e83a6d651d6c697d4254935da93973af3a289b246d668301e15beca79136499ef54ef69171605a5b0f6c980b7a469ac529698001176df313d917e69e670a8c88fd462e0b7452b19ee71e62cfec2961f8376766a1285dc91d4b16da7b574154f85d5b95431c3791c1e767e668e50ff2889366f7655639795fb66c805b95196976b06df91af745734a1940ad372e0fabb3f71b4ceda05cf3a7412539aaf6546bb836390b8b965929d19f4969c45b701db4be204447c862dcb5ac1fb36a9a57b27116421159153b9b9c313ee974f343cd3e7d56b7e6ef628d6b6b2fe5e2cf71a261e76db6164016fd2af3571fc7901452d9c00d978d5d497c9f841fde92206673358252782f650076836965c0747c0f390c0f252a2aba3d7b5eb01251935f1b7fdf532ad56e6575810b402a7dddf2187762222174516168ef4b8e3682c7945256f3b933686c5d174b1cd31db506f903aa11f80d68a0a40c3bebb02acbb7e8137253136447acdb08f683672472d20b38d4442d37ab9bc014c795c21724c8446f7eaf46774fa1c35acb6ad012dfa1667372e39b16d681bd28a865b16baa0a58143daad93416a8cd7118a0ac566d80b737c28b0a0e0dfea370dcc68625ccd6e43b09c23f0c62757061f7ace220ee4afd026d190f616a792e279741b769237ed04b4f9ad3391525b31842cc22462a3bbe682b0137410d96d20bbe117746d95c1d0e6caa3d579335574775d0443fffdb571e71d6ab2b49579e1c2fc05657086cbf2163904f0af0be016db45105163292ad3c85eb1e6718a6a60d58a83e72e70e4967271c381bb43f9d6d20b23c717e04eb617932fd5a60127829a09a136fd47714499a7138383dafa247d4204b59424a6f6f4a42da0a89151868fa5f8e21de61da7711d0c33ca368612663211934f5974b57383e83237345c94c4bc2ab7697302e5529985b6333b3b145bdbfa96f5e8c0854589988307b4229524054f83b53f8a0613cded6177d5d5d77cb612a39ed27a543267c1377bebb5b18353b2e6119eb2e0b05b5c300e3a6fe766c2ae43c9ff0a3320e77c4125242365b7b720750cf074d197a6d250e7adcef018dd4a220c629934ab4c1e2295bba686d3f573c0da71ad50db779ee24471afb19dbf24f6b59906d0d8da40e6c6bba9b1cbeb4364a575c513ec6bf1317ad73d15c3f7fe60262ddce214ca1676764cc4f011bdf48347314a75a77536d0449ef4b48c264aa667c24ed764e2bb23912e1ea43ab56f45a20b66d63b3da9936cd219d2389457136dfc1c010fa820b179174fe59b649382d15b766266588e91eddd6c529cb1ea6505ec709270c680d04b2c86822556350512bea87005775106c48c7a437c66afb292072376ac9954324f3df2777e97687655568b3351959835ab6fb7375170cb85ca5f9c1360a742715fef4fc3e5092f75c2f486c158ebd481c578a48144334921c8b8236619ebb810dcb8b924417ce7156a4e38847d3182617c86dd3032cc560697ed54738a80c3c6fc9a9cd69dadc524b6459d745ee80321bf68aee1a9cf64e515b29a074dd3b401a6020ff5b1cfca831deefb1429d2a2f0ec417832914b4e14e06c3a255737d0e3a3e876e5926dba61ec86459351ff4660f4184610e3db2d613fe5a3c0765ef973bd306624dbaa9315f26bbf63a184d4725bef6fd37bac32b77e3c6060735069a3bd7cc0d4f8cc6a120335a505e290dd950ed5cf111260d6947c0d5b35d924fe84581ffc672242334625e3ade41186ad24d9a800a20a55e8e5ab538b80cb4daea5b43a3ab230b203460a1dc9a52445c7a5949abfa2d88171b568501ec7705f4a310259b652a263a081a5f3589008f12e6443096ad2503ab843d0af92063b42e1c36d4d31b4eb840e03f1edb68101d8c311847f9600fd627e3296f50543379a5a43e03296e44931b8742856267005469612c287d2375ecdb0065082d6828f10a215cf7205114ab039427d74c985761612f0cee208532dfc17c65daf638553ca466266fb37a52bb367f420186ee63883d285b5d9bd974c653632e7889b440769c4d0990ac13081cea462c1d5a6b58ae9997325992cc488245e45248b33a1be773a5290128ba1f290cef3568aa43384a79b72d7018b33941e216211e59d433b3b1011ae2badb1c6f597b0c819c4457335cb30d75c8d4528b6db103532e3466fdc1423d8108bd7091ed2e6e00b5ff45875a03237c6e3a3272aec3357851d440ddf5c15705e4623b170974239b2ea34613443c3e9b3fb7073dea406e581d8f3c5db344244b179f52ca56ca0efcb23c0c182891710882235f28768e533fbb1843f52aef466f4dfc11522a466efe1f1d32273ab915d61b915cb01d0d3bcf98505bdbb1a61e4bf28a00af770213c17e5873c085cc3c57474d0fdce6c449c4584b279c698232f9befa491acc724650e6154ff61aa4199ae95852058b235b6563353b25e74b6e6641c95c32662307f677f259bfaf5034be556735577f3813c2809645e8337a3e948f8b323e09a41d73c24b048f8f9c757556441af4316d1ba5db9d09a442f96e274bac3cfa1f2761d1aea74409771a69dff6cf3f7deeee71f6178634ffd29f765e4ccb23a8c9075138fabf2e8176964167c3d858bcc263599101b33b5ea70b29fc87e63ba38a39398c1b4b5648ced42e6512890949a13454b74c2577b1ad1904e65e83431d67e96db19e102741ac200dbad14f49fa262a32be66a015d751e505f1a07e2355dcdf6a96c7f87486c8633a7f6bae294638660394daa72aa93720548173b915f1ced93e10ad8d3ab45b6f06049cf65aba48392179406754a9f93803b2bd662b4969c73715ce6522205e1232b07005175ea9b34ac763f0169a85bf75f2da1b72f151d821cda33d72a715670ca7f0ed63447ef62a43450876d30ed01dbc45ec00a13f9932013029549d2be7130fdb2153c1e1bd3738785e5b184e2c599fd15077f3e166752f42be1672ca2402760462722c38ab4710d3c4006fc877155b5f8e4be11cf156cc705741e4d48751a47edc44abc8852390ac3b3e9e559e3abada8f35c4fa50080393043b3807ea068f9b3c687fa3135b1e04b4434b392a17b4cc016e8735d23502b3df2253095676907dbc73f8b270166ac9525b1eb59b3778d9832889783d72db59bd086c363c5a7cd1c3108cafe56df4dabd24a4660753a563a708e09eaf57766e632669c3a6400b1cf20ec34f365e03804d5f21b4494e04f1626d0c28e420ce8c490301cde11306962a4457b5c36b40fbc410bc4e812e6364d070384d8a5f75d69412cbd52f1e9d7469723f5d2221293c536efa88de0ccb33f04ec9618c280a4bf105e1a98830e83dad6220d0bd01cc19583a9ebeac4f92184f376da5400dbf024112faf7fd3c2f7f9e5b8c9b2d5a2aec5866eeaa16558fff4a5d798400371a60602c6a2cc666cb1345136158d173e60a7e0b502a130c41aea05044f7b02079dc920a84bbcb46b3ac541b895a2030e9255d1c7bc1f22f2f99523265581d229d94dd734f1d805d291baa14f073b045c456935669d46e03d8f52d359f4030088206391fb3818d440c2a3b38243fe12ca8881e05e9b67f486815b54c77837c3c0238ac404fa06f26be25a94fd980b84262ecf61a08a0c95af0fa97172122080a5a116d2681f0621b5201e42c307b485d19b7fe5a815a30337632f841d6b8e26c5f302b515661821e95699937c59add2e72a2b92a9747954e3772e72e448ff32825dadc0e7a5f262c78a730238ce9ff0c78d6eb433b9d431c04995253708b16656be49f5958f60f265d631e2c1f15544df0feb62052d7801ee45672071e25ad683173920f8b9b7a4b7b207719288b9e31aeb9c152bf4c99108046963278e8150105729506f3c4de6926d2963ebfd6ce3f6799845fea8d153abb90f06bcf14f55757752f6c7cee2603af2db43741fe241e9b3105621cfd5935ff2cca31093d20066a86960a0d36551a6d139c5958b56e6386914f3d63424433913f473b285f3f5c5d7bce1be4079e2ba18ca45ed9d0da2932ba0b287b06930e0e2c4844f3431a280b69f441f540ea0d6a33da1a2e73b019c825a2534422e908e8819c61dbb8023ede97fd6a0bbf6a17cff7704d799f537027dc156c444a9967ebf2c91c6664d7239f6ab9111b90375b1f462075c793f5320e139d548115726e143b643b1a6b21632c4c8046b1bbc54bb369b04ae6524701cfbba2760b4d291fd7e97312b489d2596090bd7628a75c6535e5b17094e69f3ba5f8e624da128a1562439c643b2b162f680a080019e22511d0ed1a28c4747a5ccea64e12530e7107f852ce660e035255877219005dd0c635c11fd81c0b30150be884142d55cd13724867f437273f686c44c64973ba560906214e583a460bab345b1a801298ca8c50b0035c58e2d1643c9b9cd9703e1dea26e1023e3d9b58eb200d124b412b8446570ddb8564c999ba11114b74347e86d63a3e67ac5cef59c740fbf69e2b49bd4429a58af3401829c43642b31d4de314c6277f391410da9567430100114f0062ce39807ad64001097f2decf90c1cbc0b854891821a69ef761a70e7cab56148da962857665820bd88da1e061f522e5a0aec0d74d66808cd20c2497b6afe3b84bfd42053cb3001f21c84270759486951b26721d3a07a67f9b2cd128940fd5f2f1db24d6142d72113a3da3d57531168c7c3ca74159f7930e55985275f9f255bbcd9a636ade72e2c5d62a70a7cf8055d5cbf7d27feaf671e26f4f26419742e655b376e2b51336b22cb677b2d0ed7a93acbb2c05b6238226aa040ca162d56524f09881c3fbf8a4326088bcc3ceb4fc8043708a050fd5ece3a24e3673e534b1c19046248276f6a8470bb9b8569a8385d0bd85bde1cd96ac821475cef281ced4e7677a7db3e090bc45b8f0c9052438cf053c5edf55e1927ac652375fd25dd5394410fc2a12bcf780c317928f725f124877607806d2c628faf4b020e494ebc3655512222ad6f725ec82be70f2406be700b55db385a00fcd1372591ee615071fde6775f94331acf6c0f28b5b7a3724e822e5bf422991e79cddf2f1ec9761cfcda26489c72555f1c0fee331c07e546e945a504af21b243ce1e3b36a1dd3d4cf97676700917f1026f73fe4a8666ed433501b115c5d1be205d2f2269f2dcc70a3fed243a3085dd033d30334633f864634bef7c4cf4a45e01b3fbc234c4fde308c305356608f4795c0321aa3d87d31a61b7f36f2100856761c0b7213f648e2a08273b332ccd1231716be86b2fa107643d37ba6e4774ac0339218472692ffc7f28cda66b4f78f249584e40156ada7b20472648241783d0525a18e68a313687f22c1117a177eeb2c615c6af07567c7d544c246186375569cc5c5dcde96deec4a14624ec8f662878414e04acfd69da31ea4cf64a4621cd7cb50f0cd4431e2a53094ce0fbc83be27e99685f86c3371474402f0625b64c40350235e358c353eeed8433662e6f2c00b3d541848e0e2a5d27b514d5a5d5032e2ad76def0e7b09ae35a56bdcb4c44e145fa23c5d0b913b38815d5e5cfd712cc0beec358d949d732022151b31204e71666fa74e773da5406ea475053db919166343a72093c7854b306e0f322c77dd0748faca15b1c67b2bc0f31163e0876903539d2f5cb346f81c3895a857a5c485580a59752be293ff1fd57d394e7226f364ec92c745bcccc80717a9637242295e6da7b70973047c3750a5274131afeb20584511c30659932e60acd7c775da38240dffe4b21888bf4e4b207d1618975cba6deb2e332ccb43e115bddae541ab1a7f5c6ea46a1dba3dc73d151eb81c6734266dbfaf225eb753c776d4699719be42e31f74ed2c5dfabe555f4f44ef0206b4712d42606433f3a07b206664aa07af32e336d0f19d32883b7f70d3a6d570f97638774689223598b8534a77cf8c3629a7fa315114236aea060676ad8e132c47970804d2144d494c204504741b7502d56b306746c3c215bf3d96302e8f5a435d3c170051f2be31c45c9361abcb5e3c74fd4b4e09264f31696b415cb3f55666b917f33001bb3515be06cd3d0a61951eaeb6e3705e4a093c6dd26f3251612e6be858a463738ac856ca0d2510df24f356d326ae106edff53e11e6c8595b1ea110a65af90597079a602abad730ea3eea28852bfc0dfa2e6e509b09ec312ccc23509848a4272e54f324c2fbc44466daeb456cd3ef668b74c45660ae2c62ec9c8c64528d1f60c79e732266a2fb0cb2e9d9335c585042a17ae367a0c7bc73e427af1eeb4d95434aff1f740c3ab012efafa965ec5947564b3c0a3b9ca6e03441dca232dbe7d024d15ebd2d5edaaa4fe8752f449aeb55601aa0763b9375645048531f4c053ca80ed6f6124ef192e6155b99193766dba44f449afc6afe782a333582b22001d50154d4ecee6df360e51f4e15332331d3c470c3d71c2605bca76900900f6d8c70883394785637df7d09571a166657c69180718f8c4e1fa6dc0025a7780218a120ce4a6fce1f42a286e03cd9a2914429a22869a97ae61c8b1bc54049a2e04cf114a547b840df0c30c21700b6bd95278328301db322857245bac4074abc0c2df91bfb3316e6831c3624cc476a43eb5871bc3c6928af405ad32d172e81ca235830621857fe28af3d842ce46e995b88707cd45128c223517661ab975fce69843427b5b94e6bebbe138e47d10f7c093458ac145343a0b9103fb46bc473d83da3716f0ac228ae24250d067d7054fe2be33007ad3c226593df3f1586651d73b01c5c6f650b3dd78d7b477935f4171d436c38999db62a3de0d01f66af9b241067f528b4e19e1895bc43038b626331b777bb6af86c636d472943071cc624003195af069cbd49060c44ab01908b67510434c20d9e32752f8a3fe274416430491625ed6aef15383684e3fa1ae4e7912fb4b07542abe54241f6bf6e1e5e51ff3bd274b205ef029921d142e739203c14005619b46270636e195bc9e46a95b928055e2b975fb2332c66913f332bad16cd364c518b6ac52b2c27006e3c082362114c87f38966407a5c60621b4859791cd0480c00285b6523b01bb1f0f65aebae5d495b343e36c8bd0e55077e2312046c041109edc2242ef3263f838d213454f0ff34120b76765b323b391ec87a06899329226b639b37cbf08c3b0de7e2041d8ffb680770a44cd352260f0f94a86b3f284852005a4b0ebbf1df59b347f934542f3218c98fa872c2aa673b9e37a75e5d526a6a73f0f643e08ea850108acb27a6a3c9643fa73422fb071903f45b3e204d00a72ce034490d22cb9d1c9067ab5b60d0da2201541e5f2054c63ecf54506a692bb0241e17682eb1ef7d5db1118e6c959cf20818ddf465f9bd6a3323a64052777fbf14aaaac932fb0d4a0771a62547cf4caf2c00586a19d13c497645d0df4a72f97e22797f99732f55512ffec3c65f634b0f58e38c1a7537bb0f674289f0480b9a4e525685e4164fcb031c5e42c32c12b7ce12c28b0b6e7c6a40369115b253fda40e595ab0524e1273ae24cec6db3d0232f23efde8e54592d9686310377d26c46f2674589fae2b064a6e4067fba818002495549d5b31373bad532be977c1185caaee620d6b93157935562466fd231586edb64375096670df7349033ce4c633001c3b36d7c7161eeaaa3a60ba52a737e0296b1cf16f0502e19093664d8d3f5fe085926971f71956115bf707e4183441d856e03d5f729a5999636628c55c0824306add5e3620bf63d05fae3de246296728b6382db394282d62e7d64795e4163a39f7ae407824671bb16c7205c05fe73ef23f552efb24704dc0b8c20d8ce55520a240ae110d735c4a44881c03ac20ec4d736e9f37575b831f2100e6361eaa70779383041d88d0181fefab5a03557fab2ac4083a118aa3ad1454ae390dbaf6155c1004a738b6bb0d6e62981337d083e019f104ea1ff4e8b3442b19f76e363d3038054ddb35914c1d072615a916361ad52c2d1cc7307720a5281875862044d3a668caf4636659896348f985dc0fce57483c8414ae5f5438d62b99e0764b414c0e11912bf025e8737276674a263f08c2f2200fbe0e42aed1a700fd8bd913ba8867641582f555b45d2c4c7016415251359f177935fc52070243498cb5fb1b8f34ae35a40d1f1ff13e8d015dc946383135a423ff08711d842baf75c43d8b58fffb4010ce388c3fd6e9b742d3438420ab62414d866ca057f5bc4f67c916d44d855e47344d77830bc88cde2e1d15fd001803091747891823ee0bea5857111d4f24082f11fc5b520e45dd5f4afcaa8305fa5aff45018a0f04186ebb5e9053911f51f08a734fb7184cd83ab6505788ee4ed7e31a5741061d279b243435f564bb6396242429ca74cb550272246273d93312281d800d1e87cd39cf30d95380b406776ff0fc38b6272c04c9d3022a61c81a2ce8492a5e56399d49054d5507e8d61d1b5db1150385a8680867f4725240af5343e53e7473f937836e78c0166b0a371b049116a871d0555132ba4ab15e9b2f47316a37815094f4955e1a3e4d67182435610339704738476c118588fd2b8ce7d05f59e59114bec97c1ed238f335ab13e20d06eb836828e031181197671fa8f87d517777a5444227c90476bbf23f3e30d9570f599650263cca21ee44ef4d39fc3730179b965ed247e421f9dffc25a8ff0b3c322cbf2d1796c749497a106e907c8f40af4d805276377a6a2b3498424b9a591d0e7ffd5d6937bc66d3078133785da21db518404c07d1a74870a00c3461b5900e2aa43c1fa6e7cc2a8619fe700224032945fbae1c7c4e2c332ae3b6649fa46b2cb48be20f8ccdca4412169174ad4b241a275cd9613f477a21967d613738dd5a475508a24925cb841e56d6882e6f027b527a7200049743d72d20ffdd09e5fc8b569022394fd0e44b2df1fc635a7f92dc26b49e413d42e5a7727b14c30199f4570aad9d833b3caa225f42cbff5ae197a72897399a1593e690233a1b116125ea352c02af4213bb14686b628bd44814e00659a9f63470f1ccbd3e0e216710419554208755884a9047312e6cd4f0687342ae0d29615371448d11776da644660c81851289ee953fcf3f0e3d654cfb28a541cf1db57573099d16942a96aae406d24bb32e739f03399b705b51362e3309e779f7653bd79e2af3ed6d064b93075e41ffc0251c7b685285afd907c5abb770653c8167209553496bc4a36f53068e6bf1fb9866ae02dc02643e2d5390a866565850630f9dff453ea41adb03b8ea645857fe2a440cf0e537e85c271cd8f1b76e4b3a816c579135413046d74355f4e85a027ea14695203550be77dd5acd8cb05e5434af6b64c47072ecaa2c71094fa05f58a8611a6038800302986869edde173f0a44470a8cb5db47546b4c19154fc81996153932ba1ff26469425842243ba25e031f950da2f2ed61a0541b233602822c29f2f94c81c766311249bf015e5b0505cf21c27727b2c36cd852b65c7698435ba0e93a3c663b4c4256ef810580ec5438d3582752f8692f30958b9c0f7653964f5a4ef15b571cb75298d96921b2627507c3309644a84c13000de5ac59f666d602928931560e689f04c1714e6de623d06dea8c726e0dcdfc760b741a05c21e4c49c8f74b41c4216c3cf932932925a34945888ddd3e9aa75c134899627424f74235468bda30d01e07766c217d216689920c71b7981121dc3558747d070f4e65084e30770e4ef23341450194354c74e92a5dcdbc9f5a3e880658420dae34af55a75c445c950e217ff35f6e524b4b46cf7315c264f04d94602a3105e43412616e521b69579608fedcb02838d2ec76b88ef1461822eb6d5a34da0c8305f86048f661428fae3938707f58443704d35bdb8b7d7428a3ac72e80db725da8a12620424153358054f6badfbe253ce22926668178924f4cbd56587c90658b82f03042fa586643047d3569b123b2c065a43164bac3a6d4fff8d4ecbe0991af28bf14e012f01581be52d6d67828d14068df701c391fd236722ec3c94e6c7001877fb6ff8606d1b302d4d6c6ab42a45edef635bd25b8746977d191e42f7852b259bb30bf85974702df7b442a210f14db3f0fc4e16e47220c3ab2106ca63cd3324045f73a671d9423ec32d5b1492746309162d427e84895f8d6df53ba92d7f3eb485ab19047bfd295d1a45549ab05e682d363f5fb12fed5e4a781367a933ea6980c09e255a22164a199e896a7e74b1772aa2346269470b5a3eac487054c32850a7ffd96e1d965a107be3a02f5a8fbf24e01aa409180fe060ac7c40400122aa2725f5bf476961902d65b4dd0e061d3f6e96947735394802594df56d101b281e2cb25c7120dc540e5b8ebf3f67bc9cdd0406e56b4fbfef0729d4ce390fa5885b464c65906bf09ca7165b03a9749bd0531314789e7558d6b858b180a92024fbf81b124d5816fc8f3254d1b3f924af7d401b09f290097cbba56adfa74d042d83a7137aad652eeb45820f62ff2f5327fc2a28004c942edacf4f05f758c41a4769ed625055a3414f0551715ef0bd00079cde364fdc5412d318dc3cb12d3d2c48840d6f14c6d827a4672066bdf1730bdcf2063cbef3e66f160bb63c27ca82249d0197351ee45c02e1b2ad6ee7695f7192929e706d5da81c2949f63165dce3707386aa539a8ec70e5436d64f21512e283d57a0331930051e9b40ae0060fe7f03c14aac3a95f2d93709225c3785f8fe111cf4f128b100555cb2eaaf2984463631366451536db6e575992e9271b1b78734b0a9db09a2d375283fee430784d9850050edc36d3405f95ab00b9239676f9725a835ba24db5ad41795bb09263a90e002c375bb63bd53386b518a79768229e13940498d58d2df0d2fd943740dab28b56fcfd44726667fe46c1a143f453d11e430b787a8045a4e172948a4fb207c16fc133b2f451c96b9463114d68e4ca805663fdb8f814b635c452480e6b85b99f4d34082c8ed2b3da29950ae3dca63d0176f04bc30515a6d7d1d6694d8564c0873f61e386da83bd8ffa955314db56e431a407735623f1a6a78e31f2e474c387d02e03b01763e6b92ce1b76b427d366d4fd0b4c0f63b84f3668b50ef0931e6be1eda71cd6d6ef46ab743e32b0eac60fdd591b380d322b432f923c6e8a52546462ecfe317e7aac5dc215ee5c9797ff3dc1244b3dd9cf965918e1511857fa7f123e099e3351174e06dda52d065d10a473c8990d1454cbb862d2310611d75b396a1b0b75412e35cb251b2d1c75d737b35e2cd80a067d11c2557c698837045f9f2e661d4d3c14f53e756dff1d17a8e7e51c7a31b21acd371f3faf65ef164b6f7669289e9439e71f06749d19594fbae0f705edd4835b0624a4493802aa242b6971694f720a5151a53f682cbaa1742eeb0e01bdf6af30efe0b6697a36451d22791d3205690460de72ec50211bc40467fef74b49cfdf00744aec656080635852d1415ba083b10dbbd3092bcef3ee5f10c7d5298ce227014106526f2e959448831531164c7c5637bb59ba104e9a133905a1c60c65901f4694a9b53e3921da1a32f6d24b1a4b0652879f2b2d1fee802710dda20beabfdc0a466f8a6c4f97102cfc33fd352da07b5af056f643f4a49e4bcb549f62b98e1230e4b88e23da95150040d4980087324e18eb2854154c31b230929ee3262a19ed10dbe81e6b2b485c33fa770454b33e1c77fb0f89710b32023127a68d32cc2d371c5ef99517c5b2524771edf62e5d800106b57b326ebed020642b28740a986ec3303b148b5dc4dc713d74b06c161698b107eb343501c8ea42412b3c4e3cf1941474bbea7531c3084d2929a72f2b4c69460302983d4b8465f24ba2c6e542149fad2378085248a22cf4401dc7ff4e3b7cc145fffbcc6e62d8d76f4850c137d3754b3fb467e6760f1a380a65858430a370b15d9fbaa34646185c5777f8991274bc180f74d36e1e6b19d46ea78cc34f9bab8633fdbf2b6879f2325894a0ae298ae89e4e60c1c058af89ba6d90a9b43c7300f15b4ffa346169ad174402f80335f73d5434a4f9244be4e90802dba466113a95d13095845f37cadacd30588efd53c058fd43df3478658fdfcc37b8bb2858e6c4364eca38e63481baa67143e60f29142f3c076f54b163023cb83c23202f1c2f49c171bf4391407ec2285bf35f27169e17c74a7e10160f1f0f6026e56eab180e217f33104e7c05a366951a775e0d06e849824f98b0fd56f03619049ffe823d3406584e224d6904dfd20c6b16dde406e5cca02835313627cf21c060231f844ad7620720f8c1fb6b01e53b1302be477175790145f7e75f033bcfde15cfd6b53fe1cd82005d68771cb80d5644ccde125f7992e077d0c5e438d85ef36f3eea283c27efce60d00d581da21e9f55eab490199bc82458938e4e19507ef04b07ec176521a3a9603794416e081477471abc3964b675b01452087c72b99f9e49fcf06664759933417c787f6d5bd12d3868e3646cf8d82f15b41062693cbafc72b20a7007d7e4b82f91ac976020f09d55645a1f113c76205041856b1b93cf0744c3d9e50fd93d96510a4f5e09fd74f76e4026bf55533c9c3768a0e2689aab95609a7ce15ceec2640a01854e553c76e10454cb093feffd185ebc923063092f8a1001369f75bcdef14556fa1646ba2ade2c435c2d733221c2172c538d265d46911d49dbc041b7e5a610cdba815e919a5e518357b318b84f4a0357d1481a3fadf401e11341135e88065d01b2e42a939d6236f372ba4a928c106aef1959713ec0c23a7316b56a0fb43b2af5106d4f17ff5451f59b1c430945cc555c97512b2ed12732d86cdd206869f80d5cab8a6e771344291a55920bfe29fc03dd6a44429a98036e5274e15c854a90694914150b12b82d4c4cdbcd1681248d493bf9e22e014c0e261abdf11dfc79b9063b4ed8701348011a98b21f19484e944888e05c4dfe6de66c3b3e5f4f0f97360fde8c5b51b439726ab39c0e5d4308d0407e145112c6c48245f1a2ba0f5928234bb96c963d58261e390c79065cae865f2d8171e900c37d381439049963a92719646636cd259abe76293d1de14d47ba8a434fde141a2975b00824fae422020ea2535aca9c2f5c5cb4751d1a9c4b9093001af488f06199a88623bf3e7028dde2e0643f56171eb3c32f440bc49503b68dd86d37593a58f11f812ffeab3d06383363313139fd0a1371f677347bd414505bb66fc87eec14ab2d19367607fc441f4bb2017c971556374bf43fe6c9143e663e1f4004a0065d3814485959ea7b26d9b0372fc05cca3c59341d4a8046c41b7fc6b16c3f40703ab9814d66e2784a53b1dc506054068072d394af632dd86f545506c36432a9c725f014ec044c3def0914c0392105bb6c012f62e50ed13ad813a6843142b61cb572e52d501d6511bc03b48fe645a1193d5fecc10c2b12cf94140170b3226bd43115b9d87864e1918833e713155768efe65634b1e10791369065ca9c9c189cee1a1b26437864366c924eadd4fa109e47203d841d602b9a09976d26942275c58d8f0a8b2f020e4dd4582a5091df053769c26fa3cdbb4ac920be239e1ee737ba65bf561bd63618f505de46a7c5cb224d67f75f21452d0d953d6b3ef5b484702e12070b6731d91a30642376b304da01569421478f355f557c64033b85430145d48d436f700f8b03b1c80d2fb374e941daa1ac6504635463f3401e3f2b229a468291356403cac4499af663708b58af46bb00d3248571e42539dda70d5d9030582878d01c8c6993680f22826b89ea095c71c4e6018e362210739c5941dce28370134f1967c834b25a4c4082631eceab0f40194d2a24274f2f3df6c134faf38b111939a62c85b8873f4c2f3d1e3cf31d3d1e47da6e0dba794b1be557063dbcfd2cd0c0a613d99fb5481e173b0c3f67950b4e889d4829393f75bd59161e82442112af7f2f2a31bb05614157281548d8865dd2a5f21545d7fd396b4ff636c17c7209e9c6e474cc31345325476d404c2b13325276e7339ae2e31ae6b2bc44bd90d626e05f730763313a23ce262e585c8615515a19eb3df3131843eed235542b9cf35bb74f0607b850960aed7d8224a5e01731775dce2c367c396775aa5f2d64f6b6112668cd3f7c1a2662f907a640261ca423206e2e74d92ee84f3b8b9862cb44e628d70fc8535f5a86603d72696ec9fe9506f70524734eb46d36a2ff896dbbce413d8efdf024df9371086119f754fcfea53565176f140678cf31987940301678b220e4dcad694d53bf2a12da97168453985a5455326e2550df6585585252ac353f5ca776de6446f9ed625f1a583799dc54336538a64d03f2090310490129d306375712465255ff29b314f2572b67cc44fa2f06ad1a275a38791b5a6f9a44eadce103a879f30527faf63a06e202382806d103bfcb802ec3dae411bafa820046334754891d9b190d6816684d91534636727b05df28a838a2687c59e6b9056080466b2d8e35b6240857cd5659716c390e4a0a1506f2093fe7018d5bbfaaa9290e9d7335bfd3bc715f20d56077267124c9b5e01fa17e5b583bbacc6cc17e2c6bbfd5a9281d835e58c67716753adfb952f4a2f7755b888b0cb02b983ee9e9d21bbe3b184eb86fbf65f3824f1c47d849626e28e12dada49b68477b5258ca46d930c7cecf59dc7cb16f67fcd068a56ad30caa4c89289a39fc769bd5634cc3773770eb3afa09695ffd113ae1e933f10d295552dca645ee5650290e3ebb2c04591e4d86cda374fc387550339aff6cf2687f5ab3979247ce95655b85cd762996d64b59feacff556a80ab3d94288323fe4fed4ea8ae140ba24e61052114832040107025cd36bd52fb5f1040e935b845ba63185608b6ee2e15e1fd2c0f468211c7a6891c117a4d6ca3f95e773a23856edc85f60c45ef4a5b18d2bb4c48987460b5298b6a3e80d3075bc8822358f5ac1842d17850fa8b6b4210ffd0679de3054fc5ab3853ee68625872b2fe6e869cb723676e0b5d85bada05dac1fc28e0ddd23aae77ed5339c709024a80d60606b0241691d06d74f58dc106821488031ea0f11c7e96256cb21b6d5ee4b5d23c49f51b1a513de176f87dad153ae2205051f69a1c22ec301d9500ae020c2d391d11d5b26b9e3d4d26341fb757d6fddc765052d3364a2c5b0f0475bd183cb7273ca063162eeec256540b2a78501883cf4c7899fb02e771d5279d6e105faede725cb81c8f63f6e4cf3666c27b081408625d47d55247de7ecb0e8fbe7a5f83267357f3bded6c65475469e43f651c86759a5e0beb5977545cdf5ce407b05652b5a20c2396112f8ee4f124ec4cab1bbad15669210bc46530cd6e10bdd6514ea8554f62195f646911b52c7408b11f1249f4923b6d4c9c3fa15f134397ec4106c7a20e60b0e7b70023965e1ecc72024b3fc6716c0cffca047eff9746439fe633b497fe33f5e9c615d10627092f656a14921d8560f37d953fefdc1f19f79ca7077e66cc6212c893353856b91727b227210c3b8d67ea3ec26bc662c428cd39005b996788731a240c55f3c5850b9d219a0c7d0f440c44c92f2656557028ec7f5e67dfbcfd70bd741d4db6b801099f6571299f21231e878063502b1e3e1a1b7e4c1f83e09c3e8f01b927ee97221f03318513ad33dc30472d2764bad3b02b96bf2b0697fedb3c4df9b95a1482166f08e7b85910855844a093a724f77ff850056792452e04302fdb91c665014bdc73053129272508e32fa5c6f902625ba61cdafc7b2a9b7ab1178fd7ec581309d91b3acc7320415c0265dea91825f715ae12bdd56e6cf8402523d559f658a01b060cf0a4ac373793920c3ed9cc497e19fc141f454a733bf8c7159ec8925565266e545a0d9333c3262a3e9d14f309c9a9003aa8ab8a5d73a77a0021102c61c0e8ca0b341faa35cc3c8216fcacf253cb36b13451449d1177749a1cdbeaf1332760df76f91fe529980d9c2f44b7124fd2a83366ce1bff0477f2907502e3725845868402b414e22df1e3bc0a4b95940023591406b5772458b7353e03fc40a421fd9bd8025b302427b728fb5d22c19e50c67694606b182a15eb1c2512a08653345b840946157adc08525f2253330d176f9590ec60d771a0146c5dfd38b7ce342bc8c56542e59df50ffd055c6d41784700c1b8c7772beb8f16d90d316e70294d1260e29c1c177f595567cbe327d3917b0f2c6a3a59f2d2f00b3dee68544c59b05126be1b6958d5373df0f1e15b6ae1fe5376cc56278c32fa4feff9213b10c6e147088cdc4714ceaa2269908c261d889b72030d16044c13cd4c163bb569043a9123e5a48b4ac414855c58e9dc2f8b918f6316bb2409d4b05519d8d2c602b06c0c63798e4101655f441747f76e1146399d4f1284a52b263afa1b0ee0775d995d130058c2a21e4ad7541dec9dab0040c7e838f2d98648edab0e6f04ee3473f8f3d056e7d6477637c8b537cf9d9956e94d120dfadbcb271a3e021e485c210f8dd1a2049214e975ff6e305b755f1135313c2141aa02c80fd21bc42f09f0544f507de30fe390451a2a0e685f9f6c9235528ac900e3710e16ca933a59df91740a1ca6273d71f0bc5bbe753937a04e1f77e50ec71ca76c7b077c4bda54242e43026b5d4e115203d1302e2c724f3af82f118a9dae06ca142055c1f7bb2a7b049819784e502d62b8ce16c981450e422f8a3ec814225d83d6f86929d02521ec9b5f3d1d1ef41ef05fcf6b9f2f0c3b10825f0b8685615f2039a45c3378f717763a3627702ea83478e47f10be00ae08f94d9f01e24900056f2eea38f61e712bd73bbc27ef94961f26518a48f5fb463906deec172c3706113ee869172e2d9b47ccf5e757a18b463a6f09364ab9c34e4e7c33fb5abb8780439b1a9f3fe320490e56cfa061b2fe2130dc6a9e714b03526a4072a870f18c93157f5d501821fadd33e1f62d097c94b34bf48d162eccc93e4306db185a059da42d544fa663dab15e5d1ddc504199a48336f677ee74d772fe64130ddf6d6948115ffbf458470bddb51f681fbe2e0f58ec3eaf47eb47e9c3891bc9b93034076d602a9ae8056e08a5893a581ae04de3f81a556ed41301a15cef61dc2ae32a8a649c21e3f2ec26c9a12e102d5cb0245f79b4681c13c348b471176717cc7e17dcb3713b7a6c4306f31e5d545c6e89113b73fd4a1baabf3b833e37514177ec5bc59da04b1aa0ad0c1aec9a68f043c9320efb432047858f1fd4f99a09c487476b3a81660cf601695bf572c05ee25b5152e5f7251d6ff5a321bd5c011ce85c1308c0f9536b2e840163b82a2633371f4d37e788a8261ff5a5291a6d6b31ca056046415e98125d344b627c57640242e7da41c8e4ca6f773f665f0a2f1555d600421424ee9c4a23e3e576dc06fb71e40d830205e7ef43b8c84826e3711f1a57f3e45568434f380dd60e31673aa86059be386e89728c360d6b28670d7b6e2ddab0b82b4640532da1e4ad4b08b635008d6483758dc9883837047a2ebd428957ed456a6beed58b0b9595c8503422835465f0ee314da16b7661548d531bae970afe23793349835250c8a89249502a46712e632a622e91fe1893e38a48f8ae47156e363517f9f4f94e06f48d264341e6485ea2704944ca1c4bd03c140e2233f34120aec4763406f15b5389b602aae889627eca5f75f378c5046276d73e50f3d2534a06b3259900db6d357ed25d146434151474d3492ae0c401aabe3922012dca24ebaa3c0c9781b80f8f243820c5da6134492d714d6d81a304330ed00e5854ef56aa39c13eb190964c97a0d95e3ed0e0737b37ff432b891809c13c7b3edb19af1e4a098664a63fa35fffc4d13c1acc96054cac4366b96ef46017d00025844ebe11bcd6482eeaf44862f01aa05bc33a383f0f50551584c1bc5db469f6222e82fc3345400c42d3fe2b73206fa44c2375553546afee1c2dd9b8120b072b286557500f3f8f0c3504e9711730e12656e0bd110ebfcfa070f9b28020d248f441ffaab94624ed2f390ac20f34ee6e7d3a46322c2fdfe4e123988c563b17cdee6d422a15547a1fc20f923e972180f220018193272ff72dae53043adc65d91128216d091b5a1635313b80ae4c4201ab2e4ba9564f407e013027de2d4a6e21fa4d3e3e06f01d5c75cd423d02404a32079f6f89bc2066887c8d508880bb021eed5f775c023d146e2333131bd0234ebad70a555c00e728bbd7ca5ca1ed06453eef243fcfe4022b8db3d14493d9a11bf6c39b65b6c7dc53944cb424859bab1bdf5be6684132f56e46f12b6ef242706f042b0a699a8ec958e09df439d2c3de241b3ad65e38800c222cf53a69ed9c3c685c0b1e43cec00451579ba744299f845b19bb1a4bd8c7cc6291d55e5efd781274f30cdb0d39285925a015ce0618d91637a9c1b94141943d1e607c4d037c8ffd3bbe4a2d546c560b1b7cc7186dc2618f3fb5400f2db238bc56b7d3ef19fd6ecf02eac5315bd3dbc45857e4d40f2e5daf24743cd5588b64ae338313737185d3e46d4dfa205d56c380039cd12a4951e7756c51c848287d9532321bf25c4a6820054f4651cc22eaf8e74737236264875dfc5324c7ec3723939d55fbd9fe07ea91b357dbde7d07c3a6eb1c39ccac082857df28c44c6f1e2bfdd845c65748152872ba398f0ef96aa3f8f652c8ca802e00206071d12ae64312d3ab4152959b438a31436d18b2c006fd766c571292c3146c9b55222423d2361895e6004cef9d4b39dfd2002334b4539b7e9f52cbc1656f583a992de112d0126896796b55b3dd0300d6cd36051ec9013b657c180621615638f00a1f30fa931e18b36e6f072b59293495e862f4363c3c54a0d23ff0b22012a64c2825ab35ce311e95e35ec79e242200ee9521405bb13a4ddbe4084661a007059fa20945924269d7298a5e9ec81c20681a9a5f25774172480c1716ea2d475e22f7f5547d32bc078479c00eddc50a453b9b0b7144996f24cd35920032d3a6193e6ba6707b47416c183c731411f9a374893c2e2aae94d67759c43b60ffb8a65e8308175fa23822315174ae61ba2d52192b29434f8f00aa6a7f49643486300036a2d1650bf0ebe73350f340407962fd05b126af07b58605210d0cfe4593cc4939b9bcfe10b2234a3434c37e1cc562924561a7c96a81f39413e4c0d623202a3a38e26d5f076823681c71dd145202a588466dd8706abfab952621f45651d439d9119e4d644d0e14656acee168629e56024b58989a39562e4900b66b8950fbc6f34c299de95917b0b24a1a3666160b6e27236dd84763fac8fe5550e0f1579bdc2c3ea6635e4b3cfa49163becf44c27541c4f9e85291598ebb1208a8a5229506977184062b214cc2c003fa3024b1490ccc815c95c603c6cffe76b6f60cd2a11525f3ee171b0353174e434fd7b190d8531c342fb15736e4fd97c02fb816c257b174714c8e7ff6d0afd8c0e960e46695d5aab663eb952232cd4df4af7e41d5120585408385c4d07b8f68465e2fb671737a3882087c97c0912f744767c39f1693da96873aa20161f1928593d4ac46566a2e2236ec6b03526ee213b2eb7f107708259d753ccdef420a8b2d62cdef3e45465b6e004d63e20327e7a206aabe0ce1c39cbd7360d018654d8fb4b51ddd17a482da57b63ef7910398614841ee44cff2c2d9258270c9017383b295f6446a504189d7fb246c92a6d5fb3922c56fe8ac20aa6f02452cb2bb222975bd13c944298193825f524ea9156544cd7664ac2573c710c236b76f10f696e0500a5162fe52d3bc65edb723b2a8f6485d1ca2389fb69328889a41cfbf114021031b30be3ae1923dbb02332c3b691134d2e632fbdb3025b13d9e84d0ff90202344e24604fac8141188e2c715c33a76c808d68717ee30a4abbfd3c620ada64149a2eb5223d7429503e4d29688478960ee55b7c45efb96539b16e346c8ba339183449886700e52b36d8782226b2f165461ca0803c633e7131e39cec2078572757cc97da698ed8b933f641a835b2c3ee48df685964267f9a0b7894272f7929f9246a10ec6e32e9f829e7504b3e774d49315e36a9059055593be6d15469e7eab7030285c92d2482002432d4a16c4d26472391874e4d0b237b70a76ff711dfe7df1dc389ca6a857e9a26a9201b5e68f7825c89bab32854ba1f4f334cbf5e293b852521945431a058fc276514b0005f1f1108103c365a562a540c6bfb3f00f9264e5751bdc230e4c548458fb907266f3ad433506b7f61108eca4953c56e1b153b2d07ccecf21cd41aac27b1b476452d15446d10f7225432429a555150a93e15cf1f66681a0d432dc67545314d194e615ff42dd719613cf66e131df2fa6c5b9219940bd3a9a05373f1641109b2b72b99aaaf77fa73f76ccb213304851cec38ae20230c163c2c6efb6bb30333b887140b08586999945a0bbda0a061ada5ec3b3f97156a5302156bf2fef4691acfec6c073ece483c88c73e04d5d632f0bb8404b0d8e558c2a2044c23bb44409119220f3a2f2d43edcbb556cd590228cff83d1f90fc3c5ccee8d22262b9d20e81c9d03448bdb11238e4fe649968382f7b283a66972426420d39c2597671f8155e0dfd746137d81b5e73ca2287c04a570e8f211232fb856f59de692ed93b81366f9a540cd361b96a70bf424b4007895ef0534e60f5664b1dc6f049151ac65f327767d93cdb66a90600ee464af527280295e36f66e074b6047a559a69f7dae268ab2223498bbe6b24e882355531459234418a48595b5407573a3a83051a4ec8298d27821ad43fde458dab6260940e0b21dda4a74dd4524e4e73a4d56e54908d6f8f8fdd63e25c5072a8afc55fe62f0237ab5ebb0d5a40cd4c3cde8d6a6c014a59eed89563dd51cb33a3aeff19504eda12a3236e2b6e3b2749c1517f2a7df7c017002a04617bcc5c0c1c0f6a3f06d6eb04e90c6c587da3385e4886245b29adaf41c810e60f71748e424b8d582e56237d43b48df64e4ee7a81dc9ff9f43d7bd8a14e5623c4e382cb8016c381a6925c393492ea8f755f9c03118dfe1481aa876623d914e1c6d5248311e40029d177650790f7ab3c363952d54590a89982bb053ac7061e7220f90a4a7194f785d4edef94943ad369871d7570669d68a0738d83b1e4a4f12bc472e396c14b7b65a345edbb42189209f1ce8b6f94a77b59a4d80e7c404ab4e633842118d0c40af130c77453347748436180efeca03006de4286e04c421722d994dc469450ce521e84502d4091779d7f12384b1c275327e4b2f1674bc05cdb5d90ae753d222d14afe31c3862b0fca7ac6060f5f0c65998c6169f7493f4b13c5a960e7f05f45be46de19264f97022f882d6d24d9386373f03b7754af6d6f86525d023890e33b8e3b1a5aa0769f6851c3b112ea5a7444a6a2c23d4e4d966a6d241d0bf4e5a70dc476d01ed963e7378507cf67a5670a607b3988665891580dc11ccc494dcc6b46de62ea14950e6335f8d49744706ee8088f1bc03c9fd9ef58dd259335f324ac6f43842d05c6c0475a57814c6f6a2b195dea05275973c75014f912f26c2d217c360564ea13a552714712e4381f344233248d4414705c328718dc4f4b455a6c1a577d5f685a6d33447211159c1007c27037ee86aa73489386168d55326a09b2c041bbf4644fd3eebd303f1e1c580858da561064d77785e3b2086c5fe01aede88d74093fc91420629d44414b6c14903c6673db59a2061440b2702c17cc5bafed1d5da7e96d58bafb7863f6ecad1643a2702cd15ed11aa89822751280623c6cfa6e0732a25d4d11215148304cf123a9467f30a5bcea7155ef3c5a1482d945ce44ed211b3e8c42a178960e5f81350caed3560b617cb57092ebff25aeec1c23788d9e704efec072686a6357972cec51ad6ebf5d3097841e8e0e4a47cf2a8a54849ee704da05f41122fcf250658f3f60114eb62ee4e8af4fdb77d40e57a0f6018aabfc1476eca440637bb44564a436491e74196156964a06c21472468992f93231a1c44bf6a3b9223f51cb6df1828d421a84034283bd5e1e46062c4797761030533cd1365e656d671c922a1615f5fb1df6cfcd05f0aaca0cbf0f7d57776bb8379d667d4846520d69ba5c58689eac723e9ccc861603c6b13ad6a1fd0babb84e34dd25ab420c8eaf22c0926c4edde86462f34c3d1a181cb53880197f532bcd903f45833d6fbf4a1f07037154429490b662969cc730474e3922ae823a1cdcbb8b587b8ea207928f4d45f3c8d94327e5b36c22ac8c150af1a72339055c5486ef4c0ab39e06126038d364b684e34c54406d5555778b371037de594c151618815a864af76beb45ca17104c32d4ac42ea404f66efb8177093030077698bc05258731039616faf6f10a9216406ea827565fd296178ffbd532b1f6b4e78adb156229641715f7f6664e8405741c8f5eb0a1530c56b73996005bf726240a7bd06053f703f632511f26285f9ef42a1fb055a4a172773bd37c7651234d14f07bf7f12c6482d0e7e2f341ddf224e4658d1b96da17b1d583f536c3d886bf9457b2b2d63a662af0103e0c27488223d5826fe58117bb5e558dddc31706abb1d2c122530053754764f0e8b614db71e956170969352cc7a10753f7138091725770b4c834b712a7d82333cb3356be7e6f630d18d4c01877990375b1b606e7034456336eef60a1f9a5777de78df2bef9de9030153122e4d458b38fd9c905c447b12479607d50a944a200cb0bdc0454c1a462eff4ba31f30fb7824731379151edd430e16e6d13344300f35ac921866a2419b628ee86802dbf19934c27812737015b550a7a3fc391cdd521d468fb903ae8e7a2fcadc0962658e504c95bf412bd398af184e0e2c0630eb0d63a5e6d4718f00d966736b862182f54f31ea731c33ae0e913dc3485e2e4f936a39638214072d31a84fb63378642b332a1674b18756b1b50c3f7ea5b33bb02c6505ee5624256f700e31e072c35c0d8fb01fb1e8a809f73b2d4c68c8ad09129198745340fa4e56558237d32d1d65e3a2850612d11518a06ee964547cbf7187984a0cd0256b62eca6ed6c3812b1025f2d1923134ca337a4f0b746cd507e65451920252a8c543f8214944936808f397e7d04188f801706c01bad06af99784880a8a0337d87572eecd7a301af109031c3980c2bbe8d31443f2ca073ffc9747211332a257561932eba9c682c0ffc0d776caad15c9947c02a4020f820ac941147f95a685c8a83042068dd2f08fe05e14d99ab296d3b4d7e509cad93652a9c56527a6e29548eb5b2418bbd67277138d204150aa52ebf0d62412c84f66c13da33743f1337079f70d4731974fc29224762482eb388319f54a725e5d39f6aaf198f34c32bd46360e8ab399f9f7927f04cd92644bc600d8284904ad91a2f145ca529041f30f851943d24276f56f257a5dcf25764561f1db805c90f950185355b2bd1528ef36f0ddd2e046b20464a48ad34d87025542772aa87d231b2bf8352db67bb6305b0602f6f78974f0544f202c80b7a23e48678301bf4822dfecd136381a736702f80321a1519d026e14f46652f5cdf1ef930022f4295972187f0ec3a153239197bdd7610a0755e16567f0567598dce42f10f7f611a21f105990109491caead3471ed1534b533a959ae31ef6b273bd45e6b053b69a0e21139231f1b06e21af97216f61a45e9ee17270f023b47ac19992bd27ce013d900911159f7e73341e9b23cd2019f487fdaca426063f82e4c91eb1db5a7897137b8ac1b0d94844699e0a9034219605ce98fe818f97a1c5b6251905dcb92327215c64450ff72486c5a69ba5d58ffd405888d5a50270ffe701eb63b02075e0530d958054a89d0c3496a21ef1f788b03247ab4622564af11075b0c780045351d3728c91a162e64c17629af2667cbc22b447c63bd0d48bf1b2f70273461741e5e69a0e6275d04838c1525016b421660bc03527eeb74045ce865d59385243382126f0626f11e1e4a834fa578707690047e75a84c254a86153909ac850e7693077a3c8a55ed5709b5a861bd223f19240e081025abd63858d079449b79ba457ce0230b15ec0f267b8fae3f34b45d1e8feedc51516cb73adb4a04241bb72d0a96b50840e8a7764b704d4510782ecb40c0878b0323042e50df5c0f224d972958d37a0916e0a0f752c704bd5ebc6fed55acd2fb0d5f7e0737ff3c2d035621a3032507994d78a17c6d1b532c02769f73608cee6a44565c47019d53c55515c636095cfbe019560aa149bab44774c1e9a42a831b840a9c3b360e5c8afa4bc38bea4d95af201b141c13496958060900f5bc652fcc2061f5677a5114fa7574c5c1976f28d7016a5458882f54453a40ebb5ef376487555fdde2e33732687d0b703795580f9172007667955ee6d8540a5fe36e63242f2a6bcc752b4522042a011628ca33c9e3117146322a4147d30a6d4deee2279cbc4f73600ea9429fa6660fe3460c5ffa769e0289525812bae5c6504a6fce63f2a3316e54db56581fdc074a0aff591a3948713cedcdb415d663dc2047a1ea405eb20d6045029f2babb1c03cd1244313ab19d12b38bf88006045bf1a010a696bf870523127a00832d6be10703a21595a07e49d1088ade533387f492a258bca49fd2a116e3efe806e19722a2d5576c65843f28c529c11207308b5ad75d6219921d71aef0ef13ce9563ca5390d0405732cbcf587076be2f522d842eb516aa8406320a6651b8515c2136e8c372140034c4d9dd9983f73fd6313459b873dd8e1cc01a578e717f60db0768cfd69381290f742768f60614a5c8051a02236614e355c5249d64b190c127f693c74be550dba6f63e9122c2e740cce1d6f7c286279f57856d69909064aaa01767e6b2e4c09862e39ca3b450c6afede44e9c32f574c52a72dd6e61a6501df60241314961e940a524c232f5e1b9257146e4a37cb366fdf61151cde806e6c388030c378c510ff046a7596b58b749e614033a4d6652021654033ede1b549b8cb2f5ce2b7434798b88447bf0406074675e4519ed38d501d3e1e6bbef82e2ea919f0414a930567f4f4ff2f0f30685d5d4e7d2c7625e2252428ae531af9390bf6509d5d164bbc2ab1ac5727e1f93e6a4095f0209a0f2c0b037f641c54271e504deb380ee7538a1d583395428111304fc7ab816b89ad572835ee1e145c64530f34c8281e64a946167895971fcd6831564d847f19e5242d422ec2b643fb9155695c88391889618249f72a3821c62c380c0c4085345c10732f72656942c16df65ae63ebe5677385c398c471547ee2faf2693467d04ee0ce03e61bab36b89de3e1907b77a661c413f726f4f0d3c10a402720fbdcf290f51a97606554640af6e3b6cdc59865d218cba7477db8b316640ba33febb833565511c2befd90d21547530228c7c293651a9d313f7d5f55be2ce2f75fc4233563fa55e2ebd76b71f5c2eeb0e6c54d72df165396475a6aa2388ae873772dc275c7cbb99461d934f699e713a6689e2f077431d502482e433109ebda508c9e173215332084cf889ff055bc72b15e942c53584e05354293ce41e531fc342578a660ce65ac172b678da3c11ac1863b99db732b83d607185fcff6f3347335dc460a447bcdc526d1901170d96287d1520796338b41770297c19dc0246fca8255073cd43dc2ed4342d718c251cbab1194dcae4540177e60ca3361c6ccf45a5327f88220ca5ecd030fde1f4154677ac69eff28434b3f10208a56c593990ae282ea3aa8a361ad4e60166d3a008a18d5224d5502b33ba9a5766d1ee034467a55e3930cdf0621d19437565a22b5580e5522c1ca1bc497d1f9d5324fc7f576fb6364bb8799d36d6920c1b4645563b269d9e031e63a021f61974364d890321f805d16fc3c01b02bb779b540d1a305189481302101d314318625b2316962f54bb703d5090ca4855ac3c74300b09962bdeeb55438b17665b77ec32771f00bb4530b0e6248f023e370568fc07f9adfd141ca0f10ae1953363a04fff10277c106b72ccf06487cc47183b4b350f5bb7a2515f7b9d073e04f4581f35fc58a1d2915dda8cf81bf30d8256729a7d29b6276370b81d69466eb4465b5a4f7c0674df1f45ada773255fff9710a8e8ea6b9282173c84b4c2494551472182291e616a96c021b84469338916f7701a6b8430675a5c4773df155a2a2903260f564927c88b394cf296532adf34935c954d5866e94bd4083135c274bb27a032ca9df530652244681d7339429c73bf4f2af6ec0afd74d06ce4bcdb4a33e0b4016040473375b39b267185f54c073b61154c6ee905fcaaf45bf9f98307e1d349508a20f04700403f438bd2fa56beb7c7142b1c2a3347c5c3566bca7669e8631274dae94f114d1a243a6202ba1a4b3334225402c32105db28685816da61f69f226617639f34aed09e2622ef7304c7f9484630e72f11c5c89d5b504e060a671c4648ef055c499e6d8e548c153d1c340d3c499f4201138f5ab9092a4cc007034baa5222cc5563fb60c273b40f0b09f2c1f82979add64a8c399945fd95ef57ca5b411e2be2d07602dca33149ad2523bff2d168b3f43273af18dc538dfa76019a7d526f7f3e6b6a032fe704b5d0216a5b92e4708a01e63d17f3ac26549c3f45c621876fb50bb338816d475dac8e3201d4bf333b2b8b671975a2e416cc055e0366944648f9437516015aeb305e3b3f4ccef6a21495aa685768cbb75edb972c26d4af091f4bffc33d99df8d71d702cd25df1ac66b7092ce687b97242261306d290fd69e23c8a0fd3ef83e3c0659741715e9da2e047d0c406c15d9d83d7acf1e14315db15afc2b7b08b0ab56229401176f3410135b6865651744eaf62a6a7089384efdcc4869a6a3482fd64c700d1bdb1093afd872106286506a3e42201e750c41bfe35871bdcfe34d564a240ca12a391e75fa2f0ab8656847f0562a6ec61f5c46d708315d0d534b0f53c9c468b9010534b49c8439b9afa727eab1fb24d3f70365088e912708f896506ee05339572fbb00282365492b28433021dcbc629b28773161c6da078d72680dd9fcec293cfd6f12ca13352997e8445207ac1741b286085be790f458efc49c3f0200c85fb21b18506117f220d6060219fdcaa01688f80a2638c68b0ac710522160c9d16d869c3b6c1620c331e163ee0be85c1f342b2f75395e9e7b08eb5edf0ac2cebb41d1c4744d373592570a6f02438b472d20069618540fcab604cb6c401b839e560269b25109672c9f4b7949743ee0128042b46c134a136e55032dd5af77a498484a07f0771491ffcd4773def34a8dc5e83b94120828923fdf47b491f8752e5a3250f37c6e145055c11423db631f11260a16dad57d73c35caf59259d6f496d0d431b3eab3a151c06fd2234f64a107882516b755c6e56d5ce3a65b3f2f819f09cc42447f5486afc17223ee4df9b0e6939c71d15763835552c5755c2a8df6473bce905fc48ec1f9dda3710fc9afa4282188c3966538e6081d2ba38ac676a182b2e5a2dfff9730f2db0f773d6c2d33e7cc3fa344ba628252a685b00c26a343372997772bddb8312e5014d43a46de34188538a15a2af647286ca612bce43452993e2c112ae98d4000983b20e08b528629cd72f0780b3344c3c1fa300bbaffe5bebc2a227433a026209fa42601404873a6870c53d62e42c192f80d63738823a05ea3fa8644effe3056578f20f6237992ffc52e3498f60e3756bb6510ee4be8a6aeb66502290c6862bb73b344dc4b5ab63d39b635a44391c305f0a3144ff649a6e8ecb913424cf4f285b0b404cbc65a72892b1463d3f6d6d5fa4cc3f548cd196380f1e146e4e7b346865e45004971f19501369543a6c839715fd58de68bf84b2659daae55fffd7f7157af805775bd2ed41a982ca1b8b6b2e34abdab6760d5d42533162f52beff4054d5431883d01eab70e27f33420b3411868d9a38a604c9468161b9f69664b1de7521db6e254c359541e4f860d345405b5298f77a82c323ab95bb6303b413dc63b4782874038ff088271a27612593f7bf95fce93867354da78602422ee1c573ab20556a6e60a3b788b7362aece40f726e1183f02f021b7e4386a8c6fe52cbc0c775c00d43809ff3598715b9ce0018a3d210cabc50d4f7b8c7330f6987943a9ca4b3ca345b76481c1e06f662a1d756c99040da0cf2f72aaefd246dae1f04f8114e04966f31f77165a4d16b35d2d3fb934b66ddaeb4d4e6aa86a4e471756582353ed5d085e486bc8af1762a1de4f685c60b7371e6bad2a7e52ce464359db03f2cf3a30c0b10d4fb92951194d8c64221163e7596bbc16136290964d321263501445c3550dfc4960314c17365fc5294e8c0f9e6923467032953907470d07bf57a0dd80464568e949c43914705ca0c12e8592d906c1c0e70170c75e481039a448c6abb90a282b173871d3a23cc7cc5116c420cc2963b9473d0f350668260ad96397fcd706e075064ad8ee5a1b1c6db733108c2c607bcfa876cd83a63a3bb2134b7f6b753a86055e0bb2afd31b75367616a0b4b561cc75ae09c03ce110aaca857221caaf31403fc24311926042975f622dfef82b5676936f37e2130f65982dd734fa9a2f2e8effae4e68e0442df44c360368fb451e418dd3208cb4513d86b3c22bea69c05b3414814ff718373b6f4025192691ea311501d46c7235192e03a1903540bc8677049efe2b04c5f12b787967380fe0382e7c20ce0890972126a6d45847b8848934a0b0675f9fc3d858e4a17b3e018bc8383c40446cb626542ed46fab67e25e696aaec786238eb2764cce6fa933d698573858b94c57168d2b6ce0872b23fe80492bc30f906323cf8f5b0f9ffd202d77f00b2862744833adb40c914cb5530b78ab61540f0d72e8b6e760c29b5b018db0855ce20fbf6d88eaf21d70f884226c966c70c5ab4e060227014889b3d41f96a081367b74025dc2103b0d8e93853f5a40520748c14504034dfc522b10a95dfcda3e44e20da46ef7932169cc453a4c7456161d3685ea22b7ec7d5734e2aa493803c138afe08137ef45cc135798481cf593bd3a2d36fe5c836d8414dee3fc761cf6da2229964f122ce3551550abba6c6059392837b3de1b79e9a36f1bcfe21310d9280ced9ed85d8ab61b318ec80f4449e6d7330e5cd709079a9763c44a01519e626e349b87445885629a462b2baf2a92b0d711005663405acaf70572856b1634df844e0ddbba766fd844029b20b441fff71d465e068447c29c742bc878a077a6d13c2898d79143b7b48b45b6280f553447f83182045f35855e8f68552fb876de995c51a893976c60592227a39481258199153d6278003cecb9f85461397269cf10ef3ef437753768882a5ef2ce1e266872b605dfe296779ee9632134c8b6121a159803eb9564507358921b4e69426e512ad104c3006a28998aec39155e5c118db5ea6965409c20776d764f0f6da553fda54e63c332e95e4d938f4993d3a32727310059459b4453df651c53d1be4c052f21d53f0531911604795f59557e1f5da0ce1c607e0ef15e90a04c2c7c02d04813ba605ee84d91271c0617754910530f0abee45e35317853d77b5019c1f4e02b3bc5ba6d0abcd6508d3ecd4c9a0181615674e752bb214472996ead2335a81b191d45330c4297fd33f5c7e6209afc3715f8cfd34cd907976444d3db26ffafbb255706a123ad841f6081c4ee34719f0e761725550433bfce2de7f0824f0d1b760c89bac974f1d33236f9c6ed0a268db06a5f6d5d3327fd9e72b854385f54551708f201f62db77a9a017ddd041a83d8bb6c711f940da0cf2f0e8c4f4d1105092922027d6423ef9cc801d3a56f29363143555f0a625355d197701a2d3e74158ec3440e6a6e53c6a3e932a6978f4992c566262551dd15f446304575767513f3a4b24619705d5a987fa945b54b763c2fab306655483932839c065f67946d3d847d2f2dde3b914acadd9439ff85ff24a95a82132f0cd17442c4a30b5a27af256c95192c61410766ea847c57cdc26f6693b9f024a62edf47e51f8352cfc6853593a9ca6a5ca7c50f4aa8a04302e841416fce43759491fc663bd84a589c9f2a1f98680d2c6f71e63bcf90c406ea148c27643d2d6c4e25c90891b88104c2488160aa9ddc3aab52ac1b1462eb01d2a77807f3e15b4b53363e6dcf700f6dafc4771843198b1766216934baee1c2bb2b4e30ea5d7e5454fa90c3e4cd3075022b1c304c01871119cf0173f4aa1f2278d5aa22027c71a49bb50d113b84d2b36dfb04b5c2b0f7d2f8bed914870e67708a4635d2453e98f528bae89427bc2c0186ef0ba14cdb48e3d508643391cdfa43691616d4b54cbd1627dd3dc2b7e239e32218ed14224142a7235d4783651beff089313294cb0b24016fbf2682aed16af1f86c076056338433879dc5375e1746f276c286653a5f7542196b6cc3f5909046106587e65fcdd945f67540f76ee03a718cf63044d7e491c492af5bb0cf8b2f53766bce11b60b02b2ef9897d72296dd06bfe7e505bd23bd909cb30b61a25855916bf9f610c604a4c3ba0661942eeb09827a263e66c35e6484fbe62c3455b531825021ecf64301eb83b3863695c8c8eb42d386f9b065cdca40dc8c87b2e1a271f4fbd328e20992a81018d6d1c2dfffb0f3ccd8f2755c2f6ef65d9eb5322b49fe76b4cce1161055ae70eabff2f1e8c227d4ef30b6258d3a1294a133af36d31c9ae0b7851d74bdbecec2394e53434fce83255bdda0117af50414cee744045b6d1185ef3adfa0cbb87324ab9693d1edc579f6dc3498e2cf0c05a3cf2fccd3e9205da3b41f5ef406d5c7f0245f5724ea80856406590d51db0eb2f12bb491032e6de343f421b3022f39d7f552a68fb5320a4904e28e0a2128cfb603eaeb368284020254e8d8f305d2d86803e1fd5c7246044993024b00b3e0f995053bd8d0e24ae8b836f254aef49205d817031c61c5322114170b2b87a386a9e166d37145e562c8e2162fd5ea035f34f6a065ae6040f40bc1e1d1ea04c564ce0bf195032624df52a810ccf24152ea61d5c1d0584b9660ca64b0ba8f14a59d64bf85b1454b05ceb32194784f21d605be2aa3275ac8212725cf637564ee26fef8da53bdf67bd146d2a1070acd7d5482582922565a4e1622f25e85f3fd876145a5a9e2594fe2c36373c7334c6cbe8064d40d72f7310f314a637e242951fcb4b2f7a8a2eb6683d1fb499796c245f2a2a123dca01fe74a824ccb393361dfd2a3df1f7890284f6a643cd0fff57ba15d31ee1d6c30f96260d3189dc82341774441dc2ffd6617b4d1131dad723379d0a0974f7b4563c938a330865d83b1027c4fd11b0e6625a36518327bd957d11c69fc92cd54a716e1a75de5d470ea215a3a6e75fdb7e19047a7ea2339c5b556292a9f4071954f66d52c7c63c2c14c34b2e08180bd48b62630fc04d3e3332172a6f1f8b22c7370b040d73bb6cc5f6ba1d6b86596b724dbb49761af073cfeda369ee08230fafaf243b71484f28cfcfc5255117692fa6d2f321dc3aab3bd83df624aecd2f2175da741f57152547d5a02315fdf80d378efd0d6af68a7b66b9c0c26705c42450843d5720ac56d139fa926573d9b9266d79177c5bf52691283c8a667279aab532453ef309a277e7511348e441a92288567b626c61f0651b38ece05d59a0eec71c29dcc024dfcd485ee357e44a94ac421a143721601520711e9d66c85aef2f43132aaff2139f6cb252ac76d53805ef966fca5c624496cd6d40439a105e4e48ba12d695c4260e469801373d7d45da0d6038828b441ba8b022479bc9d949c37dbb514a25586a6da410554dd006425a4cb172ae77266969ec864f6393ad6ca305ec07070e44034e87f508167b7651368160466a24dc2e70abc414ef264060bdbb0034526fcc3569c2b83277c6ee6dd981dc3a3275d6588be7d364f13a056167bca44c978e351579b87469f8167e62f6134b24360b0b220af9c7024b72081a7d42922a9e7021119a5e0b1182cba7566b4f717069aee7409ed03061fb54a12232066d35ead9a1332f08701631e07043fdcfbd0b388254173686241f11cec266086d081f8e7cd214df21964367f56d6a2ccc85274dc74108fa183514cd56cb62e64049477a2df270c884512a293cf34c6cfd9240244ede692164e721fd5fca69e1391801e18b2f5575f7204cd43f785032dc1e0b4d84a30d6d38cb38962a4869517378572a354862600bbd5d2c760f197e424d62c5eb9f123609a3081fec5a63a33a3040ce11ef6e067fbe0cdd2a0076a3279767ebf4054bfcb7ba658c6bda5b72d562711e7aa82ebe4f352277443c085583706ecebcce3617d10a0c679ad4229ff7326981195c6f32479975bdc8ac73fb9bc4148436fb36e955de769d78f608c54ed20c9e601c2f1ad9d045c5d26c151308591f73b5d7104bb6660eea0d9602f8261570498a5f617315c31820a9ea6926e479304385ba6dba8d13564081ae3bd3e6cb6ebe8075742b604c6fcb6e7e553978984b578a6c67c6000c662baa4f23aabb6c09e4d5573411e2204893b8b539fdb06d444d22dd624944744354cc4813b9b3db16b7fe104e0c6ddc772938c92370f9e270d6cb870369cc140ff43bab2aec210805ca5c274993bfd41dc3333359ea30cf05195e4e36a5def415bef12f456eb1403dd9e3591205fde75d5bbf2265ef5d684bcc71fb5a10cedf3453f008182f19ff58819f934824e2ed2cb5e0c328a0c1366375440a2d7e66005d77940a07106a58278812a15c1ee4084a6d75b15e3d3c470d10df2e65860bbd23576e33656dbaa966595aad58d51b400dcdc00f058e67d0405d0306309aa4302f5b70ce49f08ffc5b00e93d082e115550e81cdc435e27da0bbf0b9c175f8cce035d51c84108d4d94f2bf7fe3ff533bc75957a136910adc07521fff147ffe5460a758f9573e1021029d36c3e484c092712d10b4374902b74427a8f466115b3b03d9065714a43fe653a4988185a59496b748199c47449c090445b956669c53b1c327f56fb19408db33b0cebd82df2d101115383b0003689ed33f6ce3a09892da77308161958f4ec3915d5cf6c029894ab030b69a337ecd63d5dacab48465caa56122bb92d588c188672416d1e3d486d6232c321ee2114391a0e333dad5be3e632731116df178137dc465f3e6577781dad74cf3dab2a92423a0e6ce5716b2720df5dfa9efb0248ba7d4b84f7060bf21c4d3ccc056e005c9e9e205059e61ee1cf742f67310e4a6404df767492c03587fb6e2ac541ee435dc1b02f724f350fbf0d0c5362a0995cc36bfc20ada6e33ca4586650145d1a778eb400768de8c6355480d842bfd39f01e49fa443f76a35742ecf58322573436d1c8bb22fb0f3ed127fbd5c29ae6c726d7d88d20eca6fbb09e7c6a13fb07e011b81373920b4acca72bd275b771dd258757e898c459d05fe573608684f03ee7a05cd12311adf9f082d67bfae30f574b93785816c02c140a2062bffb44cdd2d44285abc960915cd2a5fa0f9d84aef807c4c4fad9a07ce82e60d165a9b2396c5b85312fcc56cd69bd14eafebbb2c9f4a640a28087d76a7dfbd10bca04c6b03c65a3c2dfa3c5d107a0c2da3ecc209bf46c01e41ddec1023ed79631b3b4c5d47c5fb4fd47d39449d7d355278785422d771410bb02fb81b6745754c7ddcee4138cf7f051489a65d801606233d97d948ddff88120b5f8d5a9552c748dfc5c57191a7e71e5e0e162243deb84ce2872a0f6589851266c78c6b554f3039c3b8d42643f3ff4cb0e2d7334d43da5e3cdbfc13463dc861ab0d1d007a8dc3749e19b128a7109738ef8a3941012f473895685062f646a5412968017157eecc317394a109f7a1be3c09bfa33151904e11e0ade9020f6471772382c247d6f1e77535b0ba1a3f87e64891658611a9f6dd6e3f49004f567b3c6ea0a31711c90caf0e4a78210b2157ed33c467ea09fdce75664bd93a412cf56c19681c26018473a5053b4b623ba508c54711d9b9624adc98555de4f915f08f6914addcd0366ad06f2d0532fe53ebc6a1051366e160fcc13d6c9577bc3c7475ad5eca473202c491b535967ddf0a37d00c56488c6f2aba009b188187061c151725628ec0735b2e20d817b5cc654db0644b5e0848005d0c1700083a3f7f52ffa19d094e79f35a33b49422f87739556f31d81b37647b45cb968b41fcbf693e97c7074bcc2bdb17718a963d4e16a6608e4edd5a5bd18c46fbde733c93ccc94bf6b1b8324d3ca06e2f1f734e8e19d551a87b8025e83e64350194c414e3e7640a50cb6138875a94349e53b93a16621a02e1163f6f73544a21738ce2354112cc58b9c2654ac1d8c313baab645f234b4a70589d413e9b7d3c2580ff4f27f81b821441e8ed2f5e21f561a5f8c8199b6f063d23c0106278f5241f0a88d70a587e5a3a076eb353eaa2d64eff9ddf11bb0dde61959f4922c308dc6a4d236676cc9a66585c74190a5a49e53050e05f354da3ee493fd0f86ba78ec234a5b36e38f2eb1e376fd353386040a7595c4e730f7fccd05e957d18711aff053ccc49913a017f1e077eccec62a9ad5f0e59996a7475ca6743965e84139d4c950415819577dc975552ec8381472db3ad58b891e54fb7bcd650f0ead163ff81cc34da8beb3125214d70dcf4785dd514b80437ba6e20aaaee646bdb0c1614b2805500f28aa49dffa30284193da03d85ff95ea0373003bbb20e1a2f928b6530b3903b32f42c3f9745fe388620273b6ec40d052cb69014404e451ecbb5e443d53ad91705b8ce411edc194af1de3054786a091ed13f6b272572b75144e9d60a315a8c3a8a3a6760cc0fe20ea08d651f2b1287025df4741e3b35911d6dcb42263e64e122e85d72195cee5e00e13b290d515e350e31d45331dd72885863face614e090c287190051b613e9a18ffc18d3caa8f8902189a8751d8c1da13aaa82a0a59a97a58b1188f3778e50468c31110134188e22e374a3f4c1f770f28a0e89469ca95bb50b2d1a93e75e0456e9c31fb6f2944db294adb4c1871feaa2cd4b3fc396fc55d1b58118113bf01c0005c3a241b554c944e98305b2cabef824dc8b26e3508e90c3f927d556f60812e71ce870222c4092a303246925ac9827e04483f7a31abbb4a67f82eba632591c572c39ed547404846633bfd6e6a88b6395d9c052f3b714da81bff391f0c6bb42e1a68830959658b4f371551186c1c7fea306acecf3bbed7ad3ce87f5c75ee99503b3e081120efcad173cff8ae3d51b10c6e200bf75c762cb1204643b012198a615b6381c7591702ff4cfe80ae03e21e4a3bc1203a28070f8c1f535a851805f72d05d3719e1c96200c2b29ef4a7215a12c16fcb8966ab079c76ff888d3369b2e09309874b413fc58c61c374bf64c6964665b3ae67d6a7a83574c5c61a4659b745807d34ed8656e31480e59584c376ab72565e514ac4464d567132c3e7b1e12293d1e2b653f1da0f54a20a2652b2626d31f09f5b4093f6393a2481101087523db4038b4aa4034f08ceb40d583e2451dabda11333c3d1929ab663d90d0b74fe1ac2303cc671f04e2183c4196796f276eac5d060b29f85310e3b34acdbc93393982cb42ea729e493a4b5147d9a5f75487f5080611d22e4f7da92f052f4ba972a8c3c54b86865f1367548a50c63b6d4290f20760837fa36acddfca276f85201dbe03b718ad23881044df440df5307a573bd269502c3e600ff5860059a4a94d5cea671a20f101c452b6432801dcad454633b7972332bfee069c49ce7534db0a24ee2a604107b07c61198c57526d307f1bdbb83d3c3b92d92e3cf5c6345b6f7813e88a016fa422314d5f50f06f679fce27585daa53aa64c46f19485b6c4dcf271f7ec5f43dd305501c8d35a63d1d17d554f0ad37425ee75d509389113d0231a03dd1667d2ab5c5e5329d7e4714c7363f20bb814d12dff74658e988705738b36b3dea212c57b80dd168d851b730974d581764a10d38da367c1639f6234057d600395ef13d028a99ec23689bfd1238400925299f636f234399445483ce4b9f3f2423d743245c675d733a86848c6fd43660759e5435629d1f7b6191a52e6ffea71c3f921ea4190cd67b3d6023b973ca6eab2f86c0a15e490d2b54a80022212d67c32b326c93152c389072cfe9bc1347be0d076b06c24e1f3ab13581523925ddd6874ccf22fc1abd5a7f00247e7625a0ea5716dd92a206e9aaf5658931b05bc5e35409ff25043a5389764b5369cb5afd9be1691182df31f1d388185b4fea37eb2ff210d83db50c299d5b0065c0fc5dfb6c216e4ffa4c29e9171327b568f20eef75ec345183d054c641dd3ae83bf203278e604ce4cdb962d64ed220635d480ab579d53582071f47804fc5326baebb6967da1c0697aa5562d6c51352dcb826174518b544fae0b9492c95c93fc42f60276f8e6f50419efe72824e5a47fa662259cf080d779ba2cf5cb0dd240c7b6b9d3532945c596328cd72927bd6337a03d66533ffb66caec36a54a6aacf3dfe364e1ae660813dfb3f9f5ed2d731561f9e424fcb494a10f8f20a1b0a8f06244b63c8369460190fe27aa3727ec87d2c58b5962e66ec6a3daac1452419847652a13f0154a263e36ca12e2f18903fd0629298ae0432cdde0195b7744ff15df3482c3ee850954fd134414b953e946a7b645c35522c8da90e2eed128073b1e47b4cf58ae7721b2591348f7d9a1df5e393286bce63493c3af56fd8f5df1d81635f616635fe0457657515ef7aee5efa4b837272d18775dcb5060902d8c149b8d8091f3e8e734b95f94b63d336234b1db6a240a268891448e7a65c4c2e107736225f22491d9f03f785ce34c01ef752316f8a0d803541272cd6776addcfce42b7b461314d63ed191f18244135dd9d13832f49185bb78f262e23f16b89007a77588b4047c231f22f8e34083c32a4496641a2882e7fe02b3ff7416069a5a91b50da3dfc7128874d04ed64d35e1d931f1265a0d8287d2040481c44bd707c4ecd201c756e2ee679e420b74be46a261e2518ccc9f462d834cd4a932d7b1e49331c5fb846ae76927b8b39135ca474651871596bfb320636f4eb4bd467b66c74116926deed50530964854c66089b457b3f0e07436f9d0627b1b03803f96a150c33033659a6502032a54030041be1505db7b83b6242094e1df0fa0a777301762da5d724272a7d22e7ce5f09afa4c236b93c6542a2d95616c968ca16580613079a95d85ab5f82a211815ad21b727be4178cbfc2c7e49ed0b67d8a527c03be72c40a6781dc8f44065ee4cf22a79169c530878f66ec523cf0f03cb4417a9385c316efe5b39aa55ed4e81982e5d01a81874669d8a200662e54e8fb7500a790c9f6373c07e15a64a9f0f70032b6ff86e98319827585d684f6a0bc612591573fcbe515262ae6d5a91bc50c16b4a4c5921cb5e9d9b7d54b12fd72ba14ccf31a03e6a2d04aaa53711ab782ec3d5ec6a9751b156b6820754c222dc66d4ef8e02c03dfb4c7b8c11615c8a3c58de67e9083c0e9b70a2395f36f015e647ea715e39349c2a591248f7415e94b62baeafce411ba8df278e8126410931592906e91916773d844bc9dec70ebdeb5d030e2df064ebc9374d217efd65656e18111845574363dcb667b97d54046fcac12241899952f6577d52d3c1d001d650083ab8560d27f1b4ae3d09ff860fdae6f840751fb51ae778165d640177041712bf6306aced3feb0cdf5db0cffb1855b6d715f0b1245278238b3a44213d1629cfb53642c0a35a6046243518e65d2191729d370a7ea77050d6d634fa59bd449a7ffc6955cbfc160ed1fc44b4069c2e682f445b0aadb3408169ad433e83be229b1ec11daa8438451c8365750111e034c5d4386f86d69d016718994adf674519e3b7775a6339da6cf712c74232f9076d5eff930dedabee75057a764f27ccca1fa900fb23b1aa46646847164bade56306db69ff1206a4983dd93ca912d8b0e9592e8c2168771e5f0e9c23d5368e428102b7d89569c7af7b05f920c468ce328b23e28a4651d254d124b44a6c6c42aa27561a99d261899c3226ae431960ac4cb23baf29c01e95db4157c238a7529ef9c20a7b2945650666db614de5061267716d186c827f09d6a9d86ab076295736ac1d63b513dc6608e8792d4b94bf34d4dd084f19e7ad50edc63a1354b159719b3ab53bad310967969a0e196c69a2581d16e12192199908ec73c234007e974b53358e1edf14293092869c76907b2a220a6c392b52047651885bf962e8f4931b1baa76271cbd2f2ad0dd994ed58a8e1ad2a1e63fb8841e56637fd9343e5cd607e30a725497e3933898d1ac6501a6a7143f3da02087455c08fffd3274576e113480d1482b03afb172e2e3110fa4961973fcac3840f298d728d65cfc078e3c7e5cee1ce90910f5837317c09c3b697142534b37bf427ca0065bf1c1006c8cda2a5cf930e246b934d32cad801d77651f4b15ce0a9f30bd54e401ad743d5b694e03686ab4580708cb3211e616060dd52eed602812bf3b25802d0d2de66e63af667a5357432d2c37ab1906466c675d679b4c1af325592c52a8e754f8aa33119c680f5f9ebd794b57a6d001a128ec1cc622dd088ad17b55a2a0d5677770ae55aede1477f1dd264623f2d0470f6d921f340a03231ec067766f141334f2081f34ddb6f531e5e2bc705413e5776dd6b41107cbce3e7f50a764acdbc633cfdd0b0427f4e1760e623f69ec11e8635e9041628f5335357b3ec01d8ac8d75be9f6176a37d135424c431a7237008d3b90c32832007ddd16e912c24489c9ab327a8283323ad1d662644a93159903a75a8322782a41a90b686fc78631cc2c8e27e492344fce37f908888b472606370216ce8fc55d3ebf79244561164147ff02757026493b0726e617e0391f2a461316573d53074b3ebed01318d10148de43ab52be894e6d30378474e237a0745755724fae501e007a7b822e8778fb3d80334b21cee65d43f3e3c80def0c54020dfe0d102c657252201470689d1b3925153cbe5080964970512a5e4909e97157ceedf26bad14ed44fed1b2511d6a4d54bc5f5d13e65cff50cfb7e246d006f955406320125e156444d2dc6362612b784f9559a301ed6d523ad9299d388bb0471891a1875f1880204d131d6f3e926b283478c98f58c72bb626f5101e15b4b03c0799add6195c5830480b3f8316ee9fda1273d252510e828120978a7d03c783d6393adfad6babebab5228eefd3ef88726279843ee190923fb03bb16bb2a843a2c66bfd5bd5f09509138a14bd92ff982c2149f9a704e31c54d5611d45571b0019c01b38fc738a6ea8a5b3de48854dc2dc80702c475405508ba1f1366062396849855261ad41a9649940d2477be2e60a763758788a3253eac133d5ceff9691b7ffb0f462f444e8f2e555dc4d51646e08ca7397b1b222267e7d7320ce77f4fb301b543c15fc2619991773ede996d3b634fd70fe5472a548dca1e191d4b483ea407432fa68bd42abe692b502b840f033346851647447e40ca505d2d378d820ede3fd8585fe0cc478ffedf117dddbb47b85eef2ee7d1162837e73520afb7116180bc56778e485b77f5a5080fbb35e764f4ce8313d3bca6048ba4c15964beee3e7bbfbc3158e47e2757283573d6796a06f20c8b683493cb44ab2cc5667808d96abb35b80515f9eb74ea6c8522262d3b46db3f895d0434cd1da3c92d44e103ba29cdc3bb497bc68503ec51c811bb19b423ca711220b8b84c1015f5e05fce54e64cff6d623dace7761d7108810dcaccf272a7b869095f2f2f12cf093324cb37c61801dc6e428abab156a607b217573d8927396b19207189e929e381cb1bf5b29320307d954e97ad434a48a69c10f36c901a4587681b80ec18485887a8018de03527160cc245f7fb066e5d382142f545c312d6fa0752256bc564747d466ba080303ca79c4073422b34306f52883bd57d376d0585090748a7574655153614e53f7615f8003f199f929f365c5bb70720bd160fc10504338bf4a0183fcb2e07cdcf29699cf97f6dcece4335ee5c272eb23d0c147a848414263c150b267d6d66cc8a2f31349fb32a6603193e0edb4022bd9482078b7fe043634a4310ea2f066c8f78320bb722cf5cb2bc0e5058e8584e168d9f4be567fc680a889a1dcfd5a76cb2cd0e1995e07a4d4ff2d005208a9c4b898828008999583b79ded42a003f671a587e755451f1e02788f2111d77109959a684d733ebe6ea702bcc7d454dfb582082da905cd2f05f742841e16ced3abd4f8c1a784f48d449327737ce0c3e0b7b277d018f41efd2d70498f5ae6f0df5bd72835fa637a8456561e430ef207f4cbb39f0295c463f19941f4747922fc536eb098e81e33b3187cf61bc457c36bbee5d2a3e53e11c11d65d6b3f56a7459783584f8de45c423c4c3e00e46d4213935e6124901b8b1577a23d67cbfebe6ca209ba155f46c81b6cc5ea265073575a35178627c44fe241d161ca0550a7f04f3fb4b45740310853e151ed6e6d94c134fa631467a368551e67cef240936e813bed95276e5e8e390169a83d3875f0f9623aa8151b9955ea6163841c3fb0c60144756a8972fb2c8f55a9312d02fe02041bfaf31f5f0313052ea769091424aa6b52c47eef20860b0a15d24d3e73b89ac30dc40cdc65fe4f1509150fee666d1f9b4bd0673a4c9600001566737b48eaa4505851e4f95ab7583c7420f94e1dd7b0385c006f4d02d5e890664d5c5b5b78826b30acfd5359754fd468eda0a40dd3c5ad7654eedc3f63e10b7199ed670b991efb096f4d9b085ae8a6077ed82515283988627be7613aa8d4a655cab0ea3d434e741d6e4ba13fb99b8a567726c94706fb257719046b4409b6a0614438ee1417791102035d661bbd3ddb70cffdb420459df06cd690b3514caaa24609a6be1c670ada282e7ce561ff07065e2f4aa4120a42cc674b34b51a0e830e4dcfb59a292b210e6947cbdd3f358a6c43d51b5e4b0c0ee5345d98453ac4c6b62ebe51506be5df19270e71d55e1dc72d24cf6a3555322d024c7eb8232102c87d47851111452b26104b2e88785a7139092e766b034a67b00b4f91e876603ecf55581b1ff841adb2b60b47e80f38da18526df299b4305f9ae06f86093d365835a51d125f58003dcf8f47d9b8ac19fb9b7a61c0b2010492f56c3b3cc2d07504fab35cde545e16d466ce37656bed3b2289764bc345602da27fac500d82f82b6186841f05e0232cd9eb000374f2fe5607b0b904284fd90e4d5abd223169ef17b9439c1ab702ed01d2b33202e025ef142287617198f86933b645031b3f0c413022c62017dc7b0b6d15e279127f71714349ee690430e9ab2614231d0a0cbbb756435b47451923186ae3789948ddbf6448ed6b412cc8d2db50ce8803125704504777fd6b53b62f0048ba2b36260749514e2deaf21223b6ba4004a4d540f9aaab4d8b7216026eb41812ffb3b54155ebcd3b3721ba4cb4ffa5648886d652f27c152ad1191c4600432e23f6c0af5b23cabd398c60b561490523640c6e191d98330c119e24ad22842ede0a5319090ef72c2d3b737ae7148b151a5a4fa1d06b1f3e9a5cbb55837106661529b425355bb5fabf70174c091583ad6955f33dde5a9a6ade25f883eb206703ff41ed2c0a378116d25b6babea2eb0a44b3c2d763f00e8721071172c2804d42ed10bee45706e1818d65fa5560816f806b26708c8ea353263b35f2498d671322a613bc75af83514f2756a6aaa200e851ae303c05f8d464ffd906ea7374a410c38813ac87d9072f4627c0e7e22ae2fe3322a5d8a40af37ef088833588e4201f4e4b55b217442186a964e2b0a02113b12e17e180d816b64c3a72a4f50d31401f9d5226d647b9c0f3c0bce649c9350531dbbe05df2172712bb23fb4d5028de26222a1671f5591f5a7b07b50496c3a23799aad537d6e310137b654a2cb2a9b04616368d10db1e6a00ad539710304278461a2543415c8e106ee93c491269f19816ead6044a70e3ee31e5fcc1637b1b540ea123b05c94b16a74eb024855cb71bf2302faac2b44ea0335aaa6093185809a417684fd6e753dcd62e5908823ea967823c351492005dcba54c30d5565f13839687a8f97772292fe730a4c1d00236e9e689d9cd4357832351d1c1c1254db7b003749d6314ddb36c51903144d35a2194b5b346dd02a706c200fb46fd3345f7e4723a3c260106f8c9917c528af2e58388013dd6d42128f85762f6329a50c5b50ca48f70e9b4673514e6cf5c83f5d14234869c88b394cf296532adf34935c954d5866e94bd4083135c274bb27a032ca9df5308b68b354737aad40e77b5a58ef9d426307967c2e0269750ab036915e8f73be0658f3560e96e73a1375bde83f6a101c146894b659e421ad0b33e88b573e8274367ad2931ea1b539419189ed3425a144006185bd33055ce3107ba3c328fbdfc90035fd641c3fa7c813328eff1e0e29c72d66323c1f1f607871385d3c3a489ce5762fc1174f63004a05a8482e396205f35c3884435db21940370bd367461b0df62c96af853e3c53294e7ea55b68cfcf5272eb053c6b3593481aa2abb93ba6a0c210548dcd6c0f171e5814801931e6041303c7ce2d093fa0fb36d6ffe124c822462ce462913f4d63a851558ba0305662d514c82cce385787810e06a65753b8f50b4cc54bc619f08de301d5c520428d20cf525f65a85c305a75700c448b6515ffe32ce3f19d2f7f02ed67c3aefc725ae27d2b7b238822f169033a6ca8cb1869deab347ae42f37dc66ce19ebb828487599ff18a3cc5c668e21f840e4917149d321de608b1d510ca8d26a246e7ec8086e5f4525cadf0831c93a074689ab051d00c3eb227b7fb2726b7d7d6ea32243063e8c490b6acce111b8c37615f2677b6c5e4fcf32d9660d44f017286ed756bd153b98ae06144fc5312be5ae5900992822c07058622fd64c700d1bdb1093afd872106286506a3e42201e750c41bfe35871bdcfe34d31bf7129c0780b55b42df55e008b234ce2b7573c0ede3a0eb8e8280a4002a633b0431f096a060e46e32f4b412a930a74e0f9a5452ee2335c0171cb70e5fcd2036b532a4143b4060f1d738015b8e8276f9e327b3b1201f15907ffff6606092409be0fb0290db2b12c724ea954496e936419a2da1cd5253536b0769313d09ae66199e18e3bb7b5f00238f11837e0d642753f7265665fc412280556ba4d7a45792776ab7b58611ea021d7d11b52da0b1a58f941e23fbf0a82601a23e735efea335d9a00e54d5d61c94b810bc24b39c58c61e08f1c0da7ed5c3ce58ffa60a7aea03ae311390c139b482c1d44ce55a205604bf2425e2555de353f0b22ee605e3f951ceafa7630f5257e3e44f4662c3509a93e74f0ab689e829b73a1f2ca6a5e878956c93ec275cd6fe1296fb9355bb1110f3c6affeb09fc5673197f3a62610fcf5163ab5c324ae8d54b035854f41fcdb9260bd4949625af9e1749579adb539bb7a94f1061d338b992d6283d64f76b0f2191093343531504df8a5e5546bd744d943f3bb6cc7508caf9f3710f9ec70b74a0a8068b35a7155e8f73542269c21a0f0e27389da16d15d286046f4f345f0505b62d5c0c03a257b423b804e9e4493238445c0633801b095ff1da4a05907a52db6e786c4aaabc4e796a9a165d70f765733ea412eb148e0f66939546b50e75731409ef026a2ae520b162e45be9e39b4e0fb2b2767156ab7078873d63ea11b40ad6ba5e2fb08dfa46dd31f23e4dbaab3a1471723033afa0269685e147c275926a6acca932899b871c4774ff620073b65c8cff035a5435171642fb74123f1f5c0f2221293b4cc7a51f4189c9518a72012041b7f64cb2819a1d67603546d8c01a3cd45cde2bb31113254eb8b90a072ba16468c9f8024640a73922da371b92829d467d9b1a40c2a960540700cb5881f19c2e47f3136177e3337370b88a0a932a5c2e58f5d85dba85fc319180893ad7ceb63e9413954b6e077566e4d8fc1d2cb09e4af13b3a52a9c4673382dbb23b1cfa9f14cf23bb62ff8a9a4e7355a84f51de4d15dcaec677c528736982de6e748bb792674bb0b46522148e20c1a25638027ebb5a4955f5771888741c83961212e7a97872b3ce7a6aab2c081363bffe696194356b89c6885905d3a2345a86e7554e088674bef293096b514526521e8407865b012461360a54e4a7b744c6c2ae596cd51e198490df7759572816b515964795c9f14b9494fe122582f405db690b3394f4de3d3d644513922ffc0c60e99148ad0f3417a0d179424a53b9174d09534d25ff56751260ef450f0919676321c851e060017672d8ce2343ac4350800ed96bbc34f83fdbbcc1420abd2a44b242ee72d7c8d32f026b651408a459465705ad4f4dd51d26c08abb5f5b33202368ce806b800a87659e1c8f4da3e3595dffab09476dfa4d14f234903caf801d1051408c524fc6dd31428105523ec4996a70baf209c7cfa227cb4d591401c4896c2dd64e489e77df3cedf6df520c8e2d47e1f6e65e7000135b738d68732ac82f5d2ba17351cdbc39111ba1f948c1c5cb04d9bed24181c92837b4a7792eb925a4229340d00c8674740b195a2273a13a6942b616fa00416fac37f7c9d4626ecbdb4dcb41053f069e5719ab6ab05da0b0e1444d680f6ce1a2e36d798e4e40a552332fdb577f29e55e985e4f9bf5065c9bdc372617647372e10769dddcb376ee656865cf11780027e1084502597761abd01f258bd49b2da3ce7f2a0b27866baa4f715277bd8115f18621490326cf4de49e0267aace1f3beb5c6d1090972126a6d45847b8848934a0b0675f9fc3d858e4a17b3e018bc8383c40446c08055b4980c43d00edda926e51070b6e3cdf3c092e56ad084b7c4c2b50ee8b62f172765701c9b71bc52f5707b1960943bd9b0752811a16343bd4336b5abe2522e85ee9473c9ffc5c28e47348360fe05d30e771750dc34358bd2e8062ee1b64424f83ce6145870a300f3005149761654dfd0725287dcae40854dc344bab00ab1bdfc2725c92e98a284553f62c32fa8c424f06c76a4995ba09e0359962f2c9832605a89835f643bd1e8b9ee056938c9d5caf513a204b289c59e50b3d08fa6a5f44d89a5b0e326dc2111c684b6ec151262c645fba2690f93c6e76ea2b654b941d70816318229e154b3c924bfc2fddceb1312b5b6542aaee8270a556fa4ce3cb087524df03308b64956551a4510cbc86406994be6f0ffd630d50d539b75a3d09004fae35141973f93c38fff92f01caf2064a0a162b662c558463ac34886564d2484ad47eb46b1fcc43504ef687318480950fea6ce21ca409bd144274080a71e4d0737e5b24143d00e601360f96267afe8d2a27f2ab3b40fb1d34e8039821b8a65c4dfca333347098ba59bea0e01a7ca7da5c937ed831a82ed40f0e8a315a61f4b04c5d4492225e6d02025b35c875be37350aced3924cfd075a6167168c106546d3007358921b4e69426e512ad104c3006a28998aec39155e5c118db5ea6965409c20c0c7ed62e5d91e73d585e2266ae1591579390513f59a7e4c1ef343049899d72cc9fb674a793fe0528ea62b56829fb06bb11fde1465ae8b0884f9c2638e0fcc40d146e71ff4ec1d777ec06861890473574f35876df5ff83398635882210a0890254831346b351d62b9382353ec8cc9c06511259639b15093a922119328a8b820073068240edaebd5f01e7fc3583d1952de5254424aba1f22a83725c6d354d374286102f5bc61277412b37182b35301819bbc80742effe526c45e6fc05528c9c20e56f66451eb42c54a49de72add84df18f14f38509951121cacfd02423a64aa2497fdbb0618ec51412836e52111693165c503082db8eacc03c1437d1d70216f4c896b991274519c68e2832143aec633155b2c4712716d6f19e40425450e5b62183c697b20ccf5484dc5bb560a60379f0a0600e85b5af0ba20b8540432c9c777268c6a34353fa0434fd11fe076272ea93c607e523650e6ca5306d485181f7f536208efb75d4a0ab80656840556948f152931bee423de83cb091744110d520f4e2d9b143360a4a2484e1b7e2377b3aee7644eee8436fb05ef3540e6c66edc9553762690d86ec22c8167ff890a5abe3c01337e305049a5633212c726521f82ef4a5b8495d910ab12923f8b66200ff7edd456d38b2f2a6d04c22c2e4735034c03c32a608ae945c21140705e7fcb1dcfae662e6e8b55719820d36c2f55c40076409a483482562e7fa1885d2d89af37ace761778dcee4017b6d2f51165a4169846913370d14d663b15ce03e8dd33074fee6a75f278b7e1caf10cd050394f4687572906da5f88a4ab26eb42b6ce63e672b31f94602df7a343a749a14152c79669abb334972422a3fc8fe6831201e4f089f4c07135f8a481787b10524aeeb33657b53cb4e74e661271917ce2e3c8dac228401f93fa7366b45f9dbd12ee66baa1ca299f43a7344687239cd8b702387f904430b9050db3e9f457d01085f4e1b12514b33af48b955427060c2164151dc2e111dd78202b0d16427a7fbbb2c8ab2ea08f0e3e80dd3d8c84e1218df4cbfe482460ecfc85141e251703bc8827688566f24479f184acf0d82489d78c445661d904d60f3d9017a4aad505e2f3b58a1af6175053d00188cdc681f6d737e6d703c0e620181b30170d6fb416ed5523c8b7bfd18603ec00c195c6d5b04d90851c19ebc4bc94dad33aa320147ace70e499fadf947f0ad322f85fb8f6a51e8ad5df3783b498ff9591b0f174a53b6eb6e3060af4a279d06f33495ac2361ef90a31cd62c3c40f741654c9c98170d14e09b2c5ad6d04618a1e95041b99f493b585258b369896eae65b24ad796874be58f443fad8b2919719fd41ab7eca376e1d82043cc5cd917dc66f90a8c77c36467fc9848d6bae06a2124da024bfc3f454f05b06842224852a04ebe419c313244090c5b071203947674b75226b9a420209c8bb8623e85ba021130b2305b1bbb18d5d2f110f34f6828d676ef0284520262519908027ac24d0182ad2b0ea2152f5db932982fde711f014537f15a084ebc347717c83839f89d46b76c362d19f3095679ef883411973728dc18d016929ce360e5a5332b1669b9725742d55703993011381ae43911f5d81ba8850f0371ae6d1b63db2c2757d84d1a8659d96fe427a96f4065ea15c6a761658481956f106a2e153e24f659adfc855b115674206fa0892c4935f704eb40603c1c3db213308568392da42745b75541514be2be4f8acb044c26ca215885a283023821760fa815ba4bcbb172450df9985ea9eb8f379fb3be089651b23323020248959d9260eb99a22c05aab151f98b8705c530c15c8bc9025cb730c12933c7f61a3cd1572c055d8a23e54eb50db45d9e44d9565447dfd57e3a862813578272a767dc8e640b352a5e287ef93c63943d51251cd5044f38e76c1fd1b0224d618da7408e58c02c985ecc76d1aed26856f1d45a50f5f671fb86551d92a95b07a33f395a2ff5826d319cb946e9c6cc4dbaa60a4d1cbfcd644dba691546f0dc333cebdf26312ac712b61900031c823f69b7d7d62862def0414557cf41fef36b17ecc98502759aa06dc737745e96d5e82e192de2312216230ce926c0098242684e7e82c46fb009f6273c16c44caed6f93e2319024e81ceb126fa214052c71c6d18a37bc85497e7800a6d6ddd0b456e975e6ac34469bed920053ed8790fddad736db1804a6baecf2609dde4da0ca17f9f1785fa0f6cbe2c0f2b6877d526ff6bda1b618e55702c1bcd00f5f3b9588d9c7d51f5664107ac9d62013c3a5d33cd4a710e363ac801d3e4ba358d226e452c7f1959fa53c24e267eb448add26e3a68b3ca2252eefa14adaf075a581f60358683f63ab1330f7359682c52c5f4d749bd51316e8d9683564cc4943669cae367ceca2a7031d9f14fbbad662dcbae0f554d93853ed3497c3fd306b759ab3d8120f66eeb742691c9770a4a0f5d1c8f573f71b01a74c0ec184f18c2f04eaac4bb0fe6e20e01d768e9625ca034277c582f2b78469a008bce125a559658009be6644446ff8404af1c720dfeb32a5acb353654f3537509beb48f1322a59243937e485c79fd3a1be263070a00c22a5a56e4e2337e489d60ea746f06883c39694dba124718948c422d608e4ded52004b0b54980280245a51a06837407f0b575b340e9352c26120484d2f143cc59461062371003dd14ee0758cb1ea354ebbfd3a37d97676a29d7f0ed88636103587ff5fd1ba8b18452dce583a89d0200f92b2405128814a88c6485bd0c32058c672a2532c99fe273ada342617c98135e8876b687621d801e5eee62854b7f25da66f686a80f67277c06769529653802d982b7d305cfe7b2c69cceb4b08c0e52f59d9ae4121560c37123ec4667ff33c4a73c98c7245526f38b6563d0572ce2e0e8b2b5376569bb624017c0e766e2c5f75fc478a5a16af03767cdcac359505a90a8421ca5bb4fd8346eea9e25dc6211137c3f620640cbb8165c0fb0a528ccd425c2b1f35244555775a3968e84db1f3753e840bad0734545e58b45cdf692978fb3cbfb1ea0fdcd02e1b045b1a06abf05f751eaf0a51cf00f74389112f63e3aa1362d0fdd835492cd22c4601204d805085485f3c976a62437546ef34b05e660ae01794eac02e454a3d6d7c326e5b57ec906097b37e63dff43b288dc16e095d7d9f0c1fbf7045fbc973019306ff3dcd6eef17f1800100c0615310b9023c65f91fc70a7520cb457ffad80d1373f86514efb433f44b2d5700033957b798f21ff599f502db065e0622ae122885452a339c4af86ba1ea503635205f179be3e52658cfd63ed3005a4e53b9302b66dd5153e8df473cb285c10aac5b8846489895064f2a61776dba533d087ad148632258536d7772133377e47387919d641e2334394e00f20b3bba390668c73a1b1762c57540ff36458fa1ac60db03896addb2b21a78160a252fb7ff3f03bf7377b0e4b3443ca036073e799a5466f7cc67e76a825097dc7e36a64e95070405982c4ded67412d03312b4943da5b08daf35a1d8e7150d0aac50c330e1a7786b1785ff2a3e6382304786e90778e12a0292d088a07911e5bbcbb26b54dcd377da53f6c9e8411618d02d7138500b524b53ff0286def7a263f62c54682525f6b6b379d3877c7d744d8ae460c536f7c0a35e698160f7760777a5ae13c1e10325d58d2735f44153e18720b2c06e643e1699c832a120068557139638f44fcf14523e8b7001c4649664284da9f065c98ed638b12bd581c7b55052e6aa1036157e265f3e84145a715ce713c8c8d44adf0da77939ece1c398c64602a74e1627c898717cbc3dc02ed8f3538a6848f52ca84f9491d5d86568144fd6fe7bf1a006059f254000f0f0e8bdfd65e7b83f07434a77d590247015acf4f6636ec75e122025ce2779e43e75aa69e3c431b563a3ce9de8b65893d0457a6f0482542bb890ed25fae3d3a4e8220b849fb369bcac94b672a96035186e86e415faf24ecc31837bb29db0fb777d5507ed9b30b93d6801da21e33372672c60c222c4f52934b3c572377553850121361a5ba8311b439a626ed1e355c5d69d073d4a486384dd8891eaa87a4374d4dee710aa1823d94016a47e33b690f0f4f1a72d6ad690f115dfa2111c412690c0f463cf6669310c6127c76af872c283f2d891cd2cbc350aa485a19f3ecf116403c6e297346b24470211f3cac897d62f4daa073611edf694cae0d316a3a086179ff6428944d5318b99e4d3fd5957342a05218189aa37b4f7773fa1b87a6d315d2025554e0b21d12b03af50f44345143aa6fc92109c4265a87acf900fc611b24aa8eb55e97925e1158d6ee5783a38609bba0c27724c36437fa6f6e0d08f1c941807ef91a68dc4f62bc02072976c1926a381258137cec802477ec5c629804e25c070269142bb7f7661bea4071e3f650347bfb0828bd56e3377154a6361976ab2d55a6843b5637262b867d8b0ae2ddd41767dece5d3ed5141ea9c6a53f4ceffe32e6bdf53c40386613fa93f3288ecaef393ea3b7143d13ee0f718f7c472208e83b4c0b626a55f2d63a6915133a67462961410c63403582b214ca201345128e2b5d003ae407c64471720fae8f4c768dfd4ea9245a1804e74154b6bf9d724e41651143519654f5ed2d1e5e93ad26510b0f3b76f1244105111e2876bf9c6f2db13b1a6f4b697332309954bc16dd6208953d4ac004bb2d22502a76dc695c39d341b62fcdf1c65ba39b231eb659571bedfd3455415b0f37159caf2f4fd4790d7b2cf24e6b2a954e99985c5334dcaf04d01a8b393c8ebb67df3e7f1c85cdb6681fd5124572449d300162100b2bed63042a9274164f8e99605510870b08b4e14a7e66441b2abc3e259bfe1a7308a1bf685ee1644b888f7b17debe223473a95836e007ef08e6c023085b1194704ef6220706e9fd19ca180a71ca9ebc630505f65594f6fb7487664351e89a4b04231cf513c38a20370f2aeb6e06cbed1a499e4e3e0903012b18cc6e21a214e642f86bc74e02155e5e3f8b4777f3a3f1770db6c81d12887609b835b86927f293731fb05c2b7369f066ef433c35e9e13a26538c411866d723193a513159c992b02f93b43b6c177b840c3fa7026a42d57618f587796fb497f939777ee05274808323022dd31082c6f83cb6560310d649676fbe7131712a18131194d292227788d458f4a05d59bd613b59ac92006526f7c23448d062253821033ddb2e7a04e4055a03be593a29ab7de34c167d4c218d99f8270c0aa02f916682356098005480a18f480137e82e4213b015ad957845f3ba5e51cda2d54990e2ba188b4abb2916f3a62d16a5784446cb820b857c6200b8989d2050761c5e71263b480a4da62539378e1a30baaf34c23c914747c4d165d42e052a7695210ca5e8003cc0fcc36ceb765e2bf23d5a43e3adf8404043da1531badc75aa7a956e16dc9d071dc1f76981a5aa0c0bf56939c86596026978a91f26cccf4688ba3e096941fe041053e703b23acd0ca3278922bde1920afaac3166d299073f962a7e42d6c1381bbff4c44b978e9c44014a6b2194e0d3231a417377ceb20613305b486ca828902c40d83c6f7333c97502fa574b1056f176fb7b5546d03ab1280aca0939f987ce18a2fa6d68559eed5fc9b3fa2cb583d826b78b9e7461702253d4a1bf4d32c01607a707c569a8bd0665ced3952d7f278126fd967358953115549f97413fea4bc66410fadb761484981829019e280744e8610963fb0b8554a414b5176d3bb4864557bb90f16c04cc2b1393e17a4a6751dc3478acb1107450e525a4e49b379ef24e74f63f90335c67cf427cc66d147a572909a7d0b44f9cb7475c896d4a3cd47ab808cf6cb6063a96073841c1f90b5590be2046c695087e9d3c222bdce56b80f6a00652147c3c9e854d065d27703e34a49c4ce9b1ed2b57d93d5b6814af3273f3d61730d46d40287dcd6e955386117bc38500d52ded6fb8069774e03480100ee9c638b5135008afa6650cdc829f0e7f420d1651e81641aa4513628fddcc1a7796173f4d223a551e8b6d707b11d70c71678c06be505311e39b0a1695267146b27bb6159c1ded52e2b17406bdb12a637f35b21701670f073654c241f6101d1646b6350f644fe555006909587c68e43e30ea0b015980e076acd30d70f20a6356401e25130ef55232b43694335398b4113fd3ad6f19dfff3c18e9f33c894d17672e12f010bc91d12e4aedf661888907765425231b7ae3812ef967a549a107c2769e21611c08a1a74872bc041b7637720f2846310933605b6ab660ea33cd5953617806c6022b4e6f09e505b01c8a3ede20f81a7848b941085bb1ae474bbf847475406ec85ceb985f4e2956572ea0c55d09e6251a0b0978b64d0a77e21160760050c700185231b05220468c8e1584c2f143c96a0a21daaf5f0cbb01d55a0b16b723886b90237271765ee45f900d9fd7541ef0bfa45c9663fc72cbd8684a32899249fa9a8213f59142131684cb59a04d8b0d98adc375bd0b2b200bebf5608af54b0caa9585442de7126c35106f444154f55b49b259738d07407426de851ed04a08769c6d0854ba1b2613be8fe86fa4102b3d97f15326fb21863e78f04b2732002d5bfe27cb4e94db272e77e2de6f0850666ce06a8c62550403005bc3001d646b831a9865632102fc2e3c738ebc6df8c87e27c285400ddde5715e6f6dcf46392ed2137df9d942a414317024178f3ee7be8a31d5e895498612250f36d35a40252f1d2fc8a81f3b4c74e00a0593282f299a2a70fb22511fba59c302cdb5d8539488433d9635383d9bf5db1ff665006c11c7d12ba17d711bd8d8a911610d334ce453034edc7a4d5ea9725e4750e6f659f814ac3300c4926a3cbb5e54fca86e54052a1c29efc29305d0db585cd259f05f4a87cf0040b3e8131c262c0c0ce44e594db06f4ff4ffb075beff362c540a0930b0fe673161285a335424852540bd105fc5ea1a5844a86c199493d4581c25dc2c04963d45227751509895400751b00777741610774a854a6ab24b8b2b212ac064fa54075f20b4956447522d027be00172efa2425c0a8674485ea94569bd197c483c25835cfe3535466ea52260299a2b5ae6f22f59607ac374df3e5e6ee4f2cd35685b386ec8c508614ccc00144ab27f37cf67154dde4754086190d20f02d62500d41a5e338648fe089075ca46a13025211183f00d5d2b6020b8c4ae6dbf4e565ab3c4a323c18f9a209ecaf24485b9b60920a6d432327e1d58a0a6c909f79dcc50da3e3f49235336772984cd699e8ae14fb4f0fb431c1ff91a4d51345246368026aef9bc268b43134e9be5ef6fb7b0f835ae08f61037cf863584796b4e1128830bfda25a01bd704f20d6f57a68a8a261321e1a3248ff3e5376b779ce5f7d2896302d3d976cca596755d37aff589cf2866cc8b8c976214eb647c51ca62a6944e724b3677119f5e9c615d10627092f656a14921d8560f37d953fefdc1f19f79ca7077e66cc624a857a61ce94435dc4bd841059329f37dc1d8d3b86e55a406076d359fa8f2618e5fe1c14afa9351d003533403e713920a731796dac21fa0369be24277fcc483610d1fa6f1e2b6162dd4e40267080d747f94bcb45de5d9e03ef0a815ff98f052f85d1013986cad64810779462ad6559145bc4305bf4fd511729f94f4fe192864423e0ee61c8d8360daf1611369f3f431360bea32445bec55ee562504f752828282eed00377fb04a3a770c6b12f8a1ee4887704272cc0f240633b6e62c78b9b11ab1f8b836ad715072d90883162f4938006fceea20734eb22a16cdad5f0fc5c3641e83180e8721657324019f0eb7e40c415ce1723e237c0f77b7971d716dbf7a72ede5d3538e222b397daaab53f29c1a07c27c9306b6031176c12770026fbc7663f50f3441a15e536f71677d5f9b845137d3a43f62ac8b205c09712c58b0a87453c368e07542c6572cf8099e0e48343012a03f2414c56d3e466e9ff0379528b944be487b3e73eb5276905cb1148b6fdb60b06f730ecac5860455911712f958dd497aa2ef3d652c391ba04a674c02b5ca1111447563c58a6c76dca2fd59b5786470e8e7132bc9710646a90a4903bc4dd73cc409fc462739d206fe39610578550441b7ce342bc8c56542e59df50ffd055c6d41784700c1b8c7772beb8f16d90d316eb2f36975aada114c6b57e7514a48507207b81041fe0d560acecd02017b2f226675192957b93b2f6d736b442498338e31631f180955135f166bcbb2621a2e61075945d732e9c65c0658620e75283975622c81c3380600d71f73776c30cddcc7605582f323ebb99750ae74924493c2ea5304626b266e0f0c134ddd7f5cdde2d82f17f8384de3f2f7020ab32b524409c20e89c37072bb571041edc59b322eb20c264ecbe46e17eef542cdd8b173a341b03fd60f5b457e330156273fd75523994624721cb2729892d60034a0f10ca05728212a01e6413a96a82306a0575aa28a2a5e31745d127118f0743565cc11654b070c917d7728fe7b59432c64f305e7465b501a576d27d95d0c634d595f607441b024d0193074713d8406f337550a5c524519010368403668454e0ed76959b1b262435afb5765e8f77411b7bdef0844936b3c0557a75cc3357e4eab75f649e8580551f4eca82224d386348870ab4f845cb713bfdb5814e909c562c5db0b2a53d4354d6a35c733578d9d4ca57d0c6b878bb1454fbe054d054c4c6744a98b16c1ec9707e3038e69ebc9fb7686fcdc5c2c4dcf5a6966823508c12b2eb836ad6b16a782258e28cf772e5e000c56b07873b496823da8231a4701fd382a2e13a12b1bd1b166fa2e1a5c158bf31c08991b5f78b7ff6316efde0300781a0df24b1909c35d1049b39f703c8786ff5f0ea89c52afed5307ed28ba14c508a65b05847224b6c3072b712f8e3ab6369f26d48c442a99e1e040042edc2bdfd71e38adf0e931226b2c61b5f97160206c3f02c334a8587802002f8064fc02ce696b37b848fd5057fd7b1fb50fc6392b425520b428376e0f9a583aaee7c65930979d4f70a76033e47ba9743c4c922f88ac394e1cc7e709a88c0524fe01531a4bcea856c0edca56e21fd74b95266d34548ee763f2f3b24d5d6ad72f55183718b45978749679e31530d5893570969a267b25ee4049dfc23988cab6626d1be855df000366ff942a3abb057b025d708a14b67af5725f376a76634e3f52ecf33132bdb65a61df6b690dc0b8fe47b6ad19641f9ac66586c2d938ddaba74764d097179c939f3c04998538061ed870c264517757130b07896d6141aa3955572ad12b713cd37c602fcd9b333c71ac10ec51c454ffe4b55af8d2d2100d69382127b3571cf07acc397734441c04100508a5fbbd29721005105e99e16ded451a5da085524af7f6d93b1cb68b757065ad700698646fb7276b3ac09b8c74423ebe51dfe7aa0343d82c0b9f23d93d94e4b639b559ee4d68234a6d7d5d1671cbc171007beeb325e1932872b0b2bb3887686170b2ecb57439121c48c830f44efcff3f29dd6f081f0da7d833a9da846a023f04487b5fa9529bc8de1c70e8414bf8209c41add4a7767fccb30797a5de59f116cd51af9a884e579d1d0bb5464b280dcd7e16c67dc402e57ea201ba0b2853eaa19b7667ccf64a3762dc3f80e8035629daba2e65123d637c658c61359c917181695206b0015b28486ffa6543e4c22691e2f7682f50393aaf54df228c709c6a2f57f40285b48c48a47ca95f7145655f29af146221b1eb3332b21437530ea203250d206d8325b43f1b043624df976776c51b545d80546e35bd81b3444812b321a4e8f22cc15ccc634680790d0229a361c3cb82582392bc373474f804a367ef22a721bc043687ab0b1a1abb33ba00f93aba614b2299dd9103ec4d5f6b3791e608141235055925be132d07ee03fefa44476bf1961b94703768b1e6386ca978a14112f19313967a8118a7ee1b2ee639033ebfcfa070f9b28020d248f441ffaab94624ed2f390ac20f34ee6e7d3a46322c2fda1adf3be427fe4bad19ff55b3e20911ced365172fbf34031c311e50998aa02328ef1407e9d1255dc85fde5dcf6dc76c4300a01bf2e656356e283a7632c7c54bed06ce502d0c0a60c815092d6668ae0a132c7d39c83d21283c9b744b1d5f131b8bac0e0fb889896ec77c405a19375b71a2627840a62c334e46b09813e38ad8748c131a354b87dd524ca2695ccfcf2c2d5fc97242d2eb850dddbec50fb792636368ac2040b01faf699b938e2b1fda022efac51817854cd140c548e23293145f3c1b8bd5625ab031618bbef308405f5748ecf75a59e8425437883fae76e206bf5d1851de6393d87922fba19d420abede2027b4a327e22fa35fb6f0a73eb25a6b07635414031b861c5ef53ea756d1e2d315cee63e1351015d56ef24f92b30f5f818213f7177a2bd123c8d6a486ca8b4df6b4deeb821926fc72f98181e7004d68772e03c02529372fa4248b2d171ae7f164e4ec2aa2592283b45e257be161c48aa55b0528d4b40574f17ca7aab4502b9fd19746a8244c34a5e23f9799912b4579d25f8c40c5187ed5e3001590b06cc341536c3c36122c045c43a57aa8618c4260048a287e7476ca52339aaf6523f2ebf7e37a826d471141e2a6d3c1e6973bfe76b0dc8ca802e00206071d12ae64312d3ab4152959b438a31436d18b2c006fd766c57b6d48374386b246637cee43f34740646a4d73945f31d4f4d5a65bd25aa41b207031caf016810d348dc4f856d08fd96074a6560043b7fbb285550522ecf4570059d7f246da61df023ecfbd86574910858761ae42c99fda110de957c3a3c9aa95ea043b72e978cc31bd8f1910186c4324406d18b098a30ce3ef51d9b233bf5980e0893922be325f9184baf7670d90f8c700e87330fabb1ae142bae3e171c727441820f27513cd95b12f331c715dde0491fce5d7737f8098b41791dde0f05014054e06a79143bb701629673266e5d074b666d2219514155da6b21aeb416e6afae6fc1a3667506b7a9734a32fa173daa882eecfa281ea62d95629b0039364234b61f32f3fb20a411b8646d6b8f085b485a59054f6467b508613ef324f525dfa48468de68df49a19e544997c5d64770755b0687e2230771b69d014bc8f31b0221cf606c18bb1cfb49a10732379d293c20f1741d79af006b192918349b5467c51f957455d0d4059f868c4fec3a932e254ff40c4336fd6c66fad8182876a41d4b4c92167ba6f41f7558fb40e234321b85e97d1b9638570db218c3230428072f9505843637bce35aa9e40002a7ea4e124aa14242ea00dc4674850c17dff6df2d6da65a341020434d1f4783328562e163f8dcee277fca4220f3ffe046c938a44c67c95b4fbe5b5d0a934e4b58f7e5971d6662262c8a66eb057c1a0a64314144165e88fd50f66aca355dd65330c78bd92a2fa79d550c23220bce70af06d9e107529c32c6415416dc23e62b881ec665ef6e5ae78908772fa763c9ccbb50088c36595fa97f0ee9e3d5425c4c5154eb92c339d9bab66454961c5ebd31821d28c81e37d3f6857169a1160c984bd611699b4e755d71991336409c44f680283896473202adf2f25c59c46370c6f8a033acad663fd8fc5e5c2d893a5428b8f13c01b09e3678bfa523d4535a6bf0c89c771873526b8adc371dd37e176529c4b2413066024f6f9c9d347d39461395c13b4760c10c66b70e4b4f235e89731c7e8928f1b63e235f049843b8fb761d50b701164dcb60072d27ae61dddcf42bba786113d44ec56f50aec717ba8d990c68e51b02d0d6b96ff1086a19a537680855817135f8cbac0f94dda24945188f03dbfc7373d482ac0c6e0bc723fb30cb6405b2ce2828996453d033ef627f89c90ec59651601e0359728f60c872544dc44ea055f470420306048f89e8500180c93148f683657a5710167ed9003e9f73ae1592286545c2123716e0a2f72d5bc16b23f917925da7cf801a46629461191e6b49bc98b0383e4bb641093fde757ae0d171090dcc2f3015201ba3afc468d8cac919eff2631a3204a814749b576007de021a4a8021081b0e9b5f65acdd5170bb0742094cc45578466d33e012b93da5c62f3914797b31e23b52718525c27524657154b9f7411475cb4a0b44d6fe10cef4a139366232663b68d864f4eb6446b256eb5c965c981f2fdc2d36e38944643239c6167fc7a56d08c8ea56fcdf555226faab68160cf96f8755ba3208b3f1731079f0678fb7d362e283ad5dcdbf8752de67961b418cd81cb42f804c744d2e33dcca494ecf65743e5817b96907cfa35371aabf2d65c1b533f3b2275344e54f19fec73d05f25e7077cfc7345634c1f920a0b12a51aa3df702ca416c5d74babf439692894c0559e962aa8b9b119486f706d666c0326c771952667bc90b076eea35be18bb4c4d8d0c45e63cf14a4bbcdf31fda15324984fb247e8929b0a2bce80736021b9411e3ec24979e19449a154115a51c2830a5b11d32d86e7d04825e329379f6ddc4b4c7de26f5f2ac060fc11a75cf122045c8fc8aa5e5cdabb0a053201443b01e705dbe257704535ab4f1c3be65bf230f4355bf41d5d03be825ee7f33b74f565106ce36bf80799119e2e8725aa078ce3b100ba45022f921aa91dc7c8ee451febbd4259a7db031ab4d07279bf01654aca575b028eac2bcb0fb91cbdf6497207555732022df01e6b9a2e566930044af183536b60e47e5f8f1fae4830c7356c6200387239f8254e51f2911ab6aa95726985424774d1e6667ffc837417f76612f928993be7e2387534701a045ecf0023cb9e0b473cd96f349006d225f6ace010ac59a74131f741196c667a4f2cbe893f7e290f091ef9c035fb9f2e774b0a4847d3d3124d38413d4ab7c13f563683452b16dc673a8b02064aa526ec3943f89b685725f745f388f43ccad40631d77d2f578948c207564de802ad7e4f1508a1a15ca8b52a19c3ea6b3412e089558604ed365e859d7650ccb172afd7cf21e5fa745f5130d46aec3df60c16c7dc6481237f091e5da071ed59551c8a67342d3ee300157d50255b05de1f3e22b5bb3f1a28370332efcb632c920d127c440e471346205ed76629755c996b20e32fca76e2a75d2ce29b0c68472932538afd74370122693ef34d65454ef09f696a533b2650051706c1924f51c2c0872d5ad58266e096d70cd79f343edcee531d3f06b1457da73f6d5dcb984b67e6a248b8f7f01b6f111d4e5fde910108d20b347e5a89348361f93166adb9385046aa475f51f8712d8add2f0396a45f34eba431b3be383f5a756d0c06b1283f540b5c5ab9f42a6f57d4a22c499a5874c710952d46acca224b49dc0f64c3e550147a2332e593d17047aa455609bb4d6ed683ad524cce82089e3fd301a0a3631b6cb55b0df724f949b3fafd56bbe5ca23e2d5e0070c260864c1b0eb33f1af305411ab5d3fa8c92c49b55c3c40a2574b2b15c6335be91a551122d2ae1a9f040e197d5014398d46d3399134621c3eb0b13403a74f5f6dde982c8eab1377cfa1356a04469862b11aac19229e434dfee13a049049bb596157061f4d457c007a29a66fdf1414486e13bd0f94bfbc382db88b0307335607dff5a16886db8e5f9394043187c7a14e1c1e5a14b73f795777451e767016c0635d9a590d883a573d4cf0426045df19142c765b46f8b0ae26d280e32fba0a4157a726e62df7d8ef2fa4043b49b67b8b53267e9347c11d6c44e8a4c323a32843658d9c3849e4dd57252dd4bc39a861686f4316c31474a1d420b59f8e4904193d4d5a5d5d0a94aa9062ca7cad53a1df3a761c019e4e32d30a03b4bd621e47604265ecbbe134949e235739049d691db196051a02b2756aa5004d04ca194ef28f0a2862201231d09f31283dfb35299385fe4fb740bf6cea968775561c75479e56692fb314f435caef8132b5910b4021ccc9333256081192d4f6332a9e3b07df8f706228555c59960e316786dc482fb8454a42c2e29627d4902560f439f020b8b80c4fd5639e24c3f579384c2fb85233cc5e2d342af8229835f730165b61397a23e85c1cbace31c352eb0d24a30a745fe75c40af175c5964466034baa2da22db389a3b5825332922091232be6c8e2a309920107172aa547b987f749c567b46f85f1645b2a88145daf90b30f978ab6693e12633fe6ba13d4d47c35cbe043e569b8b161bf1fe945d7da63b0038436c5413f37b6f1df5c576545a822af2554f0531c6b93b865a7e0f856d943c9bba055748080e4bb3effa350fd1881b60228f066c607f0b9b5022148c071f57f13c780386487317987e7e6c7cb97f2b3fb5ef089db16e66d8721b5ad55e812264529f645fde6a62a6ccb276d6c8ae30ea3de54dc1615c3b9f8b45406246132ba6aa762655092835df7b9363c2f54439d3ace73d0d96520783d2b850dec088102422492380cdcc64540811599b967315257232064a21c873f8cfde4892f2dc059a705426be669001b4074a655b4fff552378915a23be72153492f62e34820570fdc53d0f8443847285a5320bf8c2ac4915e95450faee68092302f870a0634a72cff90070c6d97477006094588abdb875e6a91a27939c74684df3726aa8054146304903539066377675b7a518574adc477fc9a8417f64c133ad67c65632b1b0237616b7519af4625057b0053d04df4a65ca75086199745229b5416820de38c848e07e7b640eafbf496bc7152e64d03a4bca2f772fe0346a67de83cd3d5c0194269fcc70395dc2f30c600f566ccaf0e2632f451b66568aca3ee5ec295db0d504519452be5e617a1e373bf5781c7928eb0d9d80d86db14a0e525e0bd927b3d745733e338a53d3f46f2a1ed2d134280f914c545e27355fe6d669733bda041409be0e6f7c513debde48119ace7868665b3c76908cfb204da3cf708edfcd57e4e298746666d768975b812dd1926360aac31b1137b5e7530ba4f7425328db70b6364f5310246534f72ae748b2ff9009d33c5912a6b7945278fcc61b1ff97042cce4ac5f02a0724e3e61ce2f53934d1f2683c21f3ca52101bb350e599106bb292e8bdd3ad9dcf447707d652191681a56602dfd641f74e9673da84e53ca83d0437b03ca0821d65b2a1b22832b68424a119dd42a535d27c34d0622bb6b69bd6a0db2ef002a1932685a7aedc1771851b73bc5a50063e194c849d21e3833058fbf2e0abc066556fea769bdb0903489742f0179edbd628be39f60ac091e545c8e1f5a0c30202e5701302bdc28b0246a889a0caccbde247101306814eb7850bc382c14ddd8c955135f4552dd5485017dc33d54820f5821b5ddeb005aaf3e278556046f5f3ea81eb85b3e2295a91c2654c99e6f14e68e080e914d1d56f84d02d33e256ede54e65b1d7a4524d0f4a85972d42742e713ca036234406226bb8367b9600460feae7c10d044fb4c03c502779a5adc36621edd3940fda16b60f89e6fd3c35152f66f0101085ab3271e088c20ae0ee52fa813851c382449494c100401fce3c0096500f2303337645ee9b0e760c6e7790d021ca96b87d76d470372cb1ce0d86b3ac33e052be287594228831b2101c2ce766ec04036c8c14d5979d1b2452ff650088c30d433f652ec5d16d3dc05abbf9f489987f963b5a16643c3916731a99ee91485a4fb4360cbe1758c65e42594c9433d34f10e72c81c960f1715a146158bb13147ce1326596f0b645bf4bc570294d767e48e7377d7e8e144ee1f8813799d8668568781244f5a0509de9c2e30eaf6a448003d4b30d569bc0113958560d4ad5c0dee0f3301f9a7b35dfae9c82146501c528602ed256485f81cf701d3016439af42ca00e710ef67ba19b7e7db1f1be42b242cb6713ca47ef05515ce5314e646541f54fce6011c1ae51250daaa6800584c6f73523d5cc15fda0ca2873b3cd30ed538c55c7e203d7f155793334672e2d54c14dcf1e44cee86f11d067dfe62235c743a9e9c3c40029a0c705e5db34c0088435db9c36861a5a84904710dc00345b3944a8aa1df031b3efd1743959d554e914b6b09fdca65d223151467c2590d9d66455b91d7a65e1fa6c47236958359cc963c6f923cd658af84484e6433bc323dc3ca0343420f220bd1bc5af380eb19858e216004d0cf0e0d10c60378de5473ecfb9218dc79e53e72d3bb5297a84526a6b8b41fad551f73214a6d4f5079982e2353e45ab0ca4159e95ab00601c1515cde9fc924e77c4370fa82746c47034030a5206f31018a6515e0fc7b0c800f464975472e483bd3263b3089df5786e2743efadd5662d121a121e6eb4b4df5849742b1976c639a84f64f136372166af29f695ad6e36bd330ac51c5b1a41f2dc3715ce441d12624c9a95be2c7a91cef74f61f3286a05466d0c20e1496ad0a4aee825fdeac91429f7f24263e86876b9ac528686e6f7d5b26b8c65c77eb24041461c47157347a5ff4e98a205cc878589d663f766795ec75c52cdc001ad3d73b55ce3e1b1b1a41654e870606c313f65c289bb50af3ebee3beb8fc76c20535f433dc27548d2bd3f4f56d13f195e8bf10cea96f571315d4375a57ee80f74350c6f8e8ae43162e87e29cba8c61bdde4e50587312d0f17d6b73b084f093c2ca89d0600d22c2214f4486f809447483b50746a6184282860cd8766bb4ad834fbc75c5f6cac4330c583006d5eeeed73104c530e87bb8a05ad78f10913ee943513d08968bb932c4b11796a07fe486e114e17cb65a6966726337256322c63944bf06ef5408f1a807541ec3b147daaea575867303df272092c146c4939cc943c194da4c00aae88b418036e1304b57fc1544eab5f18bd40304dded3a509fe48fd0efdd3df0b36ab44378945195723239a73fa6c51079a2b2c13f29d54009d8dc5707ffb6e077c8dd656fbbe19325e33404b626d8b3a8038cf54ed37f71fed5f600d245cbe24673fb92f01e3f019e88116603d18226c16f1e403c04f903a7d735d287e79c90f46affd27da785f40c047db4358763d7096636b66a754d60f2e21b1003c502b53347ad73bfd4fa833f84a360a2e6dbe34e2cfe5059eed6e6ec9c22c50bf4a3f0a58f9c82a64690848ed0ea309a027c712dc265e59f96b4d2b1aec693d255d2a40ba16052580358b52d285f00e99dbdc130476e72bb58a95017341f14d3862b53211dcde0e59043557959774740d548c03b240965e4c0df26385d8b740caecd31e1c2c7f22e351d624fbedeb70c351a7208c4444745ff65235ea99c94aa461256783f2486a59df505ad9e88f06d5527a097cb28c351e9b726e6ef43622fab8271ce98637481cdf1719f349153f43c58002ebc3c25a224efa391364f533d33347568264b957ffb96e5b841f9a6db9c510412603fe106a1dae6e7828cd1a82a1a9432e076201b60a25204bb2ad023770a800326ff6637260331710644c65f615136167c2bd51f5eb723fbb179f24bd3c7f19fc6c4b77937b9e0144f470428457f71e177ab01c0086c171e221cf5e4ea0495f43c07e5b3ad9f205d83eed7711a08207b395236f9bebea384d732455bc309f45dc7b5e5c5d5ca86122e61536eb2a29750183de6eb838912497182d29255b9d35168ea8132f38331db45ea909755d1b07b343214a752e0651b3c2695c0791e21974969763e6939767a04d9c4490a7bb2ee83bb70d161c5d55f9a57440186da11c315f864d4093f8576f733162a79d40220560e952dea784661e874b774660be338e3f1e6ec4a4d62d9f66895162b1216f5b35365a20151d4fdcf22943167ea70a29a7024f0550530c79a3a9408130e8479a9abd46f3e3c34041c8801e730e4b4ae361b9255813b82bd7c747756fdac62307b8e5525059d1504718e5291b01b33af070b3118c6de546dfc63c064c25ab0536e11f0a8689dc75d65cfe05dfdc40366f98564075b35e655659cd370809f82360fc94138bc73e611c96cc4bf8e8a000789701466c821919441ee4347333d659f98db3400ce459169bd27e43465849548624ec343f88ac39e7c29a586a193d15759c4043d10cae4b43a40f4d0a281509d6091a05e6e69d205f0ecf1791a5543276646d3fb25fe53ddf31e13e1ff26d54ab9348607f37d54a160b1512b0eec70d6a5bda1eaf4636498b7d592ae4937372a07af7350da1c5611500b570703f051ffd5b3a4e2da05e2c8e23482ea057d3068d92066503dfeb0b1e7a18557de5e12d1714af000c02ff652c783068bfae9e065d84b36891f9384a1c507d4c18ea335a3ace8a3f47dfe447f7b3b85800d1c771c7362c4e7e61a830d216c1712f214802075de22df8c4d41ec24bcd3634f77d14a44ce8184de05305eb713372b637917666788a0c9cd09f5917941d5fdd9f6834730e983cf3f13947fab8d72918bf21464d8ff72655a7f011a4e2d217a1021d2c820b986d9395ed4a6aff57541b8d8360892c51511404e1073460dc3c9dee09323615941236d1e93891f9f756ae864c6cdc41467010bb5e7067070663a352e1708b40581f2cddfc4eeaf041712b3e8024418df76d16d3de0c699a5767c6ef15762becc162211b2461d34c36123260860efedc773152ed1a745442a713785916728f2f4b0b5f7f286826ceab5f51cd0f0ae8afb941b07bd44a00344c6971e7153c5250b10240a7175e0d2b6444731b6d1c393ef6567f40a632550c3336c9611e43d01c99625c488b7658aad71501b5a749cf187443a76bf608cbe3da4233354126de2c656914632f4beef85243f55b500416734d40be9a2065aba88c3b28ded537003bfb59907f370fa8754e4f21176367dadef91a1dd97a1a22f6bd4345265a4b18e40117841b8b38fb43a8674502481ebc06ce4ee4eeb31a5910db02a66e1376d5060821b290f66a7314f21d2301fa600d24315ad6c6eb6b5b61c924b180bc00e6dfb71a5cf9c4234ff9db223be3ad3bbc66806f4f180846e0127f349f995f5b8d36922a299852077064b81392d484141c43ec6b3176b80077027b4fc6a15911ec632d491abbf35b554cec0c89d904269b573601ecb6b21f9d517c15249873775f1805460581682dd4ec203c012f8f09fabf16506917702f69b7fb24511f8d2019dd9c17a4281438b59fa806595c6d4178267f6dc02c024e6356e55d804c003108961e20259002758846744e424a6d0e57f87f32d5cda6539aab88731deb4244b5c1e821e27ded05bc80c12b61c4c905e934961865c9c80245a4943281497b4eb923c77329460a64d5cad7121e8dde1141f03c5780504a64df18a8745896ca4c0b094002a60b38078ced4219ae8c6644b9efd129b975dc089e370157563a27131ca07466e9d2f016fe7cfd1674f91b3186f13b04d0e71f18db7aa530ff475b62bda42164f0d4183c13a07f5f3cc94e497f533330ba60bd4e6af7b638b900e21efefb4d18d21cf8359bb6e600c26c156d72ad9217a8c6cd2e2f7b4d43e9b049436fade4311be6ef096222715940308e47be4eb3278cce8a3da87f910a93a8e175cf5d171a804e9c1221117c14520f8951f6cd3055c3d18101df5c3772c06a7c392ba1d5578cd1d466529c4a629382ca4c77add81d6f7a564388c9fa5db170934a0bcd3b1a3d5bfb2e516b0617bc687b09abbac03c257fda55e2982e37311dbb43c5653339e9c4ce11a5442c04d3142b1a6723d73ff021051ce3da3506cddd51167343c7511e9759656c7d7446e2882529cbf46d1c734f6a6a4033eb1462fae92e7755e1607b893b47cb42cc63523dd629b3786957f60bdd4b6dc9ea3c9318231e09f6622002ec6c0db09218209833cb03bb8c92539a907c2d047e1e1c18598b03ee48414abd5d1267e58e5a527bb55d269265605df09a7f681e4e2a16bc643614241aad7052aba664ac8a073f0490f116715fd81c1f53db6815ec9543dffb5f471860d975971e2c15b6b72904d07ec731fee0be62253ad45f0c30ba062e3e972b25412121ea564d625fe22b0684a54c6027a05173a6050b24d7a8b9455bb22a537cc5484070ba6d4ea0d5d81243e199251bbe0e5adbe443444a017039c5439914c659d323b0fa115eba9ad05c48bac020f10bfe264c8c7251652d1b13d1aad83a52891a5c0935886658605d298485dd774e0a062ed6daf15b1425dc5145d13f42218c4d2ae356103939745b1c5dd11c563458b907c518e42d43f68474c4a1fd4d0cab133229520b3c5782b8015f2ad765dc0aa637dc43d318d500276ed27b241f7af8ce4c58046445ac13854d2bbb70395a1c4f39227a1a4470ad7d1633472c6d4de17541646c631fe094992a9d0bde6d57e8cb2e0d30ff77a03cfd0be891ef1d932cc35ad2ac3627d3ff2700a700c52940e3ec6241eab43eb9ce9868e2759e363ce9033538887203ade7c13139d903163ae2517589a6705aa056eb15071be17575f7170e072f556a183a5b58ce207d0a633c3a6df825f05bc115a22964758306c151d10b0d399e457bf2a2286131b26c5c9f04249b7a941e5299dd5570c5202b059a3c3ba8567f70464511192bb2d537bfb6cf30a06a2e4955abce2c7b60477530deab5319177b2851ee0560ce934e236e7f351c9a0b4c02ea07034e2f05501e05cb900c9dc0cb1c48e0f90e01518b40cba73b60950aea74eb4dfd342baead4681a5a053b60d0370db85026d8876551a253b8f65f0d25e74963da52f9bda602ba381744c4824014679b43d2a1e0f5c47f5a0f90ef4ede80eb90daa4a349cfb4af848fd22ffa07b2d6aa28d468c42db1aaf869f432ff7ae0b7d0751744f027173c3fc7845a8567e38b4fec85c2471435f1fbd9605d9c3df1cccc51756ce021700caa26d6f47144154a533cb1131f3db4b2e3e7132c006624f42b10b179f007f01ff9ad469508ec45aeda7800ab5ebba1a22bc4c12261c9236e597495bdff7b55d2a7cab470834fa149740924e066f3556160948680166a20b7919eb6af54d195e973da24d73666669847b67300d5d8e71a295dc319390176fae17bf4e2c59712cae287731a28d8027f4bc0819ec2c1c0ff962e90f4151fa0061ee6e57d6b0d15f4e14ad3251e46b761ef9432f87e1fd101522600e67681252fe4a346cc52376514770c110f9892447989bc2539747f96a1e542054b1a3fc2403c61a217277c9528226d648df228208c7aae70f0b96cc5a4ff4c7743ac7e73c7cd6346aa05f416f2c0aa44c6fa9f462c4adc030a9f81912445ffc500c52f824f22b4419cd7dcf728f80da772d93a5540b43d91f4eb3586075b1c643a008420a803758746d68da40ea21b171e868242ba721370b2a4d7d42c2cf4459aeee875033ebac0012748a635985533707bfb60de14567328812806b72dce11eb5029b14ded03f6c330ce34d97eebc1d66bf02639b1cf159a75c664af96a2c1135e31539b00f9a0bf54f45273b76ec30bf497e15cc2cb229eb3cb3730b1b694642c64a5d37c1b94e651b674a280d876c26656d4ee0a83d0c0a497a3a314a3c354f36ed3fec9f0d52fda42f739d0ddd73e5a6d917efaffa70bf86fe5572e8b547aa42325128b7704ffb329a2bf0a0ce4e964a50528f08c63378eb4e3b9c7c0021dd3dba032aef78455c3a3c6892202a27577f8e21c7464f658aff4721240ca5248ef8035e34fa5570216e95399045d249da5b8224f769e51f68a4ba303d712e15bd1ab25efba1d437554ef247b3eca75bc835795d4062b23dec5d027284491b2142a48335a9ea2c0eb1bf0c404659a900d35b11501d504733278053653a89001f06e2e72ba90b5f6debff2562b2a8fc091cfc34150ce89e3c8bb1ae01e386806d7f3192013d892a6d784cf17175e42d2ab54c0b2986dd4852419a4b728a75403d580b1c74c6d0dc2b0275391d1efa352f547d622f6e94d83dd72aee66ffb97c509b24b057f63f78618641f40ccc608b193251d65061aaa23aa8340f7580a92d243aff312b4bd0d45df79b7c556f1774208dc6f845457a253d4215764da6d75124497ec5408144c52f97eabd66b07c66328f334f73eda4c96040aba7655816c638df83c0224758e102e71f0d1c37f2db6f0b2e701c13f8b70bc5b3145920052355d6ce5f568aa59a57e01811274d47801507f83962cae1365a33535b49a95e9027c8746f4c892db825041cd92e046c222d4f680d284dc7d801c8402e02fd179277b68e21590d72221d17b66024e997436e3ffd28058cd9a003b25d2757897c1a6c54cef30adb8e7b424aeebb235701df2368749c39c032791143f5531123cedd23cddb160d3868a456b8dccc5594990f4198ab53517c35f34ab411451413fe8767ca33f61aa9cb910ed46009494f5e043f0c378b738609ce54e650fe0b89b692564da20241a54934239d25d46876a73e1e2c3d385da8d6204605843e749df8d10a755f03259166fd52be6d03752834a73b0803fb07cde7a85d2cd76d1f8e80fe3b0d2d82368fd70d64778df16de5ed1166eb5d420b919d7408e4266e35d1e0a06e1f1eff0fc1104743e4919431ad83240ccbe80458e7dba45a28091a2356eb8d71a932571b63aeab39dc728d67071c25272847f24ba0fa9519cfaa486d01af9e3741d74a374d3acb6eb8f88e5ffc797109e2b7cf5c2c530c4ea2c8a76699dedd40f9dfea5c5328ae114020fa09114c9605bb5adb059b5a136ff2cf4d2904a47d5bb76a8a247578ca0a7b22162fc59d2d12de52325c7ca305766243160454c612615bc16b23f917925da7cf801a46629461191e6b49bc98b0383e4bb641093fde757ae0d171090dcc2f3015201ba3afc468d8cac919eff2631a3204a814749b5760f2a606763b7a5d37d4073f6bddf0d1075e91363373f5a34c851ef2625c6a072341b8d165d73e8e411d51b434e0bfb123112cb94d888de5009ec3c538f833b2453598b40e10bdec39a64fd369a0c4a3138a4cb83904fc552497d4c803df04280fdb9d1d6774d5a34f73131831076b617118f2da6f39469b2146e8fb10080d3c47d9eae43d9c243d580ac5472ceba1e91b79e40b15f4a1463cdf7aeb3c9f222c58dad3422b561836050c44cd440567e25626a8bf555cafbe4ca8d70208bfe41802ac148a05851d8c464c0d7923a03cdd09a757a476d180eb53cd7a7a5c61c6c74811ff1134cd5ece194fe8f1011501f24cbe67954eeeddd4727b4f3e68ddbaf5653929ca1d5f3b7d5058ad7602b92acb3c2a1fb12331daf05dab4f2f2c183e6d3345df221f28a9c439318c8918caffd2180a60630cc780136ee09e475338e235759cdd653fdfe92f07aefd424fa79574133026b1419712cf5f25488e302a6acb124c0f6c4bbfe11f2ce026745bc9cc1857268b4c0fc068ce2736dfef0c044f0e757e6e664c3a296a184889ab3cc7d8f02a985a814d6fb7625fec532848480df5569fa6043b03bd7d5a63c83e05ff03a84d6b03544e5c5abb5facee6759045dc01448efaf127f894848237e996e0acac2234216712c971c722cf0ee3966364f2c00e5799f6148ba971ce9a86d4a7b042a136b525e62b5bdc16c006a42078396681b58f188684c09a50117e91f0131be2c63536cbe4ac5233105ef766f61103fb42ca897835dea2e5d68adcb2701f4b23520eadd0d371c5cd902942f910f5497351d8284c91a4d6484170213e64564b8fe459f6e4e6efe6c4d5ec7a9c035d5ffe50b367db344b2860f13e2bfbc6df419d06f8f265652747ddf57d4c31d4190901817e0990b5f48a30f72eeb09a4b58c6f44eb949f8618a352607fe5a2d49e4b04239bb6f174aee48350bc83ea52026611204617a802c8ad4e92c3736c953dea9250c26888974d9220e01a3c6924142ba9111774ae441ebb87b5014aec821a33c6e22f52a6e62c1f21e377b3ed95d1e4d6c21a5f7a16801a2b6715ef092606e1fbf043c8d4b2abd18dc34498d7977a1e50b60edd0b94451a203051375fe7154cd204fa1dc2d392dd5fb06f7685025b399855fe32ff43781c94a1ef6cd841abc98d46d1e55a945b7e95820f483066bb81a55725aa8584414cf7e3270228870eea1d7448ccee272ebe91a564639c36d491c736263a5e56f9b7d9a2a4c15e10dc3772a208befc65c3e99656ade2f4f0c9f3e4d4e0dad3f52031803492ad41a254c07121169eb755a4c7772060303e05614bcbd41f174d0661149d11ffa3a036f4672196f14180747afea0d5ee6eeb3206d27173c1de0bd0cbcca8710b2f4c561cd37806b8c1ce22631cc9f2b7d126568de36c760a704845465a1bc496e06db2d4a80c828ecf4e031c6581e4ac4eedd66200bd44cedd03661e3f5180f775211474eac83230b84ac2b0102043066adc7691fd501495de62c6f3a7a3a1c807e3834aefa30352864f04dff3ac26d9d80a3185115170f6a5bf82def45c5239e8b8d4871bebb6c08f0bb6a6bdda0330d88772c65ff4862e9db6d36408d9d0211dec472a94a991fc7e59a3b67851c22eafd56000fcb8e32af518c39e9e4bc494645842523abb7653ed45658dc1031368ee0ac653abb6704c4c0e9640174f43468030031f723f80087caa525846eb714e098d713a5e74e2ab4948c0b23d64519b5dd914f2f9bb01380ff8a61c3405d554cf4f7456642db300fc98617b725166ddde5a44bd9cb420ad84edc2648f39d3cf101dc74b297cd29b5735145a159c65d0d980a6a27eff91019adb2469af7720b00644a2c33d28948fa9fd15a34ec0306340448472fc57031a81bc225bbfe421bbab40c6cfde5b944da0bdc6edd3d8107d3a05307a0e403292d5f5a5645bdbc6323e0bf325468b142dcd4145302549509adb13a73b9001d33d2cbc2063160fd2e6fc787401a0567473174e369a9d0ff564c25962f139e1932700f283c4707234f2d687f43275c140a6a04996d73105c0c5b61383a0e3c972740f6bb5f3538cd3d211d134b0df931125b246e341de3572d93abcf6dc7ce8f47cf09054147fc3631371a0e666ca99b23ac10ab4e3514794e76025051231d893c83729d509f4fe4270d7177665b0760475b217967e4d11c25071c4b5fef4506415e688228b0490a023fbf76377d67f2735826a9753d020e45e01ca468d52521739bc051095c16d2033e882b460f93f26b66e1292c58bba53a70ba483edf402a473460cb1c575e7d083a3aba081e111e6b94052a3fcf6ce9127061421ccc41274cb0cb851767a6d62554021125f541f44acee08311a2830c3e5cfe863b35d62b3619a5d22915d9186301f73b404d33f04b77576104b5ef706f6b6d504363729158c735e93b50911d4c90333027fb904e506ff93c531393ca5fd7725e403ecbae4983a3170617025a03ba2a8f32835da92d25578a4149ea351b762bc845cf1e784ef48e423390d86e676c88e025db7bf94560d4ff43eaf26a1e2965ca3956d45a55fe759f1baa7ea36fdb1f1722734ff92681fc842796eed077a3c15159df9c976b6062a01c03c72550af70b633f2535d17c50f852b08e888129e3340233b9a7f11c9b28a4020b7fd4958d2db659516ff276fd5dc038bc2872040429950537b990eb965be4c58c86f41b568c2534b7039093109f44044612c5f431d4718e40e0d3e3859df11c4a0f7128127663233b7b13584c8791be0f23f16cd4f5a213089cc27ae38c25185c050171ef5881012991715b76a7b1bd7d1a27747efe1071137e80ce04ff14b7ae9c04aa421905e09109f0b4a3a2c2b473fa66a77f7ef2131bfc22808745a0f0c5df929b45b713dd7679b36c019911973e64f2b08dea8030123fb264a3621687ee1f8363e8d0b11ac3749183c496f65baff5b02f9fd883c12acd03e55732f122795e56c82452a2a4791e953876b904e452a665e268139609eba00704c936c5657f9666b0f677b60f9bdeb732a0d8928e54b0a49d21f403d11120d512f07907127cafb6b11237752bc4c9d5c574030491f31b5764e730b29ec08477492a6d06af6fab644c39ae720ef6bdc0333b52111a9b6b3639dd8ab0aebf67d141185445f3b135c06599cab4f3d7a2530398b0d470079876ac8eff22f3dd2741e7e52f060673a5f2d0e5bb00d2110884447e0fa2d63b8162804c421711409ae2d2abeec7317f63252a946fb01289a671a2d91bf6d1cdce0346f57dd597fc5336e483390759fa9e548cad1bd3234d3453cec8d0848e0a425472f95942401eff262ee62b70bc028c5697fd62b4c45bce02cb4d90e1c1dac6a2d41f87e31f53b38034983e400c466b23328bd7b6eb23ce2597258054be8c45c6f1a140c60b8733f3a3b9b131320d5f75166acb16aa0820213086e237456c58313b2a136290d2bef5c6c8d576d3fa74318b916790167e6ca7480f6462e45012021c819237263ea0670df5ab755ecdc9d26ad830c5d27cafa7713c3f66d6babe94052385e6046847159c92e4463cb0d2a75e43ad624c56d2d076a29162da6021d09c3771946abe917037e45ba77e978994e95ad137321f5f06d14fe52155c38096941c1033f543788097af3a322b2af384b7b62622db4920521151f7745bbbfda0e18353448b486cb087a0fe9557b961b38da945243a39f8912d54d8267cc9f7306d435245253bfd870645ff74ddedf0e1d129dcd681e577a75822c976b2c88422717c27f0bc0df256b6ea4e262fd64336a1beef519fe9d4424e227a211f1ecc555ecaa761584cf515ed9ccd32866eb3732c61ed442bb5120192cdb30264c88f1201c32a05d81fe3c5ebe8e1a73868e7e17ab58710aa54a4b0ee80654147b30ac0c0a4d4c3a7dac994221af321824c58f70e4c71d30700f581944a5a62f4c17360451d029328c54576948a4af337432d01d171ef10811166266ba44253f2e574a77b35da15e8d49f9053575f46142bce611c90f84470b51ba64a26cdf4f6eb07a1b535acc5b52c67908f3b7243198c7c52f4710364c21008e3567ee0c73e4e0a03c3830286d092c2d58ffa5b43ba540dd3cb106452965da9b15b3fa6475a8c8ac6d55c68251dbebec1318ce4d58107b9516ab0c9935d4d4d41e001c047143875b5418310b585dedfe32b5bec649dd54cf28037c8d65fdb268487ac5d74623abbe1e80e9ba57f10d4e66f4bfb1207627b7164db6330694065325d227a53e25ec4313e58fd4006bb6553d3018eb1bb9e0be2d630035003c1cbb4a96b4ab733f19c370d537293ec01e5e6c6dd6145757e99d0cf5af180adda704566e904e451c79ef3b252bef3558044071dc49673d7675c3522c415614a1f5fa56ed80f56896c18521f6c69e706c9d4d4ad32c0d53dd8e38692dcf1136cd8006735f063a24d9912520652daf4cbedad304089aa2358d6b8470040d99686a75e472123e907740cd65264b33c52ab4577b09d8a3174725c20464f44e252d7c49824a968c605f6a32216b51cdf3529e50c90d06bbe446e51e76200e48f31ec118ec6a57059c3dbd0cc960ba7e84711154b266b565744c8c648a1e41625d6e3e900c715dea9d55f4911e5013bd872af38bc33323bd2e54cf3e291176999f3b58080f0a102d9a546e3625623f7cf55dd293e25090a03d3760dd8045a92fe218f66aca355dd65330c78bd92a2fa79d550c23220bce70af06d9e107529c32c6415fdbae7782fc4d1311a7e41f093fd429d491c04c745dc01ad671225cdee093492a70ff14f03e9f2f1d92df419a12262522f04f38f59b69215999cd43b70c3546978e0955addba86478022f23637caf6324da6b5c961ef12392b1c252cb83696754c48822ab8c0a5a0fb57a09eef7fc3125fc0e052dbe1d3727bfc93ddd59ee740bc8be37a7088f727a484801fab28558acd8a00ae2df10509f1c53557260e341609afa2072a3b2266a235003cc3ef16e2303f23d35968210a413a4203d59b255eac7d44f1acb4f558be42b67936f6161adfda455c1141e675f0629127620366d06594e334e95b22d2e5ca02257e66b36c7203431cc145a08bb004d5f9cef3318c0f558651cc3f436a29235588e9fb53f7ff0186c4df9100d08eb142fc5a585745ef46a171f759838625aca63c4ab3d068ced2c53da5f726ea1df1907192c530ef596ca2fe00c312af1580843a1389f2ce2001b2a65412f428d9e4160b41d0740874a8e68ac288f57ee6352296d0d1c11ef756f40c1f509166bb4406c9f2bf12ce74ca11e48280704e54f5400a8d7686725bbba2c44ac113e2668ae29e0571760dee0ed6511f72f57f39bb474aaf9d56f3cf6ed558572fe117b223164bbb856778b8fd74f3c9af0718a07175584ac742022caf05b95e93f662c4c77095725c0496e0c3c45aafe051b3736e16bac14d54d672be913d7c22b38f81dc777d2a6530a809cd94cd8d839518452dd3f678d7c5e7e726b698f56db0777940c2a89f01809de68bc3d99818733f5056d22498bee41ca451d42112a4e0727dd131385d0bf12c6669d005b00dd1c1ec21824121e0f74e73cab760ecb2d56a3d36342af920e3d53d1c224efcd0625394aa638e4ab1c64259bfe2acdd90a160909f856ec49223a3ca7c20d4bd490564d7ec5069ee58d5f437ba12e396c206b32c04018120b45167b0ca33b1434232429274d6bff3a2430fcb15503761db631c22dcb6985effe15e2da0831e637212c0c4ac554d468712a15b5874c2c1777539e8d202027ca3927afe12c1382cf8a1df557152df6b1a1508e01d05fa2db4027352b7f6d56723104c5a361758a862500b4ba131b2b45a96835c883164c5e584bcf001c0a629bb866858ff73f40309521601a59519e52b9430ad53b2c95e85e588ec0be4604aa4018aa78bf1bf981255569329639f480d308c3b7961932e8ab1367018f6eae04c508aa998355ba0f6349f8e2833e27c79651257cfa4db5fba2612bac6460567d256a0d8f3371aa7b5c399bdb261933474d190974a37494bccf123216b5416059d5128e4916026602d95ab7233005d864a46ee6b6b20142bcbf3e759ba31fd7d85a6f97334749456ea941d818e62c1227654fe7b18e60c6bf946d9812cf5203907f0c2c98256175f889138a04b61df908450b80fdfe592061656da19964191e999d55784f5e71e3e87644f17a85737717420e0cf87b6a02a42c069825f74084f77741b1d07c1460e91b493feee41a6eb7e67379caa2508787300140900a7044492b0cb9c71f63766ad14e50b8b7434d314020e23b7e6300fae218dcfdb22eb2760173207106176384df29b4ff5d55b0161b0c1ffbac76d13dfc7401b4282f7c0c3929f4258f1a18f60a186129661208a34f1afa022a39202ada28f8ed5f6c1e8d6667ceb80d65f50f74064b629931773a7f5ef98513298a30d6321ba81e0ce60084393f87d73541f82603f7d2f404886c626c550ac060f7c249495df2090b915b3937e734136751cabf5fe0788d39363c6a597933233e2564906274b9c20d25afe85973b2e271fb3d9c5dc8b6902d39c3d60fb64d70688b6ea92dc4498a6204082e035d7b47397c6c99660f10e958c0ade75a10cec41abb7a611df7cd5c0925ac3d41c1ef962ac06dec04a2caeb0c42949a581db71c109eef811d41559014a1e80b12a642291a9b23c90072dbde137244fd15a46f486354e73c155591ea4933795f39989915033d73731c7aa704547b6f5627b930606e604bd5252d8a7f6622ecf6452ff014397823912035e5d402f746130335a5bd08ebaf4c44a586d80b4d7af70197afd94696a9213b4735f13a56f5a84341d39f752bb51d596e55ce216f91616529da060428a6ab0d1c0f7d3941e83e0aae0cb745fab90b61128fa94845e7e8779c6bc7762225b454fd5adf2a997dd7767d887e2af999356515051c7670769d29021c283c5433430a097b0f4b0e5cea0c14dd59645fad563db9ea0f477f50fc51089b2034be2e99110c08d2145135ba6d1f4a653c902d7137c735a86b86c60f38e432ed1ec695d71b7b4e9b1ee0aa56470ed8fc07485397260ab2a140a85fed6e44bf87748fc3544cdf9c8437e38ff97305a6c138fbe6fc4027522e4fb243464174357e2c088f5a1fe4c06571e00c1159cd07f350c7530a25bd7f8617c84fd91361ce0d6fd61d912490686b33ff4abd65018e0c13df8a463ab6a08e333c0f5352da67a42c6c7c01385bf7ac69df45a2077195701cd3fb5226ff958625fd626b045c11c942f6675c49453e947709facc11f18c115ec3807e6bb57cb10cd61aeb07002bdc4a1d0f156ea425c61f54c4620a0c650b34ec06b32e1e32e3559c76973677deff3852c3b55f53f26a74f0ff84000ecd1635e6acdf7344e7db65b48cf92a989d651a57726521800b451605eb4f29a8f34a50b8240e06d0a1e4504dc9fe59a41d8351fd4826744a764806cce4d52c8595856e4e1488593a04d15138d5a33c56bb6f3e0a8e41529cc78144c19da865437a501b437c6e462d6c8e5a550cc05f47d8920b91a29d0d21211b57bb1dff22654021008d3c7a21eb18b5080236b203b22dc6294d52171e5784f14ac7089c54e435426a0970095de014ff38ccf22f11337fc75e2d5a402a3d60a36db258fc0d6829be17170d434463e0d466ff89ad5f6b845635c8a1f5634e27ea2635e36059afd8e2359a60bc5560f1651849cb1e55b22c332e1aeff43f6ea81500e291a319987f98254cfdb80304d19658445d772ff627b0756154a76a730a5e0a1e974019b7f5f22188b7d56dfceea05e17614e7263471e4b51935d1101ad6c1001e780306b88b24f691b1131ea0d8d745f586665ee96c837bf2eb04a672b1b2f9d68b76f1e62532f3f087867019eb1204e370d2992f53e26aaf1a73418520f730c78b26bf2e6bd328b02434524248b49aad4bc10a7c9ae1315e29c3eda6be31d8bca1d31f2689b2606d4ba0f1a06835d999ddf1c749d3b35ea3b971f6ed02b1b9a4ae50856a16a181b2c0216963f35285261dd31d2a5b9565b8ded6f46c7a430303c67426101902c9ea5610d38cd4f3ade1c9510f6a0400d33dce5312b02f50ecf56c76507b2303268a38c279b535702e0332e5c29eb3a4f23759d1743a153477386773bfa4c6139f982b4323468125cefe7e64d30d09b4378857b2dba03ea55eb290149287b70679fe6331d4019d0080893581dcc751e2602fae402b9448766a4fa692aebefca3d6f6229686eb8c9144c831965e44fea0cd91836630de1103aa6ab77073a9f830808a0ab1423647b6959b7473f8625c82bc9d64b150a965a5d0f36c950d4586a24c072380dde36c42abc73e032f8035b059f1b7176bf1d617333a61b21ae6dc21cacf7373513799f17d2599b418b50ed65548ef66e4fdaaf0b77e5c76f4fb55c2ef6334128bcfa544540b2b22d5e1a3a67ee50922f7379eb709858d572ceaa63737264682e133bab2fd080302df41ea31bbdf64a0ec9c3454d570b774d55dc4d582d618114eb1ea260a13b3b28a9a39677e4fbb64e6c38ca36cee4846995b487537559c04b0dd5266f8e3a4113559ee86a1fe75d6ba618be284b93992c6a61d575ec153b75be20a775b13243082fd60261ab8f352591c057193356e34f0931fd116315f64dbd976c0a0032fc14a0a3726c6d24b32450642d2f5ab49564e94cc44e5c08f42481bc6210cbe2d62c27ced5605583110a8cb35d68788360274a24bd4be2bc134c07372437b97ab55dd5b02c39264ab8679a8353605d49f65ed6d7180c904fc62a6389b1165d39c32da95b681a641cc145b600356d85967b5291c81d6e693b811a828a0c46c8f4bb3fd447823d3dfa6221523e1b337c1c0c17e9b6121754befa33639693448734f26c5ae09373939f50117cebdd298dd06b3c90cbd10651c10712837ff820ef64760bfbe2520a02746767897a174e7d471c311b54f1562100ce2a1010a2481c7f3b4a4084244769ab822de5accf59d72fa32f9606cf6db592e1093004cc53d4f9c0191949381817d34c508dee784f8b716e27c1a0550a0592af509516dd2f6cbb586e0fd2dc2ac58a366db9c0d81ddabaea2028c741707b14524a1144c25cdb43f632909468647d4b5834135aa95d98b8173816741315f497fb047ef9160b2c12f539c9a65029adcbcd2479267d28b0fcc9028798315d68dde733d9a5b424af3abd4a42205064b1a2c336ef4bdc19ff564340fa79652b4f078c36fa0e705f761ca11af42e8743d4ca2770010b5b5447c2fa7081bc37700f34886a40a1671030124d0c4d6d465072d4be143147f356b8bdc5662f3e962d5c0cea4e2ecee9482d33735ac034932239374636ac601421b10c503b8ee69a44f05acf3bab81e014791ccf746ebcef5b06106550b488596846e3a016b3ffe32a46663149b9113239a3a81373bf191f7326eefe48ae600857d676bb188f69161b2875a43356ba341eeca7e96fa367fc12ed317e495d67373cbc0ee953779dc963bafe40098a34ba447167c23405ee842c4f762525f32f2c70e576361b2a51c76db9178f49a89cd7476e9eb11d00d4c905c0eea12521c4f24261b4bf17b2b73346d43f3c468fb18d56785eaf28504a4b22b17be176271f2f081c243c68249eb70d63dcaf4e76a007237e0b2a555cd3d653e86ddb23025676678249f743506da8688eb7e9317d9a621284c4082c26f8aa1895fda96b703483058db94607c0249b0f5009941f3292a469cdf3671728d512526cb82b6cb06278257a1e1f25efb3be52c3060000a41ffe56f88bf44bdbde381eb7e74f1d35a3471ac0cd1d0a14364255e8a3ed0d22e8a7007002802c0cf57d3a6386d52d609ca847bc6d980d74ac4e2adfb99467b00b586dd11c2c068f0383087bc9bc50f2359f4d13a8fb357edda146bed3fe34f3027e2ead38896dd2d9ce444b6c756a10ae064c232c43134efcab27c955735de9212c58e624a91ca373e60232478c30295808399e1f703933d20168f3a6625f1771046f46f41c039222dd1ffe3e1127ede26b43bbd0f70ee79ac729fa9c8e720077be77f3a841114546a91a6b35cc3e77e7727777ae860af11f4a69577e524e75372335f3b26d3d6443a340b5f2d0433b4c770101d8ca75ca07226ec1680431bd65076b83b0be3be44e227559660570d3f65a742226fd6d0ff1c25ec1393f6773873a1e7278c11bd8c0d25fc9c88c2383814925eaaf001ae0c65f13ffc98a2a29fd1451810d48020a047155bf9d051e22c11a0dfb7a983409268f50699cde16df9e4d5506d1a26ddb095b1a5b1cdf71596c1c246d4d3b58fc001c36fd40b236d5158f268ed520110fdfac1675776029f67960218413454a607bc71a0f80b05ca7f0bf256ea91111f2f8655c51091403258cc26fa0730b73884770338636096f5a320c3641d9652d7ca43d0a8606b461507e122c2c42de684e53242a8fd2c067d52f504a0bfe52180ae36927d2d37c28d5201234ec281f091de13c016e9451076fb81a4d59f6fb60f98c6a55cae9a96167e9fe26b7a03961c28e985469b0b504c95cba45150b92655161e41211926d4180e10a4296de4b49ffb2943ecf2d423637a1e70a028873065ad73d5bf71913344410fa66477b5258ca46d930c7cecf59dc7cb16f67fcd068a56ad30caa4c89289a39fc7659ab3c46f5d9fd655244595669f47c47349f350af259dc49799fcb284b811074eed4042d5c38a13d79158f406da5fe50c1ec614d4fb7ef1ffa7e6f179c026f167ccdc537ea815261f8879a6245bd0073f700fc54f8ece2271ca3cc59e4aad31134a9863b9b3e5f1cd5452b5a82621e431bc0f0753ca15a60fabd1f4ba143c02fa1bd7816db5bff5ad7c2251e07b00f4ffd688b6a09f543292a06f86f6e7de80020664765624d1d6a478aab4b68b92b58fb80f56d3e08cd6716bc071db19af64f1a7a80745a612f27805567572f25af05675bc93ce536d0188a5bf112401545555288c13b4860d065d1f29a2191be3f6bb782f457f99c0e488f87521335b4fc1b3b43dc47a0eeaf6859e4f82cfde8745b1e79f3769cbe4c0551a7d44cc22a8d523117724746e046561c024e4046df1676f18cf428cf7c5f1542e50d29b271663b558aa94251886944973496768d99782f488c045cde61365ea4f0c609759c76290b49d973645c90496514b637d407a74e93a17128b3d66e3d56a2eb22fcf3155a7f512638a6613f36f5150173197c2141bac93952b441f200e3c84960629f025ce4f22f168bdef314db43504ab1ac3845016dbb230097f4265cff505f67961a709e260c4f1f3a271e6c0c485b3c7aa439b39dbb6f6a36b90bfa1c613bb1bb2e3ef1faf61181e945014d83e85f4300567440ed86323194463cbd15ba725686a33291ff0829b11bca325b46d1656563150434ea5f6c9327af1b340d3a4994c39560a50a4b729af5746a3fb6ad5c6bef7f0cf2624464045e9f6acc1d9d144c1b221eda55de14dc1cca7612359e60146d331c678d8d52f382db1334d67a1b6175713c3524510f65b7d64fea62e06a4391bc25e366d50b924c5077890fb840f85d28102a7a6d269c617365a8846e218b219531738406433cacf149d65cdc57f2755e4fca6abc532eb2250c5ed55b2c8885af01d2af3e37ad61735c93bc8045a65eb72a94e8dc370692ce5041bcbc3c23ba120ff4f2c972a0a94461219b7c07b827f3016fb02e5d1105042b957eca2939af2f49589a7619e0bdda277ed715290b34346ebedc404c56be881f23da3e0e9a86323d6501434e64b5f00a3a128c1772221c32a2db384cad66ab6310fd143eb819395946f0ea358c0a3e46c46ef96a65c63713655928358039714b008d6a243a0c314b67c9f61f3d9409332257544193b5647177d44e5822d7a86e0dc90868d07af93721156a409820a949b44abb47f148a775a34b706aaf4b2c251d09e047b0bbfc29f0cd4563d433a33628977d2ea5215f3b47283c496f89223f155aab6dac2d0f47518b54747a1c2d4c6ce96227d7edf4320a867a71cd751a3cb86eff2455545e4edbaa313d6680414e0aff873b42a6fb3d91bc482fdc17f05cecb44c1b6b96b865363545594fa8a66bb5bad94eb185224c2965ab05d2af882854852a75263b8308076c2c23d9b48029f288d51f9d390b315f71d8550fef074865a8b21b792da40e7c5f2a63130201006947f50f8d5c5a6252fd955bca0b991fcf87213ccb64e42f4e60f12cf2e9f54bf512995594a11f6011ffac69bad1624658c4586f293b354789acda30d516981fa03a53090c57610a0f1f81193cccd768a1fb466de807cc4d5e8fa02ab15f511093aa6238118855521ac26502aa29f6441ca5763d36028b6d85b71f3e0a0a93300828ee466997a95746e2c602656e2e54b3086b30a38b4955bab55462511aff0b9719470dd24d8303518fe0326c0aae10e677e64fc3861303afee1876f5e06a08400a5b134a1611380ff8823e3db7cf22c4f08d244854e57076ecf31f15b49601b03b6b26e2f4a77730309f26068bcd3d757d6e3ab1d82f6238bf9122e627d61e2964f65dee792635dfdc980c9f6d4d255bc455380b3738427753b935597ed84e9d214512f1d0f21d923c481976699d0bbf364b62ef3b724f0559b527a5bdb42743ad711da49a921f15d58a5e2d6594567ea06154c49c4d733c794639f58f4b5624538f5f41c88c6ccf342026223d07126e5d4e3760d78b384d304d59b8bd803917225c2397997c38a434623c52b12814c307210143431120209b1977898d4b1bf38d4b6219f6c13352c3be4bea4a73074150162e69c28b60bce18c2893c6d3189f61c2100c10125e49f79467e8374d03a1d396154e5aca2d4e56bb408bb1da0025fac12aa55fe770201e44153c131064c3dada1c4091b6582f5be66ae67c9d5520bd2b40b27b53539705bb6830c5f2691fc37f3ab9541c320d1d2c77682394523c46185499e3543ca174d05c4f126013e175b570fef1556c02041650b358d722eef7e45b45474c01a5adc233ae13241dc0dc356e94205f152aa36434a7662200746cd84224ba580f915b07033868a368deaa506d92e7605ca56bc007f42d6d014e0df23c3980435a96c733307fa0b23e7715a56350838d59359db54d07442c56f7c1ef6008a383739dd30d51dacbdb57cda65f1032bedb475869ae7113970a3d6881b60f4660de063690fc343d60854623e4ba385770f17201ca6548b63bb940a3ead4778d210951be809e07a96a323dfddc4720a8ebeb7796c9ac25eae424628941b000f14dbc5cbab05047fd3cd91ab9ffef12daec0e316864d247b475080f9db5945848e57b0d839d1443cad6aa37dd261e779cff7d532d88f863225219391d3d7a003a4c7160586e442195f91260d5645a41532cf32efd7f641954cc491d0df04e692af05c247fce8b44cadc177037f71f5e088e5f0df69a5403d24adc3db107fe321d8f0a487505f64207cbf825544ac2205e300c2f1ddde21e3b90e7465063513477d13e71aaeca74e6b4a501678ced4113f8d8d33a8614e54e55f2850d5cab5115983805f52f7d1342b12cf54d8caf04d0feab057e536c45ae8224401bb369a63dc4ae260b5a6b551d2011f2fc07d08381b3f5a749185a56ac174221ffaf4d92405d1046eb274472c8ad05e12a6db2c1de63a106eb2a2806c648e27303ae3ab208ea1662eeb67ca2f1374df548081c6305a1f8d1dd038bf2fe62d23696a5e2061ab12a9015f4b2902e9e3ec17150559709f5f5b05965c0420b06a3e6067a00d2bb0b31b4b74ac1f140a9df6287cf47549440fc415a6037d182449bf2d2ec424182fbc3d26619ac1144669c531540ec12bc725ef1a6708b706b9b0d125738f552e4653e076f000153008eda4585a86d24fb20f835ad71498116475356064a65e63d2fb843fe104466e30a44065f43fbc2510afe2695cf2ca251ce5142ff8233c76b70e823fe7fcb35540df311e9a712b31731e344d993a86448ff5832ffc022a1f76a6854d4a799804bf270642c912cc6501e3454dc030ee03eab1460c749eb93a34facc49b41d205c530b6e291f2e331c140e2618665d8c12bf60475c5912af1640a71f2f5f2dfe10f97518706a12c64e73b1fa0e8edf9a07029d8d75433f77637c695548fcb5e71727b54611ea13411cd6955b50780ded720e30784ff7a7a12f82e891469e0c250b7e6ddd01f68d0714df30682a2305802f0cb5b34655e1ae720081011ba1eeec005d470e3f8e693c4ac8c3585a76926309e653925b7dbdcf38f9bf2f02251568458aaca80995e44135ed397b761ff70f7005ded55523add515a270c74cd1b6c4746aa09433556d0d2697887b700a07510975eb8f1157fe3d624e37db4466e6843d1841d92eb917a121cf9dd028b431137340452d431c2c3e362badea52b4963f652b4ad672ff4d341152af020bd1673e6cbbc9b967311791134157c50d2972f8129f150023ed8bec5ad7dec655ed983425e8fd823bf2f4a7730d11122deecde62632f62e0ee73a2217f89ca8308b5d580917f8da2a99cfc963a9e6a63960c76570dd522637e34bb121d2b1305f31fca466524b8f25ebac8c57cebbc721139e391b3dbdad3f2de93f3ffe507e177cfe5a586ba5844b6a980b338f00eb01ef485057a735da4e8108f031969bfe756975f0582b4134449ec59059cc1e653c33354f361f58ac4dac54f068c5fee463969eba70ce6d3215d7592f21eb04d8014b6c30178b23575f522c581c1cd7352c599e5563f6a29d6f54d869007fc2b42eac433321d504b75474026432175b99735c42945fc6794474b9ee8c08a740b75a8caae5280f8a584e44613b3e1acede36a836076b72356b2f3a1aa63b1467d03c5aa60a2723defe330753e81b3468e00b0894590ef8e5a208c82212758c76ba1ba4e8c95425e0004672f4651c669e035728b6de55a006b420760db15781bb0a26fa1a8874017d087642219b410013b324d8332843b95649418a83784e9a97246a815bbd634fc8b80cbd29b25cd930b1325591ae5c7a3dc4521e6049248ee0a860863a575a9ac3fa7733ff13407858893b3533db0f3b264116caff13270d16de1f452fe3068eb636208cbe10589e9e4b076403be648e252047464b903b304cd10f595eb82e46e8aa05c0c0126ac7757d348fb57c220e73151d63cff91a6788a72729124b500c46965c1cfa3468ba8a6542bf55645e30ba4b39082ad735612f780ce399141b8140a8755b166c548ee4585614acb43517bb323d61968648c29a692c2db20610bd0e592045ef9b1c0b8fd429f623276bcb20be493121cd4263336724cd7b0a511f5fcb1d77eed4571ed5c7697bc077282865bc3ab7fdc6732f41f52eb53091284afb463f80b6d11ec32dd73e9859544666707d602872e65f8c70312cac3c3e232bac26175f43316d79f57856d69909064aaa01767e6b2e4c09862e39ca3b450c6afede44e9c32f574c52a72dd6e61a6501df60241314961e940a524c232f5e1b9257146e4a37cb366fdf61151cde806e6c388030c378c510ff046a7596b58b749e614033a4d6652021654033ede1b549b8cb2f5ce2b7434798b88447bf0406074675e4519ed38d501d3e1e6bbef82e2ea919f0414a930567f4f4ff2f0f30685d5d4e7d2c7625e2252428ae531af9390bf6509d5d164bbc2ab1ac5727e1f93e6a4095f0209a0f2c0b037f641c54271e504deb380ee7538a1d583395428111304fc7ab816b89ad572835ee1e145c64530f34c8281e64a946167895971fcd6831564d847f19e5242d422ec2b643fb9155695c88391889618249f72a3821c62c380c0c4085345c10732f72656942c16df65ae63ebe5677385c398c471547ee2faf2693467d04ee0ce03e61bab36b89de3e1907b77a661c413f726f4f0d3c10a402720fbdcf290f51a97606554640af6e3b6cdc59865d218cba7477db8b316640ba33febb833565511c2befd90d21547530228c7c293651a9d313f7d5f55be2ce2f75fc4233563fa55e2e6c40795c56621c6e9b38455f84479f773f5897156782b2452a13e4614aafba543e4f60387320ac506a897a1b4e5cb32a1038b92e9bd4ab4319aa47243f8f5c5ab5cb931ba0a55344deccb35a9a2c4c55d731c04a52b5dd4a172b54778cc29d064fa84b1864febf266d7c6f514648776f1424ab72a84d3046f549d43b5a447a1b3ee3df0a1b8b3f6c02be16216103a6571c61d63e9425113e3fe4d76108723b5f473e440bd43cc0176c459d5df6b3f856902b85103bd0dc5b38f07d70b1689b0e19cb7f5c92487d68a94c80353139e36e80557d0410a7564fbbe4885795f6db35828c746468ec395137ce4b219ff76d2653b13a0e8a83c23afd2c54013d0b4664a84e485dee13cb218591d7687fc7736deb94fb15b666a0311e3efa4854f1fb5c27c0644f0a79b714738e0a614dd29246f6e2e937682ba862bfe93a348e8ad26dc6fa411c1dc7c56c2147754c2ac3b944639dba2b62351a013e93c571d47f2b5b4193db60100a4b48e7088d2e35e5c01db6859a033f213f3384b1961a134bb3541639d90d81929b62b9272502d6e8514261a1b20e17b404176e80d02d624365464fbd242bf6be551a8c0d561b287e0b2ffb2daa5af7d1b75f3ffab633eb8b5a152afb2c5c502a3d13020f95274e432e337a66104a72b4cb722384b327a94fc3562e2dec53b25be529cf83223eadb9bb5d8e431a29f3367c67e294cd296d536a65b6d46e026f3f922780c3516fc93a525fd47f9a353cef1c6724dc79479f353e60655376532228ad6e5d704d6775d6ac11e4a2fb10b31be14314a31f74fa0ac50a39b7e16625d37b700f40bb1c15cac610b3660645d030c74a5345715f50b49405130ef2779d3550105599d3553ee2fe268f445a58d123a54301d17d193e01826454acc65798d1c10f635c156639626f334e439835587b1a481741656d2657125b036a9c3b1e1bc5044557626ea3a96b3b81c90e00c871207620bf93399f9dd9402dc6283cfb9b563bc25648465a529b74aed2a9188bec1c5c0c9a3970142e6552bb845125062c61087530e33e3107b6642d61315f9ded4946d5656939144743227c434227d732460091f5c617bd3e6675137904012725cd6f6fa6702a162dd26337c76161ad28421fe176b90ca3f1d12ad6ca97670180476dfea20350bdb09f091c7af01b004f0f227413d342ef78d25ccd0ba7574350826e453df6048f1b710b34c5f70b4c40a54bcbf20d71b97e631912c170096456ca3f07e4c70ef44d85438e0a5a5944df9c3c962a570c5bea9f1a58958c5561371d12c6b1a3341e2d563fe15aa90642cd7307c24e4d274c9be76554b7e855d97bf203c72ae75631a93564d414261acc380c0a2b392a494e1fbb677d31f564c39f691ff569212aea4e405b9d75c831ac7f5e459719d568b80bc830e1983531ef51dc1d4b9d834f31f5411ebb135a251b82f076f4cd94368b8d383b1b76e936526dc33b31a26757e2d0c3242b11e00b2bad1b20c3c9ee6073d16c10ac74cb1cac77813f429b6259954ea01ada1f2057d60c5770ca961e63fa48ef11926e2b295eafe24ff169b67772bad5698f7eef48b27bf61fadb9ee03bd2ad359f09252074bb646099b4e612a1009d5547239951b99a9d7754aa018277e44b44cc37278178c87e35a7f2f0b22fb4ddd38651cb512277cf23f69856a01e9b24a64469bad4382984338cee74077f4f1ca25b6f0ba1fabb112107ef9667288329b009a32fc667ec1cb1ad32da00e69f3046de1bce92adfcb21709bf95a0b4f1e350fd8a7ad241bd64253f55d3f3d9235ff41caa8a23fc0ade277cd26991eff79851d6e34e6465bb7ba6dbda7990dd4d99d263adf3b2a16f9ca06bb17cb2d4163bd3902f7e3088262c074858f4f2ed808023074b0de705cdcf00e86251335c217db61f01415124cc0dd0b7bfb0d5641c0f84ae963e23fc8d46963331bce74829b70383e07b6088045c0640848a24fc8298c6b7409fa2bd733c41977d0c446fa4ff74a8778db425b77713b78a2643996de7908e3ad936653f52d60b94bd95f0dc66b3c6b45014fb13b343f14d33a22ead7426f65fcac18a2637b20b77a2d14adbcca3cb882df56dfea495851ba3f285c7bea07445fe323c87f6a5304eb7034bb42f9320a869933292b7e33d06268759d58fc457217391068b39a6b539a6d2d2ea3f01748296b567f06821d059ae213e53b467791dcdf6f7d575f60416af67687a8ad27169f864acb6a2c3c60f95420dcc80c599ab2b12d9043fa33a380825f2c28624950e8da3cde3d90152feae46e5aeeb960e4c8cd27ed08771b4ce0b438b4c405746538631d5eaf684bca2ce441539e284fc09ec03fcc11773ffc215b0043ff46743478fd21855af962f94da40cf1ac5653da305e4538398610e0208c67124be550cbc48469882a9756292308687959b84103402d3d3d613d3be9362501f8d8fa4f6ffc5565eec3aa19de6670331a410540563942595da4542bda880f37e4ce5235f5d20749771adc10f0883a5e8ec5b14da6d09529f19bc72af332f0042804a408c4bab15b0ba77a66183750523a8de551eb97b256e24a405be3bde86741066057faed486285f900772e321a0fea0fe3519f76e06a1baaac31819ad9611a21e940d3ccd7531c2b224c2b49ad3fff87eb20cddc50775f923f336eb1e01621f5543100e4ea3cb30652483198d925d4c8e60cf421ca6f740a88302f9ccb2d8c39d86cafb670030e78ac093f0d6e0f58e2725401ac996775d4cc37bd411560240e572d932f1c00528f110ea635801236eecf0ef9da5064c0e6e4178bddbc2aad3eb74868570003551825765c3deb5894944415244e26053c870e6a0fbed72fe3e4593b7bf0cc48b1705532194b8a6abfe99a6e7201784b81b1cc751e8f6064e08fda02a5f4c60065ef1d18deb9512728450b288415eb5b3a8198305e5e5f23b0ab6d6ef443f869f0ba8312f6305958324a2945fa102b643a444729aebe2c47fcc03276e8877f54c88607045087327521f5325bd0889144654c204a6e7ff728eed1b5130b5ffe410b2d9650e7dc8c3083f2e41cb962666b3132664bd3741d72475be7467f0077344582103dd5d90e2e25d08b598edea81105be3161bf97b4093e8e7439d210a847e112885d296c7b2144a9b92a13224e3ce4808e51b7e650207453bf166d75ce5c72d9655379c3c11baa2a922aefcd720906732a26efc66448b3f43d71ee131122328f732f37f1720d4fa6175affd8835716a5490ec848de16c030b0752f67f72d1c49754e05e5f72cfbbfd871fbeeb609f237f6726a073d147b10ae03ee50591870d7b22d06bacc537b1a80763132ee1d01cb9c0451dd7a6aa018cd1fe86ee52c545170645f49e10fa2d48459b52062560ed27f20b6715662016f281fadcf9c259603d117a4e94c71fb08e71bfb6b3d29b816930b7d1b6d77c7e9901c3ad34c5bbfde8226f5b451489fb30863829d090a4ac84e732479680001c9406ed7a0686c17045d245260797222759e419652f35cff8d5d3d5b322d2b5b99d72657a54c036b548205dc4b1171353e7d44103d635a03870d41520b9842e1e40731c86aad2f99b9914f452508719752a206517e213cabed1939161ed64d683b3e1cb29d5a74909e2645d4e25a61a49b603ceca8931c1b842647d608f069525cbc01d5bad22347ad5802d17ca00c84814b00946eb1136164f728cfeb390e28dbc53d84001800470b65765b42861a856f700977666118f2f68d1a9dbc3e121f883b7744915056233b526ab1cacd153cd7425deaa359512b0eac22ae5cc3759611bc1f5adcb769fd6b4e515a6e4250e4f6b95782efd80655be647158b03555b843d7514d46cc09f06c6c37354a2007dd9afe3d9fb86302914512688b941105e3cc6528f3d84922916ee614ae5a2634266696522cdc4956d555a641a1a82d0518b38941156b6d3f3d8a5817c85a436e8e27f370c653ce1b7d661805ca05ff0dadf9cd12d0f8387093aade1540da3a5a91722929a08a032fb1791f5c4bfcec7610a98f722ca46b50754e2c06d79a8342bbce1d223fec0d23ea4509516b266354ea47644f898c0668a1e1ba7235133b0868d9492ca6d78e2d4c2d774ae8cbef0012bf206fe8718512c7679f1f78fb0334d7155031d2da9817059acf4738355d051c6c484e622d636e8e71e52be9274606c69b7c1478c7310096e0db687a732d187de1a42122e05a6e581ac83c2f2fe52d2642c51400812a41f3d41f1584c0ee68f6e41f51eae6b6399ab3c31d6e8190189099a763bb3a640c729bb420d8fc85109dbddb776184814900a92d499c9e6d7441936f0d733c774b0c078621403f961e0ba0100aaf9af85444b80b63a245cd608449584830cbfb01f869dc6426c39234c8f3646f6341f8276052543ccce8af1abdd99e604be324416bcf64364ea5832d1309b40aa8cb354805bace09d710110f146aa94c52f5721850753729f3344f5b3bb5014db095ab2bceca04266aa45f394ee86c61bfdf2372eb811a5a9c845b217e31240b11c26a3e0500c400ca755b3ed48e2c32c819de6c5d32e2751b601e16ec5bf85bdd619b7351825242e668773cfa20682d7a2cc61da6a54e5a9fa6cb082eb961028791701d3ef3df445f2c7c2061f25206330fb05eb7fe6c6a85913e75c08dbf162051725e7cddc3169aec46247a19535ab03df11044e1f73435efad6d9e10f84e4bab79481c4c36452eb4cc5137563436e569640c18d9786d3828a53aa8103f49158cf30595233335ae1a32715501195a0aab6d1e960fdc5df37f6176fba030115138dd3aca99bc42d7f6e93621b9c906c971a249bb021157baa5d6031d3c29264619d10a1ca8f7355cf06375b5d0df391722d100c1ffb46e4422820996eb7b2a056d4056f3a1cf48379b356f25b9b970dd3fd82a95eed507fadb4f31e67a1974664f9d5374da7d2bbeac5f1f19ea3730cea9892d8e0faf05ef60dd219721a518953598326cdcdd6fcf679970d566d30afe95ff1eaf94454740d3d93b6de10965b0bfdf057b0b524ae0016f18d20f494188ed98224346bd2a10df1715605f0e68ba154a2cae028b12ce02a62f5e151674ea93ea55d03815382dd6093bdbf00f2345a9d5599329646e03c7cb363c69e753aa07fb54c359c400194a42602904eb3ec25ecd4ee8437b1d2462e63fb09c644fbcce136f2670415493888474c3360c31529d8c01062213280c1b441c4891a43f9ab7834c0991a50a349aca3fff0eb14968ab884cb01dea5fbb34603147d2111480937a3f1f891f23ad3631129d27355c9102a2472a25dd18f312e54045c3ec3fb1a10a6d6a118b61c60cf649c16d1c21c5c43141cbe27205d927f56041b13f27f419841626b53172d69804089f3ba045350969437027503289096b3a31bfa262259f00109ac04361f94c5d3f80a76c5042a4a65250dea5636dc123010457e3580a4421302ca0ae3d716a743c63bbf65cfc2fab175c2d9544343b3028eb2ce215567f2a6dfaa1fe5172c45d60a91bac720639d770c6541f12be3bbc54fb15f377363ed000a2d48725cf217075c8f2ff65b0eb966ba5547f4f3d797f50d06c3e454dbd556f4ff223186c2ee4628689f72a4cd70b3fa7fcb82c6ff3cb0cdda88e3ebb8a023cd2b6886717e3ed094aa433560bcfec2ed5e58444a359ac60f465b151ed58ce68275d1f5f9808826cd1270f2c36bfed72cfcd1c34fc066829d9e92634b513a60263b98f09f48876021e8e55067e1d9650c0a323354a2a9054736a735a9bdc347302031d7672f17b284c4d7652c25b5f054bb69a0be901516c57eca347db25d1320e734a5dd1f38665281db0200fea2410ac8d713dae22fc70950bb857591bcb6a09f846638fb99456bdf0e1132c07963bc7ba2e0f03ed470f36b9db411a5b0718ec493b2ebfc8f14749e2fe4b90c8dd110f43c1083495c267466763704064145d2264b464212421600a0f901d118b2d484fcee54d67831d2e86c2ac4fe088cc2a219eac2a3b3ac05de01d7b0b3e3cdc1bcf9a96415a638d700691f90a2168d55d49807838fc5fa64213490568d747e73edf08aa0df4ab705ddbfa881b492016775bb6621dda5e230a2e4fb31c238e77521f220370ea62e9365ffb6e60a4f1bf12490d163258823c2f0db51d65f4b5cb1f98ddbe22fa8a8b54447aa94b4e7c8d2c8521652b201f5a6b19e62b008ee2410ae7b8f938dce95c6368caa83951ed84589c658415c882896737cc5b06412fea65fde5302da14baa6f5a2d4e4e7143465952ebb56dc0169d7723dd196e2b97af3d36c7ff2ec07a992de88b2f706ff7ed778788d4513e5aee0cebf68176acc90131e5e61e64f09213217fa70b1ec7391b0d874a9a47eac977381865040f2627e9130ff1806018ada63c3db07f014afae34b3190b55e344c964986821c245a469a0d97a396272291284846a5d62525266d0cbaa2b42d27399e1b622acd717b57552f1a13fd252238922ad2010b55f9f01f580aa4f84d1fc4336daa8f4d76b5767f45617be55668c1a842be897f2a54537130dfcd8c1b73d80146d9dbbd24c2eb3c6c6730dc12d998061abe22800a585c4239013f6b72328abf443eecac6b9d90555c45ead242b931da408aa354231df75e18452de90e20c2af3902fd6d36eb130f4f09597d399cdf15237657a8613015cf5bd5f98114acf6261f929f3a34c72e316d8e7309031b5b9977a9af6f22471dfe006235d1344b0613488a2792493fa5364d9a0e4126109a527365cbc341c43a975d6d48ed4b7e97b503a5debe35b5c1566eda88495d0f3d92082d0e916183cc1f1c85efb55aa29e602abea62746ad232b5deb22546370eb5c07ed922d3e66cd3932e07f6d11b62db827b408271048484f23d00a2211ad1ad232aab4682ddf67fd6e874b944bdffe663ad2aba329c9fdb5539fbc6c1f05ab8522ddbe61301be8240ea5662d2fec4c8b3f8b1aef0060366042c9dcb529e2c9d61783a682445106be504d556068182712421e6bb1291dda026a77b418525e8b373089c1e250b1d0416161fcf4411ba67322c7a0de634ad5b76b931d43453a2a73353289bf21f4f0a85928bfc070ec0adf1445c86245c239951e274b6036d88cd31bc183ae4d6598164ea8a5e56dd24dee155cf275359614f9313386a5098cce5b3af4d8bf6a1940ba6f2a6e1d5f38ee7141cd28e716c099816b0cdec351827c8c508f33996ce280a919f4387b3b032c1637d1417472af43c73afa57fb5459951740eb002b2a1fe92076c089470971920652c6afa2495a04f31578c1456d18c484554a1d963d621e8f38dc39852fb61b676b6fe6492a4e6918666c5e3862cde1dd0d4550452b3dcdfb0f82cf7156aa383f675e72866acd18a8546b61dc43f5932f5a07459f39450175397d8bc11a15936264d3b6550c8f0f9628a6b8e90d59c2ff6825ac8f4a3c937520c0bc5e100b50483b7e1130220e587a0ee8b54d37e891bc38efdc5032475ae6553a31f055d30b1b1c10b74763370d4e5b11ca90635a84b338d4d86e1914c4fb022712d931272c8443ba30a865a78750466c893929b6f17136713689207a6b7a256ce0d61ca23ca20b676de65f2df5d23fc8246a1da8d80503b8c9442289a2f045f717344d2172491e83b3b65d3f4e9e1f941fca2e0f214f705dae164a1e7de7260f483203ef0e782db81b2226c0cc7b342f10a32f25453072b86ffd6e641df26b3537254d8361993e8af1ff36b54eb177f79a0766cb4a6646bafa753aef45e30ce017ed5222f5b971f1ddc17353558b62e5fe3d543a5a5b6bc5ece735ccac2c4cf68c5746d8d005509c682603178baf2ecd172f1274c6771b64317b63e205f16f0821ef4ff6bab80f36917e04a6fd9e2477e95857449b7368388876348953fe245fcdcb715369d43359d2983d7cda863936e09a5e4e0402371e47c22744fb09518eb90d5885524a208abe1b39f2b3c95516821f2b5e46fb67055ebc1e67f23546daaf266e6e8ac449f2f82a5605adf20930202535578183144304bc035b445700264f811ccf9e3370387583338918d16ee76cb064dbadfa5e8390b3173bdedf03ec489d08438e0e59d148ce4fe778e54ca2b12d374cfc1b183abd973b1fe03d27c2d7c163803f1428cef97e0a9d18f2763628556975bd5d60fe131058119f960a1d62385107254520c95494707d6fc05e54453c19e1fb9f214291952ef6eaff2a7c67270a7c021d25d5bc901146de645b492dee7316498643930cf51aceeed1058aa972698d76e5320669d6333f557b48b364cd6d1d2d4955e1b7855a150d0510698a62169a5be911aad97b62c8c3283484da6503a22ea81e739ceb21c4642c45976f7306384f34475af0391f5a23035a1be83f7596609b4636adef573ddd99582697ef0600a11d1e48f3a06441f6d402aff58155dc23a73f0c0ffb77c49a3207140f7943e7c1c91b7beea12b68b8bb2d5f627c1c57bebf2bfee4394d54ce0d4e674ad250b6598c2a1139ed1760edca555e2b4d472762aa4332e30a2ecf0dd2663819f0355c4c403fc7024e0ea66402175d2979537b3f2b74e8928425795da3229711e74205f242043237c14734319d75d124434e5afdd01552072d756af4ce4a9d447b6da6519203746c3b4ca541ed70b8e8df7679be0b4083ea603c38263b6637e46f5fb335f9025167a316763f900775cc03310a76da034f65033dfff3761aa9e9003c65c3e14ca4682c2d19891e360bc6da1aa0a0b96626497373a8012f2b190d3c1ff7b81c1841d1a92c73a6094843277d43f6ad5c471c3ff73611fba54d1414035564839d45e457c41863c6b11562c65645ca00a875599ac64f4af85160cea1c8530c59f803be29c31f99d82c2230411517b1924f3958b6be1cedfc4b4a39c6b352e72785046254de3c6122113d96825f5f818596650090706fa98314445ae7de0eeb8f444aef9aa11cb131bc0e6bef8e2c932b8a595a341e4f3209c65e2fce420ba1c0971cd8210f13fe42d9380f117d132b1e0523bd66f218b9b3c14ae2592a566a765a1e99c4045e74849e39b210c45a8172cd67225b362b2b7e203e27ff99325516c72c20c03a1e3930d326e0eaa562f99a28729d73575c299eec00935be20ca9e14e5e8437af3caab0fb6c3d703e2b6962675797a3151d4bfb63554e77842612b89b357ec38436f4c0100c85684744af442e5ec7af1c54b38f5c3051545061f0651b74140e755a30ea585a77c9a52981dc9a58294d826005a7c72a96d1013051c9f60bb46ef15b9a6fb7754166ce3a0030cd2a7b16ce6cf5e5d334ec98315ce661d61bb7a30d4d9a8a881460386f3c32602a2649846d5c6611d92d94a4cd60e521cd6280402e585a6ca524711ac53d901b4d696189493af9f52340c1aff3659539f66296706e190538b90645a527143893aa40142e0c122a89b82789a17e1e682ea04303de7e0609183037e618903e862729603ecdac45027ed36b3df7f0533c90836d6877ee180b7fd7725874d275cdd3d1365ce75c616893f66050a5172762e2f13d8ded276107b1063b10f9bc3ee2f3ce48e9a6a066532d2e004f7063608de7be2db0131639ad72756879118571d950922cf78a0267de225c598b36a263e7f700329dae1e72eccb392b4a49da2cdc8d232e4dd97e50ef73c366f98e583b72d9562e27a6b13a2842990bde0e7312a12be373bf29356bc7dd0f665e423b47f9d51352a84e3354b470ce3786246f1413032b1bce5c306e860a2b1dcb7f876a08d8a61ddb59ff4dd7fc067054e34b634d54ea20cb68123ee0eff221fa685f1b2d7ffe2a7a1cb45e666a2161a389eb25ffecf660f8248c3efde89970cd171826fc8b83317ee4215ce0a2a2422a003d083b8b680ff21b3d6aa0892f64b1592c2f38b9be385b4c1607749b6e37f54eaa06eaa994776d72ce227467527625d4af742ffb593586456a5c342bc969fe19ef5a2ea11871b3013f576bab644979326432b7510f58eabecf192042a33c45b1965e2caa11265581cb1f450434466b4942719e1e7a23a605891cdb180c2dadbc21692a89002e46c47d4091372b742ffd886b5c9d980aa00dd8171f650204a0b9a53b22d3a83c9b65622be51811609c81b368207e01538ee1c30dcd451f22793a58254b86fb761b59d811e831d015c6b4ff2e3d9d80045be357119f3b49115d718d6fd1833768f7ee6f75d8ac286e3839d053a7cd8b643e3e164fffbfdc63f210d16458829204c6fcb35517a33a4769299d1616194569a7507642da1782259e8d8a0b807c6357d7df1743431d9b0c41e6ef4875f01522906cd75c0c482a771094d7336ffb6b46f0c60077fbb51c1303a86a4db6cd923f80cedf6cca184869c9ca622106a0be270945a00a339916389f4565362e770f4af456d6293c93f62f882cbd6f76fb4b4111fff85904ebc271a15de1406fb8a133de616703929a7a05763e3807759e5a06b30e9d0ef432ad2364f5851ee416e42fd90eab22424d561fbebd444523ed063c4fa3b922dac9f94983665c5e7520327050e67076ad27743b2cd2b6350c51da1a6be86f139d2df00ae5fd1a6b4ce9ec75d65ceb5a27769065afcc0f0d1364f533d33347568264b957ffb96e5b841f9a6db9c510412603fe106a1dae6e7828cd1a82a1a9432e076201b60a25204bb2ad023770a800326ff663726033173415261664a5f400c3e3e52bfb8fa76a25dfef6465056c1ee8c84605241c9e560dda752421e5380a138210237d22e041dd49c1272e0de835d27c672b57664b3c59eb266f57fe9837f802295d71d25b26e6f242042757c3637d996a0e557cdf5eb343575063bcdb53ced53357a23a041be01c164eb7e057484ee62f63207ef9550f57c130315636350fb53c4e8f923075cabdbc05bba5a8384189834e3838ba4c61d557400a398d5ff1fa7275ed256e3c918bcc29a161fe3d1e19202b162e92561c514531be676227d9e6f234f05f360a76eb410fade2d004fa99485942e6ca6b583dd31481e7c317b56ecf5d4e6bb14e2b91605d5a0b393af9442b0d72393b108d58c34676aa04021b95bd669ca9d9636afa5a06f2fe0e4f516b5461db2fb110d6a6f0270291880f371c8e4e3b87e800b70dd170e7558c77c127eb1fbf76ad4ecf7f26065ba4c713a5bcb5490fe7d54eda4c2318158dd41d14345a653945fb337547885b426b8d4b9601b42d0fd3df6c24b95e4c83fd4b10eab0392e0b651b149aefd64976513009fafe1b0554c37e76312e075148e04942575244626e44c81eba21d40e24ab610bac6f8b7532bca84414c0964d1e5b001012f8815d37d4522885cc4f422604d357cf733c0687b5aa46ccfc0109ea0a84760d2d8a76092eef2c91ff976bf95ab073a5ee944446133d3f0b18995bcd3e6b48a08e9c29fa968053e7a6645ba5a5b159cc5e7e0b9427421ecb9e0b473cd96f349006d225f6ace010ac59a74131f741196c667a4f2cbe893fe77fbb2bade5d772c297b519ebebfe2e8b688d6e67d5fc0bed949e704f73a9542886e81d25fd770eda74fe5d0982c34f7afcfb4c4efefc46af6c50561d6cd74bd55cbc28f4a83801c089af066c833950b86ab200ae81d16ed9d88e00bbc76f493bb7831c3f97d802b27b063464e220322bb56e03dc0a6f3d3a7c85530908ac3a5742d65e101daa2d4e9aa51ffbe8050068fc9064fc94c3522e964e29504f5d52b99b8d400c109b0b9d6bec2bef6e73014e551704116e61035a0dac3e649af860ef56b215f32313694daf5a7786005a4d1914c453173a3d727e5eb201ad3df705061dd3688fe4c12197b6c5088dc4cf2bd9efdc10cee0215da9a55d2b44e27433f9a811265f60e710de8ec33957ed2e6eecfc3d3d54e8415e319c653661c792620544d26432c09d0fe6bc1d21ea57d0631cf3f53a5550756724487122df8e4f27e3e3112d88638173836ce10129117f75df0d2d6e17c6f7261cdb574ef9c853750979034cbe76f4005565210e9b0048661cf25758569dca049273f92008a7fa5ba33f9c4de2a27a30701f970d74f01d3dd66eb05773c8b25b7794af4eeabb162e7d2da13e65ac05411b657c5582ab1855f763f917c01f444a1a64100ea6acab039f040e197d5014398d46d3399134621c3eb0b13403a74f5f6dde982c8eab13772a520e6d4b47d748c584a223254bb27476df1333741ff63e8da0206c590cec32c81cb775200fdd65068d1774e1102e13759d51355801291276f05113ff6af63a4e0a6a47b7eefd19f472a3716b7d1616949d6965190130185b248025df66ed23bc03ef08816734705cd8d9327d279122f0cbcb2a7f60862af2a6056be5b190505791c45f72e6122fa1316e17ef34615bb122112693db0d33f60b075491126722e22718002d996661dd5c994c6a7ac364d8aac93103158f0e0e05db00fad3be1af4062f2c9446fb448e0726060237a932b9b9e761420d06082eff376dbe04ec4b21bc81163d08001361f1224e39cd2428762aaa3aa9ca347625e26e4af5939c43a56e2c093185693c80b1ce37849794487ff93f1a2e2974753a0c603f96ae7046b86ef830cdf31e67549e78075298445fea81c842e260522ab0cdb54a19e7382ba96304684d506500dfc3142e0aef4557ff3e2f4b5fd23d19531705688b166d246fb9472b457f88760a673426872df8475b501e04c7e089763bd397323c2acc156a7b81734ff4c852a03fd94f8e41392eae94dd2c03324d423b459251c1da2d48d2d0306536eefe383e007d44d7f4ad00e5782a5a5b874572bb6d2b431a476c3e1d7bd513a5e7fc590e6e9f57431f54567e78b750a00162583861524b91ba1236561e2f5aabcf1c189c09c8727d65280424c2a63b3624972f774d8259482573583c4f2e1ddd40db6f3e213109e51d4703131fed2671a56d67aa44b11ae0a7194ad52fb77364ab0e311455b432d01bea6e7bbfc70a3faf4620a89a336c9cf7b0132fcddc034300442b95116771e4b2f5356c92d6343bce1115f0c95e1a7014336b8c9fad29d585b53fcb02820ae1537602e7a2e239710baa59ea70776f18fea571702fa470cebd750bb83908670f3d5c25e099d008f6a4721821d57759cbe190729fd95718f05e1033a32d7813b292174c59a46e355054f305ddbb752498eec54b84adb56b6cd7c340bc3b9d01eb26352972a0315e5e34cd6969164c0a0fc0a35df7e8bd63b7f2de56e845603e44538335903e9b5a1d640a725946514f03e2730122f77f254afdb31da179840ff4e5824d6f434a4c71c3ef3b460f1a777196d0023fcaaf48952ee15bb8f11209189ff701cb78db2a398739403a7aad2b774a9a558299ca055b1abf413851ec5dd2556718eac3876c1a827d142dab0519d6b9334f84cf6d33217f973d579e012fee14c372cb2b602d3c87b32c2a03ab47970c8f5bc70e72333b36b84f5b10c8389b1b310dc8ddde5dedfbd940d44770268790753c71348b42903c224a186c0004a5f2fd1888dabd4b4537601792df8b2577200c36be0c0273b0f3284c8efeda1880addb368407142d1567ee692aba1757f994181caf80040fef48ac52370b1d7247590b4c0701cb655470695e5c4d58500069377783066a742dab9200c2b685599df08659e8b97219b5d68c40844cee612f5f315784f0d95d870f12658a7fa86aebcb61038071e1760d56db35c8df1277af69ba672f06c2667992a70daaf3c9354075261079828732bc5150106eb52c0194a6d63c7a80e10f652d5819e210f9364a19bb74c19d0041b7853915838db066f76b0c4b9404f974b976c0099fa853349a118a6a6c448755ac36e867e82ed108dcd0d02dd418e43cb41c9855b22fae51efb0a947c136e85970084d6db5a7b4409ecc3b6c198859520b1b224a5e5082074ccb49086e39015e3298266636e758339f58eb109e13771eb2497f5c7f346517e6af216cb24ed01c0755df448c3de0195f3ea81eb85b3e2295a91c2654c99e6f14e68e080e914d1d56f84d02d33e256ed534af7642442023540e5d40d969331b11410c5d8369cd14246b9b608f6c97179329ab3eb714e92b7e0f2f3cf6ba5538a12e3d71c6ce313db6d542472ef09f57403bbe100977cc164ddf7d175ce9e041dfb4f456638eb0417f02b26700a46f31f47eed330e134a60e9231a0f4a30024142f99a301a751e1b1fb5a663e91d4c4d911a5617f79f18265dde5122e88fc134b9518658fb99405dce0f1e33e388ce025598a04d3e01862b88bf8b110ef19009e6164f20089cdb6bf4ef19550030e370c2d2e568b7e0af3945e85816d76ae40b062b5d4ca9d35c2b03c70e56e5b77f1c0f5eed65e33c1d073af2091e3844ab5a003fcb52326843241420c908ba8fde765d4ec950bcc4d32dc6a25246580fbe2da86f3f0959b87c28e28a450af899c56a13ceac0fa58cf51611a2a5728db5d563ed1ec96e3447015bad9ff40062e5767638555a4ea203297470fefc493ffcef0b41227f20d32b9c67e6219e3bb8221306f750576bc8c4e61799c56f6312a8684e3f725e22429e934f58f70975e50eda075b8f14494c388a207d28213b028a9e2916a1a45225c1fb19de0cd61d6a1f4900c2d2a2015bb21977d842d7011ba68a18246eca3357994a049a2c9d32feecc7348aa1df031b3efd1743959d554e914b6b09fdca65d223151467c2590d9d66455b27edd2399ae4c458101ad419ec198a3c98363956789e385cec8f2019f24b800af855683f86e2f637cd538642370cf70918aef25fa563c811a605a806d4effd7069b6d575cf3b190119527f15ad9fc20ac1cc19204131a769ec9e6e5a8718c405210830702b34467712dedf0eb3de7a5d8a371326f2bef93676f4d729282dca3c8a5c475e64441c0f4da7364134336b1fa2f7cd69bc2de101bba45d130d3b8e23dce36268dd28e00067a839148b2be012f186472d44f7531a385aa84204b8376154d0c907d9095207e0e54518124d6159f66a4d1ecffcdf4aed341b4097dd34480700b138e6224627169d617460a0692f75acc417652455115df10924d594a33751023558eaab2700e63d3e4b740fef334e80712dcad0eb3430a98f1dae476e5561bea056bee86d3651b3a1088bb50f4be312ba0b6eb14b621b7d2d1890ec312d571264350d959b03d68256060e987f33e75a67024326021a9e954a7634edc5482c1ba770be954149163d271122e636661edf8a0b923247109c70ca51c89e1f52e3cee20ffc89b43a3c295161645bbe463f66ae2decfde22986dd12404d131f61d04eae61850b4c3fec6be3675e5faf541370340cd5133c72cdc5c025ef15353f84f2013a2be8e54f1e438562a6853f13e08fd612dbe140102d01d763f35d2e40056d096faf1f016283360524285a0d30e8640054e930f42bbbbbd33905468715982b33406a41ff6966814868b59e1c0c1389b92668ec5146ebd98a313d773550bf67cd66c13ca759ef9095190f3b9b08b1f3962be91b665461de4c3886bd6718317f9a597a6bc8638fe2c167ee66872d00b690389ed0ce4b6628cc5d3900b53e437b2249322e323a803883444a7fc654534ba00369e6070020e589009fce8e53d3779d1f8198f76879c80137e387ec3331739e06fddfcd4d81b95774d31ffb05ced66009d27f212b0b5c9740a524fd0d2c539b5b09d97a05ae6ded5fc6d5613b8142ec72b398b3232dd57b0ac3bd6b19e2149302059b182395c903016bf9096b0d06aa0b09bc293bf57103404d1e496e453d854eae29aa1effdc1b0b780d404f5e14b44843f358711f2e760f6375246a9d96a83044d5dc181a3c4d64037ea863a472fc4818bad33c09127313eef25503b1cbe110ea81f26ecc2b853d709c275006d7086ddc5fbd392c598c2f684ed93396ae16474e73e047776b8856d572e254a8b6b2214a649f09539847356e33345ff5505e4d6cb89f6206c1ea30de978828ff5dee30af03e108813b553b205f885ee4f9f61097e0c7504c6377453d37232834d81f36bcfdbe20c8657f3efe94963136f79576e1472d1c2a83893f47db3a54ba619815a61bf137ec07cc086b60745fe797131749b0c56aaa58c7275807ad5b267085270c5e985bed78c84892240a371201af33b551dd47ec9fee2f18b4d46cfc7de85940d4611ae13ce12264db0b29bd96b262519a400291126b132cf33b4cf3e43b082f8a2b39884eae26d7d96635aeecec689f608b615b95d85a1c47fc39cf2cb735940cea044c940b04462bab2036b03123186b6759de941e0357787c4753740548b6a5086e39cac5368a702a0d994a366491c8ef4af90cea0d2d01f60c1c739f0fda69ff2c4bdba6430f4bce08ca689413ee60b74daeb98d58cb6bee27079e9544d224d2555aed1e3282f559109e096e224eeba93c84cc1f11e882d84a304a743437636c10ccd54267b45ee57328d7d8608338d3025574ff7662eee826d89c6e1a1a6c9d08e1c5e91e2538f02eccaaff0bc477956b5f3cc4256b920e1a49c89665272e142e104ce6314fd6773dfd60733763e7166eec25dc0930306808d334ce56b08f683a8089a51d19f75952732ebb064dca0040b6d70c6163e84e1c8373f8206a8ec3012e182e65d19791696b532c5d31910000205d7375814a6e296930ae1bff660d4e1e3bfb110204e644da1d2b02fdcb7d3be757e354d016e47294a88a305f16894fd4b1d8167504be479ef2aa71e225c675efee630f7597ae6de6b8ed2a9324482fe503d316b821f12e38290464fb412775defe09559470cd13a5789e43a5cdb55a638d9470242d030d04cb4f6a97f81e0e5c092316ebfe12560106cd6a7fa36940ad97571236c4d145c0eba868a45ece52aafd0722071b970098d4976a29c31e12945d76287009e02f1176117359609c66405ade40ce8eec680ed8da28d5179f1ac62f410699a1a871c55bc056359576274e271b71cfaa510fc7b4bf1f53925442dc51ad1eccb5946cf524924d4f374e02fe86fd3b7121dc4818a93c1566d4764f8c94086b6741d1485abbe54cfa2e624b4a605069fe63b615f457db551a94dc3ad4c7ec4f2f8e8514367bfa74c479d7169304571ef3c6955d13ee145d1d273d734bf2a971af1d2a3099609e043afede0ea3ebbc2a899ce40057ae562988f18f03bd59d347d52eed51091cfa0e0af06d1cedda4150f39705013c99c165df31b929937fb6714efc7273f9a1f126aecad5739cb9390de9419e15e51fc948f1520402c0a42e336491f60e720b8f4373de2128e67fd652b797ce32407725348927eb280c498d6781f43f39655e0d232b16eb62593774247242bd13eb28264a7313f9134524581f9fee730c43df1955c3a6612fa4c43570417b6e480e69f3004d01dd1a75732260f30af55ed3d5434ebc2fe819e2f89b038e6f973a64639929b94f0a4e161c87424da4d4677ead9c603e526b338d21606992d89334387aec05726532063aafe61d0a61bf0f2b35356610746e26a8a03e45bcbce7228850905471bb36273899ce772dd90c064eecfc3d3093204bb8e62a73811160221865b026bc5e6e159b72d45577adfa578a6d8c3a41dc026e399dcc6bfcb90f3ce173dc1fdb3d174c79d3447679cd8f23ba0fcf67a49a272cb8c7a763e1b9b65b35b471584b06e216925df551f02f827037c3691630bce01b90e9fd75d50d4c73e1387a52b5c9c508e1db64335a26e32a3d942675a3460e6e3ae12e281273f543b158ef461e69b3680e17c12181c627375fceb4501c15b4264b0a6b1816a3d907dfc3140131a9a61d4f4b8553b0e0f84ecf9d6d44969d875a9611b82c2ef4672f190b155a1905431bf7cb5e13ff649b4144f4a45b4f6d7a032695556cd2ec3e4afae6424890aa405afece642d10cb8128015dce2ce1daec4f66d1df26ef69a50fdc1ee5407ad8ba339743433c3393465f394a633b3395a64ca278af51c81e73505632ab4b52f0d24740c43805d3894214be5cf927f8512a6d9a7c803e685fe15c49dec811c853083d744a4b56fb1f333e1c509d767e9b511d03ec8b46849586443897bf031fd9221a0be2745cc2e37960441ea6127681152f3b1487637126914135015d6258df8655ca7e65766ea34f6adbfe17608915f02361d4a514440b3c374aeea963a4416b3512ad4109ffd80c4a54655e16ceed595b8009466502cf064da663325e35a42402acfaf23723f9490f50c96a242c36864ac8ef05062ac78904b95cf07586a0170f754b150805aec0067fca4d153482323e8439a632b0681c5a96b5bf5e290a972c1ec2646788e7584ef8323d0de8890966d340630d5f4e645d0ed2c94d367dd357ffe4961f3e0cdc018706a6226bbefc4cfb4d6176d859be462f67ce622aba6f1ba7d6830d315bb4704f2d7f6ecc65cf125119623fec75c650524da616e50633227f2453434584980ecc0e9057391b7b0fdb45315393c44962aa415c1ff042010e27a6200ebf88cd7462ed7e6898dc6b445787f85909403b2a85dceb50d5aeee2bab9dfd5879b5db528ba9207309162e76ff6d0a658740c77674e2df2db5bdc2325b28c7581b5cfd040e79203a9a6a2f0422e344428a497554990f7132ed9e646c93e6d14d471b7853f4dca85621cb913c052d366e8580823f6a593060935215589ad946369cc5030a5f062b537a6124579f53824219742a502234646998a81c511fd5316a85533751c160e91d93eec91456391b700f53994d60f50835e75edb0e714c5d3bc4c4472dd2e31a2bac37d23d2f676b16f55f46773bb0f01fd2e0a654a3cf3d0ff32d940acd3545215e0c5307bda746608d9a243079420b51d1659e4764667240746817588163121c32ed3e57943bdd445a9cc1169925a501ed7ea31885bdc30ba5a8376963be4c376f3dc46c844b2268c80c61703a4e464d29c46b12a0b4d808ab82c646c087770708bc3d0262690a57ed3e10438651906d836a3a12234dcf32891d34524f97ba1f5d0d7c190e6a784ef2a2ff02fda5f034f56d5d2974a38534549fe63cfb023e6458cc8d136712f3053e572158e07d5751efa7350439704d5c4d33bb2477f0836b3cadc972cd7945174ea4f10a179e046655931321e9593049110bd11467314d38b6552e6e7a64aa2bdd1b4028a760a82e984d233a84fc390d94af9f4a5b20d3007d3f6f23045c105ae49c1c5bb768765b89f7d320512d331b3d548a1af11def5c21699d618c58730e948d7344e901581d74a73f4a5c287333f2200202a7836b2e5978b12133d43724da25931bd867664810c9ae2500c89e64caba3638d2563867c09ed22c2619825a81c63b14ae447329f5ab415cb5631144472c8f0c6f0e6453ead32d2c31e39a372a23a50852ad9e76873d46101643b35a4160c124d8ea626d97ad0d21a4206d6760d8af04a8854c2738430b3f85f93a52cfa5a76ef534064fcd7fdc15ed3b7e2d2dd92948165f7855c47310183f751a6796fdd447ae15104bad5452638932980d59861a61705b4a6e0414df16b3aff81d31e6d715a98edd63df622a35f1a33d4dc9906615cbffcd010dd90e60f0d1ca53f3062d03548953265e08bc380111df0e0a96934a6bbff8659e15c90366d1436749f25c0be669306be8e8a92506b765068a6f595f7c2b2c23b99e2f705a61f734701d5850fd75e4755eebb0386f8b644472ec3873ddd28f25e206e4601023ad6e65692160dc2c133b4cc4d645e5e2294c5f687b47e008075b70fd18398137fd3d6031020f9c2f9c41273f6827ac90684c5114224601865201eba102706f860869e2dc676c8854475c1595fa21e73330243cea1f6dd4e69e2cbc7a551f4d175243cbf2cd64c558aa5feef58a3913d697145c6bfc30158b2775205c365cefa89749987ccc1adf850e3476e50076bb85dc30be0d1e327c99ec0a1b68150c6191c228529e2e28bba6fa661036a967f3dce528514a0a4f9cb5ec2ebbd4a92a9ebb581a593da80faf6b9d2ef280287194d96076df62e31d7c9b9171debda3540fa19f7197904271b0fc042a7cb18f67634b062e7d4fa40f5eef346b0171543af9a5ee0e1fde451613f8c923203e705738ecfb60532ba041fe03b0322b2d2a49ee65863a69503b1dec203705569c2c2ad939f7182569835154407d6506eed3509a321033c467f0246519e9721455651eea285c235b6be1407e6af5470680c3039ab8d619f3119f0183fb4a5fcc112c27fce63310181c52212075af763047940d7f3fce0b912e2e11dab9de2d90890c638237d75c103e4f39df7a33441941ad3d253b1764094bd040e95323459523a019d6830e11d09992778db4b46bf42b2e1f85f384310fcd335e0acc0e77e3e1c855ecf76c235ce0c14f81942813496e2d699458b16332fcf52eb004c70843ee4a7786a7761146f9386769c6495c2a73221839493727a91dcf73f756c55c458260070b6f0333f5276f0e086732348753463905c9d84cd7944f68c89c0a08130aea3350de413603a4c816f8686f1e8f922f6c4b623529899962622c1a696390a7ab50af7cec1b53afd01ec1204039bce5d7254ff37702c880a9084921e570adcabb2d4b20cb2e026d2317b61a372a39023832c99ef8452e767f73e557ea1e176d8d51ece51202d36e061abb4b9f4db64ba1246f96d01dae69e257452974127a95cb50a58a193c7e269b12f6fc9c53ca90a069ef866d3da4181122caebea632dd6593130b378761a339a3d615aaa3172b08b27217125104d8b05640d348a1fb569ee5d19a18b4bbacfba6b55b1382336e2f44334a1c456bb86a16700f54575413aa20ac7d5e259f514950c557c2e37fb7b643c279d9532e3f9d43b9b78703b3536c6041378673c2f7eee42eb9efe49d2e9f465058012485c1aae44e109906d62376b1c8bedde541d0fe35882deba1730a8f65b8ac8c2757238df46a416ad59e4bb77037b996d106fd6c84eec17184d370e81500cb576296eac2762e7c9f151fdd87645ce8938687dae764fe91d715f729a89089f8a13532fe8ba1df5b0b904a113243f680e9347f6a5536281ebbf02c546ce5986fcc41610c8516f00f2404be4a5505e021c5c309a57c224e1862c13fc4db36704d8784fc56c2375ef32956469617b2663e3864c77f0381f78e16c005e5194703c04ef573fa0a871cbe31263b675bf33e5f6ea7386874a06d44ae245024e3b17c9c0de6062ac0f4aab03065561f3291fe4758f6709dcd46191ccef5ff07fee722d204530976e0747a3d3952754a4b24c7cf5c77714c8ad17dd608551b644af578acbb618f92f0115af9f955b22335165eae07d1a1b86d150ad739066f73dac2c5e31780a9fad316c7e46a1478c2a1c15575be871d2e7fa4ea402e202f5d6d60b5fef6749d0423a6e554d995ddf1c6462e8070b779eca7f08af6e9c6819744d17be53952fde49ae160456c21412bfc9516cd7d755523ddd029deebc1b14bcc8419ba7fd4ef208d8436c6da50e6185c11bea70d3487b7c135d60dcc25a4b1cc53cc924a93540afdf42a0ebc63074929e2b42f8fe58bb2443366dc6115616dd700883f69506f0d1b96fea6892717afdcd4aa8cffe5d45975b4f0ce73f474fb9594e178d2845e6cb33049fc73c67f0046b5d595d874673581003ebaadd613871951862e36637e8a18417aa4472342b159d2dc6f7d6035f6fd915ce440e091195b834024d8b0ba2f0cf71d2c32a050449415123f3bd5728faaa70ad378e084a508602e4b592118881ce4b4afdde77373fa218738ffe2fc5645c646c4c8c6993c2eb4ca1ab4e6c9fc3a175e1f64874dc90db0f684ad14377269a3de61c3f76fb531e533ddb381fab12863d532faf656ec41d12745536446677c82d92c39f53eeea801ffbbdb7602396745f26fec63d24e2020209a2ef1e46d3674ddcdce3029555f01db838361d13a87705accba910a44d496566599119a84a7c3e9c8c101bf851576e80f064777ef1894a46a6554f19368f03e7ff2d2eab19ac5d7947d972c310cb5deeef942b4143236f09a28f1b7fa82223e4cb4262476abb4029243b64b993af2fba32103497acd66a4c357313b1001d0494d544415f1a261b615bdb0d9b589609c0a9f5414f61db4a1b2bd60712023912d11f5508b009f74b9ce7f529ca2b87472a0dc130f647c95bde3bc81acc2d003a29de8a3675ce3a69a122882b3d3d3a2bafaf3007ec3eef2bf4916f3fe405ca7689d6e741f3514b74bef9546f650346519ff8b675a974aa6060d7e560109c790741284564e4711f5d46b85631395dd015fb86b3137444f31e5270392cd5fb640d42023071c87c7336e905065dd1096f39e7ef2f635aca3f0f31c323111c8ab40b30f74c65d3687123b6a1ce3baa8d650c755b64110f358d2c3c55a61f65256c473ac69a4d36d312117dbb9821ca04556a01ee2f73d8415375ecadd26aa454950879fd8a2393194342b8774c1d709e344cc970a527586d8824e1e4f528bb61177631bb8c2306ea660370fbb32c57e14b0e2da6773ad50b531f6cc6cc4b7dbe2437a6d4db48544c8e322e287e4529d62d531d80bc6f5e6c4a7300f253512c63b47293191a298dfa8a5dd5f2fb6d8e78b875dff0c36e9a29ce09ff0696361c701869a824047515c34419deba9a5179d3740a2c8f380305a09757c5a7bb2d79fe2638cdcde85b73c92861d1b82c33836e7a498ed4c144512e5736f9807366ea5d9a0a5bc5802589f49a439e5a9b68597daf17c72e02406154863f9e76f768854b80426a813d67226e4c645aa7262a788e17237523173f1ca21d723be3ec6016a2c220240f3b3688e4df0371f2444cff9e7c0326d9af49f7e1502b1ffca744e6601665afac5411f558d22ff02aa942c314bb4483bcc94cf4d5fc15b8b9e46649870a075d2002308edb3648183d8744ec769c763815654a7f0c3265cebe000a2442d427244a8e75ff684b6311a069740367d57520825f0807cc14349e268b016c905813ff462c13fc3e2c1645b10d4c92d5084c5263dd148990042addc19f48633c2a35a23e193459ca3d4595947649f6ccd5133229051a6a5b5758c73e305e49f6ca093b755a550426650d6046324ce768116076d68a4b23fc5469a8155733fb0f1c184c45f868ca512c42a4886510a4407c76413fbb535221d1680ac7e82215493f77fd5b0e2c0a3ca5603b2a460f1a43662af8669664b7ca97470541bb0439c7f042d3169c2a5988f577ba03e814343615051542981d71ed7b4864ae246dfbabcc608e68e6677daf0270c45ab80b2788fb6c9690f25099fe9919006cd13bd10f9e091847d956354e1952040f5c42f7ae100db3fc1243511d2e25692ff1013ed9900a8fce841e33ce70555d9b9302139aab6c8d465924f52c154433b333037b22436cd229f12869e5e70769411748958c1139b16b22365633ff56a2faa32d4964053bcd98ec1413d40e0288b5f85dbe165c0d59644d2c8b352751607e96717ac8824ecd0dc36d8016da661b9e5953c37f674739edd744d31df10efdadc43f5bac42328900cb65efc4f05096633e6596de640a8e8036754903a9386d6af5028c9e5603cb17a71bc4e3a359e2cbd306ce3db81f9e115012bd75175378a2a5626e04e56d71bf534588bf1b113a9135021771192c79ee8d4078f8f85f09040f3a01b22e06dd23fd059b617a0335dd1012afce8f47920096471a485d2990600631ce53dd774b48a225e3b57e38916e8d49047cfa264bed7e467ea5214852fd275adbf0f340ad471b56bdff792de391e23c39f1f565ef13004b7a033a3af4f7293cd8a724433e406b6e65953b11cb4500249dbb4e31a72ce4068c2851211304b9167b58f5381a52c3485f303673add3b07506be0e1f0278081cb3d7a468cdec913a360d462150e68231089bcf4ebc206f641512eb020bbacb670f63021037fd694179c79147fcc43153a608ca71de622e5f84e8fe5e518b4f628acdb745366ff402c4f5f0541db9592e84ae3f454067857186b6c944a63c4121376d265251ffba14ee9127290957a72f2fcf600bd117f6691a3500366319fc6b122a8743f133633fe849ed0d4b04fc676264435008b7f92674db0c5c366626188d0d7e2a26979d216f5bc93526e8e33b59c50b608fbf1544a9afbe03af08ff314679f93ebf9b24407104a85dbdf1351b2e85175e482807008ce97c60d5b6b25ae2edd54dccda04710114b9691405880ca51878370c9edd46b8d27b625be34b6e8a3c721a88e47f0d590b223119f6a873d7da37277a93344923b7276cd7c3441fea96d643edba8944c34f42753bcea73b2bc604765d0fec6aa104194831356147d51bbb551d11040148838623bbbcaf696d326f087e5ba020a1599a32805f0c1e96b7c85d913d593b41db9621b8cc7b2e15dca5390c4c374abe67170b54179b11a23b653cebb9fc408bb398306202195906f97f0c4abc471afe01815526979d4f9901d06ba9c44f02585a7b291a79185490ebb827cb6768126918ca0e70a15171162dca105b6d6e5405d4e41f711f5e610618b70e059de92946362c630b985f4bc96ec22c979371649dde4e117cee0616d8a4390ae1f8e8679316184e10c233023753d2628b5eab10411e3f67ec042a4c2ca2ec5ff5ac6c6e8338f060c15e58540a33b135797dc75c7fc6f25de2a6bb77bfa0c714f0a6074867df7454486e083c932df336760a49251c75fa1768243f0099bfd41022a0491078fe8270aeac650fd6ac9e062e365620f9d96f14ee27ed49841ca52d0e19a46170a27c01c8dedc534a78f64b14bddf1bea39ec06d3bd6463fb61e4744f20a90510d4af11c8862a01afb9ea2b4f8b0564f460836f793c26118a9d4e0a4bb3055e3b896349b2207635a0c82c3c58dc3b4978ecc15534ea8356aa40855c0da41e230f2dd734db1ea0227aa8eb5a3cc66604c9c490539de373391698c31033e41156f744d524e4fe2c7706e43e71a3f14474834b801b1e483318891ff13a4b45a83d5c6528693658c63c7b92f13ea706ee460441b74119f28f20eb23f9261dde0302ab850564ac3b9d21b292e23f905e0d4c5249ce58c941f328e545f54bb4be4175f518a6644955b55475b9001d831433264db1cd409a25f646a38db5034b4ab01b6b6ac63afd2e9343f0d532408aa5d859e0348520cc99606e8ccc801143d4f32dc9d6ee742d493628c1cc113519bb64277077fb2d1a9fd050d7536171064c046006d78568077eaa4f5e3dc10c092e2d4fcdad530a1e162c170cc8d6580cd32e53d5708648dadc62313d6be763a07fed4d3c1ef36c1543c46a3feae52ca6ce3d768791cf2e6a4fb545a319c72eefd0892fbc3cb6486d387c20bff0f56e073b191f6acacf50b1c01c1a5631885b4c454f024e51ee06db9b713ae1ce1d7046aba524af51603d134cb612866f9148dcf5de69da96134a561f857120d2612cf5e47f2bbfcceb74fc28c7204131d66920c0cd04ce6f9842c0539032922c58462a488041e8ad4354e45f4c22bc323c42148cf0397c317530d0f6542e59d5fc256a2f852f919f4f2d356c1c76c817ad3ce55ba60b68f8346f7977552f47d45d1ed5dcaa39babf9501e512d061a0329118bf10f624830b4023efe67d3ead8a033c409de32e3f800946400c56590911de17e992151cf6df9969d245e2707763e529555b90281dcc441715e5305992690e317602bb681f5d5b22d85bab0561af5868098d303b6f2e365518bc4159a381152b6db7d2417ee6936f5b9d0c57a843d445e23a6162b7212e089328f02b7fbbe370cfc6e13559f7b3190a036621ec7a9f1930ec016d99fa260118f13038d758214924c9aa19b861901b83370e32704f152624e7104e597b4842c540290147478d644247ce0e0e9177176cf344494635fd49d9748327bc41c355f813ee13e894cd5f81eb616228a6d70ad8690e0cb4090b2da0497c4f63033f5937c2a22c7db4a55e4b1a660aae67e75101f7156ab3a7f9331caa9605247e2c166457191db8798529920e7f5289ee264daadb0d081b24ae008301444865f6c753f5dc901ea2fa9d7144a6c84880a05f2714e5d7549c501e4d9acac5042649a6461f36ce05598e941a2108f44711f7da6e1aa0d23ead3871768e03e31c69b4a874dc04d80b4bc44f28f3df466a963e744da40b3336b5e61a05f70c40731c760048031ce34d8b21ef67d811f02a467900611eda9543ceec7018a9c43c287d2f2b34aeade64b6e8fdc20fabac041efb5a53212defa43ead2ae4e95ac31227e992c16aeabec1e62379657ec48f040920c4113b3f7e16c324d1310056b271b3cb29328b29f0a5a2fc8466582bebe1caae3c31b76520b5c58c4971e092d733fdc3e422191325a68753a1f2269c2cd25c08b7164ba122c2b59b1bd24aea88f2b23b8f56c50cbc72da0f2404a1a216b1cf8b2b4512fa44b1b262c2f278c2ee06608d7675f03e07714cc745f1f64daff3edeed731f16ee4b49792315330d49175174cc344fe5d7a169adf21718f926b85d3c6cc90a6ec23c12533eba12777c2d5665b2732f927de81928881a26563f0961b847ea1fe4c346419c45e419aa0a0431f56b6c2d17410b69b1a35b25e54d40062996a93170df9c45d0db2d696e3f4676f2be88065f38601c768c5a1a94f9e612c4659353f0aae051a64a011f3c4e7d166bb8d25329b4b116f4ff455db1248d17c42c4b636e34c76a2bd0d134fb91a677d9e6c7073e006569e64f385aab046d6af3214401db2a7629c684821fa62c0c0372af9022ebe36504c878a052875439043ff63c7295a34a34bb754140283cdb7641468a29be5aba52b3e79d339681365ad76adb23e01c8f09c7172210734150403183e723b3f3113d7b0c8e6dd2b11704cf95124331473325e9d9c14115472d13779e8c06ed5d90367596564cbe1d51407b569f469eeefa1dfd51f64c9ab6ea2683cb69272179fc4dba5c220ac333c6194b6b11106a91350624cacc250086942778c1e42c3d7b214b4fbc8973c0e6200e43b52075eddf1c4545b3813e52af9b1bb904855fa474622c428f6565ae9aa126bf0ab177fc4dd11d1d9ca90ec67ae6035a5ca6286c2a6a0a4f72ec24c1272f6f9c57de3eddedf22ddef3715c13a8904b99bea749453c4d503aaeea275533802ccdd1302d8cf64068474a6411ef8bc3552d62cc46cc3d94569edc9c5a91172d046dfe51341adc3b4febef6c484144e65fa2583a5b3c85256bcda7e814b515c34557d945341480ac53937e1b0b37edfd19b56fc30f6e47eb18b214450805400275d5b6386a23862b4248650b034aa9e707100a7f7522112f4fd47be15e4dbc7d0ecb064562b5a28335c0094924dd63b015042eed3ece2ffa76252a8c7225ce976e5500312672deed0db9c65a45a0db3425f8a2466416bcb5626f05f4100c00a0344969c052f77c4e2919f1537359b46705734b8c4c9c3ef2040cf509768f595c6932bd922a47b113093f08343feb9a5b6223f560379aacba39b1cf7b633e412d070e03b470f15b2b431dce513963760b508100e2521421fb42201a3a39ed7211496e0f7d31926145051ad64722a0490b667b42981c09d6473029be7a428baa292e25e7df29211ad1681ef28c520112c6304100603e9ef0df25123b1962477a9002fc7ab66fb5364f6b888d19092110412edb5799667873e64b9e4baf5b89d3426b024acc482d29f04afe2ea40ec0250429576e2a265b7a542667f5e12b3a079f51a8decd04eb260405d9622a283777d96724dd9f6bd4af8e6095d86663566e1b24b285101cd9937f3de291581e4dc3d84375d5295b833aaa23e529c562481d1914555c886d479ea6288763c60e7ec7074fe427764e0548a51469b21d2f6ba2e707bc6cbf5aa3b9a50aab49c63a3399c64f72a21142fec26d167040e22f5beb600a2b126707d8515d1729f18d3944f1b5049f721a2a5375b66e852df264212efb4212b7b8617a79183b02a1431819049d28107a3759e1de72086b612172b2d4ec1a68b76c6236fb9f3a73bf1f1d4d3c5b754f3a9b041a88be24dd6d4406f1e37b102bed35011c282b081827d8443008f7547658c41f6f07f10c6302a20fbc02d5416883854e00d22f67da752f1af7858045ff7df108728bc33080f612233bfb58742dd9d21384c3d93093e3150e8ee32d1796c6470a4810e61502d62a103f80fb59c0bbe65ff1676a0658a36720661b1e244f7b180b6addd1642f6df36f529b0d0b47f263444cc0692e7e29ef3c92b57c4244315d1068c57275c14173613961e254b7d9732e1ae2934bb254db3217fd9915105318487d81e264894f741fa9cbc84136825c0ba64c143590a9852d9c0be11c27744367bef69a3c6b2ac719401bd22450276602bbfffd6ce3b7f90076d2f275f363ba71b697f63010b83f3f427a2d6fe600da1524ebc67311a8022ee4e10539b3a15516447cff628820ab180802632ea637f544294c2750b13d8057cadd34614b7326662e892d2e9102e62f05941d563f7a1d59bdca85683546a017be703917a265b7137c9f9a28d6029d1f6ce4ac0b6363ad0fa89f8b51ad750e0678918853a0e34724057f551b1b198e5a0aa012622cc8fe0618799e7371b50347bef210600e88c71eec2b4755bd8bc0091af2566f2edb5d005d28f342b9724b74787ffe520864764a4084d139087cc46ee03c531fa79aeb67c54d2329b00ed6094dad8e3e5bee983385cb7748dfc7f46e8b7f7e2ccf07f525fa134210e2f08935722da7489eee3f0020e700719dd04476018db627b7c2242f1834716d1fc090141810a1181bc0f36af5c58d55adf2ff1d0eda7c05ad475a100de6520859ea253e95997e42c2cc0d0eff6bab402690971cbbc8433d28b3667555ca400dd4a62f57f8764f0d26fc6937ec550a4cdb2f225f05b0664c92b4664c5289db0309c39f0751c3b627b8677a44627d76228605c62330eb3c5097b83d71ce2621703ab16e5228ec6362b745813f449ad91e110a091da116135afc3a454bd9f6381784876c147184a436999de94c520a3a53b8cc394cd5492b0c8cc34e4c8e2e720f3cb6737342cb263ace023f320acc45266bc3f16329c8d676a52e3f47726c7929958b934543dbc710c7346270dfd2214a37b27459c17e5253b05e584b6e608826fddf686f8670e91824f7364dbe84886b7bbb351c4a314f66800b6a43cb248710b0a8393964eac041a4ed89672e2e3f4dfc02404e051dc816a696df5fcf18c018f97aa80ed809a65b01120a4379ff4b536c4c805cc5c65c48a4e28f0f77f35021dd83be2dee4eca06f210015c63ac9c431dbb0e76dea7c7015fe9ab34b954a75b61a1763aacbae039219de30d9f13ce21872e8121f135ab2f64bbe12fcba87f31301f687107cb9745a2536933a2a34e1d61fee629fd8a9303ac932e3b3d2db10fbf2cc650ae928543b24f75579ae84206e54283562dce076ee5cae05e35fb80249e800e29bc7baa1bbada6f26105da800baf55e3d46a07204905f6b3843d6e1488d86e906ffbb9e73815dac3fbfdce540f3b38a58e362603019d54d1cf72c3e1424fbfd41b9cf344587acb55c93e7472d0b9cdd74dd8efa58554be413b428802ee3a2850e7766ba63417dbd4eea6e333ef6a0c20800e2870bb394a56bc100d13c36733d1282c2f7581da7a45b513144605b40ad698523f3442f16155b788272363d99e80bbabb7a6b1b42e2520af3a413b147ad550ce4172b013384334b08cc5ad514a66404ffe558f871e51cc8780348311af55ce58b6c428423c573c161360fb66aaa6f2ed8e14c6245b82f580e090f043a8c515221252fe6835f473a44270299f64e5a22e0be190ee0790706fe296c1f852e2e41e14a66ec8d6048d350e276a721f83b335af73960bb066eeb53932281e83e5ff3f7d61305a7467761c31d52278f534357164622efb8a529c4c99c3feff62c372a3ec076cf20fa4008aefc255d9b0c13186d0877d9ad8d214aed68355fd48b516ab3ad7088c65e06dbc6895c7600841b05fc072d4fd1bd37363d6b562d7fcb181e26bc0dc86afe101ef5382f4d8b02172c18e5431ca0481623c4bb43e95c5c620f5b3169830ad14eed6f0b57bc5ade16fcdf473c2f1a3e136f7f27486d5f083f48c41c197b718d4118215f26f2193e680d08ed3c6527de13b21b1565de17a1737624383f4b7c915102421945a699f031ce8c0d4604ea3c0d205d51524421094dc9f04947249b7722efde9b148731333fde196d506abbe76f346d023dce629f03c0bb5917167886249762f87724c37f5d293d335d5608a12a4cf6e946bb225f36111fbc344e1dbf036debaf31e134c852358698172f286207d7d1ae2b8781d37123275b1507b2a60a72d4a471fff6cd150efafa1eea52763c195ab70ae3485874774c94527e370e583a4cd118b6087c31a4b15c62cc5b82316a8fec1dd24c414d66fc1d1dc7a83c3ec49256668bbd0757165cdc55726659245ed13772d3a5e64bb76dc64ad7868360ba847800243daa334c19802c179ea93c6eeb2656db83926edea62c01edee2c13384f93108b932e4b6999083b31154972a880f53e9cf36a4a11dcd11bf3a7e2599fbe8841809ddb045cab1b1758d1b72307eb2f1293952c6db3d9a311a11ea1657354622e8254fb33ba8b6d55da6b272935d47106afc51c60a4bb3a420a2ba40a5d2d8e05117f43562c808519576bde40c8a1aa4d30960b0118de0a275b977471e68b063f3299982b0a242b0794f28a19bed1ac64b1fe9c0c79c1d84d8baaeb393d55441ed17e7d54ca320668515e93546fda6b5bb5e5eb185f190156b254b062ef596f64612bfa0f40411e54fa0c66024d4663117c458d045d191718c9a02a061e21866373b65d6b5dca2273cf0e5c00dd8ef1727b95950f803736743fdcc739662df927f1c08209b8e60b42ce83d50130fea66f6679e16b4b32492f4c92f87049488738352f0a0591b26123dda60223d3955762fafe8e5c40d62574c09e765414520e297ec012025e70794f8a50e35782e043390c4c0b262e14690e3328897069a95551aa7b0c4d420603377d96250890b0f52b4933e719ebd86901bf26f8438ca439159405ed0b7f244b554b0b184cb65bc86d6af83415ef19fd4c17a5a5667ef2481d08f01c24f824026e3c534119da3c8654ade008263980db6bbca21203bb77e165489a6d0f96edad6d43b33d1f10f38b2f9c33dd2c0e23fb58be4d3e2d1420606086517b6c83dcdb738b8dcb73d4555b0733de2302122a20611848433710dba324931dd54c8ecb725cfc5a492977988148d4ae5046b5ed5f576c18715440df542d99cc2953151dcc4ab3f0763d59ee2c68d6b8516d3bd9c054a6efb933de69a824b8f00c1773826c0a6ec48d691cf0065d5c098f5ef80d92026407955585459e4fe532425f4b52cb12c58e5b35c1af6805b55eae53d5b89171f1ae00438d9293436afd480864685068f1e508640c740e305ecbc05525d3355cbf707a717bab58295b00de30cd8bed118fa9471321ddfa56f634035efd8609401791d34da6f7900c5b4bcf5bb3cbc439af948c10b0dcbd5d8b504d4fc7f2055d27cd3424e532fd708e5b780a47039d3980cb616c8cd02441b16f05618bcf083413515a689219a8242394a231f2b96341d9802d300fe04273d0d07c6ec87dd50d41e27e0ce10424617be9c4414bb69c3a4a453005a14f4404b3a93b729ce36b1abba81b2c7c2f335b3cfa971da9cad2433a8a4d6dfbafee30148b0862a63ca505fb5bc01e9b5f8127f2796a544788903faca0dc411e8b1949621e923bd08a9f2f087e10271b912111c0d9a13f3e430b0cf47d1064578abd4deadfb3392a07bf480b47fe330e986f69dca41a4fedfa1129031d16616a3ba058482cdd66c9fa3568fb22385faf94ab0c1f06ad0e1bfe0c1e02f5046e38e423096f41ef6b1beca83ae9a0f94fe7fe8d6d8c05c320fbe8ca4a56cb4a0434dfe35d6828cb0750af8a5f433e155270b79126f7f3eb75ef3a0360cbb3470e0dd7481d06bb2e4cae69472184751a6ab06bf614ecfbc245d6fba5413870c70696d1980249f7324f1b3cf774f6395407951aff671cd5df0a9608bc2cd8f1b76e4b3a816c579135413046d74355f4e85a027ea14695203550be77dd5ae5accd676de1e927dbdc1a5a1a513137f6637956b66d1251c2907e640e59b237eba4440b5a719048e2715f268c8bdc6c778f784c8040f7440929a813833cb063d0c7df54ce5d8931f226215fa270cc20fc09f50d48346e2ceb8d3c12e5a41e65459da108b41872605e7166726aecdc0ff629ce61a8f5ab139dba59174878cc740cc4240a4af3663675d2aa089616377475bf31693d4683236687ce6351d14f203a2a36182590e03e1a826b54091cae3d7f217102a03e5966f0937f4a38d55b6a63fe543c9aec23313068930bb9db13432f0db41ce44c5a10e2788205b16c6b58d8049774b8d2ee1c9dbb946286d62c60db5d15468a25b111595acd6db26ac76c8bdef54f7dbcba4707341d21a5559f3617d7394314a1e4101ece3c4f753ae84af6aebd02fe86651a17c7993241f7f706316ece639a2eb82015283437d582002f01b3f51f1d5c835389ef09653454614c8dda2c16c390022f26b8730fac72b90e0d7a86174698130bd2a7691436d1825fb9429b74e234680083bc4f442d0dcf217e16f802ed9d732ccb3c81437ede422f3db7da17f1baaa1441ed9a684ee9706a37699f3a1e26b31ad40a62505d8c6f481709a151863a371c30968700e2afe032a868e31953e38a5a5ad6bf19826d8306bf893f2cf0ddb8097c3e9d698e9e0b1e303d1e6455fc964e5182da0d74c687123117ba3e38be8c2d1e47c6350a8a2b15073ce06e9d752d1d512f7906bfbeea0caef4e84885ea47638f631b5585c71c349056b342c0497322c176802d6d2160627a32b64830afea0b6fa7653bf0a64d25786b626e6313bd1c9d60c704aec5874d85e1c449ba92125d42b487262a60c13655029b283bf7f518d97b562ee1331d0fef554a380892210635c33b776185063c84d8f138bbdd6b055a8e906572a2123be7c0463bdf8ff700d339d6591038a86cef760b4c258b5554c6590a4c3667913a458d47595dfa97147b84f42e5c9667507dd87b638a66910c6bbe74032357222821d9944f86850977460a1a6f07805b3351200447f4f21e30f29476134478332024e3e71432b5925d362cdc3c4d36e84a03db2d434b30155e7980b71698b17c0207cb3466b367a66f86d800494620a91603be63443e4b600eaeddd36314942e3f42cd2f5cbd4ea5263c768c6bbe7b7f3d0cf5550fbd2e527136ff7909a569480f2543fa1f069fb20f63587d03f1c6210069a3113d56122d721cf97f5238b6286f7a3c2f0c80562275de9f2034ebd25a4eb5cf466e672274031109ba6f193cdf3d2fd8b921d5febb6568e16249841f3b5e07d38850d82b70662f8ba1503edc004de6b1d663eb70ef0417ded568d9c6036ac2129a1e72ee0f4ad734f2734ff37c6175ffaa76f0c23a3045b41d5219879e43579a464eaa5c5332d0e1e30bfaf8115534015a0ed307617499ad3927a6ee3e0199ac77469562b65f6adc294dc52e7e10e073e10d1c47da64c65a2f6df48eb30bad95da206fee1d0128352544cce1af70dd6b5f746fb0fa0d1eb4bb1a0082df3ccb13b211141dc832a4ca9869674e7501b2ede238ce830a6faefefe3dd488c50c35103f623072c362776e603c31bdf9185c57ee3d22af084439d5174b5a411544bb1d7c552a1530124cfae02d44e3b01d4491965953a97424e6a7e9452b8821070379943e9076b544ac496a3ea1d77259ec81886bbf5e8848c667e977acbb067401fa2864c6056964d535ba6f50eae5545b3bbc600ab5d41efde69431ec72880356acf20937b2a8559ec8056c803a9b0ed4c5634b69461351b0aedc244133316aee14be5b4c8aa36382caba077edf901006a952253a49042e25592d585af0155f1c3c792850e060053b05df54aeb4b5550c753b10e9739c35639ca6671f5184774728630c296e6f0180059319da026b737e584413313aab4aaf81853523f1cf6a026a7f49195fc249d4dc07083f3ee009192d4b1fa61b067246a8215b6dda697488fd446fb1c0520dad8ea12b94de0d482d1527617413632a5143ee4f64d168196a5161661b4f39126891c36b2bad290ede002620bf04b61847a1c832ccd712123bb4e25740f6c426904c5207d5be3d220ece7a0c3f6cb570dd7cef21b8f41424aa30bc6c2ceba2231a40f21e4eb54d40afded2109f01ff14bf827b463b857708d03ce35ba0acc5566e334d39acd06f371959f163bb17000fd0c98c41a364a946fc0e0a14206afb1dc808fc28abc62a108a19a51f69fe562764b79c6eddb7f91d3e61f246fb15fa37980acc7143e35c3038a37661960f5a27d5597e73894ebc125e575471ab7266021c8fd45d05de03632cce044f146407761992744c2f80426a0d514a66954cdd2424431a1ff9d4d700699cc328202c6c3857a3d64c108fdf511c159d0740559b44de43ad4c86c1f5622730525cf42fb0706f65963ab3c4e82fa4df6d4dde111c17653d9e641a48410f2457394605e41566d471cd6033c3835c0e74fd6b52c74319ff291a1de050176ae54da56933aebe75dd307757ea886313bb374c097547b4346a793f2bd0e52b2122b9df775f9f971f223351560aa05f03fc906461f69b7f7704217901ec3b0a1c1df40d71d502b3056094c66be3abb446f2416456a15e62331073c41af4838156aa84b52b82e9a864e788f93ed1b69e0576c45956402b6f2c81f824323a841236f17d0546cf5aef2d6c191142e1e7c317dc8bd84e1c0e8d50ae3560185101e2056023ff3f8d63a518dc6bc55320aaf1065a66f83cc7bb362a0386b0192139874a891f11041f1f9c5332a21e274375a1284db1b10115103c3f997ded0220655515362141662d0c45057e229546413fca009d7fbc71a8c021262451a275a8d9cf015465ee6da99b5d6c4e1a00317f52dc264591e367e9dfce20731cde12c92dec72d3f5802ca644264d3872a5540ccdfa45b16b2a13384aa8446e48e243cb0d750e51778b621e60cb09a79a7334fb59c43ef348b4623ff0e707e85d7c6509492876c9e5aa2537c8e70ccf19e301eabd7977dcdf61128747490cbade7275201ef74595fd540c918ea24926801c415ad7865f4ae3bb414b6d841024168a4dc9ffb03db266722900689f49a1c6a02af247df03002a014130c05661c55c0c2d8bc8813bc0bc0e5559fd750bc3223d657d6f3f02ac65d201bb8b1f4cce59757308fe442c3cd47e0933df1a6930b41a52dd1bec334f5fbb686b56bb72c003b1117fda812cf0f37e1a82aea9482a167e08b0c5993db3d8d971669fb949b904e328e0408e4a7ff7db6e1b67c1159803f038587fea2f2f4e621613fd65132f13fc6d0388040df0e8b4167f0f8b0890f08e5fd510b95525f69403877aac000b82800a8fdeca20dd75195b647c2c35b6240448b319dc721162bd3df5b1e814483a756f85e0520e5474e82cbe53d765ad2c012c455ac21edcada94fc028685cf67f5b088ee2ed4a0cf95c526e110303b78637564928e42d26c62f4407b81f546cdbd829a5f85d12355d7e3e2488a2158622e36b86dd94652f5c3355b5ac0d47ac0019143d50173f650ec11af619561e3c47db451954bd1f7e7f9e3e798de26e1938ab451e639c1a312b4c557a2e75543439da40033cfc5c96cc6e0d24e7e7157872876e3e11d00cbc614c7670a6d4172564664089c30b573159961e012e9f3fbe0748459ad19d026f66cf028467d53fd759451696afda6537831944e2f984136e0d5d025f6dba4138e7e11641015d37981d2e025ea2b13946268e47ace15829c09f69419d2ea148ff606506928b283bbd95f9093e0d3453b8421d4aae27c572e7aa01647137e956ae3f4d6d6efd2f29990c48196aabbe499ee39470adc6435c633fe1466b8d6a6e83202d5726488e68c4a29048cb69786b8ae9e21c20e90f622c7ec766f869fd3fba0ebe0031cd1e27c4d11c2341da770620007e2a17ec4c2cb96c6742610a8253a356c21d257ced3ce1fe81325f4e887257b61f70edbc6a6030606852b942de618369730f9939756f3b5fbd06325a5e3c24bbf50ef581f43e9a1b5e4028d505505fa5a40513269f532419e5676c82ed033af0fe0b1509a56263ede05751f59f578d5f6d4db16c703902a6d41bf0893d5ccaa3b36f8173d05f98abda186189c6516fd5e82fb481890a3db955113ea3d8510f90514c8302573ef16c341e1370b555ca749a48a555590be946781e8ed24120813a944630b6b939253e7a6f8a254527353e0a68093db80a28489c0a9deaab0c246edf434d36a74e17eb6d2ebbb8a32254ca383df314bb20dbea291a37f5166bc701d773f3b78205b543bd014eb93251e8f9741eb3811051358f693fe4d5d135786db32f839715243f863f2374bcba556088b45035f3b11abb36de4478594b4e93b43b0dbcdd82217353b673f39c9e00d746f477a0fcea2a04abf70ee09eca6dba3323003887b63c7a30cc0b68fb50307d97a96bf90fd24d346f4775398dd076d9551b199c00b2467cd0ae6aab76dc6c6a1e595f045327245fde42572f8e5d4d3115aa0192fc36486994974dbd545e1aa1549d6e4b8b3d2cf496066eac180530cc5dc54f10639e7277423627ae69e257452974127a95cb50a58a193c7e269b12f6fc9c53ca90a069ef866d3da4181122caebea632dd6593130b378761a339a3d615aaa3172b08b27217125104d8b05640d348a1fb569ee5d19a18b4bbacfba6b55b1382336e2f44334a1c456bb86a16700f54575413aa20ac7d5e259f514950c557c2e37fb7b643c279d95320a3d142d4a3be83abaa6b71ca2ee5f581d09c505b40141085d891e4ad486dd486d57dd383061923cb574792c66bc725f5df79117212e4b1e17e564213de2480f0fc821099b5f2f4d6aed471dde71e269ee4fce722d627c4c5312d23dbf55060d05bcb358e6e6c46a2f77694a17b642683934cb47115c503c5f1bb23be55c090a1893403916a7d34b780aed53132300541cd19a06aa48e864f153493454396870438a6f4d8238fe62f1f4216c5119014204d8b7395cf8244a1d449a176e2eef0ac2accc48effafe1d1cf72829ef240b1634bd3918381f8f08c724e467589fcf1821c0f112b1c7de64b84b035e48c2fd0f66d4f73c2d95633cd3bba5061385fb3bd5cf3d345f75020c7330f44ff9dafc41d0751101d104515ad7bfc222daa9ce008e963053e99ebf5d28ee9c242162ab3e63620148f61b1e0865b43857b84d1d610a046f0b2fac9f0ccd5c54457ad4ce39106fe42291c9262a7f6a68165aef252ea15e5b08d1938e6bdb142d4b4ca71921f8922911b4df0f6ff4d46b26e7bb54529a00302a5479df2070e9896cf27c3204c0f19c4223060f48b7066a535217d050000000000900000000000000706f736569646f6e3262d97bc46d0a877acb857043cbb90a6beafa21c97f01472952fd28be15b4750800000000b0282000c6f44f45b5d84773736d2f7d16af1bcf85f9350c8912e63a447397d0d6eae6ed00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000010000000004000000000000000100007800000000000000000000000000000000000000000a12bed13d02e3b2864daeb2405ce14658388b804007aa3cac696762cfe35fdb" } }
 const PUBS = "0x040000000000000001000078"
 const VK = "0x32e1a33f3988c3cdf127e709cc0323a258b28df750b7a2d5ddc4c5e37f007d99"
 
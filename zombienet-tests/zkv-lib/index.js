@@ -164,8 +164,11 @@ async function waitForEvent(api, timeout, pallet, name) {
     return retVal;
 }
 
-exports.registerVk = async (pallet, signer, vk) => {
-    return await submitExtrinsic(api, pallet.registerVk(vk), signer, BlockUntil.InBlock,
+exports.registerVk = async (pallet, signer, vk, metadata = null, expiry = null) => {
+    const extrinsic = metadata === null && expiry === null
+        ? pallet.registerVk(vk)
+        : pallet.registerVkWithOptions(vk, metadata, expiry);
+    return await submitExtrinsic(api, extrinsic, signer, BlockUntil.InBlock,
         (event) => event.section == "settlementFFlonkPallet" && event.method == "VkRegistered"
     )
 }